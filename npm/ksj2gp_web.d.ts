@@ -49,8 +49,22 @@ export function convert_shp(
 	translate_colnames: boolean,
 	translate_contents: boolean,
 	ignore_translation_errors: boolean
-): void;
+): any;
+
+export function list_codelists(): string[];
+
+export function list_codes(name: string): [string, string][];
+
+export function list_columns(
+	ksj_id: string,
+	year: number,
+	target_shp: string
+): { col_id: string; name: string; codelist: string | null }[];
+
+export function list_ksj_ids(): string[];
 
 export function list_shp_files(zip_file: File): string[];
 
+export function lookup_code(name: string, code: string): string | undefined;
+
 export function transform(src: Projection, dst: Projection, point: Point): void;
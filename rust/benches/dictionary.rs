@@ -0,0 +1,81 @@
+//! Compare the lookup of the codelists and the column names (sorted slices
+//! with binary search) against `HashMap`, which was used before.
+//!
+//! Run with `cargo bench --bench dictionary`.
+
+use std::{collections::HashMap, hint::black_box, time::Instant};
+
+const ITERATIONS: usize = 1_000_000;
+
+fn main() {
+    for name in ["MED_CLASS_CD", "LAND_USE_CD_09", "WelfareFacMiclassCdH27"] {
+        let codelist = ksj2gp::find_codelist(name).unwrap();
+        let codes: Vec<&str> = codelist.iter().map(|(code, _)| code).collect();
+        println!("{name} ({} codes)", codelist.len());
+
+        // The cost of building the HashMap at first access
+        let start = Instant::now();
+        let map: HashMap<&str, &str> = black_box(codelist.iter().collect());
+        println!("  HashMap build:       {:>10.2?}", start.elapsed());
+
+        let start = Instant::now();
+        for i in 0..ITERATIONS {
+            black_box(map.get(black_box(codes[i % codes.len()])));
+        }
+        println!(
+            "  HashMap lookup:      {:>10.2?}",
+            start.elapsed() / ITERATIONS as u32
+        );
+
+        let start = Instant::now();
+        for i in 0..ITERATIONS {
+            black_box(codelist.get(black_box(codes[i % codes.len()])));
+        }
+        println!(
+            "  sorted slice lookup: {:>10.2?}",
+            start.elapsed() / ITERATIONS as u32
+        );
+    }
+
+    // The column names were in one HashMap of all the KSJ ids
+    let start = Instant::now();
+    let map: HashMap<&str, &str> = black_box(
+        ksj2gp::list_ksj_ids()
+            .into_iter()
+            .flat_map(|ksj_id| ksj2gp::list_columns(ksj_id, u16::MAX, "").unwrap())
+            .map(|column| (column.col_id, column.name))
+            .collect(),
+    );
+    println!("column names ({} columns)", map.len());
+    println!("  HashMap build:       {:>10.2?}", start.elapsed());
+
+    for ksj_id in ["A03", "A55", "P04"] {
+        let col_ids: Vec<&str> = ksj2gp::list_columns(ksj_id, u16::MAX, "")
+            .unwrap()
+            .into_iter()
+            .map(|column| column.col_id)
+            .collect();
+        println!("  {ksj_id} ({} columns)", col_ids.len());
+
+        let start = Instant::now();
+        for i in 0..ITERATIONS {
+            black_box(map.get(black_box(col_ids[i % col_ids.len()])));
+        }
+        println!(
+            "    HashMap lookup:      {:>10.2?}",
+            start.elapsed() / ITERATIONS as u32
+        );
+
+        let start = Instant::now();
+        for i in 0..ITERATIONS {
+            black_box(ksj2gp::lookup_colname(
+                black_box(ksj_id),
+                black_box(col_ids[i % col_ids.len()]),
+            ));
+        }
+        println!(
+            "    sorted slice lookup: {:>10.2?}",
+            start.elapsed() / ITERATIONS as u32
+        );
+    }
+}
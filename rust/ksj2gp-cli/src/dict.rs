@@ -0,0 +1,83 @@
+use clap::Subcommand;
+use ksj2gp::{Ksj2GpError, list_codelists, list_codes, list_columns, list_ksj_ids, lookup_code};
+
+#[derive(Subcommand, Debug)]
+pub enum DictCommand {
+    /// List the KSJ ids whose column names are available
+    Ids,
+
+    /// List the columns of the KSJ id
+    Columns {
+        /// KSJ id (e.g. P04)
+        ksj_id: String,
+
+        /// Year of the data. Some columns use different codelists by year. If
+        /// not specified, the latest spec is used.
+        #[arg(long)]
+        year: Option<u16>,
+
+        /// Name of the shapefile. Some columns use different codelists by
+        /// shapefile (e.g. A03_006).
+        #[arg(long, default_value = "")]
+        target_shp: String,
+    },
+
+    /// List the names of the codelists
+    Codelists,
+
+    /// List the codes of the codelist, or look up the label of the code
+    Codes {
+        /// Codelist name (e.g. MED_CLASS_CD) or column name (e.g. P04_001)
+        name: String,
+
+        /// Code to look up
+        code: Option<String>,
+    },
+}
+
+pub fn run_dict(command: DictCommand) -> Result<(), Ksj2GpError> {
+    match command {
+        DictCommand::Ids => {
+            for ksj_id in list_ksj_ids() {
+                println!("{ksj_id}");
+            }
+        }
+        DictCommand::Columns {
+            ksj_id,
+            year,
+            target_shp,
+        } => {
+            let columns = list_columns(&ksj_id, year.unwrap_or(u16::MAX), &target_shp)
+                .ok_or_else(|| format!("Unknown KSJ id: {ksj_id}"))?;
+            for column in columns {
+                println!(
+                    "{}\t{}\t{}",
+                    column.col_id,
+                    column.name,
+                    column.codelist.unwrap_or("")
+                );
+            }
+        }
+        DictCommand::Codelists => {
+            for name in list_codelists() {
+                println!("{name}");
+            }
+        }
+        DictCommand::Codes { name, code: None } => {
+            let codes = list_codes(&name).ok_or_else(|| format!("Unknown codelist: {name}"))?;
+            for (code, label) in codes {
+                println!("{code}\t{label}");
+            }
+        }
+        DictCommand::Codes {
+            name,
+            code: Some(code),
+        } => {
+            let label = lookup_code(&name, &code)
+                .ok_or_else(|| format!("Unknown code {code} for {name}"))?;
+            println!("{label}");
+        }
+    }
+
+    Ok(())
+}
@@ -1,26 +1,255 @@
 use std::path::PathBuf;
 
-use clap::Parser;
+use clap::{Parser, Subcommand, ValueEnum};
 use ksj2gp::{
-    Ksj2GpError, TranslateOptions, convert_shp_inner, encode_utf8_to_cp437cp932, extract_ksj_id,
+    ArrowIpcOptions, CodelistValidation, CsvGeometry, CsvOptions, DatasetOptions, GeoJsonOptions,
+    GeoParquetOptions, KmlOptions, Ksj2GpError, ParquetCompression, ParquetStatistics,
+    PmTilesOptions, PostgisOptions, RowGroupSize, ShapefileOptions, SpatialSortKey,
+    SpatialSortOptions, TopoJsonOptions, TranslateOptions, TranslationReport, WriterOptions,
+    convert_shp_dataset, convert_shp_inner, encode_utf8_to_cp437cp932, extract_ksj_id,
     find_meta_xml, list_shp_files,
 };
 
+use crate::dict::{DictCommand, run_dict};
+
+mod dict;
+
 #[derive(Parser, Debug)]
-#[command(version, about, long_about = None)]
+#[command(
+    version,
+    about,
+    long_about = None,
+    args_conflicts_with_subcommands = true,
+    subcommand_negates_reqs = true
+)]
 struct Args {
+    #[command(subcommand)]
+    command: Option<Command>,
+
     /// Path to ZIP file
-    zip: PathBuf,
+    #[arg(required = true)]
+    zip: Option<PathBuf>,
 
     /// Path to output file
-    out: PathBuf,
+    #[arg(required = true)]
+    out: Option<PathBuf>,
+
+    /// Keep the original names of the columns that cannot be translated
+    #[arg(long)]
+    ignore_translation_errors: bool,
+
+    /// Path to write the JSON report of the untranslated columns and values
+    #[arg(long)]
+    report: Option<PathBuf>,
+
+    /// Fail if a value doesn't match the codelist
+    #[arg(long, conflicts_with = "rejects")]
+    strict_codes: bool,
+
+    /// Path to write the values that don't match the codelist as CSV
+    #[arg(long)]
+    rejects: Option<PathBuf>,
+
+    /// GeoJSON: follow RFC 7946 strictly (no "crs" member, counter-clockwise exterior rings)
+    #[arg(long)]
+    rfc7946: bool,
+
+    /// GeoJSON: make the exterior rings counter-clockwise
+    #[arg(long)]
+    force_ccw: bool,
+
+    /// GeoJSON: round the coordinates to the number of decimal places (0-15)
+    #[arg(long, value_parser = clap::value_parser!(u32).range(0..=15))]
+    precision: Option<u32>,
+
+    /// GeoJSON: add "bbox" to each feature and to the FeatureCollection
+    #[arg(long)]
+    bbox: bool,
+
+    /// CSV: how to write the geometry
+    #[arg(long, value_enum, default_value_t = CsvGeometryArg::Wkt)]
+    csv_geometry: CsvGeometryArg,
+
+    /// CSV: write UTF-8 BOM so that Excel can open the file without mojibake
+    #[arg(long)]
+    bom: bool,
+
+    /// Arrow IPC and GeoParquet: use the native GeoArrow encodings instead of
+    /// WKB (falls back to WKB if the shape type has no matching encoding)
+    #[arg(long)]
+    native_encoding: bool,
+
+    /// GeoParquet: compression codec
+    #[arg(long, value_enum, default_value_t = CompressionArg::None)]
+    compression: CompressionArg,
+
+    /// GeoParquet: compression level of zstd (1-22)
+    #[arg(long)]
+    zstd_level: Option<i32>,
+
+    /// GeoParquet: maximum number of rows in a row group
+    #[arg(long, conflicts_with = "row_group_bytes")]
+    row_group_rows: Option<usize>,
+
+    /// GeoParquet: maximum bytes of a row group (before compression)
+    #[arg(long)]
+    row_group_bytes: Option<usize>,
+
+    /// GeoParquet: disable dictionary encoding
+    #[arg(long)]
+    no_dictionary: bool,
+
+    /// GeoParquet: level of the column statistics
+    #[arg(long, value_enum, default_value_t = StatisticsArg::Page)]
+    statistics: StatisticsArg,
+
+    /// GeoParquet: sort the features spatially by the space-filling curve
+    #[arg(long, value_enum)]
+    spatial_sort: Option<SpatialSortArg>,
+
+    /// GeoParquet: memory budget of the spatial sort in MiB. Exceeding data is
+    /// spilled to temporary files.
+    #[arg(long, default_value_t = 256)]
+    sort_memory_mb: usize,
+
+    /// Write a Hive-partitioned GeoParquet dataset to the output directory
+    /// (e.g. <OUT>/ksj_id=N03/year=2024/part-0.parquet)
+    #[arg(long)]
+    dataset: bool,
+
+    /// Dataset: the column to partition by (the original name, e.g. N03_007)
+    #[arg(long, requires = "dataset")]
+    partition_column: Option<String>,
+
+    /// Dataset: the key of the partition in the path (default: the column name)
+    #[arg(long, requires = "partition_column")]
+    partition_key: Option<String>,
+
+    /// Dataset: use only the first characters of the value
+    #[arg(long, requires = "partition_column")]
+    partition_prefix_len: Option<usize>,
+
+    /// Dataset: the maximum number of the files to write at the same time
+    #[arg(long, default_value_t = 64, requires = "dataset")]
+    max_open_files: usize,
+
+    /// Shapefile: transform the coordinates to WGS84
+    #[arg(long)]
+    reproject: bool,
+
+    /// KML/KMZ: the column to use as the label of the placemarks (either the
+    /// original or the translated name)
+    #[arg(long)]
+    kml_name_column: Option<String>,
+
+    /// PMTiles: the minimum zoom level
+    #[arg(long, default_value_t = 0)]
+    min_zoom: u8,
+
+    /// PMTiles: the maximum zoom level
+    #[arg(long, default_value_t = 10)]
+    max_zoom: u8,
+
+    /// PMTiles: the tolerance of the simplification in pixels (0 to disable)
+    #[arg(long, default_value_t = 1.0)]
+    simplification: f64,
+
+    /// PMTiles: the name of the layer (default: the name of the Shapefile)
+    #[arg(long)]
+    layer_name: Option<String>,
+
+    /// TopoJSON: the number of the quantized positions in each dimension (0
+    /// to disable the quantization)
+    #[arg(long, default_value_t = 100_000)]
+    quantization: u32,
+
+    /// PostGIS SQL: the name of the table (default: the name of the
+    /// Shapefile)
+    #[arg(long)]
+    table_name: Option<String>,
+
+    /// PostGIS SQL: the schema of the table
+    #[arg(long)]
+    table_schema: Option<String>,
+
+    /// PostGIS SQL: drop the table if it already exists
+    #[arg(long)]
+    drop_table: bool,
+}
+
+#[derive(ValueEnum, Clone, Copy, Debug)]
+enum SpatialSortArg {
+    /// Hilbert curve
+    Hilbert,
+    /// Z-order curve (Morton code)
+    Zorder,
+}
+
+#[derive(ValueEnum, Clone, Copy, Debug)]
+enum CompressionArg {
+    None,
+    Snappy,
+    Zstd,
+}
+
+#[derive(ValueEnum, Clone, Copy, Debug)]
+enum StatisticsArg {
+    /// No statistics
+    None,
+    /// Per row group
+    Chunk,
+    /// Per page and per row group
+    Page,
+}
+
+impl From<StatisticsArg> for ParquetStatistics {
+    fn from(value: StatisticsArg) -> Self {
+        match value {
+            StatisticsArg::None => ParquetStatistics::None,
+            StatisticsArg::Chunk => ParquetStatistics::Chunk,
+            StatisticsArg::Page => ParquetStatistics::Page,
+        }
+    }
+}
+
+#[derive(ValueEnum, Clone, Copy, Debug)]
+enum CsvGeometryArg {
+    /// WKT
+    Wkt,
+    /// Hex-encoded WKB
+    Wkb,
+    /// Longitude and latitude columns (points only)
+    Lonlat,
+}
+
+impl From<CsvGeometryArg> for CsvGeometry {
+    fn from(value: CsvGeometryArg) -> Self {
+        match value {
+            CsvGeometryArg::Wkt => CsvGeometry::Wkt,
+            CsvGeometryArg::Wkb => CsvGeometry::Wkb,
+            CsvGeometryArg::Lonlat => CsvGeometry::LonLat,
+        }
+    }
+}
+
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// Look up the column names and the codelists
+    Dict {
+        #[command(subcommand)]
+        command: DictCommand,
+    },
 }
 
 pub fn convert_shp_fs(
     zip_file: PathBuf,
     target_shp: &str,
     output_file: PathBuf,
-) -> Result<(), Ksj2GpError> {
+    ignore_translation_errors: bool,
+    codelist_validation: CodelistValidation,
+    writer_options: WriterOptions,
+    dataset: bool,
+) -> Result<TranslationReport, Ksj2GpError> {
     let filename = zip_file.file_name().unwrap().to_string_lossy().to_string();
     let (ksj_id, year) = extract_ksj_id(&filename)?;
 
@@ -35,11 +264,55 @@ pub fn convert_shp_fs(
     let tmp_dbf_file_path = tempfile::NamedTempFile::with_suffix(".dbf")?;
     let tmp_shx_file_path = tempfile::NamedTempFile::with_suffix(".shx")?;
 
+    let translate_options = TranslateOptions {
+        // TODO: pass this option from outside
+        translate_colnames: true,
+        translate_contents: true,
+        ignore_translation_errors,
+        ksj_id,
+        year,
+        target_shp: target_shp.to_string(),
+        codelist_validation,
+    };
+
+    if dataset {
+        return convert_shp_dataset(
+            zip,
+            &encode_utf8_to_cp437cp932(target_shp)?,
+            meta_xml_filename,
+            tmp_shp_file_path,
+            tmp_dbf_file_path,
+            tmp_shx_file_path,
+            |path| {
+                let path = output_file.join(path);
+                if let Some(dir) = path.parent() {
+                    std::fs::create_dir_all(dir)?;
+                }
+                Ok(std::io::BufWriter::new(std::fs::File::create(path)?))
+            },
+            translate_options,
+            writer_options,
+        );
+    }
+
     let output_format = match output_file.extension() {
         Some(ext) => match ext.to_string_lossy().as_ref() {
             "geojson" => "GeoJson",
+            "geojsons" => "GeoJsonSeq",
+            "geojsonl" | "ndjson" => "GeoJsonLines",
             "parquet" => "GeoParquet",
             "gpkg" => "Gpkg",
+            "fgb" => "FlatGeobuf",
+            "csv" => "Csv",
+            "arrow" | "feather" => "ArrowIpc",
+            "arrows" => "ArrowIpcStream",
+            // Shapefile is written as a ZIP file, since it consists of multiple files
+            "zip" => "Shapefile",
+            "kml" => "Kml",
+            "kmz" => "Kmz",
+            "pmtiles" => "PmTiles",
+            "topojson" => "TopoJson",
+            "sql" => "PostgisSql",
             e => return Err(format!("Unsupported extension: {e}").into()),
         },
         None => return Err(format!("Unsupported format: {}", output_file.display()).into()),
@@ -56,24 +329,118 @@ pub fn convert_shp_fs(
         tmp_shx_file_path,
         output_file,
         output_format,
-        TranslateOptions {
-            // TODO: pass this option from outside
-            translate_colnames: true,
-            translate_contents: true,
-            ignore_translation_errors: false,
-            ksj_id,
-            year,
-            target_shp: target_shp.to_string(),
-        },
+        translate_options,
+        writer_options,
     )
 }
 
 fn main() {
     let args = Args::parse();
 
-    let file = std::fs::File::open(args.zip.clone()).unwrap();
+    if let Some(Command::Dict { command }) = args.command {
+        run_dict(command).unwrap();
+        return;
+    }
+
+    // These are required unless a subcommand is specified
+    let zip = args.zip.unwrap();
+    let out = args.out.unwrap();
+
+    let file = std::fs::File::open(zip.clone()).unwrap();
     let buf_reader = std::io::BufReader::new(file);
     let target_shp = list_shp_files(buf_reader).unwrap();
 
-    convert_shp_fs(args.zip, &target_shp[0], args.out).unwrap();
+    let codelist_validation = if args.strict_codes {
+        CodelistValidation::Strict
+    } else if args.rejects.is_some() {
+        CodelistValidation::Reject
+    } else {
+        CodelistValidation::Off
+    };
+
+    let writer_options = WriterOptions {
+        geojson: GeoJsonOptions {
+            rfc7946: args.rfc7946,
+            force_ccw: args.force_ccw,
+            precision: args.precision,
+            bbox: args.bbox,
+        },
+        csv: CsvOptions {
+            geometry: args.csv_geometry.into(),
+            bom: args.bom,
+        },
+        arrow_ipc: ArrowIpcOptions {
+            native_encoding: args.native_encoding,
+        },
+        geoparquet: GeoParquetOptions {
+            native_encoding: args.native_encoding,
+            compression: match args.compression {
+                CompressionArg::None => ParquetCompression::Uncompressed,
+                CompressionArg::Snappy => ParquetCompression::Snappy,
+                CompressionArg::Zstd => ParquetCompression::Zstd(args.zstd_level),
+            },
+            row_group_size: match (args.row_group_rows, args.row_group_bytes) {
+                (Some(rows), _) => RowGroupSize::Rows(rows),
+                (None, Some(bytes)) => RowGroupSize::Bytes(bytes),
+                (None, None) => RowGroupSize::default(),
+            },
+            dictionary: !args.no_dictionary,
+            statistics: args.statistics.into(),
+            spatial_sort: args.spatial_sort.map(|key| SpatialSortOptions {
+                key: match key {
+                    SpatialSortArg::Hilbert => SpatialSortKey::Hilbert,
+                    SpatialSortArg::Zorder => SpatialSortKey::ZOrder,
+                },
+                memory_budget: args.sort_memory_mb * 1024 * 1024,
+                spill_dir: None,
+            }),
+        },
+        dataset: DatasetOptions {
+            partition_column: args.partition_column,
+            partition_key: args.partition_key,
+            partition_prefix_len: args.partition_prefix_len,
+            max_open_files: args.max_open_files,
+        },
+        shapefile: ShapefileOptions {
+            reproject: args.reproject,
+        },
+        kml: KmlOptions {
+            name_column: args.kml_name_column,
+        },
+        pmtiles: PmTilesOptions {
+            min_zoom: args.min_zoom,
+            max_zoom: args.max_zoom,
+            simplification: args.simplification,
+            layer_name: args.layer_name,
+        },
+        topojson: TopoJsonOptions {
+            quantization: (args.quantization > 0).then_some(args.quantization),
+        },
+        postgis: PostgisOptions {
+            table_name: args.table_name,
+            schema: args.table_schema,
+            drop_table: args.drop_table,
+        },
+    };
+
+    let report = convert_shp_fs(
+        zip,
+        &target_shp[0],
+        out,
+        args.ignore_translation_errors,
+        codelist_validation,
+        writer_options,
+        args.dataset,
+    )
+    .unwrap();
+
+    if let Some(report_path) = args.report {
+        let json = serde_json::to_string_pretty(&report.to_json()).unwrap();
+        std::fs::write(report_path, json).unwrap();
+    }
+
+    if let Some(rejects_path) = args.rejects {
+        let file = std::io::BufWriter::new(std::fs::File::create(rejects_path).unwrap());
+        report.write_rejects_csv(file).unwrap();
+    }
 }
@@ -1,5 +1,6 @@
 use ksj2gp::{
-    TranslateOptions, convert_shp_inner, encode_utf8_to_cp437cp932, extract_ksj_id, find_meta_xml,
+    CodelistValidation, CsvOptions, TranslateOptions, WriterOptions, convert_shp_inner,
+    encode_utf8_to_cp437cp932, extract_ksj_id, find_meta_xml,
 };
 use wasm_bindgen::prelude::*;
 use web_sys::FileReaderSync;
@@ -50,7 +51,7 @@ pub fn convert_shp(
     translate_colnames: bool,
     translate_contents: bool,
     ignore_translation_errors: bool,
-) -> Result<(), String> {
+) -> Result<JsValue, String> {
     let filename = zip_file.name();
     let (ksj_id, year) = extract_ksj_id(&filename)?;
 
@@ -62,7 +63,7 @@ pub fn convert_shp(
     let zip = UserLocalFile::new(zip_file);
     let output_file_opfs = std::io::BufWriter::new(OpfsFile::new(output_file)?);
 
-    convert_shp_inner(
+    let report = convert_shp_inner(
         zip,
         &encode_utf8_to_cp437cp932(target_shp)?,
         meta_xml_filename,
@@ -78,8 +79,80 @@ pub fn convert_shp(
             ksj_id,
             year,
             target_shp: target_shp.to_string(),
+            codelist_validation: CodelistValidation::Off,
+        },
+        WriterOptions {
+            // Most users of the web version would open the CSV with Excel
+            csv: CsvOptions {
+                bom: true,
+                ..Default::default()
+            },
+            ..Default::default()
         },
     )?;
 
-    Ok(())
+    // Return the translation report as a plain JS object
+    let report_json = report.to_json().to_string();
+    web_sys::js_sys::JSON::parse(&report_json).map_err(|e| format!("{e:?}"))
+}
+
+#[wasm_bindgen]
+pub fn list_ksj_ids() -> Vec<String> {
+    ksj2gp::list_ksj_ids()
+        .into_iter()
+        .map(String::from)
+        .collect()
+}
+
+/// Returns an array of `{ col_id, name, codelist }`. `year` and `target_shp`
+/// are used for the columns whose codelists depend on them.
+#[wasm_bindgen]
+pub fn list_columns(ksj_id: &str, year: u16, target_shp: &str) -> Result<JsValue, String> {
+    let columns = ksj2gp::list_columns(ksj_id, year, target_shp)
+        .ok_or_else(|| format!("Unknown KSJ id: {ksj_id}"))?;
+
+    let result = web_sys::js_sys::Array::new();
+    for column in columns {
+        let obj = web_sys::js_sys::Object::new();
+        set_property(&obj, "col_id", &column.col_id.into())?;
+        set_property(&obj, "name", &column.name.into())?;
+        let codelist = column.codelist.map(JsValue::from).unwrap_or(JsValue::NULL);
+        set_property(&obj, "codelist", &codelist)?;
+        result.push(&obj);
+    }
+
+    Ok(result.into())
+}
+
+#[wasm_bindgen]
+pub fn list_codelists() -> Vec<String> {
+    ksj2gp::list_codelists()
+        .into_iter()
+        .map(String::from)
+        .collect()
+}
+
+/// Returns an array of `[code, label]`
+#[wasm_bindgen]
+pub fn list_codes(name: &str) -> Result<JsValue, String> {
+    let codes = ksj2gp::list_codes(name).ok_or_else(|| format!("Unknown codelist: {name}"))?;
+
+    let result = web_sys::js_sys::Array::new();
+    for (code, label) in codes {
+        result.push(&web_sys::js_sys::Array::of2(&code.into(), &label.into()));
+    }
+
+    Ok(result.into())
+}
+
+/// Returns the label of the code, or undefined if not found
+#[wasm_bindgen]
+pub fn lookup_code(name: &str, code: &str) -> Option<String> {
+    ksj2gp::lookup_code(name, code).map(String::from)
+}
+
+fn set_property(obj: &web_sys::js_sys::Object, key: &str, value: &JsValue) -> Result<(), String> {
+    web_sys::js_sys::Reflect::set(obj, &key.into(), value)
+        .map(|_| ())
+        .map_err(|e| format!("{e:?}"))
 }
@@ -11,22 +11,27 @@ use dbase::FieldValue;
 
 use geoarrow_schema::GeoArrowType;
 
-use std::collections::HashMap;
+use std::borrow::Cow;
+use std::collections::BTreeSet;
 use std::sync::Arc;
-use std::sync::LazyLock;
 
 use crate::translate::TranslateOptions;
+use crate::translate::TranslationReport;
 use crate::translate::get_codelist_map;
+use crate::translate::{Codelist, CodelistValidation, RejectReason};
 use crate::{error::Ksj2GpError, translate::translate_colnames};
 
 pub(crate) struct FieldsWithGeo {
     pub(crate) schema_ref: arrow_schema::SchemaRef,
     pub(crate) non_geo_fields: Vec<Arc<arrow_schema::Field>>,
     pub(crate) geoarrow_type: geoarrow_schema::GeoArrowType,
-    pub(crate) codelist_maps: Vec<Option<&'static LazyLock<HashMap<&'static str, &'static str>>>>,
+    pub(crate) codelist_maps: Vec<Option<&'static Codelist>>,
+    pub(crate) codelist_validation: CodelistValidation,
 }
 
-#[derive(Debug)]
+type CodeDictionaryBuilder =
+    arrow_array::builder::StringDictionaryBuilder<arrow_array::types::Int16Type>;
+
 pub(crate) enum NonGeoArrayBuilder {
     // PrimitiveArray
     Float64(arrow_array::builder::Float64Builder),
@@ -40,12 +45,23 @@ pub(crate) enum NonGeoArrayBuilder {
     // Timestamp(arrow_array::builder::TimestampMillisecondBuilder)
     TranslatedCode(
         arrow_array::builder::StringBuilder,
-        &'static LazyLock<HashMap<&'static str, &'static str>>,
+        &'static Codelist,
+        CodelistValidation,
     ),
+    // Same as TranslatedCode, but the labels are stored as Dictionary(Int16, Utf8)
+    TranslatedCodeDictionary(CodeDictionaryBuilder, &'static Codelist, CodelistValidation),
 }
 
 impl NonGeoArrayBuilder {
-    pub(crate) fn push(&mut self, value: FieldValue) {
+    // `col_id` (the original column name) and `row` are used for reporting
+    // untranslated values.
+    pub(crate) fn push(
+        &mut self,
+        value: FieldValue,
+        col_id: &str,
+        row: usize,
+        report: &mut TranslationReport,
+    ) -> Result<(), Ksj2GpError> {
         match (self, value) {
             (NonGeoArrayBuilder::Float64(primitive_builder), FieldValue::Numeric(v)) => {
                 if let Some(v) = v {
@@ -98,64 +114,28 @@ impl NonGeoArrayBuilder {
 
             // translated codes
             (
-                NonGeoArrayBuilder::TranslatedCode(primitive_builder, codelist_map),
-                FieldValue::Character(Some(v)) | FieldValue::Memo(v),
-            ) => match codelist_map.get(v.as_str()) {
-                Some(label) => {
-                    primitive_builder.append_value(label);
-                }
-                None => primitive_builder.append_value(v),
-            },
-            (
-                NonGeoArrayBuilder::TranslatedCode(primitive_builder, codelist_map),
-                dbase::FieldValue::Numeric(Some(v)) | dbase::FieldValue::Double(v),
-            ) => {
-                let code = format!("{v:.0}");
-                match codelist_map.get(code.as_str()) {
-                    Some(label) => {
-                        primitive_builder.append_value(label);
-                    }
-                    None => primitive_builder.append_value(code),
-                }
-            }
-            (
-                NonGeoArrayBuilder::TranslatedCode(primitive_builder, codelist_map),
-                dbase::FieldValue::Float(Some(v)),
-            ) => {
-                let code = format!("{v:.0}");
-                match codelist_map.get(code.as_str()) {
-                    Some(label) => {
-                        primitive_builder.append_value(label);
-                    }
-                    None => primitive_builder.append_value(code),
-                }
-            }
-            (
-                NonGeoArrayBuilder::TranslatedCode(primitive_builder, codelist_map),
-                dbase::FieldValue::Integer(v),
+                NonGeoArrayBuilder::TranslatedCode(string_builder, codelist_map, validation),
+                value,
             ) => {
-                let code = format!("{v:.0}");
-                match codelist_map.get(code.as_str()) {
-                    Some(label) => {
-                        primitive_builder.append_value(label);
-                    }
-                    None => primitive_builder.append_value(code),
-                }
+                let label = translate_code(value, codelist_map, *validation, col_id, row, report)?;
+                string_builder.append_option(label);
             }
             (
-                NonGeoArrayBuilder::TranslatedCode(primitive_builder, _),
-                FieldValue::Character(None) | FieldValue::Numeric(None) | FieldValue::Float(None),
+                NonGeoArrayBuilder::TranslatedCodeDictionary(
+                    dictionary_builder,
+                    codelist_map,
+                    validation,
+                ),
+                value,
             ) => {
-                primitive_builder.append_null();
-            }
-
-            (NonGeoArrayBuilder::TranslatedCode(primitive_builder, _), _) => {
-                // TODO: handle errors
-                primitive_builder.append_value("Unexpected value");
+                let label = translate_code(value, codelist_map, *validation, col_id, row, report)?;
+                dictionary_builder.append_option(label);
             }
             // type mismatch means something is wrong...
             (_, _) => unreachable!(),
         }
+
+        Ok(())
     }
 
     pub(crate) fn finish(&mut self) -> arrow_array::ArrayRef {
@@ -181,32 +161,127 @@ impl NonGeoArrayBuilder {
             NonGeoArrayBuilder::Date32(primitive_builder) => {
                 arrow_array::builder::ArrayBuilder::finish(primitive_builder)
             }
-            NonGeoArrayBuilder::TranslatedCode(primitive_builder, _) => {
+            NonGeoArrayBuilder::TranslatedCode(primitive_builder, _, _) => {
                 arrow_array::builder::ArrayBuilder::finish(primitive_builder)
             }
+            NonGeoArrayBuilder::TranslatedCodeDictionary(dictionary_builder, _, _) => {
+                arrow_array::builder::ArrayBuilder::finish(dictionary_builder)
+            }
         }
     }
 }
 
+// Returns the label for the code. If the code is not found in the codelist, the
+// raw code is returned as it is and recorded in the report. This is shared with
+// the writers that don't use the Arrow builders (e.g. GeoPackage) so that the
+// report doesn't depend on the output format.
+pub(crate) fn translate_code(
+    value: FieldValue,
+    codelist_map: &'static Codelist,
+    validation: CodelistValidation,
+    col_id: &str,
+    row: usize,
+    report: &mut TranslationReport,
+) -> Result<Option<Cow<'static, str>>, Ksj2GpError> {
+    // The second element is the original value if it's not an integer
+    let (code, lossy_value) = match value {
+        FieldValue::Character(Some(v)) | FieldValue::Memo(v) => (v, None),
+        FieldValue::Numeric(Some(v)) | FieldValue::Double(v) | FieldValue::Currency(v) => {
+            (format!("{v:.0}"), (v.fract() != 0.0).then(|| v.to_string()))
+        }
+        FieldValue::Float(Some(v)) => {
+            (format!("{v:.0}"), (v.fract() != 0.0).then(|| v.to_string()))
+        }
+        FieldValue::Integer(v) => (format!("{v:.0}"), None),
+        FieldValue::Character(None)
+        | FieldValue::Numeric(None)
+        | FieldValue::Float(None)
+        | FieldValue::Logical(None)
+        | FieldValue::Date(None) => {
+            return Ok(None);
+        }
+        value => {
+            report.record_unexpected_value(col_id);
+            let v = format!("{value:?}");
+            report.record_invalid_value(
+                validation,
+                col_id,
+                row,
+                &v,
+                RejectReason::UnexpectedType,
+            )?;
+            return Ok(Some(Cow::Borrowed("Unexpected value")));
+        }
+    };
+
+    if let Some(v) = &lossy_value {
+        report.record_invalid_value(validation, col_id, row, v, RejectReason::LossyNumber)?;
+    }
+
+    match codelist_map.get(&code) {
+        Some(label) => Ok(Some(Cow::Borrowed(label))),
+        None => {
+            report.record_unknown_code(col_id, &code);
+            // A value is rejected only once
+            if lossy_value.is_none() {
+                report.record_invalid_value(
+                    validation,
+                    col_id,
+                    row,
+                    &code,
+                    RejectReason::UnknownCode,
+                )?;
+            }
+            Ok(Some(Cow::Owned(code)))
+        }
+    }
+}
+
+// Create a dictionary builder whose dictionary is pre-populated with all the
+// labels of the codelist, so that the keys are stable across chunks.
+fn codelist_dictionary_builder(
+    capacity: usize,
+    codelist_map: &'static Codelist,
+) -> Result<CodeDictionaryBuilder, Ksj2GpError> {
+    // Sort and deduplicate the labels
+    let labels: BTreeSet<&str> = codelist_map.iter().map(|(_, label)| label).collect();
+    let dictionary_values = arrow_array::StringArray::from_iter_values(labels);
+    Ok(CodeDictionaryBuilder::new_with_dictionary(
+        capacity,
+        &dictionary_values,
+    )?)
+}
+
 pub(crate) struct ArrayBuilderWithGeo {
     pub(crate) builders: Vec<NonGeoArrayBuilder>,
     pub(crate) geo_builder: WkbBuilder<i32>,
 }
 
 impl FieldsWithGeo {
-    // TODO: return errors
-    pub(crate) fn create_builders(&self, capacity: usize) -> ArrayBuilderWithGeo {
+    pub(crate) fn create_builders(
+        &self,
+        capacity: usize,
+    ) -> Result<ArrayBuilderWithGeo, Ksj2GpError> {
         let iter = self.non_geo_fields.iter().zip(self.codelist_maps.iter());
         let builders: Vec<NonGeoArrayBuilder> = iter
             .map(|(f, codelist_map)| {
                 if let Some(codelist_map) = codelist_map {
-                    return NonGeoArrayBuilder::TranslatedCode(
+                    if let arrow_schema::DataType::Dictionary(_, _) = f.data_type() {
+                        return Ok(NonGeoArrayBuilder::TranslatedCodeDictionary(
+                            codelist_dictionary_builder(capacity, codelist_map)?,
+                            codelist_map,
+                            self.codelist_validation,
+                        ));
+                    }
+
+                    return Ok(NonGeoArrayBuilder::TranslatedCode(
                         arrow_array::builder::StringBuilder::with_capacity(capacity, capacity * 8),
                         codelist_map,
-                    );
+                        self.codelist_validation,
+                    ));
                 }
 
-                match f.data_type() {
+                let builder = match f.data_type() {
                     arrow_schema::DataType::Float64 => NonGeoArrayBuilder::Float64(
                         arrow_array::builder::Float64Builder::with_capacity(capacity),
                     ),
@@ -229,19 +304,22 @@ impl FieldsWithGeo {
                     ),
                     // arrow_schema::DataType::Timestamp(time_unit, _) => todo!(),
                     _ => unreachable!(),
-                }
+                };
+                Ok(builder)
             })
-            .collect();
+            .collect::<Result<_, Ksj2GpError>>()?;
+
+        Ok(ArrayBuilderWithGeo {
+            builders,
+            geo_builder: self.create_geo_builder(),
+        })
+    }
 
+    pub(crate) fn create_geo_builder(&self) -> WkbBuilder<i32> {
         // Use the same GeoArrow type (with CRS metadata) as in the schema
-        let geo_builder = match &self.geoarrow_type {
+        match &self.geoarrow_type {
             GeoArrowType::Wkb(wkb_type) => WkbBuilder::new(wkb_type.clone()),
             _ => unreachable!(),
-        };
-
-        ArrayBuilderWithGeo {
-            builders,
-            geo_builder,
         }
     }
 }
@@ -261,13 +339,17 @@ pub(crate) fn construct_schema(
     fields: &[FieldInfo],
     crs: geoarrow_schema::Crs,
     translate_options: &TranslateOptions,
+    report: &mut TranslationReport,
+    // If true, the translated codes are stored as Dictionary(Int16, Utf8)
+    // instead of plain Utf8. Not all the writers support dictionary arrays.
+    dictionary_encode_codes: bool,
 ) -> Result<FieldsWithGeo, Ksj2GpError> {
     let mut non_geo_fields = Vec::with_capacity(fields.len());
     let mut codelist_maps = Vec::with_capacity(fields.len());
 
     for field in fields {
         let field_name = field.name();
-        let translated_name = translate_colnames(field_name, translate_options)?;
+        let translated_name = translate_colnames(field_name, translate_options, report)?;
 
         if translate_options.translate_contents
             && let Some(codelist_map) = get_codelist_map(
@@ -277,9 +359,17 @@ pub(crate) fn construct_schema(
             )
         {
             codelist_maps.push(Some(codelist_map));
+            let data_type = if dictionary_encode_codes {
+                arrow_schema::DataType::Dictionary(
+                    Box::new(arrow_schema::DataType::Int16),
+                    Box::new(arrow_schema::DataType::Utf8),
+                )
+            } else {
+                arrow_schema::DataType::Utf8
+            };
             non_geo_fields.push(Arc::new(arrow_schema::Field::new(
                 translated_name,
-                arrow_schema::DataType::Utf8,
+                data_type,
                 true,
             )));
             continue;
@@ -332,5 +422,112 @@ pub(crate) fn construct_schema(
         non_geo_fields,
         geoarrow_type,
         codelist_maps,
+        codelist_validation: translate_options.codelist_validation,
     })
 }
+
+#[cfg(all(test, feature = "dict-codelists", feature = "dict-facility"))]
+mod tests {
+    use super::*;
+    use arrow_array::{cast::AsArray as _, types::Int16Type};
+
+    fn p04_001_builder(validation: CodelistValidation) -> NonGeoArrayBuilder {
+        let codelist_map = get_codelist_map("P04_001", 2024, "").unwrap();
+        NonGeoArrayBuilder::TranslatedCodeDictionary(
+            codelist_dictionary_builder(4, codelist_map).unwrap(),
+            codelist_map,
+            validation,
+        )
+    }
+
+    #[test]
+    fn translated_codes_are_dictionary_encoded() {
+        let mut builder = p04_001_builder(CodelistValidation::Off);
+        let mut report = TranslationReport::default();
+
+        let values = [
+            FieldValue::Character(Some("2".to_string())),
+            FieldValue::Numeric(Some(1.0)),
+            FieldValue::Character(None),
+            FieldValue::Character(Some("99".to_string())),
+        ];
+        for (row, value) in values.into_iter().enumerate() {
+            builder.push(value, "P04_001", row, &mut report).unwrap();
+        }
+
+        let array = builder.finish();
+        let array = array.as_dictionary::<Int16Type>();
+        let values = array.values().as_string::<i32>();
+        let labels: Vec<Option<&str>> = array
+            .keys()
+            .iter()
+            .map(|key| key.map(|key| values.value(key as usize)))
+            .collect();
+
+        assert_eq!(labels, vec![Some("診療所"), Some("病院"), None, Some("99")]);
+        // The dictionary contains all the labels even if they are not used
+        assert!(values.iter().any(|v| v == Some("歯科診療所")));
+
+        assert_eq!(report.unknown_codes["P04_001"]["99"], 1);
+        assert!(report.rejects.is_empty());
+    }
+
+    #[test]
+    fn invalid_codes_are_rejected() {
+        let mut builder = p04_001_builder(CodelistValidation::Reject);
+        let mut report = TranslationReport::default();
+
+        let values = [
+            FieldValue::Numeric(Some(1.0)),
+            FieldValue::Numeric(Some(1.5)),
+            FieldValue::Character(Some("99".to_string())),
+            FieldValue::Logical(Some(true)),
+        ];
+        for (row, value) in values.into_iter().enumerate() {
+            builder.push(value, "P04_001", row, &mut report).unwrap();
+        }
+
+        let rejects: Vec<(usize, RejectReason)> =
+            report.rejects.iter().map(|r| (r.row, r.reason)).collect();
+        assert_eq!(
+            rejects,
+            vec![
+                (1, RejectReason::LossyNumber),
+                (2, RejectReason::UnknownCode),
+                (3, RejectReason::UnexpectedType),
+            ]
+        );
+    }
+
+    #[test]
+    fn invalid_values_are_rejected_once() {
+        let mut builder = p04_001_builder(CodelistValidation::Reject);
+        let mut report = TranslationReport::default();
+
+        let values = [
+            FieldValue::Currency(2.0),
+            FieldValue::Numeric(Some(99.5)),
+            FieldValue::Date(None),
+        ];
+        for (row, value) in values.into_iter().enumerate() {
+            builder.push(value, "P04_001", row, &mut report).unwrap();
+        }
+
+        let rejects: Vec<(usize, RejectReason)> =
+            report.rejects.iter().map(|r| (r.row, r.reason)).collect();
+        assert_eq!(rejects, vec![(1, RejectReason::LossyNumber)]);
+        assert!(report.unexpected_values.is_empty());
+    }
+
+    #[test]
+    fn invalid_codes_fail_in_strict_mode() {
+        let mut builder = p04_001_builder(CodelistValidation::Strict);
+        let mut report = TranslationReport::default();
+
+        let valid = FieldValue::Character(Some("1".to_string()));
+        assert!(builder.push(valid, "P04_001", 0, &mut report).is_ok());
+
+        let invalid = FieldValue::Character(Some("99".to_string()));
+        assert!(builder.push(invalid, "P04_001", 1, &mut report).is_err());
+    }
+}
@@ -9,6 +9,12 @@ const EPSG4301: &str = include_str!("epsg4301.json");
 const EPSG4612: &str = include_str!("epsg4612.json");
 const EPSG6668: &str = include_str!("epsg6668.json");
 
+// ESRI WKT for .prj files
+const ESRI_WKT_TOKYO: &str = r#"GEOGCS["GCS_Tokyo",DATUM["D_Tokyo",SPHEROID["Bessel_1841",6377397.155,299.1528128]],PRIMEM["Greenwich",0.0],UNIT["Degree",0.0174532925199433]]"#;
+const ESRI_WKT_JGD2000: &str = r#"GEOGCS["GCS_JGD_2000",DATUM["D_JGD_2000",SPHEROID["GRS_1980",6378137.0,298.257222101]],PRIMEM["Greenwich",0.0],UNIT["Degree",0.0174532925199433]]"#;
+const ESRI_WKT_JGD2011: &str = r#"GEOGCS["GCS_JGD_2011",DATUM["D_JGD_2011",SPHEROID["GRS_1980",6378137.0,298.257222101]],PRIMEM["Greenwich",0.0],UNIT["Degree",0.0174532925199433]]"#;
+pub const ESRI_WKT_WGS84: &str = r#"GEOGCS["GCS_WGS_1984",DATUM["D_WGS_1984",SPHEROID["WGS_1984",6378137.0,298.257223563]],PRIMEM["Greenwich",0.0],UNIT["Degree",0.0174532925199433]]"#;
+
 pub static PROJ4STRING_WGS84: LazyLock<Proj> = LazyLock::new(|| {
     Proj::from_proj_string("+proj=longlat +ellps=WGS84 +datum=WGS84 +no_defs").unwrap()
 });
@@ -36,6 +42,14 @@ impl JapanCrs {
             // JapanCrs::JGD2024 => todo!(),
         }
     }
+
+    pub fn to_esri_wkt(&self) -> &'static str {
+        match self {
+            JapanCrs::Tokyo => ESRI_WKT_TOKYO,
+            JapanCrs::JGD2000 => ESRI_WKT_JGD2000,
+            JapanCrs::JGD2011 => ESRI_WKT_JGD2011,
+        }
+    }
 }
 
 pub fn guess_crs_from_esri_wkt(wkt: &str) -> Result<JapanCrs, Ksj2GpError> {
@@ -121,4 +135,12 @@ mod tests {
         let crs = guess_crs_from_meta_xml(&xml).unwrap();
         assert!(matches!(crs, JapanCrs::JGD2011));
     }
+
+    #[test]
+    fn roundtrips_esri_wkt() {
+        for crs in [JapanCrs::Tokyo, JapanCrs::JGD2000, JapanCrs::JGD2011] {
+            let guessed = guess_crs_from_esri_wkt(crs.to_esri_wkt()).unwrap();
+            assert_eq!(guessed.to_projjson(), crs.to_projjson());
+        }
+    }
 }
@@ -57,6 +57,12 @@ impl From<parquet::errors::ParquetError> for Ksj2GpError {
     }
 }
 
+impl From<csv::Error> for Ksj2GpError {
+    fn from(value: csv::Error) -> Self {
+        Self(format!("csv error: {value:?}"))
+    }
+}
+
 impl From<proj4rs::errors::Error> for Ksj2GpError {
     fn from(value: proj4rs::errors::Error) -> Self {
         Self(format!("proj4rs error: {value:?}"))
@@ -3,7 +3,11 @@ use std::io::{Read, Seek, Write};
 use shapefile::{Reader, ShapeReader};
 
 use crate::{
-    writer::{write_geojson, write_geoparquet, write_gpkg},
+    writer::{
+        write_arrow_ipc, write_csv, write_flatgeobuf, write_geojson, write_geojson_seq,
+        write_geoparquet, write_geoparquet_dataset, write_gpkg, write_kml, write_pmtiles,
+        write_postgis_sql, write_shapefile_zip, write_topojson,
+    },
     zip_reader::ZippedShapefileReader,
 };
 
@@ -17,8 +21,21 @@ mod writer;
 mod zip_reader;
 
 pub use crate::error::Ksj2GpError;
+pub use crate::writer::{
+    ArrowIpcOptions, CsvGeometry, CsvOptions, DatasetOptions, GeoJsonOptions, GeoParquetOptions,
+    KmlOptions, ParquetCompression, ParquetStatistics, PmTilesOptions, PostgisOptions,
+    RowGroupSize, ShapefileOptions, SpatialSortKey, SpatialSortOptions, TopoJsonOptions,
+    WriterOptions,
+};
 pub use encoding::{decode_cp437cp932_to_utf8, encode_utf8_to_cp437cp932};
-pub use translate::{TranslateOptions, extract_ksj_id};
+pub use translate::catalogue::{
+    ColumnInfo, find_codelist, list_codelists, list_codes, list_columns, list_ksj_ids, lookup_code,
+    lookup_colname,
+};
+pub use translate::{
+    Codelist, CodelistValidation, RejectReason, RejectedValue, TranslateOptions, TranslationReport,
+    extract_ksj_id,
+};
 
 pub fn list_shp_files<R: Read + Seek>(reader: R) -> Result<Vec<String>, Ksj2GpError> {
     match zip::ZipArchive::new(reader) {
@@ -60,7 +77,183 @@ pub fn convert_shp_inner<RW: Read + Seek + Write, R: Read + Seek, W: Write + See
     // Since `zip` is a file handle, it doesn't contain the filename. So, it
     // needs to be extracted outside of this function.
     translate_options: TranslateOptions,
-) -> Result<(), Ksj2GpError> {
+    writer_options: WriterOptions,
+) -> Result<TranslationReport, Ksj2GpError> {
+    let (mut reader, dbf_fields, crs) =
+        open_shapefile(zip, target_shp, meta_xml_filename, shp, dbf, shx)?;
+
+    let mut report = TranslationReport::default();
+
+    match output_format {
+        "GeoParquet" => write_geoparquet(
+            &mut reader,
+            &mut out,
+            &dbf_fields,
+            crs,
+            &translate_options,
+            &mut report,
+            &writer_options.geoparquet,
+        )?,
+        "GeoJson" => write_geojson(
+            &mut reader,
+            &mut out,
+            &dbf_fields,
+            crs,
+            &translate_options,
+            &mut report,
+            &writer_options.geojson,
+        )?,
+        "GeoJsonSeq" | "GeoJsonLines" => write_geojson_seq(
+            &mut reader,
+            &mut out,
+            &dbf_fields,
+            crs,
+            &translate_options,
+            &mut report,
+            &writer_options.geojson,
+            // GeoJsonLines is the same as GeoJsonSeq except for the record separator
+            output_format == "GeoJsonSeq",
+        )?,
+        "ArrowIpc" | "ArrowIpcStream" => write_arrow_ipc(
+            &mut reader,
+            &mut out,
+            &dbf_fields,
+            crs,
+            &translate_options,
+            &mut report,
+            &writer_options.arrow_ipc,
+            output_format == "ArrowIpcStream",
+        )?,
+        "Csv" => write_csv(
+            &mut reader,
+            &mut out,
+            &dbf_fields,
+            crs,
+            &translate_options,
+            &mut report,
+            &writer_options.csv,
+        )?,
+        "FlatGeobuf" => write_flatgeobuf(
+            &mut reader,
+            &mut out,
+            &dbf_fields,
+            crs,
+            &translate_options,
+            &mut report,
+        )?,
+        "Shapefile" => write_shapefile_zip(
+            &mut reader,
+            &mut out,
+            &dbf_fields,
+            crs,
+            &translate_options,
+            &mut report,
+            &writer_options.shapefile,
+        )?,
+        "Kml" | "Kmz" => write_kml(
+            &mut reader,
+            &mut out,
+            &dbf_fields,
+            crs,
+            &translate_options,
+            &mut report,
+            &writer_options.kml,
+            output_format == "Kmz",
+        )?,
+        "PmTiles" => write_pmtiles(
+            &mut reader,
+            &mut out,
+            &dbf_fields,
+            crs,
+            &translate_options,
+            &mut report,
+            &writer_options.pmtiles,
+        )?,
+        "TopoJson" => write_topojson(
+            &mut reader,
+            &mut out,
+            &dbf_fields,
+            crs,
+            &translate_options,
+            &mut report,
+            &writer_options.topojson,
+        )?,
+        "PostgisSql" => write_postgis_sql(
+            &mut reader,
+            &mut out,
+            &dbf_fields,
+            crs,
+            &translate_options,
+            &mut report,
+            &writer_options.postgis,
+        )?,
+        "Gpkg" => write_gpkg(
+            &mut reader,
+            out,
+            &dbf_fields,
+            crs,
+            &translate_options,
+            &mut report,
+        )?,
+        _ => return Err(format!("Unsupported format: {output_format}").into()),
+    }
+
+    Ok(report)
+}
+
+/// Same as `convert_shp_inner()`, but writes a Hive-partitioned GeoParquet
+/// dataset. `create_file` receives the path of each part relative to the root
+/// directory of the dataset (e.g. "ksj_id=N03/year=2024/pref=13/part-0.parquet")
+/// and returns the writer for it. The parent directories need to be created in
+/// `create_file`.
+#[allow(clippy::too_many_arguments)]
+pub fn convert_shp_dataset<RW, R, W, F>(
+    zip: R,
+    target_shp: &str,
+    meta_xml_filename: Option<String>,
+    shp: RW,
+    dbf: RW,
+    shx: RW,
+    create_file: F,
+    translate_options: TranslateOptions,
+    writer_options: WriterOptions,
+) -> Result<TranslationReport, Ksj2GpError>
+where
+    RW: Read + Seek + Write,
+    R: Read + Seek,
+    W: Write + Send,
+    F: FnMut(&str) -> Result<W, Ksj2GpError>,
+{
+    let (mut reader, dbf_fields, crs) =
+        open_shapefile(zip, target_shp, meta_xml_filename, shp, dbf, shx)?;
+
+    let mut report = TranslationReport::default();
+
+    write_geoparquet_dataset(
+        &mut reader,
+        create_file,
+        &dbf_fields,
+        crs,
+        &translate_options,
+        &mut report,
+        &writer_options.geoparquet,
+        &writer_options.dataset,
+    )?;
+
+    Ok(report)
+}
+
+type ShapefileReader<RW> = Reader<std::io::BufReader<RW>, std::io::BufReader<RW>>;
+
+// Extract the Shapefile from the ZIP file to `shp`, `dbf` and `shx`, and open it
+fn open_shapefile<RW: Read + Seek + Write, R: Read + Seek>(
+    zip: R,
+    target_shp: &str,
+    meta_xml_filename: Option<String>,
+    shp: RW,
+    dbf: RW,
+    shx: RW,
+) -> Result<(ShapefileReader<RW>, Vec<dbase::FieldInfo>, crs::JapanCrs), Ksj2GpError> {
     let mut zip = match zip::ZipArchive::new(zip) {
         Ok(zip) => ZippedShapefileReader::new(zip, target_shp, meta_xml_filename),
         Err(e) => Err(format!("Failed to read ZIP file!: {e:?}").into()),
@@ -79,14 +272,5 @@ pub fn convert_shp_inner<RW: Read + Seek + Write, R: Read + Seek, W: Write + See
 
     let dbf_fields = dbase_reader.fields().to_vec();
 
-    let mut reader = Reader::new(shapefile_reader, dbase_reader);
-
-    match output_format {
-        "GeoParquet" => {
-            write_geoparquet(&mut reader, &mut out, &dbf_fields, crs, &translate_options)
-        }
-        "GeoJson" => write_geojson(&mut reader, &mut out, &dbf_fields, crs, &translate_options),
-        "Gpkg" => write_gpkg(&mut reader, out, &dbf_fields, crs, &translate_options),
-        _ => Err(format!("Unsupported format: {output_format}").into()),
-    }
+    Ok((Reader::new(shapefile_reader, dbase_reader), dbf_fields, crs))
 }
@@ -1,18 +1,65 @@
-use shapefile::Shape;
+use shapefile::{
+    Patch, PolygonRing, Shape,
+    record::{
+        polygon::GenericPolygon,
+        traits::{GrowablePoint, HasXY, ShrinkablePoint},
+    },
+};
 
 use crate::{
     crs::{JapanCrs, PROJ4STRING_TOKYO, PROJ4STRING_WGS84},
     error::Ksj2GpError,
 };
 
+// More decimal places than this are meaningless for f64 coordinates of
+// degrees, and 10^precision would overflow for a large precision.
+const MAX_PRECISION: u32 = 15;
+
 pub struct CoordTransformer {
     src: JapanCrs,
+    // Number of decimal places to round the coordinates to
+    precision: Option<u32>,
+    // If true, exterior rings are counter-clockwise and interior rings are
+    // clockwise (the right-hand rule of RFC 7946). Note that Shapefile uses the
+    // opposite orientation.
+    force_ccw: bool,
+    // If true, the M values are kept (cf. has_measures()). Otherwise, they are
+    // dropped, which is the case for the formats without M (e.g. GeoJSON).
+    measures: bool,
 }
 
 impl CoordTransformer {
     pub fn new(src: JapanCrs) -> Self {
-        Self { src }
+        Self {
+            src,
+            precision: None,
+            force_ccw: false,
+            measures: false,
+        }
+    }
+
+    // A transformer that keeps the coordinates as they are. This is used for
+    // converting shapes into geometries (JGD2011 is not transformed).
+    pub fn identity() -> Self {
+        Self::new(JapanCrs::JGD2011)
+    }
+
+    // The precision is clamped to MAX_PRECISION
+    pub fn with_precision(mut self, precision: Option<u32>) -> Self {
+        self.precision = precision.map(|p| p.min(MAX_PRECISION));
+        self
     }
+
+    pub fn with_ccw(mut self, force_ccw: bool) -> Self {
+        self.force_ccw = force_ccw;
+        self
+    }
+
+    pub fn with_measures(mut self, measures: bool) -> Self {
+        self.measures = measures;
+        self
+    }
+
     pub fn transform(
         &self,
         shape: &Shape,
@@ -22,6 +69,10 @@ impl CoordTransformer {
                 let coord = self.transform_single_point(point)?;
                 Ok(geo_traits::structs::Point::from_coord(coord).into())
             }
+            Shape::PointM(point) => {
+                let coord = self.transform_single_point_m(point)?;
+                Ok(geo_traits::structs::Point::from_coord(coord).into())
+            }
             Shape::PointZ(point) => {
                 let coord = self.transform_single_point_z(point)?;
                 Ok(geo_traits::structs::Point::from_coord(coord).into())
@@ -42,6 +93,22 @@ impl CoordTransformer {
                         .into(),
                 )
             }
+            Shape::PolylineM(polyline) => {
+                let linestrings = polyline
+                    .parts()
+                    .iter()
+                    .map(|points| {
+                        self.transform_points_m(points).map(|coords| {
+                            geo_traits::structs::LineString::from_coords(coords).unwrap()
+                        })
+                    })
+                    .collect::<Result<Vec<geo_traits::structs::LineString>, _>>()?;
+                Ok(
+                    geo_traits::structs::MultiLineString::from_line_strings(linestrings)
+                        .unwrap()
+                        .into(),
+                )
+            }
             Shape::PolylineZ(polyline) => {
                 let linestrings = polyline
                     .parts()
@@ -59,30 +126,25 @@ impl CoordTransformer {
                 )
             }
             Shape::Polygon(polygon) => {
-                let rings = polygon
-                    .rings()
-                    .iter()
-                    .map(|ring| {
-                        let coords = self.transform_points(ring.points())?;
-                        Ok(geo_traits::structs::LineString::from_coords(coords).unwrap())
-                    })
-                    .collect::<Result<Vec<geo_traits::structs::LineString>, Ksj2GpError>>()?;
-
-                Ok(geo_traits::structs::Polygon::from_rings(rings)
+                let polygons = self
+                    .transform_polygons(polygon.rings(), |points| self.transform_points(points))?;
+                Ok(geo_traits::structs::MultiPolygon::from_polygons(polygons)
+                    .unwrap()
+                    .into())
+            }
+            Shape::PolygonM(polygon) => {
+                let polygons = self.transform_polygons(polygon.rings(), |points| {
+                    self.transform_points_m(points)
+                })?;
+                Ok(geo_traits::structs::MultiPolygon::from_polygons(polygons)
                     .unwrap()
                     .into())
             }
             Shape::PolygonZ(polygon) => {
-                let rings = polygon
-                    .rings()
-                    .iter()
-                    .map(|ring| {
-                        let coords = self.transform_points_z(ring.points())?;
-                        Ok(geo_traits::structs::LineString::from_coords(coords).unwrap())
-                    })
-                    .collect::<Result<Vec<geo_traits::structs::LineString>, Ksj2GpError>>()?;
-
-                Ok(geo_traits::structs::Polygon::from_rings(rings)
+                let polygons = self.transform_polygons(polygon.rings(), |points| {
+                    self.transform_points_z(points)
+                })?;
+                Ok(geo_traits::structs::MultiPolygon::from_polygons(polygons)
                     .unwrap()
                     .into())
             }
@@ -95,6 +157,15 @@ impl CoordTransformer {
                     .unwrap()
                     .into())
             }
+            Shape::MultipointM(multipoint) => {
+                let points = self
+                    .transform_points_m(multipoint.points())?
+                    .into_iter()
+                    .map(geo_traits::structs::Point::from_coord);
+                Ok(geo_traits::structs::MultiPoint::from_points(points)
+                    .unwrap()
+                    .into())
+            }
             Shape::MultipointZ(multipoint) => {
                 let points = self
                     .transform_points_z(multipoint.points())?
@@ -104,42 +175,196 @@ impl CoordTransformer {
                     .unwrap()
                     .into())
             }
+            Shape::Multipatch(multipatch) => {
+                let polygons = multipatch_polygons(multipatch);
+                let polygons = self.build_polygons(
+                    polygons
+                        .iter()
+                        .map(|rings| rings.iter().map(Vec::as_slice).collect()),
+                    |points| self.transform_points_z(points),
+                )?;
+                Ok(geo_traits::structs::MultiPolygon::from_polygons(polygons)
+                    .unwrap()
+                    .into())
+            }
             _ => Err(format!("Unsupported shape type: {}", shape.shapetype()).into()),
         }
     }
 
+    // Same as transform(), but returns a shape instead of a geometry. The
+    // structure of the shape (e.g. the order and the orientation of the rings)
+    // is kept as it is.
+    pub fn transform_shape(&self, shape: Shape) -> Result<Shape, Ksj2GpError> {
+        let shape = match shape {
+            Shape::Point(point) => Shape::Point(self.transform_point(&point)?),
+            Shape::PointM(point) => Shape::PointM(self.transform_point_m(&point)?),
+            Shape::PointZ(point) => Shape::PointZ(self.transform_point_z(&point)?),
+            Shape::Polyline(polyline) => {
+                let parts = polyline
+                    .parts()
+                    .iter()
+                    .map(|points| points.iter().map(|p| self.transform_point(p)).collect())
+                    .collect::<Result<Vec<Vec<_>>, _>>()?;
+                Shape::Polyline(shapefile::Polyline::with_parts(parts))
+            }
+            Shape::PolylineM(polyline) => {
+                let parts = polyline
+                    .parts()
+                    .iter()
+                    .map(|points| points.iter().map(|p| self.transform_point_m(p)).collect())
+                    .collect::<Result<Vec<Vec<_>>, _>>()?;
+                Shape::PolylineM(shapefile::PolylineM::with_parts(parts))
+            }
+            Shape::PolylineZ(polyline) => {
+                let parts = polyline
+                    .parts()
+                    .iter()
+                    .map(|points| points.iter().map(|p| self.transform_point_z(p)).collect())
+                    .collect::<Result<Vec<Vec<_>>, _>>()?;
+                Shape::PolylineZ(shapefile::PolylineZ::with_parts(parts))
+            }
+            Shape::Polygon(polygon) => Shape::Polygon(shapefile::Polygon::with_rings(
+                transform_rings(polygon.rings(), |p| self.transform_point(p))?,
+            )),
+            Shape::PolygonM(polygon) => Shape::PolygonM(shapefile::PolygonM::with_rings(
+                transform_rings(polygon.rings(), |p| self.transform_point_m(p))?,
+            )),
+            Shape::PolygonZ(polygon) => Shape::PolygonZ(shapefile::PolygonZ::with_rings(
+                transform_rings(polygon.rings(), |p| self.transform_point_z(p))?,
+            )),
+            Shape::Multipoint(multipoint) => {
+                let points = multipoint
+                    .points()
+                    .iter()
+                    .map(|p| self.transform_point(p))
+                    .collect::<Result<Vec<_>, _>>()?;
+                Shape::Multipoint(shapefile::Multipoint::new(points))
+            }
+            Shape::MultipointM(multipoint) => {
+                let points = multipoint
+                    .points()
+                    .iter()
+                    .map(|p| self.transform_point_m(p))
+                    .collect::<Result<Vec<_>, _>>()?;
+                Shape::MultipointM(shapefile::MultipointM::new(points))
+            }
+            Shape::MultipointZ(multipoint) => {
+                let points = multipoint
+                    .points()
+                    .iter()
+                    .map(|p| self.transform_point_z(p))
+                    .collect::<Result<Vec<_>, _>>()?;
+                Shape::MultipointZ(shapefile::MultipointZ::new(points))
+            }
+            // Multipatch::with_parts() doesn't accept no patches
+            Shape::Multipatch(multipatch) if multipatch.patches().is_empty() => {
+                Shape::Multipatch(multipatch)
+            }
+            Shape::Multipatch(multipatch) => {
+                let patches = multipatch
+                    .patches()
+                    .iter()
+                    .map(|patch| {
+                        let points = patch
+                            .points()
+                            .iter()
+                            .map(|p| self.transform_point_z(p))
+                            .collect::<Result<Vec<_>, _>>()?;
+                        Ok(match patch {
+                            Patch::TriangleStrip(_) => Patch::TriangleStrip(points),
+                            Patch::TriangleFan(_) => Patch::TriangleFan(points),
+                            Patch::OuterRing(_) => Patch::OuterRing(points),
+                            Patch::InnerRing(_) => Patch::InnerRing(points),
+                            Patch::FirstRing(_) => Patch::FirstRing(points),
+                            Patch::Ring(_) => Patch::Ring(points),
+                        })
+                    })
+                    .collect::<Result<Vec<_>, Ksj2GpError>>()?;
+                Shape::Multipatch(shapefile::Multipatch::with_parts(patches))
+            }
+            Shape::NullShape => Shape::NullShape,
+        };
+
+        Ok(shape)
+    }
+
+    fn transform_point(&self, point: &shapefile::Point) -> Result<shapefile::Point, Ksj2GpError> {
+        let coord = self.transform_single_point(point)?;
+        Ok(shapefile::Point::new(coord.x, coord.y))
+    }
+
+    // The M value is kept as it is (including no data)
+    fn transform_point_m(
+        &self,
+        point: &shapefile::PointM,
+    ) -> Result<shapefile::PointM, Ksj2GpError> {
+        let coord = self.transform_single_point(&shapefile::Point::new(point.x, point.y))?;
+        Ok(shapefile::PointM {
+            x: coord.x,
+            y: coord.y,
+            m: point.m,
+        })
+    }
+
+    fn transform_point_z(
+        &self,
+        point: &shapefile::PointZ,
+    ) -> Result<shapefile::PointZ, Ksj2GpError> {
+        let coord = self.transform_single_point_z(point)?;
+        Ok(shapefile::PointZ {
+            x: coord.x,
+            y: coord.y,
+            z: coord.z.unwrap_or(point.z),
+            m: point.m,
+        })
+    }
+
     fn transform_single_point(
         &self,
         point: &shapefile::Point,
     ) -> Result<geo_traits::structs::Coord, Ksj2GpError> {
         // JGD2000, JGD2011 から WGS84 は無変換とする
-        match self.src {
+        let coord = match self.src {
             JapanCrs::Tokyo => {
                 // Note: proj4rs requires the longitude and latitude in radian, not in degree.
                 // So, we must convert it to radians and then convert back to degree...
                 let mut pt = (point.x.to_radians(), point.y.to_radians());
                 proj4rs::transform::transform(&PROJ4STRING_TOKYO, &PROJ4STRING_WGS84, &mut pt)?;
-                Ok(geo_traits::structs::Coord {
+                geo_traits::structs::Coord {
                     x: pt.0.to_degrees(),
                     y: pt.1.to_degrees(),
                     z: None,
                     m: None,
-                })
+                }
             }
-            JapanCrs::JGD2000 | JapanCrs::JGD2011 => Ok(geo_traits::structs::Coord {
+            JapanCrs::JGD2000 | JapanCrs::JGD2011 => geo_traits::structs::Coord {
                 x: point.x,
                 y: point.y,
                 z: None,
                 m: None,
-            }),
-        }
+            },
+        };
+
+        Ok(self.round(coord))
+    }
+
+    fn transform_single_point_m(
+        &self,
+        point: &shapefile::PointM,
+    ) -> Result<geo_traits::structs::Coord, Ksj2GpError> {
+        let coord = self.transform_single_point(&shapefile::Point::new(point.x, point.y))?;
+        Ok(geo_traits::structs::Coord {
+            m: self.measures.then(|| measure(point.m)),
+            ..coord
+        })
     }
 
     fn transform_single_point_z(
         &self,
         point: &shapefile::PointZ,
     ) -> Result<geo_traits::structs::Coord, Ksj2GpError> {
-        match self.src {
+        let m = self.measures.then(|| measure(point.m));
+        let coord = match self.src {
             JapanCrs::Tokyo => {
                 let mut pt = (
                     point.x.to_radians(),
@@ -147,20 +372,84 @@ impl CoordTransformer {
                     point.z.to_radians(),
                 );
                 proj4rs::transform::transform(&PROJ4STRING_TOKYO, &PROJ4STRING_WGS84, &mut pt)?;
-                Ok(geo_traits::structs::Coord {
+                geo_traits::structs::Coord {
                     x: pt.0.to_degrees(),
                     y: pt.1.to_degrees(),
                     z: Some(pt.2.to_degrees()),
-                    m: None,
-                })
+                    m,
+                }
             }
-            JapanCrs::JGD2000 | JapanCrs::JGD2011 => Ok(geo_traits::structs::Coord {
+            JapanCrs::JGD2000 | JapanCrs::JGD2011 => geo_traits::structs::Coord {
                 x: point.x,
                 y: point.y,
                 z: Some(point.z),
-                m: None,
-            }),
+                m,
+            },
+        };
+
+        Ok(self.round(coord))
+    }
+
+    fn round(&self, coord: geo_traits::structs::Coord) -> geo_traits::structs::Coord {
+        match self.precision {
+            Some(precision) => {
+                let scale = 10f64.powi(precision as i32);
+                let round = |v: f64| (v * scale).round() / scale;
+                geo_traits::structs::Coord {
+                    x: round(coord.x),
+                    y: round(coord.y),
+                    z: coord.z.map(round),
+                    m: coord.m,
+                }
+            }
+            None => coord,
+        }
+    }
+
+    // Assemble the rings into polygons (cf. group_rings())
+    fn transform_polygons<P: HasXY>(
+        &self,
+        rings: &[PolygonRing<P>],
+        transform_points: impl Fn(&[P]) -> Result<Vec<geo_traits::structs::Coord>, Ksj2GpError>,
+    ) -> Result<Vec<geo_traits::structs::Polygon>, Ksj2GpError> {
+        let groups = group_rings(rings)
+            .into_iter()
+            .map(|group| group.into_iter().map(|i| rings[i].points()).collect());
+        self.build_polygons(groups, transform_points)
+    }
+
+    // Build polygons from the rings of each polygon. The first ring of each
+    // polygon is the outer ring.
+    fn build_polygons<'a, P: 'a>(
+        &self,
+        groups: impl Iterator<Item = Vec<&'a [P]>>,
+        transform_points: impl Fn(&[P]) -> Result<Vec<geo_traits::structs::Coord>, Ksj2GpError>,
+    ) -> Result<Vec<geo_traits::structs::Polygon>, Ksj2GpError> {
+        groups
+            .map(|group| {
+                let rings = group
+                    .into_iter()
+                    .enumerate()
+                    .map(|(i, points)| {
+                        let coords = transform_points(points)?;
+                        let coords = self.orient_ring(coords, i == 0);
+                        Ok(geo_traits::structs::LineString::from_coords(coords).unwrap())
+                    })
+                    .collect::<Result<Vec<geo_traits::structs::LineString>, Ksj2GpError>>()?;
+                Ok(geo_traits::structs::Polygon::from_rings(rings).unwrap())
+            })
+            .collect()
+    }
+
+    fn orient_ring(
+        &self,
+        mut coords: Vec<geo_traits::structs::Coord>,
+        is_outer: bool,
+    ) -> Vec<geo_traits::structs::Coord> {
+        if self.force_ccw && (signed_area(&coords) > 0.0) != is_outer {
+            coords.reverse();
         }
+        coords
     }
 
     fn transform_points(
@@ -173,6 +462,16 @@ impl CoordTransformer {
             .collect::<Result<Vec<geo_traits::structs::Coord>, _>>()
     }
 
+    fn transform_points_m(
+        &self,
+        points: &[shapefile::PointM],
+    ) -> Result<Vec<geo_traits::structs::Coord>, Ksj2GpError> {
+        points
+            .iter()
+            .map(|point| self.transform_single_point_m(point))
+            .collect::<Result<Vec<geo_traits::structs::Coord>, _>>()
+    }
+
     fn transform_points_z(
         &self,
         points: &[shapefile::PointZ],
@@ -183,3 +482,505 @@ impl CoordTransformer {
             .collect::<Result<Vec<geo_traits::structs::Coord>, _>>()
     }
 }
+
+// Transform the points of the rings, keeping the order and the types of the
+// rings
+fn transform_rings<P: Copy>(
+    rings: &[PolygonRing<P>],
+    transform_point: impl Fn(&P) -> Result<P, Ksj2GpError>,
+) -> Result<Vec<PolygonRing<P>>, Ksj2GpError> {
+    rings
+        .iter()
+        .map(|ring| {
+            let points = ring
+                .points()
+                .iter()
+                .map(&transform_point)
+                .collect::<Result<Vec<_>, _>>()?;
+            Ok(match ring {
+                PolygonRing::Outer(_) => PolygonRing::Outer(points),
+                PolygonRing::Inner(_) => PolygonRing::Inner(points),
+            })
+        })
+        .collect()
+}
+
+// Whether the M values should be kept. The M types always have them. The Z
+// types always have the slots for M values as well, but they are usually
+// unused; in that case, the range of M in the header is either no data or
+// zero.
+pub(crate) fn has_measures(header: &shapefile::header::Header) -> bool {
+    use shapefile::ShapeType;
+
+    match header.shape_type {
+        ShapeType::PointM | ShapeType::PolylineM | ShapeType::PolygonM | ShapeType::MultipointM => {
+            true
+        }
+        ShapeType::PointZ
+        | ShapeType::PolylineZ
+        | ShapeType::PolygonZ
+        | ShapeType::MultipointZ
+        | ShapeType::Multipatch => {
+            let (min, max) = (header.bbox.min.m, header.bbox.max.m);
+            min > shapefile::NO_DATA && !(min == 0.0 && max == 0.0)
+        }
+        _ => false,
+    }
+}
+
+// An M value less than or equal to NO_DATA means no data. It's represented as
+// NaN, which is the convention of WKB (and of the other formats).
+fn measure(m: f64) -> f64 {
+    if m <= shapefile::NO_DATA { f64::NAN } else { m }
+}
+
+// Convert the patches of a Multipatch into polygons (the rings of each polygon,
+// the first one is the outer ring). Following the spec of Shapefile:
+//
+// - Each triangle of Triangle Strips and Triangle Fans becomes a polygon. The
+//   degenerate triangles, which are used for joining strips, are skipped.
+// - An Outer Ring is followed by its Inner Rings.
+// - A First Ring is followed by the Rings of the same polygon.
+//
+// A Ring not preceded by a First Ring and an orphaned Inner Ring are treated
+// as polygons without holes.
+pub(crate) fn multipatch_polygons(
+    multipatch: &shapefile::Multipatch,
+) -> Vec<Vec<Vec<shapefile::PointZ>>> {
+    fn triangle(
+        a: shapefile::PointZ,
+        b: shapefile::PointZ,
+        c: shapefile::PointZ,
+    ) -> Option<Vec<Vec<shapefile::PointZ>>> {
+        (a != b && b != c && c != a).then(|| vec![vec![a, b, c, a]])
+    }
+
+    let mut polygons: Vec<Vec<Vec<shapefile::PointZ>>> = Vec::new();
+    // The patch that started the last polygon, if it can still have holes
+    let mut last_outer: Option<&Patch> = None;
+
+    for patch in multipatch.patches() {
+        match patch {
+            Patch::TriangleStrip(points) => {
+                polygons.extend(points.windows(3).filter_map(|t| triangle(t[0], t[1], t[2])));
+                last_outer = None;
+            }
+            Patch::TriangleFan(points) => {
+                if let Some((&first, rest)) = points.split_first() {
+                    polygons.extend(rest.windows(2).filter_map(|t| triangle(first, t[0], t[1])));
+                }
+                last_outer = None;
+            }
+            Patch::InnerRing(points) if matches!(last_outer, Some(Patch::OuterRing(_))) => {
+                polygons.last_mut().unwrap().push(points.clone());
+            }
+            Patch::Ring(points) if matches!(last_outer, Some(Patch::FirstRing(_))) => {
+                polygons.last_mut().unwrap().push(points.clone());
+            }
+            Patch::OuterRing(points) | Patch::FirstRing(points) => {
+                polygons.push(vec![points.clone()]);
+                last_outer = Some(patch);
+            }
+            Patch::InnerRing(points) | Patch::Ring(points) => {
+                polygons.push(vec![points.clone()]);
+                last_outer = None;
+            }
+        }
+    }
+
+    polygons
+}
+
+// Group the rings of a Shapefile polygon into the polygons of a MultiPolygon.
+// The outer and inner rings are distinguished by the orientation (the shapefile
+// crate does it on reading), and since the order of the rings is not
+// significant in Shapefile, each inner ring is assigned to the smallest outer
+// ring containing it. An inner ring contained by no outer ring is treated as an
+// outer ring.
+//
+// Returns the indices of the rings of each polygon. The first one is the outer
+// ring.
+pub(crate) fn group_rings<P: HasXY>(rings: &[PolygonRing<P>]) -> Vec<Vec<usize>> {
+    let (outers, inners): (Vec<usize>, Vec<usize>) =
+        (0..rings.len()).partition(|&i| matches!(rings[i], PolygonRing::Outer(_)));
+
+    let mut groups: Vec<Vec<usize>> = outers.iter().map(|&i| vec![i]).collect();
+
+    let areas: Vec<f64> = outers
+        .iter()
+        .map(|&i| ring_area(rings[i].points()).abs())
+        .collect();
+
+    for inner in inners {
+        let inner_points = rings[inner].points();
+        let container = outers
+            .iter()
+            .enumerate()
+            .filter(|(_, outer)| ring_contains(rings[**outer].points(), inner_points))
+            .min_by(|(a, _), (b, _)| areas[*a].total_cmp(&areas[*b]))
+            .map(|(j, _)| j);
+
+        match container {
+            Some(j) => groups[j].push(inner),
+            None => groups.push(vec![inner]),
+        }
+    }
+
+    groups
+}
+
+// Reorder the rings so that each outer ring is followed by its inner rings (cf.
+// group_rings()), which is what the conversions of the shapefile crate (e.g. to
+// geo-types) assume. The inner rings that are treated as outer rings are
+// reversed.
+pub(crate) fn assemble_rings(shape: Shape) -> Shape {
+    fn reorder<P: GrowablePoint + ShrinkablePoint + PartialEq + HasXY + Copy>(
+        polygon: GenericPolygon<P>,
+    ) -> GenericPolygon<P> {
+        let groups = group_rings(polygon.rings());
+        if groups.is_empty() {
+            return polygon;
+        }
+
+        let mut rings: Vec<Option<PolygonRing<P>>> =
+            polygon.into_inner().into_iter().map(Some).collect();
+        let mut reordered = Vec::with_capacity(rings.len());
+        for group in groups {
+            for (i, ring_index) in group.into_iter().enumerate() {
+                let ring = rings[ring_index].take().unwrap();
+                if i == 0 {
+                    // with_rings() reorders the points to match the type
+                    reordered.push(PolygonRing::Outer(ring.into_inner()));
+                } else {
+                    reordered.push(ring);
+                }
+            }
+        }
+        GenericPolygon::with_rings(reordered)
+    }
+
+    match shape {
+        Shape::Polygon(polygon) => Shape::Polygon(reorder(polygon)),
+        Shape::PolygonM(polygon) => Shape::PolygonM(reorder(polygon)),
+        Shape::PolygonZ(polygon) => Shape::PolygonZ(reorder(polygon)),
+        shape => shape,
+    }
+}
+
+// Twice the signed area of the ring. Positive if the ring is counter-clockwise.
+fn ring_area<P: HasXY>(points: &[P]) -> f64 {
+    points
+        .iter()
+        .zip(points.iter().skip(1))
+        .map(|(a, b)| a.x() * b.y() - b.x() * a.y())
+        .sum()
+}
+
+// Whether `inner` is inside `outer`. Since the inner ring can touch the outer
+// ring, the first vertex that is not on the boundary decides.
+fn ring_contains<P: HasXY>(outer: &[P], inner: &[P]) -> bool {
+    inner
+        .iter()
+        .find_map(|p| point_in_ring(outer, p.x(), p.y()))
+        .unwrap_or(true)
+}
+
+// Ray casting. Returns None if the point is on the boundary.
+fn point_in_ring<P: HasXY>(ring: &[P], x: f64, y: f64) -> Option<bool> {
+    let mut inside = false;
+    for (a, b) in ring.iter().zip(ring.iter().skip(1)) {
+        let (ax, ay, bx, by) = (a.x(), a.y(), b.x(), b.y());
+
+        // On the segment
+        let cross = (bx - ax) * (y - ay) - (by - ay) * (x - ax);
+        if cross == 0.0 && x >= ax.min(bx) && x <= ax.max(bx) && y >= ay.min(by) && y <= ay.max(by)
+        {
+            return None;
+        }
+
+        if (ay > y) != (by > y) && x < ax + (y - ay) * (bx - ax) / (by - ay) {
+            inside = !inside;
+        }
+    }
+    Some(inside)
+}
+
+// Twice the signed area of the ring (shoelace formula). Positive if the ring is
+// counter-clockwise.
+fn signed_area(coords: &[geo_traits::structs::Coord]) -> f64 {
+    coords
+        .iter()
+        .zip(coords.iter().skip(1))
+        .map(|(a, b)| a.x * b.y - b.x * a.y)
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use geo_traits::{
+        CoordTrait, GeometryTrait, GeometryType, LineStringTrait, MultiLineStringTrait,
+        MultiPolygonTrait, PolygonTrait,
+    };
+    use shapefile::{Point, Polygon, PolygonRing, Shape};
+
+    use super::*;
+
+    fn exterior_coords(geometry: &geo_traits::structs::Geometry) -> Vec<(f64, f64)> {
+        match geometry.as_type() {
+            GeometryType::MultiPolygon(mp) => mp
+                .polygon(0)
+                .unwrap()
+                .exterior()
+                .unwrap()
+                .coords()
+                .map(|c| (c.x(), c.y()))
+                .collect(),
+            _ => panic!("not a multipolygon"),
+        }
+    }
+
+    // Clockwise square
+    fn outer(x: f64, y: f64, size: f64) -> PolygonRing<Point> {
+        PolygonRing::Outer(vec![
+            Point::new(x, y),
+            Point::new(x, y + size),
+            Point::new(x + size, y + size),
+            Point::new(x + size, y),
+            Point::new(x, y),
+        ])
+    }
+
+    // Counter-clockwise square
+    fn inner(x: f64, y: f64, size: f64) -> PolygonRing<Point> {
+        PolygonRing::Inner(vec![
+            Point::new(x, y),
+            Point::new(x + size, y),
+            Point::new(x + size, y + size),
+            Point::new(x, y + size),
+            Point::new(x, y),
+        ])
+    }
+
+    #[test]
+    fn test_group_rings() {
+        // Two islands, with the holes after them in the reverse order
+        let rings = [
+            outer(0.0, 0.0, 10.0),
+            outer(20.0, 0.0, 10.0),
+            inner(21.0, 1.0, 2.0),
+            inner(1.0, 1.0, 2.0),
+        ];
+        assert_eq!(group_rings(&rings), vec![vec![0, 3], vec![1, 2]]);
+
+        // A hole in the island inside a lake belongs to the island, not to the
+        // outer one. The hole touching the outer ring is still inside.
+        let rings = [
+            outer(0.0, 0.0, 10.0),
+            inner(1.0, 1.0, 8.0),
+            outer(2.0, 2.0, 4.0),
+            inner(3.0, 3.0, 1.0),
+            inner(0.0, 9.0, 1.0),
+        ];
+        assert_eq!(group_rings(&rings), vec![vec![0, 1, 4], vec![2, 3]]);
+
+        // An orphaned inner ring becomes a polygon
+        let rings = [outer(0.0, 0.0, 1.0), inner(5.0, 5.0, 1.0)];
+        assert_eq!(group_rings(&rings), vec![vec![0], vec![1]]);
+    }
+
+    #[test]
+    fn test_assemble_rings() {
+        let shape = Shape::Polygon(Polygon::with_rings(vec![
+            outer(0.0, 0.0, 10.0),
+            outer(20.0, 0.0, 10.0),
+            inner(1.0, 1.0, 2.0),
+        ]));
+        let Shape::Polygon(polygon) = assemble_rings(shape) else {
+            panic!("not a polygon");
+        };
+        assert_eq!(
+            polygon.rings(),
+            &[
+                outer(0.0, 0.0, 10.0),
+                inner(1.0, 1.0, 2.0),
+                outer(20.0, 0.0, 10.0)
+            ]
+        );
+
+        let geometry = CoordTransformer::new(JapanCrs::JGD2011)
+            .transform(&Shape::Polygon(polygon))
+            .unwrap();
+        let GeometryType::MultiPolygon(mp) = geometry.as_type() else {
+            panic!("not a multipolygon");
+        };
+        assert_eq!(mp.num_polygons(), 2);
+        assert_eq!(mp.polygon(0).unwrap().num_interiors(), 1);
+        assert_eq!(mp.polygon(1).unwrap().num_interiors(), 0);
+    }
+
+    #[test]
+    fn test_force_ccw_and_precision() {
+        // Shapefile stores the outer rings clockwise
+        let shape = Shape::Polygon(Polygon::new(PolygonRing::Outer(vec![
+            Point::new(139.0, 35.0),
+            Point::new(139.0, 36.0),
+            Point::new(140.1234567, 35.0),
+            Point::new(139.0, 35.0),
+        ])));
+
+        let transformer = CoordTransformer::new(JapanCrs::JGD2011);
+        let geometry = transformer.transform(&shape).unwrap();
+        assert_eq!(
+            exterior_coords(&geometry),
+            vec![
+                (139.0, 35.0),
+                (139.0, 36.0),
+                (140.1234567, 35.0),
+                (139.0, 35.0)
+            ]
+        );
+
+        let transformer = CoordTransformer::new(JapanCrs::JGD2011)
+            .with_ccw(true)
+            .with_precision(Some(3));
+        let geometry = transformer.transform(&shape).unwrap();
+        assert_eq!(
+            exterior_coords(&geometry),
+            vec![(139.0, 35.0), (140.123, 35.0), (139.0, 36.0), (139.0, 35.0)]
+        );
+
+        // A too large precision doesn't break the coordinates
+        let transformer = CoordTransformer::new(JapanCrs::JGD2011).with_precision(Some(400));
+        let geometry = transformer.transform(&shape).unwrap();
+        assert_eq!(exterior_coords(&geometry)[2], (140.1234567, 35.0));
+    }
+
+    fn measures(geometry: &geo_traits::structs::Geometry) -> Vec<Option<f64>> {
+        match geometry.as_type() {
+            GeometryType::MultiLineString(mls) => mls
+                .line_string(0)
+                .unwrap()
+                .coords()
+                .map(|c| match c.dim() {
+                    geo_traits::Dimensions::Xym => c.nth(2),
+                    geo_traits::Dimensions::Xyzm => c.nth(3),
+                    _ => None,
+                })
+                .collect(),
+            _ => panic!("not a multilinestring"),
+        }
+    }
+
+    #[test]
+    fn test_measures() {
+        let shape = Shape::PolylineM(shapefile::PolylineM::new(vec![
+            shapefile::PointM::new(139.0, 35.0, 0.0),
+            shapefile::PointM::new(139.0, 36.0, shapefile::NO_DATA),
+            shapefile::PointM::new(140.0, 36.0, 1.5),
+        ]));
+        let geometry = CoordTransformer::identity().transform(&shape).unwrap();
+        assert_eq!(geometry.dim(), geo_traits::Dimensions::Xy);
+        let geometry = CoordTransformer::identity()
+            .with_measures(true)
+            .transform(&shape)
+            .unwrap();
+        assert_eq!(geometry.dim(), geo_traits::Dimensions::Xym);
+        let m = measures(&geometry);
+        assert_eq!(m[0], Some(0.0));
+        assert!(m[1].unwrap().is_nan());
+        assert_eq!(m[2], Some(1.5));
+
+        let shape = Shape::PolylineZ(shapefile::PolylineZ::new(vec![
+            shapefile::PointZ::new(139.0, 35.0, 10.0, 100.0),
+            shapefile::PointZ::new(139.0, 36.0, 20.0, 200.0),
+        ]));
+        let geometry = CoordTransformer::identity().transform(&shape).unwrap();
+        assert_eq!(geometry.dim(), geo_traits::Dimensions::Xyz);
+        let geometry = CoordTransformer::identity()
+            .with_measures(true)
+            .transform(&shape)
+            .unwrap();
+        assert_eq!(geometry.dim(), geo_traits::Dimensions::Xyzm);
+        assert_eq!(measures(&geometry), vec![Some(100.0), Some(200.0)]);
+    }
+
+    #[test]
+    fn test_multipatch_polygons() {
+        let p = |x: f64, y: f64| shapefile::PointZ::new(x, y, 1.0, shapefile::NO_DATA);
+        let ring = |x: f64, y: f64, size: f64| {
+            vec![
+                p(x, y),
+                p(x, y + size),
+                p(x + size, y + size),
+                p(x + size, y),
+                p(x, y),
+            ]
+        };
+
+        let multipatch = shapefile::Multipatch::with_parts(vec![
+            // 2 triangles and a degenerate one
+            Patch::TriangleStrip(vec![
+                p(0.0, 0.0),
+                p(0.0, 1.0),
+                p(1.0, 0.0),
+                p(1.0, 1.0),
+                p(1.0, 1.0),
+            ]),
+            // 2 triangles sharing the first vertex
+            Patch::TriangleFan(vec![p(0.0, 0.0), p(1.0, 0.0), p(1.0, 1.0), p(0.0, 1.0)]),
+            Patch::OuterRing(ring(0.0, 0.0, 10.0)),
+            Patch::InnerRing(ring(1.0, 1.0, 1.0)),
+            Patch::FirstRing(ring(20.0, 0.0, 10.0)),
+            Patch::Ring(ring(21.0, 1.0, 1.0)),
+            Patch::Ring(ring(23.0, 1.0, 1.0)),
+            // Not preceded by a First Ring
+            Patch::TriangleFan(vec![]),
+            Patch::Ring(ring(40.0, 0.0, 1.0)),
+        ]);
+
+        let polygons = multipatch_polygons(&multipatch);
+        let num_rings: Vec<usize> = polygons.iter().map(|rings| rings.len()).collect();
+        assert_eq!(num_rings, vec![1, 1, 1, 1, 2, 3, 1]);
+        assert_eq!(
+            polygons[1][0],
+            vec![p(0.0, 1.0), p(1.0, 0.0), p(1.0, 1.0), p(0.0, 1.0)]
+        );
+        assert_eq!(
+            polygons[3][0],
+            vec![p(0.0, 0.0), p(1.0, 1.0), p(0.0, 1.0), p(0.0, 0.0)]
+        );
+
+        let geometry = CoordTransformer::identity()
+            .transform(&Shape::Multipatch(multipatch))
+            .unwrap();
+        assert_eq!(geometry.dim(), geo_traits::Dimensions::Xyz);
+        let GeometryType::MultiPolygon(mp) = geometry.as_type() else {
+            panic!("not a multipolygon");
+        };
+        assert_eq!(mp.num_polygons(), 7);
+    }
+
+    #[test]
+    fn test_has_measures() {
+        let mut header = shapefile::header::Header {
+            shape_type: shapefile::ShapeType::PolylineM,
+            ..Default::default()
+        };
+        assert!(has_measures(&header));
+
+        // No data
+        header.shape_type = shapefile::ShapeType::PolylineZ;
+        assert!(!has_measures(&header));
+
+        // Zero-filled
+        header.bbox.min.m = 0.0;
+        header.bbox.max.m = 0.0;
+        assert!(!has_measures(&header));
+
+        header.bbox.max.m = 12.5;
+        assert!(has_measures(&header));
+
+        header.shape_type = shapefile::ShapeType::Polyline;
+        assert!(!has_measures(&header));
+    }
+}
@@ -0,0 +1,154 @@
+use crate::translate::{
+    codelists::{Codelist, all_codelists, get_codelist_map, lookup_column_codelist},
+    data::{
+        colnames::{COLNAMES, lookup_colnames},
+        search,
+    },
+};
+
+/// A column of a KSJ dataset
+#[derive(Debug, Clone, PartialEq)]
+pub struct ColumnInfo {
+    /// Original column name (e.g. `P04_001`)
+    pub col_id: &'static str,
+    /// Human-readable column name
+    pub name: &'static str,
+    /// Name of the codelist, if the column is a codelist column
+    pub codelist: Option<&'static str>,
+}
+
+/// List the KSJ ids whose column names are available
+///
+/// Note: A42 and L01 are not included because the column names of them are
+/// determined by the shapefile name or the year. Also, the KSJ ids of the
+/// categories disabled by the `dict-*` features are not included.
+pub fn list_ksj_ids() -> Vec<&'static str> {
+    let mut ksj_ids: Vec<&'static str> = COLNAMES
+        .iter()
+        .flat_map(|groups| groups.iter())
+        .map(|(ksj_id, _)| *ksj_id)
+        .collect();
+    ksj_ids.sort();
+    ksj_ids
+}
+
+/// List the columns of the KSJ id, sorted by the column id
+///
+/// The codelists are looked up in the same way as the conversion, so some of
+/// them depend on the year of the data and the name of the shapefile (e.g.
+/// `A03_006` uses a different codelist for each area). `year` can be
+/// `u16::MAX` to use the latest spec, and `target_shp` can be empty if it's
+/// unknown, in which case such columns have no codelist.
+pub fn list_columns(ksj_id: &str, year: u16, target_shp: &str) -> Option<Vec<ColumnInfo>> {
+    let columns = lookup_colnames(ksj_id)?
+        .iter()
+        .map(|&(col_id, name)| ColumnInfo {
+            col_id,
+            name,
+            codelist: get_codelist_map(col_id, year, target_shp).map(Codelist::name),
+        })
+        .collect();
+
+    Some(columns)
+}
+
+/// List the names of the codelists
+pub fn list_codelists() -> Vec<&'static str> {
+    all_codelists().iter().map(|(name, _)| *name).collect()
+}
+
+/// List the codes and the labels of the codelist, sorted by the code
+///
+/// `name` can be either a codelist name (e.g. `MED_CLASS_CD`) or a column name
+/// (e.g. `P04_001`).
+pub fn list_codes(name: &str) -> Option<Vec<(&'static str, &'static str)>> {
+    let codelist = find_codelist(name)?;
+
+    let mut codes: Vec<(&'static str, &'static str)> = codelist.iter().collect();
+    // Sort numeric codes in numeric order (i.e. "2" comes before "10")
+    codes.sort_by_key(|(code, _)| (code.parse::<i64>().map_err(|_| *code), *code));
+
+    Some(codes)
+}
+
+/// Look up the human-readable name of the column of the KSJ id
+pub fn lookup_colname(ksj_id: &str, col_id: &str) -> Option<&'static str> {
+    search(lookup_colnames(ksj_id)?, col_id)
+}
+
+/// Look up the label of the code
+///
+/// `name` can be either a codelist name (e.g. `MED_CLASS_CD`) or a column name
+/// (e.g. `P04_001`).
+pub fn lookup_code(name: &str, code: &str) -> Option<&'static str> {
+    find_codelist(name)?.get(code)
+}
+
+/// Find the codelist
+///
+/// `name` can be either a codelist name (e.g. `MED_CLASS_CD`) or a column name
+/// (e.g. `P04_001`).
+pub fn find_codelist(name: &str) -> Option<&'static Codelist> {
+    search(all_codelists(), name).or_else(|| lookup_column_codelist(name))
+}
+
+#[cfg(all(
+    test,
+    feature = "dict-colnames",
+    feature = "dict-codelists",
+    feature = "dict-all"
+))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_list_columns() {
+        assert!(list_ksj_ids().contains(&"P04"));
+
+        let columns = list_columns("P04", 2024, "").unwrap();
+        let p04_001 = columns.iter().find(|c| c.col_id == "P04_001").unwrap();
+        assert_eq!(p04_001.codelist, Some("MED_CLASS_CD"));
+
+        assert!(list_columns("UNKNOWN", 2024, "").is_none());
+    }
+
+    #[test]
+    fn test_list_columns_special_cases() {
+        let codelist_of = |target_shp: &str| {
+            list_columns("A03", 2024, target_shp)
+                .unwrap()
+                .into_iter()
+                .find(|c| c.col_id == "A03_006")
+                .unwrap()
+                .codelist
+        };
+
+        // Same as the codelist used for the conversion
+        assert_eq!(
+            codelist_of("A03-03_KINKI-g_ThreeMajorMetroPlanArea.shp"),
+            Some("A03_SECTION_TYPE_CD_KINKI")
+        );
+        assert_eq!(codelist_of(""), None);
+    }
+
+    #[test]
+    fn test_list_codes() {
+        let expected = vec![("1", "病院"), ("2", "診療所"), ("3", "歯科診療所")];
+        assert_eq!(list_codes("MED_CLASS_CD").unwrap(), expected);
+        assert_eq!(list_codes("P04_001").unwrap(), expected);
+
+        // numeric order
+        let codes = list_codes("A10_AREA_CD").unwrap();
+        assert_eq!(codes[2].0, "2");
+        assert_eq!(codes[10].0, "10");
+    }
+
+    #[test]
+    fn test_lookup() {
+        assert_eq!(lookup_code("P04_001", "2"), Some("診療所"));
+        assert_eq!(lookup_code("P04_001", "99"), None);
+        assert_eq!(find_codelist("P04_001").unwrap().name(), "MED_CLASS_CD");
+        assert_eq!(lookup_colname("A03", "A03_002"), Some("都道府県名"));
+        assert_eq!(lookup_colname("P04", "A03_002"), None);
+    }
+}
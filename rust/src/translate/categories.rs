@@ -0,0 +1,38 @@
+// The categories of the dictionaries, which correspond to the `dict-*` features
+// in Cargo.toml. This file is also included by `cargo xtask gen-dict` to split
+// the generated dictionaries, so it must not depend on the rest of the crate.
+
+/// The categories and the prefixes of their KSJ ids. Most of the KSJ ids are
+/// categorized by the first letter, but the ones listed explicitly take
+/// precedence (e.g. N03 is 政策区域 while the other N* are 交通).
+pub(crate) const CATEGORIES: &[(&str, &[&str])] = &[
+    ("policy", &["A", "N03"]),
+    ("transport", &["C", "N", "S"]),
+    ("land", &["G", "L"]),
+    ("facility", &["P"]),
+    ("water", &["W"]),
+];
+
+/// The category of the KSJ id, determined by the longest matching prefix
+pub(crate) fn category_of(ksj_id: &str) -> Option<&'static str> {
+    CATEGORIES
+        .iter()
+        .flat_map(|(category, prefixes)| prefixes.iter().map(move |prefix| (*category, *prefix)))
+        .filter(|(_, prefix)| ksj_id.starts_with(prefix))
+        .max_by_key(|(_, prefix)| prefix.len())
+        .map(|(category, _)| category)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_category_of() {
+        assert_eq!(category_of("A03"), Some("policy"));
+        assert_eq!(category_of("N02"), Some("transport"));
+        assert_eq!(category_of("N03"), Some("policy"));
+        assert_eq!(category_of("P04"), Some("facility"));
+        assert_eq!(category_of("mesh1000"), None);
+    }
+}
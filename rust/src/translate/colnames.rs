@@ -1,26 +1,23 @@
-use std::{collections::HashMap, sync::LazyLock};
-
 use crate::{
     TranslateOptions,
     error::Ksj2GpError,
-    translate::data::colnames::{
-        A42_COLNAMES_NORMAL, A42_COLNAMES_SPECIAL, COLNAMES, L01_COLNAMES_1983, L01_COLNAMES_2014,
-        L01_COLNAMES_2018, L01_COLNAMES_2022, L01_COLNAMES_2024,
+    translate::TranslationReport,
+    translate::categories::category_of,
+    translate::data::{
+        colnames::{
+            A42_COLNAMES_NORMAL, A42_COLNAMES_SPECIAL, L01_COLNAMES_1983, L01_COLNAMES_2014,
+            L01_COLNAMES_2018, L01_COLNAMES_2022, L01_COLNAMES_2024, lookup_colname,
+            lookup_colnames,
+        },
+        search,
     },
 };
 
-static COLNAMES_MAP: LazyLock<HashMap<&'static str, &'static str>> = LazyLock::new(|| {
-    let mut map: HashMap<&'static str, &'static str> = HashMap::with_capacity(COLNAMES.len());
-    for (col_id, name) in COLNAMES {
-        map.insert(col_id, name);
-    }
-    map
-});
-
 // TODO: return &str to avoid unnecessary allocation
 pub(crate) fn translate_colnames(
     col_id: &str,
     translate_options: &TranslateOptions,
+    report: &mut TranslationReport,
 ) -> Result<String, Ksj2GpError> {
     // No translation
     if !translate_options.translate_colnames {
@@ -36,10 +33,22 @@ pub(crate) fn translate_colnames(
         _ => {}
     }
 
-    match COLNAMES_MAP.get(col_id) {
+    // The KSJ id from the filename doesn't always match the one of the
+    // dictionary (e.g. `G04a` and `G04-a`), in which case the column name is
+    // looked up in all the KSJ ids
+    let name = match lookup_colnames(&translate_options.ksj_id) {
+        Some(columns) => search(columns, col_id),
+        None => lookup_colname(col_id),
+    };
+
+    match name {
         Some(name) => Ok(name.to_string()),
         None => {
-            if translate_options.ignore_translation_errors {
+            // Without the dictionary, the column names are left as is
+            if translate_options.ignore_translation_errors
+                || !has_colnames_dictionary(&translate_options.ksj_id)
+            {
+                report.record_untranslated_column(col_id);
                 Ok(col_id.to_string())
             } else {
                 Err(format!("Unknown column name translation: {col_id}").into())
@@ -48,6 +57,29 @@ pub(crate) fn translate_colnames(
     }
 }
 
+// Whether the dictionary of each category is compiled in (see the `dict-*`
+// features in Cargo.toml)
+const ENABLED_CATEGORIES: &[(&str, bool)] = &[
+    ("policy", cfg!(feature = "dict-policy")),
+    ("transport", cfg!(feature = "dict-transport")),
+    ("land", cfg!(feature = "dict-land")),
+    ("facility", cfg!(feature = "dict-facility")),
+    ("water", cfg!(feature = "dict-water")),
+];
+
+// Whether the dictionary of the column names of the KSJ id is compiled in
+fn has_colnames_dictionary(ksj_id: &str) -> bool {
+    if !cfg!(feature = "dict-colnames") {
+        return false;
+    }
+
+    category_of(ksj_id).is_none_or(|category| {
+        ENABLED_CATEGORIES
+            .iter()
+            .any(|(c, enabled)| *c == category && *enabled)
+    })
+}
+
 fn translate_colnames_a42(code: &str, target_shp: &str) -> Result<String, Ksj2GpError> {
     let idx: usize = parse_idx(code)?;
 
@@ -160,6 +192,7 @@ fn parse_idx(code: &str) -> Result<usize, Ksj2GpError> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::translate::CodelistValidation;
     use crate::translate::data::colnames::{
         A42_COLNAMES_NORMAL, A42_COLNAMES_SPECIAL, L01_COLNAMES_1983, L01_COLNAMES_2014,
         L01_COLNAMES_2018, L01_COLNAMES_2022, L01_COLNAMES_2024,
@@ -173,10 +206,12 @@ mod tests {
             ksj_id: ksj_id.to_string(),
             year: 2024,
             target_shp: target_shp.to_string(),
+            codelist_validation: CodelistValidation::Off,
         }
     }
 
     #[test]
+    #[cfg(all(feature = "dict-colnames", feature = "dict-policy"))]
     fn translate_a03_columns() {
         let opts = options("A03", "");
         let cases = [
@@ -191,11 +226,45 @@ mod tests {
         ];
 
         for (code, expected) in cases {
-            let actual = translate_colnames(code, &opts).unwrap();
+            let actual =
+                translate_colnames(code, &opts, &mut TranslationReport::default()).unwrap();
             assert_eq!(actual, expected, "code={code}");
         }
     }
 
+    #[test]
+    #[cfg(all(feature = "dict-colnames", feature = "dict-policy"))]
+    fn report_untranslated_columns() {
+        let mut opts = options("A03", "");
+        opts.ignore_translation_errors = true;
+        let mut report = TranslationReport::default();
+
+        let actual = translate_colnames("UNKNOWN_COL", &opts, &mut report).unwrap();
+        assert_eq!(actual, "UNKNOWN_COL");
+        assert_eq!(report.untranslated_columns, vec!["UNKNOWN_COL".to_string()]);
+
+        opts.ignore_translation_errors = false;
+        assert!(translate_colnames("UNKNOWN_COL", &opts, &mut report).is_err());
+    }
+
+    #[test]
+    #[cfg(not(feature = "dict-colnames"))]
+    fn fall_back_without_dictionary() {
+        let opts = options("A03", "");
+        let mut report = TranslationReport::default();
+
+        let actual = translate_colnames("A03_001", &opts, &mut report).unwrap();
+        assert_eq!(actual, "A03_001");
+        assert_eq!(report.untranslated_columns, vec!["A03_001".to_string()]);
+
+        // The special cases don't depend on the dictionary
+        let opts = options("S12", "");
+        assert_eq!(
+            translate_colnames("S12_001", &opts, &mut report).unwrap(),
+            "駅名"
+        );
+    }
+
     #[test]
     fn translate_a42_normal_columns() {
         let opts = options("A42", "Preservation_Area_of_Historic_Landscape.shp");
@@ -206,7 +275,8 @@ mod tests {
         ];
 
         for (code, expected) in cases {
-            let actual = translate_colnames(code, &opts).unwrap();
+            let actual =
+                translate_colnames(code, &opts, &mut TranslationReport::default()).unwrap();
             assert_eq!(actual, expected, "code={code}");
         }
     }
@@ -221,7 +291,8 @@ mod tests {
         ];
 
         for (code, expected) in cases {
-            let actual = translate_colnames(code, &opts).unwrap();
+            let actual =
+                translate_colnames(code, &opts, &mut TranslationReport::default()).unwrap();
             assert_eq!(actual, expected, "code={code}");
         }
     }
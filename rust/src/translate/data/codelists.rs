@@ -1,9126 +1,9007 @@
-use std::{collections::HashMap, sync::LazyLock};
-
-#[rustfmt::skip]
-pub(crate) static A10_AREA_CD: LazyLock<HashMap<&'static str, &'static str>> =
-    LazyLock::new(|| {
-        [
-            ("0", "北海道以外の都府県"),
-            ("1", "石狩振興局"),
-            ("2", "渡島総合振興局"),
-            ("3", "檜山振興局"),
-            ("4", "後志総合振興局"),
-            ("5", "空知総合振興局"),
-            ("6", "上川総合振興局"),
-            ("7", "留萌振興局"),
-            ("8", "宗谷総合振興局"),
-            ("9", "網走総合振興局"),
-            ("10", "胆振総合振興局"),
-            ("11", "日高振興局"),
-            ("12", "十勝総合振興局"),
-            ("13", "釧路総合振興局"),
-            ("14", "根室振興局"),
-        ]
-        .into_iter()
-        .collect()
-    });
-
-#[rustfmt::skip]
-pub(crate) static A10_LAYER_NO: LazyLock<HashMap<&'static str, &'static str>> =
-    LazyLock::new(|| {
-        [
-            ("1", "都市地域"),
-            ("2", "市街化区域"),
-            ("3", "市街化調整区域"),
-            ("4", "その他用途地域"),
-            ("5", "農業地域"),
-            ("6", "農用地区域"),
-            ("7", "森林地域"),
-            ("8", "国有林"),
-            ("9", "地域森林計画対象民有林"),
-            ("10", "保安林"),
-            ("11", "自然公園地域"),
-            ("12", "特別地域"),
-            ("13", "特別保護地区"),
-            ("14", "自然保全地域"),
-            ("15", "原生自然環境保全地域"),
-            ("16", "特別地区"),
-        ]
-        .into_iter()
-        .collect()
-    });
-
-#[rustfmt::skip]
-pub(crate) static A10_INSIDE_DIV: LazyLock<HashMap<&'static str, &'static str>> =
-    LazyLock::new(|| {
-        [
-            ("0", "毛羽無し"),
-            ("1", "内向き"),
-            ("2", "外向き"),
-        ]
-        .into_iter()
-        .collect()
-    });
-
-#[rustfmt::skip]
-pub(crate) static A42_HISTORICAL_DISTRICT_TYPE: LazyLock<HashMap<&'static str, &'static str>> =
-    LazyLock::new(|| {
-        [
-            ("1", "歴史的風土特別保存地区"),
-            ("2", "第１種歴史的風土保存地区（明日香村のみ）"),
-            ("3", "第２種歴史的風土保存地区（明日香村のみ）"),
-        ]
-        .into_iter()
-        .collect()
-    });
-
-#[rustfmt::skip]
-pub(crate) static ADMIN_CODE: LazyLock<HashMap<&'static str, &'static str>> =
-    LazyLock::new(|| {
-        [
-            ("1", "国"),
-            ("2", "都道府県"),
-            ("3", "市区町村"),
-            ("4", "民間"),
-            ("0", "その他"),
-        ]
-        .into_iter()
-        .collect()
-    });
-
-#[rustfmt::skip]
-pub(crate) static ADMIN_CON_AREA_CD: LazyLock<HashMap<&'static str, &'static str>> =
-    LazyLock::new(|| {
-        [
-            ("1", "都道府県知事"),
-            ("2", "市町村長"),
-            ("3", "一般事務組合"),
-            ("4", "港務局"),
-            ("9", "不明"),
-            ("0", "その他"),
-        ]
-        .into_iter()
-        .collect()
-    });
-
-#[rustfmt::skip]
-pub(crate) static ADMIN_HARBOR_CD: LazyLock<HashMap<&'static str, &'static str>> =
-    LazyLock::new(|| {
-        [
-            ("1", "都道府県"),
-            ("2", "市区町村"),
-            ("3", "港務局"),
-            ("4", "一部事務組合"),
-            ("5", "港湾管理者なし"),
-            ("0", "その他"),
-        ]
-        .into_iter()
-        .collect()
-    });
-
-#[rustfmt::skip]
-pub(crate) static ADMIN_SEA_LINE_CD: LazyLock<HashMap<&'static str, &'static str>> =
-    LazyLock::new(|| {
-        [
-            ("1", "国土交通省河川局"),
-            ("2", "国土交通省港湾局"),
-            ("3", "農林水産省農村振興局"),
-            ("4", "農林水産省水産庁"),
-            ("5", "農振河川共管"),
-            ("6", "不明（原典資料を入手できなかった場合）"),
-            ("7", "不明（原典資料をデータ化できなかった場合）"),
-            ("0", "その他"),
-        ]
-        .into_iter()
-        .collect()
-    });
-
-#[rustfmt::skip]
-pub(crate) static AGGREGATE_UNIT_FLAG: LazyLock<HashMap<&'static str, &'static str>> =
-    LazyLock::new(|| {
-        [
-            ("1", "集計単位が旧市町村単位の資料から入力する場合に付与"),
-            ("2", "集計単位が現市町村単位の場合、代表市町村（※）に付与"),
-            ("3", "集計単位が現消防本部単位の場合、代表市町村（※）に付与"),
-            ("9", "現／旧市町村／消防局単位の資料が混在する資料から入力したデータに付与"),
-            ("0", "集計単位が現市町村単位または消防局単位の場合で、代表市町村でない場合に付与。これを付与した市町村で、かつ代表市町村に値が入っている年の集計値には「-3」を付与する（収集した資料に値がない「-1」と区別する）。"),
-        ]
-        .into_iter()
-        .collect()
-    });
-
-#[rustfmt::skip]
-pub(crate) static AGG_UNIT_FLAG_EMER_TRANS_CD: LazyLock<HashMap<&'static str, &'static str>> =
-    LazyLock::new(|| {
-        [
-            ("1", "集計単位が旧市町村単位の資料から入力する場合に付与"),
-            ("2", "集計単位が現市町村単位の場合、代表市町村（※）に付与"),
-            ("3", "集計単位が現消防本部単位の場合、代表市町村（※）に付与"),
-            ("9", "現／旧市町村／消防局単位の資料が混在する資料から入力したデータに付与"),
-            ("0", "集計単位が現市町村単位または消防局単位の場合で、代表市町村でない場合に付与。これを付与した市町村で、かつ代表市町村に値が入っている年の集計値には「-3」を付与する（収集した資料に値がない「-1」と区別する）。"),
-        ]
-        .into_iter()
-        .collect()
-    });
-
-#[rustfmt::skip]
-pub(crate) static AIR_JET_CD: LazyLock<HashMap<&'static str, &'static str>> =
-    LazyLock::new(|| {
-        [
-            ("1", "ジェット化"),
-            ("2", "ジェット化してない"),
-        ]
-        .into_iter()
-        .collect()
-    });
-
-#[rustfmt::skip]
-pub(crate) static AIRPORT_CAT_CD: LazyLock<HashMap<&'static str, &'static str>> =
-    LazyLock::new(|| {
-        [
-            ("1", "旧空港法に基づく第一種空港"),
-            ("2", "旧空港法に基づく第二種空港"),
-            ("3", "旧空港法に基づく第三種空港"),
-            ("4", "自衛隊、米軍との共用飛行場"),
-            ("5", "その他の共用飛行場"),
-            ("6", "拠点空港（会社管理）"),
-            ("7", "拠点空港（国管理空港）"),
-            ("8", "拠点空港（特定地方管理空港）"),
-            ("9", "地方管理空港"),
-            ("10", "その他の空港"),
-            ("11", "共用空港"),
-        ]
-        .into_iter()
-        .collect()
-    });
-
-#[rustfmt::skip]
-pub(crate) static AIRPORT_TRANSITION_CD: LazyLock<HashMap<&'static str, &'static str>> =
-    LazyLock::new(|| {
-        [
-            ("1", "空港名称"),
-            ("2", "空港種類"),
-            ("3", "設置者・管理者"),
-            ("4", "滑走路（本数、延長）"),
-        ]
-        .into_iter()
-        .collect()
-    });
-
-#[rustfmt::skip]
-pub(crate) static AIRPORT_USE_CD: LazyLock<HashMap<&'static str, &'static str>> =
-    LazyLock::new(|| {
-        [
-            ("1", "供用中"),
-            ("2", "建設中"),
-            ("3", "廃止（移転）"),
-            ("4", "休止中"),
-        ]
-        .into_iter()
-        .collect()
-    });
-
-#[rustfmt::skip]
-pub(crate) static AUTHORITY_TYPE: LazyLock<HashMap<&'static str, &'static str>> =
-    LazyLock::new(|| {
-        [
-            ("1", "国指定"),
-            ("2", "県指定"),
-        ]
-        .into_iter()
-        .collect()
-    });
-
-#[rustfmt::skip]
-pub(crate) static AVIATION_ACT_CD: LazyLock<HashMap<&'static str, &'static str>> =
-    LazyLock::new(|| {
-        [
-            ("1", "公共用へリポート"),
-            ("2", "非公共用へリポート"),
-            ("3", "その他のヘリポート"),
-        ]
-        .into_iter()
-        .collect()
-    });
-
-#[rustfmt::skip]
-pub(crate) static BIOMASS_TYPE: LazyLock<HashMap<&'static str, &'static str>> =
-    LazyLock::new(|| {
-        [
-            ("1", "家畜糞尿バイオガス化"),
-            ("2", "下水汚泥消化ガス"),
-            ("3", "バガス"),
-            ("4", "食品残渣バイオガス"),
-            ("5", "建築廃材"),
-            ("6", "木質系"),
-            ("7", "鶏糞"),
-        ]
-        .into_iter()
-        .collect()
-    });
-
-#[rustfmt::skip]
-pub(crate) static BUS_CLASS_CD: LazyLock<HashMap<&'static str, &'static str>> =
-    LazyLock::new(|| {
-        [
-            ("1", "路線バス（民間）"),
-            ("2", "路線バス（公営）"),
-            ("3", "コミュニティバス"),
-            ("4", "デマンドバス"),
-            ("5", "その他"),
-        ]
-        .into_iter()
-        .collect()
-    });
-
-#[rustfmt::skip]
-pub(crate) static BUSINESS_TECH_CD: LazyLock<HashMap<&'static str, &'static str>> =
-    LazyLock::new(|| {
-        [
-            ("1", "新住宅市街地開発事業"),
-            ("2", "一団地の住宅施設"),
-            ("3", "公的一般宅地開発事業 （公的主体による住宅・宅地開発事業で，1，2，4～7のいずれの手法にもよらないもの）"),
-            ("4", "都市計画法による開発許可"),
-            ("5", "旧住宅地造成事業に関する法律による許可"),
-            ("6", "土地区画整理事業"),
-            ("7", "その他，上記以外の事業手法"),
-            ("8", "不明"),
-        ]
-        .into_iter()
-        .collect()
-    });
-
-#[rustfmt::skip]
-pub(crate) static CITY_PARK_CD: LazyLock<HashMap<&'static str, &'static str>> =
-    LazyLock::new(|| {
-        [
-            ("1", "街区公園"),
-            ("2", "近隣公園"),
-            ("3", "地区公園（カントリーパーク）"),
-            ("4", "総合公園"),
-            ("5", "運動公園"),
-            ("6", "広域公園"),
-            ("7", "レクリエーション都市"),
-            ("8", "国営公園"),
-            ("9", "特殊公園（風致公園、動植物公園、歴史公園、墓園）"),
-            ("10", "緩衝緑地"),
-            ("11", "都市緑地"),
-            ("12", "緑道"),
-            ("13", "都市林"),
-            ("14", "広場公園"),
-        ]
-        .into_iter()
-        .collect()
-    });
-
-#[rustfmt::skip]
-pub(crate) static CLASS_FISH_PORT_CD: LazyLock<HashMap<&'static str, &'static str>> =
-    LazyLock::new(|| {
-        [
-            ("1", "第1種"),
-            ("2", "第2種"),
-            ("3", "第3種"),
-            ("4", "第4種"),
-            ("5", "特定第3種"),
-            ("0", "NA"),
-        ]
-        .into_iter()
-        .collect()
-    });
-
-#[rustfmt::skip]
-pub(crate) static CLASS_HARBOR1_CD: LazyLock<HashMap<&'static str, &'static str>> =
-    LazyLock::new(|| {
-        [
-            ("1", "甲種"),
-            ("2", "乙種"),
-            ("0", "その他"),
-        ]
-        .into_iter()
-        .collect()
-    });
-
-#[rustfmt::skip]
-pub(crate) static CLASS_HARBOR2_CD: LazyLock<HashMap<&'static str, &'static str>> =
-    LazyLock::new(|| {
-        [
-            ("11", "国際戦略港湾"),
-            ("12", "国際拠点港湾"),
-            ("13", "重要港湾"),
-            ("14", "地方港湾"),
-            ("15", "56条港湾"),
-            ("99", "その他"),
-        ]
-        .into_iter()
-        .collect()
-    });
-
-#[rustfmt::skip]
-pub(crate) static CODE_DESIGNATION_CD: LazyLock<HashMap<&'static str, &'static str>> =
-    LazyLock::new(|| {
-        [
-            ("1", "概ね重複指定"),
-            ("2", "一部指定"),
-            ("3", "未指定"),
-            ("4", "不明"),
-        ]
-        .into_iter()
-        .collect()
-    });
-
-#[rustfmt::skip]
-pub(crate) static CODE_NONCOMBUSTIBLE_CD: LazyLock<HashMap<&'static str, &'static str>> =
-    LazyLock::new(|| {
-        [
-            ("1", "旧建設省方式"),
-            ("2", "東京都方式"),
-            ("3", "大阪府方式"),
-            ("4", "市区町独自方式"),
-            ("5", "その他"),
-            ("6", "不明"),
-        ]
-        .into_iter()
-        .collect()
-    });
-
-#[rustfmt::skip]
-pub(crate) static CODE_OF_PHENOMENON: LazyLock<HashMap<&'static str, &'static str>> =
-    LazyLock::new(|| {
-        [
-            ("1", "急傾斜地の崩壊"),
-            ("2", "土石流"),
-            ("3", "地滑り"),
-        ]
-        .into_iter()
-        .collect()
-    });
-
-#[rustfmt::skip]
-pub(crate) static CODE_OF_UN_SPECIFICATION: LazyLock<HashMap<&'static str, &'static str>> =
-    LazyLock::new(|| {
-        [
-            ("0", "特別警戒区域指定済み"),
-            ("1", "特別警戒区域未指定"),
-        ]
-        .into_iter()
-        .collect()
-    });
-
-#[rustfmt::skip]
-pub(crate) static CODE_OF_ZONE_H27: LazyLock<HashMap<&'static str, &'static str>> =
-    LazyLock::new(|| {
-        [
-            ("1", "土砂災害警戒区域(指定済)"),
-            ("2", "土砂災害特別警戒区域(指定済)"),
-            ("3", "土砂災害警戒区域(指定前)"),
-            ("4", "土砂災害特別警戒区域(指定前)"),
-        ]
-        .into_iter()
-        .collect()
-    });
-
-#[rustfmt::skip]
-pub(crate) static COMMUNITY_CENTER_TYPE: LazyLock<HashMap<&'static str, &'static str>> =
-    LazyLock::new(|| {
-        [
-            ("1", "中央"),
-            ("2", "地区"),
-            ("3", "分館"),
-            ("4", "その他"),
-            ("-1", "公民館以外の施設"),
-        ]
-        .into_iter()
-        .collect()
-    });
-
-#[rustfmt::skip]
-pub(crate) static CULTURE_FAC_CD: LazyLock<HashMap<&'static str, &'static str>> =
-    LazyLock::new(|| {
-        [
-            ("03001", "美術館"),
-            ("03002", "資料館，記念館，博物館，科学館"),
-            ("03003", "図書館"),
-            ("03004", "水族館"),
-            ("03005", "動植物園"),
-            ("03101", "陸上競技場"),
-            ("03102", "野球場・ソフトボール場"),
-            ("03103", "球技場"),
-            ("03104", "多目的運動場"),
-            ("03105", "水泳プール（屋内）"),
-            ("03106", "水泳プール（屋外）"),
-            ("03107", "レジャープール"),
-            ("03108", "ダイビングプール"),
-            ("03109", "体育館"),
-            ("03110", "柔道場"),
-            ("03111", "剣道場"),
-            ("03112", "柔剣道場（武道場）"),
-            ("03113", "空手・合気道場"),
-            ("03114", "バレーボール場（屋外）"),
-            ("03115", "庭球場（屋外）"),
-            ("03116", "庭球場（屋内）"),
-            ("03117", "バスケットボール場（屋外）"),
-            ("03118", "相撲場（屋外）"),
-            ("03119", "相撲場（屋内）"),
-            ("03120", "卓球場"),
-            ("03121", "弓道場"),
-            ("03122", "アーチェリー場"),
-            ("03123", "馬場"),
-            ("03124", "アイススケート場（屋内）"),
-            ("03125", "アイススケート場（屋外）"),
-            ("03126", "ﾛｰﾗｰｽｹｰﾄ･ｲﾝﾗｲﾝｽｹｰﾄ場（屋内）"),
-            ("03127", "ﾛｰﾗｰｽｹｰﾄ･ｲﾝﾗｲﾝｽｹｰﾄ場（屋外）"),
-            ("03128", "山の家・林間学校等の施設"),
-            ("03129", "トレーニング場"),
-            ("03130", "レスリング場"),
-            ("03131", "ボクシング場"),
-            ("03132", "ダンス場"),
-            ("03133", "射撃場"),
-            ("03134", "ゴルフ場"),
-            ("03135", "ゴルフ練習場"),
-            ("03136", "ボウリング場"),
-            ("03137", "漕艇場"),
-            ("03138", "ゲートボール・クロッケー場"),
-            ("03139", "スカッシュ・ラケットボール場"),
-            ("03140", "ヨット場"),
-            ("03141", "スキー・スノーボード場"),
-            ("03142", "キャンプ場"),
-            ("03143", "ハイキンギコース"),
-            ("03144", "サイクリングコース"),
-            ("03145", "オリエンテーリングコース"),
-            ("03146", "ランニングコース"),
-            ("03147", "冒険遊具コース"),
-            ("03148", "海の家・海水浴場等の施設"),
-            ("03149", "河川・湖沼等の遊泳場"),
-            ("03150", "スカイスポーツ施設"),
-        ]
-        .into_iter()
-        .collect()
-    });
-
-#[rustfmt::skip]
-pub(crate) static DAM_INSTITUTION_CD: LazyLock<HashMap<&'static str, &'static str>> =
-    LazyLock::new(|| {
-        [
-            ("1", "国土交通省（各地方整備局、北海道開発局含む）"),
-            ("2", "沖縄開発庁"),
-            ("3", "農林水産省（各地方農政局含む）"),
-            ("4", "都道府県"),
-            ("5", "市区町村"),
-            ("6", "水資源開発公団"),
-            ("7", "その他の公共企業体"),
-            ("8", "土地改良区"),
-            ("9", "利水組合・用水組合"),
-            ("10", "電力会社・電源開発株式会社"),
-            ("11", "その他の企業"),
-            ("12", "個人"),
-            ("13", "その他"),
-        ]
-        .into_iter()
-        .collect()
-    });
-
-#[rustfmt::skip]
-pub(crate) static DAMP_URPOSE_CD: LazyLock<HashMap<&'static str, &'static str>> =
-    LazyLock::new(|| {
-        [
-            ("1", "洪水調節、農地防災"),
-            ("2", "不特定用水、河川維持用水"),
-            ("3", "灌漑、特定(新規)灌漑用水"),
-            ("4", "上水道用水"),
-            ("5", "工業用水道用水"),
-            ("6", "発電"),
-            ("7", "消流雪用水"),
-            ("8", "レクリエーション"),
-        ]
-        .into_iter()
-        .collect()
-    });
-
-#[rustfmt::skip]
-pub(crate) static DAM_TYPE_CD: LazyLock<HashMap<&'static str, &'static str>> =
-    LazyLock::new(|| {
-        [
-            ("1", "アーチダム"),
-            ("2", "バットレスダム"),
-            ("3", "アースダム"),
-            ("4", "アスファルトフェイシングダム"),
-            ("5", "アスファルトコアダム"),
-            ("6", "フローティングゲートダム（可動堰）"),
-            ("7", "重力式コンクリートダム"),
-            ("8", "重力式アーチダム"),
-            ("9", "重力式コンクリートダム・フィルダム複合ダム"),
-            ("10", "中空重力式コンクリートダム"),
-            ("11", "マルティプルアーチダム"),
-            ("12", "ロックフィルダム"),
-            ("13", "台形CSGダム"),
-        ]
-        .into_iter()
-        .collect()
-    });
-
-#[rustfmt::skip]
-pub(crate) static DIRECTION: LazyLock<HashMap<&'static str, &'static str>> =
-    LazyLock::new(|| {
-        [
-            ("0", "方向なし"),
-            ("1", "北"),
-            ("2", "北東"),
-            ("3", "東"),
-            ("4", "東南"),
-            ("5", "南"),
-            ("6", "南西"),
-            ("7", "西"),
-            ("8", "北西"),
-            ("unknown", "NA"),
-        ]
-        .into_iter()
-        .collect()
-    });
-
-#[rustfmt::skip]
-pub(crate) static DISTRIBUTION_CD: LazyLock<HashMap<&'static str, &'static str>> =
-    LazyLock::new(|| {
-        [
-            ("11", "コンテナターミナル_国際戦略港湾"),
-            ("12", "コンテナターミナル_国際拠点港湾"),
-            ("13", "コンテナターミナル_重要港湾"),
-            ("14", "コンテナターミナル_地方港湾"),
-            ("15", "コンテナターミナル_56条港湾"),
-            ("16", "コンテナターミナル_その他"),
-            ("21", "航空貨物ターミナル_拠点空港"),
-            ("22", "航空貨物ターミナル_拠点空港（特定地方管理空港）"),
-            ("23", "航空貨物ターミナル_地方管理空港"),
-            ("24", "航空貨物ターミナル_その他の空港"),
-            ("25", "航空貨物ターミナル_共用空港"),
-            ("26", "航空貨物ターミナル_その他"),
-            ("31", "鉄道貨物駅_一般"),
-            ("32", "鉄道貨物駅_一般（臨時・中継等）"),
-            ("33", "鉄道貨物駅_ORS"),
-            ("41", "保税地域_指定保税地域"),
-            ("42", "保税地域_保税蔵置場"),
-            ("43", "保税地域_総合保税地域"),
-            ("51", "トラックターミナル_トラックターミナル法"),
-            ("52", "トラックターミナル_その他"),
-            ("61", "倉庫_普通倉庫（１類倉庫）"),
-            ("62", "倉庫_普通倉庫（２類倉庫）"),
-            ("63", "倉庫_普通倉庫（３類倉庫）"),
-            ("64", "倉庫_普通倉庫（野積倉庫）"),
-            ("65", "倉庫_水面倉庫"),
-            ("66", "倉庫_普通倉庫（貯蔵槽倉庫）"),
-            ("67", "倉庫_普通倉庫（危険品倉庫（建屋））"),
-            ("68", "倉庫_普通倉庫（危険品倉庫（貯蔵槽））"),
-            ("69", "倉庫_普通倉庫（危険品倉庫（野積）））"),
-            ("70", "倉庫_冷蔵倉庫"),
-            ("71", "卸売市場_中央卸売市場"),
-            ("72", "卸売市場_地方卸売市場"),
-        ]
-        .into_iter()
-        .collect()
-    });
-
-#[rustfmt::skip]
-pub(crate) static DISTRIBUTION_CENTER_CD: LazyLock<HashMap<&'static str, &'static str>> =
-    LazyLock::new(|| {
-        [
-            ("1", "コンテナターミナル"),
-            ("2", "航空貨物ターミナル"),
-            ("3", "鉄道貨物駅"),
-            ("4", "保税地域"),
-            ("5", "トラックターミナル"),
-            ("7", "卸売市場"),
-        ]
-        .into_iter()
-        .collect()
-    });
-
-#[rustfmt::skip]
-pub(crate) static ENTREPRENEUR_CD: LazyLock<HashMap<&'static str, &'static str>> =
-    LazyLock::new(|| {
-        [
-            ("1", "公営"),
-            ("2", "民営"),
-            ("3", "その他"),
-        ]
-        .into_iter()
-        .collect()
-    });
-
-#[rustfmt::skip]
-pub(crate) static EST_CLASS_CD: LazyLock<HashMap<&'static str, &'static str>> =
-    LazyLock::new(|| {
-        [
-            ("1", "国"),
-            ("2", "公的医療機関"),
-            ("3", "社会保険関係団体"),
-            ("4", "医療法人"),
-            ("5", "個人"),
-            ("6", "その他"),
-            ("9", "分類対象外"),
-        ]
-        .into_iter()
-        .collect()
-    });
-
-#[rustfmt::skip]
-pub(crate) static FACILITIES_CLASSIFICATION_CD: LazyLock<HashMap<&'static str, &'static str>> =
-    LazyLock::new(|| {
-        [
-            ("1", "焼却施設"),
-            ("2", "粗大ごみ処理施設"),
-            ("3", "資源化等を行う施設"),
-            ("4", "ごみ燃料化施設"),
-            ("5", "その他の施設"),
-            ("6", "保管施設"),
-            ("7", "最終処分場"),
-            ("8", "し尿処理施設"),
-            ("9", "コミュニティ・プラント"),
-            ("10", "リユース・リペア施設"),
-        ]
-        .into_iter()
-        .collect()
-    });
-
-#[rustfmt::skip]
-pub(crate) static FACILITY_TYPE_CODE: LazyLock<HashMap<&'static str, &'static str>> =
-    LazyLock::new(|| {
-        [
-            ("1", "映画館"),
-            ("2", "公会堂・集会場"),
-            ("3", "劇場・演劇場"),
-            ("4", "展示場"),
-            ("5", "寄席を有する体育館・観覧場"),
-            ("6", "その他集客施設"),
-        ]
-        .into_iter()
-        .collect()
-    });
-
-#[rustfmt::skip]
-pub(crate) static FIREHOUSE_TYPE: LazyLock<HashMap<&'static str, &'static str>> =
-    LazyLock::new(|| {
-        [
-            ("1", "消防本部"),
-            ("2", "消防署"),
-            ("3", "分署・出張所"),
-        ]
-        .into_iter()
-        .collect()
-    });
-
-#[rustfmt::skip]
-pub(crate) static FISH_PORT_ADMIN_CD: LazyLock<HashMap<&'static str, &'static str>> =
-    LazyLock::new(|| {
-        [
-            ("1", "都道府県"),
-            ("2", "市区町村"),
-            ("3", "その他"),
-        ]
-        .into_iter()
-        .collect()
-    });
-
-#[rustfmt::skip]
-pub(crate) static FLOOD_DURATION_CODE: LazyLock<HashMap<&'static str, &'static str>> =
-    LazyLock::new(|| {
-        [
-            ("1", "12時間未満"),
-            ("2", "12時間以上24時間未満（1日間）"),
-            ("3", "24時間以上72時間未満（3日間）"),
-            ("4", "72時間以上168時間未満（1週間）"),
-            ("5", "168時間以上336時間未満（2週間）"),
-            ("6", "336時間以上672時間未満（4週間）"),
-            ("7", "672時間以上（4週間以上）"),
-        ]
-        .into_iter()
-        .collect()
-    });
-
-#[rustfmt::skip]
-pub(crate) static FUEL_STORE_CD: LazyLock<HashMap<&'static str, &'static str>> =
-    LazyLock::new(|| {
-        [
-            ("1", "SS（ガソリンスタンド）"),
-            ("2", "SS（LPガススタンド）"),
-            ("3", "SS以外の燃料販売店(家庭で使用する灯油等の燃料販売店）"),
-        ]
-        .into_iter()
-        .collect()
-    });
-
-#[rustfmt::skip]
-pub(crate) static FURNACE_TYPE: LazyLock<HashMap<&'static str, &'static str>> =
-    LazyLock::new(|| {
-        [
-            ("1", "GCR（ガス冷却炉）"),
-            ("2", "BWR（沸騰水型軽水炉）"),
-            ("3", "PWR（加圧水型軽水炉）"),
-            ("4", "ABWR（改良型沸騰水型軽水炉）"),
-            ("5", "ATR（新型転換炉）"),
-            ("6", "FBR（高速増殖炉）"),
-            ("7", "APWR(改良型加圧水型軽水炉)"),
-        ]
-        .into_iter()
-        .collect()
-    });
-
-#[rustfmt::skip]
-pub(crate) static HAZARDOUS_AREA_CLASSIFICATION_CODE: LazyLock<HashMap<&'static str, &'static str>> =
-    LazyLock::new(|| {
-        [
-            ("1", "氾濫流"),
-            ("2", "河岸浸食"),
-            ("3", "どちらも該当"),
-        ]
-        .into_iter()
-        .collect()
-    });
-
-#[rustfmt::skip]
-pub(crate) static HEAVY_SNOW_TYPE_CODE: LazyLock<HashMap<&'static str, &'static str>> =
-    LazyLock::new(|| {
-        [
-            ("1", "豪雪地帯"),
-            ("2", "特別豪雪地帯"),
-        ]
-        .into_iter()
-        .collect()
-    });
-
-#[rustfmt::skip]
-pub(crate) static HIGHWAY_CAT_CD: LazyLock<HashMap<&'static str, &'static str>> =
-    LazyLock::new(|| {
-        [
-            ("1", "高速自動車国道"),
-            ("2", "高速自動車国道に並行する自動車専用道路"),
-            ("3", "一般国道の自動車専用道路"),
-            ("4", "本州四国連絡高速道路"),
-            ("5", "指定都市高速道路"),
-            ("6", "その他の道路"),
-        ]
-        .into_iter()
-        .collect()
-    });
-
-#[rustfmt::skip]
-pub(crate) static HIGHWAY_CON_CD: LazyLock<HashMap<&'static str, &'static str>> =
-    LazyLock::new(|| {
-        [
-            ("1", "一般インターチェンジ"),
-            ("2", "スマートインターチェンジ"),
-            ("3", "ジャンクション"),
-            ("4", "その他の接合部"),
-        ]
-        .into_iter()
-        .collect()
-    });
-
-#[rustfmt::skip]
-pub(crate) static HIGHWAY_TRANSITION_CD: LazyLock<HashMap<&'static str, &'static str>> =
-    LazyLock::new(|| {
-        [
-            ("1", "路線名"),
-            ("2", "路線種別"),
-            ("3", "車線数"),
-        ]
-        .into_iter()
-        .collect()
-    });
-
-#[rustfmt::skip]
-pub(crate) static HIGHWAY_USE_CD: LazyLock<HashMap<&'static str, &'static str>> =
-    LazyLock::new(|| {
-        [
-            ("1", "完成供用"),
-            ("2", "暫定供用"),
-            ("3", "高速自動車国道に切り替えて供用"),
-            ("4", "一般国道に切り替えて供用"),
-            ("5", "その他の供用"),
-        ]
-        .into_iter()
-        .collect()
-    });
-
-#[rustfmt::skip]
-pub(crate) static HOANRIN_CD: LazyLock<HashMap<&'static str, &'static str>> =
-    LazyLock::new(|| {
-        [
-            ("水涵保", "水源かん養保安林"),
-            ("土流保", "土砂流出防備保安林"),
-            ("土崩保", "土砂崩壊防備保安林"),
-            ("飛砂保", "飛砂防備保安林"),
-            ("防風保", "防風保安林"),
-            ("水害保", "水害防備保安林"),
-            ("潮害保", "潮害防備保安林"),
-            ("干害保", "干害防備保安林"),
-            ("防雪保", "防雪保安林"),
-            ("防霧保", "防霧保安林"),
-            ("雪崩保", "なだれ防止保安林"),
-            ("落石保", "落石防止保安林"),
-            ("防火保", "防火保安林"),
-            ("魚付保", "魚つき保安林"),
-            ("航行保", "航行目標保安林"),
-            ("保健保", "保健保安林"),
-            ("風致保", "風致保安林"),
-            ("水涵見", "水源かん養保安林見込み地"),
-            ("土流見", "土砂流出防備保安林見込み地"),
-            ("土崩見", "土砂崩壊防備保安林見込み地"),
-            ("飛砂見", "飛砂防備保安林見込み地"),
-            ("防風見", "防風保安林見込み地"),
-            ("水害見", "水害防備保安林見込み地"),
-            ("潮害見", "潮害防備保安林見込み地"),
-            ("干害見", "干害防備保安林見込み地"),
-            ("防雪見", "防雪保安林見込み地"),
-            ("防霧見", "防霧保安林見込み地"),
-            ("雪崩見", "なだれ防止保安林見込み地"),
-            ("落石見", "落石防止保安林見込み地"),
-            ("防火見", "防火保安林見込み地"),
-            ("魚付見", "魚つき保安林見込み地"),
-            ("航行見", "航行目標保安林見込み地"),
-            ("保健見", "保健保安林見込み地"),
-            ("風致見", "風致保安林見込み地"),
-        ]
-        .into_iter()
-        .collect()
-    });
-
-#[rustfmt::skip]
-pub(crate) static HOGORIN_CD: LazyLock<HashMap<&'static str, &'static str>> =
-    LazyLock::new(|| {
-        [
-            ("保護林", "保護林に設定されているもの"),
-        ]
-        .into_iter()
-        .collect()
-    });
-
-#[rustfmt::skip]
-pub(crate) static HYDROELECTRIC_POWER_PLANT_TYPE: LazyLock<HashMap<&'static str, &'static str>> =
-    LazyLock::new(|| {
-        [
-            ("1", "ダム式"),
-            ("2", "水路式"),
-            ("3", "ダム水路"),
-        ]
-        .into_iter()
-        .collect()
-    });
+// Generated by `cargo xtask gen-dict`. Don't edit by hand.
+use crate::translate::codelists::Codelist;
+
+#[cfg(feature = "dict-policy")]
+#[rustfmt::skip]
+pub(crate) static A10_AREA_CD: Codelist = Codelist::new("A10_AREA_CD", &[
+    ("0", "北海道以外の都府県"),
+    ("1", "石狩振興局"),
+    ("10", "胆振総合振興局"),
+    ("11", "日高振興局"),
+    ("12", "十勝総合振興局"),
+    ("13", "釧路総合振興局"),
+    ("14", "根室振興局"),
+    ("2", "渡島総合振興局"),
+    ("3", "檜山振興局"),
+    ("4", "後志総合振興局"),
+    ("5", "空知総合振興局"),
+    ("6", "上川総合振興局"),
+    ("7", "留萌振興局"),
+    ("8", "宗谷総合振興局"),
+    ("9", "網走総合振興局"),
+]);
+
+#[cfg(feature = "dict-policy")]
+#[rustfmt::skip]
+pub(crate) static A10_LAYER_NO: Codelist = Codelist::new("A10_LAYER_NO", &[
+    ("1", "都市地域"),
+    ("10", "保安林"),
+    ("11", "自然公園地域"),
+    ("12", "特別地域"),
+    ("13", "特別保護地区"),
+    ("14", "自然保全地域"),
+    ("15", "原生自然環境保全地域"),
+    ("16", "特別地区"),
+    ("2", "市街化区域"),
+    ("3", "市街化調整区域"),
+    ("4", "その他用途地域"),
+    ("5", "農業地域"),
+    ("6", "農用地区域"),
+    ("7", "森林地域"),
+    ("8", "国有林"),
+    ("9", "地域森林計画対象民有林"),
+]);
+
+#[cfg(feature = "dict-policy")]
+#[rustfmt::skip]
+pub(crate) static A10_INSIDE_DIV: Codelist = Codelist::new("A10_INSIDE_DIV", &[
+    ("0", "毛羽無し"),
+    ("1", "内向き"),
+    ("2", "外向き"),
+]);
+
+#[cfg(feature = "dict-policy")]
+#[rustfmt::skip]
+pub(crate) static A42_HISTORICAL_DISTRICT_TYPE: Codelist = Codelist::new("A42_HISTORICAL_DISTRICT_TYPE", &[
+    ("1", "歴史的風土特別保存地区"),
+    ("2", "第１種歴史的風土保存地区（明日香村のみ）"),
+    ("3", "第２種歴史的風土保存地区（明日香村のみ）"),
+]);
+
+#[cfg(feature = "dict-facility")]
+#[rustfmt::skip]
+pub(crate) static ADMIN_CODE: Codelist = Codelist::new("ADMIN_CODE", &[
+    ("0", "その他"),
+    ("1", "国"),
+    ("2", "都道府県"),
+    ("3", "市区町村"),
+    ("4", "民間"),
+]);
+
+#[cfg(feature = "dict-transport")]
+#[rustfmt::skip]
+pub(crate) static ADMIN_CON_AREA_CD: Codelist = Codelist::new("ADMIN_CON_AREA_CD", &[
+    ("0", "その他"),
+    ("1", "都道府県知事"),
+    ("2", "市町村長"),
+    ("3", "一般事務組合"),
+    ("4", "港務局"),
+    ("9", "不明"),
+]);
+
+#[cfg(feature = "dict-transport")]
+#[rustfmt::skip]
+pub(crate) static ADMIN_HARBOR_CD: Codelist = Codelist::new("ADMIN_HARBOR_CD", &[
+    ("0", "その他"),
+    ("1", "都道府県"),
+    ("2", "市区町村"),
+    ("3", "港務局"),
+    ("4", "一部事務組合"),
+    ("5", "港湾管理者なし"),
+]);
+
+#[cfg(feature = "dict-transport")]
+#[rustfmt::skip]
+pub(crate) static ADMIN_SEA_LINE_CD: Codelist = Codelist::new("ADMIN_SEA_LINE_CD", &[
+    ("0", "その他"),
+    ("1", "国土交通省河川局"),
+    ("2", "国土交通省港湾局"),
+    ("3", "農林水産省農村振興局"),
+    ("4", "農林水産省水産庁"),
+    ("5", "農振河川共管"),
+    ("6", "不明（原典資料を入手できなかった場合）"),
+    ("7", "不明（原典資料をデータ化できなかった場合）"),
+]);
+
+#[cfg(feature = "dict-policy")]
+#[rustfmt::skip]
+pub(crate) static AGGREGATE_UNIT_FLAG: Codelist = Codelist::new("AGGREGATE_UNIT_FLAG", &[
+    ("0", "集計単位が現市町村単位または消防局単位の場合で、代表市町村でない場合に付与。これを付与した市町村で、かつ代表市町村に値が入っている年の集計値には「-3」を付与する（収集した資料に値がない「-1」と区別する）。"),
+    ("1", "集計単位が旧市町村単位の資料から入力する場合に付与"),
+    ("2", "集計単位が現市町村単位の場合、代表市町村（※）に付与"),
+    ("3", "集計単位が現消防本部単位の場合、代表市町村（※）に付与"),
+    ("9", "現／旧市町村／消防局単位の資料が混在する資料から入力したデータに付与"),
+]);
+
+#[cfg(feature = "dict-policy")]
+#[rustfmt::skip]
+pub(crate) static AGG_UNIT_FLAG_EMER_TRANS_CD: Codelist = Codelist::new("AGG_UNIT_FLAG_EMER_TRANS_CD", &[
+    ("0", "集計単位が現市町村単位または消防局単位の場合で、代表市町村でない場合に付与。これを付与した市町村で、かつ代表市町村に値が入っている年の集計値には「-3」を付与する（収集した資料に値がない「-1」と区別する）。"),
+    ("1", "集計単位が旧市町村単位の資料から入力する場合に付与"),
+    ("2", "集計単位が現市町村単位の場合、代表市町村（※）に付与"),
+    ("3", "集計単位が現消防本部単位の場合、代表市町村（※）に付与"),
+    ("9", "現／旧市町村／消防局単位の資料が混在する資料から入力したデータに付与"),
+]);
+
+#[cfg(feature = "dict-transport")]
+#[rustfmt::skip]
+pub(crate) static AIR_JET_CD: Codelist = Codelist::new("AIR_JET_CD", &[
+    ("1", "ジェット化"),
+    ("2", "ジェット化してない"),
+]);
+
+#[cfg(feature = "dict-transport")]
+#[rustfmt::skip]
+pub(crate) static AIRPORT_CAT_CD: Codelist = Codelist::new("AIRPORT_CAT_CD", &[
+    ("1", "旧空港法に基づく第一種空港"),
+    ("10", "その他の空港"),
+    ("11", "共用空港"),
+    ("2", "旧空港法に基づく第二種空港"),
+    ("3", "旧空港法に基づく第三種空港"),
+    ("4", "自衛隊、米軍との共用飛行場"),
+    ("5", "その他の共用飛行場"),
+    ("6", "拠点空港（会社管理）"),
+    ("7", "拠点空港（国管理空港）"),
+    ("8", "拠点空港（特定地方管理空港）"),
+    ("9", "地方管理空港"),
+]);
+
+#[cfg(feature = "dict-transport")]
+#[rustfmt::skip]
+pub(crate) static AIRPORT_TRANSITION_CD: Codelist = Codelist::new("AIRPORT_TRANSITION_CD", &[
+    ("1", "空港名称"),
+    ("2", "空港種類"),
+    ("3", "設置者・管理者"),
+    ("4", "滑走路（本数、延長）"),
+]);
+
+#[cfg(feature = "dict-transport")]
+#[rustfmt::skip]
+pub(crate) static AIRPORT_USE_CD: Codelist = Codelist::new("AIRPORT_USE_CD", &[
+    ("1", "供用中"),
+    ("2", "建設中"),
+    ("3", "廃止（移転）"),
+    ("4", "休止中"),
+]);
+
+#[cfg(feature = "dict-policy")]
+#[rustfmt::skip]
+pub(crate) static AUTHORITY_TYPE: Codelist = Codelist::new("AUTHORITY_TYPE", &[
+    ("1", "国指定"),
+    ("2", "県指定"),
+]);
+
+#[cfg(feature = "dict-transport")]
+#[rustfmt::skip]
+pub(crate) static AVIATION_ACT_CD: Codelist = Codelist::new("AVIATION_ACT_CD", &[
+    ("1", "公共用へリポート"),
+    ("2", "非公共用へリポート"),
+    ("3", "その他のヘリポート"),
+]);
+
+#[cfg(feature = "dict-facility")]
+#[rustfmt::skip]
+pub(crate) static BIOMASS_TYPE: Codelist = Codelist::new("BIOMASS_TYPE", &[
+    ("1", "家畜糞尿バイオガス化"),
+    ("2", "下水汚泥消化ガス"),
+    ("3", "バガス"),
+    ("4", "食品残渣バイオガス"),
+    ("5", "建築廃材"),
+    ("6", "木質系"),
+    ("7", "鶏糞"),
+]);
+
+#[cfg(any(feature = "dict-transport", feature = "dict-facility"))]
+#[rustfmt::skip]
+pub(crate) static BUS_CLASS_CD: Codelist = Codelist::new("BUS_CLASS_CD", &[
+    ("1", "路線バス（民間）"),
+    ("2", "路線バス（公営）"),
+    ("3", "コミュニティバス"),
+    ("4", "デマンドバス"),
+    ("5", "その他"),
+]);
+
+#[cfg(feature = "dict-facility")]
+#[rustfmt::skip]
+pub(crate) static BUSINESS_TECH_CD: Codelist = Codelist::new("BUSINESS_TECH_CD", &[
+    ("1", "新住宅市街地開発事業"),
+    ("2", "一団地の住宅施設"),
+    ("3", "公的一般宅地開発事業 （公的主体による住宅・宅地開発事業で，1，2，4～7のいずれの手法にもよらないもの）"),
+    ("4", "都市計画法による開発許可"),
+    ("5", "旧住宅地造成事業に関する法律による許可"),
+    ("6", "土地区画整理事業"),
+    ("7", "その他，上記以外の事業手法"),
+    ("8", "不明"),
+]);
+
+#[cfg(feature = "dict-facility")]
+#[rustfmt::skip]
+pub(crate) static CITY_PARK_CD: Codelist = Codelist::new("CITY_PARK_CD", &[
+    ("1", "街区公園"),
+    ("10", "緩衝緑地"),
+    ("11", "都市緑地"),
+    ("12", "緑道"),
+    ("13", "都市林"),
+    ("14", "広場公園"),
+    ("2", "近隣公園"),
+    ("3", "地区公園（カントリーパーク）"),
+    ("4", "総合公園"),
+    ("5", "運動公園"),
+    ("6", "広域公園"),
+    ("7", "レクリエーション都市"),
+    ("8", "国営公園"),
+    ("9", "特殊公園（風致公園、動植物公園、歴史公園、墓園）"),
+]);
+
+#[cfg(feature = "dict-transport")]
+#[rustfmt::skip]
+pub(crate) static CLASS_FISH_PORT_CD: Codelist = Codelist::new("CLASS_FISH_PORT_CD", &[
+    ("0", "NA"),
+    ("1", "第1種"),
+    ("2", "第2種"),
+    ("3", "第3種"),
+    ("4", "第4種"),
+    ("5", "特定第3種"),
+]);
+
+#[cfg(feature = "dict-transport")]
+#[rustfmt::skip]
+pub(crate) static CLASS_HARBOR1_CD: Codelist = Codelist::new("CLASS_HARBOR1_CD", &[
+    ("0", "その他"),
+    ("1", "甲種"),
+    ("2", "乙種"),
+]);
+
+#[cfg(feature = "dict-transport")]
+#[rustfmt::skip]
+pub(crate) static CLASS_HARBOR2_CD: Codelist = Codelist::new("CLASS_HARBOR2_CD", &[
+    ("11", "国際戦略港湾"),
+    ("12", "国際拠点港湾"),
+    ("13", "重要港湾"),
+    ("14", "地方港湾"),
+    ("15", "56条港湾"),
+    ("99", "その他"),
+]);
+
+#[cfg(feature = "dict-policy")]
+#[rustfmt::skip]
+pub(crate) static CODE_DESIGNATION_CD: Codelist = Codelist::new("CODE_DESIGNATION_CD", &[
+    ("1", "概ね重複指定"),
+    ("2", "一部指定"),
+    ("3", "未指定"),
+    ("4", "不明"),
+]);
+
+#[cfg(feature = "dict-policy")]
+#[rustfmt::skip]
+pub(crate) static CODE_NONCOMBUSTIBLE_CD: Codelist = Codelist::new("CODE_NONCOMBUSTIBLE_CD", &[
+    ("1", "旧建設省方式"),
+    ("2", "東京都方式"),
+    ("3", "大阪府方式"),
+    ("4", "市区町独自方式"),
+    ("5", "その他"),
+    ("6", "不明"),
+]);
+
+#[cfg(feature = "dict-policy")]
+#[rustfmt::skip]
+pub(crate) static CODE_OF_PHENOMENON: Codelist = Codelist::new("CODE_OF_PHENOMENON", &[
+    ("1", "急傾斜地の崩壊"),
+    ("2", "土石流"),
+    ("3", "地滑り"),
+]);
+
+#[cfg(feature = "dict-policy")]
+#[rustfmt::skip]
+pub(crate) static CODE_OF_UN_SPECIFICATION: Codelist = Codelist::new("CODE_OF_UN_SPECIFICATION", &[
+    ("0", "特別警戒区域指定済み"),
+    ("1", "特別警戒区域未指定"),
+]);
+
+#[cfg(feature = "dict-policy")]
+#[rustfmt::skip]
+pub(crate) static CODE_OF_ZONE_H27: Codelist = Codelist::new("CODE_OF_ZONE_H27", &[
+    ("1", "土砂災害警戒区域(指定済)"),
+    ("2", "土砂災害特別警戒区域(指定済)"),
+    ("3", "土砂災害警戒区域(指定前)"),
+    ("4", "土砂災害特別警戒区域(指定前)"),
+]);
+
+#[cfg(feature = "dict-facility")]
+#[rustfmt::skip]
+pub(crate) static COMMUNITY_CENTER_TYPE: Codelist = Codelist::new("COMMUNITY_CENTER_TYPE", &[
+    ("-1", "公民館以外の施設"),
+    ("1", "中央"),
+    ("2", "地区"),
+    ("3", "分館"),
+    ("4", "その他"),
+]);
+
+#[cfg(feature = "dict-facility")]
+#[rustfmt::skip]
+pub(crate) static CULTURE_FAC_CD: Codelist = Codelist::new("CULTURE_FAC_CD", &[
+    ("03001", "美術館"),
+    ("03002", "資料館，記念館，博物館，科学館"),
+    ("03003", "図書館"),
+    ("03004", "水族館"),
+    ("03005", "動植物園"),
+    ("03101", "陸上競技場"),
+    ("03102", "野球場・ソフトボール場"),
+    ("03103", "球技場"),
+    ("03104", "多目的運動場"),
+    ("03105", "水泳プール（屋内）"),
+    ("03106", "水泳プール（屋外）"),
+    ("03107", "レジャープール"),
+    ("03108", "ダイビングプール"),
+    ("03109", "体育館"),
+    ("03110", "柔道場"),
+    ("03111", "剣道場"),
+    ("03112", "柔剣道場（武道場）"),
+    ("03113", "空手・合気道場"),
+    ("03114", "バレーボール場（屋外）"),
+    ("03115", "庭球場（屋外）"),
+    ("03116", "庭球場（屋内）"),
+    ("03117", "バスケットボール場（屋外）"),
+    ("03118", "相撲場（屋外）"),
+    ("03119", "相撲場（屋内）"),
+    ("03120", "卓球場"),
+    ("03121", "弓道場"),
+    ("03122", "アーチェリー場"),
+    ("03123", "馬場"),
+    ("03124", "アイススケート場（屋内）"),
+    ("03125", "アイススケート場（屋外）"),
+    ("03126", "ﾛｰﾗｰｽｹｰﾄ･ｲﾝﾗｲﾝｽｹｰﾄ場（屋内）"),
+    ("03127", "ﾛｰﾗｰｽｹｰﾄ･ｲﾝﾗｲﾝｽｹｰﾄ場（屋外）"),
+    ("03128", "山の家・林間学校等の施設"),
+    ("03129", "トレーニング場"),
+    ("03130", "レスリング場"),
+    ("03131", "ボクシング場"),
+    ("03132", "ダンス場"),
+    ("03133", "射撃場"),
+    ("03134", "ゴルフ場"),
+    ("03135", "ゴルフ練習場"),
+    ("03136", "ボウリング場"),
+    ("03137", "漕艇場"),
+    ("03138", "ゲートボール・クロッケー場"),
+    ("03139", "スカッシュ・ラケットボール場"),
+    ("03140", "ヨット場"),
+    ("03141", "スキー・スノーボード場"),
+    ("03142", "キャンプ場"),
+    ("03143", "ハイキンギコース"),
+    ("03144", "サイクリングコース"),
+    ("03145", "オリエンテーリングコース"),
+    ("03146", "ランニングコース"),
+    ("03147", "冒険遊具コース"),
+    ("03148", "海の家・海水浴場等の施設"),
+    ("03149", "河川・湖沼等の遊泳場"),
+    ("03150", "スカイスポーツ施設"),
+]);
+
+#[cfg(feature = "dict-water")]
+#[rustfmt::skip]
+pub(crate) static DAM_INSTITUTION_CD: Codelist = Codelist::new("DAM_INSTITUTION_CD", &[
+    ("1", "国土交通省（各地方整備局、北海道開発局含む）"),
+    ("10", "電力会社・電源開発株式会社"),
+    ("11", "その他の企業"),
+    ("12", "個人"),
+    ("13", "その他"),
+    ("2", "沖縄開発庁"),
+    ("3", "農林水産省（各地方農政局含む）"),
+    ("4", "都道府県"),
+    ("5", "市区町村"),
+    ("6", "水資源開発公団"),
+    ("7", "その他の公共企業体"),
+    ("8", "土地改良区"),
+    ("9", "利水組合・用水組合"),
+]);
+
+#[cfg(feature = "dict-water")]
+#[rustfmt::skip]
+pub(crate) static DAMP_URPOSE_CD: Codelist = Codelist::new("DAMP_URPOSE_CD", &[
+    ("1", "洪水調節、農地防災"),
+    ("2", "不特定用水、河川維持用水"),
+    ("3", "灌漑、特定(新規)灌漑用水"),
+    ("4", "上水道用水"),
+    ("5", "工業用水道用水"),
+    ("6", "発電"),
+    ("7", "消流雪用水"),
+    ("8", "レクリエーション"),
+]);
+
+#[cfg(feature = "dict-water")]
+#[rustfmt::skip]
+pub(crate) static DAM_TYPE_CD: Codelist = Codelist::new("DAM_TYPE_CD", &[
+    ("1", "アーチダム"),
+    ("10", "中空重力式コンクリートダム"),
+    ("11", "マルティプルアーチダム"),
+    ("12", "ロックフィルダム"),
+    ("13", "台形CSGダム"),
+    ("2", "バットレスダム"),
+    ("3", "アースダム"),
+    ("4", "アスファルトフェイシングダム"),
+    ("5", "アスファルトコアダム"),
+    ("6", "フローティングゲートダム（可動堰）"),
+    ("7", "重力式コンクリートダム"),
+    ("8", "重力式アーチダム"),
+    ("9", "重力式コンクリートダム・フィルダム複合ダム"),
+]);
+
+#[cfg(feature = "dict-land")]
+#[rustfmt::skip]
+pub(crate) static DIRECTION: Codelist = Codelist::new("DIRECTION", &[
+    ("0", "方向なし"),
+    ("1", "北"),
+    ("2", "北東"),
+    ("3", "東"),
+    ("4", "東南"),
+    ("5", "南"),
+    ("6", "南西"),
+    ("7", "西"),
+    ("8", "北西"),
+    ("unknown", "NA"),
+]);
+
+#[cfg(feature = "dict-facility")]
+#[rustfmt::skip]
+pub(crate) static DISTRIBUTION_CD: Codelist = Codelist::new("DISTRIBUTION_CD", &[
+    ("11", "コンテナターミナル_国際戦略港湾"),
+    ("12", "コンテナターミナル_国際拠点港湾"),
+    ("13", "コンテナターミナル_重要港湾"),
+    ("14", "コンテナターミナル_地方港湾"),
+    ("15", "コンテナターミナル_56条港湾"),
+    ("16", "コンテナターミナル_その他"),
+    ("21", "航空貨物ターミナル_拠点空港"),
+    ("22", "航空貨物ターミナル_拠点空港（特定地方管理空港）"),
+    ("23", "航空貨物ターミナル_地方管理空港"),
+    ("24", "航空貨物ターミナル_その他の空港"),
+    ("25", "航空貨物ターミナル_共用空港"),
+    ("26", "航空貨物ターミナル_その他"),
+    ("31", "鉄道貨物駅_一般"),
+    ("32", "鉄道貨物駅_一般（臨時・中継等）"),
+    ("33", "鉄道貨物駅_ORS"),
+    ("41", "保税地域_指定保税地域"),
+    ("42", "保税地域_保税蔵置場"),
+    ("43", "保税地域_総合保税地域"),
+    ("51", "トラックターミナル_トラックターミナル法"),
+    ("52", "トラックターミナル_その他"),
+    ("61", "倉庫_普通倉庫（１類倉庫）"),
+    ("62", "倉庫_普通倉庫（２類倉庫）"),
+    ("63", "倉庫_普通倉庫（３類倉庫）"),
+    ("64", "倉庫_普通倉庫（野積倉庫）"),
+    ("65", "倉庫_水面倉庫"),
+    ("66", "倉庫_普通倉庫（貯蔵槽倉庫）"),
+    ("67", "倉庫_普通倉庫（危険品倉庫（建屋））"),
+    ("68", "倉庫_普通倉庫（危険品倉庫（貯蔵槽））"),
+    ("69", "倉庫_普通倉庫（危険品倉庫（野積）））"),
+    ("70", "倉庫_冷蔵倉庫"),
+    ("71", "卸売市場_中央卸売市場"),
+    ("72", "卸売市場_地方卸売市場"),
+]);
+
+#[cfg(feature = "dict-facility")]
+#[rustfmt::skip]
+pub(crate) static DISTRIBUTION_CENTER_CD: Codelist = Codelist::new("DISTRIBUTION_CENTER_CD", &[
+    ("1", "コンテナターミナル"),
+    ("2", "航空貨物ターミナル"),
+    ("3", "鉄道貨物駅"),
+    ("4", "保税地域"),
+    ("5", "トラックターミナル"),
+    ("7", "卸売市場"),
+]);
+
+#[cfg(feature = "dict-facility")]
+#[rustfmt::skip]
+pub(crate) static ENTREPRENEUR_CD: Codelist = Codelist::new("ENTREPRENEUR_CD", &[
+    ("1", "公営"),
+    ("2", "民営"),
+    ("3", "その他"),
+]);
+
+#[cfg(feature = "dict-facility")]
+#[rustfmt::skip]
+pub(crate) static EST_CLASS_CD: Codelist = Codelist::new("EST_CLASS_CD", &[
+    ("1", "国"),
+    ("2", "公的医療機関"),
+    ("3", "社会保険関係団体"),
+    ("4", "医療法人"),
+    ("5", "個人"),
+    ("6", "その他"),
+    ("9", "分類対象外"),
+]);
+
+#[cfg(feature = "dict-facility")]
+#[rustfmt::skip]
+pub(crate) static FACILITIES_CLASSIFICATION_CD: Codelist = Codelist::new("FACILITIES_CLASSIFICATION_CD", &[
+    ("1", "焼却施設"),
+    ("10", "リユース・リペア施設"),
+    ("2", "粗大ごみ処理施設"),
+    ("3", "資源化等を行う施設"),
+    ("4", "ごみ燃料化施設"),
+    ("5", "その他の施設"),
+    ("6", "保管施設"),
+    ("7", "最終処分場"),
+    ("8", "し尿処理施設"),
+    ("9", "コミュニティ・プラント"),
+]);
+
+#[cfg(feature = "dict-facility")]
+#[rustfmt::skip]
+pub(crate) static FACILITY_TYPE_CODE: Codelist = Codelist::new("FACILITY_TYPE_CODE", &[
+    ("1", "映画館"),
+    ("2", "公会堂・集会場"),
+    ("3", "劇場・演劇場"),
+    ("4", "展示場"),
+    ("5", "寄席を有する体育館・観覧場"),
+    ("6", "その他集客施設"),
+]);
+
+#[cfg(feature = "dict-facility")]
+#[rustfmt::skip]
+pub(crate) static FIREHOUSE_TYPE: Codelist = Codelist::new("FIREHOUSE_TYPE", &[
+    ("1", "消防本部"),
+    ("2", "消防署"),
+    ("3", "分署・出張所"),
+]);
+
+#[cfg(feature = "dict-transport")]
+#[rustfmt::skip]
+pub(crate) static FISH_PORT_ADMIN_CD: Codelist = Codelist::new("FISH_PORT_ADMIN_CD", &[
+    ("1", "都道府県"),
+    ("2", "市区町村"),
+    ("3", "その他"),
+]);
+
+#[cfg(feature = "dict-policy")]
+#[rustfmt::skip]
+pub(crate) static FLOOD_DURATION_CODE: Codelist = Codelist::new("FLOOD_DURATION_CODE", &[
+    ("1", "12時間未満"),
+    ("2", "12時間以上24時間未満（1日間）"),
+    ("3", "24時間以上72時間未満（3日間）"),
+    ("4", "72時間以上168時間未満（1週間）"),
+    ("5", "168時間以上336時間未満（2週間）"),
+    ("6", "336時間以上672時間未満（4週間）"),
+    ("7", "672時間以上（4週間以上）"),
+]);
+
+#[cfg(feature = "dict-facility")]
+#[rustfmt::skip]
+pub(crate) static FUEL_STORE_CD: Codelist = Codelist::new("FUEL_STORE_CD", &[
+    ("1", "SS（ガソリンスタンド）"),
+    ("2", "SS（LPガススタンド）"),
+    ("3", "SS以外の燃料販売店(家庭で使用する灯油等の燃料販売店）"),
+]);
+
+#[cfg(feature = "dict-facility")]
+#[rustfmt::skip]
+pub(crate) static FURNACE_TYPE: Codelist = Codelist::new("FURNACE_TYPE", &[
+    ("1", "GCR（ガス冷却炉）"),
+    ("2", "BWR（沸騰水型軽水炉）"),
+    ("3", "PWR（加圧水型軽水炉）"),
+    ("4", "ABWR（改良型沸騰水型軽水炉）"),
+    ("5", "ATR（新型転換炉）"),
+    ("6", "FBR（高速増殖炉）"),
+    ("7", "APWR(改良型加圧水型軽水炉)"),
+]);
+
+#[cfg(feature = "dict-policy")]
+#[rustfmt::skip]
+pub(crate) static HAZARDOUS_AREA_CLASSIFICATION_CODE: Codelist = Codelist::new("HAZARDOUS_AREA_CLASSIFICATION_CODE", &[
+    ("1", "氾濫流"),
+    ("2", "河岸浸食"),
+    ("3", "どちらも該当"),
+]);
+
+#[cfg(feature = "dict-policy")]
+#[rustfmt::skip]
+pub(crate) static HEAVY_SNOW_TYPE_CODE: Codelist = Codelist::new("HEAVY_SNOW_TYPE_CODE", &[
+    ("1", "豪雪地帯"),
+    ("2", "特別豪雪地帯"),
+]);
+
+#[cfg(feature = "dict-transport")]
+#[rustfmt::skip]
+pub(crate) static HIGHWAY_CAT_CD: Codelist = Codelist::new("HIGHWAY_CAT_CD", &[
+    ("1", "高速自動車国道"),
+    ("2", "高速自動車国道に並行する自動車専用道路"),
+    ("3", "一般国道の自動車専用道路"),
+    ("4", "本州四国連絡高速道路"),
+    ("5", "指定都市高速道路"),
+    ("6", "その他の道路"),
+]);
+
+#[cfg(feature = "dict-transport")]
+#[rustfmt::skip]
+pub(crate) static HIGHWAY_CON_CD: Codelist = Codelist::new("HIGHWAY_CON_CD", &[
+    ("1", "一般インターチェンジ"),
+    ("2", "スマートインターチェンジ"),
+    ("3", "ジャンクション"),
+    ("4", "その他の接合部"),
+]);
+
+#[cfg(feature = "dict-transport")]
+#[rustfmt::skip]
+pub(crate) static HIGHWAY_TRANSITION_CD: Codelist = Codelist::new("HIGHWAY_TRANSITION_CD", &[
+    ("1", "路線名"),
+    ("2", "路線種別"),
+    ("3", "車線数"),
+]);
+
+#[cfg(feature = "dict-transport")]
+#[rustfmt::skip]
+pub(crate) static HIGHWAY_USE_CD: Codelist = Codelist::new("HIGHWAY_USE_CD", &[
+    ("1", "完成供用"),
+    ("2", "暫定供用"),
+    ("3", "高速自動車国道に切り替えて供用"),
+    ("4", "一般国道に切り替えて供用"),
+    ("5", "その他の供用"),
+]);
+
+#[cfg(feature = "dict-policy")]
+#[rustfmt::skip]
+pub(crate) static HOANRIN_CD: Codelist = Codelist::new("HOANRIN_CD", &[
+    ("保健保", "保健保安林"),
+    ("保健見", "保健保安林見込み地"),
+    ("土崩保", "土砂崩壊防備保安林"),
+    ("土崩見", "土砂崩壊防備保安林見込み地"),
+    ("土流保", "土砂流出防備保安林"),
+    ("土流見", "土砂流出防備保安林見込み地"),
+    ("干害保", "干害防備保安林"),
+    ("干害見", "干害防備保安林見込み地"),
+    ("水害保", "水害防備保安林"),
+    ("水害見", "水害防備保安林見込み地"),
+    ("水涵保", "水源かん養保安林"),
+    ("水涵見", "水源かん養保安林見込み地"),
+    ("潮害保", "潮害防備保安林"),
+    ("潮害見", "潮害防備保安林見込み地"),
+    ("航行保", "航行目標保安林"),
+    ("航行見", "航行目標保安林見込み地"),
+    ("落石保", "落石防止保安林"),
+    ("落石見", "落石防止保安林見込み地"),
+    ("防火保", "防火保安林"),
+    ("防火見", "防火保安林見込み地"),
+    ("防雪保", "防雪保安林"),
+    ("防雪見", "防雪保安林見込み地"),
+    ("防霧保", "防霧保安林"),
+    ("防霧見", "防霧保安林見込み地"),
+    ("防風保", "防風保安林"),
+    ("防風見", "防風保安林見込み地"),
+    ("雪崩保", "なだれ防止保安林"),
+    ("雪崩見", "なだれ防止保安林見込み地"),
+    ("風致保", "風致保安林"),
+    ("風致見", "風致保安林見込み地"),
+    ("飛砂保", "飛砂防備保安林"),
+    ("飛砂見", "飛砂防備保安林見込み地"),
+    ("魚付保", "魚つき保安林"),
+    ("魚付見", "魚つき保安林見込み地"),
+]);
+
+#[cfg(feature = "dict-policy")]
+#[rustfmt::skip]
+pub(crate) static HOGORIN_CD: Codelist = Codelist::new("HOGORIN_CD", &[
+    ("保護林", "保護林に設定されているもの"),
+]);
+
+#[cfg(feature = "dict-facility")]
+#[rustfmt::skip]
+pub(crate) static HYDROELECTRIC_POWER_PLANT_TYPE: Codelist = Codelist::new("HYDROELECTRIC_POWER_PLANT_TYPE", &[
+    ("1", "ダム式"),
+    ("2", "水路式"),
+    ("3", "ダム水路"),
+]);
 
 // https://nlftp.mlit.go.jp/ksj/gml/codelist/IndexNumCd.html
 // https://nlftp.mlit.go.jp/ksj/gml/codelist/IndexNumL01-v1_1.html
 // https://nlftp.mlit.go.jp/ksj/gml/codelist/L01_v3_2_IndexNumberCodeType.html
-#[rustfmt::skip]
-pub(crate) static INDEX_NUM_L01: LazyLock<HashMap<&'static str, &'static str>> =
-    LazyLock::new(|| {
-        [
-            ("000", "住宅地"),
-            ("003", "宅地見込地"),
-            ("005", "商業地"),
-            ("007", "準工業地"),
-            ("009", "工業地"),
-            ("010", "市街化調整区域内の現況宅地"),
-            ("013", "市街化調整区域内の現況林地"),
-            ("020", "市街化調整区域内の現況林地（都道府県地価調査の場合）"),
-        ]
-        .into_iter()
-        .collect()
-    });
-
-#[rustfmt::skip]
-pub(crate) static IndexNumL02V2_4: LazyLock<HashMap<&'static str, &'static str>> =
-    LazyLock::new(|| {
-        [
-            ("000", "住宅地"),
-            ("003", "宅地見込地"),
-            ("005", "商業地"),
-            ("007", "準工業地"),
-            ("009", "工業地"),
-            ("020", "林地"),
-        ]
-        .into_iter()
-        .collect()
-    });
-
-#[rustfmt::skip]
-pub(crate) static INDUSTRIAL_WASTE_DISPOSAL: LazyLock<HashMap<&'static str, &'static str>> =
-    LazyLock::new(|| {
-        [
-            ("1", "中間処理施設"),
-            ("2", "最終処理施設"),
-        ]
-        .into_iter()
-        .collect()
-    });
-
-#[rustfmt::skip]
-pub(crate) static INDUSTRIAL_WASTE_SPECIAL_TREATMENT: LazyLock<HashMap<&'static str, &'static str>> =
-    LazyLock::new(|| {
-        [
-            ("0", "特別管理産業廃棄物処理以外"),
-            ("1", "特別管理産業廃棄物処理"),
-        ]
-        .into_iter()
-        .collect()
-    });
-
-#[rustfmt::skip]
-pub(crate) static INSTALL_ADMIN_CD_V2_3: LazyLock<HashMap<&'static str, &'static str>> =
-    LazyLock::new(|| {
-        [
-            ("1", "国土交通大臣"),
-            ("2", "防衛大臣"),
-            ("3", "米軍"),
-            ("4", "株式会社"),
-            ("5", "都道府県"),
-            ("6", "市町村"),
-            ("7", "運輸大臣"),
-            ("8", "防衛庁長官"),
-            ("9", "公団"),
-        ]
-        .into_iter()
-        .collect()
-    });
-
-#[rustfmt::skip]
-pub(crate) static INSTALL_AIRPORT_CD_V2_3: LazyLock<HashMap<&'static str, &'static str>> =
-    LazyLock::new(|| {
-        [
-            ("1", "拠点空港（会社管理）"),
-            ("2", "拠点空港（国管理空港）"),
-            ("3", "拠点空港（特定地方管理空港）"),
-            ("4", "地方管理空港"),
-            ("5", "その他の空港"),
-            ("6", "共用空港"),
-        ]
-        .into_iter()
-        .collect()
-    });
-
-#[rustfmt::skip]
-pub(crate) static INSTITUTION_TYPE_CD: LazyLock<HashMap<&'static str, &'static str>> =
-    LazyLock::new(|| {
-        [
-            ("1", "JRの新幹線"),
-            ("2", "JR在来線"),
-            ("3", "公営鉄道"),
-            ("4", "民営鉄道"),
-            ("5", "第三セクター"),
-        ]
-        .into_iter()
-        .collect()
-    });
-
-#[rustfmt::skip]
-pub(crate) static KASO_CD: LazyLock<HashMap<&'static str, &'static str>> =
-    LazyLock::new(|| {
-        [
-            ("01", "過疎市町村"),
-            ("02", "過疎地域とみなされる市町村"),
-            ("03", "過疎地域とみなされる区域"),
-        ]
-        .into_iter()
-        .collect()
-    });
-
-#[rustfmt::skip]
-pub(crate) static KINOURUIKEI_CD: LazyLock<HashMap<&'static str, &'static str>> =
-    LazyLock::new(|| {
-        [
-            ("山地災害防止（土砂）", "山地災害防止タイプ（土砂流出崩壊防備）"),
-            ("山地災害防止（気象）", "山地災害防止タイプ（気象害防備）"),
-            ("自然維持", "自然維持タイプ"),
-            ("森林空間利用", "森林空間利用タイプ"),
-            ("快適環境形成", "快適環境形成タイプ"),
-            ("水源涵養", "水源涵養タイプ"),
-        ]
-        .into_iter()
-        .collect()
-    });
-
-#[rustfmt::skip]
-pub(crate) static LANDSCAPE_DISTRICT_TYPE: LazyLock<HashMap<&'static str, &'static str>> =
-    LazyLock::new(|| {
-        [
-            ("1", "景観地区"),
-            ("2", "準景観地区"),
-        ]
-        .into_iter()
-        .collect()
-    });
+#[cfg(feature = "dict-land")]
+#[rustfmt::skip]
+pub(crate) static INDEX_NUM_L01: Codelist = Codelist::new("INDEX_NUM_L01", &[
+    ("000", "住宅地"),
+    ("003", "宅地見込地"),
+    ("005", "商業地"),
+    ("007", "準工業地"),
+    ("009", "工業地"),
+    ("010", "市街化調整区域内の現況宅地"),
+    ("013", "市街化調整区域内の現況林地"),
+    ("020", "市街化調整区域内の現況林地（都道府県地価調査の場合）"),
+]);
+
+#[rustfmt::skip]
+pub(crate) static IndexNumL02V2_4: Codelist = Codelist::new("IndexNumL02V2_4", &[
+    ("000", "住宅地"),
+    ("003", "宅地見込地"),
+    ("005", "商業地"),
+    ("007", "準工業地"),
+    ("009", "工業地"),
+    ("020", "林地"),
+]);
+
+#[cfg(feature = "dict-facility")]
+#[rustfmt::skip]
+pub(crate) static INDUSTRIAL_WASTE_DISPOSAL: Codelist = Codelist::new("INDUSTRIAL_WASTE_DISPOSAL", &[
+    ("1", "中間処理施設"),
+    ("2", "最終処理施設"),
+]);
+
+#[cfg(feature = "dict-facility")]
+#[rustfmt::skip]
+pub(crate) static INDUSTRIAL_WASTE_SPECIAL_TREATMENT: Codelist = Codelist::new("INDUSTRIAL_WASTE_SPECIAL_TREATMENT", &[
+    ("0", "特別管理産業廃棄物処理以外"),
+    ("1", "特別管理産業廃棄物処理"),
+]);
+
+#[cfg(feature = "dict-transport")]
+#[rustfmt::skip]
+pub(crate) static INSTALL_ADMIN_CD_V2_3: Codelist = Codelist::new("INSTALL_ADMIN_CD_V2_3", &[
+    ("1", "国土交通大臣"),
+    ("2", "防衛大臣"),
+    ("3", "米軍"),
+    ("4", "株式会社"),
+    ("5", "都道府県"),
+    ("6", "市町村"),
+    ("7", "運輸大臣"),
+    ("8", "防衛庁長官"),
+    ("9", "公団"),
+]);
+
+#[cfg(feature = "dict-transport")]
+#[rustfmt::skip]
+pub(crate) static INSTALL_AIRPORT_CD_V2_3: Codelist = Codelist::new("INSTALL_AIRPORT_CD_V2_3", &[
+    ("1", "拠点空港（会社管理）"),
+    ("2", "拠点空港（国管理空港）"),
+    ("3", "拠点空港（特定地方管理空港）"),
+    ("4", "地方管理空港"),
+    ("5", "その他の空港"),
+    ("6", "共用空港"),
+]);
+
+#[cfg(feature = "dict-transport")]
+#[rustfmt::skip]
+pub(crate) static INSTITUTION_TYPE_CD: Codelist = Codelist::new("INSTITUTION_TYPE_CD", &[
+    ("1", "JRの新幹線"),
+    ("2", "JR在来線"),
+    ("3", "公営鉄道"),
+    ("4", "民営鉄道"),
+    ("5", "第三セクター"),
+]);
+
+#[cfg(feature = "dict-policy")]
+#[rustfmt::skip]
+pub(crate) static KASO_CD: Codelist = Codelist::new("KASO_CD", &[
+    ("01", "過疎市町村"),
+    ("02", "過疎地域とみなされる市町村"),
+    ("03", "過疎地域とみなされる区域"),
+]);
+
+#[cfg(feature = "dict-policy")]
+#[rustfmt::skip]
+pub(crate) static KINOURUIKEI_CD: Codelist = Codelist::new("KINOURUIKEI_CD", &[
+    ("山地災害防止（土砂）", "山地災害防止タイプ（土砂流出崩壊防備）"),
+    ("山地災害防止（気象）", "山地災害防止タイプ（気象害防備）"),
+    ("快適環境形成", "快適環境形成タイプ"),
+    ("森林空間利用", "森林空間利用タイプ"),
+    ("水源涵養", "水源涵養タイプ"),
+    ("自然維持", "自然維持タイプ"),
+]);
+
+#[cfg(feature = "dict-policy")]
+#[rustfmt::skip]
+pub(crate) static LANDSCAPE_DISTRICT_TYPE: Codelist = Codelist::new("LANDSCAPE_DISTRICT_TYPE", &[
+    ("1", "景観地区"),
+    ("2", "準景観地区"),
+]);
 
 // 土地利用種別（昭和51年度） 〈ファイル名称：LandUseCd-77〉
-#[rustfmt::skip]
-pub(crate) static LAND_USE_CD_77: LazyLock<HashMap<&'static str, &'static str>> =
-    LazyLock::new(|| {
-        [
-            ("1", "田"),
-            ("2", "畑"),
-            ("3", "果樹園"),
-            ("4", "その他の樹木畑"),
-            ("5", "森林"),
-            ("6", "荒地"),
-            ("7", "建物用地A"),
-            ("8", "建物用地B"),
-            ("9", "幹線交通用地"),
-            ("A", "その他の用地"),
-            ("B", "湖沼"),
-            ("C", "河川地A"),
-            ("D", "河川地B"),
-            ("E", "海浜"),
-            ("F", "海水域"),
-        ]
-        .into_iter()
-        .collect()
-    });
+#[cfg(feature = "dict-land")]
+#[rustfmt::skip]
+pub(crate) static LAND_USE_CD_77: Codelist = Codelist::new("LAND_USE_CD_77", &[
+    ("1", "田"),
+    ("2", "畑"),
+    ("3", "果樹園"),
+    ("4", "その他の樹木畑"),
+    ("5", "森林"),
+    ("6", "荒地"),
+    ("7", "建物用地A"),
+    ("8", "建物用地B"),
+    ("9", "幹線交通用地"),
+    ("A", "その他の用地"),
+    ("B", "湖沼"),
+    ("C", "河川地A"),
+    ("D", "河川地B"),
+    ("E", "海浜"),
+    ("F", "海水域"),
+]);
 
 // 土地利用種別（昭和62年度） 〈ファイル名称：LandUseCd-88〉
-#[rustfmt::skip]
-pub(crate) static LAND_USE_CD_88: LazyLock<HashMap<&'static str, &'static str>> =
-    LazyLock::new(|| {
-        [
-            ("1", "田"),
-            ("2", "畑"),
-            ("3", "果樹園"),
-            ("4", "その他の樹木畑"),
-            ("5", "森林"),
-            ("6", "荒地"),
-            ("7", "建物用地"),
-            ("9", "幹線交通用地"),
-            ("A", "その他の用地"),
-            ("B", "内水地"),
-            ("E", "海浜"),
-            ("F", "海水域"),
-        ]
-        .into_iter()
-        .collect()
-    });
+#[cfg(feature = "dict-land")]
+#[rustfmt::skip]
+pub(crate) static LAND_USE_CD_88: Codelist = Codelist::new("LAND_USE_CD_88", &[
+    ("1", "田"),
+    ("2", "畑"),
+    ("3", "果樹園"),
+    ("4", "その他の樹木畑"),
+    ("5", "森林"),
+    ("6", "荒地"),
+    ("7", "建物用地"),
+    ("9", "幹線交通用地"),
+    ("A", "その他の用地"),
+    ("B", "内水地"),
+    ("E", "海浜"),
+    ("F", "海水域"),
+]);
 
 // 土地利用種別（平成3年度，9年度，18年度） 〈ファイル名称：LandUseCd-YY〉※ YYは西暦下2桁
-#[rustfmt::skip]
-pub(crate) static LAND_USE_CD_YY: LazyLock<HashMap<&'static str, &'static str>> =
-    LazyLock::new(|| {
-        [
-            ("1", "田"),
-            ("2", "その他の農用地"),
-            ("3", "-"),
-            ("4", "-"),
-            ("5", "森林"),
-            ("6", "荒地"),
-            ("7", "建物用地"),
-            ("8", "-"),
-            ("9", "幹線交通用地"),
-            ("A", "その他の用地"),
-            ("B", "河川地及び湖沼"),
-            ("C", "-"),
-            ("D", "-"),
-            ("E", "海浜"),
-            ("F", "海水域"),
-            ("G", "ゴルフ場"),
-        ]
-        .into_iter()
-        .collect()
-    });
+#[cfg(feature = "dict-land")]
+#[rustfmt::skip]
+pub(crate) static LAND_USE_CD_YY: Codelist = Codelist::new("LAND_USE_CD_YY", &[
+    ("1", "田"),
+    ("2", "その他の農用地"),
+    ("3", "-"),
+    ("4", "-"),
+    ("5", "森林"),
+    ("6", "荒地"),
+    ("7", "建物用地"),
+    ("8", "-"),
+    ("9", "幹線交通用地"),
+    ("A", "その他の用地"),
+    ("B", "河川地及び湖沼"),
+    ("C", "-"),
+    ("D", "-"),
+    ("E", "海浜"),
+    ("F", "海水域"),
+    ("G", "ゴルフ場"),
+]);
 
 // 土地利用種別（平成21、26、28年度、令和3年） 〈ファイル名称：LandUseCd-09.html〉
-#[rustfmt::skip]
-pub(crate) static LAND_USE_CD_09: LazyLock<HashMap<&'static str, &'static str>> =
-    LazyLock::new(|| {
-        [
-            ("0100", "田"),
-            ("0200", "その他の農用地"),
-            ("0300", "-"),
-            ("0400", "-"),
-            ("0500", "森林"),
-            ("0600", "荒地"),
-            ("0700", "建物用地"),
-            ("0800", "-"),
-            ("0901", "道路"),
-            ("0902", "鉄道"),
-            ("1000", "その他の用地"),
-            ("1100", "河川地及び湖沼"),
-            ("1200", "-"),
-            ("1300", "-"),
-            ("1400", "海浜"),
-            ("1500", "海水域"),
-            ("1600", "ゴルフ場"),
-        ]
-        .into_iter()
-        .collect()
-    });
+#[cfg(feature = "dict-land")]
+#[rustfmt::skip]
+pub(crate) static LAND_USE_CD_09: Codelist = Codelist::new("LAND_USE_CD_09", &[
+    ("0100", "田"),
+    ("0200", "その他の農用地"),
+    ("0300", "-"),
+    ("0400", "-"),
+    ("0500", "森林"),
+    ("0600", "荒地"),
+    ("0700", "建物用地"),
+    ("0800", "-"),
+    ("0901", "道路"),
+    ("0902", "鉄道"),
+    ("1000", "その他の用地"),
+    ("1100", "河川地及び湖沼"),
+    ("1200", "-"),
+    ("1300", "-"),
+    ("1400", "海浜"),
+    ("1500", "海水域"),
+    ("1600", "ゴルフ場"),
+]);
 
 // 土地利用種別（平成21、26、28年度、令和3年度） 〈ファイル名称：LandUseCd-09-u.html〉
-#[rustfmt::skip]
-pub(crate) static LAND_USE_CD_09_U: LazyLock<HashMap<&'static str, &'static str>> =
-    LazyLock::new(|| {
-        [
-            ("0100", "田"),
-            ("0200", "その他の農用地"),
-            ("0300", "-"),
-            ("0400", "-"),
-            ("0500", "森林"),
-            ("0600", "荒地"),
-            ("0700", "-"),
-            ("0701", "高層建物"),
-            ("0702", "工場"),
-            ("0703", "低層建物"),
-            ("0704", "低層建物（密集地）"),
-            ("0800", "-"),
-            ("0900", "-"),
-            ("0901", "道路"),
-            ("0902", "鉄道"),
-            ("1000", "-"),
-            ("1001", "公共施設等用地"),
-            ("1002", "空地"),
-            ("1003", "公園・緑地"),
-            ("1100", "河川地及び湖沼"),
-            ("1200", "-"),
-            ("1300", "-"),
-            ("1400", "海浜"),
-            ("1500", "海水域"),
-            ("1600", "ゴルフ場"),
-        ]
-        .into_iter()
-        .collect()
-    });
-
-#[rustfmt::skip]
-pub(crate) static LARGE_CLASSIFICATION_CD: LazyLock<HashMap<&'static str, &'static str>> =
-    LazyLock::new(|| {
-        [
-            ("1", "有形文化財"),
-            ("2", "無形文化財"),
-            ("3", "民俗文化財"),
-            ("4", "記念物"),
-            ("5", "文化的景観"),
-            ("6", "伝統的建造物群"),
-            ("7", "文化財の保存技術"),
-        ]
-        .into_iter()
-        .collect()
-    });
-
-#[rustfmt::skip]
-pub(crate) static LOCATION_ACCURACY_CD: LazyLock<HashMap<&'static str, &'static str>> =
-    LazyLock::new(|| {
-        [
-            ("1", "レベル１（位置精度最高）
+#[cfg(feature = "dict-land")]
+#[rustfmt::skip]
+pub(crate) static LAND_USE_CD_09_U: Codelist = Codelist::new("LAND_USE_CD_09_U", &[
+    ("0100", "田"),
+    ("0200", "その他の農用地"),
+    ("0300", "-"),
+    ("0400", "-"),
+    ("0500", "森林"),
+    ("0600", "荒地"),
+    ("0700", "-"),
+    ("0701", "高層建物"),
+    ("0702", "工場"),
+    ("0703", "低層建物"),
+    ("0704", "低層建物（密集地）"),
+    ("0800", "-"),
+    ("0900", "-"),
+    ("0901", "道路"),
+    ("0902", "鉄道"),
+    ("1000", "-"),
+    ("1001", "公共施設等用地"),
+    ("1002", "空地"),
+    ("1003", "公園・緑地"),
+    ("1100", "河川地及び湖沼"),
+    ("1200", "-"),
+    ("1300", "-"),
+    ("1400", "海浜"),
+    ("1500", "海水域"),
+    ("1600", "ゴルフ場"),
+]);
+
+#[cfg(feature = "dict-facility")]
+#[rustfmt::skip]
+pub(crate) static LARGE_CLASSIFICATION_CD: Codelist = Codelist::new("LARGE_CLASSIFICATION_CD", &[
+    ("1", "有形文化財"),
+    ("2", "無形文化財"),
+    ("3", "民俗文化財"),
+    ("4", "記念物"),
+    ("5", "文化的景観"),
+    ("6", "伝統的建造物群"),
+    ("7", "文化財の保存技術"),
+]);
+
+#[cfg(feature = "dict-water")]
+#[rustfmt::skip]
+pub(crate) static LOCATION_ACCURACY_CD: Codelist = Codelist::new("LOCATION_ACCURACY_CD", &[
+    ("1", "レベル１（位置精度最高）
             地形図上に対応するダムの名称およびその場所が明示されており、位置情報が明確なダム。"),
-            ("2", "レベル2（位置精度高）
+    ("2", "レベル2（位置精度高）
             対応すると考えられるダムが地形図上に図示されており、ダムの所在地等の情報から当該のダムであると判断できるダム。"),
-            ("3", "レベル3（位置精度中）
+    ("3", "レベル3（位置精度中）
             対応すると考えられるダムが地形図上に複数あり、ダムの所在地等の情報から当該のダムであると推測したダム。"),
-            ("4", "レベル4（位置精度低）
+    ("4", "レベル4（位置精度低）
             対応するダムが地形図上に明示されておらず、所在地や地形等から位置情報を推測したダム。"),
-            ("5", "レベル5（位置精度低かつ河川名称不明）"),
-        ]
-        .into_iter()
-        .collect()
-    });
-
-#[rustfmt::skip]
-pub(crate) static MARITIME_ORG_CD: LazyLock<HashMap<&'static str, &'static str>> =
-    LazyLock::new(|| {
-        [
-            ("1", "海上保安部"),
-            ("2", "税関"),
-            ("3", "海運局"),
-            ("4", "地方海難審判庁"),
-            ("5", "検疫所"),
-            ("6", "動物検疫所"),
-            ("7", "植物検疫所"),
-            ("8", "入国管理局"),
-        ]
-        .into_iter()
-        .collect()
-    });
-
-#[rustfmt::skip]
-pub(crate) static MED_CLASS_CD: LazyLock<HashMap<&'static str, &'static str>> =
-    LazyLock::new(|| {
-        [
-            ("1", "病院"),
-            ("2", "診療所"),
-            ("3", "歯科診療所"),
-        ]
-        .into_iter()
-        .collect()
-    });
-
-#[rustfmt::skip]
-pub(crate) static MIDORINOKAIRO_CD: LazyLock<HashMap<&'static str, &'static str>> =
-    LazyLock::new(|| {
-        [
-            ("知床回", "知床半島緑の回廊"),
-            ("大日回", "大雪・日高緑の回廊"),
-            ("支無回", "支笏・無意根緑の回廊"),
-            ("奥　羽", "奥羽山脈緑の回廊"),
-            ("三国線", "緑の回廊三国線"),
-            ("秩父緑", "秩父山地緑の回廊"),
-            ("回廊雨", "緑の回廊雨飾・戸隠"),
-            ("大隅回", "大隅半島緑の回廊"),
-            ("越後線", "緑の回廊越後線"),
-            ("日光線", "緑の回廊日光線"),
-            ("北上回", "北上高地緑の回廊"),
-            ("白神回", "白神八甲田緑の回廊"),
-            ("白山回", "白山山系緑の回廊"),
-            ("回廊八", "緑の回廊八ヶ岳"),
-            ("丹沢緑", "丹沢緑の回廊"),
-            ("富士緑", "富士山緑の回廊"),
-            ("四国回", "四国山地緑の回廊"),
-            ("鳥海朝日回", "鳥海朝日・飯豊吾妻緑の回廊"),
-            ("越美回", "越美山地緑の回廊"),
-            ("日光・那須", "日光・那須塩原緑の回廊"),
-            ("太平回", "八幡平太平山緑の回廊"),
-            ("綾川回", "綾川上流緑の回廊"),
-            ("会津山", "会津山地緑の回廊"),
-            ("東中国", "東中国山地緑の回廊"),
-            ("日吾緑", "日光・吾妻山地緑の回廊"),
-        ]
-        .into_iter()
-        .collect()
-    });
-
-#[rustfmt::skip]
-pub(crate) static N04FukuinH16: LazyLock<HashMap<&'static str, &'static str>> =
-    LazyLock::new(|| {
-        [
-            ("1", "幅員13.0m（11.0m）以上"),
-            ("2", "幅員5.5m以上13.0m（11.0m）未満"),
-            ("3", "幅員3.0m（2.5m）以上5.5m未満"),
-            ("4", "幅員3.0m（2.5m）未満"),
-            ("5", "幅員未調査"),
-            ("6", "幅員合計"),
-        ]
-        .into_iter()
-        .collect()
-    });
-
-#[rustfmt::skip]
-pub(crate) static N04FukuinH22: LazyLock<HashMap<&'static str, &'static str>> =
-    LazyLock::new(|| {
-        [
-            ("1", "幅員25.0m以上"),
-            ("2", "幅員19.5m以上25.0m未満"),
-            ("3", "幅員13.0m（11.0m）以上19.5m未満"),
-            ("4", "幅員13.0m（11.0m）以上で幅員不明"),
-            ("5", "幅員5.5m以上13.0m（11.0m）未満"),
-            ("6", "幅員3.0m（2.5m）以上5.5m未満"),
-            ("7", "幅員3.0m（2.5m）未満"),
-            ("8", "幅員未調査"),
-            ("9", "幅員合計"),
-        ]
-        .into_iter()
-        .collect()
-    });
-
-#[rustfmt::skip]
-pub(crate) static NATURALFEATURE_CD: LazyLock<HashMap<&'static str, &'static str>> =
-    LazyLock::new(|| {
-        [
-            ("101", "火山群"),
-            ("102", "火山"),
-            ("103", "火山性高原"),
-            ("104", "火口・カルデラ"),
-            ("105", "カルデラ壁"),
-            ("106", "流れ山群"),
-            ("107", "火山　特徴的な稜線"),
-            ("108", "溶岩トンネル・風穴"),
-            ("109", "溶岩流末端崖"),
-            ("110", "地獄、泥火山"),
-            ("111", "噴泉"),
-            ("112", "噴気孔"),
-            ("113", "間歇泉"),
-            ("114", "火山　構造土"),
-            ("115", "高山域・亜高山域"),
-            ("116", "火山　万年雪"),
-            ("201", "山脈、山地、高地"),
-            ("202", "丘陵"),
-            ("203", "非火山性高原"),
-            ("204", "大断層崖"),
-            ("205", "非火山性弧峰"),
-            ("206", "U字谷（氷食谷）"),
-            ("207", "カール"),
-            ("208", "非対称山稜"),
-            ("209", "山地　特徴的な稜線"),
-            ("210", "モレーン"),
-            ("211", "二重山稜（綿状凹地）"),
-            ("212", "山地　断崖・岩壁"),
-            ("213", "岩塊斜面、岩海"),
-            ("214", "山地　構造土"),
-            ("215", "山地　岩峰・岩柱"),
-            ("216", "崖錐"),
-            ("217", "山地　天然橋・岩門・石門"),
-            ("218", "高山域、亜高山域"),
-            ("219", "山地　万年雪"),
-            ("301", "カルスト地形"),
-            ("302", "ポリエ"),
-            ("303", "カッレンフェルト・ドリーネ群"),
-            ("304", "鍾乳洞"),
-            ("401", "陸景　節理"),
-            ("402", "陸景　岩脈"),
-            ("501", "陸景で上記以外の際立った地形"),
-            ("502", "陸景で顕著な自然現象を記録する地形"),
-            ("601", "峡谷・渓谷"),
-            ("602", "河成段丘"),
-            ("603", "自由蛇行河川"),
-            ("604", "穿入蛇行河川"),
-            ("605", "河川　断崖・岩壁"),
-            ("606", "瀞"),
-            ("607", "河川　岩峰・岩柱"),
-            ("608", "淵"),
-            ("609", "河川　甌穴群"),
-            ("610", "滝"),
-            ("611", "河川　天然橋・岩門・石門"),
-            ("701", "湖沼"),
-            ("702", "湿原"),
-            ("801", "溺れ谷"),
-            ("802", "海成段丘"),
-            ("803", "断層海岸"),
-            ("804", "火山海岸"),
-            ("805", "多島海"),
-            ("806", "隆起サンゴ礁"),
-            ("807", "砂浜、礫浜"),
-            ("808", "砂嘴"),
-            ("809", "砂州"),
-            ("810", "陸けい砂州"),
-            ("811", "砂丘"),
-            ("812", "海食崖"),
-            ("813", "波食台（ベンチ）"),
-            ("814", "岩礁"),
-            ("815", "海食洞"),
-            ("816", "岩門"),
-            ("817", "湖吹穴"),
-            ("818", "海岸　甌穴群"),
-            ("819", "潮流、渦流"),
-            ("901", "水景　節理"),
-            ("902", "水景　岩脈"),
-            ("903", "湧泉群"),
-            ("1001", "水景で上記以外の際立った地形"),
-            ("1002", "水景で顕著な自然現象を記録する地形"),
-        ]
-        .into_iter()
-        .collect()
-    });
-
-#[rustfmt::skip]
-pub(crate) static NaturalParkAreaCd: LazyLock<HashMap<&'static str, &'static str>> =
-    LazyLock::new(|| {
-        [
-            ("1", "自然公園地域"),
-            ("2", "特別地域"),
-            ("3", "特別保護地区"),
-        ]
-        .into_iter()
-        .collect()
-    });
-
-#[rustfmt::skip]
-pub(crate) static NATURALSCENE_CD: LazyLock<HashMap<&'static str, &'static str>> =
-    LazyLock::new(|| {
-        [
-            ("1", "火山景観"),
-            ("2", "山地（非火山性）景観"),
-            ("3", "石灰岩景観"),
-            ("4", "陸景特殊地学景観"),
-            ("5", "陸景その他"),
-            ("6", "河川景観"),
-            ("7", "湖沼景観"),
-            ("8", "海岸景観"),
-            ("9", "水景特殊地学景観"),
-            ("10", "水景その他"),
-        ]
-        .into_iter()
-        .collect()
-    });
-
-#[rustfmt::skip]
-pub(crate) static N13_ROAD_TYPE: LazyLock<HashMap<&'static str, &'static str>> =
-    LazyLock::new(|| {
-        [
-            ("1", "通常部"),
-            ("2", "庭園路"),
-            ("3", "徒歩道"),
-            ("4", "石段"),
-            ("5", "不明"),
-        ]
-        .into_iter()
-        .collect()
-    });
-
-#[rustfmt::skip]
-pub(crate) static N13_ROAD_CATEGORY: LazyLock<HashMap<&'static str, &'static str>> =
-    LazyLock::new(|| {
-        [
-            ("1", "国道"),
-            ("2", "都道府県道"),
-            ("3", "市区町村道等"),
-            ("4", "高速自動車国道等"),
-            ("5", "その他"),
-            ("6", "不明"),
-        ]
-        .into_iter()
-        .collect()
-    });
-
-#[rustfmt::skip]
-pub(crate) static N13_ROAD_STATE: LazyLock<HashMap<&'static str, &'static str>> =
-    LazyLock::new(|| {
-        [
-            ("1", "通常部"),
-            ("2", "橋・高架"),
-            ("3", "トンネル"),
-            ("4", "雪囲い"),
-            ("5", "建設中"),
-            ("6", "その他"),
-            ("7", "不明"),
-        ]
-        .into_iter()
-        .collect()
-    });
-
-#[rustfmt::skip]
-pub(crate) static N13_ROAD_WIDTH: LazyLock<HashMap<&'static str, &'static str>> =
-    LazyLock::new(|| {
-        [
-            ("1", "3m未満"),
-            ("2", "3m-5.5m未満"),
-            ("3", "5.5m-13m未満"),
-            ("4", "13m-19.5m未満"),
-            ("5", "19.5m以上"),
-            ("6", "不明"),
-        ]
-        .into_iter()
-        .collect()
-    });
-
-#[rustfmt::skip]
-pub(crate) static N13_TOIL_ROAD_SECTION: LazyLock<HashMap<&'static str, &'static str>> =
-    LazyLock::new(|| {
-        [
-            ("1", "有料"),
-            ("2", "無料"),
-        ]
-        .into_iter()
-        .collect()
-    });
-
-#[rustfmt::skip]
-pub(crate) static NatureConservationAreaCd: LazyLock<HashMap<&'static str, &'static str>> =
-    LazyLock::new(|| {
-        [
-            ("1", "自然保全地域"),
-            ("2", "原生自然環境保全地域"),
-            ("3", "特別地区"),
-        ]
-        .into_iter()
-        .collect()
-    });
-
-#[rustfmt::skip]
-pub(crate) static ORIGINAL_DATA_CODE_CD: LazyLock<HashMap<&'static str, &'static str>> =
-    LazyLock::new(|| {
-        [
-            ("1", "河川基盤地図2500"),
-            ("2", "河川基盤地図25000"),
-            ("3", "数値地図25000空間データ基盤"),
-            ("4", "管内図（縮尺50000以下）"),
-            ("5", "管内図（縮尺50000を超える）"),
-            ("6", "数値地図25000（地図画像）"),
-            ("7", "その他"),
-            ("0", "不明"),
-        ]
-        .into_iter()
-        .collect()
-    });
-
-#[rustfmt::skip]
-pub(crate) static POINT_CLASSIFICATION_CD: LazyLock<HashMap<&'static str, &'static str>> =
-    LazyLock::new(|| {
-        [
-            ("1", "敷地・号・建物レベル"),
-            ("2", "番地レベル"),
-            ("3", "大字・町丁目レベル"),
-            ("4", "市区町村レベル"),
-            ("5", "都道府県レベル"),
-            ("9", "緯度経度レベル"),
-        ]
-        .into_iter()
-        .collect()
-    });
-
-#[rustfmt::skip]
-pub(crate) static POINT_CLASSIFICATION_CODE: LazyLock<HashMap<&'static str, &'static str>> =
-    LazyLock::new(|| {
-        [
-            ("1", "敷地・号・建物レベル"),
-            ("2", "番地レベル"),
-            ("3", "大字・町丁目レベル"),
-            ("9", "その他のレベル"),
-        ]
-        .into_iter()
-        .collect()
-    });
-
-#[rustfmt::skip]
-pub(crate) static POLICE_STATION_CD: LazyLock<HashMap<&'static str, &'static str>> =
-    LazyLock::new(|| {
-        [
-            ("1", "警察本部"),
-            ("2", "警察署"),
-            ("3", "分庁舎"),
-            ("4", "交番"),
-            ("5", "駐在所"),
-            ("6", "派出所"),
-            ("7", "警察学校"),
-            ("8", "地域安全センター、連絡所等"),
-        ]
-        .into_iter()
-        .collect()
-    });
-
-#[rustfmt::skip]
-pub(crate) static POS_SPECIFIC_LEVEL: LazyLock<HashMap<&'static str, &'static str>> =
-    LazyLock::new(|| {
-        [
-            ("1", "位置特定"),
-            ("2", "号レベル"),
-            ("3", "番地レベル"),
-            ("4", "大字レベル"),
-            ("5", "町丁目レベル"),
-            ("6", "市区町村レベル"),
-            ("7", "都道府県レベル"),
-        ]
-        .into_iter()
-        .collect()
-    });
-
-#[rustfmt::skip]
-pub(crate) static POST_OFFICE_CD: LazyLock<HashMap<&'static str, &'static str>> =
-    LazyLock::new(|| {
-        [
-            ("18004", "簡易郵便局"),
-            ("18006", "直営郵便局"),
-        ]
-        .into_iter()
-        .collect()
-    });
-
-#[rustfmt::skip]
-pub(crate) static PROTECTION_AREA_TYPE: LazyLock<HashMap<&'static str, &'static str>> =
-    LazyLock::new(|| {
-        [
-            ("1", "鳥獣保護区"),
-            ("2", "特別保護地区"),
-            ("3", "休猟区"),
-            ("4", "特例休猟区"),
-        ]
-        .into_iter()
-        .collect()
-    });
-
-#[rustfmt::skip]
-pub(crate) static PTAreaCd: LazyLock<HashMap<&'static str, &'static str>> =
-    LazyLock::new(|| {
-        [
-            ("1", "東京"),
-            ("2", "京阪神"),
-            ("3", "中京"),
-        ]
-        .into_iter()
-        .collect()
-    });
-
-#[rustfmt::skip]
-pub(crate) static PUB_FAC_ADMIN_CD: LazyLock<HashMap<&'static str, &'static str>> =
-    LazyLock::new(|| {
-        [
-            ("001", "国"),
-            ("500", "その他の地方公共団体"),
-            ("600", "公共企業体"),
-            ("801", "民間会社"),
-            ("802", "任意団体"),
-            ("803", "個人"),
-            ("900", "その他"),
-            ("701", "社団法人"),
-            ("702", "財団法人"),
-            ("703", "宗教法人"),
-            ("704", "学校法人"),
-            ("705", "医療法人"),
-            ("706", "社会福祉法人"),
-            ("707", "特殊法人"),
-            ("709", "その他法人"),
-            ("1", "国"),
-            ("2", "都道府県"),
-            ("3", "市区町村"),
-            ("4", "民間"),
-            ("9", "不明"),
-            ("0", "その他"),
-        ]
-        .into_iter()
-        .collect()
-    });
-
-#[rustfmt::skip]
-pub(crate) static PUB_FAC_MACLASS_CD: LazyLock<HashMap<&'static str, &'static str>> =
-    LazyLock::new(|| {
-        [
-            ("3", "建物"),
-            ("9", "その他"),
-            ("11", "国の機関"),
-            ("12", "地方公共団体"),
-            ("13", "厚生機関"),
-            ("14", "警察機関"),
-            ("15", "消防署"),
-            ("16", "学校"),
-            ("17", "病院"),
-            ("18", "郵便局"),
-            ("19", "福祉施設"),
-        ]
-        .into_iter()
-        .collect()
-    });
-
-#[rustfmt::skip]
-pub(crate) static PUB_FAC_MICLASS_CD_WF: LazyLock<HashMap<&'static str, &'static str>> =
-    LazyLock::new(|| {
-        [
-            ("3001", "美術館"),
-            ("3002", "資料館、記念館、博物館、科学館"),
-            ("3003", "図書館"),
-            ("3004", "水族館"),
-            ("3005", "動植物園"),
-            ("9001", "公共企業体・政府関係機関"),
-            ("9002", "独立行政法人・大学共同利用機関法人"),
-            ("11100", "国会"),
-            ("11101", "会計検査院"),
-            ("11102", "人事院"),
-            ("11103", "内閣法制局"),
-            ("11110", "内閣府"),
-            ("11111", "内閣官房"),
-            ("11112", "宮内庁"),
-            ("11113", "金融庁"),
-            ("11114", "公正取引委員会"),
-            ("11120", "国家公安委員会"),
-            ("11121", "警察庁"),
-            ("11130", "防衛庁"),
-            ("11131", "防衛施設庁"),
-            ("11140", "総務省"),
-            ("11142", "消防庁"),
-            ("11144", "公害等調整委員会"),
-            ("11150", "法務省"),
-            ("11151", "検察庁"),
-            ("11152", "公安調査庁"),
-            ("11153", "公安審査委員会"),
-            ("11160", "外務省"),
-            ("11161", "外国公館"),
-            ("11170", "財務省"),
-            ("11171", "国税庁"),
-            ("11180", "文部科学省"),
-            ("11181", "文化庁"),
-            ("11190", "厚生労働省"),
-            ("11191", "社会保険庁"),
-            ("11192", "中央労働委員会"),
-            ("11200", "農林水産省"),
-            ("11202", "林野庁"),
-            ("11203", "水産庁"),
-            ("11210", "経済産業省"),
-            ("11211", "資源エネルギー庁"),
-            ("11212", "特許庁"),
-            ("11213", "中小企業庁"),
-            ("11220", "国土交通省"),
-            ("11221", "海上保安庁"),
-            ("11222", "海難審判庁"),
-            ("11223", "気象庁"),
-            ("11224", "船員労働委員会"),
-            ("11230", "環境省"),
-            ("11240", "裁判所"),
-            ("12001", "都道府県庁"),
-            ("12002", "区役所（東京都）、市役所"),
-            ("12003", "区役所（政令指定都市）"),
-            ("12004", "町村役場"),
-            ("12005", "都道府県の出先機関"),
-            ("13001", "保健所"),
-            ("14001", "都道府県警察本部"),
-            ("14002", "警察署"),
-            ("14003", "交番"),
-            ("14004", "駐在所"),
-            ("14005", "派出所"),
-            ("14006", "警察学校（都道府県管轄）"),
-            ("15001", "消防本部"),
-            ("15002", "消防署"),
-            ("16001", "小学校"),
-            ("16002", "中学校"),
-            ("16003", "中等教育学校"),
-            ("16004", "高等学校"),
-            ("16005", "高等専門学校"),
-            ("16006", "短期大学"),
-            ("16007", "大学"),
-            ("16008", "盲学校"),
-            ("16009", "ろう学校"),
-            ("16010", "養護学校"),
-            ("16011", "幼稚園"),
-            ("17001", "一般病院、国立療養所、医療センター"),
-            ("17002", "精神病院"),
-            ("17003", "結核療養所"),
-            ("17004", "伝染病院"),
-            ("17005", "主要な診療所"),
-            ("17006", "地域医療支援病院"),
-            ("18001", "普通郵便局"),
-            ("18002", "特定郵便局（集配局）"),
-            ("18003", "特定郵便局（無集配局）"),
-            ("18004", "簡易郵便局"),
-            ("18005", "地域区分局"),
-            ("19001", "老人福祉施設"),
-            ("19002", "老人憩の家"),
-            ("19003", "老人休養ホーム"),
-            ("19004", "有料老人ホーム"),
-            ("19005", "保護施設"),
-            ("19006", "婦人保護施設"),
-            ("19007", "身体障害者更生援護施設"),
-            ("19008", "児童福祉施設"),
-            ("19009", "知的障害者援護施設"),
-            ("19010", "精神障害者社会復帰施設"),
-            ("19011", "母子福祉施設"),
-            ("19012", "その他の社会福祉施設"),
-            ("19013", "保育所"),
-            ("19014", "へき地保育所"),
-            ("19015", "認可外保育施設"),
-            ("19016", "認定保育施設"),
-            ("19017", "事業所内保育施設"),
-            ("19018", "小規模保育事業"),
-            ("19019", "認可保育所"),
-            ("19020", "保育所訪問支援"),
-            ("19021", "認定こども園"),
-            ("19022", "幼保連携型認定こども園"),
-        ]
-        .into_iter()
-        .collect()
-    });
-
-#[rustfmt::skip]
-pub(crate) static PubFacMiclassCd: LazyLock<HashMap<&'static str, &'static str>> =
-    LazyLock::new(|| {
-        [
-            ("3001", "美術館"),
-            ("3002", "資料館、記念館、博物館、科学館"),
-            ("3003", "図書館"),
-            ("3004", "水族館"),
-            ("3005", "動植物園"),
-            ("9001", "公共企業体・政府関係機関"),
-            ("9002", "独立行政法人・大学共同利用機関法人"),
-            ("11100", "国会"),
-            ("11101", "会計検査院"),
-            ("11102", "人事院"),
-            ("11103", "内閣法制局"),
-            ("11110", "内閣府"),
-            ("11111", "内閣官房"),
-            ("11112", "宮内庁"),
-            ("11113", "金融庁"),
-            ("11114", "公正取引委員会"),
-            ("11120", "国家公安委員会"),
-            ("11121", "警察庁"),
-            ("11130", "防衛庁"),
-            ("11131", "防衛施設庁"),
-            ("11140", "総務省"),
-            ("11142", "消防庁"),
-            ("11144", "公害等調整委員会"),
-            ("11150", "法務省"),
-            ("11151", "検察庁"),
-            ("11152", "公安調査庁"),
-            ("11153", "公安審査委員会"),
-            ("11160", "外務省"),
-            ("11161", "外国公館"),
-            ("11170", "財務省"),
-            ("11171", "国税庁"),
-            ("11180", "文部科学省"),
-            ("11181", "文化庁"),
-            ("11190", "厚生労働省"),
-            ("11191", "社会保険庁"),
-            ("11192", "中央労働委員会"),
-            ("11200", "農林水産省"),
-            ("11202", "林野庁"),
-            ("11203", "水産庁"),
-            ("11210", "経済産業省"),
-            ("11211", "資源エネルギー庁"),
-            ("11212", "特許庁"),
-            ("11213", "中小企業庁"),
-            ("11220", "国土交通省"),
-            ("11221", "海上保安庁"),
-            ("11222", "海難審判庁"),
-            ("11223", "気象庁"),
-            ("11224", "船員労働委員会"),
-            ("11230", "環境省"),
-            ("11240", "裁判所"),
-            ("12001", "都道府県庁"),
-            ("12002", "区役所（東京都）、市役所"),
-            ("12003", "区役所（政令指定都市）"),
-            ("12004", "町村役場"),
-            ("12005", "都道府県の出先機関"),
-            ("13001", "保健所"),
-            ("14001", "都道府県警察本部"),
-            ("14002", "警察署"),
-            ("14003", "交番"),
-            ("14004", "駐在所"),
-            ("14005", "派出所"),
-            ("14006", "警察学校（都道府県管轄）"),
-            ("15001", "消防本部"),
-            ("15002", "消防署"),
-            ("16001", "小学校"),
-            ("16002", "中学校"),
-            ("16003", "中等教育学校"),
-            ("16004", "高等学校"),
-            ("16005", "高等専門学校"),
-            ("16006", "短期大学"),
-            ("16007", "大学"),
-            ("16008", "盲学校"),
-            ("16009", "ろう学校"),
-            ("16010", "養護学校"),
-            ("16011", "幼稚園"),
-            ("17001", "一般病院、国立療養所、医療センター"),
-            ("17002", "精神病院"),
-            ("17003", "結核療養所"),
-            ("17004", "伝染病院"),
-            ("17005", "主要な診療所"),
-            ("17006", "地域医療支援病院"),
-            ("18001", "普通郵便局"),
-            ("18002", "特定郵便局（集配局）"),
-            ("18003", "特定郵便局（無集配局）"),
-            ("18004", "簡易郵便局"),
-            ("18005", "地域区分局"),
-            ("19001", "老人福祉施設"),
-            ("19002", "老人憩の家"),
-            ("19003", "老人休養ホーム"),
-            ("19004", "有料老人ホーム"),
-            ("19005", "保護施設"),
-            ("19006", "婦人保護施設"),
-            ("19007", "身体障害者更生援護施設"),
-            ("19008", "児童福祉施設"),
-            ("19009", "知的障害者援護施設"),
-            ("19010", "精神障害者社会復帰施設"),
-            ("19011", "母子福祉施設"),
-            ("19012", "その他の社会福祉施設"),
-            ("19013", "保育所"),
-            ("19014", "へき地保育所"),
-        ]
-        .into_iter()
-        .collect()
-    });
-
-#[rustfmt::skip]
-pub(crate) static PUB_FAC_MINCLASS_CD: LazyLock<HashMap<&'static str, &'static str>> =
-    LazyLock::new(|| {
-        [
-            ("03001", "美術館"),
-            ("03002", "資料館，記念館，博物館，科学館"),
-            ("03003", "図書館"),
-            ("03004", "水族館"),
-            ("03005", "動植物園"),
-            ("09001", "公共企業体・政府関係機関"),
-            ("09002", "独立行政法人・大学共同利用機関法人"),
-            ("11100", "国会"),
-            ("11101", "会計検査院"),
-            ("11102", "人事院"),
-            ("11103", "内閣法制局"),
-            ("11110", "内閣府"),
-            ("11111", "内閣官房"),
-            ("11112", "宮内庁"),
-            ("11113", "金融庁"),
-            ("11114", "公正取引委員会"),
-            ("11120", "国家公安委員会"),
-            ("11121", "警察庁"),
-            ("11130", "防衛庁"),
-            ("11131", "防衛施設庁"),
-            ("11140", "総務省"),
-            ("11142", "消防庁"),
-            ("11144", "公害等調整委員会"),
-            ("11150", "法務省"),
-            ("11151", "検察庁"),
-            ("11152", "公安調査庁"),
-            ("11153", "公安審査委員会"),
-            ("11160", "外務省"),
-            ("11161", "外国公館"),
-            ("11170", "財務省"),
-            ("11171", "国税庁"),
-            ("11180", "文部科学省"),
-            ("11181", "文化庁"),
-            ("11190", "厚生労働省"),
-            ("11191", "社会保険庁"),
-            ("11192", "中央労働委員会"),
-            ("11200", "農林水産省"),
-            ("11202", "林野庁"),
-            ("11203", "水産庁"),
-            ("11210", "経済産業省"),
-            ("11211", "資源エネルギー庁"),
-            ("11212", "特許庁"),
-            ("11213", "中小企業庁"),
-            ("11220", "国土交通省"),
-            ("11221", "海上保安庁"),
-            ("11222", "海難審判庁"),
-            ("11223", "気象庁"),
-            ("11224", "船員労働委員会"),
-            ("11230", "環境省"),
-            ("11240", "裁判所"),
-            ("12001", "都道府県庁"),
-            ("12002", "区役所（東京都），市役所"),
-            ("12003", "区役所（政令指定都市）"),
-            ("12004", "町村役場"),
-            ("12005", "都道府県の出先機関"),
-            ("13001", "保健所"),
-            ("16001", "小学校"),
-            ("16002", "中学校"),
-            ("16003", "中等教育学校"),
-            ("16004", "高等学校"),
-            ("16005", "高等専門学校"),
-            ("16006", "短期大学"),
-            ("16007", "大学"),
-            ("16008", "盲学校"),
-            ("16009", "ろう学校"),
-            ("16010", "養護学校"),
-            ("18001", "普通郵便局"),
-            ("18002", "特定郵便局（集配局）"),
-            ("18003", "特定郵便局（無集配局）"),
-            ("18004", "簡易郵便局"),
-            ("18005", "地域区分局"),
-            ("99999", "NA"),
-        ]
-        .into_iter()
-        .collect()
-    });
-
-#[rustfmt::skip]
-pub(crate) static PUB_OFFICE_CD: LazyLock<HashMap<&'static str, &'static str>> =
-    LazyLock::new(|| {
-        [
-            ("1", "本庁（市役所、区役所、町役場、村役場）"),
-            ("2", "支所、出張所、連絡所"),
-            ("3", "上記以外の行政サービス施設"),
-            ("4", "公立公民館"),
-            ("5", "集会施設"),
-        ]
-        .into_iter()
-        .collect()
-    });
-
-#[rustfmt::skip]
-pub(crate) static PUB_OFFICE_CLASS_CD: LazyLock<HashMap<&'static str, &'static str>> =
-    LazyLock::new(|| {
-        [
-            ("1", "本庁（市役所、区役所、町役場、村役場）"),
-            ("2", "支所、出張所、連絡所"),
-        ]
-        .into_iter()
-        .collect()
-    });
-
-#[rustfmt::skip]
-pub(crate) static PUMPINGUP_TYPE: LazyLock<HashMap<&'static str, &'static str>> =
-    LazyLock::new(|| {
-        [
-            ("1", "混合揚水式水力"),
-            ("2", "純揚水式水力"),
-        ]
-        .into_iter()
-        .collect()
-    });
-
-#[rustfmt::skip]
-pub(crate) static RAILWAY_CLASS2_CD: LazyLock<HashMap<&'static str, &'static str>> =
-    LazyLock::new(|| {
-        [
-            ("1", "新幹線（旧国鉄）"),
-            ("2", "JR在来線（旧国鉄含む）"),
-            ("3", "公営鉄道"),
-            ("4", "民営鉄道"),
-            ("5", "第三セクター"),
-        ]
-        .into_iter()
-        .collect()
-    });
-
-#[rustfmt::skip]
-pub(crate) static RAILWAY_CLASS_CD: LazyLock<HashMap<&'static str, &'static str>> =
-    LazyLock::new(|| {
-        [
-            ("11", "普通鉄道JR"),
-            ("12", "普通鉄道"),
-            ("13", "鋼索鉄道"),
-            ("14", "懸垂式鉄道"),
-            ("15", "跨座式鉄道"),
-            ("16", "案内軌条式鉄道"),
-            ("17", "無軌条鉄道"),
-            ("21", "軌道"),
-            ("22", "懸垂式モノレール"),
-            ("23", "跨座式モノレール"),
-            ("24", "案内軌条式"),
-            ("25", "浮上式"),
-        ]
-        .into_iter()
-        .collect()
-    });
-
-#[rustfmt::skip]
-pub(crate) static RAILWAY_DUPLICATE_CD: LazyLock<HashMap<&'static str, &'static str>> =
-    LazyLock::new(|| {
-        [
-            ("1", "当該路線駅に記載"),
-            ("2", "他路線駅に記載"),
-            ("3", "駅なし"),
-        ]
-        .into_iter()
-        .collect()
-    });
-
-#[rustfmt::skip]
-pub(crate) static RAILWAY_EXISTENCE_CD: LazyLock<HashMap<&'static str, &'static str>> =
-    LazyLock::new(|| {
-        [
-            ("1", "データ有"),
-            ("2", "データなし"),
-            ("3", "非公開"),
-            ("4", "駅なし"),
-        ]
-        .into_iter()
-        .collect()
-    });
-
-#[rustfmt::skip]
-pub(crate) static RAILWAY_TRANSITION_CD: LazyLock<HashMap<&'static str, &'static str>> =
-    LazyLock::new(|| {
-        [
-            ("1", "事業者種別"),
-            ("2", "路線名"),
-            ("3", "駅名"),
-            ("4", "運営会社"),
-        ]
-        .into_iter()
-        .collect()
-    });
-
-#[rustfmt::skip]
-pub(crate) static REFERECED_FROM_AGRI: LazyLock<HashMap<&'static str, &'static str>> =
-    LazyLock::new(|| {
-        [
-            ("1", "グリーン・ツーリズムデータベース"),
-            ("2", "農業協同組合名鑑"),
-            ("3", "全国市民農園リスト"),
-        ]
-        .into_iter()
-        .collect()
-    });
-
-#[rustfmt::skip]
-pub(crate) static REFERENCE_DATA_CD: LazyLock<HashMap<&'static str, &'static str>> =
-    LazyLock::new(|| {
-        [
-            ("1", "10mDEM"),
-            ("2", "5m空中写真DEM"),
-            ("3", "5mレーザDEM"),
-            ("4", "2mDEM"),
-            ("5", "混在（10m含む）"),
-            ("6", "混在（10m含まない）"),
-        ]
-        .into_iter()
-        .collect()
-    });
-
-#[rustfmt::skip]
-pub(crate) static REGULAR_FLIGHT_CD: LazyLock<HashMap<&'static str, &'static str>> =
-    LazyLock::new(|| {
-        [
-            ("1", "ジェット定期便が就航している場合"),
-            ("2", "プロペラ定期便が就航している場合"),
-            ("3", "定期便が就航していない場合"),
-        ]
-        .into_iter()
-        .collect()
-    });
-
-#[rustfmt::skip]
-pub(crate) static RESEARCH_INSTITUTION_CD: LazyLock<HashMap<&'static str, &'static str>> =
-    LazyLock::new(|| {
-        [
-            ("1", "国立研究機関
+    ("5", "レベル5（位置精度低かつ河川名称不明）"),
+]);
+
+#[cfg(feature = "dict-transport")]
+#[rustfmt::skip]
+pub(crate) static MARITIME_ORG_CD: Codelist = Codelist::new("MARITIME_ORG_CD", &[
+    ("1", "海上保安部"),
+    ("2", "税関"),
+    ("3", "海運局"),
+    ("4", "地方海難審判庁"),
+    ("5", "検疫所"),
+    ("6", "動物検疫所"),
+    ("7", "植物検疫所"),
+    ("8", "入国管理局"),
+]);
+
+#[cfg(feature = "dict-facility")]
+#[rustfmt::skip]
+pub(crate) static MED_CLASS_CD: Codelist = Codelist::new("MED_CLASS_CD", &[
+    ("1", "病院"),
+    ("2", "診療所"),
+    ("3", "歯科診療所"),
+]);
+
+#[cfg(feature = "dict-policy")]
+#[rustfmt::skip]
+pub(crate) static MIDORINOKAIRO_CD: Codelist = Codelist::new("MIDORINOKAIRO_CD", &[
+    ("三国線", "緑の回廊三国線"),
+    ("丹沢緑", "丹沢緑の回廊"),
+    ("会津山", "会津山地緑の回廊"),
+    ("北上回", "北上高地緑の回廊"),
+    ("四国回", "四国山地緑の回廊"),
+    ("回廊八", "緑の回廊八ヶ岳"),
+    ("回廊雨", "緑の回廊雨飾・戸隠"),
+    ("大日回", "大雪・日高緑の回廊"),
+    ("大隅回", "大隅半島緑の回廊"),
+    ("太平回", "八幡平太平山緑の回廊"),
+    ("奥　羽", "奥羽山脈緑の回廊"),
+    ("富士緑", "富士山緑の回廊"),
+    ("支無回", "支笏・無意根緑の回廊"),
+    ("日光・那須", "日光・那須塩原緑の回廊"),
+    ("日光線", "緑の回廊日光線"),
+    ("日吾緑", "日光・吾妻山地緑の回廊"),
+    ("東中国", "東中国山地緑の回廊"),
+    ("白山回", "白山山系緑の回廊"),
+    ("白神回", "白神八甲田緑の回廊"),
+    ("知床回", "知床半島緑の回廊"),
+    ("秩父緑", "秩父山地緑の回廊"),
+    ("綾川回", "綾川上流緑の回廊"),
+    ("越後線", "緑の回廊越後線"),
+    ("越美回", "越美山地緑の回廊"),
+    ("鳥海朝日回", "鳥海朝日・飯豊吾妻緑の回廊"),
+]);
+
+#[rustfmt::skip]
+pub(crate) static N04FukuinH16: Codelist = Codelist::new("N04FukuinH16", &[
+    ("1", "幅員13.0m（11.0m）以上"),
+    ("2", "幅員5.5m以上13.0m（11.0m）未満"),
+    ("3", "幅員3.0m（2.5m）以上5.5m未満"),
+    ("4", "幅員3.0m（2.5m）未満"),
+    ("5", "幅員未調査"),
+    ("6", "幅員合計"),
+]);
+
+#[rustfmt::skip]
+pub(crate) static N04FukuinH22: Codelist = Codelist::new("N04FukuinH22", &[
+    ("1", "幅員25.0m以上"),
+    ("2", "幅員19.5m以上25.0m未満"),
+    ("3", "幅員13.0m（11.0m）以上19.5m未満"),
+    ("4", "幅員13.0m（11.0m）以上で幅員不明"),
+    ("5", "幅員5.5m以上13.0m（11.0m）未満"),
+    ("6", "幅員3.0m（2.5m）以上5.5m未満"),
+    ("7", "幅員3.0m（2.5m）未満"),
+    ("8", "幅員未調査"),
+    ("9", "幅員合計"),
+]);
+
+#[cfg(feature = "dict-facility")]
+#[rustfmt::skip]
+pub(crate) static NATURALFEATURE_CD: Codelist = Codelist::new("NATURALFEATURE_CD", &[
+    ("1001", "水景で上記以外の際立った地形"),
+    ("1002", "水景で顕著な自然現象を記録する地形"),
+    ("101", "火山群"),
+    ("102", "火山"),
+    ("103", "火山性高原"),
+    ("104", "火口・カルデラ"),
+    ("105", "カルデラ壁"),
+    ("106", "流れ山群"),
+    ("107", "火山　特徴的な稜線"),
+    ("108", "溶岩トンネル・風穴"),
+    ("109", "溶岩流末端崖"),
+    ("110", "地獄、泥火山"),
+    ("111", "噴泉"),
+    ("112", "噴気孔"),
+    ("113", "間歇泉"),
+    ("114", "火山　構造土"),
+    ("115", "高山域・亜高山域"),
+    ("116", "火山　万年雪"),
+    ("201", "山脈、山地、高地"),
+    ("202", "丘陵"),
+    ("203", "非火山性高原"),
+    ("204", "大断層崖"),
+    ("205", "非火山性弧峰"),
+    ("206", "U字谷（氷食谷）"),
+    ("207", "カール"),
+    ("208", "非対称山稜"),
+    ("209", "山地　特徴的な稜線"),
+    ("210", "モレーン"),
+    ("211", "二重山稜（綿状凹地）"),
+    ("212", "山地　断崖・岩壁"),
+    ("213", "岩塊斜面、岩海"),
+    ("214", "山地　構造土"),
+    ("215", "山地　岩峰・岩柱"),
+    ("216", "崖錐"),
+    ("217", "山地　天然橋・岩門・石門"),
+    ("218", "高山域、亜高山域"),
+    ("219", "山地　万年雪"),
+    ("301", "カルスト地形"),
+    ("302", "ポリエ"),
+    ("303", "カッレンフェルト・ドリーネ群"),
+    ("304", "鍾乳洞"),
+    ("401", "陸景　節理"),
+    ("402", "陸景　岩脈"),
+    ("501", "陸景で上記以外の際立った地形"),
+    ("502", "陸景で顕著な自然現象を記録する地形"),
+    ("601", "峡谷・渓谷"),
+    ("602", "河成段丘"),
+    ("603", "自由蛇行河川"),
+    ("604", "穿入蛇行河川"),
+    ("605", "河川　断崖・岩壁"),
+    ("606", "瀞"),
+    ("607", "河川　岩峰・岩柱"),
+    ("608", "淵"),
+    ("609", "河川　甌穴群"),
+    ("610", "滝"),
+    ("611", "河川　天然橋・岩門・石門"),
+    ("701", "湖沼"),
+    ("702", "湿原"),
+    ("801", "溺れ谷"),
+    ("802", "海成段丘"),
+    ("803", "断層海岸"),
+    ("804", "火山海岸"),
+    ("805", "多島海"),
+    ("806", "隆起サンゴ礁"),
+    ("807", "砂浜、礫浜"),
+    ("808", "砂嘴"),
+    ("809", "砂州"),
+    ("810", "陸けい砂州"),
+    ("811", "砂丘"),
+    ("812", "海食崖"),
+    ("813", "波食台（ベンチ）"),
+    ("814", "岩礁"),
+    ("815", "海食洞"),
+    ("816", "岩門"),
+    ("817", "湖吹穴"),
+    ("818", "海岸　甌穴群"),
+    ("819", "潮流、渦流"),
+    ("901", "水景　節理"),
+    ("902", "水景　岩脈"),
+    ("903", "湧泉群"),
+]);
+
+#[rustfmt::skip]
+pub(crate) static NaturalParkAreaCd: Codelist = Codelist::new("NaturalParkAreaCd", &[
+    ("1", "自然公園地域"),
+    ("2", "特別地域"),
+    ("3", "特別保護地区"),
+]);
+
+#[cfg(feature = "dict-facility")]
+#[rustfmt::skip]
+pub(crate) static NATURALSCENE_CD: Codelist = Codelist::new("NATURALSCENE_CD", &[
+    ("1", "火山景観"),
+    ("10", "水景その他"),
+    ("2", "山地（非火山性）景観"),
+    ("3", "石灰岩景観"),
+    ("4", "陸景特殊地学景観"),
+    ("5", "陸景その他"),
+    ("6", "河川景観"),
+    ("7", "湖沼景観"),
+    ("8", "海岸景観"),
+    ("9", "水景特殊地学景観"),
+]);
+
+#[cfg(feature = "dict-transport")]
+#[rustfmt::skip]
+pub(crate) static N13_ROAD_TYPE: Codelist = Codelist::new("N13_ROAD_TYPE", &[
+    ("1", "通常部"),
+    ("2", "庭園路"),
+    ("3", "徒歩道"),
+    ("4", "石段"),
+    ("5", "不明"),
+]);
+
+#[cfg(feature = "dict-transport")]
+#[rustfmt::skip]
+pub(crate) static N13_ROAD_CATEGORY: Codelist = Codelist::new("N13_ROAD_CATEGORY", &[
+    ("1", "国道"),
+    ("2", "都道府県道"),
+    ("3", "市区町村道等"),
+    ("4", "高速自動車国道等"),
+    ("5", "その他"),
+    ("6", "不明"),
+]);
+
+#[cfg(feature = "dict-transport")]
+#[rustfmt::skip]
+pub(crate) static N13_ROAD_STATE: Codelist = Codelist::new("N13_ROAD_STATE", &[
+    ("1", "通常部"),
+    ("2", "橋・高架"),
+    ("3", "トンネル"),
+    ("4", "雪囲い"),
+    ("5", "建設中"),
+    ("6", "その他"),
+    ("7", "不明"),
+]);
+
+#[cfg(feature = "dict-transport")]
+#[rustfmt::skip]
+pub(crate) static N13_ROAD_WIDTH: Codelist = Codelist::new("N13_ROAD_WIDTH", &[
+    ("1", "3m未満"),
+    ("2", "3m-5.5m未満"),
+    ("3", "5.5m-13m未満"),
+    ("4", "13m-19.5m未満"),
+    ("5", "19.5m以上"),
+    ("6", "不明"),
+]);
+
+#[cfg(feature = "dict-transport")]
+#[rustfmt::skip]
+pub(crate) static N13_TOIL_ROAD_SECTION: Codelist = Codelist::new("N13_TOIL_ROAD_SECTION", &[
+    ("1", "有料"),
+    ("2", "無料"),
+]);
+
+#[rustfmt::skip]
+pub(crate) static NatureConservationAreaCd: Codelist = Codelist::new("NatureConservationAreaCd", &[
+    ("1", "自然保全地域"),
+    ("2", "原生自然環境保全地域"),
+    ("3", "特別地区"),
+]);
+
+#[cfg(feature = "dict-water")]
+#[rustfmt::skip]
+pub(crate) static ORIGINAL_DATA_CODE_CD: Codelist = Codelist::new("ORIGINAL_DATA_CODE_CD", &[
+    ("0", "不明"),
+    ("1", "河川基盤地図2500"),
+    ("2", "河川基盤地図25000"),
+    ("3", "数値地図25000空間データ基盤"),
+    ("4", "管内図（縮尺50000以下）"),
+    ("5", "管内図（縮尺50000を超える）"),
+    ("6", "数値地図25000（地図画像）"),
+    ("7", "その他"),
+]);
+
+#[cfg(feature = "dict-facility")]
+#[rustfmt::skip]
+pub(crate) static POINT_CLASSIFICATION_CD: Codelist = Codelist::new("POINT_CLASSIFICATION_CD", &[
+    ("1", "敷地・号・建物レベル"),
+    ("2", "番地レベル"),
+    ("3", "大字・町丁目レベル"),
+    ("4", "市区町村レベル"),
+    ("5", "都道府県レベル"),
+    ("9", "緯度経度レベル"),
+]);
+
+#[cfg(feature = "dict-facility")]
+#[rustfmt::skip]
+pub(crate) static POINT_CLASSIFICATION_CODE: Codelist = Codelist::new("POINT_CLASSIFICATION_CODE", &[
+    ("1", "敷地・号・建物レベル"),
+    ("2", "番地レベル"),
+    ("3", "大字・町丁目レベル"),
+    ("9", "その他のレベル"),
+]);
+
+#[cfg(feature = "dict-facility")]
+#[rustfmt::skip]
+pub(crate) static POLICE_STATION_CD: Codelist = Codelist::new("POLICE_STATION_CD", &[
+    ("1", "警察本部"),
+    ("2", "警察署"),
+    ("3", "分庁舎"),
+    ("4", "交番"),
+    ("5", "駐在所"),
+    ("6", "派出所"),
+    ("7", "警察学校"),
+    ("8", "地域安全センター、連絡所等"),
+]);
+
+#[cfg(feature = "dict-policy")]
+#[rustfmt::skip]
+pub(crate) static POS_SPECIFIC_LEVEL: Codelist = Codelist::new("POS_SPECIFIC_LEVEL", &[
+    ("1", "位置特定"),
+    ("2", "号レベル"),
+    ("3", "番地レベル"),
+    ("4", "大字レベル"),
+    ("5", "町丁目レベル"),
+    ("6", "市区町村レベル"),
+    ("7", "都道府県レベル"),
+]);
+
+#[cfg(feature = "dict-facility")]
+#[rustfmt::skip]
+pub(crate) static POST_OFFICE_CD: Codelist = Codelist::new("POST_OFFICE_CD", &[
+    ("18004", "簡易郵便局"),
+    ("18006", "直営郵便局"),
+]);
+
+#[cfg(feature = "dict-policy")]
+#[rustfmt::skip]
+pub(crate) static PROTECTION_AREA_TYPE: Codelist = Codelist::new("PROTECTION_AREA_TYPE", &[
+    ("1", "鳥獣保護区"),
+    ("2", "特別保護地区"),
+    ("3", "休猟区"),
+    ("4", "特例休猟区"),
+]);
+
+#[rustfmt::skip]
+pub(crate) static PTAreaCd: Codelist = Codelist::new("PTAreaCd", &[
+    ("1", "東京"),
+    ("2", "京阪神"),
+    ("3", "中京"),
+]);
+
+#[cfg(feature = "dict-facility")]
+#[rustfmt::skip]
+pub(crate) static PUB_FAC_ADMIN_CD: Codelist = Codelist::new("PUB_FAC_ADMIN_CD", &[
+    ("0", "その他"),
+    ("001", "国"),
+    ("1", "国"),
+    ("2", "都道府県"),
+    ("3", "市区町村"),
+    ("4", "民間"),
+    ("500", "その他の地方公共団体"),
+    ("600", "公共企業体"),
+    ("701", "社団法人"),
+    ("702", "財団法人"),
+    ("703", "宗教法人"),
+    ("704", "学校法人"),
+    ("705", "医療法人"),
+    ("706", "社会福祉法人"),
+    ("707", "特殊法人"),
+    ("709", "その他法人"),
+    ("801", "民間会社"),
+    ("802", "任意団体"),
+    ("803", "個人"),
+    ("9", "不明"),
+    ("900", "その他"),
+]);
+
+#[cfg(feature = "dict-facility")]
+#[rustfmt::skip]
+pub(crate) static PUB_FAC_MACLASS_CD: Codelist = Codelist::new("PUB_FAC_MACLASS_CD", &[
+    ("11", "国の機関"),
+    ("12", "地方公共団体"),
+    ("13", "厚生機関"),
+    ("14", "警察機関"),
+    ("15", "消防署"),
+    ("16", "学校"),
+    ("17", "病院"),
+    ("18", "郵便局"),
+    ("19", "福祉施設"),
+    ("3", "建物"),
+    ("9", "その他"),
+]);
+
+#[cfg(feature = "dict-facility")]
+#[rustfmt::skip]
+pub(crate) static PUB_FAC_MICLASS_CD_WF: Codelist = Codelist::new("PUB_FAC_MICLASS_CD_WF", &[
+    ("11100", "国会"),
+    ("11101", "会計検査院"),
+    ("11102", "人事院"),
+    ("11103", "内閣法制局"),
+    ("11110", "内閣府"),
+    ("11111", "内閣官房"),
+    ("11112", "宮内庁"),
+    ("11113", "金融庁"),
+    ("11114", "公正取引委員会"),
+    ("11120", "国家公安委員会"),
+    ("11121", "警察庁"),
+    ("11130", "防衛庁"),
+    ("11131", "防衛施設庁"),
+    ("11140", "総務省"),
+    ("11142", "消防庁"),
+    ("11144", "公害等調整委員会"),
+    ("11150", "法務省"),
+    ("11151", "検察庁"),
+    ("11152", "公安調査庁"),
+    ("11153", "公安審査委員会"),
+    ("11160", "外務省"),
+    ("11161", "外国公館"),
+    ("11170", "財務省"),
+    ("11171", "国税庁"),
+    ("11180", "文部科学省"),
+    ("11181", "文化庁"),
+    ("11190", "厚生労働省"),
+    ("11191", "社会保険庁"),
+    ("11192", "中央労働委員会"),
+    ("11200", "農林水産省"),
+    ("11202", "林野庁"),
+    ("11203", "水産庁"),
+    ("11210", "経済産業省"),
+    ("11211", "資源エネルギー庁"),
+    ("11212", "特許庁"),
+    ("11213", "中小企業庁"),
+    ("11220", "国土交通省"),
+    ("11221", "海上保安庁"),
+    ("11222", "海難審判庁"),
+    ("11223", "気象庁"),
+    ("11224", "船員労働委員会"),
+    ("11230", "環境省"),
+    ("11240", "裁判所"),
+    ("12001", "都道府県庁"),
+    ("12002", "区役所（東京都）、市役所"),
+    ("12003", "区役所（政令指定都市）"),
+    ("12004", "町村役場"),
+    ("12005", "都道府県の出先機関"),
+    ("13001", "保健所"),
+    ("14001", "都道府県警察本部"),
+    ("14002", "警察署"),
+    ("14003", "交番"),
+    ("14004", "駐在所"),
+    ("14005", "派出所"),
+    ("14006", "警察学校（都道府県管轄）"),
+    ("15001", "消防本部"),
+    ("15002", "消防署"),
+    ("16001", "小学校"),
+    ("16002", "中学校"),
+    ("16003", "中等教育学校"),
+    ("16004", "高等学校"),
+    ("16005", "高等専門学校"),
+    ("16006", "短期大学"),
+    ("16007", "大学"),
+    ("16008", "盲学校"),
+    ("16009", "ろう学校"),
+    ("16010", "養護学校"),
+    ("16011", "幼稚園"),
+    ("17001", "一般病院、国立療養所、医療センター"),
+    ("17002", "精神病院"),
+    ("17003", "結核療養所"),
+    ("17004", "伝染病院"),
+    ("17005", "主要な診療所"),
+    ("17006", "地域医療支援病院"),
+    ("18001", "普通郵便局"),
+    ("18002", "特定郵便局（集配局）"),
+    ("18003", "特定郵便局（無集配局）"),
+    ("18004", "簡易郵便局"),
+    ("18005", "地域区分局"),
+    ("19001", "老人福祉施設"),
+    ("19002", "老人憩の家"),
+    ("19003", "老人休養ホーム"),
+    ("19004", "有料老人ホーム"),
+    ("19005", "保護施設"),
+    ("19006", "婦人保護施設"),
+    ("19007", "身体障害者更生援護施設"),
+    ("19008", "児童福祉施設"),
+    ("19009", "知的障害者援護施設"),
+    ("19010", "精神障害者社会復帰施設"),
+    ("19011", "母子福祉施設"),
+    ("19012", "その他の社会福祉施設"),
+    ("19013", "保育所"),
+    ("19014", "へき地保育所"),
+    ("19015", "認可外保育施設"),
+    ("19016", "認定保育施設"),
+    ("19017", "事業所内保育施設"),
+    ("19018", "小規模保育事業"),
+    ("19019", "認可保育所"),
+    ("19020", "保育所訪問支援"),
+    ("19021", "認定こども園"),
+    ("19022", "幼保連携型認定こども園"),
+    ("3001", "美術館"),
+    ("3002", "資料館、記念館、博物館、科学館"),
+    ("3003", "図書館"),
+    ("3004", "水族館"),
+    ("3005", "動植物園"),
+    ("9001", "公共企業体・政府関係機関"),
+    ("9002", "独立行政法人・大学共同利用機関法人"),
+]);
+
+#[rustfmt::skip]
+pub(crate) static PubFacMiclassCd: Codelist = Codelist::new("PubFacMiclassCd", &[
+    ("11100", "国会"),
+    ("11101", "会計検査院"),
+    ("11102", "人事院"),
+    ("11103", "内閣法制局"),
+    ("11110", "内閣府"),
+    ("11111", "内閣官房"),
+    ("11112", "宮内庁"),
+    ("11113", "金融庁"),
+    ("11114", "公正取引委員会"),
+    ("11120", "国家公安委員会"),
+    ("11121", "警察庁"),
+    ("11130", "防衛庁"),
+    ("11131", "防衛施設庁"),
+    ("11140", "総務省"),
+    ("11142", "消防庁"),
+    ("11144", "公害等調整委員会"),
+    ("11150", "法務省"),
+    ("11151", "検察庁"),
+    ("11152", "公安調査庁"),
+    ("11153", "公安審査委員会"),
+    ("11160", "外務省"),
+    ("11161", "外国公館"),
+    ("11170", "財務省"),
+    ("11171", "国税庁"),
+    ("11180", "文部科学省"),
+    ("11181", "文化庁"),
+    ("11190", "厚生労働省"),
+    ("11191", "社会保険庁"),
+    ("11192", "中央労働委員会"),
+    ("11200", "農林水産省"),
+    ("11202", "林野庁"),
+    ("11203", "水産庁"),
+    ("11210", "経済産業省"),
+    ("11211", "資源エネルギー庁"),
+    ("11212", "特許庁"),
+    ("11213", "中小企業庁"),
+    ("11220", "国土交通省"),
+    ("11221", "海上保安庁"),
+    ("11222", "海難審判庁"),
+    ("11223", "気象庁"),
+    ("11224", "船員労働委員会"),
+    ("11230", "環境省"),
+    ("11240", "裁判所"),
+    ("12001", "都道府県庁"),
+    ("12002", "区役所（東京都）、市役所"),
+    ("12003", "区役所（政令指定都市）"),
+    ("12004", "町村役場"),
+    ("12005", "都道府県の出先機関"),
+    ("13001", "保健所"),
+    ("14001", "都道府県警察本部"),
+    ("14002", "警察署"),
+    ("14003", "交番"),
+    ("14004", "駐在所"),
+    ("14005", "派出所"),
+    ("14006", "警察学校（都道府県管轄）"),
+    ("15001", "消防本部"),
+    ("15002", "消防署"),
+    ("16001", "小学校"),
+    ("16002", "中学校"),
+    ("16003", "中等教育学校"),
+    ("16004", "高等学校"),
+    ("16005", "高等専門学校"),
+    ("16006", "短期大学"),
+    ("16007", "大学"),
+    ("16008", "盲学校"),
+    ("16009", "ろう学校"),
+    ("16010", "養護学校"),
+    ("16011", "幼稚園"),
+    ("17001", "一般病院、国立療養所、医療センター"),
+    ("17002", "精神病院"),
+    ("17003", "結核療養所"),
+    ("17004", "伝染病院"),
+    ("17005", "主要な診療所"),
+    ("17006", "地域医療支援病院"),
+    ("18001", "普通郵便局"),
+    ("18002", "特定郵便局（集配局）"),
+    ("18003", "特定郵便局（無集配局）"),
+    ("18004", "簡易郵便局"),
+    ("18005", "地域区分局"),
+    ("19001", "老人福祉施設"),
+    ("19002", "老人憩の家"),
+    ("19003", "老人休養ホーム"),
+    ("19004", "有料老人ホーム"),
+    ("19005", "保護施設"),
+    ("19006", "婦人保護施設"),
+    ("19007", "身体障害者更生援護施設"),
+    ("19008", "児童福祉施設"),
+    ("19009", "知的障害者援護施設"),
+    ("19010", "精神障害者社会復帰施設"),
+    ("19011", "母子福祉施設"),
+    ("19012", "その他の社会福祉施設"),
+    ("19013", "保育所"),
+    ("19014", "へき地保育所"),
+    ("3001", "美術館"),
+    ("3002", "資料館、記念館、博物館、科学館"),
+    ("3003", "図書館"),
+    ("3004", "水族館"),
+    ("3005", "動植物園"),
+    ("9001", "公共企業体・政府関係機関"),
+    ("9002", "独立行政法人・大学共同利用機関法人"),
+]);
+
+#[cfg(feature = "dict-facility")]
+#[rustfmt::skip]
+pub(crate) static PUB_FAC_MINCLASS_CD: Codelist = Codelist::new("PUB_FAC_MINCLASS_CD", &[
+    ("03001", "美術館"),
+    ("03002", "資料館，記念館，博物館，科学館"),
+    ("03003", "図書館"),
+    ("03004", "水族館"),
+    ("03005", "動植物園"),
+    ("09001", "公共企業体・政府関係機関"),
+    ("09002", "独立行政法人・大学共同利用機関法人"),
+    ("11100", "国会"),
+    ("11101", "会計検査院"),
+    ("11102", "人事院"),
+    ("11103", "内閣法制局"),
+    ("11110", "内閣府"),
+    ("11111", "内閣官房"),
+    ("11112", "宮内庁"),
+    ("11113", "金融庁"),
+    ("11114", "公正取引委員会"),
+    ("11120", "国家公安委員会"),
+    ("11121", "警察庁"),
+    ("11130", "防衛庁"),
+    ("11131", "防衛施設庁"),
+    ("11140", "総務省"),
+    ("11142", "消防庁"),
+    ("11144", "公害等調整委員会"),
+    ("11150", "法務省"),
+    ("11151", "検察庁"),
+    ("11152", "公安調査庁"),
+    ("11153", "公安審査委員会"),
+    ("11160", "外務省"),
+    ("11161", "外国公館"),
+    ("11170", "財務省"),
+    ("11171", "国税庁"),
+    ("11180", "文部科学省"),
+    ("11181", "文化庁"),
+    ("11190", "厚生労働省"),
+    ("11191", "社会保険庁"),
+    ("11192", "中央労働委員会"),
+    ("11200", "農林水産省"),
+    ("11202", "林野庁"),
+    ("11203", "水産庁"),
+    ("11210", "経済産業省"),
+    ("11211", "資源エネルギー庁"),
+    ("11212", "特許庁"),
+    ("11213", "中小企業庁"),
+    ("11220", "国土交通省"),
+    ("11221", "海上保安庁"),
+    ("11222", "海難審判庁"),
+    ("11223", "気象庁"),
+    ("11224", "船員労働委員会"),
+    ("11230", "環境省"),
+    ("11240", "裁判所"),
+    ("12001", "都道府県庁"),
+    ("12002", "区役所（東京都），市役所"),
+    ("12003", "区役所（政令指定都市）"),
+    ("12004", "町村役場"),
+    ("12005", "都道府県の出先機関"),
+    ("13001", "保健所"),
+    ("16001", "小学校"),
+    ("16002", "中学校"),
+    ("16003", "中等教育学校"),
+    ("16004", "高等学校"),
+    ("16005", "高等専門学校"),
+    ("16006", "短期大学"),
+    ("16007", "大学"),
+    ("16008", "盲学校"),
+    ("16009", "ろう学校"),
+    ("16010", "養護学校"),
+    ("18001", "普通郵便局"),
+    ("18002", "特定郵便局（集配局）"),
+    ("18003", "特定郵便局（無集配局）"),
+    ("18004", "簡易郵便局"),
+    ("18005", "地域区分局"),
+    ("99999", "NA"),
+]);
+
+#[cfg(feature = "dict-facility")]
+#[rustfmt::skip]
+pub(crate) static PUB_OFFICE_CD: Codelist = Codelist::new("PUB_OFFICE_CD", &[
+    ("1", "本庁（市役所、区役所、町役場、村役場）"),
+    ("2", "支所、出張所、連絡所"),
+    ("3", "上記以外の行政サービス施設"),
+    ("4", "公立公民館"),
+    ("5", "集会施設"),
+]);
+
+#[cfg(feature = "dict-facility")]
+#[rustfmt::skip]
+pub(crate) static PUB_OFFICE_CLASS_CD: Codelist = Codelist::new("PUB_OFFICE_CLASS_CD", &[
+    ("1", "本庁（市役所、区役所、町役場、村役場）"),
+    ("2", "支所、出張所、連絡所"),
+]);
+
+#[cfg(feature = "dict-facility")]
+#[rustfmt::skip]
+pub(crate) static PUMPINGUP_TYPE: Codelist = Codelist::new("PUMPINGUP_TYPE", &[
+    ("1", "混合揚水式水力"),
+    ("2", "純揚水式水力"),
+]);
+
+#[cfg(feature = "dict-transport")]
+#[rustfmt::skip]
+pub(crate) static RAILWAY_CLASS2_CD: Codelist = Codelist::new("RAILWAY_CLASS2_CD", &[
+    ("1", "新幹線（旧国鉄）"),
+    ("2", "JR在来線（旧国鉄含む）"),
+    ("3", "公営鉄道"),
+    ("4", "民営鉄道"),
+    ("5", "第三セクター"),
+]);
+
+#[cfg(feature = "dict-transport")]
+#[rustfmt::skip]
+pub(crate) static RAILWAY_CLASS_CD: Codelist = Codelist::new("RAILWAY_CLASS_CD", &[
+    ("11", "普通鉄道JR"),
+    ("12", "普通鉄道"),
+    ("13", "鋼索鉄道"),
+    ("14", "懸垂式鉄道"),
+    ("15", "跨座式鉄道"),
+    ("16", "案内軌条式鉄道"),
+    ("17", "無軌条鉄道"),
+    ("21", "軌道"),
+    ("22", "懸垂式モノレール"),
+    ("23", "跨座式モノレール"),
+    ("24", "案内軌条式"),
+    ("25", "浮上式"),
+]);
+
+#[cfg(feature = "dict-transport")]
+#[rustfmt::skip]
+pub(crate) static RAILWAY_DUPLICATE_CD: Codelist = Codelist::new("RAILWAY_DUPLICATE_CD", &[
+    ("1", "当該路線駅に記載"),
+    ("2", "他路線駅に記載"),
+    ("3", "駅なし"),
+]);
+
+#[cfg(feature = "dict-transport")]
+#[rustfmt::skip]
+pub(crate) static RAILWAY_EXISTENCE_CD: Codelist = Codelist::new("RAILWAY_EXISTENCE_CD", &[
+    ("1", "データ有"),
+    ("2", "データなし"),
+    ("3", "非公開"),
+    ("4", "駅なし"),
+]);
+
+#[cfg(feature = "dict-transport")]
+#[rustfmt::skip]
+pub(crate) static RAILWAY_TRANSITION_CD: Codelist = Codelist::new("RAILWAY_TRANSITION_CD", &[
+    ("1", "事業者種別"),
+    ("2", "路線名"),
+    ("3", "駅名"),
+    ("4", "運営会社"),
+]);
+
+#[cfg(feature = "dict-facility")]
+#[rustfmt::skip]
+pub(crate) static REFERECED_FROM_AGRI: Codelist = Codelist::new("REFERECED_FROM_AGRI", &[
+    ("1", "グリーン・ツーリズムデータベース"),
+    ("2", "農業協同組合名鑑"),
+    ("3", "全国市民農園リスト"),
+]);
+
+#[cfg(feature = "dict-land")]
+#[rustfmt::skip]
+pub(crate) static REFERENCE_DATA_CD: Codelist = Codelist::new("REFERENCE_DATA_CD", &[
+    ("1", "10mDEM"),
+    ("2", "5m空中写真DEM"),
+    ("3", "5mレーザDEM"),
+    ("4", "2mDEM"),
+    ("5", "混在（10m含む）"),
+    ("6", "混在（10m含まない）"),
+]);
+
+#[cfg(feature = "dict-transport")]
+#[rustfmt::skip]
+pub(crate) static REGULAR_FLIGHT_CD: Codelist = Codelist::new("REGULAR_FLIGHT_CD", &[
+    ("1", "ジェット定期便が就航している場合"),
+    ("2", "プロペラ定期便が就航している場合"),
+    ("3", "定期便が就航していない場合"),
+]);
+
+#[cfg(feature = "dict-facility")]
+#[rustfmt::skip]
+pub(crate) static RESEARCH_INSTITUTION_CD: Codelist = Codelist::new("RESEARCH_INSTITUTION_CD", &[
+    ("1", "国立研究機関
             独立行政法人
             大学共同利用機関法人"),
-            ("2", "特殊法人
+    ("2", "特殊法人
             許可法人"),
-            ("3", "公設試験研究機関"),
-            ("4", "学校機関"),
-            ("5", "公益法人"),
-            ("6", "企業研究施設"),
-            ("9", "その他機関"),
-        ]
-        .into_iter()
-        .collect()
-    });
-
-#[rustfmt::skip]
-pub(crate) static RINSHUNOSAIBUN_CD: LazyLock<HashMap<&'static str, &'static str>> =
-    LazyLock::new(|| {
-        [
-            ("単", "単層林"),
-            ("単未", "単層林（主伐実行後で更新未了林分）"),
-            ("複", "複層林"),
-            ("複未", "複層林（主伐実行後で更新未了林分）"),
-            ("育", "育成天然林"),
-            ("育未", "育成天然林（主伐実行後で更新未了林分）"),
-            ("天", "天然生林"),
-            ("天未", "天然生林（主伐実行後で更新未了林分）"),
-            ("竹林", "竹林"),
-            ("人伐", "人工林伐採跡地"),
-            ("天伐", "天然林伐採跡地"),
-            ("未立", "未立木地"),
-            ("改植", "改植予定地"),
-            ("　", "NA"),
-        ]
-        .into_iter()
-        .collect()
-    });
-
-#[rustfmt::skip]
-pub(crate) static RITO_CD: LazyLock<HashMap<&'static str, &'static str>> =
-    LazyLock::new(|| {
-        [
-            ("0", "類型区分なし"),
-            ("1", "外海本土近接型"),
-            ("2", "内海本土近接型"),
-            ("3", "群島型主島"),
-            ("4", "群島型属島"),
-            ("5", "孤立大型離島"),
-            ("6", "孤立小型離島"),
-        ]
-        .into_iter()
-        .collect()
-    });
-
-#[rustfmt::skip]
-pub(crate) static ROAD_CATEGORY_CD: LazyLock<HashMap<&'static str, &'static str>> =
-    LazyLock::new(|| {
-        [
-            ("1", "高速自動車国道"),
-            ("2", "都市高速道路"),
-            ("3", "一般国道"),
-            ("4", "主要地方道（都道府県道）"),
-            ("5", "主要地方道（指定市市道）"),
-            ("6", "一般都道府県道"),
-            ("7", "市町村道（認定道路）"),
-            ("8", "その他道路"),
-        ]
-        .into_iter()
-        .collect()
-    });
-
-#[rustfmt::skip]
-pub(crate) static REGULATORY_AREA_CLASSIFICATION: LazyLock<HashMap<&'static str, &'static str>> =
-    LazyLock::new(|| {
-        [
-            ("1", "宅地造成等工事規制区域"),
-            ("2", "特定盛土等規制区域"),
-            ("9", "記載なし"),
-        ]
-        .into_iter()
-        .collect()
-    });
-
-#[rustfmt::skip]
-pub(crate) static MANAGEMENT_ORGANIZATION_CLASSIFICATION: LazyLock<HashMap<&'static str, &'static str>> =
-    LazyLock::new(|| {
-        [
-            ("A", "都道府県（B、Cを除く）"),
-            ("B", "政令指定都市"),
-            ("C", "中核市"),
-        ]
-        .into_iter()
-        .collect()
-    });
-
-#[rustfmt::skip]
-pub(crate) static SCHOOL_CLASS_CD: LazyLock<HashMap<&'static str, &'static str>> =
-    LazyLock::new(|| {
-        [
-            ("16001", "小学校"),
-            ("16002", "中学校"),
-            ("16003", "中等教育学校"),
-            ("16004", "高等学校"),
-            ("16005", "高等専門学校"),
-            ("16006", "短期大学"),
-            ("16007", "大学"),
-            ("16012", "特別支援学校"),
-        ]
-        .into_iter()
-        .collect()
-    });
-
-#[rustfmt::skip]
-pub(crate) static SeasideType: LazyLock<HashMap<&'static str, &'static str>> =
-    LazyLock::new(|| {
-        [
-            ("1", "臨海"),
-            ("2", "内陸"),
-        ]
-        .into_iter()
-        .collect()
-    });
-
-#[rustfmt::skip]
-pub(crate) static SECTION_TYPE: LazyLock<HashMap<&'static str, &'static str>> =
-    LazyLock::new(|| {
-        [
-            ("1", "1級直轄区間"),
-            ("2", "1級指定区間"),
-            ("3", "2級河川区間"),
-            ("4", "指定区間外"),
-            ("5", "1級直轄区間でかつ湖沼区間を兼ねる場合"),
-            ("6", "1級指定区間でかつ湖沼区間を兼ねる場合"),
-            ("7", "2級河川区間でかつ湖沼区間を兼ねる場合"),
-            ("8", "指定区間外でかつ湖沼区間を兼ねる場合"),
-            ("0", "不明"),
-        ]
-        .into_iter()
-        .collect()
-    });
-
-#[rustfmt::skip]
-pub(crate) static SectionCdCyubu: LazyLock<HashMap<&'static str, &'static str>> =
-    LazyLock::new(|| {
-        [
-            ("C01", "都市整備区域"),
-            ("C02TT", "都市開発区域.富山・高岡区域"),
-            ("C02KK", "都市開発区域.金沢・小松区域"),
-            ("C02FS", "都市開発区域.福井・坂井区域"),
-            ("C02NU", "都市開発区域.長野・上田区域"),
-            ("C02ID", "都市開発区域.伊那谷区域"),
-            ("C02G", "都市開発区域.岐阜区域"),
-            ("C02T", "都市開発区域.高山区域"),
-            ("C02HS", "都市開発区域.東駿河湾区域"),
-            ("C02NS", "都市開発区域.西駿河湾区域"),
-            ("C02E", "都市開発区域.遠州区域"),
-            ("C02H", "都市開発区域.東三河区域"),
-            ("C02IS", "都市開発区域.伊勢区域"),
-            ("C02B", "都市開発区域.琵琶湖東北部区域"),
-            ("C03CS", "保全区域.中部山岳区域"),
-            ("C03HS", "保全区域.白山区域"),
-            ("C03JK", "保全区域.上信越高原区域"),
-            ("C03MA", "保全区域.南アルプス区域"),
-            ("C03FI", "保全区域.富士伊豆区域"),
-            ("C03IS", "保全区域.伊勢志摩区域"),
-            ("C03NH", "保全区域.能登半島区域"),
-            ("C03EK", "保全区域.越前加賀海岸区域"),
-            ("C03YC", "保全区域.八ヶ岳中信高原区域"),
-            ("C03HK", "保全区域.飛騨木曽川区域"),
-            ("C03MW", "保全区域.三河湾区域"),
-            ("C03S", "保全区域.鈴鹿区域"),
-            ("C03TO", "保全区域.天竜奥三河区域"),
-            ("C03CA", "保全区域.中央アルプス区域"),
-            ("C03II", "保全区域.揖斐伊吹区域"),
-            ("C03H", "保全区域.浜名湖区域"),
-            ("C03AK", "保全区域.愛知高原区域"),
-            ("C03AA", "保全区域.赤目青山香肌峡区域"),
-        ]
-        .into_iter()
-        .collect()
-    });
-
-#[rustfmt::skip]
-pub(crate) static SectionCdKinki: LazyLock<HashMap<&'static str, &'static str>> =
-    LazyLock::new(|| {
-        [
-            ("K01", "既成都市区域"),
-            ("K02K", "近郊整備区域.京都地区"),
-            ("K02O", "近郊整備区域.大阪地区"),
-            ("K02H", "近郊整備区域.兵庫地区"),
-            ("K02N", "近郊整備区域.奈良地区"),
-            ("K03F", "都市開発区域.福井敦賀区域"),
-            ("K03BT", "都市開発区域.琵琶湖東部区域"),
-            ("K03K", "都市開発区域.京都中丹区域"),
-            ("K03B", "都市開発区域.播磨区域"),
-            ("K03W", "都市開発区域.和歌山区域"),
-            ("K03I", "都市開発区域.伊賀区域"),
-            ("K04E", "保全区域.越前海岸区域"),
-            ("K04W", "保全区域.若狭湾区域"),
-            ("K04SU", "保全区域.鈴鹿区域"),
-            ("K04A", "保全区域.赤目室生月瀬区域"),
-            ("K04IS", "保全区域.伊勢志摩区域"),
-            ("K04YO", "保全区域.吉野熊野区域"),
-            ("K04B", "保全区域.琵琶湖区域"),
-            ("K04SA", "保全区域.山陰海岸区域"),
-            ("K04K", "保全区域.京都区域"),
-            ("K04HO", "保全区域.北摂連山区域"),
-            ("K04KON", "保全区域.金剛生駒区域"),
-            ("K04IZ", "保全区域.和泉葛城区域"),
-            ("K04SE", "保全区域.瀬戸内海区域"),
-            ("K04R", "保全区域.六甲区域"),
-            ("K04HY", "保全区域.氷ノ山区域"),
-            ("K04HE", "保全区域.平城山の辺区域"),
-            ("K04YA", "保全区域.矢田斑鳩区域"),
-            ("K04F", "保全区域.藤原飛鳥区域"),
-            ("K04KOY", "保全区域.高野竜神区域"),
-            ("K04SH", "保全区域.白浜田辺区域"),
-            ("K04KA", "保全区域.枯木灘海岸区域"),
-            ("K05KY", "近郊緑地保全区域.京都近郊緑地保全区域"),
-            ("K05H", "近郊緑地保全区域.北摂連山近郊緑地保全区域"),
-            ("K05KO", "近郊緑地保全区域.金剛生駒近郊緑地保全区域"),
-            ("K05I", "近郊緑地保全区域.和泉葛城近郊緑地保全区域"),
-            ("K05R", "近郊緑地保全区域.六甲近郊緑地保全区域"),
-            ("K05Y", "近郊緑地保全区域.矢田斑鳩近郊緑地保全区域"),
-            ("K06KS", "近郊緑地特別保全地区.小塩山近郊緑地特別保全地区"),
-            ("K06Y", "近郊緑地特別保全地区.善峰寺近郊緑地特別保全地区"),
-            ("K06KN", "近郊緑地特別保全地区.北中山近郊緑地特別保全地区"),
-            ("K06RN", "近郊緑地特別保全地区.良元・生瀬近郊緑地特別保全地区"),
-            ("K06KK", "近郊緑地特別保全地区.剱谷・苦楽園近郊緑地特別保全地区"),
-            ("K06E", "近郊緑地特別保全地区.会下山近郊緑地特別保全地区"),
-            ("K06U", "近郊緑地特別保全地区.打越山近郊緑地特別保全地区"),
-            ("K06B", "近郊緑地特別保全地区.坊主山近郊緑地特別保全地区"),
-            ("K06MS", "近郊緑地特別保全地区.摩耶・諏訪山近郊緑地特別保全地区"),
-            ("K06T", "近郊緑地特別保全地区.高取近郊緑地特別保全地区"),
-            ("K06HS", "近郊緑地特別保全地区.東須磨近郊緑地特別保全地区"),
-            ("K06NS", "近郊緑地特別保全地区.西須磨近郊緑地特別保全地区"),
-            ("K06HB", "近郊緑地特別保全地区.鉢伏山近郊緑地特別保全地区"),
-            ("K06S", "近郊緑地特別保全地区.千刈近郊緑地特別保全地区"),
-            ("K06K", "近郊緑地特別保全地区.鎌倉峡近郊緑地特別保全地区"),
-            ("K06TT", "近郊緑地特別保全地区.帝釈丹生山近郊緑地特別保全地区"),
-            ("H06H", "近郊緑地特別保全地区.ひよどりごえ近郊緑地特別保全地区"),
-        ]
-        .into_iter()
-        .collect()
-    });
-
-#[rustfmt::skip]
-pub(crate) static SectionCdSyuto: LazyLock<HashMap<&'static str, &'static str>> =
-    LazyLock::new(|| {
-        [
-            ("S01", "既成市街地"),
-            ("S02T", "近郊整備区域.東京都"),
-            ("S02S", "近郊整備区域.埼玉県"),
-            ("S02C", "近郊整備区域.千葉県"),
-            ("S02K", "近郊整備区域.神奈川県"),
-            ("S02I", "近郊整備区域.茨城県"),
-            ("S03KF", "都市開発区域.熊谷・深谷地区"),
-            ("S03KS", "都市開発区域.古河・総和地区"),
-            ("S03I", "都市開発区域.石岡地区"),
-            ("S03TU", "都市開発区域.筑波地区"),
-            ("S03KO", "都市開発区域.甲府地区"),
-            ("S03KA", "都市開発区域.鹿島地区"),
-            ("S03MH", "都市開発区域.水戸・日立地区"),
-            ("S03OT", "都市開発区域.太田・館林地区"),
-            ("S03O", "都市開発区域.大田原地区"),
-            ("S03C", "都市開発区域.秩父地区"),
-            ("S03SU", "都市開発区域.下館・結城地区"),
-            ("S03K", "都市開発区域.桐生地区"),
-            ("S03U", "都市開発区域.宇都宮地区"),
-            ("S03SA", "都市開発区域.佐野・足利地区"),
-            ("S03TO", "都市開発区域.栃木地区"),
-            ("S03OY", "都市開発区域.小山地区"),
-            ("S03MT", "都市開発区域.前橋・高崎地区"),
-            ("S03H", "都市開発区域.本庄地区"),
-            ("S03TA", "都市開発区域.土浦・阿見地区"),
-            ("S04T", "近郊緑地保全区域.武山近郊緑地保全区域"),
-            ("S04KO", "近郊緑地保全区域.衣笠・大楠山近郊緑地保全区域"),
-            ("S04ZH", "近郊緑地保全区域.逗子・葉山近郊緑地保全区域"),
-            ("S04S", "近郊緑地保全区域.相模原近郊緑地保全区域"),
-            ("S04TK", "近郊緑地保全区域.多磨丘陵北部近郊緑地保全区域"),
-            ("S04TY", "近郊緑地保全区域.滝山近郊緑地保全区域"),
-            ("S04SY", "近郊緑地保全区域.狭山近郊緑地保全区域"),
-            ("S04A", "近郊緑地保全区域.荒川近郊緑地保全区域"),
-            ("S04AG", "近郊緑地保全区域.安行近郊緑地保全区域"),
-            ("S04HC", "近郊緑地保全区域.東千葉近郊緑地保全区域"),
-            ("S04EK", "近郊緑地保全区域.円海山・北鎌倉近郊緑地保全区域"),
-            ("S04U", "近郊緑地保全区域.牛久沼近郊緑地保全区域"),
-            ("S04H", "近郊緑地保全区域.平林寺近郊緑地保全区域"),
-            ("S04I", "近郊緑地保全区域.入間近郊緑地保全区域"),
-            ("S04G", "近郊緑地保全区域.行徳近郊緑地保全区域"),
-            ("S04KI", "近郊緑地保全区域.剣崎・岩堂山近郊緑地保全区域"),
-            ("S04K", "近郊緑地保全区域.君津近郊緑地保全区域"),
-            ("S04TS", "近郊緑地保全区域.利根川・菅生沼近郊緑地保全区域"),
-            ("S05HC", "近郊緑地特別保全地区.東千葉近郊緑地特別保全地区"),
-            ("S05SO", "近郊緑地特別保全地区.三ヶ岡山近郊緑地特別保全地区"),
-            ("S05E", "近郊緑地特別保全地区.円海山近郊緑地特別保全地区"),
-            ("S05G", "近郊緑地特別保全地区.行徳近郊緑地特別保全地区"),
-            ("S05KO", "近郊緑地特別保全地区.衣笠・大楠山近郊緑地特別保全地区"),
-            ("S05T", "近郊緑地特別保全地区.武山近郊緑地特別保全地区"),
-            ("S05S", "近郊緑地特別保全地区.相模原近郊緑地特別保全地区"),
-            ("S05H", "近郊緑地特別保全地区.平林寺近郊緑地特別保全地区"),
-            ("S05SS", "近郊緑地特別保全地区.相模横山・相模川近郊緑地特別保全地区"),
-        ]
-        .into_iter()
-        .collect()
-    });
-
-#[rustfmt::skip]
-pub(crate) static SectionTypeCdCyubu: LazyLock<HashMap<&'static str, &'static str>> =
-    LazyLock::new(|| {
-        [
-            ("0", "無指定"),
-            ("1", "都市整備区域(［保全区域］との重複無し"),
-            ("2", "都市開発区域"),
-            ("3", "保全区域（［都市整備区域］との重複無し"),
-            ("4", "保全区域、都市整備区域との重複区域"),
-        ]
-        .into_iter()
-        .collect()
-    });
-
-#[rustfmt::skip]
-pub(crate) static SectionTypeCdKinki: LazyLock<HashMap<&'static str, &'static str>> =
-    LazyLock::new(|| {
-        [
-            ("0", "無指定"),
-            ("1", "既成都市区域"),
-            ("2", "近郊整備区域"),
-            ("3", "都市開発区域"),
-            ("4", "保全区域（［近郊緑地保全区域］との重複無し）"),
-            ("5", "近郊緑地保全区域（全て［保全区域］内）"),
-            ("6", "近郊緑地特別保全地区（全て［近郊緑地保全区域］）"),
-        ]
-        .into_iter()
-        .collect()
-    });
-
-#[rustfmt::skip]
-pub(crate) static SectionTypeCdSyuto: LazyLock<HashMap<&'static str, &'static str>> =
-    LazyLock::new(|| {
-        [
-            ("0", "無指定"),
-            ("1", "既成市街地"),
-            ("2", "近郊整備地帯（[近郊緑地保全区域]との複合無し）"),
-            ("3", "都市開発区域"),
-            ("4", "近郊緑地保全区域（全て［近郊整備地帯］内）"),
-            ("5", "近郊緑地特別保全地域(全て［近郊緑地保全区域］内)"),
-        ]
-        .into_iter()
-        .collect()
-    });
-
-#[rustfmt::skip]
-pub(crate) static SEDIMENT_DISASTERS_PRONE_AREA_CD: LazyLock<HashMap<&'static str, &'static str>> =
-    LazyLock::new(|| {
-        [
-            ("1", "土石流危険渓流"),
-            ("2", "土石流危険区域"),
-            ("3", "土石流危険渓流主流路"),
-            ("4", "土石流危険渓流氾濫開始点"),
-            ("5", "急傾斜地崩壊危険箇所"),
-            ("6", "急傾斜地崩壊危険区域"),
-            ("7", "地すべり危険箇所"),
-            ("8", "地すべり危険区域"),
-            ("9", "地すべり氾濫区域"),
-            ("10", "地すべり湛水域"),
-            ("11", "雪崩危険箇所"),
-        ]
-        .into_iter()
-        .collect()
-    });
-
-#[rustfmt::skip]
-pub(crate) static SELECT_LAND_STATUS_L01V1: LazyLock<HashMap<&'static str, &'static str>> =
-    LazyLock::new(|| {
-        [
-            ("1", "継続"),
-            ("2", "標準地・基準地番号変更"),
-            ("4", "選定替えで当該年追加"),
-            ("5", "新設"),
-        ]
-        .into_iter()
-        .collect()
-    });
-
-#[rustfmt::skip]
-pub(crate) static SELECT_LAND_STATUS_L01V2: LazyLock<HashMap<&'static str, &'static str>> =
-    LazyLock::new(|| {
-        [
-            ("1", "継続"),
-            ("2", "標準地・基準地番号変更"),
-            ("4", "新設・選定替えで当該年追加"),
-        ]
-        .into_iter()
-        .collect()
-    });
-
-#[rustfmt::skip]
-pub(crate) static SETTING_FLAG: LazyLock<HashMap<&'static str, &'static str>> =
-    LazyLock::new(|| {
-        [
-            ("1", "設定あり"),
-            ("2", "設定なし"),
-        ]
-        .into_iter()
-        .collect()
-    });
-
-#[rustfmt::skip]
-pub(crate) static SHOUHANSHUBAN_CD: LazyLock<HashMap<&'static str, &'static str>> =
-    LazyLock::new(|| {
-        [
-            ("1", "い"),
-            ("2", "ろ"),
-            ("3", "は"),
-            ("4", "に"),
-            ("5", "ほ"),
-            ("6", "へ"),
-            ("7", "と"),
-            ("8", "ち"),
-            ("9", "り"),
-            ("10", "ぬ"),
-            ("11", "る"),
-            ("13", "わ"),
-            ("14", "か"),
-            ("15", "よ"),
-            ("16", "た"),
-            ("17", "れ"),
-            ("18", "そ"),
-            ("19", "つ"),
-            ("20", "ね"),
-            ("21", "な"),
-            ("22", "ら"),
-            ("23", "む"),
-            ("24", "う"),
-            ("26", "の"),
-            ("27", "お"),
-            ("28", "く"),
-            ("29", "や"),
-            ("30", "ま"),
-            ("31", "け"),
-            ("32", "ふ"),
-            ("33", "こ"),
-            ("34", "え"),
-            ("35", "て"),
-            ("36", "あ"),
-            ("37", "さ"),
-            ("38", "き"),
-            ("39", "ゆ"),
-            ("40", "め"),
-            ("41", "み"),
-            ("42", "し"),
-            ("44", "ひ"),
-            ("45", "も"),
-            ("46", "せ"),
-            ("47", "す"),
-            ("48", "ん"),
-            ("51", "イ"),
-            ("52", "ロ"),
-            ("53", "ハ"),
-            ("54", "ニ"),
-            ("55", "ホ"),
-            ("56", "ヘ"),
-            ("57", "ト"),
-            ("58", "チ"),
-            ("59", "リ"),
-            ("60", "ヌ"),
-            ("61", "ル"),
-            ("63", "ワ"),
-            ("64", "カ"),
-            ("65", "ヨ"),
-            ("66", "タ"),
-            ("67", "レ"),
-            ("68", "ソ"),
-            ("69", "ツ"),
-            ("70", "ネ"),
-            ("71", "ナ"),
-            ("72", "ラ"),
-            ("73", "ム"),
-            ("74", "ウ"),
-            ("76", "ノ"),
-            ("77", "オ"),
-            ("78", "ク"),
-            ("79", "ヤ"),
-            ("80", "マ"),
-            ("81", "ケ"),
-            ("82", "フ"),
-            ("83", "コ"),
-            ("84", "エ"),
-            ("85", "テ"),
-            ("86", "ア"),
-            ("87", "サ"),
-            ("88", "キ"),
-            ("92", "シ"),
-            ("94", "ヒ"),
-            ("95", "モ"),
-            ("96", "セ"),
-            ("97", "ス"),
-            ("98", "ン"),
-            ("0", "該当なし"),
-        ]
-        .into_iter()
-        .collect()
-    });
-
-#[rustfmt::skip]
-pub(crate) static SMALL_CLASSIFICATION_CD: LazyLock<HashMap<&'static str, &'static str>> =
-    LazyLock::new(|| {
-        [
-            ("11", "有形文化財"),
-            ("21", "無形文化財"),
-            ("31", "有形民俗文化財"),
-            ("32", "無形民俗文化財"),
-            ("41", "史跡（旧跡を含む）"),
-            ("42", "名勝"),
-            ("43", "天然記念物"),
-            ("51", "重要文化的景観"),
-            ("61", "伝統的建造物群保存地区"),
-            ("71", "選定保存技術"),
-        ]
-        .into_iter()
-        .collect()
-    });
-
-#[rustfmt::skip]
-pub(crate) static SPECIFIC_AIRPORT_SPECIFIED_SITUATION_CD: LazyLock<HashMap<&'static str, &'static str>> =
-    LazyLock::new(|| {
-        [
-            ("1", "「公共用飛行場周辺における航空機騒音による障害の防止等に関する法律」に基づく特定飛行場で、かつ周辺整備空港に指定されている場合"),
-            ("2", "周辺整備空港に指定されていない特定飛行場"),
-            ("3", "特定飛行場以外"),
-            ("9", "NA"),
-            ("999", "NA"),
-        ]
-        .into_iter()
-        .collect()
-    });
-
-#[rustfmt::skip]
-pub(crate) static SubprefectureNameCd: LazyLock<HashMap<&'static str, &'static str>> =
-    LazyLock::new(|| {
-        [
-            ("00", "北海道以外の都府県"),
-            ("01", "石狩支庁"),
-            ("02", "渡島支庁"),
-            ("03", "檜山支庁"),
-            ("04", "後志支庁"),
-            ("05", "空知支庁"),
-            ("06", "上川支庁"),
-            ("07", "留萌支庁"),
-            ("08", "宗谷支庁"),
-            ("09", "網走支庁"),
-            ("10", "胆振支庁"),
-            ("11", "日高支庁"),
-            ("12", "十勝支庁"),
-            ("13", "釧路支庁"),
-            ("14", "根室支庁"),
-        ]
-        .into_iter()
-        .collect()
-    });
-
-#[rustfmt::skip]
-pub(crate) static THERMAL_POWER_ENGINE: LazyLock<HashMap<&'static str, &'static str>> =
-    LazyLock::new(|| {
-        [
-            ("3", "石炭火力"),
-            ("4", "LNG火力"),
-            ("5", "天然ガス火力"),
-            ("8", "石油等火力"),
-        ]
-        .into_iter()
-        .collect()
-    });
-
-#[rustfmt::skip]
-pub(crate) static TOKUSYUDOJYO_CD_V3_0: LazyLock<HashMap<&'static str, &'static str>> =
-    LazyLock::new(|| {
-        [
-            ("01", "シラス・ボラ・コラ・赤ホヤ・花崗岩風化土"),
-            ("02", "赤ホヤ・シラス・花崗岩風化土"),
-            ("03", "赤ホヤ"),
-            ("04", "赤ホヤ・花崗岩風化土"),
-            ("05", "花崗岩風化土"),
-            ("06", "ヨナ・赤ホヤ・花崗岩風化土・シラス"),
-            ("07", "富士マサ"),
-            ("08", "赤ホヤ・ヨナ"),
-        ]
-        .into_iter()
-        .collect()
-    });
-
-#[rustfmt::skip]
-pub(crate) static TOURISM_RESOURCE_CATEGORY_CD: LazyLock<HashMap<&'static str, &'static str>> =
-    LazyLock::new(|| {
-        [
-            ("1", "自然（行催事・イベント）"),
-            ("2", "歴史・文化"),
-            ("3", "温泉・健康"),
-            ("4", "スポーツ・レクリエーション"),
-            ("5", "都市型観光-買物・食-"),
-            ("6", "その他"),
-        ]
-        .into_iter()
-        .collect()
-    });
-
-#[rustfmt::skip]
-pub(crate) static TripGenerationCd: LazyLock<HashMap<&'static str, &'static str>> =
-    LazyLock::new(|| {
-        [
-            ("1", "発生"),
-            ("2", "集中"),
-        ]
-        .into_iter()
-        .collect()
-    });
+    ("3", "公設試験研究機関"),
+    ("4", "学校機関"),
+    ("5", "公益法人"),
+    ("6", "企業研究施設"),
+    ("9", "その他機関"),
+]);
+
+#[cfg(feature = "dict-policy")]
+#[rustfmt::skip]
+pub(crate) static RINSHUNOSAIBUN_CD: Codelist = Codelist::new("RINSHUNOSAIBUN_CD", &[
+    ("　", "NA"),
+    ("人伐", "人工林伐採跡地"),
+    ("単", "単層林"),
+    ("単未", "単層林（主伐実行後で更新未了林分）"),
+    ("天", "天然生林"),
+    ("天伐", "天然林伐採跡地"),
+    ("天未", "天然生林（主伐実行後で更新未了林分）"),
+    ("改植", "改植予定地"),
+    ("未立", "未立木地"),
+    ("竹林", "竹林"),
+    ("育", "育成天然林"),
+    ("育未", "育成天然林（主伐実行後で更新未了林分）"),
+    ("複", "複層林"),
+    ("複未", "複層林（主伐実行後で更新未了林分）"),
+]);
+
+#[cfg(feature = "dict-policy")]
+#[rustfmt::skip]
+pub(crate) static RITO_CD: Codelist = Codelist::new("RITO_CD", &[
+    ("0", "類型区分なし"),
+    ("1", "外海本土近接型"),
+    ("2", "内海本土近接型"),
+    ("3", "群島型主島"),
+    ("4", "群島型属島"),
+    ("5", "孤立大型離島"),
+    ("6", "孤立小型離島"),
+]);
+
+#[cfg(feature = "dict-transport")]
+#[rustfmt::skip]
+pub(crate) static ROAD_CATEGORY_CD: Codelist = Codelist::new("ROAD_CATEGORY_CD", &[
+    ("1", "高速自動車国道"),
+    ("2", "都市高速道路"),
+    ("3", "一般国道"),
+    ("4", "主要地方道（都道府県道）"),
+    ("5", "主要地方道（指定市市道）"),
+    ("6", "一般都道府県道"),
+    ("7", "市町村道（認定道路）"),
+    ("8", "その他道路"),
+]);
+
+#[cfg(feature = "dict-policy")]
+#[rustfmt::skip]
+pub(crate) static REGULATORY_AREA_CLASSIFICATION: Codelist = Codelist::new("REGULATORY_AREA_CLASSIFICATION", &[
+    ("1", "宅地造成等工事規制区域"),
+    ("2", "特定盛土等規制区域"),
+    ("9", "記載なし"),
+]);
+
+#[cfg(feature = "dict-policy")]
+#[rustfmt::skip]
+pub(crate) static MANAGEMENT_ORGANIZATION_CLASSIFICATION: Codelist = Codelist::new("MANAGEMENT_ORGANIZATION_CLASSIFICATION", &[
+    ("A", "都道府県（B、Cを除く）"),
+    ("B", "政令指定都市"),
+    ("C", "中核市"),
+]);
+
+#[cfg(feature = "dict-facility")]
+#[rustfmt::skip]
+pub(crate) static SCHOOL_CLASS_CD: Codelist = Codelist::new("SCHOOL_CLASS_CD", &[
+    ("16001", "小学校"),
+    ("16002", "中学校"),
+    ("16003", "中等教育学校"),
+    ("16004", "高等学校"),
+    ("16005", "高等専門学校"),
+    ("16006", "短期大学"),
+    ("16007", "大学"),
+    ("16012", "特別支援学校"),
+]);
+
+#[rustfmt::skip]
+pub(crate) static SeasideType: Codelist = Codelist::new("SeasideType", &[
+    ("1", "臨海"),
+    ("2", "内陸"),
+]);
+
+#[cfg(feature = "dict-water")]
+#[rustfmt::skip]
+pub(crate) static SECTION_TYPE: Codelist = Codelist::new("SECTION_TYPE", &[
+    ("0", "不明"),
+    ("1", "1級直轄区間"),
+    ("2", "1級指定区間"),
+    ("3", "2級河川区間"),
+    ("4", "指定区間外"),
+    ("5", "1級直轄区間でかつ湖沼区間を兼ねる場合"),
+    ("6", "1級指定区間でかつ湖沼区間を兼ねる場合"),
+    ("7", "2級河川区間でかつ湖沼区間を兼ねる場合"),
+    ("8", "指定区間外でかつ湖沼区間を兼ねる場合"),
+]);
+
+#[rustfmt::skip]
+pub(crate) static SectionCdCyubu: Codelist = Codelist::new("SectionCdCyubu", &[
+    ("C01", "都市整備区域"),
+    ("C02B", "都市開発区域.琵琶湖東北部区域"),
+    ("C02E", "都市開発区域.遠州区域"),
+    ("C02FS", "都市開発区域.福井・坂井区域"),
+    ("C02G", "都市開発区域.岐阜区域"),
+    ("C02H", "都市開発区域.東三河区域"),
+    ("C02HS", "都市開発区域.東駿河湾区域"),
+    ("C02ID", "都市開発区域.伊那谷区域"),
+    ("C02IS", "都市開発区域.伊勢区域"),
+    ("C02KK", "都市開発区域.金沢・小松区域"),
+    ("C02NS", "都市開発区域.西駿河湾区域"),
+    ("C02NU", "都市開発区域.長野・上田区域"),
+    ("C02T", "都市開発区域.高山区域"),
+    ("C02TT", "都市開発区域.富山・高岡区域"),
+    ("C03AA", "保全区域.赤目青山香肌峡区域"),
+    ("C03AK", "保全区域.愛知高原区域"),
+    ("C03CA", "保全区域.中央アルプス区域"),
+    ("C03CS", "保全区域.中部山岳区域"),
+    ("C03EK", "保全区域.越前加賀海岸区域"),
+    ("C03FI", "保全区域.富士伊豆区域"),
+    ("C03H", "保全区域.浜名湖区域"),
+    ("C03HK", "保全区域.飛騨木曽川区域"),
+    ("C03HS", "保全区域.白山区域"),
+    ("C03II", "保全区域.揖斐伊吹区域"),
+    ("C03IS", "保全区域.伊勢志摩区域"),
+    ("C03JK", "保全区域.上信越高原区域"),
+    ("C03MA", "保全区域.南アルプス区域"),
+    ("C03MW", "保全区域.三河湾区域"),
+    ("C03NH", "保全区域.能登半島区域"),
+    ("C03S", "保全区域.鈴鹿区域"),
+    ("C03TO", "保全区域.天竜奥三河区域"),
+    ("C03YC", "保全区域.八ヶ岳中信高原区域"),
+]);
+
+#[rustfmt::skip]
+pub(crate) static SectionCdKinki: Codelist = Codelist::new("SectionCdKinki", &[
+    ("H06H", "近郊緑地特別保全地区.ひよどりごえ近郊緑地特別保全地区"),
+    ("K01", "既成都市区域"),
+    ("K02H", "近郊整備区域.兵庫地区"),
+    ("K02K", "近郊整備区域.京都地区"),
+    ("K02N", "近郊整備区域.奈良地区"),
+    ("K02O", "近郊整備区域.大阪地区"),
+    ("K03B", "都市開発区域.播磨区域"),
+    ("K03BT", "都市開発区域.琵琶湖東部区域"),
+    ("K03F", "都市開発区域.福井敦賀区域"),
+    ("K03I", "都市開発区域.伊賀区域"),
+    ("K03K", "都市開発区域.京都中丹区域"),
+    ("K03W", "都市開発区域.和歌山区域"),
+    ("K04A", "保全区域.赤目室生月瀬区域"),
+    ("K04B", "保全区域.琵琶湖区域"),
+    ("K04E", "保全区域.越前海岸区域"),
+    ("K04F", "保全区域.藤原飛鳥区域"),
+    ("K04HE", "保全区域.平城山の辺区域"),
+    ("K04HO", "保全区域.北摂連山区域"),
+    ("K04HY", "保全区域.氷ノ山区域"),
+    ("K04IS", "保全区域.伊勢志摩区域"),
+    ("K04IZ", "保全区域.和泉葛城区域"),
+    ("K04K", "保全区域.京都区域"),
+    ("K04KA", "保全区域.枯木灘海岸区域"),
+    ("K04KON", "保全区域.金剛生駒区域"),
+    ("K04KOY", "保全区域.高野竜神区域"),
+    ("K04R", "保全区域.六甲区域"),
+    ("K04SA", "保全区域.山陰海岸区域"),
+    ("K04SE", "保全区域.瀬戸内海区域"),
+    ("K04SH", "保全区域.白浜田辺区域"),
+    ("K04SU", "保全区域.鈴鹿区域"),
+    ("K04W", "保全区域.若狭湾区域"),
+    ("K04YA", "保全区域.矢田斑鳩区域"),
+    ("K04YO", "保全区域.吉野熊野区域"),
+    ("K05H", "近郊緑地保全区域.北摂連山近郊緑地保全区域"),
+    ("K05I", "近郊緑地保全区域.和泉葛城近郊緑地保全区域"),
+    ("K05KO", "近郊緑地保全区域.金剛生駒近郊緑地保全区域"),
+    ("K05KY", "近郊緑地保全区域.京都近郊緑地保全区域"),
+    ("K05R", "近郊緑地保全区域.六甲近郊緑地保全区域"),
+    ("K05Y", "近郊緑地保全区域.矢田斑鳩近郊緑地保全区域"),
+    ("K06B", "近郊緑地特別保全地区.坊主山近郊緑地特別保全地区"),
+    ("K06E", "近郊緑地特別保全地区.会下山近郊緑地特別保全地区"),
+    ("K06HB", "近郊緑地特別保全地区.鉢伏山近郊緑地特別保全地区"),
+    ("K06HS", "近郊緑地特別保全地区.東須磨近郊緑地特別保全地区"),
+    ("K06K", "近郊緑地特別保全地区.鎌倉峡近郊緑地特別保全地区"),
+    ("K06KK", "近郊緑地特別保全地区.剱谷・苦楽園近郊緑地特別保全地区"),
+    ("K06KN", "近郊緑地特別保全地区.北中山近郊緑地特別保全地区"),
+    ("K06KS", "近郊緑地特別保全地区.小塩山近郊緑地特別保全地区"),
+    ("K06MS", "近郊緑地特別保全地区.摩耶・諏訪山近郊緑地特別保全地区"),
+    ("K06NS", "近郊緑地特別保全地区.西須磨近郊緑地特別保全地区"),
+    ("K06RN", "近郊緑地特別保全地区.良元・生瀬近郊緑地特別保全地区"),
+    ("K06S", "近郊緑地特別保全地区.千刈近郊緑地特別保全地区"),
+    ("K06T", "近郊緑地特別保全地区.高取近郊緑地特別保全地区"),
+    ("K06TT", "近郊緑地特別保全地区.帝釈丹生山近郊緑地特別保全地区"),
+    ("K06U", "近郊緑地特別保全地区.打越山近郊緑地特別保全地区"),
+    ("K06Y", "近郊緑地特別保全地区.善峰寺近郊緑地特別保全地区"),
+]);
+
+#[rustfmt::skip]
+pub(crate) static SectionCdSyuto: Codelist = Codelist::new("SectionCdSyuto", &[
+    ("S01", "既成市街地"),
+    ("S02C", "近郊整備区域.千葉県"),
+    ("S02I", "近郊整備区域.茨城県"),
+    ("S02K", "近郊整備区域.神奈川県"),
+    ("S02S", "近郊整備区域.埼玉県"),
+    ("S02T", "近郊整備区域.東京都"),
+    ("S03C", "都市開発区域.秩父地区"),
+    ("S03H", "都市開発区域.本庄地区"),
+    ("S03I", "都市開発区域.石岡地区"),
+    ("S03K", "都市開発区域.桐生地区"),
+    ("S03KA", "都市開発区域.鹿島地区"),
+    ("S03KF", "都市開発区域.熊谷・深谷地区"),
+    ("S03KO", "都市開発区域.甲府地区"),
+    ("S03KS", "都市開発区域.古河・総和地区"),
+    ("S03MH", "都市開発区域.水戸・日立地区"),
+    ("S03MT", "都市開発区域.前橋・高崎地区"),
+    ("S03O", "都市開発区域.大田原地区"),
+    ("S03OT", "都市開発区域.太田・館林地区"),
+    ("S03OY", "都市開発区域.小山地区"),
+    ("S03SA", "都市開発区域.佐野・足利地区"),
+    ("S03SU", "都市開発区域.下館・結城地区"),
+    ("S03TA", "都市開発区域.土浦・阿見地区"),
+    ("S03TO", "都市開発区域.栃木地区"),
+    ("S03TU", "都市開発区域.筑波地区"),
+    ("S03U", "都市開発区域.宇都宮地区"),
+    ("S04A", "近郊緑地保全区域.荒川近郊緑地保全区域"),
+    ("S04AG", "近郊緑地保全区域.安行近郊緑地保全区域"),
+    ("S04EK", "近郊緑地保全区域.円海山・北鎌倉近郊緑地保全区域"),
+    ("S04G", "近郊緑地保全区域.行徳近郊緑地保全区域"),
+    ("S04H", "近郊緑地保全区域.平林寺近郊緑地保全区域"),
+    ("S04HC", "近郊緑地保全区域.東千葉近郊緑地保全区域"),
+    ("S04I", "近郊緑地保全区域.入間近郊緑地保全区域"),
+    ("S04K", "近郊緑地保全区域.君津近郊緑地保全区域"),
+    ("S04KI", "近郊緑地保全区域.剣崎・岩堂山近郊緑地保全区域"),
+    ("S04KO", "近郊緑地保全区域.衣笠・大楠山近郊緑地保全区域"),
+    ("S04S", "近郊緑地保全区域.相模原近郊緑地保全区域"),
+    ("S04SY", "近郊緑地保全区域.狭山近郊緑地保全区域"),
+    ("S04T", "近郊緑地保全区域.武山近郊緑地保全区域"),
+    ("S04TK", "近郊緑地保全区域.多磨丘陵北部近郊緑地保全区域"),
+    ("S04TS", "近郊緑地保全区域.利根川・菅生沼近郊緑地保全区域"),
+    ("S04TY", "近郊緑地保全区域.滝山近郊緑地保全区域"),
+    ("S04U", "近郊緑地保全区域.牛久沼近郊緑地保全区域"),
+    ("S04ZH", "近郊緑地保全区域.逗子・葉山近郊緑地保全区域"),
+    ("S05E", "近郊緑地特別保全地区.円海山近郊緑地特別保全地区"),
+    ("S05G", "近郊緑地特別保全地区.行徳近郊緑地特別保全地区"),
+    ("S05H", "近郊緑地特別保全地区.平林寺近郊緑地特別保全地区"),
+    ("S05HC", "近郊緑地特別保全地区.東千葉近郊緑地特別保全地区"),
+    ("S05KO", "近郊緑地特別保全地区.衣笠・大楠山近郊緑地特別保全地区"),
+    ("S05S", "近郊緑地特別保全地区.相模原近郊緑地特別保全地区"),
+    ("S05SO", "近郊緑地特別保全地区.三ヶ岡山近郊緑地特別保全地区"),
+    ("S05SS", "近郊緑地特別保全地区.相模横山・相模川近郊緑地特別保全地区"),
+    ("S05T", "近郊緑地特別保全地区.武山近郊緑地特別保全地区"),
+]);
+
+#[rustfmt::skip]
+pub(crate) static SectionTypeCdCyubu: Codelist = Codelist::new("SectionTypeCdCyubu", &[
+    ("0", "無指定"),
+    ("1", "都市整備区域(［保全区域］との重複無し"),
+    ("2", "都市開発区域"),
+    ("3", "保全区域（［都市整備区域］との重複無し"),
+    ("4", "保全区域、都市整備区域との重複区域"),
+]);
+
+#[rustfmt::skip]
+pub(crate) static SectionTypeCdKinki: Codelist = Codelist::new("SectionTypeCdKinki", &[
+    ("0", "無指定"),
+    ("1", "既成都市区域"),
+    ("2", "近郊整備区域"),
+    ("3", "都市開発区域"),
+    ("4", "保全区域（［近郊緑地保全区域］との重複無し）"),
+    ("5", "近郊緑地保全区域（全て［保全区域］内）"),
+    ("6", "近郊緑地特別保全地区（全て［近郊緑地保全区域］）"),
+]);
+
+#[rustfmt::skip]
+pub(crate) static SectionTypeCdSyuto: Codelist = Codelist::new("SectionTypeCdSyuto", &[
+    ("0", "無指定"),
+    ("1", "既成市街地"),
+    ("2", "近郊整備地帯（[近郊緑地保全区域]との複合無し）"),
+    ("3", "都市開発区域"),
+    ("4", "近郊緑地保全区域（全て［近郊整備地帯］内）"),
+    ("5", "近郊緑地特別保全地域(全て［近郊緑地保全区域］内)"),
+]);
+
+#[cfg(feature = "dict-policy")]
+#[rustfmt::skip]
+pub(crate) static SEDIMENT_DISASTERS_PRONE_AREA_CD: Codelist = Codelist::new("SEDIMENT_DISASTERS_PRONE_AREA_CD", &[
+    ("1", "土石流危険渓流"),
+    ("10", "地すべり湛水域"),
+    ("11", "雪崩危険箇所"),
+    ("2", "土石流危険区域"),
+    ("3", "土石流危険渓流主流路"),
+    ("4", "土石流危険渓流氾濫開始点"),
+    ("5", "急傾斜地崩壊危険箇所"),
+    ("6", "急傾斜地崩壊危険区域"),
+    ("7", "地すべり危険箇所"),
+    ("8", "地すべり危険区域"),
+    ("9", "地すべり氾濫区域"),
+]);
+
+#[cfg(feature = "dict-land")]
+#[rustfmt::skip]
+pub(crate) static SELECT_LAND_STATUS_L01V1: Codelist = Codelist::new("SELECT_LAND_STATUS_L01V1", &[
+    ("1", "継続"),
+    ("2", "標準地・基準地番号変更"),
+    ("4", "選定替えで当該年追加"),
+    ("5", "新設"),
+]);
+
+#[cfg(feature = "dict-land")]
+#[rustfmt::skip]
+pub(crate) static SELECT_LAND_STATUS_L01V2: Codelist = Codelist::new("SELECT_LAND_STATUS_L01V2", &[
+    ("1", "継続"),
+    ("2", "標準地・基準地番号変更"),
+    ("4", "新設・選定替えで当該年追加"),
+]);
+
+#[cfg(feature = "dict-policy")]
+#[rustfmt::skip]
+pub(crate) static SETTING_FLAG: Codelist = Codelist::new("SETTING_FLAG", &[
+    ("1", "設定あり"),
+    ("2", "設定なし"),
+]);
+
+#[cfg(feature = "dict-policy")]
+#[rustfmt::skip]
+pub(crate) static SHOUHANSHUBAN_CD: Codelist = Codelist::new("SHOUHANSHUBAN_CD", &[
+    ("0", "該当なし"),
+    ("1", "い"),
+    ("10", "ぬ"),
+    ("11", "る"),
+    ("13", "わ"),
+    ("14", "か"),
+    ("15", "よ"),
+    ("16", "た"),
+    ("17", "れ"),
+    ("18", "そ"),
+    ("19", "つ"),
+    ("2", "ろ"),
+    ("20", "ね"),
+    ("21", "な"),
+    ("22", "ら"),
+    ("23", "む"),
+    ("24", "う"),
+    ("26", "の"),
+    ("27", "お"),
+    ("28", "く"),
+    ("29", "や"),
+    ("3", "は"),
+    ("30", "ま"),
+    ("31", "け"),
+    ("32", "ふ"),
+    ("33", "こ"),
+    ("34", "え"),
+    ("35", "て"),
+    ("36", "あ"),
+    ("37", "さ"),
+    ("38", "き"),
+    ("39", "ゆ"),
+    ("4", "に"),
+    ("40", "め"),
+    ("41", "み"),
+    ("42", "し"),
+    ("44", "ひ"),
+    ("45", "も"),
+    ("46", "せ"),
+    ("47", "す"),
+    ("48", "ん"),
+    ("5", "ほ"),
+    ("51", "イ"),
+    ("52", "ロ"),
+    ("53", "ハ"),
+    ("54", "ニ"),
+    ("55", "ホ"),
+    ("56", "ヘ"),
+    ("57", "ト"),
+    ("58", "チ"),
+    ("59", "リ"),
+    ("6", "へ"),
+    ("60", "ヌ"),
+    ("61", "ル"),
+    ("63", "ワ"),
+    ("64", "カ"),
+    ("65", "ヨ"),
+    ("66", "タ"),
+    ("67", "レ"),
+    ("68", "ソ"),
+    ("69", "ツ"),
+    ("7", "と"),
+    ("70", "ネ"),
+    ("71", "ナ"),
+    ("72", "ラ"),
+    ("73", "ム"),
+    ("74", "ウ"),
+    ("76", "ノ"),
+    ("77", "オ"),
+    ("78", "ク"),
+    ("79", "ヤ"),
+    ("8", "ち"),
+    ("80", "マ"),
+    ("81", "ケ"),
+    ("82", "フ"),
+    ("83", "コ"),
+    ("84", "エ"),
+    ("85", "テ"),
+    ("86", "ア"),
+    ("87", "サ"),
+    ("88", "キ"),
+    ("9", "り"),
+    ("92", "シ"),
+    ("94", "ヒ"),
+    ("95", "モ"),
+    ("96", "セ"),
+    ("97", "ス"),
+    ("98", "ン"),
+]);
+
+#[cfg(feature = "dict-facility")]
+#[rustfmt::skip]
+pub(crate) static SMALL_CLASSIFICATION_CD: Codelist = Codelist::new("SMALL_CLASSIFICATION_CD", &[
+    ("11", "有形文化財"),
+    ("21", "無形文化財"),
+    ("31", "有形民俗文化財"),
+    ("32", "無形民俗文化財"),
+    ("41", "史跡（旧跡を含む）"),
+    ("42", "名勝"),
+    ("43", "天然記念物"),
+    ("51", "重要文化的景観"),
+    ("61", "伝統的建造物群保存地区"),
+    ("71", "選定保存技術"),
+]);
+
+#[cfg(feature = "dict-policy")]
+#[rustfmt::skip]
+pub(crate) static SPECIFIC_AIRPORT_SPECIFIED_SITUATION_CD: Codelist = Codelist::new("SPECIFIC_AIRPORT_SPECIFIED_SITUATION_CD", &[
+    ("1", "「公共用飛行場周辺における航空機騒音による障害の防止等に関する法律」に基づく特定飛行場で、かつ周辺整備空港に指定されている場合"),
+    ("2", "周辺整備空港に指定されていない特定飛行場"),
+    ("3", "特定飛行場以外"),
+    ("9", "NA"),
+    ("999", "NA"),
+]);
+
+#[rustfmt::skip]
+pub(crate) static SubprefectureNameCd: Codelist = Codelist::new("SubprefectureNameCd", &[
+    ("00", "北海道以外の都府県"),
+    ("01", "石狩支庁"),
+    ("02", "渡島支庁"),
+    ("03", "檜山支庁"),
+    ("04", "後志支庁"),
+    ("05", "空知支庁"),
+    ("06", "上川支庁"),
+    ("07", "留萌支庁"),
+    ("08", "宗谷支庁"),
+    ("09", "網走支庁"),
+    ("10", "胆振支庁"),
+    ("11", "日高支庁"),
+    ("12", "十勝支庁"),
+    ("13", "釧路支庁"),
+    ("14", "根室支庁"),
+]);
+
+#[cfg(feature = "dict-facility")]
+#[rustfmt::skip]
+pub(crate) static THERMAL_POWER_ENGINE: Codelist = Codelist::new("THERMAL_POWER_ENGINE", &[
+    ("3", "石炭火力"),
+    ("4", "LNG火力"),
+    ("5", "天然ガス火力"),
+    ("8", "石油等火力"),
+]);
+
+#[cfg(feature = "dict-policy")]
+#[rustfmt::skip]
+pub(crate) static TOKUSYUDOJYO_CD_V3_0: Codelist = Codelist::new("TOKUSYUDOJYO_CD_V3_0", &[
+    ("01", "シラス・ボラ・コラ・赤ホヤ・花崗岩風化土"),
+    ("02", "赤ホヤ・シラス・花崗岩風化土"),
+    ("03", "赤ホヤ"),
+    ("04", "赤ホヤ・花崗岩風化土"),
+    ("05", "花崗岩風化土"),
+    ("06", "ヨナ・赤ホヤ・花崗岩風化土・シラス"),
+    ("07", "富士マサ"),
+    ("08", "赤ホヤ・ヨナ"),
+]);
+
+#[cfg(feature = "dict-facility")]
+#[rustfmt::skip]
+pub(crate) static TOURISM_RESOURCE_CATEGORY_CD: Codelist = Codelist::new("TOURISM_RESOURCE_CATEGORY_CD", &[
+    ("1", "自然（行催事・イベント）"),
+    ("2", "歴史・文化"),
+    ("3", "温泉・健康"),
+    ("4", "スポーツ・レクリエーション"),
+    ("5", "都市型観光-買物・食-"),
+    ("6", "その他"),
+]);
+
+#[rustfmt::skip]
+pub(crate) static TripGenerationCd: Codelist = Codelist::new("TripGenerationCd", &[
+    ("1", "発生"),
+    ("2", "集中"),
+]);
 
 // 首都圏、中部圏、近畿圏でコードが異なるが、これはかぶらないので統合する
-#[rustfmt::skip]
-pub(crate) static A03_SECTION_CD: LazyLock<HashMap<&'static str, &'static str>> =
-    LazyLock::new(|| {
-        [
-            ("C01", "都市整備区域"),
-            ("C02B", "都市開発区域.琵琶湖東北部区域"),
-            ("C02E", "都市開発区域.遠州区域"),
-            ("C02FS", "都市開発区域.福井・坂井区域"),
-            ("C02G", "都市開発区域.岐阜区域"),
-            ("C02H", "都市開発区域.東三河区域"),
-            ("C02HS", "都市開発区域.東駿河湾区域"),
-            ("C02ID", "都市開発区域.伊那谷区域"),
-            ("C02IS", "都市開発区域.伊勢区域"),
-            ("C02KK", "都市開発区域.金沢・小松区域"),
-            ("C02NS", "都市開発区域.西駿河湾区域"),
-            ("C02NU", "都市開発区域.長野・上田区域"),
-            ("C02T", "都市開発区域.高山区域"),
-            ("C02TT", "都市開発区域.富山・高岡区域"),
-            ("C03AA", "保全区域.赤目青山香肌峡区域"),
-            ("C03AK", "保全区域.愛知高原区域"),
-            ("C03CA", "保全区域.中央アルプス区域"),
-            ("C03CS", "保全区域.中部山岳区域"),
-            ("C03EK", "保全区域.越前加賀海岸区域"),
-            ("C03FI", "保全区域.富士伊豆区域"),
-            ("C03H", "保全区域.浜名湖区域"),
-            ("C03HK", "保全区域.飛騨木曽川区域"),
-            ("C03HS", "保全区域.白山区域"),
-            ("C03II", "保全区域.揖斐伊吹区域"),
-            ("C03IS", "保全区域.伊勢志摩区域"),
-            ("C03JK", "保全区域.上信越高原区域"),
-            ("C03MA", "保全区域.南アルプス区域"),
-            ("C03MW", "保全区域.三河湾区域"),
-            ("C03NH", "保全区域.能登半島区域"),
-            ("C03S", "保全区域.鈴鹿区域"),
-            ("C03TO", "保全区域.天竜奥三河区域"),
-            ("C03YC", "保全区域.八ヶ岳中信高原区域"),
-            ("H06H", "近郊緑地特別保全地区.ひよどりごえ近郊緑地特別保全地区"),
-            ("K01", "既成都市区域"),
-            ("K02H", "近郊整備区域.兵庫地区"),
-            ("K02K", "近郊整備区域.京都地区"),
-            ("K02N", "近郊整備区域.奈良地区"),
-            ("K02O", "近郊整備区域.大阪地区"),
-            ("K03B", "都市開発区域.播磨区域"),
-            ("K03BT", "都市開発区域.琵琶湖東部区域"),
-            ("K03F", "都市開発区域.福井敦賀区域"),
-            ("K03I", "都市開発区域.伊賀区域"),
-            ("K03K", "都市開発区域.京都中丹区域"),
-            ("K03W", "都市開発区域.和歌山区域"),
-            ("K04A", "保全区域.赤目室生月瀬区域"),
-            ("K04B", "保全区域.琵琶湖区域"),
-            ("K04E", "保全区域.越前海岸区域"),
-            ("K04F", "保全区域.藤原飛鳥区域"),
-            ("K04HE", "保全区域.平城山の辺区域"),
-            ("K04HO", "保全区域.北摂連山区域"),
-            ("K04HY", "保全区域.氷ノ山区域"),
-            ("K04IS", "保全区域.伊勢志摩区域"),
-            ("K04IZ", "保全区域.和泉葛城区域"),
-            ("K04K", "保全区域.京都区域"),
-            ("K04KA", "保全区域.枯木灘海岸区域"),
-            ("K04KON", "保全区域.金剛生駒区域"),
-            ("K04KOY", "保全区域.高野竜神区域"),
-            ("K04R", "保全区域.六甲区域"),
-            ("K04SA", "保全区域.山陰海岸区域"),
-            ("K04SE", "保全区域.瀬戸内海区域"),
-            ("K04SH", "保全区域.白浜田辺区域"),
-            ("K04SU", "保全区域.鈴鹿区域"),
-            ("K04W", "保全区域.若狭湾区域"),
-            ("K04YA", "保全区域.矢田斑鳩区域"),
-            ("K04YO", "保全区域.吉野熊野区域"),
-            ("K05H", "近郊緑地保全区域.北摂連山近郊緑地保全区域"),
-            ("K05I", "近郊緑地保全区域.和泉葛城近郊緑地保全区域"),
-            ("K05KO", "近郊緑地保全区域.金剛生駒近郊緑地保全区域"),
-            ("K05KY", "近郊緑地保全区域.京都近郊緑地保全区域"),
-            ("K05R", "近郊緑地保全区域.六甲近郊緑地保全区域"),
-            ("K05Y", "近郊緑地保全区域.矢田斑鳩近郊緑地保全区域"),
-            ("K06B", "近郊緑地特別保全地区.坊主山近郊緑地特別保全地区"),
-            ("K06E", "近郊緑地特別保全地区.会下山近郊緑地特別保全地区"),
-            ("K06HB", "近郊緑地特別保全地区.鉢伏山近郊緑地特別保全地区"),
-            ("K06HS", "近郊緑地特別保全地区.東須磨近郊緑地特別保全地区"),
-            ("K06K", "近郊緑地特別保全地区.鎌倉峡近郊緑地特別保全地区"),
-            ("K06KK", "近郊緑地特別保全地区.剱谷・苦楽園近郊緑地特別保全地区"),
-            ("K06KN", "近郊緑地特別保全地区.北中山近郊緑地特別保全地区"),
-            ("K06KS", "近郊緑地特別保全地区.小塩山近郊緑地特別保全地区"),
-            ("K06MS", "近郊緑地特別保全地区.摩耶・諏訪山近郊緑地特別保全地区"),
-            ("K06NS", "近郊緑地特別保全地区.西須磨近郊緑地特別保全地区"),
-            ("K06RN", "近郊緑地特別保全地区.良元・生瀬近郊緑地特別保全地区"),
-            ("K06S", "近郊緑地特別保全地区.千刈近郊緑地特別保全地区"),
-            ("K06T", "近郊緑地特別保全地区.高取近郊緑地特別保全地区"),
-            ("K06TT", "近郊緑地特別保全地区.帝釈丹生山近郊緑地特別保全地区"),
-            ("K06U", "近郊緑地特別保全地区.打越山近郊緑地特別保全地区"),
-            ("K06Y", "近郊緑地特別保全地区.善峰寺近郊緑地特別保全地区"),
-            ("S01", "既成市街地"),
-            ("S02C", "近郊整備区域.千葉県"),
-            ("S02I", "近郊整備区域.茨城県"),
-            ("S02K", "近郊整備区域.神奈川県"),
-            ("S02S", "近郊整備区域.埼玉県"),
-            ("S02T", "近郊整備区域.東京都"),
-            ("S03C", "都市開発区域.秩父地区"),
-            ("S03H", "都市開発区域.本庄地区"),
-            ("S03I", "都市開発区域.石岡地区"),
-            ("S03K", "都市開発区域.桐生地区"),
-            ("S03KA", "都市開発区域.鹿島地区"),
-            ("S03KF", "都市開発区域.熊谷・深谷地区"),
-            ("S03KO", "都市開発区域.甲府地区"),
-            ("S03KS", "都市開発区域.古河・総和地区"),
-            ("S03MH", "都市開発区域.水戸・日立地区"),
-            ("S03MT", "都市開発区域.前橋・高崎地区"),
-            ("S03O", "都市開発区域.大田原地区"),
-            ("S03OT", "都市開発区域.太田・館林地区"),
-            ("S03OY", "都市開発区域.小山地区"),
-            ("S03SA", "都市開発区域.佐野・足利地区"),
-            ("S03SU", "都市開発区域.下館・結城地区"),
-            ("S03TA", "都市開発区域.土浦・阿見地区"),
-            ("S03TO", "都市開発区域.栃木地区"),
-            ("S03TU", "都市開発区域.筑波地区"),
-            ("S03U", "都市開発区域.宇都宮地区"),
-            ("S04A", "近郊緑地保全区域.荒川近郊緑地保全区域"),
-            ("S04AG", "近郊緑地保全区域.安行近郊緑地保全区域"),
-            ("S04EK", "近郊緑地保全区域.円海山・北鎌倉近郊緑地保全区域"),
-            ("S04G", "近郊緑地保全区域.行徳近郊緑地保全区域"),
-            ("S04H", "近郊緑地保全区域.平林寺近郊緑地保全区域"),
-            ("S04HC", "近郊緑地保全区域.東千葉近郊緑地保全区域"),
-            ("S04I", "近郊緑地保全区域.入間近郊緑地保全区域"),
-            ("S04K", "近郊緑地保全区域.君津近郊緑地保全区域"),
-            ("S04KI", "近郊緑地保全区域.剣崎・岩堂山近郊緑地保全区域"),
-            ("S04KO", "近郊緑地保全区域.衣笠・大楠山近郊緑地保全区域"),
-            ("S04S", "近郊緑地保全区域.相模原近郊緑地保全区域"),
-            ("S04SY", "近郊緑地保全区域.狭山近郊緑地保全区域"),
-            ("S04T", "近郊緑地保全区域.武山近郊緑地保全区域"),
-            ("S04TK", "近郊緑地保全区域.多磨丘陵北部近郊緑地保全区域"),
-            ("S04TS", "近郊緑地保全区域.利根川・菅生沼近郊緑地保全区域"),
-            ("S04TY", "近郊緑地保全区域.滝山近郊緑地保全区域"),
-            ("S04U", "近郊緑地保全区域.牛久沼近郊緑地保全区域"),
-            ("S04ZH", "近郊緑地保全区域.逗子・葉山近郊緑地保全区域"),
-            ("S05E", "近郊緑地特別保全地区.円海山近郊緑地特別保全地区"),
-            ("S05G", "近郊緑地特別保全地区.行徳近郊緑地特別保全地区"),
-            ("S05H", "近郊緑地特別保全地区.平林寺近郊緑地特別保全地区"),
-            ("S05HC", "近郊緑地特別保全地区.東千葉近郊緑地特別保全地区"),
-            ("S05KO", "近郊緑地特別保全地区.衣笠・大楠山近郊緑地特別保全地区"),
-            ("S05S", "近郊緑地特別保全地区.相模原近郊緑地特別保全地区"),
-            ("S05SO", "近郊緑地特別保全地区.三ヶ岡山近郊緑地特別保全地区"),
-            ("S05SS", "近郊緑地特別保全地区.相模横山・相模川近郊緑地特別保全地区"),
-            ("S05T", "近郊緑地特別保全地区.武山近郊緑地特別保全地区"),
-        ]
-        .into_iter()
-        .collect()
-    });
-
-#[rustfmt::skip]
-pub(crate) static A03_SECTION_TYPE_CD_KINKI: LazyLock<HashMap<&'static str, &'static str>> =
-    LazyLock::new(|| {
-        [
-            ("0",	"無指定"),
-            ("1",	"既成都市区域"),
-            ("2",	"近郊整備区域"),
-            ("3",	"都市開発区域"),
-            ("4",	"保全区域（［近郊緑地保全区域］との重複無し）"),
-            ("5",	"近郊緑地保全区域（全て［保全区域］内）"),
-            ("6",	"近郊緑地特別保全地区（全て［近郊緑地保全区域］）"),
-        ]
-        .into_iter()
-        .collect()
-    });
-
-#[rustfmt::skip]
-pub(crate) static A03_SECTION_TYPE_CD_CYUBU: LazyLock<HashMap<&'static str, &'static str>> =
-    LazyLock::new(|| {
-        [
-            ("0",	"無指定"),
-            ("1",	"都市整備区域(［保全区域］との重複無し"),
-            ("2",	"都市開発区域"),
-            ("3",	"保全区域（［都市整備区域］との重複無し"),
-            ("4",	"保全区域、都市整備区域との重複区域"),
-        ]
-        .into_iter()
-        .collect()
-    });
-
-#[rustfmt::skip]
-pub(crate) static A03_SECTION_TYPE_CD_SYUTO: LazyLock<HashMap<&'static str, &'static str>> =
-    LazyLock::new(|| {
-        [
-            ("0",	"無指定"),
-            ("1",	"既成市街地"),
-            ("2",	"近郊整備地帯（[近郊緑地保全区域]との複合無し）"),
-            ("3",	"都市開発区域"),
-            ("4",	"近郊緑地保全区域（全て［近郊整備地内］内）"),
-            ("5",	"近郊緑地特別保全地域(全て［近郊緑地保全区域］内)"),
-        ]
-        .into_iter()
-        .collect()
-    });
-
-#[rustfmt::skip]
-pub(crate) static UNDER_CONSTRUCTION: LazyLock<HashMap<&'static str, &'static str>> =
-    LazyLock::new(|| {
-        [
-            ("1", "既設"),
-            ("2", "工事中（建設中）"),
-            ("3", "着工準備中"),
-            ("4", "転換工事中"),
-        ]
-        .into_iter()
-        .collect()
-    });
-
-#[rustfmt::skip]
-pub(crate) static UNDERSEA: LazyLock<HashMap<&'static str, &'static str>> =
-    LazyLock::new(|| {
-        [
-            ("0", "その他"),
-            ("5", "海面下"),
-            ("unknown", "NA"),
-        ]
-        .into_iter()
-        .collect()
-    });
-
-#[rustfmt::skip]
-pub(crate) static URBAN_PLANNING_DECIDED: LazyLock<HashMap<&'static str, &'static str>> =
-    LazyLock::new(|| {
-        [
-            ("0", "未確認"),
-            ("1", "決定"),
-            ("2", "未決定"),
-        ]
-        .into_iter()
-        .collect()
-    });
-
-#[rustfmt::skip]
-pub(crate) static UrbanPlanningAreaCd_2019: LazyLock<HashMap<&'static str, &'static str>> =
-    LazyLock::new(|| {
-        [
-            ("1", "市街化区域"),
-            ("2", "市街化調整区域"),
-            ("3", "その他用途地域"),
-            ("4", "用途未設定"),
-        ]
-        .into_iter()
-        .collect()
-    });
-
-#[rustfmt::skip]
-pub(crate) static URGENT_ROAD_CD: LazyLock<HashMap<&'static str, &'static str>> =
-    LazyLock::new(|| {
-        [
-            ("1", "第１次緊急輸送道路"),
-            ("2", "第２次緊急輸送道路"),
-            ("3", "第３次緊急輸送道路"),
-            ("9", "未指定"),
-        ]
-        .into_iter()
-        .collect()
-    });
-
-#[rustfmt::skip]
-pub(crate) static USE_DISTRICT: LazyLock<HashMap<&'static str, &'static str>> =
-    LazyLock::new(|| {
-        [
-            ("1", "工業専用地域"),
-            ("2", "工業地域"),
-            ("3", "準工業地域"),
-            ("4", "その他の用途地域"),
-            ("9", "用途地域該当なし"),
-        ]
-        .into_iter()
-        .collect()
-    });
-
-#[rustfmt::skip]
-pub(crate) static UseDistrictCd: LazyLock<HashMap<&'static str, &'static str>> =
-    LazyLock::new(|| {
-        [
-            ("1", "第一種低層住居専用地域"),
-            ("2", "第二種低層住居専用地域"),
-            ("3", "第一種中高層住居専用地域"),
-            ("4", "第二種中高層住居専用地域"),
-            ("5", "第一種住居地域"),
-            ("6", "第二種住居地域"),
-            ("7", "準住居地域"),
-            ("8", "近隣商業地域"),
-            ("9", "商業地域"),
-            ("10", "準工業地域"),
-            ("11", "工業地域"),
-            ("12", "工業専用地域"),
-            ("21", "田園住居地域"),
-            ("99", "不明"),
-        ]
-        .into_iter()
-        .collect()
-    });
-
-#[rustfmt::skip]
-pub(crate) static WATER_DEPTH_CODE: LazyLock<HashMap<&'static str, &'static str>> =
-    LazyLock::new(|| {
-        [
-            ("1", "0m以上0.5m未満"),
-            ("2", "0.5m以上3.0m未満"),
-            ("3", "3.0m以上5.0m未満"),
-            ("4", "5.0m以上10.0m未満"),
-            ("5", "10.0m以上20.0m未満"),
-            ("6", "20.0m以上"),
-        ]
-        .into_iter()
-        .collect()
-    });
-
-#[rustfmt::skip]
-pub(crate) static WATER_SUPPLY_TYPE: LazyLock<HashMap<&'static str, &'static str>> =
-    LazyLock::new(|| {
-        [
-            ("1", "上水道"),
-            ("2", "簡易水道（公営）"),
-            ("3", "簡易水道（非公営）"),
-        ]
-        .into_iter()
-        .collect()
-    });
+#[cfg(feature = "dict-policy")]
+#[rustfmt::skip]
+pub(crate) static A03_SECTION_CD: Codelist = Codelist::new("A03_SECTION_CD", &[
+    ("C01", "都市整備区域"),
+    ("C02B", "都市開発区域.琵琶湖東北部区域"),
+    ("C02E", "都市開発区域.遠州区域"),
+    ("C02FS", "都市開発区域.福井・坂井区域"),
+    ("C02G", "都市開発区域.岐阜区域"),
+    ("C02H", "都市開発区域.東三河区域"),
+    ("C02HS", "都市開発区域.東駿河湾区域"),
+    ("C02ID", "都市開発区域.伊那谷区域"),
+    ("C02IS", "都市開発区域.伊勢区域"),
+    ("C02KK", "都市開発区域.金沢・小松区域"),
+    ("C02NS", "都市開発区域.西駿河湾区域"),
+    ("C02NU", "都市開発区域.長野・上田区域"),
+    ("C02T", "都市開発区域.高山区域"),
+    ("C02TT", "都市開発区域.富山・高岡区域"),
+    ("C03AA", "保全区域.赤目青山香肌峡区域"),
+    ("C03AK", "保全区域.愛知高原区域"),
+    ("C03CA", "保全区域.中央アルプス区域"),
+    ("C03CS", "保全区域.中部山岳区域"),
+    ("C03EK", "保全区域.越前加賀海岸区域"),
+    ("C03FI", "保全区域.富士伊豆区域"),
+    ("C03H", "保全区域.浜名湖区域"),
+    ("C03HK", "保全区域.飛騨木曽川区域"),
+    ("C03HS", "保全区域.白山区域"),
+    ("C03II", "保全区域.揖斐伊吹区域"),
+    ("C03IS", "保全区域.伊勢志摩区域"),
+    ("C03JK", "保全区域.上信越高原区域"),
+    ("C03MA", "保全区域.南アルプス区域"),
+    ("C03MW", "保全区域.三河湾区域"),
+    ("C03NH", "保全区域.能登半島区域"),
+    ("C03S", "保全区域.鈴鹿区域"),
+    ("C03TO", "保全区域.天竜奥三河区域"),
+    ("C03YC", "保全区域.八ヶ岳中信高原区域"),
+    ("H06H", "近郊緑地特別保全地区.ひよどりごえ近郊緑地特別保全地区"),
+    ("K01", "既成都市区域"),
+    ("K02H", "近郊整備区域.兵庫地区"),
+    ("K02K", "近郊整備区域.京都地区"),
+    ("K02N", "近郊整備区域.奈良地区"),
+    ("K02O", "近郊整備区域.大阪地区"),
+    ("K03B", "都市開発区域.播磨区域"),
+    ("K03BT", "都市開発区域.琵琶湖東部区域"),
+    ("K03F", "都市開発区域.福井敦賀区域"),
+    ("K03I", "都市開発区域.伊賀区域"),
+    ("K03K", "都市開発区域.京都中丹区域"),
+    ("K03W", "都市開発区域.和歌山区域"),
+    ("K04A", "保全区域.赤目室生月瀬区域"),
+    ("K04B", "保全区域.琵琶湖区域"),
+    ("K04E", "保全区域.越前海岸区域"),
+    ("K04F", "保全区域.藤原飛鳥区域"),
+    ("K04HE", "保全区域.平城山の辺区域"),
+    ("K04HO", "保全区域.北摂連山区域"),
+    ("K04HY", "保全区域.氷ノ山区域"),
+    ("K04IS", "保全区域.伊勢志摩区域"),
+    ("K04IZ", "保全区域.和泉葛城区域"),
+    ("K04K", "保全区域.京都区域"),
+    ("K04KA", "保全区域.枯木灘海岸区域"),
+    ("K04KON", "保全区域.金剛生駒区域"),
+    ("K04KOY", "保全区域.高野竜神区域"),
+    ("K04R", "保全区域.六甲区域"),
+    ("K04SA", "保全区域.山陰海岸区域"),
+    ("K04SE", "保全区域.瀬戸内海区域"),
+    ("K04SH", "保全区域.白浜田辺区域"),
+    ("K04SU", "保全区域.鈴鹿区域"),
+    ("K04W", "保全区域.若狭湾区域"),
+    ("K04YA", "保全区域.矢田斑鳩区域"),
+    ("K04YO", "保全区域.吉野熊野区域"),
+    ("K05H", "近郊緑地保全区域.北摂連山近郊緑地保全区域"),
+    ("K05I", "近郊緑地保全区域.和泉葛城近郊緑地保全区域"),
+    ("K05KO", "近郊緑地保全区域.金剛生駒近郊緑地保全区域"),
+    ("K05KY", "近郊緑地保全区域.京都近郊緑地保全区域"),
+    ("K05R", "近郊緑地保全区域.六甲近郊緑地保全区域"),
+    ("K05Y", "近郊緑地保全区域.矢田斑鳩近郊緑地保全区域"),
+    ("K06B", "近郊緑地特別保全地区.坊主山近郊緑地特別保全地区"),
+    ("K06E", "近郊緑地特別保全地区.会下山近郊緑地特別保全地区"),
+    ("K06HB", "近郊緑地特別保全地区.鉢伏山近郊緑地特別保全地区"),
+    ("K06HS", "近郊緑地特別保全地区.東須磨近郊緑地特別保全地区"),
+    ("K06K", "近郊緑地特別保全地区.鎌倉峡近郊緑地特別保全地区"),
+    ("K06KK", "近郊緑地特別保全地区.剱谷・苦楽園近郊緑地特別保全地区"),
+    ("K06KN", "近郊緑地特別保全地区.北中山近郊緑地特別保全地区"),
+    ("K06KS", "近郊緑地特別保全地区.小塩山近郊緑地特別保全地区"),
+    ("K06MS", "近郊緑地特別保全地区.摩耶・諏訪山近郊緑地特別保全地区"),
+    ("K06NS", "近郊緑地特別保全地区.西須磨近郊緑地特別保全地区"),
+    ("K06RN", "近郊緑地特別保全地区.良元・生瀬近郊緑地特別保全地区"),
+    ("K06S", "近郊緑地特別保全地区.千刈近郊緑地特別保全地区"),
+    ("K06T", "近郊緑地特別保全地区.高取近郊緑地特別保全地区"),
+    ("K06TT", "近郊緑地特別保全地区.帝釈丹生山近郊緑地特別保全地区"),
+    ("K06U", "近郊緑地特別保全地区.打越山近郊緑地特別保全地区"),
+    ("K06Y", "近郊緑地特別保全地区.善峰寺近郊緑地特別保全地区"),
+    ("S01", "既成市街地"),
+    ("S02C", "近郊整備区域.千葉県"),
+    ("S02I", "近郊整備区域.茨城県"),
+    ("S02K", "近郊整備区域.神奈川県"),
+    ("S02S", "近郊整備区域.埼玉県"),
+    ("S02T", "近郊整備区域.東京都"),
+    ("S03C", "都市開発区域.秩父地区"),
+    ("S03H", "都市開発区域.本庄地区"),
+    ("S03I", "都市開発区域.石岡地区"),
+    ("S03K", "都市開発区域.桐生地区"),
+    ("S03KA", "都市開発区域.鹿島地区"),
+    ("S03KF", "都市開発区域.熊谷・深谷地区"),
+    ("S03KO", "都市開発区域.甲府地区"),
+    ("S03KS", "都市開発区域.古河・総和地区"),
+    ("S03MH", "都市開発区域.水戸・日立地区"),
+    ("S03MT", "都市開発区域.前橋・高崎地区"),
+    ("S03O", "都市開発区域.大田原地区"),
+    ("S03OT", "都市開発区域.太田・館林地区"),
+    ("S03OY", "都市開発区域.小山地区"),
+    ("S03SA", "都市開発区域.佐野・足利地区"),
+    ("S03SU", "都市開発区域.下館・結城地区"),
+    ("S03TA", "都市開発区域.土浦・阿見地区"),
+    ("S03TO", "都市開発区域.栃木地区"),
+    ("S03TU", "都市開発区域.筑波地区"),
+    ("S03U", "都市開発区域.宇都宮地区"),
+    ("S04A", "近郊緑地保全区域.荒川近郊緑地保全区域"),
+    ("S04AG", "近郊緑地保全区域.安行近郊緑地保全区域"),
+    ("S04EK", "近郊緑地保全区域.円海山・北鎌倉近郊緑地保全区域"),
+    ("S04G", "近郊緑地保全区域.行徳近郊緑地保全区域"),
+    ("S04H", "近郊緑地保全区域.平林寺近郊緑地保全区域"),
+    ("S04HC", "近郊緑地保全区域.東千葉近郊緑地保全区域"),
+    ("S04I", "近郊緑地保全区域.入間近郊緑地保全区域"),
+    ("S04K", "近郊緑地保全区域.君津近郊緑地保全区域"),
+    ("S04KI", "近郊緑地保全区域.剣崎・岩堂山近郊緑地保全区域"),
+    ("S04KO", "近郊緑地保全区域.衣笠・大楠山近郊緑地保全区域"),
+    ("S04S", "近郊緑地保全区域.相模原近郊緑地保全区域"),
+    ("S04SY", "近郊緑地保全区域.狭山近郊緑地保全区域"),
+    ("S04T", "近郊緑地保全区域.武山近郊緑地保全区域"),
+    ("S04TK", "近郊緑地保全区域.多磨丘陵北部近郊緑地保全区域"),
+    ("S04TS", "近郊緑地保全区域.利根川・菅生沼近郊緑地保全区域"),
+    ("S04TY", "近郊緑地保全区域.滝山近郊緑地保全区域"),
+    ("S04U", "近郊緑地保全区域.牛久沼近郊緑地保全区域"),
+    ("S04ZH", "近郊緑地保全区域.逗子・葉山近郊緑地保全区域"),
+    ("S05E", "近郊緑地特別保全地区.円海山近郊緑地特別保全地区"),
+    ("S05G", "近郊緑地特別保全地区.行徳近郊緑地特別保全地区"),
+    ("S05H", "近郊緑地特別保全地区.平林寺近郊緑地特別保全地区"),
+    ("S05HC", "近郊緑地特別保全地区.東千葉近郊緑地特別保全地区"),
+    ("S05KO", "近郊緑地特別保全地区.衣笠・大楠山近郊緑地特別保全地区"),
+    ("S05S", "近郊緑地特別保全地区.相模原近郊緑地特別保全地区"),
+    ("S05SO", "近郊緑地特別保全地区.三ヶ岡山近郊緑地特別保全地区"),
+    ("S05SS", "近郊緑地特別保全地区.相模横山・相模川近郊緑地特別保全地区"),
+    ("S05T", "近郊緑地特別保全地区.武山近郊緑地特別保全地区"),
+]);
+
+#[cfg(feature = "dict-policy")]
+#[rustfmt::skip]
+pub(crate) static A03_SECTION_TYPE_CD_KINKI: Codelist = Codelist::new("A03_SECTION_TYPE_CD_KINKI", &[
+    ("0", "無指定"),
+    ("1", "既成都市区域"),
+    ("2", "近郊整備区域"),
+    ("3", "都市開発区域"),
+    ("4", "保全区域（［近郊緑地保全区域］との重複無し）"),
+    ("5", "近郊緑地保全区域（全て［保全区域］内）"),
+    ("6", "近郊緑地特別保全地区（全て［近郊緑地保全区域］）"),
+]);
+
+#[cfg(feature = "dict-policy")]
+#[rustfmt::skip]
+pub(crate) static A03_SECTION_TYPE_CD_CYUBU: Codelist = Codelist::new("A03_SECTION_TYPE_CD_CYUBU", &[
+    ("0", "無指定"),
+    ("1", "都市整備区域(［保全区域］との重複無し"),
+    ("2", "都市開発区域"),
+    ("3", "保全区域（［都市整備区域］との重複無し"),
+    ("4", "保全区域、都市整備区域との重複区域"),
+]);
+
+#[cfg(feature = "dict-policy")]
+#[rustfmt::skip]
+pub(crate) static A03_SECTION_TYPE_CD_SYUTO: Codelist = Codelist::new("A03_SECTION_TYPE_CD_SYUTO", &[
+    ("0", "無指定"),
+    ("1", "既成市街地"),
+    ("2", "近郊整備地帯（[近郊緑地保全区域]との複合無し）"),
+    ("3", "都市開発区域"),
+    ("4", "近郊緑地保全区域（全て［近郊整備地内］内）"),
+    ("5", "近郊緑地特別保全地域(全て［近郊緑地保全区域］内)"),
+]);
+
+#[cfg(feature = "dict-facility")]
+#[rustfmt::skip]
+pub(crate) static UNDER_CONSTRUCTION: Codelist = Codelist::new("UNDER_CONSTRUCTION", &[
+    ("1", "既設"),
+    ("2", "工事中（建設中）"),
+    ("3", "着工準備中"),
+    ("4", "転換工事中"),
+]);
+
+#[cfg(feature = "dict-land")]
+#[rustfmt::skip]
+pub(crate) static UNDERSEA: Codelist = Codelist::new("UNDERSEA", &[
+    ("0", "その他"),
+    ("5", "海面下"),
+    ("unknown", "NA"),
+]);
+
+#[cfg(feature = "dict-facility")]
+#[rustfmt::skip]
+pub(crate) static URBAN_PLANNING_DECIDED: Codelist = Codelist::new("URBAN_PLANNING_DECIDED", &[
+    ("0", "未確認"),
+    ("1", "決定"),
+    ("2", "未決定"),
+]);
+
+#[rustfmt::skip]
+pub(crate) static UrbanPlanningAreaCd_2019: Codelist = Codelist::new("UrbanPlanningAreaCd_2019", &[
+    ("1", "市街化区域"),
+    ("2", "市街化調整区域"),
+    ("3", "その他用途地域"),
+    ("4", "用途未設定"),
+]);
+
+#[cfg(feature = "dict-transport")]
+#[rustfmt::skip]
+pub(crate) static URGENT_ROAD_CD: Codelist = Codelist::new("URGENT_ROAD_CD", &[
+    ("1", "第１次緊急輸送道路"),
+    ("2", "第２次緊急輸送道路"),
+    ("3", "第３次緊急輸送道路"),
+    ("9", "未指定"),
+]);
+
+#[cfg(feature = "dict-land")]
+#[rustfmt::skip]
+pub(crate) static USE_DISTRICT: Codelist = Codelist::new("USE_DISTRICT", &[
+    ("1", "工業専用地域"),
+    ("2", "工業地域"),
+    ("3", "準工業地域"),
+    ("4", "その他の用途地域"),
+    ("9", "用途地域該当なし"),
+]);
+
+#[rustfmt::skip]
+pub(crate) static UseDistrictCd: Codelist = Codelist::new("UseDistrictCd", &[
+    ("1", "第一種低層住居専用地域"),
+    ("10", "準工業地域"),
+    ("11", "工業地域"),
+    ("12", "工業専用地域"),
+    ("2", "第二種低層住居専用地域"),
+    ("21", "田園住居地域"),
+    ("3", "第一種中高層住居専用地域"),
+    ("4", "第二種中高層住居専用地域"),
+    ("5", "第一種住居地域"),
+    ("6", "第二種住居地域"),
+    ("7", "準住居地域"),
+    ("8", "近隣商業地域"),
+    ("9", "商業地域"),
+    ("99", "不明"),
+]);
+
+#[cfg(feature = "dict-policy")]
+#[rustfmt::skip]
+pub(crate) static WATER_DEPTH_CODE: Codelist = Codelist::new("WATER_DEPTH_CODE", &[
+    ("1", "0m以上0.5m未満"),
+    ("2", "0.5m以上3.0m未満"),
+    ("3", "3.0m以上5.0m未満"),
+    ("4", "5.0m以上10.0m未満"),
+    ("5", "10.0m以上20.0m未満"),
+    ("6", "20.0m以上"),
+]);
+
+#[cfg(feature = "dict-facility")]
+#[rustfmt::skip]
+pub(crate) static WATER_SUPPLY_TYPE: Codelist = Codelist::new("WATER_SUPPLY_TYPE", &[
+    ("1", "上水道"),
+    ("2", "簡易水道（公営）"),
+    ("3", "簡易水道（非公営）"),
+]);
 
 // 水域系コードは、IDなのでそのまま残したいが、一方で日本語のラベルも欲しい。
 // 河川コードなどと違ってこれは別カラムに日本語名が入っていないので、:で両方入れておくことにする
-#[rustfmt::skip]
-pub(crate) static WATER_SYSTEM_CODE_CD: LazyLock<HashMap<&'static str, &'static str>> =
-    LazyLock::new(|| {
-        [
-            ("010002", "010002:声問川"),
-            ("010003", "010003:増幌川"),
-            ("010004", "010004:知来別川"),
-            ("010005", "010005:鬼志別川"),
-            ("010006", "010006:猿骨川"),
-            ("010007", "010007:猿払川"),
-            ("010008", "010008:頓別川"),
-            ("010009", "010009:豊寒別川"),
-            ("010010", "010010:エサシウエンナイ川"),
-            ("010011", "010011:北見幌別川"),
-            ("010012", "010012:徳志別川"),
-            ("010013", "010013:フーレップ川"),
-            ("010014", "010014:音標川"),
-            ("010015", "010015:幌内川"),
-            ("010016", "010016:雄武川"),
-            ("010017", "010017:興部川"),
-            ("010018", "010018:藻興部川"),
-            ("010019", "010019:藻鼈川"),
-            ("010020", "010020:シブノツナイ川"),
-            ("010021", "010021:佐呂間別川"),
-            ("010022", "010022:卯原内川"),
-            ("010023", "010023:車止内川"),
-            ("010024", "010024:藻琴川"),
-            ("010025", "010025:浦士別川"),
-            ("010026", "010026:止別川"),
-            ("010027", "010027:斜里川"),
-            ("010028", "010028:奥蘂別川"),
-            ("010029", "010029:羅臼川"),
-            ("010030", "010030:知西別川"),
-            ("010031", "010031:春苅古丹川"),
-            ("010032", "010032:植別川"),
-            ("010033", "010033:忠類川"),
-            ("010034", "010034:標津川"),
-            ("010035", "010035:春別川"),
-            ("010036", "010036:西別川"),
-            ("010037", "010037:風蓮川"),
-            ("010038", "010038:チョロベツ川"),
-            ("010039", "010039:星ガ浦川"),
-            ("010040", "010040:阿寒川"),
-            ("010041", "010041:庶路川"),
-            ("010042", "010042:茶路川"),
-            ("010043", "010043:和天別川"),
-            ("010044", "010044:音別川"),
-            ("010045", "010045:尺別川"),
-            ("010046", "010046:直別川"),
-            ("010047", "010047:厚内川"),
-            ("010048", "010048:湧洞川"),
-            ("010049", "010049:生花苗川"),
-            ("010050", "010050:当縁川"),
-            ("010051", "010051:歴舟川"),
-            ("010052", "010052:紋別川"),
-            ("010053", "010053:豊似川"),
-            ("010054", "010054:楽古川"),
-            ("010055", "010055:広尾川"),
-            ("010056", "010056:猿留川"),
-            ("010057", "010057:歌別川"),
-            ("010058", "010058:幌満川"),
-            ("010059", "010059:様似川"),
-            ("010060", "010060:海辺川"),
-            ("010061", "010061:日高幌別川"),
-            ("010062", "010062:赤川"),
-            ("010063", "010063:乳呑川"),
-            ("010064", "010064:向別川"),
-            ("010065", "010065:絵笛川"),
-            ("010066", "010066:元浦川"),
-            ("010067", "010067:ケリマイ川"),
-            ("010068", "010068:三石川"),
-            ("010069", "010069:布辻川"),
-            ("010070", "010070:捫別川"),
-            ("010071", "010071:静内川"),
-            ("010072", "010072:真沼津川"),
-            ("010073", "010073:新冠川"),
-            ("010074", "010074:厚別川"),
-            ("010075", "010075:慶能舞川"),
-            ("010076", "010076:波恵川"),
-            ("010077", "010077:日高門別川"),
-            ("010078", "010078:入鹿別川"),
-            ("010079", "010079:厚真川"),
-            ("010080", "010080:安平川"),
-            ("010081", "010081:幌内川"),
-            ("010082", "010082:苫小牧川"),
-            ("010083", "010083:錦多峰川"),
-            ("010084", "010084:別々川"),
-            ("010085", "010085:社台川"),
-            ("010086", "010086:白老川"),
-            ("010087", "010087:敷生川"),
-            ("010088", "010088:ポンアヨロ川"),
-            ("010089", "010089:登別川"),
-            ("010090", "010090:岡志別川"),
-            ("010091", "010091:胆振幌別川"),
-            ("010092", "010092:富岸川"),
-            ("010093", "010093:鷲別川"),
-            ("010094", "010094:チマイベツ川"),
-            ("010095", "010095:気仙川"),
-            ("010096", "010096:シャミチセ川"),
-            ("010097", "010097:気門別川"),
-            ("010098", "010098:長流川"),
-            ("010099", "010099:板谷川"),
-            ("010100", "010100:ベンベ川"),
-            ("010101", "010101:貫気別川"),
-            ("010102", "010102:小鉾岸川"),
-            ("010103", "010103:礼文華川"),
-            ("010104", "010104:長万部川"),
-            ("010105", "010105:紋別川"),
-            ("010106", "010106:国縫川"),
-            ("010107", "010107:遊楽部川"),
-            ("010108", "010108:野田追川"),
-            ("010109", "010109:落部川"),
-            ("010110", "010110:鳥崎川"),
-            ("010111", "010111:茅部中の川"),
-            ("010112", "010112:折戸川"),
-            ("010113", "010113:磯谷川"),
-            ("010114", "010114:大舟川"),
-            ("010115", "010115:矢尻川"),
-            ("010116", "010116:古武井川"),
-            ("010117", "010117:尻岸内川"),
-            ("010118", "010118:原木川"),
-            ("010119", "010119:熊別川"),
-            ("010120", "010120:戸井川"),
-            ("010121", "010121:汐泊川"),
-            ("010122", "010122:松倉川"),
-            ("010123", "010123:亀田川"),
-            ("010124", "010124:常盤川"),
-            ("010125", "010125:久根別川"),
-            ("010126", "010126:大野川"),
-            ("010127", "010127:戸切地川"),
-            ("010128", "010128:流渓川"),
-            ("010129", "010129:茂辺地川"),
-            ("010130", "010130:佐女川"),
-            ("010131", "010131:木古内川"),
-            ("010132", "010132:森越川"),
-            ("010133", "010133:知内川"),
-            ("010134", "010134:福島川"),
-            ("010135", "010135:白符川"),
-            ("010136", "010136:吉岡川"),
-            ("010137", "010137:及部川"),
-            ("010138", "010138:大松前川"),
-            ("010139", "010139:茂草川"),
-            ("010140", "010140:小鴨津川"),
-            ("010141", "010141:大鴨津川"),
-            ("010142", "010142:石崎川"),
-            ("010143", "010143:天野川"),
-            ("010144", "010144:厚沢部川"),
-            ("010145", "010145:姫川"),
-            ("010146", "010146:相沼内川"),
-            ("010147", "010147:見市川"),
-            ("010148", "010148:太櫓川"),
-            ("010149", "010149:馬場川"),
-            ("010150", "010150:千走川"),
-            ("010151", "010151:泊川"),
-            ("010152", "010152:太平川"),
-            ("010153", "010153:折川"),
-            ("010154", "010154:朱太川"),
-            ("010155", "010155:野束川"),
-            ("010156", "010156:堀株川"),
-            ("010157", "010157:玉川"),
-            ("010158", "010158:盃川"),
-            ("010159", "010159:古宇川"),
-            ("010160", "010160:積丹川"),
-            ("010161", "010161:美国川"),
-            ("010162", "010162:古平川"),
-            ("010163", "010163:沖村川"),
-            ("010164", "010164:湯内川"),
-            ("010165", "010165:梅川"),
-            ("010166", "010166:ヌッチ川"),
-            ("010167", "010167:余市川"),
-            ("010168", "010168:登川"),
-            ("010169", "010169:畚部川"),
-            ("010170", "010170:蘭島川"),
-            ("010171", "010171:塩谷川"),
-            ("010172", "010172:勝納川"),
-            ("010173", "010173:朝里川"),
-            ("010174", "010174:星置川"),
-            ("010175", "010175:新川"),
-            ("010176", "010176:旧知津狩川"),
-            ("010177", "010177:正利冠川"),
-            ("010178", "010178:望来川"),
-            ("010179", "010179:厚田川"),
-            ("010180", "010180:濃昼川"),
-            ("010181", "010181:浜益川"),
-            ("010182", "010182:郡別川"),
-            ("010183", "010183:幌川"),
-            ("010184", "010184:岩老川"),
-            ("010185", "010185:暑寒別川"),
-            ("010186", "010186:永寿川"),
-            ("010187", "010187:箸別川"),
-            ("010188", "010188:信砂川"),
-            ("010189", "010189:小平蘂川"),
-            ("010190", "010190:温寧川"),
-            ("010191", "010191:古丹別川"),
-            ("010192", "010192:羽幌川"),
-            ("010193", "010193:築別川"),
-            ("010194", "010194:茂築別川"),
-            ("010195", "010195:セタキナイ川"),
-            ("010196", "010196:初山別川"),
-            ("010197", "010197:茂初山別川"),
-            ("010198", "010198:風連別川"),
-            ("010199", "010199:オタコシベツ川"),
-            ("010200", "010200:遠別川"),
-            ("010201", "010201:ウツツ川"),
-            ("010202", "010202:青苗川"),
-            ("010203", "010203:タネトンナイ川"),
-            ("010204", "010204:大沢川"),
-            ("010205", "010205:チララウスナイ川"),
-            ("010207", "010207:エノシュコマナイ川"),
-            ("010208", "010208:イノコタンポ川"),
-            ("010209", "010209:オタコムシュベツ川"),
-            ("010211", "010211:オンネナイ川"),
-            ("010212", "010212:オビオショップ川"),
-            ("010213", "010213:コムニウシ川"),
-            ("010214", "010214:ハッタリ川"),
-            ("010215", "010215:汐見川"),
-            ("010216", "010216:オクネップ川"),
-            ("010217", "010217:風連別川"),
-            ("010218", "010218:野塚川"),
-            ("010219", "010219:音調津川"),
-            ("010220", "010220:門別川"),
-            ("010221", "010221:カネカリウシナイ川"),
-            ("010222", "010222:鵜苫川"),
-            ("010223", "010223:月寒川"),
-            ("010224", "010224:ウシュッペ川"),
-            ("010225", "010225:有良川"),
-            ("010226", "010226:有勢内川"),
-            ("010227", "010227:神山川"),
-            ("010228", "010228:稲荷川"),
-            ("010229", "010229:小泉の沢川"),
-            ("010230", "010230:小糸魚川"),
-            ("010231", "010231:覚生川"),
-            ("010232", "010232:樽前川"),
-            ("010234", "010234:クッタリウス川"),
-            ("010235", "010235:アヨロ川"),
-            ("010236", "010236:伏古別川"),
-            ("010237", "010237:富浦川"),
-            ("010238", "010238:ヤンケシ川"),
-            ("010240", "010240:本輪西川"),
-            ("010241", "010241:ポロペケレオタ川"),
-            ("010242", "010242:トコタン川"),
-            ("010243", "010243:鹿部川"),
-            ("010244", "010244:垣の島川"),
-            ("010245", "010245:尾札部川"),
-            ("010246", "010246:八木川"),
-            ("010247", "010247:見日川"),
-            ("010248", "010248:ポン木直川"),
-            ("010249", "010249:木直川"),
-            ("010250", "010250:志海苔川"),
-            ("010251", "010251:股瀬川"),
-            ("010252", "010252:白神川"),
-            ("010253", "010253:スズキの沢川"),
-            ("010254", "010254:荒谷川"),
-            ("010255", "010255:大沢川"),
-            ("010256", "010256:小松前川"),
-            ("010257", "010257:唐津内沢川"),
-            ("010258", "010258:大尽内川"),
-            ("010259", "010259:妻内川"),
-            ("010260", "010260:赤神川"),
-            ("010261", "010261:雨竜川"),
-            ("010262", "010262:大澗川"),
-            ("010263", "010263:二越川"),
-            ("010264", "010264:奥末川"),
-            ("010265", "010265:原口川"),
-            ("010266", "010266:小安在川"),
-            ("010267", "010267:大安在川"),
-            ("010268", "010268:緑川"),
-            ("010269", "010269:小茂内川"),
-            ("010270", "010270:突符川"),
-            ("010271", "010271:可笑内川"),
-            ("010272", "010272:花磯川"),
-            ("010273", "010273:貝取澗川"),
-            ("010275", "010275:熊碓川"),
-            ("010276", "010276:毘砂別川"),
-            ("010277", "010277:オネトマナイ川"),
-            ("010278", "010278:勇栄川"),
-            ("010279", "010279:勇知川"),
-            ("010280", "010280:メメナイ川"),
-            ("010281", "010281:抜海１線川"),
-            ("010282", "010282:クトネベツ川"),
-            ("010283", "010283:ウロンナイ川"),
-            ("010284", "010284:シュルコマナイ川"),
-            ("010285", "010285:トベンナイ川"),
-            ("010286", "010286:真言寺川"),
-            ("010287", "010287:オイショマナイ川"),
-            ("010288", "010288:富磯川"),
-            ("010289", "010289:宗谷川"),
-            ("010290", "010290:オンコロマナイ川"),
-            ("010291", "010291:清沢川"),
-            ("010292", "010292:珊内川"),
-            ("010293", "010293:鬼切別川"),
-            ("010294", "010294:知志矢川"),
-            ("010295", "010295:上知志矢川"),
-            ("010296", "010296:泊内川"),
-            ("010297", "010297:目梨川"),
-            ("010298", "010298:時前川"),
-            ("010299", "010299:下苗太路川"),
-            ("010300", "010300:シリナエ川"),
-            ("010301", "010301:ソセツ川"),
-            ("010302", "010302:ウバトマナイ川"),
-            ("010303", "010303:目梨泊川"),
-            ("010304", "010304:オケンタンベ川"),
-            ("010305", "010305:モウケシタンベ川"),
-            ("010306", "010306:落切川"),
-            ("010307", "010307:問牧川"),
-            ("010308", "010308:オキシュナイ川"),
-            ("010309", "010309:親潮川"),
-            ("010310", "010310:栄川"),
-            ("010311", "010311:ポロナイポ川"),
-            ("010312", "010312:トキワ沢川"),
-            ("010313", "010313:モウツ川"),
-            ("010314", "010314:オンネシトシスマリコタン"),
-            ("010315", "010315:岡島川"),
-            ("010316", "010316:黄金川"),
-            ("010317", "010317:オクヤラセナイ川"),
-            ("010318", "010318:オッチャラベツ川"),
-            ("010319", "010319:オタノワカナイ川"),
-            ("010320", "010320:オンネナイ川"),
-            ("010321", "010321:ウプシュナイ川"),
-            ("010322", "010322:ペラシュナイ川"),
-            ("010323", "010323:ミナミペラシュナイ川"),
-            ("010324", "010324:オレタラップ川"),
-            ("010325", "010325:モオレタラップ川"),
-            ("010326", "010326:ニウシナイポ川"),
-            ("010327", "010327:乙忠部川"),
-            ("010328", "010328:オニセップ川"),
-            ("010329", "010329:ベセトコマナイ川"),
-            ("010330", "010330:オニオマナイ川"),
-            ("010331", "010331:シャクネツナイ川"),
-            ("010332", "010332:シルコマナイ川"),
-            ("010333", "010333:オタルベン川"),
-            ("010334", "010334:ラバトナー川"),
-            ("010335", "010335:トイナイ川"),
-            ("010336", "010336:ウエンシテント川"),
-            ("010337", "010337:枝枝幸川"),
-            ("010338", "010338:オキシナイ川"),
-            ("010339", "010339:ポンオムシロナイ川"),
-            ("010340", "010340:フンベオムナイ川"),
-            ("010341", "010341:アチュウシナイ川"),
-            ("010342", "010342:音稲府川"),
-            ("010343", "010343:イウニコタニナイ川"),
-            ("010344", "010344:元稲府川"),
-            ("010347", "010347:ナヨロ川"),
-            ("010348", "010348:オタコムシュペツ川"),
-            ("010349", "010349:シケトチナイ川"),
-            ("010350", "010350:元沢木川"),
-            ("010351", "010351:御西川"),
-            ("010352", "010352:久富川"),
-            ("010353", "010353:ケミチカナイ川"),
-            ("010354", "010354:思沙留川"),
-            ("010355", "010355:一本松３線川"),
-            ("010356", "010356:フンベオマナイ川"),
-            ("010357", "010357:ヤッシュウナイ川"),
-            ("010358", "010358:オンネコムケナイ川"),
-            ("010359", "010359:大島川"),
-            ("010360", "010360:ミサキオンネナイ川"),
-            ("010361", "010361:モセウシナイ川"),
-            ("010362", "010362:ウラレナイ川"),
-            ("010363", "010363:バイラギ川"),
-            ("010364", "010364:ポンバイラギ川"),
-            ("010365", "010365:ピットカリ川"),
-            ("010366", "010366:駒場川"),
-            ("010367", "010367:オショプ川"),
-            ("010368", "010368:勇仁川"),
-            ("010369", "010369:那寄川"),
-            ("010370", "010370:ウエンベツ川"),
-            ("010371", "010371:西一線川"),
-            ("010372", "010372:シマトツカリ川"),
-            ("010373", "010373:糠真布川"),
-            ("010374", "010374:オライネコタン川"),
-            ("010375", "010375:オチカバケ川"),
-            ("010376", "010376:オタモイ川"),
-            ("010377", "010377:オショバオマブ川"),
-            ("010378", "010378:金山川"),
-            ("010379", "010379:チプラノケナイ川"),
-            ("010380", "010380:オンネベツ川"),
-            ("010381", "010381:シャリキ川"),
-            ("010382", "010382:オペケプ川"),
-            ("010383", "010383:チャラッセナイ川"),
-            ("010384", "010384:オショコマナイ川"),
-            ("010385", "010385:フンベ川"),
-            ("010386", "010386:ペレケ川"),
-            ("010387", "010387:ウトロ沢川"),
-            ("010388", "010388:ホロベツ川"),
-            ("010389", "010389:イワウベツ川"),
-            ("010390", "010390:イダシュベツ川"),
-            ("010391", "010391:イロイロ川"),
-            ("010392", "010392:カムイワッカ川"),
-            ("010393", "010393:硫黄川"),
-            ("010394", "010394:ウンメーン川"),
-            ("010395", "010395:ウソシオクベ川"),
-            ("010396", "010396:ウブシノッタ川"),
-            ("010397", "010397:ボンブタ川"),
-            ("010398", "010398:ボンベツ川"),
-            ("010399", "010399:ルシャ川"),
-            ("010400", "010400:テッパンベツ川"),
-            ("010401", "010401:チャカババイ川"),
-            ("010402", "010402:タキノ川"),
-            ("010403", "010403:鮹岩川"),
-            ("010404", "010404:知床川"),
-            ("010405", "010405:ポトピラベツ川"),
-            ("010406", "010406:マムシの川"),
-            ("010407", "010407:オケツチウシ川"),
-            ("010408", "010408:アウンルイ川"),
-            ("010409", "010409:ポロモイ川"),
-            ("010410", "010410:アカイワ川"),
-            ("010411", "010411:メオトタップ川"),
-            ("010412", "010412:メオトタキ川"),
-            ("010413", "010413:タキ川"),
-            ("010414", "010414:ペキン川"),
-            ("010415", "010415:モイレウシ川"),
-            ("010416", "010416:ウナキベツ川"),
-            ("010417", "010417:クズレハマ川"),
-            ("010418", "010418:カモイウンベ川"),
-            ("010419", "010419:アイドマリ川"),
-            ("010420", "010420:オショロッコ川"),
-            ("010421", "010421:ルサ川"),
-            ("010422", "010422:キキリベツ川"),
-            ("010423", "010423:ショウジ川"),
-            ("010424", "010424:ケンネベツ川"),
-            ("010425", "010425:チェンベツ川"),
-            ("010426", "010426:モセカルベツ川"),
-            ("010427", "010427:オッカバケ川"),
-            ("010428", "010428:サシルイ川"),
-            ("010429", "010429:ハシコイ川"),
-            ("010430", "010430:知徒来川"),
-            ("010431", "010431:立仁臼川"),
-            ("010432", "010432:飛仁臼川"),
-            ("010433", "010433:ソスケ川"),
-            ("010434", "010434:松法川"),
-            ("010435", "010435:立苅臼川"),
-            ("010436", "010436:精神川"),
-            ("010437", "010437:ポン春苅古丹川"),
-            ("010438", "010438:ポン幌萌川"),
-            ("010439", "010439:幌萌川"),
-            ("010440", "010440:幌萌小沢川"),
-            ("010441", "010441:茶志別川"),
-            ("010442", "010442:ポン陸志別川"),
-            ("010443", "010443:陸志別川"),
-            ("010444", "010444:居麻布川"),
-            ("010445", "010445:シンコウシ沢川"),
-            ("010446", "010446:鬼尾内川"),
-            ("010447", "010447:元崎無異川"),
-            ("010448", "010448:崎無異川"),
-            ("010449", "010449:薫別川"),
-            ("010450", "010450:古多糠川"),
-            ("010451", "010451:ポウ川"),
-            ("010452", "010452:茶志骨川"),
-            ("010453", "010453:当幌川"),
-            ("010454", "010454:飛雁川"),
-            ("010455", "010455:コムニウシ川"),
-            ("010456", "010456:戸川"),
-            ("010457", "010457:モノトコタン川"),
-            ("010458", "010458:ライトコタン川"),
-            ("010459", "010459:床丹川"),
-            ("010460", "010460:トリサンケベツ川"),
-            ("010461", "010461:第３川"),
-            ("010462", "010462:第４川"),
-            ("010463", "010463:オマンベツ川"),
-            ("010464", "010464:西丸別川"),
-            ("010465", "010465:オンネベツ川"),
-            ("010466", "010466:トフケナイ川"),
-            ("010467", "010467:ホロモシリ川"),
-            ("010468", "010468:第２ホニオイ川"),
-            ("010469", "010469:第１ホニオイ川"),
-            ("010470", "010470:恋問川"),
-            ("010471", "010471:ネムロベツ川"),
-            ("010472", "010472:１番川"),
-            ("010473", "010473:２番川"),
-            ("010474", "010474:コタンケシ川"),
-            ("010475", "010475:四番川"),
-            ("010476", "010476:ノッカマップ川"),
-            ("010477", "010477:オンネップ川"),
-            ("010478", "010478:コネップ川"),
-            ("010479", "010479:サンコタン川"),
-            ("010480", "010480:トーサムポロ川"),
-            ("010481", "010481:オンネモト川"),
-            ("010482", "010482:オンネモト沢川"),
-            ("010483", "010483:第二ゴヨーマイ川"),
-            ("010484", "010484:第一ゴヨーマイ川"),
-            ("010485", "010485:ゴヨーマイ川"),
-            ("010486", "010486:ハボマイ川"),
-            ("010487", "010487:婦羅理川"),
-            ("010488", "010488:ヒキウス川"),
-            ("010489", "010489:沖根辺川"),
-            ("010490", "010490:第一オキネップ川"),
-            ("010491", "010491:沖根婦川"),
-            ("010492", "010492:友知川"),
-            ("010493", "010493:端谷川"),
-            ("010494", "010494:温根沼川"),
-            ("010495", "010495:丹根沼川"),
-            ("010496", "010496:南部沼川"),
-            ("010497", "010497:カツラムイ川"),
-            ("010498", "010498:オッカイベツ川"),
-            ("010499", "010499:第１長節川"),
-            ("010500", "010500:第２長節川"),
-            ("010501", "010501:タキグチ川"),
-            ("010502", "010502:天狗岩川"),
-            ("010503", "010503:ホロニタイ川"),
-            ("010504", "010504:フレシマ川"),
-            ("010505", "010505:チッチャラベツ川"),
-            ("010506", "010506:ガッカラ川"),
-            ("010507", "010507:初田牛川"),
-            ("010508", "010508:和田牛川"),
-            ("010509", "010509:浜和田牛川"),
-            ("010510", "010510:二ツ岩１川"),
-            ("010511", "010511:二ツ岩２川"),
-            ("010512", "010512:二ツ岩３川"),
-            ("010513", "010513:三角台川"),
-            ("010514", "010514:恵茶人東川"),
-            ("010515", "010515:東小沼"),
-            ("010516", "010516:恵茶人川"),
-            ("010517", "010517:貰人川"),
-            ("010518", "010518:仙鳳跡川"),
-            ("010519", "010519:赤泊川"),
-            ("010520", "010520:羨古丹川"),
-            ("010521", "010521:奔幌戸川"),
-            ("010522", "010522:幌戸川"),
-            ("010523", "010523:幌戸東川"),
-            ("010524", "010524:後静川"),
-            ("010525", "010525:榊川"),
-            ("010526", "010526:暮帰別川"),
-            ("010527", "010527:新川"),
-            ("010528", "010528:琵琶瀬川"),
-            ("010529", "010529:渡散布川"),
-            ("010530", "010530:養老散布川"),
-            ("010531", "010531:火散布川"),
-            ("010532", "010532:藻散布川"),
-            ("010533", "010533:末広川"),
-            ("010534", "010534:幌万別川"),
-            ("010535", "010535:床潭川"),
-            ("010536", "010536:浮田川"),
-            ("010537", "010537:奔渡川"),
-            ("010538", "010538:イクラウシ川"),
-            ("010539", "010539:東梅川"),
-            ("010540", "010540:東トキタイ川"),
-            ("010541", "010541:トキタイ川"),
-            ("010542", "010542:別寒辺牛川"),
-            ("010544", "010544:別太川"),
-            ("010545", "010545:古番屋川"),
-            ("010546", "010546:別尺泊４号川"),
-            ("010547", "010547:別尺泊３号川"),
-            ("010548", "010548:別尺泊２号川"),
-            ("010549", "010549:別尺泊１号川"),
-            ("010550", "010550:知方学川"),
-            ("010551", "010551:老者舞川"),
-            ("010552", "010552:冬窓床川"),
-            ("010553", "010553:跡永賀川"),
-            ("010554", "010554:浦雲泊川"),
-            ("010555", "010555:十町瀬川"),
-            ("010556", "010556:幌内川"),
-            ("010557", "010557:アチョロベツ川"),
-            ("010558", "010558:宿徳内川"),
-            ("010559", "010559:又飯時沢川"),
-            ("010560", "010560:桂恋川"),
-            ("010561", "010561:ミツウラ川"),
-            ("010562", "010562:ビシャモン川"),
-            ("010563", "010563:イワミ川"),
-            ("010564", "010564:シラカバ川"),
-            ("010565", "010565:オコツ川"),
-            ("010566", "010566:タイヘイ川"),
-            ("010568", "010568:摩周湖"),
-            ("010569", "010569:オンネチカップ川"),
-            ("010570", "010570:シカリカップ川"),
-            ("010571", "010571:オクネップ川"),
-            ("010572", "010572:馬主来川"),
-            ("010573", "010573:チノミ川"),
-            ("010574", "010574:風連別川"),
-            ("010575", "010575:乙部川"),
-            ("010576", "010576:オニオップ川"),
-            ("010577", "010577:チプネオコッペ川"),
-            ("010578", "010578:オコッぺ沢川"),
-            ("010579", "010579:昆布刈石川"),
-            ("010580", "010580:滝の沢川"),
-            ("010582", "010582:コロモト川"),
-            ("010583", "010583:ポンモト川"),
-            ("010584", "010584:モウド川"),
-            ("010585", "010585:チラユウトン川"),
-            ("010586", "010586:生花苗沼川"),
-            ("010587", "010587:ホロカヤントウ川"),
-            ("010588", "010588:アイボシマ川"),
-            ("010589", "010589:小紋別川"),
-            ("010590", "010590:新生川"),
-            ("010591", "010591:美幌川"),
-            ("010592", "010592:オナオベツ川"),
-            ("010593", "010593:ルベシベツ川"),
-            ("010594", "010594:ヒタタヌンケ川"),
-            ("010595", "010595:ピタタヌンケ川"),
-            ("010596", "010596:オニトップ川"),
-            ("010597", "010597:咲梅川"),
-            ("010598", "010598:シトマン川"),
-            ("010599", "010599:千平川"),
-            ("010600", "010600:アアツ川"),
-            ("010601", "010601:在田川"),
-            ("010602", "010602:キスケ川"),
-            ("010604", "010604:エリモ川"),
-            ("010605", "010605:アブラコマ川"),
-            ("010606", "010606:オショロスケ川"),
-            ("010607", "010607:ヤキベツ川"),
-            ("010608", "010608:ウタロ川"),
-            ("010609", "010609:番屋の川"),
-            ("010610", "010610:コロップ川"),
-            ("010611", "010611:シンシケナイ川"),
-            ("010612", "010612:中学校沢川"),
-            ("010613", "010613:シラヌマナイ川"),
-            ("010614", "010614:幌泉川"),
-            ("010615", "010615:ナンブケ川"),
-            ("010616", "010616:ボンサツコツ川"),
-            ("010617", "010617:サツコツ川"),
-            ("010618", "010618:野津内川"),
-            ("010619", "010619:アベヤキ川"),
-            ("010620", "010620:下笛舞川"),
-            ("010621", "010621:笛舞川"),
-            ("010622", "010622:上古丹川"),
-            ("010623", "010623:サワッコ川"),
-            ("010624", "010624:シモチカウラ川"),
-            ("010625", "010625:チヨカップ川"),
-            ("010626", "010626:ニカンベツ川"),
-            ("010627", "010627:ルサキ川"),
-            ("010628", "010628:ルランベツ川"),
-            ("010629", "010629:山中川"),
-            ("010630", "010630:オソスケウス川"),
-            ("010631", "010631:コトニ川"),
-            ("010632", "010632:冬島川"),
-            ("010633", "010633:ポンサヌシベツ川"),
-            ("010634", "010634:ポロサヌシベツ川"),
-            ("010635", "010635:シミチカップ川"),
-            ("010636", "010636:冬似川"),
-            ("010637", "010637:ウロコベツ川"),
-            ("010638", "010638:昌平川"),
-            ("010639", "010639:浜荻伏川"),
-            ("010640", "010640:無名川"),
-            ("010641", "010641:マツザワ川"),
-            ("010642", "010642:鳧川"),
-            ("010643", "010643:オマイ川"),
-            ("010644", "010644:静富川"),
-            ("010645", "010645:姨布川"),
-            ("010646", "010646:ポンニノコシ川"),
-            ("010647", "010647:ニノコシ川"),
-            ("010648", "010648:元静内川"),
-            ("010649", "010649:６マップ川"),
-            ("010650", "010650:真歌川"),
-            ("010651", "010651:浦里川"),
-            ("010652", "010652:節婦川"),
-            ("010653", "010653:大節婦川"),
-            ("010654", "010654:コマチップ川"),
-            ("010655", "010655:玉水川"),
-            ("010656", "010656:賀張川"),
-            ("010657", "010657:ビラウトリ川"),
-            ("010658", "010658:旧苫小牧川"),
-            ("010659", "010659:ポン小糸魚川"),
-            ("010660", "010660:フシコベツ川"),
-            ("010661", "010661:ポンアヨロ川"),
-            ("010662", "010662:倶多楽湖"),
-            ("010663", "010663:橘湖"),
-            ("010664", "010664:絵靹川"),
-            ("010665", "010665:小橋内川"),
-            ("010666", "010666:南高平川"),
-            ("010667", "010667:中幌萌川"),
-            ("010668", "010668:幌萌川"),
-            ("010669", "010669:ポンペケレオタ川"),
-            ("010670", "010670:元室蘭川"),
-            ("010671", "010671:北黄金川"),
-            ("010672", "010672:牛舎川"),
-            ("010673", "010673:高砂川"),
-            ("010674", "010674:岩根川"),
-            ("010675", "010675:学田川"),
-            ("010676", "010676:古川"),
-            ("010677", "010677:谷藤川"),
-            ("010678", "010678:農舎川"),
-            ("010679", "010679:若生川"),
-            ("010680", "010680:東有珠川"),
-            ("010681", "010681:有珠川"),
-            ("010682", "010682:赤川"),
-            ("010683", "010683:フレナイ川"),
-            ("010684", "010684:アクシナイ川"),
-            ("010685", "010685:チヤス川"),
-            ("010686", "010686:コボロ川"),
-            ("010687", "010687:静狩川"),
-            ("010688", "010688:原野川"),
-            ("010689", "010689:オタモイ川"),
-            ("010690", "010690:ミナミサカエ川"),
-            ("010691", "010691:中の川"),
-            ("010692", "010692:中の沢川"),
-            ("010693", "010693:ワルイ川"),
-            ("010694", "010694:ボンワルイ川"),
-            ("010695", "010695:前島川"),
-            ("010696", "010696:茂国縫川"),
-            ("010697", "010697:ポンポロナイ川"),
-            ("010698", "010698:幌内川"),
-            ("010699", "010699:ルコツ川"),
-            ("010700", "010700:シラリカ川"),
-            ("010701", "010701:山崎川"),
-            ("010702", "010702:早瀬川"),
-            ("010703", "010703:ブイタウシナイ川"),
-            ("010704", "010704:ハシノスベツ川"),
-            ("010705", "010705:熱田川"),
-            ("010706", "010706:ポン奥津内川"),
-            ("010707", "010707:奥津内川"),
-            ("010708", "010708:酒屋川"),
-            ("010709", "010709:境川"),
-            ("010710", "010710:弥之助沢川"),
-            ("010711", "010711:茂無部川"),
-            ("010712", "010712:本内川"),
-            ("010713", "010713:三次郎川"),
-            ("010714", "010714:石倉川"),
-            ("010715", "010715:石川の沢川"),
-            ("010716", "010716:濁川"),
-            ("010717", "010717:地蔵川"),
-            ("010718", "010718:大江川"),
-            ("010719", "010719:蛯谷川"),
-            ("010720", "010720:カド川"),
-            ("010721", "010721:桂川"),
-            ("010722", "010722:鷲木川"),
-            ("010723", "010723:万太郎川"),
-            ("010724", "010724:角兵ヱ川"),
-            ("010725", "010725:森川"),
-            ("010726", "010726:尾白内川"),
-            ("010727", "010727:押出沢川"),
-            ("010728", "010728:小石崎川"),
-            ("010729", "010729:テントウ沢川"),
-            ("010730", "010730:梨木沢川"),
-            ("010731", "010731:弥右ヱ門沢川"),
-            ("010732", "010732:馬抛沢川"),
-            ("010733", "010733:イラ沢川"),
-            ("010734", "010734:足割川"),
-            ("010735", "010735:明神川"),
-            ("010736", "010736:鍛治屋川"),
-            ("010737", "010737:中ノ川"),
-            ("010738", "010738:トドメキ川"),
-            ("010739", "010739:尻無川"),
-            ("010740", "010740:押出沢川"),
-            ("010741", "010741:本別川"),
-            ("010742", "010742:亀泊川"),
-            ("010743", "010743:温沢川"),
-            ("010744", "010744:うなぎ川"),
-            ("010745", "010745:ザリ石川"),
-            ("010746", "010746:常路川"),
-            ("010747", "010747:三味線沢川"),
-            ("010748", "010748:ニボシ川"),
-            ("010749", "010749:中の川"),
-            ("010750", "010750:黒羽尻川"),
-            ("010751", "010751:ダイボウ川"),
-            ("010752", "010752:岩戸川"),
-            ("010753", "010753:美呂泊高田川"),
-            ("010754", "010754:美呂泊川"),
-            ("010755", "010755:太夫川"),
-            ("010756", "010756:美呂泊笹口川"),
-            ("010757", "010757:美呂泊佐々木川"),
-            ("010758", "010758:角張川"),
-            ("010759", "010759:大舟鎌田川"),
-            ("010760", "010760:大舟村上川"),
-            ("010761", "010761:入美川"),
-            ("010762", "010762:大舟中村川"),
-            ("010763", "010763:茂佐尻川"),
-            ("010764", "010764:葡萄川"),
-            ("010765", "010765:紅葉川"),
-            ("010766", "010766:入久川"),
-            ("010767", "010767:精進川"),
-            ("010768", "010768:川汲川"),
-            ("010769", "010769:清水川"),
-            ("010770", "010770:川汲コイタ川"),
-            ("010771", "010771:築上川"),
-            ("010772", "010772:マコウ川"),
-            ("010773", "010773:著保内川"),
-            ("010774", "010774:ドウヤマ川"),
-            ("010775", "010775:シマウタ川"),
-            ("010776", "010776:盤の沢川"),
-            ("010777", "010777:木直神社川"),
-            ("010778", "010778:白井川"),
-            ("010779", "010779:大梶川"),
-            ("010780", "010780:オダワラ川"),
-            ("010781", "010781:タテイワ川"),
-            ("010782", "010782:獅子鼻川"),
-            ("010783", "010783:相泊川"),
-            ("010784", "010784:滝の沢川"),
-            ("010785", "010785:銚子川"),
-            ("010786", "010786:八幡川"),
-            ("010787", "010787:番屋川"),
-            ("010788", "010788:乱塔川"),
-            ("010789", "010789:元村川"),
-            ("010790", "010790:岩田川"),
-            ("010791", "010791:温泉川"),
-            ("010792", "010792:滝沢川"),
-            ("010793", "010793:神社川"),
-            ("010794", "010794:イノウエ川"),
-            ("010795", "010795:白浜川"),
-            ("010796", "010796:平島沢川"),
-            ("010797", "010797:中野川"),
-            ("010798", "010798:４号川"),
-            ("010799", "010799:３号川"),
-            ("010800", "010800:２号川"),
-            ("010801", "010801:１号川"),
-            ("010802", "010802:山十沢川"),
-            ("010803", "010803:メノコ川"),
-            ("010804", "010804:サカサ川"),
-            ("010805", "010805:宮の沢川"),
-            ("010806", "010806:寺院川"),
-            ("010807", "010807:豊浦川"),
-            ("010808", "010808:日浦川"),
-            ("010809", "010809:里子川"),
-            ("010810", "010810:ツンタ川"),
-            ("010811", "010811:蛯子川"),
-            ("010812", "010812:蓬内川"),
-            ("010813", "010813:ウエン川"),
-            ("010814", "010814:運賀川"),
-            ("010815", "010815:小安川"),
-            ("010816", "010816:高屋敷川"),
-            ("010817", "010817:抜石川"),
-            ("010818", "010818:谷地町川"),
-            ("010819", "010819:白石川"),
-            ("010820", "010820:中村川"),
-            ("010821", "010821:寺の川"),
-            ("010822", "010822:石崎宮の川"),
-            ("010823", "010823:ムジナ川"),
-            ("010824", "010824:銭亀宮の川"),
-            ("010825", "010825:乳母川"),
-            ("010826", "010826:亀屋川"),
-            ("010827", "010827:セト川"),
-            ("010828", "010828:柾屋川"),
-            ("010829", "010829:根崎宮の川"),
-            ("010830", "010830:弥衛門川"),
-            ("010831", "010831:第一国費排水川"),
-            ("010832", "010832:大和川"),
-            ("010833", "010833:添山古川"),
-            ("010834", "010834:宗山川"),
-            ("010835", "010835:端の川"),
-            ("010836", "010836:下町沢川"),
-            ("010837", "010837:矢不来川"),
-            ("010838", "010838:下矢不来川"),
-            ("010839", "010839:当別川"),
-            ("010840", "010840:大当別川"),
-            ("010841", "010841:石倉野川"),
-            ("010842", "010842:瑞石川"),
-            ("010843", "010843:大釜谷川"),
-            ("010844", "010844:ミヤノサワ川"),
-            ("010845", "010845:ミズクミザワ川"),
-            ("010846", "010846:ハルコマ川"),
-            ("010847", "010847:サラキ川"),
-            ("010848", "010848:亀川"),
-            ("010849", "010849:橋呉川"),
-            ("010850", "010850:ボンクレ川"),
-            ("010851", "010851:幸連川"),
-            ("010852", "010852:大濶川"),
-            ("010853", "010853:蛇内川"),
-            ("010854", "010854:孫七川"),
-            ("010855", "010855:大平川"),
-            ("010856", "010856:八千代川"),
-            ("010857", "010857:建有川"),
-            ("010858", "010858:中の川"),
-            ("010859", "010859:上湯の沢川"),
-            ("010860", "010860:湯の沢川"),
-            ("010861", "010861:中の沢川"),
-            ("010862", "010862:上の沢川"),
-            ("010863", "010863:ツヅラ沢川"),
-            ("010864", "010864:岩部川"),
-            ("010865", "010865:日の出川"),
-            ("010866", "010866:鳥沢川"),
-            ("010867", "010867:浜沢川"),
-            ("010868", "010868:板橋川"),
-            ("010869", "010869:釜谷川"),
-            ("010870", "010870:中の沢川"),
-            ("010871", "010871:寺の沢川"),
-            ("010872", "010872:滝の下川"),
-            ("010873", "010873:ボウズサワ川"),
-            ("010874", "010874:駄内川"),
-            ("010875", "010875:宮歌川"),
-            ("010876", "010876:豊浜川"),
-            ("010877", "010877:吉野川"),
-            ("010878", "010878:松浦川"),
-            ("010879", "010879:ヒジノ下川"),
-            ("010880", "010880:寺沢川"),
-            ("010881", "010881:伝治沢川"),
-            ("010882", "010882:小尽内川"),
-            ("010883", "010883:ユノ沢川"),
-            ("010884", "010884:戸長川"),
-            ("010885", "010885:湯の沢川"),
-            ("010886", "010886:棚石川"),
-            ("010887", "010887:一本木川"),
-            ("010888", "010888:アイ泊川"),
-            ("010889", "010889:長内川"),
-            ("010890", "010890:中央川"),
-            ("010891", "010891:大沢川"),
-            ("010892", "010892:ハマの沢川"),
-            ("010893", "010893:イチヤマの沢川"),
-            ("010894", "010894:サキノサワ川"),
-            ("010895", "010895:寺ノ沢川"),
-            ("010896", "010896:ハッソウ沢川"),
-            ("010897", "010897:扇石の沢川"),
-            ("010898", "010898:滝の沢川"),
-            ("010899", "010899:タンコウノ沢川"),
-            ("010900", "010900:シヤミズの沢川"),
-            ("010901", "010901:コウミョウジの沢"),
-            ("010902", "010902:カイカンの沢川"),
-            ("010903", "010903:寅ノ沢川"),
-            ("010904", "010904:マツノ沢川"),
-            ("010905", "010905:クドウノ沢川"),
-            ("010906", "010906:ハラウタノ沢川"),
-            ("010907", "010907:ドウノ沢川"),
-            ("010908", "010908:上国寺の沢川"),
-            ("010909", "010909:宮ノ沢川"),
-            ("010910", "010910:椴川"),
-            ("010911", "010911:古櫃川"),
-            ("010912", "010912:五勝手川"),
-            ("010913", "010913:陣屋川"),
-            ("010914", "010914:モシリ川"),
-            ("010915", "010915:豊部内川"),
-            ("010916", "010916:泊川"),
-            ("010918", "010918:五厘沢川"),
-            ("010919", "010919:セモナイ川"),
-            ("010920", "010920:ナカザキ川"),
-            ("010921", "010921:タキノシタ川"),
-            ("010922", "010922:三ツ谷川"),
-            ("010923", "010923:琴平川"),
-            ("010924", "010924:シオミ川"),
-            ("010925", "010925:シビウタミツヤ川"),
-            ("010926", "010926:イナリノサワ川"),
-            ("010927", "010927:大岩川"),
-            ("010928", "010928:五小澗沢川"),
-            ("010929", "010929:大澗川"),
-            ("010930", "010930:薬師の川"),
-            ("010931", "010931:冷水川"),
-            ("010932", "010932:大沢川"),
-            ("010933", "010933:人住内川"),
-            ("010934", "010934:平田内川"),
-            ("010935", "010935:逆川"),
-            ("010936", "010936:勢至堂川"),
-            ("010937", "010937:朧川"),
-            ("010938", "010938:有明川"),
-            ("010939", "010939:鳴神川"),
-            ("010940", "010940:平井の川"),
-            ("010941", "010941:関内川"),
-            ("010942", "010942:五十嵐川"),
-            ("010943", "010943:高橋の沢川"),
-            ("010944", "010944:神の沢川"),
-            ("010945", "010945:公民川"),
-            ("010946", "010946:高畑の沢川"),
-            ("010947", "010947:越前川"),
-            ("010948", "010948:山川"),
-            ("010949", "010949:荷菱内川"),
-            ("010950", "010950:横澗川"),
-            ("010951", "010951:三上の沢川"),
-            ("010952", "010952:堤川"),
-            ("010953", "010953:井林川"),
-            ("010954", "010954:田中の沢川"),
-            ("010955", "010955:折戸川"),
-            ("010956", "010956:新折戸川"),
-            ("010957", "010957:勘八川"),
-            ("010958", "010958:岩澗川"),
-            ("010960", "010960:厚谷川"),
-            ("010961", "010961:マスハラ川"),
-            ("010962", "010962:福島の沢川"),
-            ("010963", "010963:弓山川"),
-            ("010965", "010965:三上川"),
-            ("010966", "010966:三新川"),
-            ("010967", "010967:竹内川"),
-            ("010968", "010968:西沢川"),
-            ("010969", "010969:瀬戸川"),
-            ("010970", "010970:シベット川"),
-            ("010971", "010971:湯の尻川"),
-            ("010972", "010972:土谷川"),
-            ("010973", "010973:ババ川"),
-            ("010974", "010974:建岩川"),
-            ("010975", "010975:守山川"),
-            ("010976", "010976:常盤川"),
-            ("010977", "010977:本陣川"),
-            ("010978", "010978:穴久遠川"),
-            ("010979", "010979:乗願寺川"),
-            ("010980", "010980:笠島の沢川"),
-            ("010982", "010982:大島川"),
-            ("010983", "010983:冷水川"),
-            ("010984", "010984:タツチノ川"),
-            ("010985", "010985:富岡川"),
-            ("010986", "010986:万谷川"),
-            ("010987", "010987:藤初川"),
-            ("010988", "010988:須藤の沢川"),
-            ("010989", "010989:相泊川"),
-            ("010991", "010991:日中戸の沢川"),
-            ("010992", "010992:泊川"),
-            ("010993", "010993:水垂の沢川"),
-            ("010994", "010994:漁港の沢川"),
-            ("010995", "010995:鵜泊川"),
-            ("010996", "010996:団地の沢川"),
-            ("010997", "010997:良瑠川"),
-            ("010998", "010998:良瑠石川"),
-            ("010999", "010999:古櫓多川"),
-            ("011000", "011000:鍋川"),
-            ("011001", "011001:水道の沢川"),
-            ("011002", "011002:太櫓中央川"),
-            ("011003", "011003:岬の沢川"),
-            ("011005", "011005:法華寺川"),
-            ("011006", "011006:立岩川"),
-            ("011007", "011007:中歌川"),
-            ("011008", "011008:元浦川"),
-            ("011009", "011009:稲荷川"),
-            ("011010", "011010:虻羅川"),
-            ("011011", "011011:窓岩川"),
-            ("011012", "011012:島歌川"),
-            ("011013", "011013:山崎川"),
-            ("011014", "011014:ミウラ川"),
-            ("011015", "011015:吹込川"),
-            ("011016", "011016:切梶川"),
-            ("011017", "011017:美谷川"),
-            ("011018", "011018:横滝川"),
-            ("011019", "011019:藻岩川"),
-            ("011020", "011020:本間の沢川"),
-            ("011021", "011021:須築川"),
-            ("011022", "011022:床前川"),
-            ("011023", "011023:オコツナイ川"),
-            ("011024", "011024:第二オコツナイ川"),
-            ("011025", "011025:山田の川"),
-            ("011026", "011026:長内川"),
-            ("011027", "011027:小田西川"),
-            ("011028", "011028:ポロ場川"),
-            ("011029", "011029:ポン狩場川"),
-            ("011030", "011030:木巻川"),
-            ("011031", "011031:政泊川"),
-            ("011032", "011032:オタダシ川"),
-            ("011033", "011033:原歌川"),
-            ("011034", "011034:フモンナイ川"),
-            ("011035", "011035:神社の川"),
-            ("011037", "011037:盤の沢川"),
-            ("011038", "011038:厳倉川"),
-            ("011039", "011039:幌内川"),
-            ("011042", "011042:ムジナ川"),
-            ("011043", "011043:サトウの川"),
-            ("011044", "011044:小林の川"),
-            ("011045", "011045:中浜の川"),
-            ("011046", "011046:組合の川"),
-            ("011047", "011047:厚瀬川"),
-            ("011048", "011048:エビナノ川"),
-            ("011049", "011049:福士の川"),
-            ("011050", "011050:コペチャナイ川"),
-            ("011051", "011051:中の川"),
-            ("011052", "011052:ワッカナイ川"),
-            ("011053", "011053:エビザワの川"),
-            ("011055", "011055:富浦川"),
-            ("011056", "011056:砂政泊川"),
-            ("011057", "011057:墓地川"),
-            ("011058", "011058:土谷の川"),
-            ("011059", "011059:岩沢の川"),
-            ("011060", "011060:滝の間川"),
-            ("011061", "011061:柴田の川"),
-            ("011062", "011062:南波の川"),
-            ("011063", "011063:大沢の川"),
-            ("011064", "011064:塚越の川"),
-            ("011065", "011065:山十の川"),
-            ("011066", "011066:大畑の川"),
-            ("011067", "011067:宮沢の川"),
-            ("011068", "011068:神社の川"),
-            ("011069", "011069:イチサンの川"),
-            ("011070", "011070:山本の川"),
-            ("011071", "011071:建岩の川"),
-            ("011072", "011072:高谷の川"),
-            ("011073", "011073:問谷の川"),
-            ("011074", "011074:柴山の川"),
-            ("011075", "011075:寺の川"),
-            ("011076", "011076:潮路の川"),
-            ("011077", "011077:寺の川"),
-            ("011078", "011078:吉田の川"),
-            ("011079", "011079:鐘台の川"),
-            ("011080", "011080:一キの川"),
-            ("011081", "011081:神社の川"),
-            ("011082", "011082:支所の川"),
-            ("011083", "011083:コタンポの川"),
-            ("011084", "011084:ワシリの川"),
-            ("011085", "011085:三〆の川"),
-            ("011086", "011086:山口の川"),
-            ("011087", "011087:中の川"),
-            ("011088", "011088:田中の川"),
-            ("011089", "011089:五郎兵の川"),
-            ("011090", "011090:秋山の川"),
-            ("011091", "011091:福士の川"),
-            ("011092", "011092:川地の川"),
-            ("011093", "011093:大丸の川"),
-            ("011094", "011094:大上の川"),
-            ("011095", "011095:幌別川"),
-            ("011096", "011096:大二の川"),
-            ("011097", "011097:野村の川"),
-            ("011098", "011098:ルウベツナイ川"),
-            ("011099", "011099:大黒ヶ沢の川"),
-            ("011100", "011100:本内の川"),
-            ("011101", "011101:金瀬の川"),
-            ("011102", "011102:田沢の川"),
-            ("011103", "011103:支所の川"),
-            ("011104", "011104:金三の川"),
-            ("011105", "011105:蛯子の川"),
-            ("011106", "011106:精進川"),
-            ("011107", "011107:雷電川"),
-            ("011108", "011108:イセバチ川"),
-            ("011109", "011109:湯内川"),
-            ("011110", "011110:親子別川"),
-            ("011111", "011111:カバソバナイ川"),
-            ("011112", "011112:アイノ川"),
-            ("011113", "011113:雷電川"),
-            ("011114", "011114:当別川"),
-            ("011115", "011115:幌別川"),
-            ("011116", "011116:ニチナイ川"),
-            ("011117", "011117:茶津川"),
-            ("011118", "011118:渋井川"),
-            ("011119", "011119:寺の川"),
-            ("011120", "011120:臼別川"),
-            ("011121", "011121:モヘル川"),
-            ("011122", "011122:糸泊川"),
-            ("011123", "011123:照岸川"),
-            ("011124", "011124:兜川"),
-            ("011125", "011125:滝の下川"),
-            ("011126", "011126:塩越川"),
-            ("011127", "011127:茂岩川"),
-            ("011128", "011128:祈石川"),
-            ("011129", "011129:弁財澗川"),
-            ("011130", "011130:尾根内川"),
-            ("011131", "011131:北田川"),
-            ("011132", "011132:魚谷川"),
-            ("011133", "011133:二の目川"),
-            ("011134", "011134:井戸屋川"),
-            ("011135", "011135:中の滝川"),
-            ("011136", "011136:滝の川"),
-            ("011137", "011137:大森川"),
-            ("011138", "011138:キナウシ川"),
-            ("011139", "011139:珊内川"),
-            ("011140", "011140:オネナイ川"),
-            ("011141", "011141:ノット川"),
-            ("011142", "011142:オブカルイシ川"),
-            ("011143", "011143:シシャモナイ川"),
-            ("011144", "011144:西河原川"),
-            ("011145", "011145:沼前川"),
-            ("011146", "011146:尾根内川"),
-            ("011147", "011147:柾泊川"),
-            ("011148", "011148:草内川"),
-            ("011149", "011149:余別川"),
-            ("011150", "011150:滝の川"),
-            ("011151", "011151:幌内府川"),
-            ("011152", "011152:ゴロタ川"),
-            ("011153", "011153:日司川"),
-            ("011154", "011154:入舸川"),
-            ("011155", "011155:茶津川"),
-            ("011156", "011156:丸山川"),
-            ("011157", "011157:チョペタ川"),
-            ("011158", "011158:歌棄川"),
-            ("011159", "011159:ツルノップ川"),
-            ("011160", "011160:番の沢川"),
-            ("011161", "011161:島泊川"),
-            ("011162", "011162:出足平川"),
-            ("011163", "011163:富沢川"),
-            ("011164", "011164:桃内川"),
-            ("011165", "011165:稲穂川"),
-            ("011166", "011166:祝津川"),
-            ("011167", "011167:牛川"),
-            ("011168", "011168:手宮川"),
-            ("011169", "011169:手宮中川"),
-            ("011170", "011170:色内川"),
-            ("011172", "011172:入舟川"),
-            ("011173", "011173:ワカタケナイ川"),
-            ("011174", "011174:柾里川"),
-            ("011175", "011175:ブドウ川"),
-            ("011176", "011176:カムイ川"),
-            ("011177", "011177:張碓川"),
-            ("011178", "011178:張碓中川"),
-            ("011179", "011179:礼文塚川"),
-            ("011180", "011180:銭函川"),
-            ("011181", "011181:ポンナイ川"),
-            ("011182", "011182:嶺泊川"),
-            ("011183", "011183:古潭川"),
-            ("011184", "011184:菊地の沢川"),
-            ("011185", "011185:八幡沢川"),
-            ("011186", "011186:幌内川"),
-            ("011187", "011187:ヤソスケ川"),
-            ("011188", "011188:北部落沢川"),
-            ("011189", "011189:滝野沢川"),
-            ("011190", "011190:大沢川"),
-            ("011191", "011191:ルーラン川"),
-            ("011192", "011192:太島内川"),
-            ("011193", "011193:送毛川"),
-            ("011194", "011194:茂生川"),
-            ("011195", "011195:本沢川"),
-            ("011196", "011196:新川"),
-            ("011197", "011197:稲荷川"),
-            ("011198", "011198:床丹川"),
-            ("011200", "011200:一の滝川"),
-            ("011201", "011201:二の滝川"),
-            ("011202", "011202:電話下川"),
-            ("011203", "011203:イカゾ川"),
-            ("011204", "011204:オフユ川"),
-            ("011205", "011205:ケマフレ川"),
-            ("011206", "011206:アカイワ川"),
-            ("011207", "011207:マルヒラ川"),
-            ("011208", "011208:クズレ川"),
-            ("011209", "011209:日方泊川"),
-            ("011210", "011210:歩古丹川"),
-            ("011211", "011211:マツカノ川"),
-            ("011212", "011212:大別苅川"),
-            ("011213", "011213:エンルコマナイ川"),
-            ("011214", "011214:ポンナイ川"),
-            ("011215", "011215:ニナイベツ川"),
-            ("011216", "011216:オタルマナイ川"),
-            ("011217", "011217:コチャイナイ川"),
-            ("011218", "011218:稲葉川"),
-            ("011219", "011219:朱文別川"),
-            ("011220", "011220:タントシナイ川"),
-            ("011221", "011221:アツブシラリ川"),
-            ("011222", "011222:セタベツ川"),
-            ("011223", "011223:モタセツ川"),
-            ("011224", "011224:オタルマタセツ川"),
-            ("011225", "011225:アツカルシナイ川"),
-            ("011226", "011226:見晴川"),
-            ("011227", "011227:小室ノ沢川"),
-            ("011228", "011228:ツネカムリ川"),
-            ("011229", "011229:臼谷ノ沢川"),
-            ("011230", "011230:鬼泊川"),
-            ("011231", "011231:大椴子川"),
-            ("011232", "011232:アイノ沢川"),
-            ("011233", "011233:小椴子川"),
-            ("011234", "011234:トチハラ川"),
-            ("011235", "011235:木ノ実川"),
-            ("011236", "011236:湯ノ沢川"),
-            ("011237", "011237:蛇ノ目沢川"),
-            ("011238", "011238:秀浦川"),
-            ("011239", "011239:番谷の沢川"),
-            ("011240", "011240:小種子川"),
-            ("011241", "011241:千松川"),
-            ("011242", "011242:山の大沢川"),
-            ("011243", "011243:茶俊内川"),
-            ("011244", "011244:サカエの沢川"),
-            ("011245", "011245:番谷の沢川"),
-            ("011246", "011246:天谷の沢川"),
-            ("011247", "011247:チシャ川"),
-            ("011248", "011248:ウエンビラ川"),
-            ("011249", "011249:ポロナイ川"),
-            ("011250", "011250:アチャナイ川"),
-            ("011251", "011251:オシルスナイ川"),
-            ("011252", "011252:ヤオシルスナイ川"),
-            ("011253", "011253:オコツナイ川"),
-            ("011254", "011254:カミノタキ川"),
-            ("011255", "011255:出雲川"),
-            ("011256", "011256:ヌップコマナイ川"),
-            ("011257", "011257:ニカリウシナイ川"),
-            ("011258", "011258:ポンニカリウシナイ川"),
-            ("011259", "011259:マスナイ川"),
-            ("011260", "011260:山根川"),
-            ("011261", "011261:ヤキナツイ川"),
-            ("011262", "011262:モセタキナイ川"),
-            ("011263", "011263:栄南川"),
-            ("011264", "011264:井上の沢川"),
-            ("011265", "011265:アカギの沢川"),
-            ("011266", "011266:大曲の沢川"),
-            ("011267", "011267:金駒内川"),
-            ("011268", "011268:第十の沢川"),
-            ("011269", "011269:大樹の沢川"),
-            ("011270", "011270:オヤルフツナイ川"),
-            ("011271", "011271:登駒内川"),
-            ("011272", "011272:遠藤ノ沢川"),
-            ("011273", "011273:モオタコシベツ川"),
-            ("011274", "011274:トマタウシュナイ川"),
-            ("011275", "011275:クマウシュナイ川"),
-            ("011276", "011276:マサロベツ川"),
-            ("011277", "011277:マサリ排水川"),
-            ("011278", "011278:啓明排水川"),
-            ("011279", "011279:パロマウツナイ川"),
-            ("011280", "011280:キピタナイ川"),
-            ("011282", "011282:トコツナイ川"),
-            ("011283", "011283:ヤナメヤチ川"),
-            ("011284", "011284:藻内川"),
-            ("011285", "011285:ホヤ石川"),
-            ("011286", "011286:神威岩川"),
-            ("011287", "011287:清次郎歌川"),
-            ("011288", "011288:神威脇川"),
-            ("011289", "011289:幌内川"),
-            ("011290", "011290:大岩生川"),
-            ("011291", "011291:小岩生川"),
-            ("011292", "011292:ノナ前川"),
-            ("011293", "011293:大谷川"),
-            ("011294", "011294:宮津沢川"),
-            ("011295", "011295:宮津川"),
-            ("011296", "011296:ヤマセ泊川"),
-            ("011297", "011297:桜屋敷川"),
-            ("011300", "011300:仏沢２号川"),
-            ("011301", "011301:仏沢１号川"),
-            ("011302", "011302:塩釜川"),
-            ("011303", "011303:釣懸川"),
-            ("011304", "011304:祖父川"),
-            ("011307", "011307:小島川"),
-            ("011308", "011308:坪谷川"),
-            ("011309", "011309:恩顧歌川"),
-            ("011310", "011310:小倉川"),
-            ("011311", "011311:力沢川"),
-            ("011312", "011312:兄力沢川"),
-            ("011313", "011313:深歌川"),
-            ("011314", "011314:ラント川"),
-            ("011315", "011315:赤川"),
-            ("011316", "011316:新海沢川"),
-            ("011317", "011317:カネシメ沢川"),
-            ("011318", "011318:トビウシナイ川"),
-            ("011319", "011319:西オビヤタンナイ沢川"),
-            ("011320", "011320:オビヤタンナイ川"),
-            ("011321", "011321:沼の沢川"),
-            ("011322", "011322:ポリマナイ川"),
-            ("011323", "011323:三の沢川"),
-            ("011324", "011324:源内沢川"),
-            ("011325", "011325:リヤウシナイ川"),
-            ("011326", "011326:モトリヤウシナイ川"),
-            ("011327", "011327:二の沢川"),
-            ("011328", "011328:一の沢川"),
-            ("011329", "011329:オモベツ川"),
-            ("011330", "011330:ノドツトマリ川"),
-            ("011331", "011331:東ノドツトマリ川"),
-            ("011332", "011332:豊漁沢川"),
-            ("011333", "011333:オチウシナイ川"),
-            ("011334", "011334:キタニチンドマリ川"),
-            ("011335", "011335:ニチンドマリ川"),
-            ("011336", "011336:朝日川"),
-            ("011337", "011337:湧水川"),
-            ("011338", "011338:アフトロマナイ川"),
-            ("011339", "011339:石崎川"),
-            ("011340", "011340:オサツルナイ川"),
-            ("011341", "011341:豊仙沢川"),
-            ("011342", "011342:ヤムナイ沢川"),
-            ("011343", "011343:堺沢川"),
-            ("011344", "011344:滝の沢川"),
-            ("011345", "011345:二又沢川"),
-            ("011346", "011346:メスウショロ川"),
-            ("011347", "011347:ナカヤマ沢川"),
-            ("011348", "011348:元村川"),
-            ("011349", "011349:セパウシ川"),
-            ("011350", "011350:神磯川"),
-            ("011351", "011351:大空川"),
-            ("011352", "011352:久連川"),
-            ("011353", "011353:蘭泊川"),
-            ("011354", "011354:ポロニショ川"),
-            ("011355", "011355:神居川"),
-            ("011356", "011356:ビヤコロ川"),
-            ("011357", "011357:新湊川"),
-            ("011358", "011358:重兵衛川"),
-            ("011359", "011359:大備川"),
-            ("011360", "011360:内路川"),
-            ("011361", "011361:山本川"),
-            ("011362", "011362:トコタン川"),
-            ("011363", "011363:キトウス川"),
-            ("011364", "011364:オバシトロマナイ川"),
-            ("011365", "011365:香深井川"),
-            ("011366", "011366:西沢川"),
-            ("011367", "011367:手然川"),
-            ("011368", "011368:石動川"),
-            ("011369", "011369:津軽川"),
-            ("011370", "011370:竹岡川"),
-            ("011371", "011371:会所川"),
-            ("011372", "011372:トンナイ川"),
-            ("011373", "011373:中村川"),
-            ("011374", "011374:志田川"),
-            ("011375", "011375:知床川"),
-            ("011376", "011376:柳谷川"),
-            ("011377", "011377:元地川"),
-            ("011378", "011378:礼文川"),
-            ("011379", "011379:宇遠内川"),
-            ("011380", "011380:沙留川"),
-            ("011381", "011381:尾幌川"),
-            ("011382", "011382:春採川"),
-            ("011383", "011383:長節川"),
-            ("011384", "011384:フシコベツ川"),
-            ("011385", "011385:知利別川"),
-            ("011386", "011386:重内川"),
-            ("011387", "011387:田沢川"),
-            ("011388", "011388:平田内川"),
-            ("011389", "011389:臼別川"),
-            ("011390", "011390:小川"),
-            ("011391", "011391:上古丹川"),
-            ("011392", "011392:砥歌川"),
-            ("011393", "011393:新甫川"),
-            ("011394", "011394:ホンベツ川"),
-            ("011395", "011395:床丹川"),
-            ("011396", "011396:歌島川"),
-            ("011397", "011397:白水川"),
-            ("011398", "011398:球浦川"),
-            ("011399", "011399:烏頭川"),
-            ("011400", "011400:赤石川"),
-            ("011401", "011401:小田島川"),
-            ("011402", "011402:シラリカップ川"),
-            ("011403", "011403:苫別川"),
-            ("011404", "011404:キリシタナイ川"),
-            ("011405", "011405:オバフ川"),
-            ("011406", "011406:サトオカシベツ川"),
-            ("011407", "011407:第一端の川"),
-            ("011408", "011408:最内川"),
-            ("011409", "011409:於古発川"),
-            ("011410", "011410:千代志別川"),
-            ("011411", "011411:サラキタナイ川"),
-            ("020001", "020001:津梅川"),
-            ("020002", "020002:小峰沢川"),
-            ("020003", "020003:合葉川"),
-            ("020004", "020004:松神川"),
-            ("020005", "020005:濁川"),
-            ("020006", "020006:笹内川"),
-            ("020007", "020007:泥川"),
-            ("020008", "020008:磯崎川"),
-            ("020009", "020009:吾妻川"),
-            ("020010", "020010:追良瀬川"),
-            ("020011", "020011:小童子川"),
-            ("020012", "020012:大童子川"),
-            ("020013", "020013:赤石川"),
-            ("020014", "020014:中村川"),
-            ("020015", "020015:鳴沢川"),
-            ("020016", "020016:磯松川"),
-            ("020017", "020017:小泊川"),
-            ("020018", "020018:元宇鉄川"),
-            ("020019", "020019:算用師川"),
-            ("020020", "020020:増川川"),
-            ("020021", "020021:長川"),
-            ("020022", "020022:今別川"),
-            ("020023", "020023:湯ノ沢川"),
-            ("020024", "020024:蟹田川"),
-            ("020025", "020025:広瀬川"),
-            ("020026", "020026:瀬辺地川"),
-            ("020027", "020027:阿弥陀川"),
-            ("020028", "020028:四戸橋川"),
-            ("020029", "020029:六枚橋川"),
-            ("020030", "020030:内真部川"),
-            ("020031", "020031:奥内川"),
-            ("020032", "020032:瀬戸子川"),
-            ("020033", "020033:天田内川"),
-            ("020034", "020034:新城川"),
-            ("020035", "020035:沖館川"),
-            ("020036", "020036:堤川"),
-            ("020037", "020037:赤川"),
-            ("020038", "020038:沼川"),
-            ("020039", "020039:野内川"),
-            ("020040", "020040:根井川"),
-            ("020041", "020041:浅虫川"),
-            ("020042", "020042:長沢川"),
-            ("020043", "020043:小湊川"),
-            ("020044", "020044:清水川"),
-            ("020045", "020045:堀差川"),
-            ("020046", "020046:野辺地川"),
-            ("020047", "020047:三保川"),
-            ("020048", "020048:桧木川"),
-            ("020049", "020049:鶏沢川"),
-            ("020050", "020050:今泉川"),
-            ("020051", "020051:田名部川"),
-            ("020052", "020052:大荒川"),
-            ("020053", "020053:戸沢川"),
-            ("020054", "020054:川内川"),
-            ("020055", "020055:桧川"),
-            ("020056", "020056:宿野部川"),
-            ("020057", "020057:男川"),
-            ("020058", "020058:脇野沢川"),
-            ("020059", "020059:福浦川"),
-            ("020060", "020060:大佐井川"),
-            ("020061", "020061:古佐井川"),
-            ("020062", "020062:林木川"),
-            ("020063", "020063:奥戸川"),
-            ("020064", "020064:目滝川"),
-            ("020065", "020065:易国間川"),
-            ("020066", "020066:大畑川"),
-            ("020067", "020067:正津川"),
-            ("020068", "020068:出戸川"),
-            ("020069", "020069:野牛川"),
-            ("020070", "020070:老部川"),
-            ("020071", "020071:明神川"),
-            ("020072", "020072:戸鎖川"),
-            ("020073", "020073:二の川"),
-            ("020074", "020074:一の川"),
-            ("020075", "020075:奥入瀬川"),
-            ("020076", "020076:五戸川"),
-            ("020077", "020077:新井田川"),
-            ("020078", "020078:田浦川"),
-            ("020079", "020079:鐇泊川"),
-            ("020080", "020080:元宇鉄川"),
-            ("020081", "020081:間沢川"),
-            ("020082", "020082:板木沢川"),
-            ("020083", "020083:蓬田川"),
-            ("020084", "020084:飛鳥川"),
-            ("020085", "020085:貴船川"),
-            ("020086", "020086:土場川"),
-            ("020087", "020087:馬門川"),
-            ("020088", "020088:近沢川"),
-            ("020089", "020089:千草橋川"),
-            ("020090", "020090:有戸川"),
-            ("020091", "020091:戸沢川"),
-            ("020092", "020092:高野川"),
-            ("020093", "020093:大間川"),
-            ("020094", "020094:小老部川"),
-            ("020095", "020095:黒崎川"),
-            ("020096", "020096:下濁川"),
-            ("020097", "020097:根滝川"),
-            ("020098", "020098:脇野沢川"),
-            ("020099", "020099:風呂の沢"),
-            ("020100", "020100:玉坂沢"),
-            ("020101", "020101:沢辺沢"),
-            ("020102", "020102:中沢川"),
-            ("020103", "020103:広戸沢"),
-            ("020104", "020104:母沢"),
-            ("020105", "020105:小浜舘川"),
-            ("020106", "020106:貝良木沢"),
-            ("020107", "020107:大船沢"),
-            ("020108", "020108:清滝川"),
-            ("020109", "020109:小田沢"),
-            ("020110", "020110:深沢"),
-            ("020111", "020111:栃沢川"),
-            ("020112", "020112:脇元川"),
-            ("020113", "020113:折戸川"),
-            ("020114", "020114:立松沢"),
-            ("020115", "020115:大山長根沢"),
-            ("020116", "020116:上小泊沢"),
-            ("020117", "020117:桧木沢"),
-            ("020118", "020118:梹椰沢"),
-            ("020119", "020119:鳴神沢"),
-            ("020120", "020120:元宇鉄川"),
-            ("020121", "020121:釜野沢"),
-            ("020122", "020122:上藤島川"),
-            ("020123", "020123:三厩沢"),
-            ("020124", "020124:藤島川"),
-            ("020125", "020125:三厩川"),
-            ("020126", "020126:黒崎川"),
-            ("020127", "020127:宇田川"),
-            ("020128", "020128:袰月沢"),
-            ("020129", "020129:砂ヶ森川"),
-            ("020130", "020130:奥平部川"),
-            ("020131", "020131:五所塚沢"),
-            ("020132", "020132:伝藤沢"),
-            ("020133", "020133:宇田沢"),
-            ("020134", "020134:項々川"),
-            ("020135", "020135:石崎沢川"),
-            ("020136", "020136:田の沢川"),
-            ("020137", "020137:寺の沢川"),
-            ("020138", "020138:十和田様川"),
-            ("020139", "020139:玉川"),
-            ("020140", "020140:大野田川"),
-            ("020141", "020141:尻高川"),
-            ("020142", "020142:小湯の沢"),
-            ("020143", "020143:目倉沢"),
-            ("020144", "020144:平家沢"),
-            ("020145", "020145:口広川"),
-            ("020146", "020146:上塩越川"),
-            ("020147", "020147:塩越川"),
-            ("020148", "020148:下塩越川"),
-            ("020149", "020149:大沢"),
-            ("020150", "020150:板木沢川"),
-            ("020151", "020151:長科川"),
-            ("020152", "020152:四戸橋川"),
-            ("020153", "020153:前田の沢川"),
-            ("020154", "020154:飛鳥沢川"),
-            ("020155", "020155:西田沢川"),
-            ("020156", "020156:油川川"),
-            ("020157", "020157:沼川"),
-            ("020158", "020158:木舟川"),
-            ("020159", "020159:茂浦川"),
-            ("020160", "020160:稲田沢"),
-            ("020161", "020161:白砂沢"),
-            ("020162", "020162:鷲の沢"),
-            ("020163", "020163:間木沢"),
-            ("020164", "020164:口広川"),
-            ("020165", "020165:吹越川"),
-            ("020166", "020166:武の川"),
-            ("020167", "020167:牛の沢川"),
-            ("020168", "020168:田の沢川"),
-            ("020169", "020169:奥内川"),
-            ("020170", "020170:今泉川"),
-            ("020171", "020171:三本松川"),
-            ("020172", "020172:小荒川"),
-            ("020173", "020173:小松野川"),
-            ("020174", "020174:浜町沢"),
-            ("020175", "020175:下浜町沢"),
-            ("020176", "020176:上町沢"),
-            ("020177", "020177:上湊川"),
-            ("020178", "020178:湊川"),
-            ("020179", "020179:上宇田沢"),
-            ("020180", "020180:宇田沢"),
-            ("020181", "020181:桜木町沢"),
-            ("020182", "020182:大近川"),
-            ("020183", "020183:宇曽利川"),
-            ("020184", "020184:永下川"),
-            ("020185", "020185:近沢川"),
-            ("020186", "020186:大川目川"),
-            ("020187", "020187:戸沢川"),
-            ("020188", "020188:熊野川"),
-            ("020189", "020189:葛沢川"),
-            ("020190", "020190:上々小沢川"),
-            ("020191", "020191:上小沢川"),
-            ("020192", "020192:小沢川"),
-            ("020193", "020193:瀬野川"),
-            ("020194", "020194:寄浪沢"),
-            ("020195", "020195:芋田沢"),
-            ("020196", "020196:九ソウ泊沢"),
-            ("020197", "020197:牛滝川"),
-            ("020198", "020198:長後川"),
-            ("020199", "020199:黒岩川"),
-            ("020200", "020200:原田川"),
-            ("020201", "020201:小奥戸川"),
-            ("020202", "020202:下沢の黒沢"),
-            ("020203", "020203:新湯川"),
-            ("020204", "020204:大赤川"),
-            ("020205", "020205:小赤川"),
-            ("020206", "020206:三右衛門沢"),
-            ("020207", "020207:下釣屋浜沢"),
-            ("020208", "020208:下狄川"),
-            ("020209", "020209:茶水川"),
-            ("020210", "020210:袰部川"),
-            ("020211", "020211:尻労川"),
-            ("020212", "020212:白糖川"),
-            ("020213", "020213:堤沢"),
-            ("020214", "020214:大明神川"),
-            ("020215", "020215:押付沢"),
-            ("020216", "020216:月山沢"),
-            ("020217", "020217:明神沢"),
-            ("020218", "020218:石川沢"),
-            ("020219", "020219:二又川"),
-            ("020220", "020220:六の川"),
-            ("020221", "020221:五の川"),
-            ("020222", "020222:三沢川"),
-            ("020223", "020223:三の川"),
-            ("020224", "020224:大渡川"),
-            ("020225", "020225:榊浜川"),
-            ("020226", "020226:道仏川"),
-            ("020227", "020227:二十一川"),
-            ("020228", "020228:明神川"),
-            ("030001", "030001:八木沢川"),
-            ("030002", "030002:川尻川"),
-            ("030003", "030003:有家川"),
-            ("030004", "030004:高家川"),
-            ("030005", "030005:久慈川"),
-            ("030006", "030006:玉の脇川"),
-            ("030007", "030007:宇部川"),
-            ("030008", "030008:米田川"),
-            ("030009", "030009:安家川"),
-            ("030010", "030010:普代川"),
-            ("030011", "030011:明戸川"),
-            ("030012", "030012:平井賀川"),
-            ("030013", "030013:松前川"),
-            ("030014", "030014:小本川"),
-            ("030015", "030015:摂待川"),
-            ("030016", "030016:田代川"),
-            ("030017", "030017:閉伊川"),
-            ("030018", "030018:八木沢川"),
-            ("030019", "030019:津軽石川"),
-            ("030020", "030020:重茂川"),
-            ("030021", "030021:大沢川"),
-            ("030022", "030022:関口川"),
-            ("030023", "030023:織笠川"),
-            ("030024", "030024:大鎚川"),
-            ("030025", "030025:小鎚川"),
-            ("030026", "030026:鵜住居川"),
-            ("030027", "030027:水海川"),
-            ("030028", "030028:甲子川"),
-            ("030029", "030029:片岸川"),
-            ("030030", "030030:熊野川"),
-            ("030031", "030031:吉浜川"),
-            ("030032", "030032:浦浜川"),
-            ("030033", "030033:泊川"),
-            ("030034", "030034:甫嶺川"),
-            ("030035", "030035:稜里川"),
-            ("030036", "030036:合足川"),
-            ("030037", "030037:後の入川"),
-            ("030038", "030038:盛川"),
-            ("030039", "030039:須崎川"),
-            ("030040", "030040:船河原川"),
-            ("030041", "030041:浜田川"),
-            ("030042", "030042:気仙川"),
-            ("030043", "030043:長部川"),
-            ("030044", "030044:玉川"),
-            ("030045", "030045:沢川"),
-            ("030046", "030046:力持川"),
-            ("030047", "030047:大沢川"),
-            ("030048", "030048:黒崎川"),
-            ("030049", "030049:ヨシロイ川"),
-            ("030050", "030050:カワドケシリ川"),
-            ("030051", "030051:大峰川"),
-            ("030052", "030052:机川"),
-            ("030053", "030053:ハイペ川"),
-            ("030054", "030054:コイコロベ川"),
-            ("030055", "030055:島ノ沢川"),
-            ("030056", "030056:白池川"),
-            ("030057", "030057:槙木沢川"),
-            ("030058", "030058:小成川"),
-            ("030059", "030059:長内川"),
-            ("030060", "030060:女遊戸川"),
-            ("030061", "030061:下在家川"),
-            ("030062", "030062:大沢川"),
-            ("030063", "030063:清水川"),
-            ("030064", "030064:梅翁寺川"),
-            ("030065", "030065:八木沢川"),
-            ("030066", "030066:神倉川"),
-            ("030067", "030067:寺川"),
-            ("030068", "030068:西川"),
-            ("030069", "030069:川村川"),
-            ("030070", "030070:細浦川"),
-            ("030071", "030071:中瀬川"),
-            ("030072", "030072:ワラビ川"),
-            ("030073", "030073:秀禅川"),
-            ("030074", "030074:女川"),
-            ("030075", "030075:早川川"),
-            ("030076", "030076:岩ヶ沢川"),
-            ("030077", "030077:山の内川"),
-            ("030078", "030078:浪板川"),
-            ("030079", "030079:沢山沢川"),
-            ("030080", "030080:沼川川"),
-            ("030081", "030081:両石川"),
-            ("030082", "030082:上平田川"),
-            ("030083", "030083:下平田川"),
-            ("030084", "030084:白浜川"),
-            ("030085", "030085:佐須川"),
-            ("030086", "030086:花呂部川"),
-            ("030087", "030087:太曽根川"),
-            ("030088", "030088:二本梨川"),
-            ("030089", "030089:熊野川"),
-            ("030090", "030090:大石川"),
-            ("030091", "030091:大立川"),
-            ("030092", "030092:沼田川"),
-            ("030093", "030093:八木川"),
-            ("030094", "030094:音部川"),
-            ("030095", "030095:矢作川"),
-            ("040001", "040001:青野沢川"),
-            ("040002", "040002:只越川"),
-            ("040003", "040003:鹿折川"),
-            ("040004", "040004:大川"),
-            ("040005", "040005:面瀬川"),
-            ("040006", "040006:沖ノ田川"),
-            ("040007", "040007:津谷川"),
-            ("040008", "040008:港川"),
-            ("040009", "040009:稲淵川"),
-            ("040010", "040010:伊里前川"),
-            ("040011", "040011:桜川"),
-            ("040012", "040012:新井田川"),
-            ("040013", "040013:八幡川"),
-            ("040014", "040014:水尻川"),
-            ("040015", "040015:折立川"),
-            ("040016", "040016:水戸辺川"),
-            ("040017", "040017:長清水川"),
-            ("040018", "040018:相川沢川"),
-            ("040019", "040019:大原川"),
-            ("040020", "040020:女川"),
-            ("040021", "040021:後川"),
-            ("040022", "040022:湊川"),
-            ("040023", "040023:淀川"),
-            ("040024", "040024:大沢川"),
-            ("040025", "040025:定川"),
-            ("040026", "040026:高城川"),
-            ("040027", "040027:砂押川"),
-            ("040028", "040028:七北田川"),
-            ("040029", "040029:坂元川"),
-            ("040030", "040030:東舞根川"),
-            ("040031", "040031:田の浦川"),
-            ("040032", "040032:寄木川"),
-            ("040033", "040033:後川"),
-            ("040034", "040034:津持川"),
-            ("040035", "040035:千鳥川"),
-            ("040036", "040036:大沢川"),
-            ("040037", "040037:蛇王川"),
-            ("040038", "040038:水浜川"),
-            ("040039", "040039:新町川"),
-            ("040040", "040040:鐙川"),
-            ("040041", "040041:落堀川"),
-            ("040042", "040042:鷲足川"),
-            ("040043", "040043:高瀬川"),
-            ("050001", "050001:清水川"),
-            ("050002", "050002:奈曽川"),
-            ("050003", "050003:象潟川"),
-            ("050004", "050004:赤石川"),
-            ("050005", "050005:白雪川"),
-            ("050006", "050006:大沢川"),
-            ("050007", "050007:西目川"),
-            ("050008", "050008:衣川"),
-            ("050009", "050009:二古川"),
-            ("050010", "050010:君が野川"),
-            ("050011", "050011:勝手川"),
-            ("050012", "050012:下浜鮎川"),
-            ("050013", "050013:馬場目川"),
-            ("050014", "050014:比詰川"),
-            ("050015", "050015:加茂川"),
-            ("050016", "050016:滝川"),
-            ("050017", "050017:鮪川川"),
-            ("050018", "050018:竹生川"),
-            ("050019", "050019:塙川"),
-            ("050020", "050020:水沢川"),
-            ("050021", "050021:真瀬川"),
-            ("050022", "050022:清水川"),
-            ("050023", "050023:川袋川"),
-            ("050024", "050024:中の沢川"),
-            ("050025", "050025:市の沢川"),
-            ("050026", "050026:琴浦川"),
-            ("050027", "050027:阿部堂川"),
-            ("050028", "050028:沼尻川"),
-            ("050029", "050029:雪川川"),
-            ("050030", "050030:保量川"),
-            ("050031", "050031:増川"),
-            ("050032", "050032:小増川"),
-            ("050033", "050033:野村川"),
-            ("050034", "050034:賀茂川"),
-            ("050035", "050035:相川"),
-            ("050036", "050036:大増川"),
-            ("050037", "050037:小増川"),
-            ("050038", "050038:安田川"),
-            ("060001", "060001:鼠ヶ関川"),
-            ("060002", "060002:早田川"),
-            ("060003", "060003:出口沢川"),
-            ("060004", "060004:巌沢川"),
-            ("060005", "060005:庄内小国川"),
-            ("060006", "060006:温海川"),
-            ("060007", "060007:五十川"),
-            ("060008", "060008:天竜川"),
-            ("060009", "060009:三瀬川"),
-            ("060010", "060010:楯下川"),
-            ("060011", "060011:長者川"),
-            ("060012", "060012:村上川"),
-            ("060013", "060013:油戸川"),
-            ("060014", "060014:岡町川"),
-            ("060015", "060015:新井田川"),
-            ("060016", "060016:日向川"),
-            ("060017", "060017:月光川"),
-            ("070001", "070001:三滝川"),
-            ("070002", "070002:砂子田川"),
-            ("070003", "070003:濁川"),
-            ("070004", "070004:地蔵川"),
-            ("070005", "070005:小泉川"),
-            ("070006", "070006:宇多川"),
-            ("070007", "070007:梅川"),
-            ("070008", "070008:日下石川"),
-            ("070009", "070009:真野川"),
-            ("070010", "070010:新田川"),
-            ("070011", "070011:太田川"),
-            ("070012", "070012:小高川"),
-            ("070013", "070013:宮田川"),
-            ("070014", "070014:請戸川"),
-            ("070015", "070015:前田川"),
-            ("070016", "070016:夫沢川"),
-            ("070017", "070017:小入野川"),
-            ("070018", "070018:熊川"),
-            ("070019", "070019:富岡川"),
-            ("070020", "070020:紅葉川"),
-            ("070021", "070021:井出川"),
-            ("070022", "070022:木戸川"),
-            ("070023", "070023:北迫川"),
-            ("070024", "070024:浅見川"),
-            ("070025", "070025:折木川"),
-            ("070026", "070026:末続川"),
-            ("070027", "070027:大久川"),
-            ("070028", "070028:夏井川"),
-            ("070029", "070029:滑津川"),
-            ("070030", "070030:弁天川"),
-            ("070031", "070031:諏訪川"),
-            ("070032", "070032:神白川"),
-            ("070033", "070033:藤原川"),
-            ("070034", "070034:渚川"),
-            ("070035", "070035:鮫川"),
-            ("070036", "070036:蛭田川"),
-            ("070037", "070037:金沢川"),
-            ("070038", "070038:北原川"),
-            ("070039", "070039:塩民川"),
-            ("070040", "070040:境川"),
-            ("070041", "070041:天神前川"),
-            ("080001", "080001:里根川"),
-            ("080002", "080002:江戸上川"),
-            ("080003", "080003:大北川"),
-            ("080004", "080004:塩田川"),
-            ("080005", "080005:関根川"),
-            ("080006", "080006:花貫川"),
-            ("080007", "080007:小石川"),
-            ("080008", "080008:十王川"),
-            ("080009", "080009:東連津川"),
-            ("080010", "080010:宮田川"),
-            ("080011", "080011:鮎川"),
-            ("080012", "080012:桜川"),
-            ("080013", "080013:金沢川"),
-            ("080014", "080014:大沼川"),
-            ("080015", "080015:瀬上川"),
-            ("080016", "080016:新川"),
-            ("080018", "080018:沢尻川"),
-            ("080019", "080019:折笠川"),
-            ("080020", "080020:田尻川"),
-            ("080021", "080021:北川"),
-            ("080022", "080022:大川"),
-            ("120001", "120001:小畑川"),
-            ("120002", "120002:新川"),
-            ("120003", "120003:栗山川"),
-            ("120004", "120004:木戸川"),
-            ("120005", "120005:作田川"),
-            ("120006", "120006:真亀川"),
-            ("120007", "120007:堀川"),
-            ("120008", "120008:南白亀川"),
-            ("120009", "120009:一宮川"),
-            ("120010", "120010:夷隅川"),
-            ("120011", "120011:塩田川"),
-            ("120012", "120012:清水川"),
-            ("120013", "120013:墨名川"),
-            ("120014", "120014:開戸川"),
-            ("120015", "120015:大風沢川"),
-            ("120016", "120016:神明川"),
-            ("120017", "120017:二タ間川"),
-            ("120018", "120018:待崎川"),
-            ("120019", "120019:加茂川"),
-            ("120020", "120020:曽呂川"),
-            ("120021", "120021:州貝川"),
-            ("120022", "120022:長者川"),
-            ("120023", "120023:三原川"),
-            ("120024", "120024:温石川"),
-            ("120025", "120025:丸山川"),
-            ("120026", "120026:瀬戸川"),
-            ("120027", "120027:川尻川"),
-            ("120028", "120028:長尾川"),
-            ("120029", "120029:汐入川"),
-            ("120030", "120030:平久里川"),
-            ("120031", "120031:岡本川"),
-            ("120032", "120032:岩井川"),
-            ("120033", "120033:佐久間川"),
-            ("120034", "120034:保田川"),
-            ("120035", "120035:元名川"),
-            ("120036", "120036:金谷川"),
-            ("120037", "120037:白狐川"),
-            ("120038", "120038:湊川"),
-            ("120039", "120039:染川"),
-            ("120040", "120040:小久保川"),
-            ("120041", "120041:岩瀬川"),
-            ("120042", "120042:小糸川"),
-            ("120043", "120043:畑沢川"),
-            ("120044", "120044:烏田川"),
-            ("120045", "120045:矢那川"),
-            ("120046", "120046:小櫃川"),
-            ("120047", "120047:浮戸川"),
-            ("120048", "120048:椎津川"),
-            ("120049", "120049:前川"),
-            ("120050", "120050:養老川"),
-            ("120051", "120051:村田川"),
-            ("120052", "120052:浜野川"),
-            ("120053", "120053:生実川"),
-            ("120054", "120054:都川"),
-            ("120055", "120055:浜田川"),
-            ("120056", "120056:菊田川"),
-            ("120057", "120057:谷津川"),
-            ("120058", "120058:高瀬川"),
-            ("120059", "120059:海老川"),
-            ("120060", "120060:猫実川"),
-            ("120061", "120061:南川尻川"),
-            ("120062", "120062:ビチャ川"),
-            ("120063", "120063:境川"),
-            ("120064", "120064:岩井川"),
-            ("120065", "120065:夜長川"),
-            ("120066", "120066:蟹田川"),
-            ("120067", "120067:真浦川"),
-            ("120068", "120068:大石川"),
-            ("120069", "120069:蟹田川"),
-            ("120070", "120070:どんどん川"),
-            ("120071", "120071:汐入川"),
-            ("120072", "120072:大六川"),
-            ("120073", "120073:小磯川"),
-            ("120074", "120074:世入川"),
-            ("120075", "120075:笹毛川"),
-            ("120076", "120076:夕田川"),
-            ("120077", "120077:宮脇川"),
-            ("120078", "120078:本名輪川"),
-            ("120079", "120079:蔵波川"),
-            ("120080", "120080:久保田川"),
-            ("120081", "120081:浜宿川"),
-            ("120082", "120082:笠上川"),
-            ("120083", "120083:今津川"),
-            ("120084", "120084:海神川"),
-            ("130001", "130001:八ツ瀬川"),
-            ("130002", "130002:呑川"),
-            ("130003", "130003:内川"),
-            ("130004", "130004:立会川"),
-            ("130005", "130005:目黒川"),
-            ("130006", "130006:古川"),
-            ("130007", "130007:汐留川"),
-            ("130008", "130008:築地川"),
-            ("130009", "130009:越中島川"),
-            ("130010", "130010:境川"),
-            ("130011", "130011:ヌタの沢川"),
-            ("130012", "130012:北の山川"),
-            ("130013", "130013:根古沢"),
-            ("130014", "130014:蛇洞沢"),
-            ("130015", "130015:大島沢"),
-            ("130016", "130016:新堀川"),
-            ("130017", "130017:とりが沢"),
-            ("130018", "130018:川田沢"),
-            ("130019", "130019:芦川"),
-            ("130020", "130020:清瀬川"),
-            ("140001", "140001:帷子川"),
-            ("140002", "140002:大岡川"),
-            ("140003", "140003:宮川"),
-            ("140004", "140004:侍従川"),
-            ("140005", "140005:鷹取川"),
-            ("140006", "140006:平作川"),
-            ("140007", "140007:松越川"),
-            ("140008", "140008:下山川"),
-            ("140009", "140009:森戸川"),
-            ("140010", "140010:田越川"),
-            ("140011", "140011:滑川"),
-            ("140012", "140012:神戸川"),
-            ("140013", "140013:境川"),
-            ("140014", "140014:引地川"),
-            ("140015", "140015:金目川"),
-            ("140016", "140016:葛川"),
-            ("140017", "140017:中村川"),
-            ("140018", "140018:森戸川"),
-            ("140019", "140019:酒匂川"),
-            ("140020", "140020:山王川"),
-            ("140021", "140021:早川"),
-            ("140022", "140022:新崎川"),
-            ("140023", "140023:千歳川"),
-            ("140024", "140024:入江川"),
-            ("140025", "140025:滝の川"),
-            ("140026", "140026:野比川"),
-            ("140027", "140027:長沢川"),
-            ("140028", "140028:津久井川"),
-            ("140029", "140029:狭塚川"),
-            ("140030", "140030:川間川"),
-            ("140031", "140031:前田川"),
-            ("140032", "140032:血洗川"),
-            ("140033", "140033:塔台川"),
-            ("140034", "140034:唐沢川"),
-            ("140035", "140035:新川"),
-            ("140036", "140036:玉川"),
-            ("140037", "140037:水無川"),
-            ("140038", "140038:清水川"),
-            ("140039", "140039:白糸川"),
-            ("140040", "140040:よもぎ川"),
-            ("140041", "140041:堀切川"),
-            ("140042", "140042:音無川"),
-            ("150001", "150001:外波川"),
-            ("150002", "150002:歌川"),
-            ("150003", "150003:青海川"),
-            ("150004", "150004:田海川"),
-            ("150005", "150005:八千川"),
-            ("150006", "150006:海川"),
-            ("150007", "150007:前川"),
-            ("150008", "150008:早川"),
-            ("150009", "150009:中央川"),
-            ("150010", "150010:古川"),
-            ("150011", "150011:木浦川"),
-            ("150012", "150012:能生川"),
-            ("150013", "150013:山王川"),
-            ("150014", "150014:筒石川"),
-            ("150015", "150015:徳合川"),
-            ("150016", "150016:名立川"),
-            ("150017", "150017:桑取川"),
-            ("150018", "150018:柿崎川"),
-            ("150019", "150019:オガチ川"),
-            ("150020", "150020:谷根川"),
-            ("150021", "150021:前川"),
-            ("150022", "150022:鵜川"),
-            ("150023", "150023:鯖石川"),
-            ("150024", "150024:二位殿川"),
-            ("150025", "150025:大津川"),
-            ("150026", "150026:尾町川"),
-            ("150027", "150027:石地川"),
-            ("150028", "150028:相場川"),
-            ("150029", "150029:郷本川"),
-            ("150030", "150030:新島崎川"),
-            ("150031", "150031:大三川"),
-            ("150032", "150032:藤四郎川"),
-            ("150033", "150033:高堂川"),
-            ("150034", "150034:次郎七川"),
-            ("150035", "150035:大野積川"),
-            ("150036", "150036:白勢川"),
-            ("150037", "150037:坂本川"),
-            ("150038", "150038:新川"),
-            ("150039", "150039:加治川"),
-            ("150040", "150040:落堀川"),
-            ("150041", "150041:胎内川"),
-            ("150042", "150042:石川"),
-            ("150043", "150043:三面川"),
-            ("150044", "150044:大川"),
-            ("150045", "150045:早川"),
-            ("150046", "150046:桑川"),
-            ("150047", "150047:笹川"),
-            ("150048", "150048:板貝川"),
-            ("150049", "150049:脇川"),
-            ("150050", "150050:葡萄川"),
-            ("150051", "150051:勝木川"),
-            ("150052", "150052:碁石川"),
-            ("150053", "150053:間の内川"),
-            ("150054", "150054:大川"),
-            ("150055", "150055:中ノ沢川"),
-            ("150056", "150056:黒姫川"),
-            ("150057", "150057:歌見川"),
-            ("150058", "150058:馬首川"),
-            ("150059", "150059:和木川"),
-            ("150060", "150060:玉川"),
-            ("150061", "150061:白瀬川"),
-            ("150062", "150062:北五十里川"),
-            ("150063", "150063:椿川"),
-            ("150064", "150064:羽黒川"),
-            ("150065", "150065:梅津川"),
-            ("150066", "150066:外城川"),
-            ("150067", "150067:貝喰川"),
-            ("150068", "150068:長江川"),
-            ("150069", "150069:天王川"),
-            ("150070", "150070:久知川"),
-            ("150071", "150071:河崎川"),
-            ("150072", "150072:真更川"),
-            ("150073", "150073:両尾川"),
-            ("150074", "150074:大川"),
-            ("150075", "150075:野浦川"),
-            ("150076", "150076:東立島川"),
-            ("150077", "150077:赤玉北川"),
-            ("150078", "150078:立間川"),
-            ("150079", "150079:柿野浦川"),
-            ("150080", "150080:東鵜島川"),
-            ("150081", "150081:岩首川"),
-            ("150082", "150082:浦之河内川"),
-            ("150083", "150083:河内川"),
-            ("150084", "150084:腰細川"),
-            ("150085", "150085:高川川"),
-            ("150086", "150086:中野川"),
-            ("150087", "150087:荒町川"),
-            ("150088", "150088:柳沢川"),
-            ("150089", "150089:堀切川"),
-            ("150090", "150090:羽茂川"),
-            ("150091", "150091:小比叡川"),
-            ("150092", "150092:泉財川"),
-            ("150093", "150093:称光寺川"),
-            ("150094", "150094:大浦川"),
-            ("150095", "150095:椿尾川"),
-            ("150096", "150096:高崎川"),
-            ("150097", "150097:西三川川"),
-            ("150098", "150098:三貫目川"),
-            ("150099", "150099:小川内川"),
-            ("150100", "150100:真野川"),
-            ("150101", "150101:高立川"),
-            ("150102", "150102:国府川"),
-            ("150103", "150103:石田川"),
-            ("150104", "150104:荒町川"),
-            ("150105", "150105:沓掛川"),
-            ("150106", "150106:玄道川"),
-            ("150107", "150107:播磨川"),
-            ("150108", "150108:質場川"),
-            ("150109", "150109:羽二生川"),
-            ("150110", "150110:目観音川"),
-            ("150111", "150111:海士町川"),
-            ("150112", "150112:大仏川"),
-            ("150113", "150113:間切川"),
-            ("150114", "150114:濁川"),
-            ("150115", "150115:水金川"),
-            ("150116", "150116:小川川"),
-            ("150117", "150117:達者川"),
-            ("150118", "150118:小川"),
-            ("150119", "150119:北狄川"),
-            ("150120", "150120:中の坂川"),
-            ("150121", "150121:戸地川"),
-            ("150122", "150122:松島川"),
-            ("150123", "150123:石花川"),
-            ("150124", "150124:小川"),
-            ("150125", "150125:桜川"),
-            ("150126", "150126:北立島川"),
-            ("150127", "150127:安田川"),
-            ("150128", "150128:入川"),
-            ("150129", "150129:堀切川"),
-            ("150130", "150130:孫助川"),
-            ("150131", "150131:小野見川"),
-            ("150132", "150132:馬込川"),
-            ("150133", "150133:石名川"),
-            ("150134", "150134:大倉川"),
-            ("150135", "150135:堂の川"),
-            ("150136", "150136:矢柄川"),
-            ("150137", "150137:関川"),
-            ("150138", "150138:五十浦川"),
-            ("150139", "150139:岩谷口小川"),
-            ("150140", "150140:大川"),
-            ("150141", "150141:ぬな川"),
-            ("150142", "150142:今川"),
-            ("150143", "150143:大窪沢川"),
-            ("150144", "150144:法事川"),
-            ("150145", "150145:新田川"),
-            ("150146", "150146:寺の川"),
-            ("150147", "150147:宮の沢川"),
-            ("150148", "150148:多町川"),
-            ("150149", "150149:上の川"),
-            ("150150", "150150:姫津川"),
-            ("150151", "150151:本の川"),
-            ("150152", "150152:戸中川"),
-            ("150153", "150153:中の坂川"),
-            ("150154", "150154:梅川"),
-            ("150155", "150155:源平川"),
-            ("150156", "150156:寺の沢川"),
-            ("150157", "150157:大塚川"),
-            ("150158", "150158:一丁尻川"),
-            ("150159", "150159:ノタカ沢川"),
-            ("150160", "150160:立髪川"),
-            ("150161", "150161:北の川"),
-            ("150162", "150162:島崎川"),
-            ("160001", "160001:下田川"),
-            ("160002", "160002:宇波川"),
-            ("160003", "160003:阿尾川"),
-            ("160004", "160004:余川川"),
-            ("160005", "160005:上庄川"),
-            ("160006", "160006:仏生寺川"),
-            ("160007", "160007:泉川"),
-            ("160008", "160008:内川"),
-            ("160009", "160009:放生津潟"),
-            ("160010", "160010:新堀川"),
-            ("160011", "160011:下条川"),
-            ("160012", "160012:古川"),
-            ("160013", "160013:白岩川"),
-            ("160014", "160014:上市川"),
-            ("160015", "160015:中川"),
-            ("160016", "160016:早月川"),
-            ("160017", "160017:角川"),
-            ("160018", "160018:鴨川"),
-            ("160019", "160019:中川"),
-            ("160020", "160020:片貝川"),
-            ("160021", "160021:黒瀬川"),
-            ("160022", "160022:高橋川"),
-            ("160023", "160023:吉田川"),
-            ("160024", "160024:平曽川"),
-            ("160025", "160025:入川"),
-            ("160026", "160026:小川"),
-            ("160027", "160027:寺川"),
-            ("160028", "160028:木流川"),
-            ("160029", "160029:笹川"),
-            ("160030", "160030:境川"),
-            ("160031", "160031:下田川"),
-            ("160032", "160032:渋谷川"),
-            ("160033", "160033:がめ川"),
-            ("160034", "160034:田中川"),
-            ("160036", "160036:こうなぎ川"),
-            ("160037", "160037:清水川"),
-            ("160038", "160038:出戸川"),
-            ("160039", "160039:大野川"),
-            ("160040", "160040:平曽川"),
-            ("170001", "170001:大聖寺川"),
-            ("170002", "170002:田尻川"),
-            ("170003", "170003:新堀川"),
-            ("170004", "170004:大慶寺川"),
-            ("170005", "170005:倉部川"),
-            ("170006", "170006:犀川"),
-            ("170007", "170007:大野川"),
-            ("170008", "170008:大海川"),
-            ("170009", "170009:前田川"),
-            ("170010", "170010:宝達川"),
-            ("170011", "170011:相見川"),
-            ("170012", "170012:羽咋川"),
-            ("170013", "170013:菱根川"),
-            ("170014", "170014:米町川"),
-            ("170015", "170015:富来川"),
-            ("170016", "170016:酒見川"),
-            ("170017", "170017:仁岸川"),
-            ("170018", "170018:阿岸川"),
-            ("170019", "170019:八ヶ川"),
-            ("170020", "170020:西二又川"),
-            ("170021", "170021:河原田川"),
-            ("170022", "170022:塚田川"),
-            ("170023", "170023:南志見川"),
-            ("170024", "170024:町野川"),
-            ("170025", "170025:珠洲大谷川"),
-            ("170026", "170026:折戸川"),
-            ("170027", "170027:粟津川"),
-            ("170028", "170028:紀の川"),
-            ("170029", "170029:飯川"),
-            ("170030", "170030:金川"),
-            ("170031", "170031:若山川"),
-            ("170032", "170032:竹中川"),
-            ("170033", "170033:磐若川"),
-            ("170034", "170034:鵜飼川"),
-            ("170035", "170035:舟橋川"),
-            ("170036", "170036:松波川"),
-            ("170037", "170037:九里川尻川"),
-            ("170038", "170038:白丸川"),
-            ("170039", "170039:梶川"),
-            ("170040", "170040:山田川"),
-            ("170041", "170041:太田川"),
-            ("170042", "170042:諸橋川"),
-            ("170043", "170043:日詰川"),
-            ("170044", "170044:七海川"),
-            ("170045", "170045:小又川"),
-            ("170046", "170046:小牧川"),
-            ("170047", "170047:熊木川"),
-            ("170048", "170048:日用川"),
-            ("170049", "170049:笠師川"),
-            ("170050", "170050:塩津川"),
-            ("170051", "170051:大津川"),
-            ("170052", "170052:深見川"),
-            ("170053", "170053:三引川"),
-            ("170054", "170054:二宮川"),
-            ("170055", "170055:赤浦川"),
-            ("170056", "170056:御祓川"),
-            ("170057", "170057:大谷川"),
-            ("170058", "170058:崎山川"),
-            ("170059", "170059:熊渕川"),
-            ("170060", "170060:衣川"),
-            ("170061", "170061:甘田川"),
-            ("170062", "170062:大滝川"),
-            ("170063", "170063:小浦川"),
-            ("170064", "170064:百浦川"),
-            ("170065", "170065:大鳥川"),
-            ("170066", "170066:江野川"),
-            ("170067", "170067:松戸川"),
-            ("170068", "170068:赤住川"),
-            ("170070", "170070:蛸島川"),
-            ("170071", "170071:奥原川"),
-            ("170072", "170072:浜岡川"),
-            ("170073", "170073:祖浜川"),
-            ("170074", "170074:江川"),
-            ("170075", "170075:松原川"),
-            ("170076", "170076:赤星川"),
-            ("170077", "170077:臼池川"),
-            ("170078", "170078:高田川"),
-            ("170079", "170079:太田八幡川"),
-            ("170080", "170080:滝尻川"),
-            ("170081", "170081:赤崎川"),
-            ("170082", "170082:前の川"),
-            ("170083", "170083:窪川"),
-            ("170084", "170084:今田川"),
-            ("170085", "170085:福留川"),
-            ("170086", "170086:福浦川"),
-            ("170087", "170087:日越川"),
-            ("170088", "170088:大野木川"),
-            ("170089", "170089:馬谷川"),
-            ("170090", "170090:江泊川"),
-            ("170091", "170091:百海川"),
-            ("170092", "170092:赤岩川"),
-            ("170093", "170093:谷内川"),
-            ("170094", "170094:波出川"),
-            ("170095", "170095:胴田川"),
-            ("170096", "170096:虫崎川"),
-            ("170097", "170097:八幡川"),
-            ("170098", "170098:佐々波川"),
-            ("170099", "170099:黒崎川"),
-            ("170100", "170100:東浜川"),
-            ("170101", "170101:鮭谷川"),
-            ("170102", "170102:時合川"),
-            ("170103", "170103:大泊川"),
-            ("170104", "170104:新池川"),
-            ("170105", "170105:ヤブライチ川"),
-            ("170106", "170106:大坪川"),
-            ("170107", "170107:よしら川"),
-            ("170108", "170108:神川"),
-            ("170109", "170109:水の澗川"),
-            ("170110", "170110:がんもん川"),
-            ("170111", "170111:サット川"),
-            ("170112", "170112:牛下川"),
-            ("170113", "170113:生神川"),
-            ("170114", "170114:七海川"),
-            ("170115", "170115:滝出川"),
-            ("170116", "170116:小窪川"),
-            ("170117", "170117:鹿頭川"),
-            ("170118", "170118:小山出川"),
-            ("170119", "170119:前浜川"),
-            ("170120", "170120:深谷川"),
-            ("180001", "180001:関屋川"),
-            ("180002", "180002:子生川"),
-            ("180003", "180003:佐分利川"),
-            ("180004", "180004:本所川"),
-            ("180005", "180005:飯盛川"),
-            ("180006", "180006:南川"),
-            ("180007", "180007:多田川"),
-            ("180008", "180008:早瀬川"),
-            ("180009", "180009:耳川"),
-            ("180010", "180010:太田川"),
-            ("180011", "180011:馬背川"),
-            ("180012", "180012:落合川"),
-            ("180013", "180013:井口川"),
-            ("180014", "180014:笙の川"),
-            ("180015", "180015:河野川"),
-            ("180016", "180016:糠川"),
-            ("180017", "180017:玉川川"),
-            ("180018", "180018:大味川"),
-            ("180019", "180019:一光川"),
-            ("180020", "180020:三本木川"),
-            ("180021", "180021:高須川"),
-            ("180022", "180022:笠原川"),
-            ("180023", "180023:手の浦川"),
-            ("180024", "180024:毬山川"),
-            ("180025", "180025:田猪川"),
-            ("180026", "180026:赤崎川"),
-            ("180027", "180027:平野川"),
-            ("180028", "180028:大毛谷川"),
-            ("180029", "180029:竹鼻川"),
-            ("180030", "180030:大川"),
-            ("180031", "180031:免鳥川"),
-            ("180032", "180032:今津川"),
-            ("190001", "190001:西湖"),
-            ("190002", "190002:精進湖"),
-            ("190003", "190003:本栖湖"),
-            ("220001", "220001:千才川"),
-            ("220002", "220002:逢初川"),
-            ("220003", "220003:糸川"),
-            ("220004", "220004:初川"),
-            ("220005", "220005:熱海和田川"),
-            ("220006", "220006:上多賀大川"),
-            ("220007", "220007:熱海宮川"),
-            ("220008", "220008:熱海仲川"),
-            ("220009", "220009:鍛治川"),
-            ("220010", "220010:水神川"),
-            ("220011", "220011:烏川"),
-            ("220012", "220012:伊東仲川"),
-            ("220013", "220013:伊東宮川"),
-            ("220014", "220014:北川"),
-            ("220015", "220015:伊東大川"),
-            ("220016", "220016:草崎川"),
-            ("220017", "220017:大川川"),
-            ("220018", "220018:濁川"),
-            ("220019", "220019:白田川"),
-            ("220020", "220020:稲取大川"),
-            ("220021", "220021:河津川"),
-            ("220022", "220022:稲生沢川"),
-            ("220023", "220023:大賀茂川"),
-            ("220024", "220024:青野川"),
-            ("220025", "220025:中木川"),
-            ("220026", "220026:殿田川"),
-            ("220027", "220027:五十鈴川"),
-            ("220028", "220028:那賀川"),
-            ("220029", "220029:仁科川"),
-            ("220030", "220030:安良里浜川"),
-            ("220031", "220031:宇久須川"),
-            ("220032", "220032:八木沢大川"),
-            ("220033", "220033:松原川"),
-            ("220034", "220034:火振川"),
-            ("220035", "220035:山川"),
-            ("220036", "220036:小土肥大川"),
-            ("220037", "220037:大川"),
-            ("220038", "220038:沢海川"),
-            ("220039", "220039:井田大川"),
-            ("220040", "220040:古宇川"),
-            ("220041", "220041:立保川"),
-            ("220042", "220042:西浦河内川"),
-            ("220043", "220043:陰野川"),
-            ("220044", "220044:新中川"),
-            ("220045", "220045:山居沢川"),
-            ("220046", "220046:向田川"),
-            ("220047", "220047:堰沢川"),
-            ("220048", "220048:八木沢川"),
-            ("220049", "220049:神沢川"),
-            ("220050", "220050:由比川"),
-            ("220051", "220051:和瀬川"),
-            ("220052", "220052:興津川"),
-            ("220053", "220053:波多打川"),
-            ("220054", "220054:庵原川"),
-            ("220055", "220055:巴川"),
-            ("220056", "220056:滝ヶ原川"),
-            ("220057", "220057:古安川"),
-            ("220058", "220058:殿谷川"),
-            ("220060", "220060:浜川"),
-            ("220061", "220061:小坂川"),
-            ("220062", "220062:瀬戸川"),
-            ("220063", "220063:小石川"),
-            ("220064", "220064:栃山川"),
-            ("220065", "220065:志太田中川"),
-            ("220066", "220066:湯日川"),
-            ("220067", "220067:坂口谷川"),
-            ("220068", "220068:勝間田川"),
-            ("220069", "220069:萩間川"),
-            ("220070", "220070:須々木川"),
-            ("220071", "220071:東沢川"),
-            ("220072", "220072:中西川"),
-            ("220073", "220073:筬川"),
-            ("220074", "220074:新野川"),
-            ("220075", "220075:竜今寺川"),
-            ("220076", "220076:東大谷川"),
-            ("220077", "220077:弁財天川"),
-            ("220078", "220078:前川"),
-            ("220079", "220079:太田川"),
-            ("220080", "220080:馬込川"),
-            ("220081", "220081:都田川"),
-            ("220082", "220082:鳴沢川"),
-            ("220083", "220083:対馬川"),
-            ("220084", "220084:八幡野川"),
-            ("220085", "220085:一色川"),
-            ("220086", "220086:砥川"),
-            ("220087", "220087:御嶽川"),
-            ("220088", "220088:古根川"),
-            ("220089", "220089:宮前川"),
-            ("220090", "220090:下篠川"),
-            ("220091", "220091:上ノ山川"),
-            ("220092", "220092:外浦川"),
-            ("220093", "220093:須崎川"),
-            ("220094", "220094:鍋田川"),
-            ("220095", "220095:多々戸川"),
-            ("220096", "220096:入田川"),
-            ("220097", "220097:大浜川"),
-            ("220098", "220098:田牛川"),
-            ("220099", "220099:太田川"),
-            ("220100", "220100:山道川"),
-            ("220101", "220101:谷地川"),
-            ("220102", "220102:小田瀬川"),
-            ("220103", "220103:井田子川"),
-            ("220104", "220104:太田川"),
-            ("220105", "220105:不動尊川"),
-            ("220106", "220106:深田川"),
-            ("220107", "220107:滝堂川"),
-            ("220108", "220108:獅子浜川"),
-            ("220109", "220109:塚田川"),
-            ("220110", "220110:元富士二号排水路"),
-            ("220111", "220111:新川"),
-            ("220112", "220112:浜田川"),
-            ("220113", "220113:大田川"),
-            ("220114", "220114:藤守川"),
-            ("220115", "220115:堺川"),
-            ("220116", "220116:堀切川"),
-            ("220117", "220117:大磯川"),
-            ("220118", "220118:法京川"),
-            ("220119", "220119:ラムネ川"),
-            ("220120", "220120:寺川"),
-            ("220121", "220121:穴川"),
-            ("220122", "220122:滝ノ川"),
-            ("220123", "220123:地代川"),
-            ("220124", "220124:倉沢川"),
-            ("220125", "220125:甚太郎川"),
-            ("220126", "220126:旧大谷川"),
-            ("230001", "230001:池尻川"),
-            ("230002", "230002:精進川"),
-            ("230003", "230003:天白川"),
-            ("230004", "230004:免々田川"),
-            ("230005", "230005:新堀川"),
-            ("230006", "230006:今堀川"),
-            ("230007", "230007:今池川"),
-            ("230008", "230008:汐川"),
-            ("230009", "230009:蜆川"),
-            ("230010", "230010:紙田川"),
-            ("230011", "230011:境川"),
-            ("230012", "230012:梅田川"),
-            ("230013", "230013:柳生川"),
-            ("230014", "230014:佐奈川"),
-            ("230015", "230015:音羽川"),
-            ("230016", "230016:御津川"),
-            ("230017", "230017:紫川"),
-            ("230018", "230018:西田川"),
-            ("230019", "230019:落合川"),
-            ("230020", "230020:拾石川"),
-            ("230021", "230021:八幡川"),
-            ("230022", "230022:鳥羽川"),
-            ("230023", "230023:矢崎川"),
-            ("230024", "230024:北浜川"),
-            ("230025", "230025:蜆川"),
-            ("230026", "230026:高浜川"),
-            ("230027", "230027:前川"),
-            ("230028", "230028:猿渡川"),
-            ("230029", "230029:境川"),
-            ("230030", "230030:明徳寺川"),
-            ("230031", "230031:豆搗川"),
-            ("230032", "230032:須賀川"),
-            ("230033", "230033:稗田川"),
-            ("230034", "230034:阿久比川"),
-            ("230035", "230035:十ヶ川"),
-            ("230036", "230036:神戸川"),
-            ("230037", "230037:石川"),
-            ("230038", "230038:堀川"),
-            ("230039", "230039:新川"),
-            ("230040", "230040:布土川"),
-            ("230041", "230041:新江川"),
-            ("230042", "230042:大川"),
-            ("230043", "230043:五宝川"),
-            ("230044", "230044:山海川"),
-            ("230045", "230045:内海川"),
-            ("230046", "230046:山王川"),
-            ("230047", "230047:稲早川"),
-            ("230048", "230048:矢田川"),
-            ("230049", "230049:日長川"),
-            ("230050", "230050:信濃川"),
-            ("230051", "230051:大田川"),
-            ("230052", "230052:天白川"),
-            ("230053", "230053:山崎川"),
-            ("230054", "230054:日光川"),
-            ("230055", "230055:筏川"),
-            ("230056", "230056:大西川"),
-            ("230057", "230057:庄五郎川"),
-            ("230058", "230058:弘法川"),
-            ("230059", "230059:波治神川"),
-            ("230060", "230060:文録川"),
-            ("230061", "230061:新堀川"),
-            ("230062", "230062:大堀川"),
-            ("230063", "230063:豊島排水路"),
-            ("230064", "230064:入江川"),
-            ("230065", "230065:大川"),
-            ("230066", "230066:紺屋川"),
-            ("230067", "230067:大湯川"),
-            ("230068", "230068:御山川"),
-            ("230069", "230069:背戸田川"),
-            ("230070", "230070:江川"),
-            ("230071", "230071:西方川"),
-            ("230072", "230072:堺川"),
-            ("230073", "230073:丹下川"),
-            ("230074", "230074:丸差川"),
-            ("230075", "230075:勝川"),
-            ("230076", "230076:向山下水"),
-            ("230077", "230077:尺地川"),
-            ("230078", "230078:都川"),
-            ("230079", "230079:中野川"),
-            ("230080", "230080:天神川"),
-            ("230081", "230081:袋川"),
-            ("230082", "230082:境川"),
-            ("230083", "230083:鹿川"),
-            ("230084", "230084:小野ヶ谷川"),
-            ("230085", "230085:清水川"),
-            ("230086", "230086:蛇抜川"),
-            ("230087", "230087:白浜川"),
-            ("230088", "230088:堀割川"),
-            ("230089", "230089:堀川"),
-            ("230090", "230090:田戸川"),
-            ("230091", "230091:江川"),
-            ("230092", "230092:浦島川"),
-            ("230093", "230093:北川"),
-            ("230094", "230094:石田川"),
-            ("230095", "230095:笠松川"),
-            ("230096", "230096:大池川"),
-            ("230097", "230097:時志川"),
-            ("230098", "230098:北方川"),
-            ("230099", "230099:古江川"),
-            ("230100", "230100:大井川"),
-            ("230101", "230101:片名川"),
-            ("230102", "230102:高浜谷川"),
-            ("230103", "230103:鳥居川"),
-            ("230104", "230104:小野浦川"),
-            ("230105", "230105:八幡川"),
-            ("230106", "230106:富具崎川"),
-            ("230107", "230107:杉谷川"),
-            ("230108", "230108:大谷川"),
-            ("230109", "230109:境川"),
-            ("230110", "230110:唐崎川"),
-            ("230111", "230111:樽水川"),
-            ("230112", "230112:大落川"),
-            ("230113", "230113:井口川"),
-            ("230114", "230114:美濃川"),
-            ("230115", "230115:土留木川"),
-            ("240001", "240001:員弁川"),
-            ("240002", "240002:朝明川"),
-            ("240003", "240003:海蔵川"),
-            ("240004", "240004:三滝川"),
-            ("240005", "240005:天白川"),
-            ("240006", "240006:金沢川"),
-            ("240007", "240007:堀切川"),
-            ("240008", "240008:中ノ川"),
-            ("240009", "240009:田中川"),
-            ("240010", "240010:志登茂川"),
-            ("240011", "240011:安濃川"),
-            ("240012", "240012:岩田川"),
-            ("240013", "240013:相川"),
-            ("240014", "240014:碧川"),
-            ("240015", "240015:三渡川"),
-            ("240016", "240016:阪内川"),
-            ("240017", "240017:金剛川"),
-            ("240018", "240018:中川"),
-            ("240019", "240019:笹笛川"),
-            ("240020", "240020:大堀川"),
-            ("240021", "240021:江川"),
-            ("240022", "240022:外城田川"),
-            ("240023", "240023:堀通川"),
-            ("240024", "240024:紙漉川"),
-            ("240025", "240025:加茂川"),
-            ("240026", "240026:大吉川"),
-            ("240027", "240027:藤谷川"),
-            ("240028", "240028:磯部川"),
-            ("240029", "240029:日出川"),
-            ("240030", "240030:東海川"),
-            ("240031", "240031:前川"),
-            ("240032", "240032:西川"),
-            ("240033", "240033:迫子川"),
-            ("240034", "240034:清水川"),
-            ("240035", "240035:桧山路川"),
-            ("240036", "240036:南張川"),
-            ("240037", "240037:神津佐川"),
-            ("240038", "240038:泉川"),
-            ("240039", "240039:五ヶ所川"),
-            ("240040", "240040:中の谷川"),
-            ("240041", "240041:伊勢路川"),
-            ("240042", "240042:大川"),
-            ("240043", "240043:大江川"),
-            ("240044", "240044:河内川"),
-            ("240045", "240045:村山川"),
-            ("240046", "240046:小方川"),
-            ("240047", "240047:古和川"),
-            ("240048", "240048:奥川"),
-            ("240049", "240049:小名倉川"),
-            ("240050", "240050:片上川"),
-            ("240051", "240051:赤羽川"),
-            ("240052", "240052:大瀬川"),
-            ("240053", "240053:元谷川"),
-            ("240054", "240054:船津川"),
-            ("240055", "240055:銚子川"),
-            ("240056", "240056:北川"),
-            ("240057", "240057:中川"),
-            ("240058", "240058:矢ノ川"),
-            ("240059", "240059:田海道川"),
-            ("240060", "240060:沓川"),
-            ("240061", "240061:八十川"),
-            ("240062", "240062:小浜川"),
-            ("240063", "240063:古川"),
-            ("240064", "240064:逢川"),
-            ("240065", "240065:湊川"),
-            ("240066", "240066:里川"),
-            ("240067", "240067:熊野宮川"),
-            ("240068", "240068:西郷川"),
-            ("240069", "240069:井戸川"),
-            ("240070", "240070:志原川"),
-            ("240071", "240071:市木川"),
-            ("240072", "240072:尾呂志川"),
-            ("240073", "240073:井田川"),
-            ("240074", "240074:神内川"),
-            ("240075", "240075:松白川"),
-            ("240076", "240076:十四川"),
-            ("240077", "240077:米洗川"),
-            ("240078", "240078:北長太川"),
-            ("240079", "240079:一本木川"),
-            ("240080", "240080:二本木川"),
-            ("240081", "240081:白子川"),
-            ("240082", "240082:月見川"),
-            ("240083", "240083:川関川"),
-            ("240084", "240084:小海川"),
-            ("240085", "240085:長崎川"),
-            ("240086", "240086:松振川"),
-            ("240087", "240087:鞍川"),
-            ("240088", "240088:つつみの川"),
-            ("240089", "240089:一本松川"),
-            ("240090", "240090:水深川"),
-            ("240091", "240091:江田川"),
-            ("240092", "240092:清四郎川"),
-            ("240093", "240093:桧枝田川"),
-            ("240094", "240094:阿津里川"),
-            ("240096", "240096:おおばし川"),
-            ("240097", "240097:寺川"),
-            ("240098", "240098:前田川"),
-            ("240099", "240099:宮西川"),
-            ("240100", "240100:石光浦川"),
-            ("240101", "240101:下津浦川"),
-            ("240102", "240102:スルジ川"),
-            ("240103", "240103:形黒川"),
-            ("240104", "240104:福浦川"),
-            ("240105", "240105:三浦押淵越地川"),
-            ("240106", "240106:三浦大江越地川"),
-            ("240107", "240107:六川原川"),
-            ("240108", "240108:大方川"),
-            ("240109", "240109:道行川"),
-            ("240110", "240110:道方川"),
-            ("240111", "240111:荒谷川"),
-            ("240112", "240112:一里川"),
-            ("240113", "240113:東宮川"),
-            ("240114", "240114:伊勢地川"),
-            ("240115", "240115:下の谷川"),
-            ("240116", "240116:方座川"),
-            ("240117", "240117:奥の山川"),
-            ("240118", "240118:棚橋川"),
-            ("240119", "240119:新桑川"),
-            ("240120", "240120:福羅川"),
-            ("240121", "240121:谷山川"),
-            ("240122", "240122:寺山川"),
-            ("240123", "240123:大師川"),
-            ("240124", "240124:久野川"),
-            ("240125", "240125:横手川"),
-            ("240126", "240126:笠子川"),
-            ("240127", "240127:宮前川"),
-            ("240128", "240128:大谷川"),
-            ("240129", "240129:オマワキ川"),
-            ("240130", "240130:市ノ川"),
-            ("240131", "240131:田ノ谷川"),
-            ("240132", "240132:太地川"),
-            ("240133", "240133:中熊川"),
-            ("240134", "240134:玉戸川"),
-            ("240135", "240135:西谷川"),
-            ("240136", "240136:谷地川"),
-            ("240137", "240137:大川"),
-            ("240138", "240138:樫の木谷川"),
-            ("240139", "240139:厨川"),
-            ("240140", "240140:松原谷川"),
-            ("240141", "240141:長浜大谷川"),
-            ("240142", "240142:甫母大川"),
-            ("240143", "240143:オコ谷川"),
-            ("240144", "240144:西の谷川"),
-            ("240145", "240145:小向井川"),
-            ("240146", "240146:新田川"),
-            ("240147", "240147:遊木川"),
-            ("240148", "240148:波田須川"),
-            ("240149", "240149:流川"),
-            ("260001", "260001:久美谷川"),
-            ("260002", "260002:栃谷川"),
-            ("260003", "260003:川上谷川"),
-            ("260004", "260004:佐濃谷川"),
-            ("260005", "260005:木津川"),
-            ("260006", "260006:福田川"),
-            ("260007", "260007:新樋越川"),
-            ("260008", "260008:竹野川"),
-            ("260009", "260009:宇川"),
-            ("260010", "260010:吉野川"),
-            ("260011", "260011:筒川"),
-            ("260012", "260012:朝妻川"),
-            ("260013", "260013:犀川"),
-            ("260014", "260014:波見川"),
-            ("260015", "260015:世屋川"),
-            ("260016", "260016:畑川"),
-            ("260017", "260017:真名井川"),
-            ("260018", "260018:三田川"),
-            ("260019", "260019:男山川"),
-            ("260020", "260020:野田川"),
-            ("260021", "260021:宮川"),
-            ("260022", "260022:大手川"),
-            ("260023", "260023:大膳川"),
-            ("260024", "260024:神子川"),
-            ("260025", "260025:大雲川"),
-            ("260026", "260026:福井川"),
-            ("260027", "260027:高野川"),
-            ("260028", "260028:伊佐津川"),
-            ("260029", "260029:与保呂川"),
-            ("260030", "260030:祖母谷川"),
-            ("260031", "260031:志楽川"),
-            ("260032", "260032:朝来川"),
-            ("260033", "260033:河辺川"),
-            ("260034", "260034:大丹生川"),
-            ("260035", "260035:瀬崎川"),
-            ("260036", "260036:野原川"),
-            ("260037", "260037:掛津川"),
-            ("260038", "260038:落川"),
-            ("260039", "260039:カマヤ川"),
-            ("260040", "260040:真嶋谷川"),
-            ("260041", "260041:小川"),
-            ("260042", "260042:小泊川"),
-            ("260043", "260043:日出川"),
-            ("270001", "270001:東川"),
-            ("270002", "270002:大川"),
-            ("270003", "270003:番川"),
-            ("270004", "270004:茶屋川"),
-            ("270005", "270005:男里川"),
-            ("270006", "270006:樫井川"),
-            ("270007", "270007:田尻川"),
-            ("270008", "270008:佐野川"),
-            ("270009", "270009:見出川"),
-            ("270010", "270010:近木川"),
-            ("270011", "270011:津田川"),
-            ("270012", "270012:春木川"),
-            ("270013", "270013:大津川"),
-            ("270014", "270014:王子川"),
-            ("270015", "270015:芦田川"),
-            ("270016", "270016:石津川"),
-            ("270017", "270017:内川"),
-            ("270018", "270018:池詰川"),
-            ("280001", "280001:結川"),
-            ("280002", "280002:大栃川"),
-            ("280003", "280003:岸田川"),
-            ("280004", "280004:西川"),
-            ("280005", "280005:長谷川"),
-            ("280006", "280006:矢田川"),
-            ("280007", "280007:香住谷川"),
-            ("280008", "280008:上計川"),
-            ("280009", "280009:佐津川"),
-            ("280010", "280010:安木川"),
-            ("280011", "280011:須井川"),
-            ("280012", "280012:竹野川"),
-            ("280013", "280013:蓬川"),
-            ("280014", "280014:武庫川"),
-            ("280015", "280015:新川"),
-            ("280016", "280016:東川"),
-            ("280017", "280017:洗戎川"),
-            ("280018", "280018:夙川"),
-            ("280019", "280019:堀切川"),
-            ("280020", "280020:宮川"),
-            ("280021", "280021:芦屋川"),
-            ("280022", "280022:高橋川"),
-            ("280023", "280023:天上川"),
-            ("280024", "280024:住吉川"),
-            ("280025", "280025:西獺川"),
-            ("280026", "280026:天神川"),
-            ("280027", "280027:石屋川"),
-            ("280028", "280028:高羽川"),
-            ("280029", "280029:都賀川"),
-            ("280030", "280030:西郷川"),
-            ("280031", "280031:西谷川"),
-            ("280032", "280032:生田川"),
-            ("280033", "280033:鯉川"),
-            ("280034", "280034:宇治川"),
-            ("280035", "280035:新湊川"),
-            ("280036", "280036:妙法寺川"),
-            ("280037", "280037:千森川"),
-            ("280038", "280038:一ノ谷川"),
-            ("280039", "280039:塩屋谷川"),
-            ("280040", "280040:福田川"),
-            ("280041", "280041:山田川"),
-            ("280042", "280042:朝霧川"),
-            ("280043", "280043:明石川"),
-            ("280044", "280044:谷八木川"),
-            ("280045", "280045:赤根川"),
-            ("280046", "280046:瀬戸川"),
-            ("280047", "280047:喜瀬川"),
-            ("280048", "280048:泊川"),
-            ("280049", "280049:法華山谷川"),
-            ("280050", "280050:天川"),
-            ("280051", "280051:西浜川"),
-            ("280052", "280052:八家川"),
-            ("280053", "280053:市川"),
-            ("280054", "280054:野田川"),
-            ("280055", "280055:船場川"),
-            ("280056", "280056:夢前川"),
-            ("280057", "280057:汐入川"),
-            ("280058", "280058:大津茂川"),
-            ("280059", "280059:富島川"),
-            ("280060", "280060:亀の尾川"),
-            ("280061", "280061:大谷川"),
-            ("280062", "280062:苧谷川"),
-            ("280063", "280063:佐方川"),
-            ("280064", "280064:千種川"),
-            ("280065", "280065:大津川"),
-            ("280066", "280066:長谷川"),
-            ("280067", "280067:茶間川"),
-            ("280068", "280068:楠本川"),
-            ("280069", "280069:浦川"),
-            ("280070", "280070:砂川"),
-            ("280071", "280071:老松川"),
-            ("280072", "280072:佐野川"),
-            ("280073", "280073:生穂川"),
-            ("280074", "280074:大谷川"),
-            ("280075", "280075:志筑川"),
-            ("280076", "280076:岩戸川"),
-            ("280077", "280077:洲本川"),
-            ("280078", "280078:天川"),
-            ("280079", "280079:本庄川"),
-            ("280080", "280080:塩屋川"),
-            ("280081", "280081:津井川"),
-            ("280082", "280082:三原川"),
-            ("280083", "280083:鳥飼川"),
-            ("280084", "280084:都志川"),
-            ("280085", "280085:山田川"),
-            ("280086", "280086:覗川"),
-            ("280087", "280087:郡家川"),
-            ("280088", "280088:新川"),
-            ("280089", "280089:室津川"),
-            ("280090", "280090:育波川"),
-            ("280091", "280091:豊島川"),
-            ("280092", "280092:野島川"),
-            ("280093", "280093:松村川"),
-            ("280094", "280094:大谷川"),
-            ("300001", "300001:佐野川"),
-            ("300002", "300002:長野川"),
-            ("300003", "300003:狗子の川"),
-            ("300004", "300004:那智川"),
-            ("300005", "300005:天満川"),
-            ("300006", "300006:二河川"),
-            ("300007", "300007:与根子川"),
-            ("300008", "300008:太田川"),
-            ("300009", "300009:粉白川"),
-            ("300010", "300010:田無川"),
-            ("300011", "300011:田原川"),
-            ("300012", "300012:津荷川"),
-            ("300013", "300013:古座川"),
-            ("300014", "300014:神野川"),
-            ("300015", "300015:伊串川"),
-            ("300016", "300016:姫川"),
-            ("300017", "300017:宮川"),
-            ("300018", "300018:須賀川"),
-            ("300019", "300019:鬮野川"),
-            ("300020", "300020:二色川"),
-            ("300021", "300021:高富川"),
-            ("300022", "300022:貝岡川"),
-            ("300023", "300023:有田川"),
-            ("300024", "300024:田並川"),
-            ("300025", "300025:江田川"),
-            ("300026", "300026:田子川"),
-            ("300027", "300027:安指川"),
-            ("300028", "300028:和深川"),
-            ("300029", "300029:熊谷川"),
-            ("300030", "300030:里野西地川"),
-            ("300031", "300031:江住川"),
-            ("300032", "300032:江須の川"),
-            ("300033", "300033:和深川"),
-            ("300034", "300034:周参見川"),
-            ("300035", "300035:伊古木川"),
-            ("300036", "300036:日置川"),
-            ("300037", "300037:志原川"),
-            ("300038", "300038:市江川"),
-            ("300039", "300039:朝来帰川"),
-            ("300040", "300040:見草川"),
-            ("300041", "300041:袋川"),
-            ("300042", "300042:富田川"),
-            ("300043", "300043:安久川"),
-            ("300044", "300044:仙波谷川"),
-            ("300045", "300045:新川"),
-            ("300046", "300046:出井川"),
-            ("300047", "300047:名喜里川"),
-            ("300048", "300048:橋谷川"),
-            ("300049", "300049:左会津川"),
-            ("300050", "300050:芳養川"),
-            ("300051", "300051:井原川"),
-            ("300052", "300052:堺川"),
-            ("300053", "300053:埴田川"),
-            ("300054", "300054:南部川"),
-            ("300055", "300055:東岩代川"),
-            ("300056", "300056:西岩代川"),
-            ("300057", "300057:切目川"),
-            ("300058", "300058:富の川"),
-            ("300059", "300059:印南川"),
-            ("300060", "300060:津井川"),
-            ("300061", "300061:楠井川"),
-            ("300062", "300062:上野川"),
-            ("300063", "300063:野島川"),
-            ("300064", "300064:壁川"),
-            ("300065", "300065:王子川"),
-            ("300066", "300066:日高川"),
-            ("300067", "300067:南出川"),
-            ("300068", "300068:産湯川"),
-            ("300069", "300069:比井川"),
-            ("300070", "300070:由良川"),
-            ("300071", "300071:前田川"),
-            ("300072", "300072:西広川"),
-            ("300073", "300073:江上川"),
-            ("300074", "300074:広川"),
-            ("300075", "300075:山田川"),
-            ("300076", "300076:出合川"),
-            ("300077", "300077:有田川"),
-            ("300078", "300078:小島川"),
-            ("300079", "300079:小原川"),
-            ("300080", "300080:加茂川"),
-            ("300081", "300081:日方川"),
-            ("300082", "300082:亀の川"),
-            ("300083", "300083:堤川"),
-            ("300084", "300084:阿振川"),
-            ("300085", "300085:清水川"),
-            ("300086", "300086:鴨居川"),
-            ("300087", "300087:横浦川"),
-            ("300088", "300088:馬谷川"),
-            ("300089", "300089:東谷川"),
-            ("300090", "300090:名杭川"),
-            ("300091", "300091:神谷川"),
-            ("300092", "300092:松原川"),
-            ("300093", "300093:山田川"),
-            ("300094", "300094:大崎川"),
-            ("300095", "300095:川尻川"),
-            ("300096", "300096:大谷川"),
-            ("310001", "310001:大水落川"),
-            ("310002", "310002:加茂新川"),
-            ("310003", "310003:佐蛇川"),
-            ("310004", "310004:塩川"),
-            ("310005", "310005:宇田川"),
-            ("310006", "310006:妻木川"),
-            ("310007", "310007:谷川"),
-            ("310008", "310008:江東川"),
-            ("310009", "310009:阿弥陀川"),
-            ("310010", "310010:名和川"),
-            ("310011", "310011:真子川"),
-            ("310012", "310012:宮川"),
-            ("310013", "310013:下市川"),
-            ("310014", "310014:甲川"),
-            ("310015", "310015:黒川"),
-            ("310016", "310016:勝田川"),
-            ("310017", "310017:月の輪川"),
-            ("310018", "310018:化粧川"),
-            ("310019", "310019:本谷川"),
-            ("310020", "310020:八橋川"),
-            ("310021", "310021:茅町川"),
-            ("310022", "310022:元旧川"),
-            ("310023", "310023:洗川"),
-            ("310024", "310024:馬込川"),
-            ("310025", "310025:御幸川"),
-            ("310026", "310026:加勢蛇川"),
-            ("310027", "310027:由良川"),
-            ("310028", "310028:橋津川"),
-            ("310029", "310029:宇谷川"),
-            ("310030", "310030:原川"),
-            ("310031", "310031:園川"),
-            ("310032", "310032:石脇川"),
-            ("310033", "310033:勝部川"),
-            ("310034", "310034:永江川"),
-            ("310035", "310035:浜村川"),
-            ("310036", "310036:河内川"),
-            ("310037", "310037:内海川"),
-            ("310038", "310038:溝川"),
-            ("310039", "310039:塩見川"),
-            ("310040", "310040:蒲生川"),
-            ("310041", "310041:吉田川"),
-            ("310042", "310042:陸上川"),
-            ("310043", "310043:堀川"),
-            ("310044", "310044:浅中川"),
-            ("310045", "310045:御大師川"),
-            ("310046", "310046:系図川"),
-            ("310047", "310047:大谷川"),
-            ("310048", "310048:西谷川"),
-            ("310049", "310049:海蔵寺川"),
-            ("310050", "310050:別所川"),
-            ("310051", "310051:兵川"),
-            ("310052", "310052:清水川"),
-            ("310053", "310053:小浜川"),
-            ("310054", "310054:奥沢見川"),
-            ("310055", "310055:小沢見川"),
-            ("310056", "310056:西ノ川"),
-            ("320001", "320001:飯浦川"),
-            ("320002", "320002:二見川"),
-            ("320003", "320003:喜阿弥川"),
-            ("320004", "320004:益田川"),
-            ("320005", "320005:遠田川"),
-            ("320006", "320006:津田川"),
-            ("320007", "320007:沖田川"),
-            ("320008", "320008:土田川"),
-            ("320009", "320009:岡見川"),
-            ("320010", "320010:三隅川"),
-            ("320011", "320011:周布川"),
-            ("320012", "320012:青川川"),
-            ("320013", "320013:浜田川"),
-            ("320014", "320014:下府川"),
-            ("320015", "320015:唐鐘川"),
-            ("320016", "320016:久代川"),
-            ("320017", "320017:曲川"),
-            ("320018", "320018:敬川"),
-            ("320019", "320019:水尻川"),
-            ("320020", "320020:和木川"),
-            ("320021", "320021:新川"),
-            ("320022", "320022:東川"),
-            ("320023", "320023:大池川"),
-            ("320024", "320024:倉谷川"),
-            ("320025", "320025:福光川"),
-            ("320026", "320026:小浜川"),
-            ("320027", "320027:湯里川"),
-            ("320028", "320028:塩郷川"),
-            ("320029", "320029:潮川"),
-            ("320030", "320030:宅野川"),
-            ("320031", "320031:逢浜川"),
-            ("320032", "320032:静間川"),
-            ("320033", "320033:大原川"),
-            ("320034", "320034:波根川"),
-            ("320035", "320035:田儀川"),
-            ("320036", "320036:小田川"),
-            ("320037", "320037:十間川"),
-            ("320039", "320039:堀川"),
-            ("320040", "320040:八千代川"),
-            ("320041", "320041:猪目川"),
-            ("320042", "320042:唐川川"),
-            ("320043", "320043:相代川"),
-            ("320044", "320044:森田川"),
-            ("320045", "320045:澄水川"),
-            ("320046", "320046:里路川"),
-            ("320047", "320047:千酌路川"),
-            ("320048", "320048:千酌川"),
-            ("320049", "320049:八尾川"),
-            ("320050", "320050:加茂川"),
-            ("320051", "320051:都万川"),
-            ("320052", "320052:那久川"),
-            ("320053", "320053:油井川"),
-            ("320054", "320054:長尾田川"),
-            ("320055", "320055:重栖川"),
-            ("320056", "320056:代川"),
-            ("320057", "320057:久見川"),
-            ("320058", "320058:中村川"),
-            ("320059", "320059:元屋川"),
-            ("320060", "320060:飯美川"),
-            ("320061", "320061:春日川"),
-            ("320062", "320062:卯敷川"),
-            ("320063", "320063:大久川"),
-            ("320064", "320064:飯田川"),
-            ("320065", "320065:東郷川"),
-            ("320066", "320066:大川"),
-            ("320067", "320067:多井川"),
-            ("320068", "320068:境川"),
-            ("320069", "320069:諏訪川"),
-            ("320070", "320070:美田川"),
-            ("320071", "320071:三度川"),
-            ("320072", "320072:横尾川"),
-            ("320073", "320073:須津川"),
-            ("320074", "320074:平原川"),
-            ("320075", "320075:折居川"),
-            ("320076", "320076:原川"),
-            ("320077", "320077:第二新川"),
-            ("320078", "320078:塩田川"),
-            ("320079", "320079:藪川"),
-            ("320080", "320080:野田川"),
-            ("320081", "320081:布勢川"),
-            ("320082", "320082:釜谷川"),
-            ("320083", "320083:三津川"),
-            ("320084", "320084:坂浦川"),
-            ("320085", "320085:末路川"),
-            ("320086", "320086:大山２号川"),
-            ("320087", "320087:代官屋川"),
-            ("320088", "320088:籾ノ谷川"),
-            ("320089", "320089:白海士川"),
-            ("330001", "330001:石谷川"),
-            ("330002", "330002:伊里川"),
-            ("330003", "330003:馬場川"),
-            ("330004", "330004:新田川"),
-            ("330005", "330005:奥山川"),
-            ("330006", "330006:幸崎川"),
-            ("330007", "330007:幸田川"),
-            ("330008", "330008:江川"),
-            ("330009", "330009:笹ヶ瀬川"),
-            ("330010", "330010:倉敷川"),
-            ("330011", "330011:鴨川"),
-            ("330012", "330012:庄田川"),
-            ("330013", "330013:長谷川"),
-            ("330014", "330014:下村川"),
-            ("330015", "330015:小田川"),
-            ("330016", "330016:砂川"),
-            ("330017", "330017:溜川"),
-            ("330018", "330018:里見川"),
-            ("330019", "330019:大島川"),
-            ("330020", "330020:今立川"),
-            ("330021", "330021:吉田川"),
-            ("330022", "330022:用之江川"),
-            ("330023", "330023:中州川"),
-            ("330024", "330024:流川"),
-            ("330025", "330025:国頭港横川"),
-            ("330026", "330026:東新開縦川"),
-            ("330027", "330027:新十郎川"),
-            ("330028", "330028:中新開川"),
-            ("330029", "330029:白砂川"),
-            ("330030", "330030:早崎川"),
-            ("330031", "330031:汐入川"),
-            ("330032", "330032:隅田川"),
-            ("340001", "340001:手城川"),
-            ("340002", "340002:本谷川"),
-            ("340003", "340003:山南川"),
-            ("340004", "340004:才戸川"),
-            ("340005", "340005:新川"),
-            ("340006", "340006:羽原川"),
-            ("340007", "340007:本郷川"),
-            ("340008", "340008:藤井川"),
-            ("340009", "340009:太田川"),
-            ("340010", "340010:栗原川"),
-            ("340011", "340011:和久原川"),
-            ("340012", "340012:西野川"),
-            ("340013", "340013:沼田川"),
-            ("340014", "340014:本川"),
-            ("340015", "340015:賀茂川"),
-            ("340016", "340016:木谷郷川"),
-            ("340017", "340017:三津大川"),
-            ("340018", "340018:蛇道川"),
-            ("340019", "340019:高野川"),
-            ("340020", "340020:野呂川"),
-            ("340021", "340021:黒瀬川"),
-            ("340022", "340022:大谷川"),
-            ("340023", "340023:堺川"),
-            ("340024", "340024:二河川"),
-            ("340025", "340025:総頭川"),
-            ("340026", "340026:矢野川"),
-            ("340027", "340027:尾崎川"),
-            ("340028", "340028:瀬野川"),
-            ("340029", "340029:八幡川"),
-            ("340030", "340030:岡の下川"),
-            ("340031", "340031:可愛川"),
-            ("340032", "340032:御手洗川"),
-            ("340033", "340033:永慶寺川"),
-            ("340034", "340034:大河内川"),
-            ("340035", "340035:倉崎川"),
-            ("340036", "340036:重井川"),
-            ("340037", "340037:大正川"),
-            ("340038", "340038:沖田川"),
-            ("340039", "340039:熱田川"),
-            ("340040", "340040:小原川"),
-            ("340041", "340041:原下川"),
-            ("340042", "340042:原田川"),
-            ("340043", "340043:大長川"),
-            ("340044", "340044:高田川"),
-            ("340045", "340045:田中川"),
-            ("340046", "340046:小鹿野川"),
-            ("340047", "340047:永田川"),
-            ("340048", "340048:古山田川"),
-            ("340049", "340049:真田川"),
-            ("340050", "340050:吉和川"),
-            ("340051", "340051:味潟川"),
-            ("340052", "340052:子之浦川"),
-            ("340053", "340053:三津口西川"),
-            ("340054", "340054:三津口谷川"),
-            ("340055", "340055:亀戸川"),
-            ("340056", "340056:日之浦南川"),
-            ("340057", "340057:日之浦北川"),
-            ("340058", "340058:小日之浦川"),
-            ("340059", "340059:仕形川"),
-            ("340060", "340060:大泊川"),
-            ("340061", "340061:塩谷東川"),
-            ("340062", "340062:塩谷西川"),
-            ("340063", "340063:小島川"),
-            ("340064", "340064:久多田川"),
-            ("340065", "340065:恵川"),
-            ("340066", "340066:新涯川"),
-            ("340067", "340067:原川"),
-            ("340068", "340068:迫田川"),
-            ("340069", "340069:中野川"),
-            ("350001", "350001:麻里布川"),
-            ("350002", "350002:錦川"),
-            ("350003", "350003:平田川"),
-            ("350004", "350004:通津川"),
-            ("350005", "350005:由宇川"),
-            ("350006", "350006:室岡川"),
-            ("350007", "350007:滝川"),
-            ("350008", "350008:石神川"),
-            ("350009", "350009:境川"),
-            ("350010", "350010:片野川"),
-            ("350011", "350011:柳井川"),
-            ("350012", "350012:土穂石川"),
-            ("350013", "350013:荒木川"),
-            ("350014", "350014:大井川"),
-            ("350015", "350015:大内川"),
-            ("350016", "350016:田布施川"),
-            ("350017", "350017:桜川"),
-            ("350018", "350018:光井川"),
-            ("350019", "350019:島田川"),
-            ("350020", "350020:西の河原川"),
-            ("350021", "350021:切戸川"),
-            ("350022", "350022:玉鶴川"),
-            ("350023", "350023:平田川"),
-            ("350024", "350024:末武川"),
-            ("350025", "350025:西光寺川"),
-            ("350026", "350026:東川"),
-            ("350027", "350027:富田川"),
-            ("350028", "350028:夜市川"),
-            ("350029", "350029:新川"),
-            ("350030", "350030:柳川"),
-            ("350031", "350031:馬刀川"),
-            ("350032", "350032:江川"),
-            ("350033", "350033:河内川"),
-            ("350034", "350034:長沢川"),
-            ("350035", "350035:南若川"),
-            ("350036", "350036:椹野川"),
-            ("350037", "350037:幸之江川"),
-            ("350038", "350038:土路石川"),
-            ("350039", "350039:井関川"),
-            ("350040", "350040:植松川"),
-            ("350041", "350041:五反田川"),
-            ("350042", "350042:沢波川"),
-            ("350043", "350043:江頭川"),
-            ("350044", "350044:真締川"),
-            ("350045", "350045:三角田川"),
-            ("350046", "350046:厚東川"),
-            ("350047", "350047:有帆川"),
-            ("350048", "350048:厚狭川"),
-            ("350049", "350049:前場川"),
-            ("350050", "350050:糸根川"),
-            ("350051", "350051:木屋川"),
-            ("350052", "350052:浜田川"),
-            ("350053", "350053:神田川"),
-            ("350054", "350054:新川"),
-            ("350055", "350055:武久川"),
-            ("350056", "350056:明見田川"),
-            ("350057", "350057:綾羅木川"),
-            ("350058", "350058:梶栗川"),
-            ("350059", "350059:友田川"),
-            ("350060", "350060:西田川"),
-            ("350061", "350061:永田川"),
-            ("350062", "350062:草場川"),
-            ("350063", "350063:黒井川"),
-            ("350064", "350064:吉永川"),
-            ("350065", "350065:川棚川"),
-            ("350066", "350066:二見川"),
-            ("350067", "350067:矢玉川"),
-            ("350068", "350068:荒田川"),
-            ("350069", "350069:島戸川"),
-            ("350070", "350070:沖田川"),
-            ("350071", "350071:粟野川"),
-            ("350072", "350072:泉川"),
-            ("350073", "350073:浅井川"),
-            ("350074", "350074:掛淵川"),
-            ("350075", "350075:小田川"),
-            ("350076", "350076:本郷川"),
-            ("350077", "350077:走下川"),
-            ("350078", "350078:深川川"),
-            ("350079", "350079:浅田川"),
-            ("350080", "350080:三隅川"),
-            ("350081", "350081:三見川"),
-            ("350082", "350082:阿武川"),
-            ("350083", "350083:大谷川"),
-            ("350084", "350084:庄屋川"),
-            ("350085", "350085:大井川"),
-            ("350086", "350086:郷川"),
-            ("350087", "350087:木与川"),
-            ("350088", "350088:宇田川"),
-            ("350089", "350089:白須川"),
-            ("350090", "350090:まてかた川"),
-            ("350091", "350091:須佐川"),
-            ("350092", "350092:津田川"),
-            ("350093", "350093:江津川"),
-            ("350094", "350094:田万川"),
-            ("350095", "350095:坂本川"),
-            ("350096", "350096:屋代川"),
-            ("350097", "350097:三蒲川"),
-            ("350098", "350098:椋野本川"),
-            ("350099", "350099:庄地川"),
-            ("350100", "350100:流田川"),
-            ("350101", "350101:津原川"),
-            ("350102", "350102:宮崎川"),
-            ("350103", "350103:山根川"),
-            ("350104", "350104:立田川"),
-            ("350105", "350105:宮川"),
-            ("350106", "350106:藤岡川"),
-            ("350107", "350107:地蔵川"),
-            ("350108", "350108:長野川"),
-            ("350109", "350109:大田川"),
-            ("350110", "350110:竜華川"),
-            ("350111", "350111:大木尾川"),
-            ("350112", "350112:神出川"),
-            ("350113", "350113:上八川"),
-            ("350114", "350114:中村川"),
-            ("350115", "350115:向田川"),
-            ("350116", "350116:走田川"),
-            ("350117", "350117:相の浦川"),
-            ("350118", "350118:水無川"),
-            ("350119", "350119:大谷川"),
-            ("350120", "350120:大踏川"),
-            ("350121", "350121:西光寺川"),
-            ("350122", "350122:梅花川"),
-            ("350123", "350123:末田川"),
-            ("350124", "350124:水尻川"),
-            ("350125", "350125:勘場川"),
-            ("350126", "350126:古川"),
-            ("350127", "350127:堂面川"),
-            ("350128", "350128:手水川"),
-            ("350129", "350129:湯ノ尻川"),
-            ("350130", "350130:清水川"),
-            ("350131", "350131:団平川"),
-            ("350132", "350132:中ノ浦川"),
-            ("350133", "350133:堰戸川"),
-            ("350134", "350134:矢石川"),
-            ("350135", "350135:浜田川"),
-            ("350136", "350136:中川"),
-            ("350137", "350137:印内川"),
-            ("350138", "350138:田中川"),
-            ("350139", "350139:塩田川"),
-            ("350140", "350140:汐入川"),
-            ("350141", "350141:河原川"),
-            ("350142", "350142:新田川"),
-            ("350143", "350143:磯上川"),
-            ("350144", "350144:鳥越川"),
-            ("350145", "350145:水無川"),
-            ("350146", "350146:水尻川"),
-            ("350147", "350147:稲荷川"),
-            ("350148", "350148:堂道川"),
-            ("350149", "350149:犬啼川"),
-            ("350150", "350150:後浜川"),
-            ("350151", "350151:犬鳴川"),
-            ("350152", "350152:湯玉川"),
-            ("350153", "350153:中山川"),
-            ("350154", "350154:本郷川"),
-            ("350155", "350155:桜川"),
-            ("350156", "350156:新川"),
-            ("350157", "350157:十楽川"),
-            ("350158", "350158:下郷川"),
-            ("350159", "350159:江ノ川"),
-            ("350160", "350160:小浜川"),
-            ("350161", "350161:西ヶ浴川"),
-            ("350162", "350162:浜田川"),
-            ("350163", "350163:本城川"),
-            ("350164", "350164:真浜川"),
-            ("350165", "350165:大日比川"),
-            ("350166", "350166:黒瀬川"),
-            ("350167", "350167:田ノ浦川"),
-            ("350168", "350168:中ノ浴川"),
-            ("350169", "350169:日々の木川"),
-            ("350170", "350170:流川"),
-            ("350171", "350171:友信川"),
-            ("350172", "350172:粕田川"),
-            ("350173", "350173:里川"),
-            ("350174", "350174:御開作大道川"),
-            ("350175", "350175:大素川"),
-            ("350176", "350176:保津川"),
-            ("350177", "350177:宮川"),
-            ("350178", "350178:壇具川"),
-            ("350179", "350179:勘場川"),
-            ("350180", "350180:前田川"),
-            ("350181", "350181:大谷川"),
-            ("360001", "360001:大須川"),
-            ("360002", "360002:折野川"),
-            ("360003", "360003:大浦川"),
-            ("360004", "360004:粟田川"),
-            ("360005", "360005:櫛木川"),
-            ("360006", "360006:明神川"),
-            ("360007", "360007:勝浦川"),
-            ("360008", "360008:神田瀬川"),
-            ("360009", "360009:立江川"),
-            ("360010", "360010:太田川"),
-            ("360011", "360011:幾島川"),
-            ("360012", "360012:落合川"),
-            ("360013", "360013:苅屋川"),
-            ("360014", "360014:打樋川"),
-            ("360015", "360015:鵜川"),
-            ("360016", "360016:福井川"),
-            ("360017", "360017:椿川"),
-            ("360018", "360018:伊座利川"),
-            ("360019", "360019:阿部東川"),
-            ("360020", "360020:志和岐川"),
-            ("360021", "360021:田井川"),
-            ("360022", "360022:喜多地川"),
-            ("360023", "360023:木岐川"),
-            ("360024", "360024:田井川"),
-            ("360025", "360025:日和佐川"),
-            ("360026", "360026:奥潟川"),
-            ("360027", "360027:東谷川"),
-            ("360028", "360028:牟岐川"),
-            ("360029", "360029:瀬戸川"),
-            ("360030", "360030:内妻川"),
-            ("360031", "360031:古江川"),
-            ("360032", "360032:鯖瀬川"),
-            ("360033", "360033:粟の浦川"),
-            ("360034", "360034:伊勢田川"),
-            ("360035", "360035:浦上川"),
-            ("360036", "360036:海部川"),
-            ("360037", "360037:江川"),
-            ("360038", "360038:穴喰川"),
-            ("360039", "360039:鳥ヶ丸川"),
-            ("360040", "360040:宿毛谷川"),
-            ("360041", "360041:辷岩水尾川"),
-            ("360042", "360042:須屋川"),
-            ("360043", "360043:魚呑川"),
-            ("360044", "360044:西の地川"),
-            ("360045", "360045:観音寺川"),
-            ("360046", "360046:福良川"),
-            ("360047", "360047:小鯖瀬川"),
-            ("360048", "360048:那佐川"),
-            ("360049", "360049:板取西川"),
-            ("360050", "360050:正梶川"),
-            ("360051", "360051:正梶南谷川"),
-            ("360052", "360052:古目谷川"),
-            ("360053", "360053:島田川"),
-            ("360054", "360054:高島大水尾川"),
-            ("360055", "360055:中島水尾川"),
-            ("360056", "360056:アイノ水尾川"),
-            ("360057", "360057:大溜谷川"),
-            ("370001", "370001:四方堂川"),
-            ("370002", "370002:吉田川"),
-            ("370003", "370003:白坂川"),
-            ("370004", "370004:唐井手川"),
-            ("370005", "370005:柞田川"),
-            ("370006", "370006:一の谷川"),
-            ("370007", "370007:財田川"),
-            ("370008", "370008:苧扱川"),
-            ("370009", "370009:天王川"),
-            ("370010", "370010:江尻川"),
-            ("370011", "370011:田井川"),
-            ("370012", "370012:瀬入川"),
-            ("370013", "370013:高瀬川"),
-            ("370014", "370014:弘田川"),
-            ("370015", "370015:桜川"),
-            ("370016", "370016:金倉川"),
-            ("370017", "370017:西汐入川"),
-            ("370018", "370018:大束川"),
-            ("370019", "370019:綾川"),
-            ("370020", "370020:青海川"),
-            ("370021", "370021:野田川"),
-            ("370022", "370022:玉川"),
-            ("370023", "370023:亀水川"),
-            ("370024", "370024:住吉川"),
-            ("370025", "370025:本津川"),
-            ("370026", "370026:香東川"),
-            ("370027", "370027:摺鉢谷川"),
-            ("370028", "370028:詰田川"),
-            ("370029", "370029:新川"),
-            ("370030", "370030:相引川"),
-            ("370031", "370031:牟礼川"),
-            ("370032", "370032:長者川"),
-            ("370033", "370033:下井手川"),
-            ("370034", "370034:玉浦川"),
-            ("370035", "370035:大橋川"),
-            ("370036", "370036:弁天川"),
-            ("370037", "370037:鴨部川"),
-            ("370038", "370038:滝川"),
-            ("370039", "370039:津田川"),
-            ("370040", "370040:梅川"),
-            ("370041", "370041:梶川"),
-            ("370042", "370042:西代川"),
-            ("370043", "370043:東代川"),
-            ("370044", "370044:大山川"),
-            ("370045", "370045:田尻川"),
-            ("370046", "370046:番屋川"),
-            ("370047", "370047:与田川"),
-            ("370048", "370048:古川"),
-            ("370049", "370049:湊川"),
-            ("370050", "370050:中川"),
-            ("370051", "370051:小海川"),
-            ("370052", "370052:古川"),
-            ("370053", "370053:足谷川"),
-            ("370054", "370054:馬宿川"),
-            ("370055", "370055:菜切川"),
-            ("370056", "370056:坂本川"),
-            ("370057", "370057:大谷川"),
-            ("370058", "370058:小瀬桂川"),
-            ("370059", "370059:皇踏川"),
-            ("370060", "370060:橘川"),
-            ("370061", "370061:桂川"),
-            ("370062", "370062:東川"),
-            ("370063", "370063:吉田川"),
-            ("370064", "370064:伊豆川"),
-            ("370065", "370065:森庄川"),
-            ("370066", "370066:城石川"),
-            ("370067", "370067:向条川"),
-            ("370068", "370068:中筋川"),
-            ("370069", "370069:安田大川"),
-            ("370070", "370070:木庄川"),
-            ("370071", "370071:片城川"),
-            ("370072", "370072:別当川"),
-            ("370073", "370073:明神川"),
-            ("370074", "370074:春田川"),
-            ("370075", "370075:競川"),
-            ("370076", "370076:池田大川"),
-            ("370077", "370077:角田川"),
-            ("370078", "370078:伝法川"),
-            ("370079", "370079:春日川"),
-            ("370080", "370080:新川"),
-            ("370081", "370081:古江上川"),
-            ("370082", "370082:久保谷川"),
-            ("370083", "370083:不動川"),
-            ("370084", "370084:久通川"),
-            ("370085", "370085:南久通川"),
-            ("370086", "370086:大丁場川"),
-            ("370087", "370087:中丁場川"),
-            ("370088", "370088:大谷川"),
-            ("370089", "370089:ハミ谷川"),
-            ("370090", "370090:汐入川"),
-            ("370091", "370091:鎌野川"),
-            ("370092", "370092:シノオ北川"),
-            ("370093", "370093:シノオ南川"),
-            ("370094", "370094:鞍谷川"),
-            ("370095", "370095:コウ尻北川"),
-            ("370096", "370096:コウ尻南川"),
-            ("370097", "370097:宮北川"),
-            ("370098", "370098:房前川"),
-            ("370099", "370099:中川"),
-            ("370100", "370100:タコウ川"),
-            ("370101", "370101:深田川"),
-            ("370102", "370102:ホウエイ川"),
-            ("370103", "370103:西の谷川"),
-            ("370104", "370104:平野川"),
-            ("370105", "370105:入部川"),
-            ("370106", "370106:前川"),
-            ("380001", "380001:脇本川"),
-            ("380002", "380002:惣川"),
-            ("380003", "380003:岡山川"),
-            ("380004", "380004:大駄川"),
-            ("380005", "380005:蓮乗寺川"),
-            ("380006", "380006:僧都川"),
-            ("380007", "380007:長洲川"),
-            ("380008", "380008:神田川"),
-            ("380009", "380009:菊川"),
-            ("380010", "380010:柏川"),
-            ("380011", "380011:中実川"),
-            ("380012", "380012:神田川"),
-            ("380013", "380013:岩松川"),
-            ("380014", "380014:本谷川"),
-            ("380015", "380015:来村川"),
-            ("380016", "380016:神田川"),
-            ("380017", "380017:辰野川"),
-            ("380018", "380018:須賀川"),
-            ("380019", "380019:広見川"),
-            ("380020", "380020:立間尻川"),
-            ("380021", "380021:立間川"),
-            ("380022", "380022:鶴間川"),
-            ("380023", "380023:渡川"),
-            ("380024", "380024:日ノ地川"),
-            ("380025", "380025:成谷川"),
-            ("380026", "380026:東川"),
-            ("380027", "380027:宮崎川"),
-            ("380028", "380028:大川"),
-            ("380029", "380029:皆江大川"),
-            ("380030", "380030:三島川"),
-            ("380031", "380031:谷道川"),
-            ("380032", "380032:朝立川"),
-            ("380033", "380033:東川"),
-            ("380034", "380034:川上大川"),
-            ("380035", "380035:蟻王川"),
-            ("380036", "380036:千丈川"),
-            ("380037", "380037:喜木川"),
-            ("380038", "380038:宮内川"),
-            ("380039", "380039:伊方大川"),
-            ("380040", "380040:伊方新川"),
-            ("380041", "380041:九町新川"),
-            ("380042", "380042:三崎大川"),
-            ("380043", "380043:大谷川"),
-            ("380044", "380044:高茂川"),
-            ("380045", "380045:丸田川"),
-            ("380046", "380046:峯川"),
-            ("380047", "380047:中之谷川"),
-            ("380048", "380048:磯崎里川"),
-            ("380049", "380049:河原川"),
-            ("380050", "380050:横松郷川"),
-            ("380051", "380051:富貴川"),
-            ("380052", "380052:本村川"),
-            ("380053", "380053:池之窪川"),
-            ("380054", "380054:豊田川"),
-            ("380055", "380055:日喰川"),
-            ("380056", "380056:富岡川"),
-            ("380057", "380057:潤住川"),
-            ("380058", "380058:石の久保川"),
-            ("380059", "380059:本谷川"),
-            ("380060", "380060:長薮川"),
-            ("380061", "380061:引地川"),
-            ("380062", "380062:上灘川"),
-            ("380063", "380063:小網川"),
-            ("380064", "380064:出口川"),
-            ("380065", "380065:畑川"),
-            ("380066", "380066:森川"),
-            ("380067", "380067:天神川"),
-            ("380068", "380068:古小川"),
-            ("380069", "380069:大谷川"),
-            ("380070", "380070:長尾谷川"),
-            ("380071", "380071:国近川"),
-            ("380072", "380072:洗地川"),
-            ("380073", "380073:堂ノ元川"),
-            ("380074", "380074:宮前川"),
-            ("380075", "380075:大川"),
-            ("380076", "380076:明神川"),
-            ("380077", "380077:権現川"),
-            ("380078", "380078:郷谷川"),
-            ("380079", "380079:払川"),
-            ("380080", "380080:粟井川"),
-            ("380081", "380081:片平川"),
-            ("380082", "380082:高山川"),
-            ("380083", "380083:河野川"),
-            ("380084", "380084:立岩川"),
-            ("380085", "380085:小久保川"),
-            ("380086", "380086:谷川"),
-            ("380087", "380087:石風呂川"),
-            ("380088", "380088:山本川"),
-            ("380089", "380089:仙波川"),
-            ("380090", "380090:菊間川"),
-            ("380091", "380091:種川"),
-            ("380092", "380092:佐方川"),
-            ("380093", "380093:ナラズ川"),
-            ("380094", "380094:天満川"),
-            ("380095", "380095:山之内川"),
-            ("380096", "380096:宮脇川"),
-            ("380097", "380097:一心川"),
-            ("380098", "380098:品部川"),
-            ("380099", "380099:樋之口川"),
-            ("380100", "380100:中井出川"),
-            ("380101", "380101:浅川"),
-            ("380102", "380102:蒼社川"),
-            ("380103", "380103:竜登川"),
-            ("380104", "380104:銅川"),
-            ("380105", "380105:頓田川"),
-            ("380106", "380106:大川"),
-            ("380107", "380107:中川"),
-            ("380108", "380108:北川"),
-            ("380109", "380109:小向川"),
-            ("380110", "380110:大明神川"),
-            ("380111", "380111:境川"),
-            ("380112", "380112:新川"),
-            ("380113", "380113:大曲川"),
-            ("380114", "380114:崩口川"),
-            ("380115", "380115:一ツ橋川"),
-            ("380116", "380116:広江川"),
-            ("380117", "380117:中山川"),
-            ("380118", "380118:前神寺谷川"),
-            ("380119", "380119:加茂川"),
-            ("380120", "380120:渦井川"),
-            ("380121", "380121:王子川"),
-            ("380122", "380122:東川"),
-            ("380123", "380123:尻無川"),
-            ("380124", "380124:国領川"),
-            ("380125", "380125:落神川"),
-            ("380126", "380126:又野川"),
-            ("380127", "380127:阿島川"),
-            ("380128", "380128:荷内川"),
-            ("380129", "380129:千々ノ木川"),
-            ("380130", "380130:関川"),
-            ("380131", "380131:檜木川"),
-            ("380132", "380132:面白川"),
-            ("380133", "380133:大地川"),
-            ("380134", "380134:隅田川"),
-            ("380135", "380135:鎌谷川"),
-            ("380136", "380136:豊岡川"),
-            ("380137", "380137:西谷川"),
-            ("380138", "380138:樋ノ尾谷川"),
-            ("380139", "380139:大谷川"),
-            ("380140", "380140:井関川"),
-            ("380141", "380141:宮川"),
-            ("380142", "380142:寺川"),
-            ("380143", "380143:海岸寺川"),
-            ("380144", "380144:赤之井川"),
-            ("380145", "380145:川茂川"),
-            ("380146", "380146:契川"),
-            ("380147", "380147:金生川"),
-            ("380148", "380148:三谷川"),
-            ("380149", "380149:千歳川"),
-            ("380150", "380150:大川"),
-            ("380151", "380151:皇ノ川"),
-            ("380152", "380152:天神川"),
-            ("380153", "380153:山狩川"),
-            ("380154", "380154:流内川"),
-            ("380155", "380155:中村川"),
-            ("380156", "380156:沢津川"),
-            ("380157", "380157:引野川"),
-            ("380158", "380158:船越川"),
-            ("380159", "380159:長江川"),
-            ("380160", "380160:小漕川"),
-            ("380161", "380161:樋ノ口川"),
-            ("380162", "380162:中川"),
-            ("380163", "380163:戸板川"),
-            ("380164", "380164:古戸川"),
-            ("380165", "380165:井口本川"),
-            ("380166", "380166:竹下川"),
-            ("380167", "380167:高知川"),
-            ("380168", "380168:明日本川"),
-            ("380169", "380169:宮浦本川"),
-            ("380170", "380170:台本川"),
-            ("380171", "380171:野々江大川"),
-            ("380172", "380172:口総大川"),
-            ("380173", "380173:大川"),
-            ("380174", "380174:余所国東川"),
-            ("380175", "380175:泊北谷川"),
-            ("380176", "380176:相田川"),
-            ("380177", "380177:浜田川"),
-            ("380178", "380178:北谷川"),
-            ("380179", "380179:仁江川"),
-            ("380180", "380180:舫大川"),
-            ("380181", "380181:元ノ浦川"),
-            ("380182", "380182:内之洲川"),
-            ("380183", "380183:畑枝川"),
-            ("380184", "380184:谷川"),
-            ("380185", "380185:名坂川"),
-            ("380186", "380186:重田川"),
-            ("380187", "380187:先目川"),
-            ("380188", "380188:久藤川"),
-            ("380189", "380189:久浦松川"),
-            ("380190", "380190:前浜川"),
-            ("380191", "380191:明神川"),
-            ("380192", "380192:三机大川"),
-            ("380193", "380193:三机東大川"),
-            ("380194", "380194:足成大川"),
-            ("380195", "380195:梢川"),
-            ("380196", "380196:古小川"),
-            ("380197", "380197:三反地川"),
-            ("380198", "380198:長沢川"),
-            ("380199", "380199:太山寺川"),
-            ("380200", "380200:中溝川"),
-            ("380201", "380201:泉川"),
-            ("380202", "380202:三反地川"),
-            ("380203", "380203:名切川"),
-            ("380204", "380204:拝志川"),
-            ("380205", "380205:宮川"),
-            ("380206", "380206:灘川"),
-            ("380207", "380207:影平川"),
-            ("380208", "380208:脇川"),
-            ("380209", "380209:安広川"),
-            ("380210", "380210:隅田川"),
-            ("380211", "380211:錆川"),
-            ("380212", "380212:天神川"),
-            ("380213", "380213:柳川"),
-            ("380214", "380214:本川"),
-            ("380215", "380215:三ツ杭川"),
-            ("380216", "380216:出海川"),
-            ("390001", "390001:小池川"),
-            ("390002", "390002:河内川"),
-            ("390003", "390003:生見川"),
-            ("390004", "390004:相間川"),
-            ("390005", "390005:野根川"),
-            ("390006", "390006:入木川"),
-            ("390007", "390007:佐喜浜川"),
-            ("390008", "390008:弥谷川"),
-            ("390009", "390009:尾崎川"),
-            ("390010", "390010:椎名川"),
-            ("390011", "390011:室津川"),
-            ("390012", "390012:奈良師川"),
-            ("390013", "390013:岩谷川"),
-            ("390014", "390014:元川"),
-            ("390015", "390015:盲堂谷川"),
-            ("390016", "390016:津以谷川"),
-            ("390017", "390017:東の川"),
-            ("390018", "390018:西の川"),
-            ("390019", "390019:黒茂谷川"),
-            ("390020", "390020:羽根川"),
-            ("390021", "390021:加曽谷川"),
-            ("390022", "390022:赤木谷川"),
-            ("390023", "390023:須川川"),
-            ("390024", "390024:長谷川"),
-            ("390025", "390025:奈半利川"),
-            ("390026", "390026:安田川"),
-            ("390027", "390027:東谷川"),
-            ("390028", "390028:名村川"),
-            ("390029", "390029:伊尾木川"),
-            ("390030", "390030:安芸川"),
-            ("390031", "390031:穴内川"),
-            ("390032", "390032:赤野川"),
-            ("390033", "390033:和食川"),
-            ("390034", "390034:夜須川"),
-            ("390035", "390035:香宗川"),
-            ("390036", "390036:東沢川"),
-            ("390037", "390037:十市川"),
-            ("390038", "390038:下田川"),
-            ("390039", "390039:国分川"),
-            ("390040", "390040:鏡川"),
-            ("390041", "390041:竹島川"),
-            ("390042", "390042:西谷川"),
-            ("390043", "390043:新川川"),
-            ("390044", "390044:中口川"),
-            ("390045", "390045:萩谷川"),
-            ("390046", "390046:灰方川"),
-            ("390047", "390047:塩間川"),
-            ("390048", "390048:出見川"),
-            ("390049", "390049:摺木川"),
-            ("390050", "390050:奥浦川"),
-            ("390051", "390051:中の谷川"),
-            ("390052", "390052:桜川"),
-            ("390053", "390053:御手洗川"),
-            ("390054", "390054:イケノウチ川"),
-            ("390055", "390055:新荘川"),
-            ("390056", "390056:安和川"),
-            ("390057", "390057:久礼川"),
-            ("390058", "390058:大坂谷川"),
-            ("390059", "390059:上の加江川"),
-            ("390060", "390060:小矢井賀川"),
-            ("390061", "390061:矢井賀川"),
-            ("390062", "390062:志和川"),
-            ("390063", "390063:後川"),
-            ("390064", "390064:伊与木川"),
-            ("390065", "390065:伊田川"),
-            ("390066", "390066:有井川"),
-            ("390067", "390067:蜷川"),
-            ("390068", "390068:東分川"),
-            ("390069", "390069:湊川"),
-            ("390070", "390070:加持川"),
-            ("390071", "390071:蛎瀬川"),
-            ("390072", "390072:田野浦川"),
-            ("390073", "390073:西間川"),
-            ("390074", "390074:渕の川"),
-            ("390075", "390075:立石川"),
-            ("390076", "390076:布川"),
-            ("390077", "390077:下の加江川"),
-            ("390078", "390078:鍵掛川"),
-            ("390079", "390079:久百百川"),
-            ("390080", "390080:大岐川"),
-            ("390081", "390081:以布利川"),
-            ("390082", "390082:浦尻川"),
-            ("390083", "390083:加久見川"),
-            ("390084", "390084:益野川"),
-            ("390085", "390085:三崎川"),
-            ("390086", "390086:宗呂川"),
-            ("390087", "390087:貝の川川"),
-            ("390088", "390088:小才角川"),
-            ("390089", "390089:才角川"),
-            ("390090", "390090:大浦川"),
-            ("390091", "390091:周防形川"),
-            ("390092", "390092:頭集川"),
-            ("390093", "390093:平山川"),
-            ("390094", "390094:福良川"),
-            ("390095", "390095:伊与野川"),
-            ("390096", "390096:松田川"),
-            ("390097", "390097:与市明川"),
-            ("390098", "390098:東川川"),
-            ("390099", "390099:菅川"),
-            ("390100", "390100:鈴川"),
-            ("390101", "390101:小鈴川"),
-            ("390102", "390102:スガル川"),
-            ("390103", "390103:熊の浦東谷川"),
-            ("390104", "390104:熊の浦西谷川"),
-            ("390105", "390105:久保浦川"),
-            ("390106", "390106:西分川"),
-            ("390107", "390107:深木川"),
-            ("390108", "390108:仁崎川"),
-            ("390109", "390109:塩屋谷川"),
-            ("390110", "390110:菅川"),
-            ("390111", "390111:南の谷川"),
-            ("390112", "390112:大神谷川"),
-            ("390113", "390113:田の浦川"),
-            ("390114", "390114:志沢尾川"),
-            ("400001", "400001:諏訪川（関川）"),
-            ("400002", "400002:大牟田川"),
-            ("400003", "400003:堂面川"),
-            ("400004", "400004:隅川"),
-            ("400005", "400005:福吉川"),
-            ("400006", "400006:加茂川"),
-            ("400007", "400007:一貴山川"),
-            ("400008", "400008:雷山川"),
-            ("400009", "400009:桜井川"),
-            ("400010", "400010:瑞梅寺川"),
-            ("400011", "400011:江ノ口川"),
-            ("400012", "400012:七寺川"),
-            ("400013", "400013:十郎川"),
-            ("400014", "400014:名柄川"),
-            ("400015", "400015:室見川"),
-            ("400016", "400016:樋井川"),
-            ("400017", "400017:那珂川"),
-            ("400018", "400018:御笠川"),
-            ("400019", "400019:多々良川"),
-            ("400020", "400020:唐の原川"),
-            ("400021", "400021:湊川"),
-            ("400022", "400022:大根川"),
-            ("400023", "400023:中川"),
-            ("400024", "400024:西郷川"),
-            ("400025", "400025:釣川"),
-            ("400026", "400026:汐入川"),
-            ("400027", "400027:矢矧川"),
-            ("400028", "400028:金手川"),
-            ("400029", "400029:金山川"),
-            ("400030", "400030:割子川"),
-            ("400031", "400031:撥川"),
-            ("400032", "400032:板櫃川"),
-            ("400033", "400033:紫川"),
-            ("400034", "400034:村中川"),
-            ("400035", "400035:大川"),
-            ("400036", "400036:清滝川"),
-            ("400037", "400037:奧畑川"),
-            ("400038", "400038:竹馬川"),
-            ("400039", "400039:貫川"),
-            ("400040", "400040:長峡川"),
-            ("400041", "400041:今川"),
-            ("400042", "400042:江尻川"),
-            ("400043", "400043:祓川"),
-            ("400044", "400044:音無川"),
-            ("400045", "400045:城井川"),
-            ("400046", "400046:上ノ河内川"),
-            ("400047", "400047:角田川"),
-            ("400048", "400048:中川"),
-            ("400049", "400049:岩岳川"),
-            ("400050", "400050:佐井川"),
-            ("400051", "400051:鹿家川"),
-            ("400052", "400052:柳川"),
-            ("400053", "400053:松末羅漢川"),
-            ("400054", "400054:羅漢川"),
-            ("400055", "400055:殿山川"),
-            ("400056", "400056:寺山川"),
-            ("400057", "400057:冲田川"),
-            ("400058", "400058:岐志新開川"),
-            ("400059", "400059:大坪川"),
-            ("400060", "400060:福ノ浦川"),
-            ("400061", "400061:小金丸川"),
-            ("400062", "400062:幸田川"),
-            ("400063", "400063:汐出川"),
-            ("400064", "400064:汐除川"),
-            ("400065", "400065:香椎川"),
-            ("400067", "400067:奴山川"),
-            ("400068", "400068:天籟寺川"),
-            ("400070", "400070:大野川"),
-            ("400071", "400071:朽網川"),
-            ("400072", "400072:読川"),
-            ("400073", "400073:舟入川"),
-            ("400074", "400074:鈴子川"),
-            ("400075", "400075:城根川"),
-            ("400076", "400076:経済川"),
-            ("400077", "400077:梨ノ木川"),
-            ("400078", "400078:御界川"),
-            ("400079", "400079:猿喰川"),
-            ("400080", "400080:手光今川"),
-            ("400081", "400081:相割川"),
-            ("410001", "410001:佐代川"),
-            ("410002", "410002:楠久川"),
-            ("410003", "410003:後川"),
-            ("410004", "410004:里川"),
-            ("410005", "410005:脇野川"),
-            ("410006", "410006:有田川"),
-            ("410007", "410007:伊万里川"),
-            ("410008", "410008:木須川"),
-            ("410009", "410009:黒塩川"),
-            ("410010", "410010:拝川"),
-            ("410011", "410011:立川"),
-            ("410012", "410012:波多津川"),
-            ("410013", "410013:田野新田川"),
-            ("410014", "410014:座川"),
-            ("410015", "410015:有浦川"),
-            ("410016", "410016:石田川"),
-            ("410017", "410017:浜野浦川"),
-            ("410018", "410018:八田川"),
-            ("410019", "410019:志礼川"),
-            ("410020", "410020:浦町川"),
-            ("410021", "410021:野元川"),
-            ("410022", "410022:潟川"),
-            ("410023", "410023:江頭川"),
-            ("410024", "410024:呼子川"),
-            ("410025", "410025:橋本川"),
-            ("410026", "410026:浦川"),
-            ("410027", "410027:佐志川"),
-            ("410028", "410028:玉島川"),
-            ("410029", "410029:福所江"),
-            ("410030", "410030:福富川"),
-            ("410031", "410031:只江川"),
-            ("410032", "410032:廻里江川"),
-            ("410033", "410033:塩田川"),
-            ("410034", "410034:鹿島川"),
-            ("410035", "410035:石木津川"),
-            ("410036", "410036:浜川"),
-            ("410037", "410037:西葉川"),
-            ("410038", "410038:母ヶ浦川"),
-            ("410039", "410039:音成川"),
-            ("410040", "410040:黒木川"),
-            ("410041", "410041:嘉瀬の浦川"),
-            ("410042", "410042:龍宿浦川"),
-            ("410043", "410043:飯田川"),
-            ("410044", "410044:江福川"),
-            ("410045", "410045:伊福川"),
-            ("410046", "410046:江岡川"),
-            ("410047", "410047:早垣川"),
-            ("410048", "410048:多良川"),
-            ("410049", "410049:嫁川"),
-            ("410050", "410050:糸岐川"),
-            ("410051", "410051:小田川"),
-            ("410052", "410052:波瀬ノ浦河"),
-            ("410053", "410053:休石川"),
-            ("410054", "410054:亀ノ浦川"),
-            ("410055", "410055:田古里川"),
-            ("410056", "410056:河内川"),
-            ("410057", "410057:大泊川"),
-            ("410058", "410058:波瀬川"),
-            ("410060", "410060:長浜川"),
-            ("410061", "410061:見上川"),
-            ("410062", "410062:煤屋川"),
-            ("410063", "410063:辻川"),
-            ("410064", "410064:梅崎川"),
-            ("410065", "410065:大鶴川"),
-            ("410066", "410066:黒形川"),
-            ("410067", "410067:渡瀬川"),
-            ("410068", "410068:鳩川川"),
-            ("410069", "410069:戊辰川"),
-            ("410070", "410070:東塩屋川"),
-            ("410071", "410071:干潟川"),
-            ("410072", "410072:福川内川"),
-            ("420001", "420001:今里川"),
-            ("420002", "420002:船津川"),
-            ("420003", "420003:小深井川"),
-            ("420004", "420004:長里川"),
-            ("420005", "420005:境川"),
-            ("420006", "420006:湯江川"),
-            ("420007", "420007:田島川"),
-            ("420008", "420008:小江川"),
-            ("420009", "420009:深海川"),
-            ("420010", "420010:段堂川"),
-            ("420011", "420011:仁反田川"),
-            ("420012", "420012:有明川"),
-            ("420013", "420013:千鳥川"),
-            ("420014", "420014:山田川"),
-            ("420015", "420015:田川原川"),
-            ("420016", "420016:土井川"),
-            ("420017", "420017:田内川"),
-            ("420018", "420018:船津川"),
-            ("420019", "420019:権現川"),
-            ("420020", "420020:松江川"),
-            ("420021", "420021:西郷川"),
-            ("420022", "420022:神代川"),
-            ("420023", "420023:倉地川"),
-            ("420024", "420024:土黒川"),
-            ("420025", "420025:多比良川"),
-            ("420026", "420026:栗谷川"),
-            ("420027", "420027:湯江川"),
-            ("420028", "420028:西川"),
-            ("420029", "420029:中尾川"),
-            ("420030", "420030:北川"),
-            ("420031", "420031:大手川"),
-            ("420032", "420032:白水川"),
-            ("420033", "420033:新湊川"),
-            ("420034", "420034:鮎川"),
-            ("420035", "420035:水無川"),
-            ("420036", "420036:深江川"),
-            ("420037", "420037:新川"),
-            ("420038", "420038:蒲河川"),
-            ("420039", "420039:有家川"),
-            ("420040", "420040:須川川"),
-            ("420041", "420041:竜石川"),
-            ("420042", "420042:大手川"),
-            ("420043", "420043:有馬川"),
-            ("420044", "420044:葉山川"),
-            ("420045", "420045:田町川"),
-            ("420046", "420046:六反田川"),
-            ("420047", "420047:中谷川"),
-            ("420048", "420048:貝瀬川"),
-            ("420049", "420049:堀川"),
-            ("420050", "420050:小松川"),
-            ("420051", "420051:小津波見川"),
-            ("420052", "420052:津波見川"),
-            ("420053", "420053:川内川"),
-            ("420054", "420054:境川"),
-            ("420055", "420055:千々石川"),
-            ("420056", "420056:唐比川"),
-            ("420057", "420057:有喜川"),
-            ("420058", "420058:江ノ浦川"),
-            ("420059", "420059:田結川"),
-            ("420060", "420060:戸石川"),
-            ("420061", "420061:八郎川"),
-            ("420062", "420062:日見川"),
-            ("420063", "420063:若菜川"),
-            ("420064", "420064:千々川"),
-            ("420065", "420065:大川"),
-            ("420066", "420066:宮崎川"),
-            ("420067", "420067:江川"),
-            ("420068", "420068:黒浜川"),
-            ("420069", "420069:蚊焼大川"),
-            ("420070", "420070:江川川"),
-            ("420071", "420071:鹿尾川"),
-            ("420072", "420072:中島川"),
-            ("420073", "420073:浦上川"),
-            ("420074", "420074:大浦川"),
-            ("420075", "420075:小江川"),
-            ("420076", "420076:手熊川"),
-            ("420077", "420077:式見川"),
-            ("420078", "420078:多以良川"),
-            ("420079", "420079:三重川"),
-            ("420080", "420080:川下川"),
-            ("420081", "420081:黒崎川"),
-            ("420082", "420082:出津川"),
-            ("420083", "420083:神ノ浦川"),
-            ("420084", "420084:雪ノ浦川"),
-            ("420085", "420085:多以良川"),
-            ("420086", "420086:柚木川"),
-            ("420087", "420087:伊佐ノ浦川"),
-            ("420088", "420088:江川内川"),
-            ("420089", "420089:面高川"),
-            ("420090", "420090:高地川"),
-            ("420091", "420091:木場川"),
-            ("420092", "420092:鳥加川"),
-            ("420093", "420093:大明寺川"),
-            ("420094", "420094:大江川"),
-            ("420095", "420095:手崎川"),
-            ("420096", "420096:戸根川"),
-            ("420097", "420097:村松川"),
-            ("420098", "420098:西海川"),
-            ("420099", "420099:子々川川"),
-            ("420100", "420100:時津川"),
-            ("420101", "420101:長与川"),
-            ("420102", "420102:伊木力川"),
-            ("420103", "420103:喜々津川"),
-            ("420104", "420104:久山川"),
-            ("420105", "420105:西大川"),
-            ("420106", "420106:東大川"),
-            ("420107", "420107:鈴田川"),
-            ("420108", "420108:内田川"),
-            ("420109", "420109:大上戸川"),
-            ("420110", "420110:郡川"),
-            ("420111", "420111:よし川"),
-            ("420112", "420112:江の串川"),
-            ("420113", "420113:串川"),
-            ("420114", "420114:千綿川"),
-            ("420115", "420115:彼杵川"),
-            ("420116", "420116:川棚川"),
-            ("420117", "420117:宮村川"),
-            ("420118", "420118:金田川"),
-            ("420119", "420119:小森川"),
-            ("420120", "420120:早岐川"),
-            ("420121", "420121:日宇川"),
-            ("420122", "420122:福石川"),
-            ("420123", "420123:佐世保川"),
-            ("420124", "420124:相浦川"),
-            ("420125", "420125:佐々川"),
-            ("420126", "420126:小佐々川"),
-            ("420127", "420127:竹田川"),
-            ("420128", "420128:上矢岳川"),
-            ("420129", "420129:大加勢川"),
-            ("420130", "420130:樋口川"),
-            ("420131", "420131:鹿町川"),
-            ("420132", "420132:江迎川"),
-            ("420133", "420133:釜田川"),
-            ("420134", "420134:久吹川"),
-            ("420135", "420135:坂瀬川"),
-            ("420136", "420136:竜尾川"),
-            ("420137", "420137:悪太郎川"),
-            ("420138", "420138:志佐川"),
-            ("420139", "420139:調川川"),
-            ("420140", "420140:今福川"),
-            ("420141", "420141:人柱川"),
-            ("420142", "420142:市小木川"),
-            ("420143", "420143:猪ノ木川"),
-            ("420144", "420144:大川原川"),
-            ("420145", "420145:鰐川"),
-            ("420146", "420146:浦ノ川"),
-            ("420147", "420147:一ノ川"),
-            ("420148", "420148:前田川"),
-            ("420149", "420149:福江川"),
-            ("420150", "420150:増田川"),
-            ("420151", "420151:田尾川"),
-            ("420152", "420152:山手川"),
-            ("420153", "420153:丸子川"),
-            ("420154", "420154:小川川"),
-            ("420155", "420155:中須川"),
-            ("420156", "420156:荒川川"),
-            ("420157", "420157:丹奈川"),
-            ("420158", "420158:佐野原川"),
-            ("420159", "420159:相河川"),
-            ("420160", "420160:釣道川"),
-            ("420161", "420161:宮ノ川"),
-            ("420162", "420162:大川"),
-            ("420163", "420163:大川"),
-            ("420164", "420164:檮ノ木川"),
-            ("420165", "420165:大左右川"),
-            ("420166", "420166:谷江川"),
-            ("420167", "420167:梅の木川"),
-            ("420168", "420168:幡鉾川"),
-            ("420169", "420169:永田川"),
-            ("420170", "420170:刈田院川"),
-            ("420171", "420171:百合畑川"),
-            ("420172", "420172:比田勝川"),
-            ("420173", "420173:玖須川"),
-            ("420174", "420174:舟志川"),
-            ("420175", "420175:琴川"),
-            ("420176", "420176:芦見川"),
-            ("420177", "420177:一重川"),
-            ("420178", "420178:小鹿川"),
-            ("420179", "420179:志多賀川"),
-            ("420180", "420180:佐賀川"),
-            ("420181", "420181:駄道川"),
-            ("420182", "420182:曽川"),
-            ("420183", "420183:け知川"),
-            ("420184", "420184:小浦川"),
-            ("420185", "420185:阿須川"),
-            ("420186", "420186:厳原本川"),
-            ("420187", "420187:久田川"),
-            ("420188", "420188:久和川"),
-            ("420189", "420189:浅藻川"),
-            ("420190", "420190:瀬川"),
-            ("420191", "420191:久根川"),
-            ("420192", "420192:椎根川"),
-            ("420193", "420193:佐須川"),
-            ("420194", "420194:阿連川"),
-            ("420195", "420195:今里川"),
-            ("420196", "420196:加志川"),
-            ("420197", "420197:洲藻川"),
-            ("420198", "420198:仁位川"),
-            ("420199", "420199:卯麦川"),
-            ("420200", "420200:田川"),
-            ("420201", "420201:吉田川"),
-            ("420202", "420202:三根川"),
-            ("420203", "420203:鹿見川"),
-            ("420204", "420204:仁田川"),
-            ("420205", "420205:伊奈川"),
-            ("420206", "420206:志田留川"),
-            ("420207", "420207:佐護川"),
-            ("420208", "420208:佐須奈川"),
-            ("420209", "420209:大浦川"),
-            ("420210", "420210:豊川"),
-            ("420211", "420211:江端川"),
-            ("420212", "420212:神曽根川"),
-            ("420213", "420213:鏡川"),
-            ("420214", "420214:安満川"),
-            ("420215", "420215:中川"),
-            ("420216", "420216:古田川"),
-            ("420217", "420217:敷佐川"),
-            ("420218", "420218:中津良川"),
-            ("420219", "420219:神の川"),
-            ("420220", "420220:東流川"),
-            ("420221", "420221:西流川"),
-            ("420222", "420222:谷郷川"),
-            ("420223", "420223:早見川"),
-            ("420224", "420224:尾向川"),
-            ("420225", "420225:西昭和開川"),
-            ("420226", "420226:東昭和開川"),
-            ("420227", "420227:尾茂川"),
-            ("420228", "420228:たゆび川"),
-            ("420229", "420229:引無田川"),
-            ("420230", "420230:永無田川"),
-            ("420231", "420231:西浦川"),
-            ("420232", "420232:東川"),
-            ("420233", "420233:苧扱川"),
-            ("420234", "420234:白浜川"),
-            ("420235", "420235:木下川"),
-            ("420236", "420236:北土瀬戸川"),
-            ("420237", "420237:土瀬戸川"),
-            ("420238", "420238:水ノ浦川"),
-            ("420239", "420239:板引川"),
-            ("420240", "420240:清水川"),
-            ("420241", "420241:金浜川"),
-            ("420242", "420242:木指川"),
-            ("420243", "420243:鬢串川"),
-            ("420244", "420244:古賀川"),
-            ("420245", "420245:藤田尾川"),
-            ("420246", "420246:六軒川"),
-            ("420247", "420247:川原古川"),
-            ("420248", "420248:池田川"),
-            ("420249", "420249:金取川"),
-            ("420250", "420250:熊川"),
-            ("420251", "420251:熊川"),
-            ("420252", "420252:松尾川"),
-            ("420253", "420253:新川"),
-            ("420254", "420254:曲川"),
-            ("420255", "420255:向川"),
-            ("420256", "420256:深浦川"),
-            ("420257", "420257:出口川"),
-            ("420258", "420258:浜田川"),
-            ("420259", "420259:野々串川"),
-            ("420260", "420260:以下宿川"),
-            ("420261", "420261:小家川"),
-            ("420262", "420262:相川川"),
-            ("420263", "420263:樫山川"),
-            ("420264", "420264:菰立川"),
-            ("420265", "420265:綿打川"),
-            ("420266", "420266:柚子の川"),
-            ("420267", "420267:俵頭川"),
-            ("420268", "420268:風早川"),
-            ("420269", "420269:四戸ノ川"),
-            ("420270", "420270:戸根原川"),
-            ("420271", "420271:久留里川"),
-            ("420272", "420272:四釜川"),
-            ("420273", "420273:オーコ川"),
-            ("420274", "420274:満永川"),
-            ("420275", "420275:大堂川"),
-            ("420276", "420276:長峰川"),
-            ("420277", "420277:大浦川"),
-            ("420278", "420278:五十石川"),
-            ("420279", "420279:百石川"),
-            ("420280", "420280:浦川内川"),
-            ("420281", "420281:蓼根原川"),
-            ("420282", "420282:田中川"),
-            ("420283", "420283:浮津川"),
-            ("420284", "420284:野副川"),
-            ("420285", "420285:川内川"),
-            ("420286", "420286:東園川"),
-            ("420287", "420287:木床川"),
-            ("420288", "420288:串ノ浦川"),
-            ("420289", "420289:橋ノ浦川"),
-            ("420290", "420290:白井川"),
-            ("420291", "420291:大音琴川"),
-            ("420292", "420292:小音琴川"),
-            ("420293", "420293:田の頭川"),
-            ("420294", "420294:有福川"),
-            ("420295", "420295:真申川"),
-            ("420296", "420296:神林川"),
-            ("420297", "420297:浦川内川"),
-            ("420298", "420298:歌ヶ浦川"),
-            ("420299", "420299:本ヶ浦川"),
-            ("420300", "420300:大屋川"),
-            ("420301", "420301:宮ノ前川"),
-            ("420302", "420302:加椎川"),
-            ("420303", "420303:白浜川"),
-            ("420304", "420304:平尾川"),
-            ("420305", "420305:長羽田川"),
-            ("420306", "420306:田部手川"),
-            ("420307", "420307:上ノ川"),
-            ("420308", "420308:七岳川"),
-            ("420309", "420309:後諸津川"),
-            ("420310", "420310:前諸津川"),
-            ("420311", "420311:芦辺川"),
-            ("420313", "420313:納屋場川"),
-            ("420314", "420314:椎ノ木川"),
-            ("420315", "420315:門野田川"),
-            ("420316", "420316:梅津川"),
-            ("420317", "420317:潮川"),
-            ("420318", "420318:宇戸川"),
-            ("420319", "420319:榎川川"),
-            ("420320", "420320:浦海川"),
-            ("420321", "420321:小浦海川"),
-            ("420322", "420322:御手洗川"),
-            ("420323", "420323:杉山川"),
-            ("420324", "420324:千馬川"),
-            ("420325", "420325:和板川"),
-            ("420326", "420326:糠川"),
-            ("420327", "420327:佐保川"),
-            ("420328", "420328:大綱川"),
-            ("420329", "420329:徳万川"),
-            ("420330", "420330:長谷川"),
-            ("420331", "420331:水ノ浦川"),
-            ("420332", "420332:鯨ノ浦川"),
-            ("420333", "420333:轟川"),
-            ("420334", "420334:船越川"),
-            ("420335", "420335:谷川"),
-            ("420336", "420336:渡瀬川"),
-            ("420337", "420337:保辺谷川"),
-            ("420338", "420338:和歌ノ浦川"),
-            ("420339", "420339:筒井川"),
-            ("420340", "420340:笛吹川"),
-            ("420341", "420341:八竜川"),
-            ("420342", "420342:箕坪川"),
-            ("420343", "420343:福浦川"),
-            ("420344", "420344:玖島川"),
-            ("420345", "420345:変配川"),
-            ("420346", "420346:餅の浜川"),
-            ("430001", "430001:境川"),
-            ("430002", "430002:袋川"),
-            ("430003", "430003:坂口川"),
-            ("430004", "430004:水俣川"),
-            ("430005", "430005:小津奈木川"),
-            ("430006", "430006:津奈木川"),
-            ("430007", "430007:女島川"),
-            ("430008", "430008:湯の浦川"),
-            ("430009", "430009:佐敷川"),
-            ("430010", "430010:小田浦川"),
-            ("430011", "430011:宮の浦川"),
-            ("430012", "430012:田浦川"),
-            ("430013", "430013:赤松川"),
-            ("430014", "430014:二見川"),
-            ("430015", "430015:流藻川"),
-            ("430016", "430016:水無川"),
-            ("430017", "430017:大鞘川"),
-            ("430018", "430018:鏡川"),
-            ("430019", "430019:氷川"),
-            ("430020", "430020:八間川"),
-            ("430021", "430021:砂川"),
-            ("430022", "430022:八枚戸川"),
-            ("430023", "430023:五丁川"),
-            ("430024", "430024:大野川"),
-            ("430025", "430025:浦上川"),
-            ("430026", "430026:長崎川"),
-            ("430027", "430027:西浦川"),
-            ("430028", "430028:大見川"),
-            ("430029", "430029:底江川"),
-            ("430030", "430030:里浦川"),
-            ("430031", "430031:郡浦川"),
-            ("430032", "430032:波多川"),
-            ("430033", "430033:網田川"),
-            ("430034", "430034:網津川"),
-            ("430035", "430035:千間江湖"),
-            ("430036", "430036:除川"),
-            ("430037", "430037:坪井川"),
-            ("430038", "430038:河内川"),
-            ("430039", "430039:唐人川"),
-            ("430040", "430040:境川"),
-            ("430041", "430041:行末川"),
-            ("430042", "430042:宮崎川"),
-            ("430043", "430043:菜切川"),
-            ("430044", "430044:浦川"),
-            ("430045", "430045:関川"),
-            ("430046", "430046:志岐川"),
-            ("430047", "430047:上津深江川"),
-            ("430048", "430048:松原川"),
-            ("430049", "430049:内野川"),
-            ("430050", "430050:中洲川"),
-            ("430051", "430051:隅田川"),
-            ("430052", "430052:広瀬川"),
-            ("430053", "430053:町山口川"),
-            ("430054", "430054:亀川"),
-            ("430055", "430055:下津浦川"),
-            ("430056", "430056:上津浦川"),
-            ("430057", "430057:楠甫川"),
-            ("430058", "430058:教良木川"),
-            ("430059", "430059:今泉川"),
-            ("430060", "430060:合津川"),
-            ("430061", "430061:七ツ割川"),
-            ("430062", "430062:岩下川"),
-            ("430063", "430063:目玉川"),
-            ("430064", "430064:浦川"),
-            ("430065", "430065:河内川"),
-            ("430066", "430066:白洲川"),
-            ("430067", "430067:方原川"),
-            ("430068", "430068:大宮地川"),
-            ("430069", "430069:流合川"),
-            ("430070", "430070:中田川"),
-            ("430071", "430071:久玉川"),
-            ("430072", "430072:桜川"),
-            ("430073", "430073:亀浦川"),
-            ("430074", "430074:早浦川"),
-            ("430075", "430075:路木川"),
-            ("430076", "430076:一町田川"),
-            ("430077", "430077:今富川"),
-            ("430078", "430078:大江川"),
-            ("430079", "430079:高浜川"),
-            ("430080", "430080:下津深江川"),
-            ("430081", "430081:都呂々川"),
-            ("430082", "430082:白ヶ浦川"),
-            ("430083", "430083:大泊川"),
-            ("430084", "430084:新立川"),
-            ("430085", "430085:赤崎川"),
-            ("430086", "430086:日当川"),
-            ("430087", "430087:塩屋川"),
-            ("430088", "430088:永田川"),
-            ("430089", "430089:平国川"),
-            ("430090", "430090:竹迫川"),
-            ("430091", "430091:鶴木山川"),
-            ("430092", "430092:塩屋浦川"),
-            ("430093", "430093:桂原川"),
-            ("430094", "430094:黒田川"),
-            ("430095", "430095:於呂口川"),
-            ("430096", "430096:浦ノ谷川"),
-            ("430097", "430097:大口川"),
-            ("430098", "430098:手場川"),
-            ("430099", "430099:金桁川"),
-            ("430100", "430100:古永川"),
-            ("430101", "430101:大田尾川"),
-            ("430102", "430102:矢答川"),
-            ("430103", "430103:赤瀬川"),
-            ("430104", "430104:平岩川"),
-            ("430105", "430105:小松川"),
-            ("430106", "430106:長浜川"),
-            ("430107", "430107:境谷"),
-            ("430108", "430108:湯の浦川"),
-            ("430109", "430109:呑崎水路"),
-            ("430110", "430110:宮内川"),
-            ("430111", "430111:阿村川"),
-            ("430112", "430112:塩屋川"),
-            ("430113", "430113:西河内川"),
-            ("430114", "430114:中浦川"),
-            ("430115", "430115:柿戸川"),
-            ("430116", "430116:南平川"),
-            ("430117", "430117:棚底川"),
-            ("430118", "430118:浦下川"),
-            ("430119", "430119:芋洗川"),
-            ("430120", "430120:塚原川"),
-            ("430121", "430121:才津原川"),
-            ("430122", "430122:坂田川"),
-            ("430123", "430123:宮田川"),
-            ("430124", "430124:砥石川"),
-            ("430125", "430125:梅ノ木川"),
-            ("430126", "430126:久木山川"),
-            ("430127", "430127:野田川"),
-            ("430128", "430128:山下川"),
-            ("430129", "430129:Ｎｏ．１梅津川"),
-            ("430130", "430130:Ｎｏ．２梅津川"),
-            ("430131", "430131:塩浜川"),
-            ("430132", "430132:棚田川"),
-            ("430133", "430133:小峰川"),
-            ("430134", "430134:宇土川"),
-            ("430135", "430135:田導寺川"),
-            ("430136", "430136:西高根川"),
-            ("430137", "430137:本郷川"),
-            ("430138", "430138:前田川"),
-            ("430139", "430139:中ノ迫川"),
-            ("430140", "430140:山ノ浦川"),
-            ("430141", "430141:中ノ浦川"),
-            ("430142", "430142:吉田川"),
-            ("430143", "430143:里浦川"),
-            ("430144", "430144:砂子川"),
-            ("430145", "430145:古江川"),
-            ("430146", "430146:小島川"),
-            ("430147", "430147:潟川"),
-            ("430148", "430148:小田床川"),
-            ("430149", "430149:小松川"),
-            ("430150", "430150:三会川"),
-            ("430151", "430151:小路川"),
-            ("430152", "430152:二江川"),
-            ("430153", "430153:引坂川"),
-            ("430154", "430154:宮津川"),
-            ("430155", "430155:鬼池川"),
-            ("430156", "430156:南川"),
-            ("440001", "440001:蛎瀬川"),
-            ("440002", "440002:自見川"),
-            ("440003", "440003:舞手川"),
-            ("440004", "440004:犬丸川"),
-            ("440005", "440005:伊呂波川"),
-            ("440006", "440006:山城川"),
-            ("440007", "440007:黒川"),
-            ("440008", "440008:駅館川"),
-            ("440009", "440009:寄藻川"),
-            ("440010", "440010:桂川"),
-            ("440011", "440011:石部川"),
-            ("440012", "440012:広瀬川"),
-            ("440013", "440013:赤坂川"),
-            ("440014", "440014:真玉川"),
-            ("440015", "440015:臼野川"),
-            ("440016", "440016:北川"),
-            ("440017", "440017:堅来川"),
-            ("440018", "440018:羽根川"),
-            ("440019", "440019:竹田川"),
-            ("440020", "440020:見目川"),
-            ("440021", "440021:竹田津川"),
-            ("440022", "440022:伊美川"),
-            ("440023", "440023:櫛来川"),
-            ("440024", "440024:岐部川"),
-            ("440025", "440025:向田川"),
-            ("440026", "440026:来浦川"),
-            ("440027", "440027:深江川"),
-            ("440028", "440028:堅来川"),
-            ("440029", "440029:富来川"),
-            ("440030", "440030:北江川"),
-            ("440031", "440031:田深川"),
-            ("440032", "440032:清流川"),
-            ("440033", "440033:治郎丸川"),
-            ("440034", "440034:重綱川"),
-            ("440035", "440035:内田川"),
-            ("440036", "440036:武蔵川"),
-            ("440037", "440037:小城川"),
-            ("440038", "440038:大海田川"),
-            ("440039", "440039:安岐川"),
-            ("440040", "440040:古町川"),
-            ("440041", "440041:住吉川"),
-            ("440042", "440042:江頭川"),
-            ("440043", "440043:高山川"),
-            ("440044", "440044:八坂川"),
-            ("440045", "440045:丸尾川"),
-            ("440046", "440046:金井田川"),
-            ("440047", "440047:三川"),
-            ("440048", "440048:江上川"),
-            ("440049", "440049:新川"),
-            ("440050", "440050:春木川"),
-            ("440051", "440051:境川"),
-            ("440052", "440052:朝見川"),
-            ("440053", "440053:祓川"),
-            ("440054", "440054:住吉川"),
-            ("440055", "440055:日美天川"),
-            ("440056", "440056:丹生川"),
-            ("440057", "440057:本田川"),
-            ("440058", "440058:小猫川"),
-            ("440059", "440059:湊川"),
-            ("440060", "440060:志生木川"),
-            ("440061", "440061:有屋川"),
-            ("440062", "440062:佐志生川"),
-            ("440063", "440063:下の江川"),
-            ("440064", "440064:熊崎川"),
-            ("440065", "440065:末広川"),
-            ("440066", "440066:臼杵川"),
-            ("440067", "440067:海添川"),
-            ("440068", "440068:堅浦川"),
-            ("440069", "440069:徳浦川"),
-            ("440070", "440070:青江川"),
-            ("440071", "440071:津久見川"),
-            ("440072", "440072:千怒川"),
-            ("440073", "440073:暁嵐川"),
-            ("440074", "440074:狩生川"),
-            ("440075", "440075:戸穴川"),
-            ("440076", "440076:吹浦川"),
-            ("440077", "440077:色利川"),
-            ("440078", "440078:畑野浦川"),
-            ("440079", "440079:楠本川"),
-            ("440080", "440080:正金川"),
-            ("440081", "440081:河内川"),
-            ("440082", "440082:猪串川"),
-            ("440083", "440083:森崎川"),
-            ("440084", "440084:芹川"),
-            ("440085", "440085:江川"),
-            ("440086", "440086:大内川"),
-            ("440087", "440087:波当津川"),
-            ("440088", "440088:桂区川"),
-            ("440089", "440089:正添川"),
-            ("440090", "440090:横泊川"),
-            ("440091", "440091:板敷川"),
-            ("440092", "440092:西堅来川"),
-            ("440093", "440093:松津川"),
-            ("440094", "440094:高汐川"),
-            ("440095", "440095:堀切川"),
-            ("440096", "440096:東高島川"),
-            ("440097", "440097:天村川"),
-            ("440098", "440098:年田川"),
-            ("440099", "440099:観音川"),
-            ("440100", "440100:深江江上川"),
-            ("440101", "440101:堀川"),
-            ("440102", "440102:曲木川"),
-            ("440103", "440103:宮川"),
-            ("440104", "440104:友安川"),
-            ("440105", "440105:金谷川"),
-            ("440106", "440106:志手川"),
-            ("440107", "440107:トヤノ口川"),
-            ("440108", "440108:江口川"),
-            ("440109", "440109:井ノ谷川"),
-            ("440110", "440110:桑ノ浦川"),
-            ("440111", "440111:水ヶ浦川"),
-            ("440112", "440112:日の浦川"),
-            ("440113", "440113:帆波浦川"),
-            ("440114", "440114:西の浦川"),
-            ("440115", "440115:殿ノ上川"),
-            ("440116", "440116:中越川"),
-            ("440117", "440117:大川"),
-            ("440118", "440118:川尻川"),
-            ("440119", "440119:梶寄川"),
-            ("440120", "440120:江頭川"),
-            ("450001", "450001:市振川"),
-            ("450002", "450002:古江川"),
-            ("450003", "450003:中港川"),
-            ("450004", "450004:阿蘇川"),
-            ("450005", "450005:熊野江川"),
-            ("450006", "450006:須美江川"),
-            ("450007", "450007:浦尻川"),
-            ("450008", "450008:沖田川"),
-            ("450009", "450009:浦上川"),
-            ("450010", "450010:丸バエ川"),
-            ("450011", "450011:鳴子川"),
-            ("450012", "450012:五十鈴川"),
-            ("450013", "450013:庄手川"),
-            ("450014", "450014:亀崎川"),
-            ("450015", "450015:塩見川"),
-            ("450016", "450016:赤岩川"),
-            ("450017", "450017:耳川"),
-            ("450018", "450018:石並川"),
-            ("450019", "450019:水無川"),
-            ("450020", "450020:心見川"),
-            ("450021", "450021:都農川"),
-            ("450022", "450022:名貫川"),
-            ("450023", "450023:平田川"),
-            ("450024", "450024:一ツ瀬川"),
-            ("450025", "450025:石崎川"),
-            ("450026", "450026:清武川"),
-            ("450027", "450027:加江田川"),
-            ("450028", "450028:知福川"),
-            ("450029", "450029:突浪川"),
-            ("450030", "450030:内海川"),
-            ("450031", "450031:野島川"),
-            ("450032", "450032:小内海川"),
-            ("450033", "450033:鶯巣川"),
-            ("450034", "450034:伊比井川"),
-            ("450035", "450035:富土川"),
-            ("450036", "450036:宮浦川"),
-            ("450037", "450037:手洗川"),
-            ("450038", "450038:大浦川"),
-            ("450039", "450039:風田川"),
-            ("450040", "450040:広渡川"),
-            ("450041", "450041:隈谷川"),
-            ("450042", "450042:細田川"),
-            ("450043", "450043:潟上川"),
-            ("450044", "450044:贄波川"),
-            ("450045", "450045:市木川"),
-            ("450046", "450046:大納川"),
-            ("450047", "450047:宮之浦川"),
-            ("450048", "450048:都井川"),
-            ("450049", "450049:永田川"),
-            ("450050", "450050:本城川"),
-            ("450051", "450051:千野川"),
-            ("450052", "450052:福島川"),
-            ("450053", "450053:甫場谷川"),
-            ("450054", "450054:太田谷川"),
-            ("450055", "450055:小目井川"),
-            ("450056", "450056:小吹毛井川"),
-            ("460001", "460001:前川"),
-            ("460002", "460002:安楽川"),
-            ("460003", "460003:菱田川"),
-            ("460004", "460004:田原川"),
-            ("460005", "460005:広瀬川"),
-            ("460006", "460006:久保田川"),
-            ("460007", "460007:郡川"),
-            ("460008", "460008:大泊川"),
-            ("460009", "460009:島泊川"),
-            ("460010", "460010:上之園川"),
-            ("460011", "460011:雄川"),
-            ("460012", "460012:堂之元川"),
-            ("460013", "460013:神ノ川"),
-            ("460014", "460014:浜田川"),
-            ("460015", "460015:高須川"),
-            ("460016", "460016:小谷川"),
-            ("460017", "460017:本城川"),
-            ("460018", "460018:河崎川"),
-            ("460019", "460019:中俣川"),
-            ("460020", "460020:持木川"),
-            ("460021", "460021:野尻川"),
-            ("460022", "460022:松崎川"),
-            ("460023", "460023:髙橋川"),
-            ("460024", "460024:検校川"),
-            ("460025", "460025:天降川"),
-            ("460026", "460026:清水川"),
-            ("460027", "460027:日木山川"),
-            ("460028", "460028:網掛川"),
-            ("460029", "460029:別府川"),
-            ("460030", "460030:思川"),
-            ("460031", "460031:稲荷川"),
-            ("460032", "460032:甲突川"),
-            ("460033", "460033:新川"),
-            ("460034", "460034:脇田川"),
-            ("460035", "460035:永田川"),
-            ("460036", "460036:和田川"),
-            ("460037", "460037:愛宕川"),
-            ("460038", "460038:八幡川"),
-            ("460039", "460039:貝底川"),
-            ("460040", "460040:田貫川"),
-            ("460041", "460041:湊川"),
-            ("460042", "460042:二反田川"),
-            ("460043", "460043:新川"),
-            ("460044", "460044:集川"),
-            ("460045", "460045:馬渡川"),
-            ("460046", "460046:石垣川"),
-            ("460047", "460047:加治佐川"),
-            ("460048", "460048:尻無川"),
-            ("460049", "460049:花渡川"),
-            ("460050", "460050:馬追川"),
-            ("460051", "460051:泊川"),
-            ("460052", "460052:久志川"),
-            ("460053", "460053:秋目川"),
-            ("460054", "460054:大当川"),
-            ("460055", "460055:祓川"),
-            ("460056", "460056:笠石川"),
-            ("460057", "460057:大浦川"),
-            ("460058", "460058:遠田川"),
-            ("460059", "460059:相星川"),
-            ("460060", "460060:万之瀬川"),
-            ("460061", "460061:伊作川"),
-            ("460062", "460062:小野川"),
-            ("460063", "460063:永吉川"),
-            ("460064", "460064:大川"),
-            ("460065", "460065:神之川"),
-            ("460066", "460066:江口川"),
-            ("460067", "460067:大里川"),
-            ("460068", "460068:八房川"),
-            ("460069", "460069:五反田川"),
-            ("460070", "460070:荒川"),
-            ("460071", "460071:平身川"),
-            ("460072", "460072:長川"),
-            ("460073", "460073:浜田川"),
-            ("460074", "460074:中津川"),
-            ("460075", "460075:小島川"),
-            ("460076", "460076:土川川"),
-            ("460077", "460077:轟川"),
-            ("460078", "460078:原田川"),
-            ("460079", "460079:湯田川"),
-            ("460080", "460080:西方川"),
-            ("460081", "460081:汐見川"),
-            ("460082", "460082:城川内川"),
-            ("460083", "460083:指江川"),
-            ("460084", "460084:小浜川"),
-            ("460085", "460085:浦底川"),
-            ("460086", "460086:尻無川"),
-            ("460087", "460087:大川"),
-            ("460088", "460088:大橋川"),
-            ("460089", "460089:高松川"),
-            ("460090", "460090:折口川"),
-            ("460091", "460091:新田川"),
-            ("460092", "460092:江内川"),
-            ("460093", "460093:高尾野川"),
-            ("460094", "460094:米之津川"),
-            ("460095", "460095:西京川"),
-            ("460096", "460096:湊川"),
-            ("460097", "460097:川脇川"),
-            ("460098", "460098:向井川"),
-            ("460099", "460099:熊野川"),
-            ("460100", "460100:阿獄川"),
-            ("460101", "460101:大浦川"),
-            ("460102", "460102:宮瀬川"),
-            ("460103", "460103:郡川"),
-            ("460104", "460104:鹿鳴川"),
-            ("460105", "460105:古川川"),
-            ("460106", "460106:苦浜川"),
-            ("460107", "460107:甲女川"),
-            ("460108", "460108:城之川"),
-            ("460109", "460109:安房川"),
-            ("460110", "460110:中間川"),
-            ("460111", "460111:栗生川"),
-            ("460112", "460112:獄之川"),
-            ("460113", "460113:永田川"),
-            ("460114", "460114:一湊川"),
-            ("460115", "460115:志戸子川"),
-            ("460116", "460116:宮之浦川"),
-            ("460117", "460117:浦上川"),
-            ("460118", "460118:芦花部川"),
-            ("460119", "460119:秋名川"),
-            ("460120", "460120:嘉渡川"),
-            ("460121", "460121:宮久田川"),
-            ("460122", "460122:前田川"),
-            ("460123", "460123:屋仁川"),
-            ("460124", "460124:佐仁川"),
-            ("460125", "460125:大美川"),
-            ("460126", "460126:大川"),
-            ("460127", "460127:金久田川"),
-            ("460128", "460128:川内川"),
-            ("460129", "460129:住用川"),
-            ("460130", "460130:役勝川"),
-            ("460131", "460131:山間川"),
-            ("460132", "460132:勝浦川"),
-            ("460133", "460133:阿木名川"),
-            ("460134", "460134:仲里川"),
-            ("460135", "460135:仲金久川"),
-            ("460136", "460136:篠川川"),
-            ("460137", "460137:河内川"),
-            ("460138", "460138:名音川"),
-            ("460139", "460139:大和川"),
-            ("460140", "460140:知名瀬川"),
-            ("460141", "460141:小宿大川"),
-            ("460142", "460142:新川"),
-            ("460143", "460143:奧川"),
-            ("460144", "460144:石橋川"),
-            ("460145", "460145:余多川"),
-            ("460146", "460146:港川"),
-            ("460147", "460147:万田川"),
-            ("460148", "460148:下田川"),
-            ("460149", "460149:伊宝川"),
-            ("460150", "460150:名田川"),
-            ("460151", "460151:亀徳川"),
-            ("460152", "460152:大瀬川"),
-            ("460153", "460153:面縄川"),
-            ("460154", "460154:ウワナル川"),
-            ("460155", "460155:秋利神川"),
-            ("460156", "460156:真瀬名川"),
-            ("460157", "460157:湾屋川"),
-            ("460158", "460158:南川"),
-            ("460159", "460159:港川"),
-            ("460160", "460160:夏井川"),
-            ("460161", "460161:天神川"),
-            ("460162", "460162:水尻川"),
-            ("460163", "460163:小野川"),
-            ("460164", "460164:辺塚川"),
-            ("460165", "460165:古里川"),
-            ("460166", "460166:大川"),
-            ("460167", "460167:長谷川"),
-            ("460168", "460168:石走川"),
-            ("460169", "460169:下園川"),
-            ("460170", "460170:炭屋川"),
-            ("460171", "460171:塩屋川"),
-            ("460172", "460172:苙川"),
-            ("460173", "460173:立神川"),
-            ("460174", "460174:二川"),
-            ("460175", "460175:天目石川"),
-            ("460176", "460176:小浜川"),
-            ("460177", "460177:大浜川"),
-            ("460178", "460178:阿潟浜川"),
-            ("460179", "460179:丸峯川"),
-            ("460180", "460180:城元川"),
-            ("460181", "460181:永目川"),
-            ("460182", "460182:荒平川"),
-            ("460183", "460183:小薗川"),
-            ("460184", "460184:鎌脇川"),
-            ("460185", "460185:井手尻川"),
-            ("460186", "460186:馬形川"),
-            ("460187", "460187:塩入川"),
-            ("460188", "460188:赤迫川"),
-            ("460189", "460189:尾迫川"),
-            ("460190", "460190:俣江川"),
-            ("460191", "460191:北迫川"),
-            ("460192", "460192:城之下川"),
-            ("460193", "460193:飛岡川"),
-            ("460194", "460194:鶴田川"),
-            ("460195", "460195:小浜川"),
-            ("460196", "460196:宮崎川"),
-            ("460197", "460197:仏石川"),
-            ("460198", "460198:大迫川"),
-            ("460199", "460199:松ヶ崎川"),
-            ("460200", "460200:辺田川"),
-            ("460201", "460201:平野川"),
-            ("460202", "460202:観音川"),
-            ("460203", "460203:川原川"),
-            ("460204", "460204:中浜川"),
-            ("460205", "460205:深港川"),
-            ("460206", "460206:境川"),
-            ("460207", "460207:金床川"),
-            ("460208", "460208:阿弥謙川"),
-            ("460209", "460209:三本松川"),
-            ("460210", "460210:木之下川"),
-            ("460211", "460211:熊谷川"),
-            ("460212", "460212:田尻川"),
-            ("460213", "460213:雁添川"),
-            ("460214", "460214:福ノ川"),
-            ("460215", "460215:塩入川"),
-            ("460216", "460216:花倉川"),
-            ("460217", "460217:磯川"),
-            ("460218", "460218:障子川"),
-            ("460219", "460219:五位野川"),
-            ("460220", "460220:谷川"),
-            ("460221", "460221:八幡川"),
-            ("460222", "460222:逆瀬川"),
-            ("460223", "460223:丹波川"),
-            ("460224", "460224:山王川"),
-            ("460225", "460225:水成川"),
-            ("460226", "460226:東塩屋川"),
-            ("460227", "460227:竹迫川"),
-            ("460228", "460228:東俣川"),
-            ("460229", "460229:赤水川"),
-            ("460230", "460230:行司堀川"),
-            ("460231", "460231:岬川"),
-            ("460232", "460232:網代川"),
-            ("460233", "460233:坊の浜川"),
-            ("460234", "460234:下浜川"),
-            ("460235", "460235:大谷川"),
-            ("460236", "460236:小泊川"),
-            ("460237", "460237:大智院川"),
-            ("460238", "460238:宇都川"),
-            ("460239", "460239:楠崎川"),
-            ("460240", "460240:松山平川"),
-            ("460241", "460241:麻畑川"),
-            ("460242", "460242:丸木浜川"),
-            ("460243", "460243:田崎川"),
-            ("460244", "460244:唐人町川"),
-            ("460245", "460245:博多川"),
-            ("460246", "460246:平尾川"),
-            ("460247", "460247:小原川"),
-            ("460248", "460248:小ヶ倉川"),
-            ("460249", "460249:塩屋谷川"),
-            ("460250", "460250:大平川"),
-            ("460251", "460251:正仙川"),
-            ("460252", "460252:末柏川"),
-            ("460253", "460253:鳥井口川"),
-            ("460254", "460254:馬込川"),
-            ("460255", "460255:赤之間川"),
-            ("460256", "460256:芝之川"),
-            ("460257", "460257:田之尻川"),
-            ("460258", "460258:平崎川"),
-            ("460259", "460259:落水川"),
-            ("460260", "460260:座頭落川"),
-            ("460261", "460261:田平川"),
-            ("460262", "460262:塩屋川"),
-            ("460263", "460263:山神川"),
-            ("460264", "460264:宇都上川"),
-            ("460265", "460265:唐瀬川"),
-            ("460266", "460266:谷山川"),
-            ("460267", "460267:江石川"),
-            ("460268", "460268:堂之元川"),
-            ("460269", "460269:水月川"),
-            ("460270", "460270:女渕川"),
-            ("460271", "460271:野中川"),
-            ("460272", "460272:津山川"),
-            ("460273", "460273:真砂川"),
-            ("460274", "460274:蔵之元川"),
-            ("460275", "460275:船津川"),
-            ("460276", "460276:温之浦川"),
-            ("460277", "460277:浜漉川"),
-            ("460278", "460278:北方崎川"),
-            ("460279", "460279:茅屋川"),
-            ("460280", "460280:口之福浦川"),
-            ("460281", "460281:赤崎川"),
-            ("460282", "460282:片側川"),
-            ("460283", "460283:御所浦川"),
-            ("460284", "460284:馬見塚川"),
-            ("460285", "460285:飛松川"),
-            ("460286", "460286:池尻川"),
-            ("460287", "460287:槝之浦川"),
-            ("460288", "460288:黒之浜川"),
-            ("460289", "460289:八郷川"),
-            ("460290", "460290:蛇渕川"),
-            ("460291", "460291:浦田川"),
-            ("460292", "460292:犬童川"),
-            ("460293", "460293:針原川"),
-            ("460294", "460294:櫓木川"),
-            ("460295", "460295:西前田川"),
-            ("460296", "460296:前田川"),
-            ("460297", "460297:湊川"),
-            ("460298", "460298:安納川"),
-            ("460299", "460299:浅川川"),
-            ("460300", "460300:大河田川"),
-            ("460301", "460301:早稲田川"),
-            ("460302", "460302:沸川"),
-            ("460303", "460303:中山川"),
-            ("460304", "460304:野添川"),
-            ("460305", "460305:女洲川"),
-            ("460306", "460306:今熊野川"),
-            ("460307", "460307:浜川"),
-            ("460308", "460308:黒甲川"),
-            ("460309", "460309:広田川"),
-            ("460310", "460310:阿武鋤川"),
-            ("460311", "460311:大川川"),
-            ("460312", "460312:那辺川"),
-            ("460313", "460313:岩川"),
-            ("460314", "460314:牛郷川"),
-            ("460315", "460315:島間川"),
-            ("460316", "460316:塚川"),
-            ("460317", "460317:谷切川"),
-            ("460318", "460318:阿高磯川"),
-            ("460319", "460319:伊原川"),
-            ("460320", "460320:深田川"),
-            ("460321", "460321:大渡瀬川"),
-            ("460322", "460322:脇之川"),
-            ("460323", "460323:小蟹川"),
-            ("460324", "460324:大蟹川"),
-            ("460325", "460325:浜之町川"),
-            ("460326", "460326:椨川"),
-            ("460327", "460327:男川"),
-            ("460328", "460328:女川"),
-            ("460329", "460329:長間川"),
-            ("460330", "460330:温泉川"),
-            ("460331", "460331:長浜川"),
-            ("460332", "460332:宮向川"),
-            ("460333", "460333:イノクチ川"),
-            ("460334", "460334:大平瀬川"),
-            ("460335", "460335:堀田川"),
-            ("460336", "460336:一五川"),
-            ("460337", "460337:名残谷川"),
-            ("460338", "460338:中里川"),
-            ("460339", "460339:唐浜川"),
-            ("460340", "460340:佐大熊川"),
-            ("460341", "460341:大熊川"),
-            ("460342", "460342:有良川"),
-            ("460343", "460343:円本川"),
-            ("460344", "460344:川内川"),
-            ("460345", "460345:竜郷川"),
-            ("460346", "460346:久場川"),
-            ("460347", "460347:瀬留川"),
-            ("460348", "460348:浦川"),
-            ("460349", "460349:屋入川"),
-            ("460350", "460350:里川"),
-            ("460351", "460351:唐浜川"),
-            ("460352", "460352:須野川"),
-            ("460353", "460353:宇宿前川"),
-            ("460354", "460354:節田川"),
-            ("460355", "460355:手広川"),
-            ("460356", "460356:小湊山田川"),
-            ("460357", "460357:小和瀬川"),
-            ("460358", "460358:脇之戸川"),
-            ("460359", "460359:見里川"),
-            ("460360", "460360:かずら勝川"),
-            ("460361", "460361:戸玉川"),
-            ("460362", "460362:金子川"),
-            ("460363", "460363:大川"),
-            ("460364", "460364:幸田川"),
-            ("460365", "460365:ふゆかけ川"),
-            ("460366", "460366:嘉徳川"),
-            ("460367", "460367:節子川"),
-            ("460368", "460368:網野子川"),
-            ("460369", "460369:嘉鉄川"),
-            ("460370", "460370:清水川"),
-            ("460371", "460371:瀬久井川"),
-            ("460372", "460372:久根津川"),
-            ("460373", "460373:須佐礼川"),
-            ("460374", "460374:阿鉄川"),
-            ("460375", "460375:小名瀬川"),
-            ("460376", "460376:摺勝川"),
-            ("460377", "460377:越地川"),
-            ("460378", "460378:古志川"),
-            ("460379", "460379:浦川"),
-            ("460380", "460380:伊目川"),
-            ("460381", "460381:川内川"),
-            ("460382", "460382:小勝川"),
-            ("460383", "460383:挟間川"),
-            ("460384", "460384:本金久川"),
-            ("460385", "460385:西古見川"),
-            ("460386", "460386:屋鈍川"),
-            ("460387", "460387:阿室川"),
-            ("460388", "460388:平田川"),
-            ("460389", "460389:佐念川"),
-            ("460390", "460390:里川"),
-            ("460391", "460391:前田川"),
-            ("460392", "460392:名柄川"),
-            ("460393", "460393:尾羅川"),
-            ("460394", "460394:部連川"),
-            ("460395", "460395:部連小勝川"),
-            ("460396", "460396:小部連川"),
-            ("460397", "460397:第二中浜川"),
-            ("460398", "460398:中浜川"),
-            ("460399", "460399:須古川"),
-            ("460400", "460400:鯨戸川"),
-            ("460401", "460401:湯湾川"),
-            ("460402", "460402:田検川"),
-            ("460403", "460403:田検小勝川"),
-            ("460404", "460404:浦川"),
-            ("460405", "460405:芦検中川"),
-            ("460406", "460406:大良川"),
-            ("460407", "460407:伊仁川"),
-            ("460408", "460408:桁蔵川"),
-            ("460409", "460409:生勝川"),
-            ("460410", "460410:久志川"),
-            ("460411", "460411:脇川"),
-            ("460412", "460412:宇検川"),
-            ("460413", "460413:宮勝川"),
-            ("460414", "460414:田原川"),
-            ("460415", "460415:泉川"),
-            ("460416", "460416:その平川"),
-            ("460417", "460417:村内川"),
-            ("460418", "460418:志戸勘川"),
-            ("460419", "460419:徳浜川"),
-            ("460420", "460420:ヒエン川"),
-            ("460421", "460421:戸円川"),
-            ("460422", "460422:大金久川"),
-            ("460423", "460423:里川"),
-            ("460424", "460424:脇田川"),
-            ("460425", "460425:大棚川"),
-            ("460426", "460426:毛陣川"),
-            ("460427", "460427:石川川"),
-            ("460428", "460428:津名久川"),
-            ("460429", "460429:赤井川"),
-            ("460430", "460430:河平川"),
-            ("460431", "460431:山長川"),
-            ("460432", "460432:文田川"),
-            ("460433", "460433:湯湾釜川"),
-            ("460434", "460434:高述川"),
-            ("460435", "460435:宮崎川"),
-            ("460436", "460436:国直川"),
-            ("460437", "460437:前田川"),
-            ("460438", "460438:有面川"),
-            ("460439", "460439:大津川"),
-            ("460440", "460440:三儀山川"),
-            ("460441", "460441:朝仁天川"),
-            ("460442", "460442:朝仁新川"),
-            ("460443", "460443:塩浜川"),
-            ("460444", "460444:屋仁川"),
-            ("460445", "460445:沖名川"),
-            ("460446", "460446:味意竿川"),
-            ("460447", "460447:関田川"),
-            ("460448", "460448:汐見川"),
-            ("460449", "460449:浜田川"),
-            ("460450", "460450:早町川"),
-            ("460451", "460451:中川"),
-            ("460452", "460452:河原川"),
-            ("460453", "460453:草田川"),
-            ("460454", "460454:白嶺川"),
-            ("460455", "460455:神田川"),
-            ("460456", "460456:向井田川"),
-            ("460457", "460457:前川"),
-            ("460458", "460458:蒲生川"),
-            ("460459", "460459:下城久川"),
-            ("460460", "460460:中浦原川"),
-            ("460461", "460461:念辻川"),
-            ("460462", "460462:ウスク川"),
-            ("460463", "460463:宮戸川"),
-            ("460464", "460464:赤連川"),
-            ("460465", "460465:池治川"),
-            ("460466", "460466:山田丙内川"),
-            ("460467", "460467:殿川"),
-            ("460468", "460468:中里川"),
-            ("460469", "460469:小川"),
-            ("460470", "460470:大朝戸川"),
-            ("460471", "460471:中熊川"),
-            ("460472", "460472:西目川"),
-            ("460473", "460473:坂嶺川"),
-            ("460474", "460474:作田川"),
-            ("460475", "460475:ウオン川"),
-            ("460476", "460476:長迫川"),
-            ("460477", "460477:雁股川"),
-            ("460478", "460478:神宮中川"),
-            ("460479", "460479:大川川"),
-            ("460480", "460480:瀬武川"),
-            ("460481", "460481:木慈川"),
-            ("460482", "460482:武名川"),
-            ("460483", "460483:俵川"),
-            ("460484", "460484:瀬相川"),
-            ("460485", "460485:押角川"),
-            ("460486", "460486:伊佐根久川"),
-            ("460487", "460487:勝能川"),
-            ("460488", "460488:仲田川"),
-            ("460489", "460489:小勝川"),
-            ("460490", "460490:野見山川"),
-            ("460491", "460491:秋徳大川川"),
-            ("460492", "460492:勢里川"),
-            ("460493", "460493:西阿室川"),
-            ("460494", "460494:嘉入川"),
-            ("460495", "460495:須子茂川"),
-            ("460496", "460496:実久川"),
-            ("460497", "460497:芝川"),
-            ("460498", "460498:池地川"),
-            ("460499", "460499:請阿室川"),
-            ("460500", "460500:与路川"),
-            ("460501", "460501:小名発川"),
-            ("460502", "460502:脇田川"),
-            ("460503", "460503:波留川"),
-            ("460504", "460504:坂元川"),
-            ("460505", "460505:米作川"),
-            ("460506", "460506:後浜川"),
-            ("460507", "460507:千屋川"),
-            ("460508", "460508:窪田川"),
-            ("460509", "460509:里久川"),
-            ("460510", "460510:当田川"),
-            ("460511", "460511:花時名川"),
-            ("460512", "460512:福川"),
-            ("460513", "460513:麦田川"),
-            ("460514", "460514:南川"),
-            ("460515", "460515:中里川"),
-            ("460516", "460516:金久田川"),
-            ("460517", "460517:嘉ノ子川"),
-            ("460518", "460518:志行川"),
-            ("460519", "460519:三寿里川"),
-            ("460520", "460520:黒石原川"),
-            ("460521", "460521:田行川"),
-            ("460522", "460522:堀切川"),
-            ("460523", "460523:杉川"),
-            ("460524", "460524:米川"),
-            ("460525", "460525:宝川"),
-            ("460526", "460526:神之嶺川"),
-            ("460527", "460527:和瀬川"),
-            ("460528", "460528:案川"),
-            ("460529", "460529:丹向川"),
-            ("460530", "460530:奥名川"),
-            ("460531", "460531:双ツ川"),
-            ("460532", "460532:南原川"),
-            ("460533", "460533:本川"),
-            ("460534", "460534:古里川"),
-            ("460535", "460535:鹿浦川"),
-            ("460536", "460536:阿権川"),
-            ("460537", "460537:前川"),
-            ("460538", "460538:当田川"),
-            ("460539", "460539:雪取川"),
-            ("460540", "460540:屋子母川"),
-            ("460541", "460541:河田川"),
-            ("460542", "460542:阿場川"),
-            ("460543", "460543:増木名川"),
-            ("460544", "460544:本町川"),
-            ("460545", "460545:酔之尾川"),
-            ("460546", "460546:肉返川"),
-            ("460547", "460547:船津平川"),
-            ("460548", "460548:嘉徳川"),
-            ("460549", "460549:腰京川"),
-            ("460550", "460550:安良川"),
-            ("460551", "460551:大井川"),
-            ("460552", "460552:辺留川"),
-            ("460553", "460553:須野里川"),
-            ("460554", "460554:湊川"),
-            ("460555", "460555:宮浦川"),
-            ("460556", "460556:磯脇川"),
-            ("470001", "470001:奥川"),
-            ("470002", "470002:安波川"),
-            ("470003", "470003:新川川"),
-            ("470004", "470004:福地川"),
-            ("470005", "470005:有銘川"),
-            ("470006", "470006:汀間川"),
-            ("470007", "470007:漢那福地川"),
-            ("470008", "470008:億首川"),
-            ("470009", "470009:石川川"),
-            ("470010", "470010:天願川"),
-            ("470011", "470011:雄樋川"),
-            ("470012", "470012:報得川"),
-            ("470013", "470013:国場川"),
-            ("470014", "470014:安里川"),
-            ("470015", "470015:安謝川"),
-            ("470016", "470016:小湾川"),
-            ("470017", "470017:牧港川"),
-            ("470018", "470018:普天間川"),
-            ("470019", "470019:白比川"),
-            ("470020", "470020:比謝川"),
-            ("470022", "470022:名嘉真川"),
-            ("470023", "470023:真謝川"),
-            ("470024", "470024:轟川"),
-            ("470025", "470025:幸地川"),
-            ("470026", "470026:屋部川"),
-            ("470027", "470027:満名川"),
-            ("470028", "470028:大井川"),
-            ("470029", "470029:我部祖河川"),
-            ("470030", "470030:羽地大川"),
-            ("470031", "470031:源河川"),
-            ("470032", "470032:大保川"),
-            ("470033", "470033:田嘉里川"),
-            ("470034", "470034:比地川"),
-            ("470035", "470035:与那川"),
-            ("470036", "470036:辺野喜川"),
-            ("470037", "470037:座津武川"),
-            ("470038", "470038:渡嘉敷川"),
-            ("470040", "470040:内川"),
-            ("470041", "470041:中の川"),
-            ("470042", "470042:謝名堂川"),
-            ("470043", "470043:儀間川"),
-            ("470044", "470044:宮良川"),
-            ("470045", "470045:石垣新川川"),
-            ("470046", "470046:名蔵川"),
-            ("470047", "470047:浦内川"),
-            ("470048", "470048:仲間川"),
-            ("470049", "470049:越良川"),
-            ("470050", "470050:仲良川"),
-            ("470051", "470051:小波津川"),
-            ("470052", "470052:兼久川"),
-            ("470053", "470053:世富慶川"),
-            ("470054", "470054:荒川"),
-            ("470055", "470055:大浦川"),
-            ("470056", "470056:ソージ川"),
-            ("470057", "470057:通路川"),
-            ("470058", "470058:轟川"),
-            ("470059", "470059:磯辺川"),
-            ("470060", "470060:田原川"),
-            ("470061", "470061:真喜屋大川"),
-            ("810101", "810101:天塩川"),
-            ("810102", "810102:留萌川"),
-            ("810103", "810103:石狩川"),
-            ("810104", "810104:尻別川"),
-            ("810105", "810105:後志利別川"),
-            ("810106", "810106:鵡川"),
-            ("810107", "810107:沙流川"),
-            ("810108", "810108:十勝川"),
-            ("810109", "810109:釧路川"),
-            ("810110", "810110:網走川"),
-            ("810111", "810111:常呂川"),
-            ("810112", "810112:湧別川"),
-            ("810113", "810113:渚滑川"),
-            ("820201", "820201:阿武隈川"),
-            ("820202", "820202:名取川"),
-            ("820203", "820203:鳴瀬川"),
-            ("820204", "820204:北上川"),
-            ("820205", "820205:馬淵川"),
-            ("820206", "820206:高瀬川"),
-            ("820207", "820207:岩木川"),
-            ("820208", "820208:米代川"),
-            ("820209", "820209:雄物川"),
-            ("820210", "820210:子吉川"),
-            ("820211", "820211:最上川"),
-            ("820212", "820212:赤川"),
-            ("830301", "830301:久慈川"),
-            ("830302", "830302:那珂川"),
-            ("830303", "830303:利根川"),
-            ("830304", "830304:荒川"),
-            ("830305", "830305:多摩川"),
-            ("830306", "830306:鶴見川"),
-            ("830307", "830307:相模川"),
-            ("830308", "830308:富士川"),
-            ("840401", "840401:荒川"),
-            ("840402", "840402:阿賀野川"),
-            ("840403", "840403:信濃川"),
-            ("840404", "840404:関川"),
-            ("840405", "840405:姫川"),
-            ("840406", "840406:黒部川"),
-            ("840407", "840407:常願寺川"),
-            ("840408", "840408:神通川"),
-            ("840409", "840409:庄川"),
-            ("840410", "840410:小矢部川"),
-            ("840411", "840411:手取川"),
-            ("840412", "840412:梯川"),
-            ("850501", "850501:狩野川"),
-            ("850502", "850502:安倍川"),
-            ("850503", "850503:大井川"),
-            ("850504", "850504:菊川"),
-            ("850505", "850505:天竜川"),
-            ("850506", "850506:豊川"),
-            ("850507", "850507:矢作川"),
-            ("850508", "850508:庄内川"),
-            ("850509", "850509:木曽川"),
-            ("850510", "850510:鈴鹿川"),
-            ("850511", "850511:雲出川"),
-            ("850512", "850512:櫛田川"),
-            ("850513", "850513:宮川"),
-            ("860601", "860601:新宮川"),
-            ("860602", "860602:紀の川"),
-            ("860603", "860603:大和川"),
-            ("860604", "860604:淀川"),
-            ("860605", "860605:加古川"),
-            ("860606", "860606:揖保川"),
-            ("860607", "860607:九頭竜川"),
-            ("860608", "860608:北川"),
-            ("860609", "860609:由良川"),
-            ("860610", "860610:円山川"),
-            ("870701", "870701:千代川"),
-            ("870702", "870702:天神川"),
-            ("870703", "870703:日野川"),
-            ("870704", "870704:斐伊川"),
-            ("870705", "870705:江の川"),
-            ("870706", "870706:高津川"),
-            ("870707", "870707:佐波川"),
-            ("870708", "870708:小瀬川"),
-            ("870709", "870709:太田川"),
-            ("870710", "870710:芦田川"),
-            ("870711", "870711:高梁川"),
-            ("870712", "870712:旭川"),
-            ("870713", "870713:吉井川"),
-            ("880801", "880801:重信川"),
-            ("880802", "880802:肱川"),
-            ("880803", "880803:渡川"),
-            ("880804", "880804:仁淀川"),
-            ("880805", "880805:物部川"),
-            ("880806", "880806:那賀川"),
-            ("880807", "880807:吉野川"),
-            ("880808", "880808:土器川"),
-            ("890901", "890901:遠賀川"),
-            ("890902", "890902:松浦川"),
-            ("890903", "890903:本明川"),
-            ("890904", "890904:六角川"),
-            ("890905", "890905:嘉瀬川"),
-            ("890906", "890906:筑後川"),
-            ("890907", "890907:矢部川"),
-            ("890908", "890908:菊池川"),
-            ("890909", "890909:白川"),
-            ("890910", "890910:緑川"),
-            ("890911", "890911:球磨川"),
-            ("890912", "890912:川内川"),
-            ("890913", "890913:肝属川"),
-            ("890914", "890914:大淀川"),
-            ("890915", "890915:小丸川"),
-            ("890916", "890916:五ヶ瀬川"),
-            ("890917", "890917:番匠川"),
-            ("890918", "890918:大野川"),
-            ("890919", "890919:大分川"),
-            ("890920", "890920:山国川"),
-        ]
-        .into_iter()
-        .collect()
-    });
-
-#[rustfmt::skip]
-pub(crate) static WelfareFacMiclassCdH23: LazyLock<HashMap<&'static str, &'static str>> =
-    LazyLock::new(|| {
-        [
-            ("101", "通所介護事業所"),
-            ("102", "老人短期入所介護事業所"),
-            ("103", "養護老人ホーム（一般"),
-            ("104", "養護老人ホーム（盲）"),
-            ("105", "特別養護老人ホーム"),
-            ("106", "軽費老人ホーム（A型）"),
-            ("107", "軽費老人ホーム（B型）"),
-            ("108", "軽費老人ホーム（介護利用型）"),
-            ("109", "老人福祉センター（特A型）"),
-            ("110", "老人福祉センター（A型）"),
-            ("111", "老人福祉センター（B型）"),
-            ("112", "老人介護支援センター"),
-            ("113", "老人福祉センター"),
-            ("114", "養護老人ホーム"),
-            ("115", "軽費老人ホーム"),
-            ("201", "老人憩いの家"),
-            ("202", "老人休養ホーム"),
-            ("203", "有料老人ホーム"),
-            ("301", "肢体不自由者更生施設"),
-            ("302", "視覚障害者更生施設"),
-            ("303", "聴覚・言語障害者更生施設"),
-            ("304", "内部障害者更生施設"),
-            ("305", "身体障害者療護施設"),
-            ("306", "身体障害者授産施設"),
-            ("307", "身体障害者通所授産施設"),
-            ("308", "身体障害者小規模通所授産施設"),
-            ("309", "身体障害者福祉ホーム"),
-            ("310", "身体障害者福祉センター（A型）"),
-            ("311", "身体障害者福祉センター（B型）"),
-            ("312", "在宅障害者デイサービス施設"),
-            ("313", "障害者更生センター"),
-            ("314", "補装具製作施設"),
-            ("315", "聴覚障害者情報提供施設"),
-            ("316", "点字図書館"),
-            ("317", "点字出版施設"),
-            ("318", "盲導犬訓練施設"),
-            ("319", "身体障害者福祉工場"),
-            ("401", "助産施設"),
-            ("402", "乳児院"),
-            ("403", "母子生活支援施設"),
-            ("404", "大型児童館（A型）"),
-            ("405", "大型児童館（B型）"),
-            ("406", "大型児童館（C型）"),
-            ("407", "小型児童館"),
-            ("408", "その他の児童館"),
-            ("409", "児童センター"),
-            ("410", "児童遊園"),
-            ("411", "児童養護施設"),
-            ("412", "知的障害児施設"),
-            ("413", "自閉症児施設"),
-            ("414", "知的障害児通園施設"),
-            ("415", "盲児施設"),
-            ("416", "ろうあ児施設"),
-            ("417", "難聴幼児通園施設"),
-            ("418", "肢体不自由児施設"),
-            ("419", "肢体不自由児療護施設"),
-            ("420", "重症心身障害児施設"),
-            ("421", "情緒障害児短期治療施設"),
-            ("422", "児童自立支援施設"),
-            ("423", "児童家庭支援センター"),
-            ("501", "知的障害者授産施設（通所）"),
-            ("502", "知的障害者授産施設（入所）"),
-            ("503", "知的障害者小規模通所授産施設"),
-            ("504", "知的障害者更生施設（通所）"),
-            ("505", "知的障害者更生施設（入所）"),
-            ("506", "知的障害者通勤寮"),
-            ("507", "知的障害者福祉ホーム"),
-            ("508", "知的障害者デイサービスセンター"),
-            ("509", "知的障害者福祉工場"),
-            ("510", "知的障害者授産施設"),
-            ("601", "精神障害者生活訓練施設"),
-            ("602", "精神障害者通所授産施設"),
-            ("603", "精神障害者入所授産施設"),
-            ("604", "精神障害者小規模通所授産施設"),
-            ("605", "精神障害者福祉ホーム（B型）"),
-            ("606", "精神障害者福祉ホーム（B型を除く）"),
-            ("607", "精神障害者福祉工場"),
-            ("608", "精神障害者地域生活支援センター"),
-            ("609", "精神障害者福祉ホーム"),
-            ("701", "へき地保健福祉館"),
-            ("702", "地域福祉センター"),
-            ("703", "授産施設（その他の社会福祉施設）"),
-            ("704", "無料低額診療施設"),
-            ("705", "宿泊提供施設（その他の社会福祉施設）"),
-            ("706", "盲人ホーム"),
-            ("707", "福祉ホーム"),
-            ("801", "保育所"),
-            ("802", "へき地保育所"),
-            ("803", "認可外保育施設"),
-            ("804", "幼稚園"),
-            ("805", "認定こども園"),
-            ("NA", "NA"),
-        ]
-        .into_iter()
-        .collect()
-    });
-
-#[rustfmt::skip]
-pub(crate) static WelfareFacMiclassCdH27: LazyLock<HashMap<&'static str, &'static str>> =
-    LazyLock::new(|| {
-        [
-            ("101", "通所介護事業所"),
-            ("102", "老人短期入所介護事業所"),
-            ("103", "養護老人ホーム（一般）"),
-            ("104", "養護老人ホーム（盲）"),
-            ("105", "特別養護老人ホーム"),
-            ("106", "軽費老人ホーム（ A型）"),
-            ("107", "軽費老人ホーム（ B型）"),
-            ("108", "軽費老人ホーム（介護利用型）"),
-            ("109", "老人福祉センター（特 A型）"),
-            ("110", "老人福祉センター（ A型）"),
-            ("111", "老人福祉センター（ B型）"),
-            ("112", "在宅介護支援センター"),
-            ("113", "老人福祉センター"),
-            ("114", "養護老人ホーム"),
-            ("115", "軽費老人ホーム"),
-            ("116", "軽費老人ホーム（ケアハウス）"),
-            ("117", "軽費老人ホーム（Ａ型）"),
-            ("118", "老人介護支援センター"),
-            ("119", "在宅介護支援センター（老人介護支援センター )"),
-            ("120", "県内在宅介護支援センター"),
-            ("201", "老人憩いの家"),
-            ("202", "老人休養ホーム"),
-            ("203", "有料老人ホーム"),
-            ("204", "生活支援ハウス（高齢者生活福祉センター）"),
-            ("205", "介護老人保健施設"),
-            ("206", "訪問看護ステーション"),
-            ("207", "認知症対応型共同生活介護"),
-            ("208", "軽費老人ホームＡ型・Ｂ型"),
-            ("209", "特別養護老人ホーム（介護老人福祉施設）"),
-            ("210", "認知症疾患医療センター"),
-            ("211", "介護福祉士指定養成施設"),
-            ("212", "老人デイサービスセンター"),
-            ("213", "老人短期入所施設"),
-            ("214", "短期入所生活介護"),
-            ("215", "重度訪問介護"),
-            ("216", "デイサービス"),
-            ("217", "短期入所"),
-            ("218", "通所介護（デイサービス）"),
-            ("219", "ケアハウス"),
-            ("220", "認知症対応型共同生活介護（グループホーム）"),
-            ("221", "経過的生活介護"),
-            ("222", "短期入所生活介護事業所"),
-            ("223", "老人いこいの家"),
-            ("224", "生活支援ハウス"),
-            ("226", "老人憩の家（市町村）"),
-            ("227", "老人福祉センター（市町村）"),
-            ("228", "有料老人ホーム住所地特例"),
-            ("229", "無料低額介護老人保健施設"),
-            ("230", "老人デイサービスセンター（通所介護、介護予防通所介護）"),
-            ("231", "経過的軽費老人ホーム（ A型）"),
-            ("232", "老人デイサービスセンター (認知症対応型通所介護・介護予防認知症対応型通所介護）"),
-            ("233", "認知症高齢者グループホーム"),
-            ("234", "介護予防認知症対応型共同生活介護"),
-            ("235", "地域密着型介護老人福祉施設入所者生活介護"),
-            ("236", "地域密着型特定施設入所者生活介護"),
-            ("237", "特別養護老人ホーム (介護老人福祉施設 )"),
-            ("238", "認知症対応型通所介護"),
-            ("239", "訪問介護看護"),
-            ("240", "夜間対応型訪問介護"),
-            ("241", "老人デイサービスセンター (デイサービス事業実施施設を含む。 )"),
-            ("242", "老人憩の家"),
-            ("243", "地域密着型介護老人福祉施設 (小規模特別養護老人ホーム )"),
-            ("244", "指定認知症対応型通所介護事業所"),
-            ("245", "指定介護老人福祉施設"),
-            ("246", "指定地域密着型介護老人福祉施設"),
-            ("247", "指定通所介護事業所"),
-            ("248", "老人短期入所施設〔単独型指定短期入所生活介護〕"),
-            ("249", "老人短期入所事業所〔併設型指定短期入所生活介護〕"),
-            ("250", "重度訪問介護事業所"),
-            ("251", "生活介護事業所"),
-            ("252", "デイサービスセンター（指定通所介護事業所を含む）"),
-            ("253", "老人（在宅）介護支援センター"),
-            ("254", "デイサービスセンター"),
-            ("255", "認知症対応型共同生活介護事業"),
-            ("256", "有料老人ホーム（介護付）"),
-            ("257", "有料老人ホーム（住宅型）"),
-            ("258", "老人性認知症センター"),
-            ("259", "老人福祉センター（その他）"),
-            ("260", "生活介護（基準該当）"),
-            ("261", "介護研修施設"),
-            ("262", "老人デイサービスセンター（一般型）"),
-            ("263", "老人デイサービスセンター（認知症対応型）"),
-            ("301", "肢体不自由者更生施設"),
-            ("302", "視覚障害者更生施設"),
-            ("303", "聴覚・言語障害者更生施設"),
-            ("304", "内部障害者更生施設"),
-            ("305", "身体障害者療護施設"),
-            ("306", "身体障害者授産施設"),
-            ("307", "身体障害者通所授産施設"),
-            ("308", "身体障害者小規模通所授産施設"),
-            ("309", "身体障害者福祉ホーム"),
-            ("310", "身体障害者福祉センター（ A型）"),
-            ("311", "身体障害者福祉センター（ B型）"),
-            ("312", "在宅障害者デイサービス施設"),
-            ("313", "障害者更生センター"),
-            ("314", "補装具製作施設"),
-            ("315", "視覚障害者情報提供施設"),
-            ("316", "点字図書館"),
-            ("317", "点字出版施設"),
-            ("318", "盲導犬訓練施設"),
-            ("319", "身体障害者福祉工場"),
-            ("320", "身体障害者福祉センター"),
-            ("321", "救護施設"),
-            ("322", "聴覚障害者情報提供施設"),
-            ("323", "障害者支援施設（生活介護）"),
-            ("324", "障害者支援施設（生活介護・自立訓練）"),
-            ("325", "障害者支援施設（生活介護・就労継続支援Ｂ型）"),
-            ("326", "障害者支援施設"),
-            ("327", "経過的療養介護"),
-            ("328", "視聴覚障害者情報提供施設"),
-            ("401", "助産施設"),
-            ("402", "乳児院"),
-            ("403", "母子生活支援施設"),
-            ("404", "大型児童館（ A型）"),
-            ("405", "大型児童館（ B型）"),
-            ("406", "大型児童館（ C型）"),
-            ("407", "小型児童館"),
-            ("408", "その他の児童館"),
-            ("409", "児童センター"),
-            ("410", "児童遊園"),
-            ("411", "児童養護施設"),
-            ("412", "知的障害児施設"),
-            ("413", "自閉症児施設"),
-            ("414", "知的障害児通園施設"),
-            ("415", "盲児施設"),
-            ("416", "ろうあ児施設"),
-            ("417", "難聴幼児通園施設"),
-            ("418", "肢体不自由児施設"),
-            ("419", "肢体不自由児療護施設"),
-            ("420", "重症心身障害児施設"),
-            ("421", "情緒障害児短期治療施設"),
-            ("422", "児童自立支援施設"),
-            ("423", "児童家庭支援センター"),
-            ("424", "医療型障害児入所施設"),
-            ("425", "医療型児童発達支援センター"),
-            ("426", "福祉型障害児入所施設"),
-            ("427", "児童館（児童センター）"),
-            ("428", "母子父子福祉センター"),
-            ("429", "在宅心身障がい児者保養訓練センター"),
-            ("430", "児童自立生活援助事業"),
-            ("431", "小規模住居型児童養育事業"),
-            ("432", "児童発達支援"),
-            ("435", "医療型障害児入所施設及び指定医療機関"),
-            ("436", "母子福祉センター"),
-            ("437", "児童館・児童センター・児童室"),
-            ("438", "児童発達支援センター"),
-            ("439", "障害児入所施設（短期入所）"),
-            ("440", "児童発達支援（センター以外）"),
-            ("441", "障害児入所施設"),
-            ("442", "児童館"),
-            ("443", "指定発達支援医療機関（肢体不自由児）"),
-            ("444", "指定発達支援医療機関（重症心身障害児）"),
-            ("445", "障害児相談支援事業"),
-            ("446", "放課後児童健全育成事業"),
-            ("447", "祉型障害児入所施設"),
-            ("448", "障害者就労移行、就労継続 B、生活訓練、 (児童発達、放課後デイ )"),
-            ("449", "障害者就労継続 A(児童デイサービス )"),
-            ("450", "障害者生活介護 (児童デイサービス )"),
-            ("451", "障害者生活介護、就労継続 A就労継続 B(児童デイサービス )"),
-            ("452", "母子・父子福祉施設"),
-            ("453", "児童発達支援センター（福祉型）"),
-            ("454", "障害児相談支援"),
-            ("455", "指定医療型障害児入所施設"),
-            ("456", "指定児童発達支援事業所（児童発達支援センターを除く）"),
-            ("457", "指定障害児相談支援"),
-            ("458", "指定障害児相談支援指定障害児入所施設等 _指定医療機関（独立行政法人国立病院機構）"),
-            ("459", "指定福祉型児童発達支援センター"),
-            ("460", "指定福祉型障害児入所施設"),
-            ("461", "指定障害児入所施設等 _指定医療機関（独立行政法人国立病院機構）"),
-            ("462", "障害児等療育支援事業施設"),
-            ("463", "子ども総合センター・児童相談所"),
-            ("464", "指定医療機関（肢体不自由児）"),
-            ("465", "児童相談診療施設"),
-            ("466", "障害児通所支援"),
-            ("501", "知的障害者授産施設（通所）"),
-            ("502", "知的障害者授産施設（入所）"),
-            ("503", "知的障害者小規模通所授産施設"),
-            ("504", "知的障害者更生施設（通所）"),
-            ("505", "知的障害者更生施設（入所）"),
-            ("506", "知的障害者通勤寮"),
-            ("507", "知的障害者福祉ホーム"),
-            ("508", "知的障害者デイサービスセンター"),
-            ("509", "知的障害者福祉工場"),
-            ("510", "福祉型児童発達支援センター"),
-            ("511", "知的障害者福祉施設"),
-            ("526", "障害者支援施設（自立訓練・就労移行支援）"),
-            ("527", "多機能型"),
-            ("528", "身体障がい者保養所"),
-            ("529", "障害者支援施設（生活介護・就労移行支援）"),
-            ("530", "就労継続支援 (Ａ型 )"),
-            ("531", "就労継続支援 (Ｂ型 )"),
-            ("532", "就労移行支援"),
-            ("533", "生活介護"),
-            ("534", "共同生活援助（介護サービス包括型）"),
-            ("535", "放課後等デイサービス"),
-            ("536", "施設入所支援（障害者支援施設）"),
-            ("537", "外部サービス利用型共同生活援助"),
-            ("538", "自立訓練 (機能訓練 )（障害者支援施設）"),
-            ("539", "自立訓練 (生活訓練 )"),
-            ("540", "自立訓練 (生活訓練 )（障害者支援施設）"),
-            ("541", "就労移行支援（障害者支援施設）"),
-            ("542", "宿泊型自立訓練"),
-            ("543", "生活介護（障害者支援施設）"),
-            ("544", "療養介護"),
-            ("545", "自立訓練 (機能訓練 )"),
-            ("546", "共同生活援助事業所"),
-            ("547", "同行援護"),
-            ("548", "機能訓練"),
-            ("549", "経過的施設入所支援"),
-            ("550", "重度障害者包括支援"),
-            ("551", "生活訓練"),
-            ("552", "生活介護・自立訓練・就労移行・就労継続・施設入所・短期入所"),
-            ("553", "自立訓練（機能訓練）事業所"),
-            ("554", "自立訓練（生活訓練）事業所"),
-            ("555", "就労継続支援（Ａ型）事業所"),
-            ("556", "就労継続支援（Ｂ型）事業所"),
-            ("557", "宿泊型自立訓練事業所"),
-            ("558", "同行援護事業所"),
-            ("559", "障害者施設入所支援"),
-            ("560", "旧身体障害者社会参加施設"),
-            ("561", "視覚障害者情報センター"),
-            ("562", "自立訓練 (機能訓練 )(基準該当 )"),
-            ("563", "聴覚障害者情報センター"),
-            ("567", "身体障害者入所授産施設"),
-            ("568", "身体障害者入所療護施設"),
-            ("569", "身体障害者補助犬訓練施設（盲導犬訓練施設を除く）"),
-            ("601", "精神障害者生活訓練施設"),
-            ("602", "精神障害者通所授産施設"),
-            ("603", "精神障害者入所授産施設"),
-            ("604", "精神障害者小規模通所授産施設"),
-            ("605", "精神障害者福祉ホーム（ B型）"),
-            ("606", "精神障害者福祉ホーム（ B型を除く）"),
-            ("607", "精神障害者福祉工場"),
-            ("608", "精神障害者地域生活支援センター"),
-            ("609", "精神障害者福祉ホーム"),
-            ("610", "精神障害者ふれあいホーム (精神障害者生活ホーム )"),
-            ("611", "精神障害者共同作業所"),
-            ("612", "精神保健福祉センター"),
-            ("613", "在宅心身障害者保養施設"),
-            ("614", "精神障害者コミュニティサロン"),
-            ("615", "精神障害者小規模作業所"),
-            ("701", "へき地保健福祉館"),
-            ("702", "地域包括支援センター"),
-            ("703", "授産施設（その他の社会福祉施設）"),
-            ("704", "無料低額診療施設"),
-            ("705", "宿所提供施設"),
-            ("706", "盲人ホーム"),
-            ("707", "福祉ホーム"),
-            ("708", "婦人保護施設"),
-            ("709", "婦人相談所一時保護所"),
-            ("710", "簡易住宅"),
-            ("711", "無料低額宿泊所"),
-            ("712", "福祉休養ホーム"),
-            ("713", "相談機関"),
-            ("714", "福祉事務所"),
-            ("715", "保健所"),
-            ("716", "サービス付き高齢者向け住宅"),
-            ("717", "医務国保課関係団体"),
-            ("718", "介護福祉士養成施設"),
-            ("719", "健康福祉総務課関係団体"),
-            ("720", "子育て支援課関係団体"),
-            ("721", "市町地域包括支援センター"),
-            ("722", "市町保健センター (類似施設を含む）"),
-            ("723", "医療保護施設"),
-            ("724", "指定保育士養成施設"),
-            ("725", "指定訪問看護ステーション"),
-            ("726", "社会福祉士養成施設"),
-            ("727", "小規模多機能型居宅介護"),
-            ("728", "障害児通所支援事業所"),
-            ("729", "障害者グループホーム"),
-            ("730", "障害福祉課関係団体"),
-            ("731", "心身障害者小規模通所作業所"),
-            ("732", "相談支援事業所"),
-            ("733", "地域活動支援センター"),
-            ("734", "地域福祉センタ"),
-            ("735", "長寿社会対策課関係団体"),
-            ("736", "薬務感染症対策課関係団体"),
-            ("737", "隣保館"),
-            ("738", "授産施設"),
-            ("740", "保育所等訪問支援事業所"),
-            ("741", "児童発達支援事業所"),
-            ("742", "障害児相談支援事業所"),
-            ("743", "就労移行支援事業所"),
-            ("744", "行動援護事業所"),
-            ("745", "居宅介護事業所"),
-            ("746", "短期入所事業所"),
-            ("749", "グループホーム"),
-            ("750", "居宅介護"),
-            ("751", "施設入所支援"),
-            ("752", "単独施設"),
-            ("753", "その他施設"),
-            ("754", "福祉複合施設（再掲）"),
-            ("756", "福祉センター及び福祉会館"),
-            ("757", "居宅介護・重度訪問介護"),
-            ("758", "地域福祉センター"),
-            ("760", "生活ホーム事業"),
-            ("762", "地域子育て支援拠点事業"),
-            ("763", "障害者就業・生活支援センター"),
-            ("766", "共同生活援助 (GH)"),
-            ("768", "福祉センター等"),
-            ("769", "看護小規模多機能型居宅介護"),
-            ("771", "介護療養型医療施設"),
-            ("772", "無料低額診療事業所"),
-            ("772", "無料低額診療事業所"),
-            ("773", "行動援護"),
-            ("773", "行動援護"),
-            ("774", "介護予防小規模多機能型居宅介護"),
-            ("774", "介護予防小規模多機能型居宅介護"),
-            ("775", "障がい児 (者 )地域療育等支援"),
-            ("775", "障がい児 (者 )地域療育等支援"),
-            ("776", "障がい者自立生活センター"),
-            ("777", "障がい者就業、生活支援センター"),
-            ("778", "障害者就労移行"),
-            ("779", "障害者就労移行、就労継続 A"),
-            ("780", "障害者就労移行、就労継続 A、 B"),
-            ("781", "障害者就労移行、就労継続 B"),
-            ("783", "障害者就労継続 A"),
-            ("784", "障害者就労継続 A、 B"),
-            ("785", "障害者就労継続 A、 B、就労移行"),
-            ("786", "障害者就労継続 A、就労移行"),
-            ("788", "障害者就労継続 B"),
-            ("797", "障害者宿泊型自立訓練"),
-            ("798", "障害者生活介護"),
-            ("799", "障害者生活介護 (児デイ )"),
-            ("801", "保育所"),
-            ("802", "へき地保育所"),
-            ("803", "認可外保育施設"),
-            ("804", "幼稚園"),
-            ("805", "認定こども園"),
-            ("806", "幼保連携型認定こども園"),
-            ("807", "母子父子休養ホーム"),
-            ("808", "認定保育施設"),
-            ("809", "事業所内保育施設"),
-            ("810", "小規模保育事業"),
-            ("811", "事業所内保育事業"),
-            ("812", "幼稚園型認定こども園"),
-            ("813", "保育所型認定こども園"),
-            ("814", "地方裁量型認定こども園"),
-            ("815", "指定保育所等訪問支援事業所"),
-            ("816", "小規模保育事業所"),
-            ("852", "保育所訪問支援"),
-            ("854", "保育所（分園）"),
-            ("855", "保育所型"),
-            ("856", "幼稚園型"),
-            ("857", "幼保連携型"),
-            ("859", "認証保育所（ A型）"),
-            ("860", "認証保育所（ B型）"),
-            ("901", "指定医療機関（重心障害児）"),
-            ("902", "肢体不自由児施設（入所）"),
-            ("903", "保育所等訪問支援"),
-            ("904", "医療型障害児入所支援"),
-            ("905", "障害児入所支援"),
-            ("1001", "共同生活援助"),
-            ("1002", "共同生活介護"),
-            ("1003", "計画相談支援"),
-            ("1004", "児童デイサービス"),
-            ("1005", "相談支援事業"),
-            ("1006", "地域移行支援"),
-            ("1007", "地域定着支援"),
-            ("1701", "障害者生活介護、就労移行"),
-            ("1702", "障害者生活介護、就労移行、就労継続 B"),
-            ("1703", "障害者生活介護、就労継続 A"),
-            ("1704", "障害者生活介護、就労継続 A、就労継続 B、生活訓練"),
-            ("1705", "障害者生活介護、就労継続 B"),
-            ("1707", "障害者生活介護、就労継続 B、生活訓練"),
-            ("1708", "障害者生活介護、生活訓練"),
-            ("1710", "障害者生活訓練"),
-            ("1711", "障害者生活訓練 (休止中 )、就労継続 B"),
-            ("1712", "障害者生活訓練、就労移行"),
-            ("1713", "障害者生活訓練、就労継続 B"),
-            ("1714", "障害者生活訓練、宿泊型自立訓練"),
-            ("1715", "障害者生活訓練、生活介護"),
-            ("1717", "障害福祉サービス事業所 (短期入所 )"),
-            ("1718", "障害福祉地域活動支援センター"),
-            ("1719", "独立行政法人国立病院機構：重症心身障害児入所委託"),
-            ("1720", "複合型サービス"),
-            ("1721", "一時保護所"),
-            ("1722", "指定医療機関"),
-            ("1723", "自立援助ホーム"),
-            ("1724", "障害者生活ホーム"),
-            ("1725", "心身障害者小規模福祉作業所"),
-            ("1726", "心身障害者福祉作業所（市町村立）"),
-            ("1727", "生活介護（障害者支援施設に指定されている昼間実施サービスを含む）"),
-            ("1728", "生活保護施設（医療保護施設）"),
-            ("1729", "生活保護施設（救護施設）"),
-            ("1730", "通所リハビリテーション事業所"),
-            ("1731", "放課後等デイサービス事業所"),
-            ("1732", "宿泊提供施設（その他の社会福祉施設）"),
-            ("1733", "無料低額宿泊事業所"),
-            ("1734", "相談支援事業所（特定相談支援事業、障害児相談支援事業、一般相談支援事業）"),
-            ("1735", "障害福祉サービス事業（居宅介護）"),
-            ("1736", "障害福祉サービス事業（共同生活援助（グループホーム））"),
-            ("1737", "障害福祉サービス事業（行動援護）"),
-            ("1738", "障害福祉サービス事業（自立訓練）"),
-            ("1739", "障害福祉サービス事業（就労移行支援一般型）"),
-            ("1740", "障害福祉サービス事業（就労継続支援Ａ型）"),
-            ("1741", "障害福祉サービス事業（就労継続支援Ｂ型）"),
-            ("1742", "障害福祉サービス事業（重度訪問介護）"),
-            ("1743", "障害福祉サービス事業（生活介護）"),
-            ("1744", "障害福祉サービス事業（短期入所）"),
-            ("1745", "障害福祉サービス事業（同行援護）"),
-            ("1746", "障害福祉サービス事業（療養介護）"),
-            ("1747", "ファミリーホーム"),
-            ("1748", "障害者共同生活援助事業所"),
-            ("1749", "障害者就労移行支援事業所"),
-            ("1750", "障害者就労継続支援事業所Ａ型"),
-            ("1751", "障害者生活訓練事業所"),
-            ("1752", "障害者自立訓練（生活訓練）"),
-            ("1753", "障害者就労移行支援"),
-            ("1754", "障害者就労継続支援 A型"),
-            ("1755", "障害者就労継続支援 B型"),
-            ("1756", "障害者療養介護"),
-            ("1757", "地域活動支援センター Ⅲ型"),
-            ("1758", "その他の保健福祉施設"),
-            ("1759", "市町村保健センター"),
-            ("1760", "指定一般相談支援事業 (地域移行・地域定着）"),
-            ("1761", "指定介護療養型医療施設"),
-            ("1762", "指定特定相談支援事業 (計画相談）"),
-            ("1763", "指定放課後等デイサービス事業所"),
-            ("1764", "心身障害者福祉センター"),
-            ("1765", "生活館"),
-            ("1766", "総合保健センター"),
-            ("1767", "地域振興センター"),
-            ("1768", "就労移行支援（一般型）"),
-            ("1769", "療養介護事業所"),
-            ("1770", "障害福祉サービス事業所"),
-            ("1771", "障害福祉サービス事業所（基準該当）"),
-            ("1772", "県立病院"),
-            ("1773", "社会福祉センター"),
-            ("1774", "小規模作業所"),
-            ("1775", "障害者更生相談所・精神保健福祉センター・発達障害者支援センター"),
-            ("1776", "障害者小規模地域活動センター"),
-            ("1777", "障害者地域活動推進センター"),
-            ("1778", "地域相談支援"),
-            ("1779", "介護保険施設"),
-            ("1780", "更生施設"),
-            ("1781", "授産施設（授産場）"),
-            ("1782", "宿泊所"),
-            ("1783", "労働・福祉センター"),
-            ("NA", "NA"),
-        ]
-        .into_iter()
-        .collect()
-    });
-
-#[rustfmt::skip]
-pub(crate) static WORLD_HERITAGE_CD: LazyLock<HashMap<&'static str, &'static str>> =
-    LazyLock::new(|| {
-        [
-            ("01", "知床"),
-            ("02", "白神山地"),
-            ("03", "屋久島"),
-            ("04", "小笠原"),
-        ]
-        .into_iter()
-        .collect()
-    });
+#[cfg(feature = "dict-water")]
+#[rustfmt::skip]
+pub(crate) static WATER_SYSTEM_CODE_CD: Codelist = Codelist::new("WATER_SYSTEM_CODE_CD", &[
+    ("010002", "010002:声問川"),
+    ("010003", "010003:増幌川"),
+    ("010004", "010004:知来別川"),
+    ("010005", "010005:鬼志別川"),
+    ("010006", "010006:猿骨川"),
+    ("010007", "010007:猿払川"),
+    ("010008", "010008:頓別川"),
+    ("010009", "010009:豊寒別川"),
+    ("010010", "010010:エサシウエンナイ川"),
+    ("010011", "010011:北見幌別川"),
+    ("010012", "010012:徳志別川"),
+    ("010013", "010013:フーレップ川"),
+    ("010014", "010014:音標川"),
+    ("010015", "010015:幌内川"),
+    ("010016", "010016:雄武川"),
+    ("010017", "010017:興部川"),
+    ("010018", "010018:藻興部川"),
+    ("010019", "010019:藻鼈川"),
+    ("010020", "010020:シブノツナイ川"),
+    ("010021", "010021:佐呂間別川"),
+    ("010022", "010022:卯原内川"),
+    ("010023", "010023:車止内川"),
+    ("010024", "010024:藻琴川"),
+    ("010025", "010025:浦士別川"),
+    ("010026", "010026:止別川"),
+    ("010027", "010027:斜里川"),
+    ("010028", "010028:奥蘂別川"),
+    ("010029", "010029:羅臼川"),
+    ("010030", "010030:知西別川"),
+    ("010031", "010031:春苅古丹川"),
+    ("010032", "010032:植別川"),
+    ("010033", "010033:忠類川"),
+    ("010034", "010034:標津川"),
+    ("010035", "010035:春別川"),
+    ("010036", "010036:西別川"),
+    ("010037", "010037:風蓮川"),
+    ("010038", "010038:チョロベツ川"),
+    ("010039", "010039:星ガ浦川"),
+    ("010040", "010040:阿寒川"),
+    ("010041", "010041:庶路川"),
+    ("010042", "010042:茶路川"),
+    ("010043", "010043:和天別川"),
+    ("010044", "010044:音別川"),
+    ("010045", "010045:尺別川"),
+    ("010046", "010046:直別川"),
+    ("010047", "010047:厚内川"),
+    ("010048", "010048:湧洞川"),
+    ("010049", "010049:生花苗川"),
+    ("010050", "010050:当縁川"),
+    ("010051", "010051:歴舟川"),
+    ("010052", "010052:紋別川"),
+    ("010053", "010053:豊似川"),
+    ("010054", "010054:楽古川"),
+    ("010055", "010055:広尾川"),
+    ("010056", "010056:猿留川"),
+    ("010057", "010057:歌別川"),
+    ("010058", "010058:幌満川"),
+    ("010059", "010059:様似川"),
+    ("010060", "010060:海辺川"),
+    ("010061", "010061:日高幌別川"),
+    ("010062", "010062:赤川"),
+    ("010063", "010063:乳呑川"),
+    ("010064", "010064:向別川"),
+    ("010065", "010065:絵笛川"),
+    ("010066", "010066:元浦川"),
+    ("010067", "010067:ケリマイ川"),
+    ("010068", "010068:三石川"),
+    ("010069", "010069:布辻川"),
+    ("010070", "010070:捫別川"),
+    ("010071", "010071:静内川"),
+    ("010072", "010072:真沼津川"),
+    ("010073", "010073:新冠川"),
+    ("010074", "010074:厚別川"),
+    ("010075", "010075:慶能舞川"),
+    ("010076", "010076:波恵川"),
+    ("010077", "010077:日高門別川"),
+    ("010078", "010078:入鹿別川"),
+    ("010079", "010079:厚真川"),
+    ("010080", "010080:安平川"),
+    ("010081", "010081:幌内川"),
+    ("010082", "010082:苫小牧川"),
+    ("010083", "010083:錦多峰川"),
+    ("010084", "010084:別々川"),
+    ("010085", "010085:社台川"),
+    ("010086", "010086:白老川"),
+    ("010087", "010087:敷生川"),
+    ("010088", "010088:ポンアヨロ川"),
+    ("010089", "010089:登別川"),
+    ("010090", "010090:岡志別川"),
+    ("010091", "010091:胆振幌別川"),
+    ("010092", "010092:富岸川"),
+    ("010093", "010093:鷲別川"),
+    ("010094", "010094:チマイベツ川"),
+    ("010095", "010095:気仙川"),
+    ("010096", "010096:シャミチセ川"),
+    ("010097", "010097:気門別川"),
+    ("010098", "010098:長流川"),
+    ("010099", "010099:板谷川"),
+    ("010100", "010100:ベンベ川"),
+    ("010101", "010101:貫気別川"),
+    ("010102", "010102:小鉾岸川"),
+    ("010103", "010103:礼文華川"),
+    ("010104", "010104:長万部川"),
+    ("010105", "010105:紋別川"),
+    ("010106", "010106:国縫川"),
+    ("010107", "010107:遊楽部川"),
+    ("010108", "010108:野田追川"),
+    ("010109", "010109:落部川"),
+    ("010110", "010110:鳥崎川"),
+    ("010111", "010111:茅部中の川"),
+    ("010112", "010112:折戸川"),
+    ("010113", "010113:磯谷川"),
+    ("010114", "010114:大舟川"),
+    ("010115", "010115:矢尻川"),
+    ("010116", "010116:古武井川"),
+    ("010117", "010117:尻岸内川"),
+    ("010118", "010118:原木川"),
+    ("010119", "010119:熊別川"),
+    ("010120", "010120:戸井川"),
+    ("010121", "010121:汐泊川"),
+    ("010122", "010122:松倉川"),
+    ("010123", "010123:亀田川"),
+    ("010124", "010124:常盤川"),
+    ("010125", "010125:久根別川"),
+    ("010126", "010126:大野川"),
+    ("010127", "010127:戸切地川"),
+    ("010128", "010128:流渓川"),
+    ("010129", "010129:茂辺地川"),
+    ("010130", "010130:佐女川"),
+    ("010131", "010131:木古内川"),
+    ("010132", "010132:森越川"),
+    ("010133", "010133:知内川"),
+    ("010134", "010134:福島川"),
+    ("010135", "010135:白符川"),
+    ("010136", "010136:吉岡川"),
+    ("010137", "010137:及部川"),
+    ("010138", "010138:大松前川"),
+    ("010139", "010139:茂草川"),
+    ("010140", "010140:小鴨津川"),
+    ("010141", "010141:大鴨津川"),
+    ("010142", "010142:石崎川"),
+    ("010143", "010143:天野川"),
+    ("010144", "010144:厚沢部川"),
+    ("010145", "010145:姫川"),
+    ("010146", "010146:相沼内川"),
+    ("010147", "010147:見市川"),
+    ("010148", "010148:太櫓川"),
+    ("010149", "010149:馬場川"),
+    ("010150", "010150:千走川"),
+    ("010151", "010151:泊川"),
+    ("010152", "010152:太平川"),
+    ("010153", "010153:折川"),
+    ("010154", "010154:朱太川"),
+    ("010155", "010155:野束川"),
+    ("010156", "010156:堀株川"),
+    ("010157", "010157:玉川"),
+    ("010158", "010158:盃川"),
+    ("010159", "010159:古宇川"),
+    ("010160", "010160:積丹川"),
+    ("010161", "010161:美国川"),
+    ("010162", "010162:古平川"),
+    ("010163", "010163:沖村川"),
+    ("010164", "010164:湯内川"),
+    ("010165", "010165:梅川"),
+    ("010166", "010166:ヌッチ川"),
+    ("010167", "010167:余市川"),
+    ("010168", "010168:登川"),
+    ("010169", "010169:畚部川"),
+    ("010170", "010170:蘭島川"),
+    ("010171", "010171:塩谷川"),
+    ("010172", "010172:勝納川"),
+    ("010173", "010173:朝里川"),
+    ("010174", "010174:星置川"),
+    ("010175", "010175:新川"),
+    ("010176", "010176:旧知津狩川"),
+    ("010177", "010177:正利冠川"),
+    ("010178", "010178:望来川"),
+    ("010179", "010179:厚田川"),
+    ("010180", "010180:濃昼川"),
+    ("010181", "010181:浜益川"),
+    ("010182", "010182:郡別川"),
+    ("010183", "010183:幌川"),
+    ("010184", "010184:岩老川"),
+    ("010185", "010185:暑寒別川"),
+    ("010186", "010186:永寿川"),
+    ("010187", "010187:箸別川"),
+    ("010188", "010188:信砂川"),
+    ("010189", "010189:小平蘂川"),
+    ("010190", "010190:温寧川"),
+    ("010191", "010191:古丹別川"),
+    ("010192", "010192:羽幌川"),
+    ("010193", "010193:築別川"),
+    ("010194", "010194:茂築別川"),
+    ("010195", "010195:セタキナイ川"),
+    ("010196", "010196:初山別川"),
+    ("010197", "010197:茂初山別川"),
+    ("010198", "010198:風連別川"),
+    ("010199", "010199:オタコシベツ川"),
+    ("010200", "010200:遠別川"),
+    ("010201", "010201:ウツツ川"),
+    ("010202", "010202:青苗川"),
+    ("010203", "010203:タネトンナイ川"),
+    ("010204", "010204:大沢川"),
+    ("010205", "010205:チララウスナイ川"),
+    ("010207", "010207:エノシュコマナイ川"),
+    ("010208", "010208:イノコタンポ川"),
+    ("010209", "010209:オタコムシュベツ川"),
+    ("010211", "010211:オンネナイ川"),
+    ("010212", "010212:オビオショップ川"),
+    ("010213", "010213:コムニウシ川"),
+    ("010214", "010214:ハッタリ川"),
+    ("010215", "010215:汐見川"),
+    ("010216", "010216:オクネップ川"),
+    ("010217", "010217:風連別川"),
+    ("010218", "010218:野塚川"),
+    ("010219", "010219:音調津川"),
+    ("010220", "010220:門別川"),
+    ("010221", "010221:カネカリウシナイ川"),
+    ("010222", "010222:鵜苫川"),
+    ("010223", "010223:月寒川"),
+    ("010224", "010224:ウシュッペ川"),
+    ("010225", "010225:有良川"),
+    ("010226", "010226:有勢内川"),
+    ("010227", "010227:神山川"),
+    ("010228", "010228:稲荷川"),
+    ("010229", "010229:小泉の沢川"),
+    ("010230", "010230:小糸魚川"),
+    ("010231", "010231:覚生川"),
+    ("010232", "010232:樽前川"),
+    ("010234", "010234:クッタリウス川"),
+    ("010235", "010235:アヨロ川"),
+    ("010236", "010236:伏古別川"),
+    ("010237", "010237:富浦川"),
+    ("010238", "010238:ヤンケシ川"),
+    ("010240", "010240:本輪西川"),
+    ("010241", "010241:ポロペケレオタ川"),
+    ("010242", "010242:トコタン川"),
+    ("010243", "010243:鹿部川"),
+    ("010244", "010244:垣の島川"),
+    ("010245", "010245:尾札部川"),
+    ("010246", "010246:八木川"),
+    ("010247", "010247:見日川"),
+    ("010248", "010248:ポン木直川"),
+    ("010249", "010249:木直川"),
+    ("010250", "010250:志海苔川"),
+    ("010251", "010251:股瀬川"),
+    ("010252", "010252:白神川"),
+    ("010253", "010253:スズキの沢川"),
+    ("010254", "010254:荒谷川"),
+    ("010255", "010255:大沢川"),
+    ("010256", "010256:小松前川"),
+    ("010257", "010257:唐津内沢川"),
+    ("010258", "010258:大尽内川"),
+    ("010259", "010259:妻内川"),
+    ("010260", "010260:赤神川"),
+    ("010261", "010261:雨竜川"),
+    ("010262", "010262:大澗川"),
+    ("010263", "010263:二越川"),
+    ("010264", "010264:奥末川"),
+    ("010265", "010265:原口川"),
+    ("010266", "010266:小安在川"),
+    ("010267", "010267:大安在川"),
+    ("010268", "010268:緑川"),
+    ("010269", "010269:小茂内川"),
+    ("010270", "010270:突符川"),
+    ("010271", "010271:可笑内川"),
+    ("010272", "010272:花磯川"),
+    ("010273", "010273:貝取澗川"),
+    ("010275", "010275:熊碓川"),
+    ("010276", "010276:毘砂別川"),
+    ("010277", "010277:オネトマナイ川"),
+    ("010278", "010278:勇栄川"),
+    ("010279", "010279:勇知川"),
+    ("010280", "010280:メメナイ川"),
+    ("010281", "010281:抜海１線川"),
+    ("010282", "010282:クトネベツ川"),
+    ("010283", "010283:ウロンナイ川"),
+    ("010284", "010284:シュルコマナイ川"),
+    ("010285", "010285:トベンナイ川"),
+    ("010286", "010286:真言寺川"),
+    ("010287", "010287:オイショマナイ川"),
+    ("010288", "010288:富磯川"),
+    ("010289", "010289:宗谷川"),
+    ("010290", "010290:オンコロマナイ川"),
+    ("010291", "010291:清沢川"),
+    ("010292", "010292:珊内川"),
+    ("010293", "010293:鬼切別川"),
+    ("010294", "010294:知志矢川"),
+    ("010295", "010295:上知志矢川"),
+    ("010296", "010296:泊内川"),
+    ("010297", "010297:目梨川"),
+    ("010298", "010298:時前川"),
+    ("010299", "010299:下苗太路川"),
+    ("010300", "010300:シリナエ川"),
+    ("010301", "010301:ソセツ川"),
+    ("010302", "010302:ウバトマナイ川"),
+    ("010303", "010303:目梨泊川"),
+    ("010304", "010304:オケンタンベ川"),
+    ("010305", "010305:モウケシタンベ川"),
+    ("010306", "010306:落切川"),
+    ("010307", "010307:問牧川"),
+    ("010308", "010308:オキシュナイ川"),
+    ("010309", "010309:親潮川"),
+    ("010310", "010310:栄川"),
+    ("010311", "010311:ポロナイポ川"),
+    ("010312", "010312:トキワ沢川"),
+    ("010313", "010313:モウツ川"),
+    ("010314", "010314:オンネシトシスマリコタン"),
+    ("010315", "010315:岡島川"),
+    ("010316", "010316:黄金川"),
+    ("010317", "010317:オクヤラセナイ川"),
+    ("010318", "010318:オッチャラベツ川"),
+    ("010319", "010319:オタノワカナイ川"),
+    ("010320", "010320:オンネナイ川"),
+    ("010321", "010321:ウプシュナイ川"),
+    ("010322", "010322:ペラシュナイ川"),
+    ("010323", "010323:ミナミペラシュナイ川"),
+    ("010324", "010324:オレタラップ川"),
+    ("010325", "010325:モオレタラップ川"),
+    ("010326", "010326:ニウシナイポ川"),
+    ("010327", "010327:乙忠部川"),
+    ("010328", "010328:オニセップ川"),
+    ("010329", "010329:ベセトコマナイ川"),
+    ("010330", "010330:オニオマナイ川"),
+    ("010331", "010331:シャクネツナイ川"),
+    ("010332", "010332:シルコマナイ川"),
+    ("010333", "010333:オタルベン川"),
+    ("010334", "010334:ラバトナー川"),
+    ("010335", "010335:トイナイ川"),
+    ("010336", "010336:ウエンシテント川"),
+    ("010337", "010337:枝枝幸川"),
+    ("010338", "010338:オキシナイ川"),
+    ("010339", "010339:ポンオムシロナイ川"),
+    ("010340", "010340:フンベオムナイ川"),
+    ("010341", "010341:アチュウシナイ川"),
+    ("010342", "010342:音稲府川"),
+    ("010343", "010343:イウニコタニナイ川"),
+    ("010344", "010344:元稲府川"),
+    ("010347", "010347:ナヨロ川"),
+    ("010348", "010348:オタコムシュペツ川"),
+    ("010349", "010349:シケトチナイ川"),
+    ("010350", "010350:元沢木川"),
+    ("010351", "010351:御西川"),
+    ("010352", "010352:久富川"),
+    ("010353", "010353:ケミチカナイ川"),
+    ("010354", "010354:思沙留川"),
+    ("010355", "010355:一本松３線川"),
+    ("010356", "010356:フンベオマナイ川"),
+    ("010357", "010357:ヤッシュウナイ川"),
+    ("010358", "010358:オンネコムケナイ川"),
+    ("010359", "010359:大島川"),
+    ("010360", "010360:ミサキオンネナイ川"),
+    ("010361", "010361:モセウシナイ川"),
+    ("010362", "010362:ウラレナイ川"),
+    ("010363", "010363:バイラギ川"),
+    ("010364", "010364:ポンバイラギ川"),
+    ("010365", "010365:ピットカリ川"),
+    ("010366", "010366:駒場川"),
+    ("010367", "010367:オショプ川"),
+    ("010368", "010368:勇仁川"),
+    ("010369", "010369:那寄川"),
+    ("010370", "010370:ウエンベツ川"),
+    ("010371", "010371:西一線川"),
+    ("010372", "010372:シマトツカリ川"),
+    ("010373", "010373:糠真布川"),
+    ("010374", "010374:オライネコタン川"),
+    ("010375", "010375:オチカバケ川"),
+    ("010376", "010376:オタモイ川"),
+    ("010377", "010377:オショバオマブ川"),
+    ("010378", "010378:金山川"),
+    ("010379", "010379:チプラノケナイ川"),
+    ("010380", "010380:オンネベツ川"),
+    ("010381", "010381:シャリキ川"),
+    ("010382", "010382:オペケプ川"),
+    ("010383", "010383:チャラッセナイ川"),
+    ("010384", "010384:オショコマナイ川"),
+    ("010385", "010385:フンベ川"),
+    ("010386", "010386:ペレケ川"),
+    ("010387", "010387:ウトロ沢川"),
+    ("010388", "010388:ホロベツ川"),
+    ("010389", "010389:イワウベツ川"),
+    ("010390", "010390:イダシュベツ川"),
+    ("010391", "010391:イロイロ川"),
+    ("010392", "010392:カムイワッカ川"),
+    ("010393", "010393:硫黄川"),
+    ("010394", "010394:ウンメーン川"),
+    ("010395", "010395:ウソシオクベ川"),
+    ("010396", "010396:ウブシノッタ川"),
+    ("010397", "010397:ボンブタ川"),
+    ("010398", "010398:ボンベツ川"),
+    ("010399", "010399:ルシャ川"),
+    ("010400", "010400:テッパンベツ川"),
+    ("010401", "010401:チャカババイ川"),
+    ("010402", "010402:タキノ川"),
+    ("010403", "010403:鮹岩川"),
+    ("010404", "010404:知床川"),
+    ("010405", "010405:ポトピラベツ川"),
+    ("010406", "010406:マムシの川"),
+    ("010407", "010407:オケツチウシ川"),
+    ("010408", "010408:アウンルイ川"),
+    ("010409", "010409:ポロモイ川"),
+    ("010410", "010410:アカイワ川"),
+    ("010411", "010411:メオトタップ川"),
+    ("010412", "010412:メオトタキ川"),
+    ("010413", "010413:タキ川"),
+    ("010414", "010414:ペキン川"),
+    ("010415", "010415:モイレウシ川"),
+    ("010416", "010416:ウナキベツ川"),
+    ("010417", "010417:クズレハマ川"),
+    ("010418", "010418:カモイウンベ川"),
+    ("010419", "010419:アイドマリ川"),
+    ("010420", "010420:オショロッコ川"),
+    ("010421", "010421:ルサ川"),
+    ("010422", "010422:キキリベツ川"),
+    ("010423", "010423:ショウジ川"),
+    ("010424", "010424:ケンネベツ川"),
+    ("010425", "010425:チェンベツ川"),
+    ("010426", "010426:モセカルベツ川"),
+    ("010427", "010427:オッカバケ川"),
+    ("010428", "010428:サシルイ川"),
+    ("010429", "010429:ハシコイ川"),
+    ("010430", "010430:知徒来川"),
+    ("010431", "010431:立仁臼川"),
+    ("010432", "010432:飛仁臼川"),
+    ("010433", "010433:ソスケ川"),
+    ("010434", "010434:松法川"),
+    ("010435", "010435:立苅臼川"),
+    ("010436", "010436:精神川"),
+    ("010437", "010437:ポン春苅古丹川"),
+    ("010438", "010438:ポン幌萌川"),
+    ("010439", "010439:幌萌川"),
+    ("010440", "010440:幌萌小沢川"),
+    ("010441", "010441:茶志別川"),
+    ("010442", "010442:ポン陸志別川"),
+    ("010443", "010443:陸志別川"),
+    ("010444", "010444:居麻布川"),
+    ("010445", "010445:シンコウシ沢川"),
+    ("010446", "010446:鬼尾内川"),
+    ("010447", "010447:元崎無異川"),
+    ("010448", "010448:崎無異川"),
+    ("010449", "010449:薫別川"),
+    ("010450", "010450:古多糠川"),
+    ("010451", "010451:ポウ川"),
+    ("010452", "010452:茶志骨川"),
+    ("010453", "010453:当幌川"),
+    ("010454", "010454:飛雁川"),
+    ("010455", "010455:コムニウシ川"),
+    ("010456", "010456:戸川"),
+    ("010457", "010457:モノトコタン川"),
+    ("010458", "010458:ライトコタン川"),
+    ("010459", "010459:床丹川"),
+    ("010460", "010460:トリサンケベツ川"),
+    ("010461", "010461:第３川"),
+    ("010462", "010462:第４川"),
+    ("010463", "010463:オマンベツ川"),
+    ("010464", "010464:西丸別川"),
+    ("010465", "010465:オンネベツ川"),
+    ("010466", "010466:トフケナイ川"),
+    ("010467", "010467:ホロモシリ川"),
+    ("010468", "010468:第２ホニオイ川"),
+    ("010469", "010469:第１ホニオイ川"),
+    ("010470", "010470:恋問川"),
+    ("010471", "010471:ネムロベツ川"),
+    ("010472", "010472:１番川"),
+    ("010473", "010473:２番川"),
+    ("010474", "010474:コタンケシ川"),
+    ("010475", "010475:四番川"),
+    ("010476", "010476:ノッカマップ川"),
+    ("010477", "010477:オンネップ川"),
+    ("010478", "010478:コネップ川"),
+    ("010479", "010479:サンコタン川"),
+    ("010480", "010480:トーサムポロ川"),
+    ("010481", "010481:オンネモト川"),
+    ("010482", "010482:オンネモト沢川"),
+    ("010483", "010483:第二ゴヨーマイ川"),
+    ("010484", "010484:第一ゴヨーマイ川"),
+    ("010485", "010485:ゴヨーマイ川"),
+    ("010486", "010486:ハボマイ川"),
+    ("010487", "010487:婦羅理川"),
+    ("010488", "010488:ヒキウス川"),
+    ("010489", "010489:沖根辺川"),
+    ("010490", "010490:第一オキネップ川"),
+    ("010491", "010491:沖根婦川"),
+    ("010492", "010492:友知川"),
+    ("010493", "010493:端谷川"),
+    ("010494", "010494:温根沼川"),
+    ("010495", "010495:丹根沼川"),
+    ("010496", "010496:南部沼川"),
+    ("010497", "010497:カツラムイ川"),
+    ("010498", "010498:オッカイベツ川"),
+    ("010499", "010499:第１長節川"),
+    ("010500", "010500:第２長節川"),
+    ("010501", "010501:タキグチ川"),
+    ("010502", "010502:天狗岩川"),
+    ("010503", "010503:ホロニタイ川"),
+    ("010504", "010504:フレシマ川"),
+    ("010505", "010505:チッチャラベツ川"),
+    ("010506", "010506:ガッカラ川"),
+    ("010507", "010507:初田牛川"),
+    ("010508", "010508:和田牛川"),
+    ("010509", "010509:浜和田牛川"),
+    ("010510", "010510:二ツ岩１川"),
+    ("010511", "010511:二ツ岩２川"),
+    ("010512", "010512:二ツ岩３川"),
+    ("010513", "010513:三角台川"),
+    ("010514", "010514:恵茶人東川"),
+    ("010515", "010515:東小沼"),
+    ("010516", "010516:恵茶人川"),
+    ("010517", "010517:貰人川"),
+    ("010518", "010518:仙鳳跡川"),
+    ("010519", "010519:赤泊川"),
+    ("010520", "010520:羨古丹川"),
+    ("010521", "010521:奔幌戸川"),
+    ("010522", "010522:幌戸川"),
+    ("010523", "010523:幌戸東川"),
+    ("010524", "010524:後静川"),
+    ("010525", "010525:榊川"),
+    ("010526", "010526:暮帰別川"),
+    ("010527", "010527:新川"),
+    ("010528", "010528:琵琶瀬川"),
+    ("010529", "010529:渡散布川"),
+    ("010530", "010530:養老散布川"),
+    ("010531", "010531:火散布川"),
+    ("010532", "010532:藻散布川"),
+    ("010533", "010533:末広川"),
+    ("010534", "010534:幌万別川"),
+    ("010535", "010535:床潭川"),
+    ("010536", "010536:浮田川"),
+    ("010537", "010537:奔渡川"),
+    ("010538", "010538:イクラウシ川"),
+    ("010539", "010539:東梅川"),
+    ("010540", "010540:東トキタイ川"),
+    ("010541", "010541:トキタイ川"),
+    ("010542", "010542:別寒辺牛川"),
+    ("010544", "010544:別太川"),
+    ("010545", "010545:古番屋川"),
+    ("010546", "010546:別尺泊４号川"),
+    ("010547", "010547:別尺泊３号川"),
+    ("010548", "010548:別尺泊２号川"),
+    ("010549", "010549:別尺泊１号川"),
+    ("010550", "010550:知方学川"),
+    ("010551", "010551:老者舞川"),
+    ("010552", "010552:冬窓床川"),
+    ("010553", "010553:跡永賀川"),
+    ("010554", "010554:浦雲泊川"),
+    ("010555", "010555:十町瀬川"),
+    ("010556", "010556:幌内川"),
+    ("010557", "010557:アチョロベツ川"),
+    ("010558", "010558:宿徳内川"),
+    ("010559", "010559:又飯時沢川"),
+    ("010560", "010560:桂恋川"),
+    ("010561", "010561:ミツウラ川"),
+    ("010562", "010562:ビシャモン川"),
+    ("010563", "010563:イワミ川"),
+    ("010564", "010564:シラカバ川"),
+    ("010565", "010565:オコツ川"),
+    ("010566", "010566:タイヘイ川"),
+    ("010568", "010568:摩周湖"),
+    ("010569", "010569:オンネチカップ川"),
+    ("010570", "010570:シカリカップ川"),
+    ("010571", "010571:オクネップ川"),
+    ("010572", "010572:馬主来川"),
+    ("010573", "010573:チノミ川"),
+    ("010574", "010574:風連別川"),
+    ("010575", "010575:乙部川"),
+    ("010576", "010576:オニオップ川"),
+    ("010577", "010577:チプネオコッペ川"),
+    ("010578", "010578:オコッぺ沢川"),
+    ("010579", "010579:昆布刈石川"),
+    ("010580", "010580:滝の沢川"),
+    ("010582", "010582:コロモト川"),
+    ("010583", "010583:ポンモト川"),
+    ("010584", "010584:モウド川"),
+    ("010585", "010585:チラユウトン川"),
+    ("010586", "010586:生花苗沼川"),
+    ("010587", "010587:ホロカヤントウ川"),
+    ("010588", "010588:アイボシマ川"),
+    ("010589", "010589:小紋別川"),
+    ("010590", "010590:新生川"),
+    ("010591", "010591:美幌川"),
+    ("010592", "010592:オナオベツ川"),
+    ("010593", "010593:ルベシベツ川"),
+    ("010594", "010594:ヒタタヌンケ川"),
+    ("010595", "010595:ピタタヌンケ川"),
+    ("010596", "010596:オニトップ川"),
+    ("010597", "010597:咲梅川"),
+    ("010598", "010598:シトマン川"),
+    ("010599", "010599:千平川"),
+    ("010600", "010600:アアツ川"),
+    ("010601", "010601:在田川"),
+    ("010602", "010602:キスケ川"),
+    ("010604", "010604:エリモ川"),
+    ("010605", "010605:アブラコマ川"),
+    ("010606", "010606:オショロスケ川"),
+    ("010607", "010607:ヤキベツ川"),
+    ("010608", "010608:ウタロ川"),
+    ("010609", "010609:番屋の川"),
+    ("010610", "010610:コロップ川"),
+    ("010611", "010611:シンシケナイ川"),
+    ("010612", "010612:中学校沢川"),
+    ("010613", "010613:シラヌマナイ川"),
+    ("010614", "010614:幌泉川"),
+    ("010615", "010615:ナンブケ川"),
+    ("010616", "010616:ボンサツコツ川"),
+    ("010617", "010617:サツコツ川"),
+    ("010618", "010618:野津内川"),
+    ("010619", "010619:アベヤキ川"),
+    ("010620", "010620:下笛舞川"),
+    ("010621", "010621:笛舞川"),
+    ("010622", "010622:上古丹川"),
+    ("010623", "010623:サワッコ川"),
+    ("010624", "010624:シモチカウラ川"),
+    ("010625", "010625:チヨカップ川"),
+    ("010626", "010626:ニカンベツ川"),
+    ("010627", "010627:ルサキ川"),
+    ("010628", "010628:ルランベツ川"),
+    ("010629", "010629:山中川"),
+    ("010630", "010630:オソスケウス川"),
+    ("010631", "010631:コトニ川"),
+    ("010632", "010632:冬島川"),
+    ("010633", "010633:ポンサヌシベツ川"),
+    ("010634", "010634:ポロサヌシベツ川"),
+    ("010635", "010635:シミチカップ川"),
+    ("010636", "010636:冬似川"),
+    ("010637", "010637:ウロコベツ川"),
+    ("010638", "010638:昌平川"),
+    ("010639", "010639:浜荻伏川"),
+    ("010640", "010640:無名川"),
+    ("010641", "010641:マツザワ川"),
+    ("010642", "010642:鳧川"),
+    ("010643", "010643:オマイ川"),
+    ("010644", "010644:静富川"),
+    ("010645", "010645:姨布川"),
+    ("010646", "010646:ポンニノコシ川"),
+    ("010647", "010647:ニノコシ川"),
+    ("010648", "010648:元静内川"),
+    ("010649", "010649:６マップ川"),
+    ("010650", "010650:真歌川"),
+    ("010651", "010651:浦里川"),
+    ("010652", "010652:節婦川"),
+    ("010653", "010653:大節婦川"),
+    ("010654", "010654:コマチップ川"),
+    ("010655", "010655:玉水川"),
+    ("010656", "010656:賀張川"),
+    ("010657", "010657:ビラウトリ川"),
+    ("010658", "010658:旧苫小牧川"),
+    ("010659", "010659:ポン小糸魚川"),
+    ("010660", "010660:フシコベツ川"),
+    ("010661", "010661:ポンアヨロ川"),
+    ("010662", "010662:倶多楽湖"),
+    ("010663", "010663:橘湖"),
+    ("010664", "010664:絵靹川"),
+    ("010665", "010665:小橋内川"),
+    ("010666", "010666:南高平川"),
+    ("010667", "010667:中幌萌川"),
+    ("010668", "010668:幌萌川"),
+    ("010669", "010669:ポンペケレオタ川"),
+    ("010670", "010670:元室蘭川"),
+    ("010671", "010671:北黄金川"),
+    ("010672", "010672:牛舎川"),
+    ("010673", "010673:高砂川"),
+    ("010674", "010674:岩根川"),
+    ("010675", "010675:学田川"),
+    ("010676", "010676:古川"),
+    ("010677", "010677:谷藤川"),
+    ("010678", "010678:農舎川"),
+    ("010679", "010679:若生川"),
+    ("010680", "010680:東有珠川"),
+    ("010681", "010681:有珠川"),
+    ("010682", "010682:赤川"),
+    ("010683", "010683:フレナイ川"),
+    ("010684", "010684:アクシナイ川"),
+    ("010685", "010685:チヤス川"),
+    ("010686", "010686:コボロ川"),
+    ("010687", "010687:静狩川"),
+    ("010688", "010688:原野川"),
+    ("010689", "010689:オタモイ川"),
+    ("010690", "010690:ミナミサカエ川"),
+    ("010691", "010691:中の川"),
+    ("010692", "010692:中の沢川"),
+    ("010693", "010693:ワルイ川"),
+    ("010694", "010694:ボンワルイ川"),
+    ("010695", "010695:前島川"),
+    ("010696", "010696:茂国縫川"),
+    ("010697", "010697:ポンポロナイ川"),
+    ("010698", "010698:幌内川"),
+    ("010699", "010699:ルコツ川"),
+    ("010700", "010700:シラリカ川"),
+    ("010701", "010701:山崎川"),
+    ("010702", "010702:早瀬川"),
+    ("010703", "010703:ブイタウシナイ川"),
+    ("010704", "010704:ハシノスベツ川"),
+    ("010705", "010705:熱田川"),
+    ("010706", "010706:ポン奥津内川"),
+    ("010707", "010707:奥津内川"),
+    ("010708", "010708:酒屋川"),
+    ("010709", "010709:境川"),
+    ("010710", "010710:弥之助沢川"),
+    ("010711", "010711:茂無部川"),
+    ("010712", "010712:本内川"),
+    ("010713", "010713:三次郎川"),
+    ("010714", "010714:石倉川"),
+    ("010715", "010715:石川の沢川"),
+    ("010716", "010716:濁川"),
+    ("010717", "010717:地蔵川"),
+    ("010718", "010718:大江川"),
+    ("010719", "010719:蛯谷川"),
+    ("010720", "010720:カド川"),
+    ("010721", "010721:桂川"),
+    ("010722", "010722:鷲木川"),
+    ("010723", "010723:万太郎川"),
+    ("010724", "010724:角兵ヱ川"),
+    ("010725", "010725:森川"),
+    ("010726", "010726:尾白内川"),
+    ("010727", "010727:押出沢川"),
+    ("010728", "010728:小石崎川"),
+    ("010729", "010729:テントウ沢川"),
+    ("010730", "010730:梨木沢川"),
+    ("010731", "010731:弥右ヱ門沢川"),
+    ("010732", "010732:馬抛沢川"),
+    ("010733", "010733:イラ沢川"),
+    ("010734", "010734:足割川"),
+    ("010735", "010735:明神川"),
+    ("010736", "010736:鍛治屋川"),
+    ("010737", "010737:中ノ川"),
+    ("010738", "010738:トドメキ川"),
+    ("010739", "010739:尻無川"),
+    ("010740", "010740:押出沢川"),
+    ("010741", "010741:本別川"),
+    ("010742", "010742:亀泊川"),
+    ("010743", "010743:温沢川"),
+    ("010744", "010744:うなぎ川"),
+    ("010745", "010745:ザリ石川"),
+    ("010746", "010746:常路川"),
+    ("010747", "010747:三味線沢川"),
+    ("010748", "010748:ニボシ川"),
+    ("010749", "010749:中の川"),
+    ("010750", "010750:黒羽尻川"),
+    ("010751", "010751:ダイボウ川"),
+    ("010752", "010752:岩戸川"),
+    ("010753", "010753:美呂泊高田川"),
+    ("010754", "010754:美呂泊川"),
+    ("010755", "010755:太夫川"),
+    ("010756", "010756:美呂泊笹口川"),
+    ("010757", "010757:美呂泊佐々木川"),
+    ("010758", "010758:角張川"),
+    ("010759", "010759:大舟鎌田川"),
+    ("010760", "010760:大舟村上川"),
+    ("010761", "010761:入美川"),
+    ("010762", "010762:大舟中村川"),
+    ("010763", "010763:茂佐尻川"),
+    ("010764", "010764:葡萄川"),
+    ("010765", "010765:紅葉川"),
+    ("010766", "010766:入久川"),
+    ("010767", "010767:精進川"),
+    ("010768", "010768:川汲川"),
+    ("010769", "010769:清水川"),
+    ("010770", "010770:川汲コイタ川"),
+    ("010771", "010771:築上川"),
+    ("010772", "010772:マコウ川"),
+    ("010773", "010773:著保内川"),
+    ("010774", "010774:ドウヤマ川"),
+    ("010775", "010775:シマウタ川"),
+    ("010776", "010776:盤の沢川"),
+    ("010777", "010777:木直神社川"),
+    ("010778", "010778:白井川"),
+    ("010779", "010779:大梶川"),
+    ("010780", "010780:オダワラ川"),
+    ("010781", "010781:タテイワ川"),
+    ("010782", "010782:獅子鼻川"),
+    ("010783", "010783:相泊川"),
+    ("010784", "010784:滝の沢川"),
+    ("010785", "010785:銚子川"),
+    ("010786", "010786:八幡川"),
+    ("010787", "010787:番屋川"),
+    ("010788", "010788:乱塔川"),
+    ("010789", "010789:元村川"),
+    ("010790", "010790:岩田川"),
+    ("010791", "010791:温泉川"),
+    ("010792", "010792:滝沢川"),
+    ("010793", "010793:神社川"),
+    ("010794", "010794:イノウエ川"),
+    ("010795", "010795:白浜川"),
+    ("010796", "010796:平島沢川"),
+    ("010797", "010797:中野川"),
+    ("010798", "010798:４号川"),
+    ("010799", "010799:３号川"),
+    ("010800", "010800:２号川"),
+    ("010801", "010801:１号川"),
+    ("010802", "010802:山十沢川"),
+    ("010803", "010803:メノコ川"),
+    ("010804", "010804:サカサ川"),
+    ("010805", "010805:宮の沢川"),
+    ("010806", "010806:寺院川"),
+    ("010807", "010807:豊浦川"),
+    ("010808", "010808:日浦川"),
+    ("010809", "010809:里子川"),
+    ("010810", "010810:ツンタ川"),
+    ("010811", "010811:蛯子川"),
+    ("010812", "010812:蓬内川"),
+    ("010813", "010813:ウエン川"),
+    ("010814", "010814:運賀川"),
+    ("010815", "010815:小安川"),
+    ("010816", "010816:高屋敷川"),
+    ("010817", "010817:抜石川"),
+    ("010818", "010818:谷地町川"),
+    ("010819", "010819:白石川"),
+    ("010820", "010820:中村川"),
+    ("010821", "010821:寺の川"),
+    ("010822", "010822:石崎宮の川"),
+    ("010823", "010823:ムジナ川"),
+    ("010824", "010824:銭亀宮の川"),
+    ("010825", "010825:乳母川"),
+    ("010826", "010826:亀屋川"),
+    ("010827", "010827:セト川"),
+    ("010828", "010828:柾屋川"),
+    ("010829", "010829:根崎宮の川"),
+    ("010830", "010830:弥衛門川"),
+    ("010831", "010831:第一国費排水川"),
+    ("010832", "010832:大和川"),
+    ("010833", "010833:添山古川"),
+    ("010834", "010834:宗山川"),
+    ("010835", "010835:端の川"),
+    ("010836", "010836:下町沢川"),
+    ("010837", "010837:矢不来川"),
+    ("010838", "010838:下矢不来川"),
+    ("010839", "010839:当別川"),
+    ("010840", "010840:大当別川"),
+    ("010841", "010841:石倉野川"),
+    ("010842", "010842:瑞石川"),
+    ("010843", "010843:大釜谷川"),
+    ("010844", "010844:ミヤノサワ川"),
+    ("010845", "010845:ミズクミザワ川"),
+    ("010846", "010846:ハルコマ川"),
+    ("010847", "010847:サラキ川"),
+    ("010848", "010848:亀川"),
+    ("010849", "010849:橋呉川"),
+    ("010850", "010850:ボンクレ川"),
+    ("010851", "010851:幸連川"),
+    ("010852", "010852:大濶川"),
+    ("010853", "010853:蛇内川"),
+    ("010854", "010854:孫七川"),
+    ("010855", "010855:大平川"),
+    ("010856", "010856:八千代川"),
+    ("010857", "010857:建有川"),
+    ("010858", "010858:中の川"),
+    ("010859", "010859:上湯の沢川"),
+    ("010860", "010860:湯の沢川"),
+    ("010861", "010861:中の沢川"),
+    ("010862", "010862:上の沢川"),
+    ("010863", "010863:ツヅラ沢川"),
+    ("010864", "010864:岩部川"),
+    ("010865", "010865:日の出川"),
+    ("010866", "010866:鳥沢川"),
+    ("010867", "010867:浜沢川"),
+    ("010868", "010868:板橋川"),
+    ("010869", "010869:釜谷川"),
+    ("010870", "010870:中の沢川"),
+    ("010871", "010871:寺の沢川"),
+    ("010872", "010872:滝の下川"),
+    ("010873", "010873:ボウズサワ川"),
+    ("010874", "010874:駄内川"),
+    ("010875", "010875:宮歌川"),
+    ("010876", "010876:豊浜川"),
+    ("010877", "010877:吉野川"),
+    ("010878", "010878:松浦川"),
+    ("010879", "010879:ヒジノ下川"),
+    ("010880", "010880:寺沢川"),
+    ("010881", "010881:伝治沢川"),
+    ("010882", "010882:小尽内川"),
+    ("010883", "010883:ユノ沢川"),
+    ("010884", "010884:戸長川"),
+    ("010885", "010885:湯の沢川"),
+    ("010886", "010886:棚石川"),
+    ("010887", "010887:一本木川"),
+    ("010888", "010888:アイ泊川"),
+    ("010889", "010889:長内川"),
+    ("010890", "010890:中央川"),
+    ("010891", "010891:大沢川"),
+    ("010892", "010892:ハマの沢川"),
+    ("010893", "010893:イチヤマの沢川"),
+    ("010894", "010894:サキノサワ川"),
+    ("010895", "010895:寺ノ沢川"),
+    ("010896", "010896:ハッソウ沢川"),
+    ("010897", "010897:扇石の沢川"),
+    ("010898", "010898:滝の沢川"),
+    ("010899", "010899:タンコウノ沢川"),
+    ("010900", "010900:シヤミズの沢川"),
+    ("010901", "010901:コウミョウジの沢"),
+    ("010902", "010902:カイカンの沢川"),
+    ("010903", "010903:寅ノ沢川"),
+    ("010904", "010904:マツノ沢川"),
+    ("010905", "010905:クドウノ沢川"),
+    ("010906", "010906:ハラウタノ沢川"),
+    ("010907", "010907:ドウノ沢川"),
+    ("010908", "010908:上国寺の沢川"),
+    ("010909", "010909:宮ノ沢川"),
+    ("010910", "010910:椴川"),
+    ("010911", "010911:古櫃川"),
+    ("010912", "010912:五勝手川"),
+    ("010913", "010913:陣屋川"),
+    ("010914", "010914:モシリ川"),
+    ("010915", "010915:豊部内川"),
+    ("010916", "010916:泊川"),
+    ("010918", "010918:五厘沢川"),
+    ("010919", "010919:セモナイ川"),
+    ("010920", "010920:ナカザキ川"),
+    ("010921", "010921:タキノシタ川"),
+    ("010922", "010922:三ツ谷川"),
+    ("010923", "010923:琴平川"),
+    ("010924", "010924:シオミ川"),
+    ("010925", "010925:シビウタミツヤ川"),
+    ("010926", "010926:イナリノサワ川"),
+    ("010927", "010927:大岩川"),
+    ("010928", "010928:五小澗沢川"),
+    ("010929", "010929:大澗川"),
+    ("010930", "010930:薬師の川"),
+    ("010931", "010931:冷水川"),
+    ("010932", "010932:大沢川"),
+    ("010933", "010933:人住内川"),
+    ("010934", "010934:平田内川"),
+    ("010935", "010935:逆川"),
+    ("010936", "010936:勢至堂川"),
+    ("010937", "010937:朧川"),
+    ("010938", "010938:有明川"),
+    ("010939", "010939:鳴神川"),
+    ("010940", "010940:平井の川"),
+    ("010941", "010941:関内川"),
+    ("010942", "010942:五十嵐川"),
+    ("010943", "010943:高橋の沢川"),
+    ("010944", "010944:神の沢川"),
+    ("010945", "010945:公民川"),
+    ("010946", "010946:高畑の沢川"),
+    ("010947", "010947:越前川"),
+    ("010948", "010948:山川"),
+    ("010949", "010949:荷菱内川"),
+    ("010950", "010950:横澗川"),
+    ("010951", "010951:三上の沢川"),
+    ("010952", "010952:堤川"),
+    ("010953", "010953:井林川"),
+    ("010954", "010954:田中の沢川"),
+    ("010955", "010955:折戸川"),
+    ("010956", "010956:新折戸川"),
+    ("010957", "010957:勘八川"),
+    ("010958", "010958:岩澗川"),
+    ("010960", "010960:厚谷川"),
+    ("010961", "010961:マスハラ川"),
+    ("010962", "010962:福島の沢川"),
+    ("010963", "010963:弓山川"),
+    ("010965", "010965:三上川"),
+    ("010966", "010966:三新川"),
+    ("010967", "010967:竹内川"),
+    ("010968", "010968:西沢川"),
+    ("010969", "010969:瀬戸川"),
+    ("010970", "010970:シベット川"),
+    ("010971", "010971:湯の尻川"),
+    ("010972", "010972:土谷川"),
+    ("010973", "010973:ババ川"),
+    ("010974", "010974:建岩川"),
+    ("010975", "010975:守山川"),
+    ("010976", "010976:常盤川"),
+    ("010977", "010977:本陣川"),
+    ("010978", "010978:穴久遠川"),
+    ("010979", "010979:乗願寺川"),
+    ("010980", "010980:笠島の沢川"),
+    ("010982", "010982:大島川"),
+    ("010983", "010983:冷水川"),
+    ("010984", "010984:タツチノ川"),
+    ("010985", "010985:富岡川"),
+    ("010986", "010986:万谷川"),
+    ("010987", "010987:藤初川"),
+    ("010988", "010988:須藤の沢川"),
+    ("010989", "010989:相泊川"),
+    ("010991", "010991:日中戸の沢川"),
+    ("010992", "010992:泊川"),
+    ("010993", "010993:水垂の沢川"),
+    ("010994", "010994:漁港の沢川"),
+    ("010995", "010995:鵜泊川"),
+    ("010996", "010996:団地の沢川"),
+    ("010997", "010997:良瑠川"),
+    ("010998", "010998:良瑠石川"),
+    ("010999", "010999:古櫓多川"),
+    ("011000", "011000:鍋川"),
+    ("011001", "011001:水道の沢川"),
+    ("011002", "011002:太櫓中央川"),
+    ("011003", "011003:岬の沢川"),
+    ("011005", "011005:法華寺川"),
+    ("011006", "011006:立岩川"),
+    ("011007", "011007:中歌川"),
+    ("011008", "011008:元浦川"),
+    ("011009", "011009:稲荷川"),
+    ("011010", "011010:虻羅川"),
+    ("011011", "011011:窓岩川"),
+    ("011012", "011012:島歌川"),
+    ("011013", "011013:山崎川"),
+    ("011014", "011014:ミウラ川"),
+    ("011015", "011015:吹込川"),
+    ("011016", "011016:切梶川"),
+    ("011017", "011017:美谷川"),
+    ("011018", "011018:横滝川"),
+    ("011019", "011019:藻岩川"),
+    ("011020", "011020:本間の沢川"),
+    ("011021", "011021:須築川"),
+    ("011022", "011022:床前川"),
+    ("011023", "011023:オコツナイ川"),
+    ("011024", "011024:第二オコツナイ川"),
+    ("011025", "011025:山田の川"),
+    ("011026", "011026:長内川"),
+    ("011027", "011027:小田西川"),
+    ("011028", "011028:ポロ場川"),
+    ("011029", "011029:ポン狩場川"),
+    ("011030", "011030:木巻川"),
+    ("011031", "011031:政泊川"),
+    ("011032", "011032:オタダシ川"),
+    ("011033", "011033:原歌川"),
+    ("011034", "011034:フモンナイ川"),
+    ("011035", "011035:神社の川"),
+    ("011037", "011037:盤の沢川"),
+    ("011038", "011038:厳倉川"),
+    ("011039", "011039:幌内川"),
+    ("011042", "011042:ムジナ川"),
+    ("011043", "011043:サトウの川"),
+    ("011044", "011044:小林の川"),
+    ("011045", "011045:中浜の川"),
+    ("011046", "011046:組合の川"),
+    ("011047", "011047:厚瀬川"),
+    ("011048", "011048:エビナノ川"),
+    ("011049", "011049:福士の川"),
+    ("011050", "011050:コペチャナイ川"),
+    ("011051", "011051:中の川"),
+    ("011052", "011052:ワッカナイ川"),
+    ("011053", "011053:エビザワの川"),
+    ("011055", "011055:富浦川"),
+    ("011056", "011056:砂政泊川"),
+    ("011057", "011057:墓地川"),
+    ("011058", "011058:土谷の川"),
+    ("011059", "011059:岩沢の川"),
+    ("011060", "011060:滝の間川"),
+    ("011061", "011061:柴田の川"),
+    ("011062", "011062:南波の川"),
+    ("011063", "011063:大沢の川"),
+    ("011064", "011064:塚越の川"),
+    ("011065", "011065:山十の川"),
+    ("011066", "011066:大畑の川"),
+    ("011067", "011067:宮沢の川"),
+    ("011068", "011068:神社の川"),
+    ("011069", "011069:イチサンの川"),
+    ("011070", "011070:山本の川"),
+    ("011071", "011071:建岩の川"),
+    ("011072", "011072:高谷の川"),
+    ("011073", "011073:問谷の川"),
+    ("011074", "011074:柴山の川"),
+    ("011075", "011075:寺の川"),
+    ("011076", "011076:潮路の川"),
+    ("011077", "011077:寺の川"),
+    ("011078", "011078:吉田の川"),
+    ("011079", "011079:鐘台の川"),
+    ("011080", "011080:一キの川"),
+    ("011081", "011081:神社の川"),
+    ("011082", "011082:支所の川"),
+    ("011083", "011083:コタンポの川"),
+    ("011084", "011084:ワシリの川"),
+    ("011085", "011085:三〆の川"),
+    ("011086", "011086:山口の川"),
+    ("011087", "011087:中の川"),
+    ("011088", "011088:田中の川"),
+    ("011089", "011089:五郎兵の川"),
+    ("011090", "011090:秋山の川"),
+    ("011091", "011091:福士の川"),
+    ("011092", "011092:川地の川"),
+    ("011093", "011093:大丸の川"),
+    ("011094", "011094:大上の川"),
+    ("011095", "011095:幌別川"),
+    ("011096", "011096:大二の川"),
+    ("011097", "011097:野村の川"),
+    ("011098", "011098:ルウベツナイ川"),
+    ("011099", "011099:大黒ヶ沢の川"),
+    ("011100", "011100:本内の川"),
+    ("011101", "011101:金瀬の川"),
+    ("011102", "011102:田沢の川"),
+    ("011103", "011103:支所の川"),
+    ("011104", "011104:金三の川"),
+    ("011105", "011105:蛯子の川"),
+    ("011106", "011106:精進川"),
+    ("011107", "011107:雷電川"),
+    ("011108", "011108:イセバチ川"),
+    ("011109", "011109:湯内川"),
+    ("011110", "011110:親子別川"),
+    ("011111", "011111:カバソバナイ川"),
+    ("011112", "011112:アイノ川"),
+    ("011113", "011113:雷電川"),
+    ("011114", "011114:当別川"),
+    ("011115", "011115:幌別川"),
+    ("011116", "011116:ニチナイ川"),
+    ("011117", "011117:茶津川"),
+    ("011118", "011118:渋井川"),
+    ("011119", "011119:寺の川"),
+    ("011120", "011120:臼別川"),
+    ("011121", "011121:モヘル川"),
+    ("011122", "011122:糸泊川"),
+    ("011123", "011123:照岸川"),
+    ("011124", "011124:兜川"),
+    ("011125", "011125:滝の下川"),
+    ("011126", "011126:塩越川"),
+    ("011127", "011127:茂岩川"),
+    ("011128", "011128:祈石川"),
+    ("011129", "011129:弁財澗川"),
+    ("011130", "011130:尾根内川"),
+    ("011131", "011131:北田川"),
+    ("011132", "011132:魚谷川"),
+    ("011133", "011133:二の目川"),
+    ("011134", "011134:井戸屋川"),
+    ("011135", "011135:中の滝川"),
+    ("011136", "011136:滝の川"),
+    ("011137", "011137:大森川"),
+    ("011138", "011138:キナウシ川"),
+    ("011139", "011139:珊内川"),
+    ("011140", "011140:オネナイ川"),
+    ("011141", "011141:ノット川"),
+    ("011142", "011142:オブカルイシ川"),
+    ("011143", "011143:シシャモナイ川"),
+    ("011144", "011144:西河原川"),
+    ("011145", "011145:沼前川"),
+    ("011146", "011146:尾根内川"),
+    ("011147", "011147:柾泊川"),
+    ("011148", "011148:草内川"),
+    ("011149", "011149:余別川"),
+    ("011150", "011150:滝の川"),
+    ("011151", "011151:幌内府川"),
+    ("011152", "011152:ゴロタ川"),
+    ("011153", "011153:日司川"),
+    ("011154", "011154:入舸川"),
+    ("011155", "011155:茶津川"),
+    ("011156", "011156:丸山川"),
+    ("011157", "011157:チョペタ川"),
+    ("011158", "011158:歌棄川"),
+    ("011159", "011159:ツルノップ川"),
+    ("011160", "011160:番の沢川"),
+    ("011161", "011161:島泊川"),
+    ("011162", "011162:出足平川"),
+    ("011163", "011163:富沢川"),
+    ("011164", "011164:桃内川"),
+    ("011165", "011165:稲穂川"),
+    ("011166", "011166:祝津川"),
+    ("011167", "011167:牛川"),
+    ("011168", "011168:手宮川"),
+    ("011169", "011169:手宮中川"),
+    ("011170", "011170:色内川"),
+    ("011172", "011172:入舟川"),
+    ("011173", "011173:ワカタケナイ川"),
+    ("011174", "011174:柾里川"),
+    ("011175", "011175:ブドウ川"),
+    ("011176", "011176:カムイ川"),
+    ("011177", "011177:張碓川"),
+    ("011178", "011178:張碓中川"),
+    ("011179", "011179:礼文塚川"),
+    ("011180", "011180:銭函川"),
+    ("011181", "011181:ポンナイ川"),
+    ("011182", "011182:嶺泊川"),
+    ("011183", "011183:古潭川"),
+    ("011184", "011184:菊地の沢川"),
+    ("011185", "011185:八幡沢川"),
+    ("011186", "011186:幌内川"),
+    ("011187", "011187:ヤソスケ川"),
+    ("011188", "011188:北部落沢川"),
+    ("011189", "011189:滝野沢川"),
+    ("011190", "011190:大沢川"),
+    ("011191", "011191:ルーラン川"),
+    ("011192", "011192:太島内川"),
+    ("011193", "011193:送毛川"),
+    ("011194", "011194:茂生川"),
+    ("011195", "011195:本沢川"),
+    ("011196", "011196:新川"),
+    ("011197", "011197:稲荷川"),
+    ("011198", "011198:床丹川"),
+    ("011200", "011200:一の滝川"),
+    ("011201", "011201:二の滝川"),
+    ("011202", "011202:電話下川"),
+    ("011203", "011203:イカゾ川"),
+    ("011204", "011204:オフユ川"),
+    ("011205", "011205:ケマフレ川"),
+    ("011206", "011206:アカイワ川"),
+    ("011207", "011207:マルヒラ川"),
+    ("011208", "011208:クズレ川"),
+    ("011209", "011209:日方泊川"),
+    ("011210", "011210:歩古丹川"),
+    ("011211", "011211:マツカノ川"),
+    ("011212", "011212:大別苅川"),
+    ("011213", "011213:エンルコマナイ川"),
+    ("011214", "011214:ポンナイ川"),
+    ("011215", "011215:ニナイベツ川"),
+    ("011216", "011216:オタルマナイ川"),
+    ("011217", "011217:コチャイナイ川"),
+    ("011218", "011218:稲葉川"),
+    ("011219", "011219:朱文別川"),
+    ("011220", "011220:タントシナイ川"),
+    ("011221", "011221:アツブシラリ川"),
+    ("011222", "011222:セタベツ川"),
+    ("011223", "011223:モタセツ川"),
+    ("011224", "011224:オタルマタセツ川"),
+    ("011225", "011225:アツカルシナイ川"),
+    ("011226", "011226:見晴川"),
+    ("011227", "011227:小室ノ沢川"),
+    ("011228", "011228:ツネカムリ川"),
+    ("011229", "011229:臼谷ノ沢川"),
+    ("011230", "011230:鬼泊川"),
+    ("011231", "011231:大椴子川"),
+    ("011232", "011232:アイノ沢川"),
+    ("011233", "011233:小椴子川"),
+    ("011234", "011234:トチハラ川"),
+    ("011235", "011235:木ノ実川"),
+    ("011236", "011236:湯ノ沢川"),
+    ("011237", "011237:蛇ノ目沢川"),
+    ("011238", "011238:秀浦川"),
+    ("011239", "011239:番谷の沢川"),
+    ("011240", "011240:小種子川"),
+    ("011241", "011241:千松川"),
+    ("011242", "011242:山の大沢川"),
+    ("011243", "011243:茶俊内川"),
+    ("011244", "011244:サカエの沢川"),
+    ("011245", "011245:番谷の沢川"),
+    ("011246", "011246:天谷の沢川"),
+    ("011247", "011247:チシャ川"),
+    ("011248", "011248:ウエンビラ川"),
+    ("011249", "011249:ポロナイ川"),
+    ("011250", "011250:アチャナイ川"),
+    ("011251", "011251:オシルスナイ川"),
+    ("011252", "011252:ヤオシルスナイ川"),
+    ("011253", "011253:オコツナイ川"),
+    ("011254", "011254:カミノタキ川"),
+    ("011255", "011255:出雲川"),
+    ("011256", "011256:ヌップコマナイ川"),
+    ("011257", "011257:ニカリウシナイ川"),
+    ("011258", "011258:ポンニカリウシナイ川"),
+    ("011259", "011259:マスナイ川"),
+    ("011260", "011260:山根川"),
+    ("011261", "011261:ヤキナツイ川"),
+    ("011262", "011262:モセタキナイ川"),
+    ("011263", "011263:栄南川"),
+    ("011264", "011264:井上の沢川"),
+    ("011265", "011265:アカギの沢川"),
+    ("011266", "011266:大曲の沢川"),
+    ("011267", "011267:金駒内川"),
+    ("011268", "011268:第十の沢川"),
+    ("011269", "011269:大樹の沢川"),
+    ("011270", "011270:オヤルフツナイ川"),
+    ("011271", "011271:登駒内川"),
+    ("011272", "011272:遠藤ノ沢川"),
+    ("011273", "011273:モオタコシベツ川"),
+    ("011274", "011274:トマタウシュナイ川"),
+    ("011275", "011275:クマウシュナイ川"),
+    ("011276", "011276:マサロベツ川"),
+    ("011277", "011277:マサリ排水川"),
+    ("011278", "011278:啓明排水川"),
+    ("011279", "011279:パロマウツナイ川"),
+    ("011280", "011280:キピタナイ川"),
+    ("011282", "011282:トコツナイ川"),
+    ("011283", "011283:ヤナメヤチ川"),
+    ("011284", "011284:藻内川"),
+    ("011285", "011285:ホヤ石川"),
+    ("011286", "011286:神威岩川"),
+    ("011287", "011287:清次郎歌川"),
+    ("011288", "011288:神威脇川"),
+    ("011289", "011289:幌内川"),
+    ("011290", "011290:大岩生川"),
+    ("011291", "011291:小岩生川"),
+    ("011292", "011292:ノナ前川"),
+    ("011293", "011293:大谷川"),
+    ("011294", "011294:宮津沢川"),
+    ("011295", "011295:宮津川"),
+    ("011296", "011296:ヤマセ泊川"),
+    ("011297", "011297:桜屋敷川"),
+    ("011300", "011300:仏沢２号川"),
+    ("011301", "011301:仏沢１号川"),
+    ("011302", "011302:塩釜川"),
+    ("011303", "011303:釣懸川"),
+    ("011304", "011304:祖父川"),
+    ("011307", "011307:小島川"),
+    ("011308", "011308:坪谷川"),
+    ("011309", "011309:恩顧歌川"),
+    ("011310", "011310:小倉川"),
+    ("011311", "011311:力沢川"),
+    ("011312", "011312:兄力沢川"),
+    ("011313", "011313:深歌川"),
+    ("011314", "011314:ラント川"),
+    ("011315", "011315:赤川"),
+    ("011316", "011316:新海沢川"),
+    ("011317", "011317:カネシメ沢川"),
+    ("011318", "011318:トビウシナイ川"),
+    ("011319", "011319:西オビヤタンナイ沢川"),
+    ("011320", "011320:オビヤタンナイ川"),
+    ("011321", "011321:沼の沢川"),
+    ("011322", "011322:ポリマナイ川"),
+    ("011323", "011323:三の沢川"),
+    ("011324", "011324:源内沢川"),
+    ("011325", "011325:リヤウシナイ川"),
+    ("011326", "011326:モトリヤウシナイ川"),
+    ("011327", "011327:二の沢川"),
+    ("011328", "011328:一の沢川"),
+    ("011329", "011329:オモベツ川"),
+    ("011330", "011330:ノドツトマリ川"),
+    ("011331", "011331:東ノドツトマリ川"),
+    ("011332", "011332:豊漁沢川"),
+    ("011333", "011333:オチウシナイ川"),
+    ("011334", "011334:キタニチンドマリ川"),
+    ("011335", "011335:ニチンドマリ川"),
+    ("011336", "011336:朝日川"),
+    ("011337", "011337:湧水川"),
+    ("011338", "011338:アフトロマナイ川"),
+    ("011339", "011339:石崎川"),
+    ("011340", "011340:オサツルナイ川"),
+    ("011341", "011341:豊仙沢川"),
+    ("011342", "011342:ヤムナイ沢川"),
+    ("011343", "011343:堺沢川"),
+    ("011344", "011344:滝の沢川"),
+    ("011345", "011345:二又沢川"),
+    ("011346", "011346:メスウショロ川"),
+    ("011347", "011347:ナカヤマ沢川"),
+    ("011348", "011348:元村川"),
+    ("011349", "011349:セパウシ川"),
+    ("011350", "011350:神磯川"),
+    ("011351", "011351:大空川"),
+    ("011352", "011352:久連川"),
+    ("011353", "011353:蘭泊川"),
+    ("011354", "011354:ポロニショ川"),
+    ("011355", "011355:神居川"),
+    ("011356", "011356:ビヤコロ川"),
+    ("011357", "011357:新湊川"),
+    ("011358", "011358:重兵衛川"),
+    ("011359", "011359:大備川"),
+    ("011360", "011360:内路川"),
+    ("011361", "011361:山本川"),
+    ("011362", "011362:トコタン川"),
+    ("011363", "011363:キトウス川"),
+    ("011364", "011364:オバシトロマナイ川"),
+    ("011365", "011365:香深井川"),
+    ("011366", "011366:西沢川"),
+    ("011367", "011367:手然川"),
+    ("011368", "011368:石動川"),
+    ("011369", "011369:津軽川"),
+    ("011370", "011370:竹岡川"),
+    ("011371", "011371:会所川"),
+    ("011372", "011372:トンナイ川"),
+    ("011373", "011373:中村川"),
+    ("011374", "011374:志田川"),
+    ("011375", "011375:知床川"),
+    ("011376", "011376:柳谷川"),
+    ("011377", "011377:元地川"),
+    ("011378", "011378:礼文川"),
+    ("011379", "011379:宇遠内川"),
+    ("011380", "011380:沙留川"),
+    ("011381", "011381:尾幌川"),
+    ("011382", "011382:春採川"),
+    ("011383", "011383:長節川"),
+    ("011384", "011384:フシコベツ川"),
+    ("011385", "011385:知利別川"),
+    ("011386", "011386:重内川"),
+    ("011387", "011387:田沢川"),
+    ("011388", "011388:平田内川"),
+    ("011389", "011389:臼別川"),
+    ("011390", "011390:小川"),
+    ("011391", "011391:上古丹川"),
+    ("011392", "011392:砥歌川"),
+    ("011393", "011393:新甫川"),
+    ("011394", "011394:ホンベツ川"),
+    ("011395", "011395:床丹川"),
+    ("011396", "011396:歌島川"),
+    ("011397", "011397:白水川"),
+    ("011398", "011398:球浦川"),
+    ("011399", "011399:烏頭川"),
+    ("011400", "011400:赤石川"),
+    ("011401", "011401:小田島川"),
+    ("011402", "011402:シラリカップ川"),
+    ("011403", "011403:苫別川"),
+    ("011404", "011404:キリシタナイ川"),
+    ("011405", "011405:オバフ川"),
+    ("011406", "011406:サトオカシベツ川"),
+    ("011407", "011407:第一端の川"),
+    ("011408", "011408:最内川"),
+    ("011409", "011409:於古発川"),
+    ("011410", "011410:千代志別川"),
+    ("011411", "011411:サラキタナイ川"),
+    ("020001", "020001:津梅川"),
+    ("020002", "020002:小峰沢川"),
+    ("020003", "020003:合葉川"),
+    ("020004", "020004:松神川"),
+    ("020005", "020005:濁川"),
+    ("020006", "020006:笹内川"),
+    ("020007", "020007:泥川"),
+    ("020008", "020008:磯崎川"),
+    ("020009", "020009:吾妻川"),
+    ("020010", "020010:追良瀬川"),
+    ("020011", "020011:小童子川"),
+    ("020012", "020012:大童子川"),
+    ("020013", "020013:赤石川"),
+    ("020014", "020014:中村川"),
+    ("020015", "020015:鳴沢川"),
+    ("020016", "020016:磯松川"),
+    ("020017", "020017:小泊川"),
+    ("020018", "020018:元宇鉄川"),
+    ("020019", "020019:算用師川"),
+    ("020020", "020020:増川川"),
+    ("020021", "020021:長川"),
+    ("020022", "020022:今別川"),
+    ("020023", "020023:湯ノ沢川"),
+    ("020024", "020024:蟹田川"),
+    ("020025", "020025:広瀬川"),
+    ("020026", "020026:瀬辺地川"),
+    ("020027", "020027:阿弥陀川"),
+    ("020028", "020028:四戸橋川"),
+    ("020029", "020029:六枚橋川"),
+    ("020030", "020030:内真部川"),
+    ("020031", "020031:奥内川"),
+    ("020032", "020032:瀬戸子川"),
+    ("020033", "020033:天田内川"),
+    ("020034", "020034:新城川"),
+    ("020035", "020035:沖館川"),
+    ("020036", "020036:堤川"),
+    ("020037", "020037:赤川"),
+    ("020038", "020038:沼川"),
+    ("020039", "020039:野内川"),
+    ("020040", "020040:根井川"),
+    ("020041", "020041:浅虫川"),
+    ("020042", "020042:長沢川"),
+    ("020043", "020043:小湊川"),
+    ("020044", "020044:清水川"),
+    ("020045", "020045:堀差川"),
+    ("020046", "020046:野辺地川"),
+    ("020047", "020047:三保川"),
+    ("020048", "020048:桧木川"),
+    ("020049", "020049:鶏沢川"),
+    ("020050", "020050:今泉川"),
+    ("020051", "020051:田名部川"),
+    ("020052", "020052:大荒川"),
+    ("020053", "020053:戸沢川"),
+    ("020054", "020054:川内川"),
+    ("020055", "020055:桧川"),
+    ("020056", "020056:宿野部川"),
+    ("020057", "020057:男川"),
+    ("020058", "020058:脇野沢川"),
+    ("020059", "020059:福浦川"),
+    ("020060", "020060:大佐井川"),
+    ("020061", "020061:古佐井川"),
+    ("020062", "020062:林木川"),
+    ("020063", "020063:奥戸川"),
+    ("020064", "020064:目滝川"),
+    ("020065", "020065:易国間川"),
+    ("020066", "020066:大畑川"),
+    ("020067", "020067:正津川"),
+    ("020068", "020068:出戸川"),
+    ("020069", "020069:野牛川"),
+    ("020070", "020070:老部川"),
+    ("020071", "020071:明神川"),
+    ("020072", "020072:戸鎖川"),
+    ("020073", "020073:二の川"),
+    ("020074", "020074:一の川"),
+    ("020075", "020075:奥入瀬川"),
+    ("020076", "020076:五戸川"),
+    ("020077", "020077:新井田川"),
+    ("020078", "020078:田浦川"),
+    ("020079", "020079:鐇泊川"),
+    ("020080", "020080:元宇鉄川"),
+    ("020081", "020081:間沢川"),
+    ("020082", "020082:板木沢川"),
+    ("020083", "020083:蓬田川"),
+    ("020084", "020084:飛鳥川"),
+    ("020085", "020085:貴船川"),
+    ("020086", "020086:土場川"),
+    ("020087", "020087:馬門川"),
+    ("020088", "020088:近沢川"),
+    ("020089", "020089:千草橋川"),
+    ("020090", "020090:有戸川"),
+    ("020091", "020091:戸沢川"),
+    ("020092", "020092:高野川"),
+    ("020093", "020093:大間川"),
+    ("020094", "020094:小老部川"),
+    ("020095", "020095:黒崎川"),
+    ("020096", "020096:下濁川"),
+    ("020097", "020097:根滝川"),
+    ("020098", "020098:脇野沢川"),
+    ("020099", "020099:風呂の沢"),
+    ("020100", "020100:玉坂沢"),
+    ("020101", "020101:沢辺沢"),
+    ("020102", "020102:中沢川"),
+    ("020103", "020103:広戸沢"),
+    ("020104", "020104:母沢"),
+    ("020105", "020105:小浜舘川"),
+    ("020106", "020106:貝良木沢"),
+    ("020107", "020107:大船沢"),
+    ("020108", "020108:清滝川"),
+    ("020109", "020109:小田沢"),
+    ("020110", "020110:深沢"),
+    ("020111", "020111:栃沢川"),
+    ("020112", "020112:脇元川"),
+    ("020113", "020113:折戸川"),
+    ("020114", "020114:立松沢"),
+    ("020115", "020115:大山長根沢"),
+    ("020116", "020116:上小泊沢"),
+    ("020117", "020117:桧木沢"),
+    ("020118", "020118:梹椰沢"),
+    ("020119", "020119:鳴神沢"),
+    ("020120", "020120:元宇鉄川"),
+    ("020121", "020121:釜野沢"),
+    ("020122", "020122:上藤島川"),
+    ("020123", "020123:三厩沢"),
+    ("020124", "020124:藤島川"),
+    ("020125", "020125:三厩川"),
+    ("020126", "020126:黒崎川"),
+    ("020127", "020127:宇田川"),
+    ("020128", "020128:袰月沢"),
+    ("020129", "020129:砂ヶ森川"),
+    ("020130", "020130:奥平部川"),
+    ("020131", "020131:五所塚沢"),
+    ("020132", "020132:伝藤沢"),
+    ("020133", "020133:宇田沢"),
+    ("020134", "020134:項々川"),
+    ("020135", "020135:石崎沢川"),
+    ("020136", "020136:田の沢川"),
+    ("020137", "020137:寺の沢川"),
+    ("020138", "020138:十和田様川"),
+    ("020139", "020139:玉川"),
+    ("020140", "020140:大野田川"),
+    ("020141", "020141:尻高川"),
+    ("020142", "020142:小湯の沢"),
+    ("020143", "020143:目倉沢"),
+    ("020144", "020144:平家沢"),
+    ("020145", "020145:口広川"),
+    ("020146", "020146:上塩越川"),
+    ("020147", "020147:塩越川"),
+    ("020148", "020148:下塩越川"),
+    ("020149", "020149:大沢"),
+    ("020150", "020150:板木沢川"),
+    ("020151", "020151:長科川"),
+    ("020152", "020152:四戸橋川"),
+    ("020153", "020153:前田の沢川"),
+    ("020154", "020154:飛鳥沢川"),
+    ("020155", "020155:西田沢川"),
+    ("020156", "020156:油川川"),
+    ("020157", "020157:沼川"),
+    ("020158", "020158:木舟川"),
+    ("020159", "020159:茂浦川"),
+    ("020160", "020160:稲田沢"),
+    ("020161", "020161:白砂沢"),
+    ("020162", "020162:鷲の沢"),
+    ("020163", "020163:間木沢"),
+    ("020164", "020164:口広川"),
+    ("020165", "020165:吹越川"),
+    ("020166", "020166:武の川"),
+    ("020167", "020167:牛の沢川"),
+    ("020168", "020168:田の沢川"),
+    ("020169", "020169:奥内川"),
+    ("020170", "020170:今泉川"),
+    ("020171", "020171:三本松川"),
+    ("020172", "020172:小荒川"),
+    ("020173", "020173:小松野川"),
+    ("020174", "020174:浜町沢"),
+    ("020175", "020175:下浜町沢"),
+    ("020176", "020176:上町沢"),
+    ("020177", "020177:上湊川"),
+    ("020178", "020178:湊川"),
+    ("020179", "020179:上宇田沢"),
+    ("020180", "020180:宇田沢"),
+    ("020181", "020181:桜木町沢"),
+    ("020182", "020182:大近川"),
+    ("020183", "020183:宇曽利川"),
+    ("020184", "020184:永下川"),
+    ("020185", "020185:近沢川"),
+    ("020186", "020186:大川目川"),
+    ("020187", "020187:戸沢川"),
+    ("020188", "020188:熊野川"),
+    ("020189", "020189:葛沢川"),
+    ("020190", "020190:上々小沢川"),
+    ("020191", "020191:上小沢川"),
+    ("020192", "020192:小沢川"),
+    ("020193", "020193:瀬野川"),
+    ("020194", "020194:寄浪沢"),
+    ("020195", "020195:芋田沢"),
+    ("020196", "020196:九ソウ泊沢"),
+    ("020197", "020197:牛滝川"),
+    ("020198", "020198:長後川"),
+    ("020199", "020199:黒岩川"),
+    ("020200", "020200:原田川"),
+    ("020201", "020201:小奥戸川"),
+    ("020202", "020202:下沢の黒沢"),
+    ("020203", "020203:新湯川"),
+    ("020204", "020204:大赤川"),
+    ("020205", "020205:小赤川"),
+    ("020206", "020206:三右衛門沢"),
+    ("020207", "020207:下釣屋浜沢"),
+    ("020208", "020208:下狄川"),
+    ("020209", "020209:茶水川"),
+    ("020210", "020210:袰部川"),
+    ("020211", "020211:尻労川"),
+    ("020212", "020212:白糖川"),
+    ("020213", "020213:堤沢"),
+    ("020214", "020214:大明神川"),
+    ("020215", "020215:押付沢"),
+    ("020216", "020216:月山沢"),
+    ("020217", "020217:明神沢"),
+    ("020218", "020218:石川沢"),
+    ("020219", "020219:二又川"),
+    ("020220", "020220:六の川"),
+    ("020221", "020221:五の川"),
+    ("020222", "020222:三沢川"),
+    ("020223", "020223:三の川"),
+    ("020224", "020224:大渡川"),
+    ("020225", "020225:榊浜川"),
+    ("020226", "020226:道仏川"),
+    ("020227", "020227:二十一川"),
+    ("020228", "020228:明神川"),
+    ("030001", "030001:八木沢川"),
+    ("030002", "030002:川尻川"),
+    ("030003", "030003:有家川"),
+    ("030004", "030004:高家川"),
+    ("030005", "030005:久慈川"),
+    ("030006", "030006:玉の脇川"),
+    ("030007", "030007:宇部川"),
+    ("030008", "030008:米田川"),
+    ("030009", "030009:安家川"),
+    ("030010", "030010:普代川"),
+    ("030011", "030011:明戸川"),
+    ("030012", "030012:平井賀川"),
+    ("030013", "030013:松前川"),
+    ("030014", "030014:小本川"),
+    ("030015", "030015:摂待川"),
+    ("030016", "030016:田代川"),
+    ("030017", "030017:閉伊川"),
+    ("030018", "030018:八木沢川"),
+    ("030019", "030019:津軽石川"),
+    ("030020", "030020:重茂川"),
+    ("030021", "030021:大沢川"),
+    ("030022", "030022:関口川"),
+    ("030023", "030023:織笠川"),
+    ("030024", "030024:大鎚川"),
+    ("030025", "030025:小鎚川"),
+    ("030026", "030026:鵜住居川"),
+    ("030027", "030027:水海川"),
+    ("030028", "030028:甲子川"),
+    ("030029", "030029:片岸川"),
+    ("030030", "030030:熊野川"),
+    ("030031", "030031:吉浜川"),
+    ("030032", "030032:浦浜川"),
+    ("030033", "030033:泊川"),
+    ("030034", "030034:甫嶺川"),
+    ("030035", "030035:稜里川"),
+    ("030036", "030036:合足川"),
+    ("030037", "030037:後の入川"),
+    ("030038", "030038:盛川"),
+    ("030039", "030039:須崎川"),
+    ("030040", "030040:船河原川"),
+    ("030041", "030041:浜田川"),
+    ("030042", "030042:気仙川"),
+    ("030043", "030043:長部川"),
+    ("030044", "030044:玉川"),
+    ("030045", "030045:沢川"),
+    ("030046", "030046:力持川"),
+    ("030047", "030047:大沢川"),
+    ("030048", "030048:黒崎川"),
+    ("030049", "030049:ヨシロイ川"),
+    ("030050", "030050:カワドケシリ川"),
+    ("030051", "030051:大峰川"),
+    ("030052", "030052:机川"),
+    ("030053", "030053:ハイペ川"),
+    ("030054", "030054:コイコロベ川"),
+    ("030055", "030055:島ノ沢川"),
+    ("030056", "030056:白池川"),
+    ("030057", "030057:槙木沢川"),
+    ("030058", "030058:小成川"),
+    ("030059", "030059:長内川"),
+    ("030060", "030060:女遊戸川"),
+    ("030061", "030061:下在家川"),
+    ("030062", "030062:大沢川"),
+    ("030063", "030063:清水川"),
+    ("030064", "030064:梅翁寺川"),
+    ("030065", "030065:八木沢川"),
+    ("030066", "030066:神倉川"),
+    ("030067", "030067:寺川"),
+    ("030068", "030068:西川"),
+    ("030069", "030069:川村川"),
+    ("030070", "030070:細浦川"),
+    ("030071", "030071:中瀬川"),
+    ("030072", "030072:ワラビ川"),
+    ("030073", "030073:秀禅川"),
+    ("030074", "030074:女川"),
+    ("030075", "030075:早川川"),
+    ("030076", "030076:岩ヶ沢川"),
+    ("030077", "030077:山の内川"),
+    ("030078", "030078:浪板川"),
+    ("030079", "030079:沢山沢川"),
+    ("030080", "030080:沼川川"),
+    ("030081", "030081:両石川"),
+    ("030082", "030082:上平田川"),
+    ("030083", "030083:下平田川"),
+    ("030084", "030084:白浜川"),
+    ("030085", "030085:佐須川"),
+    ("030086", "030086:花呂部川"),
+    ("030087", "030087:太曽根川"),
+    ("030088", "030088:二本梨川"),
+    ("030089", "030089:熊野川"),
+    ("030090", "030090:大石川"),
+    ("030091", "030091:大立川"),
+    ("030092", "030092:沼田川"),
+    ("030093", "030093:八木川"),
+    ("030094", "030094:音部川"),
+    ("030095", "030095:矢作川"),
+    ("040001", "040001:青野沢川"),
+    ("040002", "040002:只越川"),
+    ("040003", "040003:鹿折川"),
+    ("040004", "040004:大川"),
+    ("040005", "040005:面瀬川"),
+    ("040006", "040006:沖ノ田川"),
+    ("040007", "040007:津谷川"),
+    ("040008", "040008:港川"),
+    ("040009", "040009:稲淵川"),
+    ("040010", "040010:伊里前川"),
+    ("040011", "040011:桜川"),
+    ("040012", "040012:新井田川"),
+    ("040013", "040013:八幡川"),
+    ("040014", "040014:水尻川"),
+    ("040015", "040015:折立川"),
+    ("040016", "040016:水戸辺川"),
+    ("040017", "040017:長清水川"),
+    ("040018", "040018:相川沢川"),
+    ("040019", "040019:大原川"),
+    ("040020", "040020:女川"),
+    ("040021", "040021:後川"),
+    ("040022", "040022:湊川"),
+    ("040023", "040023:淀川"),
+    ("040024", "040024:大沢川"),
+    ("040025", "040025:定川"),
+    ("040026", "040026:高城川"),
+    ("040027", "040027:砂押川"),
+    ("040028", "040028:七北田川"),
+    ("040029", "040029:坂元川"),
+    ("040030", "040030:東舞根川"),
+    ("040031", "040031:田の浦川"),
+    ("040032", "040032:寄木川"),
+    ("040033", "040033:後川"),
+    ("040034", "040034:津持川"),
+    ("040035", "040035:千鳥川"),
+    ("040036", "040036:大沢川"),
+    ("040037", "040037:蛇王川"),
+    ("040038", "040038:水浜川"),
+    ("040039", "040039:新町川"),
+    ("040040", "040040:鐙川"),
+    ("040041", "040041:落堀川"),
+    ("040042", "040042:鷲足川"),
+    ("040043", "040043:高瀬川"),
+    ("050001", "050001:清水川"),
+    ("050002", "050002:奈曽川"),
+    ("050003", "050003:象潟川"),
+    ("050004", "050004:赤石川"),
+    ("050005", "050005:白雪川"),
+    ("050006", "050006:大沢川"),
+    ("050007", "050007:西目川"),
+    ("050008", "050008:衣川"),
+    ("050009", "050009:二古川"),
+    ("050010", "050010:君が野川"),
+    ("050011", "050011:勝手川"),
+    ("050012", "050012:下浜鮎川"),
+    ("050013", "050013:馬場目川"),
+    ("050014", "050014:比詰川"),
+    ("050015", "050015:加茂川"),
+    ("050016", "050016:滝川"),
+    ("050017", "050017:鮪川川"),
+    ("050018", "050018:竹生川"),
+    ("050019", "050019:塙川"),
+    ("050020", "050020:水沢川"),
+    ("050021", "050021:真瀬川"),
+    ("050022", "050022:清水川"),
+    ("050023", "050023:川袋川"),
+    ("050024", "050024:中の沢川"),
+    ("050025", "050025:市の沢川"),
+    ("050026", "050026:琴浦川"),
+    ("050027", "050027:阿部堂川"),
+    ("050028", "050028:沼尻川"),
+    ("050029", "050029:雪川川"),
+    ("050030", "050030:保量川"),
+    ("050031", "050031:増川"),
+    ("050032", "050032:小増川"),
+    ("050033", "050033:野村川"),
+    ("050034", "050034:賀茂川"),
+    ("050035", "050035:相川"),
+    ("050036", "050036:大増川"),
+    ("050037", "050037:小増川"),
+    ("050038", "050038:安田川"),
+    ("060001", "060001:鼠ヶ関川"),
+    ("060002", "060002:早田川"),
+    ("060003", "060003:出口沢川"),
+    ("060004", "060004:巌沢川"),
+    ("060005", "060005:庄内小国川"),
+    ("060006", "060006:温海川"),
+    ("060007", "060007:五十川"),
+    ("060008", "060008:天竜川"),
+    ("060009", "060009:三瀬川"),
+    ("060010", "060010:楯下川"),
+    ("060011", "060011:長者川"),
+    ("060012", "060012:村上川"),
+    ("060013", "060013:油戸川"),
+    ("060014", "060014:岡町川"),
+    ("060015", "060015:新井田川"),
+    ("060016", "060016:日向川"),
+    ("060017", "060017:月光川"),
+    ("070001", "070001:三滝川"),
+    ("070002", "070002:砂子田川"),
+    ("070003", "070003:濁川"),
+    ("070004", "070004:地蔵川"),
+    ("070005", "070005:小泉川"),
+    ("070006", "070006:宇多川"),
+    ("070007", "070007:梅川"),
+    ("070008", "070008:日下石川"),
+    ("070009", "070009:真野川"),
+    ("070010", "070010:新田川"),
+    ("070011", "070011:太田川"),
+    ("070012", "070012:小高川"),
+    ("070013", "070013:宮田川"),
+    ("070014", "070014:請戸川"),
+    ("070015", "070015:前田川"),
+    ("070016", "070016:夫沢川"),
+    ("070017", "070017:小入野川"),
+    ("070018", "070018:熊川"),
+    ("070019", "070019:富岡川"),
+    ("070020", "070020:紅葉川"),
+    ("070021", "070021:井出川"),
+    ("070022", "070022:木戸川"),
+    ("070023", "070023:北迫川"),
+    ("070024", "070024:浅見川"),
+    ("070025", "070025:折木川"),
+    ("070026", "070026:末続川"),
+    ("070027", "070027:大久川"),
+    ("070028", "070028:夏井川"),
+    ("070029", "070029:滑津川"),
+    ("070030", "070030:弁天川"),
+    ("070031", "070031:諏訪川"),
+    ("070032", "070032:神白川"),
+    ("070033", "070033:藤原川"),
+    ("070034", "070034:渚川"),
+    ("070035", "070035:鮫川"),
+    ("070036", "070036:蛭田川"),
+    ("070037", "070037:金沢川"),
+    ("070038", "070038:北原川"),
+    ("070039", "070039:塩民川"),
+    ("070040", "070040:境川"),
+    ("070041", "070041:天神前川"),
+    ("080001", "080001:里根川"),
+    ("080002", "080002:江戸上川"),
+    ("080003", "080003:大北川"),
+    ("080004", "080004:塩田川"),
+    ("080005", "080005:関根川"),
+    ("080006", "080006:花貫川"),
+    ("080007", "080007:小石川"),
+    ("080008", "080008:十王川"),
+    ("080009", "080009:東連津川"),
+    ("080010", "080010:宮田川"),
+    ("080011", "080011:鮎川"),
+    ("080012", "080012:桜川"),
+    ("080013", "080013:金沢川"),
+    ("080014", "080014:大沼川"),
+    ("080015", "080015:瀬上川"),
+    ("080016", "080016:新川"),
+    ("080018", "080018:沢尻川"),
+    ("080019", "080019:折笠川"),
+    ("080020", "080020:田尻川"),
+    ("080021", "080021:北川"),
+    ("080022", "080022:大川"),
+    ("120001", "120001:小畑川"),
+    ("120002", "120002:新川"),
+    ("120003", "120003:栗山川"),
+    ("120004", "120004:木戸川"),
+    ("120005", "120005:作田川"),
+    ("120006", "120006:真亀川"),
+    ("120007", "120007:堀川"),
+    ("120008", "120008:南白亀川"),
+    ("120009", "120009:一宮川"),
+    ("120010", "120010:夷隅川"),
+    ("120011", "120011:塩田川"),
+    ("120012", "120012:清水川"),
+    ("120013", "120013:墨名川"),
+    ("120014", "120014:開戸川"),
+    ("120015", "120015:大風沢川"),
+    ("120016", "120016:神明川"),
+    ("120017", "120017:二タ間川"),
+    ("120018", "120018:待崎川"),
+    ("120019", "120019:加茂川"),
+    ("120020", "120020:曽呂川"),
+    ("120021", "120021:州貝川"),
+    ("120022", "120022:長者川"),
+    ("120023", "120023:三原川"),
+    ("120024", "120024:温石川"),
+    ("120025", "120025:丸山川"),
+    ("120026", "120026:瀬戸川"),
+    ("120027", "120027:川尻川"),
+    ("120028", "120028:長尾川"),
+    ("120029", "120029:汐入川"),
+    ("120030", "120030:平久里川"),
+    ("120031", "120031:岡本川"),
+    ("120032", "120032:岩井川"),
+    ("120033", "120033:佐久間川"),
+    ("120034", "120034:保田川"),
+    ("120035", "120035:元名川"),
+    ("120036", "120036:金谷川"),
+    ("120037", "120037:白狐川"),
+    ("120038", "120038:湊川"),
+    ("120039", "120039:染川"),
+    ("120040", "120040:小久保川"),
+    ("120041", "120041:岩瀬川"),
+    ("120042", "120042:小糸川"),
+    ("120043", "120043:畑沢川"),
+    ("120044", "120044:烏田川"),
+    ("120045", "120045:矢那川"),
+    ("120046", "120046:小櫃川"),
+    ("120047", "120047:浮戸川"),
+    ("120048", "120048:椎津川"),
+    ("120049", "120049:前川"),
+    ("120050", "120050:養老川"),
+    ("120051", "120051:村田川"),
+    ("120052", "120052:浜野川"),
+    ("120053", "120053:生実川"),
+    ("120054", "120054:都川"),
+    ("120055", "120055:浜田川"),
+    ("120056", "120056:菊田川"),
+    ("120057", "120057:谷津川"),
+    ("120058", "120058:高瀬川"),
+    ("120059", "120059:海老川"),
+    ("120060", "120060:猫実川"),
+    ("120061", "120061:南川尻川"),
+    ("120062", "120062:ビチャ川"),
+    ("120063", "120063:境川"),
+    ("120064", "120064:岩井川"),
+    ("120065", "120065:夜長川"),
+    ("120066", "120066:蟹田川"),
+    ("120067", "120067:真浦川"),
+    ("120068", "120068:大石川"),
+    ("120069", "120069:蟹田川"),
+    ("120070", "120070:どんどん川"),
+    ("120071", "120071:汐入川"),
+    ("120072", "120072:大六川"),
+    ("120073", "120073:小磯川"),
+    ("120074", "120074:世入川"),
+    ("120075", "120075:笹毛川"),
+    ("120076", "120076:夕田川"),
+    ("120077", "120077:宮脇川"),
+    ("120078", "120078:本名輪川"),
+    ("120079", "120079:蔵波川"),
+    ("120080", "120080:久保田川"),
+    ("120081", "120081:浜宿川"),
+    ("120082", "120082:笠上川"),
+    ("120083", "120083:今津川"),
+    ("120084", "120084:海神川"),
+    ("130001", "130001:八ツ瀬川"),
+    ("130002", "130002:呑川"),
+    ("130003", "130003:内川"),
+    ("130004", "130004:立会川"),
+    ("130005", "130005:目黒川"),
+    ("130006", "130006:古川"),
+    ("130007", "130007:汐留川"),
+    ("130008", "130008:築地川"),
+    ("130009", "130009:越中島川"),
+    ("130010", "130010:境川"),
+    ("130011", "130011:ヌタの沢川"),
+    ("130012", "130012:北の山川"),
+    ("130013", "130013:根古沢"),
+    ("130014", "130014:蛇洞沢"),
+    ("130015", "130015:大島沢"),
+    ("130016", "130016:新堀川"),
+    ("130017", "130017:とりが沢"),
+    ("130018", "130018:川田沢"),
+    ("130019", "130019:芦川"),
+    ("130020", "130020:清瀬川"),
+    ("140001", "140001:帷子川"),
+    ("140002", "140002:大岡川"),
+    ("140003", "140003:宮川"),
+    ("140004", "140004:侍従川"),
+    ("140005", "140005:鷹取川"),
+    ("140006", "140006:平作川"),
+    ("140007", "140007:松越川"),
+    ("140008", "140008:下山川"),
+    ("140009", "140009:森戸川"),
+    ("140010", "140010:田越川"),
+    ("140011", "140011:滑川"),
+    ("140012", "140012:神戸川"),
+    ("140013", "140013:境川"),
+    ("140014", "140014:引地川"),
+    ("140015", "140015:金目川"),
+    ("140016", "140016:葛川"),
+    ("140017", "140017:中村川"),
+    ("140018", "140018:森戸川"),
+    ("140019", "140019:酒匂川"),
+    ("140020", "140020:山王川"),
+    ("140021", "140021:早川"),
+    ("140022", "140022:新崎川"),
+    ("140023", "140023:千歳川"),
+    ("140024", "140024:入江川"),
+    ("140025", "140025:滝の川"),
+    ("140026", "140026:野比川"),
+    ("140027", "140027:長沢川"),
+    ("140028", "140028:津久井川"),
+    ("140029", "140029:狭塚川"),
+    ("140030", "140030:川間川"),
+    ("140031", "140031:前田川"),
+    ("140032", "140032:血洗川"),
+    ("140033", "140033:塔台川"),
+    ("140034", "140034:唐沢川"),
+    ("140035", "140035:新川"),
+    ("140036", "140036:玉川"),
+    ("140037", "140037:水無川"),
+    ("140038", "140038:清水川"),
+    ("140039", "140039:白糸川"),
+    ("140040", "140040:よもぎ川"),
+    ("140041", "140041:堀切川"),
+    ("140042", "140042:音無川"),
+    ("150001", "150001:外波川"),
+    ("150002", "150002:歌川"),
+    ("150003", "150003:青海川"),
+    ("150004", "150004:田海川"),
+    ("150005", "150005:八千川"),
+    ("150006", "150006:海川"),
+    ("150007", "150007:前川"),
+    ("150008", "150008:早川"),
+    ("150009", "150009:中央川"),
+    ("150010", "150010:古川"),
+    ("150011", "150011:木浦川"),
+    ("150012", "150012:能生川"),
+    ("150013", "150013:山王川"),
+    ("150014", "150014:筒石川"),
+    ("150015", "150015:徳合川"),
+    ("150016", "150016:名立川"),
+    ("150017", "150017:桑取川"),
+    ("150018", "150018:柿崎川"),
+    ("150019", "150019:オガチ川"),
+    ("150020", "150020:谷根川"),
+    ("150021", "150021:前川"),
+    ("150022", "150022:鵜川"),
+    ("150023", "150023:鯖石川"),
+    ("150024", "150024:二位殿川"),
+    ("150025", "150025:大津川"),
+    ("150026", "150026:尾町川"),
+    ("150027", "150027:石地川"),
+    ("150028", "150028:相場川"),
+    ("150029", "150029:郷本川"),
+    ("150030", "150030:新島崎川"),
+    ("150031", "150031:大三川"),
+    ("150032", "150032:藤四郎川"),
+    ("150033", "150033:高堂川"),
+    ("150034", "150034:次郎七川"),
+    ("150035", "150035:大野積川"),
+    ("150036", "150036:白勢川"),
+    ("150037", "150037:坂本川"),
+    ("150038", "150038:新川"),
+    ("150039", "150039:加治川"),
+    ("150040", "150040:落堀川"),
+    ("150041", "150041:胎内川"),
+    ("150042", "150042:石川"),
+    ("150043", "150043:三面川"),
+    ("150044", "150044:大川"),
+    ("150045", "150045:早川"),
+    ("150046", "150046:桑川"),
+    ("150047", "150047:笹川"),
+    ("150048", "150048:板貝川"),
+    ("150049", "150049:脇川"),
+    ("150050", "150050:葡萄川"),
+    ("150051", "150051:勝木川"),
+    ("150052", "150052:碁石川"),
+    ("150053", "150053:間の内川"),
+    ("150054", "150054:大川"),
+    ("150055", "150055:中ノ沢川"),
+    ("150056", "150056:黒姫川"),
+    ("150057", "150057:歌見川"),
+    ("150058", "150058:馬首川"),
+    ("150059", "150059:和木川"),
+    ("150060", "150060:玉川"),
+    ("150061", "150061:白瀬川"),
+    ("150062", "150062:北五十里川"),
+    ("150063", "150063:椿川"),
+    ("150064", "150064:羽黒川"),
+    ("150065", "150065:梅津川"),
+    ("150066", "150066:外城川"),
+    ("150067", "150067:貝喰川"),
+    ("150068", "150068:長江川"),
+    ("150069", "150069:天王川"),
+    ("150070", "150070:久知川"),
+    ("150071", "150071:河崎川"),
+    ("150072", "150072:真更川"),
+    ("150073", "150073:両尾川"),
+    ("150074", "150074:大川"),
+    ("150075", "150075:野浦川"),
+    ("150076", "150076:東立島川"),
+    ("150077", "150077:赤玉北川"),
+    ("150078", "150078:立間川"),
+    ("150079", "150079:柿野浦川"),
+    ("150080", "150080:東鵜島川"),
+    ("150081", "150081:岩首川"),
+    ("150082", "150082:浦之河内川"),
+    ("150083", "150083:河内川"),
+    ("150084", "150084:腰細川"),
+    ("150085", "150085:高川川"),
+    ("150086", "150086:中野川"),
+    ("150087", "150087:荒町川"),
+    ("150088", "150088:柳沢川"),
+    ("150089", "150089:堀切川"),
+    ("150090", "150090:羽茂川"),
+    ("150091", "150091:小比叡川"),
+    ("150092", "150092:泉財川"),
+    ("150093", "150093:称光寺川"),
+    ("150094", "150094:大浦川"),
+    ("150095", "150095:椿尾川"),
+    ("150096", "150096:高崎川"),
+    ("150097", "150097:西三川川"),
+    ("150098", "150098:三貫目川"),
+    ("150099", "150099:小川内川"),
+    ("150100", "150100:真野川"),
+    ("150101", "150101:高立川"),
+    ("150102", "150102:国府川"),
+    ("150103", "150103:石田川"),
+    ("150104", "150104:荒町川"),
+    ("150105", "150105:沓掛川"),
+    ("150106", "150106:玄道川"),
+    ("150107", "150107:播磨川"),
+    ("150108", "150108:質場川"),
+    ("150109", "150109:羽二生川"),
+    ("150110", "150110:目観音川"),
+    ("150111", "150111:海士町川"),
+    ("150112", "150112:大仏川"),
+    ("150113", "150113:間切川"),
+    ("150114", "150114:濁川"),
+    ("150115", "150115:水金川"),
+    ("150116", "150116:小川川"),
+    ("150117", "150117:達者川"),
+    ("150118", "150118:小川"),
+    ("150119", "150119:北狄川"),
+    ("150120", "150120:中の坂川"),
+    ("150121", "150121:戸地川"),
+    ("150122", "150122:松島川"),
+    ("150123", "150123:石花川"),
+    ("150124", "150124:小川"),
+    ("150125", "150125:桜川"),
+    ("150126", "150126:北立島川"),
+    ("150127", "150127:安田川"),
+    ("150128", "150128:入川"),
+    ("150129", "150129:堀切川"),
+    ("150130", "150130:孫助川"),
+    ("150131", "150131:小野見川"),
+    ("150132", "150132:馬込川"),
+    ("150133", "150133:石名川"),
+    ("150134", "150134:大倉川"),
+    ("150135", "150135:堂の川"),
+    ("150136", "150136:矢柄川"),
+    ("150137", "150137:関川"),
+    ("150138", "150138:五十浦川"),
+    ("150139", "150139:岩谷口小川"),
+    ("150140", "150140:大川"),
+    ("150141", "150141:ぬな川"),
+    ("150142", "150142:今川"),
+    ("150143", "150143:大窪沢川"),
+    ("150144", "150144:法事川"),
+    ("150145", "150145:新田川"),
+    ("150146", "150146:寺の川"),
+    ("150147", "150147:宮の沢川"),
+    ("150148", "150148:多町川"),
+    ("150149", "150149:上の川"),
+    ("150150", "150150:姫津川"),
+    ("150151", "150151:本の川"),
+    ("150152", "150152:戸中川"),
+    ("150153", "150153:中の坂川"),
+    ("150154", "150154:梅川"),
+    ("150155", "150155:源平川"),
+    ("150156", "150156:寺の沢川"),
+    ("150157", "150157:大塚川"),
+    ("150158", "150158:一丁尻川"),
+    ("150159", "150159:ノタカ沢川"),
+    ("150160", "150160:立髪川"),
+    ("150161", "150161:北の川"),
+    ("150162", "150162:島崎川"),
+    ("160001", "160001:下田川"),
+    ("160002", "160002:宇波川"),
+    ("160003", "160003:阿尾川"),
+    ("160004", "160004:余川川"),
+    ("160005", "160005:上庄川"),
+    ("160006", "160006:仏生寺川"),
+    ("160007", "160007:泉川"),
+    ("160008", "160008:内川"),
+    ("160009", "160009:放生津潟"),
+    ("160010", "160010:新堀川"),
+    ("160011", "160011:下条川"),
+    ("160012", "160012:古川"),
+    ("160013", "160013:白岩川"),
+    ("160014", "160014:上市川"),
+    ("160015", "160015:中川"),
+    ("160016", "160016:早月川"),
+    ("160017", "160017:角川"),
+    ("160018", "160018:鴨川"),
+    ("160019", "160019:中川"),
+    ("160020", "160020:片貝川"),
+    ("160021", "160021:黒瀬川"),
+    ("160022", "160022:高橋川"),
+    ("160023", "160023:吉田川"),
+    ("160024", "160024:平曽川"),
+    ("160025", "160025:入川"),
+    ("160026", "160026:小川"),
+    ("160027", "160027:寺川"),
+    ("160028", "160028:木流川"),
+    ("160029", "160029:笹川"),
+    ("160030", "160030:境川"),
+    ("160031", "160031:下田川"),
+    ("160032", "160032:渋谷川"),
+    ("160033", "160033:がめ川"),
+    ("160034", "160034:田中川"),
+    ("160036", "160036:こうなぎ川"),
+    ("160037", "160037:清水川"),
+    ("160038", "160038:出戸川"),
+    ("160039", "160039:大野川"),
+    ("160040", "160040:平曽川"),
+    ("170001", "170001:大聖寺川"),
+    ("170002", "170002:田尻川"),
+    ("170003", "170003:新堀川"),
+    ("170004", "170004:大慶寺川"),
+    ("170005", "170005:倉部川"),
+    ("170006", "170006:犀川"),
+    ("170007", "170007:大野川"),
+    ("170008", "170008:大海川"),
+    ("170009", "170009:前田川"),
+    ("170010", "170010:宝達川"),
+    ("170011", "170011:相見川"),
+    ("170012", "170012:羽咋川"),
+    ("170013", "170013:菱根川"),
+    ("170014", "170014:米町川"),
+    ("170015", "170015:富来川"),
+    ("170016", "170016:酒見川"),
+    ("170017", "170017:仁岸川"),
+    ("170018", "170018:阿岸川"),
+    ("170019", "170019:八ヶ川"),
+    ("170020", "170020:西二又川"),
+    ("170021", "170021:河原田川"),
+    ("170022", "170022:塚田川"),
+    ("170023", "170023:南志見川"),
+    ("170024", "170024:町野川"),
+    ("170025", "170025:珠洲大谷川"),
+    ("170026", "170026:折戸川"),
+    ("170027", "170027:粟津川"),
+    ("170028", "170028:紀の川"),
+    ("170029", "170029:飯川"),
+    ("170030", "170030:金川"),
+    ("170031", "170031:若山川"),
+    ("170032", "170032:竹中川"),
+    ("170033", "170033:磐若川"),
+    ("170034", "170034:鵜飼川"),
+    ("170035", "170035:舟橋川"),
+    ("170036", "170036:松波川"),
+    ("170037", "170037:九里川尻川"),
+    ("170038", "170038:白丸川"),
+    ("170039", "170039:梶川"),
+    ("170040", "170040:山田川"),
+    ("170041", "170041:太田川"),
+    ("170042", "170042:諸橋川"),
+    ("170043", "170043:日詰川"),
+    ("170044", "170044:七海川"),
+    ("170045", "170045:小又川"),
+    ("170046", "170046:小牧川"),
+    ("170047", "170047:熊木川"),
+    ("170048", "170048:日用川"),
+    ("170049", "170049:笠師川"),
+    ("170050", "170050:塩津川"),
+    ("170051", "170051:大津川"),
+    ("170052", "170052:深見川"),
+    ("170053", "170053:三引川"),
+    ("170054", "170054:二宮川"),
+    ("170055", "170055:赤浦川"),
+    ("170056", "170056:御祓川"),
+    ("170057", "170057:大谷川"),
+    ("170058", "170058:崎山川"),
+    ("170059", "170059:熊渕川"),
+    ("170060", "170060:衣川"),
+    ("170061", "170061:甘田川"),
+    ("170062", "170062:大滝川"),
+    ("170063", "170063:小浦川"),
+    ("170064", "170064:百浦川"),
+    ("170065", "170065:大鳥川"),
+    ("170066", "170066:江野川"),
+    ("170067", "170067:松戸川"),
+    ("170068", "170068:赤住川"),
+    ("170070", "170070:蛸島川"),
+    ("170071", "170071:奥原川"),
+    ("170072", "170072:浜岡川"),
+    ("170073", "170073:祖浜川"),
+    ("170074", "170074:江川"),
+    ("170075", "170075:松原川"),
+    ("170076", "170076:赤星川"),
+    ("170077", "170077:臼池川"),
+    ("170078", "170078:高田川"),
+    ("170079", "170079:太田八幡川"),
+    ("170080", "170080:滝尻川"),
+    ("170081", "170081:赤崎川"),
+    ("170082", "170082:前の川"),
+    ("170083", "170083:窪川"),
+    ("170084", "170084:今田川"),
+    ("170085", "170085:福留川"),
+    ("170086", "170086:福浦川"),
+    ("170087", "170087:日越川"),
+    ("170088", "170088:大野木川"),
+    ("170089", "170089:馬谷川"),
+    ("170090", "170090:江泊川"),
+    ("170091", "170091:百海川"),
+    ("170092", "170092:赤岩川"),
+    ("170093", "170093:谷内川"),
+    ("170094", "170094:波出川"),
+    ("170095", "170095:胴田川"),
+    ("170096", "170096:虫崎川"),
+    ("170097", "170097:八幡川"),
+    ("170098", "170098:佐々波川"),
+    ("170099", "170099:黒崎川"),
+    ("170100", "170100:東浜川"),
+    ("170101", "170101:鮭谷川"),
+    ("170102", "170102:時合川"),
+    ("170103", "170103:大泊川"),
+    ("170104", "170104:新池川"),
+    ("170105", "170105:ヤブライチ川"),
+    ("170106", "170106:大坪川"),
+    ("170107", "170107:よしら川"),
+    ("170108", "170108:神川"),
+    ("170109", "170109:水の澗川"),
+    ("170110", "170110:がんもん川"),
+    ("170111", "170111:サット川"),
+    ("170112", "170112:牛下川"),
+    ("170113", "170113:生神川"),
+    ("170114", "170114:七海川"),
+    ("170115", "170115:滝出川"),
+    ("170116", "170116:小窪川"),
+    ("170117", "170117:鹿頭川"),
+    ("170118", "170118:小山出川"),
+    ("170119", "170119:前浜川"),
+    ("170120", "170120:深谷川"),
+    ("180001", "180001:関屋川"),
+    ("180002", "180002:子生川"),
+    ("180003", "180003:佐分利川"),
+    ("180004", "180004:本所川"),
+    ("180005", "180005:飯盛川"),
+    ("180006", "180006:南川"),
+    ("180007", "180007:多田川"),
+    ("180008", "180008:早瀬川"),
+    ("180009", "180009:耳川"),
+    ("180010", "180010:太田川"),
+    ("180011", "180011:馬背川"),
+    ("180012", "180012:落合川"),
+    ("180013", "180013:井口川"),
+    ("180014", "180014:笙の川"),
+    ("180015", "180015:河野川"),
+    ("180016", "180016:糠川"),
+    ("180017", "180017:玉川川"),
+    ("180018", "180018:大味川"),
+    ("180019", "180019:一光川"),
+    ("180020", "180020:三本木川"),
+    ("180021", "180021:高須川"),
+    ("180022", "180022:笠原川"),
+    ("180023", "180023:手の浦川"),
+    ("180024", "180024:毬山川"),
+    ("180025", "180025:田猪川"),
+    ("180026", "180026:赤崎川"),
+    ("180027", "180027:平野川"),
+    ("180028", "180028:大毛谷川"),
+    ("180029", "180029:竹鼻川"),
+    ("180030", "180030:大川"),
+    ("180031", "180031:免鳥川"),
+    ("180032", "180032:今津川"),
+    ("190001", "190001:西湖"),
+    ("190002", "190002:精進湖"),
+    ("190003", "190003:本栖湖"),
+    ("220001", "220001:千才川"),
+    ("220002", "220002:逢初川"),
+    ("220003", "220003:糸川"),
+    ("220004", "220004:初川"),
+    ("220005", "220005:熱海和田川"),
+    ("220006", "220006:上多賀大川"),
+    ("220007", "220007:熱海宮川"),
+    ("220008", "220008:熱海仲川"),
+    ("220009", "220009:鍛治川"),
+    ("220010", "220010:水神川"),
+    ("220011", "220011:烏川"),
+    ("220012", "220012:伊東仲川"),
+    ("220013", "220013:伊東宮川"),
+    ("220014", "220014:北川"),
+    ("220015", "220015:伊東大川"),
+    ("220016", "220016:草崎川"),
+    ("220017", "220017:大川川"),
+    ("220018", "220018:濁川"),
+    ("220019", "220019:白田川"),
+    ("220020", "220020:稲取大川"),
+    ("220021", "220021:河津川"),
+    ("220022", "220022:稲生沢川"),
+    ("220023", "220023:大賀茂川"),
+    ("220024", "220024:青野川"),
+    ("220025", "220025:中木川"),
+    ("220026", "220026:殿田川"),
+    ("220027", "220027:五十鈴川"),
+    ("220028", "220028:那賀川"),
+    ("220029", "220029:仁科川"),
+    ("220030", "220030:安良里浜川"),
+    ("220031", "220031:宇久須川"),
+    ("220032", "220032:八木沢大川"),
+    ("220033", "220033:松原川"),
+    ("220034", "220034:火振川"),
+    ("220035", "220035:山川"),
+    ("220036", "220036:小土肥大川"),
+    ("220037", "220037:大川"),
+    ("220038", "220038:沢海川"),
+    ("220039", "220039:井田大川"),
+    ("220040", "220040:古宇川"),
+    ("220041", "220041:立保川"),
+    ("220042", "220042:西浦河内川"),
+    ("220043", "220043:陰野川"),
+    ("220044", "220044:新中川"),
+    ("220045", "220045:山居沢川"),
+    ("220046", "220046:向田川"),
+    ("220047", "220047:堰沢川"),
+    ("220048", "220048:八木沢川"),
+    ("220049", "220049:神沢川"),
+    ("220050", "220050:由比川"),
+    ("220051", "220051:和瀬川"),
+    ("220052", "220052:興津川"),
+    ("220053", "220053:波多打川"),
+    ("220054", "220054:庵原川"),
+    ("220055", "220055:巴川"),
+    ("220056", "220056:滝ヶ原川"),
+    ("220057", "220057:古安川"),
+    ("220058", "220058:殿谷川"),
+    ("220060", "220060:浜川"),
+    ("220061", "220061:小坂川"),
+    ("220062", "220062:瀬戸川"),
+    ("220063", "220063:小石川"),
+    ("220064", "220064:栃山川"),
+    ("220065", "220065:志太田中川"),
+    ("220066", "220066:湯日川"),
+    ("220067", "220067:坂口谷川"),
+    ("220068", "220068:勝間田川"),
+    ("220069", "220069:萩間川"),
+    ("220070", "220070:須々木川"),
+    ("220071", "220071:東沢川"),
+    ("220072", "220072:中西川"),
+    ("220073", "220073:筬川"),
+    ("220074", "220074:新野川"),
+    ("220075", "220075:竜今寺川"),
+    ("220076", "220076:東大谷川"),
+    ("220077", "220077:弁財天川"),
+    ("220078", "220078:前川"),
+    ("220079", "220079:太田川"),
+    ("220080", "220080:馬込川"),
+    ("220081", "220081:都田川"),
+    ("220082", "220082:鳴沢川"),
+    ("220083", "220083:対馬川"),
+    ("220084", "220084:八幡野川"),
+    ("220085", "220085:一色川"),
+    ("220086", "220086:砥川"),
+    ("220087", "220087:御嶽川"),
+    ("220088", "220088:古根川"),
+    ("220089", "220089:宮前川"),
+    ("220090", "220090:下篠川"),
+    ("220091", "220091:上ノ山川"),
+    ("220092", "220092:外浦川"),
+    ("220093", "220093:須崎川"),
+    ("220094", "220094:鍋田川"),
+    ("220095", "220095:多々戸川"),
+    ("220096", "220096:入田川"),
+    ("220097", "220097:大浜川"),
+    ("220098", "220098:田牛川"),
+    ("220099", "220099:太田川"),
+    ("220100", "220100:山道川"),
+    ("220101", "220101:谷地川"),
+    ("220102", "220102:小田瀬川"),
+    ("220103", "220103:井田子川"),
+    ("220104", "220104:太田川"),
+    ("220105", "220105:不動尊川"),
+    ("220106", "220106:深田川"),
+    ("220107", "220107:滝堂川"),
+    ("220108", "220108:獅子浜川"),
+    ("220109", "220109:塚田川"),
+    ("220110", "220110:元富士二号排水路"),
+    ("220111", "220111:新川"),
+    ("220112", "220112:浜田川"),
+    ("220113", "220113:大田川"),
+    ("220114", "220114:藤守川"),
+    ("220115", "220115:堺川"),
+    ("220116", "220116:堀切川"),
+    ("220117", "220117:大磯川"),
+    ("220118", "220118:法京川"),
+    ("220119", "220119:ラムネ川"),
+    ("220120", "220120:寺川"),
+    ("220121", "220121:穴川"),
+    ("220122", "220122:滝ノ川"),
+    ("220123", "220123:地代川"),
+    ("220124", "220124:倉沢川"),
+    ("220125", "220125:甚太郎川"),
+    ("220126", "220126:旧大谷川"),
+    ("230001", "230001:池尻川"),
+    ("230002", "230002:精進川"),
+    ("230003", "230003:天白川"),
+    ("230004", "230004:免々田川"),
+    ("230005", "230005:新堀川"),
+    ("230006", "230006:今堀川"),
+    ("230007", "230007:今池川"),
+    ("230008", "230008:汐川"),
+    ("230009", "230009:蜆川"),
+    ("230010", "230010:紙田川"),
+    ("230011", "230011:境川"),
+    ("230012", "230012:梅田川"),
+    ("230013", "230013:柳生川"),
+    ("230014", "230014:佐奈川"),
+    ("230015", "230015:音羽川"),
+    ("230016", "230016:御津川"),
+    ("230017", "230017:紫川"),
+    ("230018", "230018:西田川"),
+    ("230019", "230019:落合川"),
+    ("230020", "230020:拾石川"),
+    ("230021", "230021:八幡川"),
+    ("230022", "230022:鳥羽川"),
+    ("230023", "230023:矢崎川"),
+    ("230024", "230024:北浜川"),
+    ("230025", "230025:蜆川"),
+    ("230026", "230026:高浜川"),
+    ("230027", "230027:前川"),
+    ("230028", "230028:猿渡川"),
+    ("230029", "230029:境川"),
+    ("230030", "230030:明徳寺川"),
+    ("230031", "230031:豆搗川"),
+    ("230032", "230032:須賀川"),
+    ("230033", "230033:稗田川"),
+    ("230034", "230034:阿久比川"),
+    ("230035", "230035:十ヶ川"),
+    ("230036", "230036:神戸川"),
+    ("230037", "230037:石川"),
+    ("230038", "230038:堀川"),
+    ("230039", "230039:新川"),
+    ("230040", "230040:布土川"),
+    ("230041", "230041:新江川"),
+    ("230042", "230042:大川"),
+    ("230043", "230043:五宝川"),
+    ("230044", "230044:山海川"),
+    ("230045", "230045:内海川"),
+    ("230046", "230046:山王川"),
+    ("230047", "230047:稲早川"),
+    ("230048", "230048:矢田川"),
+    ("230049", "230049:日長川"),
+    ("230050", "230050:信濃川"),
+    ("230051", "230051:大田川"),
+    ("230052", "230052:天白川"),
+    ("230053", "230053:山崎川"),
+    ("230054", "230054:日光川"),
+    ("230055", "230055:筏川"),
+    ("230056", "230056:大西川"),
+    ("230057", "230057:庄五郎川"),
+    ("230058", "230058:弘法川"),
+    ("230059", "230059:波治神川"),
+    ("230060", "230060:文録川"),
+    ("230061", "230061:新堀川"),
+    ("230062", "230062:大堀川"),
+    ("230063", "230063:豊島排水路"),
+    ("230064", "230064:入江川"),
+    ("230065", "230065:大川"),
+    ("230066", "230066:紺屋川"),
+    ("230067", "230067:大湯川"),
+    ("230068", "230068:御山川"),
+    ("230069", "230069:背戸田川"),
+    ("230070", "230070:江川"),
+    ("230071", "230071:西方川"),
+    ("230072", "230072:堺川"),
+    ("230073", "230073:丹下川"),
+    ("230074", "230074:丸差川"),
+    ("230075", "230075:勝川"),
+    ("230076", "230076:向山下水"),
+    ("230077", "230077:尺地川"),
+    ("230078", "230078:都川"),
+    ("230079", "230079:中野川"),
+    ("230080", "230080:天神川"),
+    ("230081", "230081:袋川"),
+    ("230082", "230082:境川"),
+    ("230083", "230083:鹿川"),
+    ("230084", "230084:小野ヶ谷川"),
+    ("230085", "230085:清水川"),
+    ("230086", "230086:蛇抜川"),
+    ("230087", "230087:白浜川"),
+    ("230088", "230088:堀割川"),
+    ("230089", "230089:堀川"),
+    ("230090", "230090:田戸川"),
+    ("230091", "230091:江川"),
+    ("230092", "230092:浦島川"),
+    ("230093", "230093:北川"),
+    ("230094", "230094:石田川"),
+    ("230095", "230095:笠松川"),
+    ("230096", "230096:大池川"),
+    ("230097", "230097:時志川"),
+    ("230098", "230098:北方川"),
+    ("230099", "230099:古江川"),
+    ("230100", "230100:大井川"),
+    ("230101", "230101:片名川"),
+    ("230102", "230102:高浜谷川"),
+    ("230103", "230103:鳥居川"),
+    ("230104", "230104:小野浦川"),
+    ("230105", "230105:八幡川"),
+    ("230106", "230106:富具崎川"),
+    ("230107", "230107:杉谷川"),
+    ("230108", "230108:大谷川"),
+    ("230109", "230109:境川"),
+    ("230110", "230110:唐崎川"),
+    ("230111", "230111:樽水川"),
+    ("230112", "230112:大落川"),
+    ("230113", "230113:井口川"),
+    ("230114", "230114:美濃川"),
+    ("230115", "230115:土留木川"),
+    ("240001", "240001:員弁川"),
+    ("240002", "240002:朝明川"),
+    ("240003", "240003:海蔵川"),
+    ("240004", "240004:三滝川"),
+    ("240005", "240005:天白川"),
+    ("240006", "240006:金沢川"),
+    ("240007", "240007:堀切川"),
+    ("240008", "240008:中ノ川"),
+    ("240009", "240009:田中川"),
+    ("240010", "240010:志登茂川"),
+    ("240011", "240011:安濃川"),
+    ("240012", "240012:岩田川"),
+    ("240013", "240013:相川"),
+    ("240014", "240014:碧川"),
+    ("240015", "240015:三渡川"),
+    ("240016", "240016:阪内川"),
+    ("240017", "240017:金剛川"),
+    ("240018", "240018:中川"),
+    ("240019", "240019:笹笛川"),
+    ("240020", "240020:大堀川"),
+    ("240021", "240021:江川"),
+    ("240022", "240022:外城田川"),
+    ("240023", "240023:堀通川"),
+    ("240024", "240024:紙漉川"),
+    ("240025", "240025:加茂川"),
+    ("240026", "240026:大吉川"),
+    ("240027", "240027:藤谷川"),
+    ("240028", "240028:磯部川"),
+    ("240029", "240029:日出川"),
+    ("240030", "240030:東海川"),
+    ("240031", "240031:前川"),
+    ("240032", "240032:西川"),
+    ("240033", "240033:迫子川"),
+    ("240034", "240034:清水川"),
+    ("240035", "240035:桧山路川"),
+    ("240036", "240036:南張川"),
+    ("240037", "240037:神津佐川"),
+    ("240038", "240038:泉川"),
+    ("240039", "240039:五ヶ所川"),
+    ("240040", "240040:中の谷川"),
+    ("240041", "240041:伊勢路川"),
+    ("240042", "240042:大川"),
+    ("240043", "240043:大江川"),
+    ("240044", "240044:河内川"),
+    ("240045", "240045:村山川"),
+    ("240046", "240046:小方川"),
+    ("240047", "240047:古和川"),
+    ("240048", "240048:奥川"),
+    ("240049", "240049:小名倉川"),
+    ("240050", "240050:片上川"),
+    ("240051", "240051:赤羽川"),
+    ("240052", "240052:大瀬川"),
+    ("240053", "240053:元谷川"),
+    ("240054", "240054:船津川"),
+    ("240055", "240055:銚子川"),
+    ("240056", "240056:北川"),
+    ("240057", "240057:中川"),
+    ("240058", "240058:矢ノ川"),
+    ("240059", "240059:田海道川"),
+    ("240060", "240060:沓川"),
+    ("240061", "240061:八十川"),
+    ("240062", "240062:小浜川"),
+    ("240063", "240063:古川"),
+    ("240064", "240064:逢川"),
+    ("240065", "240065:湊川"),
+    ("240066", "240066:里川"),
+    ("240067", "240067:熊野宮川"),
+    ("240068", "240068:西郷川"),
+    ("240069", "240069:井戸川"),
+    ("240070", "240070:志原川"),
+    ("240071", "240071:市木川"),
+    ("240072", "240072:尾呂志川"),
+    ("240073", "240073:井田川"),
+    ("240074", "240074:神内川"),
+    ("240075", "240075:松白川"),
+    ("240076", "240076:十四川"),
+    ("240077", "240077:米洗川"),
+    ("240078", "240078:北長太川"),
+    ("240079", "240079:一本木川"),
+    ("240080", "240080:二本木川"),
+    ("240081", "240081:白子川"),
+    ("240082", "240082:月見川"),
+    ("240083", "240083:川関川"),
+    ("240084", "240084:小海川"),
+    ("240085", "240085:長崎川"),
+    ("240086", "240086:松振川"),
+    ("240087", "240087:鞍川"),
+    ("240088", "240088:つつみの川"),
+    ("240089", "240089:一本松川"),
+    ("240090", "240090:水深川"),
+    ("240091", "240091:江田川"),
+    ("240092", "240092:清四郎川"),
+    ("240093", "240093:桧枝田川"),
+    ("240094", "240094:阿津里川"),
+    ("240096", "240096:おおばし川"),
+    ("240097", "240097:寺川"),
+    ("240098", "240098:前田川"),
+    ("240099", "240099:宮西川"),
+    ("240100", "240100:石光浦川"),
+    ("240101", "240101:下津浦川"),
+    ("240102", "240102:スルジ川"),
+    ("240103", "240103:形黒川"),
+    ("240104", "240104:福浦川"),
+    ("240105", "240105:三浦押淵越地川"),
+    ("240106", "240106:三浦大江越地川"),
+    ("240107", "240107:六川原川"),
+    ("240108", "240108:大方川"),
+    ("240109", "240109:道行川"),
+    ("240110", "240110:道方川"),
+    ("240111", "240111:荒谷川"),
+    ("240112", "240112:一里川"),
+    ("240113", "240113:東宮川"),
+    ("240114", "240114:伊勢地川"),
+    ("240115", "240115:下の谷川"),
+    ("240116", "240116:方座川"),
+    ("240117", "240117:奥の山川"),
+    ("240118", "240118:棚橋川"),
+    ("240119", "240119:新桑川"),
+    ("240120", "240120:福羅川"),
+    ("240121", "240121:谷山川"),
+    ("240122", "240122:寺山川"),
+    ("240123", "240123:大師川"),
+    ("240124", "240124:久野川"),
+    ("240125", "240125:横手川"),
+    ("240126", "240126:笠子川"),
+    ("240127", "240127:宮前川"),
+    ("240128", "240128:大谷川"),
+    ("240129", "240129:オマワキ川"),
+    ("240130", "240130:市ノ川"),
+    ("240131", "240131:田ノ谷川"),
+    ("240132", "240132:太地川"),
+    ("240133", "240133:中熊川"),
+    ("240134", "240134:玉戸川"),
+    ("240135", "240135:西谷川"),
+    ("240136", "240136:谷地川"),
+    ("240137", "240137:大川"),
+    ("240138", "240138:樫の木谷川"),
+    ("240139", "240139:厨川"),
+    ("240140", "240140:松原谷川"),
+    ("240141", "240141:長浜大谷川"),
+    ("240142", "240142:甫母大川"),
+    ("240143", "240143:オコ谷川"),
+    ("240144", "240144:西の谷川"),
+    ("240145", "240145:小向井川"),
+    ("240146", "240146:新田川"),
+    ("240147", "240147:遊木川"),
+    ("240148", "240148:波田須川"),
+    ("240149", "240149:流川"),
+    ("260001", "260001:久美谷川"),
+    ("260002", "260002:栃谷川"),
+    ("260003", "260003:川上谷川"),
+    ("260004", "260004:佐濃谷川"),
+    ("260005", "260005:木津川"),
+    ("260006", "260006:福田川"),
+    ("260007", "260007:新樋越川"),
+    ("260008", "260008:竹野川"),
+    ("260009", "260009:宇川"),
+    ("260010", "260010:吉野川"),
+    ("260011", "260011:筒川"),
+    ("260012", "260012:朝妻川"),
+    ("260013", "260013:犀川"),
+    ("260014", "260014:波見川"),
+    ("260015", "260015:世屋川"),
+    ("260016", "260016:畑川"),
+    ("260017", "260017:真名井川"),
+    ("260018", "260018:三田川"),
+    ("260019", "260019:男山川"),
+    ("260020", "260020:野田川"),
+    ("260021", "260021:宮川"),
+    ("260022", "260022:大手川"),
+    ("260023", "260023:大膳川"),
+    ("260024", "260024:神子川"),
+    ("260025", "260025:大雲川"),
+    ("260026", "260026:福井川"),
+    ("260027", "260027:高野川"),
+    ("260028", "260028:伊佐津川"),
+    ("260029", "260029:与保呂川"),
+    ("260030", "260030:祖母谷川"),
+    ("260031", "260031:志楽川"),
+    ("260032", "260032:朝来川"),
+    ("260033", "260033:河辺川"),
+    ("260034", "260034:大丹生川"),
+    ("260035", "260035:瀬崎川"),
+    ("260036", "260036:野原川"),
+    ("260037", "260037:掛津川"),
+    ("260038", "260038:落川"),
+    ("260039", "260039:カマヤ川"),
+    ("260040", "260040:真嶋谷川"),
+    ("260041", "260041:小川"),
+    ("260042", "260042:小泊川"),
+    ("260043", "260043:日出川"),
+    ("270001", "270001:東川"),
+    ("270002", "270002:大川"),
+    ("270003", "270003:番川"),
+    ("270004", "270004:茶屋川"),
+    ("270005", "270005:男里川"),
+    ("270006", "270006:樫井川"),
+    ("270007", "270007:田尻川"),
+    ("270008", "270008:佐野川"),
+    ("270009", "270009:見出川"),
+    ("270010", "270010:近木川"),
+    ("270011", "270011:津田川"),
+    ("270012", "270012:春木川"),
+    ("270013", "270013:大津川"),
+    ("270014", "270014:王子川"),
+    ("270015", "270015:芦田川"),
+    ("270016", "270016:石津川"),
+    ("270017", "270017:内川"),
+    ("270018", "270018:池詰川"),
+    ("280001", "280001:結川"),
+    ("280002", "280002:大栃川"),
+    ("280003", "280003:岸田川"),
+    ("280004", "280004:西川"),
+    ("280005", "280005:長谷川"),
+    ("280006", "280006:矢田川"),
+    ("280007", "280007:香住谷川"),
+    ("280008", "280008:上計川"),
+    ("280009", "280009:佐津川"),
+    ("280010", "280010:安木川"),
+    ("280011", "280011:須井川"),
+    ("280012", "280012:竹野川"),
+    ("280013", "280013:蓬川"),
+    ("280014", "280014:武庫川"),
+    ("280015", "280015:新川"),
+    ("280016", "280016:東川"),
+    ("280017", "280017:洗戎川"),
+    ("280018", "280018:夙川"),
+    ("280019", "280019:堀切川"),
+    ("280020", "280020:宮川"),
+    ("280021", "280021:芦屋川"),
+    ("280022", "280022:高橋川"),
+    ("280023", "280023:天上川"),
+    ("280024", "280024:住吉川"),
+    ("280025", "280025:西獺川"),
+    ("280026", "280026:天神川"),
+    ("280027", "280027:石屋川"),
+    ("280028", "280028:高羽川"),
+    ("280029", "280029:都賀川"),
+    ("280030", "280030:西郷川"),
+    ("280031", "280031:西谷川"),
+    ("280032", "280032:生田川"),
+    ("280033", "280033:鯉川"),
+    ("280034", "280034:宇治川"),
+    ("280035", "280035:新湊川"),
+    ("280036", "280036:妙法寺川"),
+    ("280037", "280037:千森川"),
+    ("280038", "280038:一ノ谷川"),
+    ("280039", "280039:塩屋谷川"),
+    ("280040", "280040:福田川"),
+    ("280041", "280041:山田川"),
+    ("280042", "280042:朝霧川"),
+    ("280043", "280043:明石川"),
+    ("280044", "280044:谷八木川"),
+    ("280045", "280045:赤根川"),
+    ("280046", "280046:瀬戸川"),
+    ("280047", "280047:喜瀬川"),
+    ("280048", "280048:泊川"),
+    ("280049", "280049:法華山谷川"),
+    ("280050", "280050:天川"),
+    ("280051", "280051:西浜川"),
+    ("280052", "280052:八家川"),
+    ("280053", "280053:市川"),
+    ("280054", "280054:野田川"),
+    ("280055", "280055:船場川"),
+    ("280056", "280056:夢前川"),
+    ("280057", "280057:汐入川"),
+    ("280058", "280058:大津茂川"),
+    ("280059", "280059:富島川"),
+    ("280060", "280060:亀の尾川"),
+    ("280061", "280061:大谷川"),
+    ("280062", "280062:苧谷川"),
+    ("280063", "280063:佐方川"),
+    ("280064", "280064:千種川"),
+    ("280065", "280065:大津川"),
+    ("280066", "280066:長谷川"),
+    ("280067", "280067:茶間川"),
+    ("280068", "280068:楠本川"),
+    ("280069", "280069:浦川"),
+    ("280070", "280070:砂川"),
+    ("280071", "280071:老松川"),
+    ("280072", "280072:佐野川"),
+    ("280073", "280073:生穂川"),
+    ("280074", "280074:大谷川"),
+    ("280075", "280075:志筑川"),
+    ("280076", "280076:岩戸川"),
+    ("280077", "280077:洲本川"),
+    ("280078", "280078:天川"),
+    ("280079", "280079:本庄川"),
+    ("280080", "280080:塩屋川"),
+    ("280081", "280081:津井川"),
+    ("280082", "280082:三原川"),
+    ("280083", "280083:鳥飼川"),
+    ("280084", "280084:都志川"),
+    ("280085", "280085:山田川"),
+    ("280086", "280086:覗川"),
+    ("280087", "280087:郡家川"),
+    ("280088", "280088:新川"),
+    ("280089", "280089:室津川"),
+    ("280090", "280090:育波川"),
+    ("280091", "280091:豊島川"),
+    ("280092", "280092:野島川"),
+    ("280093", "280093:松村川"),
+    ("280094", "280094:大谷川"),
+    ("300001", "300001:佐野川"),
+    ("300002", "300002:長野川"),
+    ("300003", "300003:狗子の川"),
+    ("300004", "300004:那智川"),
+    ("300005", "300005:天満川"),
+    ("300006", "300006:二河川"),
+    ("300007", "300007:与根子川"),
+    ("300008", "300008:太田川"),
+    ("300009", "300009:粉白川"),
+    ("300010", "300010:田無川"),
+    ("300011", "300011:田原川"),
+    ("300012", "300012:津荷川"),
+    ("300013", "300013:古座川"),
+    ("300014", "300014:神野川"),
+    ("300015", "300015:伊串川"),
+    ("300016", "300016:姫川"),
+    ("300017", "300017:宮川"),
+    ("300018", "300018:須賀川"),
+    ("300019", "300019:鬮野川"),
+    ("300020", "300020:二色川"),
+    ("300021", "300021:高富川"),
+    ("300022", "300022:貝岡川"),
+    ("300023", "300023:有田川"),
+    ("300024", "300024:田並川"),
+    ("300025", "300025:江田川"),
+    ("300026", "300026:田子川"),
+    ("300027", "300027:安指川"),
+    ("300028", "300028:和深川"),
+    ("300029", "300029:熊谷川"),
+    ("300030", "300030:里野西地川"),
+    ("300031", "300031:江住川"),
+    ("300032", "300032:江須の川"),
+    ("300033", "300033:和深川"),
+    ("300034", "300034:周参見川"),
+    ("300035", "300035:伊古木川"),
+    ("300036", "300036:日置川"),
+    ("300037", "300037:志原川"),
+    ("300038", "300038:市江川"),
+    ("300039", "300039:朝来帰川"),
+    ("300040", "300040:見草川"),
+    ("300041", "300041:袋川"),
+    ("300042", "300042:富田川"),
+    ("300043", "300043:安久川"),
+    ("300044", "300044:仙波谷川"),
+    ("300045", "300045:新川"),
+    ("300046", "300046:出井川"),
+    ("300047", "300047:名喜里川"),
+    ("300048", "300048:橋谷川"),
+    ("300049", "300049:左会津川"),
+    ("300050", "300050:芳養川"),
+    ("300051", "300051:井原川"),
+    ("300052", "300052:堺川"),
+    ("300053", "300053:埴田川"),
+    ("300054", "300054:南部川"),
+    ("300055", "300055:東岩代川"),
+    ("300056", "300056:西岩代川"),
+    ("300057", "300057:切目川"),
+    ("300058", "300058:富の川"),
+    ("300059", "300059:印南川"),
+    ("300060", "300060:津井川"),
+    ("300061", "300061:楠井川"),
+    ("300062", "300062:上野川"),
+    ("300063", "300063:野島川"),
+    ("300064", "300064:壁川"),
+    ("300065", "300065:王子川"),
+    ("300066", "300066:日高川"),
+    ("300067", "300067:南出川"),
+    ("300068", "300068:産湯川"),
+    ("300069", "300069:比井川"),
+    ("300070", "300070:由良川"),
+    ("300071", "300071:前田川"),
+    ("300072", "300072:西広川"),
+    ("300073", "300073:江上川"),
+    ("300074", "300074:広川"),
+    ("300075", "300075:山田川"),
+    ("300076", "300076:出合川"),
+    ("300077", "300077:有田川"),
+    ("300078", "300078:小島川"),
+    ("300079", "300079:小原川"),
+    ("300080", "300080:加茂川"),
+    ("300081", "300081:日方川"),
+    ("300082", "300082:亀の川"),
+    ("300083", "300083:堤川"),
+    ("300084", "300084:阿振川"),
+    ("300085", "300085:清水川"),
+    ("300086", "300086:鴨居川"),
+    ("300087", "300087:横浦川"),
+    ("300088", "300088:馬谷川"),
+    ("300089", "300089:東谷川"),
+    ("300090", "300090:名杭川"),
+    ("300091", "300091:神谷川"),
+    ("300092", "300092:松原川"),
+    ("300093", "300093:山田川"),
+    ("300094", "300094:大崎川"),
+    ("300095", "300095:川尻川"),
+    ("300096", "300096:大谷川"),
+    ("310001", "310001:大水落川"),
+    ("310002", "310002:加茂新川"),
+    ("310003", "310003:佐蛇川"),
+    ("310004", "310004:塩川"),
+    ("310005", "310005:宇田川"),
+    ("310006", "310006:妻木川"),
+    ("310007", "310007:谷川"),
+    ("310008", "310008:江東川"),
+    ("310009", "310009:阿弥陀川"),
+    ("310010", "310010:名和川"),
+    ("310011", "310011:真子川"),
+    ("310012", "310012:宮川"),
+    ("310013", "310013:下市川"),
+    ("310014", "310014:甲川"),
+    ("310015", "310015:黒川"),
+    ("310016", "310016:勝田川"),
+    ("310017", "310017:月の輪川"),
+    ("310018", "310018:化粧川"),
+    ("310019", "310019:本谷川"),
+    ("310020", "310020:八橋川"),
+    ("310021", "310021:茅町川"),
+    ("310022", "310022:元旧川"),
+    ("310023", "310023:洗川"),
+    ("310024", "310024:馬込川"),
+    ("310025", "310025:御幸川"),
+    ("310026", "310026:加勢蛇川"),
+    ("310027", "310027:由良川"),
+    ("310028", "310028:橋津川"),
+    ("310029", "310029:宇谷川"),
+    ("310030", "310030:原川"),
+    ("310031", "310031:園川"),
+    ("310032", "310032:石脇川"),
+    ("310033", "310033:勝部川"),
+    ("310034", "310034:永江川"),
+    ("310035", "310035:浜村川"),
+    ("310036", "310036:河内川"),
+    ("310037", "310037:内海川"),
+    ("310038", "310038:溝川"),
+    ("310039", "310039:塩見川"),
+    ("310040", "310040:蒲生川"),
+    ("310041", "310041:吉田川"),
+    ("310042", "310042:陸上川"),
+    ("310043", "310043:堀川"),
+    ("310044", "310044:浅中川"),
+    ("310045", "310045:御大師川"),
+    ("310046", "310046:系図川"),
+    ("310047", "310047:大谷川"),
+    ("310048", "310048:西谷川"),
+    ("310049", "310049:海蔵寺川"),
+    ("310050", "310050:別所川"),
+    ("310051", "310051:兵川"),
+    ("310052", "310052:清水川"),
+    ("310053", "310053:小浜川"),
+    ("310054", "310054:奥沢見川"),
+    ("310055", "310055:小沢見川"),
+    ("310056", "310056:西ノ川"),
+    ("320001", "320001:飯浦川"),
+    ("320002", "320002:二見川"),
+    ("320003", "320003:喜阿弥川"),
+    ("320004", "320004:益田川"),
+    ("320005", "320005:遠田川"),
+    ("320006", "320006:津田川"),
+    ("320007", "320007:沖田川"),
+    ("320008", "320008:土田川"),
+    ("320009", "320009:岡見川"),
+    ("320010", "320010:三隅川"),
+    ("320011", "320011:周布川"),
+    ("320012", "320012:青川川"),
+    ("320013", "320013:浜田川"),
+    ("320014", "320014:下府川"),
+    ("320015", "320015:唐鐘川"),
+    ("320016", "320016:久代川"),
+    ("320017", "320017:曲川"),
+    ("320018", "320018:敬川"),
+    ("320019", "320019:水尻川"),
+    ("320020", "320020:和木川"),
+    ("320021", "320021:新川"),
+    ("320022", "320022:東川"),
+    ("320023", "320023:大池川"),
+    ("320024", "320024:倉谷川"),
+    ("320025", "320025:福光川"),
+    ("320026", "320026:小浜川"),
+    ("320027", "320027:湯里川"),
+    ("320028", "320028:塩郷川"),
+    ("320029", "320029:潮川"),
+    ("320030", "320030:宅野川"),
+    ("320031", "320031:逢浜川"),
+    ("320032", "320032:静間川"),
+    ("320033", "320033:大原川"),
+    ("320034", "320034:波根川"),
+    ("320035", "320035:田儀川"),
+    ("320036", "320036:小田川"),
+    ("320037", "320037:十間川"),
+    ("320039", "320039:堀川"),
+    ("320040", "320040:八千代川"),
+    ("320041", "320041:猪目川"),
+    ("320042", "320042:唐川川"),
+    ("320043", "320043:相代川"),
+    ("320044", "320044:森田川"),
+    ("320045", "320045:澄水川"),
+    ("320046", "320046:里路川"),
+    ("320047", "320047:千酌路川"),
+    ("320048", "320048:千酌川"),
+    ("320049", "320049:八尾川"),
+    ("320050", "320050:加茂川"),
+    ("320051", "320051:都万川"),
+    ("320052", "320052:那久川"),
+    ("320053", "320053:油井川"),
+    ("320054", "320054:長尾田川"),
+    ("320055", "320055:重栖川"),
+    ("320056", "320056:代川"),
+    ("320057", "320057:久見川"),
+    ("320058", "320058:中村川"),
+    ("320059", "320059:元屋川"),
+    ("320060", "320060:飯美川"),
+    ("320061", "320061:春日川"),
+    ("320062", "320062:卯敷川"),
+    ("320063", "320063:大久川"),
+    ("320064", "320064:飯田川"),
+    ("320065", "320065:東郷川"),
+    ("320066", "320066:大川"),
+    ("320067", "320067:多井川"),
+    ("320068", "320068:境川"),
+    ("320069", "320069:諏訪川"),
+    ("320070", "320070:美田川"),
+    ("320071", "320071:三度川"),
+    ("320072", "320072:横尾川"),
+    ("320073", "320073:須津川"),
+    ("320074", "320074:平原川"),
+    ("320075", "320075:折居川"),
+    ("320076", "320076:原川"),
+    ("320077", "320077:第二新川"),
+    ("320078", "320078:塩田川"),
+    ("320079", "320079:藪川"),
+    ("320080", "320080:野田川"),
+    ("320081", "320081:布勢川"),
+    ("320082", "320082:釜谷川"),
+    ("320083", "320083:三津川"),
+    ("320084", "320084:坂浦川"),
+    ("320085", "320085:末路川"),
+    ("320086", "320086:大山２号川"),
+    ("320087", "320087:代官屋川"),
+    ("320088", "320088:籾ノ谷川"),
+    ("320089", "320089:白海士川"),
+    ("330001", "330001:石谷川"),
+    ("330002", "330002:伊里川"),
+    ("330003", "330003:馬場川"),
+    ("330004", "330004:新田川"),
+    ("330005", "330005:奥山川"),
+    ("330006", "330006:幸崎川"),
+    ("330007", "330007:幸田川"),
+    ("330008", "330008:江川"),
+    ("330009", "330009:笹ヶ瀬川"),
+    ("330010", "330010:倉敷川"),
+    ("330011", "330011:鴨川"),
+    ("330012", "330012:庄田川"),
+    ("330013", "330013:長谷川"),
+    ("330014", "330014:下村川"),
+    ("330015", "330015:小田川"),
+    ("330016", "330016:砂川"),
+    ("330017", "330017:溜川"),
+    ("330018", "330018:里見川"),
+    ("330019", "330019:大島川"),
+    ("330020", "330020:今立川"),
+    ("330021", "330021:吉田川"),
+    ("330022", "330022:用之江川"),
+    ("330023", "330023:中州川"),
+    ("330024", "330024:流川"),
+    ("330025", "330025:国頭港横川"),
+    ("330026", "330026:東新開縦川"),
+    ("330027", "330027:新十郎川"),
+    ("330028", "330028:中新開川"),
+    ("330029", "330029:白砂川"),
+    ("330030", "330030:早崎川"),
+    ("330031", "330031:汐入川"),
+    ("330032", "330032:隅田川"),
+    ("340001", "340001:手城川"),
+    ("340002", "340002:本谷川"),
+    ("340003", "340003:山南川"),
+    ("340004", "340004:才戸川"),
+    ("340005", "340005:新川"),
+    ("340006", "340006:羽原川"),
+    ("340007", "340007:本郷川"),
+    ("340008", "340008:藤井川"),
+    ("340009", "340009:太田川"),
+    ("340010", "340010:栗原川"),
+    ("340011", "340011:和久原川"),
+    ("340012", "340012:西野川"),
+    ("340013", "340013:沼田川"),
+    ("340014", "340014:本川"),
+    ("340015", "340015:賀茂川"),
+    ("340016", "340016:木谷郷川"),
+    ("340017", "340017:三津大川"),
+    ("340018", "340018:蛇道川"),
+    ("340019", "340019:高野川"),
+    ("340020", "340020:野呂川"),
+    ("340021", "340021:黒瀬川"),
+    ("340022", "340022:大谷川"),
+    ("340023", "340023:堺川"),
+    ("340024", "340024:二河川"),
+    ("340025", "340025:総頭川"),
+    ("340026", "340026:矢野川"),
+    ("340027", "340027:尾崎川"),
+    ("340028", "340028:瀬野川"),
+    ("340029", "340029:八幡川"),
+    ("340030", "340030:岡の下川"),
+    ("340031", "340031:可愛川"),
+    ("340032", "340032:御手洗川"),
+    ("340033", "340033:永慶寺川"),
+    ("340034", "340034:大河内川"),
+    ("340035", "340035:倉崎川"),
+    ("340036", "340036:重井川"),
+    ("340037", "340037:大正川"),
+    ("340038", "340038:沖田川"),
+    ("340039", "340039:熱田川"),
+    ("340040", "340040:小原川"),
+    ("340041", "340041:原下川"),
+    ("340042", "340042:原田川"),
+    ("340043", "340043:大長川"),
+    ("340044", "340044:高田川"),
+    ("340045", "340045:田中川"),
+    ("340046", "340046:小鹿野川"),
+    ("340047", "340047:永田川"),
+    ("340048", "340048:古山田川"),
+    ("340049", "340049:真田川"),
+    ("340050", "340050:吉和川"),
+    ("340051", "340051:味潟川"),
+    ("340052", "340052:子之浦川"),
+    ("340053", "340053:三津口西川"),
+    ("340054", "340054:三津口谷川"),
+    ("340055", "340055:亀戸川"),
+    ("340056", "340056:日之浦南川"),
+    ("340057", "340057:日之浦北川"),
+    ("340058", "340058:小日之浦川"),
+    ("340059", "340059:仕形川"),
+    ("340060", "340060:大泊川"),
+    ("340061", "340061:塩谷東川"),
+    ("340062", "340062:塩谷西川"),
+    ("340063", "340063:小島川"),
+    ("340064", "340064:久多田川"),
+    ("340065", "340065:恵川"),
+    ("340066", "340066:新涯川"),
+    ("340067", "340067:原川"),
+    ("340068", "340068:迫田川"),
+    ("340069", "340069:中野川"),
+    ("350001", "350001:麻里布川"),
+    ("350002", "350002:錦川"),
+    ("350003", "350003:平田川"),
+    ("350004", "350004:通津川"),
+    ("350005", "350005:由宇川"),
+    ("350006", "350006:室岡川"),
+    ("350007", "350007:滝川"),
+    ("350008", "350008:石神川"),
+    ("350009", "350009:境川"),
+    ("350010", "350010:片野川"),
+    ("350011", "350011:柳井川"),
+    ("350012", "350012:土穂石川"),
+    ("350013", "350013:荒木川"),
+    ("350014", "350014:大井川"),
+    ("350015", "350015:大内川"),
+    ("350016", "350016:田布施川"),
+    ("350017", "350017:桜川"),
+    ("350018", "350018:光井川"),
+    ("350019", "350019:島田川"),
+    ("350020", "350020:西の河原川"),
+    ("350021", "350021:切戸川"),
+    ("350022", "350022:玉鶴川"),
+    ("350023", "350023:平田川"),
+    ("350024", "350024:末武川"),
+    ("350025", "350025:西光寺川"),
+    ("350026", "350026:東川"),
+    ("350027", "350027:富田川"),
+    ("350028", "350028:夜市川"),
+    ("350029", "350029:新川"),
+    ("350030", "350030:柳川"),
+    ("350031", "350031:馬刀川"),
+    ("350032", "350032:江川"),
+    ("350033", "350033:河内川"),
+    ("350034", "350034:長沢川"),
+    ("350035", "350035:南若川"),
+    ("350036", "350036:椹野川"),
+    ("350037", "350037:幸之江川"),
+    ("350038", "350038:土路石川"),
+    ("350039", "350039:井関川"),
+    ("350040", "350040:植松川"),
+    ("350041", "350041:五反田川"),
+    ("350042", "350042:沢波川"),
+    ("350043", "350043:江頭川"),
+    ("350044", "350044:真締川"),
+    ("350045", "350045:三角田川"),
+    ("350046", "350046:厚東川"),
+    ("350047", "350047:有帆川"),
+    ("350048", "350048:厚狭川"),
+    ("350049", "350049:前場川"),
+    ("350050", "350050:糸根川"),
+    ("350051", "350051:木屋川"),
+    ("350052", "350052:浜田川"),
+    ("350053", "350053:神田川"),
+    ("350054", "350054:新川"),
+    ("350055", "350055:武久川"),
+    ("350056", "350056:明見田川"),
+    ("350057", "350057:綾羅木川"),
+    ("350058", "350058:梶栗川"),
+    ("350059", "350059:友田川"),
+    ("350060", "350060:西田川"),
+    ("350061", "350061:永田川"),
+    ("350062", "350062:草場川"),
+    ("350063", "350063:黒井川"),
+    ("350064", "350064:吉永川"),
+    ("350065", "350065:川棚川"),
+    ("350066", "350066:二見川"),
+    ("350067", "350067:矢玉川"),
+    ("350068", "350068:荒田川"),
+    ("350069", "350069:島戸川"),
+    ("350070", "350070:沖田川"),
+    ("350071", "350071:粟野川"),
+    ("350072", "350072:泉川"),
+    ("350073", "350073:浅井川"),
+    ("350074", "350074:掛淵川"),
+    ("350075", "350075:小田川"),
+    ("350076", "350076:本郷川"),
+    ("350077", "350077:走下川"),
+    ("350078", "350078:深川川"),
+    ("350079", "350079:浅田川"),
+    ("350080", "350080:三隅川"),
+    ("350081", "350081:三見川"),
+    ("350082", "350082:阿武川"),
+    ("350083", "350083:大谷川"),
+    ("350084", "350084:庄屋川"),
+    ("350085", "350085:大井川"),
+    ("350086", "350086:郷川"),
+    ("350087", "350087:木与川"),
+    ("350088", "350088:宇田川"),
+    ("350089", "350089:白須川"),
+    ("350090", "350090:まてかた川"),
+    ("350091", "350091:須佐川"),
+    ("350092", "350092:津田川"),
+    ("350093", "350093:江津川"),
+    ("350094", "350094:田万川"),
+    ("350095", "350095:坂本川"),
+    ("350096", "350096:屋代川"),
+    ("350097", "350097:三蒲川"),
+    ("350098", "350098:椋野本川"),
+    ("350099", "350099:庄地川"),
+    ("350100", "350100:流田川"),
+    ("350101", "350101:津原川"),
+    ("350102", "350102:宮崎川"),
+    ("350103", "350103:山根川"),
+    ("350104", "350104:立田川"),
+    ("350105", "350105:宮川"),
+    ("350106", "350106:藤岡川"),
+    ("350107", "350107:地蔵川"),
+    ("350108", "350108:長野川"),
+    ("350109", "350109:大田川"),
+    ("350110", "350110:竜華川"),
+    ("350111", "350111:大木尾川"),
+    ("350112", "350112:神出川"),
+    ("350113", "350113:上八川"),
+    ("350114", "350114:中村川"),
+    ("350115", "350115:向田川"),
+    ("350116", "350116:走田川"),
+    ("350117", "350117:相の浦川"),
+    ("350118", "350118:水無川"),
+    ("350119", "350119:大谷川"),
+    ("350120", "350120:大踏川"),
+    ("350121", "350121:西光寺川"),
+    ("350122", "350122:梅花川"),
+    ("350123", "350123:末田川"),
+    ("350124", "350124:水尻川"),
+    ("350125", "350125:勘場川"),
+    ("350126", "350126:古川"),
+    ("350127", "350127:堂面川"),
+    ("350128", "350128:手水川"),
+    ("350129", "350129:湯ノ尻川"),
+    ("350130", "350130:清水川"),
+    ("350131", "350131:団平川"),
+    ("350132", "350132:中ノ浦川"),
+    ("350133", "350133:堰戸川"),
+    ("350134", "350134:矢石川"),
+    ("350135", "350135:浜田川"),
+    ("350136", "350136:中川"),
+    ("350137", "350137:印内川"),
+    ("350138", "350138:田中川"),
+    ("350139", "350139:塩田川"),
+    ("350140", "350140:汐入川"),
+    ("350141", "350141:河原川"),
+    ("350142", "350142:新田川"),
+    ("350143", "350143:磯上川"),
+    ("350144", "350144:鳥越川"),
+    ("350145", "350145:水無川"),
+    ("350146", "350146:水尻川"),
+    ("350147", "350147:稲荷川"),
+    ("350148", "350148:堂道川"),
+    ("350149", "350149:犬啼川"),
+    ("350150", "350150:後浜川"),
+    ("350151", "350151:犬鳴川"),
+    ("350152", "350152:湯玉川"),
+    ("350153", "350153:中山川"),
+    ("350154", "350154:本郷川"),
+    ("350155", "350155:桜川"),
+    ("350156", "350156:新川"),
+    ("350157", "350157:十楽川"),
+    ("350158", "350158:下郷川"),
+    ("350159", "350159:江ノ川"),
+    ("350160", "350160:小浜川"),
+    ("350161", "350161:西ヶ浴川"),
+    ("350162", "350162:浜田川"),
+    ("350163", "350163:本城川"),
+    ("350164", "350164:真浜川"),
+    ("350165", "350165:大日比川"),
+    ("350166", "350166:黒瀬川"),
+    ("350167", "350167:田ノ浦川"),
+    ("350168", "350168:中ノ浴川"),
+    ("350169", "350169:日々の木川"),
+    ("350170", "350170:流川"),
+    ("350171", "350171:友信川"),
+    ("350172", "350172:粕田川"),
+    ("350173", "350173:里川"),
+    ("350174", "350174:御開作大道川"),
+    ("350175", "350175:大素川"),
+    ("350176", "350176:保津川"),
+    ("350177", "350177:宮川"),
+    ("350178", "350178:壇具川"),
+    ("350179", "350179:勘場川"),
+    ("350180", "350180:前田川"),
+    ("350181", "350181:大谷川"),
+    ("360001", "360001:大須川"),
+    ("360002", "360002:折野川"),
+    ("360003", "360003:大浦川"),
+    ("360004", "360004:粟田川"),
+    ("360005", "360005:櫛木川"),
+    ("360006", "360006:明神川"),
+    ("360007", "360007:勝浦川"),
+    ("360008", "360008:神田瀬川"),
+    ("360009", "360009:立江川"),
+    ("360010", "360010:太田川"),
+    ("360011", "360011:幾島川"),
+    ("360012", "360012:落合川"),
+    ("360013", "360013:苅屋川"),
+    ("360014", "360014:打樋川"),
+    ("360015", "360015:鵜川"),
+    ("360016", "360016:福井川"),
+    ("360017", "360017:椿川"),
+    ("360018", "360018:伊座利川"),
+    ("360019", "360019:阿部東川"),
+    ("360020", "360020:志和岐川"),
+    ("360021", "360021:田井川"),
+    ("360022", "360022:喜多地川"),
+    ("360023", "360023:木岐川"),
+    ("360024", "360024:田井川"),
+    ("360025", "360025:日和佐川"),
+    ("360026", "360026:奥潟川"),
+    ("360027", "360027:東谷川"),
+    ("360028", "360028:牟岐川"),
+    ("360029", "360029:瀬戸川"),
+    ("360030", "360030:内妻川"),
+    ("360031", "360031:古江川"),
+    ("360032", "360032:鯖瀬川"),
+    ("360033", "360033:粟の浦川"),
+    ("360034", "360034:伊勢田川"),
+    ("360035", "360035:浦上川"),
+    ("360036", "360036:海部川"),
+    ("360037", "360037:江川"),
+    ("360038", "360038:穴喰川"),
+    ("360039", "360039:鳥ヶ丸川"),
+    ("360040", "360040:宿毛谷川"),
+    ("360041", "360041:辷岩水尾川"),
+    ("360042", "360042:須屋川"),
+    ("360043", "360043:魚呑川"),
+    ("360044", "360044:西の地川"),
+    ("360045", "360045:観音寺川"),
+    ("360046", "360046:福良川"),
+    ("360047", "360047:小鯖瀬川"),
+    ("360048", "360048:那佐川"),
+    ("360049", "360049:板取西川"),
+    ("360050", "360050:正梶川"),
+    ("360051", "360051:正梶南谷川"),
+    ("360052", "360052:古目谷川"),
+    ("360053", "360053:島田川"),
+    ("360054", "360054:高島大水尾川"),
+    ("360055", "360055:中島水尾川"),
+    ("360056", "360056:アイノ水尾川"),
+    ("360057", "360057:大溜谷川"),
+    ("370001", "370001:四方堂川"),
+    ("370002", "370002:吉田川"),
+    ("370003", "370003:白坂川"),
+    ("370004", "370004:唐井手川"),
+    ("370005", "370005:柞田川"),
+    ("370006", "370006:一の谷川"),
+    ("370007", "370007:財田川"),
+    ("370008", "370008:苧扱川"),
+    ("370009", "370009:天王川"),
+    ("370010", "370010:江尻川"),
+    ("370011", "370011:田井川"),
+    ("370012", "370012:瀬入川"),
+    ("370013", "370013:高瀬川"),
+    ("370014", "370014:弘田川"),
+    ("370015", "370015:桜川"),
+    ("370016", "370016:金倉川"),
+    ("370017", "370017:西汐入川"),
+    ("370018", "370018:大束川"),
+    ("370019", "370019:綾川"),
+    ("370020", "370020:青海川"),
+    ("370021", "370021:野田川"),
+    ("370022", "370022:玉川"),
+    ("370023", "370023:亀水川"),
+    ("370024", "370024:住吉川"),
+    ("370025", "370025:本津川"),
+    ("370026", "370026:香東川"),
+    ("370027", "370027:摺鉢谷川"),
+    ("370028", "370028:詰田川"),
+    ("370029", "370029:新川"),
+    ("370030", "370030:相引川"),
+    ("370031", "370031:牟礼川"),
+    ("370032", "370032:長者川"),
+    ("370033", "370033:下井手川"),
+    ("370034", "370034:玉浦川"),
+    ("370035", "370035:大橋川"),
+    ("370036", "370036:弁天川"),
+    ("370037", "370037:鴨部川"),
+    ("370038", "370038:滝川"),
+    ("370039", "370039:津田川"),
+    ("370040", "370040:梅川"),
+    ("370041", "370041:梶川"),
+    ("370042", "370042:西代川"),
+    ("370043", "370043:東代川"),
+    ("370044", "370044:大山川"),
+    ("370045", "370045:田尻川"),
+    ("370046", "370046:番屋川"),
+    ("370047", "370047:与田川"),
+    ("370048", "370048:古川"),
+    ("370049", "370049:湊川"),
+    ("370050", "370050:中川"),
+    ("370051", "370051:小海川"),
+    ("370052", "370052:古川"),
+    ("370053", "370053:足谷川"),
+    ("370054", "370054:馬宿川"),
+    ("370055", "370055:菜切川"),
+    ("370056", "370056:坂本川"),
+    ("370057", "370057:大谷川"),
+    ("370058", "370058:小瀬桂川"),
+    ("370059", "370059:皇踏川"),
+    ("370060", "370060:橘川"),
+    ("370061", "370061:桂川"),
+    ("370062", "370062:東川"),
+    ("370063", "370063:吉田川"),
+    ("370064", "370064:伊豆川"),
+    ("370065", "370065:森庄川"),
+    ("370066", "370066:城石川"),
+    ("370067", "370067:向条川"),
+    ("370068", "370068:中筋川"),
+    ("370069", "370069:安田大川"),
+    ("370070", "370070:木庄川"),
+    ("370071", "370071:片城川"),
+    ("370072", "370072:別当川"),
+    ("370073", "370073:明神川"),
+    ("370074", "370074:春田川"),
+    ("370075", "370075:競川"),
+    ("370076", "370076:池田大川"),
+    ("370077", "370077:角田川"),
+    ("370078", "370078:伝法川"),
+    ("370079", "370079:春日川"),
+    ("370080", "370080:新川"),
+    ("370081", "370081:古江上川"),
+    ("370082", "370082:久保谷川"),
+    ("370083", "370083:不動川"),
+    ("370084", "370084:久通川"),
+    ("370085", "370085:南久通川"),
+    ("370086", "370086:大丁場川"),
+    ("370087", "370087:中丁場川"),
+    ("370088", "370088:大谷川"),
+    ("370089", "370089:ハミ谷川"),
+    ("370090", "370090:汐入川"),
+    ("370091", "370091:鎌野川"),
+    ("370092", "370092:シノオ北川"),
+    ("370093", "370093:シノオ南川"),
+    ("370094", "370094:鞍谷川"),
+    ("370095", "370095:コウ尻北川"),
+    ("370096", "370096:コウ尻南川"),
+    ("370097", "370097:宮北川"),
+    ("370098", "370098:房前川"),
+    ("370099", "370099:中川"),
+    ("370100", "370100:タコウ川"),
+    ("370101", "370101:深田川"),
+    ("370102", "370102:ホウエイ川"),
+    ("370103", "370103:西の谷川"),
+    ("370104", "370104:平野川"),
+    ("370105", "370105:入部川"),
+    ("370106", "370106:前川"),
+    ("380001", "380001:脇本川"),
+    ("380002", "380002:惣川"),
+    ("380003", "380003:岡山川"),
+    ("380004", "380004:大駄川"),
+    ("380005", "380005:蓮乗寺川"),
+    ("380006", "380006:僧都川"),
+    ("380007", "380007:長洲川"),
+    ("380008", "380008:神田川"),
+    ("380009", "380009:菊川"),
+    ("380010", "380010:柏川"),
+    ("380011", "380011:中実川"),
+    ("380012", "380012:神田川"),
+    ("380013", "380013:岩松川"),
+    ("380014", "380014:本谷川"),
+    ("380015", "380015:来村川"),
+    ("380016", "380016:神田川"),
+    ("380017", "380017:辰野川"),
+    ("380018", "380018:須賀川"),
+    ("380019", "380019:広見川"),
+    ("380020", "380020:立間尻川"),
+    ("380021", "380021:立間川"),
+    ("380022", "380022:鶴間川"),
+    ("380023", "380023:渡川"),
+    ("380024", "380024:日ノ地川"),
+    ("380025", "380025:成谷川"),
+    ("380026", "380026:東川"),
+    ("380027", "380027:宮崎川"),
+    ("380028", "380028:大川"),
+    ("380029", "380029:皆江大川"),
+    ("380030", "380030:三島川"),
+    ("380031", "380031:谷道川"),
+    ("380032", "380032:朝立川"),
+    ("380033", "380033:東川"),
+    ("380034", "380034:川上大川"),
+    ("380035", "380035:蟻王川"),
+    ("380036", "380036:千丈川"),
+    ("380037", "380037:喜木川"),
+    ("380038", "380038:宮内川"),
+    ("380039", "380039:伊方大川"),
+    ("380040", "380040:伊方新川"),
+    ("380041", "380041:九町新川"),
+    ("380042", "380042:三崎大川"),
+    ("380043", "380043:大谷川"),
+    ("380044", "380044:高茂川"),
+    ("380045", "380045:丸田川"),
+    ("380046", "380046:峯川"),
+    ("380047", "380047:中之谷川"),
+    ("380048", "380048:磯崎里川"),
+    ("380049", "380049:河原川"),
+    ("380050", "380050:横松郷川"),
+    ("380051", "380051:富貴川"),
+    ("380052", "380052:本村川"),
+    ("380053", "380053:池之窪川"),
+    ("380054", "380054:豊田川"),
+    ("380055", "380055:日喰川"),
+    ("380056", "380056:富岡川"),
+    ("380057", "380057:潤住川"),
+    ("380058", "380058:石の久保川"),
+    ("380059", "380059:本谷川"),
+    ("380060", "380060:長薮川"),
+    ("380061", "380061:引地川"),
+    ("380062", "380062:上灘川"),
+    ("380063", "380063:小網川"),
+    ("380064", "380064:出口川"),
+    ("380065", "380065:畑川"),
+    ("380066", "380066:森川"),
+    ("380067", "380067:天神川"),
+    ("380068", "380068:古小川"),
+    ("380069", "380069:大谷川"),
+    ("380070", "380070:長尾谷川"),
+    ("380071", "380071:国近川"),
+    ("380072", "380072:洗地川"),
+    ("380073", "380073:堂ノ元川"),
+    ("380074", "380074:宮前川"),
+    ("380075", "380075:大川"),
+    ("380076", "380076:明神川"),
+    ("380077", "380077:権現川"),
+    ("380078", "380078:郷谷川"),
+    ("380079", "380079:払川"),
+    ("380080", "380080:粟井川"),
+    ("380081", "380081:片平川"),
+    ("380082", "380082:高山川"),
+    ("380083", "380083:河野川"),
+    ("380084", "380084:立岩川"),
+    ("380085", "380085:小久保川"),
+    ("380086", "380086:谷川"),
+    ("380087", "380087:石風呂川"),
+    ("380088", "380088:山本川"),
+    ("380089", "380089:仙波川"),
+    ("380090", "380090:菊間川"),
+    ("380091", "380091:種川"),
+    ("380092", "380092:佐方川"),
+    ("380093", "380093:ナラズ川"),
+    ("380094", "380094:天満川"),
+    ("380095", "380095:山之内川"),
+    ("380096", "380096:宮脇川"),
+    ("380097", "380097:一心川"),
+    ("380098", "380098:品部川"),
+    ("380099", "380099:樋之口川"),
+    ("380100", "380100:中井出川"),
+    ("380101", "380101:浅川"),
+    ("380102", "380102:蒼社川"),
+    ("380103", "380103:竜登川"),
+    ("380104", "380104:銅川"),
+    ("380105", "380105:頓田川"),
+    ("380106", "380106:大川"),
+    ("380107", "380107:中川"),
+    ("380108", "380108:北川"),
+    ("380109", "380109:小向川"),
+    ("380110", "380110:大明神川"),
+    ("380111", "380111:境川"),
+    ("380112", "380112:新川"),
+    ("380113", "380113:大曲川"),
+    ("380114", "380114:崩口川"),
+    ("380115", "380115:一ツ橋川"),
+    ("380116", "380116:広江川"),
+    ("380117", "380117:中山川"),
+    ("380118", "380118:前神寺谷川"),
+    ("380119", "380119:加茂川"),
+    ("380120", "380120:渦井川"),
+    ("380121", "380121:王子川"),
+    ("380122", "380122:東川"),
+    ("380123", "380123:尻無川"),
+    ("380124", "380124:国領川"),
+    ("380125", "380125:落神川"),
+    ("380126", "380126:又野川"),
+    ("380127", "380127:阿島川"),
+    ("380128", "380128:荷内川"),
+    ("380129", "380129:千々ノ木川"),
+    ("380130", "380130:関川"),
+    ("380131", "380131:檜木川"),
+    ("380132", "380132:面白川"),
+    ("380133", "380133:大地川"),
+    ("380134", "380134:隅田川"),
+    ("380135", "380135:鎌谷川"),
+    ("380136", "380136:豊岡川"),
+    ("380137", "380137:西谷川"),
+    ("380138", "380138:樋ノ尾谷川"),
+    ("380139", "380139:大谷川"),
+    ("380140", "380140:井関川"),
+    ("380141", "380141:宮川"),
+    ("380142", "380142:寺川"),
+    ("380143", "380143:海岸寺川"),
+    ("380144", "380144:赤之井川"),
+    ("380145", "380145:川茂川"),
+    ("380146", "380146:契川"),
+    ("380147", "380147:金生川"),
+    ("380148", "380148:三谷川"),
+    ("380149", "380149:千歳川"),
+    ("380150", "380150:大川"),
+    ("380151", "380151:皇ノ川"),
+    ("380152", "380152:天神川"),
+    ("380153", "380153:山狩川"),
+    ("380154", "380154:流内川"),
+    ("380155", "380155:中村川"),
+    ("380156", "380156:沢津川"),
+    ("380157", "380157:引野川"),
+    ("380158", "380158:船越川"),
+    ("380159", "380159:長江川"),
+    ("380160", "380160:小漕川"),
+    ("380161", "380161:樋ノ口川"),
+    ("380162", "380162:中川"),
+    ("380163", "380163:戸板川"),
+    ("380164", "380164:古戸川"),
+    ("380165", "380165:井口本川"),
+    ("380166", "380166:竹下川"),
+    ("380167", "380167:高知川"),
+    ("380168", "380168:明日本川"),
+    ("380169", "380169:宮浦本川"),
+    ("380170", "380170:台本川"),
+    ("380171", "380171:野々江大川"),
+    ("380172", "380172:口総大川"),
+    ("380173", "380173:大川"),
+    ("380174", "380174:余所国東川"),
+    ("380175", "380175:泊北谷川"),
+    ("380176", "380176:相田川"),
+    ("380177", "380177:浜田川"),
+    ("380178", "380178:北谷川"),
+    ("380179", "380179:仁江川"),
+    ("380180", "380180:舫大川"),
+    ("380181", "380181:元ノ浦川"),
+    ("380182", "380182:内之洲川"),
+    ("380183", "380183:畑枝川"),
+    ("380184", "380184:谷川"),
+    ("380185", "380185:名坂川"),
+    ("380186", "380186:重田川"),
+    ("380187", "380187:先目川"),
+    ("380188", "380188:久藤川"),
+    ("380189", "380189:久浦松川"),
+    ("380190", "380190:前浜川"),
+    ("380191", "380191:明神川"),
+    ("380192", "380192:三机大川"),
+    ("380193", "380193:三机東大川"),
+    ("380194", "380194:足成大川"),
+    ("380195", "380195:梢川"),
+    ("380196", "380196:古小川"),
+    ("380197", "380197:三反地川"),
+    ("380198", "380198:長沢川"),
+    ("380199", "380199:太山寺川"),
+    ("380200", "380200:中溝川"),
+    ("380201", "380201:泉川"),
+    ("380202", "380202:三反地川"),
+    ("380203", "380203:名切川"),
+    ("380204", "380204:拝志川"),
+    ("380205", "380205:宮川"),
+    ("380206", "380206:灘川"),
+    ("380207", "380207:影平川"),
+    ("380208", "380208:脇川"),
+    ("380209", "380209:安広川"),
+    ("380210", "380210:隅田川"),
+    ("380211", "380211:錆川"),
+    ("380212", "380212:天神川"),
+    ("380213", "380213:柳川"),
+    ("380214", "380214:本川"),
+    ("380215", "380215:三ツ杭川"),
+    ("380216", "380216:出海川"),
+    ("390001", "390001:小池川"),
+    ("390002", "390002:河内川"),
+    ("390003", "390003:生見川"),
+    ("390004", "390004:相間川"),
+    ("390005", "390005:野根川"),
+    ("390006", "390006:入木川"),
+    ("390007", "390007:佐喜浜川"),
+    ("390008", "390008:弥谷川"),
+    ("390009", "390009:尾崎川"),
+    ("390010", "390010:椎名川"),
+    ("390011", "390011:室津川"),
+    ("390012", "390012:奈良師川"),
+    ("390013", "390013:岩谷川"),
+    ("390014", "390014:元川"),
+    ("390015", "390015:盲堂谷川"),
+    ("390016", "390016:津以谷川"),
+    ("390017", "390017:東の川"),
+    ("390018", "390018:西の川"),
+    ("390019", "390019:黒茂谷川"),
+    ("390020", "390020:羽根川"),
+    ("390021", "390021:加曽谷川"),
+    ("390022", "390022:赤木谷川"),
+    ("390023", "390023:須川川"),
+    ("390024", "390024:長谷川"),
+    ("390025", "390025:奈半利川"),
+    ("390026", "390026:安田川"),
+    ("390027", "390027:東谷川"),
+    ("390028", "390028:名村川"),
+    ("390029", "390029:伊尾木川"),
+    ("390030", "390030:安芸川"),
+    ("390031", "390031:穴内川"),
+    ("390032", "390032:赤野川"),
+    ("390033", "390033:和食川"),
+    ("390034", "390034:夜須川"),
+    ("390035", "390035:香宗川"),
+    ("390036", "390036:東沢川"),
+    ("390037", "390037:十市川"),
+    ("390038", "390038:下田川"),
+    ("390039", "390039:国分川"),
+    ("390040", "390040:鏡川"),
+    ("390041", "390041:竹島川"),
+    ("390042", "390042:西谷川"),
+    ("390043", "390043:新川川"),
+    ("390044", "390044:中口川"),
+    ("390045", "390045:萩谷川"),
+    ("390046", "390046:灰方川"),
+    ("390047", "390047:塩間川"),
+    ("390048", "390048:出見川"),
+    ("390049", "390049:摺木川"),
+    ("390050", "390050:奥浦川"),
+    ("390051", "390051:中の谷川"),
+    ("390052", "390052:桜川"),
+    ("390053", "390053:御手洗川"),
+    ("390054", "390054:イケノウチ川"),
+    ("390055", "390055:新荘川"),
+    ("390056", "390056:安和川"),
+    ("390057", "390057:久礼川"),
+    ("390058", "390058:大坂谷川"),
+    ("390059", "390059:上の加江川"),
+    ("390060", "390060:小矢井賀川"),
+    ("390061", "390061:矢井賀川"),
+    ("390062", "390062:志和川"),
+    ("390063", "390063:後川"),
+    ("390064", "390064:伊与木川"),
+    ("390065", "390065:伊田川"),
+    ("390066", "390066:有井川"),
+    ("390067", "390067:蜷川"),
+    ("390068", "390068:東分川"),
+    ("390069", "390069:湊川"),
+    ("390070", "390070:加持川"),
+    ("390071", "390071:蛎瀬川"),
+    ("390072", "390072:田野浦川"),
+    ("390073", "390073:西間川"),
+    ("390074", "390074:渕の川"),
+    ("390075", "390075:立石川"),
+    ("390076", "390076:布川"),
+    ("390077", "390077:下の加江川"),
+    ("390078", "390078:鍵掛川"),
+    ("390079", "390079:久百百川"),
+    ("390080", "390080:大岐川"),
+    ("390081", "390081:以布利川"),
+    ("390082", "390082:浦尻川"),
+    ("390083", "390083:加久見川"),
+    ("390084", "390084:益野川"),
+    ("390085", "390085:三崎川"),
+    ("390086", "390086:宗呂川"),
+    ("390087", "390087:貝の川川"),
+    ("390088", "390088:小才角川"),
+    ("390089", "390089:才角川"),
+    ("390090", "390090:大浦川"),
+    ("390091", "390091:周防形川"),
+    ("390092", "390092:頭集川"),
+    ("390093", "390093:平山川"),
+    ("390094", "390094:福良川"),
+    ("390095", "390095:伊与野川"),
+    ("390096", "390096:松田川"),
+    ("390097", "390097:与市明川"),
+    ("390098", "390098:東川川"),
+    ("390099", "390099:菅川"),
+    ("390100", "390100:鈴川"),
+    ("390101", "390101:小鈴川"),
+    ("390102", "390102:スガル川"),
+    ("390103", "390103:熊の浦東谷川"),
+    ("390104", "390104:熊の浦西谷川"),
+    ("390105", "390105:久保浦川"),
+    ("390106", "390106:西分川"),
+    ("390107", "390107:深木川"),
+    ("390108", "390108:仁崎川"),
+    ("390109", "390109:塩屋谷川"),
+    ("390110", "390110:菅川"),
+    ("390111", "390111:南の谷川"),
+    ("390112", "390112:大神谷川"),
+    ("390113", "390113:田の浦川"),
+    ("390114", "390114:志沢尾川"),
+    ("400001", "400001:諏訪川（関川）"),
+    ("400002", "400002:大牟田川"),
+    ("400003", "400003:堂面川"),
+    ("400004", "400004:隅川"),
+    ("400005", "400005:福吉川"),
+    ("400006", "400006:加茂川"),
+    ("400007", "400007:一貴山川"),
+    ("400008", "400008:雷山川"),
+    ("400009", "400009:桜井川"),
+    ("400010", "400010:瑞梅寺川"),
+    ("400011", "400011:江ノ口川"),
+    ("400012", "400012:七寺川"),
+    ("400013", "400013:十郎川"),
+    ("400014", "400014:名柄川"),
+    ("400015", "400015:室見川"),
+    ("400016", "400016:樋井川"),
+    ("400017", "400017:那珂川"),
+    ("400018", "400018:御笠川"),
+    ("400019", "400019:多々良川"),
+    ("400020", "400020:唐の原川"),
+    ("400021", "400021:湊川"),
+    ("400022", "400022:大根川"),
+    ("400023", "400023:中川"),
+    ("400024", "400024:西郷川"),
+    ("400025", "400025:釣川"),
+    ("400026", "400026:汐入川"),
+    ("400027", "400027:矢矧川"),
+    ("400028", "400028:金手川"),
+    ("400029", "400029:金山川"),
+    ("400030", "400030:割子川"),
+    ("400031", "400031:撥川"),
+    ("400032", "400032:板櫃川"),
+    ("400033", "400033:紫川"),
+    ("400034", "400034:村中川"),
+    ("400035", "400035:大川"),
+    ("400036", "400036:清滝川"),
+    ("400037", "400037:奧畑川"),
+    ("400038", "400038:竹馬川"),
+    ("400039", "400039:貫川"),
+    ("400040", "400040:長峡川"),
+    ("400041", "400041:今川"),
+    ("400042", "400042:江尻川"),
+    ("400043", "400043:祓川"),
+    ("400044", "400044:音無川"),
+    ("400045", "400045:城井川"),
+    ("400046", "400046:上ノ河内川"),
+    ("400047", "400047:角田川"),
+    ("400048", "400048:中川"),
+    ("400049", "400049:岩岳川"),
+    ("400050", "400050:佐井川"),
+    ("400051", "400051:鹿家川"),
+    ("400052", "400052:柳川"),
+    ("400053", "400053:松末羅漢川"),
+    ("400054", "400054:羅漢川"),
+    ("400055", "400055:殿山川"),
+    ("400056", "400056:寺山川"),
+    ("400057", "400057:冲田川"),
+    ("400058", "400058:岐志新開川"),
+    ("400059", "400059:大坪川"),
+    ("400060", "400060:福ノ浦川"),
+    ("400061", "400061:小金丸川"),
+    ("400062", "400062:幸田川"),
+    ("400063", "400063:汐出川"),
+    ("400064", "400064:汐除川"),
+    ("400065", "400065:香椎川"),
+    ("400067", "400067:奴山川"),
+    ("400068", "400068:天籟寺川"),
+    ("400070", "400070:大野川"),
+    ("400071", "400071:朽網川"),
+    ("400072", "400072:読川"),
+    ("400073", "400073:舟入川"),
+    ("400074", "400074:鈴子川"),
+    ("400075", "400075:城根川"),
+    ("400076", "400076:経済川"),
+    ("400077", "400077:梨ノ木川"),
+    ("400078", "400078:御界川"),
+    ("400079", "400079:猿喰川"),
+    ("400080", "400080:手光今川"),
+    ("400081", "400081:相割川"),
+    ("410001", "410001:佐代川"),
+    ("410002", "410002:楠久川"),
+    ("410003", "410003:後川"),
+    ("410004", "410004:里川"),
+    ("410005", "410005:脇野川"),
+    ("410006", "410006:有田川"),
+    ("410007", "410007:伊万里川"),
+    ("410008", "410008:木須川"),
+    ("410009", "410009:黒塩川"),
+    ("410010", "410010:拝川"),
+    ("410011", "410011:立川"),
+    ("410012", "410012:波多津川"),
+    ("410013", "410013:田野新田川"),
+    ("410014", "410014:座川"),
+    ("410015", "410015:有浦川"),
+    ("410016", "410016:石田川"),
+    ("410017", "410017:浜野浦川"),
+    ("410018", "410018:八田川"),
+    ("410019", "410019:志礼川"),
+    ("410020", "410020:浦町川"),
+    ("410021", "410021:野元川"),
+    ("410022", "410022:潟川"),
+    ("410023", "410023:江頭川"),
+    ("410024", "410024:呼子川"),
+    ("410025", "410025:橋本川"),
+    ("410026", "410026:浦川"),
+    ("410027", "410027:佐志川"),
+    ("410028", "410028:玉島川"),
+    ("410029", "410029:福所江"),
+    ("410030", "410030:福富川"),
+    ("410031", "410031:只江川"),
+    ("410032", "410032:廻里江川"),
+    ("410033", "410033:塩田川"),
+    ("410034", "410034:鹿島川"),
+    ("410035", "410035:石木津川"),
+    ("410036", "410036:浜川"),
+    ("410037", "410037:西葉川"),
+    ("410038", "410038:母ヶ浦川"),
+    ("410039", "410039:音成川"),
+    ("410040", "410040:黒木川"),
+    ("410041", "410041:嘉瀬の浦川"),
+    ("410042", "410042:龍宿浦川"),
+    ("410043", "410043:飯田川"),
+    ("410044", "410044:江福川"),
+    ("410045", "410045:伊福川"),
+    ("410046", "410046:江岡川"),
+    ("410047", "410047:早垣川"),
+    ("410048", "410048:多良川"),
+    ("410049", "410049:嫁川"),
+    ("410050", "410050:糸岐川"),
+    ("410051", "410051:小田川"),
+    ("410052", "410052:波瀬ノ浦河"),
+    ("410053", "410053:休石川"),
+    ("410054", "410054:亀ノ浦川"),
+    ("410055", "410055:田古里川"),
+    ("410056", "410056:河内川"),
+    ("410057", "410057:大泊川"),
+    ("410058", "410058:波瀬川"),
+    ("410060", "410060:長浜川"),
+    ("410061", "410061:見上川"),
+    ("410062", "410062:煤屋川"),
+    ("410063", "410063:辻川"),
+    ("410064", "410064:梅崎川"),
+    ("410065", "410065:大鶴川"),
+    ("410066", "410066:黒形川"),
+    ("410067", "410067:渡瀬川"),
+    ("410068", "410068:鳩川川"),
+    ("410069", "410069:戊辰川"),
+    ("410070", "410070:東塩屋川"),
+    ("410071", "410071:干潟川"),
+    ("410072", "410072:福川内川"),
+    ("420001", "420001:今里川"),
+    ("420002", "420002:船津川"),
+    ("420003", "420003:小深井川"),
+    ("420004", "420004:長里川"),
+    ("420005", "420005:境川"),
+    ("420006", "420006:湯江川"),
+    ("420007", "420007:田島川"),
+    ("420008", "420008:小江川"),
+    ("420009", "420009:深海川"),
+    ("420010", "420010:段堂川"),
+    ("420011", "420011:仁反田川"),
+    ("420012", "420012:有明川"),
+    ("420013", "420013:千鳥川"),
+    ("420014", "420014:山田川"),
+    ("420015", "420015:田川原川"),
+    ("420016", "420016:土井川"),
+    ("420017", "420017:田内川"),
+    ("420018", "420018:船津川"),
+    ("420019", "420019:権現川"),
+    ("420020", "420020:松江川"),
+    ("420021", "420021:西郷川"),
+    ("420022", "420022:神代川"),
+    ("420023", "420023:倉地川"),
+    ("420024", "420024:土黒川"),
+    ("420025", "420025:多比良川"),
+    ("420026", "420026:栗谷川"),
+    ("420027", "420027:湯江川"),
+    ("420028", "420028:西川"),
+    ("420029", "420029:中尾川"),
+    ("420030", "420030:北川"),
+    ("420031", "420031:大手川"),
+    ("420032", "420032:白水川"),
+    ("420033", "420033:新湊川"),
+    ("420034", "420034:鮎川"),
+    ("420035", "420035:水無川"),
+    ("420036", "420036:深江川"),
+    ("420037", "420037:新川"),
+    ("420038", "420038:蒲河川"),
+    ("420039", "420039:有家川"),
+    ("420040", "420040:須川川"),
+    ("420041", "420041:竜石川"),
+    ("420042", "420042:大手川"),
+    ("420043", "420043:有馬川"),
+    ("420044", "420044:葉山川"),
+    ("420045", "420045:田町川"),
+    ("420046", "420046:六反田川"),
+    ("420047", "420047:中谷川"),
+    ("420048", "420048:貝瀬川"),
+    ("420049", "420049:堀川"),
+    ("420050", "420050:小松川"),
+    ("420051", "420051:小津波見川"),
+    ("420052", "420052:津波見川"),
+    ("420053", "420053:川内川"),
+    ("420054", "420054:境川"),
+    ("420055", "420055:千々石川"),
+    ("420056", "420056:唐比川"),
+    ("420057", "420057:有喜川"),
+    ("420058", "420058:江ノ浦川"),
+    ("420059", "420059:田結川"),
+    ("420060", "420060:戸石川"),
+    ("420061", "420061:八郎川"),
+    ("420062", "420062:日見川"),
+    ("420063", "420063:若菜川"),
+    ("420064", "420064:千々川"),
+    ("420065", "420065:大川"),
+    ("420066", "420066:宮崎川"),
+    ("420067", "420067:江川"),
+    ("420068", "420068:黒浜川"),
+    ("420069", "420069:蚊焼大川"),
+    ("420070", "420070:江川川"),
+    ("420071", "420071:鹿尾川"),
+    ("420072", "420072:中島川"),
+    ("420073", "420073:浦上川"),
+    ("420074", "420074:大浦川"),
+    ("420075", "420075:小江川"),
+    ("420076", "420076:手熊川"),
+    ("420077", "420077:式見川"),
+    ("420078", "420078:多以良川"),
+    ("420079", "420079:三重川"),
+    ("420080", "420080:川下川"),
+    ("420081", "420081:黒崎川"),
+    ("420082", "420082:出津川"),
+    ("420083", "420083:神ノ浦川"),
+    ("420084", "420084:雪ノ浦川"),
+    ("420085", "420085:多以良川"),
+    ("420086", "420086:柚木川"),
+    ("420087", "420087:伊佐ノ浦川"),
+    ("420088", "420088:江川内川"),
+    ("420089", "420089:面高川"),
+    ("420090", "420090:高地川"),
+    ("420091", "420091:木場川"),
+    ("420092", "420092:鳥加川"),
+    ("420093", "420093:大明寺川"),
+    ("420094", "420094:大江川"),
+    ("420095", "420095:手崎川"),
+    ("420096", "420096:戸根川"),
+    ("420097", "420097:村松川"),
+    ("420098", "420098:西海川"),
+    ("420099", "420099:子々川川"),
+    ("420100", "420100:時津川"),
+    ("420101", "420101:長与川"),
+    ("420102", "420102:伊木力川"),
+    ("420103", "420103:喜々津川"),
+    ("420104", "420104:久山川"),
+    ("420105", "420105:西大川"),
+    ("420106", "420106:東大川"),
+    ("420107", "420107:鈴田川"),
+    ("420108", "420108:内田川"),
+    ("420109", "420109:大上戸川"),
+    ("420110", "420110:郡川"),
+    ("420111", "420111:よし川"),
+    ("420112", "420112:江の串川"),
+    ("420113", "420113:串川"),
+    ("420114", "420114:千綿川"),
+    ("420115", "420115:彼杵川"),
+    ("420116", "420116:川棚川"),
+    ("420117", "420117:宮村川"),
+    ("420118", "420118:金田川"),
+    ("420119", "420119:小森川"),
+    ("420120", "420120:早岐川"),
+    ("420121", "420121:日宇川"),
+    ("420122", "420122:福石川"),
+    ("420123", "420123:佐世保川"),
+    ("420124", "420124:相浦川"),
+    ("420125", "420125:佐々川"),
+    ("420126", "420126:小佐々川"),
+    ("420127", "420127:竹田川"),
+    ("420128", "420128:上矢岳川"),
+    ("420129", "420129:大加勢川"),
+    ("420130", "420130:樋口川"),
+    ("420131", "420131:鹿町川"),
+    ("420132", "420132:江迎川"),
+    ("420133", "420133:釜田川"),
+    ("420134", "420134:久吹川"),
+    ("420135", "420135:坂瀬川"),
+    ("420136", "420136:竜尾川"),
+    ("420137", "420137:悪太郎川"),
+    ("420138", "420138:志佐川"),
+    ("420139", "420139:調川川"),
+    ("420140", "420140:今福川"),
+    ("420141", "420141:人柱川"),
+    ("420142", "420142:市小木川"),
+    ("420143", "420143:猪ノ木川"),
+    ("420144", "420144:大川原川"),
+    ("420145", "420145:鰐川"),
+    ("420146", "420146:浦ノ川"),
+    ("420147", "420147:一ノ川"),
+    ("420148", "420148:前田川"),
+    ("420149", "420149:福江川"),
+    ("420150", "420150:増田川"),
+    ("420151", "420151:田尾川"),
+    ("420152", "420152:山手川"),
+    ("420153", "420153:丸子川"),
+    ("420154", "420154:小川川"),
+    ("420155", "420155:中須川"),
+    ("420156", "420156:荒川川"),
+    ("420157", "420157:丹奈川"),
+    ("420158", "420158:佐野原川"),
+    ("420159", "420159:相河川"),
+    ("420160", "420160:釣道川"),
+    ("420161", "420161:宮ノ川"),
+    ("420162", "420162:大川"),
+    ("420163", "420163:大川"),
+    ("420164", "420164:檮ノ木川"),
+    ("420165", "420165:大左右川"),
+    ("420166", "420166:谷江川"),
+    ("420167", "420167:梅の木川"),
+    ("420168", "420168:幡鉾川"),
+    ("420169", "420169:永田川"),
+    ("420170", "420170:刈田院川"),
+    ("420171", "420171:百合畑川"),
+    ("420172", "420172:比田勝川"),
+    ("420173", "420173:玖須川"),
+    ("420174", "420174:舟志川"),
+    ("420175", "420175:琴川"),
+    ("420176", "420176:芦見川"),
+    ("420177", "420177:一重川"),
+    ("420178", "420178:小鹿川"),
+    ("420179", "420179:志多賀川"),
+    ("420180", "420180:佐賀川"),
+    ("420181", "420181:駄道川"),
+    ("420182", "420182:曽川"),
+    ("420183", "420183:け知川"),
+    ("420184", "420184:小浦川"),
+    ("420185", "420185:阿須川"),
+    ("420186", "420186:厳原本川"),
+    ("420187", "420187:久田川"),
+    ("420188", "420188:久和川"),
+    ("420189", "420189:浅藻川"),
+    ("420190", "420190:瀬川"),
+    ("420191", "420191:久根川"),
+    ("420192", "420192:椎根川"),
+    ("420193", "420193:佐須川"),
+    ("420194", "420194:阿連川"),
+    ("420195", "420195:今里川"),
+    ("420196", "420196:加志川"),
+    ("420197", "420197:洲藻川"),
+    ("420198", "420198:仁位川"),
+    ("420199", "420199:卯麦川"),
+    ("420200", "420200:田川"),
+    ("420201", "420201:吉田川"),
+    ("420202", "420202:三根川"),
+    ("420203", "420203:鹿見川"),
+    ("420204", "420204:仁田川"),
+    ("420205", "420205:伊奈川"),
+    ("420206", "420206:志田留川"),
+    ("420207", "420207:佐護川"),
+    ("420208", "420208:佐須奈川"),
+    ("420209", "420209:大浦川"),
+    ("420210", "420210:豊川"),
+    ("420211", "420211:江端川"),
+    ("420212", "420212:神曽根川"),
+    ("420213", "420213:鏡川"),
+    ("420214", "420214:安満川"),
+    ("420215", "420215:中川"),
+    ("420216", "420216:古田川"),
+    ("420217", "420217:敷佐川"),
+    ("420218", "420218:中津良川"),
+    ("420219", "420219:神の川"),
+    ("420220", "420220:東流川"),
+    ("420221", "420221:西流川"),
+    ("420222", "420222:谷郷川"),
+    ("420223", "420223:早見川"),
+    ("420224", "420224:尾向川"),
+    ("420225", "420225:西昭和開川"),
+    ("420226", "420226:東昭和開川"),
+    ("420227", "420227:尾茂川"),
+    ("420228", "420228:たゆび川"),
+    ("420229", "420229:引無田川"),
+    ("420230", "420230:永無田川"),
+    ("420231", "420231:西浦川"),
+    ("420232", "420232:東川"),
+    ("420233", "420233:苧扱川"),
+    ("420234", "420234:白浜川"),
+    ("420235", "420235:木下川"),
+    ("420236", "420236:北土瀬戸川"),
+    ("420237", "420237:土瀬戸川"),
+    ("420238", "420238:水ノ浦川"),
+    ("420239", "420239:板引川"),
+    ("420240", "420240:清水川"),
+    ("420241", "420241:金浜川"),
+    ("420242", "420242:木指川"),
+    ("420243", "420243:鬢串川"),
+    ("420244", "420244:古賀川"),
+    ("420245", "420245:藤田尾川"),
+    ("420246", "420246:六軒川"),
+    ("420247", "420247:川原古川"),
+    ("420248", "420248:池田川"),
+    ("420249", "420249:金取川"),
+    ("420250", "420250:熊川"),
+    ("420251", "420251:熊川"),
+    ("420252", "420252:松尾川"),
+    ("420253", "420253:新川"),
+    ("420254", "420254:曲川"),
+    ("420255", "420255:向川"),
+    ("420256", "420256:深浦川"),
+    ("420257", "420257:出口川"),
+    ("420258", "420258:浜田川"),
+    ("420259", "420259:野々串川"),
+    ("420260", "420260:以下宿川"),
+    ("420261", "420261:小家川"),
+    ("420262", "420262:相川川"),
+    ("420263", "420263:樫山川"),
+    ("420264", "420264:菰立川"),
+    ("420265", "420265:綿打川"),
+    ("420266", "420266:柚子の川"),
+    ("420267", "420267:俵頭川"),
+    ("420268", "420268:風早川"),
+    ("420269", "420269:四戸ノ川"),
+    ("420270", "420270:戸根原川"),
+    ("420271", "420271:久留里川"),
+    ("420272", "420272:四釜川"),
+    ("420273", "420273:オーコ川"),
+    ("420274", "420274:満永川"),
+    ("420275", "420275:大堂川"),
+    ("420276", "420276:長峰川"),
+    ("420277", "420277:大浦川"),
+    ("420278", "420278:五十石川"),
+    ("420279", "420279:百石川"),
+    ("420280", "420280:浦川内川"),
+    ("420281", "420281:蓼根原川"),
+    ("420282", "420282:田中川"),
+    ("420283", "420283:浮津川"),
+    ("420284", "420284:野副川"),
+    ("420285", "420285:川内川"),
+    ("420286", "420286:東園川"),
+    ("420287", "420287:木床川"),
+    ("420288", "420288:串ノ浦川"),
+    ("420289", "420289:橋ノ浦川"),
+    ("420290", "420290:白井川"),
+    ("420291", "420291:大音琴川"),
+    ("420292", "420292:小音琴川"),
+    ("420293", "420293:田の頭川"),
+    ("420294", "420294:有福川"),
+    ("420295", "420295:真申川"),
+    ("420296", "420296:神林川"),
+    ("420297", "420297:浦川内川"),
+    ("420298", "420298:歌ヶ浦川"),
+    ("420299", "420299:本ヶ浦川"),
+    ("420300", "420300:大屋川"),
+    ("420301", "420301:宮ノ前川"),
+    ("420302", "420302:加椎川"),
+    ("420303", "420303:白浜川"),
+    ("420304", "420304:平尾川"),
+    ("420305", "420305:長羽田川"),
+    ("420306", "420306:田部手川"),
+    ("420307", "420307:上ノ川"),
+    ("420308", "420308:七岳川"),
+    ("420309", "420309:後諸津川"),
+    ("420310", "420310:前諸津川"),
+    ("420311", "420311:芦辺川"),
+    ("420313", "420313:納屋場川"),
+    ("420314", "420314:椎ノ木川"),
+    ("420315", "420315:門野田川"),
+    ("420316", "420316:梅津川"),
+    ("420317", "420317:潮川"),
+    ("420318", "420318:宇戸川"),
+    ("420319", "420319:榎川川"),
+    ("420320", "420320:浦海川"),
+    ("420321", "420321:小浦海川"),
+    ("420322", "420322:御手洗川"),
+    ("420323", "420323:杉山川"),
+    ("420324", "420324:千馬川"),
+    ("420325", "420325:和板川"),
+    ("420326", "420326:糠川"),
+    ("420327", "420327:佐保川"),
+    ("420328", "420328:大綱川"),
+    ("420329", "420329:徳万川"),
+    ("420330", "420330:長谷川"),
+    ("420331", "420331:水ノ浦川"),
+    ("420332", "420332:鯨ノ浦川"),
+    ("420333", "420333:轟川"),
+    ("420334", "420334:船越川"),
+    ("420335", "420335:谷川"),
+    ("420336", "420336:渡瀬川"),
+    ("420337", "420337:保辺谷川"),
+    ("420338", "420338:和歌ノ浦川"),
+    ("420339", "420339:筒井川"),
+    ("420340", "420340:笛吹川"),
+    ("420341", "420341:八竜川"),
+    ("420342", "420342:箕坪川"),
+    ("420343", "420343:福浦川"),
+    ("420344", "420344:玖島川"),
+    ("420345", "420345:変配川"),
+    ("420346", "420346:餅の浜川"),
+    ("430001", "430001:境川"),
+    ("430002", "430002:袋川"),
+    ("430003", "430003:坂口川"),
+    ("430004", "430004:水俣川"),
+    ("430005", "430005:小津奈木川"),
+    ("430006", "430006:津奈木川"),
+    ("430007", "430007:女島川"),
+    ("430008", "430008:湯の浦川"),
+    ("430009", "430009:佐敷川"),
+    ("430010", "430010:小田浦川"),
+    ("430011", "430011:宮の浦川"),
+    ("430012", "430012:田浦川"),
+    ("430013", "430013:赤松川"),
+    ("430014", "430014:二見川"),
+    ("430015", "430015:流藻川"),
+    ("430016", "430016:水無川"),
+    ("430017", "430017:大鞘川"),
+    ("430018", "430018:鏡川"),
+    ("430019", "430019:氷川"),
+    ("430020", "430020:八間川"),
+    ("430021", "430021:砂川"),
+    ("430022", "430022:八枚戸川"),
+    ("430023", "430023:五丁川"),
+    ("430024", "430024:大野川"),
+    ("430025", "430025:浦上川"),
+    ("430026", "430026:長崎川"),
+    ("430027", "430027:西浦川"),
+    ("430028", "430028:大見川"),
+    ("430029", "430029:底江川"),
+    ("430030", "430030:里浦川"),
+    ("430031", "430031:郡浦川"),
+    ("430032", "430032:波多川"),
+    ("430033", "430033:網田川"),
+    ("430034", "430034:網津川"),
+    ("430035", "430035:千間江湖"),
+    ("430036", "430036:除川"),
+    ("430037", "430037:坪井川"),
+    ("430038", "430038:河内川"),
+    ("430039", "430039:唐人川"),
+    ("430040", "430040:境川"),
+    ("430041", "430041:行末川"),
+    ("430042", "430042:宮崎川"),
+    ("430043", "430043:菜切川"),
+    ("430044", "430044:浦川"),
+    ("430045", "430045:関川"),
+    ("430046", "430046:志岐川"),
+    ("430047", "430047:上津深江川"),
+    ("430048", "430048:松原川"),
+    ("430049", "430049:内野川"),
+    ("430050", "430050:中洲川"),
+    ("430051", "430051:隅田川"),
+    ("430052", "430052:広瀬川"),
+    ("430053", "430053:町山口川"),
+    ("430054", "430054:亀川"),
+    ("430055", "430055:下津浦川"),
+    ("430056", "430056:上津浦川"),
+    ("430057", "430057:楠甫川"),
+    ("430058", "430058:教良木川"),
+    ("430059", "430059:今泉川"),
+    ("430060", "430060:合津川"),
+    ("430061", "430061:七ツ割川"),
+    ("430062", "430062:岩下川"),
+    ("430063", "430063:目玉川"),
+    ("430064", "430064:浦川"),
+    ("430065", "430065:河内川"),
+    ("430066", "430066:白洲川"),
+    ("430067", "430067:方原川"),
+    ("430068", "430068:大宮地川"),
+    ("430069", "430069:流合川"),
+    ("430070", "430070:中田川"),
+    ("430071", "430071:久玉川"),
+    ("430072", "430072:桜川"),
+    ("430073", "430073:亀浦川"),
+    ("430074", "430074:早浦川"),
+    ("430075", "430075:路木川"),
+    ("430076", "430076:一町田川"),
+    ("430077", "430077:今富川"),
+    ("430078", "430078:大江川"),
+    ("430079", "430079:高浜川"),
+    ("430080", "430080:下津深江川"),
+    ("430081", "430081:都呂々川"),
+    ("430082", "430082:白ヶ浦川"),
+    ("430083", "430083:大泊川"),
+    ("430084", "430084:新立川"),
+    ("430085", "430085:赤崎川"),
+    ("430086", "430086:日当川"),
+    ("430087", "430087:塩屋川"),
+    ("430088", "430088:永田川"),
+    ("430089", "430089:平国川"),
+    ("430090", "430090:竹迫川"),
+    ("430091", "430091:鶴木山川"),
+    ("430092", "430092:塩屋浦川"),
+    ("430093", "430093:桂原川"),
+    ("430094", "430094:黒田川"),
+    ("430095", "430095:於呂口川"),
+    ("430096", "430096:浦ノ谷川"),
+    ("430097", "430097:大口川"),
+    ("430098", "430098:手場川"),
+    ("430099", "430099:金桁川"),
+    ("430100", "430100:古永川"),
+    ("430101", "430101:大田尾川"),
+    ("430102", "430102:矢答川"),
+    ("430103", "430103:赤瀬川"),
+    ("430104", "430104:平岩川"),
+    ("430105", "430105:小松川"),
+    ("430106", "430106:長浜川"),
+    ("430107", "430107:境谷"),
+    ("430108", "430108:湯の浦川"),
+    ("430109", "430109:呑崎水路"),
+    ("430110", "430110:宮内川"),
+    ("430111", "430111:阿村川"),
+    ("430112", "430112:塩屋川"),
+    ("430113", "430113:西河内川"),
+    ("430114", "430114:中浦川"),
+    ("430115", "430115:柿戸川"),
+    ("430116", "430116:南平川"),
+    ("430117", "430117:棚底川"),
+    ("430118", "430118:浦下川"),
+    ("430119", "430119:芋洗川"),
+    ("430120", "430120:塚原川"),
+    ("430121", "430121:才津原川"),
+    ("430122", "430122:坂田川"),
+    ("430123", "430123:宮田川"),
+    ("430124", "430124:砥石川"),
+    ("430125", "430125:梅ノ木川"),
+    ("430126", "430126:久木山川"),
+    ("430127", "430127:野田川"),
+    ("430128", "430128:山下川"),
+    ("430129", "430129:Ｎｏ．１梅津川"),
+    ("430130", "430130:Ｎｏ．２梅津川"),
+    ("430131", "430131:塩浜川"),
+    ("430132", "430132:棚田川"),
+    ("430133", "430133:小峰川"),
+    ("430134", "430134:宇土川"),
+    ("430135", "430135:田導寺川"),
+    ("430136", "430136:西高根川"),
+    ("430137", "430137:本郷川"),
+    ("430138", "430138:前田川"),
+    ("430139", "430139:中ノ迫川"),
+    ("430140", "430140:山ノ浦川"),
+    ("430141", "430141:中ノ浦川"),
+    ("430142", "430142:吉田川"),
+    ("430143", "430143:里浦川"),
+    ("430144", "430144:砂子川"),
+    ("430145", "430145:古江川"),
+    ("430146", "430146:小島川"),
+    ("430147", "430147:潟川"),
+    ("430148", "430148:小田床川"),
+    ("430149", "430149:小松川"),
+    ("430150", "430150:三会川"),
+    ("430151", "430151:小路川"),
+    ("430152", "430152:二江川"),
+    ("430153", "430153:引坂川"),
+    ("430154", "430154:宮津川"),
+    ("430155", "430155:鬼池川"),
+    ("430156", "430156:南川"),
+    ("440001", "440001:蛎瀬川"),
+    ("440002", "440002:自見川"),
+    ("440003", "440003:舞手川"),
+    ("440004", "440004:犬丸川"),
+    ("440005", "440005:伊呂波川"),
+    ("440006", "440006:山城川"),
+    ("440007", "440007:黒川"),
+    ("440008", "440008:駅館川"),
+    ("440009", "440009:寄藻川"),
+    ("440010", "440010:桂川"),
+    ("440011", "440011:石部川"),
+    ("440012", "440012:広瀬川"),
+    ("440013", "440013:赤坂川"),
+    ("440014", "440014:真玉川"),
+    ("440015", "440015:臼野川"),
+    ("440016", "440016:北川"),
+    ("440017", "440017:堅来川"),
+    ("440018", "440018:羽根川"),
+    ("440019", "440019:竹田川"),
+    ("440020", "440020:見目川"),
+    ("440021", "440021:竹田津川"),
+    ("440022", "440022:伊美川"),
+    ("440023", "440023:櫛来川"),
+    ("440024", "440024:岐部川"),
+    ("440025", "440025:向田川"),
+    ("440026", "440026:来浦川"),
+    ("440027", "440027:深江川"),
+    ("440028", "440028:堅来川"),
+    ("440029", "440029:富来川"),
+    ("440030", "440030:北江川"),
+    ("440031", "440031:田深川"),
+    ("440032", "440032:清流川"),
+    ("440033", "440033:治郎丸川"),
+    ("440034", "440034:重綱川"),
+    ("440035", "440035:内田川"),
+    ("440036", "440036:武蔵川"),
+    ("440037", "440037:小城川"),
+    ("440038", "440038:大海田川"),
+    ("440039", "440039:安岐川"),
+    ("440040", "440040:古町川"),
+    ("440041", "440041:住吉川"),
+    ("440042", "440042:江頭川"),
+    ("440043", "440043:高山川"),
+    ("440044", "440044:八坂川"),
+    ("440045", "440045:丸尾川"),
+    ("440046", "440046:金井田川"),
+    ("440047", "440047:三川"),
+    ("440048", "440048:江上川"),
+    ("440049", "440049:新川"),
+    ("440050", "440050:春木川"),
+    ("440051", "440051:境川"),
+    ("440052", "440052:朝見川"),
+    ("440053", "440053:祓川"),
+    ("440054", "440054:住吉川"),
+    ("440055", "440055:日美天川"),
+    ("440056", "440056:丹生川"),
+    ("440057", "440057:本田川"),
+    ("440058", "440058:小猫川"),
+    ("440059", "440059:湊川"),
+    ("440060", "440060:志生木川"),
+    ("440061", "440061:有屋川"),
+    ("440062", "440062:佐志生川"),
+    ("440063", "440063:下の江川"),
+    ("440064", "440064:熊崎川"),
+    ("440065", "440065:末広川"),
+    ("440066", "440066:臼杵川"),
+    ("440067", "440067:海添川"),
+    ("440068", "440068:堅浦川"),
+    ("440069", "440069:徳浦川"),
+    ("440070", "440070:青江川"),
+    ("440071", "440071:津久見川"),
+    ("440072", "440072:千怒川"),
+    ("440073", "440073:暁嵐川"),
+    ("440074", "440074:狩生川"),
+    ("440075", "440075:戸穴川"),
+    ("440076", "440076:吹浦川"),
+    ("440077", "440077:色利川"),
+    ("440078", "440078:畑野浦川"),
+    ("440079", "440079:楠本川"),
+    ("440080", "440080:正金川"),
+    ("440081", "440081:河内川"),
+    ("440082", "440082:猪串川"),
+    ("440083", "440083:森崎川"),
+    ("440084", "440084:芹川"),
+    ("440085", "440085:江川"),
+    ("440086", "440086:大内川"),
+    ("440087", "440087:波当津川"),
+    ("440088", "440088:桂区川"),
+    ("440089", "440089:正添川"),
+    ("440090", "440090:横泊川"),
+    ("440091", "440091:板敷川"),
+    ("440092", "440092:西堅来川"),
+    ("440093", "440093:松津川"),
+    ("440094", "440094:高汐川"),
+    ("440095", "440095:堀切川"),
+    ("440096", "440096:東高島川"),
+    ("440097", "440097:天村川"),
+    ("440098", "440098:年田川"),
+    ("440099", "440099:観音川"),
+    ("440100", "440100:深江江上川"),
+    ("440101", "440101:堀川"),
+    ("440102", "440102:曲木川"),
+    ("440103", "440103:宮川"),
+    ("440104", "440104:友安川"),
+    ("440105", "440105:金谷川"),
+    ("440106", "440106:志手川"),
+    ("440107", "440107:トヤノ口川"),
+    ("440108", "440108:江口川"),
+    ("440109", "440109:井ノ谷川"),
+    ("440110", "440110:桑ノ浦川"),
+    ("440111", "440111:水ヶ浦川"),
+    ("440112", "440112:日の浦川"),
+    ("440113", "440113:帆波浦川"),
+    ("440114", "440114:西の浦川"),
+    ("440115", "440115:殿ノ上川"),
+    ("440116", "440116:中越川"),
+    ("440117", "440117:大川"),
+    ("440118", "440118:川尻川"),
+    ("440119", "440119:梶寄川"),
+    ("440120", "440120:江頭川"),
+    ("450001", "450001:市振川"),
+    ("450002", "450002:古江川"),
+    ("450003", "450003:中港川"),
+    ("450004", "450004:阿蘇川"),
+    ("450005", "450005:熊野江川"),
+    ("450006", "450006:須美江川"),
+    ("450007", "450007:浦尻川"),
+    ("450008", "450008:沖田川"),
+    ("450009", "450009:浦上川"),
+    ("450010", "450010:丸バエ川"),
+    ("450011", "450011:鳴子川"),
+    ("450012", "450012:五十鈴川"),
+    ("450013", "450013:庄手川"),
+    ("450014", "450014:亀崎川"),
+    ("450015", "450015:塩見川"),
+    ("450016", "450016:赤岩川"),
+    ("450017", "450017:耳川"),
+    ("450018", "450018:石並川"),
+    ("450019", "450019:水無川"),
+    ("450020", "450020:心見川"),
+    ("450021", "450021:都農川"),
+    ("450022", "450022:名貫川"),
+    ("450023", "450023:平田川"),
+    ("450024", "450024:一ツ瀬川"),
+    ("450025", "450025:石崎川"),
+    ("450026", "450026:清武川"),
+    ("450027", "450027:加江田川"),
+    ("450028", "450028:知福川"),
+    ("450029", "450029:突浪川"),
+    ("450030", "450030:内海川"),
+    ("450031", "450031:野島川"),
+    ("450032", "450032:小内海川"),
+    ("450033", "450033:鶯巣川"),
+    ("450034", "450034:伊比井川"),
+    ("450035", "450035:富土川"),
+    ("450036", "450036:宮浦川"),
+    ("450037", "450037:手洗川"),
+    ("450038", "450038:大浦川"),
+    ("450039", "450039:風田川"),
+    ("450040", "450040:広渡川"),
+    ("450041", "450041:隈谷川"),
+    ("450042", "450042:細田川"),
+    ("450043", "450043:潟上川"),
+    ("450044", "450044:贄波川"),
+    ("450045", "450045:市木川"),
+    ("450046", "450046:大納川"),
+    ("450047", "450047:宮之浦川"),
+    ("450048", "450048:都井川"),
+    ("450049", "450049:永田川"),
+    ("450050", "450050:本城川"),
+    ("450051", "450051:千野川"),
+    ("450052", "450052:福島川"),
+    ("450053", "450053:甫場谷川"),
+    ("450054", "450054:太田谷川"),
+    ("450055", "450055:小目井川"),
+    ("450056", "450056:小吹毛井川"),
+    ("460001", "460001:前川"),
+    ("460002", "460002:安楽川"),
+    ("460003", "460003:菱田川"),
+    ("460004", "460004:田原川"),
+    ("460005", "460005:広瀬川"),
+    ("460006", "460006:久保田川"),
+    ("460007", "460007:郡川"),
+    ("460008", "460008:大泊川"),
+    ("460009", "460009:島泊川"),
+    ("460010", "460010:上之園川"),
+    ("460011", "460011:雄川"),
+    ("460012", "460012:堂之元川"),
+    ("460013", "460013:神ノ川"),
+    ("460014", "460014:浜田川"),
+    ("460015", "460015:高須川"),
+    ("460016", "460016:小谷川"),
+    ("460017", "460017:本城川"),
+    ("460018", "460018:河崎川"),
+    ("460019", "460019:中俣川"),
+    ("460020", "460020:持木川"),
+    ("460021", "460021:野尻川"),
+    ("460022", "460022:松崎川"),
+    ("460023", "460023:髙橋川"),
+    ("460024", "460024:検校川"),
+    ("460025", "460025:天降川"),
+    ("460026", "460026:清水川"),
+    ("460027", "460027:日木山川"),
+    ("460028", "460028:網掛川"),
+    ("460029", "460029:別府川"),
+    ("460030", "460030:思川"),
+    ("460031", "460031:稲荷川"),
+    ("460032", "460032:甲突川"),
+    ("460033", "460033:新川"),
+    ("460034", "460034:脇田川"),
+    ("460035", "460035:永田川"),
+    ("460036", "460036:和田川"),
+    ("460037", "460037:愛宕川"),
+    ("460038", "460038:八幡川"),
+    ("460039", "460039:貝底川"),
+    ("460040", "460040:田貫川"),
+    ("460041", "460041:湊川"),
+    ("460042", "460042:二反田川"),
+    ("460043", "460043:新川"),
+    ("460044", "460044:集川"),
+    ("460045", "460045:馬渡川"),
+    ("460046", "460046:石垣川"),
+    ("460047", "460047:加治佐川"),
+    ("460048", "460048:尻無川"),
+    ("460049", "460049:花渡川"),
+    ("460050", "460050:馬追川"),
+    ("460051", "460051:泊川"),
+    ("460052", "460052:久志川"),
+    ("460053", "460053:秋目川"),
+    ("460054", "460054:大当川"),
+    ("460055", "460055:祓川"),
+    ("460056", "460056:笠石川"),
+    ("460057", "460057:大浦川"),
+    ("460058", "460058:遠田川"),
+    ("460059", "460059:相星川"),
+    ("460060", "460060:万之瀬川"),
+    ("460061", "460061:伊作川"),
+    ("460062", "460062:小野川"),
+    ("460063", "460063:永吉川"),
+    ("460064", "460064:大川"),
+    ("460065", "460065:神之川"),
+    ("460066", "460066:江口川"),
+    ("460067", "460067:大里川"),
+    ("460068", "460068:八房川"),
+    ("460069", "460069:五反田川"),
+    ("460070", "460070:荒川"),
+    ("460071", "460071:平身川"),
+    ("460072", "460072:長川"),
+    ("460073", "460073:浜田川"),
+    ("460074", "460074:中津川"),
+    ("460075", "460075:小島川"),
+    ("460076", "460076:土川川"),
+    ("460077", "460077:轟川"),
+    ("460078", "460078:原田川"),
+    ("460079", "460079:湯田川"),
+    ("460080", "460080:西方川"),
+    ("460081", "460081:汐見川"),
+    ("460082", "460082:城川内川"),
+    ("460083", "460083:指江川"),
+    ("460084", "460084:小浜川"),
+    ("460085", "460085:浦底川"),
+    ("460086", "460086:尻無川"),
+    ("460087", "460087:大川"),
+    ("460088", "460088:大橋川"),
+    ("460089", "460089:高松川"),
+    ("460090", "460090:折口川"),
+    ("460091", "460091:新田川"),
+    ("460092", "460092:江内川"),
+    ("460093", "460093:高尾野川"),
+    ("460094", "460094:米之津川"),
+    ("460095", "460095:西京川"),
+    ("460096", "460096:湊川"),
+    ("460097", "460097:川脇川"),
+    ("460098", "460098:向井川"),
+    ("460099", "460099:熊野川"),
+    ("460100", "460100:阿獄川"),
+    ("460101", "460101:大浦川"),
+    ("460102", "460102:宮瀬川"),
+    ("460103", "460103:郡川"),
+    ("460104", "460104:鹿鳴川"),
+    ("460105", "460105:古川川"),
+    ("460106", "460106:苦浜川"),
+    ("460107", "460107:甲女川"),
+    ("460108", "460108:城之川"),
+    ("460109", "460109:安房川"),
+    ("460110", "460110:中間川"),
+    ("460111", "460111:栗生川"),
+    ("460112", "460112:獄之川"),
+    ("460113", "460113:永田川"),
+    ("460114", "460114:一湊川"),
+    ("460115", "460115:志戸子川"),
+    ("460116", "460116:宮之浦川"),
+    ("460117", "460117:浦上川"),
+    ("460118", "460118:芦花部川"),
+    ("460119", "460119:秋名川"),
+    ("460120", "460120:嘉渡川"),
+    ("460121", "460121:宮久田川"),
+    ("460122", "460122:前田川"),
+    ("460123", "460123:屋仁川"),
+    ("460124", "460124:佐仁川"),
+    ("460125", "460125:大美川"),
+    ("460126", "460126:大川"),
+    ("460127", "460127:金久田川"),
+    ("460128", "460128:川内川"),
+    ("460129", "460129:住用川"),
+    ("460130", "460130:役勝川"),
+    ("460131", "460131:山間川"),
+    ("460132", "460132:勝浦川"),
+    ("460133", "460133:阿木名川"),
+    ("460134", "460134:仲里川"),
+    ("460135", "460135:仲金久川"),
+    ("460136", "460136:篠川川"),
+    ("460137", "460137:河内川"),
+    ("460138", "460138:名音川"),
+    ("460139", "460139:大和川"),
+    ("460140", "460140:知名瀬川"),
+    ("460141", "460141:小宿大川"),
+    ("460142", "460142:新川"),
+    ("460143", "460143:奧川"),
+    ("460144", "460144:石橋川"),
+    ("460145", "460145:余多川"),
+    ("460146", "460146:港川"),
+    ("460147", "460147:万田川"),
+    ("460148", "460148:下田川"),
+    ("460149", "460149:伊宝川"),
+    ("460150", "460150:名田川"),
+    ("460151", "460151:亀徳川"),
+    ("460152", "460152:大瀬川"),
+    ("460153", "460153:面縄川"),
+    ("460154", "460154:ウワナル川"),
+    ("460155", "460155:秋利神川"),
+    ("460156", "460156:真瀬名川"),
+    ("460157", "460157:湾屋川"),
+    ("460158", "460158:南川"),
+    ("460159", "460159:港川"),
+    ("460160", "460160:夏井川"),
+    ("460161", "460161:天神川"),
+    ("460162", "460162:水尻川"),
+    ("460163", "460163:小野川"),
+    ("460164", "460164:辺塚川"),
+    ("460165", "460165:古里川"),
+    ("460166", "460166:大川"),
+    ("460167", "460167:長谷川"),
+    ("460168", "460168:石走川"),
+    ("460169", "460169:下園川"),
+    ("460170", "460170:炭屋川"),
+    ("460171", "460171:塩屋川"),
+    ("460172", "460172:苙川"),
+    ("460173", "460173:立神川"),
+    ("460174", "460174:二川"),
+    ("460175", "460175:天目石川"),
+    ("460176", "460176:小浜川"),
+    ("460177", "460177:大浜川"),
+    ("460178", "460178:阿潟浜川"),
+    ("460179", "460179:丸峯川"),
+    ("460180", "460180:城元川"),
+    ("460181", "460181:永目川"),
+    ("460182", "460182:荒平川"),
+    ("460183", "460183:小薗川"),
+    ("460184", "460184:鎌脇川"),
+    ("460185", "460185:井手尻川"),
+    ("460186", "460186:馬形川"),
+    ("460187", "460187:塩入川"),
+    ("460188", "460188:赤迫川"),
+    ("460189", "460189:尾迫川"),
+    ("460190", "460190:俣江川"),
+    ("460191", "460191:北迫川"),
+    ("460192", "460192:城之下川"),
+    ("460193", "460193:飛岡川"),
+    ("460194", "460194:鶴田川"),
+    ("460195", "460195:小浜川"),
+    ("460196", "460196:宮崎川"),
+    ("460197", "460197:仏石川"),
+    ("460198", "460198:大迫川"),
+    ("460199", "460199:松ヶ崎川"),
+    ("460200", "460200:辺田川"),
+    ("460201", "460201:平野川"),
+    ("460202", "460202:観音川"),
+    ("460203", "460203:川原川"),
+    ("460204", "460204:中浜川"),
+    ("460205", "460205:深港川"),
+    ("460206", "460206:境川"),
+    ("460207", "460207:金床川"),
+    ("460208", "460208:阿弥謙川"),
+    ("460209", "460209:三本松川"),
+    ("460210", "460210:木之下川"),
+    ("460211", "460211:熊谷川"),
+    ("460212", "460212:田尻川"),
+    ("460213", "460213:雁添川"),
+    ("460214", "460214:福ノ川"),
+    ("460215", "460215:塩入川"),
+    ("460216", "460216:花倉川"),
+    ("460217", "460217:磯川"),
+    ("460218", "460218:障子川"),
+    ("460219", "460219:五位野川"),
+    ("460220", "460220:谷川"),
+    ("460221", "460221:八幡川"),
+    ("460222", "460222:逆瀬川"),
+    ("460223", "460223:丹波川"),
+    ("460224", "460224:山王川"),
+    ("460225", "460225:水成川"),
+    ("460226", "460226:東塩屋川"),
+    ("460227", "460227:竹迫川"),
+    ("460228", "460228:東俣川"),
+    ("460229", "460229:赤水川"),
+    ("460230", "460230:行司堀川"),
+    ("460231", "460231:岬川"),
+    ("460232", "460232:網代川"),
+    ("460233", "460233:坊の浜川"),
+    ("460234", "460234:下浜川"),
+    ("460235", "460235:大谷川"),
+    ("460236", "460236:小泊川"),
+    ("460237", "460237:大智院川"),
+    ("460238", "460238:宇都川"),
+    ("460239", "460239:楠崎川"),
+    ("460240", "460240:松山平川"),
+    ("460241", "460241:麻畑川"),
+    ("460242", "460242:丸木浜川"),
+    ("460243", "460243:田崎川"),
+    ("460244", "460244:唐人町川"),
+    ("460245", "460245:博多川"),
+    ("460246", "460246:平尾川"),
+    ("460247", "460247:小原川"),
+    ("460248", "460248:小ヶ倉川"),
+    ("460249", "460249:塩屋谷川"),
+    ("460250", "460250:大平川"),
+    ("460251", "460251:正仙川"),
+    ("460252", "460252:末柏川"),
+    ("460253", "460253:鳥井口川"),
+    ("460254", "460254:馬込川"),
+    ("460255", "460255:赤之間川"),
+    ("460256", "460256:芝之川"),
+    ("460257", "460257:田之尻川"),
+    ("460258", "460258:平崎川"),
+    ("460259", "460259:落水川"),
+    ("460260", "460260:座頭落川"),
+    ("460261", "460261:田平川"),
+    ("460262", "460262:塩屋川"),
+    ("460263", "460263:山神川"),
+    ("460264", "460264:宇都上川"),
+    ("460265", "460265:唐瀬川"),
+    ("460266", "460266:谷山川"),
+    ("460267", "460267:江石川"),
+    ("460268", "460268:堂之元川"),
+    ("460269", "460269:水月川"),
+    ("460270", "460270:女渕川"),
+    ("460271", "460271:野中川"),
+    ("460272", "460272:津山川"),
+    ("460273", "460273:真砂川"),
+    ("460274", "460274:蔵之元川"),
+    ("460275", "460275:船津川"),
+    ("460276", "460276:温之浦川"),
+    ("460277", "460277:浜漉川"),
+    ("460278", "460278:北方崎川"),
+    ("460279", "460279:茅屋川"),
+    ("460280", "460280:口之福浦川"),
+    ("460281", "460281:赤崎川"),
+    ("460282", "460282:片側川"),
+    ("460283", "460283:御所浦川"),
+    ("460284", "460284:馬見塚川"),
+    ("460285", "460285:飛松川"),
+    ("460286", "460286:池尻川"),
+    ("460287", "460287:槝之浦川"),
+    ("460288", "460288:黒之浜川"),
+    ("460289", "460289:八郷川"),
+    ("460290", "460290:蛇渕川"),
+    ("460291", "460291:浦田川"),
+    ("460292", "460292:犬童川"),
+    ("460293", "460293:針原川"),
+    ("460294", "460294:櫓木川"),
+    ("460295", "460295:西前田川"),
+    ("460296", "460296:前田川"),
+    ("460297", "460297:湊川"),
+    ("460298", "460298:安納川"),
+    ("460299", "460299:浅川川"),
+    ("460300", "460300:大河田川"),
+    ("460301", "460301:早稲田川"),
+    ("460302", "460302:沸川"),
+    ("460303", "460303:中山川"),
+    ("460304", "460304:野添川"),
+    ("460305", "460305:女洲川"),
+    ("460306", "460306:今熊野川"),
+    ("460307", "460307:浜川"),
+    ("460308", "460308:黒甲川"),
+    ("460309", "460309:広田川"),
+    ("460310", "460310:阿武鋤川"),
+    ("460311", "460311:大川川"),
+    ("460312", "460312:那辺川"),
+    ("460313", "460313:岩川"),
+    ("460314", "460314:牛郷川"),
+    ("460315", "460315:島間川"),
+    ("460316", "460316:塚川"),
+    ("460317", "460317:谷切川"),
+    ("460318", "460318:阿高磯川"),
+    ("460319", "460319:伊原川"),
+    ("460320", "460320:深田川"),
+    ("460321", "460321:大渡瀬川"),
+    ("460322", "460322:脇之川"),
+    ("460323", "460323:小蟹川"),
+    ("460324", "460324:大蟹川"),
+    ("460325", "460325:浜之町川"),
+    ("460326", "460326:椨川"),
+    ("460327", "460327:男川"),
+    ("460328", "460328:女川"),
+    ("460329", "460329:長間川"),
+    ("460330", "460330:温泉川"),
+    ("460331", "460331:長浜川"),
+    ("460332", "460332:宮向川"),
+    ("460333", "460333:イノクチ川"),
+    ("460334", "460334:大平瀬川"),
+    ("460335", "460335:堀田川"),
+    ("460336", "460336:一五川"),
+    ("460337", "460337:名残谷川"),
+    ("460338", "460338:中里川"),
+    ("460339", "460339:唐浜川"),
+    ("460340", "460340:佐大熊川"),
+    ("460341", "460341:大熊川"),
+    ("460342", "460342:有良川"),
+    ("460343", "460343:円本川"),
+    ("460344", "460344:川内川"),
+    ("460345", "460345:竜郷川"),
+    ("460346", "460346:久場川"),
+    ("460347", "460347:瀬留川"),
+    ("460348", "460348:浦川"),
+    ("460349", "460349:屋入川"),
+    ("460350", "460350:里川"),
+    ("460351", "460351:唐浜川"),
+    ("460352", "460352:須野川"),
+    ("460353", "460353:宇宿前川"),
+    ("460354", "460354:節田川"),
+    ("460355", "460355:手広川"),
+    ("460356", "460356:小湊山田川"),
+    ("460357", "460357:小和瀬川"),
+    ("460358", "460358:脇之戸川"),
+    ("460359", "460359:見里川"),
+    ("460360", "460360:かずら勝川"),
+    ("460361", "460361:戸玉川"),
+    ("460362", "460362:金子川"),
+    ("460363", "460363:大川"),
+    ("460364", "460364:幸田川"),
+    ("460365", "460365:ふゆかけ川"),
+    ("460366", "460366:嘉徳川"),
+    ("460367", "460367:節子川"),
+    ("460368", "460368:網野子川"),
+    ("460369", "460369:嘉鉄川"),
+    ("460370", "460370:清水川"),
+    ("460371", "460371:瀬久井川"),
+    ("460372", "460372:久根津川"),
+    ("460373", "460373:須佐礼川"),
+    ("460374", "460374:阿鉄川"),
+    ("460375", "460375:小名瀬川"),
+    ("460376", "460376:摺勝川"),
+    ("460377", "460377:越地川"),
+    ("460378", "460378:古志川"),
+    ("460379", "460379:浦川"),
+    ("460380", "460380:伊目川"),
+    ("460381", "460381:川内川"),
+    ("460382", "460382:小勝川"),
+    ("460383", "460383:挟間川"),
+    ("460384", "460384:本金久川"),
+    ("460385", "460385:西古見川"),
+    ("460386", "460386:屋鈍川"),
+    ("460387", "460387:阿室川"),
+    ("460388", "460388:平田川"),
+    ("460389", "460389:佐念川"),
+    ("460390", "460390:里川"),
+    ("460391", "460391:前田川"),
+    ("460392", "460392:名柄川"),
+    ("460393", "460393:尾羅川"),
+    ("460394", "460394:部連川"),
+    ("460395", "460395:部連小勝川"),
+    ("460396", "460396:小部連川"),
+    ("460397", "460397:第二中浜川"),
+    ("460398", "460398:中浜川"),
+    ("460399", "460399:須古川"),
+    ("460400", "460400:鯨戸川"),
+    ("460401", "460401:湯湾川"),
+    ("460402", "460402:田検川"),
+    ("460403", "460403:田検小勝川"),
+    ("460404", "460404:浦川"),
+    ("460405", "460405:芦検中川"),
+    ("460406", "460406:大良川"),
+    ("460407", "460407:伊仁川"),
+    ("460408", "460408:桁蔵川"),
+    ("460409", "460409:生勝川"),
+    ("460410", "460410:久志川"),
+    ("460411", "460411:脇川"),
+    ("460412", "460412:宇検川"),
+    ("460413", "460413:宮勝川"),
+    ("460414", "460414:田原川"),
+    ("460415", "460415:泉川"),
+    ("460416", "460416:その平川"),
+    ("460417", "460417:村内川"),
+    ("460418", "460418:志戸勘川"),
+    ("460419", "460419:徳浜川"),
+    ("460420", "460420:ヒエン川"),
+    ("460421", "460421:戸円川"),
+    ("460422", "460422:大金久川"),
+    ("460423", "460423:里川"),
+    ("460424", "460424:脇田川"),
+    ("460425", "460425:大棚川"),
+    ("460426", "460426:毛陣川"),
+    ("460427", "460427:石川川"),
+    ("460428", "460428:津名久川"),
+    ("460429", "460429:赤井川"),
+    ("460430", "460430:河平川"),
+    ("460431", "460431:山長川"),
+    ("460432", "460432:文田川"),
+    ("460433", "460433:湯湾釜川"),
+    ("460434", "460434:高述川"),
+    ("460435", "460435:宮崎川"),
+    ("460436", "460436:国直川"),
+    ("460437", "460437:前田川"),
+    ("460438", "460438:有面川"),
+    ("460439", "460439:大津川"),
+    ("460440", "460440:三儀山川"),
+    ("460441", "460441:朝仁天川"),
+    ("460442", "460442:朝仁新川"),
+    ("460443", "460443:塩浜川"),
+    ("460444", "460444:屋仁川"),
+    ("460445", "460445:沖名川"),
+    ("460446", "460446:味意竿川"),
+    ("460447", "460447:関田川"),
+    ("460448", "460448:汐見川"),
+    ("460449", "460449:浜田川"),
+    ("460450", "460450:早町川"),
+    ("460451", "460451:中川"),
+    ("460452", "460452:河原川"),
+    ("460453", "460453:草田川"),
+    ("460454", "460454:白嶺川"),
+    ("460455", "460455:神田川"),
+    ("460456", "460456:向井田川"),
+    ("460457", "460457:前川"),
+    ("460458", "460458:蒲生川"),
+    ("460459", "460459:下城久川"),
+    ("460460", "460460:中浦原川"),
+    ("460461", "460461:念辻川"),
+    ("460462", "460462:ウスク川"),
+    ("460463", "460463:宮戸川"),
+    ("460464", "460464:赤連川"),
+    ("460465", "460465:池治川"),
+    ("460466", "460466:山田丙内川"),
+    ("460467", "460467:殿川"),
+    ("460468", "460468:中里川"),
+    ("460469", "460469:小川"),
+    ("460470", "460470:大朝戸川"),
+    ("460471", "460471:中熊川"),
+    ("460472", "460472:西目川"),
+    ("460473", "460473:坂嶺川"),
+    ("460474", "460474:作田川"),
+    ("460475", "460475:ウオン川"),
+    ("460476", "460476:長迫川"),
+    ("460477", "460477:雁股川"),
+    ("460478", "460478:神宮中川"),
+    ("460479", "460479:大川川"),
+    ("460480", "460480:瀬武川"),
+    ("460481", "460481:木慈川"),
+    ("460482", "460482:武名川"),
+    ("460483", "460483:俵川"),
+    ("460484", "460484:瀬相川"),
+    ("460485", "460485:押角川"),
+    ("460486", "460486:伊佐根久川"),
+    ("460487", "460487:勝能川"),
+    ("460488", "460488:仲田川"),
+    ("460489", "460489:小勝川"),
+    ("460490", "460490:野見山川"),
+    ("460491", "460491:秋徳大川川"),
+    ("460492", "460492:勢里川"),
+    ("460493", "460493:西阿室川"),
+    ("460494", "460494:嘉入川"),
+    ("460495", "460495:須子茂川"),
+    ("460496", "460496:実久川"),
+    ("460497", "460497:芝川"),
+    ("460498", "460498:池地川"),
+    ("460499", "460499:請阿室川"),
+    ("460500", "460500:与路川"),
+    ("460501", "460501:小名発川"),
+    ("460502", "460502:脇田川"),
+    ("460503", "460503:波留川"),
+    ("460504", "460504:坂元川"),
+    ("460505", "460505:米作川"),
+    ("460506", "460506:後浜川"),
+    ("460507", "460507:千屋川"),
+    ("460508", "460508:窪田川"),
+    ("460509", "460509:里久川"),
+    ("460510", "460510:当田川"),
+    ("460511", "460511:花時名川"),
+    ("460512", "460512:福川"),
+    ("460513", "460513:麦田川"),
+    ("460514", "460514:南川"),
+    ("460515", "460515:中里川"),
+    ("460516", "460516:金久田川"),
+    ("460517", "460517:嘉ノ子川"),
+    ("460518", "460518:志行川"),
+    ("460519", "460519:三寿里川"),
+    ("460520", "460520:黒石原川"),
+    ("460521", "460521:田行川"),
+    ("460522", "460522:堀切川"),
+    ("460523", "460523:杉川"),
+    ("460524", "460524:米川"),
+    ("460525", "460525:宝川"),
+    ("460526", "460526:神之嶺川"),
+    ("460527", "460527:和瀬川"),
+    ("460528", "460528:案川"),
+    ("460529", "460529:丹向川"),
+    ("460530", "460530:奥名川"),
+    ("460531", "460531:双ツ川"),
+    ("460532", "460532:南原川"),
+    ("460533", "460533:本川"),
+    ("460534", "460534:古里川"),
+    ("460535", "460535:鹿浦川"),
+    ("460536", "460536:阿権川"),
+    ("460537", "460537:前川"),
+    ("460538", "460538:当田川"),
+    ("460539", "460539:雪取川"),
+    ("460540", "460540:屋子母川"),
+    ("460541", "460541:河田川"),
+    ("460542", "460542:阿場川"),
+    ("460543", "460543:増木名川"),
+    ("460544", "460544:本町川"),
+    ("460545", "460545:酔之尾川"),
+    ("460546", "460546:肉返川"),
+    ("460547", "460547:船津平川"),
+    ("460548", "460548:嘉徳川"),
+    ("460549", "460549:腰京川"),
+    ("460550", "460550:安良川"),
+    ("460551", "460551:大井川"),
+    ("460552", "460552:辺留川"),
+    ("460553", "460553:須野里川"),
+    ("460554", "460554:湊川"),
+    ("460555", "460555:宮浦川"),
+    ("460556", "460556:磯脇川"),
+    ("470001", "470001:奥川"),
+    ("470002", "470002:安波川"),
+    ("470003", "470003:新川川"),
+    ("470004", "470004:福地川"),
+    ("470005", "470005:有銘川"),
+    ("470006", "470006:汀間川"),
+    ("470007", "470007:漢那福地川"),
+    ("470008", "470008:億首川"),
+    ("470009", "470009:石川川"),
+    ("470010", "470010:天願川"),
+    ("470011", "470011:雄樋川"),
+    ("470012", "470012:報得川"),
+    ("470013", "470013:国場川"),
+    ("470014", "470014:安里川"),
+    ("470015", "470015:安謝川"),
+    ("470016", "470016:小湾川"),
+    ("470017", "470017:牧港川"),
+    ("470018", "470018:普天間川"),
+    ("470019", "470019:白比川"),
+    ("470020", "470020:比謝川"),
+    ("470022", "470022:名嘉真川"),
+    ("470023", "470023:真謝川"),
+    ("470024", "470024:轟川"),
+    ("470025", "470025:幸地川"),
+    ("470026", "470026:屋部川"),
+    ("470027", "470027:満名川"),
+    ("470028", "470028:大井川"),
+    ("470029", "470029:我部祖河川"),
+    ("470030", "470030:羽地大川"),
+    ("470031", "470031:源河川"),
+    ("470032", "470032:大保川"),
+    ("470033", "470033:田嘉里川"),
+    ("470034", "470034:比地川"),
+    ("470035", "470035:与那川"),
+    ("470036", "470036:辺野喜川"),
+    ("470037", "470037:座津武川"),
+    ("470038", "470038:渡嘉敷川"),
+    ("470040", "470040:内川"),
+    ("470041", "470041:中の川"),
+    ("470042", "470042:謝名堂川"),
+    ("470043", "470043:儀間川"),
+    ("470044", "470044:宮良川"),
+    ("470045", "470045:石垣新川川"),
+    ("470046", "470046:名蔵川"),
+    ("470047", "470047:浦内川"),
+    ("470048", "470048:仲間川"),
+    ("470049", "470049:越良川"),
+    ("470050", "470050:仲良川"),
+    ("470051", "470051:小波津川"),
+    ("470052", "470052:兼久川"),
+    ("470053", "470053:世富慶川"),
+    ("470054", "470054:荒川"),
+    ("470055", "470055:大浦川"),
+    ("470056", "470056:ソージ川"),
+    ("470057", "470057:通路川"),
+    ("470058", "470058:轟川"),
+    ("470059", "470059:磯辺川"),
+    ("470060", "470060:田原川"),
+    ("470061", "470061:真喜屋大川"),
+    ("810101", "810101:天塩川"),
+    ("810102", "810102:留萌川"),
+    ("810103", "810103:石狩川"),
+    ("810104", "810104:尻別川"),
+    ("810105", "810105:後志利別川"),
+    ("810106", "810106:鵡川"),
+    ("810107", "810107:沙流川"),
+    ("810108", "810108:十勝川"),
+    ("810109", "810109:釧路川"),
+    ("810110", "810110:網走川"),
+    ("810111", "810111:常呂川"),
+    ("810112", "810112:湧別川"),
+    ("810113", "810113:渚滑川"),
+    ("820201", "820201:阿武隈川"),
+    ("820202", "820202:名取川"),
+    ("820203", "820203:鳴瀬川"),
+    ("820204", "820204:北上川"),
+    ("820205", "820205:馬淵川"),
+    ("820206", "820206:高瀬川"),
+    ("820207", "820207:岩木川"),
+    ("820208", "820208:米代川"),
+    ("820209", "820209:雄物川"),
+    ("820210", "820210:子吉川"),
+    ("820211", "820211:最上川"),
+    ("820212", "820212:赤川"),
+    ("830301", "830301:久慈川"),
+    ("830302", "830302:那珂川"),
+    ("830303", "830303:利根川"),
+    ("830304", "830304:荒川"),
+    ("830305", "830305:多摩川"),
+    ("830306", "830306:鶴見川"),
+    ("830307", "830307:相模川"),
+    ("830308", "830308:富士川"),
+    ("840401", "840401:荒川"),
+    ("840402", "840402:阿賀野川"),
+    ("840403", "840403:信濃川"),
+    ("840404", "840404:関川"),
+    ("840405", "840405:姫川"),
+    ("840406", "840406:黒部川"),
+    ("840407", "840407:常願寺川"),
+    ("840408", "840408:神通川"),
+    ("840409", "840409:庄川"),
+    ("840410", "840410:小矢部川"),
+    ("840411", "840411:手取川"),
+    ("840412", "840412:梯川"),
+    ("850501", "850501:狩野川"),
+    ("850502", "850502:安倍川"),
+    ("850503", "850503:大井川"),
+    ("850504", "850504:菊川"),
+    ("850505", "850505:天竜川"),
+    ("850506", "850506:豊川"),
+    ("850507", "850507:矢作川"),
+    ("850508", "850508:庄内川"),
+    ("850509", "850509:木曽川"),
+    ("850510", "850510:鈴鹿川"),
+    ("850511", "850511:雲出川"),
+    ("850512", "850512:櫛田川"),
+    ("850513", "850513:宮川"),
+    ("860601", "860601:新宮川"),
+    ("860602", "860602:紀の川"),
+    ("860603", "860603:大和川"),
+    ("860604", "860604:淀川"),
+    ("860605", "860605:加古川"),
+    ("860606", "860606:揖保川"),
+    ("860607", "860607:九頭竜川"),
+    ("860608", "860608:北川"),
+    ("860609", "860609:由良川"),
+    ("860610", "860610:円山川"),
+    ("870701", "870701:千代川"),
+    ("870702", "870702:天神川"),
+    ("870703", "870703:日野川"),
+    ("870704", "870704:斐伊川"),
+    ("870705", "870705:江の川"),
+    ("870706", "870706:高津川"),
+    ("870707", "870707:佐波川"),
+    ("870708", "870708:小瀬川"),
+    ("870709", "870709:太田川"),
+    ("870710", "870710:芦田川"),
+    ("870711", "870711:高梁川"),
+    ("870712", "870712:旭川"),
+    ("870713", "870713:吉井川"),
+    ("880801", "880801:重信川"),
+    ("880802", "880802:肱川"),
+    ("880803", "880803:渡川"),
+    ("880804", "880804:仁淀川"),
+    ("880805", "880805:物部川"),
+    ("880806", "880806:那賀川"),
+    ("880807", "880807:吉野川"),
+    ("880808", "880808:土器川"),
+    ("890901", "890901:遠賀川"),
+    ("890902", "890902:松浦川"),
+    ("890903", "890903:本明川"),
+    ("890904", "890904:六角川"),
+    ("890905", "890905:嘉瀬川"),
+    ("890906", "890906:筑後川"),
+    ("890907", "890907:矢部川"),
+    ("890908", "890908:菊池川"),
+    ("890909", "890909:白川"),
+    ("890910", "890910:緑川"),
+    ("890911", "890911:球磨川"),
+    ("890912", "890912:川内川"),
+    ("890913", "890913:肝属川"),
+    ("890914", "890914:大淀川"),
+    ("890915", "890915:小丸川"),
+    ("890916", "890916:五ヶ瀬川"),
+    ("890917", "890917:番匠川"),
+    ("890918", "890918:大野川"),
+    ("890919", "890919:大分川"),
+    ("890920", "890920:山国川"),
+]);
+
+#[rustfmt::skip]
+pub(crate) static WelfareFacMiclassCdH23: Codelist = Codelist::new("WelfareFacMiclassCdH23", &[
+    ("101", "通所介護事業所"),
+    ("102", "老人短期入所介護事業所"),
+    ("103", "養護老人ホーム（一般"),
+    ("104", "養護老人ホーム（盲）"),
+    ("105", "特別養護老人ホーム"),
+    ("106", "軽費老人ホーム（A型）"),
+    ("107", "軽費老人ホーム（B型）"),
+    ("108", "軽費老人ホーム（介護利用型）"),
+    ("109", "老人福祉センター（特A型）"),
+    ("110", "老人福祉センター（A型）"),
+    ("111", "老人福祉センター（B型）"),
+    ("112", "老人介護支援センター"),
+    ("113", "老人福祉センター"),
+    ("114", "養護老人ホーム"),
+    ("115", "軽費老人ホーム"),
+    ("201", "老人憩いの家"),
+    ("202", "老人休養ホーム"),
+    ("203", "有料老人ホーム"),
+    ("301", "肢体不自由者更生施設"),
+    ("302", "視覚障害者更生施設"),
+    ("303", "聴覚・言語障害者更生施設"),
+    ("304", "内部障害者更生施設"),
+    ("305", "身体障害者療護施設"),
+    ("306", "身体障害者授産施設"),
+    ("307", "身体障害者通所授産施設"),
+    ("308", "身体障害者小規模通所授産施設"),
+    ("309", "身体障害者福祉ホーム"),
+    ("310", "身体障害者福祉センター（A型）"),
+    ("311", "身体障害者福祉センター（B型）"),
+    ("312", "在宅障害者デイサービス施設"),
+    ("313", "障害者更生センター"),
+    ("314", "補装具製作施設"),
+    ("315", "聴覚障害者情報提供施設"),
+    ("316", "点字図書館"),
+    ("317", "点字出版施設"),
+    ("318", "盲導犬訓練施設"),
+    ("319", "身体障害者福祉工場"),
+    ("401", "助産施設"),
+    ("402", "乳児院"),
+    ("403", "母子生活支援施設"),
+    ("404", "大型児童館（A型）"),
+    ("405", "大型児童館（B型）"),
+    ("406", "大型児童館（C型）"),
+    ("407", "小型児童館"),
+    ("408", "その他の児童館"),
+    ("409", "児童センター"),
+    ("410", "児童遊園"),
+    ("411", "児童養護施設"),
+    ("412", "知的障害児施設"),
+    ("413", "自閉症児施設"),
+    ("414", "知的障害児通園施設"),
+    ("415", "盲児施設"),
+    ("416", "ろうあ児施設"),
+    ("417", "難聴幼児通園施設"),
+    ("418", "肢体不自由児施設"),
+    ("419", "肢体不自由児療護施設"),
+    ("420", "重症心身障害児施設"),
+    ("421", "情緒障害児短期治療施設"),
+    ("422", "児童自立支援施設"),
+    ("423", "児童家庭支援センター"),
+    ("501", "知的障害者授産施設（通所）"),
+    ("502", "知的障害者授産施設（入所）"),
+    ("503", "知的障害者小規模通所授産施設"),
+    ("504", "知的障害者更生施設（通所）"),
+    ("505", "知的障害者更生施設（入所）"),
+    ("506", "知的障害者通勤寮"),
+    ("507", "知的障害者福祉ホーム"),
+    ("508", "知的障害者デイサービスセンター"),
+    ("509", "知的障害者福祉工場"),
+    ("510", "知的障害者授産施設"),
+    ("601", "精神障害者生活訓練施設"),
+    ("602", "精神障害者通所授産施設"),
+    ("603", "精神障害者入所授産施設"),
+    ("604", "精神障害者小規模通所授産施設"),
+    ("605", "精神障害者福祉ホーム（B型）"),
+    ("606", "精神障害者福祉ホーム（B型を除く）"),
+    ("607", "精神障害者福祉工場"),
+    ("608", "精神障害者地域生活支援センター"),
+    ("609", "精神障害者福祉ホーム"),
+    ("701", "へき地保健福祉館"),
+    ("702", "地域福祉センター"),
+    ("703", "授産施設（その他の社会福祉施設）"),
+    ("704", "無料低額診療施設"),
+    ("705", "宿泊提供施設（その他の社会福祉施設）"),
+    ("706", "盲人ホーム"),
+    ("707", "福祉ホーム"),
+    ("801", "保育所"),
+    ("802", "へき地保育所"),
+    ("803", "認可外保育施設"),
+    ("804", "幼稚園"),
+    ("805", "認定こども園"),
+    ("NA", "NA"),
+]);
+
+#[rustfmt::skip]
+pub(crate) static WelfareFacMiclassCdH27: Codelist = Codelist::new("WelfareFacMiclassCdH27", &[
+    ("1001", "共同生活援助"),
+    ("1002", "共同生活介護"),
+    ("1003", "計画相談支援"),
+    ("1004", "児童デイサービス"),
+    ("1005", "相談支援事業"),
+    ("1006", "地域移行支援"),
+    ("1007", "地域定着支援"),
+    ("101", "通所介護事業所"),
+    ("102", "老人短期入所介護事業所"),
+    ("103", "養護老人ホーム（一般）"),
+    ("104", "養護老人ホーム（盲）"),
+    ("105", "特別養護老人ホーム"),
+    ("106", "軽費老人ホーム（ A型）"),
+    ("107", "軽費老人ホーム（ B型）"),
+    ("108", "軽費老人ホーム（介護利用型）"),
+    ("109", "老人福祉センター（特 A型）"),
+    ("110", "老人福祉センター（ A型）"),
+    ("111", "老人福祉センター（ B型）"),
+    ("112", "在宅介護支援センター"),
+    ("113", "老人福祉センター"),
+    ("114", "養護老人ホーム"),
+    ("115", "軽費老人ホーム"),
+    ("116", "軽費老人ホーム（ケアハウス）"),
+    ("117", "軽費老人ホーム（Ａ型）"),
+    ("118", "老人介護支援センター"),
+    ("119", "在宅介護支援センター（老人介護支援センター )"),
+    ("120", "県内在宅介護支援センター"),
+    ("1701", "障害者生活介護、就労移行"),
+    ("1702", "障害者生活介護、就労移行、就労継続 B"),
+    ("1703", "障害者生活介護、就労継続 A"),
+    ("1704", "障害者生活介護、就労継続 A、就労継続 B、生活訓練"),
+    ("1705", "障害者生活介護、就労継続 B"),
+    ("1707", "障害者生活介護、就労継続 B、生活訓練"),
+    ("1708", "障害者生活介護、生活訓練"),
+    ("1710", "障害者生活訓練"),
+    ("1711", "障害者生活訓練 (休止中 )、就労継続 B"),
+    ("1712", "障害者生活訓練、就労移行"),
+    ("1713", "障害者生活訓練、就労継続 B"),
+    ("1714", "障害者生活訓練、宿泊型自立訓練"),
+    ("1715", "障害者生活訓練、生活介護"),
+    ("1717", "障害福祉サービス事業所 (短期入所 )"),
+    ("1718", "障害福祉地域活動支援センター"),
+    ("1719", "独立行政法人国立病院機構：重症心身障害児入所委託"),
+    ("1720", "複合型サービス"),
+    ("1721", "一時保護所"),
+    ("1722", "指定医療機関"),
+    ("1723", "自立援助ホーム"),
+    ("1724", "障害者生活ホーム"),
+    ("1725", "心身障害者小規模福祉作業所"),
+    ("1726", "心身障害者福祉作業所（市町村立）"),
+    ("1727", "生活介護（障害者支援施設に指定されている昼間実施サービスを含む）"),
+    ("1728", "生活保護施設（医療保護施設）"),
+    ("1729", "生活保護施設（救護施設）"),
+    ("1730", "通所リハビリテーション事業所"),
+    ("1731", "放課後等デイサービス事業所"),
+    ("1732", "宿泊提供施設（その他の社会福祉施設）"),
+    ("1733", "無料低額宿泊事業所"),
+    ("1734", "相談支援事業所（特定相談支援事業、障害児相談支援事業、一般相談支援事業）"),
+    ("1735", "障害福祉サービス事業（居宅介護）"),
+    ("1736", "障害福祉サービス事業（共同生活援助（グループホーム））"),
+    ("1737", "障害福祉サービス事業（行動援護）"),
+    ("1738", "障害福祉サービス事業（自立訓練）"),
+    ("1739", "障害福祉サービス事業（就労移行支援一般型）"),
+    ("1740", "障害福祉サービス事業（就労継続支援Ａ型）"),
+    ("1741", "障害福祉サービス事業（就労継続支援Ｂ型）"),
+    ("1742", "障害福祉サービス事業（重度訪問介護）"),
+    ("1743", "障害福祉サービス事業（生活介護）"),
+    ("1744", "障害福祉サービス事業（短期入所）"),
+    ("1745", "障害福祉サービス事業（同行援護）"),
+    ("1746", "障害福祉サービス事業（療養介護）"),
+    ("1747", "ファミリーホーム"),
+    ("1748", "障害者共同生活援助事業所"),
+    ("1749", "障害者就労移行支援事業所"),
+    ("1750", "障害者就労継続支援事業所Ａ型"),
+    ("1751", "障害者生活訓練事業所"),
+    ("1752", "障害者自立訓練（生活訓練）"),
+    ("1753", "障害者就労移行支援"),
+    ("1754", "障害者就労継続支援 A型"),
+    ("1755", "障害者就労継続支援 B型"),
+    ("1756", "障害者療養介護"),
+    ("1757", "地域活動支援センター Ⅲ型"),
+    ("1758", "その他の保健福祉施設"),
+    ("1759", "市町村保健センター"),
+    ("1760", "指定一般相談支援事業 (地域移行・地域定着）"),
+    ("1761", "指定介護療養型医療施設"),
+    ("1762", "指定特定相談支援事業 (計画相談）"),
+    ("1763", "指定放課後等デイサービス事業所"),
+    ("1764", "心身障害者福祉センター"),
+    ("1765", "生活館"),
+    ("1766", "総合保健センター"),
+    ("1767", "地域振興センター"),
+    ("1768", "就労移行支援（一般型）"),
+    ("1769", "療養介護事業所"),
+    ("1770", "障害福祉サービス事業所"),
+    ("1771", "障害福祉サービス事業所（基準該当）"),
+    ("1772", "県立病院"),
+    ("1773", "社会福祉センター"),
+    ("1774", "小規模作業所"),
+    ("1775", "障害者更生相談所・精神保健福祉センター・発達障害者支援センター"),
+    ("1776", "障害者小規模地域活動センター"),
+    ("1777", "障害者地域活動推進センター"),
+    ("1778", "地域相談支援"),
+    ("1779", "介護保険施設"),
+    ("1780", "更生施設"),
+    ("1781", "授産施設（授産場）"),
+    ("1782", "宿泊所"),
+    ("1783", "労働・福祉センター"),
+    ("201", "老人憩いの家"),
+    ("202", "老人休養ホーム"),
+    ("203", "有料老人ホーム"),
+    ("204", "生活支援ハウス（高齢者生活福祉センター）"),
+    ("205", "介護老人保健施設"),
+    ("206", "訪問看護ステーション"),
+    ("207", "認知症対応型共同生活介護"),
+    ("208", "軽費老人ホームＡ型・Ｂ型"),
+    ("209", "特別養護老人ホーム（介護老人福祉施設）"),
+    ("210", "認知症疾患医療センター"),
+    ("211", "介護福祉士指定養成施設"),
+    ("212", "老人デイサービスセンター"),
+    ("213", "老人短期入所施設"),
+    ("214", "短期入所生活介護"),
+    ("215", "重度訪問介護"),
+    ("216", "デイサービス"),
+    ("217", "短期入所"),
+    ("218", "通所介護（デイサービス）"),
+    ("219", "ケアハウス"),
+    ("220", "認知症対応型共同生活介護（グループホーム）"),
+    ("221", "経過的生活介護"),
+    ("222", "短期入所生活介護事業所"),
+    ("223", "老人いこいの家"),
+    ("224", "生活支援ハウス"),
+    ("226", "老人憩の家（市町村）"),
+    ("227", "老人福祉センター（市町村）"),
+    ("228", "有料老人ホーム住所地特例"),
+    ("229", "無料低額介護老人保健施設"),
+    ("230", "老人デイサービスセンター（通所介護、介護予防通所介護）"),
+    ("231", "経過的軽費老人ホーム（ A型）"),
+    ("232", "老人デイサービスセンター (認知症対応型通所介護・介護予防認知症対応型通所介護）"),
+    ("233", "認知症高齢者グループホーム"),
+    ("234", "介護予防認知症対応型共同生活介護"),
+    ("235", "地域密着型介護老人福祉施設入所者生活介護"),
+    ("236", "地域密着型特定施設入所者生活介護"),
+    ("237", "特別養護老人ホーム (介護老人福祉施設 )"),
+    ("238", "認知症対応型通所介護"),
+    ("239", "訪問介護看護"),
+    ("240", "夜間対応型訪問介護"),
+    ("241", "老人デイサービスセンター (デイサービス事業実施施設を含む。 )"),
+    ("242", "老人憩の家"),
+    ("243", "地域密着型介護老人福祉施設 (小規模特別養護老人ホーム )"),
+    ("244", "指定認知症対応型通所介護事業所"),
+    ("245", "指定介護老人福祉施設"),
+    ("246", "指定地域密着型介護老人福祉施設"),
+    ("247", "指定通所介護事業所"),
+    ("248", "老人短期入所施設〔単独型指定短期入所生活介護〕"),
+    ("249", "老人短期入所事業所〔併設型指定短期入所生活介護〕"),
+    ("250", "重度訪問介護事業所"),
+    ("251", "生活介護事業所"),
+    ("252", "デイサービスセンター（指定通所介護事業所を含む）"),
+    ("253", "老人（在宅）介護支援センター"),
+    ("254", "デイサービスセンター"),
+    ("255", "認知症対応型共同生活介護事業"),
+    ("256", "有料老人ホーム（介護付）"),
+    ("257", "有料老人ホーム（住宅型）"),
+    ("258", "老人性認知症センター"),
+    ("259", "老人福祉センター（その他）"),
+    ("260", "生活介護（基準該当）"),
+    ("261", "介護研修施設"),
+    ("262", "老人デイサービスセンター（一般型）"),
+    ("263", "老人デイサービスセンター（認知症対応型）"),
+    ("301", "肢体不自由者更生施設"),
+    ("302", "視覚障害者更生施設"),
+    ("303", "聴覚・言語障害者更生施設"),
+    ("304", "内部障害者更生施設"),
+    ("305", "身体障害者療護施設"),
+    ("306", "身体障害者授産施設"),
+    ("307", "身体障害者通所授産施設"),
+    ("308", "身体障害者小規模通所授産施設"),
+    ("309", "身体障害者福祉ホーム"),
+    ("310", "身体障害者福祉センター（ A型）"),
+    ("311", "身体障害者福祉センター（ B型）"),
+    ("312", "在宅障害者デイサービス施設"),
+    ("313", "障害者更生センター"),
+    ("314", "補装具製作施設"),
+    ("315", "視覚障害者情報提供施設"),
+    ("316", "点字図書館"),
+    ("317", "点字出版施設"),
+    ("318", "盲導犬訓練施設"),
+    ("319", "身体障害者福祉工場"),
+    ("320", "身体障害者福祉センター"),
+    ("321", "救護施設"),
+    ("322", "聴覚障害者情報提供施設"),
+    ("323", "障害者支援施設（生活介護）"),
+    ("324", "障害者支援施設（生活介護・自立訓練）"),
+    ("325", "障害者支援施設（生活介護・就労継続支援Ｂ型）"),
+    ("326", "障害者支援施設"),
+    ("327", "経過的療養介護"),
+    ("328", "視聴覚障害者情報提供施設"),
+    ("401", "助産施設"),
+    ("402", "乳児院"),
+    ("403", "母子生活支援施設"),
+    ("404", "大型児童館（ A型）"),
+    ("405", "大型児童館（ B型）"),
+    ("406", "大型児童館（ C型）"),
+    ("407", "小型児童館"),
+    ("408", "その他の児童館"),
+    ("409", "児童センター"),
+    ("410", "児童遊園"),
+    ("411", "児童養護施設"),
+    ("412", "知的障害児施設"),
+    ("413", "自閉症児施設"),
+    ("414", "知的障害児通園施設"),
+    ("415", "盲児施設"),
+    ("416", "ろうあ児施設"),
+    ("417", "難聴幼児通園施設"),
+    ("418", "肢体不自由児施設"),
+    ("419", "肢体不自由児療護施設"),
+    ("420", "重症心身障害児施設"),
+    ("421", "情緒障害児短期治療施設"),
+    ("422", "児童自立支援施設"),
+    ("423", "児童家庭支援センター"),
+    ("424", "医療型障害児入所施設"),
+    ("425", "医療型児童発達支援センター"),
+    ("426", "福祉型障害児入所施設"),
+    ("427", "児童館（児童センター）"),
+    ("428", "母子父子福祉センター"),
+    ("429", "在宅心身障がい児者保養訓練センター"),
+    ("430", "児童自立生活援助事業"),
+    ("431", "小規模住居型児童養育事業"),
+    ("432", "児童発達支援"),
+    ("435", "医療型障害児入所施設及び指定医療機関"),
+    ("436", "母子福祉センター"),
+    ("437", "児童館・児童センター・児童室"),
+    ("438", "児童発達支援センター"),
+    ("439", "障害児入所施設（短期入所）"),
+    ("440", "児童発達支援（センター以外）"),
+    ("441", "障害児入所施設"),
+    ("442", "児童館"),
+    ("443", "指定発達支援医療機関（肢体不自由児）"),
+    ("444", "指定発達支援医療機関（重症心身障害児）"),
+    ("445", "障害児相談支援事業"),
+    ("446", "放課後児童健全育成事業"),
+    ("447", "祉型障害児入所施設"),
+    ("448", "障害者就労移行、就労継続 B、生活訓練、 (児童発達、放課後デイ )"),
+    ("449", "障害者就労継続 A(児童デイサービス )"),
+    ("450", "障害者生活介護 (児童デイサービス )"),
+    ("451", "障害者生活介護、就労継続 A就労継続 B(児童デイサービス )"),
+    ("452", "母子・父子福祉施設"),
+    ("453", "児童発達支援センター（福祉型）"),
+    ("454", "障害児相談支援"),
+    ("455", "指定医療型障害児入所施設"),
+    ("456", "指定児童発達支援事業所（児童発達支援センターを除く）"),
+    ("457", "指定障害児相談支援"),
+    ("458", "指定障害児相談支援指定障害児入所施設等 _指定医療機関（独立行政法人国立病院機構）"),
+    ("459", "指定福祉型児童発達支援センター"),
+    ("460", "指定福祉型障害児入所施設"),
+    ("461", "指定障害児入所施設等 _指定医療機関（独立行政法人国立病院機構）"),
+    ("462", "障害児等療育支援事業施設"),
+    ("463", "子ども総合センター・児童相談所"),
+    ("464", "指定医療機関（肢体不自由児）"),
+    ("465", "児童相談診療施設"),
+    ("466", "障害児通所支援"),
+    ("501", "知的障害者授産施設（通所）"),
+    ("502", "知的障害者授産施設（入所）"),
+    ("503", "知的障害者小規模通所授産施設"),
+    ("504", "知的障害者更生施設（通所）"),
+    ("505", "知的障害者更生施設（入所）"),
+    ("506", "知的障害者通勤寮"),
+    ("507", "知的障害者福祉ホーム"),
+    ("508", "知的障害者デイサービスセンター"),
+    ("509", "知的障害者福祉工場"),
+    ("510", "福祉型児童発達支援センター"),
+    ("511", "知的障害者福祉施設"),
+    ("526", "障害者支援施設（自立訓練・就労移行支援）"),
+    ("527", "多機能型"),
+    ("528", "身体障がい者保養所"),
+    ("529", "障害者支援施設（生活介護・就労移行支援）"),
+    ("530", "就労継続支援 (Ａ型 )"),
+    ("531", "就労継続支援 (Ｂ型 )"),
+    ("532", "就労移行支援"),
+    ("533", "生活介護"),
+    ("534", "共同生活援助（介護サービス包括型）"),
+    ("535", "放課後等デイサービス"),
+    ("536", "施設入所支援（障害者支援施設）"),
+    ("537", "外部サービス利用型共同生活援助"),
+    ("538", "自立訓練 (機能訓練 )（障害者支援施設）"),
+    ("539", "自立訓練 (生活訓練 )"),
+    ("540", "自立訓練 (生活訓練 )（障害者支援施設）"),
+    ("541", "就労移行支援（障害者支援施設）"),
+    ("542", "宿泊型自立訓練"),
+    ("543", "生活介護（障害者支援施設）"),
+    ("544", "療養介護"),
+    ("545", "自立訓練 (機能訓練 )"),
+    ("546", "共同生活援助事業所"),
+    ("547", "同行援護"),
+    ("548", "機能訓練"),
+    ("549", "経過的施設入所支援"),
+    ("550", "重度障害者包括支援"),
+    ("551", "生活訓練"),
+    ("552", "生活介護・自立訓練・就労移行・就労継続・施設入所・短期入所"),
+    ("553", "自立訓練（機能訓練）事業所"),
+    ("554", "自立訓練（生活訓練）事業所"),
+    ("555", "就労継続支援（Ａ型）事業所"),
+    ("556", "就労継続支援（Ｂ型）事業所"),
+    ("557", "宿泊型自立訓練事業所"),
+    ("558", "同行援護事業所"),
+    ("559", "障害者施設入所支援"),
+    ("560", "旧身体障害者社会参加施設"),
+    ("561", "視覚障害者情報センター"),
+    ("562", "自立訓練 (機能訓練 )(基準該当 )"),
+    ("563", "聴覚障害者情報センター"),
+    ("567", "身体障害者入所授産施設"),
+    ("568", "身体障害者入所療護施設"),
+    ("569", "身体障害者補助犬訓練施設（盲導犬訓練施設を除く）"),
+    ("601", "精神障害者生活訓練施設"),
+    ("602", "精神障害者通所授産施設"),
+    ("603", "精神障害者入所授産施設"),
+    ("604", "精神障害者小規模通所授産施設"),
+    ("605", "精神障害者福祉ホーム（ B型）"),
+    ("606", "精神障害者福祉ホーム（ B型を除く）"),
+    ("607", "精神障害者福祉工場"),
+    ("608", "精神障害者地域生活支援センター"),
+    ("609", "精神障害者福祉ホーム"),
+    ("610", "精神障害者ふれあいホーム (精神障害者生活ホーム )"),
+    ("611", "精神障害者共同作業所"),
+    ("612", "精神保健福祉センター"),
+    ("613", "在宅心身障害者保養施設"),
+    ("614", "精神障害者コミュニティサロン"),
+    ("615", "精神障害者小規模作業所"),
+    ("701", "へき地保健福祉館"),
+    ("702", "地域包括支援センター"),
+    ("703", "授産施設（その他の社会福祉施設）"),
+    ("704", "無料低額診療施設"),
+    ("705", "宿所提供施設"),
+    ("706", "盲人ホーム"),
+    ("707", "福祉ホーム"),
+    ("708", "婦人保護施設"),
+    ("709", "婦人相談所一時保護所"),
+    ("710", "簡易住宅"),
+    ("711", "無料低額宿泊所"),
+    ("712", "福祉休養ホーム"),
+    ("713", "相談機関"),
+    ("714", "福祉事務所"),
+    ("715", "保健所"),
+    ("716", "サービス付き高齢者向け住宅"),
+    ("717", "医務国保課関係団体"),
+    ("718", "介護福祉士養成施設"),
+    ("719", "健康福祉総務課関係団体"),
+    ("720", "子育て支援課関係団体"),
+    ("721", "市町地域包括支援センター"),
+    ("722", "市町保健センター (類似施設を含む）"),
+    ("723", "医療保護施設"),
+    ("724", "指定保育士養成施設"),
+    ("725", "指定訪問看護ステーション"),
+    ("726", "社会福祉士養成施設"),
+    ("727", "小規模多機能型居宅介護"),
+    ("728", "障害児通所支援事業所"),
+    ("729", "障害者グループホーム"),
+    ("730", "障害福祉課関係団体"),
+    ("731", "心身障害者小規模通所作業所"),
+    ("732", "相談支援事業所"),
+    ("733", "地域活動支援センター"),
+    ("734", "地域福祉センタ"),
+    ("735", "長寿社会対策課関係団体"),
+    ("736", "薬務感染症対策課関係団体"),
+    ("737", "隣保館"),
+    ("738", "授産施設"),
+    ("740", "保育所等訪問支援事業所"),
+    ("741", "児童発達支援事業所"),
+    ("742", "障害児相談支援事業所"),
+    ("743", "就労移行支援事業所"),
+    ("744", "行動援護事業所"),
+    ("745", "居宅介護事業所"),
+    ("746", "短期入所事業所"),
+    ("749", "グループホーム"),
+    ("750", "居宅介護"),
+    ("751", "施設入所支援"),
+    ("752", "単独施設"),
+    ("753", "その他施設"),
+    ("754", "福祉複合施設（再掲）"),
+    ("756", "福祉センター及び福祉会館"),
+    ("757", "居宅介護・重度訪問介護"),
+    ("758", "地域福祉センター"),
+    ("760", "生活ホーム事業"),
+    ("762", "地域子育て支援拠点事業"),
+    ("763", "障害者就業・生活支援センター"),
+    ("766", "共同生活援助 (GH)"),
+    ("768", "福祉センター等"),
+    ("769", "看護小規模多機能型居宅介護"),
+    ("771", "介護療養型医療施設"),
+    ("772", "無料低額診療事業所"),
+    ("773", "行動援護"),
+    ("774", "介護予防小規模多機能型居宅介護"),
+    ("775", "障がい児 (者 )地域療育等支援"),
+    ("776", "障がい者自立生活センター"),
+    ("777", "障がい者就業、生活支援センター"),
+    ("778", "障害者就労移行"),
+    ("779", "障害者就労移行、就労継続 A"),
+    ("780", "障害者就労移行、就労継続 A、 B"),
+    ("781", "障害者就労移行、就労継続 B"),
+    ("783", "障害者就労継続 A"),
+    ("784", "障害者就労継続 A、 B"),
+    ("785", "障害者就労継続 A、 B、就労移行"),
+    ("786", "障害者就労継続 A、就労移行"),
+    ("788", "障害者就労継続 B"),
+    ("797", "障害者宿泊型自立訓練"),
+    ("798", "障害者生活介護"),
+    ("799", "障害者生活介護 (児デイ )"),
+    ("801", "保育所"),
+    ("802", "へき地保育所"),
+    ("803", "認可外保育施設"),
+    ("804", "幼稚園"),
+    ("805", "認定こども園"),
+    ("806", "幼保連携型認定こども園"),
+    ("807", "母子父子休養ホーム"),
+    ("808", "認定保育施設"),
+    ("809", "事業所内保育施設"),
+    ("810", "小規模保育事業"),
+    ("811", "事業所内保育事業"),
+    ("812", "幼稚園型認定こども園"),
+    ("813", "保育所型認定こども園"),
+    ("814", "地方裁量型認定こども園"),
+    ("815", "指定保育所等訪問支援事業所"),
+    ("816", "小規模保育事業所"),
+    ("852", "保育所訪問支援"),
+    ("854", "保育所（分園）"),
+    ("855", "保育所型"),
+    ("856", "幼稚園型"),
+    ("857", "幼保連携型"),
+    ("859", "認証保育所（ A型）"),
+    ("860", "認証保育所（ B型）"),
+    ("901", "指定医療機関（重心障害児）"),
+    ("902", "肢体不自由児施設（入所）"),
+    ("903", "保育所等訪問支援"),
+    ("904", "医療型障害児入所支援"),
+    ("905", "障害児入所支援"),
+    ("NA", "NA"),
+]);
+
+#[cfg(feature = "dict-policy")]
+#[rustfmt::skip]
+pub(crate) static WORLD_HERITAGE_CD: Codelist = Codelist::new("WORLD_HERITAGE_CD", &[
+    ("01", "知床"),
+    ("02", "白神山地"),
+    ("03", "屋久島"),
+    ("04", "小笠原"),
+]);
+
+// All the codelists of the enabled categories with their names, sorted by the name
+#[rustfmt::skip]
+pub(crate) static CODELISTS: &[(&str, &Codelist)] = &[
+    #[cfg(feature = "dict-policy")]
+    ("A03_SECTION_CD", &A03_SECTION_CD),
+    #[cfg(feature = "dict-policy")]
+    ("A03_SECTION_TYPE_CD_CYUBU", &A03_SECTION_TYPE_CD_CYUBU),
+    #[cfg(feature = "dict-policy")]
+    ("A03_SECTION_TYPE_CD_KINKI", &A03_SECTION_TYPE_CD_KINKI),
+    #[cfg(feature = "dict-policy")]
+    ("A03_SECTION_TYPE_CD_SYUTO", &A03_SECTION_TYPE_CD_SYUTO),
+    #[cfg(feature = "dict-policy")]
+    ("A10_AREA_CD", &A10_AREA_CD),
+    #[cfg(feature = "dict-policy")]
+    ("A10_INSIDE_DIV", &A10_INSIDE_DIV),
+    #[cfg(feature = "dict-policy")]
+    ("A10_LAYER_NO", &A10_LAYER_NO),
+    #[cfg(feature = "dict-policy")]
+    ("A42_HISTORICAL_DISTRICT_TYPE", &A42_HISTORICAL_DISTRICT_TYPE),
+    #[cfg(feature = "dict-facility")]
+    ("ADMIN_CODE", &ADMIN_CODE),
+    #[cfg(feature = "dict-transport")]
+    ("ADMIN_CON_AREA_CD", &ADMIN_CON_AREA_CD),
+    #[cfg(feature = "dict-transport")]
+    ("ADMIN_HARBOR_CD", &ADMIN_HARBOR_CD),
+    #[cfg(feature = "dict-transport")]
+    ("ADMIN_SEA_LINE_CD", &ADMIN_SEA_LINE_CD),
+    #[cfg(feature = "dict-policy")]
+    ("AGGREGATE_UNIT_FLAG", &AGGREGATE_UNIT_FLAG),
+    #[cfg(feature = "dict-policy")]
+    ("AGG_UNIT_FLAG_EMER_TRANS_CD", &AGG_UNIT_FLAG_EMER_TRANS_CD),
+    #[cfg(feature = "dict-transport")]
+    ("AIRPORT_CAT_CD", &AIRPORT_CAT_CD),
+    #[cfg(feature = "dict-transport")]
+    ("AIRPORT_TRANSITION_CD", &AIRPORT_TRANSITION_CD),
+    #[cfg(feature = "dict-transport")]
+    ("AIRPORT_USE_CD", &AIRPORT_USE_CD),
+    #[cfg(feature = "dict-transport")]
+    ("AIR_JET_CD", &AIR_JET_CD),
+    #[cfg(feature = "dict-policy")]
+    ("AUTHORITY_TYPE", &AUTHORITY_TYPE),
+    #[cfg(feature = "dict-transport")]
+    ("AVIATION_ACT_CD", &AVIATION_ACT_CD),
+    #[cfg(feature = "dict-facility")]
+    ("BIOMASS_TYPE", &BIOMASS_TYPE),
+    #[cfg(feature = "dict-facility")]
+    ("BUSINESS_TECH_CD", &BUSINESS_TECH_CD),
+    #[cfg(any(feature = "dict-transport", feature = "dict-facility"))]
+    ("BUS_CLASS_CD", &BUS_CLASS_CD),
+    #[cfg(feature = "dict-facility")]
+    ("CITY_PARK_CD", &CITY_PARK_CD),
+    #[cfg(feature = "dict-transport")]
+    ("CLASS_FISH_PORT_CD", &CLASS_FISH_PORT_CD),
+    #[cfg(feature = "dict-transport")]
+    ("CLASS_HARBOR1_CD", &CLASS_HARBOR1_CD),
+    #[cfg(feature = "dict-transport")]
+    ("CLASS_HARBOR2_CD", &CLASS_HARBOR2_CD),
+    #[cfg(feature = "dict-policy")]
+    ("CODE_DESIGNATION_CD", &CODE_DESIGNATION_CD),
+    #[cfg(feature = "dict-policy")]
+    ("CODE_NONCOMBUSTIBLE_CD", &CODE_NONCOMBUSTIBLE_CD),
+    #[cfg(feature = "dict-policy")]
+    ("CODE_OF_PHENOMENON", &CODE_OF_PHENOMENON),
+    #[cfg(feature = "dict-policy")]
+    ("CODE_OF_UN_SPECIFICATION", &CODE_OF_UN_SPECIFICATION),
+    #[cfg(feature = "dict-policy")]
+    ("CODE_OF_ZONE_H27", &CODE_OF_ZONE_H27),
+    #[cfg(feature = "dict-facility")]
+    ("COMMUNITY_CENTER_TYPE", &COMMUNITY_CENTER_TYPE),
+    #[cfg(feature = "dict-facility")]
+    ("CULTURE_FAC_CD", &CULTURE_FAC_CD),
+    #[cfg(feature = "dict-water")]
+    ("DAMP_URPOSE_CD", &DAMP_URPOSE_CD),
+    #[cfg(feature = "dict-water")]
+    ("DAM_INSTITUTION_CD", &DAM_INSTITUTION_CD),
+    #[cfg(feature = "dict-water")]
+    ("DAM_TYPE_CD", &DAM_TYPE_CD),
+    #[cfg(feature = "dict-land")]
+    ("DIRECTION", &DIRECTION),
+    #[cfg(feature = "dict-facility")]
+    ("DISTRIBUTION_CD", &DISTRIBUTION_CD),
+    #[cfg(feature = "dict-facility")]
+    ("DISTRIBUTION_CENTER_CD", &DISTRIBUTION_CENTER_CD),
+    #[cfg(feature = "dict-facility")]
+    ("ENTREPRENEUR_CD", &ENTREPRENEUR_CD),
+    #[cfg(feature = "dict-facility")]
+    ("EST_CLASS_CD", &EST_CLASS_CD),
+    #[cfg(feature = "dict-facility")]
+    ("FACILITIES_CLASSIFICATION_CD", &FACILITIES_CLASSIFICATION_CD),
+    #[cfg(feature = "dict-facility")]
+    ("FACILITY_TYPE_CODE", &FACILITY_TYPE_CODE),
+    #[cfg(feature = "dict-facility")]
+    ("FIREHOUSE_TYPE", &FIREHOUSE_TYPE),
+    #[cfg(feature = "dict-transport")]
+    ("FISH_PORT_ADMIN_CD", &FISH_PORT_ADMIN_CD),
+    #[cfg(feature = "dict-policy")]
+    ("FLOOD_DURATION_CODE", &FLOOD_DURATION_CODE),
+    #[cfg(feature = "dict-facility")]
+    ("FUEL_STORE_CD", &FUEL_STORE_CD),
+    #[cfg(feature = "dict-facility")]
+    ("FURNACE_TYPE", &FURNACE_TYPE),
+    #[cfg(feature = "dict-policy")]
+    ("HAZARDOUS_AREA_CLASSIFICATION_CODE", &HAZARDOUS_AREA_CLASSIFICATION_CODE),
+    #[cfg(feature = "dict-policy")]
+    ("HEAVY_SNOW_TYPE_CODE", &HEAVY_SNOW_TYPE_CODE),
+    #[cfg(feature = "dict-transport")]
+    ("HIGHWAY_CAT_CD", &HIGHWAY_CAT_CD),
+    #[cfg(feature = "dict-transport")]
+    ("HIGHWAY_CON_CD", &HIGHWAY_CON_CD),
+    #[cfg(feature = "dict-transport")]
+    ("HIGHWAY_TRANSITION_CD", &HIGHWAY_TRANSITION_CD),
+    #[cfg(feature = "dict-transport")]
+    ("HIGHWAY_USE_CD", &HIGHWAY_USE_CD),
+    #[cfg(feature = "dict-policy")]
+    ("HOANRIN_CD", &HOANRIN_CD),
+    #[cfg(feature = "dict-policy")]
+    ("HOGORIN_CD", &HOGORIN_CD),
+    #[cfg(feature = "dict-facility")]
+    ("HYDROELECTRIC_POWER_PLANT_TYPE", &HYDROELECTRIC_POWER_PLANT_TYPE),
+    #[cfg(feature = "dict-land")]
+    ("INDEX_NUM_L01", &INDEX_NUM_L01),
+    #[cfg(feature = "dict-facility")]
+    ("INDUSTRIAL_WASTE_DISPOSAL", &INDUSTRIAL_WASTE_DISPOSAL),
+    #[cfg(feature = "dict-facility")]
+    ("INDUSTRIAL_WASTE_SPECIAL_TREATMENT", &INDUSTRIAL_WASTE_SPECIAL_TREATMENT),
+    #[cfg(feature = "dict-transport")]
+    ("INSTALL_ADMIN_CD_V2_3", &INSTALL_ADMIN_CD_V2_3),
+    #[cfg(feature = "dict-transport")]
+    ("INSTALL_AIRPORT_CD_V2_3", &INSTALL_AIRPORT_CD_V2_3),
+    #[cfg(feature = "dict-transport")]
+    ("INSTITUTION_TYPE_CD", &INSTITUTION_TYPE_CD),
+    ("IndexNumL02V2_4", &IndexNumL02V2_4),
+    #[cfg(feature = "dict-policy")]
+    ("KASO_CD", &KASO_CD),
+    #[cfg(feature = "dict-policy")]
+    ("KINOURUIKEI_CD", &KINOURUIKEI_CD),
+    #[cfg(feature = "dict-policy")]
+    ("LANDSCAPE_DISTRICT_TYPE", &LANDSCAPE_DISTRICT_TYPE),
+    #[cfg(feature = "dict-land")]
+    ("LAND_USE_CD_09", &LAND_USE_CD_09),
+    #[cfg(feature = "dict-land")]
+    ("LAND_USE_CD_09_U", &LAND_USE_CD_09_U),
+    #[cfg(feature = "dict-land")]
+    ("LAND_USE_CD_77", &LAND_USE_CD_77),
+    #[cfg(feature = "dict-land")]
+    ("LAND_USE_CD_88", &LAND_USE_CD_88),
+    #[cfg(feature = "dict-land")]
+    ("LAND_USE_CD_YY", &LAND_USE_CD_YY),
+    #[cfg(feature = "dict-facility")]
+    ("LARGE_CLASSIFICATION_CD", &LARGE_CLASSIFICATION_CD),
+    #[cfg(feature = "dict-water")]
+    ("LOCATION_ACCURACY_CD", &LOCATION_ACCURACY_CD),
+    #[cfg(feature = "dict-policy")]
+    ("MANAGEMENT_ORGANIZATION_CLASSIFICATION", &MANAGEMENT_ORGANIZATION_CLASSIFICATION),
+    #[cfg(feature = "dict-transport")]
+    ("MARITIME_ORG_CD", &MARITIME_ORG_CD),
+    #[cfg(feature = "dict-facility")]
+    ("MED_CLASS_CD", &MED_CLASS_CD),
+    #[cfg(feature = "dict-policy")]
+    ("MIDORINOKAIRO_CD", &MIDORINOKAIRO_CD),
+    ("N04FukuinH16", &N04FukuinH16),
+    ("N04FukuinH22", &N04FukuinH22),
+    #[cfg(feature = "dict-transport")]
+    ("N13_ROAD_CATEGORY", &N13_ROAD_CATEGORY),
+    #[cfg(feature = "dict-transport")]
+    ("N13_ROAD_STATE", &N13_ROAD_STATE),
+    #[cfg(feature = "dict-transport")]
+    ("N13_ROAD_TYPE", &N13_ROAD_TYPE),
+    #[cfg(feature = "dict-transport")]
+    ("N13_ROAD_WIDTH", &N13_ROAD_WIDTH),
+    #[cfg(feature = "dict-transport")]
+    ("N13_TOIL_ROAD_SECTION", &N13_TOIL_ROAD_SECTION),
+    #[cfg(feature = "dict-facility")]
+    ("NATURALFEATURE_CD", &NATURALFEATURE_CD),
+    #[cfg(feature = "dict-facility")]
+    ("NATURALSCENE_CD", &NATURALSCENE_CD),
+    ("NaturalParkAreaCd", &NaturalParkAreaCd),
+    ("NatureConservationAreaCd", &NatureConservationAreaCd),
+    #[cfg(feature = "dict-water")]
+    ("ORIGINAL_DATA_CODE_CD", &ORIGINAL_DATA_CODE_CD),
+    #[cfg(feature = "dict-facility")]
+    ("POINT_CLASSIFICATION_CD", &POINT_CLASSIFICATION_CD),
+    #[cfg(feature = "dict-facility")]
+    ("POINT_CLASSIFICATION_CODE", &POINT_CLASSIFICATION_CODE),
+    #[cfg(feature = "dict-facility")]
+    ("POLICE_STATION_CD", &POLICE_STATION_CD),
+    #[cfg(feature = "dict-facility")]
+    ("POST_OFFICE_CD", &POST_OFFICE_CD),
+    #[cfg(feature = "dict-policy")]
+    ("POS_SPECIFIC_LEVEL", &POS_SPECIFIC_LEVEL),
+    #[cfg(feature = "dict-policy")]
+    ("PROTECTION_AREA_TYPE", &PROTECTION_AREA_TYPE),
+    ("PTAreaCd", &PTAreaCd),
+    #[cfg(feature = "dict-facility")]
+    ("PUB_FAC_ADMIN_CD", &PUB_FAC_ADMIN_CD),
+    #[cfg(feature = "dict-facility")]
+    ("PUB_FAC_MACLASS_CD", &PUB_FAC_MACLASS_CD),
+    #[cfg(feature = "dict-facility")]
+    ("PUB_FAC_MICLASS_CD_WF", &PUB_FAC_MICLASS_CD_WF),
+    #[cfg(feature = "dict-facility")]
+    ("PUB_FAC_MINCLASS_CD", &PUB_FAC_MINCLASS_CD),
+    #[cfg(feature = "dict-facility")]
+    ("PUB_OFFICE_CD", &PUB_OFFICE_CD),
+    #[cfg(feature = "dict-facility")]
+    ("PUB_OFFICE_CLASS_CD", &PUB_OFFICE_CLASS_CD),
+    #[cfg(feature = "dict-facility")]
+    ("PUMPINGUP_TYPE", &PUMPINGUP_TYPE),
+    ("PubFacMiclassCd", &PubFacMiclassCd),
+    #[cfg(feature = "dict-transport")]
+    ("RAILWAY_CLASS2_CD", &RAILWAY_CLASS2_CD),
+    #[cfg(feature = "dict-transport")]
+    ("RAILWAY_CLASS_CD", &RAILWAY_CLASS_CD),
+    #[cfg(feature = "dict-transport")]
+    ("RAILWAY_DUPLICATE_CD", &RAILWAY_DUPLICATE_CD),
+    #[cfg(feature = "dict-transport")]
+    ("RAILWAY_EXISTENCE_CD", &RAILWAY_EXISTENCE_CD),
+    #[cfg(feature = "dict-transport")]
+    ("RAILWAY_TRANSITION_CD", &RAILWAY_TRANSITION_CD),
+    #[cfg(feature = "dict-facility")]
+    ("REFERECED_FROM_AGRI", &REFERECED_FROM_AGRI),
+    #[cfg(feature = "dict-land")]
+    ("REFERENCE_DATA_CD", &REFERENCE_DATA_CD),
+    #[cfg(feature = "dict-transport")]
+    ("REGULAR_FLIGHT_CD", &REGULAR_FLIGHT_CD),
+    #[cfg(feature = "dict-policy")]
+    ("REGULATORY_AREA_CLASSIFICATION", &REGULATORY_AREA_CLASSIFICATION),
+    #[cfg(feature = "dict-facility")]
+    ("RESEARCH_INSTITUTION_CD", &RESEARCH_INSTITUTION_CD),
+    #[cfg(feature = "dict-policy")]
+    ("RINSHUNOSAIBUN_CD", &RINSHUNOSAIBUN_CD),
+    #[cfg(feature = "dict-policy")]
+    ("RITO_CD", &RITO_CD),
+    #[cfg(feature = "dict-transport")]
+    ("ROAD_CATEGORY_CD", &ROAD_CATEGORY_CD),
+    #[cfg(feature = "dict-facility")]
+    ("SCHOOL_CLASS_CD", &SCHOOL_CLASS_CD),
+    #[cfg(feature = "dict-water")]
+    ("SECTION_TYPE", &SECTION_TYPE),
+    #[cfg(feature = "dict-policy")]
+    ("SEDIMENT_DISASTERS_PRONE_AREA_CD", &SEDIMENT_DISASTERS_PRONE_AREA_CD),
+    #[cfg(feature = "dict-land")]
+    ("SELECT_LAND_STATUS_L01V1", &SELECT_LAND_STATUS_L01V1),
+    #[cfg(feature = "dict-land")]
+    ("SELECT_LAND_STATUS_L01V2", &SELECT_LAND_STATUS_L01V2),
+    #[cfg(feature = "dict-policy")]
+    ("SETTING_FLAG", &SETTING_FLAG),
+    #[cfg(feature = "dict-policy")]
+    ("SHOUHANSHUBAN_CD", &SHOUHANSHUBAN_CD),
+    #[cfg(feature = "dict-facility")]
+    ("SMALL_CLASSIFICATION_CD", &SMALL_CLASSIFICATION_CD),
+    #[cfg(feature = "dict-policy")]
+    ("SPECIFIC_AIRPORT_SPECIFIED_SITUATION_CD", &SPECIFIC_AIRPORT_SPECIFIED_SITUATION_CD),
+    ("SeasideType", &SeasideType),
+    ("SectionCdCyubu", &SectionCdCyubu),
+    ("SectionCdKinki", &SectionCdKinki),
+    ("SectionCdSyuto", &SectionCdSyuto),
+    ("SectionTypeCdCyubu", &SectionTypeCdCyubu),
+    ("SectionTypeCdKinki", &SectionTypeCdKinki),
+    ("SectionTypeCdSyuto", &SectionTypeCdSyuto),
+    ("SubprefectureNameCd", &SubprefectureNameCd),
+    #[cfg(feature = "dict-facility")]
+    ("THERMAL_POWER_ENGINE", &THERMAL_POWER_ENGINE),
+    #[cfg(feature = "dict-policy")]
+    ("TOKUSYUDOJYO_CD_V3_0", &TOKUSYUDOJYO_CD_V3_0),
+    #[cfg(feature = "dict-facility")]
+    ("TOURISM_RESOURCE_CATEGORY_CD", &TOURISM_RESOURCE_CATEGORY_CD),
+    ("TripGenerationCd", &TripGenerationCd),
+    #[cfg(feature = "dict-land")]
+    ("UNDERSEA", &UNDERSEA),
+    #[cfg(feature = "dict-facility")]
+    ("UNDER_CONSTRUCTION", &UNDER_CONSTRUCTION),
+    #[cfg(feature = "dict-facility")]
+    ("URBAN_PLANNING_DECIDED", &URBAN_PLANNING_DECIDED),
+    #[cfg(feature = "dict-transport")]
+    ("URGENT_ROAD_CD", &URGENT_ROAD_CD),
+    #[cfg(feature = "dict-land")]
+    ("USE_DISTRICT", &USE_DISTRICT),
+    ("UrbanPlanningAreaCd_2019", &UrbanPlanningAreaCd_2019),
+    ("UseDistrictCd", &UseDistrictCd),
+    #[cfg(feature = "dict-policy")]
+    ("WATER_DEPTH_CODE", &WATER_DEPTH_CODE),
+    #[cfg(feature = "dict-facility")]
+    ("WATER_SUPPLY_TYPE", &WATER_SUPPLY_TYPE),
+    #[cfg(feature = "dict-water")]
+    ("WATER_SYSTEM_CODE_CD", &WATER_SYSTEM_CODE_CD),
+    #[cfg(feature = "dict-policy")]
+    ("WORLD_HERITAGE_CD", &WORLD_HERITAGE_CD),
+    ("WelfareFacMiclassCdH23", &WelfareFacMiclassCdH23),
+    ("WelfareFacMiclassCdH27", &WelfareFacMiclassCdH27),
+];
+const _: () = assert!(super::is_sorted_by_key(CODELISTS));
+
+// The codelists of the columns, sorted by the column name. The codelists of the
+// disabled categories are not included.
+
+#[cfg(feature = "dict-policy")]
+#[rustfmt::skip]
+pub(crate) const CODELIST_COLUMNS_POLICY: &[(&str, &Codelist)] = &[
+    ("A03SectionTypeCdCyubu", &A03_SECTION_TYPE_CD_CYUBU),
+    ("A03SectionTypeCdKinki", &A03_SECTION_TYPE_CD_KINKI),
+    ("A03SectionTypeCdSyuto", &A03_SECTION_TYPE_CD_SYUTO),
+    ("A03_007", &A03_SECTION_CD),
+    ("A15_003", &AUTHORITY_TYPE),
+    ("A15_004", &PROTECTION_AREA_TYPE),
+    ("A17_009", &KASO_CD),
+    ("A18_008", &SPECIFIC_AIRPORT_SPECIFIED_SITUATION_CD),
+    ("A19_009", &RITO_CD),
+    ("A19_010", &SPECIFIC_AIRPORT_SPECIFIED_SITUATION_CD),
+    ("A20_008", &SPECIFIC_AIRPORT_SPECIFIED_SITUATION_CD),
+    ("A21_007", &SPECIFIC_AIRPORT_SPECIFIED_SITUATION_CD),
+    ("A22_007", &HEAVY_SNOW_TYPE_CODE),
+    ("A22_008", &SPECIFIC_AIRPORT_SPECIFIED_SITUATION_CD),
+    ("A22_009", &HEAVY_SNOW_TYPE_CODE),
+    ("A22_050001", &POS_SPECIFIC_LEVEL),
+    ("A22_100005", &AGGREGATE_UNIT_FLAG),
+    ("A22_100007", &AGGREGATE_UNIT_FLAG),
+    ("A22_40009", &POS_SPECIFIC_LEVEL),
+    ("A23_009", &TOKUSYUDOJYO_CD_V3_0),
+    ("A26_005", &SEDIMENT_DISASTERS_PRONE_AREA_CD),
+    ("A31_101", &WATER_DEPTH_CODE),
+    ("A31_201", &WATER_DEPTH_CODE),
+    ("A31_301", &FLOOD_DURATION_CODE),
+    ("A31_401", &HAZARDOUS_AREA_CLASSIFICATION_CODE),
+    ("A33_001", &CODE_OF_PHENOMENON),
+    ("A33_002", &CODE_OF_ZONE_H27),
+    ("A33_008", &CODE_OF_UN_SPECIFICATION),
+    ("A35d_009", &LANDSCAPE_DISTRICT_TYPE),
+    ("A35e_009", &LANDSCAPE_DISTRICT_TYPE),
+    ("A35f_007", &LANDSCAPE_DISTRICT_TYPE),
+    ("A37_300007", &AGG_UNIT_FLAG_EMER_TRANS_CD),
+    ("A38a_005", &SETTING_FLAG),
+    ("A39_021", &CODE_NONCOMBUSTIBLE_CD),
+    ("A39_025", &CODE_DESIGNATION_CD),
+    ("A42HistoricalDistrictType", &A42_HISTORICAL_DISTRICT_TYPE),
+    ("A45_006", &SHOUHANSHUBAN_CD),
+    ("A45_025", &RINSHUNOSAIBUN_CD),
+    ("A45_026", &KINOURUIKEI_CD),
+    ("A45_028", &HOANRIN_CD),
+    ("A45_029", &HOANRIN_CD),
+    ("A45_030", &HOANRIN_CD),
+    ("A45_031", &HOANRIN_CD),
+    ("A45_032", &HOGORIN_CD),
+    ("A45_033", &MIDORINOKAIRO_CD),
+    ("A56_004", &REGULATORY_AREA_CLASSIFICATION),
+    ("A56_005", &MANAGEMENT_ORGANIZATION_CLASSIFICATION),
+    ("AREA_CD", &A10_AREA_CD), // A10~A13
+    ("IOSIDE_DIV", &A10_INSIDE_DIV), // A10~A13
+    ("LAYER_NO", &A10_LAYER_NO), // A10~A13
+    ("WHC", &WORLD_HERITAGE_CD), // A28
+];
+#[cfg(feature = "dict-policy")]
+const _: () = assert!(super::is_sorted_by_key(CODELIST_COLUMNS_POLICY));
+
+#[cfg(feature = "dict-transport")]
+#[rustfmt::skip]
+pub(crate) const CODELIST_COLUMNS_TRANSPORT: &[(&str, &Codelist)] = &[
+    ("C02_001", &CLASS_HARBOR1_CD),
+    ("C02_002", &CLASS_HARBOR2_CD),
+    ("C02_006", &ADMIN_HARBOR_CD),
+    ("C02_010", &MARITIME_ORG_CD),
+    ("C09_004", &CLASS_FISH_PORT_CD),
+    ("C09_005", &FISH_PORT_ADMIN_CD),
+    ("C23_002", &ADMIN_SEA_LINE_CD),
+    ("C23_005", &ADMIN_CON_AREA_CD),
+    ("C28_003", &INSTALL_AIRPORT_CD_V2_3),
+    ("C28_006", &INSTALL_ADMIN_CD_V2_3),
+    ("C28_007", &INSTALL_ADMIN_CD_V2_3),
+    ("C28_011", &REGULAR_FLIGHT_CD),
+    ("N02_001", &RAILWAY_CLASS_CD),
+    ("N02_002", &INSTITUTION_TYPE_CD),
+    ("N05_001", &RAILWAY_CLASS2_CD),
+    ("N05_007", &RAILWAY_TRANSITION_CD),
+    ("N06_005", &HIGHWAY_TRANSITION_CD),
+    ("N06_008", &HIGHWAY_CAT_CD),
+    ("N06_009", &HIGHWAY_USE_CD),
+    ("N06_016", &HIGHWAY_TRANSITION_CD),
+    ("N06_019", &HIGHWAY_CON_CD),
+    ("N07_001", &BUS_CLASS_CD),
+    ("N08_002", &AIRPORT_CAT_CD),
+    ("N08_004", &INSTALL_ADMIN_CD_V2_3),
+    ("N08_005", &INSTALL_ADMIN_CD_V2_3),
+    ("N08_010", &REGULAR_FLIGHT_CD),
+    ("N08_011", &AIR_JET_CD),
+    ("N08_013", &AIRPORT_USE_CD),
+    ("N08_017", &AIRPORT_TRANSITION_CD),
+    ("N08_021", &AIRPORT_USE_CD),
+    ("N10_002", &URGENT_ROAD_CD),
+    ("N10_003", &ROAD_CATEGORY_CD),
+    ("N11_002", &AVIATION_ACT_CD),
+    ("N13_002", &N13_ROAD_TYPE),
+    ("N13_003", &N13_ROAD_CATEGORY),
+    ("N13_004", &N13_ROAD_STATE),
+    ("N13_006", &N13_ROAD_WIDTH),
+    ("N13_007", &N13_TOIL_ROAD_SECTION),
+    ("S12_004", &RAILWAY_CLASS_CD),
+    ("S12_005", &INSTITUTION_TYPE_CD),
+    ("S12_006", &RAILWAY_DUPLICATE_CD),
+    ("S12_007", &RAILWAY_EXISTENCE_CD),
+    ("S12_010", &RAILWAY_DUPLICATE_CD),
+    ("S12_011", &RAILWAY_EXISTENCE_CD),
+    ("S12_014", &RAILWAY_DUPLICATE_CD),
+    ("S12_015", &RAILWAY_EXISTENCE_CD),
+    ("S12_018", &RAILWAY_DUPLICATE_CD),
+    ("S12_019", &RAILWAY_EXISTENCE_CD),
+    ("S12_022", &RAILWAY_DUPLICATE_CD),
+    ("S12_023", &RAILWAY_EXISTENCE_CD),
+    ("S12_026", &RAILWAY_DUPLICATE_CD),
+    ("S12_027", &RAILWAY_EXISTENCE_CD),
+    ("S12_030", &RAILWAY_DUPLICATE_CD),
+    ("S12_031", &RAILWAY_EXISTENCE_CD),
+    ("S12_034", &RAILWAY_DUPLICATE_CD),
+    ("S12_035", &RAILWAY_EXISTENCE_CD),
+];
+#[cfg(feature = "dict-transport")]
+const _: () = assert!(super::is_sorted_by_key(CODELIST_COLUMNS_TRANSPORT));
+
+#[cfg(feature = "dict-land")]
+#[rustfmt::skip]
+pub(crate) const CODELIST_COLUMNS_LAND: &[(&str, &Codelist)] = &[
+    ("G04a_005", &UNDERSEA),
+    ("G04a_007", &DIRECTION),
+    ("G04a_009", &DIRECTION),
+    ("G04c_005", &UNDERSEA),
+    ("G04c_007", &DIRECTION),
+    ("G04c_009", &DIRECTION),
+    ("G04d_005", &UNDERSEA),
+    ("G04d_007", &DIRECTION),
+    ("G04d_009", &DIRECTION),
+    ("G08_003", &REFERENCE_DATA_CD),
+    ("IndexNumL01", &INDEX_NUM_L01), // L01
+    ("L05_013", &USE_DISTRICT),
+    ("LandUseCd09", &LAND_USE_CD_09), // L03-b
+    ("LandUseCd09-u", &LAND_USE_CD_09_U), // L03-b
+    ("LandUseCd77", &LAND_USE_CD_77), // L03-b
+    ("LandUseCd88", &LAND_USE_CD_88), // L03-b
+    ("LandUseCdYY", &LAND_USE_CD_YY), // L03-b
+    ("SelectLandStatusL01V1", &SELECT_LAND_STATUS_L01V1), // L01
+    ("SelectLandStatusL01V2", &SELECT_LAND_STATUS_L01V2), // L01
+];
+#[cfg(feature = "dict-land")]
+const _: () = assert!(super::is_sorted_by_key(CODELIST_COLUMNS_LAND));
+
+#[cfg(feature = "dict-facility")]
+#[rustfmt::skip]
+pub(crate) const CODELIST_COLUMNS_FACILITY: &[(&str, &Codelist)] = &[
+    ("P03_0004", &UNDER_CONSTRUCTION),
+    ("P03_0102", &HYDROELECTRIC_POWER_PLANT_TYPE),
+    ("P03_0209", &PUMPINGUP_TYPE),
+    ("P03_0404", &THERMAL_POWER_ENGINE),
+    ("P03_0602", &FURNACE_TYPE),
+    ("P03_0901", &BIOMASS_TYPE),
+    ("P04_001", &MED_CLASS_CD),
+    ("P04_007", &EST_CLASS_CD),
+    ("P07_001", &FUEL_STORE_CD),
+    ("P11_002", &BUS_CLASS_CD),
+    ("P12_007", &TOURISM_RESOURCE_CATEGORY_CD),
+    ("P13_004", &CITY_PARK_CD),
+    ("P13_009", &URBAN_PLANNING_DECIDED),
+    ("P14_004", &PUB_FAC_MACLASS_CD),
+    ("P14_005", &PUB_FAC_MICLASS_CD_WF),
+    ("P14_008", &PUB_FAC_ADMIN_CD),
+    ("P15_003", &FACILITIES_CLASSIFICATION_CD),
+    ("P15_017", &INDUSTRIAL_WASTE_DISPOSAL),
+    ("P15_018", &INDUSTRIAL_WASTE_SPECIAL_TREATMENT),
+    ("P16_002", &RESEARCH_INSTITUTION_CD),
+    ("P17_003", &FIREHOUSE_TYPE),
+    ("P18_003", &POLICE_STATION_CD),
+    ("P19_004", &NATURALSCENE_CD),
+    ("P19_006", &NATURALFEATURE_CD),
+    ("P21A_003", &WATER_SUPPLY_TYPE),
+    ("P24_011", &REFERECED_FROM_AGRI),
+    ("P26_009", &BUSINESS_TECH_CD),
+    ("P27_002", &PUB_FAC_MACLASS_CD),
+    ("P27_003", &PUB_FAC_MINCLASS_CD),
+    ("P27_004", &CULTURE_FAC_CD),
+    ("P27_007", &ADMIN_CODE),
+    ("P28_002", &PUB_FAC_MACLASS_CD),
+    ("P28_003", &PUB_FAC_MINCLASS_CD),
+    ("P28_007", &ADMIN_CODE),
+    ("P29_002", &PUB_FAC_MACLASS_CD),
+    ("P29_003", &PUB_FAC_MINCLASS_CD),
+    ("P29_004", &SCHOOL_CLASS_CD),
+    ("P29_007", &ADMIN_CODE),
+    ("P30_002", &PUB_FAC_MACLASS_CD),
+    ("P30_003", &PUB_FAC_MINCLASS_CD),
+    ("P30_004", &POST_OFFICE_CD),
+    ("P30_007", &ADMIN_CODE),
+    ("P31_002", &DISTRIBUTION_CENTER_CD),
+    ("P31_003", &DISTRIBUTION_CD),
+    ("P31_006", &ENTREPRENEUR_CD),
+    ("P32_004", &LARGE_CLASSIFICATION_CD),
+    ("P32_005", &SMALL_CLASSIFICATION_CD),
+    ("P32_009", &POINT_CLASSIFICATION_CD),
+    ("P33_004", &FACILITY_TYPE_CODE),
+    ("P33_014", &COMMUNITY_CENTER_TYPE),
+    ("P33_041", &POINT_CLASSIFICATION_CODE),
+    ("P34_002", &PUB_OFFICE_CLASS_CD),
+    ("PubOfficeCd", &PUB_OFFICE_CD), // P05
+];
+#[cfg(feature = "dict-facility")]
+const _: () = assert!(super::is_sorted_by_key(CODELIST_COLUMNS_FACILITY));
+
+#[cfg(feature = "dict-water")]
+#[rustfmt::skip]
+pub(crate) const CODELIST_COLUMNS_WATER: &[(&str, &Codelist)] = &[
+    ("W01_005", &DAM_TYPE_CD),
+    ("W01_006", &DAMP_URPOSE_CD),
+    ("W01_011", &DAM_INSTITUTION_CD),
+    ("W01_014", &LOCATION_ACCURACY_CD),
+    ("W05_001", &WATER_SYSTEM_CODE_CD),
+    ("W05_003", &SECTION_TYPE),
+    ("W05_005", &ORIGINAL_DATA_CODE_CD),
+];
+#[cfg(feature = "dict-water")]
+const _: () = assert!(super::is_sorted_by_key(CODELIST_COLUMNS_WATER));
@@ -1,4143 +1,4196 @@
-#[rustfmt::skip]
-pub(crate) const COLNAMES: &[(&str, &str)] = &[
-
-  ///// A03 /////
-
-  ("A03_001", "行政区域コード"),
-  ("A03_002", "都道府県名"),
-  ("A03_003", "郡市名"),
-  ("A03_004", "区町村名"),
-  ("A03_005", "陸水等区分"),
-  ("A03_006", "区域区分"),
-  ("A03_007", "区域コード"),
-  ("A03_008", "備考"),
-
-  ///// A09 /////
-
-  ("prefec_cd", "都道府県コード"),
-  ("area_cd", "地区コード"),
-  ("layer_no", "レイヤ番号"),
-
-  ///// A10 /////
-
-  ("AREA_CD", "地区コード"),
-  ("AREA_SIZE", "ポリゴン面積"),
-  ("CTV_NAME", "市町村名"),
-  ("FIS_YEAR", "年度"),
-  ("IOSIDE_DIV", "内外区分"),
-  ("LAYER_NO", "レイヤ番号"),
-  ("OBJ_NAME", "名称"),
-  ("PREFEC_CD", "都道府県コード"),
-  ("REMARK_STR", "備考"),
-  ("THEMA_NO", "主題番号"),
-
-  ///// A11 /////
-
-  ("AREA_CD", "地区コード"),
-  ("AREA_SIZE", "ポリゴン面積"),
-  ("CTV_NAME", "市町村名"),
-  ("FIS_YEAR", "年度"),
-  ("IOSIDE_DIV", "内外区分"),
-  ("LAYER_NO", "レイヤ番号"),
-  ("OBJ_NAME", "名称"),
-  ("PREFEC_CD", "都道府県コード"),
-  ("REMARK_STR", "備考"),
-  ("THEMA_NO", "主題番号"),
-
-  ///// A12 /////
-
-  ("AREA_CD", "地区コード"),
-  ("AREA_SIZE", "ポリゴン面積"),
-  ("CTV_NAME", "市町村名"),
-  ("FIS_YEAR", "年度"),
-  ("IOSIDE_DIV", "内外区分"),
-  ("LAYER_NO", "レイヤ番号"),
-  ("OBJ_NAME", "名称"),
-  ("PREFEC_CD", "都道府県コード"),
-  ("REMARK_STR", "備考"),
-  ("THEMA_NO", "主題番号"),
-
-  ///// A13 /////
-
-  ("AREA_CD", "地区コード"),
-  ("AREA_SIZE", "ポリゴン面積"),
-  ("CTV_NAME", "市町村名"),
-  ("FIS_YEAR", "年度"),
-  ("IOSIDE_DIV", "内外区分"),
-  ("LAYER_NO", "レイヤ番号"),
-  ("OBJ_NAME", "名称"),
-  ("PREFEC_CD", "都道府県コード"),
-  ("REMARK_STR", "備考"),
-  ("THEMA_NO", "主題番号"),
-
-  ///// A15 /////
-
-  ("A15_001", "鳥獣保護区名"),
-  ("A15_002", "都道府県コード"),
-  ("A15_003", "指定機関：指定機関コード"),
-  ("A15_004", "保護区分：保護区分コード"),
-  ("A15_005", "指定日"),
-  ("A15_006", "解除日"),
-
-  ///// A16 /////
-
-  ("A16_001", "DIDid"),
-  ("A16_002", "市区町村コード"),
-  ("A16_003", "市区町村名称"),
-  ("A16_004", "人口集中地区符合"),
-  ("A16_005", "人口"),
-  ("A16_006", "面積"),
-  ("A16_007", "前回人口"),
-  ("A16_008", "前回面積"),
-  ("A16_009", "全域に占める人口集中地区の人口割合"),
-  ("A16_010", "全域に占める人口集中地区の面積割合"),
-  ("A16_011", "国勢調査年度"),
-
-  ///// A17 /////
-
-  ("A17_001", "過疎ID"),
-  ("A17_002", "行政区域コード"),
-  ("A17_003", "都道府県名"),
-  ("A17_004", "支庁・振興局名"),
-  ("A17_005", "郡・政令都市名"),
-  ("A17_006", "市区町村名"),
-  ("A17_007", "原典市区町村名"),
-  ("A17_008", "旧市町村名"),
-  ("A17_009", "過疎区分コード"),
-
-  ///// A18 /////
-
-  ("A18_001", "半島ID"),
-  ("A18_002", "行政区域コード"),
-  ("A18_003", "都道府県名"),
-  ("A18_004", "支庁・振興局名"),
-  ("A18_005", "郡・政令都市名"),
-  ("A18_006", "市区町村名"),
-  ("A18_007", "原典市区町村名"),
-  ("A18_008", "旧市町村名"),
-  ("A18_009", "指定地域名"),
-  ("A18_010", "指定日"),
-
-  ///// A18s-a /////
-
-  ("A18_001", "半島振興対策実施地域ID"),
-  ("A18_002", "行政コード"),
-  ("A18_003", "都道府県名"),
-  ("A18_004", "支庁名"),
-  ("A18_005", "郡・政令市名"),
-  ("A18_006", "市区町村名"),
-  ("A18_007", "指定地域名"),
-  ("A18_008", "指定状況コード"),
-  ("A18_009", "調査年度"),
-  ("A18_010", "概要　人口"),
-  ("A18_011", "概要　世帯数"),
-  ("A18_012", "男　国勢調査人口総数"),
-  ("A18_013", "男　年少人口　0-4"),
-  ("A18_014", "男　年少人口　5-9"),
-  ("A18_015", "男　年少人口　10-14"),
-  ("A18_016", "男　年少人口　小計"),
-  ("A18_017", "男　生産年齢人口　15-19"),
-  ("A18_018", "男　生産年齢人口　20-24"),
-  ("A18_019", "男　生産年齢人口　25-29"),
-  ("A18_020", "男　生産年齢人口　30-34"),
-  ("A18_021", "男　生産年齢人口　35-39"),
-  ("A18_022", "男　生産年齢人口　40-44"),
-  ("A18_023", "男　生産年齢人口　45-49"),
-  ("A18_024", "男　生産年齢人口　50-54"),
-  ("A18_025", "男　生産年齢人口　55-59"),
-  ("A18_026", "男　生産年齢人口　60-64"),
-  ("A18_027", "男　生産年齢人口　小計"),
-  ("A18_028", "男　老年人口　65-69"),
-  ("A18_029", "男　老年人口　70-74"),
-  ("A18_030", "男　老年人口　75-79"),
-  ("A18_031", "男　老年人口　80-84"),
-  ("A18_032", "男　老年人口　85歳以上"),
-  ("A18_033", "男　老年人口　小計"),
-  ("A18_034", "男　不詳　"),
-  ("A18_035", "女　国勢調査人口総数　"),
-  ("A18_036", "女　年少人口　0-4"),
-  ("A18_037", "女　年少人口　5-9"),
-  ("A18_038", "女　年少人口　10-14"),
-  ("A18_039", "女　年少人口　小計"),
-  ("A18_040", "女　生産年齢人口　15-19"),
-  ("A18_041", "女　生産年齢人口　20-24"),
-  ("A18_042", "女　生産年齢人口　25-29"),
-  ("A18_043", "女　生産年齢人口　30-34"),
-  ("A18_044", "女　生産年齢人口　35-39"),
-  ("A18_045", "女　生産年齢人口　40-44"),
-  ("A18_046", "女　生産年齢人口　45-49"),
-  ("A18_047", "女　生産年齢人口　50-54"),
-  ("A18_048", "女　生産年齢人口　55-59"),
-  ("A18_049", "女　生産年齢人口　60-64"),
-  ("A18_050", "女　生産年齢人口　小計"),
-  ("A18_051", "女　老年人口　65-69"),
-  ("A18_052", "女　老年人口　70-74"),
-  ("A18_053", "女　老年人口　75-79"),
-  ("A18_054", "女　老年人口　80-84"),
-  ("A18_055", "女　老年人口　85歳以上"),
-  ("A18_056", "女　老年人口　小計"),
-  ("A18_057", "女　不詳　"),
-  ("A18_058", "男女計　国勢調査人口総数　"),
-  ("A18_059", "男女計　年少人口　0-4"),
-  ("A18_060", "男女計　年少人口　5-9"),
-  ("A18_061", "男女計　年少人口　10-14"),
-  ("A18_062", "男女計　年少人口　小計"),
-  ("A18_063", "男女計　生産年齢人口　15-19"),
-  ("A18_064", "男女計　生産年齢人口　20-24"),
-  ("A18_065", "男女計　生産年齢人口　25-29"),
-  ("A18_066", "男女計　生産年齢人口　30-34"),
-  ("A18_067", "男女計　生産年齢人口　35-39"),
-  ("A18_068", "男女計　生産年齢人口　40-44"),
-  ("A18_069", "男女計　生産年齢人口　45-49"),
-  ("A18_070", "男女計　生産年齢人口　50-54"),
-  ("A18_071", "男女計　生産年齢人口　55-59"),
-  ("A18_072", "男女計　生産年齢人口　60-64"),
-  ("A18_073", "男女計　生産年齢人口　小計"),
-  ("A18_074", "男女計　老年人口　65-69"),
-  ("A18_075", "男女計　老年人口　70-74"),
-  ("A18_076", "男女計　老年人口　75-79"),
-  ("A18_077", "男女計　老年人口　80-84"),
-  ("A18_078", "男女計　老年人口　85歳以上"),
-  ("A18_079", "男女計　老年人口　小計"),
-  ("A18_080", "男女計　不詳　"),
-  ("A18_081", "就業者数　就業者総数　"),
-  ("A18_082", "就業者数　第1次　農業"),
-  ("A18_083", "就業者数　第1次　農業、林業"),
-  ("A18_084", "就業者数　第1次　農業、林業（うち農業）"),
-  ("A18_085", "就業者数　第1次　林業"),
-  ("A18_086", "就業者数　第1次　漁業"),
-  ("A18_087", "就業者数　第1次　小計"),
-  ("A18_088", "就業者数　第2次　鉱業"),
-  ("A18_089", "就業者数　第2次　鉱業，採石業，砂利採取業"),
-  ("A18_090", "就業者数　第2次　建設業"),
-  ("A18_091", "就業者数　第2次　製造業"),
-  ("A18_092", "就業者数　第2次　小計"),
-  ("A18_093", "就業者数　第3次　電気・ガス・熱供給・水道業"),
-  ("A18_094", "就業者数　第3次　運輸・通信業"),
-  ("A18_095", "就業者数　第3次　情報・通信業"),
-  ("A18_096", "就業者数　第3次　運輸業"),
-  ("A18_097", "就業者数　第3次　運輸業、郵便業"),
-  ("A18_098", "就業者数　第3次　卸売・小売業・飲食店"),
-  ("A18_099", "就業者数　第3次　卸売・小売業"),
-  ("A18_100", "就業者数　第3次　金融・保険業"),
-  ("A18_101", "就業者数　第3次　不動産業"),
-  ("A18_102", "就業者数　第3次　不動産業、物品賃貸業"),
-  ("A18_103", "就業者数　第3次　学術研究，専門・技術サービス業"),
-  ("A18_104", "就業者数　第3次　飲食店・宿泊業"),
-  ("A18_105", "就業者数　第3次　宿泊業，飲食サービス業　"),
-  ("A18_106", "就業者数　第3次　生活関連サービス業，娯楽業"),
-  ("A18_107", "就業者数　第3次　医療･福祉"),
-  ("A18_108", "就業者数　第3次　教育・学習支援業"),
-  ("A18_109", "就業者数　第3次　複合サービス事業"),
-  ("A18_110", "就業者数　第3次　サービス業"),
-  ("A18_111", "就業者数　第3次　公務"),
-  ("A18_112", "就業者数　第3次　小計"),
-  ("A18_113", "就業者数　分類不能　"),
-  ("A18_114", "調査年度"),
-  ("A18_115", "事業所　全産業"),
-  ("A18_116", "事業所　全産業（S公務を除く）"),
-  ("A18_117", "事業所　農林漁業"),
-  ("A18_118", "事業所　非農林漁業1"),
-  ("A18_119", "事業所　非農林漁業2"),
-  ("A18_120", "事業所　鉱業"),
-  ("A18_121", "事業所　鉱業、採石業、砂利採取業"),
-  ("A18_122", "事業所　建設業"),
-  ("A18_123", "事業所　製造業"),
-  ("A18_124", "事業所　電気・ガス・熱供給・水道業"),
-  ("A18_125", "事業所　運輸・通信業"),
-  ("A18_126", "事業所　情報・通信業"),
-  ("A18_127", "事業所　運輸業"),
-  ("A18_128", "事業所　運輸業、郵便業"),
-  ("A18_129", "事業所　卸売・小売業・飲食業"),
-  ("A18_130", "事業所　卸売・小売業"),
-  ("A18_131", "事業所　金融・保険業"),
-  ("A18_132", "事業所　不動産業"),
-  ("A18_133", "事業所　 不動産業、物品賃貸業"),
-  ("A18_134", "事業所　学術研究、専門・技術サービス業"),
-  ("A18_135", "事業所　飲食店・宿泊業"),
-  ("A18_136", "事業所　宿泊業、飲食サービス業"),
-  ("A18_137", "事業所　生活関連サービス業、娯楽業"),
-  ("A18_138", "事業所　医療･福祉"),
-  ("A18_139", "事業所　教育・学習支援業"),
-  ("A18_140", "事業所　複合サービス事業"),
-  ("A18_141", "事業所　サービス業"),
-  ("A18_142", "事業所　公務"),
-  ("A18_143", "事業所　1～4人"),
-  ("A18_144", "事業所　5～9人"),
-  ("A18_145", "事業所　10～19人"),
-  ("A18_146", "事業所　20～29人"),
-  ("A18_147", "事業所　30人以上"),
-  ("A18_148", "事業所　派遣・下請従業者のみ"),
-  ("A18_149", "事業所　経営組織別事業所数（民営事業所）"),
-  ("A18_150", "事業所　事業所の形態別事業所数（民営事業所）"),
-  ("A18_151", "事業所　うち個人"),
-  ("A18_152", "事業所　うち法人"),
-  ("A18_153", "事業所　うち会社"),
-  ("A18_154", "事業所　店舗・飲食店"),
-  ("A18_155", "事業所　事務所・営業所"),
-  ("A18_156", "事業所　工場・作業所・鉱業所"),
-  ("A18_157", "事業所　輸送センター・配送センター・これらの倉庫"),
-  ("A18_158", "事業所　自家用倉庫・自家用油槽所"),
-  ("A18_159", "事業所　外見上一般の住居と区別しにくい事業所"),
-  ("A18_160", "事業所　その他（学校・病院・寺社・旅館・浴場など）"),
-
-  ///// A19 /////
-
-  ("A19_001", "離島ID"),
-  ("A19_002", "行政区域コード"),
-  ("A19_003", "都道府県名"),
-  ("A19_004", "支庁・振興局名"),
-  ("A19_005", "郡・政令都市名"),
-  ("A19_006", "市区町村名"),
-  ("A19_007", "原典市区町村名"),
-  ("A19_008", "指定地域名"),
-  ("A19_009", "島名"),
-
-  ///// A19s /////
-
-  ("A19_001", "離島振興対策実施地域ID"),
-  ("A19_002", "行政コード"),
-  ("A19_003", "都道府県名"),
-  ("A19_004", "支庁名"),
-  ("A19_005", "郡・政令市名"),
-  ("A19_006", "市区町村名"),
-  ("A19_007", "指定地域名"),
-  ("A19_008", "島嶼名"),
-  ("A19_009", "離島区分コード"),
-  ("A19_010", "指定状況コード"),
-  ("A19_011", "調査年度"),
-  ("A19_012", "概要　(name)"),
-  ("A19_013", "概要　指定回次"),
-  ("A19_014", "概要　郡名"),
-  ("A19_015", "概要　人口"),
-  ("A19_016", "概要　世帯数"),
-  ("A19_017", "概要　面積"),
-  ("A19_018", "概要　海岸延長"),
-  ("A19_019", "概要　備考"),
-  ("A19_020", "前年　住民登録人口　男"),
-  ("A19_021", "前年　住民登録人口　女"),
-  ("A19_022", "前年　住民登録人口　計"),
-  ("A19_023", "前年　世帯数　"),
-  ("A19_024", "人口移動　出生数　"),
-  ("A19_025", "人口移動　死亡数　"),
-  ("A19_026", "人口移動　自然増△減　"),
-  ("A19_027", "人口移動　転入数　"),
-  ("A19_028", "人口移動　転出数　"),
-  ("A19_029", "人口移動　社会増△減　"),
-  ("A19_030", "人口移動　増減計　"),
-  ("A19_031", "現年　住民登録人口　男"),
-  ("A19_032", "現年　住民登録人口　女"),
-  ("A19_033", "現年　住民登録人口　計"),
-  ("A19_034", "現年　世帯数　"),
-  ("A19_035", "人口・世帯数・人口動態　備考"),
-  ("A19_036", "男　国勢調査人口総数"),
-  ("A19_037", "男　年少人口　0-4"),
-  ("A19_038", "男　年少人口　5-9"),
-  ("A19_039", "男　年少人口　10-14"),
-  ("A19_040", "男　年少人口　小計"),
-  ("A19_041", "男　生産年齢人口　15-19"),
-  ("A19_042", "男　生産年齢人口　20-24"),
-  ("A19_043", "男　生産年齢人口　25-29"),
-  ("A19_044", "男　生産年齢人口　30-34"),
-  ("A19_045", "男　生産年齢人口　35-39"),
-  ("A19_046", "男　生産年齢人口　40-44"),
-  ("A19_047", "男　生産年齢人口　45-49"),
-  ("A19_048", "男　生産年齢人口　50-54"),
-  ("A19_049", "男　生産年齢人口　55-59"),
-  ("A19_050", "男　生産年齢人口　60-64"),
-  ("A19_051", "男　生産年齢人口　小計"),
-  ("A19_052", "男　老年人口　65-69"),
-  ("A19_053", "男　老年人口　70-74"),
-  ("A19_054", "男　老年人口　75-79"),
-  ("A19_055", "男　老年人口　80-84"),
-  ("A19_056", "男　老年人口　85歳以上"),
-  ("A19_057", "男　老年人口　小計"),
-  ("A19_058", "男　不詳　"),
-  ("A19_059", "男　生産年齢人口指数　"),
-  ("A19_060", "男　老齢化率　"),
-  ("A19_061", "女　国勢調査人口総数　"),
-  ("A19_062", "女　年少人口　0-4"),
-  ("A19_063", "女　年少人口　5-9"),
-  ("A19_064", "女　年少人口　10-14"),
-  ("A19_065", "女　年少人口　小計"),
-  ("A19_066", "女　生産年齢人口　15-19"),
-  ("A19_067", "女　生産年齢人口　20-24"),
-  ("A19_068", "女　生産年齢人口　25-29"),
-  ("A19_069", "女　生産年齢人口　30-34"),
-  ("A19_070", "女　生産年齢人口　35-39"),
-  ("A19_071", "女　生産年齢人口　40-44"),
-  ("A19_072", "女　生産年齢人口　45-49"),
-  ("A19_073", "女　生産年齢人口　50-54"),
-  ("A19_074", "女　生産年齢人口　55-59"),
-  ("A19_075", "女　生産年齢人口　60-64"),
-  ("A19_076", "女　生産年齢人口　小計"),
-  ("A19_077", "女　老年人口　65-69"),
-  ("A19_078", "女　老年人口　70-74"),
-  ("A19_079", "女　老年人口　75-79"),
-  ("A19_080", "女　老年人口　80-84"),
-  ("A19_081", "女　老年人口　85歳以上"),
-  ("A19_082", "女　老年人口　小計"),
-  ("A19_083", "女　不詳　"),
-  ("A19_084", "女　生産年齢人口指数　"),
-  ("A19_085", "女　老齢化率　"),
-  ("A19_086", "男女計　国勢調査人口総数　"),
-  ("A19_087", "男女計　年少人口　0-4"),
-  ("A19_088", "男女計　年少人口　5-9"),
-  ("A19_089", "男女計　年少人口　10-14"),
-  ("A19_090", "男女計　年少人口　小計"),
-  ("A19_091", "男女計　生産年齢人口　15-19"),
-  ("A19_092", "男女計　生産年齢人口　20-24"),
-  ("A19_093", "男女計　生産年齢人口　25-29"),
-  ("A19_094", "男女計　生産年齢人口　30-34"),
-  ("A19_095", "男女計　生産年齢人口　35-39"),
-  ("A19_096", "男女計　生産年齢人口　40-44"),
-  ("A19_097", "男女計　生産年齢人口　45-49"),
-  ("A19_098", "男女計　生産年齢人口　50-54"),
-  ("A19_099", "男女計　生産年齢人口　55-59"),
-  ("A19_100", "男女計　生産年齢人口　60-64"),
-  ("A19_101", "男女計　生産年齢人口　小計"),
-  ("A19_102", "男女計　老年人口　65-69"),
-  ("A19_103", "男女計　老年人口　70-74"),
-  ("A19_104", "男女計　老年人口　75-79"),
-  ("A19_105", "男女計　老年人口　80-84"),
-  ("A19_106", "男女計　老年人口　85歳以上"),
-  ("A19_107", "男女計　老年人口　小計"),
-  ("A19_108", "男女計　不詳　"),
-  ("A19_109", "男女計　生産年齢人口指数　"),
-  ("A19_110", "男女計　老齢化率　"),
-  ("A19_111", "年齢別･男女別人口　備考"),
-  ("A19_112", "調査年度"),
-  ("A19_113", "土地利用　総面積　"),
-  ("A19_114", "土地利用　農用地　農用地合計"),
-  ("A19_115", "土地利用　農用地　農地"),
-  ("A19_116", "土地利用　農用地　採草放牧地"),
-  ("A19_117", "土地利用　森林　"),
-  ("A19_118", "土地利用　原野　"),
-  ("A19_119", "土地利用　水面・河川・水路　"),
-  ("A19_120", "土地利用　道路　"),
-  ("A19_121", "土地利用　宅地　"),
-  ("A19_122", "土地利用　その他　"),
-  ("A19_123", "土地利用　備考　"),
-  ("A19_124", "学校　保　保育所数　本所"),
-  ("A19_125", "学校　保　保育所数　分所"),
-  ("A19_126", "学校　保　乳幼児数　在所"),
-  ("A19_127", "学校　保　乳幼児数　通所"),
-  ("A19_128", "学校　幼　幼稚園数　本園"),
-  ("A19_129", "学校　幼　幼稚園数　分園"),
-  ("A19_130", "学校　幼　園児数　在園"),
-  ("A19_131", "学校　幼　園児数　通園"),
-  ("A19_132", "学校　小　学校数　本校"),
-  ("A19_133", "学校　小　学校数　分校"),
-  ("A19_134", "学校　小　教（職）員数　教員数"),
-  ("A19_135", "学校　小　教（職）員数　職員数"),
-  ("A19_136", "学校　小　学級数"),
-  ("A19_137", "学校　小　児童数　在校"),
-  ("A19_138", "学校　小　児童数　通学"),
-  ("A19_139", "学校　中　学校数　本校"),
-  ("A19_140", "学校　中　学校数　分校"),
-  ("A19_141", "学校　中　教（職）員数　教員数"),
-  ("A19_142", "学校　中　教（職）員数　職員数"),
-  ("A19_143", "学校　中　学級数"),
-  ("A19_144", "学校　中　生徒数　在校"),
-  ("A19_145", "学校　中　生徒数　通学"),
-  ("A19_146", "学校　高　学校数　本校"),
-  ("A19_147", "学校　高　学校数　分校"),
-  ("A19_148", "学校　高　教（職）員数　教員数"),
-  ("A19_149", "学校　高　教（職）員数　職員数"),
-  ("A19_150", "学校　高　学級数"),
-  ("A19_151", "学校　高　生徒数　在校"),
-  ("A19_152", "学校　高　生徒数　通学"),
-  ("A19_153", "学校　備考"),
-  ("A19_154", "卒業者状況　中　卒業生数　　"),
-  ("A19_155", "卒業者状況　中　就職　総数　"),
-  ("A19_156", "卒業者状況　中　就職　島内　計"),
-  ("A19_157", "卒業者状況　中　就職　島内　農林業"),
-  ("A19_158", "卒業者状況　中　就職　島内　水産業"),
-  ("A19_159", "卒業者状況　中　就職　島内　2次産業"),
-  ("A19_160", "卒業者状況　中　就職　島内　3次産業"),
-  ("A19_161", "卒業者状況　中　就職　島外　計"),
-  ("A19_162", "卒業者状況　中　就職　島外　1次産業"),
-  ("A19_163", "卒業者状況　中　就職　島外　2次産業"),
-  ("A19_164", "卒業者状況　中　就職　島外　3次産業"),
-  ("A19_165", "卒業者状況　中　進学　総数　"),
-  ("A19_166", "卒業者状況　中　進学　島内　"),
-  ("A19_167", "卒業者状況　中　進学　島外　"),
-  ("A19_168", "卒業者状況　中　進学　進学率　"),
-  ("A19_169", "卒業者状況　中　未就職・その他　島内　"),
-  ("A19_170", "卒業者状況　中　未就職・その他　島外　"),
-  ("A19_171", "卒業者状況　高　卒業生数　　"),
-  ("A19_172", "卒業者状況　高　就職　総数　"),
-  ("A19_173", "卒業者状況　高　就職　島内　計"),
-  ("A19_174", "卒業者状況　高　就職　島内　農林業"),
-  ("A19_175", "卒業者状況　高　就職　島内　水産業"),
-  ("A19_176", "卒業者状況　高　就職　島内　2次産業"),
-  ("A19_177", "卒業者状況　高　就職　島内　3次産業"),
-  ("A19_178", "卒業者状況　高　就職　島外　計"),
-  ("A19_179", "卒業者状況　高　就職　島外　1次産業"),
-  ("A19_180", "卒業者状況　高　就職　島外　2次産業"),
-  ("A19_181", "卒業者状況　高　就職　島外　3次産業"),
-  ("A19_182", "卒業者状況　高　進学　総数　"),
-  ("A19_183", "卒業者状況　高　進学　島内　"),
-  ("A19_184", "卒業者状況　高　進学　島外　"),
-  ("A19_185", "卒業者状況　高　進学　進学率　"),
-  ("A19_186", "卒業者状況　高　未就職・その他　島内　"),
-  ("A19_187", "卒業者状況　高　未就職・その他　島外　"),
-  ("A19_188", "公園等　国調人口　　　"),
-  ("A19_189", "公園等　離島振興関係一括事業　都市公園　都市計画公園　カ所数"),
-  ("A19_190", "公園等　離島振興関係一括事業　都市公園　都市計画公園　面積"),
-  ("A19_191", "公園等　離島振興関係一括事業　都市公園　特定地区公園　カ所数"),
-  ("A19_192", "公園等　離島振興関係一括事業　都市公園　特定地区公園　面積"),
-  ("A19_193", "公園等　離島振興関係一括事業　都市公園　計　カ所数"),
-  ("A19_194", "公園等　離島振興関係一括事業　都市公園　計　面積"),
-  ("A19_195", "公園等　離島振興関係一括事業　港湾環境整備緑地　カ所数　"),
-  ("A19_196", "公園等　離島振興関係一括事業　港湾環境整備緑地　面積　"),
-  ("A19_197", "公園等　離島振興関係一括事業　漁港漁村環境整備　カ所数　"),
-  ("A19_198", "公園等　離島振興関係一括事業　漁港漁村環境整備　面積　"),
-  ("A19_199", "公園等　離島振興関係一括事業　海岸環境整備　カ所数　"),
-  ("A19_200", "公園等　離島振興関係一括事業　海岸環境整備　面積　"),
-  ("A19_201", "公園等　離島振興関係一括事業　農業集落環境整備　カ所数　"),
-  ("A19_202", "公園等　離島振興関係一括事業　農業集落環境整備　面積　"),
-  ("A19_203", "公園等　離島振興関係一括事業　コミュニティ・アイランド　カ所数　"),
-  ("A19_204", "公園等　離島振興関係一括事業　コミュニティ・アイランド　面積　"),
-  ("A19_205", "公園等　離島振興関係一括事業　自然公園施設設備（園地等）　カ所数　"),
-  ("A19_206", "公園等　離島振興関係一括事業　自然公園施設設備（園地等）　面積　"),
-  ("A19_207", "公園等　離島振興関係一括事業　その他　カ所数　"),
-  ("A19_208", "公園等　離島振興関係一括事業　その他　面積　"),
-  ("A19_209", "公園等　離島振興関係一括事業　小計　カ所数　"),
-  ("A19_210", "公園等　離島振興関係一括事業　小計　面積　"),
-  ("A19_211", "公園等　地方公共団体単独事業　カ所数　　"),
-  ("A19_212", "公園等　地方公共団体単独事業　面積　　"),
-  ("A19_213", "公園等　合計　カ所数　　"),
-  ("A19_214", "公園等　合計　面積　　"),
-  ("A19_215", "公園等　一人当たり公園・緑地面積　　　"),
-  ("A19_216", "道路　国道　総延長"),
-  ("A19_217", "道路　国道　重用延長"),
-  ("A19_218", "道路　国道　未供用延長"),
-  ("A19_219", "道路　国道　実延長"),
-  ("A19_220", "道路　国道　実延長　規格改良済延長"),
-  ("A19_221", "道路　国道　実延長　規格改良済延長　内車道幅員55m以上"),
-  ("A19_222", "道路　国道　実延長　未改良延長"),
-  ("A19_223", "道路　国道　実延長　未改良延長　内自動車交通不能"),
-  ("A19_224", "道路　国道　実延長　舗装済延長"),
-  ("A19_225", "道路　国道　実延長　舗装済延長　内本舗装延長"),
-  ("A19_226", "道路　国道　実延長　未舗装延長"),
-  ("A19_227", "道路　国道　歩道設置道路実延長"),
-  ("A19_228", "道路　国道　道路面積　車道面積"),
-  ("A19_229", "道路　国道　道路面積　道路部面積"),
-  ("A19_230", "道路　国道　道路面積　道路敷面積"),
-  ("A19_231", "道路　主要地方道　総延長"),
-  ("A19_232", "道路　主要地方道　重用延長"),
-  ("A19_233", "道路　主要地方道　未供用延長"),
-  ("A19_234", "道路　主要地方道　実延長"),
-  ("A19_235", "道路　主要地方道　実延長　規格改良済延長"),
-  ("A19_236", "道路　主要地方道　実延長　規格改良済延長　内車道幅員55m以上"),
-  ("A19_237", "道路　主要地方道　実延長　未改良延長"),
-  ("A19_238", "道路　主要地方道　実延長　未改良延長　内自動車交通不能"),
-  ("A19_239", "道路　主要地方道　実延長　舗装済延長"),
-  ("A19_240", "道路　主要地方道　実延長　舗装済延長　内本舗装延長"),
-  ("A19_241", "道路　主要地方道　実延長　未舗装延長"),
-  ("A19_242", "道路　主要地方道　歩道設置道路実延長"),
-  ("A19_243", "道路　主要地方道　道路面積　車道面積"),
-  ("A19_244", "道路　主要地方道　道路面積　道路部面積"),
-  ("A19_245", "道路　主要地方道　道路面積　道路敷面積"),
-  ("A19_246", "道路　都道県道　総延長"),
-  ("A19_247", "道路　都道県道　重用延長"),
-  ("A19_248", "道路　都道県道　未供用延長"),
-  ("A19_249", "道路　都道県道　実延長"),
-  ("A19_250", "道路　都道県道　実延長　規格改良済延長"),
-  ("A19_251", "道路　都道県道　実延長　規格改良済延長　内車道幅員55m以上"),
-  ("A19_252", "道路　都道県道　実延長　未改良延長"),
-  ("A19_253", "道路　都道県道　実延長　未改良延長　内自動車交通不能"),
-  ("A19_254", "道路　都道県道　実延長　舗装済延長"),
-  ("A19_255", "道路　都道県道　実延長　舗装済延長　内本舗装延長"),
-  ("A19_256", "道路　都道県道　実延長　未舗装延長"),
-  ("A19_257", "道路　都道県道　歩道設置道路実延長"),
-  ("A19_258", "道路　都道県道　道路面積　車道面積"),
-  ("A19_259", "道路　都道県道　道路面積　道路部面積"),
-  ("A19_260", "道路　都道県道　道路面積　道路敷面積"),
-  ("A19_261", "道路　市町村道　総延長"),
-  ("A19_262", "道路　市町村道　重用延長"),
-  ("A19_263", "道路　市町村道　未供用延長"),
-  ("A19_264", "道路　市町村道　実延長"),
-  ("A19_265", "道路　市町村道　実延長　規格改良済延長"),
-  ("A19_266", "道路　市町村道　実延長　規格改良済延長　内車道幅員55m以上"),
-  ("A19_267", "道路　市町村道　実延長　未改良延長"),
-  ("A19_268", "道路　市町村道　実延長　未改良延長　内自動車交通不能"),
-  ("A19_269", "道路　市町村道　実延長　舗装済延長"),
-  ("A19_270", "道路　市町村道　実延長　舗装済延長　内本舗装延長"),
-  ("A19_271", "道路　市町村道　実延長　未舗装延長"),
-  ("A19_272", "道路　市町村道　歩道設置道路実延長"),
-  ("A19_273", "道路　市町村道　道路面積　車道面積"),
-  ("A19_274", "道路　市町村道　道路面積　道路部面積"),
-  ("A19_275", "道路　市町村道　道路面積　道路敷面積"),
-  ("A19_276", "道路　備考"),
-  ("A19_277", "調査年度"),
-  ("A19_278", "車輌保有台数　保有車両合計"),
-  ("A19_279", "車輌保有台数　登録車両　小計"),
-  ("A19_280", "車輌保有台数　登録車両　トラック　計"),
-  ("A19_281", "車輌保有台数　登録車両　トラック　普通車"),
-  ("A19_282", "車輌保有台数　登録車両　トラック　小型車"),
-  ("A19_283", "車輌保有台数　登録車両　トラック　トレーラー"),
-  ("A19_284", "車輌保有台数　登録車両　バス　"),
-  ("A19_285", "車輌保有台数　登録車両　乗用車　計"),
-  ("A19_286", "車輌保有台数　登録車両　乗用車　普通車"),
-  ("A19_287", "車輌保有台数　登録車両　乗用車　小型車"),
-  ("A19_288", "車輌保有台数　登録車両　特種用途車　"),
-  ("A19_289", "車輌保有台数　登録車両　大型特殊車　"),
-  ("A19_290", "車輌保有台数　軽自動車"),
-  ("A19_291", "車輌保有台数　二輪車"),
-  ("A19_292", "車輌保有台数　原動機付自転車"),
-  ("A19_293", "車輌保有台数　小型特殊車"),
-  ("A19_294", "車輌保有台数　備考"),
-  ("A19_295", "医療　住民登録人口"),
-  ("A19_296", "医療　施設　総数"),
-  ("A19_297", "医療　施設　病院"),
-  ("A19_298", "医療　施設　診療所"),
-  ("A19_299", "医療　施設　診療所　有床診療所"),
-  ("A19_300", "医療　施設　歯科診療所"),
-  ("A19_301", "医療　施設　一般診療所の歯科診療科目"),
-  ("A19_302", "医療　施設　開設者別　国"),
-  ("A19_303", "医療　施設　開設者別　都道県"),
-  ("A19_304", "医療　施設　開設者別　市町村"),
-  ("A19_305", "医療　施設　開設者別　個人"),
-  ("A19_306", "医療　施設　開設者別　その他"),
-  ("A19_307", "医療　病床数"),
-  ("A19_308", "医療　従事者　医師　常勤"),
-  ("A19_309", "医療　従事者　医師　非常勤"),
-  ("A19_310", "医療　従事者　歯科医師　常勤"),
-  ("A19_311", "医療　従事者　歯科医師　非常勤"),
-  ("A19_312", "医療　従事者　保健師　医療施設勤務"),
-  ("A19_313", "医療　従事者　保健師　医療施設外勤務"),
-  ("A19_314", "医療　従事者　助産師　医療施設勤務"),
-  ("A19_315", "医療　従事者　助産師　医療施設外勤務"),
-  ("A19_316", "医療　従事者　看護師　医療施設勤務"),
-  ("A19_317", "医療　従事者　看護師　医療施設外勤務"),
-  ("A19_318", "医療　従事者　歯科衛生士　医療施設勤務"),
-  ("A19_319", "医療　従事者　歯科衛生士　医療施設外勤務"),
-  ("A19_320", "医療　搬送設備等　巡回診療車"),
-  ("A19_321", "医療　搬送設備等　巡回診療船"),
-  ("A19_322", "医療　搬送設備等　患者輸送車"),
-  ("A19_323", "医療　搬送設備等　患者輸送船"),
-  ("A19_324", "医療　備考"),
-  ("A19_325", "水道　住民登録人口"),
-  ("A19_326", "水道　利用人口　上水道"),
-  ("A19_327", "水道　利用人口　専用水道"),
-  ("A19_328", "水道　利用人口　簡易水道"),
-  ("A19_329", "水道　利用人口　計"),
-  ("A19_330", "水道　普及率"),
-  ("A19_331", "水道　その他飲料水利用人口　井戸"),
-  ("A19_332", "水道　その他飲料水利用人口　流湧水"),
-  ("A19_333", "水道　その他飲料水利用人口　天水"),
-  ("A19_334", "水道　その他飲料水利用人口　計"),
-  ("A19_335", "水道　備考"),
-  ("A19_336", "し尿処理状況　住民登録人口"),
-  ("A19_337", "し尿処理状況　計画処理区域内人口　水洗化人口"),
-  ("A19_338", "し尿処理状況　計画処理区域内人口　非水洗化人口　収集人口"),
-  ("A19_339", "し尿処理状況　計画処理区域内人口　非水洗化人口　自家処理人口"),
-  ("A19_340", "し尿処理状況　計画処理区域内人口　計"),
-  ("A19_341", "し尿処理状況　計画処理区域内人口　計画収集率"),
-  ("A19_342", "し尿処理状況　区域内人口比率"),
-  ("A19_343", "し尿処理状況　収集し尿処理内訳　施設処理"),
-  ("A19_344", "し尿処理状況　収集し尿処理内訳　埋立て"),
-  ("A19_345", "し尿処理状況　収集し尿処理内訳　海洋投入"),
-  ("A19_346", "し尿処理状況　収集し尿処理内訳　その他"),
-  ("A19_347", "し尿処理状況　収集し尿処理内訳　計"),
-  ("A19_348", "し尿処理状況　収集し尿処理内訳　施設処理率"),
-  ("A19_349", "し尿処理状況　備考"),
-  ("A19_350", "ごみ処理状況　住民登録人口"),
-  ("A19_351", "ごみ処理状況　計画処理区域内人口　計画収集人口"),
-  ("A19_352", "ごみ処理状況　計画処理区域内人口　自家処理人口"),
-  ("A19_353", "ごみ処理状況　計画処理区域内人口　計"),
-  ("A19_354", "ごみ処理状況　計画処理区域内人口　計画収集率"),
-  ("A19_355", "ごみ処理状況　区域内人口比率"),
-  ("A19_356", "ごみ処理状況　収集ごみ処理内訳　施設処理"),
-  ("A19_357", "ごみ処理状況　収集ごみ処理内訳　埋立て"),
-  ("A19_358", "ごみ処理状況　収集ごみ処理内訳　その他"),
-  ("A19_359", "ごみ処理状況　収集ごみ処理内訳　計"),
-  ("A19_360", "ごみ処理状況　収集ごみ処理内訳　施設処理率"),
-  ("A19_361", "ごみ処理状況　備考"),
-  ("A19_362", "汚水処理状況　住民登録人口"),
-  ("A19_363", "汚水処理状況　計画処理区域内人口　公共・流域下水道"),
-  ("A19_364", "汚水処理状況　計画処理区域内人口　農業集落排水"),
-  ("A19_365", "汚水処理状況　計画処理区域内人口　漁業集落環境整備"),
-  ("A19_366", "汚水処理状況　計画処理区域内人口　コミュニティ・プラント"),
-  ("A19_367", "汚水処理状況　計画処理区域内人口　合併処理浄化槽"),
-  ("A19_368", "汚水処理状況　計画処理区域内人口　その他"),
-  ("A19_369", "汚水処理状況　計画処理区域内人口　計"),
-  ("A19_370", "汚水処理状況　区域内人口比率"),
-  ("A19_371", "汚水処理状況　処理人口　公共・流域下水道"),
-  ("A19_372", "汚水処理状況　処理人口　農業集落排水"),
-  ("A19_373", "汚水処理状況　処理人口　漁業集落環境整備"),
-  ("A19_374", "汚水処理状況　処理人口　コミュニティ・プラント"),
-  ("A19_375", "汚水処理状況　処理人口　合併処理浄化槽"),
-  ("A19_376", "汚水処理状況　処理人口　その他"),
-  ("A19_377", "汚水処理状況　処理人口　計"),
-  ("A19_378", "汚水処理状況　処理人口普及率"),
-  ("A19_379", "汚水処理状況　備考"),
-  ("A19_380", "財政1　決算　基準財政需要額"),
-  ("A19_381", "財政1　決算　基準財政収入額"),
-  ("A19_382", "財政1　決算　財政力指数"),
-  ("A19_383", "財政1　現在　積立金現在高"),
-  ("A19_384", "財政1　現在　地方債現在高"),
-  ("A19_385", "財政1　歳入　地方税収入"),
-  ("A19_386", "財政1　歳入　地方交付税"),
-  ("A19_387", "財政1　歳入　国庫支出金"),
-  ("A19_388", "財政1　歳入　都道県支出金"),
-  ("A19_389", "財政1　歳入　地方債"),
-  ("A19_390", "財政1　歳入　その他"),
-  ("A19_391", "財政1　歳入　計"),
-  ("A19_392", "財政2　歳出　議会費"),
-  ("A19_393", "財政2　歳出　総務費"),
-  ("A19_394", "財政2　歳出　民生費"),
-  ("A19_395", "財政2　歳出　衛生費"),
-  ("A19_396", "財政2　歳出　労働費"),
-  ("A19_397", "財政2　歳出　農林水産費"),
-  ("A19_398", "財政2　歳出　商工費"),
-  ("A19_399", "財政2　歳出　土木費"),
-  ("A19_400", "財政2　歳出　消防費"),
-  ("A19_401", "財政2　歳出　教育費"),
-  ("A19_402", "財政2　歳出　災害復旧費"),
-  ("A19_403", "財政2　歳出　公債費"),
-  ("A19_404", "財政2　歳出　諸支出金"),
-  ("A19_405", "財政2　歳出　計"),
-  ("A19_406", "調査年度"),
-  ("A19_407", "就業者数　就業者総数　"),
-  ("A19_408", "就業者数　第1次　農業"),
-  ("A19_409", "就業者数　第1次　農業、林業"),
-  ("A19_410", "就業者数　第1次　農業、林業（うち農業）"),
-  ("A19_411", "就業者数　第1次　林業"),
-  ("A19_412", "就業者数　第1次　漁業"),
-  ("A19_413", "就業者数　第1次　小計"),
-  ("A19_414", "就業者数　第2次　鉱業"),
-  ("A19_415", "就業者数　第2次　鉱業，採石業，砂利採取業"),
-  ("A19_416", "就業者数　第2次　建設業"),
-  ("A19_417", "就業者数　第2次　製造業"),
-  ("A19_418", "就業者数　第2次　小計"),
-  ("A19_419", "就業者数　第3次　電気・ガス・熱供給・水道業"),
-  ("A19_420", "就業者数　第3次　運輸・通信業"),
-  ("A19_421", "就業者数　第3次　情報・通信業"),
-  ("A19_422", "就業者数　第3次　運輸業"),
-  ("A19_423", "就業者数　第3次　運輸業、郵便業"),
-  ("A19_424", "就業者数　第3次　卸売・小売業・飲食業"),
-  ("A19_425", "就業者数　第3次　卸売・小売業"),
-  ("A19_426", "就業者数　第3次　金融・保険業"),
-  ("A19_427", "就業者数　第3次　不動産業"),
-  ("A19_428", "就業者数　第3次　不動産業、物品賃貸業"),
-  ("A19_429", "就業者数　第3次　学術研究，専門・技術サービス業"),
-  ("A19_430", "就業者数　第3次　飲食店・宿泊業"),
-  ("A19_431", "就業者数　第3次　宿泊業，飲食サービス業　"),
-  ("A19_432", "就業者数　第3次　生活関連サービス業，娯楽業"),
-  ("A19_433", "就業者数　第3次　医療･福祉"),
-  ("A19_434", "就業者数　第3次　教育・学習支援業"),
-  ("A19_435", "就業者数　第3次　複合サービス事業"),
-  ("A19_436", "就業者数　第3次　サービス業"),
-  ("A19_437", "就業者数　第3次　公務"),
-  ("A19_438", "就業者数　第3次　小計"),
-  ("A19_439", "就業者数　分類不能　"),
-  ("A19_440", "就業者数　備考　"),
-  ("A19_441", "事業所数　総数　"),
-  ("A19_442", "事業所数　第1次　農業"),
-  ("A19_443", "事業所数　第1次　林業"),
-  ("A19_444", "事業所数　第1次　漁業"),
-  ("A19_445", "事業所数　第1次　小計"),
-  ("A19_446", "事業所数　第2次　鉱業"),
-  ("A19_447", "事業所数　第2次　建設業"),
-  ("A19_448", "事業所数　第2次　製造業"),
-  ("A19_449", "事業所数　第2次　小計"),
-  ("A19_450", "事業所数　第3次　電気・ガス・熱供給・水道業"),
-  ("A19_451", "事業所数　第3次　運輸・通信業"),
-  ("A19_452", "事業所数　第3次　卸売・小売業・飲食業"),
-  ("A19_453", "事業所数　第3次　金融・保険業"),
-  ("A19_454", "事業所数　第3次　不動産業"),
-  ("A19_455", "事業所数　第3次　サービス業"),
-  ("A19_456", "事業所数　第3次　公務"),
-  ("A19_457", "事業所数　第3次　小計"),
-  ("A19_458", "事業所数　分類不能　"),
-  ("A19_459", "従業者数　総数　"),
-  ("A19_460", "従業者数　第1次　農業"),
-  ("A19_461", "従業者数　第1次　林業"),
-  ("A19_462", "従業者数　第1次　漁業"),
-  ("A19_463", "従業者数　第1次　小計"),
-  ("A19_464", "従業者数　第2次　鉱業"),
-  ("A19_465", "従業者数　第2次　建設業"),
-  ("A19_466", "従業者数　第2次　製造業"),
-  ("A19_467", "従業者数　第2次　小計"),
-  ("A19_468", "従業者数　第3次　電気・ガス・熱供給・水道業"),
-  ("A19_469", "従業者数　第3次　運輸・通信業"),
-  ("A19_470", "従業者数　第3次　卸売・小売業・飲食業"),
-  ("A19_471", "従業者数　第3次　金融・保険業"),
-  ("A19_472", "従業者数　第3次　不動産業"),
-  ("A19_473", "従業者数　第3次　サービス業"),
-  ("A19_474", "従業者数　第3次　公務"),
-  ("A19_475", "従業者数　第3次　小計"),
-  ("A19_476", "従業者数　分類不能　"),
-  ("A19_477", "農林業　総面積　"),
-  ("A19_478", "農林業　農振面積　"),
-  ("A19_479", "農林業　耕地面積　総数"),
-  ("A19_480", "農林業　耕地面積　田"),
-  ("A19_481", "農林業　耕地面積　畑"),
-  ("A19_482", "農林業　耕地面積　樹園地"),
-  ("A19_483", "農林業　耕地面積　牧草地"),
-  ("A19_484", "農林業　耕地化率　"),
-  ("A19_485", "農林業　家畜飼育数　乳用牛"),
-  ("A19_486", "農林業　家畜飼育数　肉用牛"),
-  ("A19_487", "農林業　家畜飼育数　豚"),
-  ("A19_488", "農林業　家畜飼育数　鶏"),
-  ("A19_489", "農林業　農道延長　"),
-  ("A19_490", "農林業　林道延長　"),
-  ("A19_491", "農林業　林道延長　内国有林道"),
-  ("A19_492", "農林業　森林面積　総数　"),
-  ("A19_493", "農林業　森林面積　総数　うち人工林"),
-  ("A19_494", "農林業　森林面積　国有林　"),
-  ("A19_495", "農林業　森林面積　国有林　うち人工林"),
-  ("A19_496", "農林業　森林面積　民有林　"),
-  ("A19_497", "農林業　森林面積　民有林　うち人工林"),
-  ("A19_498", "農林業　森林蓄積量　"),
-  ("A19_499", "農林業　備考　"),
-  ("A19_500", "水産業現況_港湾　水揚高　属人水揚　計(水揚トン数)"),
-  ("A19_501", "水産業現況_港湾　水揚高　属人水揚　計(水揚金額)"),
-  ("A19_502", "水産業現況_港湾　水揚高　属人水揚　海面漁業(水揚トン数)"),
-  ("A19_503", "水産業現況_港湾　水揚高　属人水揚　海面漁業(水揚金額)"),
-  ("A19_504", "水産業現況_港湾　水揚高　属人水揚　海面養殖業(水揚トン数)"),
-  ("A19_505", "水産業現況_港湾　水揚高　属人水揚　海面養殖業(水揚金額)"),
-  ("A19_506", "水産業現況_港湾　水揚高　属地水揚　計(水揚トン数)"),
-  ("A19_507", "水産業現況_港湾　水揚高　属地水揚　計(水揚金額)"),
-  ("A19_508", "水産業現況_港湾　水揚高　属地水揚　海面漁業(水揚トン数)"),
-  ("A19_509", "水産業現況_港湾　水揚高　属地水揚　海面漁業(水揚金額)"),
-  ("A19_510", "水産業現況_港湾　水揚高　属地水揚　海面養殖業(水揚トン数)"),
-  ("A19_511", "水産業現況_港湾　水揚高　属地水揚　海面養殖業(水揚金額)"),
-  ("A19_512", "水産業現況_漁港　水揚高　属人水揚　計(水揚トン数)"),
-  ("A19_513", "水産業現況_漁港　水揚高　属人水揚　計(水揚金額)"),
-  ("A19_514", "水産業現況_漁港　水揚高　属人水揚　海面漁業(水揚トン数)"),
-  ("A19_515", "水産業現況_漁港　水揚高　属人水揚　海面漁業(水揚金額)"),
-  ("A19_516", "水産業現況_漁港　水揚高　属人水揚　海面養殖業(水揚トン数)"),
-  ("A19_517", "水産業現況_漁港　水揚高　属人水揚　海面養殖業(水揚金額)"),
-  ("A19_518", "水産業現況_漁港　水揚高　属地水揚　計(水揚トン数)"),
-  ("A19_519", "水産業現況_漁港　水揚高　属地水揚　計(水揚金額)"),
-  ("A19_520", "水産業現況_漁港　水揚高　属地水揚　海面漁業(水揚トン数)"),
-  ("A19_521", "水産業現況_漁港　水揚高　属地水揚　海面漁業(水揚金額)"),
-  ("A19_522", "水産業現況_漁港　水揚高　属地水揚　海面養殖業(水揚トン数)"),
-  ("A19_523", "水産業現況_漁港　水揚高　属地水揚　海面養殖業(水揚金額)"),
-  ("A19_524", "経営体　農家戸数　総数　　"),
-  ("A19_525", "経営体　農家戸数　販売農業　専業　"),
-  ("A19_526", "経営体　農家戸数　販売農業　兼業　1種"),
-  ("A19_527", "経営体　農家戸数　販売農業　兼業　2種"),
-  ("A19_528", "経営体　農家戸数　自給的農家　　"),
-  ("A19_529", "経営体　漁家戸数　総数　　"),
-  ("A19_530", "経営体　漁家戸数　専業　　"),
-  ("A19_531", "経営体　漁家戸数　兼業　1種　"),
-  ("A19_532", "経営体　漁家戸数　兼業　2種　"),
-  ("A19_533", "経営体　林家戸数　総数　　"),
-  ("A19_534", "経営体　林家戸数　農家林家　　"),
-  ("A19_535", "経営体　林家戸数　非農家林家　　"),
-  ("A19_536", "経営体　組合数　農業協同組合　組合数（本部）"),
-  ("A19_537", "経営体　組合数　農業協同組合　組合数（支部・支所）"),
-  ("A19_538", "経営体　組合数　農業協同組合　組合員数"),
-  ("A19_539", "経営体　組合数　漁業協同組合　組合数（本部）"),
-  ("A19_540", "経営体　組合数　漁業協同組合　組合数（支部・支所）"),
-  ("A19_541", "経営体　組合数　漁業協同組合　組合員数"),
-  ("A19_542", "経営体　組合数　森林組合　組合数（本部）"),
-  ("A19_543", "経営体　組合数　森林組合　組合数（支部・支所）"),
-  ("A19_544", "経営体　組合数　森林組合　組合員数"),
-  ("A19_545", "生産額　農業　耕種　米"),
-  ("A19_546", "生産額　農業　耕種　麦"),
-  ("A19_547", "生産額　農業　耕種　いも"),
-  ("A19_548", "生産額　農業　耕種　豆･雑穀"),
-  ("A19_549", "生産額　農業　耕種　野菜"),
-  ("A19_550", "生産額　農業　耕種　果実"),
-  ("A19_551", "生産額　農業　耕種　花卉"),
-  ("A19_552", "生産額　農業　耕種　工芸作物"),
-  ("A19_553", "生産額　農業　耕種　その他"),
-  ("A19_554", "生産額　農業　耕種　小計"),
-  ("A19_555", "生産額　農業　養蚕　"),
-  ("A19_556", "生産額　農業　畜産　牛肉"),
-  ("A19_557", "生産額　農業　畜産　豚肉"),
-  ("A19_558", "生産額　農業　畜産　牛乳"),
-  ("A19_559", "生産額　農業　畜産　鶏卵"),
-  ("A19_560", "生産額　農業　畜産　その他"),
-  ("A19_561", "生産額　農業　畜産　小計"),
-  ("A19_562", "生産額　農業　合計　"),
-  ("A19_563", "生産額　林業　木材　用材"),
-  ("A19_564", "生産額　林業　木材　薪炭材"),
-  ("A19_565", "生産額　林業　木炭　"),
-  ("A19_566", "生産額　林業　しいたけ　"),
-  ("A19_567", "生産額　林業　その他　"),
-  ("A19_568", "生産額　林業　合計　"),
-  ("A19_569", "生産額　水産業　魚類　"),
-  ("A19_570", "生産額　水産業　貝類　"),
-  ("A19_571", "生産額　水産業　水産動物　"),
-  ("A19_572", "生産額　水産業　海草類　"),
-  ("A19_573", "生産額　水産業　その他　"),
-  ("A19_574", "生産額　水産業　合計　"),
-  ("A19_575", "生産額　農林水産業総計　　"),
-  ("A19_576", "生産額　備考　　"),
-  ("A19_577", "観光客数　春　"),
-  ("A19_578", "観光客数　夏　"),
-  ("A19_579", "観光客数　秋　"),
-  ("A19_580", "観光客数　冬　"),
-  ("A19_581", "観光客数　計　"),
-  ("A19_582", "宿泊能力(最盛期)　旅館・ホテル　数"),
-  ("A19_583", "宿泊能力(最盛期)　旅館・ホテル　収容能力"),
-  ("A19_584", "宿泊能力(最盛期)　民宿　数"),
-  ("A19_585", "宿泊能力(最盛期)　民宿　収容能力"),
-  ("A19_586", "宿泊能力　年間宿泊者数　　"),
-  ("A19_587", "自然公園面積　国立公園　"),
-  ("A19_588", "自然公園面積　国定公園　"),
-  ("A19_589", "自然公園面積　県立公園　"),
-  ("A19_590", "観光客数・宿泊能力・自然公園　備考　　"),
-  ("A19_591", "港湾・航路現況_港湾　港湾利用状況　入港船舶　隻数"),
-  ("A19_592", "港湾・航路現況_港湾　総屯数"),
-  ("A19_593", "港湾・航路現況_港湾　乗降人員　"),
-  ("A19_594", "港湾・航路現況_港湾　海上出入貨物　出"),
-  ("A19_595", "港湾・航路現況_港湾　海上出入貨物　入"),
-  ("A19_596", "港湾・航路現況_港湾　海上出入貨物　計"),
-  ("A19_597", "港湾・航路現況_漁港　港湾利用状況　入港船舶　隻数"),
-  ("A19_598", "港湾・航路現況_漁港　総屯数"),
-  ("A19_599", "港湾・航路現況_漁港　乗降人員　"),
-  ("A19_600", "港湾・航路現況_漁港　海上出入貨物　出"),
-  ("A19_601", "港湾・航路現況_漁港　海上出入貨物　入"),
-  ("A19_602", "港湾・航路現況_漁港　海上出入貨物　計"),
-  ("A19_603", "空港・航空路現況　都道県名　空港名　　"),
-  ("A19_604", "空港・航空路現況　運航実績"),
-  ("A19_605", "空港・航空路現況　　1日当たり運航回数　最盛期"),
-  ("A19_606", "空港・航空路現況　　年間乗降人員　"),
-
-  ///// A20 /////
-
-  ("A20_001", "奄美ID"),
-  ("A20_002", "行政コード"),
-  ("A20_003", "都道府県名"),
-  ("A20_004", "支庁名"),
-  ("A20_005", "郡・政令市名"),
-  ("A20_006", "市区町村名"),
-  ("A20_007", "島嶼名"),
-  ("A20_008", "指定日"),
-
-  ///// A20s /////
-
-  ("A20_001", "奄美群島ID"),
-  ("A20_002", "行政コード"),
-  ("A20_003", "都道府県名"),
-  ("A20_004", "支庁名"),
-  ("A20_005", "郡・政令市名"),
-  ("A20_006", "市区町村名"),
-  ("A20_007", "島嶼名"),
-  ("A20_008", "指定状況コード"),
-  ("A20_009", "調査年度"),
-  ("A20_010", "概要　(name)"),
-  ("A20_011", "概要　指定回次"),
-  ("A20_012", "概要　郡名"),
-  ("A20_013", "概要　人口"),
-  ("A20_014", "概要　世帯数"),
-  ("A20_015", "概要　面積"),
-  ("A20_016", "概要　海岸延長"),
-  ("A20_017", "概要　備考"),
-  ("A20_018", "前年　住民登録人口　男"),
-  ("A20_019", "前年　住民登録人口　女"),
-  ("A20_020", "前年　住民登録人口　計"),
-  ("A20_021", "前年　世帯数　"),
-  ("A20_022", "人口移動　出生数　"),
-  ("A20_023", "人口移動　死亡数　"),
-  ("A20_024", "人口移動　自然増△減　"),
-  ("A20_025", "人口移動　転入数　"),
-  ("A20_026", "人口移動　転出数　"),
-  ("A20_027", "人口移動　社会増△減　"),
-  ("A20_028", "人口移動　増減計　"),
-  ("A20_029", "現年　住民登録人口　男"),
-  ("A20_030", "現年　住民登録人口　女"),
-  ("A20_031", "現年　住民登録人口　計"),
-  ("A20_032", "現年　世帯数　"),
-  ("A20_033", "人口・世帯数・人口動態　備考"),
-  ("A20_034", "男　国勢調査人口総数"),
-  ("A20_035", "男　年少人口　0-4"),
-  ("A20_036", "男　年少人口　5-9"),
-  ("A20_037", "男　年少人口　10-14"),
-  ("A20_038", "男　年少人口　小計"),
-  ("A20_039", "男　生産年齢人口　15-19"),
-  ("A20_040", "男　生産年齢人口　20-24"),
-  ("A20_041", "男　生産年齢人口　25-29"),
-  ("A20_042", "男　生産年齢人口　30-34"),
-  ("A20_043", "男　生産年齢人口　35-39"),
-  ("A20_044", "男　生産年齢人口　40-44"),
-  ("A20_045", "男　生産年齢人口　45-49"),
-  ("A20_046", "男　生産年齢人口　50-54"),
-  ("A20_047", "男　生産年齢人口　55-59"),
-  ("A20_048", "男　生産年齢人口　60-64"),
-  ("A20_049", "男　生産年齢人口　小計"),
-  ("A20_050", "男　老年人口　65-69"),
-  ("A20_051", "男　老年人口　70-74"),
-  ("A20_052", "男　老年人口　75-79"),
-  ("A20_053", "男　老年人口　80-84"),
-  ("A20_054", "男　老年人口　85歳以上"),
-  ("A20_055", "男　老年人口　小計"),
-  ("A20_056", "男　不詳　"),
-  ("A20_057", "男　生産年齢人口指数　"),
-  ("A20_058", "男　老齢化率　"),
-  ("A20_059", "女　国勢調査人口総数　"),
-  ("A20_060", "女　年少人口　0-4"),
-  ("A20_061", "女　年少人口　5-9"),
-  ("A20_062", "女　年少人口　10-14"),
-  ("A20_063", "女　年少人口　小計"),
-  ("A20_064", "女　生産年齢人口　15-19"),
-  ("A20_065", "女　生産年齢人口　20-24"),
-  ("A20_066", "女　生産年齢人口　25-29"),
-  ("A20_067", "女　生産年齢人口　30-34"),
-  ("A20_068", "女　生産年齢人口　35-39"),
-  ("A20_069", "女　生産年齢人口　40-44"),
-  ("A20_070", "女　生産年齢人口　45-49"),
-  ("A20_071", "女　生産年齢人口　50-54"),
-  ("A20_072", "女　生産年齢人口　55-59"),
-  ("A20_073", "女　生産年齢人口　60-64"),
-  ("A20_074", "女　生産年齢人口　小計"),
-  ("A20_075", "女　老年人口　65-69"),
-  ("A20_076", "女　老年人口　70-74"),
-  ("A20_077", "女　老年人口　75-79"),
-  ("A20_078", "女　老年人口　80-84"),
-  ("A20_079", "女　老年人口　85歳以上"),
-  ("A20_080", "女　老年人口　小計"),
-  ("A20_081", "女　不詳　"),
-  ("A20_082", "女　生産年齢人口指数　"),
-  ("A20_083", "女　老齢化率　"),
-  ("A20_084", "男女計　国勢調査人口総数　"),
-  ("A20_085", "男女計　年少人口　0-4"),
-  ("A20_086", "男女計　年少人口　5-9"),
-  ("A20_087", "男女計　年少人口　10-14"),
-  ("A20_088", "男女計　年少人口　小計"),
-  ("A20_089", "男女計　生産年齢人口　15-19"),
-  ("A20_090", "男女計　生産年齢人口　20-24"),
-  ("A20_091", "男女計　生産年齢人口　25-29"),
-  ("A20_092", "男女計　生産年齢人口　30-34"),
-  ("A20_093", "男女計　生産年齢人口　35-39"),
-  ("A20_094", "男女計　生産年齢人口　40-44"),
-  ("A20_095", "男女計　生産年齢人口　45-49"),
-  ("A20_096", "男女計　生産年齢人口　50-54"),
-  ("A20_097", "男女計　生産年齢人口　55-59"),
-  ("A20_098", "男女計　生産年齢人口　60-64"),
-  ("A20_099", "男女計　生産年齢人口　小計"),
-  ("A20_100", "男女計　老年人口　65-69"),
-  ("A20_101", "男女計　老年人口　70-74"),
-  ("A20_102", "男女計　老年人口　75-79"),
-  ("A20_103", "男女計　老年人口　80-84"),
-  ("A20_104", "男女計　老年人口　85歳以上"),
-  ("A20_105", "男女計　老年人口　小計"),
-  ("A20_106", "男女計　不詳　"),
-  ("A20_107", "男女計　生産年齢人口指数　"),
-  ("A20_108", "男女計　老齢化率　"),
-  ("A20_109", "年齢別･男女別人口　備考"),
-  ("A20_110", "調査年度"),
-  ("A20_111", "土地利用　総面積　"),
-  ("A20_112", "土地利用　農用地　農用地合計"),
-  ("A20_113", "土地利用　農用地　農地"),
-  ("A20_114", "土地利用　農用地　採草放牧地"),
-  ("A20_115", "土地利用　森林　"),
-  ("A20_116", "土地利用　原野　"),
-  ("A20_117", "土地利用　水面・河川・水路　"),
-  ("A20_118", "土地利用　道路　"),
-  ("A20_119", "土地利用　宅地　"),
-  ("A20_120", "土地利用　その他　"),
-  ("A20_121", "土地利用　備考　"),
-  ("A20_122", "学校　保　保育所数　本所"),
-  ("A20_123", "学校　保　保育所数　分所"),
-  ("A20_124", "学校　保　乳幼児数　在所"),
-  ("A20_125", "学校　保　乳幼児数　通所"),
-  ("A20_126", "学校　幼　幼稚園数　本園"),
-  ("A20_127", "学校　幼　幼稚園数　分園"),
-  ("A20_128", "学校　幼　園児数　在園"),
-  ("A20_129", "学校　幼　園児数　通園"),
-  ("A20_130", "学校　小　学校数　本校"),
-  ("A20_131", "学校　小　学校数　分校"),
-  ("A20_132", "学校　小　教（職）員数　教員数"),
-  ("A20_133", "学校　小　教（職）員数　職員数"),
-  ("A20_134", "学校　小　学級数"),
-  ("A20_135", "学校　小　児童数　在校"),
-  ("A20_136", "学校　小　児童数　通学"),
-  ("A20_137", "学校　中　学校数　本校"),
-  ("A20_138", "学校　中　学校数　分校"),
-  ("A20_139", "学校　中　教（職）員数　教員数"),
-  ("A20_140", "学校　中　教（職）員数　職員数"),
-  ("A20_141", "学校　中　学級数"),
-  ("A20_142", "学校　中　生徒数　在校"),
-  ("A20_143", "学校　中　生徒数　通学"),
-  ("A20_144", "学校　高　学校数　本校"),
-  ("A20_145", "学校　高　学校数　分校"),
-  ("A20_146", "学校　高　教（職）員数　教員数"),
-  ("A20_147", "学校　高　教（職）員数　職員数"),
-  ("A20_148", "学校　高　学級数"),
-  ("A20_149", "学校　高　生徒数　在校"),
-  ("A20_150", "学校　高　生徒数　通学"),
-  ("A20_151", "学校　備考"),
-  ("A20_152", "卒業者状況　中　卒業生数　　"),
-  ("A20_153", "卒業者状況　中　就職　総数　"),
-  ("A20_154", "卒業者状況　中　就職　島内　計"),
-  ("A20_155", "卒業者状況　中　就職　島内　農林業"),
-  ("A20_156", "卒業者状況　中　就職　島内　水産業"),
-  ("A20_157", "卒業者状況　中　就職　島内　2次産業"),
-  ("A20_158", "卒業者状況　中　就職　島内　3次産業"),
-  ("A20_159", "卒業者状況　中　就職　島外　計"),
-  ("A20_160", "卒業者状況　中　就職　島外　1次産業"),
-  ("A20_161", "卒業者状況　中　就職　島外　2次産業"),
-  ("A20_162", "卒業者状況　中　就職　島外　3次産業"),
-  ("A20_163", "卒業者状況　中　進学　総数　"),
-  ("A20_164", "卒業者状況　中　進学　島内　"),
-  ("A20_165", "卒業者状況　中　進学　島外　"),
-  ("A20_166", "卒業者状況　中　進学　進学率　"),
-  ("A20_167", "卒業者状況　中　未就職・その他　島内　"),
-  ("A20_168", "卒業者状況　中　未就職・その他　島外　"),
-  ("A20_169", "卒業者状況　高　卒業生数　　"),
-  ("A20_170", "卒業者状況　高　就職　総数　"),
-  ("A20_171", "卒業者状況　高　就職　島内　計"),
-  ("A20_172", "卒業者状況　高　就職　島内　農林業"),
-  ("A20_173", "卒業者状況　高　就職　島内　水産業"),
-  ("A20_174", "卒業者状況　高　就職　島内　2次産業"),
-  ("A20_175", "卒業者状況　高　就職　島内　3次産業"),
-  ("A20_176", "卒業者状況　高　就職　島外　計"),
-  ("A20_177", "卒業者状況　高　就職　島外　1次産業"),
-  ("A20_178", "卒業者状況　高　就職　島外　2次産業"),
-  ("A20_179", "卒業者状況　高　就職　島外　3次産業"),
-  ("A20_180", "卒業者状況　高　進学　総数　"),
-  ("A20_181", "卒業者状況　高　進学　島内　"),
-  ("A20_182", "卒業者状況　高　進学　島外　"),
-  ("A20_183", "卒業者状況　高　進学　進学率　"),
-  ("A20_184", "卒業者状況　高　未就職・その他　島内　"),
-  ("A20_185", "卒業者状況　高　未就職・その他　島外　"),
-  ("A20_186", "公園等　国調人口　　　"),
-  ("A20_187", "公園等　離島振興関係一括事業　都市公園　都市計画公園　カ所数"),
-  ("A20_188", "公園等　離島振興関係一括事業　都市公園　都市計画公園　面積"),
-  ("A20_189", "公園等　離島振興関係一括事業　都市公園　特定地区公園　カ所数"),
-  ("A20_190", "公園等　離島振興関係一括事業　都市公園　特定地区公園　面積"),
-  ("A20_191", "公園等　離島振興関係一括事業　都市公園　計　カ所数"),
-  ("A20_192", "公園等　離島振興関係一括事業　都市公園　計　面積"),
-  ("A20_193", "公園等　離島振興関係一括事業　港湾環境整備緑地　カ所数　"),
-  ("A20_194", "公園等　離島振興関係一括事業　港湾環境整備緑地　面積　"),
-  ("A20_195", "公園等　離島振興関係一括事業　漁港漁村環境整備　カ所数　"),
-  ("A20_196", "公園等　離島振興関係一括事業　漁港漁村環境整備　面積　"),
-  ("A20_197", "公園等　離島振興関係一括事業　海岸環境整備　カ所数　"),
-  ("A20_198", "公園等　離島振興関係一括事業　海岸環境整備　面積　"),
-  ("A20_199", "公園等　離島振興関係一括事業　農業集落環境整備　カ所数　"),
-  ("A20_200", "公園等　離島振興関係一括事業　農業集落環境整備　面積　"),
-  ("A20_201", "公園等　離島振興関係一括事業　コミュニティ・アイランド　カ所数　"),
-  ("A20_202", "公園等　離島振興関係一括事業　コミュニティ・アイランド　面積　"),
-  ("A20_203", "公園等　離島振興関係一括事業　自然公園施設設備（園地等）　カ所数　"),
-  ("A20_204", "公園等　離島振興関係一括事業　自然公園施設設備（園地等）　面積　"),
-  ("A20_205", "公園等　離島振興関係一括事業　その他　カ所数　"),
-  ("A20_206", "公園等　離島振興関係一括事業　その他　面積　"),
-  ("A20_207", "公園等　離島振興関係一括事業　小計　カ所数　"),
-  ("A20_208", "公園等　離島振興関係一括事業　小計　面積　"),
-  ("A20_209", "公園等　地方公共団体単独事業　カ所数　　"),
-  ("A20_210", "公園等　地方公共団体単独事業　面積　　"),
-  ("A20_211", "公園等　合計　カ所数　　"),
-  ("A20_212", "公園等　合計　面積　　"),
-  ("A20_213", "公園等　一人当たり公園・緑地面積　　　"),
-  ("A20_214", "道路　国道　総延長"),
-  ("A20_215", "道路　国道　重用延長"),
-  ("A20_216", "道路　国道　未供用延長"),
-  ("A20_217", "道路　国道　実延長"),
-  ("A20_218", "道路　国道　実延長　規格改良済延長"),
-  ("A20_219", "道路　国道　実延長　規格改良済延長　内車道幅員55m以上"),
-  ("A20_220", "道路　国道　実延長　未改良延長"),
-  ("A20_221", "道路　国道　実延長　未改良延長　内自動車交通不能"),
-  ("A20_222", "道路　国道　実延長　舗装済延長"),
-  ("A20_223", "道路　国道　実延長　舗装済延長　内本舗装延長"),
-  ("A20_224", "道路　国道　実延長　未舗装延長"),
-  ("A20_225", "道路　国道　歩道設置道路実延長"),
-  ("A20_226", "道路　国道　道路面積　車道面積"),
-  ("A20_227", "道路　国道　道路面積　道路部面積"),
-  ("A20_228", "道路　国道　道路面積　道路敷面積"),
-  ("A20_229", "道路　主要地方道　総延長"),
-  ("A20_230", "道路　主要地方道　重用延長"),
-  ("A20_231", "道路　主要地方道　未供用延長"),
-  ("A20_232", "道路　主要地方道　実延長"),
-  ("A20_233", "道路　主要地方道　実延長　規格改良済延長"),
-  ("A20_234", "道路　主要地方道　実延長　規格改良済延長　内車道幅員55m以上"),
-  ("A20_235", "道路　主要地方道　実延長　未改良延長"),
-  ("A20_236", "道路　主要地方道　実延長　未改良延長　内自動車交通不能"),
-  ("A20_237", "道路　主要地方道　実延長　舗装済延長"),
-  ("A20_238", "道路　主要地方道　実延長　舗装済延長　内本舗装延長"),
-  ("A20_239", "道路　主要地方道　実延長　未舗装延長"),
-  ("A20_240", "道路　主要地方道　歩道設置道路実延長"),
-  ("A20_241", "道路　主要地方道　道路面積　車道面積"),
-  ("A20_242", "道路　主要地方道　道路面積　道路部面積"),
-  ("A20_243", "道路　主要地方道　道路面積　道路敷面積"),
-  ("A20_244", "道路　都道県道　総延長"),
-  ("A20_245", "道路　都道県道　重用延長"),
-  ("A20_246", "道路　都道県道　未供用延長"),
-  ("A20_247", "道路　都道県道　実延長"),
-  ("A20_248", "道路　都道県道　実延長　規格改良済延長"),
-  ("A20_249", "道路　都道県道　実延長　規格改良済延長　内車道幅員55m以上"),
-  ("A20_250", "道路　都道県道　実延長　未改良延長"),
-  ("A20_251", "道路　都道県道　実延長　未改良延長　内自動車交通不能"),
-  ("A20_252", "道路　都道県道　実延長　舗装済延長"),
-  ("A20_253", "道路　都道県道　実延長　舗装済延長　内本舗装延長"),
-  ("A20_254", "道路　都道県道　実延長　未舗装延長"),
-  ("A20_255", "道路　都道県道　歩道設置道路実延長"),
-  ("A20_256", "道路　都道県道　道路面積　車道面積"),
-  ("A20_257", "道路　都道県道　道路面積　道路部面積"),
-  ("A20_258", "道路　都道県道　道路面積　道路敷面積"),
-  ("A20_259", "道路　市町村道　総延長"),
-  ("A20_260", "道路　市町村道　重用延長"),
-  ("A20_261", "道路　市町村道　未供用延長"),
-  ("A20_262", "道路　市町村道　実延長"),
-  ("A20_263", "道路　市町村道　実延長　規格改良済延長"),
-  ("A20_264", "道路　市町村道　実延長　規格改良済延長　内車道幅員55m以上"),
-  ("A20_265", "道路　市町村道　実延長　未改良延長"),
-  ("A20_266", "道路　市町村道　実延長　未改良延長　内自動車交通不能"),
-  ("A20_267", "道路　市町村道　実延長　舗装済延長"),
-  ("A20_268", "道路　市町村道　実延長　舗装済延長　内本舗装延長"),
-  ("A20_269", "道路　市町村道　実延長　未舗装延長"),
-  ("A20_270", "道路　市町村道　歩道設置道路実延長"),
-  ("A20_271", "道路　市町村道　道路面積　車道面積"),
-  ("A20_272", "道路　市町村道　道路面積　道路部面積"),
-  ("A20_273", "道路　市町村道　道路面積　道路敷面積"),
-  ("A20_274", "道路　備考"),
-  ("A20_275", "調査年度"),
-  ("A20_276", "車輌保有台数　保有車両合計"),
-  ("A20_277", "車輌保有台数　登録車両　小計"),
-  ("A20_278", "車輌保有台数　登録車両　トラック　計"),
-  ("A20_279", "車輌保有台数　登録車両　トラック　普通車"),
-  ("A20_280", "車輌保有台数　登録車両　トラック　小型車"),
-  ("A20_281", "車輌保有台数　登録車両　トラック　トレーラー"),
-  ("A20_282", "車輌保有台数　登録車両　バス　"),
-  ("A20_283", "車輌保有台数　登録車両　乗用車　計"),
-  ("A20_284", "車輌保有台数　登録車両　乗用車　普通車"),
-  ("A20_285", "車輌保有台数　登録車両　乗用車　小型車"),
-  ("A20_286", "車輌保有台数　登録車両　特種用途車　"),
-  ("A20_287", "車輌保有台数　登録車両　大型特殊車　"),
-  ("A20_288", "車輌保有台数　軽自動車"),
-  ("A20_289", "車輌保有台数　二輪車"),
-  ("A20_290", "車輌保有台数　原動機付自転車"),
-  ("A20_291", "車輌保有台数　小型特殊車"),
-  ("A20_292", "車輌保有台数　備考"),
-  ("A20_293", "医療　住民登録人口"),
-  ("A20_294", "医療　施設　総数"),
-  ("A20_295", "医療　施設　病院"),
-  ("A20_296", "医療　施設　診療所"),
-  ("A20_297", "医療　施設　診療所　有床診療所"),
-  ("A20_298", "医療　施設　歯科診療所"),
-  ("A20_299", "医療　施設　一般診療所の歯科診療科目"),
-  ("A20_300", "医療　施設　開設者別　国"),
-  ("A20_301", "医療　施設　開設者別　都道県"),
-  ("A20_302", "医療　施設　開設者別　市町村"),
-  ("A20_303", "医療　施設　開設者別　個人"),
-  ("A20_304", "医療　施設　開設者別　その他"),
-  ("A20_305", "医療　病床数"),
-  ("A20_306", "医療　従事者　医師　常勤"),
-  ("A20_307", "医療　従事者　医師　非常勤"),
-  ("A20_308", "医療　従事者　歯科医師　常勤"),
-  ("A20_309", "医療　従事者　歯科医師　非常勤"),
-  ("A20_310", "医療　従事者　保健師　医療施設勤務"),
-  ("A20_311", "医療　従事者　保健師　医療施設外勤務"),
-  ("A20_312", "医療　従事者　助産師　医療施設勤務"),
-  ("A20_313", "医療　従事者　助産師　医療施設外勤務"),
-  ("A20_314", "医療　従事者　看護師　医療施設勤務"),
-  ("A20_315", "医療　従事者　看護師　医療施設外勤務"),
-  ("A20_316", "医療　従事者　歯科衛生士　医療施設勤務"),
-  ("A20_317", "医療　従事者　歯科衛生士　医療施設外勤務"),
-  ("A20_318", "医療　搬送設備等　巡回診療車"),
-  ("A20_319", "医療　搬送設備等　巡回診療船"),
-  ("A20_320", "医療　搬送設備等　患者輸送車"),
-  ("A20_321", "医療　搬送設備等　患者輸送船"),
-  ("A20_322", "医療　備考"),
-  ("A20_323", "水道　住民登録人口"),
-  ("A20_324", "水道　利用人口　上水道"),
-  ("A20_325", "水道　利用人口　専用水道"),
-  ("A20_326", "水道　利用人口　簡易水道"),
-  ("A20_327", "水道　利用人口　計"),
-  ("A20_328", "水道　普及率"),
-  ("A20_329", "水道　その他飲料水利用人口　井戸"),
-  ("A20_330", "水道　その他飲料水利用人口　流湧水"),
-  ("A20_331", "水道　その他飲料水利用人口　天水"),
-  ("A20_332", "水道　その他飲料水利用人口　計"),
-  ("A20_333", "水道　備考"),
-  ("A20_334", "し尿処理状況　住民登録人口"),
-  ("A20_335", "し尿処理状況　計画処理区域内人口　水洗化人口"),
-  ("A20_336", "し尿処理状況　計画処理区域内人口　非水洗化人口　収集人口"),
-  ("A20_337", "し尿処理状況　計画処理区域内人口　非水洗化人口　自家処理人口"),
-  ("A20_338", "し尿処理状況　計画処理区域内人口　計"),
-  ("A20_339", "し尿処理状況　計画処理区域内人口　計画収集率"),
-  ("A20_340", "し尿処理状況　区域内人口比率"),
-  ("A20_341", "し尿処理状況　収集し尿処理内訳　施設処理"),
-  ("A20_342", "し尿処理状況　収集し尿処理内訳　埋立て"),
-  ("A20_343", "し尿処理状況　収集し尿処理内訳　海洋投入"),
-  ("A20_344", "し尿処理状況　収集し尿処理内訳　その他"),
-  ("A20_345", "し尿処理状況　収集し尿処理内訳　計"),
-  ("A20_346", "し尿処理状況　収集し尿処理内訳　施設処理率"),
-  ("A20_347", "し尿処理状況　備考"),
-  ("A20_348", "ごみ処理状況　住民登録人口"),
-  ("A20_349", "ごみ処理状況　計画処理区域内人口　計画収集人口"),
-  ("A20_350", "ごみ処理状況　計画処理区域内人口　自家処理人口"),
-  ("A20_351", "ごみ処理状況　計画処理区域内人口　計"),
-  ("A20_352", "ごみ処理状況　計画処理区域内人口　計画収集率"),
-  ("A20_353", "ごみ処理状況　区域内人口比率"),
-  ("A20_354", "ごみ処理状況　収集ごみ処理内訳　施設処理"),
-  ("A20_355", "ごみ処理状況　収集ごみ処理内訳　埋立て"),
-  ("A20_356", "ごみ処理状況　収集ごみ処理内訳　その他"),
-  ("A20_357", "ごみ処理状況　収集ごみ処理内訳　計"),
-  ("A20_358", "ごみ処理状況　収集ごみ処理内訳　施設処理率"),
-  ("A20_359", "ごみ処理状況　備考"),
-  ("A20_360", "汚水処理状況　住民登録人口"),
-  ("A20_361", "汚水処理状況　計画処理区域内人口　公共・流域下水道"),
-  ("A20_362", "汚水処理状況　計画処理区域内人口　農業集落排水"),
-  ("A20_363", "汚水処理状況　計画処理区域内人口　漁業集落環境整備"),
-  ("A20_364", "汚水処理状況　計画処理区域内人口　コミュニティ・プラント"),
-  ("A20_365", "汚水処理状況　計画処理区域内人口　合併処理浄化槽"),
-  ("A20_366", "汚水処理状況　計画処理区域内人口　その他"),
-  ("A20_367", "汚水処理状況　計画処理区域内人口　計"),
-  ("A20_368", "汚水処理状況　区域内人口比率"),
-  ("A20_369", "汚水処理状況　処理人口　公共・流域下水道"),
-  ("A20_370", "汚水処理状況　処理人口　農業集落排水"),
-  ("A20_371", "汚水処理状況　処理人口　漁業集落環境整備"),
-  ("A20_372", "汚水処理状況　処理人口　コミュニティ・プラント"),
-  ("A20_373", "汚水処理状況　処理人口　合併処理浄化槽"),
-  ("A20_374", "汚水処理状況　処理人口　その他"),
-  ("A20_375", "汚水処理状況　処理人口　計"),
-  ("A20_376", "汚水処理状況　処理人口普及率"),
-  ("A20_377", "汚水処理状況　備考"),
-  ("A20_378", "財政1　決算　基準財政需要額"),
-  ("A20_379", "財政1　決算　基準財政収入額"),
-  ("A20_380", "財政1　決算　財政力指数"),
-  ("A20_381", "財政1　現在　積立金現在高"),
-  ("A20_382", "財政1　現在　地方債現在高"),
-  ("A20_383", "財政1　歳入　地方税収入"),
-  ("A20_384", "財政1　歳入　地方交付税"),
-  ("A20_385", "財政1　歳入　国庫支出金"),
-  ("A20_386", "財政1　歳入　都道県支出金"),
-  ("A20_387", "財政1　歳入　地方債"),
-  ("A20_388", "財政1　歳入　その他"),
-  ("A20_389", "財政1　歳入　計"),
-  ("A20_390", "財政2　歳出　議会費"),
-  ("A20_391", "財政2　歳出　総務費"),
-  ("A20_392", "財政2　歳出　民生費"),
-  ("A20_393", "財政2　歳出　衛生費"),
-  ("A20_394", "財政2　歳出　労働費"),
-  ("A20_395", "財政2　歳出　農林水産費"),
-  ("A20_396", "財政2　歳出　商工費"),
-  ("A20_397", "財政2　歳出　土木費"),
-  ("A20_398", "財政2　歳出　消防費"),
-  ("A20_399", "財政2　歳出　教育費"),
-  ("A20_400", "財政2　歳出　災害復旧費"),
-  ("A20_401", "財政2　歳出　公債費"),
-  ("A20_402", "財政2　歳出　諸支出金"),
-  ("A20_403", "財政2　歳出　計"),
-  ("A20_404", "調査年度"),
-  ("A20_405", "就業者数　就業者総数　"),
-  ("A20_406", "就業者数　第1次　農業"),
-  ("A20_407", "就業者数　第1次　農業、林業"),
-  ("A20_408", "就業者数　第1次　農業、林業（うち農業）"),
-  ("A20_409", "就業者数　第1次　林業"),
-  ("A20_410", "就業者数　第1次　漁業"),
-  ("A20_411", "就業者数　第1次　小計"),
-  ("A20_412", "就業者数　第2次　鉱業"),
-  ("A20_413", "就業者数　第2次　鉱業，採石業，砂利採取業"),
-  ("A20_414", "就業者数　第2次　建設業"),
-  ("A20_415", "就業者数　第2次　製造業"),
-  ("A20_416", "就業者数　第2次　小計"),
-  ("A20_417", "就業者数　第3次　電気・ガス・熱供給・水道業"),
-  ("A20_418", "就業者数　第3次　運輸・通信業"),
-  ("A20_419", "就業者数　第3次　情報・通信業"),
-  ("A20_420", "就業者数　第3次　運輸業"),
-  ("A20_421", "就業者数　第3次　運輸業、郵便業"),
-  ("A20_422", "就業者数　第3次　卸売・小売業・飲食業"),
-  ("A20_423", "就業者数　第3次　卸売・小売業"),
-  ("A20_424", "就業者数　第3次　金融・保険業"),
-  ("A20_425", "就業者数　第3次　不動産業"),
-  ("A20_426", "就業者数　第3次　不動産業、物品賃貸業"),
-  ("A20_427", "就業者数　第3次　学術研究，専門・技術サービス業"),
-  ("A20_428", "就業者数　第3次　飲食店・宿泊業"),
-  ("A20_429", "就業者数　第3次　宿泊業，飲食サービス業　"),
-  ("A20_430", "就業者数　第3次　生活関連サービス業，娯楽業"),
-  ("A20_431", "就業者数　第3次　医療･福祉"),
-  ("A20_432", "就業者数　第3次　教育・学習支援業"),
-  ("A20_433", "就業者数　第3次　複合サービス事業"),
-  ("A20_434", "就業者数　第3次　サービス業"),
-  ("A20_435", "就業者数　第3次　公務"),
-  ("A20_436", "就業者数　第3次　小計"),
-  ("A20_437", "就業者数　分類不能　"),
-  ("A20_438", "就業者数　備考　"),
-  ("A20_439", "事業所数　総数　"),
-  ("A20_440", "事業所数　第1次　農業"),
-  ("A20_441", "事業所数　第1次　林業"),
-  ("A20_442", "事業所数　第1次　漁業"),
-  ("A20_443", "事業所数　第1次　小計"),
-  ("A20_444", "事業所数　第2次　鉱業"),
-  ("A20_445", "事業所数　第2次　建設業"),
-  ("A20_446", "事業所数　第2次　製造業"),
-  ("A20_447", "事業所数　第2次　小計"),
-  ("A20_448", "事業所数　第3次　電気・ガス・熱供給・水道業"),
-  ("A20_449", "事業所数　第3次　運輸・通信業"),
-  ("A20_450", "事業所数　第3次　卸売・小売業・飲食業"),
-  ("A20_451", "事業所数　第3次　金融・保険業"),
-  ("A20_452", "事業所数　第3次　不動産業"),
-  ("A20_453", "事業所数　第3次　サービス業"),
-  ("A20_454", "事業所数　第3次　公務"),
-  ("A20_455", "事業所数　第3次　小計"),
-  ("A20_456", "事業所数　分類不能　"),
-  ("A20_457", "従業者数　総数　"),
-  ("A20_458", "従業者数　第1次　農業"),
-  ("A20_459", "従業者数　第1次　林業"),
-  ("A20_460", "従業者数　第1次　漁業"),
-  ("A20_461", "従業者数　第1次　小計"),
-  ("A20_462", "従業者数　第2次　鉱業"),
-  ("A20_463", "従業者数　第2次　建設業"),
-  ("A20_464", "従業者数　第2次　製造業"),
-  ("A20_465", "従業者数　第2次　小計"),
-  ("A20_466", "従業者数　第3次　電気・ガス・熱供給・水道業"),
-  ("A20_467", "従業者数　第3次　運輸・通信業"),
-  ("A20_468", "従業者数　第3次　卸売・小売業・飲食業"),
-  ("A20_469", "従業者数　第3次　金融・保険業"),
-  ("A20_470", "従業者数　第3次　不動産業"),
-  ("A20_471", "従業者数　第3次　サービス業"),
-  ("A20_472", "従業者数　第3次　公務"),
-  ("A20_473", "従業者数　第3次　小計"),
-  ("A20_474", "従業者数　分類不能　"),
-  ("A20_475", "農林業　総面積　"),
-  ("A20_476", "農林業　農振面積　"),
-  ("A20_477", "農林業　耕地面積　総数"),
-  ("A20_478", "農林業　耕地面積　田"),
-  ("A20_479", "農林業　耕地面積　畑"),
-  ("A20_480", "農林業　耕地面積　樹園地"),
-  ("A20_481", "農林業　耕地面積　牧草地"),
-  ("A20_482", "農林業　耕地化率　"),
-  ("A20_483", "農林業　家畜飼育数　乳用牛"),
-  ("A20_484", "農林業　家畜飼育数　肉用牛"),
-  ("A20_485", "農林業　家畜飼育数　豚"),
-  ("A20_486", "農林業　家畜飼育数　鶏"),
-  ("A20_487", "農林業　農道延長　"),
-  ("A20_488", "農林業　林道延長　"),
-  ("A20_489", "農林業　林道延長　内国有林道"),
-  ("A20_490", "農林業　森林面積　総数　"),
-  ("A20_491", "農林業　森林面積　総数　うち人工林"),
-  ("A20_492", "農林業　森林面積　国有林　"),
-  ("A20_493", "農林業　森林面積　国有林　うち人工林"),
-  ("A20_494", "農林業　森林面積　民有林　"),
-  ("A20_495", "農林業　森林面積　民有林　うち人工林"),
-  ("A20_496", "農林業　森林蓄積量　"),
-  ("A20_497", "農林業　備考　"),
-  ("A20_498", "水産業現況_港湾　水揚高　属人水揚　計(水揚トン数)"),
-  ("A20_499", "水産業現況_港湾　水揚高　属人水揚　計(水揚金額)"),
-  ("A20_500", "水産業現況_港湾　水揚高　属人水揚　海面漁業(水揚トン数)"),
-  ("A20_501", "水産業現況_港湾　水揚高　属人水揚　海面漁業(水揚金額)"),
-  ("A20_502", "水産業現況_港湾　水揚高　属人水揚　海面養殖業(水揚トン数)"),
-  ("A20_503", "水産業現況_港湾　水揚高　属人水揚　海面養殖業(水揚金額)"),
-  ("A20_504", "水産業現況_港湾　水揚高　属地水揚　計(水揚トン数)"),
-  ("A20_505", "水産業現況_港湾　水揚高　属地水揚　計(水揚金額)"),
-  ("A20_506", "水産業現況_港湾　水揚高　属地水揚　海面漁業(水揚トン数)"),
-  ("A20_507", "水産業現況_港湾　水揚高　属地水揚　海面漁業(水揚金額)"),
-  ("A20_508", "水産業現況_港湾　水揚高　属地水揚　海面養殖業(水揚トン数)"),
-  ("A20_509", "水産業現況_港湾　水揚高　属地水揚　海面養殖業(水揚金額)"),
-  ("A20_510", "水産業現況_漁港　水揚高　属人水揚　計(水揚トン数)"),
-  ("A20_511", "水産業現況_漁港　水揚高　属人水揚　計(水揚金額)"),
-  ("A20_512", "水産業現況_漁港　水揚高　属人水揚　海面漁業(水揚トン数)"),
-  ("A20_513", "水産業現況_漁港　水揚高　属人水揚　海面漁業(水揚金額)"),
-  ("A20_514", "水産業現況_漁港　水揚高　属人水揚　海面養殖業(水揚トン数)"),
-  ("A20_515", "水産業現況_漁港　水揚高　属人水揚　海面養殖業(水揚金額)"),
-  ("A20_516", "水産業現況_漁港　水揚高　属地水揚　計(水揚トン数)"),
-  ("A20_517", "水産業現況_漁港　水揚高　属地水揚　計(水揚金額)"),
-  ("A20_518", "水産業現況_漁港　水揚高　属地水揚　海面漁業(水揚トン数)"),
-  ("A20_519", "水産業現況_漁港　水揚高　属地水揚　海面漁業(水揚金額)"),
-  ("A20_520", "水産業現況_漁港　水揚高　属地水揚　海面養殖業(水揚トン数)"),
-  ("A20_521", "水産業現況_漁港　水揚高　属地水揚　海面養殖業(水揚金額)"),
-  ("A20_522", "経営体　農家戸数　総数　　"),
-  ("A20_523", "経営体　農家戸数　販売農業　専業　"),
-  ("A20_524", "経営体　農家戸数　販売農業　兼業　1種"),
-  ("A20_525", "経営体　農家戸数　販売農業　兼業　2種"),
-  ("A20_526", "経営体　農家戸数　自給的農家　　"),
-  ("A20_527", "経営体　漁家戸数　総数　　"),
-  ("A20_528", "経営体　漁家戸数　専業　　"),
-  ("A20_529", "経営体　漁家戸数　兼業　1種　"),
-  ("A20_530", "経営体　漁家戸数　兼業　2種　"),
-  ("A20_531", "経営体　林家戸数　総数　　"),
-  ("A20_532", "経営体　林家戸数　農家林家　　"),
-  ("A20_533", "経営体　林家戸数　非農家林家　　"),
-  ("A20_534", "経営体　組合数　農業協同組合　組合数（本部）"),
-  ("A20_535", "経営体　組合数　農業協同組合　組合数（支部・支所）"),
-  ("A20_536", "経営体　組合数　農業協同組合　組合員数"),
-  ("A20_537", "経営体　組合数　漁業協同組合　組合数（本部）"),
-  ("A20_538", "経営体　組合数　漁業協同組合　組合数（支部・支所）"),
-  ("A20_539", "経営体　組合数　漁業協同組合　組合員数"),
-  ("A20_540", "経営体　組合数　森林組合　組合数（本部）"),
-  ("A20_541", "経営体　組合数　森林組合　組合数（支部・支所）"),
-  ("A20_542", "経営体　組合数　森林組合　組合員数"),
-  ("A20_543", "生産額　農業　耕種　米"),
-  ("A20_544", "生産額　農業　耕種　麦"),
-  ("A20_545", "生産額　農業　耕種　いも"),
-  ("A20_546", "生産額　農業　耕種　豆･雑穀"),
-  ("A20_547", "生産額　農業　耕種　野菜"),
-  ("A20_548", "生産額　農業　耕種　果実"),
-  ("A20_549", "生産額　農業　耕種　花卉"),
-  ("A20_550", "生産額　農業　耕種　工芸作物"),
-  ("A20_551", "生産額　農業　耕種　その他"),
-  ("A20_552", "生産額　農業　耕種　小計"),
-  ("A20_553", "生産額　農業　養蚕　"),
-  ("A20_554", "生産額　農業　畜産　牛肉"),
-  ("A20_555", "生産額　農業　畜産　豚肉"),
-  ("A20_556", "生産額　農業　畜産　牛乳"),
-  ("A20_557", "生産額　農業　畜産　鶏卵"),
-  ("A20_558", "生産額　農業　畜産　その他"),
-  ("A20_559", "生産額　農業　畜産　小計"),
-  ("A20_560", "生産額　農業　合計　"),
-  ("A20_561", "生産額　林業　木材　用材"),
-  ("A20_562", "生産額　林業　木材　薪炭材"),
-  ("A20_563", "生産額　林業　木炭　"),
-  ("A20_564", "生産額　林業　しいたけ　"),
-  ("A20_565", "生産額　林業　その他　"),
-  ("A20_566", "生産額　林業　合計　"),
-  ("A20_567", "生産額　水産業　魚類　"),
-  ("A20_568", "生産額　水産業　貝類　"),
-  ("A20_569", "生産額　水産業　水産動物　"),
-  ("A20_570", "生産額　水産業　海草類　"),
-  ("A20_571", "生産額　水産業　その他　"),
-  ("A20_572", "生産額　水産業　合計　"),
-  ("A20_573", "生産額　農林水産業総計　　"),
-  ("A20_574", "生産額　備考　　"),
-  ("A20_575", "観光客数　春　"),
-  ("A20_576", "観光客数　夏　"),
-  ("A20_577", "観光客数　秋　"),
-  ("A20_578", "観光客数　冬　"),
-  ("A20_579", "観光客数　計　"),
-  ("A20_580", "宿泊能力(最盛期)　旅館・ホテル　数"),
-  ("A20_581", "宿泊能力(最盛期)　旅館・ホテル　収容能力"),
-  ("A20_582", "宿泊能力(最盛期)　民宿　数"),
-  ("A20_583", "宿泊能力(最盛期)　民宿　収容能力"),
-  ("A20_584", "宿泊能力　年間宿泊者数　　"),
-  ("A20_585", "自然公園面積　国立公園　"),
-  ("A20_586", "自然公園面積　国定公園　"),
-  ("A20_587", "自然公園面積　県立公園　"),
-  ("A20_588", "観光客数・宿泊能力・自然公園　備考　　"),
-  ("A20_589", "港湾・航路現況_港湾　港湾利用状況　入港船舶　隻数"),
-  ("A20_590", "港湾・航路現況_港湾　総屯数"),
-  ("A20_591", "港湾・航路現況_港湾　乗降人員　"),
-  ("A20_592", "港湾・航路現況_港湾　海上出入貨物　出"),
-  ("A20_593", "港湾・航路現況_港湾　海上出入貨物　入"),
-  ("A20_594", "港湾・航路現況_港湾　海上出入貨物　計"),
-  ("A20_595", "港湾・航路現況_漁港　港湾利用状況　入港船舶　隻数"),
-  ("A20_596", "港湾・航路現況_漁港　総屯数"),
-  ("A20_597", "港湾・航路現況_漁港　乗降人員　"),
-  ("A20_598", "港湾・航路現況_漁港　海上出入貨物　出"),
-  ("A20_599", "港湾・航路現況_漁港　海上出入貨物　入"),
-  ("A20_600", "港湾・航路現況_漁港　海上出入貨物　計"),
-  ("A20_601", "空港・航空路現況　都道県名　空港名　　"),
-  ("A20_602", "空港・航空路現況　運航実績"),
-  ("A20_603", "空港・航空路現況　　1日当たり運航回数　最盛期"),
-  ("A20_604", "空港・航空路現況　　年間乗降人員　"),
-
-  ///// A21 /////
-
-  ("A21_001", "小笠原ID"),
-  ("A21_002", "行政コード"),
-  ("A21_003", "都道府県名"),
-  ("A21_004", "支庁名"),
-  ("A21_005", "市区町村名"),
-  ("A21_006", "島嶼名"),
-  ("A21_007", "指定日"),
-
-  ///// A21s /////
-
-  ("A21_001", "小笠原諸島ID"),
-  ("A21_002", "行政コード"),
-  ("A21_003", "都道府県名"),
-  ("A21_004", "支庁名"),
-  ("A21_005", "市区町村名"),
-  ("A21_006", "島嶼名"),
-  ("A21_007", "指定状況コード"),
-  ("A21_008", "調査年度"),
-  ("A21_009", "概要　(name)"),
-  ("A21_010", "概要　指定回次"),
-  ("A21_011", "概要　郡名"),
-  ("A21_012", "概要　人口"),
-  ("A21_013", "概要　世帯数"),
-  ("A21_014", "概要　面積"),
-  ("A21_015", "概要　海岸延長"),
-  ("A21_016", "概要　備考"),
-  ("A21_017", "前年　住民登録人口　男"),
-  ("A21_018", "前年　住民登録人口　女"),
-  ("A21_019", "前年　住民登録人口　計"),
-  ("A21_020", "前年　世帯数　"),
-  ("A21_021", "人口移動　出生数　"),
-  ("A21_022", "人口移動　死亡数　"),
-  ("A21_023", "人口移動　自然増△減　"),
-  ("A21_024", "人口移動　転入数　"),
-  ("A21_025", "人口移動　転出数　"),
-  ("A21_026", "人口移動　社会増△減　"),
-  ("A21_027", "人口移動　増減計　"),
-  ("A21_028", "現年　住民登録人口　男"),
-  ("A21_029", "現年　住民登録人口　女"),
-  ("A21_030", "現年　住民登録人口　計"),
-  ("A21_031", "現年　世帯数　"),
-  ("A21_032", "人口・世帯数・人口動態　備考"),
-  ("A21_033", "男　国勢調査人口総数"),
-  ("A21_034", "男　年少人口　0-4"),
-  ("A21_035", "男　年少人口　5-9"),
-  ("A21_036", "男　年少人口　10-14"),
-  ("A21_037", "男　年少人口　小計"),
-  ("A21_038", "男　生産年齢人口　15-19"),
-  ("A21_039", "男　生産年齢人口　20-24"),
-  ("A21_040", "男　生産年齢人口　25-29"),
-  ("A21_041", "男　生産年齢人口　30-34"),
-  ("A21_042", "男　生産年齢人口　35-39"),
-  ("A21_043", "男　生産年齢人口　40-44"),
-  ("A21_044", "男　生産年齢人口　45-49"),
-  ("A21_045", "男　生産年齢人口　50-54"),
-  ("A21_046", "男　生産年齢人口　55-59"),
-  ("A21_047", "男　生産年齢人口　60-64"),
-  ("A21_048", "男　生産年齢人口　小計"),
-  ("A21_049", "男　老年人口　65-69"),
-  ("A21_050", "男　老年人口　70-74"),
-  ("A21_051", "男　老年人口　75-79"),
-  ("A21_052", "男　老年人口　80-84"),
-  ("A21_053", "男　老年人口　85歳以上"),
-  ("A21_054", "男　老年人口　小計"),
-  ("A21_055", "男　不詳　"),
-  ("A21_056", "男　生産年齢人口指数　"),
-  ("A21_057", "男　老齢化率　"),
-  ("A21_058", "女　国勢調査人口総数　"),
-  ("A21_059", "女　年少人口　0-4"),
-  ("A21_060", "女　年少人口　5-9"),
-  ("A21_061", "女　年少人口　10-14"),
-  ("A21_062", "女　年少人口　小計"),
-  ("A21_063", "女　生産年齢人口　15-19"),
-  ("A21_064", "女　生産年齢人口　20-24"),
-  ("A21_065", "女　生産年齢人口　25-29"),
-  ("A21_066", "女　生産年齢人口　30-34"),
-  ("A21_067", "女　生産年齢人口　35-39"),
-  ("A21_068", "女　生産年齢人口　40-44"),
-  ("A21_069", "女　生産年齢人口　45-49"),
-  ("A21_070", "女　生産年齢人口　50-54"),
-  ("A21_071", "女　生産年齢人口　55-59"),
-  ("A21_072", "女　生産年齢人口　60-64"),
-  ("A21_073", "女　生産年齢人口　小計"),
-  ("A21_074", "女　老年人口　65-69"),
-  ("A21_075", "女　老年人口　70-74"),
-  ("A21_076", "女　老年人口　75-79"),
-  ("A21_077", "女　老年人口　80-84"),
-  ("A21_078", "女　老年人口　85歳以上"),
-  ("A21_079", "女　老年人口　小計"),
-  ("A21_080", "女　不詳　"),
-  ("A21_081", "女　生産年齢人口指数　"),
-  ("A21_082", "女　老齢化率　"),
-  ("A21_083", "男女計　国勢調査人口総数　"),
-  ("A21_084", "男女計　年少人口　0-4"),
-  ("A21_085", "男女計　年少人口　5-9"),
-  ("A21_086", "男女計　年少人口　10-14"),
-  ("A21_087", "男女計　年少人口　小計"),
-  ("A21_088", "男女計　生産年齢人口　15-19"),
-  ("A21_089", "男女計　生産年齢人口　20-24"),
-  ("A21_090", "男女計　生産年齢人口　25-29"),
-  ("A21_091", "男女計　生産年齢人口　30-34"),
-  ("A21_092", "男女計　生産年齢人口　35-39"),
-  ("A21_093", "男女計　生産年齢人口　40-44"),
-  ("A21_094", "男女計　生産年齢人口　45-49"),
-  ("A21_095", "男女計　生産年齢人口　50-54"),
-  ("A21_096", "男女計　生産年齢人口　55-59"),
-  ("A21_097", "男女計　生産年齢人口　60-64"),
-  ("A21_098", "男女計　生産年齢人口　小計"),
-  ("A21_099", "男女計　老年人口　65-69"),
-  ("A21_100", "男女計　老年人口　70-74"),
-  ("A21_101", "男女計　老年人口　75-79"),
-  ("A21_102", "男女計　老年人口　80-84"),
-  ("A21_103", "男女計　老年人口　85歳以上"),
-  ("A21_104", "男女計　老年人口　小計"),
-  ("A21_105", "男女計　不詳　"),
-  ("A21_106", "男女計　生産年齢人口指数　"),
-  ("A21_107", "男女計　老齢化率　"),
-  ("A21_108", "年齢別･男女別人口　備考"),
-  ("A21_109", "調査年度"),
-  ("A21_110", "土地利用　総面積　"),
-  ("A21_111", "土地利用　農用地　農用地合計"),
-  ("A21_112", "土地利用　農用地　農地"),
-  ("A21_113", "土地利用　農用地　採草放牧地"),
-  ("A21_114", "土地利用　森林　"),
-  ("A21_115", "土地利用　原野　"),
-  ("A21_116", "土地利用　水面・河川・水路　"),
-  ("A21_117", "土地利用　道路　"),
-  ("A21_118", "土地利用　宅地　"),
-  ("A21_119", "土地利用　その他　"),
-  ("A21_120", "土地利用　備考　"),
-  ("A21_121", "学校　保　保育所数　本所"),
-  ("A21_122", "学校　保　保育所数　分所"),
-  ("A21_123", "学校　保　乳幼児数　在所"),
-  ("A21_124", "学校　保　乳幼児数　通所"),
-  ("A21_125", "学校　幼　幼稚園数　本園"),
-  ("A21_126", "学校　幼　幼稚園数　分園"),
-  ("A21_127", "学校　幼　園児数　在園"),
-  ("A21_128", "学校　幼　園児数　通園"),
-  ("A21_129", "学校　小　学校数　本校"),
-  ("A21_130", "学校　小　学校数　分校"),
-  ("A21_131", "学校　小　教（職）員数　教員数"),
-  ("A21_132", "学校　小　教（職）員数　職員数"),
-  ("A21_133", "学校　小　学級数"),
-  ("A21_134", "学校　小　児童数　在校"),
-  ("A21_135", "学校　小　児童数　通学"),
-  ("A21_136", "学校　中　学校数　本校"),
-  ("A21_137", "学校　中　学校数　分校"),
-  ("A21_138", "学校　中　教（職）員数　教員数"),
-  ("A21_139", "学校　中　教（職）員数　職員数"),
-  ("A21_140", "学校　中　学級数"),
-  ("A21_141", "学校　中　生徒数　在校"),
-  ("A21_142", "学校　中　生徒数　通学"),
-  ("A21_143", "学校　高　学校数　本校"),
-  ("A21_144", "学校　高　学校数　分校"),
-  ("A21_145", "学校　高　教（職）員数　教員数"),
-  ("A21_146", "学校　高　教（職）員数　職員数"),
-  ("A21_147", "学校　高　学級数"),
-  ("A21_148", "学校　高　生徒数　在校"),
-  ("A21_149", "学校　高　生徒数　通学"),
-  ("A21_150", "学校　備考"),
-  ("A21_151", "卒業者状況　中　卒業生数　　"),
-  ("A21_152", "卒業者状況　中　就職　総数　"),
-  ("A21_153", "卒業者状況　中　就職　島内　計"),
-  ("A21_154", "卒業者状況　中　就職　島内　農林業"),
-  ("A21_155", "卒業者状況　中　就職　島内　水産業"),
-  ("A21_156", "卒業者状況　中　就職　島内　2次産業"),
-  ("A21_157", "卒業者状況　中　就職　島内　3次産業"),
-  ("A21_158", "卒業者状況　中　就職　島外　計"),
-  ("A21_159", "卒業者状況　中　就職　島外　1次産業"),
-  ("A21_160", "卒業者状況　中　就職　島外　2次産業"),
-  ("A21_161", "卒業者状況　中　就職　島外　3次産業"),
-  ("A21_162", "卒業者状況　中　進学　総数　"),
-  ("A21_163", "卒業者状況　中　進学　島内　"),
-  ("A21_164", "卒業者状況　中　進学　島外　"),
-  ("A21_165", "卒業者状況　中　進学　進学率　"),
-  ("A21_166", "卒業者状況　中　未就職・その他　島内　"),
-  ("A21_167", "卒業者状況　中　未就職・その他　島外　"),
-  ("A21_168", "卒業者状況　高　卒業生数　　"),
-  ("A21_169", "卒業者状況　高　就職　総数　"),
-  ("A21_170", "卒業者状況　高　就職　島内　計"),
-  ("A21_171", "卒業者状況　高　就職　島内　農林業"),
-  ("A21_172", "卒業者状況　高　就職　島内　水産業"),
-  ("A21_173", "卒業者状況　高　就職　島内　2次産業"),
-  ("A21_174", "卒業者状況　高　就職　島内　3次産業"),
-  ("A21_175", "卒業者状況　高　就職　島外　計"),
-  ("A21_176", "卒業者状況　高　就職　島外　1次産業"),
-  ("A21_177", "卒業者状況　高　就職　島外　2次産業"),
-  ("A21_178", "卒業者状況　高　就職　島外　3次産業"),
-  ("A21_179", "卒業者状況　高　進学　総数　"),
-  ("A21_180", "卒業者状況　高　進学　島内　"),
-  ("A21_181", "卒業者状況　高　進学　島外　"),
-  ("A21_182", "卒業者状況　高　進学　進学率　"),
-  ("A21_183", "卒業者状況　高　未就職・その他　島内　"),
-  ("A21_184", "卒業者状況　高　未就職・その他　島外　"),
-  ("A21_185", "公園等　国調人口　　　"),
-  ("A21_186", "公園等　離島振興関係一括事業　都市公園　都市計画公園　カ所数"),
-  ("A21_187", "公園等　離島振興関係一括事業　都市公園　都市計画公園　面積"),
-  ("A21_188", "公園等　離島振興関係一括事業　都市公園　特定地区公園　カ所数"),
-  ("A21_189", "公園等　離島振興関係一括事業　都市公園　特定地区公園　面積"),
-  ("A21_190", "公園等　離島振興関係一括事業　都市公園　計　カ所数"),
-  ("A21_191", "公園等　離島振興関係一括事業　都市公園　計　面積"),
-  ("A21_192", "公園等　離島振興関係一括事業　港湾環境整備緑地　カ所数　"),
-  ("A21_193", "公園等　離島振興関係一括事業　港湾環境整備緑地　面積　"),
-  ("A21_194", "公園等　離島振興関係一括事業　漁港漁村環境整備　カ所数　"),
-  ("A21_195", "公園等　離島振興関係一括事業　漁港漁村環境整備　面積　"),
-  ("A21_196", "公園等　離島振興関係一括事業　海岸環境整備　カ所数　"),
-  ("A21_197", "公園等　離島振興関係一括事業　海岸環境整備　面積　"),
-  ("A21_198", "公園等　離島振興関係一括事業　農業集落環境整備　カ所数　"),
-  ("A21_199", "公園等　離島振興関係一括事業　農業集落環境整備　面積　"),
-  ("A21_200", "公園等　離島振興関係一括事業　コミュニティ・アイランド　カ所数　"),
-  ("A21_201", "公園等　離島振興関係一括事業　コミュニティ・アイランド　面積　"),
-  ("A21_202", "公園等　離島振興関係一括事業　自然公園施設設備（園地等）　カ所数　"),
-  ("A21_203", "公園等　離島振興関係一括事業　自然公園施設設備（園地等）　面積　"),
-  ("A21_204", "公園等　離島振興関係一括事業　その他　カ所数　"),
-  ("A21_205", "公園等　離島振興関係一括事業　その他　面積　"),
-  ("A21_206", "公園等　離島振興関係一括事業　小計　カ所数　"),
-  ("A21_207", "公園等　離島振興関係一括事業　小計　面積　"),
-  ("A21_208", "公園等　地方公共団体単独事業　カ所数　　"),
-  ("A21_209", "公園等　地方公共団体単独事業　面積　　"),
-  ("A21_210", "公園等　合計　カ所数　　"),
-  ("A21_211", "公園等　合計　面積　　"),
-  ("A21_212", "公園等　一人当たり公園・緑地面積　　　"),
-  ("A21_213", "道路　国道　総延長"),
-  ("A21_214", "道路　国道　重用延長"),
-  ("A21_215", "道路　国道　未供用延長"),
-  ("A21_216", "道路　国道　実延長"),
-  ("A21_217", "道路　国道　実延長　規格改良済延長"),
-  ("A21_218", "道路　国道　実延長　規格改良済延長　内車道幅員55m以上"),
-  ("A21_219", "道路　国道　実延長　未改良延長"),
-  ("A21_220", "道路　国道　実延長　未改良延長　内自動車交通不能"),
-  ("A21_221", "道路　国道　実延長　舗装済延長"),
-  ("A21_222", "道路　国道　実延長　舗装済延長　内本舗装延長"),
-  ("A21_223", "道路　国道　実延長　未舗装延長"),
-  ("A21_224", "道路　国道　歩道設置道路実延長"),
-  ("A21_225", "道路　国道　道路面積　車道面積"),
-  ("A21_226", "道路　国道　道路面積　道路部面積"),
-  ("A21_227", "道路　国道　道路面積　道路敷面積"),
-  ("A21_228", "道路　主要地方道　総延長"),
-  ("A21_229", "道路　主要地方道　重用延長"),
-  ("A21_230", "道路　主要地方道　未供用延長"),
-  ("A21_231", "道路　主要地方道　実延長"),
-  ("A21_232", "道路　主要地方道　実延長　規格改良済延長"),
-  ("A21_233", "道路　主要地方道　実延長　規格改良済延長　内車道幅員55m以上"),
-  ("A21_234", "道路　主要地方道　実延長　未改良延長"),
-  ("A21_235", "道路　主要地方道　実延長　未改良延長　内自動車交通不能"),
-  ("A21_236", "道路　主要地方道　実延長　舗装済延長"),
-  ("A21_237", "道路　主要地方道　実延長　舗装済延長　内本舗装延長"),
-  ("A21_238", "道路　主要地方道　実延長　未舗装延長"),
-  ("A21_239", "道路　主要地方道　歩道設置道路実延長"),
-  ("A21_240", "道路　主要地方道　道路面積　車道面積"),
-  ("A21_241", "道路　主要地方道　道路面積　道路部面積"),
-  ("A21_242", "道路　主要地方道　道路面積　道路敷面積"),
-  ("A21_243", "道路　都道県道　総延長"),
-  ("A21_244", "道路　都道県道　重用延長"),
-  ("A21_245", "道路　都道県道　未供用延長"),
-  ("A21_246", "道路　都道県道　実延長"),
-  ("A21_247", "道路　都道県道　実延長　規格改良済延長"),
-  ("A21_248", "道路　都道県道　実延長　規格改良済延長　内車道幅員55m以上"),
-  ("A21_249", "道路　都道県道　実延長　未改良延長"),
-  ("A21_250", "道路　都道県道　実延長　未改良延長　内自動車交通不能"),
-  ("A21_251", "道路　都道県道　実延長　舗装済延長"),
-  ("A21_252", "道路　都道県道　実延長　舗装済延長　内本舗装延長"),
-  ("A21_253", "道路　都道県道　実延長　未舗装延長"),
-  ("A21_254", "道路　都道県道　歩道設置道路実延長"),
-  ("A21_255", "道路　都道県道　道路面積　車道面積"),
-  ("A21_256", "道路　都道県道　道路面積　道路部面積"),
-  ("A21_257", "道路　都道県道　道路面積　道路敷面積"),
-  ("A21_258", "道路　市町村道　総延長"),
-  ("A21_259", "道路　市町村道　重用延長"),
-  ("A21_260", "道路　市町村道　未供用延長"),
-  ("A21_261", "道路　市町村道　実延長"),
-  ("A21_262", "道路　市町村道　実延長　規格改良済延長"),
-  ("A21_263", "道路　市町村道　実延長　規格改良済延長　内車道幅員55m以上"),
-  ("A21_264", "道路　市町村道　実延長　未改良延長"),
-  ("A21_265", "道路　市町村道　実延長　未改良延長　内自動車交通不能"),
-  ("A21_266", "道路　市町村道　実延長　舗装済延長"),
-  ("A21_267", "道路　市町村道　実延長　舗装済延長　内本舗装延長"),
-  ("A21_268", "道路　市町村道　実延長　未舗装延長"),
-  ("A21_269", "道路　市町村道　歩道設置道路実延長"),
-  ("A21_270", "道路　市町村道　道路面積　車道面積"),
-  ("A21_271", "道路　市町村道　道路面積　道路部面積"),
-  ("A21_272", "道路　市町村道　道路面積　道路敷面積"),
-  ("A21_273", "道路　備考"),
-  ("A21_274", "調査年度"),
-  ("A21_275", "車輌保有台数　保有車両合計"),
-  ("A21_276", "車輌保有台数　登録車両　小計"),
-  ("A21_277", "車輌保有台数　登録車両　トラック　計"),
-  ("A21_278", "車輌保有台数　登録車両　トラック　普通車"),
-  ("A21_279", "車輌保有台数　登録車両　トラック　小型車"),
-  ("A21_280", "車輌保有台数　登録車両　トラック　トレーラー"),
-  ("A21_281", "車輌保有台数　登録車両　バス　"),
-  ("A21_282", "車輌保有台数　登録車両　乗用車　計"),
-  ("A21_283", "車輌保有台数　登録車両　乗用車　普通車"),
-  ("A21_284", "車輌保有台数　登録車両　乗用車　小型車"),
-  ("A21_285", "車輌保有台数　登録車両　特種用途車　"),
-  ("A21_286", "車輌保有台数　登録車両　大型特殊車　"),
-  ("A21_287", "車輌保有台数　軽自動車"),
-  ("A21_288", "車輌保有台数　二輪車"),
-  ("A21_289", "車輌保有台数　原動機付自転車"),
-  ("A21_290", "車輌保有台数　小型特殊車"),
-  ("A21_291", "車輌保有台数　備考"),
-  ("A21_292", "医療　住民登録人口"),
-  ("A21_293", "医療　施設　総数"),
-  ("A21_294", "医療　施設　病院"),
-  ("A21_295", "医療　施設　診療所"),
-  ("A21_296", "医療　施設　診療所　有床診療所"),
-  ("A21_297", "医療　施設　歯科診療所"),
-  ("A21_298", "医療　施設　一般診療所の歯科診療科目"),
-  ("A21_299", "医療　施設　開設者別　国"),
-  ("A21_300", "医療　施設　開設者別　都道県"),
-  ("A21_301", "医療　施設　開設者別　市町村"),
-  ("A21_302", "医療　施設　開設者別　個人"),
-  ("A21_303", "医療　施設　開設者別　その他"),
-  ("A21_304", "医療　病床数"),
-  ("A21_305", "医療　従事者　医師　常勤"),
-  ("A21_306", "医療　従事者　医師　非常勤"),
-  ("A21_307", "医療　従事者　歯科医師　常勤"),
-  ("A21_308", "医療　従事者　歯科医師　非常勤"),
-  ("A21_309", "医療　従事者　保健師　医療施設勤務"),
-  ("A21_310", "医療　従事者　保健師　医療施設外勤務"),
-  ("A21_311", "医療　従事者　助産師　医療施設勤務"),
-  ("A21_312", "医療　従事者　助産師　医療施設外勤務"),
-  ("A21_313", "医療　従事者　看護師　医療施設勤務"),
-  ("A21_314", "医療　従事者　看護師　医療施設外勤務"),
-  ("A21_315", "医療　従事者　歯科衛生士　医療施設勤務"),
-  ("A21_316", "医療　従事者　歯科衛生士　医療施設外勤務"),
-  ("A21_317", "医療　搬送設備等　巡回診療車"),
-  ("A21_318", "医療　搬送設備等　巡回診療船"),
-  ("A21_319", "医療　搬送設備等　患者輸送車"),
-  ("A21_320", "医療　搬送設備等　患者輸送船"),
-  ("A21_321", "医療　備考"),
-  ("A21_322", "水道　住民登録人口"),
-  ("A21_323", "水道　利用人口　上水道"),
-  ("A21_324", "水道　利用人口　専用水道"),
-  ("A21_325", "水道　利用人口　簡易水道"),
-  ("A21_326", "水道　利用人口　計"),
-  ("A21_327", "水道　普及率"),
-  ("A21_328", "水道　その他飲料水利用人口　井戸"),
-  ("A21_329", "水道　その他飲料水利用人口　流湧水"),
-  ("A21_330", "水道　その他飲料水利用人口　天水"),
-  ("A21_331", "水道　その他飲料水利用人口　計"),
-  ("A21_332", "水道　備考"),
-  ("A21_333", "し尿処理状況　住民登録人口"),
-  ("A21_334", "し尿処理状況　計画処理区域内人口　水洗化人口"),
-  ("A21_335", "し尿処理状況　計画処理区域内人口　非水洗化人口　収集人口"),
-  ("A21_336", "し尿処理状況　計画処理区域内人口　非水洗化人口　自家処理人口"),
-  ("A21_337", "し尿処理状況　計画処理区域内人口　計"),
-  ("A21_338", "し尿処理状況　計画処理区域内人口　計画収集率"),
-  ("A21_339", "し尿処理状況　区域内人口比率"),
-  ("A21_340", "し尿処理状況　収集し尿処理内訳　施設処理"),
-  ("A21_341", "し尿処理状況　収集し尿処理内訳　埋立て"),
-  ("A21_342", "し尿処理状況　収集し尿処理内訳　海洋投入"),
-  ("A21_343", "し尿処理状況　収集し尿処理内訳　その他"),
-  ("A21_344", "し尿処理状況　収集し尿処理内訳　計"),
-  ("A21_345", "し尿処理状況　収集し尿処理内訳　施設処理率"),
-  ("A21_346", "し尿処理状況　備考"),
-  ("A21_347", "ごみ処理状況　住民登録人口"),
-  ("A21_348", "ごみ処理状況　計画処理区域内人口　計画収集人口"),
-  ("A21_349", "ごみ処理状況　計画処理区域内人口　自家処理人口"),
-  ("A21_350", "ごみ処理状況　計画処理区域内人口　計"),
-  ("A21_351", "ごみ処理状況　計画処理区域内人口　計画収集率"),
-  ("A21_352", "ごみ処理状況　区域内人口比率"),
-  ("A21_353", "ごみ処理状況　収集ごみ処理内訳　施設処理"),
-  ("A21_354", "ごみ処理状況　収集ごみ処理内訳　埋立て"),
-  ("A21_355", "ごみ処理状況　収集ごみ処理内訳　その他"),
-  ("A21_356", "ごみ処理状況　収集ごみ処理内訳　計"),
-  ("A21_357", "ごみ処理状況　収集ごみ処理内訳　施設処理率"),
-  ("A21_358", "ごみ処理状況　備考"),
-  ("A21_359", "汚水処理状況　住民登録人口"),
-  ("A21_360", "汚水処理状況　計画処理区域内人口　公共・流域下水道"),
-  ("A21_361", "汚水処理状況　計画処理区域内人口　農業集落排水"),
-  ("A21_362", "汚水処理状況　計画処理区域内人口　漁業集落環境整備"),
-  ("A21_363", "汚水処理状況　計画処理区域内人口　コミュニティ・プラント"),
-  ("A21_364", "汚水処理状況　計画処理区域内人口　合併処理浄化槽"),
-  ("A21_365", "汚水処理状況　計画処理区域内人口　その他"),
-  ("A21_366", "汚水処理状況　計画処理区域内人口　計"),
-  ("A21_367", "汚水処理状況　区域内人口比率"),
-  ("A21_368", "汚水処理状況　処理人口　公共・流域下水道"),
-  ("A21_369", "汚水処理状況　処理人口　農業集落排水"),
-  ("A21_370", "汚水処理状況　処理人口　漁業集落環境整備"),
-  ("A21_371", "汚水処理状況　処理人口　コミュニティ・プラント"),
-  ("A21_372", "汚水処理状況　処理人口　合併処理浄化槽"),
-  ("A21_373", "汚水処理状況　処理人口　その他"),
-  ("A21_374", "汚水処理状況　処理人口　計"),
-  ("A21_375", "汚水処理状況　処理人口普及率"),
-  ("A21_376", "汚水処理状況　備考"),
-  ("A21_377", "財政1　決算　基準財政需要額"),
-  ("A21_378", "財政1　決算　基準財政収入額"),
-  ("A21_379", "財政1　決算　財政力指数"),
-  ("A21_380", "財政1　現在　積立金現在高"),
-  ("A21_381", "財政1　現在　地方債現在高"),
-  ("A21_382", "財政1　歳入　地方税収入"),
-  ("A21_383", "財政1　歳入　地方交付税"),
-  ("A21_384", "財政1　歳入　国庫支出金"),
-  ("A21_385", "財政1　歳入　都道県支出金"),
-  ("A21_386", "財政1　歳入　地方債"),
-  ("A21_387", "財政1　歳入　その他"),
-  ("A21_388", "財政1　歳入　計"),
-  ("A21_389", "財政2　歳出　議会費"),
-  ("A21_390", "財政2　歳出　総務費"),
-  ("A21_391", "財政2　歳出　民生費"),
-  ("A21_392", "財政2　歳出　衛生費"),
-  ("A21_393", "財政2　歳出　労働費"),
-  ("A21_394", "財政2　歳出　農林水産費"),
-  ("A21_395", "財政2　歳出　商工費"),
-  ("A21_396", "財政2　歳出　土木費"),
-  ("A21_397", "財政2　歳出　消防費"),
-  ("A21_398", "財政2　歳出　教育費"),
-  ("A21_399", "財政2　歳出　災害復旧費"),
-  ("A21_400", "財政2　歳出　公債費"),
-  ("A21_401", "財政2　歳出　諸支出金"),
-  ("A21_402", "財政2　歳出　計"),
-  ("A21_403", "調査年度"),
-  ("A21_404", "就業者数　就業者総数　"),
-  ("A21_405", "就業者数　第1次　農業"),
-  ("A21_406", "就業者数　第1次　農業、林業"),
-  ("A21_407", "就業者数　第1次　農業、林業（うち農業）"),
-  ("A21_408", "就業者数　第1次　林業"),
-  ("A21_409", "就業者数　第1次　漁業"),
-  ("A21_410", "就業者数　第1次　小計"),
-  ("A21_411", "就業者数　第2次　鉱業"),
-  ("A21_412", "就業者数　第2次　鉱業，採石業，砂利採取業"),
-  ("A21_413", "就業者数　第2次　建設業"),
-  ("A21_414", "就業者数　第2次　製造業"),
-  ("A21_415", "就業者数　第2次　小計"),
-  ("A21_416", "就業者数　第3次　電気・ガス・熱供給・水道業"),
-  ("A21_417", "就業者数　第3次　運輸・通信業"),
-  ("A21_418", "就業者数　第3次　情報・通信業"),
-  ("A21_419", "就業者数　第3次　運輸業"),
-  ("A21_420", "就業者数　第3次　運輸業、郵便業"),
-  ("A21_421", "就業者数　第3次　卸売・小売業・飲食業"),
-  ("A21_422", "就業者数　第3次　卸売・小売業"),
-  ("A21_423", "就業者数　第3次　金融・保険業"),
-  ("A21_424", "就業者数　第3次　不動産業"),
-  ("A21_425", "就業者数　第3次　不動産業、物品賃貸業"),
-  ("A21_426", "就業者数　第3次　学術研究，専門・技術サービス業"),
-  ("A21_427", "就業者数　第3次　飲食店・宿泊業"),
-  ("A21_428", "就業者数　第3次　宿泊業，飲食サービス業　"),
-  ("A21_429", "就業者数　第3次　生活関連サービス業，娯楽業"),
-  ("A21_430", "就業者数　第3次　医療･福祉"),
-  ("A21_431", "就業者数　第3次　教育・学習支援業"),
-  ("A21_432", "就業者数　第3次　複合サービス事業"),
-  ("A21_433", "就業者数　第3次　サービス業"),
-  ("A21_434", "就業者数　第3次　公務"),
-  ("A21_435", "就業者数　第3次　小計"),
-  ("A21_436", "就業者数　分類不能　"),
-  ("A21_437", "就業者数　備考　"),
-  ("A21_438", "事業所数　総数　"),
-  ("A21_439", "事業所数　第1次　農業"),
-  ("A21_440", "事業所数　第1次　林業"),
-  ("A21_441", "事業所数　第1次　漁業"),
-  ("A21_442", "事業所数　第1次　小計"),
-  ("A21_443", "事業所数　第2次　鉱業"),
-  ("A21_444", "事業所数　第2次　建設業"),
-  ("A21_445", "事業所数　第2次　製造業"),
-  ("A21_446", "事業所数　第2次　小計"),
-  ("A21_447", "事業所数　第3次　電気・ガス・熱供給・水道業"),
-  ("A21_448", "事業所数　第3次　運輸・通信業"),
-  ("A21_449", "事業所数　第3次　卸売・小売業・飲食業"),
-  ("A21_450", "事業所数　第3次　金融・保険業"),
-  ("A21_451", "事業所数　第3次　不動産業"),
-  ("A21_452", "事業所数　第3次　サービス業"),
-  ("A21_453", "事業所数　第3次　公務"),
-  ("A21_454", "事業所数　第3次　小計"),
-  ("A21_455", "事業所数　分類不能　"),
-  ("A21_456", "従業者数　総数　"),
-  ("A21_457", "従業者数　第1次　農業"),
-  ("A21_458", "従業者数　第1次　林業"),
-  ("A21_459", "従業者数　第1次　漁業"),
-  ("A21_460", "従業者数　第1次　小計"),
-  ("A21_461", "従業者数　第2次　鉱業"),
-  ("A21_462", "従業者数　第2次　建設業"),
-  ("A21_463", "従業者数　第2次　製造業"),
-  ("A21_464", "従業者数　第2次　小計"),
-  ("A21_465", "従業者数　第3次　電気・ガス・熱供給・水道業"),
-  ("A21_466", "従業者数　第3次　運輸・通信業"),
-  ("A21_467", "従業者数　第3次　卸売・小売業・飲食業"),
-  ("A21_468", "従業者数　第3次　金融・保険業"),
-  ("A21_469", "従業者数　第3次　不動産業"),
-  ("A21_470", "従業者数　第3次　サービス業"),
-  ("A21_471", "従業者数　第3次　公務"),
-  ("A21_472", "従業者数　第3次　小計"),
-  ("A21_473", "従業者数　分類不能　"),
-  ("A21_474", "農林業　総面積　"),
-  ("A21_475", "農林業　農振面積　"),
-  ("A21_476", "農林業　耕地面積　総数"),
-  ("A21_477", "農林業　耕地面積　田"),
-  ("A21_478", "農林業　耕地面積　畑"),
-  ("A21_479", "農林業　耕地面積　樹園地"),
-  ("A21_480", "農林業　耕地面積　牧草地"),
-  ("A21_481", "農林業　耕地化率　"),
-  ("A21_482", "農林業　家畜飼育数　乳用牛"),
-  ("A21_483", "農林業　家畜飼育数　肉用牛"),
-  ("A21_484", "農林業　家畜飼育数　豚"),
-  ("A21_485", "農林業　家畜飼育数　鶏"),
-  ("A21_486", "農林業　農道延長　"),
-  ("A21_487", "農林業　林道延長　"),
-  ("A21_488", "農林業　林道延長　内国有林道"),
-  ("A21_489", "農林業　森林面積　総数　"),
-  ("A21_490", "農林業　森林面積　総数　うち人工林"),
-  ("A21_491", "農林業　森林面積　国有林　"),
-  ("A21_492", "農林業　森林面積　国有林　うち人工林"),
-  ("A21_493", "農林業　森林面積　民有林　"),
-  ("A21_494", "農林業　森林面積　民有林　うち人工林"),
-  ("A21_495", "農林業　森林蓄積量　"),
-  ("A21_496", "農林業　備考　"),
-  ("A21_497", "水産業現況_港湾　水揚高　属人水揚　計(水揚トン数)"),
-  ("A21_498", "水産業現況_港湾　水揚高　属人水揚　計(水揚金額)"),
-  ("A21_499", "水産業現況_港湾　水揚高　属人水揚　海面漁業(水揚トン数)"),
-  ("A21_500", "水産業現況_港湾　水揚高　属人水揚　海面漁業(水揚金額)"),
-  ("A21_501", "水産業現況_港湾　水揚高　属人水揚　海面養殖業(水揚トン数)"),
-  ("A21_502", "水産業現況_港湾　水揚高　属人水揚　海面養殖業(水揚金額)"),
-  ("A21_503", "水産業現況_港湾　水揚高　属地水揚　計(水揚トン数)"),
-  ("A21_504", "水産業現況_港湾　水揚高　属地水揚　計(水揚金額)"),
-  ("A21_505", "水産業現況_港湾　水揚高　属地水揚　海面漁業(水揚トン数)"),
-  ("A21_506", "水産業現況_港湾　水揚高　属地水揚　海面漁業(水揚金額)"),
-  ("A21_507", "水産業現況_港湾　水揚高　属地水揚　海面養殖業(水揚トン数)"),
-  ("A21_508", "水産業現況_港湾　水揚高　属地水揚　海面養殖業(水揚金額)"),
-  ("A21_509", "水産業現況_漁港　水揚高　属人水揚　計(水揚トン数)"),
-  ("A21_510", "水産業現況_漁港　水揚高　属人水揚　計(水揚金額)"),
-  ("A21_511", "水産業現況_漁港　水揚高　属人水揚　海面漁業(水揚トン数)"),
-  ("A21_512", "水産業現況_漁港　水揚高　属人水揚　海面漁業(水揚金額)"),
-  ("A21_513", "水産業現況_漁港　水揚高　属人水揚　海面養殖業(水揚トン数)"),
-  ("A21_514", "水産業現況_漁港　水揚高　属人水揚　海面養殖業(水揚金額)"),
-  ("A21_515", "水産業現況_漁港　水揚高　属地水揚　計(水揚トン数)"),
-  ("A21_516", "水産業現況_漁港　水揚高　属地水揚　計(水揚金額)"),
-  ("A21_517", "水産業現況_漁港　水揚高　属地水揚　海面漁業(水揚トン数)"),
-  ("A21_518", "水産業現況_漁港　水揚高　属地水揚　海面漁業(水揚金額)"),
-  ("A21_519", "水産業現況_漁港　水揚高　属地水揚　海面養殖業(水揚トン数)"),
-  ("A21_520", "水産業現況_漁港　水揚高　属地水揚　海面養殖業(水揚金額)"),
-  ("A21_521", "経営体　農家戸数　総数　　"),
-  ("A21_522", "経営体　農家戸数　販売農業　専業　"),
-  ("A21_523", "経営体　農家戸数　販売農業　兼業　1種"),
-  ("A21_524", "経営体　農家戸数　販売農業　兼業　2種"),
-  ("A21_525", "経営体　農家戸数　自給的農家　　"),
-  ("A21_526", "経営体　漁家戸数　総数　　"),
-  ("A21_527", "経営体　漁家戸数　専業　　"),
-  ("A21_528", "経営体　漁家戸数　兼業　1種　"),
-  ("A21_529", "経営体　漁家戸数　兼業　2種　"),
-  ("A21_530", "経営体　林家戸数　総数　　"),
-  ("A21_531", "経営体　林家戸数　農家林家　　"),
-  ("A21_532", "経営体　林家戸数　非農家林家　　"),
-  ("A21_533", "経営体　組合数　農業協同組合　組合数（本部）"),
-  ("A21_534", "経営体　組合数　農業協同組合　組合数（支部・支所）"),
-  ("A21_535", "経営体　組合数　農業協同組合　組合員数"),
-  ("A21_536", "経営体　組合数　漁業協同組合　組合数（本部）"),
-  ("A21_537", "経営体　組合数　漁業協同組合　組合数（支部・支所）"),
-  ("A21_538", "経営体　組合数　漁業協同組合　組合員数"),
-  ("A21_539", "経営体　組合数　森林組合　組合数（本部）"),
-  ("A21_540", "経営体　組合数　森林組合　組合数（支部・支所）"),
-  ("A21_541", "経営体　組合数　森林組合　組合員数"),
-  ("A21_542", "生産額　農業　耕種　米"),
-  ("A21_543", "生産額　農業　耕種　麦"),
-  ("A21_544", "生産額　農業　耕種　いも"),
-  ("A21_545", "生産額　農業　耕種　豆･雑穀"),
-  ("A21_546", "生産額　農業　耕種　野菜"),
-  ("A21_547", "生産額　農業　耕種　果実"),
-  ("A21_548", "生産額　農業　耕種　花卉"),
-  ("A21_549", "生産額　農業　耕種　工芸作物"),
-  ("A21_550", "生産額　農業　耕種　その他"),
-  ("A21_551", "生産額　農業　耕種　小計"),
-  ("A21_552", "生産額　農業　養蚕　"),
-  ("A21_553", "生産額　農業　畜産　牛肉"),
-  ("A21_554", "生産額　農業　畜産　豚肉"),
-  ("A21_555", "生産額　農業　畜産　牛乳"),
-  ("A21_556", "生産額　農業　畜産　鶏卵"),
-  ("A21_557", "生産額　農業　畜産　その他"),
-  ("A21_558", "生産額　農業　畜産　小計"),
-  ("A21_559", "生産額　農業　合計　"),
-  ("A21_560", "生産額　林業　木材　用材"),
-  ("A21_561", "生産額　林業　木材　薪炭材"),
-  ("A21_562", "生産額　林業　木炭　"),
-  ("A21_563", "生産額　林業　しいたけ　"),
-  ("A21_564", "生産額　林業　その他　"),
-  ("A21_565", "生産額　林業　合計　"),
-  ("A21_566", "生産額　水産業　魚類　"),
-  ("A21_567", "生産額　水産業　貝類　"),
-  ("A21_568", "生産額　水産業　水産動物　"),
-  ("A21_569", "生産額　水産業　海草類　"),
-  ("A21_570", "生産額　水産業　その他　"),
-  ("A21_571", "生産額　水産業　合計　"),
-  ("A21_572", "生産額　農林水産業総計　　"),
-  ("A21_573", "生産額　備考　　"),
-  ("A21_574", "観光客数　春　"),
-  ("A21_575", "観光客数　夏　"),
-  ("A21_576", "観光客数　秋　"),
-  ("A21_577", "観光客数　冬　"),
-  ("A21_578", "観光客数　計　"),
-  ("A21_579", "宿泊能力(最盛期)　旅館・ホテル　数"),
-  ("A21_580", "宿泊能力(最盛期)　旅館・ホテル　収容能力"),
-  ("A21_581", "宿泊能力(最盛期)　民宿　数"),
-  ("A21_582", "宿泊能力(最盛期)　民宿　収容能力"),
-  ("A21_583", "宿泊能力　年間宿泊者数　　"),
-  ("A21_584", "自然公園面積　国立公園　"),
-  ("A21_585", "自然公園面積　国定公園　"),
-  ("A21_586", "自然公園面積　県立公園　"),
-  ("A21_587", "観光客数・宿泊能力・自然公園　備考　　"),
-  ("A21_588", "港湾・航路現況_港湾　港湾利用状況　入港船舶　隻数"),
-  ("A21_589", "港湾・航路現況_港湾　総屯数"),
-  ("A21_590", "港湾・航路現況_港湾　乗降人員　"),
-  ("A21_591", "港湾・航路現況_港湾　海上出入貨物　出"),
-  ("A21_592", "港湾・航路現況_港湾　海上出入貨物　入"),
-  ("A21_593", "港湾・航路現況_港湾　海上出入貨物　計"),
-  ("A21_594", "港湾・航路現況_漁港　港湾利用状況　入港船舶　隻数"),
-  ("A21_595", "港湾・航路現況_漁港　総屯数"),
-  ("A21_596", "港湾・航路現況_漁港　乗降人員　"),
-  ("A21_597", "港湾・航路現況_漁港　海上出入貨物　出"),
-  ("A21_598", "港湾・航路現況_漁港　海上出入貨物　入"),
-  ("A21_599", "港湾・航路現況_漁港　海上出入貨物　計"),
-  ("A21_600", "空港・航空路現況　都道県名　空港名　　"),
-  ("A21_601", "空港・航空路現況　運航実績　1日当たり運航回数　平常時"),
-  ("A21_602", "空港・航空路現況　　1日当たり運航回数　最盛期"),
-  ("A21_603", "空港・航空路現況　　年間乗降人員　"),
-
-  ///// A22 /////
-
-  ("A22_001", "豪雪地帯ID"),
-  ("A22_002", "行政区域コード"),
-  ("A22_003", "都道府県名"),
-  ("A22_004", "支庁・振興局名"),
-  ("A22_005", "郡・政令都市名"),
-  ("A22_006", "市区町村名"),
-  ("A22_007", "原典市区町村名"),
-  ("A22_008", "旧市町村名"),
-  ("A22_009", "豪雪区分コード"),
-
-  ///// A22-m /////
-
-  ("A22_000001", "観測点名"),
-  ("A22_000002", "観測点の所在地"),
-  ("A22_000003", "観測点の管理者"),
-  ("A22_050001", "位置特定レベル"),
-  ("A22_010001", "各年度別最深積雪（平均値）"),
-  ("A22_010002", "各年度別最深積雪（最大値）"),
-  ("A22_020001", "各年度別累計降雪量（平均値）"),
-  ("A22_020002", "各年度別累計降雪量（最大値）"),
-  ("A22_030001", "各年度別最低気温（平均値）"),
-  ("A22_030002", "各年度別最低気温（最低値）"),
-  ("A22_040001", "各年度別平均風速（平均値）"),
-  ("A22_040002", "各年度別平均風速（最大値）"),
-  ("A22_100001", "豪雪地帯ID"),
-  ("A22_100002", "地域指定時の市区町村名"),
-  ("A22_100003", "データ整備時の行政コード"),
-  ("A22_100004", "集計単位となる団体名（雪害）"),
-  ("A22_100005", "集計単位フラグ（雪害）"),
-  ("A22_100006", "集計単位となる団体名（除雪ボランティア）"),
-  ("A22_100007", "集計単位フラグ（除雪ボランティア）"),
-  ("A22_40001", "利雪施設名"),
-  ("A22_40002", "利雪施設の設置者"),
-  ("A22_40003", "利雪施設の所在地"),
-  ("A22_40004", "冷熱源確保方式"),
-  ("A22_40005", "冷熱供給方式"),
-  ("A22_40006", "完成年度"),
-  ("A22_40007", "施設の規模"),
-  ("A22_40008", "貯雪量"),
-  ("A22_40009", "位置特定レベル"),
-
-  ///// A22s /////
-
-  ("A22_001", "豪雪地帯ID"),
-  ("A22_002", "行政コード"),
-  ("A22_003", "都道府県名"),
-  ("A22_004", "支庁名"),
-  ("A22_005", "郡・政令市名"),
-  ("A22_006", "市区町村名"),
-  ("A22_007", "豪雪区分コード"),
-  ("A22_008", "指定状況コード"),
-  ("A22_009", "調査年度"),
-  ("A22_010", "概要　人口"),
-  ("A22_011", "概要　世帯数"),
-  ("A22_012", "男　国勢調査人口総数"),
-  ("A22_013", "男　年少人口　0-4"),
-  ("A22_014", "男　年少人口　5-9"),
-  ("A22_015", "男　年少人口　10-14"),
-  ("A22_016", "男　年少人口　小計"),
-  ("A22_017", "男　生産年齢人口　15-19"),
-  ("A22_018", "男　生産年齢人口　20-24"),
-  ("A22_019", "男　生産年齢人口　25-29"),
-  ("A22_020", "男　生産年齢人口　30-34"),
-  ("A22_021", "男　生産年齢人口　35-39"),
-  ("A22_022", "男　生産年齢人口　40-44"),
-  ("A22_023", "男　生産年齢人口　45-49"),
-  ("A22_024", "男　生産年齢人口　50-54"),
-  ("A22_025", "男　生産年齢人口　55-59"),
-  ("A22_026", "男　生産年齢人口　60-64"),
-  ("A22_027", "男　生産年齢人口　小計"),
-  ("A22_028", "男　老年人口　65-69"),
-  ("A22_029", "男　老年人口　70-74"),
-  ("A22_030", "男　老年人口　75-79"),
-  ("A22_031", "男　老年人口　80-84"),
-  ("A22_032", "男　老年人口　85歳以上"),
-  ("A22_033", "男　老年人口　小計"),
-  ("A22_034", "男　不詳　"),
-  ("A22_035", "女　国勢調査人口総数　"),
-  ("A22_036", "女　年少人口　0-4"),
-  ("A22_037", "女　年少人口　5-9"),
-  ("A22_038", "女　年少人口　10-14"),
-  ("A22_039", "女　年少人口　小計"),
-  ("A22_040", "女　生産年齢人口　15-19"),
-  ("A22_041", "女　生産年齢人口　20-24"),
-  ("A22_042", "女　生産年齢人口　25-29"),
-  ("A22_043", "女　生産年齢人口　30-34"),
-  ("A22_044", "女　生産年齢人口　35-39"),
-  ("A22_045", "女　生産年齢人口　40-44"),
-  ("A22_046", "女　生産年齢人口　45-49"),
-  ("A22_047", "女　生産年齢人口　50-54"),
-  ("A22_048", "女　生産年齢人口　55-59"),
-  ("A22_049", "女　生産年齢人口　60-64"),
-  ("A22_050", "女　生産年齢人口　小計"),
-  ("A22_051", "女　老年人口　65-69"),
-  ("A22_052", "女　老年人口　70-74"),
-  ("A22_053", "女　老年人口　75-79"),
-  ("A22_054", "女　老年人口　80-84"),
-  ("A22_055", "女　老年人口　85歳以上"),
-  ("A22_056", "女　老年人口　小計"),
-  ("A22_057", "女　不詳　"),
-  ("A22_058", "男女計　国勢調査人口総数　"),
-  ("A22_059", "男女計　年少人口　0-4"),
-  ("A22_060", "男女計　年少人口　5-9"),
-  ("A22_061", "男女計　年少人口　10-14"),
-  ("A22_062", "男女計　年少人口　小計"),
-  ("A22_063", "男女計　生産年齢人口　15-19"),
-  ("A22_064", "男女計　生産年齢人口　20-24"),
-  ("A22_065", "男女計　生産年齢人口　25-29"),
-  ("A22_066", "男女計　生産年齢人口　30-34"),
-  ("A22_067", "男女計　生産年齢人口　35-39"),
-  ("A22_068", "男女計　生産年齢人口　40-44"),
-  ("A22_069", "男女計　生産年齢人口　45-49"),
-  ("A22_070", "男女計　生産年齢人口　50-54"),
-  ("A22_071", "男女計　生産年齢人口　55-59"),
-  ("A22_072", "男女計　生産年齢人口　60-64"),
-  ("A22_073", "男女計　生産年齢人口　小計"),
-  ("A22_074", "男女計　老年人口　65-69"),
-  ("A22_075", "男女計　老年人口　70-74"),
-  ("A22_076", "男女計　老年人口　75-79"),
-  ("A22_077", "男女計　老年人口　80-84"),
-  ("A22_078", "男女計　老年人口　85歳以上"),
-  ("A22_079", "男女計　老年人口　小計"),
-  ("A22_080", "男女計　不詳　"),
-  ("A22_081", "就業者数　就業者総数　"),
-  ("A22_082", "就業者数　第1次　農業"),
-  ("A22_083", "就業者数　第1次　農業、林業"),
-  ("A22_084", "就業者数　第1次　農業、林業（うち農業）"),
-  ("A22_085", "就業者数　第1次　林業"),
-  ("A22_086", "就業者数　第1次　漁業"),
-  ("A22_087", "就業者数　第1次　小計"),
-  ("A22_088", "就業者数　第2次　鉱業"),
-  ("A22_089", "就業者数　第2次　鉱業，採石業，砂利採取業"),
-  ("A22_090", "就業者数　第2次　建設業"),
-  ("A22_091", "就業者数　第2次　製造業"),
-  ("A22_092", "就業者数　第2次　小計"),
-  ("A22_093", "就業者数　第3次　電気・ガス・熱供給・水道業"),
-  ("A22_094", "就業者数　第3次　運輸・通信業"),
-  ("A22_095", "就業者数　第3次　情報・通信業"),
-  ("A22_096", "就業者数　第3次　運輸業"),
-  ("A22_097", "就業者数　第3次　運輸業、郵便業"),
-  ("A22_098", "就業者数　第3次　卸売・小売業・飲食店"),
-  ("A22_099", "就業者数　第3次　卸売・小売業"),
-  ("A22_100", "就業者数　第3次　金融・保険業"),
-  ("A22_101", "就業者数　第3次　不動産業"),
-  ("A22_102", "就業者数　第3次　不動産業、物品賃貸業"),
-  ("A22_103", "就業者数　第3次　学術研究，専門・技術サービス業"),
-  ("A22_104", "就業者数　第3次　飲食店・宿泊業"),
-  ("A22_105", "就業者数　第3次　宿泊業，飲食サービス業　"),
-  ("A22_106", "就業者数　第3次　生活関連サービス業，娯楽業"),
-  ("A22_107", "就業者数　第3次　医療･福祉"),
-  ("A22_108", "就業者数　第3次　教育・学習支援業"),
-  ("A22_109", "就業者数　第3次　複合サービス事業"),
-  ("A22_110", "就業者数　第3次　サービス業"),
-  ("A22_111", "就業者数　第3次　公務"),
-  ("A22_112", "就業者数　第3次　小計"),
-  ("A22_113", "就業者数　分類不能　"),
-  ("A22_114", "調査年度"),
-  ("A22_115", "事業所　全産業"),
-  ("A22_116", "事業所　全産業（S公務を除く）"),
-  ("A22_117", "事業所　農林漁業"),
-  ("A22_118", "事業所　非農林漁業1"),
-  ("A22_119", "事業所　非農林漁業2"),
-  ("A22_120", "事業所　鉱業"),
-  ("A22_121", "事業所　鉱業、採石業、砂利採取業"),
-  ("A22_122", "事業所　建設業"),
-  ("A22_123", "事業所　製造業"),
-  ("A22_124", "事業所　電気・ガス・熱供給・水道業"),
-  ("A22_125", "事業所　運輸・通信業"),
-  ("A22_126", "事業所　情報・通信業"),
-  ("A22_127", "事業所　運輸業"),
-  ("A22_128", "事業所　運輸業、郵便業"),
-  ("A22_129", "事業所　卸売・小売業・飲食業"),
-  ("A22_130", "事業所　卸売・小売業"),
-  ("A22_131", "事業所　金融・保険業"),
-  ("A22_132", "事業所　不動産業"),
-  ("A22_133", "事業所　 不動産業、物品賃貸業"),
-  ("A22_134", "事業所　学術研究、専門・技術サービス業"),
-  ("A22_135", "事業所　飲食店・宿泊業"),
-  ("A22_136", "事業所　宿泊業、飲食サービス業"),
-  ("A22_137", "事業所　生活関連サービス業、娯楽業"),
-  ("A22_138", "事業所　医療･福祉"),
-  ("A22_139", "事業所　教育・学習支援業"),
-  ("A22_140", "事業所　複合サービス事業"),
-  ("A22_141", "事業所　サービス業"),
-  ("A22_142", "事業所　公務"),
-  ("A22_143", "事業所　1～4人"),
-  ("A22_144", "事業所　5～9人"),
-  ("A22_145", "事業所　10～19人"),
-  ("A22_146", "事業所　20～29人"),
-  ("A22_147", "事業所　30人以上"),
-  ("A22_148", "事業所　派遣・下請従業者のみ"),
-  ("A22_149", "事業所　経営組織別事業所数（民営事業所）"),
-  ("A22_150", "事業所　事業所の形態別事業所数（民営事業所）"),
-  ("A22_151", "事業所　うち個人"),
-  ("A22_152", "事業所　うち法人"),
-  ("A22_153", "事業所　うち会社"),
-  ("A22_154", "事業所　店舗・飲食店"),
-  ("A22_155", "事業所　事務所・営業所"),
-  ("A22_156", "事業所　工場・作業所・鉱業所"),
-  ("A22_157", "事業所　輸送センター・配送センター・これらの倉庫"),
-  ("A22_158", "事業所　自家用倉庫・自家用油槽所"),
-  ("A22_159", "事業所　外見上一般の住居と区別しにくい事業所"),
-  ("A22_160", "事業所　その他（学校・病院・寺社・旅館・浴場など）"),
-
-  ///// A23 /////
-
-  ("A23_001", "特殊土壌ID"),
-  ("A23_002", "行政区域コード"),
-  ("A23_003", "都道府県名"),
-  ("A23_004", "支庁・振興局名"),
-  ("A23_005", "郡・政令都市名"),
-  ("A23_006", "市区町村名"),
-  ("A23_007", "原典市区町村名"),
-  ("A23_008", "旧市町村名"),
-  ("A23_009", "特殊土壌区分コード"),
-
-  ///// A24 /////
-
-  ("A24_001", "振興山村ID"),
-  ("A24_002", "行政区域コード"),
-  ("A24_003", "都道府県名"),
-  ("A24_004", "支庁・振興局名"),
-  ("A24_005", "郡・政令都市名"),
-  ("A24_006", "市区町村名"),
-  ("A24_007", "原典市区町村名"),
-  ("A24_008", "合併前市町村名"),
-  ("A24_009", "旧市町村名"),
-  ("A24_010", "指定年度"),
-  ("A24_011", "指定番号"),
-
-  ///// A25 /////
-
-  ("A25_001", "特定農山村ID"),
-  ("A25_002", "行政区域コード"),
-  ("A25_003", "都道府県名"),
-  ("A25_004", "支庁・振興局名"),
-  ("A25_005", "郡・政令都市名"),
-  ("A25_006", "市区町村名"),
-  ("A25_007", "原典市区町村名"),
-  ("A25_008", "旧市町村名"),
-
-  ///// A26 /////
-
-  ("A26_001", "危険箇所種別"),
-  ("A26_002", "備考"),
-  ("A26_003", "危険箇所種別"),
-  ("A26_004", "備考"),
-  ("A26_005", "危険箇所種別"),
-  ("A26_006", "備考"),
-
-  ///// A27 /////
-
-  ("A27_001", "市区町村コード"),
-  ("A27_002", "設置主体"),
-  ("A27_003", "名称"),
-  ("A27_004", "所在地"),
-  ("A27_005", "市区町村コード"),
-  ("A27_006", "設置主体"),
-  ("A27_007", "名称"),
-  ("A27_008", "所在地"),
-
-  ///// A28 /////
-
-  ("WHC", "世界自然遺産名称コード"),
-
-  ///// A29 /////
-
-  ("A29_001", "行政区域コード"),
-  ("A29_002", "都道府県名"),
-  ("A29_003", "市区町村名"),
-  ("A29_004", "用途地域コード"),
-  ("A29_005", "用途地域名"),
-  ("A29_006", "建蔽率"),
-  ("A29_007", "容積率"),
-  ("A29_008", "備考"),
-
-  ///// A30a5 /////
-
-  ("A30a5_001", "発生場所（都道府県）"),
-  ("A30a5_002", "発生場所（郡・市・区・町・村）"),
-  ("A30a5_003", "発生日"),
-  ("A30a5_004", "災害の種類"),
-  ("A30a5_005", "最大24時間雨量"),
-  ("A30a5_006", "最大時間雨量"),
-  ("A30a5_007", "勾配"),
-  ("A30a5_008", "流出土砂量"),
-  ("A30a5_009", "地すべり・雪崩の長さ"),
-  ("A30a5_010", "空間属性（メッシュ）"),
-
-  ///// A30b /////
-
-  ("A30b_001", "竜巻等の突風番号"),
-  ("A30b_002", "竜巻ダウンバースト区別"),
-  ("A30b_003", "発生_日時"),
-  ("A30b_004", "発生_誤差"),
-  ("A30b_005", "発生_都道府県名"),
-  ("A30b_006", "発生_市区町村名"),
-  ("A30b_007", "発生_番地"),
-  ("A30b_008", "消滅_日時"),
-  ("A30b_009", "消滅_誤差"),
-  ("A30b_010", "消滅_都道府県名"),
-  ("A30b_011", "消滅_市区町村名"),
-  ("A30b_012", "消滅_番地"),
-  ("A30b_013", "藤田スケール（Fスケール）"),
-  ("A30b_014", "被害域_幅"),
-  ("A30b_015", "被害域_長さ"),
-  ("A30b_016", "移動_方向1"),
-  ("A30b_017", "移動_方向2"),
-  ("A30b_018", "移動_速度"),
-  ("A30b_019", "継続時間"),
-  ("A30b_020", "x回転方向"),
-  ("A30b_021", "発生地点区別"),
-  ("A30b_022", "総観場_1"),
-  ("A30b_023", "総観場_2"),
-  ("A30b_024", "総観場_3"),
-  ("A30b_025", "総観規模じょう乱からの位置"),
-  ("A30b_026", "特徴"),
-  ("A30b_027", "発生位置緯度誤差"),
-  ("A30b_028", "発生位置経度誤差"),
-  ("A30b_029", "発生位置緯度"),
-  ("A30b_030", "発生位置経度"),
-  ("A30b_031", "消滅位置緯度誤差"),
-  ("A30b_032", "消滅位置経度誤差"),
-  ("A30b_033", "消滅位置緯度"),
-  ("A30b_034", "消滅位置経度"),
-
-  ///// A31 /////
-
-  ("A31_101", "浸水深ランク"),
-  ("A31_102", "指定年月日"),
-  ("A31_103", "告示番号"),
-  ("A31_104", "指定の前提となる降雨"),
-  ("A31_201", "浸水深ランク"),
-  ("A31_202", "指定年月日"),
-  ("A31_203", "告示番号"),
-  ("A31_204", "指定の前提となる降雨"),
-  ("A31_301", "浸水継続時間ランク"),
-  ("A31_302", "指定年月日"),
-  ("A31_303", "告示番号"),
-  ("A31_304", "指定の前提となる降雨"),
-  ("A31_401", "危険区域区分"),
-  ("A31_402", "指定年月日"),
-  ("A31_403", "告示番号"),
-  ("A31_404", "指定の前提となる降雨"),
-
-  ///// A32 /////
-
-  ("A32_001", "市区町村コード"),
-  ("A32_002", "設置主体"),
-  ("A32_003", "名称"),
-  ("A32_004", "所在地"),
-  ("A32_005", "通学区域ファイル名"),
-  ("A32_006", "市区町村コード"),
-  ("A32_007", "設置主体"),
-  ("A32_008", "名称"),
-  ("A32_009", "所在地"),
-  ("A32_010", "通学区域ファイル名"),
-
-  ///// A33 /////
-
-  ("A33_001", "現象の種類"),
-  ("A33_002", "区域区分"),
-  ("A33_003", "都道府県コード"),
-  ("A33_004", "区域番号"),
-  ("A33_005", "区域名"),
-  ("A33_006", "所在地"),
-  ("A33_007", "告示日"),
-  ("A33_008", "特別警戒未指定フラグ"),
-
-  ///// A34 /////
-
-  ("A34a_001", "構成資産範囲ID"),
-  ("A34a_002", "世界遺産番号"),
-  ("A34a_003", "世界文化遺産名"),
-  ("A34a_004", "地区名"),
-  ("A34a_005", "都道府県名"),
-  ("A34a_006", "構成資産範囲面積"),
-  ("A34a_007", "構成資産"),
-  ("A34a_008", "登録基準区分"),
-  ("A34a_009", "記載年月"),
-  ("A34b_001", "構成資産範囲ID"),
-  ("A34b_002", "世界遺産番号"),
-  ("A34b_003", "世界文化遺産名"),
-  ("A34b_004", "地区名"),
-  ("A34b_005", "都道府県名"),
-  ("A34b_006", "構成資産範囲面積"),
-  ("A34b_007", "構成資産"),
-  ("A34b_008", "登録基準区分"),
-  ("A34b_009", "記載年月"),
-  ("A34c_001", "構成資産範囲ID"),
-  ("A34c_002", "世界遺産番号"),
-  ("A34c_003", "世界文化遺産名"),
-  ("A34c_004", "地区名"),
-  ("A34c_005", "都道府県名"),
-  ("A34c_006", "構成資産範囲面積"),
-  ("A34c_007", "構成資産"),
-  ("A34c_008", "登録基準区分"),
-  ("A34c_009", "記載年月"),
-  ("A34d_001", "構成資産範囲ID"),
-  ("A34d_002", "世界遺産番号"),
-  ("A34d_003", "世界文化遺産名"),
-  ("A34d_004", "地区名"),
-  ("A34d_005", "都道府県名"),
-  ("A34d_006", "構成資産範囲面積"),
-  ("A34d_007", "構成資産"),
-  ("A34d_008", "登録基準区分"),
-  ("A34d_009", "記載年月"),
-  ("A34e_001", "構成資産ID"),
-  ("A34e_002", "世界遺産番号"),
-  ("A34e_003", "世界文化遺産名"),
-  ("A34e_004", "構成資産名"),
-  ("A34f_001", "緩衝地帯ID"),
-  ("A34f_002", "世界遺産番号"),
-  ("A34f_003", "世界文化遺産名"),
-  ("A34f_004", "地区名"),
-  ("A34f_005", "緩衝地帯面積"),
-  ("A34g_001", "緩衝地帯ID"),
-  ("A34g_002", "世界遺産番号"),
-  ("A34g_003", "世界文化遺産名"),
-  ("A34g_004", "地区名"),
-  ("A34g_005", "緩衝地帯面積"),
-
-  ///// A35a /////
-
-  ("A35a_001", "景観計画区域ID"),
-  ("A35a_002", "都道府県コード"),
-  ("A35a_003", "団体名"),
-  ("A35a_004", "行政コード"),
-  ("A35a_005", "策定年月日"),
-  ("A35a_006", "面積"),
-  ("A35a_007", "景観計画未策定フラグ"),
-  ("A35b_001", "景観計画区域ID"),
-  ("A35b_002", "都道府県コード"),
-  ("A35b_003", "団体名"),
-  ("A35b_004", "行政コード"),
-  ("A35b_005", "策定年月日"),
-  ("A35b_006", "面積"),
-  ("A35b_007", "景観計画未策定フラグ"),
-  ("A35c_001", "景観計画区域ID"),
-  ("A35c_002", "都道府県コード"),
-  ("A35c_003", "団体名"),
-  ("A35c_004", "行政コード"),
-  ("A35c_005", "地区名称"),
-
-  ///// A35b /////
-
-  ("A35d_001", "景観地区ID"),
-  ("A35d_002", "都道府県コード"),
-  ("A35d_003", "団体名"),
-  ("A35d_004", "行政コード"),
-  ("A35d_005", "名称"),
-  ("A35d_006", "位置"),
-  ("A35d_007", "面積"),
-  ("A35d_008", "決定年月日"),
-  ("A35d_009", "種別コード"),
-  ("A35e_001", "景観地区ID"),
-  ("A35e_002", "都道府県コード"),
-  ("A35e_003", "団体名"),
-  ("A35e_004", "行政コード"),
-  ("A35e_005", "名称"),
-  ("A35e_006", "位置"),
-  ("A35e_007", "面積"),
-  ("A35e_008", "決定年月日"),
-  ("A35e_009", "種別コード"),
-  ("A35f_001", "景観地区ゾーンID"),
-  ("A35f_002", "都道府県コード"),
-  ("A35f_003", "団体名"),
-  ("A35f_004", "行政コード"),
-  ("A35f_005", "景観地区・準景観地区名称"),
-  ("A35f_006", "ゾーン名称"),
-  ("A35f_007", "種別"),
-
-  ///// A35c /////
-
-  ("A35g_001", "景観重要建造物ID"),
-  ("A35g_002", "都道府県コード"),
-  ("A35g_003", "団体名"),
-  ("A35g_004", "行政コード"),
-  ("A35g_005", "指定番号"),
-  ("A35g_006", "指定年月日"),
-  ("A35g_007", "名称"),
-  ("A35g_008", "所在地"),
-  ("A35g_009", "外観の特徴"),
-  ("A35h_001", "景観重要樹木ID"),
-  ("A35h_002", "都道府県コード"),
-  ("A35h_003", "団体名"),
-  ("A35h_004", "行政コード"),
-  ("A35h_005", "指定番号"),
-  ("A35h_006", "指定年月日"),
-  ("A35h_007", "樹種"),
-  ("A35h_008", "所在地"),
-  ("A35h_009", "外観の特徴"),
-
-  ///// A37 /////
-
-  ("A37_000001", "指定時の路線名"),
-  ("A37_000002", "半島振興対策実施地域名"),
-  ("A37_000003", "指定時の道路種別"),
-  ("A37_000004", "最大交通量"),
-  ("A37_000005", "平均交通量"),
-  ("A37_000006", "指定時の区間（住所）"),
-  ("A37_100001", "半島振興対策実施地域名"),
-  ("A37_100002", "道路種別"),
-  ("A37_200001", "路線名"),
-  ("A37_200002", "道路種別"),
-  ("A37_200003", "規制基準雨量（連続雨量）"),
-  ("A37_200004", "規制基準雨量（1時間雨量）"),
-  ("A37_200005", "規制基準雨量（24時間雨量）"),
-  ("A37_200006", "規制基準"),
-  ("A37_210001", "通行規制区間の起点（住所）"),
-  ("A37_210002", "通行規制区間の終点（住所）"),
-  ("A37_300001", "半島ID"),
-  ("A37_300002", "半島振興対策実施地域名"),
-  ("A37_300003", "市町村名"),
-  ("A37_300004", "地域指定当時の市町村名"),
-  ("A37_300005", "集計単位となる団体名（救急搬送）"),
-  ("A37_300006", "データ整備時の行政コード"),
-  ("A37_300007", "集計単位フラグ（救急搬送）"),
-  ("A37_310001", "年平均日射量"),
-  ("A37_310002", "平均風速（標高30m）"),
-  ("A37_310003", "平均風速（標高50m）"),
-  ("A37_310004", "平均風速（標高70m）"),
-  ("A37_320001", "耕作放棄地面積"),
-  ("A37_330001", "宿泊能力（客室数）"),
-  ("A37_382000", "小・中学校生徒数（H12）"),
-  ("A37_382010", "小・中学校生徒数（H22）"),
-  ("A37_392000", "高校生徒数（H12）"),
-  ("A37_392010", "高校生徒数（H22）"),
-  ("A37_400001", "最寄空港までのアクセス時間（起点の施設名）"),
-  ("A37_400002", "最寄空港までのアクセス時間（終点の施設名）"),
-  ("A37_400003", "最寄空港までのアクセス時間（平均所要時間）"),
-  ("A37_410001", "最寄新幹線停車駅までのアクセス時間（起点の施設名）"),
-  ("A37_410002", "最寄新幹線停車駅までのアクセス時間（終点の施設名）"),
-  ("A37_410003", "最寄新幹線停車駅までのアクセス時間（平均所要時間）"),
-  ("A37_420001", "最寄ICまでのアクセス時間（起点の施設名）"),
-  ("A37_420002", "最寄ICまでのアクセス時間（終点の施設名）"),
-  ("A37_420003", "最寄ICまでのアクセス時間（平均所要時間）"),
-
-  ///// A38 /////
-
-  ("A38a_001", "一次医療圏_行政区域コード"),
-  ("A38a_002", "一次医療圏_市区町村名"),
-  ("A38a_003", "一次医療圏_二次医療圏コード"),
-  ("A38a_004", "一次医療圏_二次医療圏名"),
-  ("A38a_005", "一次医療圏_設定フラグ"),
-  ("A38b_001", "二次医療圏_行政区域コード"),
-  ("A38b_002", "二次医療圏_市区町村名"),
-  ("A38b_003", "二次医療圏_二次医療圏コード"),
-  ("A38b_004", "二次医療圏_二次医療圏名"),
-  ("A38b_005", "二次医療圏_面積（医療計画）"),
-  ("A38b_006", "二次医療圏_面積（国土地理院）"),
-  ("A38b_007", "二次医療圏_人口（医療計画）"),
-  ("A38b_008", "二次医療圏_総人口（住民基本台帳）"),
-  ("A38b_009", "二次医療圏_人口（15才未満）（住民基本台帳）"),
-  ("A38b_010", "二次医療圏_人口（15才以上65才未満）（住民基本台帳）"),
-  ("A38b_011", "二次医療圏_人口（65才以上）（住民基本台帳）"),
-  ("A38c_001", "三次医療圏_都道府県名"),
-  ("A38c_002", "三次医療圏_三次医療圏名"),
-
-  ///// A39 /////
-
-  ("A39_001", "都道府県コード"),
-  ("A39_002", "行政コード"),
-  ("A39_003", "市区町村名"),
-  ("A39_004", "地区ID"),
-  ("A39_005", "地区分割フラグ"),
-  ("A39_006", "地区名"),
-  ("A39_007", "地区面積"),
-  ("A39_008", "所在地"),
-  ("A39_009", "地区人口a"),
-  ("A39_010", "地区人口b"),
-  ("A39_011", "地区世帯数a"),
-  ("A39_012", "地区世帯数b"),
-  ("A39_013", "建物総棟数a"),
-  ("A39_014", "建物総棟数b"),
-  ("A39_015", "木造建物棟数"),
-  ("A39_016", "裸木造建物棟数"),
-  ("A39_017", "防火木造建物棟数"),
-  ("A39_018", "準耐火木造建物棟数"),
-  ("A39_019", "耐火木造建物棟数"),
-  ("A39_020", "不燃領域率"),
-  ("A39_021", "不燃領域率定義"),
-  ("A39_022", "木防率"),
-  ("A39_023", "調査年度"),
-  ("A39_024", "75歳以上高齢者数"),
-  ("A39_025", "防災再開発促進地区指定"),
-
-  ///// A40 /////
-
-  ("A40_001", "都道府県名"),
-  ("A40_002", "都道府県コード"),
-  ("A40_003", "津波浸水深の区分"),
-
-  ///// A43 /////
-
-  ("A43_001", "都道府県名"),
-  ("A43_002", "市町村名"),
-  ("A43_003", "行政コード"),
-  ("A43_004", "地区名称"),
-  ("A43_005", "指定"),
-  ("A43_006", "所在地"),
-  ("A43_007", "種別"),
-  ("A43_008", "選定日"),
-  ("A43_009", "面積"),
-  ("A43_010", "文化遺産オンラインURL"),
-  ("A43_011", "中心地緯度"),
-  ("A43_012", "中心地経度"),
-
-  ///// A44 /////
-
-  ("A44_001", "都道府県名"),
-  ("A44_002", "市町村名"),
-  ("A44_003", "行政コード"),
-  ("A44_004", "計画名称"),
-  ("A44_005", "認定日"),
-  ("A44_006", "計画期間開始日"),
-  ("A44_007", "計画期間終了日"),
-  ("A44_008", "重点区域面積"),
-  ("A44_009", "中心地緯度"),
-  ("A44_010", "中心地経度"),
-
-  ///// A45 /////
-
-  ("A45_001", "小班ID"),
-  ("A45_002", "森林管理局"),
-  ("A45_003", "森林管理署"),
-  ("A45_004", "林班主番"),
-  ("A45_005", "林班枝番"),
-  ("A45_006", "小班主番"),
-  ("A45_007", "小班枝番"),
-  ("A45_008", "局名称"),
-  ("A45_009", "署名称"),
-  ("A45_010", "小班名称"),
-  ("A45_011", "林小班名称"),
-  ("A45_012", "材積"),
-  ("A45_013", "国有林名称"),
-  ("A45_014", "県市町村名称"),
-  ("A45_015", "樹種１"),
-  ("A45_016", "樹立林齢１"),
-  ("A45_017", "最新林齢１"),
-  ("A45_018", "樹種２"),
-  ("A45_019", "樹立林齢２"),
-  ("A45_020", "最新林齢２"),
-  ("A45_021", "樹種３"),
-  ("A45_022", "樹立林齢３"),
-  ("A45_023", "最新林齢３"),
-  ("A45_024", "計画区名称"),
-  ("A45_025", "林種の細分"),
-  ("A45_026", "機能類型"),
-  ("A45_027", "面積"),
-  ("A45_028", "保安林１"),
-  ("A45_029", "保安林２"),
-  ("A45_030", "保安林３"),
-  ("A45_031", "保安林４"),
-  ("A45_032", "保護林"),
-  ("A45_033", "緑の回廊"),
-
-  ///// A55 /////
-
-  // 共通
-  ("FAR", "容積率"),
-  ("BCR", "建蔽率"),
-  ("Pref", "都道府県名"),
-  ("Citycode", "行政コード"),
-  ("Cityname", "市町村名"),
-  ("INDate", "効力を生じる日"),
-  ("FNDate", "公示日"),
-  ("ValidType", "効力を生じる日の種類"),
-  ("Custodian", "都市計画を定める者の名称"),
-  ("INNumber", "公示番号（当初）"),
-  ("FNNumber", "公示番号（最終）"),
-
-  ("YoutoName", "用途名"),
-  ("YoutoCode", "用途コード"),
-
-  ("TokeiName", "区域名"),
-  ("TokeiType", "種類"),
-  ("TokeiCode", "種類コード"),
-
-  ("AreaType", "種類"),
-  ("AreaCode", "種類コード"),
-
-  ("DouroType", "種類"),
-  ("DouroCode", "種類コード"),
-
-  ("DistName", "地区名"),
-  ("DistType", "種類"),
-  ("DistCode", "種類コード"),
-
-  ("ParkName", "公園名"),
-  ("ParkType", "種類"),
-  ("ParkCode", "種類コード"),
-
-  ("FaciName", "施設名"),
-  ("FaciType", "種類"),
-  ("FaciCode", "種類コード"),
-
-  ///// A56 /////
-
-  ("A56_001", "都道府県コード"),
-  ("A56_002", "行政コード"),
-  ("A56_003", "市区町村名"),
-  ("A56_004", "規制区域区分"),
-  ("A56_005", "指定主体区分"),
-  ("A56_006", "告示番号"),
-  ("A56_007", "施行年月日"),
-
-  ///// C02 /////
-
-  ("C02_001", "種別（１）"),
-  ("C02_002", "種別（２）"),
-  ("C02_003", "行政区域コード"),
-  ("C02_004", "港湾コード"),
-  ("C02_005", "港湾名"),
-  ("C02_006", "管理者区分"),
-  ("C02_007", "管理者名"),
-  ("C02_008", "政令指定年月日"),
-  ("C02_009", "設立年月日"),
-  ("C02_010", "海事機関種別"),
-  ("C02_011", "外郭施設延長"),
-  ("C02_012", "係留施設延長"),
-  ("C02_013", "フェリー便有無"),
-  ("C02_014", "都道府県コード"),
-  ("C02_015", "港湾コード"),
-  ("C02_016", "都道府県コード"),
-  ("C02_017", "港湾・漁港コード_港湾コード"),
-  ("C02_018", "港湾・漁港コード_漁港コード"),
-
-  ///// C09 /////
-
-  ("C09_001", "漁港コード"),
-  ("C09_002", "漁港名"),
-  ("C09_003", "行政区域コード"),
-  ("C09_004", "種別"),
-  ("C09_005", "管理者区分"),
-  ("C09_006", "管理者名"),
-  ("C09_007", "指定年月日"),
-  ("C09_008", "関係漁業共同組合"),
-  ("C09_009", "外郭施設延長"),
-  ("C09_010", "係留施設延長"),
-  ("C09_011", "都道府県コード"),
-  ("C09_012", "漁港コード"),
-
-  ///// C23 /////
-
-  ("C23_001", "行政区域コード"),
-  ("C23_002", "所管官庁"),
-  ("C23_003", "海岸保全区域番号"),
-  ("C23_004", "海岸保全区域・海岸名"),
-  ("C23_005", "海岸保全区域・海岸管理者コード"),
-  ("C23_006", "海岸保全区域・海岸管理者名"),
-  ("C23_007", "河口"),
-
-  ///// C28 /////
-
-  ("C28_001", "行政区域コード"),
-  ("C28_003", "種別"),
-  ("C28_004", "供用中・建設中の区別"),
-  ("C28_005", "名称"),
-  ("C28_006", "設置者"),
-  ("C28_007", "管理者"),
-  ("C28_008", "特定飛行場の指定状況"),
-  ("C28_009", "運用時間（開始）"),
-  ("C28_010", "運用時間（終了）"),
-  ("C28_011", "定期便の有無"),
-  ("C28_012", "滑走路延長"),
-  ("C28_013", "滑走路幅"),
-  ("C28_014", "調査年"),
-  ("C28_015", "１日あたりの着陸回数"),
-  ("C28_016", "１日あたりの乗降客数"),
-  ("C28_000", "ID"),
-  ("C28_101", "標点ID"),
-  ("C28_102", "ターミナルビルID"),
-  ("C28_103", "調査内容ID"),
-  ("C28_104", "滑走路ID"),
-
-  ///// G02 /////
-
-  ("G02_001", "3次メッシュコード"),
-  ("G02_002", "1月降水量"),
-  ("G02_003", "2月降水量"),
-  ("G02_004", "3月降水量"),
-  ("G02_005", "4月降水量"),
-  ("G02_006", "5月降水量"),
-  ("G02_007", "6月降水量"),
-  ("G02_008", "7月降水量"),
-  ("G02_009", "8月降水量"),
-  ("G02_010", "9月降水量"),
-  ("G02_011", "10月降水量"),
-  ("G02_012", "11月降水量"),
-  ("G02_013", "12月降水量"),
-  ("G02_014", "年降水量"),
-  ("G02_015", "1月最高気温"),
-  ("G02_016", "1月最低気温"),
-  ("G02_017", "1月平均気温"),
-  ("G02_018", "2月最高気温"),
-  ("G02_019", "2月最低気温"),
-  ("G02_020", "2月平均気温"),
-  ("G02_021", "3月最高気温"),
-  ("G02_022", "3月最低気温"),
-  ("G02_023", "3月平均気温"),
-  ("G02_024", "4月最高気温"),
-  ("G02_025", "4月最低気温"),
-  ("G02_026", "4月平均気温"),
-  ("G02_027", "5月最高気温"),
-  ("G02_028", "5月最低気温"),
-  ("G02_029", "5月平均気温"),
-  ("G02_030", "6月最高気温"),
-  ("G02_031", "6月最低気温"),
-  ("G02_032", "6月平均気温"),
-  ("G02_033", "7月最高気温"),
-  ("G02_034", "7月最低気温"),
-  ("G02_035", "7月平均気温"),
-  ("G02_036", "8月最高気温"),
-  ("G02_037", "8月最低気温"),
-  ("G02_038", "8月平均気温"),
-  ("G02_039", "9月最高気温"),
-  ("G02_040", "9月最低気温"),
-  ("G02_041", "9月平均気温"),
-  ("G02_042", "10月最高気温"),
-  ("G02_043", "10月最低気温"),
-  ("G02_044", "10月平均気温"),
-  ("G02_045", "11月最高気温"),
-  ("G02_046", "11月最低気温"),
-  ("G02_047", "11月平均気温"),
-  ("G02_048", "12月最高気温"),
-  ("G02_049", "12月最低気温"),
-  ("G02_050", "12月平均気温"),
-  ("G02_051", "年最高気温"),
-  ("G02_052", "年最低気温"),
-  ("G02_053", "年平均気温"),
-  ("G02_054", "1月最深積雪"),
-  ("G02_055", "2月最深積雪"),
-  ("G02_056", "3月最深積雪"),
-  ("G02_057", "12月最深積雪"),
-  ("G02_058", "年最深積雪"),
-  ("G02_059", "1月日照時間"),
-  ("G02_060", "2月日照時間"),
-  ("G02_061", "3月日照時間"),
-  ("G02_062", "4月日照時間"),
-  ("G02_063", "5月日照時間"),
-  ("G02_064", "6月日照時間"),
-  ("G02_065", "7月日照時間"),
-  ("G02_066", "8月日照時間"),
-  ("G02_067", "9月日照時間"),
-  ("G02_068", "10月日照時間"),
-  ("G02_069", "11月日照時間"),
-  ("G02_070", "12月日照時間"),
-  ("G02_071", "年合計日照時間"),
-  ("G02_072", "1月全天日射量"),
-  ("G02_073", "2月全天日射量"),
-  ("G02_074", "3月全天日射量"),
-  ("G02_075", "4月全天日射量"),
-  ("G02_076", "5月全天日射量"),
-  ("G02_077", "6月全天日射量"),
-  ("G02_078", "7月全天日射量"),
-  ("G02_079", "8月全天日射量"),
-  ("G02_080", "9月全天日射量"),
-  ("G02_081", "10月全天日射量"),
-  ("G02_082", "11月全天日射量"),
-  ("G02_083", "12月全天日射量"),
-  ("G02_084", "年平均全天日射量"),
-
-  ///// G04-a /////
-
-  ("G04a_001", "3次メッシュコード"),
-  ("G04a_002", "平均標高"),
-  ("G04a_003", "最高標高"),
-  ("G04a_004", "最低標高"),
-  ("G04a_005", "最低標高コード"),
-  ("G04a_006", "最大傾斜角度"),
-  ("G04a_007", "最大傾斜方向"),
-  ("G04a_008", "最小傾斜角度"),
-  ("G04a_009", "最小傾斜方向"),
-  ("G04a_010", "平均傾斜角度"),
-
-  ///// G04-c /////
-
-  ("G04c_001", "4次メッシュコード"),
-  ("G04c_002", "平均標高"),
-  ("G04c_003", "最高標高"),
-  ("G04c_004", "最低標高"),
-  ("G04c_005", "最低標高コード"),
-  ("G04c_006", "最大傾斜角度"),
-  ("G04c_007", "最大傾斜方向"),
-  ("G04c_008", "最小傾斜角度"),
-  ("G04c_009", "最小傾斜方向"),
-  ("G04c_010", "平均傾斜角度"),
+// Generated by `cargo xtask gen-dict`. Don't edit by hand.
 
-  ///// G04-d /////
+// The pair of the KSJ id and its column names
+type Colnames = (&'static str, &'static [(&'static str, &'static str)]);
 
-  ("G04d_001", "5次メッシュコード"),
-  ("G04d_002", "平均標高"),
-  ("G04d_003", "最高標高"),
-  ("G04d_004", "最低標高"),
-  ("G04d_005", "最低標高コード"),
-  ("G04d_006", "最大傾斜角度"),
-  ("G04d_007", "最大傾斜方向"),
-  ("G04d_008", "最小傾斜角度"),
-  ("G04d_009", "最小傾斜方向"),
-  ("G04d_010", "平均傾斜角度"),
-
-  ///// G08 /////
-
-  ("G08_001", "面積"),
-  ("G08_002", "最大浸水深"),
-  ("G08_003", "参照資料コード"),
-
-  ///// L03-b /////
-
-  ("L03b_001", "メッシュコード"),
-  ("L03b_002", "土地利用種別"),
-  ("L03b_003", "衛星写真撮影年月日"),
-  ("L03b_004", "都市地域範囲"),
-
-  ///// L03-b-c /////
-
-  ("L03b_c_001", "メッシュコード"),
-  ("L03b_c_002", "土地利用種別"),
-  ("L03b_c_003", "衛星写真撮影年月日"),
-  ("L03b_c_004", "都市地域範囲"),
-
-  ///// L05 /////
-
-  ("L05_001", "工業用地ID"),
-  ("L05_002", "工業用地名"),
-  ("L05_003", "行政コード"),
-  ("L05_004", "市区町村名"),
-  ("L05_005", "臨海・内陸区分コード"),
-  ("L05_006", "特記事項"),
-  ("L05_007", "事業主体名"),
-  ("L05_008", "事業完了年"),
-  ("L05_009", "全体開発面積"),
-  ("L05_010", "工業用地面積"),
-  ("L05_011", "未利用面積"),
-  ("L05_013", "用途地域コード"),
-  ("L05_014", "立地企業数"),
-  ("L05_015", "立地企業名1～10"),
-  ("L05_016", "業種"),
-
-  ///// N02 /////
-
-  ("N02_001", "鉄道区分"),
-  ("N02_002", "事業者種別"),
-  ("N02_003", "路線名"),
-  ("N02_004", "運営会社"),
-  ("N02_005", "駅名"),
-  ("N02_005c", "駅コード"),
-  ("N02_005g", "グループコード"),
-
-  ///// N03 /////
-
-  ("N03_001", "都道府県名"),
-  ("N03_002", "支庁・振興局名"),
-  ("N03_003", "郡・政令都市名"),
-  ("N03_004", "市区町村名"),
-  ("N03_007", "行政区域コード"),
-
-  ///// N05 /////
-
-  ("N05_001", "事業者種別"),
-  ("N05_002", "路線名"),
-  ("N05_003", "運営会社"),
-  ("N05_004", "供用開始年"),
-  ("N05_005b", "設置期間（設置開始）"),
-  ("N05_005e", "設置期間（設置終了）"),
-  ("N05_006", "関係ID"),
-  ("N05_007", "変遷ID"),
-  ("N05_008", "変遷備考"),
-  ("N05_009", "備考"),
-  ("N05_010", "関係備考"),
-  ("N05_011", "駅名"),
-
-  ///// N06 /////
-
-  ("N06_001", "供用開始年"),
-  ("N06_002", "設置期間（開始年）"),
-  ("N06_003", "設置期間（終了年）"),
-  ("N06_004", "関係ID"),
-  ("N06_005", "変遷ID"),
-  ("N06_006", "変遷備考"),
-  ("N06_007", "路線名"),
-  ("N06_008", "路線種別"),
-  ("N06_009", "供用状況"),
-  ("N06_010", "車線数"),
-  ("N06_011", "関係備考"),
-  ("N06_012", "供用開始年"),
-  ("N06_013", "設置期間（開始年）"),
-  ("N06_014", "設置期間（終了年）"),
-  ("N06_015", "関係ID"),
-  ("N06_016", "変遷ID"),
-  ("N06_017", "変遷備考"),
-  ("N06_018", "地点名"),
-  ("N06_019", "接合部種別"),
-
-  ///// N07 /////
-
-  ("N07_001", "バス区分"),
-  ("N07_002", "事業者名"),
-  ("N07_003", "バス系統"),
-  ("N07_004", "平日運行頻度"),
-  ("N07_005", "土曜日運行頻度"),
-  ("N07_006", "日祝日運行頻度"),
-  ("N07_007", "備考"),
-
-  ///// N08 /////
-
-  ("N08_001", "行政区域"),
-  ("N08_002", "空港種別"),
-  ("N08_003", "空港名称"),
-  ("N08_004", "設置者"),
-  ("N08_005", "管理者"),
-  ("N08_006", "滑走路本数"),
-  ("N08_007", "滑走路延長"),
-  ("N08_008", "運用時間（開始）"),
-  ("N08_009", "運用時間（終了）"),
-  ("N08_010", "定期便"),
-  ("N08_011", "ジェット化"),
-  ("N08_012", "供用開始年"),
-  ("N08_013", "供用状況"),
-  ("N08_014", "設置期間（開始年）"),
-  ("N08_015", "設置期間（終了年）"),
-  ("N08_016", "関係ID"),
-  ("N08_017", "変遷ID"),
-  ("N08_018", "変遷備考"),
-  ("N08_019", "空港名称"),
-  ("N08_020", "供用開始年"),
-  ("N08_021", "供用状況"),
-  ("N08_022", "設置期間（開始年）"),
-  ("N08_023", "設置期間（終了年）"),
-  ("N08_024", "関係ID"),
-  ("N08_025", "備考"),
-
-  ///// N09 /////
-
-  ("N09_001", "港ID"),
-  ("N09_002", "都道府県名"),
-  ("N09_003", "島嶼名"),
-  ("N09_004", "港名"),
-  ("N09_005", "航路ID"),
-  ("N09_006", "航路名"),
-  ("N09_007", "航路の起終点"),
-  ("N09_008", "航路の総距離"),
-  ("N09_009", "事業者名"),
-  ("N09_010", "航路線ID"),
-  ("N09_011", "港ID1"),
-  ("N09_012", "港1島嶼名"),
-  ("N09_013", "港名1"),
-  ("N09_014", "港ID2"),
-  ("N09_015", "港2島嶼名"),
-  ("N09_016", "港名2"),
-  ("N09_017", "航行している航種（フェリー）"),
-  ("N09_018", "航行している航種（旅客船）"),
-  ("N09_019", "航行している航種（高速船）"),
-  ("N09_020", "航行している航種（ジェットフォイル）"),
-  ("N09_021", "航行している航種（その他）"),
-  ("N09_022", "便数（上り）"),
-  ("N09_023", "便数（下り）"),
-  ("N09_024", "所要時間（最短）"),
-  ("N09_025", "所要時間（最長）"),
-  ("N09_026", "標準ダイヤの運行期間"),
-  ("N09_027", "旅客定員（最大）"),
-  ("N09_028", "旅客定員（最小）"),
-  ("N09_029", "乗用車積載可能車両数"),
-  ("N09_030", "トラック積載可能車両数"),
-
-  ///// N10 /////
-
-  ("N10_001", "都道府県コード"),
-  ("N10_002", "区分"),
-  ("N10_003", "道路種別"),
-  ("N10_004", "路線名称"),
-  ("N10_005", "任意ID"),
-  ("N10_006", "枝ID"),
-  ("N10_007", "資料名称"),
-  ("N10_008", "資料年月"),
-
-  ///// N11 /////
-
-  ("N11_001", "名称"),
-  ("N11_002", "航空法分類"),
-  ("N11_003", "地域防災計画分類"),
-  ("N11_004", "都道府県コード"),
-  ("N11_005", "所在地"),
-  ("N11_006", "管理者名"),
-  ("N11_007", "滑走路面積"),
-
-  ///// N13 /////
-  ("N13_001", "データ登録日"),
-  ("N13_002", "種別"),
-  ("N13_003", "道路分類"),
-  ("N13_004", "道路状態"),
-  ("N13_005", "階層順"),
-  ("N13_006", "幅員区分"),
-  ("N13_007", "有料区分"),
-  ("N13_008", "二次メッシュ番号"),
-
-  ///// P03 /////
-
-  ("P03_0001", "事業者名等"),
-  ("P03_0002", "発電施設名称"),
-  ("P03_0003", "所在地"),
-  ("P03_0004", "既設・建設中区分"),
-  ("P03_0005", "運転開始年月日"),
-  ("P03_0006", "出力"),
-  ("P03_0007", "RPS法_認定日"),
-  ("P03_0008", "RPS法_発電出力"),
-  ("P03_0009", "グリーン発電電力設備_認定日"),
-  ("P03_0010", "グリーン発電電力設備_設備容量"),
-  ("P03_0101", "水系名"),
-  ("P03_0102", "型式"),
-  ("P03_0201", "水系名"),
-  ("P03_0202", "最大使用水量"),
-  ("P03_0203", "最大有効落差"),
-  ("P03_0204", "上池"),
-  ("P03_0205", "下池"),
-  ("P03_0206", "水車容量"),
-  ("P03_0207", "水車形式"),
-  ("P03_0208", "水車台数"),
-  ("P03_0209", "水力原動力"),
-  ("P03_0301", "ID"),
-  ("P03_0302", "名称"),
-  ("P03_0303", "有効容量"),
-  ("P03_0304", "利用水深"),
-  ("P03_0401", "号機"),
-  ("P03_0402", "転換年月"),
-  ("P03_0403", "専・混焼の別"),
-  ("P03_0404", "火力原動力"),
-  ("P03_0405", "混焼含有物"),
-  ("P03_0406", "脱硫方式"),
-  ("P03_0407", "処理ガス量"),
-  ("P03_0408", "比率"),
-  ("P03_0409", "処理ガス量"),
-  ("P03_0410", "比率"),
-  ("P03_0501", "蒸気供給部門"),
-  ("P03_0502", "号機"),
-  ("P03_0601", "号機"),
-  ("P03_0602", "炉型"),
-  ("P03_0603", "電源開発基本計画 組入年月"),
-  ("P03_0604", "原子炉設置許可年月日"),
-  ("P03_0605", "第一回工事計画認可年月"),
-  ("P03_0701", "号機"),
-  ("P03_0801", "号機"),
-  ("P03_0901", "種別"),
-
-  ///// P04 /////
-
-  ("P04_001", "医療機関分類"),
-  ("P04_002", "施設名称"),
-  ("P04_003", "所在地"),
-  ("P04_004", "診療科目１"),
-  ("P04_005", "診療科目２"),
-  ("P04_006", "診療科目３"),
-  ("P04_007", "開設者分類"),
-
-  ///// P05 /////
-
-  ("NA", "行政区域コード"),
-  ("NA", "施設分類"),
-  ("NA", "名称"),
-  ("NA", "所在地"),
-
-  ///// P07 /////
-
-  ("P07_001", "店舗分類コード"),
-  ("P07_002", "所在地"),
-
-  ///// P09 /////
-
-  ("P09_001", "3次メッシュコード"),
-  ("P09_002", "施設数（ホテル）"),
-  ("P09_003", "施設数（旅館）"),
-  ("P09_004", "施設数（公共宿泊施設）"),
-  ("P09_005", "施設数（民宿）"),
-  ("P09_006", "施設数（ペンション）"),
-  ("P09_007", "施設数（宿坊）"),
-  ("P09_008", "施設数（コテージ・貸し別荘・山荘）"),
-  ("P09_009", "施設数（ユースホステル）"),
-  ("P09_010", "施設数（カプセルホテル）"),
-  ("P09_011", "施設数（研修センター）"),
-  ("P09_012", "施設総数"),
-  ("P09_013", "収容人数"),
-  ("P09_014", "室数"),
-
-  ///// P11 /////
-
-  ("P11_001", "バス停名"),
-  ("P11_002", "バス区分"),
-  ("P11_003_1", "事業者名_1"),
-  ("P11_003_2", "事業者名_2"),
-  ("P11_003_3", "事業者名_3"),
-  ("P11_003_4", "事業者名_4"),
-  ("P11_003_5", "事業者名_5"),
-  ("P11_003_6", "事業者名_6"),
-  ("P11_003_7", "事業者名_7"),
-  ("P11_003_8", "事業者名_8"),
-  ("P11_003_9", "事業者名_9"),
-  ("P11_003_10", "事業者名_10"),
-  ("P11_003_11", "事業者名_11"),
-  ("P11_003_12", "事業者名_12"),
-  ("P11_003_13", "事業者名_13"),
-  ("P11_003_14", "事業者名_14"),
-  ("P11_003_15", "事業者名_15"),
-  ("P11_003_16", "事業者名_16"),
-  ("P11_003_17", "事業者名_17"),
-  ("P11_003_18", "事業者名_18"),
-  ("P11_003_19", "事業者名_19"),
-  ("P11_004_1", "バス系統_1"),
-  ("P11_004_2", "バス系統_2"),
-  ("P11_004_3", "バス系統_3"),
-  ("P11_004_4", "バス系統_4"),
-  ("P11_004_5", "バス系統_5"),
-  ("P11_004_6", "バス系統_6"),
-  ("P11_004_7", "バス系統_7"),
-  ("P11_004_8", "バス系統_8"),
-  ("P11_004_9", "バス系統_9"),
-  ("P11_004_10", "バス系統_10"),
-  ("P11_004_11", "バス系統_11"),
-  ("P11_004_12", "バス系統_12"),
-  ("P11_004_13", "バス系統_13"),
-  ("P11_004_14", "バス系統_14"),
-  ("P11_004_15", "バス系統_15"),
-  ("P11_004_16", "バス系統_16"),
-  ("P11_004_17", "バス系統_17"),
-  ("P11_004_18", "バス系統_18"),
-  ("P11_004_19", "バス系統_19"),
-
-  ///// P12 /////
-
-  ("P12_001", "観光資源_ID"),
-  ("P12_002", "観光資源名"),
-  ("P12_003", "都道府県コード"),
-  ("P12_004", "行政コード"),
-  ("P12_005", "種別名称"),
-  ("P12_006", "所在地住所"),
-  ("P12_007", "観光資源分類コード"),
-
-  ///// P13 /////
-
-  ("P13_001", "管理都道府県・整備局"),
-  ("P13_002", "管理市区町村"),
-  ("P13_003", "公園名"),
-  ("P13_004", "公園種別"),
-  ("P13_005", "所在地都道府県名"),
-  ("P13_006", "所在地市区町村名"),
-  ("P13_007", "供用開始年"),
-  ("P13_008", "供用済面積"),
-  ("P13_009", "都市計画決定"),
-  ("P13_010", "備考"),
-
-  ///// P14 /////
-
-  ("P14_001", "都道府県名"),
-  ("P14_002", "市区町村名"),
-  ("P14_003", "所在地"),
-  ("P14_004", "公共施設大分類"),
-  ("P14_005", "公共施設小分類"),
-  ("P14_006", "福祉施設細分類"),
-  ("P14_007", "名称"),
-  ("P14_008", "管理者"),
-  ("P14_009", "定員"),
-  ("P14_010", "原典資料名"),
-  ("P14_011", "主題属性取得資料名"),
-
-  ///// P15 /////
-
-  ("P15_001", "施設名称"),
-  ("P15_002", "地方公共団体名"),
-  ("P15_003", "施設種別"),
-  ("P15_004", "施設タイプ"),
-  ("P15_005", "処理能力（t/日）"),
-  ("P15_006", "屋内面積"),
-  ("P15_007", "屋外面積"),
-  ("P15_008", "全体容量"),
-  ("P15_009", "処理能力（kL/日）"),
-  ("P15_010", "計画最大汚水量"),
-  ("P15_011", "処理物"),
-  ("P15_012", "処理方式"),
-  ("P15_013", "炉形式"),
-  ("P15_014", "発電能力"),
-  ("P15_015", "事業者名"),
-  ("P15_016", "所在地"),
-  ("P15_017", "産廃施設種別"),
-  ("P15_018", "特別管理"),
-  ("P15_019", "取扱品目1"),
-  ("P15_020", "取扱品目2"),
-  ("P15_021", "取扱品目3"),
-  ("P15_022", "取扱品目4"),
-  ("P15_023", "取扱品目5"),
-  ("P15_024", "取扱品目6"),
-  ("P15_025", "取扱品目7"),
-  ("P15_026", "取扱品目8"),
-  ("P15_027", "取扱品目9"),
-  ("P15_028", "取扱品目10"),
-  ("P15_029", "取扱品目11"),
-  ("P15_030", "取扱品目12"),
-  ("P15_031", "取扱品目13"),
-  ("P15_032", "取扱品目14"),
-  ("P15_033", "取扱品目15"),
-
-  ///// P16 /////
-
-  ("P16_001", "名称"),
-  ("P16_002", "主体コード"),
-  ("P16_003", "都道府県コード"),
-  ("P16_004", "所在地"),
-  ("P16_005", "ID"),
-  ("P16_006", "原典1"),
-  ("P16_007", "原典2"),
-  ("P16_008", "原典3"),
-  ("P16_009", "原典4"),
-  ("P16_010", "原典5"),
-  ("P16_011", "原典6"),
-  ("P16_012", "原典7"),
-  ("P16_013", "原典8"),
-  ("P16_014", "原典9"),
-  ("P16_015", "備考1"),
-  ("P16_016", "備考2"),
-  ("P16_017", "備考3"),
-  ("P16_018", "備考4"),
-  ("P16_019", "備考5"),
-  ("P16_020", "備考6"),
-  ("P16_021", "備考7"),
-  ("P16_022", "備考8"),
-  ("P16_023", "備考9"),
-  ("P16_024", "備考10"),
-  ("P16_025", "備考11"),
-  ("P16_026", "備考12"),
-
-  ///// P17 /////
-
-  ("P17_001", "名称"),
-  ("P17_002", "行政コード"),
-  ("P17_003", "種別コード"),
-  ("P17_004", "所在地"),
-  ("P17_005", "名称"),
-
-  ///// P18 /////
-
-  ("P18_001", "名称"),
-  ("P18_002", "行政コード"),
-  ("P18_003", "種別コード"),
-  ("P18_004", "所在地"),
-  ("P18_005", "名称"),
-
-  ///// P19 /////
-
-  ("P19_001", "地域資源ID"),
-  ("P19_002", "都道府県コード"),
-  ("P19_003", "都道府県名"),
-  ("P19_004", "類型コード"),
-  ("P19_005", "類型名"),
-  ("P19_006", "自然景観資源分類コード"),
-  ("P19_007", "自然景観資源分類名"),
-  ("P19_008", "名称"),
-
-  ///// P20 /////
-
-  ("P20_001", "行政区域"),
-  ("P20_002", "名称"),
-  ("P20_003", "住所"),
-  ("P20_004", "施設の種類"),
-  ("P20_005", "収容人数"),
-  ("P20_006", "施設規模"),
-  ("P20_007", "災害分類_地震災害"),
-  ("P20_008", "災害分類_津波災害"),
-  ("P20_009", "災害分類_水害"),
-  ("P20_010", "災害分類_火山災害"),
-  ("P20_011", "災害分類_その他"),
-  ("P20_012", "災害分類_指定なし"),
-
-  ///// P21 /////
-
-  ("P21A_001", "事業主体"),
-  ("P21A_002", "事業名称"),
-  ("P21A_003", "種別"),
-  ("P21A_004", "給水人口"),
-  ("P21A_005", "日最大給水量"),
-  ("P21B_001", "事業主体"),
-  ("P21B_002", "事業名称"),
-  ("P21B_003", "施設名称"),
-  ("P21B_004", "日最大給水量"),
-
-  ///// P22 /////
-
-  ("P22a_001", "行政区域"),
-  ("P22a_002", "施設名称"),
-  ("P22a_003", "所在地"),
-  ("P22a_004", "市町村等団体名"),
-  ("P22a_005", "事業区分名"),
-  ("P22a_006", "事業名"),
-  ("P22a_007", "全体計画処理面積_合計"),
-  ("P22a_008", "全体計画処理面積_汚水（分流）"),
-  ("P22a_009", "全体計画処理面積_雨水（分流）"),
-  ("P22a_010", "全体計画処理面積_合流"),
-  ("P22a_011", "全体計画計画処理人口"),
-  ("P22a_012", "全体計画区域内人口"),
-  ("P22a_013", "雨水計画_降雨強度最小"),
-  ("P22a_014", "雨水計画_降雨強度最大"),
-  ("P22a_015", "雨水計画_確率年最小"),
-  ("P22a_016", "雨水計画_確率年最大"),
-  ("P22a_017", "汚水計画_1人1日平均汚水最小"),
-  ("P22a_018", "汚水計画_1人1日平均汚水最大"),
-  ("P22a_019", "汚水計画_1人1日最大汚水最小"),
-  ("P22a_020", "汚水計画_1人1日最大汚水次第"),
-  ("P22a_021", "計画排水面積_汚水"),
-  ("P22a_022", "計画排水面積_雨水"),
-  ("P22a_023", "計画排水量_汚水（晴天時最大）"),
-  ("P22a_024", "計画排水量_汚水（雨天時最大）"),
-  ("P22a_025", "計画排水量_雨水"),
-  ("P22a_026", "現有排水能力_汚水揚水量（晴天時最大）"),
-  ("P22a_027", "現有排水能力_汚水揚水量（雨天時最大）"),
-  ("P22a_028", "現有排水能力_雨水揚水量"),
-  ("P22b_001", "行政区域"),
-  ("P22b_002", "施設名称"),
-  ("P22b_003", "所在地"),
-  ("P22b_004", "市町村等団体名"),
-  ("P22b_005", "事業区分名"),
-  ("P22b_006", "事業名"),
-  ("P22b_007", "全体計画処理面積_合計"),
-  ("P22b_008", "全体計画処理面積_汚水（分流）"),
-  ("P22b_009", "全体計画処理面積_雨水（分流）"),
-  ("P22b_010", "全体計画処理面積_合流"),
-  ("P22b_011", "全体計画計画処理人口"),
-  ("P22b_012", "全体計画区域内人口"),
-  ("P22b_013", "雨水計画_降雨強度最小"),
-  ("P22b_014", "雨水計画_降雨強度最大"),
-  ("P22b_015", "雨水計画_確率年最小"),
-  ("P22b_016", "雨水計画_確率年最大"),
-  ("P22b_017", "汚水計画_1人1日平均汚水最小"),
-  ("P22b_018", "汚水計画_1人1日平均汚水最大"),
-  ("P22b_019", "汚水計画_1人1日最大汚水最小"),
-  ("P22b_020", "汚水計画_1人1日最大汚水次第"),
-  ("P22b_021", "種類_水処理施設"),
-  ("P22b_022", "種類_汚泥処理施設"),
-  ("P22b_023", "種類_有効利用施設"),
-  ("P22b_024", "種類_再生水利用"),
-  ("P22b_025", "放流先河川"),
-  ("P22b_026", "水処理施設処理面積_現在"),
-  ("P22b_027", "水処理施設処理面積_計画"),
-  ("P22b_028", "水処理施設処理人口_現在"),
-  ("P22b_029", "水処理施設処理人口_計画"),
-  ("P22b_030", "水処理施設全体計画区域内人口"),
-  ("P22b_031", "水処理施設晴天時1日最大処理量_現在"),
-  ("P22b_032", "水処理施設晴天時1日最大処理量_計画"),
-  ("P22b_033", "水処理施設雨天時最大処理量_現在"),
-  ("P22b_034", "水処理施設雨天時最大処理量_計画"),
-
-  ///// P23 /////
-
-  ("P23a_001", "行政区域"),
-  ("P23a_002", "所管省庁"),
-  ("P23a_003", "管理者"),
-  ("P23a_004", "堤防"),
-  ("P23a_005", "突堤"),
-  ("P23a_006", "護岸"),
-  ("P23a_007", "胸壁"),
-  ("P23a_008", "離岸堤"),
-  ("P23a_009", "砂浜"),
-  ("P23a_010", "その他の施設"),
-  ("P23a_011", "延長"),
-  ("P23a_012", "天端高最大（現況）"),
-  ("P23a_013", "天端高最小（現況）"),
-  ("P23a_014", "天端高最大（計画）"),
-  ("P23a_015", "天端高最小（計画）"),
-  ("P23a_016", "基準面"),
-  ("P23b_001", "行政区域"),
-  ("P23b_002", "所管省庁"),
-  ("P23b_003", "管理者"),
-  ("P23b_004", "堤防"),
-  ("P23b_005", "突堤"),
-  ("P23b_006", "護岸"),
-  ("P23b_007", "胸壁"),
-  ("P23b_008", "離岸堤"),
-  ("P23b_009", "砂浜"),
-  ("P23b_010", "その他の施設"),
-  ("P23b_011", "延長"),
-  ("P23b_012", "天端高最大（現況）"),
-  ("P23b_013", "天端高最小（現況）"),
-  ("P23b_014", "天端高最大（計画）"),
-  ("P23b_015", "天端高最小（計画）"),
-  ("P23b_016", "基準面"),
-
-  ///// P24 /////
-
-  ("P24_001", "地場産業関連施設ID"),
-  ("P24_002", "行政コード"),
-  ("P24_003", "都道府県名"),
-  ("P24_004", "市区町村名"),
-  ("P24_005", "施設名"),
-  ("P24_006", "所在地"),
-  ("P24_007", "直売所有り"),
-  ("P24_008", "直営レストラン有り"),
-  ("P24_009", "農林漁業体験民宿有り"),
-  ("P24_010", "市民農園有り"),
-  ("P24_011", "原典コード"),
-  ("P24_012", "URL"),
-
-  ///// P26 /////
-
-  ("P26_001", "都道府県名"),
-  ("P26_002", "市区町村名"),
-  ("P26_003", "地方公共団体コード"),
-  ("P26_004", "地区名"),
-  ("P26_005", "愛称名"),
-  ("P26_006", "連たんニュータウン名称"),
-  ("P26_007", "施行面積"),
-  ("P26_008", "事業主体"),
-  ("P26_009", "事業手法"),
-  ("P26_010", "事業開始年度（西暦）"),
-  ("P26_011", "事業終了（予定）年度（西暦）"),
-  ("P26_012", "計画戸数"),
-  ("P26_013", "計画人口"),
-
-  ///// P27 /////
-
-  ("P27_001", "行政区域コード"),
-  ("P27_002", "公共施設大分類"),
-  ("P27_003", "公共施設小分類"),
-  ("P27_004", "文化施設分類"),
-  ("P27_005", "名称"),
-  ("P27_006", "所在地"),
-  ("P27_007", "管理者コード"),
-  ("P27_008", "階数"),
-  ("P27_009", "建築年"),
-
-  ///// P28 /////
-
-  ("P28_001", "行政区域コード"),
-  ("P28_002", "公共施設大分類"),
-  ("P28_003", "公共施設小分類"),
-  ("P28_004", "国・都道府県の機関分類"),
-  ("P28_005", "名称"),
-  ("P28_006", "所在地"),
-  ("P28_007", "管理者コード"),
-
-  ///// P29 /////
-
-  ("P29_001", "行政区域コード"),
-  ("P29_002", "公共施設大分類"),
-  ("P29_003", "公共施設小分類"),
-  ("P29_004", "学校分類"),
-  ("P29_005", "名称"),
-  ("P29_006", "所在地"),
-  ("P29_007", "管理者コード"),
-
-  ///// P30 /////
-
-  ("P30_001", "行政区域コード"),
-  ("P30_002", "公共施設大分類"),
-  ("P30_003", "公共施設小分類"),
-  ("P30_004", "郵便局分類"),
-  ("P30_005", "名称"),
-  ("P30_006", "所在地"),
-  ("P30_007", "管理者コード"),
-
-  ///// P31 /////
-
-  ("P31_001", "名称"),
-  ("P31_002", "種別"),
-  ("P31_003", "分類"),
-  ("P31_004", "都道府県コード"),
-  ("P31_005", "所在地"),
-  ("P31_006", "事業者分類"),
-  ("P31_007", "整備年"),
-  ("P31_008", "規模"),
-  ("P31_009", "備考"),
-
-  ///// P32 /////
-
-  ("P32_001", "文化財ID"),
-  ("P32_002", "都道府県コード"),
-  ("P32_003", "行政コード"),
-  ("P32_004", "種別大区分コード"),
-  ("P32_005", "種別小区分コード"),
-  ("P32_006", "名称"),
-  ("P32_007", "所在地住所"),
-  ("P32_008", "指定年月日"),
-  ("P32_009", "代表点区分コード"),
-
-  ///// P33 /////
-
-  ("P33_001", "施設ID"),
-  ("P33_002", "行政コード"),
-  ("P33_003", "都道府県コード"),
-  ("P33_004", "施設区分コード"),
-  ("P33_005", "施設名称"),
-  ("P33_006", "郵便番号"),
-  ("P33_007", "住所・所在地"),
-  ("P33_008", "電話番号"),
-  ("P33_009", "開設年月日"),
-  ("P33_010", "ホームページURL"),
-  ("P33_011", "アクセス"),
-  ("P33_012", "スクリーン数"),
-  ("P33_013", "総席数"),
-  ("P33_014", "公民館の種別"),
-  ("P33_015", "営業・稼働日数"),
-  ("P33_016", "営業時間"),
-  ("P33_017", "入場料の有無"),
-  ("P33_018", "敷地面積"),
-  ("P33_019", "建築総面積"),
-  ("P33_020", "ホール数"),
-  ("P33_021", "ホールの最大席数"),
-  ("P33_022", "ホールの総席数"),
-  ("P33_023", "会議室数"),
-  ("P33_024", "展示室数"),
-  ("P33_041", "代表点区分"),
-
-  ///// P34 /////
-
-  ("P34_001", "行政区域コード"),
-  ("P34_002", "施設分類"),
-  ("P34_003", "名称"),
-  ("P34_004", "所在地"),
-
-  ///// P35 /////
-
-  ("P35_001", "緯度"),
-  ("P35_002", "経度"),
-  ("P35_003", "都道府県名"),
-  ("P35_004", "市町村名"),
-  ("P35_005", "行政コード"),
-  ("P35_006", "道の駅名"),
-  ("P35_007", "ホームページアドレス1"),
-  ("P35_008", "ホームページアドレス2"),
-  ("P35_009", "ホームページアドレス3"),
-  ("P35_010", "ホームページアドレス4"),
-  ("P35_011", "ATM有無"),
-  ("P35_012", "ベビーベッド有無"),
-  ("P35_013", "レストラン有無"),
-  ("P35_014", "軽食・喫茶有無"),
-  ("P35_015", "宿泊施設有無"),
-  ("P35_016", "温泉施設有無"),
-  ("P35_017", "キャンプ場等有無"),
-  ("P35_018", "公園有無"),
-  ("P35_019", "展望台有無"),
-  ("P35_020", "美術館・博物館有無"),
-  ("P35_021", "ガソリンスタンド有無"),
-  ("P35_022", "EV充電施設有無"),
-  ("P35_023", "無線LAN有無"),
-  ("P35_024", "シャワー有無"),
-  ("P35_025", "体験施設有無"),
-  ("P35_026", "観光案内有無"),
-  ("P35_027", "身障者トイレ有無"),
-  ("P35_028", "ショップ有無"),
-
-  ///// S05-c /////
-
-  ("S05c_001", "調査年度"),
-  ("S05c_002", "駅コード"),
-  ("S05c_003", "運営会社"),
-  ("S05c_004", "駅名"),
-  ("S05c_005", "乗車_バス"),
-  ("S05c_006", "乗車_自家用自動車"),
-  ("S05c_007", "乗車_タクシー・ハイヤー"),
-  ("S05c_008", "乗車_自動車"),
-  ("S05c_009", "乗車_自動二輪原付"),
-  ("S05c_010", "乗車_自転車"),
-  ("S05c_011", "乗車_二輪"),
-  ("S05c_012", "乗車_徒歩"),
-  ("S05c_013", "乗車_その他"),
-  ("S05c_014", "乗車_合計"),
-  ("S05c_015", "降車_バス"),
-  ("S05c_016", "降車_自家用自動車"),
-  ("S05c_017", "降車_タクシー・ハイヤー"),
-  ("S05c_018", "降車_自動車"),
-  ("S05c_019", "降車_自動二輪原付"),
-  ("S05c_020", "降車_自転車"),
-  ("S05c_021", "降車_二輪"),
-  ("S05c_022", "降車_徒歩"),
-  ("S05c_023", "降車_その他"),
-  ("S05c_024", "降車_合計"),
-  ("S05c_025", "調査年度"),
-  ("S05c_026", "駅コード"),
-  ("S05c_027", "駅名"),
-  ("S05c_028", "乗車_路線バス・都電"),
-  ("S05c_029", "乗車_乗用車"),
-  ("S05c_030", "乗車_軽乗用車"),
-  ("S05c_031", "乗車_貨物自動車・軽貨物車"),
-  ("S05c_032", "乗車_自家用バス・貸切りバス"),
-  ("S05c_033", "乗車_タクシー・ハイヤー"),
-  ("S05c_034", "乗車_自動二輪車"),
-  ("S05c_035", "乗車_原動機付き自転車"),
-  ("S05c_036", "乗車_自転車"),
-  ("S05c_037", "乗車_徒歩"),
-  ("S05c_038", "乗車_航空機"),
-  ("S05c_039", "乗車_船舶"),
-  ("S05c_040", "乗車_その他"),
-  ("S05c_041", "乗車_不明"),
-  ("S05c_042", "乗車_合計"),
-  ("S05c_043", "降車_路線バス・都電"),
-  ("S05c_044", "降車_乗用車"),
-  ("S05c_045", "降車_軽乗用車"),
-  ("S05c_046", "降車_貨物自動車・軽貨物車"),
-  ("S05c_047", "降車_自家用バス・貸切りバス"),
-  ("S05c_048", "降車_タクシー・ハイヤー"),
-  ("S05c_049", "降車_自動二輪車"),
-  ("S05c_050", "降車_原動機付き自転車"),
-  ("S05c_051", "降車_自転車"),
-  ("S05c_052", "降車_徒歩"),
-  ("S05c_053", "降車_航空機"),
-  ("S05c_054", "降車_船舶"),
-  ("S05c_055", "降車_その他"),
-  ("S05c_056", "降車_不明"),
-  ("S05c_057", "降車_合計"),
-
-  ///// S05-d /////
-
-  ("S05d_001", "調査年度"),
-  ("S05d_002", "発ゾーンコード"),
-  ("S05d_003", "着ゾーンコード"),
-  ("S05d_004", "全機関_農水産品トン数"),
-  ("S05d_005", "全機関_林産品トン数"),
-  ("S05d_006", "全機関_鉱産品トン数"),
-  ("S05d_007", "全機関_金属・機械工業品トン数"),
-  ("S05d_008", "全機関_化学工業品トン数"),
-  ("S05d_009", "全機関_軽工業品トン数"),
-  ("S05d_010", "全機関_雑工業品トン数"),
-  ("S05d_011", "全機関_特種品トン数"),
-  ("S05d_012", "全機関_その他トン数"),
-  ("S05d_013", "全機関_合計トン数"),
-  ("S05d_014", "鉄道_農水産品トン数"),
-  ("S05d_015", "鉄道_林産品トン数"),
-  ("S05d_016", "鉄道_鉱産品トン数"),
-  ("S05d_017", "鉄道_金属・機械工業品トン数"),
-  ("S05d_018", "鉄道_化学工業品トン数"),
-  ("S05d_019", "鉄道_軽工業品トン数"),
-  ("S05d_020", "鉄道_雑工業品トン数"),
-  ("S05d_021", "鉄道_特種品トン数"),
-  ("S05d_022", "鉄道_その他トン数"),
-  ("S05d_023", "鉄道_合計トン数"),
-  ("S05d_024", "海運_農水産品トン数"),
-  ("S05d_025", "海運_林産品トン数"),
-  ("S05d_026", "海運_鉱産品トン数"),
-  ("S05d_027", "海運_金属・機械工業品トン数"),
-  ("S05d_028", "海運_化学工業品トン数"),
-  ("S05d_029", "海運_軽工業品トン数"),
-  ("S05d_030", "海運_雑工業品トン数"),
-  ("S05d_031", "海運_特種品トン数"),
-  ("S05d_032", "海運_その他トン数"),
-  ("S05d_033", "海運_合計トン数"),
-  ("S05d_034", "自動車_農水産品トン数"),
-  ("S05d_035", "自動車_林産品トン数"),
-  ("S05d_036", "自動車_鉱産品トン数"),
-  ("S05d_037", "自動車_金属・工業品トン数"),
-  ("S05d_038", "自動車_化学工業品トン数"),
-  ("S05d_039", "自動車_軽工業品トン数"),
-  ("S05d_040", "自動車_雑工業品トン数"),
-  ("S05d_041", "自動車_特種品トン数"),
-  ("S05d_042", "自動車_その他トン数"),
-  ("S05d_043", "自動車_合計トン数"),
-  ("S05d_044", "調査年度"),
-  ("S05d_045", "発ゾーンコード"),
-  ("S05d_046", "着ゾーンコード"),
-  ("S05d_047", "ＪＲ定期旅客数"),
-  ("S05d_048", "ＪＲ定期外旅客数"),
-  ("S05d_049", "ＪＲ合計旅客数"),
-  ("S05d_050", "民鉄定期旅客数"),
-  ("S05d_051", "民鉄定期外旅客数"),
-  ("S05d_052", "民鉄合計旅客数"),
-  ("S05d_053", "乗合バス旅客数"),
-  ("S05d_054", "貸切バス旅客数"),
-  ("S05d_055", "自家用バス旅客数"),
-  ("S05d_056", "営業用乗用車旅客数"),
-  ("S05d_057", "自家用乗用車旅客数"),
-  ("S05d_058", "自動車合計旅客数"),
-  ("S05d_059", "旅客船旅客数"),
-  ("S05d_060", "航空旅客数"),
-  ("S05d_061", "全機関旅客数"),
-
-  ///// S10a /////
-
-  ("S10a_001", "出発港_都道府県"),
-  ("S10a_002", "出発港_港湾名"),
-  ("S10a_003", "到着港_都道府県"),
-  ("S10a_004", "到着港_港湾名"),
-  ("S10a_005", "頻度_貨客船定期便就航数"),
-  ("S10a_006", "頻度_フェリー定期便就航数"),
-  ("S10a_007", "頻度_貨物船定期便就航数"),
-  ("S10a_008", "頻度_RORO船定期便就航数"),
-  ("S10a_009", "運搬可能旅客数"),
-  ("S10a_010", "積載可能貨物量_トラック"),
-  ("S10a_011", "積載可能貨物量_シャーシ"),
-  ("S10a_012", "積載可能貨物量_トレーラー"),
-  ("S10a_013", "積載可能貨物量_中型車"),
-  ("S10a_014", "積載可能貨物量_乗用車"),
-  ("S10a_015", "積載可能貨物量_コンテナ"),
-  ("S10a_016", "積載可能貨物量_その他"),
-  ("S10a_017", "積載可能貨物量_化成品"),
-  ("S10a_018", "大分類流通量_農水産品"),
-  ("S10a_019", "大分類流通量_林産品"),
-  ("S10a_020", "大分類流通量_鉱産品"),
-  ("S10a_021", "大分類流通量_金属機械工業品"),
-  ("S10a_022", "大分類流通量_化学工業品"),
-  ("S10a_023", "大分類流通量_軽工業品"),
-  ("S10a_024", "大分類流通量_雑工業品"),
-  ("S10a_025", "大分類流通量_特殊品"),
-  ("S10a_026", "大分類流通量_分類不能のもの（大分類）"),
-  ("S10a_027", "中分類流通量_011麦"),
-  ("S10a_028", "中分類流通量_021米"),
-  ("S10a_029", "中分類流通量_022とうもろこし"),
-  ("S10a_030", "中分類流通量_023豆類"),
-  ("S10a_031", "中分類流通量_024その他雑穀"),
-  ("S10a_032", "中分類流通量_031野菜・果物"),
-  ("S10a_033", "中分類流通量_041綿花"),
-  ("S10a_034", "中分類流通量_051その他農産品"),
-  ("S10a_035", "中分類流通量_061羊毛"),
-  ("S10a_036", "中分類流通量_071その他畜産品"),
-  ("S10a_037", "中分類流通量_081水産品"),
-  ("S10a_038", "中分類流通量_091原木"),
-  ("S10a_039", "中分類流通量_092製材"),
-  ("S10a_040", "中分類流通量_101樹脂類"),
-  ("S10a_041", "中分類流通量_111木材チップ"),
-  ("S10a_042", "中分類流通量_112その他林産品"),
-  ("S10a_043", "中分類流通量_121薪炭"),
-  ("S10a_044", "中分類流通量_131石炭"),
-  ("S10a_045", "中分類流通量_141鉄鉱石"),
-  ("S10a_046", "中分類流通量_151金属鉱"),
-  ("S10a_047", "中分類流通量_161砂利・砂"),
-  ("S10a_048", "中分類流通量_162石材"),
-  ("S10a_049", "中分類流通量_171原油"),
-  ("S10a_050", "中分類流通量_181りん鉱石"),
-  ("S10a_051", "中分類流通量_191石灰石"),
-  ("S10a_052", "中分類流通量_201原塩"),
-  ("S10a_053", "中分類流通量_211非金属鉱物"),
-  ("S10a_054", "中分類流通量_221鉄鋼"),
-  ("S10a_055", "中分類流通量_222鋼材"),
-  ("S10a_056", "中分類流通量_231非鉄金属"),
-  ("S10a_057", "中分類流通量_241金属製品"),
-  ("S10a_058", "中分類流通量_251鉄道車両"),
-  ("S10a_059", "中分類流通量_252完成自動車"),
-  ("S10a_060", "中分類流通量_253その他輸送用車両"),
-  ("S10a_061", "中分類流通量_254二輪自動車"),
-  ("S10a_062", "中分類流通量_255自動車部品"),
-  ("S10a_063", "中分類流通量_256その他輸送機械"),
-  ("S10a_064", "中分類流通量_261産業機械"),
-  ("S10a_065", "中分類流通量_262電気機械"),
-  ("S10a_066", "中分類流通量_263測量・光学・医療用器械"),
-  ("S10a_067", "中分類流通量_264事務用機器"),
-  ("S10a_068", "中分類流通量_265その他機械"),
-  ("S10a_069", "中分類流通量_271陶磁器"),
-  ("S10a_070", "中分類流通量_281セメント"),
-  ("S10a_071", "中分類流通量_291ガラス類"),
-  ("S10a_072", "中分類流通量_301窯業品"),
-  ("S10a_073", "中分類流通量_311重油"),
-  ("S10a_074", "中分類流通量_321石油製品"),
-  ("S10a_075", "中分類流通量_322LNG（液化天然ガス）"),
-  ("S10a_076", "中分類流通量_323LPG（液化石油ガス）"),
-  ("S10a_077", "中分類流通量_324その他石油製品"),
-  ("S10a_078", "中分類流通量_331コークス"),
-  ("S10a_079", "中分類流通量_341石炭製品"),
-  ("S10a_080", "中分類流通量_351化学薬品"),
-  ("S10a_081", "中分類流通量_361化学肥料"),
-  ("S10a_082", "中分類流通量_371染料・塗料・合成樹脂・その他化学工業品"),
-  ("S10a_083", "中分類流通量_381紙・パルプ"),
-  ("S10a_084", "中分類流通量_391糸及び紡績半製品"),
-  ("S10a_085", "中分類流通量_401その他繊維工業品"),
-  ("S10a_086", "中分類流通量_411砂糖"),
-  ("S10a_087", "中分類流通量_421製造食品"),
-  ("S10a_088", "中分類流通量_422飲料"),
-  ("S10a_089", "中分類流通量_423水"),
-  ("S10a_090", "中分類流通量_424たばこ"),
-  ("S10a_091", "中分類流通量_425その他食料工業品"),
-  ("S10a_092", "中分類流通量_431がん具"),
-  ("S10a_093", "中分類流通量_441衣服・身廻品・はきもの"),
-  ("S10a_094", "中分類流通量_442文房具・運動娯楽用品・楽器"),
-  ("S10a_095", "中分類流通量_443家具装備品"),
-  ("S10a_096", "中分類流通量_444その他日用品"),
-  ("S10a_097", "中分類流通量_451ゴム製品"),
-  ("S10a_098", "中分類流通量_461木製品（他に分類されないもの）"),
-  ("S10a_099", "中分類流通量_471その他製造工業品"),
-  ("S10a_100", "中分類流通量_481金属くず"),
-  ("S10a_101", "中分類流通量_491再利用資材"),
-  ("S10a_102", "中分類流通量_501動植物性製造飼肥料"),
-  ("S10a_103", "中分類流通量_511廃棄物"),
-  ("S10a_104", "中分類流通量_512廃土砂"),
-  ("S10a_105", "中分類流通量_521輸送用容器"),
-  ("S10a_106", "中分類流通量_531取合せ品"),
-  ("S10a_107", "中分類流通量_541分類不能のもの（中分類）"),
-  ("S10a_108", "出発港_都道府県"),
-  ("S10a_109", "出発港_港湾名"),
-  ("S10a_110", "寄港地_都道府県"),
-  ("S10a_111", "寄港地_港湾名"),
-  ("S10a_112", "到着港_都道府県"),
-  ("S10a_113", "到着港_港湾名"),
-  ("S10a_114", "船種"),
-  ("S10a_115", "航行距離"),
-  ("S10a_116", "総トン"),
-  ("S10a_117", "積載可能貨物量_トラック"),
-  ("S10a_118", "積載可能貨物量_シャーシ"),
-  ("S10a_119", "積載可能貨物量_トレーラー"),
-  ("S10a_120", "積載可能貨物量_中型車"),
-  ("S10a_121", "積載可能貨物量_乗用車"),
-  ("S10a_122", "積載可能貨物量_コンテナ"),
-  ("S10a_123", "積載可能貨物量_その他"),
-  ("S10a_124", "積載可能貨物量_化成品"),
-  ("S10a_125", "運搬可能旅客数"),
-  ("S10a_126", "頻度"),
-
-  ///// S10b /////
-
-  ("S10b_001", "出発空港"),
-  ("S10b_002", "経由空港１"),
-  ("S10b_003", "経由空港２"),
-  ("S10b_004", "到着空港"),
-  ("S10b_005", "区間距離"),
-  ("S10b_006", "頻度"),
-  ("S10b_007", "旅客数"),
-  ("S10b_008", "運搬可能旅客数"),
-  ("S10b_009", "貨物量"),
-
-  ///// S12 /////
-
-  ("S12_001", "駅名"),
-  ("S12_002", "運営会社"),
-  ("S12_003", "路線名"),
-  ("S12_004", "鉄道区分"),
-  ("S12_005", "事業者種別"),
-  ("S12_006", "重複コード2011"),
-  ("S12_007", "データ有無コード2011"),
-  ("S12_008", "備考2011"),
-  ("S12_009", "乗降客数2011"),
-  ("S12_010", "重複コード2012"),
-  ("S12_011", "データ有無コード2012"),
-  ("S12_012", "備考2012"),
-  ("S12_013", "乗降客数2012"),
-  ("S12_014", "重複コード2013"),
-  ("S12_015", "データ有無コード2013"),
-  ("S12_016", "備考2013"),
-  ("S12_017", "乗降客数2013"),
-  ("S12_018", "重複コード2014"),
-  ("S12_019", "データ有無コード2014"),
-  ("S12_020", "備考2014"),
-  ("S12_021", "乗降客数2014"),
-  ("S12_022", "重複コード2015"),
-  ("S12_023", "データ有無コード2015"),
-  ("S12_024", "備考2015"),
-  ("S12_025", "乗降客数2015"),
-  ("S12_026", "重複コード2016"),
-  ("S12_027", "データ有無コード 2016"),
-  ("S12_028", "備考2016"),
-  ("S12_029", "乗降客数2016"),
-  ("S12_030", "重複コード2017"),
-  ("S12_031", "データ有無コード 2017"),
-  ("S12_032", "備考2017"),
-  ("S12_033", "乗降客数2017"),
-  ("S12_034", "重複コード2018"),
-  ("S12_035", "データ有無コード 2018"),
-  ("S12_036", "備考2018"),
-  ("S12_037", "乗降客数2018"),
-
-  ///// W01 /////
-
-  ("W01_001", "ダム名"),
-  ("W01_002", "ダムコード"),
-  ("W01_003", "水系名"),
-  ("W01_004", "河川名"),
-  ("W01_005", "形式"),
-  ("W01_006", "目的"),
-  ("W01_007", "ダム規模（堤高）"),
-  ("W01_008", "ダム規模（堤頂長）"),
-  ("W01_009", "堤体積"),
-  ("W01_010", "総貯水量"),
-  ("W01_011", "ダム事業者"),
-  ("W01_012", "竣工年"),
-  ("W01_013", "所在地"),
-  ("W01_014", "位置情報精度"),
-
-  ///// W05 /////
-
-  ("W05_001", "水系域コード"),
-  ("W05_002", "河川コード"),
-  ("W05_003", "区間種別"),
-  ("W05_004", "河川名"),
-  ("W05_005", "原典資料種別"),
-  ("W05_006", "流下方向判定"),
-  ("W05_007", "河川始点"),
-  ("W05_008", "河川終点"),
-  ("W05_009", "流路始点"),
-  ("W05_010", "流路終点"),
-  ("W05_011", "標高"),
+#[cfg(all(feature = "dict-colnames", feature = "dict-policy"))]
+#[rustfmt::skip]
+const COLNAMES_POLICY: &[Colnames] = &[
+  ("A03", &[
+    ("A03_001", "行政区域コード"),
+    ("A03_002", "都道府県名"),
+    ("A03_003", "郡市名"),
+    ("A03_004", "区町村名"),
+    ("A03_005", "陸水等区分"),
+    ("A03_006", "区域区分"),
+    ("A03_007", "区域コード"),
+    ("A03_008", "備考"),
+  ]),
+
+  ("A09", &[
+    ("area_cd", "地区コード"),
+    ("layer_no", "レイヤ番号"),
+    ("prefec_cd", "都道府県コード"),
+  ]),
+
+  ("A10", &[
+    ("AREA_CD", "地区コード"),
+    ("AREA_SIZE", "ポリゴン面積"),
+    ("CTV_NAME", "市町村名"),
+    ("FIS_YEAR", "年度"),
+    ("IOSIDE_DIV", "内外区分"),
+    ("LAYER_NO", "レイヤ番号"),
+    ("OBJ_NAME", "名称"),
+    ("PREFEC_CD", "都道府県コード"),
+    ("REMARK_STR", "備考"),
+    ("THEMA_NO", "主題番号"),
+  ]),
+
+  ("A11", &[
+    ("AREA_CD", "地区コード"),
+    ("AREA_SIZE", "ポリゴン面積"),
+    ("CTV_NAME", "市町村名"),
+    ("FIS_YEAR", "年度"),
+    ("IOSIDE_DIV", "内外区分"),
+    ("LAYER_NO", "レイヤ番号"),
+    ("OBJ_NAME", "名称"),
+    ("PREFEC_CD", "都道府県コード"),
+    ("REMARK_STR", "備考"),
+    ("THEMA_NO", "主題番号"),
+  ]),
+
+  ("A12", &[
+    ("AREA_CD", "地区コード"),
+    ("AREA_SIZE", "ポリゴン面積"),
+    ("CTV_NAME", "市町村名"),
+    ("FIS_YEAR", "年度"),
+    ("IOSIDE_DIV", "内外区分"),
+    ("LAYER_NO", "レイヤ番号"),
+    ("OBJ_NAME", "名称"),
+    ("PREFEC_CD", "都道府県コード"),
+    ("REMARK_STR", "備考"),
+    ("THEMA_NO", "主題番号"),
+  ]),
+
+  ("A13", &[
+    ("AREA_CD", "地区コード"),
+    ("AREA_SIZE", "ポリゴン面積"),
+    ("CTV_NAME", "市町村名"),
+    ("FIS_YEAR", "年度"),
+    ("IOSIDE_DIV", "内外区分"),
+    ("LAYER_NO", "レイヤ番号"),
+    ("OBJ_NAME", "名称"),
+    ("PREFEC_CD", "都道府県コード"),
+    ("REMARK_STR", "備考"),
+    ("THEMA_NO", "主題番号"),
+  ]),
+
+  ("A15", &[
+    ("A15_001", "鳥獣保護区名"),
+    ("A15_002", "都道府県コード"),
+    ("A15_003", "指定機関：指定機関コード"),
+    ("A15_004", "保護区分：保護区分コード"),
+    ("A15_005", "指定日"),
+    ("A15_006", "解除日"),
+  ]),
+
+  ("A16", &[
+    ("A16_001", "DIDid"),
+    ("A16_002", "市区町村コード"),
+    ("A16_003", "市区町村名称"),
+    ("A16_004", "人口集中地区符合"),
+    ("A16_005", "人口"),
+    ("A16_006", "面積"),
+    ("A16_007", "前回人口"),
+    ("A16_008", "前回面積"),
+    ("A16_009", "全域に占める人口集中地区の人口割合"),
+    ("A16_010", "全域に占める人口集中地区の面積割合"),
+    ("A16_011", "国勢調査年度"),
+  ]),
+
+  ("A17", &[
+    ("A17_001", "過疎ID"),
+    ("A17_002", "行政区域コード"),
+    ("A17_003", "都道府県名"),
+    ("A17_004", "支庁・振興局名"),
+    ("A17_005", "郡・政令都市名"),
+    ("A17_006", "市区町村名"),
+    ("A17_007", "原典市区町村名"),
+    ("A17_008", "旧市町村名"),
+    ("A17_009", "過疎区分コード"),
+  ]),
+
+  ("A18", &[
+    ("A18_001", "半島ID"),
+    ("A18_002", "行政区域コード"),
+    ("A18_003", "都道府県名"),
+    ("A18_004", "支庁・振興局名"),
+    ("A18_005", "郡・政令都市名"),
+    ("A18_006", "市区町村名"),
+    ("A18_007", "原典市区町村名"),
+    ("A18_008", "旧市町村名"),
+    ("A18_009", "指定地域名"),
+    ("A18_010", "指定日"),
+  ]),
+
+  ("A18s-a", &[
+    ("A18_001", "半島振興対策実施地域ID"),
+    ("A18_002", "行政コード"),
+    ("A18_003", "都道府県名"),
+    ("A18_004", "支庁名"),
+    ("A18_005", "郡・政令市名"),
+    ("A18_006", "市区町村名"),
+    ("A18_007", "指定地域名"),
+    ("A18_008", "指定状況コード"),
+    ("A18_009", "調査年度"),
+    ("A18_010", "概要　人口"),
+    ("A18_011", "概要　世帯数"),
+    ("A18_012", "男　国勢調査人口総数"),
+    ("A18_013", "男　年少人口　0-4"),
+    ("A18_014", "男　年少人口　5-9"),
+    ("A18_015", "男　年少人口　10-14"),
+    ("A18_016", "男　年少人口　小計"),
+    ("A18_017", "男　生産年齢人口　15-19"),
+    ("A18_018", "男　生産年齢人口　20-24"),
+    ("A18_019", "男　生産年齢人口　25-29"),
+    ("A18_020", "男　生産年齢人口　30-34"),
+    ("A18_021", "男　生産年齢人口　35-39"),
+    ("A18_022", "男　生産年齢人口　40-44"),
+    ("A18_023", "男　生産年齢人口　45-49"),
+    ("A18_024", "男　生産年齢人口　50-54"),
+    ("A18_025", "男　生産年齢人口　55-59"),
+    ("A18_026", "男　生産年齢人口　60-64"),
+    ("A18_027", "男　生産年齢人口　小計"),
+    ("A18_028", "男　老年人口　65-69"),
+    ("A18_029", "男　老年人口　70-74"),
+    ("A18_030", "男　老年人口　75-79"),
+    ("A18_031", "男　老年人口　80-84"),
+    ("A18_032", "男　老年人口　85歳以上"),
+    ("A18_033", "男　老年人口　小計"),
+    ("A18_034", "男　不詳　"),
+    ("A18_035", "女　国勢調査人口総数　"),
+    ("A18_036", "女　年少人口　0-4"),
+    ("A18_037", "女　年少人口　5-9"),
+    ("A18_038", "女　年少人口　10-14"),
+    ("A18_039", "女　年少人口　小計"),
+    ("A18_040", "女　生産年齢人口　15-19"),
+    ("A18_041", "女　生産年齢人口　20-24"),
+    ("A18_042", "女　生産年齢人口　25-29"),
+    ("A18_043", "女　生産年齢人口　30-34"),
+    ("A18_044", "女　生産年齢人口　35-39"),
+    ("A18_045", "女　生産年齢人口　40-44"),
+    ("A18_046", "女　生産年齢人口　45-49"),
+    ("A18_047", "女　生産年齢人口　50-54"),
+    ("A18_048", "女　生産年齢人口　55-59"),
+    ("A18_049", "女　生産年齢人口　60-64"),
+    ("A18_050", "女　生産年齢人口　小計"),
+    ("A18_051", "女　老年人口　65-69"),
+    ("A18_052", "女　老年人口　70-74"),
+    ("A18_053", "女　老年人口　75-79"),
+    ("A18_054", "女　老年人口　80-84"),
+    ("A18_055", "女　老年人口　85歳以上"),
+    ("A18_056", "女　老年人口　小計"),
+    ("A18_057", "女　不詳　"),
+    ("A18_058", "男女計　国勢調査人口総数　"),
+    ("A18_059", "男女計　年少人口　0-4"),
+    ("A18_060", "男女計　年少人口　5-9"),
+    ("A18_061", "男女計　年少人口　10-14"),
+    ("A18_062", "男女計　年少人口　小計"),
+    ("A18_063", "男女計　生産年齢人口　15-19"),
+    ("A18_064", "男女計　生産年齢人口　20-24"),
+    ("A18_065", "男女計　生産年齢人口　25-29"),
+    ("A18_066", "男女計　生産年齢人口　30-34"),
+    ("A18_067", "男女計　生産年齢人口　35-39"),
+    ("A18_068", "男女計　生産年齢人口　40-44"),
+    ("A18_069", "男女計　生産年齢人口　45-49"),
+    ("A18_070", "男女計　生産年齢人口　50-54"),
+    ("A18_071", "男女計　生産年齢人口　55-59"),
+    ("A18_072", "男女計　生産年齢人口　60-64"),
+    ("A18_073", "男女計　生産年齢人口　小計"),
+    ("A18_074", "男女計　老年人口　65-69"),
+    ("A18_075", "男女計　老年人口　70-74"),
+    ("A18_076", "男女計　老年人口　75-79"),
+    ("A18_077", "男女計　老年人口　80-84"),
+    ("A18_078", "男女計　老年人口　85歳以上"),
+    ("A18_079", "男女計　老年人口　小計"),
+    ("A18_080", "男女計　不詳　"),
+    ("A18_081", "就業者数　就業者総数　"),
+    ("A18_082", "就業者数　第1次　農業"),
+    ("A18_083", "就業者数　第1次　農業、林業"),
+    ("A18_084", "就業者数　第1次　農業、林業（うち農業）"),
+    ("A18_085", "就業者数　第1次　林業"),
+    ("A18_086", "就業者数　第1次　漁業"),
+    ("A18_087", "就業者数　第1次　小計"),
+    ("A18_088", "就業者数　第2次　鉱業"),
+    ("A18_089", "就業者数　第2次　鉱業，採石業，砂利採取業"),
+    ("A18_090", "就業者数　第2次　建設業"),
+    ("A18_091", "就業者数　第2次　製造業"),
+    ("A18_092", "就業者数　第2次　小計"),
+    ("A18_093", "就業者数　第3次　電気・ガス・熱供給・水道業"),
+    ("A18_094", "就業者数　第3次　運輸・通信業"),
+    ("A18_095", "就業者数　第3次　情報・通信業"),
+    ("A18_096", "就業者数　第3次　運輸業"),
+    ("A18_097", "就業者数　第3次　運輸業、郵便業"),
+    ("A18_098", "就業者数　第3次　卸売・小売業・飲食店"),
+    ("A18_099", "就業者数　第3次　卸売・小売業"),
+    ("A18_100", "就業者数　第3次　金融・保険業"),
+    ("A18_101", "就業者数　第3次　不動産業"),
+    ("A18_102", "就業者数　第3次　不動産業、物品賃貸業"),
+    ("A18_103", "就業者数　第3次　学術研究，専門・技術サービス業"),
+    ("A18_104", "就業者数　第3次　飲食店・宿泊業"),
+    ("A18_105", "就業者数　第3次　宿泊業，飲食サービス業　"),
+    ("A18_106", "就業者数　第3次　生活関連サービス業，娯楽業"),
+    ("A18_107", "就業者数　第3次　医療･福祉"),
+    ("A18_108", "就業者数　第3次　教育・学習支援業"),
+    ("A18_109", "就業者数　第3次　複合サービス事業"),
+    ("A18_110", "就業者数　第3次　サービス業"),
+    ("A18_111", "就業者数　第3次　公務"),
+    ("A18_112", "就業者数　第3次　小計"),
+    ("A18_113", "就業者数　分類不能　"),
+    ("A18_114", "調査年度"),
+    ("A18_115", "事業所　全産業"),
+    ("A18_116", "事業所　全産業（S公務を除く）"),
+    ("A18_117", "事業所　農林漁業"),
+    ("A18_118", "事業所　非農林漁業1"),
+    ("A18_119", "事業所　非農林漁業2"),
+    ("A18_120", "事業所　鉱業"),
+    ("A18_121", "事業所　鉱業、採石業、砂利採取業"),
+    ("A18_122", "事業所　建設業"),
+    ("A18_123", "事業所　製造業"),
+    ("A18_124", "事業所　電気・ガス・熱供給・水道業"),
+    ("A18_125", "事業所　運輸・通信業"),
+    ("A18_126", "事業所　情報・通信業"),
+    ("A18_127", "事業所　運輸業"),
+    ("A18_128", "事業所　運輸業、郵便業"),
+    ("A18_129", "事業所　卸売・小売業・飲食業"),
+    ("A18_130", "事業所　卸売・小売業"),
+    ("A18_131", "事業所　金融・保険業"),
+    ("A18_132", "事業所　不動産業"),
+    ("A18_133", "事業所　 不動産業、物品賃貸業"),
+    ("A18_134", "事業所　学術研究、専門・技術サービス業"),
+    ("A18_135", "事業所　飲食店・宿泊業"),
+    ("A18_136", "事業所　宿泊業、飲食サービス業"),
+    ("A18_137", "事業所　生活関連サービス業、娯楽業"),
+    ("A18_138", "事業所　医療･福祉"),
+    ("A18_139", "事業所　教育・学習支援業"),
+    ("A18_140", "事業所　複合サービス事業"),
+    ("A18_141", "事業所　サービス業"),
+    ("A18_142", "事業所　公務"),
+    ("A18_143", "事業所　1～4人"),
+    ("A18_144", "事業所　5～9人"),
+    ("A18_145", "事業所　10～19人"),
+    ("A18_146", "事業所　20～29人"),
+    ("A18_147", "事業所　30人以上"),
+    ("A18_148", "事業所　派遣・下請従業者のみ"),
+    ("A18_149", "事業所　経営組織別事業所数（民営事業所）"),
+    ("A18_150", "事業所　事業所の形態別事業所数（民営事業所）"),
+    ("A18_151", "事業所　うち個人"),
+    ("A18_152", "事業所　うち法人"),
+    ("A18_153", "事業所　うち会社"),
+    ("A18_154", "事業所　店舗・飲食店"),
+    ("A18_155", "事業所　事務所・営業所"),
+    ("A18_156", "事業所　工場・作業所・鉱業所"),
+    ("A18_157", "事業所　輸送センター・配送センター・これらの倉庫"),
+    ("A18_158", "事業所　自家用倉庫・自家用油槽所"),
+    ("A18_159", "事業所　外見上一般の住居と区別しにくい事業所"),
+    ("A18_160", "事業所　その他（学校・病院・寺社・旅館・浴場など）"),
+  ]),
+
+  ("A19", &[
+    ("A19_001", "離島ID"),
+    ("A19_002", "行政区域コード"),
+    ("A19_003", "都道府県名"),
+    ("A19_004", "支庁・振興局名"),
+    ("A19_005", "郡・政令都市名"),
+    ("A19_006", "市区町村名"),
+    ("A19_007", "原典市区町村名"),
+    ("A19_008", "指定地域名"),
+    ("A19_009", "島名"),
+  ]),
+
+  ("A19s", &[
+    ("A19_001", "離島振興対策実施地域ID"),
+    ("A19_002", "行政コード"),
+    ("A19_003", "都道府県名"),
+    ("A19_004", "支庁名"),
+    ("A19_005", "郡・政令市名"),
+    ("A19_006", "市区町村名"),
+    ("A19_007", "指定地域名"),
+    ("A19_008", "島嶼名"),
+    ("A19_009", "離島区分コード"),
+    ("A19_010", "指定状況コード"),
+    ("A19_011", "調査年度"),
+    ("A19_012", "概要　(name)"),
+    ("A19_013", "概要　指定回次"),
+    ("A19_014", "概要　郡名"),
+    ("A19_015", "概要　人口"),
+    ("A19_016", "概要　世帯数"),
+    ("A19_017", "概要　面積"),
+    ("A19_018", "概要　海岸延長"),
+    ("A19_019", "概要　備考"),
+    ("A19_020", "前年　住民登録人口　男"),
+    ("A19_021", "前年　住民登録人口　女"),
+    ("A19_022", "前年　住民登録人口　計"),
+    ("A19_023", "前年　世帯数　"),
+    ("A19_024", "人口移動　出生数　"),
+    ("A19_025", "人口移動　死亡数　"),
+    ("A19_026", "人口移動　自然増△減　"),
+    ("A19_027", "人口移動　転入数　"),
+    ("A19_028", "人口移動　転出数　"),
+    ("A19_029", "人口移動　社会増△減　"),
+    ("A19_030", "人口移動　増減計　"),
+    ("A19_031", "現年　住民登録人口　男"),
+    ("A19_032", "現年　住民登録人口　女"),
+    ("A19_033", "現年　住民登録人口　計"),
+    ("A19_034", "現年　世帯数　"),
+    ("A19_035", "人口・世帯数・人口動態　備考"),
+    ("A19_036", "男　国勢調査人口総数"),
+    ("A19_037", "男　年少人口　0-4"),
+    ("A19_038", "男　年少人口　5-9"),
+    ("A19_039", "男　年少人口　10-14"),
+    ("A19_040", "男　年少人口　小計"),
+    ("A19_041", "男　生産年齢人口　15-19"),
+    ("A19_042", "男　生産年齢人口　20-24"),
+    ("A19_043", "男　生産年齢人口　25-29"),
+    ("A19_044", "男　生産年齢人口　30-34"),
+    ("A19_045", "男　生産年齢人口　35-39"),
+    ("A19_046", "男　生産年齢人口　40-44"),
+    ("A19_047", "男　生産年齢人口　45-49"),
+    ("A19_048", "男　生産年齢人口　50-54"),
+    ("A19_049", "男　生産年齢人口　55-59"),
+    ("A19_050", "男　生産年齢人口　60-64"),
+    ("A19_051", "男　生産年齢人口　小計"),
+    ("A19_052", "男　老年人口　65-69"),
+    ("A19_053", "男　老年人口　70-74"),
+    ("A19_054", "男　老年人口　75-79"),
+    ("A19_055", "男　老年人口　80-84"),
+    ("A19_056", "男　老年人口　85歳以上"),
+    ("A19_057", "男　老年人口　小計"),
+    ("A19_058", "男　不詳　"),
+    ("A19_059", "男　生産年齢人口指数　"),
+    ("A19_060", "男　老齢化率　"),
+    ("A19_061", "女　国勢調査人口総数　"),
+    ("A19_062", "女　年少人口　0-4"),
+    ("A19_063", "女　年少人口　5-9"),
+    ("A19_064", "女　年少人口　10-14"),
+    ("A19_065", "女　年少人口　小計"),
+    ("A19_066", "女　生産年齢人口　15-19"),
+    ("A19_067", "女　生産年齢人口　20-24"),
+    ("A19_068", "女　生産年齢人口　25-29"),
+    ("A19_069", "女　生産年齢人口　30-34"),
+    ("A19_070", "女　生産年齢人口　35-39"),
+    ("A19_071", "女　生産年齢人口　40-44"),
+    ("A19_072", "女　生産年齢人口　45-49"),
+    ("A19_073", "女　生産年齢人口　50-54"),
+    ("A19_074", "女　生産年齢人口　55-59"),
+    ("A19_075", "女　生産年齢人口　60-64"),
+    ("A19_076", "女　生産年齢人口　小計"),
+    ("A19_077", "女　老年人口　65-69"),
+    ("A19_078", "女　老年人口　70-74"),
+    ("A19_079", "女　老年人口　75-79"),
+    ("A19_080", "女　老年人口　80-84"),
+    ("A19_081", "女　老年人口　85歳以上"),
+    ("A19_082", "女　老年人口　小計"),
+    ("A19_083", "女　不詳　"),
+    ("A19_084", "女　生産年齢人口指数　"),
+    ("A19_085", "女　老齢化率　"),
+    ("A19_086", "男女計　国勢調査人口総数　"),
+    ("A19_087", "男女計　年少人口　0-4"),
+    ("A19_088", "男女計　年少人口　5-9"),
+    ("A19_089", "男女計　年少人口　10-14"),
+    ("A19_090", "男女計　年少人口　小計"),
+    ("A19_091", "男女計　生産年齢人口　15-19"),
+    ("A19_092", "男女計　生産年齢人口　20-24"),
+    ("A19_093", "男女計　生産年齢人口　25-29"),
+    ("A19_094", "男女計　生産年齢人口　30-34"),
+    ("A19_095", "男女計　生産年齢人口　35-39"),
+    ("A19_096", "男女計　生産年齢人口　40-44"),
+    ("A19_097", "男女計　生産年齢人口　45-49"),
+    ("A19_098", "男女計　生産年齢人口　50-54"),
+    ("A19_099", "男女計　生産年齢人口　55-59"),
+    ("A19_100", "男女計　生産年齢人口　60-64"),
+    ("A19_101", "男女計　生産年齢人口　小計"),
+    ("A19_102", "男女計　老年人口　65-69"),
+    ("A19_103", "男女計　老年人口　70-74"),
+    ("A19_104", "男女計　老年人口　75-79"),
+    ("A19_105", "男女計　老年人口　80-84"),
+    ("A19_106", "男女計　老年人口　85歳以上"),
+    ("A19_107", "男女計　老年人口　小計"),
+    ("A19_108", "男女計　不詳　"),
+    ("A19_109", "男女計　生産年齢人口指数　"),
+    ("A19_110", "男女計　老齢化率　"),
+    ("A19_111", "年齢別･男女別人口　備考"),
+    ("A19_112", "調査年度"),
+    ("A19_113", "土地利用　総面積　"),
+    ("A19_114", "土地利用　農用地　農用地合計"),
+    ("A19_115", "土地利用　農用地　農地"),
+    ("A19_116", "土地利用　農用地　採草放牧地"),
+    ("A19_117", "土地利用　森林　"),
+    ("A19_118", "土地利用　原野　"),
+    ("A19_119", "土地利用　水面・河川・水路　"),
+    ("A19_120", "土地利用　道路　"),
+    ("A19_121", "土地利用　宅地　"),
+    ("A19_122", "土地利用　その他　"),
+    ("A19_123", "土地利用　備考　"),
+    ("A19_124", "学校　保　保育所数　本所"),
+    ("A19_125", "学校　保　保育所数　分所"),
+    ("A19_126", "学校　保　乳幼児数　在所"),
+    ("A19_127", "学校　保　乳幼児数　通所"),
+    ("A19_128", "学校　幼　幼稚園数　本園"),
+    ("A19_129", "学校　幼　幼稚園数　分園"),
+    ("A19_130", "学校　幼　園児数　在園"),
+    ("A19_131", "学校　幼　園児数　通園"),
+    ("A19_132", "学校　小　学校数　本校"),
+    ("A19_133", "学校　小　学校数　分校"),
+    ("A19_134", "学校　小　教（職）員数　教員数"),
+    ("A19_135", "学校　小　教（職）員数　職員数"),
+    ("A19_136", "学校　小　学級数"),
+    ("A19_137", "学校　小　児童数　在校"),
+    ("A19_138", "学校　小　児童数　通学"),
+    ("A19_139", "学校　中　学校数　本校"),
+    ("A19_140", "学校　中　学校数　分校"),
+    ("A19_141", "学校　中　教（職）員数　教員数"),
+    ("A19_142", "学校　中　教（職）員数　職員数"),
+    ("A19_143", "学校　中　学級数"),
+    ("A19_144", "学校　中　生徒数　在校"),
+    ("A19_145", "学校　中　生徒数　通学"),
+    ("A19_146", "学校　高　学校数　本校"),
+    ("A19_147", "学校　高　学校数　分校"),
+    ("A19_148", "学校　高　教（職）員数　教員数"),
+    ("A19_149", "学校　高　教（職）員数　職員数"),
+    ("A19_150", "学校　高　学級数"),
+    ("A19_151", "学校　高　生徒数　在校"),
+    ("A19_152", "学校　高　生徒数　通学"),
+    ("A19_153", "学校　備考"),
+    ("A19_154", "卒業者状況　中　卒業生数　　"),
+    ("A19_155", "卒業者状況　中　就職　総数　"),
+    ("A19_156", "卒業者状況　中　就職　島内　計"),
+    ("A19_157", "卒業者状況　中　就職　島内　農林業"),
+    ("A19_158", "卒業者状況　中　就職　島内　水産業"),
+    ("A19_159", "卒業者状況　中　就職　島内　2次産業"),
+    ("A19_160", "卒業者状況　中　就職　島内　3次産業"),
+    ("A19_161", "卒業者状況　中　就職　島外　計"),
+    ("A19_162", "卒業者状況　中　就職　島外　1次産業"),
+    ("A19_163", "卒業者状況　中　就職　島外　2次産業"),
+    ("A19_164", "卒業者状況　中　就職　島外　3次産業"),
+    ("A19_165", "卒業者状況　中　進学　総数　"),
+    ("A19_166", "卒業者状況　中　進学　島内　"),
+    ("A19_167", "卒業者状況　中　進学　島外　"),
+    ("A19_168", "卒業者状況　中　進学　進学率　"),
+    ("A19_169", "卒業者状況　中　未就職・その他　島内　"),
+    ("A19_170", "卒業者状況　中　未就職・その他　島外　"),
+    ("A19_171", "卒業者状況　高　卒業生数　　"),
+    ("A19_172", "卒業者状況　高　就職　総数　"),
+    ("A19_173", "卒業者状況　高　就職　島内　計"),
+    ("A19_174", "卒業者状況　高　就職　島内　農林業"),
+    ("A19_175", "卒業者状況　高　就職　島内　水産業"),
+    ("A19_176", "卒業者状況　高　就職　島内　2次産業"),
+    ("A19_177", "卒業者状況　高　就職　島内　3次産業"),
+    ("A19_178", "卒業者状況　高　就職　島外　計"),
+    ("A19_179", "卒業者状況　高　就職　島外　1次産業"),
+    ("A19_180", "卒業者状況　高　就職　島外　2次産業"),
+    ("A19_181", "卒業者状況　高　就職　島外　3次産業"),
+    ("A19_182", "卒業者状況　高　進学　総数　"),
+    ("A19_183", "卒業者状況　高　進学　島内　"),
+    ("A19_184", "卒業者状況　高　進学　島外　"),
+    ("A19_185", "卒業者状況　高　進学　進学率　"),
+    ("A19_186", "卒業者状況　高　未就職・その他　島内　"),
+    ("A19_187", "卒業者状況　高　未就職・その他　島外　"),
+    ("A19_188", "公園等　国調人口　　　"),
+    ("A19_189", "公園等　離島振興関係一括事業　都市公園　都市計画公園　カ所数"),
+    ("A19_190", "公園等　離島振興関係一括事業　都市公園　都市計画公園　面積"),
+    ("A19_191", "公園等　離島振興関係一括事業　都市公園　特定地区公園　カ所数"),
+    ("A19_192", "公園等　離島振興関係一括事業　都市公園　特定地区公園　面積"),
+    ("A19_193", "公園等　離島振興関係一括事業　都市公園　計　カ所数"),
+    ("A19_194", "公園等　離島振興関係一括事業　都市公園　計　面積"),
+    ("A19_195", "公園等　離島振興関係一括事業　港湾環境整備緑地　カ所数　"),
+    ("A19_196", "公園等　離島振興関係一括事業　港湾環境整備緑地　面積　"),
+    ("A19_197", "公園等　離島振興関係一括事業　漁港漁村環境整備　カ所数　"),
+    ("A19_198", "公園等　離島振興関係一括事業　漁港漁村環境整備　面積　"),
+    ("A19_199", "公園等　離島振興関係一括事業　海岸環境整備　カ所数　"),
+    ("A19_200", "公園等　離島振興関係一括事業　海岸環境整備　面積　"),
+    ("A19_201", "公園等　離島振興関係一括事業　農業集落環境整備　カ所数　"),
+    ("A19_202", "公園等　離島振興関係一括事業　農業集落環境整備　面積　"),
+    ("A19_203", "公園等　離島振興関係一括事業　コミュニティ・アイランド　カ所数　"),
+    ("A19_204", "公園等　離島振興関係一括事業　コミュニティ・アイランド　面積　"),
+    ("A19_205", "公園等　離島振興関係一括事業　自然公園施設設備（園地等）　カ所数　"),
+    ("A19_206", "公園等　離島振興関係一括事業　自然公園施設設備（園地等）　面積　"),
+    ("A19_207", "公園等　離島振興関係一括事業　その他　カ所数　"),
+    ("A19_208", "公園等　離島振興関係一括事業　その他　面積　"),
+    ("A19_209", "公園等　離島振興関係一括事業　小計　カ所数　"),
+    ("A19_210", "公園等　離島振興関係一括事業　小計　面積　"),
+    ("A19_211", "公園等　地方公共団体単独事業　カ所数　　"),
+    ("A19_212", "公園等　地方公共団体単独事業　面積　　"),
+    ("A19_213", "公園等　合計　カ所数　　"),
+    ("A19_214", "公園等　合計　面積　　"),
+    ("A19_215", "公園等　一人当たり公園・緑地面積　　　"),
+    ("A19_216", "道路　国道　総延長"),
+    ("A19_217", "道路　国道　重用延長"),
+    ("A19_218", "道路　国道　未供用延長"),
+    ("A19_219", "道路　国道　実延長"),
+    ("A19_220", "道路　国道　実延長　規格改良済延長"),
+    ("A19_221", "道路　国道　実延長　規格改良済延長　内車道幅員55m以上"),
+    ("A19_222", "道路　国道　実延長　未改良延長"),
+    ("A19_223", "道路　国道　実延長　未改良延長　内自動車交通不能"),
+    ("A19_224", "道路　国道　実延長　舗装済延長"),
+    ("A19_225", "道路　国道　実延長　舗装済延長　内本舗装延長"),
+    ("A19_226", "道路　国道　実延長　未舗装延長"),
+    ("A19_227", "道路　国道　歩道設置道路実延長"),
+    ("A19_228", "道路　国道　道路面積　車道面積"),
+    ("A19_229", "道路　国道　道路面積　道路部面積"),
+    ("A19_230", "道路　国道　道路面積　道路敷面積"),
+    ("A19_231", "道路　主要地方道　総延長"),
+    ("A19_232", "道路　主要地方道　重用延長"),
+    ("A19_233", "道路　主要地方道　未供用延長"),
+    ("A19_234", "道路　主要地方道　実延長"),
+    ("A19_235", "道路　主要地方道　実延長　規格改良済延長"),
+    ("A19_236", "道路　主要地方道　実延長　規格改良済延長　内車道幅員55m以上"),
+    ("A19_237", "道路　主要地方道　実延長　未改良延長"),
+    ("A19_238", "道路　主要地方道　実延長　未改良延長　内自動車交通不能"),
+    ("A19_239", "道路　主要地方道　実延長　舗装済延長"),
+    ("A19_240", "道路　主要地方道　実延長　舗装済延長　内本舗装延長"),
+    ("A19_241", "道路　主要地方道　実延長　未舗装延長"),
+    ("A19_242", "道路　主要地方道　歩道設置道路実延長"),
+    ("A19_243", "道路　主要地方道　道路面積　車道面積"),
+    ("A19_244", "道路　主要地方道　道路面積　道路部面積"),
+    ("A19_245", "道路　主要地方道　道路面積　道路敷面積"),
+    ("A19_246", "道路　都道県道　総延長"),
+    ("A19_247", "道路　都道県道　重用延長"),
+    ("A19_248", "道路　都道県道　未供用延長"),
+    ("A19_249", "道路　都道県道　実延長"),
+    ("A19_250", "道路　都道県道　実延長　規格改良済延長"),
+    ("A19_251", "道路　都道県道　実延長　規格改良済延長　内車道幅員55m以上"),
+    ("A19_252", "道路　都道県道　実延長　未改良延長"),
+    ("A19_253", "道路　都道県道　実延長　未改良延長　内自動車交通不能"),
+    ("A19_254", "道路　都道県道　実延長　舗装済延長"),
+    ("A19_255", "道路　都道県道　実延長　舗装済延長　内本舗装延長"),
+    ("A19_256", "道路　都道県道　実延長　未舗装延長"),
+    ("A19_257", "道路　都道県道　歩道設置道路実延長"),
+    ("A19_258", "道路　都道県道　道路面積　車道面積"),
+    ("A19_259", "道路　都道県道　道路面積　道路部面積"),
+    ("A19_260", "道路　都道県道　道路面積　道路敷面積"),
+    ("A19_261", "道路　市町村道　総延長"),
+    ("A19_262", "道路　市町村道　重用延長"),
+    ("A19_263", "道路　市町村道　未供用延長"),
+    ("A19_264", "道路　市町村道　実延長"),
+    ("A19_265", "道路　市町村道　実延長　規格改良済延長"),
+    ("A19_266", "道路　市町村道　実延長　規格改良済延長　内車道幅員55m以上"),
+    ("A19_267", "道路　市町村道　実延長　未改良延長"),
+    ("A19_268", "道路　市町村道　実延長　未改良延長　内自動車交通不能"),
+    ("A19_269", "道路　市町村道　実延長　舗装済延長"),
+    ("A19_270", "道路　市町村道　実延長　舗装済延長　内本舗装延長"),
+    ("A19_271", "道路　市町村道　実延長　未舗装延長"),
+    ("A19_272", "道路　市町村道　歩道設置道路実延長"),
+    ("A19_273", "道路　市町村道　道路面積　車道面積"),
+    ("A19_274", "道路　市町村道　道路面積　道路部面積"),
+    ("A19_275", "道路　市町村道　道路面積　道路敷面積"),
+    ("A19_276", "道路　備考"),
+    ("A19_277", "調査年度"),
+    ("A19_278", "車輌保有台数　保有車両合計"),
+    ("A19_279", "車輌保有台数　登録車両　小計"),
+    ("A19_280", "車輌保有台数　登録車両　トラック　計"),
+    ("A19_281", "車輌保有台数　登録車両　トラック　普通車"),
+    ("A19_282", "車輌保有台数　登録車両　トラック　小型車"),
+    ("A19_283", "車輌保有台数　登録車両　トラック　トレーラー"),
+    ("A19_284", "車輌保有台数　登録車両　バス　"),
+    ("A19_285", "車輌保有台数　登録車両　乗用車　計"),
+    ("A19_286", "車輌保有台数　登録車両　乗用車　普通車"),
+    ("A19_287", "車輌保有台数　登録車両　乗用車　小型車"),
+    ("A19_288", "車輌保有台数　登録車両　特種用途車　"),
+    ("A19_289", "車輌保有台数　登録車両　大型特殊車　"),
+    ("A19_290", "車輌保有台数　軽自動車"),
+    ("A19_291", "車輌保有台数　二輪車"),
+    ("A19_292", "車輌保有台数　原動機付自転車"),
+    ("A19_293", "車輌保有台数　小型特殊車"),
+    ("A19_294", "車輌保有台数　備考"),
+    ("A19_295", "医療　住民登録人口"),
+    ("A19_296", "医療　施設　総数"),
+    ("A19_297", "医療　施設　病院"),
+    ("A19_298", "医療　施設　診療所"),
+    ("A19_299", "医療　施設　診療所　有床診療所"),
+    ("A19_300", "医療　施設　歯科診療所"),
+    ("A19_301", "医療　施設　一般診療所の歯科診療科目"),
+    ("A19_302", "医療　施設　開設者別　国"),
+    ("A19_303", "医療　施設　開設者別　都道県"),
+    ("A19_304", "医療　施設　開設者別　市町村"),
+    ("A19_305", "医療　施設　開設者別　個人"),
+    ("A19_306", "医療　施設　開設者別　その他"),
+    ("A19_307", "医療　病床数"),
+    ("A19_308", "医療　従事者　医師　常勤"),
+    ("A19_309", "医療　従事者　医師　非常勤"),
+    ("A19_310", "医療　従事者　歯科医師　常勤"),
+    ("A19_311", "医療　従事者　歯科医師　非常勤"),
+    ("A19_312", "医療　従事者　保健師　医療施設勤務"),
+    ("A19_313", "医療　従事者　保健師　医療施設外勤務"),
+    ("A19_314", "医療　従事者　助産師　医療施設勤務"),
+    ("A19_315", "医療　従事者　助産師　医療施設外勤務"),
+    ("A19_316", "医療　従事者　看護師　医療施設勤務"),
+    ("A19_317", "医療　従事者　看護師　医療施設外勤務"),
+    ("A19_318", "医療　従事者　歯科衛生士　医療施設勤務"),
+    ("A19_319", "医療　従事者　歯科衛生士　医療施設外勤務"),
+    ("A19_320", "医療　搬送設備等　巡回診療車"),
+    ("A19_321", "医療　搬送設備等　巡回診療船"),
+    ("A19_322", "医療　搬送設備等　患者輸送車"),
+    ("A19_323", "医療　搬送設備等　患者輸送船"),
+    ("A19_324", "医療　備考"),
+    ("A19_325", "水道　住民登録人口"),
+    ("A19_326", "水道　利用人口　上水道"),
+    ("A19_327", "水道　利用人口　専用水道"),
+    ("A19_328", "水道　利用人口　簡易水道"),
+    ("A19_329", "水道　利用人口　計"),
+    ("A19_330", "水道　普及率"),
+    ("A19_331", "水道　その他飲料水利用人口　井戸"),
+    ("A19_332", "水道　その他飲料水利用人口　流湧水"),
+    ("A19_333", "水道　その他飲料水利用人口　天水"),
+    ("A19_334", "水道　その他飲料水利用人口　計"),
+    ("A19_335", "水道　備考"),
+    ("A19_336", "し尿処理状況　住民登録人口"),
+    ("A19_337", "し尿処理状況　計画処理区域内人口　水洗化人口"),
+    ("A19_338", "し尿処理状況　計画処理区域内人口　非水洗化人口　収集人口"),
+    ("A19_339", "し尿処理状況　計画処理区域内人口　非水洗化人口　自家処理人口"),
+    ("A19_340", "し尿処理状況　計画処理区域内人口　計"),
+    ("A19_341", "し尿処理状況　計画処理区域内人口　計画収集率"),
+    ("A19_342", "し尿処理状況　区域内人口比率"),
+    ("A19_343", "し尿処理状況　収集し尿処理内訳　施設処理"),
+    ("A19_344", "し尿処理状況　収集し尿処理内訳　埋立て"),
+    ("A19_345", "し尿処理状況　収集し尿処理内訳　海洋投入"),
+    ("A19_346", "し尿処理状況　収集し尿処理内訳　その他"),
+    ("A19_347", "し尿処理状況　収集し尿処理内訳　計"),
+    ("A19_348", "し尿処理状況　収集し尿処理内訳　施設処理率"),
+    ("A19_349", "し尿処理状況　備考"),
+    ("A19_350", "ごみ処理状況　住民登録人口"),
+    ("A19_351", "ごみ処理状況　計画処理区域内人口　計画収集人口"),
+    ("A19_352", "ごみ処理状況　計画処理区域内人口　自家処理人口"),
+    ("A19_353", "ごみ処理状況　計画処理区域内人口　計"),
+    ("A19_354", "ごみ処理状況　計画処理区域内人口　計画収集率"),
+    ("A19_355", "ごみ処理状況　区域内人口比率"),
+    ("A19_356", "ごみ処理状況　収集ごみ処理内訳　施設処理"),
+    ("A19_357", "ごみ処理状況　収集ごみ処理内訳　埋立て"),
+    ("A19_358", "ごみ処理状況　収集ごみ処理内訳　その他"),
+    ("A19_359", "ごみ処理状況　収集ごみ処理内訳　計"),
+    ("A19_360", "ごみ処理状況　収集ごみ処理内訳　施設処理率"),
+    ("A19_361", "ごみ処理状況　備考"),
+    ("A19_362", "汚水処理状況　住民登録人口"),
+    ("A19_363", "汚水処理状況　計画処理区域内人口　公共・流域下水道"),
+    ("A19_364", "汚水処理状況　計画処理区域内人口　農業集落排水"),
+    ("A19_365", "汚水処理状況　計画処理区域内人口　漁業集落環境整備"),
+    ("A19_366", "汚水処理状況　計画処理区域内人口　コミュニティ・プラント"),
+    ("A19_367", "汚水処理状況　計画処理区域内人口　合併処理浄化槽"),
+    ("A19_368", "汚水処理状況　計画処理区域内人口　その他"),
+    ("A19_369", "汚水処理状況　計画処理区域内人口　計"),
+    ("A19_370", "汚水処理状況　区域内人口比率"),
+    ("A19_371", "汚水処理状況　処理人口　公共・流域下水道"),
+    ("A19_372", "汚水処理状況　処理人口　農業集落排水"),
+    ("A19_373", "汚水処理状況　処理人口　漁業集落環境整備"),
+    ("A19_374", "汚水処理状況　処理人口　コミュニティ・プラント"),
+    ("A19_375", "汚水処理状況　処理人口　合併処理浄化槽"),
+    ("A19_376", "汚水処理状況　処理人口　その他"),
+    ("A19_377", "汚水処理状況　処理人口　計"),
+    ("A19_378", "汚水処理状況　処理人口普及率"),
+    ("A19_379", "汚水処理状況　備考"),
+    ("A19_380", "財政1　決算　基準財政需要額"),
+    ("A19_381", "財政1　決算　基準財政収入額"),
+    ("A19_382", "財政1　決算　財政力指数"),
+    ("A19_383", "財政1　現在　積立金現在高"),
+    ("A19_384", "財政1　現在　地方債現在高"),
+    ("A19_385", "財政1　歳入　地方税収入"),
+    ("A19_386", "財政1　歳入　地方交付税"),
+    ("A19_387", "財政1　歳入　国庫支出金"),
+    ("A19_388", "財政1　歳入　都道県支出金"),
+    ("A19_389", "財政1　歳入　地方債"),
+    ("A19_390", "財政1　歳入　その他"),
+    ("A19_391", "財政1　歳入　計"),
+    ("A19_392", "財政2　歳出　議会費"),
+    ("A19_393", "財政2　歳出　総務費"),
+    ("A19_394", "財政2　歳出　民生費"),
+    ("A19_395", "財政2　歳出　衛生費"),
+    ("A19_396", "財政2　歳出　労働費"),
+    ("A19_397", "財政2　歳出　農林水産費"),
+    ("A19_398", "財政2　歳出　商工費"),
+    ("A19_399", "財政2　歳出　土木費"),
+    ("A19_400", "財政2　歳出　消防費"),
+    ("A19_401", "財政2　歳出　教育費"),
+    ("A19_402", "財政2　歳出　災害復旧費"),
+    ("A19_403", "財政2　歳出　公債費"),
+    ("A19_404", "財政2　歳出　諸支出金"),
+    ("A19_405", "財政2　歳出　計"),
+    ("A19_406", "調査年度"),
+    ("A19_407", "就業者数　就業者総数　"),
+    ("A19_408", "就業者数　第1次　農業"),
+    ("A19_409", "就業者数　第1次　農業、林業"),
+    ("A19_410", "就業者数　第1次　農業、林業（うち農業）"),
+    ("A19_411", "就業者数　第1次　林業"),
+    ("A19_412", "就業者数　第1次　漁業"),
+    ("A19_413", "就業者数　第1次　小計"),
+    ("A19_414", "就業者数　第2次　鉱業"),
+    ("A19_415", "就業者数　第2次　鉱業，採石業，砂利採取業"),
+    ("A19_416", "就業者数　第2次　建設業"),
+    ("A19_417", "就業者数　第2次　製造業"),
+    ("A19_418", "就業者数　第2次　小計"),
+    ("A19_419", "就業者数　第3次　電気・ガス・熱供給・水道業"),
+    ("A19_420", "就業者数　第3次　運輸・通信業"),
+    ("A19_421", "就業者数　第3次　情報・通信業"),
+    ("A19_422", "就業者数　第3次　運輸業"),
+    ("A19_423", "就業者数　第3次　運輸業、郵便業"),
+    ("A19_424", "就業者数　第3次　卸売・小売業・飲食業"),
+    ("A19_425", "就業者数　第3次　卸売・小売業"),
+    ("A19_426", "就業者数　第3次　金融・保険業"),
+    ("A19_427", "就業者数　第3次　不動産業"),
+    ("A19_428", "就業者数　第3次　不動産業、物品賃貸業"),
+    ("A19_429", "就業者数　第3次　学術研究，専門・技術サービス業"),
+    ("A19_430", "就業者数　第3次　飲食店・宿泊業"),
+    ("A19_431", "就業者数　第3次　宿泊業，飲食サービス業　"),
+    ("A19_432", "就業者数　第3次　生活関連サービス業，娯楽業"),
+    ("A19_433", "就業者数　第3次　医療･福祉"),
+    ("A19_434", "就業者数　第3次　教育・学習支援業"),
+    ("A19_435", "就業者数　第3次　複合サービス事業"),
+    ("A19_436", "就業者数　第3次　サービス業"),
+    ("A19_437", "就業者数　第3次　公務"),
+    ("A19_438", "就業者数　第3次　小計"),
+    ("A19_439", "就業者数　分類不能　"),
+    ("A19_440", "就業者数　備考　"),
+    ("A19_441", "事業所数　総数　"),
+    ("A19_442", "事業所数　第1次　農業"),
+    ("A19_443", "事業所数　第1次　林業"),
+    ("A19_444", "事業所数　第1次　漁業"),
+    ("A19_445", "事業所数　第1次　小計"),
+    ("A19_446", "事業所数　第2次　鉱業"),
+    ("A19_447", "事業所数　第2次　建設業"),
+    ("A19_448", "事業所数　第2次　製造業"),
+    ("A19_449", "事業所数　第2次　小計"),
+    ("A19_450", "事業所数　第3次　電気・ガス・熱供給・水道業"),
+    ("A19_451", "事業所数　第3次　運輸・通信業"),
+    ("A19_452", "事業所数　第3次　卸売・小売業・飲食業"),
+    ("A19_453", "事業所数　第3次　金融・保険業"),
+    ("A19_454", "事業所数　第3次　不動産業"),
+    ("A19_455", "事業所数　第3次　サービス業"),
+    ("A19_456", "事業所数　第3次　公務"),
+    ("A19_457", "事業所数　第3次　小計"),
+    ("A19_458", "事業所数　分類不能　"),
+    ("A19_459", "従業者数　総数　"),
+    ("A19_460", "従業者数　第1次　農業"),
+    ("A19_461", "従業者数　第1次　林業"),
+    ("A19_462", "従業者数　第1次　漁業"),
+    ("A19_463", "従業者数　第1次　小計"),
+    ("A19_464", "従業者数　第2次　鉱業"),
+    ("A19_465", "従業者数　第2次　建設業"),
+    ("A19_466", "従業者数　第2次　製造業"),
+    ("A19_467", "従業者数　第2次　小計"),
+    ("A19_468", "従業者数　第3次　電気・ガス・熱供給・水道業"),
+    ("A19_469", "従業者数　第3次　運輸・通信業"),
+    ("A19_470", "従業者数　第3次　卸売・小売業・飲食業"),
+    ("A19_471", "従業者数　第3次　金融・保険業"),
+    ("A19_472", "従業者数　第3次　不動産業"),
+    ("A19_473", "従業者数　第3次　サービス業"),
+    ("A19_474", "従業者数　第3次　公務"),
+    ("A19_475", "従業者数　第3次　小計"),
+    ("A19_476", "従業者数　分類不能　"),
+    ("A19_477", "農林業　総面積　"),
+    ("A19_478", "農林業　農振面積　"),
+    ("A19_479", "農林業　耕地面積　総数"),
+    ("A19_480", "農林業　耕地面積　田"),
+    ("A19_481", "農林業　耕地面積　畑"),
+    ("A19_482", "農林業　耕地面積　樹園地"),
+    ("A19_483", "農林業　耕地面積　牧草地"),
+    ("A19_484", "農林業　耕地化率　"),
+    ("A19_485", "農林業　家畜飼育数　乳用牛"),
+    ("A19_486", "農林業　家畜飼育数　肉用牛"),
+    ("A19_487", "農林業　家畜飼育数　豚"),
+    ("A19_488", "農林業　家畜飼育数　鶏"),
+    ("A19_489", "農林業　農道延長　"),
+    ("A19_490", "農林業　林道延長　"),
+    ("A19_491", "農林業　林道延長　内国有林道"),
+    ("A19_492", "農林業　森林面積　総数　"),
+    ("A19_493", "農林業　森林面積　総数　うち人工林"),
+    ("A19_494", "農林業　森林面積　国有林　"),
+    ("A19_495", "農林業　森林面積　国有林　うち人工林"),
+    ("A19_496", "農林業　森林面積　民有林　"),
+    ("A19_497", "農林業　森林面積　民有林　うち人工林"),
+    ("A19_498", "農林業　森林蓄積量　"),
+    ("A19_499", "農林業　備考　"),
+    ("A19_500", "水産業現況_港湾　水揚高　属人水揚　計(水揚トン数)"),
+    ("A19_501", "水産業現況_港湾　水揚高　属人水揚　計(水揚金額)"),
+    ("A19_502", "水産業現況_港湾　水揚高　属人水揚　海面漁業(水揚トン数)"),
+    ("A19_503", "水産業現況_港湾　水揚高　属人水揚　海面漁業(水揚金額)"),
+    ("A19_504", "水産業現況_港湾　水揚高　属人水揚　海面養殖業(水揚トン数)"),
+    ("A19_505", "水産業現況_港湾　水揚高　属人水揚　海面養殖業(水揚金額)"),
+    ("A19_506", "水産業現況_港湾　水揚高　属地水揚　計(水揚トン数)"),
+    ("A19_507", "水産業現況_港湾　水揚高　属地水揚　計(水揚金額)"),
+    ("A19_508", "水産業現況_港湾　水揚高　属地水揚　海面漁業(水揚トン数)"),
+    ("A19_509", "水産業現況_港湾　水揚高　属地水揚　海面漁業(水揚金額)"),
+    ("A19_510", "水産業現況_港湾　水揚高　属地水揚　海面養殖業(水揚トン数)"),
+    ("A19_511", "水産業現況_港湾　水揚高　属地水揚　海面養殖業(水揚金額)"),
+    ("A19_512", "水産業現況_漁港　水揚高　属人水揚　計(水揚トン数)"),
+    ("A19_513", "水産業現況_漁港　水揚高　属人水揚　計(水揚金額)"),
+    ("A19_514", "水産業現況_漁港　水揚高　属人水揚　海面漁業(水揚トン数)"),
+    ("A19_515", "水産業現況_漁港　水揚高　属人水揚　海面漁業(水揚金額)"),
+    ("A19_516", "水産業現況_漁港　水揚高　属人水揚　海面養殖業(水揚トン数)"),
+    ("A19_517", "水産業現況_漁港　水揚高　属人水揚　海面養殖業(水揚金額)"),
+    ("A19_518", "水産業現況_漁港　水揚高　属地水揚　計(水揚トン数)"),
+    ("A19_519", "水産業現況_漁港　水揚高　属地水揚　計(水揚金額)"),
+    ("A19_520", "水産業現況_漁港　水揚高　属地水揚　海面漁業(水揚トン数)"),
+    ("A19_521", "水産業現況_漁港　水揚高　属地水揚　海面漁業(水揚金額)"),
+    ("A19_522", "水産業現況_漁港　水揚高　属地水揚　海面養殖業(水揚トン数)"),
+    ("A19_523", "水産業現況_漁港　水揚高　属地水揚　海面養殖業(水揚金額)"),
+    ("A19_524", "経営体　農家戸数　総数　　"),
+    ("A19_525", "経営体　農家戸数　販売農業　専業　"),
+    ("A19_526", "経営体　農家戸数　販売農業　兼業　1種"),
+    ("A19_527", "経営体　農家戸数　販売農業　兼業　2種"),
+    ("A19_528", "経営体　農家戸数　自給的農家　　"),
+    ("A19_529", "経営体　漁家戸数　総数　　"),
+    ("A19_530", "経営体　漁家戸数　専業　　"),
+    ("A19_531", "経営体　漁家戸数　兼業　1種　"),
+    ("A19_532", "経営体　漁家戸数　兼業　2種　"),
+    ("A19_533", "経営体　林家戸数　総数　　"),
+    ("A19_534", "経営体　林家戸数　農家林家　　"),
+    ("A19_535", "経営体　林家戸数　非農家林家　　"),
+    ("A19_536", "経営体　組合数　農業協同組合　組合数（本部）"),
+    ("A19_537", "経営体　組合数　農業協同組合　組合数（支部・支所）"),
+    ("A19_538", "経営体　組合数　農業協同組合　組合員数"),
+    ("A19_539", "経営体　組合数　漁業協同組合　組合数（本部）"),
+    ("A19_540", "経営体　組合数　漁業協同組合　組合数（支部・支所）"),
+    ("A19_541", "経営体　組合数　漁業協同組合　組合員数"),
+    ("A19_542", "経営体　組合数　森林組合　組合数（本部）"),
+    ("A19_543", "経営体　組合数　森林組合　組合数（支部・支所）"),
+    ("A19_544", "経営体　組合数　森林組合　組合員数"),
+    ("A19_545", "生産額　農業　耕種　米"),
+    ("A19_546", "生産額　農業　耕種　麦"),
+    ("A19_547", "生産額　農業　耕種　いも"),
+    ("A19_548", "生産額　農業　耕種　豆･雑穀"),
+    ("A19_549", "生産額　農業　耕種　野菜"),
+    ("A19_550", "生産額　農業　耕種　果実"),
+    ("A19_551", "生産額　農業　耕種　花卉"),
+    ("A19_552", "生産額　農業　耕種　工芸作物"),
+    ("A19_553", "生産額　農業　耕種　その他"),
+    ("A19_554", "生産額　農業　耕種　小計"),
+    ("A19_555", "生産額　農業　養蚕　"),
+    ("A19_556", "生産額　農業　畜産　牛肉"),
+    ("A19_557", "生産額　農業　畜産　豚肉"),
+    ("A19_558", "生産額　農業　畜産　牛乳"),
+    ("A19_559", "生産額　農業　畜産　鶏卵"),
+    ("A19_560", "生産額　農業　畜産　その他"),
+    ("A19_561", "生産額　農業　畜産　小計"),
+    ("A19_562", "生産額　農業　合計　"),
+    ("A19_563", "生産額　林業　木材　用材"),
+    ("A19_564", "生産額　林業　木材　薪炭材"),
+    ("A19_565", "生産額　林業　木炭　"),
+    ("A19_566", "生産額　林業　しいたけ　"),
+    ("A19_567", "生産額　林業　その他　"),
+    ("A19_568", "生産額　林業　合計　"),
+    ("A19_569", "生産額　水産業　魚類　"),
+    ("A19_570", "生産額　水産業　貝類　"),
+    ("A19_571", "生産額　水産業　水産動物　"),
+    ("A19_572", "生産額　水産業　海草類　"),
+    ("A19_573", "生産額　水産業　その他　"),
+    ("A19_574", "生産額　水産業　合計　"),
+    ("A19_575", "生産額　農林水産業総計　　"),
+    ("A19_576", "生産額　備考　　"),
+    ("A19_577", "観光客数　春　"),
+    ("A19_578", "観光客数　夏　"),
+    ("A19_579", "観光客数　秋　"),
+    ("A19_580", "観光客数　冬　"),
+    ("A19_581", "観光客数　計　"),
+    ("A19_582", "宿泊能力(最盛期)　旅館・ホテル　数"),
+    ("A19_583", "宿泊能力(最盛期)　旅館・ホテル　収容能力"),
+    ("A19_584", "宿泊能力(最盛期)　民宿　数"),
+    ("A19_585", "宿泊能力(最盛期)　民宿　収容能力"),
+    ("A19_586", "宿泊能力　年間宿泊者数　　"),
+    ("A19_587", "自然公園面積　国立公園　"),
+    ("A19_588", "自然公園面積　国定公園　"),
+    ("A19_589", "自然公園面積　県立公園　"),
+    ("A19_590", "観光客数・宿泊能力・自然公園　備考　　"),
+    ("A19_591", "港湾・航路現況_港湾　港湾利用状況　入港船舶　隻数"),
+    ("A19_592", "港湾・航路現況_港湾　総屯数"),
+    ("A19_593", "港湾・航路現況_港湾　乗降人員　"),
+    ("A19_594", "港湾・航路現況_港湾　海上出入貨物　出"),
+    ("A19_595", "港湾・航路現況_港湾　海上出入貨物　入"),
+    ("A19_596", "港湾・航路現況_港湾　海上出入貨物　計"),
+    ("A19_597", "港湾・航路現況_漁港　港湾利用状況　入港船舶　隻数"),
+    ("A19_598", "港湾・航路現況_漁港　総屯数"),
+    ("A19_599", "港湾・航路現況_漁港　乗降人員　"),
+    ("A19_600", "港湾・航路現況_漁港　海上出入貨物　出"),
+    ("A19_601", "港湾・航路現況_漁港　海上出入貨物　入"),
+    ("A19_602", "港湾・航路現況_漁港　海上出入貨物　計"),
+    ("A19_603", "空港・航空路現況　都道県名　空港名　　"),
+    ("A19_604", "空港・航空路現況　運航実績"),
+    ("A19_605", "空港・航空路現況　　1日当たり運航回数　最盛期"),
+    ("A19_606", "空港・航空路現況　　年間乗降人員　"),
+  ]),
+
+  ("A20", &[
+    ("A20_001", "奄美ID"),
+    ("A20_002", "行政コード"),
+    ("A20_003", "都道府県名"),
+    ("A20_004", "支庁名"),
+    ("A20_005", "郡・政令市名"),
+    ("A20_006", "市区町村名"),
+    ("A20_007", "島嶼名"),
+    ("A20_008", "指定日"),
+  ]),
+
+  ("A20s", &[
+    ("A20_001", "奄美群島ID"),
+    ("A20_002", "行政コード"),
+    ("A20_003", "都道府県名"),
+    ("A20_004", "支庁名"),
+    ("A20_005", "郡・政令市名"),
+    ("A20_006", "市区町村名"),
+    ("A20_007", "島嶼名"),
+    ("A20_008", "指定状況コード"),
+    ("A20_009", "調査年度"),
+    ("A20_010", "概要　(name)"),
+    ("A20_011", "概要　指定回次"),
+    ("A20_012", "概要　郡名"),
+    ("A20_013", "概要　人口"),
+    ("A20_014", "概要　世帯数"),
+    ("A20_015", "概要　面積"),
+    ("A20_016", "概要　海岸延長"),
+    ("A20_017", "概要　備考"),
+    ("A20_018", "前年　住民登録人口　男"),
+    ("A20_019", "前年　住民登録人口　女"),
+    ("A20_020", "前年　住民登録人口　計"),
+    ("A20_021", "前年　世帯数　"),
+    ("A20_022", "人口移動　出生数　"),
+    ("A20_023", "人口移動　死亡数　"),
+    ("A20_024", "人口移動　自然増△減　"),
+    ("A20_025", "人口移動　転入数　"),
+    ("A20_026", "人口移動　転出数　"),
+    ("A20_027", "人口移動　社会増△減　"),
+    ("A20_028", "人口移動　増減計　"),
+    ("A20_029", "現年　住民登録人口　男"),
+    ("A20_030", "現年　住民登録人口　女"),
+    ("A20_031", "現年　住民登録人口　計"),
+    ("A20_032", "現年　世帯数　"),
+    ("A20_033", "人口・世帯数・人口動態　備考"),
+    ("A20_034", "男　国勢調査人口総数"),
+    ("A20_035", "男　年少人口　0-4"),
+    ("A20_036", "男　年少人口　5-9"),
+    ("A20_037", "男　年少人口　10-14"),
+    ("A20_038", "男　年少人口　小計"),
+    ("A20_039", "男　生産年齢人口　15-19"),
+    ("A20_040", "男　生産年齢人口　20-24"),
+    ("A20_041", "男　生産年齢人口　25-29"),
+    ("A20_042", "男　生産年齢人口　30-34"),
+    ("A20_043", "男　生産年齢人口　35-39"),
+    ("A20_044", "男　生産年齢人口　40-44"),
+    ("A20_045", "男　生産年齢人口　45-49"),
+    ("A20_046", "男　生産年齢人口　50-54"),
+    ("A20_047", "男　生産年齢人口　55-59"),
+    ("A20_048", "男　生産年齢人口　60-64"),
+    ("A20_049", "男　生産年齢人口　小計"),
+    ("A20_050", "男　老年人口　65-69"),
+    ("A20_051", "男　老年人口　70-74"),
+    ("A20_052", "男　老年人口　75-79"),
+    ("A20_053", "男　老年人口　80-84"),
+    ("A20_054", "男　老年人口　85歳以上"),
+    ("A20_055", "男　老年人口　小計"),
+    ("A20_056", "男　不詳　"),
+    ("A20_057", "男　生産年齢人口指数　"),
+    ("A20_058", "男　老齢化率　"),
+    ("A20_059", "女　国勢調査人口総数　"),
+    ("A20_060", "女　年少人口　0-4"),
+    ("A20_061", "女　年少人口　5-9"),
+    ("A20_062", "女　年少人口　10-14"),
+    ("A20_063", "女　年少人口　小計"),
+    ("A20_064", "女　生産年齢人口　15-19"),
+    ("A20_065", "女　生産年齢人口　20-24"),
+    ("A20_066", "女　生産年齢人口　25-29"),
+    ("A20_067", "女　生産年齢人口　30-34"),
+    ("A20_068", "女　生産年齢人口　35-39"),
+    ("A20_069", "女　生産年齢人口　40-44"),
+    ("A20_070", "女　生産年齢人口　45-49"),
+    ("A20_071", "女　生産年齢人口　50-54"),
+    ("A20_072", "女　生産年齢人口　55-59"),
+    ("A20_073", "女　生産年齢人口　60-64"),
+    ("A20_074", "女　生産年齢人口　小計"),
+    ("A20_075", "女　老年人口　65-69"),
+    ("A20_076", "女　老年人口　70-74"),
+    ("A20_077", "女　老年人口　75-79"),
+    ("A20_078", "女　老年人口　80-84"),
+    ("A20_079", "女　老年人口　85歳以上"),
+    ("A20_080", "女　老年人口　小計"),
+    ("A20_081", "女　不詳　"),
+    ("A20_082", "女　生産年齢人口指数　"),
+    ("A20_083", "女　老齢化率　"),
+    ("A20_084", "男女計　国勢調査人口総数　"),
+    ("A20_085", "男女計　年少人口　0-4"),
+    ("A20_086", "男女計　年少人口　5-9"),
+    ("A20_087", "男女計　年少人口　10-14"),
+    ("A20_088", "男女計　年少人口　小計"),
+    ("A20_089", "男女計　生産年齢人口　15-19"),
+    ("A20_090", "男女計　生産年齢人口　20-24"),
+    ("A20_091", "男女計　生産年齢人口　25-29"),
+    ("A20_092", "男女計　生産年齢人口　30-34"),
+    ("A20_093", "男女計　生産年齢人口　35-39"),
+    ("A20_094", "男女計　生産年齢人口　40-44"),
+    ("A20_095", "男女計　生産年齢人口　45-49"),
+    ("A20_096", "男女計　生産年齢人口　50-54"),
+    ("A20_097", "男女計　生産年齢人口　55-59"),
+    ("A20_098", "男女計　生産年齢人口　60-64"),
+    ("A20_099", "男女計　生産年齢人口　小計"),
+    ("A20_100", "男女計　老年人口　65-69"),
+    ("A20_101", "男女計　老年人口　70-74"),
+    ("A20_102", "男女計　老年人口　75-79"),
+    ("A20_103", "男女計　老年人口　80-84"),
+    ("A20_104", "男女計　老年人口　85歳以上"),
+    ("A20_105", "男女計　老年人口　小計"),
+    ("A20_106", "男女計　不詳　"),
+    ("A20_107", "男女計　生産年齢人口指数　"),
+    ("A20_108", "男女計　老齢化率　"),
+    ("A20_109", "年齢別･男女別人口　備考"),
+    ("A20_110", "調査年度"),
+    ("A20_111", "土地利用　総面積　"),
+    ("A20_112", "土地利用　農用地　農用地合計"),
+    ("A20_113", "土地利用　農用地　農地"),
+    ("A20_114", "土地利用　農用地　採草放牧地"),
+    ("A20_115", "土地利用　森林　"),
+    ("A20_116", "土地利用　原野　"),
+    ("A20_117", "土地利用　水面・河川・水路　"),
+    ("A20_118", "土地利用　道路　"),
+    ("A20_119", "土地利用　宅地　"),
+    ("A20_120", "土地利用　その他　"),
+    ("A20_121", "土地利用　備考　"),
+    ("A20_122", "学校　保　保育所数　本所"),
+    ("A20_123", "学校　保　保育所数　分所"),
+    ("A20_124", "学校　保　乳幼児数　在所"),
+    ("A20_125", "学校　保　乳幼児数　通所"),
+    ("A20_126", "学校　幼　幼稚園数　本園"),
+    ("A20_127", "学校　幼　幼稚園数　分園"),
+    ("A20_128", "学校　幼　園児数　在園"),
+    ("A20_129", "学校　幼　園児数　通園"),
+    ("A20_130", "学校　小　学校数　本校"),
+    ("A20_131", "学校　小　学校数　分校"),
+    ("A20_132", "学校　小　教（職）員数　教員数"),
+    ("A20_133", "学校　小　教（職）員数　職員数"),
+    ("A20_134", "学校　小　学級数"),
+    ("A20_135", "学校　小　児童数　在校"),
+    ("A20_136", "学校　小　児童数　通学"),
+    ("A20_137", "学校　中　学校数　本校"),
+    ("A20_138", "学校　中　学校数　分校"),
+    ("A20_139", "学校　中　教（職）員数　教員数"),
+    ("A20_140", "学校　中　教（職）員数　職員数"),
+    ("A20_141", "学校　中　学級数"),
+    ("A20_142", "学校　中　生徒数　在校"),
+    ("A20_143", "学校　中　生徒数　通学"),
+    ("A20_144", "学校　高　学校数　本校"),
+    ("A20_145", "学校　高　学校数　分校"),
+    ("A20_146", "学校　高　教（職）員数　教員数"),
+    ("A20_147", "学校　高　教（職）員数　職員数"),
+    ("A20_148", "学校　高　学級数"),
+    ("A20_149", "学校　高　生徒数　在校"),
+    ("A20_150", "学校　高　生徒数　通学"),
+    ("A20_151", "学校　備考"),
+    ("A20_152", "卒業者状況　中　卒業生数　　"),
+    ("A20_153", "卒業者状況　中　就職　総数　"),
+    ("A20_154", "卒業者状況　中　就職　島内　計"),
+    ("A20_155", "卒業者状況　中　就職　島内　農林業"),
+    ("A20_156", "卒業者状況　中　就職　島内　水産業"),
+    ("A20_157", "卒業者状況　中　就職　島内　2次産業"),
+    ("A20_158", "卒業者状況　中　就職　島内　3次産業"),
+    ("A20_159", "卒業者状況　中　就職　島外　計"),
+    ("A20_160", "卒業者状況　中　就職　島外　1次産業"),
+    ("A20_161", "卒業者状況　中　就職　島外　2次産業"),
+    ("A20_162", "卒業者状況　中　就職　島外　3次産業"),
+    ("A20_163", "卒業者状況　中　進学　総数　"),
+    ("A20_164", "卒業者状況　中　進学　島内　"),
+    ("A20_165", "卒業者状況　中　進学　島外　"),
+    ("A20_166", "卒業者状況　中　進学　進学率　"),
+    ("A20_167", "卒業者状況　中　未就職・その他　島内　"),
+    ("A20_168", "卒業者状況　中　未就職・その他　島外　"),
+    ("A20_169", "卒業者状況　高　卒業生数　　"),
+    ("A20_170", "卒業者状況　高　就職　総数　"),
+    ("A20_171", "卒業者状況　高　就職　島内　計"),
+    ("A20_172", "卒業者状況　高　就職　島内　農林業"),
+    ("A20_173", "卒業者状況　高　就職　島内　水産業"),
+    ("A20_174", "卒業者状況　高　就職　島内　2次産業"),
+    ("A20_175", "卒業者状況　高　就職　島内　3次産業"),
+    ("A20_176", "卒業者状況　高　就職　島外　計"),
+    ("A20_177", "卒業者状況　高　就職　島外　1次産業"),
+    ("A20_178", "卒業者状況　高　就職　島外　2次産業"),
+    ("A20_179", "卒業者状況　高　就職　島外　3次産業"),
+    ("A20_180", "卒業者状況　高　進学　総数　"),
+    ("A20_181", "卒業者状況　高　進学　島内　"),
+    ("A20_182", "卒業者状況　高　進学　島外　"),
+    ("A20_183", "卒業者状況　高　進学　進学率　"),
+    ("A20_184", "卒業者状況　高　未就職・その他　島内　"),
+    ("A20_185", "卒業者状況　高　未就職・その他　島外　"),
+    ("A20_186", "公園等　国調人口　　　"),
+    ("A20_187", "公園等　離島振興関係一括事業　都市公園　都市計画公園　カ所数"),
+    ("A20_188", "公園等　離島振興関係一括事業　都市公園　都市計画公園　面積"),
+    ("A20_189", "公園等　離島振興関係一括事業　都市公園　特定地区公園　カ所数"),
+    ("A20_190", "公園等　離島振興関係一括事業　都市公園　特定地区公園　面積"),
+    ("A20_191", "公園等　離島振興関係一括事業　都市公園　計　カ所数"),
+    ("A20_192", "公園等　離島振興関係一括事業　都市公園　計　面積"),
+    ("A20_193", "公園等　離島振興関係一括事業　港湾環境整備緑地　カ所数　"),
+    ("A20_194", "公園等　離島振興関係一括事業　港湾環境整備緑地　面積　"),
+    ("A20_195", "公園等　離島振興関係一括事業　漁港漁村環境整備　カ所数　"),
+    ("A20_196", "公園等　離島振興関係一括事業　漁港漁村環境整備　面積　"),
+    ("A20_197", "公園等　離島振興関係一括事業　海岸環境整備　カ所数　"),
+    ("A20_198", "公園等　離島振興関係一括事業　海岸環境整備　面積　"),
+    ("A20_199", "公園等　離島振興関係一括事業　農業集落環境整備　カ所数　"),
+    ("A20_200", "公園等　離島振興関係一括事業　農業集落環境整備　面積　"),
+    ("A20_201", "公園等　離島振興関係一括事業　コミュニティ・アイランド　カ所数　"),
+    ("A20_202", "公園等　離島振興関係一括事業　コミュニティ・アイランド　面積　"),
+    ("A20_203", "公園等　離島振興関係一括事業　自然公園施設設備（園地等）　カ所数　"),
+    ("A20_204", "公園等　離島振興関係一括事業　自然公園施設設備（園地等）　面積　"),
+    ("A20_205", "公園等　離島振興関係一括事業　その他　カ所数　"),
+    ("A20_206", "公園等　離島振興関係一括事業　その他　面積　"),
+    ("A20_207", "公園等　離島振興関係一括事業　小計　カ所数　"),
+    ("A20_208", "公園等　離島振興関係一括事業　小計　面積　"),
+    ("A20_209", "公園等　地方公共団体単独事業　カ所数　　"),
+    ("A20_210", "公園等　地方公共団体単独事業　面積　　"),
+    ("A20_211", "公園等　合計　カ所数　　"),
+    ("A20_212", "公園等　合計　面積　　"),
+    ("A20_213", "公園等　一人当たり公園・緑地面積　　　"),
+    ("A20_214", "道路　国道　総延長"),
+    ("A20_215", "道路　国道　重用延長"),
+    ("A20_216", "道路　国道　未供用延長"),
+    ("A20_217", "道路　国道　実延長"),
+    ("A20_218", "道路　国道　実延長　規格改良済延長"),
+    ("A20_219", "道路　国道　実延長　規格改良済延長　内車道幅員55m以上"),
+    ("A20_220", "道路　国道　実延長　未改良延長"),
+    ("A20_221", "道路　国道　実延長　未改良延長　内自動車交通不能"),
+    ("A20_222", "道路　国道　実延長　舗装済延長"),
+    ("A20_223", "道路　国道　実延長　舗装済延長　内本舗装延長"),
+    ("A20_224", "道路　国道　実延長　未舗装延長"),
+    ("A20_225", "道路　国道　歩道設置道路実延長"),
+    ("A20_226", "道路　国道　道路面積　車道面積"),
+    ("A20_227", "道路　国道　道路面積　道路部面積"),
+    ("A20_228", "道路　国道　道路面積　道路敷面積"),
+    ("A20_229", "道路　主要地方道　総延長"),
+    ("A20_230", "道路　主要地方道　重用延長"),
+    ("A20_231", "道路　主要地方道　未供用延長"),
+    ("A20_232", "道路　主要地方道　実延長"),
+    ("A20_233", "道路　主要地方道　実延長　規格改良済延長"),
+    ("A20_234", "道路　主要地方道　実延長　規格改良済延長　内車道幅員55m以上"),
+    ("A20_235", "道路　主要地方道　実延長　未改良延長"),
+    ("A20_236", "道路　主要地方道　実延長　未改良延長　内自動車交通不能"),
+    ("A20_237", "道路　主要地方道　実延長　舗装済延長"),
+    ("A20_238", "道路　主要地方道　実延長　舗装済延長　内本舗装延長"),
+    ("A20_239", "道路　主要地方道　実延長　未舗装延長"),
+    ("A20_240", "道路　主要地方道　歩道設置道路実延長"),
+    ("A20_241", "道路　主要地方道　道路面積　車道面積"),
+    ("A20_242", "道路　主要地方道　道路面積　道路部面積"),
+    ("A20_243", "道路　主要地方道　道路面積　道路敷面積"),
+    ("A20_244", "道路　都道県道　総延長"),
+    ("A20_245", "道路　都道県道　重用延長"),
+    ("A20_246", "道路　都道県道　未供用延長"),
+    ("A20_247", "道路　都道県道　実延長"),
+    ("A20_248", "道路　都道県道　実延長　規格改良済延長"),
+    ("A20_249", "道路　都道県道　実延長　規格改良済延長　内車道幅員55m以上"),
+    ("A20_250", "道路　都道県道　実延長　未改良延長"),
+    ("A20_251", "道路　都道県道　実延長　未改良延長　内自動車交通不能"),
+    ("A20_252", "道路　都道県道　実延長　舗装済延長"),
+    ("A20_253", "道路　都道県道　実延長　舗装済延長　内本舗装延長"),
+    ("A20_254", "道路　都道県道　実延長　未舗装延長"),
+    ("A20_255", "道路　都道県道　歩道設置道路実延長"),
+    ("A20_256", "道路　都道県道　道路面積　車道面積"),
+    ("A20_257", "道路　都道県道　道路面積　道路部面積"),
+    ("A20_258", "道路　都道県道　道路面積　道路敷面積"),
+    ("A20_259", "道路　市町村道　総延長"),
+    ("A20_260", "道路　市町村道　重用延長"),
+    ("A20_261", "道路　市町村道　未供用延長"),
+    ("A20_262", "道路　市町村道　実延長"),
+    ("A20_263", "道路　市町村道　実延長　規格改良済延長"),
+    ("A20_264", "道路　市町村道　実延長　規格改良済延長　内車道幅員55m以上"),
+    ("A20_265", "道路　市町村道　実延長　未改良延長"),
+    ("A20_266", "道路　市町村道　実延長　未改良延長　内自動車交通不能"),
+    ("A20_267", "道路　市町村道　実延長　舗装済延長"),
+    ("A20_268", "道路　市町村道　実延長　舗装済延長　内本舗装延長"),
+    ("A20_269", "道路　市町村道　実延長　未舗装延長"),
+    ("A20_270", "道路　市町村道　歩道設置道路実延長"),
+    ("A20_271", "道路　市町村道　道路面積　車道面積"),
+    ("A20_272", "道路　市町村道　道路面積　道路部面積"),
+    ("A20_273", "道路　市町村道　道路面積　道路敷面積"),
+    ("A20_274", "道路　備考"),
+    ("A20_275", "調査年度"),
+    ("A20_276", "車輌保有台数　保有車両合計"),
+    ("A20_277", "車輌保有台数　登録車両　小計"),
+    ("A20_278", "車輌保有台数　登録車両　トラック　計"),
+    ("A20_279", "車輌保有台数　登録車両　トラック　普通車"),
+    ("A20_280", "車輌保有台数　登録車両　トラック　小型車"),
+    ("A20_281", "車輌保有台数　登録車両　トラック　トレーラー"),
+    ("A20_282", "車輌保有台数　登録車両　バス　"),
+    ("A20_283", "車輌保有台数　登録車両　乗用車　計"),
+    ("A20_284", "車輌保有台数　登録車両　乗用車　普通車"),
+    ("A20_285", "車輌保有台数　登録車両　乗用車　小型車"),
+    ("A20_286", "車輌保有台数　登録車両　特種用途車　"),
+    ("A20_287", "車輌保有台数　登録車両　大型特殊車　"),
+    ("A20_288", "車輌保有台数　軽自動車"),
+    ("A20_289", "車輌保有台数　二輪車"),
+    ("A20_290", "車輌保有台数　原動機付自転車"),
+    ("A20_291", "車輌保有台数　小型特殊車"),
+    ("A20_292", "車輌保有台数　備考"),
+    ("A20_293", "医療　住民登録人口"),
+    ("A20_294", "医療　施設　総数"),
+    ("A20_295", "医療　施設　病院"),
+    ("A20_296", "医療　施設　診療所"),
+    ("A20_297", "医療　施設　診療所　有床診療所"),
+    ("A20_298", "医療　施設　歯科診療所"),
+    ("A20_299", "医療　施設　一般診療所の歯科診療科目"),
+    ("A20_300", "医療　施設　開設者別　国"),
+    ("A20_301", "医療　施設　開設者別　都道県"),
+    ("A20_302", "医療　施設　開設者別　市町村"),
+    ("A20_303", "医療　施設　開設者別　個人"),
+    ("A20_304", "医療　施設　開設者別　その他"),
+    ("A20_305", "医療　病床数"),
+    ("A20_306", "医療　従事者　医師　常勤"),
+    ("A20_307", "医療　従事者　医師　非常勤"),
+    ("A20_308", "医療　従事者　歯科医師　常勤"),
+    ("A20_309", "医療　従事者　歯科医師　非常勤"),
+    ("A20_310", "医療　従事者　保健師　医療施設勤務"),
+    ("A20_311", "医療　従事者　保健師　医療施設外勤務"),
+    ("A20_312", "医療　従事者　助産師　医療施設勤務"),
+    ("A20_313", "医療　従事者　助産師　医療施設外勤務"),
+    ("A20_314", "医療　従事者　看護師　医療施設勤務"),
+    ("A20_315", "医療　従事者　看護師　医療施設外勤務"),
+    ("A20_316", "医療　従事者　歯科衛生士　医療施設勤務"),
+    ("A20_317", "医療　従事者　歯科衛生士　医療施設外勤務"),
+    ("A20_318", "医療　搬送設備等　巡回診療車"),
+    ("A20_319", "医療　搬送設備等　巡回診療船"),
+    ("A20_320", "医療　搬送設備等　患者輸送車"),
+    ("A20_321", "医療　搬送設備等　患者輸送船"),
+    ("A20_322", "医療　備考"),
+    ("A20_323", "水道　住民登録人口"),
+    ("A20_324", "水道　利用人口　上水道"),
+    ("A20_325", "水道　利用人口　専用水道"),
+    ("A20_326", "水道　利用人口　簡易水道"),
+    ("A20_327", "水道　利用人口　計"),
+    ("A20_328", "水道　普及率"),
+    ("A20_329", "水道　その他飲料水利用人口　井戸"),
+    ("A20_330", "水道　その他飲料水利用人口　流湧水"),
+    ("A20_331", "水道　その他飲料水利用人口　天水"),
+    ("A20_332", "水道　その他飲料水利用人口　計"),
+    ("A20_333", "水道　備考"),
+    ("A20_334", "し尿処理状況　住民登録人口"),
+    ("A20_335", "し尿処理状況　計画処理区域内人口　水洗化人口"),
+    ("A20_336", "し尿処理状況　計画処理区域内人口　非水洗化人口　収集人口"),
+    ("A20_337", "し尿処理状況　計画処理区域内人口　非水洗化人口　自家処理人口"),
+    ("A20_338", "し尿処理状況　計画処理区域内人口　計"),
+    ("A20_339", "し尿処理状況　計画処理区域内人口　計画収集率"),
+    ("A20_340", "し尿処理状況　区域内人口比率"),
+    ("A20_341", "し尿処理状況　収集し尿処理内訳　施設処理"),
+    ("A20_342", "し尿処理状況　収集し尿処理内訳　埋立て"),
+    ("A20_343", "し尿処理状況　収集し尿処理内訳　海洋投入"),
+    ("A20_344", "し尿処理状況　収集し尿処理内訳　その他"),
+    ("A20_345", "し尿処理状況　収集し尿処理内訳　計"),
+    ("A20_346", "し尿処理状況　収集し尿処理内訳　施設処理率"),
+    ("A20_347", "し尿処理状況　備考"),
+    ("A20_348", "ごみ処理状況　住民登録人口"),
+    ("A20_349", "ごみ処理状況　計画処理区域内人口　計画収集人口"),
+    ("A20_350", "ごみ処理状況　計画処理区域内人口　自家処理人口"),
+    ("A20_351", "ごみ処理状況　計画処理区域内人口　計"),
+    ("A20_352", "ごみ処理状況　計画処理区域内人口　計画収集率"),
+    ("A20_353", "ごみ処理状況　区域内人口比率"),
+    ("A20_354", "ごみ処理状況　収集ごみ処理内訳　施設処理"),
+    ("A20_355", "ごみ処理状況　収集ごみ処理内訳　埋立て"),
+    ("A20_356", "ごみ処理状況　収集ごみ処理内訳　その他"),
+    ("A20_357", "ごみ処理状況　収集ごみ処理内訳　計"),
+    ("A20_358", "ごみ処理状況　収集ごみ処理内訳　施設処理率"),
+    ("A20_359", "ごみ処理状況　備考"),
+    ("A20_360", "汚水処理状況　住民登録人口"),
+    ("A20_361", "汚水処理状況　計画処理区域内人口　公共・流域下水道"),
+    ("A20_362", "汚水処理状況　計画処理区域内人口　農業集落排水"),
+    ("A20_363", "汚水処理状況　計画処理区域内人口　漁業集落環境整備"),
+    ("A20_364", "汚水処理状況　計画処理区域内人口　コミュニティ・プラント"),
+    ("A20_365", "汚水処理状況　計画処理区域内人口　合併処理浄化槽"),
+    ("A20_366", "汚水処理状況　計画処理区域内人口　その他"),
+    ("A20_367", "汚水処理状況　計画処理区域内人口　計"),
+    ("A20_368", "汚水処理状況　区域内人口比率"),
+    ("A20_369", "汚水処理状況　処理人口　公共・流域下水道"),
+    ("A20_370", "汚水処理状況　処理人口　農業集落排水"),
+    ("A20_371", "汚水処理状況　処理人口　漁業集落環境整備"),
+    ("A20_372", "汚水処理状況　処理人口　コミュニティ・プラント"),
+    ("A20_373", "汚水処理状況　処理人口　合併処理浄化槽"),
+    ("A20_374", "汚水処理状況　処理人口　その他"),
+    ("A20_375", "汚水処理状況　処理人口　計"),
+    ("A20_376", "汚水処理状況　処理人口普及率"),
+    ("A20_377", "汚水処理状況　備考"),
+    ("A20_378", "財政1　決算　基準財政需要額"),
+    ("A20_379", "財政1　決算　基準財政収入額"),
+    ("A20_380", "財政1　決算　財政力指数"),
+    ("A20_381", "財政1　現在　積立金現在高"),
+    ("A20_382", "財政1　現在　地方債現在高"),
+    ("A20_383", "財政1　歳入　地方税収入"),
+    ("A20_384", "財政1　歳入　地方交付税"),
+    ("A20_385", "財政1　歳入　国庫支出金"),
+    ("A20_386", "財政1　歳入　都道県支出金"),
+    ("A20_387", "財政1　歳入　地方債"),
+    ("A20_388", "財政1　歳入　その他"),
+    ("A20_389", "財政1　歳入　計"),
+    ("A20_390", "財政2　歳出　議会費"),
+    ("A20_391", "財政2　歳出　総務費"),
+    ("A20_392", "財政2　歳出　民生費"),
+    ("A20_393", "財政2　歳出　衛生費"),
+    ("A20_394", "財政2　歳出　労働費"),
+    ("A20_395", "財政2　歳出　農林水産費"),
+    ("A20_396", "財政2　歳出　商工費"),
+    ("A20_397", "財政2　歳出　土木費"),
+    ("A20_398", "財政2　歳出　消防費"),
+    ("A20_399", "財政2　歳出　教育費"),
+    ("A20_400", "財政2　歳出　災害復旧費"),
+    ("A20_401", "財政2　歳出　公債費"),
+    ("A20_402", "財政2　歳出　諸支出金"),
+    ("A20_403", "財政2　歳出　計"),
+    ("A20_404", "調査年度"),
+    ("A20_405", "就業者数　就業者総数　"),
+    ("A20_406", "就業者数　第1次　農業"),
+    ("A20_407", "就業者数　第1次　農業、林業"),
+    ("A20_408", "就業者数　第1次　農業、林業（うち農業）"),
+    ("A20_409", "就業者数　第1次　林業"),
+    ("A20_410", "就業者数　第1次　漁業"),
+    ("A20_411", "就業者数　第1次　小計"),
+    ("A20_412", "就業者数　第2次　鉱業"),
+    ("A20_413", "就業者数　第2次　鉱業，採石業，砂利採取業"),
+    ("A20_414", "就業者数　第2次　建設業"),
+    ("A20_415", "就業者数　第2次　製造業"),
+    ("A20_416", "就業者数　第2次　小計"),
+    ("A20_417", "就業者数　第3次　電気・ガス・熱供給・水道業"),
+    ("A20_418", "就業者数　第3次　運輸・通信業"),
+    ("A20_419", "就業者数　第3次　情報・通信業"),
+    ("A20_420", "就業者数　第3次　運輸業"),
+    ("A20_421", "就業者数　第3次　運輸業、郵便業"),
+    ("A20_422", "就業者数　第3次　卸売・小売業・飲食業"),
+    ("A20_423", "就業者数　第3次　卸売・小売業"),
+    ("A20_424", "就業者数　第3次　金融・保険業"),
+    ("A20_425", "就業者数　第3次　不動産業"),
+    ("A20_426", "就業者数　第3次　不動産業、物品賃貸業"),
+    ("A20_427", "就業者数　第3次　学術研究，専門・技術サービス業"),
+    ("A20_428", "就業者数　第3次　飲食店・宿泊業"),
+    ("A20_429", "就業者数　第3次　宿泊業，飲食サービス業　"),
+    ("A20_430", "就業者数　第3次　生活関連サービス業，娯楽業"),
+    ("A20_431", "就業者数　第3次　医療･福祉"),
+    ("A20_432", "就業者数　第3次　教育・学習支援業"),
+    ("A20_433", "就業者数　第3次　複合サービス事業"),
+    ("A20_434", "就業者数　第3次　サービス業"),
+    ("A20_435", "就業者数　第3次　公務"),
+    ("A20_436", "就業者数　第3次　小計"),
+    ("A20_437", "就業者数　分類不能　"),
+    ("A20_438", "就業者数　備考　"),
+    ("A20_439", "事業所数　総数　"),
+    ("A20_440", "事業所数　第1次　農業"),
+    ("A20_441", "事業所数　第1次　林業"),
+    ("A20_442", "事業所数　第1次　漁業"),
+    ("A20_443", "事業所数　第1次　小計"),
+    ("A20_444", "事業所数　第2次　鉱業"),
+    ("A20_445", "事業所数　第2次　建設業"),
+    ("A20_446", "事業所数　第2次　製造業"),
+    ("A20_447", "事業所数　第2次　小計"),
+    ("A20_448", "事業所数　第3次　電気・ガス・熱供給・水道業"),
+    ("A20_449", "事業所数　第3次　運輸・通信業"),
+    ("A20_450", "事業所数　第3次　卸売・小売業・飲食業"),
+    ("A20_451", "事業所数　第3次　金融・保険業"),
+    ("A20_452", "事業所数　第3次　不動産業"),
+    ("A20_453", "事業所数　第3次　サービス業"),
+    ("A20_454", "事業所数　第3次　公務"),
+    ("A20_455", "事業所数　第3次　小計"),
+    ("A20_456", "事業所数　分類不能　"),
+    ("A20_457", "従業者数　総数　"),
+    ("A20_458", "従業者数　第1次　農業"),
+    ("A20_459", "従業者数　第1次　林業"),
+    ("A20_460", "従業者数　第1次　漁業"),
+    ("A20_461", "従業者数　第1次　小計"),
+    ("A20_462", "従業者数　第2次　鉱業"),
+    ("A20_463", "従業者数　第2次　建設業"),
+    ("A20_464", "従業者数　第2次　製造業"),
+    ("A20_465", "従業者数　第2次　小計"),
+    ("A20_466", "従業者数　第3次　電気・ガス・熱供給・水道業"),
+    ("A20_467", "従業者数　第3次　運輸・通信業"),
+    ("A20_468", "従業者数　第3次　卸売・小売業・飲食業"),
+    ("A20_469", "従業者数　第3次　金融・保険業"),
+    ("A20_470", "従業者数　第3次　不動産業"),
+    ("A20_471", "従業者数　第3次　サービス業"),
+    ("A20_472", "従業者数　第3次　公務"),
+    ("A20_473", "従業者数　第3次　小計"),
+    ("A20_474", "従業者数　分類不能　"),
+    ("A20_475", "農林業　総面積　"),
+    ("A20_476", "農林業　農振面積　"),
+    ("A20_477", "農林業　耕地面積　総数"),
+    ("A20_478", "農林業　耕地面積　田"),
+    ("A20_479", "農林業　耕地面積　畑"),
+    ("A20_480", "農林業　耕地面積　樹園地"),
+    ("A20_481", "農林業　耕地面積　牧草地"),
+    ("A20_482", "農林業　耕地化率　"),
+    ("A20_483", "農林業　家畜飼育数　乳用牛"),
+    ("A20_484", "農林業　家畜飼育数　肉用牛"),
+    ("A20_485", "農林業　家畜飼育数　豚"),
+    ("A20_486", "農林業　家畜飼育数　鶏"),
+    ("A20_487", "農林業　農道延長　"),
+    ("A20_488", "農林業　林道延長　"),
+    ("A20_489", "農林業　林道延長　内国有林道"),
+    ("A20_490", "農林業　森林面積　総数　"),
+    ("A20_491", "農林業　森林面積　総数　うち人工林"),
+    ("A20_492", "農林業　森林面積　国有林　"),
+    ("A20_493", "農林業　森林面積　国有林　うち人工林"),
+    ("A20_494", "農林業　森林面積　民有林　"),
+    ("A20_495", "農林業　森林面積　民有林　うち人工林"),
+    ("A20_496", "農林業　森林蓄積量　"),
+    ("A20_497", "農林業　備考　"),
+    ("A20_498", "水産業現況_港湾　水揚高　属人水揚　計(水揚トン数)"),
+    ("A20_499", "水産業現況_港湾　水揚高　属人水揚　計(水揚金額)"),
+    ("A20_500", "水産業現況_港湾　水揚高　属人水揚　海面漁業(水揚トン数)"),
+    ("A20_501", "水産業現況_港湾　水揚高　属人水揚　海面漁業(水揚金額)"),
+    ("A20_502", "水産業現況_港湾　水揚高　属人水揚　海面養殖業(水揚トン数)"),
+    ("A20_503", "水産業現況_港湾　水揚高　属人水揚　海面養殖業(水揚金額)"),
+    ("A20_504", "水産業現況_港湾　水揚高　属地水揚　計(水揚トン数)"),
+    ("A20_505", "水産業現況_港湾　水揚高　属地水揚　計(水揚金額)"),
+    ("A20_506", "水産業現況_港湾　水揚高　属地水揚　海面漁業(水揚トン数)"),
+    ("A20_507", "水産業現況_港湾　水揚高　属地水揚　海面漁業(水揚金額)"),
+    ("A20_508", "水産業現況_港湾　水揚高　属地水揚　海面養殖業(水揚トン数)"),
+    ("A20_509", "水産業現況_港湾　水揚高　属地水揚　海面養殖業(水揚金額)"),
+    ("A20_510", "水産業現況_漁港　水揚高　属人水揚　計(水揚トン数)"),
+    ("A20_511", "水産業現況_漁港　水揚高　属人水揚　計(水揚金額)"),
+    ("A20_512", "水産業現況_漁港　水揚高　属人水揚　海面漁業(水揚トン数)"),
+    ("A20_513", "水産業現況_漁港　水揚高　属人水揚　海面漁業(水揚金額)"),
+    ("A20_514", "水産業現況_漁港　水揚高　属人水揚　海面養殖業(水揚トン数)"),
+    ("A20_515", "水産業現況_漁港　水揚高　属人水揚　海面養殖業(水揚金額)"),
+    ("A20_516", "水産業現況_漁港　水揚高　属地水揚　計(水揚トン数)"),
+    ("A20_517", "水産業現況_漁港　水揚高　属地水揚　計(水揚金額)"),
+    ("A20_518", "水産業現況_漁港　水揚高　属地水揚　海面漁業(水揚トン数)"),
+    ("A20_519", "水産業現況_漁港　水揚高　属地水揚　海面漁業(水揚金額)"),
+    ("A20_520", "水産業現況_漁港　水揚高　属地水揚　海面養殖業(水揚トン数)"),
+    ("A20_521", "水産業現況_漁港　水揚高　属地水揚　海面養殖業(水揚金額)"),
+    ("A20_522", "経営体　農家戸数　総数　　"),
+    ("A20_523", "経営体　農家戸数　販売農業　専業　"),
+    ("A20_524", "経営体　農家戸数　販売農業　兼業　1種"),
+    ("A20_525", "経営体　農家戸数　販売農業　兼業　2種"),
+    ("A20_526", "経営体　農家戸数　自給的農家　　"),
+    ("A20_527", "経営体　漁家戸数　総数　　"),
+    ("A20_528", "経営体　漁家戸数　専業　　"),
+    ("A20_529", "経営体　漁家戸数　兼業　1種　"),
+    ("A20_530", "経営体　漁家戸数　兼業　2種　"),
+    ("A20_531", "経営体　林家戸数　総数　　"),
+    ("A20_532", "経営体　林家戸数　農家林家　　"),
+    ("A20_533", "経営体　林家戸数　非農家林家　　"),
+    ("A20_534", "経営体　組合数　農業協同組合　組合数（本部）"),
+    ("A20_535", "経営体　組合数　農業協同組合　組合数（支部・支所）"),
+    ("A20_536", "経営体　組合数　農業協同組合　組合員数"),
+    ("A20_537", "経営体　組合数　漁業協同組合　組合数（本部）"),
+    ("A20_538", "経営体　組合数　漁業協同組合　組合数（支部・支所）"),
+    ("A20_539", "経営体　組合数　漁業協同組合　組合員数"),
+    ("A20_540", "経営体　組合数　森林組合　組合数（本部）"),
+    ("A20_541", "経営体　組合数　森林組合　組合数（支部・支所）"),
+    ("A20_542", "経営体　組合数　森林組合　組合員数"),
+    ("A20_543", "生産額　農業　耕種　米"),
+    ("A20_544", "生産額　農業　耕種　麦"),
+    ("A20_545", "生産額　農業　耕種　いも"),
+    ("A20_546", "生産額　農業　耕種　豆･雑穀"),
+    ("A20_547", "生産額　農業　耕種　野菜"),
+    ("A20_548", "生産額　農業　耕種　果実"),
+    ("A20_549", "生産額　農業　耕種　花卉"),
+    ("A20_550", "生産額　農業　耕種　工芸作物"),
+    ("A20_551", "生産額　農業　耕種　その他"),
+    ("A20_552", "生産額　農業　耕種　小計"),
+    ("A20_553", "生産額　農業　養蚕　"),
+    ("A20_554", "生産額　農業　畜産　牛肉"),
+    ("A20_555", "生産額　農業　畜産　豚肉"),
+    ("A20_556", "生産額　農業　畜産　牛乳"),
+    ("A20_557", "生産額　農業　畜産　鶏卵"),
+    ("A20_558", "生産額　農業　畜産　その他"),
+    ("A20_559", "生産額　農業　畜産　小計"),
+    ("A20_560", "生産額　農業　合計　"),
+    ("A20_561", "生産額　林業　木材　用材"),
+    ("A20_562", "生産額　林業　木材　薪炭材"),
+    ("A20_563", "生産額　林業　木炭　"),
+    ("A20_564", "生産額　林業　しいたけ　"),
+    ("A20_565", "生産額　林業　その他　"),
+    ("A20_566", "生産額　林業　合計　"),
+    ("A20_567", "生産額　水産業　魚類　"),
+    ("A20_568", "生産額　水産業　貝類　"),
+    ("A20_569", "生産額　水産業　水産動物　"),
+    ("A20_570", "生産額　水産業　海草類　"),
+    ("A20_571", "生産額　水産業　その他　"),
+    ("A20_572", "生産額　水産業　合計　"),
+    ("A20_573", "生産額　農林水産業総計　　"),
+    ("A20_574", "生産額　備考　　"),
+    ("A20_575", "観光客数　春　"),
+    ("A20_576", "観光客数　夏　"),
+    ("A20_577", "観光客数　秋　"),
+    ("A20_578", "観光客数　冬　"),
+    ("A20_579", "観光客数　計　"),
+    ("A20_580", "宿泊能力(最盛期)　旅館・ホテル　数"),
+    ("A20_581", "宿泊能力(最盛期)　旅館・ホテル　収容能力"),
+    ("A20_582", "宿泊能力(最盛期)　民宿　数"),
+    ("A20_583", "宿泊能力(最盛期)　民宿　収容能力"),
+    ("A20_584", "宿泊能力　年間宿泊者数　　"),
+    ("A20_585", "自然公園面積　国立公園　"),
+    ("A20_586", "自然公園面積　国定公園　"),
+    ("A20_587", "自然公園面積　県立公園　"),
+    ("A20_588", "観光客数・宿泊能力・自然公園　備考　　"),
+    ("A20_589", "港湾・航路現況_港湾　港湾利用状況　入港船舶　隻数"),
+    ("A20_590", "港湾・航路現況_港湾　総屯数"),
+    ("A20_591", "港湾・航路現況_港湾　乗降人員　"),
+    ("A20_592", "港湾・航路現況_港湾　海上出入貨物　出"),
+    ("A20_593", "港湾・航路現況_港湾　海上出入貨物　入"),
+    ("A20_594", "港湾・航路現況_港湾　海上出入貨物　計"),
+    ("A20_595", "港湾・航路現況_漁港　港湾利用状況　入港船舶　隻数"),
+    ("A20_596", "港湾・航路現況_漁港　総屯数"),
+    ("A20_597", "港湾・航路現況_漁港　乗降人員　"),
+    ("A20_598", "港湾・航路現況_漁港　海上出入貨物　出"),
+    ("A20_599", "港湾・航路現況_漁港　海上出入貨物　入"),
+    ("A20_600", "港湾・航路現況_漁港　海上出入貨物　計"),
+    ("A20_601", "空港・航空路現況　都道県名　空港名　　"),
+    ("A20_602", "空港・航空路現況　運航実績"),
+    ("A20_603", "空港・航空路現況　　1日当たり運航回数　最盛期"),
+    ("A20_604", "空港・航空路現況　　年間乗降人員　"),
+  ]),
+
+  ("A21", &[
+    ("A21_001", "小笠原ID"),
+    ("A21_002", "行政コード"),
+    ("A21_003", "都道府県名"),
+    ("A21_004", "支庁名"),
+    ("A21_005", "市区町村名"),
+    ("A21_006", "島嶼名"),
+    ("A21_007", "指定日"),
+  ]),
+
+  ("A21s", &[
+    ("A21_001", "小笠原諸島ID"),
+    ("A21_002", "行政コード"),
+    ("A21_003", "都道府県名"),
+    ("A21_004", "支庁名"),
+    ("A21_005", "市区町村名"),
+    ("A21_006", "島嶼名"),
+    ("A21_007", "指定状況コード"),
+    ("A21_008", "調査年度"),
+    ("A21_009", "概要　(name)"),
+    ("A21_010", "概要　指定回次"),
+    ("A21_011", "概要　郡名"),
+    ("A21_012", "概要　人口"),
+    ("A21_013", "概要　世帯数"),
+    ("A21_014", "概要　面積"),
+    ("A21_015", "概要　海岸延長"),
+    ("A21_016", "概要　備考"),
+    ("A21_017", "前年　住民登録人口　男"),
+    ("A21_018", "前年　住民登録人口　女"),
+    ("A21_019", "前年　住民登録人口　計"),
+    ("A21_020", "前年　世帯数　"),
+    ("A21_021", "人口移動　出生数　"),
+    ("A21_022", "人口移動　死亡数　"),
+    ("A21_023", "人口移動　自然増△減　"),
+    ("A21_024", "人口移動　転入数　"),
+    ("A21_025", "人口移動　転出数　"),
+    ("A21_026", "人口移動　社会増△減　"),
+    ("A21_027", "人口移動　増減計　"),
+    ("A21_028", "現年　住民登録人口　男"),
+    ("A21_029", "現年　住民登録人口　女"),
+    ("A21_030", "現年　住民登録人口　計"),
+    ("A21_031", "現年　世帯数　"),
+    ("A21_032", "人口・世帯数・人口動態　備考"),
+    ("A21_033", "男　国勢調査人口総数"),
+    ("A21_034", "男　年少人口　0-4"),
+    ("A21_035", "男　年少人口　5-9"),
+    ("A21_036", "男　年少人口　10-14"),
+    ("A21_037", "男　年少人口　小計"),
+    ("A21_038", "男　生産年齢人口　15-19"),
+    ("A21_039", "男　生産年齢人口　20-24"),
+    ("A21_040", "男　生産年齢人口　25-29"),
+    ("A21_041", "男　生産年齢人口　30-34"),
+    ("A21_042", "男　生産年齢人口　35-39"),
+    ("A21_043", "男　生産年齢人口　40-44"),
+    ("A21_044", "男　生産年齢人口　45-49"),
+    ("A21_045", "男　生産年齢人口　50-54"),
+    ("A21_046", "男　生産年齢人口　55-59"),
+    ("A21_047", "男　生産年齢人口　60-64"),
+    ("A21_048", "男　生産年齢人口　小計"),
+    ("A21_049", "男　老年人口　65-69"),
+    ("A21_050", "男　老年人口　70-74"),
+    ("A21_051", "男　老年人口　75-79"),
+    ("A21_052", "男　老年人口　80-84"),
+    ("A21_053", "男　老年人口　85歳以上"),
+    ("A21_054", "男　老年人口　小計"),
+    ("A21_055", "男　不詳　"),
+    ("A21_056", "男　生産年齢人口指数　"),
+    ("A21_057", "男　老齢化率　"),
+    ("A21_058", "女　国勢調査人口総数　"),
+    ("A21_059", "女　年少人口　0-4"),
+    ("A21_060", "女　年少人口　5-9"),
+    ("A21_061", "女　年少人口　10-14"),
+    ("A21_062", "女　年少人口　小計"),
+    ("A21_063", "女　生産年齢人口　15-19"),
+    ("A21_064", "女　生産年齢人口　20-24"),
+    ("A21_065", "女　生産年齢人口　25-29"),
+    ("A21_066", "女　生産年齢人口　30-34"),
+    ("A21_067", "女　生産年齢人口　35-39"),
+    ("A21_068", "女　生産年齢人口　40-44"),
+    ("A21_069", "女　生産年齢人口　45-49"),
+    ("A21_070", "女　生産年齢人口　50-54"),
+    ("A21_071", "女　生産年齢人口　55-59"),
+    ("A21_072", "女　生産年齢人口　60-64"),
+    ("A21_073", "女　生産年齢人口　小計"),
+    ("A21_074", "女　老年人口　65-69"),
+    ("A21_075", "女　老年人口　70-74"),
+    ("A21_076", "女　老年人口　75-79"),
+    ("A21_077", "女　老年人口　80-84"),
+    ("A21_078", "女　老年人口　85歳以上"),
+    ("A21_079", "女　老年人口　小計"),
+    ("A21_080", "女　不詳　"),
+    ("A21_081", "女　生産年齢人口指数　"),
+    ("A21_082", "女　老齢化率　"),
+    ("A21_083", "男女計　国勢調査人口総数　"),
+    ("A21_084", "男女計　年少人口　0-4"),
+    ("A21_085", "男女計　年少人口　5-9"),
+    ("A21_086", "男女計　年少人口　10-14"),
+    ("A21_087", "男女計　年少人口　小計"),
+    ("A21_088", "男女計　生産年齢人口　15-19"),
+    ("A21_089", "男女計　生産年齢人口　20-24"),
+    ("A21_090", "男女計　生産年齢人口　25-29"),
+    ("A21_091", "男女計　生産年齢人口　30-34"),
+    ("A21_092", "男女計　生産年齢人口　35-39"),
+    ("A21_093", "男女計　生産年齢人口　40-44"),
+    ("A21_094", "男女計　生産年齢人口　45-49"),
+    ("A21_095", "男女計　生産年齢人口　50-54"),
+    ("A21_096", "男女計　生産年齢人口　55-59"),
+    ("A21_097", "男女計　生産年齢人口　60-64"),
+    ("A21_098", "男女計　生産年齢人口　小計"),
+    ("A21_099", "男女計　老年人口　65-69"),
+    ("A21_100", "男女計　老年人口　70-74"),
+    ("A21_101", "男女計　老年人口　75-79"),
+    ("A21_102", "男女計　老年人口　80-84"),
+    ("A21_103", "男女計　老年人口　85歳以上"),
+    ("A21_104", "男女計　老年人口　小計"),
+    ("A21_105", "男女計　不詳　"),
+    ("A21_106", "男女計　生産年齢人口指数　"),
+    ("A21_107", "男女計　老齢化率　"),
+    ("A21_108", "年齢別･男女別人口　備考"),
+    ("A21_109", "調査年度"),
+    ("A21_110", "土地利用　総面積　"),
+    ("A21_111", "土地利用　農用地　農用地合計"),
+    ("A21_112", "土地利用　農用地　農地"),
+    ("A21_113", "土地利用　農用地　採草放牧地"),
+    ("A21_114", "土地利用　森林　"),
+    ("A21_115", "土地利用　原野　"),
+    ("A21_116", "土地利用　水面・河川・水路　"),
+    ("A21_117", "土地利用　道路　"),
+    ("A21_118", "土地利用　宅地　"),
+    ("A21_119", "土地利用　その他　"),
+    ("A21_120", "土地利用　備考　"),
+    ("A21_121", "学校　保　保育所数　本所"),
+    ("A21_122", "学校　保　保育所数　分所"),
+    ("A21_123", "学校　保　乳幼児数　在所"),
+    ("A21_124", "学校　保　乳幼児数　通所"),
+    ("A21_125", "学校　幼　幼稚園数　本園"),
+    ("A21_126", "学校　幼　幼稚園数　分園"),
+    ("A21_127", "学校　幼　園児数　在園"),
+    ("A21_128", "学校　幼　園児数　通園"),
+    ("A21_129", "学校　小　学校数　本校"),
+    ("A21_130", "学校　小　学校数　分校"),
+    ("A21_131", "学校　小　教（職）員数　教員数"),
+    ("A21_132", "学校　小　教（職）員数　職員数"),
+    ("A21_133", "学校　小　学級数"),
+    ("A21_134", "学校　小　児童数　在校"),
+    ("A21_135", "学校　小　児童数　通学"),
+    ("A21_136", "学校　中　学校数　本校"),
+    ("A21_137", "学校　中　学校数　分校"),
+    ("A21_138", "学校　中　教（職）員数　教員数"),
+    ("A21_139", "学校　中　教（職）員数　職員数"),
+    ("A21_140", "学校　中　学級数"),
+    ("A21_141", "学校　中　生徒数　在校"),
+    ("A21_142", "学校　中　生徒数　通学"),
+    ("A21_143", "学校　高　学校数　本校"),
+    ("A21_144", "学校　高　学校数　分校"),
+    ("A21_145", "学校　高　教（職）員数　教員数"),
+    ("A21_146", "学校　高　教（職）員数　職員数"),
+    ("A21_147", "学校　高　学級数"),
+    ("A21_148", "学校　高　生徒数　在校"),
+    ("A21_149", "学校　高　生徒数　通学"),
+    ("A21_150", "学校　備考"),
+    ("A21_151", "卒業者状況　中　卒業生数　　"),
+    ("A21_152", "卒業者状況　中　就職　総数　"),
+    ("A21_153", "卒業者状況　中　就職　島内　計"),
+    ("A21_154", "卒業者状況　中　就職　島内　農林業"),
+    ("A21_155", "卒業者状況　中　就職　島内　水産業"),
+    ("A21_156", "卒業者状況　中　就職　島内　2次産業"),
+    ("A21_157", "卒業者状況　中　就職　島内　3次産業"),
+    ("A21_158", "卒業者状況　中　就職　島外　計"),
+    ("A21_159", "卒業者状況　中　就職　島外　1次産業"),
+    ("A21_160", "卒業者状況　中　就職　島外　2次産業"),
+    ("A21_161", "卒業者状況　中　就職　島外　3次産業"),
+    ("A21_162", "卒業者状況　中　進学　総数　"),
+    ("A21_163", "卒業者状況　中　進学　島内　"),
+    ("A21_164", "卒業者状況　中　進学　島外　"),
+    ("A21_165", "卒業者状況　中　進学　進学率　"),
+    ("A21_166", "卒業者状況　中　未就職・その他　島内　"),
+    ("A21_167", "卒業者状況　中　未就職・その他　島外　"),
+    ("A21_168", "卒業者状況　高　卒業生数　　"),
+    ("A21_169", "卒業者状況　高　就職　総数　"),
+    ("A21_170", "卒業者状況　高　就職　島内　計"),
+    ("A21_171", "卒業者状況　高　就職　島内　農林業"),
+    ("A21_172", "卒業者状況　高　就職　島内　水産業"),
+    ("A21_173", "卒業者状況　高　就職　島内　2次産業"),
+    ("A21_174", "卒業者状況　高　就職　島内　3次産業"),
+    ("A21_175", "卒業者状況　高　就職　島外　計"),
+    ("A21_176", "卒業者状況　高　就職　島外　1次産業"),
+    ("A21_177", "卒業者状況　高　就職　島外　2次産業"),
+    ("A21_178", "卒業者状況　高　就職　島外　3次産業"),
+    ("A21_179", "卒業者状況　高　進学　総数　"),
+    ("A21_180", "卒業者状況　高　進学　島内　"),
+    ("A21_181", "卒業者状況　高　進学　島外　"),
+    ("A21_182", "卒業者状況　高　進学　進学率　"),
+    ("A21_183", "卒業者状況　高　未就職・その他　島内　"),
+    ("A21_184", "卒業者状況　高　未就職・その他　島外　"),
+    ("A21_185", "公園等　国調人口　　　"),
+    ("A21_186", "公園等　離島振興関係一括事業　都市公園　都市計画公園　カ所数"),
+    ("A21_187", "公園等　離島振興関係一括事業　都市公園　都市計画公園　面積"),
+    ("A21_188", "公園等　離島振興関係一括事業　都市公園　特定地区公園　カ所数"),
+    ("A21_189", "公園等　離島振興関係一括事業　都市公園　特定地区公園　面積"),
+    ("A21_190", "公園等　離島振興関係一括事業　都市公園　計　カ所数"),
+    ("A21_191", "公園等　離島振興関係一括事業　都市公園　計　面積"),
+    ("A21_192", "公園等　離島振興関係一括事業　港湾環境整備緑地　カ所数　"),
+    ("A21_193", "公園等　離島振興関係一括事業　港湾環境整備緑地　面積　"),
+    ("A21_194", "公園等　離島振興関係一括事業　漁港漁村環境整備　カ所数　"),
+    ("A21_195", "公園等　離島振興関係一括事業　漁港漁村環境整備　面積　"),
+    ("A21_196", "公園等　離島振興関係一括事業　海岸環境整備　カ所数　"),
+    ("A21_197", "公園等　離島振興関係一括事業　海岸環境整備　面積　"),
+    ("A21_198", "公園等　離島振興関係一括事業　農業集落環境整備　カ所数　"),
+    ("A21_199", "公園等　離島振興関係一括事業　農業集落環境整備　面積　"),
+    ("A21_200", "公園等　離島振興関係一括事業　コミュニティ・アイランド　カ所数　"),
+    ("A21_201", "公園等　離島振興関係一括事業　コミュニティ・アイランド　面積　"),
+    ("A21_202", "公園等　離島振興関係一括事業　自然公園施設設備（園地等）　カ所数　"),
+    ("A21_203", "公園等　離島振興関係一括事業　自然公園施設設備（園地等）　面積　"),
+    ("A21_204", "公園等　離島振興関係一括事業　その他　カ所数　"),
+    ("A21_205", "公園等　離島振興関係一括事業　その他　面積　"),
+    ("A21_206", "公園等　離島振興関係一括事業　小計　カ所数　"),
+    ("A21_207", "公園等　離島振興関係一括事業　小計　面積　"),
+    ("A21_208", "公園等　地方公共団体単独事業　カ所数　　"),
+    ("A21_209", "公園等　地方公共団体単独事業　面積　　"),
+    ("A21_210", "公園等　合計　カ所数　　"),
+    ("A21_211", "公園等　合計　面積　　"),
+    ("A21_212", "公園等　一人当たり公園・緑地面積　　　"),
+    ("A21_213", "道路　国道　総延長"),
+    ("A21_214", "道路　国道　重用延長"),
+    ("A21_215", "道路　国道　未供用延長"),
+    ("A21_216", "道路　国道　実延長"),
+    ("A21_217", "道路　国道　実延長　規格改良済延長"),
+    ("A21_218", "道路　国道　実延長　規格改良済延長　内車道幅員55m以上"),
+    ("A21_219", "道路　国道　実延長　未改良延長"),
+    ("A21_220", "道路　国道　実延長　未改良延長　内自動車交通不能"),
+    ("A21_221", "道路　国道　実延長　舗装済延長"),
+    ("A21_222", "道路　国道　実延長　舗装済延長　内本舗装延長"),
+    ("A21_223", "道路　国道　実延長　未舗装延長"),
+    ("A21_224", "道路　国道　歩道設置道路実延長"),
+    ("A21_225", "道路　国道　道路面積　車道面積"),
+    ("A21_226", "道路　国道　道路面積　道路部面積"),
+    ("A21_227", "道路　国道　道路面積　道路敷面積"),
+    ("A21_228", "道路　主要地方道　総延長"),
+    ("A21_229", "道路　主要地方道　重用延長"),
+    ("A21_230", "道路　主要地方道　未供用延長"),
+    ("A21_231", "道路　主要地方道　実延長"),
+    ("A21_232", "道路　主要地方道　実延長　規格改良済延長"),
+    ("A21_233", "道路　主要地方道　実延長　規格改良済延長　内車道幅員55m以上"),
+    ("A21_234", "道路　主要地方道　実延長　未改良延長"),
+    ("A21_235", "道路　主要地方道　実延長　未改良延長　内自動車交通不能"),
+    ("A21_236", "道路　主要地方道　実延長　舗装済延長"),
+    ("A21_237", "道路　主要地方道　実延長　舗装済延長　内本舗装延長"),
+    ("A21_238", "道路　主要地方道　実延長　未舗装延長"),
+    ("A21_239", "道路　主要地方道　歩道設置道路実延長"),
+    ("A21_240", "道路　主要地方道　道路面積　車道面積"),
+    ("A21_241", "道路　主要地方道　道路面積　道路部面積"),
+    ("A21_242", "道路　主要地方道　道路面積　道路敷面積"),
+    ("A21_243", "道路　都道県道　総延長"),
+    ("A21_244", "道路　都道県道　重用延長"),
+    ("A21_245", "道路　都道県道　未供用延長"),
+    ("A21_246", "道路　都道県道　実延長"),
+    ("A21_247", "道路　都道県道　実延長　規格改良済延長"),
+    ("A21_248", "道路　都道県道　実延長　規格改良済延長　内車道幅員55m以上"),
+    ("A21_249", "道路　都道県道　実延長　未改良延長"),
+    ("A21_250", "道路　都道県道　実延長　未改良延長　内自動車交通不能"),
+    ("A21_251", "道路　都道県道　実延長　舗装済延長"),
+    ("A21_252", "道路　都道県道　実延長　舗装済延長　内本舗装延長"),
+    ("A21_253", "道路　都道県道　実延長　未舗装延長"),
+    ("A21_254", "道路　都道県道　歩道設置道路実延長"),
+    ("A21_255", "道路　都道県道　道路面積　車道面積"),
+    ("A21_256", "道路　都道県道　道路面積　道路部面積"),
+    ("A21_257", "道路　都道県道　道路面積　道路敷面積"),
+    ("A21_258", "道路　市町村道　総延長"),
+    ("A21_259", "道路　市町村道　重用延長"),
+    ("A21_260", "道路　市町村道　未供用延長"),
+    ("A21_261", "道路　市町村道　実延長"),
+    ("A21_262", "道路　市町村道　実延長　規格改良済延長"),
+    ("A21_263", "道路　市町村道　実延長　規格改良済延長　内車道幅員55m以上"),
+    ("A21_264", "道路　市町村道　実延長　未改良延長"),
+    ("A21_265", "道路　市町村道　実延長　未改良延長　内自動車交通不能"),
+    ("A21_266", "道路　市町村道　実延長　舗装済延長"),
+    ("A21_267", "道路　市町村道　実延長　舗装済延長　内本舗装延長"),
+    ("A21_268", "道路　市町村道　実延長　未舗装延長"),
+    ("A21_269", "道路　市町村道　歩道設置道路実延長"),
+    ("A21_270", "道路　市町村道　道路面積　車道面積"),
+    ("A21_271", "道路　市町村道　道路面積　道路部面積"),
+    ("A21_272", "道路　市町村道　道路面積　道路敷面積"),
+    ("A21_273", "道路　備考"),
+    ("A21_274", "調査年度"),
+    ("A21_275", "車輌保有台数　保有車両合計"),
+    ("A21_276", "車輌保有台数　登録車両　小計"),
+    ("A21_277", "車輌保有台数　登録車両　トラック　計"),
+    ("A21_278", "車輌保有台数　登録車両　トラック　普通車"),
+    ("A21_279", "車輌保有台数　登録車両　トラック　小型車"),
+    ("A21_280", "車輌保有台数　登録車両　トラック　トレーラー"),
+    ("A21_281", "車輌保有台数　登録車両　バス　"),
+    ("A21_282", "車輌保有台数　登録車両　乗用車　計"),
+    ("A21_283", "車輌保有台数　登録車両　乗用車　普通車"),
+    ("A21_284", "車輌保有台数　登録車両　乗用車　小型車"),
+    ("A21_285", "車輌保有台数　登録車両　特種用途車　"),
+    ("A21_286", "車輌保有台数　登録車両　大型特殊車　"),
+    ("A21_287", "車輌保有台数　軽自動車"),
+    ("A21_288", "車輌保有台数　二輪車"),
+    ("A21_289", "車輌保有台数　原動機付自転車"),
+    ("A21_290", "車輌保有台数　小型特殊車"),
+    ("A21_291", "車輌保有台数　備考"),
+    ("A21_292", "医療　住民登録人口"),
+    ("A21_293", "医療　施設　総数"),
+    ("A21_294", "医療　施設　病院"),
+    ("A21_295", "医療　施設　診療所"),
+    ("A21_296", "医療　施設　診療所　有床診療所"),
+    ("A21_297", "医療　施設　歯科診療所"),
+    ("A21_298", "医療　施設　一般診療所の歯科診療科目"),
+    ("A21_299", "医療　施設　開設者別　国"),
+    ("A21_300", "医療　施設　開設者別　都道県"),
+    ("A21_301", "医療　施設　開設者別　市町村"),
+    ("A21_302", "医療　施設　開設者別　個人"),
+    ("A21_303", "医療　施設　開設者別　その他"),
+    ("A21_304", "医療　病床数"),
+    ("A21_305", "医療　従事者　医師　常勤"),
+    ("A21_306", "医療　従事者　医師　非常勤"),
+    ("A21_307", "医療　従事者　歯科医師　常勤"),
+    ("A21_308", "医療　従事者　歯科医師　非常勤"),
+    ("A21_309", "医療　従事者　保健師　医療施設勤務"),
+    ("A21_310", "医療　従事者　保健師　医療施設外勤務"),
+    ("A21_311", "医療　従事者　助産師　医療施設勤務"),
+    ("A21_312", "医療　従事者　助産師　医療施設外勤務"),
+    ("A21_313", "医療　従事者　看護師　医療施設勤務"),
+    ("A21_314", "医療　従事者　看護師　医療施設外勤務"),
+    ("A21_315", "医療　従事者　歯科衛生士　医療施設勤務"),
+    ("A21_316", "医療　従事者　歯科衛生士　医療施設外勤務"),
+    ("A21_317", "医療　搬送設備等　巡回診療車"),
+    ("A21_318", "医療　搬送設備等　巡回診療船"),
+    ("A21_319", "医療　搬送設備等　患者輸送車"),
+    ("A21_320", "医療　搬送設備等　患者輸送船"),
+    ("A21_321", "医療　備考"),
+    ("A21_322", "水道　住民登録人口"),
+    ("A21_323", "水道　利用人口　上水道"),
+    ("A21_324", "水道　利用人口　専用水道"),
+    ("A21_325", "水道　利用人口　簡易水道"),
+    ("A21_326", "水道　利用人口　計"),
+    ("A21_327", "水道　普及率"),
+    ("A21_328", "水道　その他飲料水利用人口　井戸"),
+    ("A21_329", "水道　その他飲料水利用人口　流湧水"),
+    ("A21_330", "水道　その他飲料水利用人口　天水"),
+    ("A21_331", "水道　その他飲料水利用人口　計"),
+    ("A21_332", "水道　備考"),
+    ("A21_333", "し尿処理状況　住民登録人口"),
+    ("A21_334", "し尿処理状況　計画処理区域内人口　水洗化人口"),
+    ("A21_335", "し尿処理状況　計画処理区域内人口　非水洗化人口　収集人口"),
+    ("A21_336", "し尿処理状況　計画処理区域内人口　非水洗化人口　自家処理人口"),
+    ("A21_337", "し尿処理状況　計画処理区域内人口　計"),
+    ("A21_338", "し尿処理状況　計画処理区域内人口　計画収集率"),
+    ("A21_339", "し尿処理状況　区域内人口比率"),
+    ("A21_340", "し尿処理状況　収集し尿処理内訳　施設処理"),
+    ("A21_341", "し尿処理状況　収集し尿処理内訳　埋立て"),
+    ("A21_342", "し尿処理状況　収集し尿処理内訳　海洋投入"),
+    ("A21_343", "し尿処理状況　収集し尿処理内訳　その他"),
+    ("A21_344", "し尿処理状況　収集し尿処理内訳　計"),
+    ("A21_345", "し尿処理状況　収集し尿処理内訳　施設処理率"),
+    ("A21_346", "し尿処理状況　備考"),
+    ("A21_347", "ごみ処理状況　住民登録人口"),
+    ("A21_348", "ごみ処理状況　計画処理区域内人口　計画収集人口"),
+    ("A21_349", "ごみ処理状況　計画処理区域内人口　自家処理人口"),
+    ("A21_350", "ごみ処理状況　計画処理区域内人口　計"),
+    ("A21_351", "ごみ処理状況　計画処理区域内人口　計画収集率"),
+    ("A21_352", "ごみ処理状況　区域内人口比率"),
+    ("A21_353", "ごみ処理状況　収集ごみ処理内訳　施設処理"),
+    ("A21_354", "ごみ処理状況　収集ごみ処理内訳　埋立て"),
+    ("A21_355", "ごみ処理状況　収集ごみ処理内訳　その他"),
+    ("A21_356", "ごみ処理状況　収集ごみ処理内訳　計"),
+    ("A21_357", "ごみ処理状況　収集ごみ処理内訳　施設処理率"),
+    ("A21_358", "ごみ処理状況　備考"),
+    ("A21_359", "汚水処理状況　住民登録人口"),
+    ("A21_360", "汚水処理状況　計画処理区域内人口　公共・流域下水道"),
+    ("A21_361", "汚水処理状況　計画処理区域内人口　農業集落排水"),
+    ("A21_362", "汚水処理状況　計画処理区域内人口　漁業集落環境整備"),
+    ("A21_363", "汚水処理状況　計画処理区域内人口　コミュニティ・プラント"),
+    ("A21_364", "汚水処理状況　計画処理区域内人口　合併処理浄化槽"),
+    ("A21_365", "汚水処理状況　計画処理区域内人口　その他"),
+    ("A21_366", "汚水処理状況　計画処理区域内人口　計"),
+    ("A21_367", "汚水処理状況　区域内人口比率"),
+    ("A21_368", "汚水処理状況　処理人口　公共・流域下水道"),
+    ("A21_369", "汚水処理状況　処理人口　農業集落排水"),
+    ("A21_370", "汚水処理状況　処理人口　漁業集落環境整備"),
+    ("A21_371", "汚水処理状況　処理人口　コミュニティ・プラント"),
+    ("A21_372", "汚水処理状況　処理人口　合併処理浄化槽"),
+    ("A21_373", "汚水処理状況　処理人口　その他"),
+    ("A21_374", "汚水処理状況　処理人口　計"),
+    ("A21_375", "汚水処理状況　処理人口普及率"),
+    ("A21_376", "汚水処理状況　備考"),
+    ("A21_377", "財政1　決算　基準財政需要額"),
+    ("A21_378", "財政1　決算　基準財政収入額"),
+    ("A21_379", "財政1　決算　財政力指数"),
+    ("A21_380", "財政1　現在　積立金現在高"),
+    ("A21_381", "財政1　現在　地方債現在高"),
+    ("A21_382", "財政1　歳入　地方税収入"),
+    ("A21_383", "財政1　歳入　地方交付税"),
+    ("A21_384", "財政1　歳入　国庫支出金"),
+    ("A21_385", "財政1　歳入　都道県支出金"),
+    ("A21_386", "財政1　歳入　地方債"),
+    ("A21_387", "財政1　歳入　その他"),
+    ("A21_388", "財政1　歳入　計"),
+    ("A21_389", "財政2　歳出　議会費"),
+    ("A21_390", "財政2　歳出　総務費"),
+    ("A21_391", "財政2　歳出　民生費"),
+    ("A21_392", "財政2　歳出　衛生費"),
+    ("A21_393", "財政2　歳出　労働費"),
+    ("A21_394", "財政2　歳出　農林水産費"),
+    ("A21_395", "財政2　歳出　商工費"),
+    ("A21_396", "財政2　歳出　土木費"),
+    ("A21_397", "財政2　歳出　消防費"),
+    ("A21_398", "財政2　歳出　教育費"),
+    ("A21_399", "財政2　歳出　災害復旧費"),
+    ("A21_400", "財政2　歳出　公債費"),
+    ("A21_401", "財政2　歳出　諸支出金"),
+    ("A21_402", "財政2　歳出　計"),
+    ("A21_403", "調査年度"),
+    ("A21_404", "就業者数　就業者総数　"),
+    ("A21_405", "就業者数　第1次　農業"),
+    ("A21_406", "就業者数　第1次　農業、林業"),
+    ("A21_407", "就業者数　第1次　農業、林業（うち農業）"),
+    ("A21_408", "就業者数　第1次　林業"),
+    ("A21_409", "就業者数　第1次　漁業"),
+    ("A21_410", "就業者数　第1次　小計"),
+    ("A21_411", "就業者数　第2次　鉱業"),
+    ("A21_412", "就業者数　第2次　鉱業，採石業，砂利採取業"),
+    ("A21_413", "就業者数　第2次　建設業"),
+    ("A21_414", "就業者数　第2次　製造業"),
+    ("A21_415", "就業者数　第2次　小計"),
+    ("A21_416", "就業者数　第3次　電気・ガス・熱供給・水道業"),
+    ("A21_417", "就業者数　第3次　運輸・通信業"),
+    ("A21_418", "就業者数　第3次　情報・通信業"),
+    ("A21_419", "就業者数　第3次　運輸業"),
+    ("A21_420", "就業者数　第3次　運輸業、郵便業"),
+    ("A21_421", "就業者数　第3次　卸売・小売業・飲食業"),
+    ("A21_422", "就業者数　第3次　卸売・小売業"),
+    ("A21_423", "就業者数　第3次　金融・保険業"),
+    ("A21_424", "就業者数　第3次　不動産業"),
+    ("A21_425", "就業者数　第3次　不動産業、物品賃貸業"),
+    ("A21_426", "就業者数　第3次　学術研究，専門・技術サービス業"),
+    ("A21_427", "就業者数　第3次　飲食店・宿泊業"),
+    ("A21_428", "就業者数　第3次　宿泊業，飲食サービス業　"),
+    ("A21_429", "就業者数　第3次　生活関連サービス業，娯楽業"),
+    ("A21_430", "就業者数　第3次　医療･福祉"),
+    ("A21_431", "就業者数　第3次　教育・学習支援業"),
+    ("A21_432", "就業者数　第3次　複合サービス事業"),
+    ("A21_433", "就業者数　第3次　サービス業"),
+    ("A21_434", "就業者数　第3次　公務"),
+    ("A21_435", "就業者数　第3次　小計"),
+    ("A21_436", "就業者数　分類不能　"),
+    ("A21_437", "就業者数　備考　"),
+    ("A21_438", "事業所数　総数　"),
+    ("A21_439", "事業所数　第1次　農業"),
+    ("A21_440", "事業所数　第1次　林業"),
+    ("A21_441", "事業所数　第1次　漁業"),
+    ("A21_442", "事業所数　第1次　小計"),
+    ("A21_443", "事業所数　第2次　鉱業"),
+    ("A21_444", "事業所数　第2次　建設業"),
+    ("A21_445", "事業所数　第2次　製造業"),
+    ("A21_446", "事業所数　第2次　小計"),
+    ("A21_447", "事業所数　第3次　電気・ガス・熱供給・水道業"),
+    ("A21_448", "事業所数　第3次　運輸・通信業"),
+    ("A21_449", "事業所数　第3次　卸売・小売業・飲食業"),
+    ("A21_450", "事業所数　第3次　金融・保険業"),
+    ("A21_451", "事業所数　第3次　不動産業"),
+    ("A21_452", "事業所数　第3次　サービス業"),
+    ("A21_453", "事業所数　第3次　公務"),
+    ("A21_454", "事業所数　第3次　小計"),
+    ("A21_455", "事業所数　分類不能　"),
+    ("A21_456", "従業者数　総数　"),
+    ("A21_457", "従業者数　第1次　農業"),
+    ("A21_458", "従業者数　第1次　林業"),
+    ("A21_459", "従業者数　第1次　漁業"),
+    ("A21_460", "従業者数　第1次　小計"),
+    ("A21_461", "従業者数　第2次　鉱業"),
+    ("A21_462", "従業者数　第2次　建設業"),
+    ("A21_463", "従業者数　第2次　製造業"),
+    ("A21_464", "従業者数　第2次　小計"),
+    ("A21_465", "従業者数　第3次　電気・ガス・熱供給・水道業"),
+    ("A21_466", "従業者数　第3次　運輸・通信業"),
+    ("A21_467", "従業者数　第3次　卸売・小売業・飲食業"),
+    ("A21_468", "従業者数　第3次　金融・保険業"),
+    ("A21_469", "従業者数　第3次　不動産業"),
+    ("A21_470", "従業者数　第3次　サービス業"),
+    ("A21_471", "従業者数　第3次　公務"),
+    ("A21_472", "従業者数　第3次　小計"),
+    ("A21_473", "従業者数　分類不能　"),
+    ("A21_474", "農林業　総面積　"),
+    ("A21_475", "農林業　農振面積　"),
+    ("A21_476", "農林業　耕地面積　総数"),
+    ("A21_477", "農林業　耕地面積　田"),
+    ("A21_478", "農林業　耕地面積　畑"),
+    ("A21_479", "農林業　耕地面積　樹園地"),
+    ("A21_480", "農林業　耕地面積　牧草地"),
+    ("A21_481", "農林業　耕地化率　"),
+    ("A21_482", "農林業　家畜飼育数　乳用牛"),
+    ("A21_483", "農林業　家畜飼育数　肉用牛"),
+    ("A21_484", "農林業　家畜飼育数　豚"),
+    ("A21_485", "農林業　家畜飼育数　鶏"),
+    ("A21_486", "農林業　農道延長　"),
+    ("A21_487", "農林業　林道延長　"),
+    ("A21_488", "農林業　林道延長　内国有林道"),
+    ("A21_489", "農林業　森林面積　総数　"),
+    ("A21_490", "農林業　森林面積　総数　うち人工林"),
+    ("A21_491", "農林業　森林面積　国有林　"),
+    ("A21_492", "農林業　森林面積　国有林　うち人工林"),
+    ("A21_493", "農林業　森林面積　民有林　"),
+    ("A21_494", "農林業　森林面積　民有林　うち人工林"),
+    ("A21_495", "農林業　森林蓄積量　"),
+    ("A21_496", "農林業　備考　"),
+    ("A21_497", "水産業現況_港湾　水揚高　属人水揚　計(水揚トン数)"),
+    ("A21_498", "水産業現況_港湾　水揚高　属人水揚　計(水揚金額)"),
+    ("A21_499", "水産業現況_港湾　水揚高　属人水揚　海面漁業(水揚トン数)"),
+    ("A21_500", "水産業現況_港湾　水揚高　属人水揚　海面漁業(水揚金額)"),
+    ("A21_501", "水産業現況_港湾　水揚高　属人水揚　海面養殖業(水揚トン数)"),
+    ("A21_502", "水産業現況_港湾　水揚高　属人水揚　海面養殖業(水揚金額)"),
+    ("A21_503", "水産業現況_港湾　水揚高　属地水揚　計(水揚トン数)"),
+    ("A21_504", "水産業現況_港湾　水揚高　属地水揚　計(水揚金額)"),
+    ("A21_505", "水産業現況_港湾　水揚高　属地水揚　海面漁業(水揚トン数)"),
+    ("A21_506", "水産業現況_港湾　水揚高　属地水揚　海面漁業(水揚金額)"),
+    ("A21_507", "水産業現況_港湾　水揚高　属地水揚　海面養殖業(水揚トン数)"),
+    ("A21_508", "水産業現況_港湾　水揚高　属地水揚　海面養殖業(水揚金額)"),
+    ("A21_509", "水産業現況_漁港　水揚高　属人水揚　計(水揚トン数)"),
+    ("A21_510", "水産業現況_漁港　水揚高　属人水揚　計(水揚金額)"),
+    ("A21_511", "水産業現況_漁港　水揚高　属人水揚　海面漁業(水揚トン数)"),
+    ("A21_512", "水産業現況_漁港　水揚高　属人水揚　海面漁業(水揚金額)"),
+    ("A21_513", "水産業現況_漁港　水揚高　属人水揚　海面養殖業(水揚トン数)"),
+    ("A21_514", "水産業現況_漁港　水揚高　属人水揚　海面養殖業(水揚金額)"),
+    ("A21_515", "水産業現況_漁港　水揚高　属地水揚　計(水揚トン数)"),
+    ("A21_516", "水産業現況_漁港　水揚高　属地水揚　計(水揚金額)"),
+    ("A21_517", "水産業現況_漁港　水揚高　属地水揚　海面漁業(水揚トン数)"),
+    ("A21_518", "水産業現況_漁港　水揚高　属地水揚　海面漁業(水揚金額)"),
+    ("A21_519", "水産業現況_漁港　水揚高　属地水揚　海面養殖業(水揚トン数)"),
+    ("A21_520", "水産業現況_漁港　水揚高　属地水揚　海面養殖業(水揚金額)"),
+    ("A21_521", "経営体　農家戸数　総数　　"),
+    ("A21_522", "経営体　農家戸数　販売農業　専業　"),
+    ("A21_523", "経営体　農家戸数　販売農業　兼業　1種"),
+    ("A21_524", "経営体　農家戸数　販売農業　兼業　2種"),
+    ("A21_525", "経営体　農家戸数　自給的農家　　"),
+    ("A21_526", "経営体　漁家戸数　総数　　"),
+    ("A21_527", "経営体　漁家戸数　専業　　"),
+    ("A21_528", "経営体　漁家戸数　兼業　1種　"),
+    ("A21_529", "経営体　漁家戸数　兼業　2種　"),
+    ("A21_530", "経営体　林家戸数　総数　　"),
+    ("A21_531", "経営体　林家戸数　農家林家　　"),
+    ("A21_532", "経営体　林家戸数　非農家林家　　"),
+    ("A21_533", "経営体　組合数　農業協同組合　組合数（本部）"),
+    ("A21_534", "経営体　組合数　農業協同組合　組合数（支部・支所）"),
+    ("A21_535", "経営体　組合数　農業協同組合　組合員数"),
+    ("A21_536", "経営体　組合数　漁業協同組合　組合数（本部）"),
+    ("A21_537", "経営体　組合数　漁業協同組合　組合数（支部・支所）"),
+    ("A21_538", "経営体　組合数　漁業協同組合　組合員数"),
+    ("A21_539", "経営体　組合数　森林組合　組合数（本部）"),
+    ("A21_540", "経営体　組合数　森林組合　組合数（支部・支所）"),
+    ("A21_541", "経営体　組合数　森林組合　組合員数"),
+    ("A21_542", "生産額　農業　耕種　米"),
+    ("A21_543", "生産額　農業　耕種　麦"),
+    ("A21_544", "生産額　農業　耕種　いも"),
+    ("A21_545", "生産額　農業　耕種　豆･雑穀"),
+    ("A21_546", "生産額　農業　耕種　野菜"),
+    ("A21_547", "生産額　農業　耕種　果実"),
+    ("A21_548", "生産額　農業　耕種　花卉"),
+    ("A21_549", "生産額　農業　耕種　工芸作物"),
+    ("A21_550", "生産額　農業　耕種　その他"),
+    ("A21_551", "生産額　農業　耕種　小計"),
+    ("A21_552", "生産額　農業　養蚕　"),
+    ("A21_553", "生産額　農業　畜産　牛肉"),
+    ("A21_554", "生産額　農業　畜産　豚肉"),
+    ("A21_555", "生産額　農業　畜産　牛乳"),
+    ("A21_556", "生産額　農業　畜産　鶏卵"),
+    ("A21_557", "生産額　農業　畜産　その他"),
+    ("A21_558", "生産額　農業　畜産　小計"),
+    ("A21_559", "生産額　農業　合計　"),
+    ("A21_560", "生産額　林業　木材　用材"),
+    ("A21_561", "生産額　林業　木材　薪炭材"),
+    ("A21_562", "生産額　林業　木炭　"),
+    ("A21_563", "生産額　林業　しいたけ　"),
+    ("A21_564", "生産額　林業　その他　"),
+    ("A21_565", "生産額　林業　合計　"),
+    ("A21_566", "生産額　水産業　魚類　"),
+    ("A21_567", "生産額　水産業　貝類　"),
+    ("A21_568", "生産額　水産業　水産動物　"),
+    ("A21_569", "生産額　水産業　海草類　"),
+    ("A21_570", "生産額　水産業　その他　"),
+    ("A21_571", "生産額　水産業　合計　"),
+    ("A21_572", "生産額　農林水産業総計　　"),
+    ("A21_573", "生産額　備考　　"),
+    ("A21_574", "観光客数　春　"),
+    ("A21_575", "観光客数　夏　"),
+    ("A21_576", "観光客数　秋　"),
+    ("A21_577", "観光客数　冬　"),
+    ("A21_578", "観光客数　計　"),
+    ("A21_579", "宿泊能力(最盛期)　旅館・ホテル　数"),
+    ("A21_580", "宿泊能力(最盛期)　旅館・ホテル　収容能力"),
+    ("A21_581", "宿泊能力(最盛期)　民宿　数"),
+    ("A21_582", "宿泊能力(最盛期)　民宿　収容能力"),
+    ("A21_583", "宿泊能力　年間宿泊者数　　"),
+    ("A21_584", "自然公園面積　国立公園　"),
+    ("A21_585", "自然公園面積　国定公園　"),
+    ("A21_586", "自然公園面積　県立公園　"),
+    ("A21_587", "観光客数・宿泊能力・自然公園　備考　　"),
+    ("A21_588", "港湾・航路現況_港湾　港湾利用状況　入港船舶　隻数"),
+    ("A21_589", "港湾・航路現況_港湾　総屯数"),
+    ("A21_590", "港湾・航路現況_港湾　乗降人員　"),
+    ("A21_591", "港湾・航路現況_港湾　海上出入貨物　出"),
+    ("A21_592", "港湾・航路現況_港湾　海上出入貨物　入"),
+    ("A21_593", "港湾・航路現況_港湾　海上出入貨物　計"),
+    ("A21_594", "港湾・航路現況_漁港　港湾利用状況　入港船舶　隻数"),
+    ("A21_595", "港湾・航路現況_漁港　総屯数"),
+    ("A21_596", "港湾・航路現況_漁港　乗降人員　"),
+    ("A21_597", "港湾・航路現況_漁港　海上出入貨物　出"),
+    ("A21_598", "港湾・航路現況_漁港　海上出入貨物　入"),
+    ("A21_599", "港湾・航路現況_漁港　海上出入貨物　計"),
+    ("A21_600", "空港・航空路現況　都道県名　空港名　　"),
+    ("A21_601", "空港・航空路現況　運航実績　1日当たり運航回数　平常時"),
+    ("A21_602", "空港・航空路現況　　1日当たり運航回数　最盛期"),
+    ("A21_603", "空港・航空路現況　　年間乗降人員　"),
+  ]),
+
+  ("A22", &[
+    ("A22_001", "豪雪地帯ID"),
+    ("A22_002", "行政区域コード"),
+    ("A22_003", "都道府県名"),
+    ("A22_004", "支庁・振興局名"),
+    ("A22_005", "郡・政令都市名"),
+    ("A22_006", "市区町村名"),
+    ("A22_007", "原典市区町村名"),
+    ("A22_008", "旧市町村名"),
+    ("A22_009", "豪雪区分コード"),
+  ]),
+
+  ("A22-m", &[
+    ("A22_000001", "観測点名"),
+    ("A22_000002", "観測点の所在地"),
+    ("A22_000003", "観測点の管理者"),
+    ("A22_010001", "各年度別最深積雪（平均値）"),
+    ("A22_010002", "各年度別最深積雪（最大値）"),
+    ("A22_020001", "各年度別累計降雪量（平均値）"),
+    ("A22_020002", "各年度別累計降雪量（最大値）"),
+    ("A22_030001", "各年度別最低気温（平均値）"),
+    ("A22_030002", "各年度別最低気温（最低値）"),
+    ("A22_040001", "各年度別平均風速（平均値）"),
+    ("A22_040002", "各年度別平均風速（最大値）"),
+    ("A22_050001", "位置特定レベル"),
+    ("A22_100001", "豪雪地帯ID"),
+    ("A22_100002", "地域指定時の市区町村名"),
+    ("A22_100003", "データ整備時の行政コード"),
+    ("A22_100004", "集計単位となる団体名（雪害）"),
+    ("A22_100005", "集計単位フラグ（雪害）"),
+    ("A22_100006", "集計単位となる団体名（除雪ボランティア）"),
+    ("A22_100007", "集計単位フラグ（除雪ボランティア）"),
+    ("A22_40001", "利雪施設名"),
+    ("A22_40002", "利雪施設の設置者"),
+    ("A22_40003", "利雪施設の所在地"),
+    ("A22_40004", "冷熱源確保方式"),
+    ("A22_40005", "冷熱供給方式"),
+    ("A22_40006", "完成年度"),
+    ("A22_40007", "施設の規模"),
+    ("A22_40008", "貯雪量"),
+    ("A22_40009", "位置特定レベル"),
+  ]),
+
+  ("A22s", &[
+    ("A22_001", "豪雪地帯ID"),
+    ("A22_002", "行政コード"),
+    ("A22_003", "都道府県名"),
+    ("A22_004", "支庁名"),
+    ("A22_005", "郡・政令市名"),
+    ("A22_006", "市区町村名"),
+    ("A22_007", "豪雪区分コード"),
+    ("A22_008", "指定状況コード"),
+    ("A22_009", "調査年度"),
+    ("A22_010", "概要　人口"),
+    ("A22_011", "概要　世帯数"),
+    ("A22_012", "男　国勢調査人口総数"),
+    ("A22_013", "男　年少人口　0-4"),
+    ("A22_014", "男　年少人口　5-9"),
+    ("A22_015", "男　年少人口　10-14"),
+    ("A22_016", "男　年少人口　小計"),
+    ("A22_017", "男　生産年齢人口　15-19"),
+    ("A22_018", "男　生産年齢人口　20-24"),
+    ("A22_019", "男　生産年齢人口　25-29"),
+    ("A22_020", "男　生産年齢人口　30-34"),
+    ("A22_021", "男　生産年齢人口　35-39"),
+    ("A22_022", "男　生産年齢人口　40-44"),
+    ("A22_023", "男　生産年齢人口　45-49"),
+    ("A22_024", "男　生産年齢人口　50-54"),
+    ("A22_025", "男　生産年齢人口　55-59"),
+    ("A22_026", "男　生産年齢人口　60-64"),
+    ("A22_027", "男　生産年齢人口　小計"),
+    ("A22_028", "男　老年人口　65-69"),
+    ("A22_029", "男　老年人口　70-74"),
+    ("A22_030", "男　老年人口　75-79"),
+    ("A22_031", "男　老年人口　80-84"),
+    ("A22_032", "男　老年人口　85歳以上"),
+    ("A22_033", "男　老年人口　小計"),
+    ("A22_034", "男　不詳　"),
+    ("A22_035", "女　国勢調査人口総数　"),
+    ("A22_036", "女　年少人口　0-4"),
+    ("A22_037", "女　年少人口　5-9"),
+    ("A22_038", "女　年少人口　10-14"),
+    ("A22_039", "女　年少人口　小計"),
+    ("A22_040", "女　生産年齢人口　15-19"),
+    ("A22_041", "女　生産年齢人口　20-24"),
+    ("A22_042", "女　生産年齢人口　25-29"),
+    ("A22_043", "女　生産年齢人口　30-34"),
+    ("A22_044", "女　生産年齢人口　35-39"),
+    ("A22_045", "女　生産年齢人口　40-44"),
+    ("A22_046", "女　生産年齢人口　45-49"),
+    ("A22_047", "女　生産年齢人口　50-54"),
+    ("A22_048", "女　生産年齢人口　55-59"),
+    ("A22_049", "女　生産年齢人口　60-64"),
+    ("A22_050", "女　生産年齢人口　小計"),
+    ("A22_051", "女　老年人口　65-69"),
+    ("A22_052", "女　老年人口　70-74"),
+    ("A22_053", "女　老年人口　75-79"),
+    ("A22_054", "女　老年人口　80-84"),
+    ("A22_055", "女　老年人口　85歳以上"),
+    ("A22_056", "女　老年人口　小計"),
+    ("A22_057", "女　不詳　"),
+    ("A22_058", "男女計　国勢調査人口総数　"),
+    ("A22_059", "男女計　年少人口　0-4"),
+    ("A22_060", "男女計　年少人口　5-9"),
+    ("A22_061", "男女計　年少人口　10-14"),
+    ("A22_062", "男女計　年少人口　小計"),
+    ("A22_063", "男女計　生産年齢人口　15-19"),
+    ("A22_064", "男女計　生産年齢人口　20-24"),
+    ("A22_065", "男女計　生産年齢人口　25-29"),
+    ("A22_066", "男女計　生産年齢人口　30-34"),
+    ("A22_067", "男女計　生産年齢人口　35-39"),
+    ("A22_068", "男女計　生産年齢人口　40-44"),
+    ("A22_069", "男女計　生産年齢人口　45-49"),
+    ("A22_070", "男女計　生産年齢人口　50-54"),
+    ("A22_071", "男女計　生産年齢人口　55-59"),
+    ("A22_072", "男女計　生産年齢人口　60-64"),
+    ("A22_073", "男女計　生産年齢人口　小計"),
+    ("A22_074", "男女計　老年人口　65-69"),
+    ("A22_075", "男女計　老年人口　70-74"),
+    ("A22_076", "男女計　老年人口　75-79"),
+    ("A22_077", "男女計　老年人口　80-84"),
+    ("A22_078", "男女計　老年人口　85歳以上"),
+    ("A22_079", "男女計　老年人口　小計"),
+    ("A22_080", "男女計　不詳　"),
+    ("A22_081", "就業者数　就業者総数　"),
+    ("A22_082", "就業者数　第1次　農業"),
+    ("A22_083", "就業者数　第1次　農業、林業"),
+    ("A22_084", "就業者数　第1次　農業、林業（うち農業）"),
+    ("A22_085", "就業者数　第1次　林業"),
+    ("A22_086", "就業者数　第1次　漁業"),
+    ("A22_087", "就業者数　第1次　小計"),
+    ("A22_088", "就業者数　第2次　鉱業"),
+    ("A22_089", "就業者数　第2次　鉱業，採石業，砂利採取業"),
+    ("A22_090", "就業者数　第2次　建設業"),
+    ("A22_091", "就業者数　第2次　製造業"),
+    ("A22_092", "就業者数　第2次　小計"),
+    ("A22_093", "就業者数　第3次　電気・ガス・熱供給・水道業"),
+    ("A22_094", "就業者数　第3次　運輸・通信業"),
+    ("A22_095", "就業者数　第3次　情報・通信業"),
+    ("A22_096", "就業者数　第3次　運輸業"),
+    ("A22_097", "就業者数　第3次　運輸業、郵便業"),
+    ("A22_098", "就業者数　第3次　卸売・小売業・飲食店"),
+    ("A22_099", "就業者数　第3次　卸売・小売業"),
+    ("A22_100", "就業者数　第3次　金融・保険業"),
+    ("A22_101", "就業者数　第3次　不動産業"),
+    ("A22_102", "就業者数　第3次　不動産業、物品賃貸業"),
+    ("A22_103", "就業者数　第3次　学術研究，専門・技術サービス業"),
+    ("A22_104", "就業者数　第3次　飲食店・宿泊業"),
+    ("A22_105", "就業者数　第3次　宿泊業，飲食サービス業　"),
+    ("A22_106", "就業者数　第3次　生活関連サービス業，娯楽業"),
+    ("A22_107", "就業者数　第3次　医療･福祉"),
+    ("A22_108", "就業者数　第3次　教育・学習支援業"),
+    ("A22_109", "就業者数　第3次　複合サービス事業"),
+    ("A22_110", "就業者数　第3次　サービス業"),
+    ("A22_111", "就業者数　第3次　公務"),
+    ("A22_112", "就業者数　第3次　小計"),
+    ("A22_113", "就業者数　分類不能　"),
+    ("A22_114", "調査年度"),
+    ("A22_115", "事業所　全産業"),
+    ("A22_116", "事業所　全産業（S公務を除く）"),
+    ("A22_117", "事業所　農林漁業"),
+    ("A22_118", "事業所　非農林漁業1"),
+    ("A22_119", "事業所　非農林漁業2"),
+    ("A22_120", "事業所　鉱業"),
+    ("A22_121", "事業所　鉱業、採石業、砂利採取業"),
+    ("A22_122", "事業所　建設業"),
+    ("A22_123", "事業所　製造業"),
+    ("A22_124", "事業所　電気・ガス・熱供給・水道業"),
+    ("A22_125", "事業所　運輸・通信業"),
+    ("A22_126", "事業所　情報・通信業"),
+    ("A22_127", "事業所　運輸業"),
+    ("A22_128", "事業所　運輸業、郵便業"),
+    ("A22_129", "事業所　卸売・小売業・飲食業"),
+    ("A22_130", "事業所　卸売・小売業"),
+    ("A22_131", "事業所　金融・保険業"),
+    ("A22_132", "事業所　不動産業"),
+    ("A22_133", "事業所　 不動産業、物品賃貸業"),
+    ("A22_134", "事業所　学術研究、専門・技術サービス業"),
+    ("A22_135", "事業所　飲食店・宿泊業"),
+    ("A22_136", "事業所　宿泊業、飲食サービス業"),
+    ("A22_137", "事業所　生活関連サービス業、娯楽業"),
+    ("A22_138", "事業所　医療･福祉"),
+    ("A22_139", "事業所　教育・学習支援業"),
+    ("A22_140", "事業所　複合サービス事業"),
+    ("A22_141", "事業所　サービス業"),
+    ("A22_142", "事業所　公務"),
+    ("A22_143", "事業所　1～4人"),
+    ("A22_144", "事業所　5～9人"),
+    ("A22_145", "事業所　10～19人"),
+    ("A22_146", "事業所　20～29人"),
+    ("A22_147", "事業所　30人以上"),
+    ("A22_148", "事業所　派遣・下請従業者のみ"),
+    ("A22_149", "事業所　経営組織別事業所数（民営事業所）"),
+    ("A22_150", "事業所　事業所の形態別事業所数（民営事業所）"),
+    ("A22_151", "事業所　うち個人"),
+    ("A22_152", "事業所　うち法人"),
+    ("A22_153", "事業所　うち会社"),
+    ("A22_154", "事業所　店舗・飲食店"),
+    ("A22_155", "事業所　事務所・営業所"),
+    ("A22_156", "事業所　工場・作業所・鉱業所"),
+    ("A22_157", "事業所　輸送センター・配送センター・これらの倉庫"),
+    ("A22_158", "事業所　自家用倉庫・自家用油槽所"),
+    ("A22_159", "事業所　外見上一般の住居と区別しにくい事業所"),
+    ("A22_160", "事業所　その他（学校・病院・寺社・旅館・浴場など）"),
+  ]),
+
+  ("A23", &[
+    ("A23_001", "特殊土壌ID"),
+    ("A23_002", "行政区域コード"),
+    ("A23_003", "都道府県名"),
+    ("A23_004", "支庁・振興局名"),
+    ("A23_005", "郡・政令都市名"),
+    ("A23_006", "市区町村名"),
+    ("A23_007", "原典市区町村名"),
+    ("A23_008", "旧市町村名"),
+    ("A23_009", "特殊土壌区分コード"),
+  ]),
+
+  ("A24", &[
+    ("A24_001", "振興山村ID"),
+    ("A24_002", "行政区域コード"),
+    ("A24_003", "都道府県名"),
+    ("A24_004", "支庁・振興局名"),
+    ("A24_005", "郡・政令都市名"),
+    ("A24_006", "市区町村名"),
+    ("A24_007", "原典市区町村名"),
+    ("A24_008", "合併前市町村名"),
+    ("A24_009", "旧市町村名"),
+    ("A24_010", "指定年度"),
+    ("A24_011", "指定番号"),
+  ]),
+
+  ("A25", &[
+    ("A25_001", "特定農山村ID"),
+    ("A25_002", "行政区域コード"),
+    ("A25_003", "都道府県名"),
+    ("A25_004", "支庁・振興局名"),
+    ("A25_005", "郡・政令都市名"),
+    ("A25_006", "市区町村名"),
+    ("A25_007", "原典市区町村名"),
+    ("A25_008", "旧市町村名"),
+  ]),
+
+  ("A26", &[
+    ("A26_001", "危険箇所種別"),
+    ("A26_002", "備考"),
+    ("A26_003", "危険箇所種別"),
+    ("A26_004", "備考"),
+    ("A26_005", "危険箇所種別"),
+    ("A26_006", "備考"),
+  ]),
+
+  ("A27", &[
+    ("A27_001", "市区町村コード"),
+    ("A27_002", "設置主体"),
+    ("A27_003", "名称"),
+    ("A27_004", "所在地"),
+    ("A27_005", "市区町村コード"),
+    ("A27_006", "設置主体"),
+    ("A27_007", "名称"),
+    ("A27_008", "所在地"),
+  ]),
+
+  ("A28", &[
+    ("WHC", "世界自然遺産名称コード"),
+  ]),
+
+  ("A29", &[
+    ("A29_001", "行政区域コード"),
+    ("A29_002", "都道府県名"),
+    ("A29_003", "市区町村名"),
+    ("A29_004", "用途地域コード"),
+    ("A29_005", "用途地域名"),
+    ("A29_006", "建蔽率"),
+    ("A29_007", "容積率"),
+    ("A29_008", "備考"),
+  ]),
+
+  ("A30a5", &[
+    ("A30a5_001", "発生場所（都道府県）"),
+    ("A30a5_002", "発生場所（郡・市・区・町・村）"),
+    ("A30a5_003", "発生日"),
+    ("A30a5_004", "災害の種類"),
+    ("A30a5_005", "最大24時間雨量"),
+    ("A30a5_006", "最大時間雨量"),
+    ("A30a5_007", "勾配"),
+    ("A30a5_008", "流出土砂量"),
+    ("A30a5_009", "地すべり・雪崩の長さ"),
+    ("A30a5_010", "空間属性（メッシュ）"),
+  ]),
+
+  ("A30b", &[
+    ("A30b_001", "竜巻等の突風番号"),
+    ("A30b_002", "竜巻ダウンバースト区別"),
+    ("A30b_003", "発生_日時"),
+    ("A30b_004", "発生_誤差"),
+    ("A30b_005", "発生_都道府県名"),
+    ("A30b_006", "発生_市区町村名"),
+    ("A30b_007", "発生_番地"),
+    ("A30b_008", "消滅_日時"),
+    ("A30b_009", "消滅_誤差"),
+    ("A30b_010", "消滅_都道府県名"),
+    ("A30b_011", "消滅_市区町村名"),
+    ("A30b_012", "消滅_番地"),
+    ("A30b_013", "藤田スケール（Fスケール）"),
+    ("A30b_014", "被害域_幅"),
+    ("A30b_015", "被害域_長さ"),
+    ("A30b_016", "移動_方向1"),
+    ("A30b_017", "移動_方向2"),
+    ("A30b_018", "移動_速度"),
+    ("A30b_019", "継続時間"),
+    ("A30b_020", "x回転方向"),
+    ("A30b_021", "発生地点区別"),
+    ("A30b_022", "総観場_1"),
+    ("A30b_023", "総観場_2"),
+    ("A30b_024", "総観場_3"),
+    ("A30b_025", "総観規模じょう乱からの位置"),
+    ("A30b_026", "特徴"),
+    ("A30b_027", "発生位置緯度誤差"),
+    ("A30b_028", "発生位置経度誤差"),
+    ("A30b_029", "発生位置緯度"),
+    ("A30b_030", "発生位置経度"),
+    ("A30b_031", "消滅位置緯度誤差"),
+    ("A30b_032", "消滅位置経度誤差"),
+    ("A30b_033", "消滅位置緯度"),
+    ("A30b_034", "消滅位置経度"),
+  ]),
+
+  ("A31", &[
+    ("A31_101", "浸水深ランク"),
+    ("A31_102", "指定年月日"),
+    ("A31_103", "告示番号"),
+    ("A31_104", "指定の前提となる降雨"),
+    ("A31_201", "浸水深ランク"),
+    ("A31_202", "指定年月日"),
+    ("A31_203", "告示番号"),
+    ("A31_204", "指定の前提となる降雨"),
+    ("A31_301", "浸水継続時間ランク"),
+    ("A31_302", "指定年月日"),
+    ("A31_303", "告示番号"),
+    ("A31_304", "指定の前提となる降雨"),
+    ("A31_401", "危険区域区分"),
+    ("A31_402", "指定年月日"),
+    ("A31_403", "告示番号"),
+    ("A31_404", "指定の前提となる降雨"),
+  ]),
+
+  ("A32", &[
+    ("A32_001", "市区町村コード"),
+    ("A32_002", "設置主体"),
+    ("A32_003", "名称"),
+    ("A32_004", "所在地"),
+    ("A32_005", "通学区域ファイル名"),
+    ("A32_006", "市区町村コード"),
+    ("A32_007", "設置主体"),
+    ("A32_008", "名称"),
+    ("A32_009", "所在地"),
+    ("A32_010", "通学区域ファイル名"),
+  ]),
+
+  ("A33", &[
+    ("A33_001", "現象の種類"),
+    ("A33_002", "区域区分"),
+    ("A33_003", "都道府県コード"),
+    ("A33_004", "区域番号"),
+    ("A33_005", "区域名"),
+    ("A33_006", "所在地"),
+    ("A33_007", "告示日"),
+    ("A33_008", "特別警戒未指定フラグ"),
+  ]),
+
+  ("A34", &[
+    ("A34a_001", "構成資産範囲ID"),
+    ("A34a_002", "世界遺産番号"),
+    ("A34a_003", "世界文化遺産名"),
+    ("A34a_004", "地区名"),
+    ("A34a_005", "都道府県名"),
+    ("A34a_006", "構成資産範囲面積"),
+    ("A34a_007", "構成資産"),
+    ("A34a_008", "登録基準区分"),
+    ("A34a_009", "記載年月"),
+    ("A34b_001", "構成資産範囲ID"),
+    ("A34b_002", "世界遺産番号"),
+    ("A34b_003", "世界文化遺産名"),
+    ("A34b_004", "地区名"),
+    ("A34b_005", "都道府県名"),
+    ("A34b_006", "構成資産範囲面積"),
+    ("A34b_007", "構成資産"),
+    ("A34b_008", "登録基準区分"),
+    ("A34b_009", "記載年月"),
+    ("A34c_001", "構成資産範囲ID"),
+    ("A34c_002", "世界遺産番号"),
+    ("A34c_003", "世界文化遺産名"),
+    ("A34c_004", "地区名"),
+    ("A34c_005", "都道府県名"),
+    ("A34c_006", "構成資産範囲面積"),
+    ("A34c_007", "構成資産"),
+    ("A34c_008", "登録基準区分"),
+    ("A34c_009", "記載年月"),
+    ("A34d_001", "構成資産範囲ID"),
+    ("A34d_002", "世界遺産番号"),
+    ("A34d_003", "世界文化遺産名"),
+    ("A34d_004", "地区名"),
+    ("A34d_005", "都道府県名"),
+    ("A34d_006", "構成資産範囲面積"),
+    ("A34d_007", "構成資産"),
+    ("A34d_008", "登録基準区分"),
+    ("A34d_009", "記載年月"),
+    ("A34e_001", "構成資産ID"),
+    ("A34e_002", "世界遺産番号"),
+    ("A34e_003", "世界文化遺産名"),
+    ("A34e_004", "構成資産名"),
+    ("A34f_001", "緩衝地帯ID"),
+    ("A34f_002", "世界遺産番号"),
+    ("A34f_003", "世界文化遺産名"),
+    ("A34f_004", "地区名"),
+    ("A34f_005", "緩衝地帯面積"),
+    ("A34g_001", "緩衝地帯ID"),
+    ("A34g_002", "世界遺産番号"),
+    ("A34g_003", "世界文化遺産名"),
+    ("A34g_004", "地区名"),
+    ("A34g_005", "緩衝地帯面積"),
+  ]),
+
+  ("A35a", &[
+    ("A35a_001", "景観計画区域ID"),
+    ("A35a_002", "都道府県コード"),
+    ("A35a_003", "団体名"),
+    ("A35a_004", "行政コード"),
+    ("A35a_005", "策定年月日"),
+    ("A35a_006", "面積"),
+    ("A35a_007", "景観計画未策定フラグ"),
+    ("A35b_001", "景観計画区域ID"),
+    ("A35b_002", "都道府県コード"),
+    ("A35b_003", "団体名"),
+    ("A35b_004", "行政コード"),
+    ("A35b_005", "策定年月日"),
+    ("A35b_006", "面積"),
+    ("A35b_007", "景観計画未策定フラグ"),
+    ("A35c_001", "景観計画区域ID"),
+    ("A35c_002", "都道府県コード"),
+    ("A35c_003", "団体名"),
+    ("A35c_004", "行政コード"),
+    ("A35c_005", "地区名称"),
+  ]),
+
+  ("A35b", &[
+    ("A35d_001", "景観地区ID"),
+    ("A35d_002", "都道府県コード"),
+    ("A35d_003", "団体名"),
+    ("A35d_004", "行政コード"),
+    ("A35d_005", "名称"),
+    ("A35d_006", "位置"),
+    ("A35d_007", "面積"),
+    ("A35d_008", "決定年月日"),
+    ("A35d_009", "種別コード"),
+    ("A35e_001", "景観地区ID"),
+    ("A35e_002", "都道府県コード"),
+    ("A35e_003", "団体名"),
+    ("A35e_004", "行政コード"),
+    ("A35e_005", "名称"),
+    ("A35e_006", "位置"),
+    ("A35e_007", "面積"),
+    ("A35e_008", "決定年月日"),
+    ("A35e_009", "種別コード"),
+    ("A35f_001", "景観地区ゾーンID"),
+    ("A35f_002", "都道府県コード"),
+    ("A35f_003", "団体名"),
+    ("A35f_004", "行政コード"),
+    ("A35f_005", "景観地区・準景観地区名称"),
+    ("A35f_006", "ゾーン名称"),
+    ("A35f_007", "種別"),
+  ]),
+
+  ("A35c", &[
+    ("A35g_001", "景観重要建造物ID"),
+    ("A35g_002", "都道府県コード"),
+    ("A35g_003", "団体名"),
+    ("A35g_004", "行政コード"),
+    ("A35g_005", "指定番号"),
+    ("A35g_006", "指定年月日"),
+    ("A35g_007", "名称"),
+    ("A35g_008", "所在地"),
+    ("A35g_009", "外観の特徴"),
+    ("A35h_001", "景観重要樹木ID"),
+    ("A35h_002", "都道府県コード"),
+    ("A35h_003", "団体名"),
+    ("A35h_004", "行政コード"),
+    ("A35h_005", "指定番号"),
+    ("A35h_006", "指定年月日"),
+    ("A35h_007", "樹種"),
+    ("A35h_008", "所在地"),
+    ("A35h_009", "外観の特徴"),
+  ]),
+
+  ("A37", &[
+    ("A37_000001", "指定時の路線名"),
+    ("A37_000002", "半島振興対策実施地域名"),
+    ("A37_000003", "指定時の道路種別"),
+    ("A37_000004", "最大交通量"),
+    ("A37_000005", "平均交通量"),
+    ("A37_000006", "指定時の区間（住所）"),
+    ("A37_100001", "半島振興対策実施地域名"),
+    ("A37_100002", "道路種別"),
+    ("A37_200001", "路線名"),
+    ("A37_200002", "道路種別"),
+    ("A37_200003", "規制基準雨量（連続雨量）"),
+    ("A37_200004", "規制基準雨量（1時間雨量）"),
+    ("A37_200005", "規制基準雨量（24時間雨量）"),
+    ("A37_200006", "規制基準"),
+    ("A37_210001", "通行規制区間の起点（住所）"),
+    ("A37_210002", "通行規制区間の終点（住所）"),
+    ("A37_300001", "半島ID"),
+    ("A37_300002", "半島振興対策実施地域名"),
+    ("A37_300003", "市町村名"),
+    ("A37_300004", "地域指定当時の市町村名"),
+    ("A37_300005", "集計単位となる団体名（救急搬送）"),
+    ("A37_300006", "データ整備時の行政コード"),
+    ("A37_300007", "集計単位フラグ（救急搬送）"),
+    ("A37_310001", "年平均日射量"),
+    ("A37_310002", "平均風速（標高30m）"),
+    ("A37_310003", "平均風速（標高50m）"),
+    ("A37_310004", "平均風速（標高70m）"),
+    ("A37_320001", "耕作放棄地面積"),
+    ("A37_330001", "宿泊能力（客室数）"),
+    ("A37_382000", "小・中学校生徒数（H12）"),
+    ("A37_382010", "小・中学校生徒数（H22）"),
+    ("A37_392000", "高校生徒数（H12）"),
+    ("A37_392010", "高校生徒数（H22）"),
+    ("A37_400001", "最寄空港までのアクセス時間（起点の施設名）"),
+    ("A37_400002", "最寄空港までのアクセス時間（終点の施設名）"),
+    ("A37_400003", "最寄空港までのアクセス時間（平均所要時間）"),
+    ("A37_410001", "最寄新幹線停車駅までのアクセス時間（起点の施設名）"),
+    ("A37_410002", "最寄新幹線停車駅までのアクセス時間（終点の施設名）"),
+    ("A37_410003", "最寄新幹線停車駅までのアクセス時間（平均所要時間）"),
+    ("A37_420001", "最寄ICまでのアクセス時間（起点の施設名）"),
+    ("A37_420002", "最寄ICまでのアクセス時間（終点の施設名）"),
+    ("A37_420003", "最寄ICまでのアクセス時間（平均所要時間）"),
+  ]),
+
+  ("A38", &[
+    ("A38a_001", "一次医療圏_行政区域コード"),
+    ("A38a_002", "一次医療圏_市区町村名"),
+    ("A38a_003", "一次医療圏_二次医療圏コード"),
+    ("A38a_004", "一次医療圏_二次医療圏名"),
+    ("A38a_005", "一次医療圏_設定フラグ"),
+    ("A38b_001", "二次医療圏_行政区域コード"),
+    ("A38b_002", "二次医療圏_市区町村名"),
+    ("A38b_003", "二次医療圏_二次医療圏コード"),
+    ("A38b_004", "二次医療圏_二次医療圏名"),
+    ("A38b_005", "二次医療圏_面積（医療計画）"),
+    ("A38b_006", "二次医療圏_面積（国土地理院）"),
+    ("A38b_007", "二次医療圏_人口（医療計画）"),
+    ("A38b_008", "二次医療圏_総人口（住民基本台帳）"),
+    ("A38b_009", "二次医療圏_人口（15才未満）（住民基本台帳）"),
+    ("A38b_010", "二次医療圏_人口（15才以上65才未満）（住民基本台帳）"),
+    ("A38b_011", "二次医療圏_人口（65才以上）（住民基本台帳）"),
+    ("A38c_001", "三次医療圏_都道府県名"),
+    ("A38c_002", "三次医療圏_三次医療圏名"),
+  ]),
+
+  ("A39", &[
+    ("A39_001", "都道府県コード"),
+    ("A39_002", "行政コード"),
+    ("A39_003", "市区町村名"),
+    ("A39_004", "地区ID"),
+    ("A39_005", "地区分割フラグ"),
+    ("A39_006", "地区名"),
+    ("A39_007", "地区面積"),
+    ("A39_008", "所在地"),
+    ("A39_009", "地区人口a"),
+    ("A39_010", "地区人口b"),
+    ("A39_011", "地区世帯数a"),
+    ("A39_012", "地区世帯数b"),
+    ("A39_013", "建物総棟数a"),
+    ("A39_014", "建物総棟数b"),
+    ("A39_015", "木造建物棟数"),
+    ("A39_016", "裸木造建物棟数"),
+    ("A39_017", "防火木造建物棟数"),
+    ("A39_018", "準耐火木造建物棟数"),
+    ("A39_019", "耐火木造建物棟数"),
+    ("A39_020", "不燃領域率"),
+    ("A39_021", "不燃領域率定義"),
+    ("A39_022", "木防率"),
+    ("A39_023", "調査年度"),
+    ("A39_024", "75歳以上高齢者数"),
+    ("A39_025", "防災再開発促進地区指定"),
+  ]),
+
+  ("A40", &[
+    ("A40_001", "都道府県名"),
+    ("A40_002", "都道府県コード"),
+    ("A40_003", "津波浸水深の区分"),
+  ]),
+
+  ("A43", &[
+    ("A43_001", "都道府県名"),
+    ("A43_002", "市町村名"),
+    ("A43_003", "行政コード"),
+    ("A43_004", "地区名称"),
+    ("A43_005", "指定"),
+    ("A43_006", "所在地"),
+    ("A43_007", "種別"),
+    ("A43_008", "選定日"),
+    ("A43_009", "面積"),
+    ("A43_010", "文化遺産オンラインURL"),
+    ("A43_011", "中心地緯度"),
+    ("A43_012", "中心地経度"),
+  ]),
+
+  ("A44", &[
+    ("A44_001", "都道府県名"),
+    ("A44_002", "市町村名"),
+    ("A44_003", "行政コード"),
+    ("A44_004", "計画名称"),
+    ("A44_005", "認定日"),
+    ("A44_006", "計画期間開始日"),
+    ("A44_007", "計画期間終了日"),
+    ("A44_008", "重点区域面積"),
+    ("A44_009", "中心地緯度"),
+    ("A44_010", "中心地経度"),
+  ]),
+
+  ("A45", &[
+    ("A45_001", "小班ID"),
+    ("A45_002", "森林管理局"),
+    ("A45_003", "森林管理署"),
+    ("A45_004", "林班主番"),
+    ("A45_005", "林班枝番"),
+    ("A45_006", "小班主番"),
+    ("A45_007", "小班枝番"),
+    ("A45_008", "局名称"),
+    ("A45_009", "署名称"),
+    ("A45_010", "小班名称"),
+    ("A45_011", "林小班名称"),
+    ("A45_012", "材積"),
+    ("A45_013", "国有林名称"),
+    ("A45_014", "県市町村名称"),
+    ("A45_015", "樹種１"),
+    ("A45_016", "樹立林齢１"),
+    ("A45_017", "最新林齢１"),
+    ("A45_018", "樹種２"),
+    ("A45_019", "樹立林齢２"),
+    ("A45_020", "最新林齢２"),
+    ("A45_021", "樹種３"),
+    ("A45_022", "樹立林齢３"),
+    ("A45_023", "最新林齢３"),
+    ("A45_024", "計画区名称"),
+    ("A45_025", "林種の細分"),
+    ("A45_026", "機能類型"),
+    ("A45_027", "面積"),
+    ("A45_028", "保安林１"),
+    ("A45_029", "保安林２"),
+    ("A45_030", "保安林３"),
+    ("A45_031", "保安林４"),
+    ("A45_032", "保護林"),
+    ("A45_033", "緑の回廊"),
+  ]),
+
+  ("A55", &[
+    ("AreaCode", "種類コード"),
+    ("AreaType", "種類"),
+    ("BCR", "建蔽率"),
+    ("Citycode", "行政コード"),
+    ("Cityname", "市町村名"),
+    ("Custodian", "都市計画を定める者の名称"),
+    ("DistCode", "種類コード"),
+    ("DistName", "地区名"),
+    ("DistType", "種類"),
+    ("DouroCode", "種類コード"),
+    ("DouroType", "種類"),
+    ("FAR", "容積率"),
+    ("FNDate", "公示日"),
+    ("FNNumber", "公示番号（最終）"),
+    ("FaciCode", "種類コード"),
+    ("FaciName", "施設名"),
+    ("FaciType", "種類"),
+    ("INDate", "効力を生じる日"),
+    ("INNumber", "公示番号（当初）"),
+    ("ParkCode", "種類コード"),
+    ("ParkName", "公園名"),
+    ("ParkType", "種類"),
+    ("Pref", "都道府県名"),
+    ("TokeiCode", "種類コード"),
+    ("TokeiName", "区域名"),
+    ("TokeiType", "種類"),
+    ("ValidType", "効力を生じる日の種類"),
+    ("YoutoCode", "用途コード"),
+    ("YoutoName", "用途名"),
+  ]),
+
+  ("A56", &[
+    ("A56_001", "都道府県コード"),
+    ("A56_002", "行政コード"),
+    ("A56_003", "市区町村名"),
+    ("A56_004", "規制区域区分"),
+    ("A56_005", "指定主体区分"),
+    ("A56_006", "告示番号"),
+    ("A56_007", "施行年月日"),
+  ]),
+
+  ("N03", &[
+    ("N03_001", "都道府県名"),
+    ("N03_002", "支庁・振興局名"),
+    ("N03_003", "郡・政令都市名"),
+    ("N03_004", "市区町村名"),
+    ("N03_007", "行政区域コード"),
+  ]),
+];
+#[cfg(all(feature = "dict-colnames", feature = "dict-policy"))]
+const _: () = assert!(super::is_sorted_by_key(COLNAMES_POLICY) && super::is_each_sorted_by_key(COLNAMES_POLICY));
 
-  ///// W07 /////
+#[cfg(all(feature = "dict-colnames", feature = "dict-transport"))]
+#[rustfmt::skip]
+const COLNAMES_TRANSPORT: &[Colnames] = &[
+  ("C02", &[
+    ("C02_001", "種別（１）"),
+    ("C02_002", "種別（２）"),
+    ("C02_003", "行政区域コード"),
+    ("C02_004", "港湾コード"),
+    ("C02_005", "港湾名"),
+    ("C02_006", "管理者区分"),
+    ("C02_007", "管理者名"),
+    ("C02_008", "政令指定年月日"),
+    ("C02_009", "設立年月日"),
+    ("C02_010", "海事機関種別"),
+    ("C02_011", "外郭施設延長"),
+    ("C02_012", "係留施設延長"),
+    ("C02_013", "フェリー便有無"),
+    ("C02_014", "都道府県コード"),
+    ("C02_015", "港湾コード"),
+    ("C02_016", "都道府県コード"),
+    ("C02_017", "港湾・漁港コード_港湾コード"),
+    ("C02_018", "港湾・漁港コード_漁港コード"),
+  ]),
+
+  ("C09", &[
+    ("C09_001", "漁港コード"),
+    ("C09_002", "漁港名"),
+    ("C09_003", "行政区域コード"),
+    ("C09_004", "種別"),
+    ("C09_005", "管理者区分"),
+    ("C09_006", "管理者名"),
+    ("C09_007", "指定年月日"),
+    ("C09_008", "関係漁業共同組合"),
+    ("C09_009", "外郭施設延長"),
+    ("C09_010", "係留施設延長"),
+    ("C09_011", "都道府県コード"),
+    ("C09_012", "漁港コード"),
+  ]),
+
+  ("C23", &[
+    ("C23_001", "行政区域コード"),
+    ("C23_002", "所管官庁"),
+    ("C23_003", "海岸保全区域番号"),
+    ("C23_004", "海岸保全区域・海岸名"),
+    ("C23_005", "海岸保全区域・海岸管理者コード"),
+    ("C23_006", "海岸保全区域・海岸管理者名"),
+    ("C23_007", "河口"),
+  ]),
+
+  ("C28", &[
+    ("C28_000", "ID"),
+    ("C28_001", "行政区域コード"),
+    ("C28_003", "種別"),
+    ("C28_004", "供用中・建設中の区別"),
+    ("C28_005", "名称"),
+    ("C28_006", "設置者"),
+    ("C28_007", "管理者"),
+    ("C28_008", "特定飛行場の指定状況"),
+    ("C28_009", "運用時間（開始）"),
+    ("C28_010", "運用時間（終了）"),
+    ("C28_011", "定期便の有無"),
+    ("C28_012", "滑走路延長"),
+    ("C28_013", "滑走路幅"),
+    ("C28_014", "調査年"),
+    ("C28_015", "１日あたりの着陸回数"),
+    ("C28_016", "１日あたりの乗降客数"),
+    ("C28_101", "標点ID"),
+    ("C28_102", "ターミナルビルID"),
+    ("C28_103", "調査内容ID"),
+    ("C28_104", "滑走路ID"),
+  ]),
+
+  ("N02", &[
+    ("N02_001", "鉄道区分"),
+    ("N02_002", "事業者種別"),
+    ("N02_003", "路線名"),
+    ("N02_004", "運営会社"),
+    ("N02_005", "駅名"),
+    ("N02_005c", "駅コード"),
+    ("N02_005g", "グループコード"),
+  ]),
+
+  ("N05", &[
+    ("N05_001", "事業者種別"),
+    ("N05_002", "路線名"),
+    ("N05_003", "運営会社"),
+    ("N05_004", "供用開始年"),
+    ("N05_005b", "設置期間（設置開始）"),
+    ("N05_005e", "設置期間（設置終了）"),
+    ("N05_006", "関係ID"),
+    ("N05_007", "変遷ID"),
+    ("N05_008", "変遷備考"),
+    ("N05_009", "備考"),
+    ("N05_010", "関係備考"),
+    ("N05_011", "駅名"),
+  ]),
+
+  ("N06", &[
+    ("N06_001", "供用開始年"),
+    ("N06_002", "設置期間（開始年）"),
+    ("N06_003", "設置期間（終了年）"),
+    ("N06_004", "関係ID"),
+    ("N06_005", "変遷ID"),
+    ("N06_006", "変遷備考"),
+    ("N06_007", "路線名"),
+    ("N06_008", "路線種別"),
+    ("N06_009", "供用状況"),
+    ("N06_010", "車線数"),
+    ("N06_011", "関係備考"),
+    ("N06_012", "供用開始年"),
+    ("N06_013", "設置期間（開始年）"),
+    ("N06_014", "設置期間（終了年）"),
+    ("N06_015", "関係ID"),
+    ("N06_016", "変遷ID"),
+    ("N06_017", "変遷備考"),
+    ("N06_018", "地点名"),
+    ("N06_019", "接合部種別"),
+  ]),
+
+  ("N07", &[
+    ("N07_001", "バス区分"),
+    ("N07_002", "事業者名"),
+    ("N07_003", "バス系統"),
+    ("N07_004", "平日運行頻度"),
+    ("N07_005", "土曜日運行頻度"),
+    ("N07_006", "日祝日運行頻度"),
+    ("N07_007", "備考"),
+  ]),
+
+  ("N08", &[
+    ("N08_001", "行政区域"),
+    ("N08_002", "空港種別"),
+    ("N08_003", "空港名称"),
+    ("N08_004", "設置者"),
+    ("N08_005", "管理者"),
+    ("N08_006", "滑走路本数"),
+    ("N08_007", "滑走路延長"),
+    ("N08_008", "運用時間（開始）"),
+    ("N08_009", "運用時間（終了）"),
+    ("N08_010", "定期便"),
+    ("N08_011", "ジェット化"),
+    ("N08_012", "供用開始年"),
+    ("N08_013", "供用状況"),
+    ("N08_014", "設置期間（開始年）"),
+    ("N08_015", "設置期間（終了年）"),
+    ("N08_016", "関係ID"),
+    ("N08_017", "変遷ID"),
+    ("N08_018", "変遷備考"),
+    ("N08_019", "空港名称"),
+    ("N08_020", "供用開始年"),
+    ("N08_021", "供用状況"),
+    ("N08_022", "設置期間（開始年）"),
+    ("N08_023", "設置期間（終了年）"),
+    ("N08_024", "関係ID"),
+    ("N08_025", "備考"),
+  ]),
+
+  ("N09", &[
+    ("N09_001", "港ID"),
+    ("N09_002", "都道府県名"),
+    ("N09_003", "島嶼名"),
+    ("N09_004", "港名"),
+    ("N09_005", "航路ID"),
+    ("N09_006", "航路名"),
+    ("N09_007", "航路の起終点"),
+    ("N09_008", "航路の総距離"),
+    ("N09_009", "事業者名"),
+    ("N09_010", "航路線ID"),
+    ("N09_011", "港ID1"),
+    ("N09_012", "港1島嶼名"),
+    ("N09_013", "港名1"),
+    ("N09_014", "港ID2"),
+    ("N09_015", "港2島嶼名"),
+    ("N09_016", "港名2"),
+    ("N09_017", "航行している航種（フェリー）"),
+    ("N09_018", "航行している航種（旅客船）"),
+    ("N09_019", "航行している航種（高速船）"),
+    ("N09_020", "航行している航種（ジェットフォイル）"),
+    ("N09_021", "航行している航種（その他）"),
+    ("N09_022", "便数（上り）"),
+    ("N09_023", "便数（下り）"),
+    ("N09_024", "所要時間（最短）"),
+    ("N09_025", "所要時間（最長）"),
+    ("N09_026", "標準ダイヤの運行期間"),
+    ("N09_027", "旅客定員（最大）"),
+    ("N09_028", "旅客定員（最小）"),
+    ("N09_029", "乗用車積載可能車両数"),
+    ("N09_030", "トラック積載可能車両数"),
+  ]),
+
+  ("N10", &[
+    ("N10_001", "都道府県コード"),
+    ("N10_002", "区分"),
+    ("N10_003", "道路種別"),
+    ("N10_004", "路線名称"),
+    ("N10_005", "任意ID"),
+    ("N10_006", "枝ID"),
+    ("N10_007", "資料名称"),
+    ("N10_008", "資料年月"),
+  ]),
+
+  ("N11", &[
+    ("N11_001", "名称"),
+    ("N11_002", "航空法分類"),
+    ("N11_003", "地域防災計画分類"),
+    ("N11_004", "都道府県コード"),
+    ("N11_005", "所在地"),
+    ("N11_006", "管理者名"),
+    ("N11_007", "滑走路面積"),
+  ]),
+
+  ("N13", &[
+    ("N13_001", "データ登録日"),
+    ("N13_002", "種別"),
+    ("N13_003", "道路分類"),
+    ("N13_004", "道路状態"),
+    ("N13_005", "階層順"),
+    ("N13_006", "幅員区分"),
+    ("N13_007", "有料区分"),
+    ("N13_008", "二次メッシュ番号"),
+  ]),
+
+  ("S05-c", &[
+    ("S05c_001", "調査年度"),
+    ("S05c_002", "駅コード"),
+    ("S05c_003", "運営会社"),
+    ("S05c_004", "駅名"),
+    ("S05c_005", "乗車_バス"),
+    ("S05c_006", "乗車_自家用自動車"),
+    ("S05c_007", "乗車_タクシー・ハイヤー"),
+    ("S05c_008", "乗車_自動車"),
+    ("S05c_009", "乗車_自動二輪原付"),
+    ("S05c_010", "乗車_自転車"),
+    ("S05c_011", "乗車_二輪"),
+    ("S05c_012", "乗車_徒歩"),
+    ("S05c_013", "乗車_その他"),
+    ("S05c_014", "乗車_合計"),
+    ("S05c_015", "降車_バス"),
+    ("S05c_016", "降車_自家用自動車"),
+    ("S05c_017", "降車_タクシー・ハイヤー"),
+    ("S05c_018", "降車_自動車"),
+    ("S05c_019", "降車_自動二輪原付"),
+    ("S05c_020", "降車_自転車"),
+    ("S05c_021", "降車_二輪"),
+    ("S05c_022", "降車_徒歩"),
+    ("S05c_023", "降車_その他"),
+    ("S05c_024", "降車_合計"),
+    ("S05c_025", "調査年度"),
+    ("S05c_026", "駅コード"),
+    ("S05c_027", "駅名"),
+    ("S05c_028", "乗車_路線バス・都電"),
+    ("S05c_029", "乗車_乗用車"),
+    ("S05c_030", "乗車_軽乗用車"),
+    ("S05c_031", "乗車_貨物自動車・軽貨物車"),
+    ("S05c_032", "乗車_自家用バス・貸切りバス"),
+    ("S05c_033", "乗車_タクシー・ハイヤー"),
+    ("S05c_034", "乗車_自動二輪車"),
+    ("S05c_035", "乗車_原動機付き自転車"),
+    ("S05c_036", "乗車_自転車"),
+    ("S05c_037", "乗車_徒歩"),
+    ("S05c_038", "乗車_航空機"),
+    ("S05c_039", "乗車_船舶"),
+    ("S05c_040", "乗車_その他"),
+    ("S05c_041", "乗車_不明"),
+    ("S05c_042", "乗車_合計"),
+    ("S05c_043", "降車_路線バス・都電"),
+    ("S05c_044", "降車_乗用車"),
+    ("S05c_045", "降車_軽乗用車"),
+    ("S05c_046", "降車_貨物自動車・軽貨物車"),
+    ("S05c_047", "降車_自家用バス・貸切りバス"),
+    ("S05c_048", "降車_タクシー・ハイヤー"),
+    ("S05c_049", "降車_自動二輪車"),
+    ("S05c_050", "降車_原動機付き自転車"),
+    ("S05c_051", "降車_自転車"),
+    ("S05c_052", "降車_徒歩"),
+    ("S05c_053", "降車_航空機"),
+    ("S05c_054", "降車_船舶"),
+    ("S05c_055", "降車_その他"),
+    ("S05c_056", "降車_不明"),
+    ("S05c_057", "降車_合計"),
+  ]),
+
+  ("S05-d", &[
+    ("S05d_001", "調査年度"),
+    ("S05d_002", "発ゾーンコード"),
+    ("S05d_003", "着ゾーンコード"),
+    ("S05d_004", "全機関_農水産品トン数"),
+    ("S05d_005", "全機関_林産品トン数"),
+    ("S05d_006", "全機関_鉱産品トン数"),
+    ("S05d_007", "全機関_金属・機械工業品トン数"),
+    ("S05d_008", "全機関_化学工業品トン数"),
+    ("S05d_009", "全機関_軽工業品トン数"),
+    ("S05d_010", "全機関_雑工業品トン数"),
+    ("S05d_011", "全機関_特種品トン数"),
+    ("S05d_012", "全機関_その他トン数"),
+    ("S05d_013", "全機関_合計トン数"),
+    ("S05d_014", "鉄道_農水産品トン数"),
+    ("S05d_015", "鉄道_林産品トン数"),
+    ("S05d_016", "鉄道_鉱産品トン数"),
+    ("S05d_017", "鉄道_金属・機械工業品トン数"),
+    ("S05d_018", "鉄道_化学工業品トン数"),
+    ("S05d_019", "鉄道_軽工業品トン数"),
+    ("S05d_020", "鉄道_雑工業品トン数"),
+    ("S05d_021", "鉄道_特種品トン数"),
+    ("S05d_022", "鉄道_その他トン数"),
+    ("S05d_023", "鉄道_合計トン数"),
+    ("S05d_024", "海運_農水産品トン数"),
+    ("S05d_025", "海運_林産品トン数"),
+    ("S05d_026", "海運_鉱産品トン数"),
+    ("S05d_027", "海運_金属・機械工業品トン数"),
+    ("S05d_028", "海運_化学工業品トン数"),
+    ("S05d_029", "海運_軽工業品トン数"),
+    ("S05d_030", "海運_雑工業品トン数"),
+    ("S05d_031", "海運_特種品トン数"),
+    ("S05d_032", "海運_その他トン数"),
+    ("S05d_033", "海運_合計トン数"),
+    ("S05d_034", "自動車_農水産品トン数"),
+    ("S05d_035", "自動車_林産品トン数"),
+    ("S05d_036", "自動車_鉱産品トン数"),
+    ("S05d_037", "自動車_金属・工業品トン数"),
+    ("S05d_038", "自動車_化学工業品トン数"),
+    ("S05d_039", "自動車_軽工業品トン数"),
+    ("S05d_040", "自動車_雑工業品トン数"),
+    ("S05d_041", "自動車_特種品トン数"),
+    ("S05d_042", "自動車_その他トン数"),
+    ("S05d_043", "自動車_合計トン数"),
+    ("S05d_044", "調査年度"),
+    ("S05d_045", "発ゾーンコード"),
+    ("S05d_046", "着ゾーンコード"),
+    ("S05d_047", "ＪＲ定期旅客数"),
+    ("S05d_048", "ＪＲ定期外旅客数"),
+    ("S05d_049", "ＪＲ合計旅客数"),
+    ("S05d_050", "民鉄定期旅客数"),
+    ("S05d_051", "民鉄定期外旅客数"),
+    ("S05d_052", "民鉄合計旅客数"),
+    ("S05d_053", "乗合バス旅客数"),
+    ("S05d_054", "貸切バス旅客数"),
+    ("S05d_055", "自家用バス旅客数"),
+    ("S05d_056", "営業用乗用車旅客数"),
+    ("S05d_057", "自家用乗用車旅客数"),
+    ("S05d_058", "自動車合計旅客数"),
+    ("S05d_059", "旅客船旅客数"),
+    ("S05d_060", "航空旅客数"),
+    ("S05d_061", "全機関旅客数"),
+  ]),
+
+  ("S10a", &[
+    ("S10a_001", "出発港_都道府県"),
+    ("S10a_002", "出発港_港湾名"),
+    ("S10a_003", "到着港_都道府県"),
+    ("S10a_004", "到着港_港湾名"),
+    ("S10a_005", "頻度_貨客船定期便就航数"),
+    ("S10a_006", "頻度_フェリー定期便就航数"),
+    ("S10a_007", "頻度_貨物船定期便就航数"),
+    ("S10a_008", "頻度_RORO船定期便就航数"),
+    ("S10a_009", "運搬可能旅客数"),
+    ("S10a_010", "積載可能貨物量_トラック"),
+    ("S10a_011", "積載可能貨物量_シャーシ"),
+    ("S10a_012", "積載可能貨物量_トレーラー"),
+    ("S10a_013", "積載可能貨物量_中型車"),
+    ("S10a_014", "積載可能貨物量_乗用車"),
+    ("S10a_015", "積載可能貨物量_コンテナ"),
+    ("S10a_016", "積載可能貨物量_その他"),
+    ("S10a_017", "積載可能貨物量_化成品"),
+    ("S10a_018", "大分類流通量_農水産品"),
+    ("S10a_019", "大分類流通量_林産品"),
+    ("S10a_020", "大分類流通量_鉱産品"),
+    ("S10a_021", "大分類流通量_金属機械工業品"),
+    ("S10a_022", "大分類流通量_化学工業品"),
+    ("S10a_023", "大分類流通量_軽工業品"),
+    ("S10a_024", "大分類流通量_雑工業品"),
+    ("S10a_025", "大分類流通量_特殊品"),
+    ("S10a_026", "大分類流通量_分類不能のもの（大分類）"),
+    ("S10a_027", "中分類流通量_011麦"),
+    ("S10a_028", "中分類流通量_021米"),
+    ("S10a_029", "中分類流通量_022とうもろこし"),
+    ("S10a_030", "中分類流通量_023豆類"),
+    ("S10a_031", "中分類流通量_024その他雑穀"),
+    ("S10a_032", "中分類流通量_031野菜・果物"),
+    ("S10a_033", "中分類流通量_041綿花"),
+    ("S10a_034", "中分類流通量_051その他農産品"),
+    ("S10a_035", "中分類流通量_061羊毛"),
+    ("S10a_036", "中分類流通量_071その他畜産品"),
+    ("S10a_037", "中分類流通量_081水産品"),
+    ("S10a_038", "中分類流通量_091原木"),
+    ("S10a_039", "中分類流通量_092製材"),
+    ("S10a_040", "中分類流通量_101樹脂類"),
+    ("S10a_041", "中分類流通量_111木材チップ"),
+    ("S10a_042", "中分類流通量_112その他林産品"),
+    ("S10a_043", "中分類流通量_121薪炭"),
+    ("S10a_044", "中分類流通量_131石炭"),
+    ("S10a_045", "中分類流通量_141鉄鉱石"),
+    ("S10a_046", "中分類流通量_151金属鉱"),
+    ("S10a_047", "中分類流通量_161砂利・砂"),
+    ("S10a_048", "中分類流通量_162石材"),
+    ("S10a_049", "中分類流通量_171原油"),
+    ("S10a_050", "中分類流通量_181りん鉱石"),
+    ("S10a_051", "中分類流通量_191石灰石"),
+    ("S10a_052", "中分類流通量_201原塩"),
+    ("S10a_053", "中分類流通量_211非金属鉱物"),
+    ("S10a_054", "中分類流通量_221鉄鋼"),
+    ("S10a_055", "中分類流通量_222鋼材"),
+    ("S10a_056", "中分類流通量_231非鉄金属"),
+    ("S10a_057", "中分類流通量_241金属製品"),
+    ("S10a_058", "中分類流通量_251鉄道車両"),
+    ("S10a_059", "中分類流通量_252完成自動車"),
+    ("S10a_060", "中分類流通量_253その他輸送用車両"),
+    ("S10a_061", "中分類流通量_254二輪自動車"),
+    ("S10a_062", "中分類流通量_255自動車部品"),
+    ("S10a_063", "中分類流通量_256その他輸送機械"),
+    ("S10a_064", "中分類流通量_261産業機械"),
+    ("S10a_065", "中分類流通量_262電気機械"),
+    ("S10a_066", "中分類流通量_263測量・光学・医療用器械"),
+    ("S10a_067", "中分類流通量_264事務用機器"),
+    ("S10a_068", "中分類流通量_265その他機械"),
+    ("S10a_069", "中分類流通量_271陶磁器"),
+    ("S10a_070", "中分類流通量_281セメント"),
+    ("S10a_071", "中分類流通量_291ガラス類"),
+    ("S10a_072", "中分類流通量_301窯業品"),
+    ("S10a_073", "中分類流通量_311重油"),
+    ("S10a_074", "中分類流通量_321石油製品"),
+    ("S10a_075", "中分類流通量_322LNG（液化天然ガス）"),
+    ("S10a_076", "中分類流通量_323LPG（液化石油ガス）"),
+    ("S10a_077", "中分類流通量_324その他石油製品"),
+    ("S10a_078", "中分類流通量_331コークス"),
+    ("S10a_079", "中分類流通量_341石炭製品"),
+    ("S10a_080", "中分類流通量_351化学薬品"),
+    ("S10a_081", "中分類流通量_361化学肥料"),
+    ("S10a_082", "中分類流通量_371染料・塗料・合成樹脂・その他化学工業品"),
+    ("S10a_083", "中分類流通量_381紙・パルプ"),
+    ("S10a_084", "中分類流通量_391糸及び紡績半製品"),
+    ("S10a_085", "中分類流通量_401その他繊維工業品"),
+    ("S10a_086", "中分類流通量_411砂糖"),
+    ("S10a_087", "中分類流通量_421製造食品"),
+    ("S10a_088", "中分類流通量_422飲料"),
+    ("S10a_089", "中分類流通量_423水"),
+    ("S10a_090", "中分類流通量_424たばこ"),
+    ("S10a_091", "中分類流通量_425その他食料工業品"),
+    ("S10a_092", "中分類流通量_431がん具"),
+    ("S10a_093", "中分類流通量_441衣服・身廻品・はきもの"),
+    ("S10a_094", "中分類流通量_442文房具・運動娯楽用品・楽器"),
+    ("S10a_095", "中分類流通量_443家具装備品"),
+    ("S10a_096", "中分類流通量_444その他日用品"),
+    ("S10a_097", "中分類流通量_451ゴム製品"),
+    ("S10a_098", "中分類流通量_461木製品（他に分類されないもの）"),
+    ("S10a_099", "中分類流通量_471その他製造工業品"),
+    ("S10a_100", "中分類流通量_481金属くず"),
+    ("S10a_101", "中分類流通量_491再利用資材"),
+    ("S10a_102", "中分類流通量_501動植物性製造飼肥料"),
+    ("S10a_103", "中分類流通量_511廃棄物"),
+    ("S10a_104", "中分類流通量_512廃土砂"),
+    ("S10a_105", "中分類流通量_521輸送用容器"),
+    ("S10a_106", "中分類流通量_531取合せ品"),
+    ("S10a_107", "中分類流通量_541分類不能のもの（中分類）"),
+    ("S10a_108", "出発港_都道府県"),
+    ("S10a_109", "出発港_港湾名"),
+    ("S10a_110", "寄港地_都道府県"),
+    ("S10a_111", "寄港地_港湾名"),
+    ("S10a_112", "到着港_都道府県"),
+    ("S10a_113", "到着港_港湾名"),
+    ("S10a_114", "船種"),
+    ("S10a_115", "航行距離"),
+    ("S10a_116", "総トン"),
+    ("S10a_117", "積載可能貨物量_トラック"),
+    ("S10a_118", "積載可能貨物量_シャーシ"),
+    ("S10a_119", "積載可能貨物量_トレーラー"),
+    ("S10a_120", "積載可能貨物量_中型車"),
+    ("S10a_121", "積載可能貨物量_乗用車"),
+    ("S10a_122", "積載可能貨物量_コンテナ"),
+    ("S10a_123", "積載可能貨物量_その他"),
+    ("S10a_124", "積載可能貨物量_化成品"),
+    ("S10a_125", "運搬可能旅客数"),
+    ("S10a_126", "頻度"),
+  ]),
+
+  ("S10b", &[
+    ("S10b_001", "出発空港"),
+    ("S10b_002", "経由空港１"),
+    ("S10b_003", "経由空港２"),
+    ("S10b_004", "到着空港"),
+    ("S10b_005", "区間距離"),
+    ("S10b_006", "頻度"),
+    ("S10b_007", "旅客数"),
+    ("S10b_008", "運搬可能旅客数"),
+    ("S10b_009", "貨物量"),
+  ]),
+
+  ("S12", &[
+    ("S12_001", "駅名"),
+    ("S12_002", "運営会社"),
+    ("S12_003", "路線名"),
+    ("S12_004", "鉄道区分"),
+    ("S12_005", "事業者種別"),
+    ("S12_006", "重複コード2011"),
+    ("S12_007", "データ有無コード2011"),
+    ("S12_008", "備考2011"),
+    ("S12_009", "乗降客数2011"),
+    ("S12_010", "重複コード2012"),
+    ("S12_011", "データ有無コード2012"),
+    ("S12_012", "備考2012"),
+    ("S12_013", "乗降客数2012"),
+    ("S12_014", "重複コード2013"),
+    ("S12_015", "データ有無コード2013"),
+    ("S12_016", "備考2013"),
+    ("S12_017", "乗降客数2013"),
+    ("S12_018", "重複コード2014"),
+    ("S12_019", "データ有無コード2014"),
+    ("S12_020", "備考2014"),
+    ("S12_021", "乗降客数2014"),
+    ("S12_022", "重複コード2015"),
+    ("S12_023", "データ有無コード2015"),
+    ("S12_024", "備考2015"),
+    ("S12_025", "乗降客数2015"),
+    ("S12_026", "重複コード2016"),
+    ("S12_027", "データ有無コード 2016"),
+    ("S12_028", "備考2016"),
+    ("S12_029", "乗降客数2016"),
+    ("S12_030", "重複コード2017"),
+    ("S12_031", "データ有無コード 2017"),
+    ("S12_032", "備考2017"),
+    ("S12_033", "乗降客数2017"),
+    ("S12_034", "重複コード2018"),
+    ("S12_035", "データ有無コード 2018"),
+    ("S12_036", "備考2018"),
+    ("S12_037", "乗降客数2018"),
+  ]),
+];
+#[cfg(all(feature = "dict-colnames", feature = "dict-transport"))]
+const _: () = assert!(super::is_sorted_by_key(COLNAMES_TRANSPORT) && super::is_each_sorted_by_key(COLNAMES_TRANSPORT));
 
-  ("W07_001", "細分メッシュコード"),
-  ("W07_002", "水系域コード"),
-  ("W07_003", "河川コード"),
-  ("W07_004", "水系名"),
-  ("W07_005", "河川名"),
-  ("W07_006", "単位流域コード"),
+#[cfg(all(feature = "dict-colnames", feature = "dict-land"))]
+#[rustfmt::skip]
+const COLNAMES_LAND: &[Colnames] = &[
+  ("G02", &[
+    ("G02_001", "3次メッシュコード"),
+    ("G02_002", "1月降水量"),
+    ("G02_003", "2月降水量"),
+    ("G02_004", "3月降水量"),
+    ("G02_005", "4月降水量"),
+    ("G02_006", "5月降水量"),
+    ("G02_007", "6月降水量"),
+    ("G02_008", "7月降水量"),
+    ("G02_009", "8月降水量"),
+    ("G02_010", "9月降水量"),
+    ("G02_011", "10月降水量"),
+    ("G02_012", "11月降水量"),
+    ("G02_013", "12月降水量"),
+    ("G02_014", "年降水量"),
+    ("G02_015", "1月最高気温"),
+    ("G02_016", "1月最低気温"),
+    ("G02_017", "1月平均気温"),
+    ("G02_018", "2月最高気温"),
+    ("G02_019", "2月最低気温"),
+    ("G02_020", "2月平均気温"),
+    ("G02_021", "3月最高気温"),
+    ("G02_022", "3月最低気温"),
+    ("G02_023", "3月平均気温"),
+    ("G02_024", "4月最高気温"),
+    ("G02_025", "4月最低気温"),
+    ("G02_026", "4月平均気温"),
+    ("G02_027", "5月最高気温"),
+    ("G02_028", "5月最低気温"),
+    ("G02_029", "5月平均気温"),
+    ("G02_030", "6月最高気温"),
+    ("G02_031", "6月最低気温"),
+    ("G02_032", "6月平均気温"),
+    ("G02_033", "7月最高気温"),
+    ("G02_034", "7月最低気温"),
+    ("G02_035", "7月平均気温"),
+    ("G02_036", "8月最高気温"),
+    ("G02_037", "8月最低気温"),
+    ("G02_038", "8月平均気温"),
+    ("G02_039", "9月最高気温"),
+    ("G02_040", "9月最低気温"),
+    ("G02_041", "9月平均気温"),
+    ("G02_042", "10月最高気温"),
+    ("G02_043", "10月最低気温"),
+    ("G02_044", "10月平均気温"),
+    ("G02_045", "11月最高気温"),
+    ("G02_046", "11月最低気温"),
+    ("G02_047", "11月平均気温"),
+    ("G02_048", "12月最高気温"),
+    ("G02_049", "12月最低気温"),
+    ("G02_050", "12月平均気温"),
+    ("G02_051", "年最高気温"),
+    ("G02_052", "年最低気温"),
+    ("G02_053", "年平均気温"),
+    ("G02_054", "1月最深積雪"),
+    ("G02_055", "2月最深積雪"),
+    ("G02_056", "3月最深積雪"),
+    ("G02_057", "12月最深積雪"),
+    ("G02_058", "年最深積雪"),
+    ("G02_059", "1月日照時間"),
+    ("G02_060", "2月日照時間"),
+    ("G02_061", "3月日照時間"),
+    ("G02_062", "4月日照時間"),
+    ("G02_063", "5月日照時間"),
+    ("G02_064", "6月日照時間"),
+    ("G02_065", "7月日照時間"),
+    ("G02_066", "8月日照時間"),
+    ("G02_067", "9月日照時間"),
+    ("G02_068", "10月日照時間"),
+    ("G02_069", "11月日照時間"),
+    ("G02_070", "12月日照時間"),
+    ("G02_071", "年合計日照時間"),
+    ("G02_072", "1月全天日射量"),
+    ("G02_073", "2月全天日射量"),
+    ("G02_074", "3月全天日射量"),
+    ("G02_075", "4月全天日射量"),
+    ("G02_076", "5月全天日射量"),
+    ("G02_077", "6月全天日射量"),
+    ("G02_078", "7月全天日射量"),
+    ("G02_079", "8月全天日射量"),
+    ("G02_080", "9月全天日射量"),
+    ("G02_081", "10月全天日射量"),
+    ("G02_082", "11月全天日射量"),
+    ("G02_083", "12月全天日射量"),
+    ("G02_084", "年平均全天日射量"),
+  ]),
+
+  ("G04-a", &[
+    ("G04a_001", "3次メッシュコード"),
+    ("G04a_002", "平均標高"),
+    ("G04a_003", "最高標高"),
+    ("G04a_004", "最低標高"),
+    ("G04a_005", "最低標高コード"),
+    ("G04a_006", "最大傾斜角度"),
+    ("G04a_007", "最大傾斜方向"),
+    ("G04a_008", "最小傾斜角度"),
+    ("G04a_009", "最小傾斜方向"),
+    ("G04a_010", "平均傾斜角度"),
+  ]),
+
+  ("G04-c", &[
+    ("G04c_001", "4次メッシュコード"),
+    ("G04c_002", "平均標高"),
+    ("G04c_003", "最高標高"),
+    ("G04c_004", "最低標高"),
+    ("G04c_005", "最低標高コード"),
+    ("G04c_006", "最大傾斜角度"),
+    ("G04c_007", "最大傾斜方向"),
+    ("G04c_008", "最小傾斜角度"),
+    ("G04c_009", "最小傾斜方向"),
+    ("G04c_010", "平均傾斜角度"),
+  ]),
+
+  ("G04-d", &[
+    ("G04d_001", "5次メッシュコード"),
+    ("G04d_002", "平均標高"),
+    ("G04d_003", "最高標高"),
+    ("G04d_004", "最低標高"),
+    ("G04d_005", "最低標高コード"),
+    ("G04d_006", "最大傾斜角度"),
+    ("G04d_007", "最大傾斜方向"),
+    ("G04d_008", "最小傾斜角度"),
+    ("G04d_009", "最小傾斜方向"),
+    ("G04d_010", "平均傾斜角度"),
+  ]),
+
+  ("G08", &[
+    ("G08_001", "面積"),
+    ("G08_002", "最大浸水深"),
+    ("G08_003", "参照資料コード"),
+  ]),
+
+  ("L03-b", &[
+    ("L03b_001", "メッシュコード"),
+    ("L03b_002", "土地利用種別"),
+    ("L03b_003", "衛星写真撮影年月日"),
+    ("L03b_004", "都市地域範囲"),
+  ]),
+
+  ("L03-b-c", &[
+    ("L03b_c_001", "メッシュコード"),
+    ("L03b_c_002", "土地利用種別"),
+    ("L03b_c_003", "衛星写真撮影年月日"),
+    ("L03b_c_004", "都市地域範囲"),
+  ]),
+
+  ("L05", &[
+    ("L05_001", "工業用地ID"),
+    ("L05_002", "工業用地名"),
+    ("L05_003", "行政コード"),
+    ("L05_004", "市区町村名"),
+    ("L05_005", "臨海・内陸区分コード"),
+    ("L05_006", "特記事項"),
+    ("L05_007", "事業主体名"),
+    ("L05_008", "事業完了年"),
+    ("L05_009", "全体開発面積"),
+    ("L05_010", "工業用地面積"),
+    ("L05_011", "未利用面積"),
+    ("L05_013", "用途地域コード"),
+    ("L05_014", "立地企業数"),
+    ("L05_015", "立地企業名1～10"),
+    ("L05_016", "業種"),
+  ]),
+];
+#[cfg(all(feature = "dict-colnames", feature = "dict-land"))]
+const _: () = assert!(super::is_sorted_by_key(COLNAMES_LAND) && super::is_each_sorted_by_key(COLNAMES_LAND));
 
-  ///// W09 /////
+#[cfg(all(feature = "dict-colnames", feature = "dict-facility"))]
+#[rustfmt::skip]
+const COLNAMES_FACILITY: &[Colnames] = &[
+  ("P03", &[
+    ("P03_0001", "事業者名等"),
+    ("P03_0002", "発電施設名称"),
+    ("P03_0003", "所在地"),
+    ("P03_0004", "既設・建設中区分"),
+    ("P03_0005", "運転開始年月日"),
+    ("P03_0006", "出力"),
+    ("P03_0007", "RPS法_認定日"),
+    ("P03_0008", "RPS法_発電出力"),
+    ("P03_0009", "グリーン発電電力設備_認定日"),
+    ("P03_0010", "グリーン発電電力設備_設備容量"),
+    ("P03_0101", "水系名"),
+    ("P03_0102", "型式"),
+    ("P03_0201", "水系名"),
+    ("P03_0202", "最大使用水量"),
+    ("P03_0203", "最大有効落差"),
+    ("P03_0204", "上池"),
+    ("P03_0205", "下池"),
+    ("P03_0206", "水車容量"),
+    ("P03_0207", "水車形式"),
+    ("P03_0208", "水車台数"),
+    ("P03_0209", "水力原動力"),
+    ("P03_0301", "ID"),
+    ("P03_0302", "名称"),
+    ("P03_0303", "有効容量"),
+    ("P03_0304", "利用水深"),
+    ("P03_0401", "号機"),
+    ("P03_0402", "転換年月"),
+    ("P03_0403", "専・混焼の別"),
+    ("P03_0404", "火力原動力"),
+    ("P03_0405", "混焼含有物"),
+    ("P03_0406", "脱硫方式"),
+    ("P03_0407", "処理ガス量"),
+    ("P03_0408", "比率"),
+    ("P03_0409", "処理ガス量"),
+    ("P03_0410", "比率"),
+    ("P03_0501", "蒸気供給部門"),
+    ("P03_0502", "号機"),
+    ("P03_0601", "号機"),
+    ("P03_0602", "炉型"),
+    ("P03_0603", "電源開発基本計画 組入年月"),
+    ("P03_0604", "原子炉設置許可年月日"),
+    ("P03_0605", "第一回工事計画認可年月"),
+    ("P03_0701", "号機"),
+    ("P03_0801", "号機"),
+    ("P03_0901", "種別"),
+  ]),
+
+  ("P04", &[
+    ("P04_001", "医療機関分類"),
+    ("P04_002", "施設名称"),
+    ("P04_003", "所在地"),
+    ("P04_004", "診療科目１"),
+    ("P04_005", "診療科目２"),
+    ("P04_006", "診療科目３"),
+    ("P04_007", "開設者分類"),
+  ]),
+
+  ("P05", &[
+    ("NA", "所在地"),
+  ]),
+
+  ("P07", &[
+    ("P07_001", "店舗分類コード"),
+    ("P07_002", "所在地"),
+  ]),
+
+  ("P09", &[
+    ("P09_001", "3次メッシュコード"),
+    ("P09_002", "施設数（ホテル）"),
+    ("P09_003", "施設数（旅館）"),
+    ("P09_004", "施設数（公共宿泊施設）"),
+    ("P09_005", "施設数（民宿）"),
+    ("P09_006", "施設数（ペンション）"),
+    ("P09_007", "施設数（宿坊）"),
+    ("P09_008", "施設数（コテージ・貸し別荘・山荘）"),
+    ("P09_009", "施設数（ユースホステル）"),
+    ("P09_010", "施設数（カプセルホテル）"),
+    ("P09_011", "施設数（研修センター）"),
+    ("P09_012", "施設総数"),
+    ("P09_013", "収容人数"),
+    ("P09_014", "室数"),
+  ]),
+
+  ("P11", &[
+    ("P11_001", "バス停名"),
+    ("P11_002", "バス区分"),
+    ("P11_003_1", "事業者名_1"),
+    ("P11_003_10", "事業者名_10"),
+    ("P11_003_11", "事業者名_11"),
+    ("P11_003_12", "事業者名_12"),
+    ("P11_003_13", "事業者名_13"),
+    ("P11_003_14", "事業者名_14"),
+    ("P11_003_15", "事業者名_15"),
+    ("P11_003_16", "事業者名_16"),
+    ("P11_003_17", "事業者名_17"),
+    ("P11_003_18", "事業者名_18"),
+    ("P11_003_19", "事業者名_19"),
+    ("P11_003_2", "事業者名_2"),
+    ("P11_003_3", "事業者名_3"),
+    ("P11_003_4", "事業者名_4"),
+    ("P11_003_5", "事業者名_5"),
+    ("P11_003_6", "事業者名_6"),
+    ("P11_003_7", "事業者名_7"),
+    ("P11_003_8", "事業者名_8"),
+    ("P11_003_9", "事業者名_9"),
+    ("P11_004_1", "バス系統_1"),
+    ("P11_004_10", "バス系統_10"),
+    ("P11_004_11", "バス系統_11"),
+    ("P11_004_12", "バス系統_12"),
+    ("P11_004_13", "バス系統_13"),
+    ("P11_004_14", "バス系統_14"),
+    ("P11_004_15", "バス系統_15"),
+    ("P11_004_16", "バス系統_16"),
+    ("P11_004_17", "バス系統_17"),
+    ("P11_004_18", "バス系統_18"),
+    ("P11_004_19", "バス系統_19"),
+    ("P11_004_2", "バス系統_2"),
+    ("P11_004_3", "バス系統_3"),
+    ("P11_004_4", "バス系統_4"),
+    ("P11_004_5", "バス系統_5"),
+    ("P11_004_6", "バス系統_6"),
+    ("P11_004_7", "バス系統_7"),
+    ("P11_004_8", "バス系統_8"),
+    ("P11_004_9", "バス系統_9"),
+  ]),
+
+  ("P12", &[
+    ("P12_001", "観光資源_ID"),
+    ("P12_002", "観光資源名"),
+    ("P12_003", "都道府県コード"),
+    ("P12_004", "行政コード"),
+    ("P12_005", "種別名称"),
+    ("P12_006", "所在地住所"),
+    ("P12_007", "観光資源分類コード"),
+  ]),
+
+  ("P13", &[
+    ("P13_001", "管理都道府県・整備局"),
+    ("P13_002", "管理市区町村"),
+    ("P13_003", "公園名"),
+    ("P13_004", "公園種別"),
+    ("P13_005", "所在地都道府県名"),
+    ("P13_006", "所在地市区町村名"),
+    ("P13_007", "供用開始年"),
+    ("P13_008", "供用済面積"),
+    ("P13_009", "都市計画決定"),
+    ("P13_010", "備考"),
+  ]),
+
+  ("P14", &[
+    ("P14_001", "都道府県名"),
+    ("P14_002", "市区町村名"),
+    ("P14_003", "所在地"),
+    ("P14_004", "公共施設大分類"),
+    ("P14_005", "公共施設小分類"),
+    ("P14_006", "福祉施設細分類"),
+    ("P14_007", "名称"),
+    ("P14_008", "管理者"),
+    ("P14_009", "定員"),
+    ("P14_010", "原典資料名"),
+    ("P14_011", "主題属性取得資料名"),
+  ]),
+
+  ("P15", &[
+    ("P15_001", "施設名称"),
+    ("P15_002", "地方公共団体名"),
+    ("P15_003", "施設種別"),
+    ("P15_004", "施設タイプ"),
+    ("P15_005", "処理能力（t/日）"),
+    ("P15_006", "屋内面積"),
+    ("P15_007", "屋外面積"),
+    ("P15_008", "全体容量"),
+    ("P15_009", "処理能力（kL/日）"),
+    ("P15_010", "計画最大汚水量"),
+    ("P15_011", "処理物"),
+    ("P15_012", "処理方式"),
+    ("P15_013", "炉形式"),
+    ("P15_014", "発電能力"),
+    ("P15_015", "事業者名"),
+    ("P15_016", "所在地"),
+    ("P15_017", "産廃施設種別"),
+    ("P15_018", "特別管理"),
+    ("P15_019", "取扱品目1"),
+    ("P15_020", "取扱品目2"),
+    ("P15_021", "取扱品目3"),
+    ("P15_022", "取扱品目4"),
+    ("P15_023", "取扱品目5"),
+    ("P15_024", "取扱品目6"),
+    ("P15_025", "取扱品目7"),
+    ("P15_026", "取扱品目8"),
+    ("P15_027", "取扱品目9"),
+    ("P15_028", "取扱品目10"),
+    ("P15_029", "取扱品目11"),
+    ("P15_030", "取扱品目12"),
+    ("P15_031", "取扱品目13"),
+    ("P15_032", "取扱品目14"),
+    ("P15_033", "取扱品目15"),
+  ]),
+
+  ("P16", &[
+    ("P16_001", "名称"),
+    ("P16_002", "主体コード"),
+    ("P16_003", "都道府県コード"),
+    ("P16_004", "所在地"),
+    ("P16_005", "ID"),
+    ("P16_006", "原典1"),
+    ("P16_007", "原典2"),
+    ("P16_008", "原典3"),
+    ("P16_009", "原典4"),
+    ("P16_010", "原典5"),
+    ("P16_011", "原典6"),
+    ("P16_012", "原典7"),
+    ("P16_013", "原典8"),
+    ("P16_014", "原典9"),
+    ("P16_015", "備考1"),
+    ("P16_016", "備考2"),
+    ("P16_017", "備考3"),
+    ("P16_018", "備考4"),
+    ("P16_019", "備考5"),
+    ("P16_020", "備考6"),
+    ("P16_021", "備考7"),
+    ("P16_022", "備考8"),
+    ("P16_023", "備考9"),
+    ("P16_024", "備考10"),
+    ("P16_025", "備考11"),
+    ("P16_026", "備考12"),
+  ]),
+
+  ("P17", &[
+    ("P17_001", "名称"),
+    ("P17_002", "行政コード"),
+    ("P17_003", "種別コード"),
+    ("P17_004", "所在地"),
+    ("P17_005", "名称"),
+  ]),
+
+  ("P18", &[
+    ("P18_001", "名称"),
+    ("P18_002", "行政コード"),
+    ("P18_003", "種別コード"),
+    ("P18_004", "所在地"),
+    ("P18_005", "名称"),
+  ]),
+
+  ("P19", &[
+    ("P19_001", "地域資源ID"),
+    ("P19_002", "都道府県コード"),
+    ("P19_003", "都道府県名"),
+    ("P19_004", "類型コード"),
+    ("P19_005", "類型名"),
+    ("P19_006", "自然景観資源分類コード"),
+    ("P19_007", "自然景観資源分類名"),
+    ("P19_008", "名称"),
+  ]),
+
+  ("P20", &[
+    ("P20_001", "行政区域"),
+    ("P20_002", "名称"),
+    ("P20_003", "住所"),
+    ("P20_004", "施設の種類"),
+    ("P20_005", "収容人数"),
+    ("P20_006", "施設規模"),
+    ("P20_007", "災害分類_地震災害"),
+    ("P20_008", "災害分類_津波災害"),
+    ("P20_009", "災害分類_水害"),
+    ("P20_010", "災害分類_火山災害"),
+    ("P20_011", "災害分類_その他"),
+    ("P20_012", "災害分類_指定なし"),
+  ]),
+
+  ("P21", &[
+    ("P21A_001", "事業主体"),
+    ("P21A_002", "事業名称"),
+    ("P21A_003", "種別"),
+    ("P21A_004", "給水人口"),
+    ("P21A_005", "日最大給水量"),
+    ("P21B_001", "事業主体"),
+    ("P21B_002", "事業名称"),
+    ("P21B_003", "施設名称"),
+    ("P21B_004", "日最大給水量"),
+  ]),
+
+  ("P22", &[
+    ("P22a_001", "行政区域"),
+    ("P22a_002", "施設名称"),
+    ("P22a_003", "所在地"),
+    ("P22a_004", "市町村等団体名"),
+    ("P22a_005", "事業区分名"),
+    ("P22a_006", "事業名"),
+    ("P22a_007", "全体計画処理面積_合計"),
+    ("P22a_008", "全体計画処理面積_汚水（分流）"),
+    ("P22a_009", "全体計画処理面積_雨水（分流）"),
+    ("P22a_010", "全体計画処理面積_合流"),
+    ("P22a_011", "全体計画計画処理人口"),
+    ("P22a_012", "全体計画区域内人口"),
+    ("P22a_013", "雨水計画_降雨強度最小"),
+    ("P22a_014", "雨水計画_降雨強度最大"),
+    ("P22a_015", "雨水計画_確率年最小"),
+    ("P22a_016", "雨水計画_確率年最大"),
+    ("P22a_017", "汚水計画_1人1日平均汚水最小"),
+    ("P22a_018", "汚水計画_1人1日平均汚水最大"),
+    ("P22a_019", "汚水計画_1人1日最大汚水最小"),
+    ("P22a_020", "汚水計画_1人1日最大汚水次第"),
+    ("P22a_021", "計画排水面積_汚水"),
+    ("P22a_022", "計画排水面積_雨水"),
+    ("P22a_023", "計画排水量_汚水（晴天時最大）"),
+    ("P22a_024", "計画排水量_汚水（雨天時最大）"),
+    ("P22a_025", "計画排水量_雨水"),
+    ("P22a_026", "現有排水能力_汚水揚水量（晴天時最大）"),
+    ("P22a_027", "現有排水能力_汚水揚水量（雨天時最大）"),
+    ("P22a_028", "現有排水能力_雨水揚水量"),
+    ("P22b_001", "行政区域"),
+    ("P22b_002", "施設名称"),
+    ("P22b_003", "所在地"),
+    ("P22b_004", "市町村等団体名"),
+    ("P22b_005", "事業区分名"),
+    ("P22b_006", "事業名"),
+    ("P22b_007", "全体計画処理面積_合計"),
+    ("P22b_008", "全体計画処理面積_汚水（分流）"),
+    ("P22b_009", "全体計画処理面積_雨水（分流）"),
+    ("P22b_010", "全体計画処理面積_合流"),
+    ("P22b_011", "全体計画計画処理人口"),
+    ("P22b_012", "全体計画区域内人口"),
+    ("P22b_013", "雨水計画_降雨強度最小"),
+    ("P22b_014", "雨水計画_降雨強度最大"),
+    ("P22b_015", "雨水計画_確率年最小"),
+    ("P22b_016", "雨水計画_確率年最大"),
+    ("P22b_017", "汚水計画_1人1日平均汚水最小"),
+    ("P22b_018", "汚水計画_1人1日平均汚水最大"),
+    ("P22b_019", "汚水計画_1人1日最大汚水最小"),
+    ("P22b_020", "汚水計画_1人1日最大汚水次第"),
+    ("P22b_021", "種類_水処理施設"),
+    ("P22b_022", "種類_汚泥処理施設"),
+    ("P22b_023", "種類_有効利用施設"),
+    ("P22b_024", "種類_再生水利用"),
+    ("P22b_025", "放流先河川"),
+    ("P22b_026", "水処理施設処理面積_現在"),
+    ("P22b_027", "水処理施設処理面積_計画"),
+    ("P22b_028", "水処理施設処理人口_現在"),
+    ("P22b_029", "水処理施設処理人口_計画"),
+    ("P22b_030", "水処理施設全体計画区域内人口"),
+    ("P22b_031", "水処理施設晴天時1日最大処理量_現在"),
+    ("P22b_032", "水処理施設晴天時1日最大処理量_計画"),
+    ("P22b_033", "水処理施設雨天時最大処理量_現在"),
+    ("P22b_034", "水処理施設雨天時最大処理量_計画"),
+  ]),
+
+  ("P23", &[
+    ("P23a_001", "行政区域"),
+    ("P23a_002", "所管省庁"),
+    ("P23a_003", "管理者"),
+    ("P23a_004", "堤防"),
+    ("P23a_005", "突堤"),
+    ("P23a_006", "護岸"),
+    ("P23a_007", "胸壁"),
+    ("P23a_008", "離岸堤"),
+    ("P23a_009", "砂浜"),
+    ("P23a_010", "その他の施設"),
+    ("P23a_011", "延長"),
+    ("P23a_012", "天端高最大（現況）"),
+    ("P23a_013", "天端高最小（現況）"),
+    ("P23a_014", "天端高最大（計画）"),
+    ("P23a_015", "天端高最小（計画）"),
+    ("P23a_016", "基準面"),
+    ("P23b_001", "行政区域"),
+    ("P23b_002", "所管省庁"),
+    ("P23b_003", "管理者"),
+    ("P23b_004", "堤防"),
+    ("P23b_005", "突堤"),
+    ("P23b_006", "護岸"),
+    ("P23b_007", "胸壁"),
+    ("P23b_008", "離岸堤"),
+    ("P23b_009", "砂浜"),
+    ("P23b_010", "その他の施設"),
+    ("P23b_011", "延長"),
+    ("P23b_012", "天端高最大（現況）"),
+    ("P23b_013", "天端高最小（現況）"),
+    ("P23b_014", "天端高最大（計画）"),
+    ("P23b_015", "天端高最小（計画）"),
+    ("P23b_016", "基準面"),
+  ]),
+
+  ("P24", &[
+    ("P24_001", "地場産業関連施設ID"),
+    ("P24_002", "行政コード"),
+    ("P24_003", "都道府県名"),
+    ("P24_004", "市区町村名"),
+    ("P24_005", "施設名"),
+    ("P24_006", "所在地"),
+    ("P24_007", "直売所有り"),
+    ("P24_008", "直営レストラン有り"),
+    ("P24_009", "農林漁業体験民宿有り"),
+    ("P24_010", "市民農園有り"),
+    ("P24_011", "原典コード"),
+    ("P24_012", "URL"),
+  ]),
+
+  ("P26", &[
+    ("P26_001", "都道府県名"),
+    ("P26_002", "市区町村名"),
+    ("P26_003", "地方公共団体コード"),
+    ("P26_004", "地区名"),
+    ("P26_005", "愛称名"),
+    ("P26_006", "連たんニュータウン名称"),
+    ("P26_007", "施行面積"),
+    ("P26_008", "事業主体"),
+    ("P26_009", "事業手法"),
+    ("P26_010", "事業開始年度（西暦）"),
+    ("P26_011", "事業終了（予定）年度（西暦）"),
+    ("P26_012", "計画戸数"),
+    ("P26_013", "計画人口"),
+  ]),
+
+  ("P27", &[
+    ("P27_001", "行政区域コード"),
+    ("P27_002", "公共施設大分類"),
+    ("P27_003", "公共施設小分類"),
+    ("P27_004", "文化施設分類"),
+    ("P27_005", "名称"),
+    ("P27_006", "所在地"),
+    ("P27_007", "管理者コード"),
+    ("P27_008", "階数"),
+    ("P27_009", "建築年"),
+  ]),
+
+  ("P28", &[
+    ("P28_001", "行政区域コード"),
+    ("P28_002", "公共施設大分類"),
+    ("P28_003", "公共施設小分類"),
+    ("P28_004", "国・都道府県の機関分類"),
+    ("P28_005", "名称"),
+    ("P28_006", "所在地"),
+    ("P28_007", "管理者コード"),
+  ]),
+
+  ("P29", &[
+    ("P29_001", "行政区域コード"),
+    ("P29_002", "公共施設大分類"),
+    ("P29_003", "公共施設小分類"),
+    ("P29_004", "学校分類"),
+    ("P29_005", "名称"),
+    ("P29_006", "所在地"),
+    ("P29_007", "管理者コード"),
+  ]),
+
+  ("P30", &[
+    ("P30_001", "行政区域コード"),
+    ("P30_002", "公共施設大分類"),
+    ("P30_003", "公共施設小分類"),
+    ("P30_004", "郵便局分類"),
+    ("P30_005", "名称"),
+    ("P30_006", "所在地"),
+    ("P30_007", "管理者コード"),
+  ]),
+
+  ("P31", &[
+    ("P31_001", "名称"),
+    ("P31_002", "種別"),
+    ("P31_003", "分類"),
+    ("P31_004", "都道府県コード"),
+    ("P31_005", "所在地"),
+    ("P31_006", "事業者分類"),
+    ("P31_007", "整備年"),
+    ("P31_008", "規模"),
+    ("P31_009", "備考"),
+  ]),
+
+  ("P32", &[
+    ("P32_001", "文化財ID"),
+    ("P32_002", "都道府県コード"),
+    ("P32_003", "行政コード"),
+    ("P32_004", "種別大区分コード"),
+    ("P32_005", "種別小区分コード"),
+    ("P32_006", "名称"),
+    ("P32_007", "所在地住所"),
+    ("P32_008", "指定年月日"),
+    ("P32_009", "代表点区分コード"),
+  ]),
+
+  ("P33", &[
+    ("P33_001", "施設ID"),
+    ("P33_002", "行政コード"),
+    ("P33_003", "都道府県コード"),
+    ("P33_004", "施設区分コード"),
+    ("P33_005", "施設名称"),
+    ("P33_006", "郵便番号"),
+    ("P33_007", "住所・所在地"),
+    ("P33_008", "電話番号"),
+    ("P33_009", "開設年月日"),
+    ("P33_010", "ホームページURL"),
+    ("P33_011", "アクセス"),
+    ("P33_012", "スクリーン数"),
+    ("P33_013", "総席数"),
+    ("P33_014", "公民館の種別"),
+    ("P33_015", "営業・稼働日数"),
+    ("P33_016", "営業時間"),
+    ("P33_017", "入場料の有無"),
+    ("P33_018", "敷地面積"),
+    ("P33_019", "建築総面積"),
+    ("P33_020", "ホール数"),
+    ("P33_021", "ホールの最大席数"),
+    ("P33_022", "ホールの総席数"),
+    ("P33_023", "会議室数"),
+    ("P33_024", "展示室数"),
+    ("P33_041", "代表点区分"),
+  ]),
+
+  ("P34", &[
+    ("P34_001", "行政区域コード"),
+    ("P34_002", "施設分類"),
+    ("P34_003", "名称"),
+    ("P34_004", "所在地"),
+  ]),
+
+  ("P35", &[
+    ("P35_001", "緯度"),
+    ("P35_002", "経度"),
+    ("P35_003", "都道府県名"),
+    ("P35_004", "市町村名"),
+    ("P35_005", "行政コード"),
+    ("P35_006", "道の駅名"),
+    ("P35_007", "ホームページアドレス1"),
+    ("P35_008", "ホームページアドレス2"),
+    ("P35_009", "ホームページアドレス3"),
+    ("P35_010", "ホームページアドレス4"),
+    ("P35_011", "ATM有無"),
+    ("P35_012", "ベビーベッド有無"),
+    ("P35_013", "レストラン有無"),
+    ("P35_014", "軽食・喫茶有無"),
+    ("P35_015", "宿泊施設有無"),
+    ("P35_016", "温泉施設有無"),
+    ("P35_017", "キャンプ場等有無"),
+    ("P35_018", "公園有無"),
+    ("P35_019", "展望台有無"),
+    ("P35_020", "美術館・博物館有無"),
+    ("P35_021", "ガソリンスタンド有無"),
+    ("P35_022", "EV充電施設有無"),
+    ("P35_023", "無線LAN有無"),
+    ("P35_024", "シャワー有無"),
+    ("P35_025", "体験施設有無"),
+    ("P35_026", "観光案内有無"),
+    ("P35_027", "身障者トイレ有無"),
+    ("P35_028", "ショップ有無"),
+  ]),
+];
+#[cfg(all(feature = "dict-colnames", feature = "dict-facility"))]
+const _: () = assert!(super::is_sorted_by_key(COLNAMES_FACILITY) && super::is_each_sorted_by_key(COLNAMES_FACILITY));
 
-  ("W09_001", "湖沼名"),
-  ("W09_002", "行政区域"),
-  ("W09_003", "最大水深"),
-  ("W09_004", "水面標高"),
+#[cfg(all(feature = "dict-colnames", feature = "dict-water"))]
+#[rustfmt::skip]
+const COLNAMES_WATER: &[Colnames] = &[
+  ("W01", &[
+    ("W01_001", "ダム名"),
+    ("W01_002", "ダムコード"),
+    ("W01_003", "水系名"),
+    ("W01_004", "河川名"),
+    ("W01_005", "形式"),
+    ("W01_006", "目的"),
+    ("W01_007", "ダム規模（堤高）"),
+    ("W01_008", "ダム規模（堤頂長）"),
+    ("W01_009", "堤体積"),
+    ("W01_010", "総貯水量"),
+    ("W01_011", "ダム事業者"),
+    ("W01_012", "竣工年"),
+    ("W01_013", "所在地"),
+    ("W01_014", "位置情報精度"),
+  ]),
+
+  ("W05", &[
+    ("W05_001", "水系域コード"),
+    ("W05_002", "河川コード"),
+    ("W05_003", "区間種別"),
+    ("W05_004", "河川名"),
+    ("W05_005", "原典資料種別"),
+    ("W05_006", "流下方向判定"),
+    ("W05_007", "河川始点"),
+    ("W05_008", "河川終点"),
+    ("W05_009", "流路始点"),
+    ("W05_010", "流路終点"),
+    ("W05_011", "標高"),
+  ]),
+
+  ("W07", &[
+    ("W07_001", "細分メッシュコード"),
+    ("W07_002", "水系域コード"),
+    ("W07_003", "河川コード"),
+    ("W07_004", "水系名"),
+    ("W07_005", "河川名"),
+    ("W07_006", "単位流域コード"),
+  ]),
+
+  ("W09", &[
+    ("W09_001", "湖沼名"),
+    ("W09_002", "行政区域"),
+    ("W09_003", "最大水深"),
+    ("W09_004", "水面標高"),
+  ]),
+];
+#[cfg(all(feature = "dict-colnames", feature = "dict-water"))]
+const _: () = assert!(super::is_sorted_by_key(COLNAMES_WATER) && super::is_each_sorted_by_key(COLNAMES_WATER));
 
+// The column names of the enabled categories
+#[rustfmt::skip]
+pub(crate) static COLNAMES: &[&[Colnames]] = &[
+    #[cfg(all(feature = "dict-colnames", feature = "dict-policy"))]
+    COLNAMES_POLICY,
+    #[cfg(all(feature = "dict-colnames", feature = "dict-transport"))]
+    COLNAMES_TRANSPORT,
+    #[cfg(all(feature = "dict-colnames", feature = "dict-land"))]
+    COLNAMES_LAND,
+    #[cfg(all(feature = "dict-colnames", feature = "dict-facility"))]
+    COLNAMES_FACILITY,
+    #[cfg(all(feature = "dict-colnames", feature = "dict-water"))]
+    COLNAMES_WATER,
 ];
 
+/// Look up the column names of the KSJ id in the enabled categories
+pub(crate) fn lookup_colnames(ksj_id: &str) -> Option<&'static [(&'static str, &'static str)]> {
+    COLNAMES
+        .iter()
+        .find_map(|groups| super::search(groups, ksj_id))
+}
+
+/// Look up the column name in all the KSJ ids of the enabled categories. If
+/// the same column id appears in more than one KSJ id, the last one wins.
+pub(crate) fn lookup_colname(col_id: &str) -> Option<&'static str> {
+    COLNAMES
+        .iter()
+        .flat_map(|groups| groups.iter())
+        .rev()
+        .find_map(|(_, columns)| super::search(columns, col_id))
+}
+// End of the generated part
+
 pub(crate) const A42_COLNAMES_NORMAL: [&str; 9] = [
     "都道府県名",
     "市町村名",
@@ -1,2 +1,95 @@
+use std::cmp::Ordering;
+
+#[cfg(feature = "dict-codelists")]
 pub(crate) mod codelists;
 pub(crate) mod colnames;
+
+/// Look up the value of the key from the entries sorted by the key. This is
+/// `const fn` so that the dictionaries can be looked up at compile time.
+pub(crate) const fn search<T: Copy>(entries: &[(&str, T)], key: &str) -> Option<T> {
+    let (mut lo, mut hi) = (0, entries.len());
+    while lo < hi {
+        let mid = lo + (hi - lo) / 2;
+        match str_cmp(entries[mid].0, key) {
+            Ordering::Less => lo = mid + 1,
+            Ordering::Greater => hi = mid,
+            Ordering::Equal => return Some(entries[mid].1),
+        }
+    }
+    None
+}
+
+/// Whether the entries are sorted by the key without duplicates. This is
+/// `const fn` so that unsorted data can be rejected at compile time.
+#[cfg_attr(not(feature = "dict-codelists"), allow(dead_code))]
+pub(crate) const fn is_sorted_by_key<T>(entries: &[(&str, T)]) -> bool {
+    let mut i = 1;
+    while i < entries.len() {
+        if !matches!(str_cmp(entries[i - 1].0, entries[i].0), Ordering::Less) {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+/// Whether the entries of each group are sorted by the key in the same way as
+/// [`is_sorted_by_key`]
+// Unused when no category of the column names is enabled
+#[allow(dead_code)]
+pub(crate) const fn is_each_sorted_by_key<T>(groups: &[(&str, &[(&str, T)])]) -> bool {
+    let mut i = 0;
+    while i < groups.len() {
+        if !is_sorted_by_key(groups[i].1) {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+// Same as `a.cmp(b)`, which is not available in const context
+const fn str_cmp(a: &str, b: &str) -> Ordering {
+    let (a, b) = (a.as_bytes(), b.as_bytes());
+    let mut i = 0;
+    while i < a.len() && i < b.len() {
+        if a[i] != b[i] {
+            return if a[i] < b[i] {
+                Ordering::Less
+            } else {
+                Ordering::Greater
+            };
+        }
+        i += 1;
+    }
+    if a.len() < b.len() {
+        Ordering::Less
+    } else if a.len() > b.len() {
+        Ordering::Greater
+    } else {
+        Ordering::Equal
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sorted_entries() {
+        let entries = [("1", "a"), ("10", "b"), ("2", "c")];
+        assert!(is_sorted_by_key(&entries));
+        assert_eq!(search(&entries, "10"), Some("b"));
+        assert_eq!(search(&entries, "3"), None);
+        assert_eq!(search(&entries, "0"), None);
+        const FOUND: Option<&str> = search(&[("a", "x"), ("b", "y")], "b");
+        assert_eq!(FOUND, Some("y"));
+
+        assert!(!is_sorted_by_key(&[("2", "a"), ("10", "b")]));
+        assert!(!is_sorted_by_key(&[("1", "a"), ("1", "b")]));
+
+        let groups: &[(&str, &[(&str, &str)])] = &[("A", &[("1", "a"), ("2", "b")]), ("B", &[])];
+        assert!(is_each_sorted_by_key(groups));
+        assert!(!is_each_sorted_by_key(&[("A", &[("2", "a"), ("1", "b")])]));
+    }
+}
@@ -1,11 +1,16 @@
+pub(crate) mod catalogue;
+mod categories;
 mod codelists;
 mod colnames;
 mod data;
 mod ksj_id;
+mod report;
 
+pub use codelists::Codelist;
 pub(crate) use codelists::get_codelist_map;
 pub(crate) use colnames::translate_colnames;
 pub use ksj_id::extract_ksj_id;
+pub use report::{RejectReason, RejectedValue, TranslationReport};
 
 pub struct TranslateOptions {
     pub translate_colnames: bool,
@@ -14,4 +19,17 @@ pub struct TranslateOptions {
     pub ksj_id: String,
     pub year: u16,
     pub target_shp: String,
+    pub codelist_validation: CodelistValidation,
+}
+
+/// How to handle the values that don't match the codelist
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum CodelistValidation {
+    /// Keep the raw value as it is
+    #[default]
+    Off,
+    /// Fail the conversion at the first invalid value
+    Strict,
+    /// Keep the raw value, and record it in the rejects of the report
+    Reject,
 }
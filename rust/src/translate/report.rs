@@ -0,0 +1,215 @@
+use std::collections::BTreeMap;
+use std::io::Write;
+
+use crate::{error::Ksj2GpError, translate::CodelistValidation};
+
+/// Summary of the values that couldn't be translated during the conversion.
+///
+/// The keys are the original column names (e.g. `P04_001`), so that the
+/// missing entries can be added to the dictionaries.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct TranslationReport {
+    /// Columns whose names are kept as they are because no translation is found
+    pub untranslated_columns: Vec<String>,
+    /// Codes not found in the codelist, with the number of occurrences
+    pub unknown_codes: BTreeMap<String, BTreeMap<String, u64>>,
+    /// Number of values that cannot be treated as a code (e.g. a date in a
+    /// codelist column), which are written as `"Unexpected value"`
+    pub unexpected_values: BTreeMap<String, u64>,
+    /// Values that don't match the codelist. This is recorded only when
+    /// `CodelistValidation::Reject` is specified.
+    pub rejects: Vec<RejectedValue>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct RejectedValue {
+    /// 0-based index of the record in the shapefile
+    pub row: usize,
+    /// Original column name
+    pub column: String,
+    pub value: String,
+    pub reason: RejectReason,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RejectReason {
+    /// The code is not found in the codelist
+    UnknownCode,
+    /// The value is not a type that can be a code (e.g. date)
+    UnexpectedType,
+    /// The numeric value is not an integer (e.g. `1.5`), so converting it to a
+    /// code loses information
+    LossyNumber,
+}
+
+impl RejectReason {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            RejectReason::UnknownCode => "unknown_code",
+            RejectReason::UnexpectedType => "unexpected_type",
+            RejectReason::LossyNumber => "lossy_number",
+        }
+    }
+}
+
+impl TranslationReport {
+    pub fn is_empty(&self) -> bool {
+        self.untranslated_columns.is_empty()
+            && self.unknown_codes.is_empty()
+            && self.unexpected_values.is_empty()
+            && self.rejects.is_empty()
+    }
+
+    pub fn to_json(&self) -> serde_json::Value {
+        let rejects: Vec<serde_json::Value> = self
+            .rejects
+            .iter()
+            .map(|r| {
+                serde_json::json!({
+                    "row": r.row,
+                    "column": r.column,
+                    "value": r.value,
+                    "reason": r.reason.as_str(),
+                })
+            })
+            .collect();
+
+        serde_json::json!({
+            "untranslated_columns": self.untranslated_columns,
+            "unknown_codes": self.unknown_codes,
+            "unexpected_values": self.unexpected_values,
+            "rejects": rejects,
+        })
+    }
+
+    /// Write the rejected values as CSV
+    pub fn write_rejects_csv<W: Write>(&self, writer: W) -> Result<(), Ksj2GpError> {
+        let mut writer = csv::Writer::from_writer(writer);
+        writer.write_record(["row", "column", "value", "reason"])?;
+        for r in &self.rejects {
+            writer.write_record([
+                r.row.to_string().as_str(),
+                r.column.as_str(),
+                r.value.as_str(),
+                r.reason.as_str(),
+            ])?;
+        }
+        writer.flush()?;
+        Ok(())
+    }
+
+    // Handle a value that doesn't match the codelist according to the
+    // validation mode.
+    pub(crate) fn record_invalid_value(
+        &mut self,
+        validation: CodelistValidation,
+        col_id: &str,
+        row: usize,
+        value: &str,
+        reason: RejectReason,
+    ) -> Result<(), Ksj2GpError> {
+        match validation {
+            CodelistValidation::Off => Ok(()),
+            CodelistValidation::Strict => Err(format!(
+                "Invalid value for {col_id} at row {row}: {value} ({})",
+                reason.as_str()
+            )
+            .into()),
+            CodelistValidation::Reject => {
+                self.rejects.push(RejectedValue {
+                    row,
+                    column: col_id.to_string(),
+                    value: value.to_string(),
+                    reason,
+                });
+                Ok(())
+            }
+        }
+    }
+
+    pub(crate) fn record_untranslated_column(&mut self, col_id: &str) {
+        if !self.untranslated_columns.iter().any(|c| c == col_id) {
+            self.untranslated_columns.push(col_id.to_string());
+        }
+    }
+
+    pub(crate) fn record_unknown_code(&mut self, col_id: &str, code: &str) {
+        let counts = self.unknown_codes.entry(col_id.to_string()).or_default();
+        *counts.entry(code.to_string()).or_insert(0) += 1;
+    }
+
+    pub(crate) fn record_unexpected_value(&mut self, col_id: &str) {
+        *self
+            .unexpected_values
+            .entry(col_id.to_string())
+            .or_insert(0) += 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn counts_unknown_codes() {
+        let mut report = TranslationReport::default();
+        report.record_unknown_code("P04_001", "99");
+        report.record_unknown_code("P04_001", "99");
+        report.record_unknown_code("P04_001", "98");
+        report.record_unexpected_value("P04_007");
+        report.record_untranslated_column("FOO");
+        report.record_untranslated_column("FOO");
+
+        assert_eq!(
+            report.to_json(),
+            serde_json::json!({
+                "untranslated_columns": ["FOO"],
+                "unknown_codes": { "P04_001": { "98": 1, "99": 2 } },
+                "unexpected_values": { "P04_007": 1 },
+                "rejects": [],
+            })
+        );
+    }
+
+    #[test]
+    fn handles_invalid_values() {
+        let mut report = TranslationReport::default();
+
+        report
+            .record_invalid_value(
+                CodelistValidation::Off,
+                "P04_001",
+                0,
+                "99",
+                RejectReason::UnknownCode,
+            )
+            .unwrap();
+        assert!(report.rejects.is_empty());
+
+        let result = report.record_invalid_value(
+            CodelistValidation::Strict,
+            "P04_001",
+            1,
+            "1.5",
+            RejectReason::LossyNumber,
+        );
+        assert!(result.is_err());
+
+        report
+            .record_invalid_value(
+                CodelistValidation::Reject,
+                "P04_001",
+                2,
+                "a,b",
+                RejectReason::UnknownCode,
+            )
+            .unwrap();
+
+        let mut csv = Vec::new();
+        report.write_rejects_csv(&mut csv).unwrap();
+        assert_eq!(
+            String::from_utf8(csv).unwrap(),
+            "row,column,value,reason\n2,P04_001,\"a,b\",unknown_code\n"
+        );
+    }
+}
@@ -0,0 +1,91 @@
+use std::io::{Read, Seek, Write};
+
+use arrow_ipc::writer::{FileWriter, StreamWriter};
+
+use crate::{
+    crs::JapanCrs,
+    error::Ksj2GpError,
+    transform_coord::has_measures,
+    translate::{TranslateOptions, TranslationReport},
+    writer::{
+        ArrowIpcOptions, construct_schema_from_crs, finish_geometry, for_each_chunk,
+        native_type_from_shape_type, schema_with_native_geometry, wkb_array,
+    },
+};
+
+enum IpcWriter<W: Write> {
+    File(FileWriter<W>),
+    Stream(StreamWriter<W>),
+}
+
+impl<W: Write> IpcWriter<W> {
+    fn write(&mut self, batch: &arrow_array::RecordBatch) -> Result<(), Ksj2GpError> {
+        match self {
+            IpcWriter::File(w) => w.write(batch)?,
+            IpcWriter::Stream(w) => w.write(batch)?,
+        }
+        Ok(())
+    }
+
+    fn finish(&mut self) -> Result<(), Ksj2GpError> {
+        match self {
+            IpcWriter::File(w) => w.finish()?,
+            IpcWriter::Stream(w) => w.finish()?,
+        }
+        Ok(())
+    }
+}
+
+// Write the RecordBatches as Arrow IPC. If `stream` is true, the IPC streaming
+// format is used instead of the file format (a.k.a. Feather v2).
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn write_arrow_ipc<T: Read + Seek, D: Read + Seek, W: Write + Send>(
+    reader: &mut shapefile::Reader<T, D>,
+    writer: &mut W,
+    dbf_fields: &[dbase::FieldInfo],
+    crs: JapanCrs,
+    translate_options: &TranslateOptions,
+    report: &mut TranslationReport,
+    options: &ArrowIpcOptions,
+    stream: bool,
+) -> Result<(), Ksj2GpError> {
+    let fields_info = construct_schema_from_crs(dbf_fields, &crs, translate_options, report, true)?;
+
+    let measures = has_measures(reader.header());
+
+    // Fall back to WKB if there's no matching native type
+    let native_type = if options.native_encoding {
+        native_type_from_shape_type(
+            reader.header().shape_type,
+            measures,
+            &fields_info.geoarrow_type,
+        )
+    } else {
+        None
+    };
+    let schema_ref = match &native_type {
+        Some(native_type) => schema_with_native_geometry(&fields_info, native_type),
+        None => fields_info.schema_ref.clone(),
+    };
+
+    let mut ipc_writer = if stream {
+        IpcWriter::Stream(StreamWriter::try_new(writer, &schema_ref)?)
+    } else {
+        IpcWriter::File(FileWriter::try_new(writer, &schema_ref)?)
+    };
+
+    for_each_chunk(reader, dbf_fields, &fields_info, report, |chunk| {
+        let mut arrays = chunk.arrays;
+        arrays.push(finish_geometry(
+            wkb_array(&fields_info, chunk.shapes, measures)?,
+            native_type.as_ref(),
+        )?);
+
+        let batch = arrow_array::RecordBatch::try_new(schema_ref.clone(), arrays)?;
+        ipc_writer.write(&batch)
+    })?;
+
+    ipc_writer.finish()?;
+
+    Ok(())
+}
@@ -0,0 +1,224 @@
+use std::io::{Read, Seek, Write};
+
+use arrow_array::{Array, cast::AsArray as _};
+use geoarrow_array::cast::to_wkt;
+
+use crate::{
+    crs::JapanCrs,
+    error::Ksj2GpError,
+    transform_coord::{CoordTransformer, has_measures},
+    translate::{TranslateOptions, TranslationReport},
+    writer::{CsvGeometry, CsvOptions, construct_schema_from_crs, for_each_chunk, wkb_array},
+};
+
+const UTF8_BOM: &[u8] = b"\xEF\xBB\xBF";
+
+// Since CSV has no way to tell the CRS, the coordinates are transformed to WGS84
+// in the same way as GeoJSON (Tokyo Datum is transformed, and JGD2000 and
+// JGD2011 are written as they are).
+pub(crate) fn write_csv<T: Read + Seek, D: Read + Seek, W: Write + Send>(
+    reader: &mut shapefile::Reader<T, D>,
+    writer: &mut W,
+    dbf_fields: &[dbase::FieldInfo],
+    crs: JapanCrs,
+    translate_options: &TranslateOptions,
+    report: &mut TranslationReport,
+    options: &CsvOptions,
+) -> Result<(), Ksj2GpError> {
+    if options.geometry == CsvGeometry::LonLat {
+        let shape_type = reader.header().shape_type;
+        if !matches!(
+            shape_type,
+            shapefile::ShapeType::Point
+                | shapefile::ShapeType::PointM
+                | shapefile::ShapeType::PointZ
+                | shapefile::ShapeType::NullShape
+        ) {
+            return Err(format!(
+                "Longitude and latitude columns are only available for points, but the shape type is {shape_type}"
+            )
+            .into());
+        }
+    }
+
+    let measures = has_measures(reader.header());
+    let transformer = CoordTransformer::new(crs.clone());
+
+    let fields_info =
+        construct_schema_from_crs(dbf_fields, &crs, translate_options, report, false)?;
+
+    if options.bom {
+        writer.write_all(UTF8_BOM)?;
+    }
+
+    let mut csv_writer = csv::Writer::from_writer(writer);
+
+    let mut header: Vec<&str> = fields_info
+        .non_geo_fields
+        .iter()
+        .map(|f| f.name().as_str())
+        .collect();
+    match options.geometry {
+        CsvGeometry::Wkt | CsvGeometry::Wkb => header.push("geometry"),
+        CsvGeometry::LonLat => header.extend(["longitude", "latitude"]),
+    }
+    csv_writer.write_record(&header)?;
+
+    let mut record: Vec<String> = Vec::with_capacity(header.len());
+
+    for_each_chunk(reader, dbf_fields, &fields_info, report, |chunk| {
+        let num_rows = chunk.shapes.len();
+        let mut shapes = Vec::new();
+        let mut lonlats = Vec::new();
+
+        for shape in chunk.shapes {
+            let shape = transformer.transform_shape(shape)?;
+            match options.geometry {
+                CsvGeometry::Wkt | CsvGeometry::Wkb => shapes.push(shape),
+                CsvGeometry::LonLat => lonlats.push(to_lonlat(&shape)?),
+            }
+        }
+
+        let wkb_array = wkb_array(&fields_info, shapes, measures)?;
+        let wkt_array = match options.geometry {
+            CsvGeometry::Wkt => Some(to_wkt::<i32>(&wkb_array)?),
+            _ => None,
+        };
+
+        let mut lonlats = lonlats.into_iter();
+        for i in 0..num_rows {
+            record.clear();
+            for array in &chunk.arrays {
+                record.push(format_value(array.as_ref(), i)?);
+            }
+
+            match options.geometry {
+                CsvGeometry::Wkt => {
+                    let wkt = wkt_array.as_ref().unwrap().inner();
+                    record.push(if wkt.is_null(i) {
+                        String::new()
+                    } else {
+                        wkt.value(i).to_string()
+                    });
+                }
+                CsvGeometry::Wkb => {
+                    let wkb = wkb_array.inner();
+                    record.push(if wkb.is_null(i) {
+                        String::new()
+                    } else {
+                        to_hex(wkb.value(i))
+                    });
+                }
+                CsvGeometry::LonLat => match lonlats.next().flatten() {
+                    Some((lon, lat)) => {
+                        record.push(lon.to_string());
+                        record.push(lat.to_string());
+                    }
+                    None => record.extend([String::new(), String::new()]),
+                },
+            }
+
+            csv_writer.write_record(&record)?;
+        }
+
+        Ok(())
+    })?;
+
+    csv_writer.flush()?;
+
+    Ok(())
+}
+
+// A null shape is written as empty longitude and latitude
+fn to_lonlat(shape: &shapefile::Shape) -> Result<Option<(f64, f64)>, Ksj2GpError> {
+    match shape {
+        shapefile::Shape::Point(p) => Ok(Some((p.x, p.y))),
+        shapefile::Shape::PointM(p) => Ok(Some((p.x, p.y))),
+        shapefile::Shape::PointZ(p) => Ok(Some((p.x, p.y))),
+        shapefile::Shape::NullShape => Ok(None),
+        _ => Err(format!("Unexpected shape type: {}", shape.shapetype()).into()),
+    }
+}
+
+// Null is written as an empty string
+pub(super) fn format_value(array: &dyn Array, i: usize) -> Result<String, Ksj2GpError> {
+    if array.is_null(i) {
+        return Ok(String::new());
+    }
+
+    let value = match array.data_type() {
+        arrow_schema::DataType::Boolean => array.as_boolean().value(i).to_string(),
+        arrow_schema::DataType::Int32 => array
+            .as_primitive::<arrow_array::types::Int32Type>()
+            .value(i)
+            .to_string(),
+        arrow_schema::DataType::Float32 => array
+            .as_primitive::<arrow_array::types::Float32Type>()
+            .value(i)
+            .to_string(),
+        arrow_schema::DataType::Float64 => array
+            .as_primitive::<arrow_array::types::Float64Type>()
+            .value(i)
+            .to_string(),
+        arrow_schema::DataType::Utf8 => array.as_string::<i32>().value(i).to_string(),
+        arrow_schema::DataType::Date32 => array
+            .as_primitive::<arrow_array::types::Date32Type>()
+            .value_as_date(i)
+            .ok_or_else(|| format!("Invalid date at row {i}"))?
+            .to_string(),
+        data_type => {
+            return Err(format!("Unsupported column type for CSV: {data_type}").into());
+        }
+    };
+
+    Ok(value)
+}
+
+pub(super) fn to_hex(bytes: &[u8]) -> String {
+    const HEX: &[u8; 16] = b"0123456789ABCDEF";
+    let mut s = String::with_capacity(bytes.len() * 2);
+    for b in bytes {
+        s.push(HEX[(b >> 4) as usize] as char);
+        s.push(HEX[(b & 0x0F) as usize] as char);
+    }
+    s
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_value() {
+        let strings = arrow_array::StringArray::from(vec![Some("病院"), None]);
+        assert_eq!(format_value(&strings, 0).unwrap(), "病院");
+        assert_eq!(format_value(&strings, 1).unwrap(), "");
+
+        let floats = arrow_array::Float64Array::from(vec![1.5, 2.0]);
+        assert_eq!(format_value(&floats, 0).unwrap(), "1.5");
+        assert_eq!(format_value(&floats, 1).unwrap(), "2");
+
+        let dates = arrow_array::Date32Array::from(vec![19000]);
+        assert_eq!(format_value(&dates, 0).unwrap(), "2022-01-08");
+    }
+
+    #[test]
+    fn test_to_lonlat() {
+        let point = shapefile::Shape::Point(shapefile::Point::new(139.7, 35.7));
+        assert_eq!(to_lonlat(&point).unwrap(), Some((139.7, 35.7)));
+        assert_eq!(to_lonlat(&shapefile::Shape::NullShape).unwrap(), None);
+
+        // Tokyo Datum is transformed to WGS84
+        let transformer = CoordTransformer::new(JapanCrs::Tokyo);
+        let (lon, lat) = to_lonlat(&transformer.transform_shape(point).unwrap())
+            .unwrap()
+            .unwrap();
+        assert!((lon - 139.7).abs() > 1e-4 && (lon - 139.7).abs() < 1e-2);
+        assert!((lat - 35.7).abs() > 1e-4 && (lat - 35.7).abs() < 1e-2);
+    }
+
+    #[test]
+    fn test_to_hex() {
+        assert_eq!(to_hex(&[0x01, 0xAB, 0x00, 0xFF]), "01AB00FF");
+    }
+}
@@ -0,0 +1,397 @@
+use std::{
+    collections::HashMap,
+    io::{Read, Seek, Write},
+};
+
+use dbase::FieldValue;
+
+use crate::{
+    builder::{ArrayBuilderWithGeo, FieldsWithGeo},
+    crs::JapanCrs,
+    error::Ksj2GpError,
+    transform_coord::has_measures,
+    translate::{TranslateOptions, TranslationReport},
+    writer::{
+        CHUNK_SIZE, DatasetOptions, GeoParquetOptions, construct_schema_from_crs,
+        geoparquet_writer::GeoParquetBatchWriter, get_fields_except_geometry,
+        native_type_from_shape_type, push_shape,
+    },
+};
+
+// Same as Hive and Spark
+const NULL_PARTITION: &str = "__HIVE_DEFAULT_PARTITION__";
+
+struct Partition<W: Write + Send> {
+    builders: ArrayBuilderWithGeo,
+    num_rows: usize,
+    batch_writer: GeoParquetBatchWriter<W>,
+    // The row where the partition is used last, to find the least recently
+    // used one
+    last_used: usize,
+}
+
+impl<W: Write + Send> Partition<W> {
+    fn flush(&mut self, fields_info: &FieldsWithGeo) -> Result<(), Ksj2GpError> {
+        if self.num_rows == 0 {
+            return Ok(());
+        }
+
+        let builders =
+            std::mem::replace(&mut self.builders, fields_info.create_builders(CHUNK_SIZE)?);
+        let batch =
+            arrow_array::RecordBatch::try_new(fields_info.schema_ref.clone(), builders.finish())?;
+        self.batch_writer.write(&batch)?;
+        self.num_rows = 0;
+
+        Ok(())
+    }
+
+    fn finish(mut self, fields_info: &FieldsWithGeo) -> Result<(), Ksj2GpError> {
+        self.flush(fields_info)?;
+        self.batch_writer.finish()
+    }
+}
+
+// Write a Hive-partitioned GeoParquet dataset. `create_file` receives the path
+// relative to the root of the dataset (e.g.
+// "ksj_id=N03/year=2024/pref=13/part-0.parquet") and returns the writer for it.
+// Every part has the same schema.
+//
+// At most `max_open_files` parts are open at the same time. When a new
+// partition appears, the least recently used part is finished, and if the
+// partition appears again, the rest is written to a new part (e.g.
+// "part-1.parquet").
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn write_geoparquet_dataset<T, D, W, F>(
+    reader: &mut shapefile::Reader<T, D>,
+    mut create_file: F,
+    dbf_fields: &[dbase::FieldInfo],
+    crs: JapanCrs,
+    translate_options: &TranslateOptions,
+    report: &mut TranslationReport,
+    options: &GeoParquetOptions,
+    dataset_options: &DatasetOptions,
+) -> Result<(), Ksj2GpError>
+where
+    T: Read + Seek,
+    D: Read + Seek,
+    W: Write + Send,
+    F: FnMut(&str) -> Result<W, Ksj2GpError>,
+{
+    if options.spatial_sort.is_some() {
+        return Err("Spatial sort is not supported for the dataset output".into());
+    }
+
+    let fields_info = construct_schema_from_crs(dbf_fields, &crs, translate_options, report, true)?;
+
+    let measures = has_measures(reader.header());
+
+    // Fall back to WKB if there's no matching native type
+    let native_type = if options.native_encoding {
+        native_type_from_shape_type(
+            reader.header().shape_type,
+            measures,
+            &fields_info.geoarrow_type,
+        )
+    } else {
+        None
+    };
+
+    // Since shapefile::Record is a HashMap, the iterator of it doesn't maintain
+    // the order. So, this column names vector is needed to ensure the consistent
+    // order with the schema.
+    let field_names = get_fields_except_geometry(dbf_fields);
+
+    let partition_column = match &dataset_options.partition_column {
+        Some(column) => {
+            if !field_names.contains(&column.as_str()) {
+                return Err(format!("Partition column {column} doesn't exist").into());
+            }
+            Some(column.as_str())
+        }
+        None => None,
+    };
+    let partition_key = dataset_options
+        .partition_key
+        .as_deref()
+        .or(partition_column)
+        .map(escape_path_component);
+
+    let base_dir = format!(
+        "ksj_id={}/year={}",
+        escape_path_component(&translate_options.ksj_id),
+        translate_options.year
+    );
+
+    let max_open_files = dataset_options.max_open_files.max(1);
+    let mut partitions: HashMap<String, Partition<W>> = HashMap::new();
+    // Number of the parts created so far for each partition
+    let mut num_parts: HashMap<String, usize> = HashMap::new();
+
+    for (row, result) in reader.iter_shapes_and_records().enumerate() {
+        let (shape, mut record) = result?;
+
+        let dir = match (partition_column, &partition_key) {
+            (Some(column), Some(key)) => {
+                let value = match record.get(column) {
+                    Some(value) => partition_value(value, dataset_options.partition_prefix_len)?,
+                    None => return Err(format!("Not found {column}").into()),
+                };
+                format!("{base_dir}/{key}={value}")
+            }
+            _ => base_dir.clone(),
+        };
+
+        if !partitions.contains_key(&dir) {
+            if partitions.len() >= max_open_files {
+                let lru = partitions
+                    .iter()
+                    .min_by_key(|(_, partition)| partition.last_used)
+                    .map(|(dir, _)| dir.clone())
+                    .expect("partitions should not be empty");
+                if let Some(partition) = partitions.remove(&lru) {
+                    partition.finish(&fields_info)?;
+                }
+            }
+
+            let part = num_parts.entry(dir.clone()).or_insert(0);
+            let writer = create_file(&format!("{dir}/part-{part}.parquet"))?;
+            *part += 1;
+
+            let partition = Partition {
+                builders: fields_info.create_builders(CHUNK_SIZE)?,
+                num_rows: 0,
+                batch_writer: GeoParquetBatchWriter::try_new(
+                    writer,
+                    &fields_info,
+                    native_type.clone(),
+                    options,
+                )?,
+                last_used: row,
+            };
+            partitions.insert(dir.clone(), partition);
+        }
+
+        let partition = partitions
+            .get_mut(&dir)
+            .expect("the partition should be open");
+        partition.last_used = row;
+
+        for (i, field_name) in field_names.iter().enumerate() {
+            let value = record
+                .remove(field_name)
+                .ok_or_else(|| format!("Not found {field_name}"))?;
+            partition.builders.builders[i].push(value, field_name, row, report)?;
+        }
+
+        push_shape(&mut partition.builders.geo_builder, shape, measures)?;
+
+        partition.num_rows += 1;
+        if partition.num_rows >= CHUNK_SIZE {
+            partition.flush(&fields_info)?;
+        }
+    }
+
+    for (_, partition) in partitions {
+        partition.finish(&fields_info)?;
+    }
+
+    Ok(())
+}
+
+// The value as a path component. If `prefix_len` is specified, only the first
+// characters are used (e.g. the prefecture code "13" of the administrative
+// area code "13101").
+fn partition_value(value: &FieldValue, prefix_len: Option<usize>) -> Result<String, Ksj2GpError> {
+    let value = match value {
+        FieldValue::Character(Some(s)) | FieldValue::Memo(s) => s.trim().to_string(),
+        FieldValue::Numeric(Some(v)) | FieldValue::Double(v) | FieldValue::Currency(v) => {
+            if v.fract() == 0.0 {
+                format!("{}", *v as i64)
+            } else {
+                v.to_string()
+            }
+        }
+        FieldValue::Float(Some(v)) => v.to_string(),
+        FieldValue::Integer(v) => v.to_string(),
+        FieldValue::Logical(Some(v)) => v.to_string(),
+        FieldValue::Date(Some(d)) => {
+            format!("{:04}-{:02}-{:02}", d.year(), d.month(), d.day())
+        }
+        FieldValue::Character(None)
+        | FieldValue::Numeric(None)
+        | FieldValue::Float(None)
+        | FieldValue::Logical(None)
+        | FieldValue::Date(None) => return Ok(NULL_PARTITION.to_string()),
+        v => return Err(format!("Unsupported partition value: {v:?}").into()),
+    };
+
+    let value = match prefix_len {
+        Some(len) => value.chars().take(len).collect(),
+        None => value,
+    };
+
+    if value.is_empty() {
+        return Ok(NULL_PARTITION.to_string());
+    }
+
+    Ok(escape_path_component(&value))
+}
+
+// Characters that are percent-encoded in the path, in the same way as Hive
+const ESCAPED_CHARS: &[char] = &[
+    '"', '#', '%', '\'', '*', '/', ':', '=', '?', '\\', '{', '[', ']', '^', '<', '>', '|',
+];
+
+fn escape_path_component(s: &str) -> String {
+    let mut escaped = String::with_capacity(s.len());
+    for c in s.chars() {
+        if c.is_ascii_control() || ESCAPED_CHARS.contains(&c) {
+            escaped.push_str(&format!("%{:02X}", c as u32));
+        } else {
+            escaped.push(c);
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use arrow_array::cast::AsArray as _;
+    use parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
+
+    use super::*;
+    use crate::{translate::CodelistValidation, writer::shp_writer::write_shp_and_shx};
+
+    #[test]
+    fn test_write_geoparquet_dataset() {
+        let codes = ["13101", "27100", "13102", "27140"];
+
+        let shapes: Vec<shapefile::Shape> = (0..codes.len())
+            .map(|i| shapefile::Shape::Point(shapefile::Point::new(139.0 + i as f64, 35.0)))
+            .collect();
+        let (shp, shx) = write_shp_and_shx(&shapes, shapefile::ShapeType::Point).unwrap();
+
+        let mut dbf = Cursor::new(Vec::new());
+        {
+            let name = dbase::FieldName::try_from("N03_007").unwrap();
+            let mut dbf_writer = dbase::TableWriterBuilder::new()
+                .add_character_field(name, 5)
+                .build_with_dest(&mut dbf);
+            for code in codes {
+                let mut record = dbase::Record::default();
+                record.insert(
+                    "N03_007".to_string(),
+                    FieldValue::Character(Some(code.to_string())),
+                );
+                dbf_writer.write_record(&record).unwrap();
+            }
+            dbf_writer.finalize().unwrap();
+        }
+        dbf.set_position(0);
+
+        let dbase_reader = dbase::Reader::new(dbf).unwrap();
+        let dbf_fields = dbase_reader.fields().to_vec();
+        let shape_reader =
+            shapefile::ShapeReader::with_shx(Cursor::new(shp), Cursor::new(shx)).unwrap();
+        let mut reader = shapefile::Reader::new(shape_reader, dbase_reader);
+
+        let translate_options = TranslateOptions {
+            translate_colnames: false,
+            translate_contents: false,
+            ignore_translation_errors: false,
+            ksj_id: "N03".to_string(),
+            year: 2024,
+            target_shp: String::new(),
+            codelist_validation: CodelistValidation::Off,
+        };
+        let dataset_options = DatasetOptions {
+            partition_column: Some("N03_007".to_string()),
+            partition_key: Some("pref".to_string()),
+            partition_prefix_len: Some(2),
+            // The partitions alternate, so every row closes the other file
+            max_open_files: 1,
+        };
+
+        let root = std::env::temp_dir().join(format!("ksj2gp-dataset-test-{}", std::process::id()));
+        let mut paths = Vec::new();
+        write_geoparquet_dataset(
+            &mut reader,
+            |path| {
+                paths.push(path.to_string());
+                let path = root.join(path);
+                std::fs::create_dir_all(path.parent().unwrap())?;
+                Ok(std::fs::File::create(path)?)
+            },
+            &dbf_fields,
+            JapanCrs::JGD2011,
+            &translate_options,
+            &mut TranslationReport::default(),
+            &GeoParquetOptions::default(),
+            &dataset_options,
+        )
+        .unwrap();
+
+        assert_eq!(
+            paths,
+            [
+                "ksj_id=N03/year=2024/pref=13/part-0.parquet",
+                "ksj_id=N03/year=2024/pref=27/part-0.parquet",
+                "ksj_id=N03/year=2024/pref=13/part-1.parquet",
+                "ksj_id=N03/year=2024/pref=27/part-1.parquet",
+            ]
+        );
+
+        // Read the parts back
+        let mut values = Vec::new();
+        for path in &paths {
+            let file = std::fs::File::open(root.join(path)).unwrap();
+            let builder = ParquetRecordBatchReaderBuilder::try_new(file).unwrap();
+            assert!(
+                builder
+                    .metadata()
+                    .file_metadata()
+                    .key_value_metadata()
+                    .unwrap()
+                    .iter()
+                    .any(|kv| kv.key == "geo")
+            );
+
+            for batch in builder.build().unwrap() {
+                let batch = batch.unwrap();
+                let column = batch.column_by_name("N03_007").unwrap().as_string::<i32>();
+                values.extend(column.iter().map(|v| v.unwrap().to_string()));
+            }
+        }
+        assert_eq!(values, ["13101", "27100", "13102", "27140"]);
+
+        std::fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn test_partition_value() {
+        let value = FieldValue::Character(Some("13101".to_string()));
+        assert_eq!(partition_value(&value, None).unwrap(), "13101");
+        assert_eq!(partition_value(&value, Some(2)).unwrap(), "13");
+
+        let value = FieldValue::Numeric(Some(2024.0));
+        assert_eq!(partition_value(&value, None).unwrap(), "2024");
+
+        let value = FieldValue::Character(Some("東京都".to_string()));
+        assert_eq!(partition_value(&value, None).unwrap(), "東京都");
+
+        let value = FieldValue::Character(None);
+        assert_eq!(partition_value(&value, None).unwrap(), NULL_PARTITION);
+        let value = FieldValue::Character(Some("  ".to_string()));
+        assert_eq!(partition_value(&value, None).unwrap(), NULL_PARTITION);
+    }
+
+    #[test]
+    fn test_escape_path_component() {
+        assert_eq!(escape_path_component("N03"), "N03");
+        assert_eq!(escape_path_component("a/b=c"), "a%2Fb%3Dc");
+        assert_eq!(escape_path_component("100%"), "100%25");
+    }
+}
@@ -0,0 +1,801 @@
+use std::io::{Read, Seek, Write};
+
+use arrow_array::{Array, cast::AsArray as _};
+use flatbuffers::{FlatBufferBuilder, WIPOffset};
+use geo_traits::{
+    CoordTrait, Dimensions, GeometryTrait, GeometryType, LineStringTrait, MultiLineStringTrait,
+    MultiPointTrait, MultiPolygonTrait, PointTrait, PolygonTrait,
+};
+
+use crate::{
+    crs::JapanCrs,
+    error::Ksj2GpError,
+    transform_coord::has_measures,
+    translate::{TranslateOptions, TranslationReport},
+    writer::{construct_schema_from_crs, for_each_chunk, shape_to_geometry, spatial_sort::hilbert},
+};
+
+// https://github.com/flatgeobuf/flatgeobuf/blob/master/src/fbs/header.fbs
+const MAGIC_BYTES: [u8; 8] = [b'f', b'g', b'b', 3, b'f', b'g', b'b', 0];
+const INDEX_NODE_SIZE: u16 = 16;
+
+// GeometryType enum
+const GEOMETRY_TYPE_UNKNOWN: u8 = 0;
+const GEOMETRY_TYPE_POINT: u8 = 1;
+const GEOMETRY_TYPE_POLYGON: u8 = 3;
+const GEOMETRY_TYPE_MULTIPOINT: u8 = 4;
+const GEOMETRY_TYPE_MULTILINESTRING: u8 = 5;
+const GEOMETRY_TYPE_MULTIPOLYGON: u8 = 6;
+
+// ColumnType enum
+const COLUMN_TYPE_BOOL: u8 = 2;
+const COLUMN_TYPE_INT: u8 = 5;
+const COLUMN_TYPE_FLOAT: u8 = 9;
+const COLUMN_TYPE_DOUBLE: u8 = 10;
+const COLUMN_TYPE_STRING: u8 = 11;
+const COLUMN_TYPE_DATETIME: u8 = 13;
+
+// The vtable offset of the n-th field of a table is 4 + 2 * n
+const fn field_offset(n: u16) -> u16 {
+    4 + 2 * n
+}
+
+// Since the features need to be sorted along the Hilbert curve before writing
+// the index, the encoded features are kept in memory until all the shapes are
+// read. This means the memory usage is roughly the same as the output size.
+pub(crate) fn write_flatgeobuf<T: Read + Seek, D: Read + Seek, W: Write + Send>(
+    reader: &mut shapefile::Reader<T, D>,
+    writer: &mut W,
+    dbf_fields: &[dbase::FieldInfo],
+    crs: JapanCrs,
+    translate_options: &TranslateOptions,
+    report: &mut TranslationReport,
+) -> Result<(), Ksj2GpError> {
+    // Use the same column types as GeoParquet, except for dictionary encoding
+    // because FlatGeobuf has no such column type.
+    let fields_info =
+        construct_schema_from_crs(dbf_fields, &crs, translate_options, report, false)?;
+    let columns = fields_info
+        .non_geo_fields
+        .iter()
+        .map(|f| Ok((f.name().clone(), column_type(f.data_type())?)))
+        .collect::<Result<Vec<_>, Ksj2GpError>>()?;
+
+    let shape_type = reader.header().shape_type;
+    let measures = has_measures(reader.header());
+    let layer_geometry = LayerGeometry {
+        geometry_type: geometry_type_from_shape_type(shape_type),
+        has_z: shape_type.has_z(),
+        has_m: measures,
+    };
+
+    let mut features = FeatureBuffer::default();
+    let mut fbb = FlatBufferBuilder::new();
+    let mut properties = Vec::new();
+
+    for_each_chunk(reader, dbf_fields, &fields_info, report, |chunk| {
+        for (i, shape) in chunk.shapes.into_iter().enumerate() {
+            let geometry = match shape {
+                shapefile::Shape::NullShape => None,
+                shape => Some(shape_to_geometry(&shape, measures)?),
+            };
+
+            properties.clear();
+            for (col, array) in chunk.arrays.iter().enumerate() {
+                encode_property(&mut properties, col as u16, array.as_ref(), i)?;
+            }
+
+            fbb.reset();
+            let bbox = encode_feature(
+                &mut fbb,
+                geometry.as_ref(),
+                layer_geometry.geometry_type,
+                &properties,
+            )?;
+            features.push(fbb.finished_data(), bbox);
+        }
+
+        Ok(())
+    })?;
+
+    let layer_name = std::path::Path::new(&translate_options.target_shp)
+        .file_stem()
+        .map(|x| x.to_string_lossy().to_string())
+        .unwrap_or_else(|| "layer".to_string());
+
+    features.write(writer, &layer_name, layer_geometry, &columns, &crs)?;
+
+    Ok(())
+}
+
+// The geometry type and the dimensions declared in the header
+#[derive(Debug, Clone, Copy)]
+struct LayerGeometry {
+    geometry_type: u8,
+    has_z: bool,
+    has_m: bool,
+}
+
+// Multipatch is converted to MultiPolygon by CoordTransformer
+fn geometry_type_from_shape_type(shape_type: shapefile::ShapeType) -> u8 {
+    match shape_type {
+        shapefile::ShapeType::Point
+        | shapefile::ShapeType::PointM
+        | shapefile::ShapeType::PointZ => GEOMETRY_TYPE_POINT,
+        shapefile::ShapeType::Multipoint
+        | shapefile::ShapeType::MultipointM
+        | shapefile::ShapeType::MultipointZ => GEOMETRY_TYPE_MULTIPOINT,
+        shapefile::ShapeType::Polyline
+        | shapefile::ShapeType::PolylineM
+        | shapefile::ShapeType::PolylineZ => GEOMETRY_TYPE_MULTILINESTRING,
+        shapefile::ShapeType::Polygon
+        | shapefile::ShapeType::PolygonM
+        | shapefile::ShapeType::PolygonZ
+        | shapefile::ShapeType::Multipatch => GEOMETRY_TYPE_MULTIPOLYGON,
+        shapefile::ShapeType::NullShape => GEOMETRY_TYPE_UNKNOWN,
+    }
+}
+
+fn column_type(data_type: &arrow_schema::DataType) -> Result<u8, Ksj2GpError> {
+    match data_type {
+        arrow_schema::DataType::Boolean => Ok(COLUMN_TYPE_BOOL),
+        arrow_schema::DataType::Int32 => Ok(COLUMN_TYPE_INT),
+        arrow_schema::DataType::Float32 => Ok(COLUMN_TYPE_FLOAT),
+        arrow_schema::DataType::Float64 => Ok(COLUMN_TYPE_DOUBLE),
+        arrow_schema::DataType::Utf8 => Ok(COLUMN_TYPE_STRING),
+        arrow_schema::DataType::Date32 => Ok(COLUMN_TYPE_DATETIME),
+        _ => Err(format!("Unsupported column type for FlatGeobuf: {data_type}").into()),
+    }
+}
+
+// Properties are encoded as a sequence of (column index, value) pairs. Null
+// values are simply omitted.
+fn encode_property(
+    buf: &mut Vec<u8>,
+    col: u16,
+    array: &dyn Array,
+    i: usize,
+) -> Result<(), Ksj2GpError> {
+    if array.is_null(i) {
+        return Ok(());
+    }
+
+    buf.extend_from_slice(&col.to_le_bytes());
+    match array.data_type() {
+        arrow_schema::DataType::Boolean => {
+            buf.push(array.as_boolean().value(i) as u8);
+        }
+        arrow_schema::DataType::Int32 => {
+            let v = array
+                .as_primitive::<arrow_array::types::Int32Type>()
+                .value(i);
+            buf.extend_from_slice(&v.to_le_bytes());
+        }
+        arrow_schema::DataType::Float32 => {
+            let v = array
+                .as_primitive::<arrow_array::types::Float32Type>()
+                .value(i);
+            buf.extend_from_slice(&v.to_le_bytes());
+        }
+        arrow_schema::DataType::Float64 => {
+            let v = array
+                .as_primitive::<arrow_array::types::Float64Type>()
+                .value(i);
+            buf.extend_from_slice(&v.to_le_bytes());
+        }
+        arrow_schema::DataType::Utf8 => {
+            encode_string(buf, array.as_string::<i32>().value(i));
+        }
+        arrow_schema::DataType::Date32 => {
+            // DateTime is stored as an ISO 8601 string
+            let date = array
+                .as_primitive::<arrow_array::types::Date32Type>()
+                .value_as_date(i)
+                .ok_or_else(|| format!("Invalid date at row {i}"))?;
+            encode_string(buf, &date.to_string());
+        }
+        data_type => {
+            return Err(format!("Unsupported column type for FlatGeobuf: {data_type}").into());
+        }
+    }
+
+    Ok(())
+}
+
+fn encode_string(buf: &mut Vec<u8>, s: &str) {
+    buf.extend_from_slice(&(s.len() as u32).to_le_bytes());
+    buf.extend_from_slice(s.as_bytes());
+}
+
+/// Bounding box of a feature, or a node of the R-tree.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct NodeItem {
+    min_x: f64,
+    min_y: f64,
+    max_x: f64,
+    max_y: f64,
+    // For leaf nodes, the byte offset of the feature. For other nodes, the
+    // index of the first child node.
+    offset: u64,
+}
+
+impl NodeItem {
+    fn empty() -> Self {
+        Self {
+            min_x: f64::INFINITY,
+            min_y: f64::INFINITY,
+            max_x: f64::NEG_INFINITY,
+            max_y: f64::NEG_INFINITY,
+            offset: 0,
+        }
+    }
+
+    fn expand(&mut self, other: &NodeItem) {
+        self.min_x = self.min_x.min(other.min_x);
+        self.min_y = self.min_y.min(other.min_y);
+        self.max_x = self.max_x.max(other.max_x);
+        self.max_y = self.max_y.max(other.max_y);
+    }
+
+    fn expand_xy(&mut self, x: f64, y: f64) {
+        self.min_x = self.min_x.min(x);
+        self.min_y = self.min_y.min(y);
+        self.max_x = self.max_x.max(x);
+        self.max_y = self.max_y.max(y);
+    }
+
+    fn write_to(&self, buf: &mut Vec<u8>) {
+        buf.extend_from_slice(&self.min_x.to_le_bytes());
+        buf.extend_from_slice(&self.min_y.to_le_bytes());
+        buf.extend_from_slice(&self.max_x.to_le_bytes());
+        buf.extend_from_slice(&self.max_y.to_le_bytes());
+        buf.extend_from_slice(&self.offset.to_le_bytes());
+    }
+}
+
+#[derive(Default)]
+struct FeatureBuffer {
+    // Encoded features, concatenated
+    data: Vec<u8>,
+    // (start, end) of each feature in data
+    ranges: Vec<(usize, usize)>,
+    bboxes: Vec<NodeItem>,
+    // Whether any of the features has no geometry
+    has_null: bool,
+}
+
+impl FeatureBuffer {
+    // bbox is None if the feature has no geometry
+    fn push(&mut self, feature: &[u8], bbox: Option<NodeItem>) {
+        let start = self.data.len();
+        self.data.extend_from_slice(feature);
+        self.ranges.push((start, self.data.len()));
+        self.has_null |= bbox.is_none();
+        self.bboxes.push(bbox.unwrap_or_else(NodeItem::empty));
+    }
+
+    fn write<W: Write>(
+        self,
+        writer: &mut W,
+        layer_name: &str,
+        layer_geometry: LayerGeometry,
+        columns: &[(String, u8)],
+        crs: &JapanCrs,
+    ) -> Result<(), Ksj2GpError> {
+        let mut extent = NodeItem::empty();
+        for bbox in &self.bboxes {
+            extent.expand(bbox);
+        }
+
+        // Sort the features along the Hilbert curve. The offsets of the leaf
+        // nodes are rewritten to the byte offsets in the sorted order. A
+        // spatial index can't contain features without geometry, so the index
+        // is omitted and the original order is kept if there's any.
+        let order = if self.has_null {
+            (0..self.bboxes.len()).collect()
+        } else {
+            hilbert_order(&self.bboxes, &extent)
+        };
+        let mut leaves = Vec::with_capacity(order.len());
+        let mut offset = 0;
+        for &i in &order {
+            let (start, end) = self.ranges[i];
+            leaves.push(NodeItem {
+                offset,
+                ..self.bboxes[i]
+            });
+            offset += (end - start) as u64;
+        }
+
+        let features_count = leaves.len() as u64;
+        let with_index = !leaves.is_empty() && !self.has_null;
+        let index_node_size = if !with_index { 0 } else { INDEX_NODE_SIZE };
+
+        writer.write_all(&MAGIC_BYTES)?;
+
+        let header = encode_header(
+            layer_name,
+            &extent,
+            layer_geometry,
+            columns,
+            features_count,
+            index_node_size,
+            crs,
+        );
+        writer.write_all(&header)?;
+
+        if with_index {
+            let mut buf = Vec::new();
+            for node in build_packed_rtree(&leaves, &extent, INDEX_NODE_SIZE) {
+                node.write_to(&mut buf);
+            }
+            writer.write_all(&buf)?;
+        }
+
+        for &i in &order {
+            let (start, end) = self.ranges[i];
+            writer.write_all(&self.data[start..end])?;
+        }
+
+        Ok(())
+    }
+}
+
+fn encode_header(
+    layer_name: &str,
+    extent: &NodeItem,
+    layer_geometry: LayerGeometry,
+    columns: &[(String, u8)],
+    features_count: u64,
+    index_node_size: u16,
+    crs: &JapanCrs,
+) -> Vec<u8> {
+    let mut fbb = FlatBufferBuilder::new();
+
+    let name = fbb.create_string(layer_name);
+    let envelope = (features_count > 0 && extent.min_x <= extent.max_x)
+        .then(|| fbb.create_vector(&[extent.min_x, extent.min_y, extent.max_x, extent.max_y]));
+
+    let column_offsets: Vec<_> = columns
+        .iter()
+        .map(|(name, column_type)| {
+            let name = fbb.create_string(name);
+            let start = fbb.start_table();
+            fbb.push_slot_always(field_offset(0), name); // name
+            fbb.push_slot(field_offset(1), *column_type, 0); // type
+            fbb.end_table(start)
+        })
+        .collect();
+    let columns = fbb.create_vector(&column_offsets);
+
+    let (code, crs_name): (i32, _) = match crs {
+        JapanCrs::Tokyo => (4301, "Tokyo"),
+        JapanCrs::JGD2000 => (4612, "JGD2000"),
+        JapanCrs::JGD2011 => (6668, "JGD2011"),
+    };
+    let org = fbb.create_string("EPSG");
+    let crs_name = fbb.create_string(crs_name);
+    let start = fbb.start_table();
+    fbb.push_slot_always(field_offset(0), org); // org
+    fbb.push_slot(field_offset(1), code, 0); // code
+    fbb.push_slot_always(field_offset(2), crs_name); // name
+    let crs = fbb.end_table(start);
+
+    let start = fbb.start_table();
+    fbb.push_slot(field_offset(8), features_count, 0); // features_count
+    fbb.push_slot_always(field_offset(0), name); // name
+    if let Some(envelope) = envelope {
+        fbb.push_slot_always(field_offset(1), envelope); // envelope
+    }
+    fbb.push_slot_always(field_offset(7), columns); // columns
+    fbb.push_slot_always(field_offset(10), crs); // crs
+    fbb.push_slot(field_offset(9), index_node_size, 16); // index_node_size
+    fbb.push_slot(field_offset(2), layer_geometry.geometry_type, 0); // geometry_type
+    fbb.push_slot(field_offset(3), layer_geometry.has_z, false); // has_z
+    fbb.push_slot(field_offset(4), layer_geometry.has_m, false); // has_m
+    let header = fbb.end_table(start);
+
+    fbb.finish_size_prefixed(header, None);
+    fbb.finished_data().to_vec()
+}
+
+#[derive(Default)]
+struct GeometryParts {
+    xy: Vec<f64>,
+    z: Vec<f64>,
+    m: Vec<f64>,
+    ends: Vec<u32>,
+}
+
+impl GeometryParts {
+    fn push_coords(&mut self, coords: impl Iterator<Item = impl CoordTrait<T = f64>>) {
+        for c in coords {
+            self.xy.push(c.x());
+            self.xy.push(c.y());
+            match c.dim() {
+                Dimensions::Xyz => self.z.push(c.nth_or_panic(2)),
+                Dimensions::Xym => self.m.push(c.nth_or_panic(2)),
+                Dimensions::Xyzm => {
+                    self.z.push(c.nth_or_panic(2));
+                    self.m.push(c.nth_or_panic(3));
+                }
+                _ => {}
+            }
+        }
+        self.ends.push((self.xy.len() / 2) as u32);
+    }
+
+    fn push_polygon(&mut self, polygon: &impl PolygonTrait<T = f64>) {
+        if let Some(exterior) = polygon.exterior() {
+            self.push_coords(exterior.coords());
+        }
+        for interior in polygon.interiors() {
+            self.push_coords(interior.coords());
+        }
+    }
+
+    fn bbox(&self) -> NodeItem {
+        let mut bbox = NodeItem::empty();
+        for xy in self.xy.chunks_exact(2) {
+            bbox.expand_xy(xy[0], xy[1]);
+        }
+        bbox
+    }
+
+    fn finish<'a>(
+        &self,
+        fbb: &mut FlatBufferBuilder<'a>,
+        geometry_type: u8,
+    ) -> WIPOffset<flatbuffers::TableFinishedWIPOffset> {
+        // ends can be omitted when there's only one part
+        let ends = (self.ends.len() > 1).then(|| fbb.create_vector(&self.ends));
+        let xy = fbb.create_vector(&self.xy);
+        let z = (!self.z.is_empty()).then(|| fbb.create_vector(&self.z));
+        let m = (!self.m.is_empty()).then(|| fbb.create_vector(&self.m));
+
+        let start = fbb.start_table();
+        if let Some(ends) = ends {
+            fbb.push_slot_always(field_offset(0), ends); // ends
+        }
+        fbb.push_slot_always(field_offset(1), xy); // xy
+        if let Some(z) = z {
+            fbb.push_slot_always(field_offset(2), z); // z
+        }
+        if let Some(m) = m {
+            fbb.push_slot_always(field_offset(3), m); // m
+        }
+        fbb.push_slot(field_offset(6), geometry_type, 0); // type
+        fbb.end_table(start)
+    }
+}
+
+// Encode a feature into fbb and return the bounding box of the geometry. The
+// geometry is one produced by CoordTransformer, so polylines and polygons are
+// always Multi* types, same as the type in the header. If the geometry is None,
+// the feature is written without geometry and None is returned.
+fn encode_feature(
+    fbb: &mut FlatBufferBuilder,
+    geometry: Option<&geo_traits::structs::Geometry<f64>>,
+    geometry_type: u8,
+    properties: &[u8],
+) -> Result<Option<NodeItem>, Ksj2GpError> {
+    let Some(geometry) = geometry else {
+        let properties = fbb.create_vector(properties);
+        let start = fbb.start_table();
+        fbb.push_slot_always(field_offset(1), properties); // properties
+        let feature = fbb.end_table(start);
+        fbb.finish_size_prefixed(feature, None);
+        return Ok(None);
+    };
+
+    let (geometry, bbox) = match (geometry_type, geometry.as_type()) {
+        (GEOMETRY_TYPE_POINT, GeometryType::Point(p)) => {
+            let mut parts = GeometryParts::default();
+            parts.push_coords(p.coord().into_iter());
+            (parts.finish(fbb, geometry_type), parts.bbox())
+        }
+        (GEOMETRY_TYPE_MULTIPOINT, GeometryType::MultiPoint(mp)) => {
+            let mut parts = GeometryParts::default();
+            parts.push_coords(mp.points().filter_map(|p| p.coord()));
+            (parts.finish(fbb, geometry_type), parts.bbox())
+        }
+        (GEOMETRY_TYPE_MULTILINESTRING, GeometryType::MultiLineString(mls)) => {
+            let mut parts = GeometryParts::default();
+            for ls in mls.line_strings() {
+                parts.push_coords(ls.coords());
+            }
+            (parts.finish(fbb, geometry_type), parts.bbox())
+        }
+        (GEOMETRY_TYPE_MULTIPOLYGON, GeometryType::MultiPolygon(mp)) => {
+            encode_multipolygon(fbb, mp)
+        }
+        _ => {
+            return Err(format!(
+                "Unexpected geometry for FlatGeobuf (geometry type: {geometry_type})"
+            )
+            .into());
+        }
+    };
+
+    let properties = fbb.create_vector(properties);
+
+    let start = fbb.start_table();
+    fbb.push_slot_always(field_offset(0), geometry); // geometry
+    fbb.push_slot_always(field_offset(1), properties); // properties
+    let feature = fbb.end_table(start);
+
+    fbb.finish_size_prefixed(feature, None);
+
+    Ok(Some(bbox))
+}
+
+// MultiPolygon is stored as a vector of Polygon geometries in parts
+fn encode_multipolygon(
+    fbb: &mut FlatBufferBuilder,
+    multi_polygon: &impl MultiPolygonTrait<T = f64>,
+) -> (WIPOffset<flatbuffers::TableFinishedWIPOffset>, NodeItem) {
+    let mut bbox = NodeItem::empty();
+    let offsets: Vec<_> = multi_polygon
+        .polygons()
+        .map(|polygon| {
+            let mut parts = GeometryParts::default();
+            parts.push_polygon(&polygon);
+            bbox.expand(&parts.bbox());
+            parts.finish(fbb, GEOMETRY_TYPE_POLYGON)
+        })
+        .collect();
+    let parts = fbb.create_vector(&offsets);
+
+    let start = fbb.start_table();
+    fbb.push_slot(field_offset(6), GEOMETRY_TYPE_MULTIPOLYGON, 0); // type
+    fbb.push_slot_always(field_offset(7), parts); // parts
+    (fbb.end_table(start), bbox)
+}
+
+// Returns the indices of the items sorted by the Hilbert value of the center
+// of their bounding boxes. Same as the reference implementation, the order is
+// descending.
+fn hilbert_order(items: &[NodeItem], extent: &NodeItem) -> Vec<usize> {
+    const HILBERT_MAX: f64 = ((1 << 16) - 1) as f64;
+
+    let width = extent.max_x - extent.min_x;
+    let height = extent.max_y - extent.min_y;
+    let scale = |v: f64, min: f64, size: f64| {
+        if size > 0.0 {
+            (HILBERT_MAX * (v - min) / size).floor() as u32
+        } else {
+            0
+        }
+    };
+
+    let values: Vec<u32> = items
+        .iter()
+        .map(|item| {
+            let x = scale((item.min_x + item.max_x) / 2.0, extent.min_x, width);
+            let y = scale((item.min_y + item.max_y) / 2.0, extent.min_y, height);
+            hilbert(x, y)
+        })
+        .collect();
+
+    let mut order: Vec<usize> = (0..items.len()).collect();
+    order.sort_by(|&a, &b| values[b].cmp(&values[a]));
+    order
+}
+
+// Ranges of the node indices of each level, from the leaves to the root. The
+// nodes are stored from the root, so the leaves come last.
+fn level_bounds(num_items: usize, node_size: u16) -> Vec<std::ops::Range<usize>> {
+    let node_size = node_size.max(2) as usize;
+
+    let mut n = num_items;
+    let mut num_nodes = n;
+    let mut level_num_nodes = vec![n];
+    loop {
+        n = n.div_ceil(node_size);
+        num_nodes += n;
+        level_num_nodes.push(n);
+        if n == 1 {
+            break;
+        }
+    }
+
+    let mut bounds = Vec::with_capacity(level_num_nodes.len());
+    let mut end = num_nodes;
+    for size in level_num_nodes {
+        bounds.push(end - size..end);
+        end -= size;
+    }
+    bounds
+}
+
+// Build a packed Hilbert R-tree from the (already sorted) leaf nodes.
+fn build_packed_rtree(leaves: &[NodeItem], extent: &NodeItem, node_size: u16) -> Vec<NodeItem> {
+    let bounds = level_bounds(leaves.len(), node_size);
+    let num_nodes = bounds[0].end;
+
+    let mut nodes = vec![NodeItem::empty(); num_nodes];
+    nodes[bounds[0].clone()].copy_from_slice(leaves);
+
+    let node_size = node_size.max(2) as usize;
+    for level in 0..(bounds.len() - 1) {
+        let children = bounds[level].clone();
+        let parents = bounds[level + 1].clone();
+        for (parent, chunk_start) in parents.zip(children.clone().step_by(node_size)) {
+            let chunk_end = (chunk_start + node_size).min(children.end);
+            let mut node = NodeItem::empty();
+            for child in &nodes[chunk_start..chunk_end] {
+                node.expand(child);
+            }
+            node.offset = chunk_start as u64;
+            nodes[parent] = node;
+        }
+    }
+
+    // The root should cover the whole extent
+    debug_assert!(
+        nodes[0].min_x == extent.min_x
+            && nodes[0].min_y == extent.min_y
+            && nodes[0].max_x == extent.max_x
+            && nodes[0].max_y == extent.max_y
+    );
+
+    nodes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bbox(min_x: f64, min_y: f64, max_x: f64, max_y: f64) -> NodeItem {
+        NodeItem {
+            min_x,
+            min_y,
+            max_x,
+            max_y,
+            offset: 0,
+        }
+    }
+
+    #[test]
+    fn test_level_bounds() {
+        assert_eq!(level_bounds(1, 16), vec![1..2, 0..1]);
+        assert_eq!(level_bounds(16, 16), vec![1..17, 0..1]);
+        assert_eq!(level_bounds(17, 16), vec![3..20, 1..3, 0..1]);
+    }
+
+    #[test]
+    fn test_build_packed_rtree() {
+        let leaves: Vec<NodeItem> = (0..20)
+            .map(|i| {
+                let v = i as f64;
+                NodeItem {
+                    offset: i * 100,
+                    ..bbox(v, v, v + 1.0, v + 1.0)
+                }
+            })
+            .collect();
+        let extent = bbox(0.0, 0.0, 20.0, 20.0);
+
+        let nodes = build_packed_rtree(&leaves, &extent, 16);
+        assert_eq!(nodes.len(), 23);
+
+        // root
+        assert_eq!(
+            nodes[0],
+            NodeItem {
+                offset: 1,
+                ..extent
+            }
+        );
+        // intermediate nodes point to the first child
+        assert_eq!(
+            nodes[1],
+            NodeItem {
+                offset: 3,
+                ..bbox(0.0, 0.0, 16.0, 16.0)
+            }
+        );
+        assert_eq!(
+            nodes[2],
+            NodeItem {
+                offset: 19,
+                ..bbox(16.0, 16.0, 20.0, 20.0)
+            }
+        );
+        // leaves keep the feature offsets
+        assert_eq!(&nodes[3..], &leaves);
+    }
+
+    #[test]
+    fn test_hilbert_order() {
+        let items = vec![
+            bbox(0.0, 0.0, 1.0, 1.0),
+            bbox(9.0, 0.0, 10.0, 1.0),
+            bbox(0.0, 9.0, 1.0, 10.0),
+            bbox(9.0, 9.0, 10.0, 10.0),
+        ];
+        let extent = bbox(0.0, 0.0, 10.0, 10.0);
+
+        // The Hilbert curve visits (0, 0) -> (0, 1) -> (1, 1) -> (1, 0), and
+        // the order is descending
+        assert_eq!(hilbert_order(&items, &extent), vec![1, 3, 2, 0]);
+    }
+
+    #[test]
+    fn test_geometry_parts() {
+        let shape = shapefile::Shape::PolylineZ(shapefile::PolylineZ::new(vec![
+            shapefile::PointZ::new(139.0, 35.0, 10.0, 1.0),
+            shapefile::PointZ::new(140.0, 36.0, 20.0, 2.0),
+        ]));
+
+        let parts = |measures: bool| {
+            let geometry = shape_to_geometry(&shape, measures).unwrap();
+            let GeometryType::MultiLineString(mls) = geometry.as_type() else {
+                panic!("not a multilinestring");
+            };
+            let mut parts = GeometryParts::default();
+            for ls in mls.line_strings() {
+                parts.push_coords(ls.coords());
+            }
+            parts
+        };
+
+        let xyz = parts(false);
+        assert_eq!(xyz.xy, [139.0, 35.0, 140.0, 36.0]);
+        assert_eq!(xyz.z, [10.0, 20.0]);
+        assert!(xyz.m.is_empty());
+        assert_eq!(xyz.ends, [2]);
+
+        let xyzm = parts(true);
+        assert_eq!(xyzm.z, [10.0, 20.0]);
+        assert_eq!(xyzm.m, [1.0, 2.0]);
+    }
+
+    #[test]
+    fn test_feature_buffer_with_null() {
+        let layer_geometry = LayerGeometry {
+            geometry_type: GEOMETRY_TYPE_POINT,
+            has_z: false,
+            has_m: false,
+        };
+
+        let mut features = FeatureBuffer::default();
+        features.push(&[1, 1], Some(bbox(1.0, 1.0, 1.0, 1.0)));
+        features.push(&[2, 2], None);
+        features.push(&[3, 3], Some(bbox(0.0, 0.0, 0.0, 0.0)));
+
+        let mut buf = Vec::new();
+        features
+            .write(&mut buf, "layer", layer_geometry, &[], &JapanCrs::JGD2011)
+            .unwrap();
+
+        // No index, and the features are in the original order
+        let header = encode_header(
+            "layer",
+            &bbox(0.0, 0.0, 1.0, 1.0),
+            layer_geometry,
+            &[],
+            3,
+            0,
+            &JapanCrs::JGD2011,
+        );
+        assert_eq!(&buf[MAGIC_BYTES.len()..][..header.len()], header.as_slice());
+        assert_eq!(&buf[MAGIC_BYTES.len() + header.len()..], [1, 1, 2, 2, 3, 3]);
+    }
+
+    #[test]
+    fn test_encode_property() {
+        let mut buf = Vec::new();
+        let strings = arrow_array::StringArray::from(vec![Some("あ"), None]);
+        let ints = arrow_array::Int32Array::from(vec![1, 2]);
+
+        encode_property(&mut buf, 0, &strings, 0).unwrap();
+        encode_property(&mut buf, 1, &ints, 0).unwrap();
+        assert_eq!(buf, [0, 0, 3, 0, 0, 0, 0xE3, 0x81, 0x82, 1, 0, 1, 0, 0, 0]);
+
+        // null values are omitted
+        buf.clear();
+        encode_property(&mut buf, 0, &strings, 1).unwrap();
+        assert!(buf.is_empty());
+    }
+}
@@ -1,62 +1,455 @@
-use std::io::{Read, Seek, Write};
+use std::{
+    cell::RefCell,
+    io::{Read, Seek, Write},
+    rc::Rc,
+};
 
-use itertools::Itertools as _;
+use geo_traits::{
+    CoordTrait, GeometryCollectionTrait, GeometryTrait, GeometryType, LineStringTrait, LineTrait,
+    MultiLineStringTrait, MultiPointTrait, MultiPolygonTrait, PointTrait, PolygonTrait, RectTrait,
+    TriangleTrait,
+};
+use geoarrow_array::GeoArrowArray as _;
 
 use crate::{
-    builder::construct_schema, crs::JapanCrs, error::Ksj2GpError,
-    transform_coord::CoordTransformer, translate::TranslateOptions,
-    writer::get_fields_except_geometry,
+    crs::JapanCrs,
+    error::Ksj2GpError,
+    transform_coord::CoordTransformer,
+    translate::{TranslateOptions, TranslationReport},
+    writer::{GeoJsonOptions, construct_schema_from_crs, for_each_chunk},
 };
 
-// Number of rows to process at once
-const CHUNK_SIZE: usize = 2048;
-
 pub(crate) fn write_geojson<T: Read + Seek, D: Read + Seek, W: Write + Send>(
     reader: &mut shapefile::Reader<T, D>,
     writer: &mut W,
     dbf_fields: &[dbase::FieldInfo],
     crs: JapanCrs,
     translate_options: &TranslateOptions,
+    report: &mut TranslationReport,
+    options: &GeoJsonOptions,
 ) -> Result<(), Ksj2GpError> {
-    // TODO: include this in FieldsWithGeo
-    let transformer = CoordTransformer::new(crs.clone());
+    if !options.rfc7946 && !options.bbox {
+        let mut geojson_writer = geoarrow_geojson::writer::GeoJsonWriter::new(writer)?;
+
+        for_each_batch(
+            reader,
+            dbf_fields,
+            crs,
+            translate_options,
+            report,
+            options,
+            |batch, _| Ok(geojson_writer.write(batch)?),
+        )?;
+
+        geojson_writer.finish()?;
+
+        return Ok(());
+    }
+
+    // In RFC 7946 mode or when bbox is needed, the FeatureCollection is written
+    // here instead of GeoJsonWriter so that no foreign members (e.g. "crs") are
+    // included.
+    writer.write_all(br#"{"type":"FeatureCollection","features":["#)?;
+
+    let mut collection_bbox = Bbox::default();
+    let mut is_first = true;
+    let mut write_feature = |feature: &[u8]| -> Result<(), Ksj2GpError> {
+        if !is_first {
+            writer.write_all(b",")?;
+        }
+        is_first = false;
+        writer.write_all(b"\n")?;
+        writer.write_all(feature)?;
+        Ok(())
+    };
+
+    let mut serializer = FeatureSerializer::new();
+    for_each_batch(
+        reader,
+        dbf_fields,
+        crs,
+        translate_options,
+        report,
+        options,
+        |batch, bboxes| {
+            for bbox in bboxes.iter().flatten() {
+                collection_bbox.merge(bbox);
+            }
+
+            serializer.for_each_feature(batch, bboxes, &mut write_feature)
+        },
+    )?;
+    serializer.finish(&mut write_feature)?;
 
-    let projjson: serde_json::Value = serde_json::from_str(crs.to_projjson())
-        .expect("embedded PROJJSON should be valid JSON");
-    let crs = geoarrow_schema::Crs::from_projjson(projjson);
+    writer.write_all(b"\n]")?;
+    if options.bbox && !collection_bbox.is_empty() {
+        writer.write_all(br#","bbox":"#)?;
+        serde_json::to_writer(&mut *writer, &collection_bbox.to_array())
+            .map_err(|e| format!("{e:?}"))?;
+    }
+    writer.write_all(b"}\n")?;
+    writer.flush()?;
 
-    let fields_info = construct_schema(dbf_fields, crs, translate_options)?;
-    let schema_ref = fields_info.schema_ref.clone();
+    Ok(())
+}
 
-    // Since shapefile::Record is a HashMap, the iterator of it doesn't maintain
-    // the order. So, this column names vector is needed to ensure the consistent
-    // order with the schema.
-    let field_names = get_fields_except_geometry(dbf_fields);
+// Write one feature per line. If `record_separator` is true, each line is
+// prefixed with RS (0x1E) as specified in RFC 8142 (GeoJSON Text Sequences).
+// Otherwise, the output is plain newline-delimited GeoJSON.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn write_geojson_seq<T: Read + Seek, D: Read + Seek, W: Write + Send>(
+    reader: &mut shapefile::Reader<T, D>,
+    writer: &mut W,
+    dbf_fields: &[dbase::FieldInfo],
+    crs: JapanCrs,
+    translate_options: &TranslateOptions,
+    report: &mut TranslationReport,
+    options: &GeoJsonOptions,
+    record_separator: bool,
+) -> Result<(), Ksj2GpError> {
+    let mut write_feature = |feature: &[u8]| -> Result<(), Ksj2GpError> {
+        if record_separator {
+            writer.write_all(&[0x1E])?;
+        }
+        writer.write_all(feature)?;
+        writer.write_all(b"\n")?;
+        Ok(())
+    };
 
-    let mut geojson_writer = geoarrow_geojson::writer::GeoJsonWriter::new(writer)?;
+    let mut serializer = FeatureSerializer::new();
+    for_each_batch(
+        reader,
+        dbf_fields,
+        crs,
+        translate_options,
+        report,
+        options,
+        |batch, bboxes| serializer.for_each_feature(batch, bboxes, &mut write_feature),
+    )?;
+    serializer.finish(&mut write_feature)?;
 
-    for chunk in &reader.iter_shapes_and_records().chunks(CHUNK_SIZE) {
-        let mut builders = fields_info.create_builders(CHUNK_SIZE);
+    writer.flush()?;
 
-        for result in chunk {
-            let (shape, mut record) = result.unwrap();
+    Ok(())
+}
 
-            for (i, field_name) in field_names.iter().enumerate() {
-                let value = record
-                    .remove(field_name)
-                    .ok_or_else(|| format!("Not found {field_name}"))?;
-                builders.builders[i].push(value);
+// Read the shapes and records by chunk, and call `f` with the translated
+// RecordBatch and the bounding boxes of the features (only when
+// `options.bbox` is true). Unlike GeoParquet, the coordinates in Tokyo Datum
+// are transformed to WGS84 because GeoJSON doesn't allow other CRSs.
+fn for_each_batch<T: Read + Seek, D: Read + Seek>(
+    reader: &mut shapefile::Reader<T, D>,
+    dbf_fields: &[dbase::FieldInfo],
+    crs: JapanCrs,
+    translate_options: &TranslateOptions,
+    report: &mut TranslationReport,
+    options: &GeoJsonOptions,
+    mut f: impl FnMut(&arrow_array::RecordBatch, &[Option<Bbox>]) -> Result<(), Ksj2GpError>,
+) -> Result<(), Ksj2GpError> {
+    // TODO: include this in FieldsWithGeo
+    let transformer = CoordTransformer::new(crs.clone())
+        .with_precision(options.precision)
+        // RFC 7946 requires the right-hand rule
+        .with_ccw(options.force_ccw || options.rfc7946);
+
+    let fields_info =
+        construct_schema_from_crs(dbf_fields, &crs, translate_options, report, false)?;
+
+    let mut bboxes = Vec::new();
+
+    for_each_chunk(reader, dbf_fields, &fields_info, report, |chunk| {
+        let mut geo_builder = fields_info.create_geo_builder();
+        bboxes.clear();
+
+        for shape in chunk.shapes {
+            // A null shape is written as `"geometry": null`
+            if let shapefile::Shape::NullShape = shape {
+                if options.bbox {
+                    bboxes.push(None);
+                }
+                geo_builder.push_wkb(None)?;
+                continue;
             }
 
             let geometry = transformer.transform(&shape)?;
-            builders.geo_builder.push_geometry(Some(&geometry))?;
+            if options.bbox {
+                bboxes.push(Some(Bbox::from_geometry(&geometry)));
+            }
+            geo_builder.push_geometry(Some(&geometry))?;
         }
 
-        let batch = arrow_array::RecordBatch::try_new(schema_ref.clone(), builders.finish())?;
-        geojson_writer.write(&batch)?;
+        let mut arrays = chunk.arrays;
+        arrays.push(geo_builder.finish().into_array_ref());
+        let batch = arrow_array::RecordBatch::try_new(fields_info.schema_ref.clone(), arrays)?;
+        f(&batch, &bboxes)
+    })
+}
+
+// Serialize the batches into GeoJSON Features. One GeoJsonLinesWriter is used
+// for the whole output, and the features are read out of the shared buffer
+// after each batch.
+struct FeatureSerializer {
+    lines_writer: geoarrow_geojson::writer::GeoJsonLinesWriter<SharedBuffer>,
+    buffer: SharedBuffer,
+}
+
+impl FeatureSerializer {
+    fn new() -> Self {
+        let buffer = SharedBuffer::default();
+        Self {
+            lines_writer: geoarrow_geojson::writer::GeoJsonLinesWriter::new(buffer.clone()),
+            buffer,
+        }
     }
 
-    geojson_writer.finish()?;
+    // Call `f` with each feature of the batch. If `bboxes` is not empty,
+    // "bbox" member is added to the features.
+    fn for_each_feature(
+        &mut self,
+        batch: &arrow_array::RecordBatch,
+        bboxes: &[Option<Bbox>],
+        f: impl FnMut(&[u8]) -> Result<(), Ksj2GpError>,
+    ) -> Result<(), Ksj2GpError> {
+        self.lines_writer.write(batch)?;
+        Self::drain(&self.buffer, bboxes, f)
+    }
+
+    // Finish the writer, and call `f` with the features written on finishing,
+    // if any
+    fn finish(self, f: impl FnMut(&[u8]) -> Result<(), Ksj2GpError>) -> Result<(), Ksj2GpError> {
+        let Self {
+            lines_writer,
+            buffer,
+        } = self;
+        lines_writer.finish()?;
+        Self::drain(&buffer, &[], f)
+    }
+
+    fn drain(
+        buffer: &SharedBuffer,
+        bboxes: &[Option<Bbox>],
+        mut f: impl FnMut(&[u8]) -> Result<(), Ksj2GpError>,
+    ) -> Result<(), Ksj2GpError> {
+        let buf = buffer.take();
+
+        // Since JSON never contains a raw newline inside a string, it's safe to
+        // split the features simply by b'\n'.
+        let features = buf.split(|&b| b == b'\n').filter(|line| !line.is_empty());
+
+        let mut feature_with_bbox = Vec::new();
+        for (i, feature) in features.enumerate() {
+            match bboxes.get(i) {
+                Some(Some(bbox)) if !bbox.is_empty() => {
+                    feature_with_bbox.clear();
+                    add_bbox(&mut feature_with_bbox, feature, bbox)?;
+                    f(&feature_with_bbox)?;
+                }
+                _ => f(feature)?,
+            }
+        }
+
+        Ok(())
+    }
+}
+
+// A buffer that can be read while GeoJsonLinesWriter holds it
+#[derive(Clone, Default)]
+struct SharedBuffer(Rc<RefCell<Vec<u8>>>);
+
+impl SharedBuffer {
+    fn take(&self) -> Vec<u8> {
+        std::mem::take(&mut *self.0.borrow_mut())
+    }
+}
+
+impl Write for SharedBuffer {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.0.borrow_mut().extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+// Insert "bbox" member at the beginning of the object
+fn add_bbox(out: &mut Vec<u8>, feature: &[u8], bbox: &Bbox) -> Result<(), Ksj2GpError> {
+    let Some(rest) = feature.strip_prefix(b"{") else {
+        return Err("A GeoJSON Feature must be an object".into());
+    };
+
+    out.extend_from_slice(br#"{"bbox":"#);
+    serde_json::to_writer(&mut *out, &bbox.to_array()).map_err(|e| format!("{e:?}"))?;
+    if rest != b"}" {
+        out.push(b',');
+    }
+    out.extend_from_slice(rest);
 
     Ok(())
 }
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Bbox {
+    min_x: f64,
+    min_y: f64,
+    max_x: f64,
+    max_y: f64,
+}
+
+impl Default for Bbox {
+    fn default() -> Self {
+        Self {
+            min_x: f64::INFINITY,
+            min_y: f64::INFINITY,
+            max_x: f64::NEG_INFINITY,
+            max_y: f64::NEG_INFINITY,
+        }
+    }
+}
+
+impl Bbox {
+    fn from_geometry(geometry: &impl GeometryTrait<T = f64>) -> Self {
+        let mut bbox = Self::default();
+        bbox.add_geometry(geometry);
+        bbox
+    }
+
+    fn is_empty(&self) -> bool {
+        self.min_x > self.max_x
+    }
+
+    fn to_array(self) -> [f64; 4] {
+        [self.min_x, self.min_y, self.max_x, self.max_y]
+    }
+
+    fn merge(&mut self, other: &Bbox) {
+        self.min_x = self.min_x.min(other.min_x);
+        self.min_y = self.min_y.min(other.min_y);
+        self.max_x = self.max_x.max(other.max_x);
+        self.max_y = self.max_y.max(other.max_y);
+    }
+
+    fn add_coord(&mut self, coord: impl CoordTrait<T = f64>) {
+        self.min_x = self.min_x.min(coord.x());
+        self.min_y = self.min_y.min(coord.y());
+        self.max_x = self.max_x.max(coord.x());
+        self.max_y = self.max_y.max(coord.y());
+    }
+
+    fn add_coords(&mut self, coords: impl Iterator<Item = impl CoordTrait<T = f64>>) {
+        for coord in coords {
+            self.add_coord(coord);
+        }
+    }
+
+    fn add_polygon(&mut self, polygon: &impl PolygonTrait<T = f64>) {
+        // interior rings are always inside the exterior ring
+        if let Some(exterior) = polygon.exterior() {
+            self.add_coords(exterior.coords());
+        }
+    }
+
+    fn add_geometry(&mut self, geometry: &impl GeometryTrait<T = f64>) {
+        match geometry.as_type() {
+            GeometryType::Point(p) => {
+                if let Some(coord) = p.coord() {
+                    self.add_coord(coord);
+                }
+            }
+            GeometryType::LineString(ls) => self.add_coords(ls.coords()),
+            GeometryType::Polygon(p) => self.add_polygon(p),
+            GeometryType::MultiPoint(mp) => {
+                for p in mp.points() {
+                    if let Some(coord) = p.coord() {
+                        self.add_coord(coord);
+                    }
+                }
+            }
+            GeometryType::MultiLineString(mls) => {
+                for ls in mls.line_strings() {
+                    self.add_coords(ls.coords());
+                }
+            }
+            GeometryType::MultiPolygon(mp) => {
+                for p in mp.polygons() {
+                    self.add_polygon(&p);
+                }
+            }
+            GeometryType::GeometryCollection(gc) => {
+                for g in gc.geometries() {
+                    self.add_geometry(&g);
+                }
+            }
+            GeometryType::Rect(r) => {
+                self.add_coord(r.min());
+                self.add_coord(r.max());
+            }
+            GeometryType::Triangle(t) => self.add_coords(t.coords().into_iter()),
+            GeometryType::Line(l) => self.add_coords(l.coords().into_iter()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn coord(x: f64, y: f64) -> geo_traits::structs::Coord {
+        geo_traits::structs::Coord {
+            x,
+            y,
+            z: None,
+            m: None,
+        }
+    }
+
+    #[test]
+    fn test_bbox() {
+        let ring = geo_traits::structs::LineString::from_coords(vec![
+            coord(139.0, 35.0),
+            coord(140.0, 35.0),
+            coord(140.0, 36.5),
+            coord(139.0, 35.0),
+        ])
+        .unwrap();
+        let polygon: geo_traits::structs::Geometry =
+            geo_traits::structs::Polygon::from_rings(vec![ring])
+                .unwrap()
+                .into();
+        let bbox = Bbox::from_geometry(&polygon);
+        assert_eq!(bbox.to_array(), [139.0, 35.0, 140.0, 36.5]);
+
+        let point: geo_traits::structs::Geometry =
+            geo_traits::structs::Point::from_coord(coord(141.0, 34.0)).into();
+        let mut collection_bbox = Bbox::default();
+        assert!(collection_bbox.is_empty());
+        collection_bbox.merge(&bbox);
+        collection_bbox.merge(&Bbox::from_geometry(&point));
+        assert_eq!(collection_bbox.to_array(), [139.0, 34.0, 141.0, 36.5]);
+    }
+
+    #[test]
+    fn test_add_bbox() {
+        let bbox = Bbox {
+            min_x: 139.0,
+            min_y: 35.0,
+            max_x: 140.5,
+            max_y: 36.0,
+        };
+
+        let mut out = Vec::new();
+        add_bbox(&mut out, br#"{"type":"Feature"}"#, &bbox).unwrap();
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            r#"{"bbox":[139.0,35.0,140.5,36.0],"type":"Feature"}"#
+        );
+
+        let mut out = Vec::new();
+        add_bbox(&mut out, b"{}", &bbox).unwrap();
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            r#"{"bbox":[139.0,35.0,140.5,36.0]}"#
+        );
+    }
+}
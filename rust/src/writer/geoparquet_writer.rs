@@ -1,100 +1,240 @@
 use std::io::{Read, Seek, Write};
 
-use geoparquet::writer::{GeoParquetRecordBatchEncoder, GeoParquetWriterOptionsBuilder};
-use itertools::Itertools;
-use parquet::arrow::ArrowWriter;
+use geoarrow_array::{GeoArrowArray as _, array::WkbArray};
+use geoarrow_schema::GeoArrowType;
+use geoparquet::writer::{
+    GeoParquetRecordBatchEncoder, GeoParquetWriterEncoding, GeoParquetWriterOptionsBuilder,
+};
+use parquet::{
+    arrow::ArrowWriter,
+    basic::{Compression, ZstdLevel},
+    file::properties::{EnabledStatistics, WriterProperties},
+};
 
 use crate::{
-    builder::construct_schema, crs::JapanCrs, error::Ksj2GpError, translate::TranslateOptions,
-    writer::get_fields_except_geometry,
+    builder::FieldsWithGeo,
+    crs::JapanCrs,
+    error::Ksj2GpError,
+    transform_coord::has_measures,
+    translate::{TranslateOptions, TranslationReport},
+    writer::{
+        GeoParquetOptions, ParquetCompression, ParquetStatistics, RowGroupSize,
+        construct_schema_from_crs, finish_geometry, for_each_chunk, native_type_from_shape_type,
+        schema_with_native_geometry, spatial_sort::SpatialSorter, wkb_array,
+    },
 };
 
-// Number of rows to process at once
-const CHUNK_SIZE: usize = 2048;
-
 pub(crate) fn write_geoparquet<T: Read + Seek, D: Read + Seek, W: Write + Send>(
     reader: &mut shapefile::Reader<T, D>,
     writer: &mut W,
     dbf_fields: &[dbase::FieldInfo],
     crs: JapanCrs,
     translate_options: &TranslateOptions,
+    report: &mut TranslationReport,
+    options: &GeoParquetOptions,
 ) -> Result<(), Ksj2GpError> {
-    let projjson: serde_json::Value = serde_json::from_str(crs.to_projjson())
-        .expect("embedded PROJJSON should be valid JSON");
-    let crs = geoarrow_schema::Crs::from_projjson(projjson);
-
-    let fields_info = construct_schema(dbf_fields, crs, translate_options)?;
-    let schema_ref = fields_info.schema_ref.clone();
-
-    // Since shapefile::Record is a HashMap, the iterator of it doesn't maintain
-    // the order. So, this column names vector is needed to ensure the consistent
-    // order with the schema.
-    let field_names = get_fields_except_geometry(dbf_fields);
-
-    let options = GeoParquetWriterOptionsBuilder::default()
-        .set_encoding(geoparquet::writer::GeoParquetWriterEncoding::WKB)
-        .set_generate_covering(true)
-        .build();
-    let mut gpq_encoder = GeoParquetRecordBatchEncoder::try_new(&schema_ref, &options)?;
-
-    let mut parquet_writer = ArrowWriter::try_new(writer, gpq_encoder.target_schema(), None)?;
-
-    for chunk in &reader.iter_shapes_and_records().chunks(CHUNK_SIZE) {
-        let mut builders = fields_info.create_builders(CHUNK_SIZE);
-
-        for result in chunk {
-            let (shape, mut record) = result.unwrap();
-
-            for (i, field_name) in field_names.iter().enumerate() {
-                let value = record
-                    .remove(field_name)
-                    .ok_or_else(|| format!("Not found {field_name}"))?;
-                builders.builders[i].push(value);
-            }
-
-            match shape {
-                shapefile::Shape::Point(point) => {
-                    builders.geo_builder.push_geometry(Some(&point))?;
-                }
-                shapefile::Shape::PointZ(point_z) => {
-                    builders.geo_builder.push_geometry(Some(&point_z))?;
-                }
-                shapefile::Shape::Polyline(polyline) => {
-                    builders.geo_builder.push_geometry(Some(&polyline))?;
-                }
-                shapefile::Shape::PolylineZ(polyline_z) => {
-                    builders.geo_builder.push_geometry(Some(&polyline_z))?;
-                }
-                shapefile::Shape::Polygon(polygon) => {
-                    builders
-                        .geo_builder
-                        .push_geometry(Some(&polygon.try_into_geo_traits()?))?;
-                }
-                shapefile::Shape::PolygonZ(polygon_z) => {
-                    builders
-                        .geo_builder
-                        .push_geometry(Some(&polygon_z.try_into_geo_traits()?))?;
-                }
-                shapefile::Shape::Multipoint(multipoint) => {
-                    builders.geo_builder.push_geometry(Some(&multipoint))?;
-                }
-                shapefile::Shape::MultipointZ(multipoint) => {
-                    builders.geo_builder.push_geometry(Some(&multipoint))?;
-                }
-                _ => return Err(format!("Unsupported shape type: {}", shape.shapetype()).into()),
-            }
+    let fields_info = construct_schema_from_crs(dbf_fields, &crs, translate_options, report, true)?;
+
+    let measures = has_measures(reader.header());
+
+    // Fall back to WKB if there's no matching native type
+    let native_type = if options.native_encoding {
+        native_type_from_shape_type(
+            reader.header().shape_type,
+            measures,
+            &fields_info.geoarrow_type,
+        )
+    } else {
+        None
+    };
+
+    let mut batch_writer =
+        GeoParquetBatchWriter::try_new(writer, &fields_info, native_type, options)?;
+
+    let mut sorter = options
+        .spatial_sort
+        .as_ref()
+        .map(|o| SpatialSorter::new(fields_info.schema_ref.clone(), o, reader.header()));
+
+    for_each_chunk(reader, dbf_fields, &fields_info, report, |chunk| {
+        let sort_keys = match &sorter {
+            Some(sorter) => chunk.shapes.iter().map(|s| sorter.sort_key(s)).collect(),
+            None => Vec::new(),
+        };
+
+        let mut arrays = chunk.arrays;
+        arrays.push(wkb_array(&fields_info, chunk.shapes, measures)?.into_array_ref());
+        let batch = arrow_array::RecordBatch::try_new(fields_info.schema_ref.clone(), arrays)?;
+
+        match &mut sorter {
+            Some(sorter) => sorter.push(batch, sort_keys),
+            None => batch_writer.write(&batch),
         }
+    })?;
+
+    if let Some(sorter) = sorter {
+        sorter.finish(|batch| batch_writer.write(&batch))?;
+    }
 
-        let batch = arrow_array::RecordBatch::try_new(schema_ref.clone(), builders.finish())?;
-        let encoded_batch = gpq_encoder.encode_record_batch(&batch)?;
+    batch_writer.finish()
+}
+
+// Encode the RecordBatches of WKB geometry into GeoParquet
+pub(super) struct GeoParquetBatchWriter<W: Write + Send> {
+    parquet_writer: ArrowWriter<W>,
+    gpq_encoder: GeoParquetRecordBatchEncoder,
+    schema_ref: arrow_schema::SchemaRef,
+    // The field of the WKB geometry column of the input
+    wkb_field: arrow_schema::Field,
+    // If None, the geometry is written as WKB
+    native_type: Option<GeoArrowType>,
+    row_group_size: RowGroupSize,
+}
 
-        parquet_writer.write(&encoded_batch)?;
-        parquet_writer.flush()?;
+impl<W: Write + Send> GeoParquetBatchWriter<W> {
+    pub(super) fn try_new(
+        writer: W,
+        fields_info: &FieldsWithGeo,
+        native_type: Option<GeoArrowType>,
+        options: &GeoParquetOptions,
+    ) -> Result<Self, Ksj2GpError> {
+        let schema_ref = match &native_type {
+            Some(native_type) => schema_with_native_geometry(fields_info, native_type),
+            None => fields_info.schema_ref.clone(),
+        };
+
+        // The bbox covering column is only needed for WKB; the native encodings
+        // already have the coordinates as separate columns, which are usable for
+        // statistics.
+        let gpq_options = match native_type {
+            Some(_) => GeoParquetWriterOptionsBuilder::default()
+                .set_encoding(GeoParquetWriterEncoding::GeoArrow)
+                .build(),
+            None => GeoParquetWriterOptionsBuilder::default()
+                .set_encoding(GeoParquetWriterEncoding::WKB)
+                .set_generate_covering(true)
+                .build(),
+        };
+        let gpq_encoder = GeoParquetRecordBatchEncoder::try_new(&schema_ref, &gpq_options)?;
+
+        let props = writer_properties(options)?;
+        let parquet_writer =
+            ArrowWriter::try_new(writer, gpq_encoder.target_schema(), Some(props))?;
+
+        let wkb_field = fields_info.geoarrow_type.to_field("geometry", true);
+
+        Ok(Self {
+            parquet_writer,
+            gpq_encoder,
+            schema_ref,
+            wkb_field,
+            native_type,
+            row_group_size: options.row_group_size,
+        })
     }
 
-    let kv_metadata = gpq_encoder.into_keyvalue().unwrap();
-    parquet_writer.append_key_value_metadata(kv_metadata);
-    parquet_writer.finish()?;
+    // The geometry column of the batch must be WKB (i.e. the schema is
+    // `fields_info.schema_ref`)
+    pub(super) fn write(&mut self, batch: &arrow_array::RecordBatch) -> Result<(), Ksj2GpError> {
+        let mut arrays = batch.columns().to_vec();
+        let wkb_array = arrays.pop().expect("the geometry column should exist");
+        let wkb_array = WkbArray::try_from((wkb_array.as_ref(), &self.wkb_field))?;
+        arrays.push(finish_geometry(wkb_array, self.native_type.as_ref())?);
+
+        let batch = arrow_array::RecordBatch::try_new(self.schema_ref.clone(), arrays)?;
+        let encoded_batch = self.gpq_encoder.encode_record_batch(&batch)?;
+
+        self.parquet_writer.write(&encoded_batch)?;
+
+        // When the size is specified by rows, ArrowWriter flushes the row group
+        // by itself.
+        if let RowGroupSize::Bytes(max_bytes) = self.row_group_size
+            && self.parquet_writer.in_progress_size() >= max_bytes
+        {
+            self.parquet_writer.flush()?;
+        }
 
-    Ok(())
+        Ok(())
+    }
+
+    pub(super) fn finish(mut self) -> Result<(), Ksj2GpError> {
+        let kv_metadata = self.gpq_encoder.into_keyvalue().unwrap();
+        self.parquet_writer.append_key_value_metadata(kv_metadata);
+        self.parquet_writer.finish()?;
+
+        Ok(())
+    }
+}
+
+pub(crate) fn writer_properties(
+    options: &GeoParquetOptions,
+) -> Result<WriterProperties, Ksj2GpError> {
+    let compression = match options.compression {
+        ParquetCompression::Uncompressed => Compression::UNCOMPRESSED,
+        ParquetCompression::Snappy => Compression::SNAPPY,
+        ParquetCompression::Zstd(None) => Compression::ZSTD(ZstdLevel::default()),
+        ParquetCompression::Zstd(Some(level)) => Compression::ZSTD(ZstdLevel::try_new(level)?),
+    };
+
+    let max_row_group_row_count = match options.row_group_size {
+        RowGroupSize::Rows(0) | RowGroupSize::Bytes(0) => {
+            return Err("Row group size must be greater than 0".into());
+        }
+        RowGroupSize::Rows(rows) => Some(rows),
+        // Flushed manually based on the size
+        RowGroupSize::Bytes(_) => None,
+    };
+
+    let statistics = match options.statistics {
+        ParquetStatistics::None => EnabledStatistics::None,
+        ParquetStatistics::Chunk => EnabledStatistics::Chunk,
+        ParquetStatistics::Page => EnabledStatistics::Page,
+    };
+
+    Ok(WriterProperties::builder()
+        .set_compression(compression)
+        .set_max_row_group_row_count(max_row_group_row_count)
+        .set_dictionary_enabled(options.dictionary)
+        .set_statistics_enabled(statistics)
+        .build())
+}
+
+#[cfg(test)]
+mod tests {
+    use parquet::schema::types::ColumnPath;
+
+    use super::*;
+
+    #[test]
+    fn test_writer_properties() {
+        let col = ColumnPath::from("geometry");
+
+        let props = writer_properties(&GeoParquetOptions::default()).unwrap();
+        assert_eq!(props.compression(&col), Compression::UNCOMPRESSED);
+        assert_eq!(props.max_row_group_row_count(), Some(1024 * 1024));
+        assert!(props.dictionary_enabled(&col));
+        assert_eq!(props.statistics_enabled(&col), EnabledStatistics::Page);
+
+        let options = GeoParquetOptions {
+            compression: ParquetCompression::Zstd(Some(3)),
+            row_group_size: RowGroupSize::Rows(10000),
+            dictionary: false,
+            statistics: ParquetStatistics::Chunk,
+            ..Default::default()
+        };
+        let props = writer_properties(&options).unwrap();
+        assert_eq!(
+            props.compression(&col),
+            Compression::ZSTD(ZstdLevel::try_new(3).unwrap())
+        );
+        assert_eq!(props.max_row_group_row_count(), Some(10000));
+        assert!(!props.dictionary_enabled(&col));
+        assert_eq!(props.statistics_enabled(&col), EnabledStatistics::Chunk);
+
+        let options = GeoParquetOptions {
+            row_group_size: RowGroupSize::Bytes(0),
+            ..Default::default()
+        };
+        assert!(writer_properties(&options).is_err());
+    }
 }
@@ -1,37 +1,61 @@
-use std::collections::HashMap;
-use std::io::{Read, Seek, Write};
-use std::sync::LazyLock;
+use std::{
+    io::{Read, Seek, Write},
+    sync::atomic::{AtomicUsize, Ordering},
+};
 
 use rusqlite_gpkg::{ColumnSpec, ColumnType, Dimension, GeometryType, Gpkg};
 
 use crate::{
     Ksj2GpError, TranslateOptions,
+    builder::translate_code,
     crs::JapanCrs,
-    translate::{get_codelist_map, translate_colnames},
-    writer::get_fields_except_geometry,
+    transform_coord::has_measures,
+    translate::{Codelist, TranslationReport, get_codelist_map, translate_colnames},
+    writer::{get_fields_except_geometry, shape_to_geometry},
 };
 
-pub(crate) fn write_gpkg<T: Read + Seek, D: Read + Seek, W: Write + Seek + Send + 'static>(
+// Used for the names of the in-memory databases, which need to be unique in the
+// process
+static GPKG_COUNT: AtomicUsize = AtomicUsize::new(0);
+
+// Metadata of a column for the Schema extension
+struct DataColumn<'a> {
+    // Column name in the GeoPackage (translated)
+    name: String,
+    // Original column id of KSJ (e.g. "N03_001")
+    ksj_id: &'a str,
+    codelist: Option<&'static Codelist>,
+    // Whether the codes are stored as the labels
+    translated: bool,
+}
+
+pub(crate) fn write_gpkg<T: Read + Seek, D: Read + Seek, W: Write>(
     reader: &mut shapefile::Reader<T, D>,
-    writer: W,
+    mut writer: W,
     dbf_fields: &[dbase::FieldInfo],
     crs: JapanCrs,
     translate_options: &TranslateOptions,
+    report: &mut TranslationReport,
 ) -> Result<(), Ksj2GpError> {
-    #[cfg(target_family = "wasm")]
-    let gpkg = Gpkg::open_with_writer("output.gpkg", writer)
-        .map_err(|e| Ksj2GpError::from(format!("{e:?}")))?;
-    #[cfg(not(target_family = "wasm"))]
-    let gpkg = Gpkg::open_in_memory().map_err(|e| Ksj2GpError::from(format!("{e:?}")))?;
+    let (conn, gpkg) = open_gpkg()?;
     // TODO: write_gpkg doesn't know the filename
     let layer_name = "layer";
+    let geometry_column = "geom";
 
     let shape_type = reader.header().shape_type;
-    let geometry_type = geometry_type_from_shape_type(shape_type)?;
+    let measures = has_measures(reader.header());
+    let geometry_type = geometry_type_from_shape_type(shape_type);
 
     let field_names = get_fields_except_geometry(dbf_fields);
     let (column_specs, codelist_maps) =
-        build_column_specs(dbf_fields, translate_options, &field_names)?;
+        build_column_specs(dbf_fields, translate_options, &field_names, report)?;
+    let data_columns = build_data_columns(
+        dbf_fields,
+        translate_options,
+        &field_names,
+        &column_specs,
+        &codelist_maps,
+    );
 
     let (srs_id, srs_name) = match crs {
         JapanCrs::Tokyo => (4301, "Tokyo"),
@@ -52,12 +76,12 @@ pub(crate) fn write_gpkg<T: Read + Seek, D: Read + Seek, W: Write + Seek + Send
     }
 
     let iter = reader.iter_shapes_and_records();
-    let dimension = default_dimension_from_shape_type(shape_type);
+    let dimension = dimension_from_shape_type(shape_type, measures);
 
     let layer = gpkg
         .create_layer(
             layer_name,
-            "geom",
+            geometry_column,
             geometry_type,
             dimension,
             srs_id as u32,
@@ -65,54 +89,90 @@ pub(crate) fn write_gpkg<T: Read + Seek, D: Read + Seek, W: Write + Seek + Send
         )
         .map_err(|e| Ksj2GpError::from(format!("{e:?}")))?;
 
-    for result in iter {
+    // rusqlite_gpkg can't insert a null geometry, so the rows of the null shapes
+    // are inserted via the other connection
+    let mut insert_null_geometry =
+        prepare_insert_null_geometry(&conn, layer_name, geometry_column, &column_specs)?;
+
+    for (row, result) in iter.enumerate() {
         let (shape, record) = result?;
-        insert_shape_record(
-            &layer,
-            shape,
+        let values = record_to_sql_values(
             record,
+            row,
             &field_names,
             &codelist_maps,
-            translate_options.translate_contents,
+            translate_options,
+            report,
         )?;
+
+        match shape {
+            shapefile::Shape::NullShape => {
+                insert_null_geometry
+                    .execute(rusqlite::params_from_iter(&values))
+                    .map_err(|e| format!("{e:?}"))?;
+            }
+            // Unlike geo-types, this keeps the Z and M values
+            shape => layer
+                .insert(shape_to_geometry(&shape, measures)?, &values)
+                .map_err(|e| Ksj2GpError::from(format!("{e:?}")))?,
+        }
     }
 
-    #[cfg(target_family = "wasm")]
+    drop(layer);
     drop(gpkg);
-    #[cfg(not(target_family = "wasm"))]
-    {
-        let bytes = gpkg.to_bytes().map_err(|e| format!("{e:?}"))?;
-        let mut cursor = std::io::Cursor::new(bytes);
-        let mut writer = writer;
-        std::io::copy(&mut cursor, &mut writer).map_err(|e| format!("{e:?}"))?;
-    }
+
+    // rusqlite_gpkg creates the R-tree index (gpkg_rtree_index extension) on
+    // create_layer(), but doesn't support the Schema extension, so it's written
+    // via the other connection.
+    write_schema_extension(&conn, layer_name, &data_columns).map_err(|e| format!("{e:?}"))?;
+
+    let data = conn.serialize("main").map_err(|e| format!("{e:?}"))?;
+    writer.write_all(&data)?;
 
     Ok(())
 }
 
-fn geometry_type_from_shape_type(
-    shape_type: shapefile::ShapeType,
-) -> Result<GeometryType, Ksj2GpError> {
+// rusqlite_gpkg doesn't expose its connection, so the GeoPackage is created on
+// a named in-memory database (memdb VFS), which is shared by all the connections
+// to the same name in the process. The returned connection is for writing what
+// rusqlite_gpkg doesn't handle, and keeps the database alive until it's
+// serialized. This works in the same way on wasm, as the `vfs` parameter of the
+// URI takes precedence over the VFS rusqlite_gpkg specifies.
+fn open_gpkg() -> Result<(rusqlite::Connection, Gpkg), Ksj2GpError> {
+    let id = GPKG_COUNT.fetch_add(1, Ordering::Relaxed);
+    let uri = format!("file:/ksj2gp-{id}.gpkg?vfs=memdb");
+
+    let conn = rusqlite::Connection::open(&uri).map_err(|e| format!("{e:?}"))?;
+    let gpkg = Gpkg::open(&uri).map_err(|e| Ksj2GpError::from(format!("{e:?}")))?;
+
+    Ok((conn, gpkg))
+}
+
+// Multipatch is converted to MultiPolygon by CoordTransformer. The layer of
+// NullShape only has null geometries, but rusqlite_gpkg has no GEOMETRY type,
+// so GEOMETRYCOLLECTION is used instead.
+fn geometry_type_from_shape_type(shape_type: shapefile::ShapeType) -> GeometryType {
     match shape_type {
         shapefile::ShapeType::Point
         | shapefile::ShapeType::PointM
-        | shapefile::ShapeType::PointZ => Ok(GeometryType::Point),
+        | shapefile::ShapeType::PointZ => GeometryType::Point,
         shapefile::ShapeType::Multipoint
         | shapefile::ShapeType::MultipointM
-        | shapefile::ShapeType::MultipointZ => Ok(GeometryType::MultiPoint),
+        | shapefile::ShapeType::MultipointZ => GeometryType::MultiPoint,
         shapefile::ShapeType::Polyline
         | shapefile::ShapeType::PolylineM
-        | shapefile::ShapeType::PolylineZ => Ok(GeometryType::MultiLineString),
+        | shapefile::ShapeType::PolylineZ => GeometryType::MultiLineString,
         shapefile::ShapeType::Polygon
         | shapefile::ShapeType::PolygonM
-        | shapefile::ShapeType::PolygonZ => Ok(GeometryType::MultiPolygon),
-        shapefile::ShapeType::NullShape | shapefile::ShapeType::Multipatch => {
-            Err(format!("Unsupported shape type: {shape_type}").into())
-        }
+        | shapefile::ShapeType::PolygonZ
+        | shapefile::ShapeType::Multipatch => GeometryType::MultiPolygon,
+        shapefile::ShapeType::NullShape => GeometryType::GeometryCollection,
     }
 }
 
-fn default_dimension_from_shape_type(shape_type: shapefile::ShapeType) -> Dimension {
+// `measures` is the result of has_measures(), which decides whether the Z types
+// have M values.
+fn dimension_from_shape_type(shape_type: shapefile::ShapeType, measures: bool) -> Dimension {
     match shape_type {
         shapefile::ShapeType::PointM
         | shapefile::ShapeType::PolylineM
@@ -121,7 +181,14 @@ fn default_dimension_from_shape_type(shape_type: shapefile::ShapeType) -> Dimens
         shapefile::ShapeType::PointZ
         | shapefile::ShapeType::PolylineZ
         | shapefile::ShapeType::PolygonZ
-        | shapefile::ShapeType::MultipointZ => Dimension::Xyzm,
+        | shapefile::ShapeType::MultipointZ
+        | shapefile::ShapeType::Multipatch => {
+            if measures {
+                Dimension::Xyzm
+            } else {
+                Dimension::Xyz
+            }
+        }
         _ => Dimension::Xy,
     }
 }
@@ -130,13 +197,8 @@ fn build_column_specs(
     dbf_fields: &[dbase::FieldInfo],
     translate_options: &TranslateOptions,
     field_names: &[&str],
-) -> Result<
-    (
-        Vec<ColumnSpec>,
-        Vec<Option<&'static LazyLock<HashMap<&'static str, &'static str>>>>,
-    ),
-    Ksj2GpError,
-> {
+    report: &mut TranslationReport,
+) -> Result<(Vec<ColumnSpec>, Vec<Option<&'static Codelist>>), Ksj2GpError> {
     let mut column_specs = Vec::with_capacity(dbf_fields.len());
     let mut codelist_maps = Vec::with_capacity(dbf_fields.len());
 
@@ -167,7 +229,7 @@ fn build_column_specs(
             column_type = ColumnType::Varchar;
         }
 
-        let translated_name = translate_colnames(field.name(), translate_options)?;
+        let translated_name = translate_colnames(field.name(), translate_options, report)?;
         column_specs.push(ColumnSpec {
             name: translated_name,
             column_type,
@@ -178,49 +240,197 @@ fn build_column_specs(
     Ok((column_specs, codelist_maps))
 }
 
-fn insert_shape_record(
-    layer: &rusqlite_gpkg::GpkgLayer,
-    shape: shapefile::Shape,
+// Unlike build_column_specs(), the codelists are looked up even when the contents
+// are not translated, so that the codes can be shown with the labels.
+fn build_data_columns<'a>(
+    dbf_fields: &[dbase::FieldInfo],
+    translate_options: &TranslateOptions,
+    field_names: &[&'a str],
+    column_specs: &[ColumnSpec],
+    codelist_maps: &[Option<&'static Codelist>],
+) -> Vec<DataColumn<'a>> {
+    dbf_fields
+        .iter()
+        .zip(field_names)
+        .zip(column_specs.iter().zip(codelist_maps))
+        .map(|((field, field_name), (spec, codelist_map))| {
+            let translated = codelist_map.is_some();
+            let codelist = if translated {
+                *codelist_map
+            } else if matches!(
+                field.field_type(),
+                dbase::FieldType::Character | dbase::FieldType::Memo
+            ) {
+                // Numeric codes are not stored as text, so they can't match the
+                // values of the enum constraint
+                get_codelist_map(
+                    field_name,
+                    translate_options.year,
+                    &translate_options.target_shp,
+                )
+            } else {
+                None
+            };
+
+            DataColumn {
+                name: spec.name.clone(),
+                ksj_id: field_name,
+                codelist,
+                translated,
+            }
+        })
+        .collect()
+}
+
+// Write gpkg_data_columns and gpkg_data_column_constraints of the Schema
+// extension. The original column ids are kept in the `name` column, and the
+// codelists become the enum constraints, which QGIS shows as value maps.
+//
+// cf. https://www.geopackage.org/spec/#extension_schema
+fn write_schema_extension(
+    conn: &rusqlite::Connection,
+    table_name: &str,
+    data_columns: &[DataColumn],
+) -> rusqlite::Result<()> {
+    conn.execute_batch(
+        "
+CREATE TABLE IF NOT EXISTS gpkg_data_columns (
+  table_name TEXT NOT NULL,
+  column_name TEXT NOT NULL,
+  name TEXT,
+  title TEXT,
+  description TEXT,
+  mime_type TEXT,
+  constraint_name TEXT,
+  CONSTRAINT pk_gdc PRIMARY KEY (table_name, column_name),
+  CONSTRAINT gdc_tn UNIQUE (table_name, name)
+);
+CREATE TABLE IF NOT EXISTS gpkg_data_column_constraints (
+  constraint_name TEXT NOT NULL,
+  constraint_type TEXT NOT NULL,
+  value TEXT,
+  min NUMERIC,
+  min_is_inclusive BOOLEAN,
+  max NUMERIC,
+  max_is_inclusive BOOLEAN,
+  description TEXT,
+  CONSTRAINT gdcc_ntv UNIQUE (constraint_name, constraint_type, value)
+);
+INSERT OR IGNORE INTO gpkg_extensions (table_name, column_name, extension_name, definition, scope)
+VALUES
+  ('gpkg_data_columns', NULL, 'gpkg_schema', 'http://www.geopackage.org/spec/#extension_schema', 'read-write'),
+  ('gpkg_data_column_constraints', NULL, 'gpkg_schema', 'http://www.geopackage.org/spec/#extension_schema', 'read-write');
+",
+    )?;
+
+    let mut insert_column = conn.prepare(
+        "INSERT INTO gpkg_data_columns (table_name, column_name, name, title, constraint_name) VALUES (?1, ?2, ?3, ?4, ?5)",
+    )?;
+    // Different codes can have the same label, so duplicated values are ignored
+    let mut insert_constraint = conn.prepare(
+        "INSERT OR IGNORE INTO gpkg_data_column_constraints (constraint_name, constraint_type, value, description) VALUES (?1, 'enum', ?2, ?3)",
+    )?;
+
+    for column in data_columns {
+        let constraint_name = column
+            .codelist
+            .map(|_| format!("{table_name}_{}", column.ksj_id));
+
+        insert_column.execute(rusqlite::params![
+            table_name,
+            column.name,
+            column.ksj_id,
+            column.name,
+            constraint_name,
+        ])?;
+
+        if let (Some(codelist), Some(constraint_name)) = (column.codelist, &constraint_name) {
+            for (code, label) in codelist.iter() {
+                let value = if column.translated { label } else { code };
+                insert_constraint.execute(rusqlite::params![constraint_name, value, label])?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+// The geometry column is set to NULL, and the other columns are bound in the
+// same order as GpkgLayer::insert(). The triggers of the R-tree index skip null
+// geometries, but the spatial functions in them need to be registered on this
+// connection.
+fn prepare_insert_null_geometry<'conn>(
+    conn: &'conn rusqlite::Connection,
+    table_name: &str,
+    geometry_column: &str,
+    column_specs: &[ColumnSpec],
+) -> Result<rusqlite::Statement<'conn>, Ksj2GpError> {
+    rusqlite_gpkg::register_spatial_functions(conn).map_err(|e| format!("{e:?}"))?;
+
+    let mut columns = vec![format!(r#""{geometry_column}""#)];
+    let mut values = vec!["NULL".to_string()];
+    for (i, spec) in column_specs.iter().enumerate() {
+        columns.push(format!(r#""{}""#, spec.name));
+        values.push(format!("?{}", i + 1));
+    }
+
+    let sql = format!(
+        r#"INSERT INTO "{table_name}" ({}) VALUES ({})"#,
+        columns.join(", "),
+        values.join(", ")
+    );
+    conn.prepare(&sql)
+        .map_err(|e| Ksj2GpError::from(format!("{e:?}")))
+}
+
+fn record_to_sql_values(
     mut record: dbase::Record,
+    row: usize,
     field_names: &[&str],
-    codelist_maps: &[Option<&'static LazyLock<HashMap<&'static str, &'static str>>>],
-    translate_contents: bool,
-) -> Result<(), Ksj2GpError> {
+    codelist_maps: &[Option<&'static Codelist>],
+    translate_options: &TranslateOptions,
+    report: &mut TranslationReport,
+) -> Result<Vec<rusqlite_gpkg::Value>, Ksj2GpError> {
     let mut values = Vec::with_capacity(field_names.len());
 
     for (index, field_name) in field_names.iter().enumerate() {
         let value = record
             .remove(field_name)
             .ok_or_else(|| format!("Not found {field_name}"))?;
-        let sql_value = field_value_to_sql_value(value, codelist_maps[index], translate_contents);
+        let sql_value = field_value_to_sql_value(
+            value,
+            codelist_maps[index],
+            translate_options,
+            field_name,
+            row,
+            report,
+        )?;
         values.push(sql_value);
     }
 
-    let params = &values;
-
-    let geometry = geo_types::Geometry::<f64>::try_from(shape)?;
-    layer
-        .insert(geometry, params)
-        .map_err(|e| Ksj2GpError::from(format!("{e:?}")))?;
-
-    Ok(())
+    Ok(values)
 }
 
 fn field_value_to_sql_value(
     value: dbase::FieldValue,
-    codelist_map: Option<&'static LazyLock<HashMap<&'static str, &'static str>>>,
-    translate_contents: bool,
-) -> rusqlite_gpkg::Value {
-    if translate_contents {
-        if let Some(map) = codelist_map {
-            if let Some(label) = translate_codelist_value(&value, map) {
-                return rusqlite_gpkg::Value::Text(label);
-            }
-            return rusqlite_gpkg::Value::Null;
-        }
+    codelist_map: Option<&'static Codelist>,
+    translate_options: &TranslateOptions,
+    col_id: &str,
+    row: usize,
+    report: &mut TranslationReport,
+) -> Result<rusqlite_gpkg::Value, Ksj2GpError> {
+    if translate_options.translate_contents
+        && let Some(map) = codelist_map
+    {
+        let validation = translate_options.codelist_validation;
+        let sql_value = match translate_code(value, map, validation, col_id, row, report)? {
+            Some(label) => rusqlite_gpkg::Value::Text(label.into_owned()),
+            None => rusqlite_gpkg::Value::Null,
+        };
+        return Ok(sql_value);
     }
 
-    match value {
+    let sql_value = match value {
         dbase::FieldValue::Character(Some(v)) => rusqlite_gpkg::Value::Text(v),
         dbase::FieldValue::Character(None) => rusqlite_gpkg::Value::Null,
         dbase::FieldValue::Memo(v) => rusqlite_gpkg::Value::Text(v),
@@ -238,82 +448,283 @@ fn field_value_to_sql_value(
         }
         dbase::FieldValue::Date(None) => rusqlite_gpkg::Value::Null,
         dbase::FieldValue::DateTime(dt) => rusqlite_gpkg::Value::Integer(dt.to_unix_timestamp()),
-    }
+    };
+
+    Ok(sql_value)
 }
 
-fn translate_codelist_value(
-    value: &dbase::FieldValue,
-    map: &'static LazyLock<HashMap<&'static str, &'static str>>,
-) -> Option<String> {
-    match value {
-        dbase::FieldValue::Character(Some(v)) | dbase::FieldValue::Memo(v) => Some(
-            map.get(v.as_str())
-                .copied()
-                .unwrap_or(v.as_str())
-                .to_string(),
-        ),
-        dbase::FieldValue::Character(None)
-        | dbase::FieldValue::Numeric(None)
-        | dbase::FieldValue::Float(None)
-        | dbase::FieldValue::Logical(None)
-        | dbase::FieldValue::Date(None) => None,
-        dbase::FieldValue::Numeric(Some(v)) | dbase::FieldValue::Double(v) => {
-            let code = format!("{v:.0}");
-            Some(
-                map.get(code.as_str())
-                    .copied()
-                    .unwrap_or(code.as_str())
-                    .to_string(),
-            )
-        }
-        dbase::FieldValue::Currency(v) => {
-            let code = format!("{v:.0}");
-            Some(
-                map.get(code.as_str())
-                    .copied()
-                    .unwrap_or(code.as_str())
-                    .to_string(),
-            )
-        }
-        dbase::FieldValue::Float(Some(v)) => {
-            let code = format!("{v:.0}");
-            Some(
-                map.get(code.as_str())
-                    .copied()
-                    .unwrap_or(code.as_str())
-                    .to_string(),
-            )
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use super::*;
+    use crate::{
+        translate::{CodelistValidation, RejectReason},
+        writer::shp_writer::write_shp_and_shx,
+    };
+
+    static CODELIST: Codelist =
+        Codelist::new("TEST", &[("1", "国"), ("2", "都道府県"), ("3", "国")]);
+
+    // Write the shapes with a code column to a GeoPackage, and open it
+    fn write_test_gpkg(
+        shapes: &[shapefile::Shape],
+        shape_type: shapefile::ShapeType,
+    ) -> rusqlite::Connection {
+        let (shp, shx) = write_shp_and_shx(shapes, shape_type).unwrap();
+
+        let mut dbf = Cursor::new(Vec::new());
+        {
+            let name = dbase::FieldName::try_from("N03_007").unwrap();
+            let mut dbf_writer = dbase::TableWriterBuilder::new()
+                .add_character_field(name, 5)
+                .build_with_dest(&mut dbf);
+            for i in 0..shapes.len() {
+                let mut record = dbase::Record::default();
+                record.insert(
+                    "N03_007".to_string(),
+                    dbase::FieldValue::Character(Some(format!("1310{}", i + 1))),
+                );
+                dbf_writer.write_record(&record).unwrap();
+            }
+            dbf_writer.finalize().unwrap();
         }
-        dbase::FieldValue::Integer(v) => {
-            let code = format!("{v:.0}");
-            Some(
-                map.get(code.as_str())
-                    .copied()
-                    .unwrap_or(code.as_str())
-                    .to_string(),
+        dbf.set_position(0);
+
+        let dbase_reader = dbase::Reader::new(dbf).unwrap();
+        let dbf_fields = dbase_reader.fields().to_vec();
+        let shape_reader =
+            shapefile::ShapeReader::with_shx(Cursor::new(shp), Cursor::new(shx)).unwrap();
+        let mut reader = shapefile::Reader::new(shape_reader, dbase_reader);
+
+        let translate_options = TranslateOptions {
+            translate_colnames: false,
+            translate_contents: false,
+            ignore_translation_errors: false,
+            ksj_id: "N03".to_string(),
+            year: 2024,
+            target_shp: String::new(),
+            codelist_validation: CodelistValidation::Off,
+        };
+
+        let mut bytes = Vec::new();
+        write_gpkg(
+            &mut reader,
+            &mut bytes,
+            &dbf_fields,
+            JapanCrs::JGD2011,
+            &translate_options,
+            &mut TranslationReport::default(),
+        )
+        .unwrap();
+
+        let mut conn = rusqlite::Connection::open_in_memory().unwrap();
+        conn.deserialize_read_exact("main", bytes.as_slice(), bytes.len(), true)
+            .unwrap();
+        conn
+    }
+
+    fn query_ids(conn: &rusqlite::Connection, sql: &str) -> Vec<i64> {
+        conn.prepare(sql)
+            .unwrap()
+            .query_map([], |row| row.get(0))
+            .unwrap()
+            .collect::<Result<_, _>>()
+            .unwrap()
+    }
+
+    // Also runs on wasm, where rusqlite_gpkg opens the databases with another
+    // VFS by default
+    #[cfg_attr(not(target_family = "wasm"), test)]
+    #[cfg_attr(target_family = "wasm", wasm_bindgen_test::wasm_bindgen_test)]
+    fn test_write_gpkg() {
+        let shapes = [
+            shapefile::Shape::Point(shapefile::Point::new(139.0, 35.0)),
+            shapefile::Shape::NullShape,
+            shapefile::Shape::Point(shapefile::Point::new(140.0, 35.0)),
+        ];
+        let conn = write_test_gpkg(&shapes, shapefile::ShapeType::Point);
+
+        let codes: Vec<String> = conn
+            .prepare("SELECT N03_007 FROM layer ORDER BY fid")
+            .unwrap()
+            .query_map([], |row| row.get(0))
+            .unwrap()
+            .collect::<Result<_, _>>()
+            .unwrap();
+        assert_eq!(codes, ["13101", "13102", "13103"]);
+
+        let nulls = query_ids(&conn, "SELECT fid FROM layer WHERE geom IS NULL");
+        assert_eq!(nulls, [2]);
+        let ids = query_ids(&conn, "SELECT id FROM rtree_layer_geom ORDER BY id");
+        assert_eq!(ids, [1, 3]);
+
+        // The Schema extension is written in the same database
+        let name: String = conn
+            .query_row(
+                "SELECT name FROM gpkg_data_columns WHERE column_name = 'N03_007'",
+                [],
+                |row| row.get(0),
             )
-        }
-        dbase::FieldValue::Logical(Some(v)) => {
-            let code = if *v { "1" } else { "0" };
-            Some(map.get(code).copied().unwrap_or(code).to_string())
-        }
-        dbase::FieldValue::Date(Some(date)) => {
-            let code = date.to_string();
-            Some(
-                map.get(code.as_str())
-                    .copied()
-                    .unwrap_or(code.as_str())
-                    .to_string(),
+            .unwrap();
+        assert_eq!(name, "N03_007");
+    }
+
+    // Null shapes are inserted without a geometry, whatever the type and the
+    // dimension of the layer are
+    #[test]
+    fn test_null_geometries_in_multipolygon_z_layer() {
+        let square = |x: f64| {
+            let points = [(0.0, 0.0), (0.0, 1.0), (1.0, 1.0), (1.0, 0.0), (0.0, 0.0)]
+                .map(|(dx, dy)| shapefile::PointZ::new(x + dx, 35.0 + dy, 10.0, 0.0));
+            shapefile::Shape::PolygonZ(shapefile::PolygonZ::new(shapefile::PolygonRing::Outer(
+                points.to_vec(),
+            )))
+        };
+        let shapes = [
+            shapefile::Shape::NullShape,
+            square(139.0),
+            shapefile::Shape::NullShape,
+            square(140.0),
+        ];
+        let conn = write_test_gpkg(&shapes, shapefile::ShapeType::PolygonZ);
+
+        let (geometry_type, z, m): (String, i64, i64) = conn
+            .query_row(
+                "SELECT geometry_type_name, z, m FROM gpkg_geometry_columns WHERE table_name = 'layer'",
+                [],
+                |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)),
             )
-        }
-        dbase::FieldValue::DateTime(dt) => {
-            let code = dt.to_unix_timestamp().to_string();
-            Some(
-                map.get(code.as_str())
-                    .copied()
-                    .unwrap_or(code.as_str())
-                    .to_string(),
+            .unwrap();
+        assert_eq!((geometry_type.as_str(), z, m), ("MULTIPOLYGON", 1, 0));
+
+        // The attributes stay with their geometries
+        let nulls = query_ids(
+            &conn,
+            "SELECT fid FROM layer WHERE geom IS NULL ORDER BY fid",
+        );
+        assert_eq!(nulls, [1, 3]);
+        let code: String = conn
+            .query_row("SELECT N03_007 FROM layer WHERE fid = 4", [], |row| {
+                row.get(0)
+            })
+            .unwrap();
+        assert_eq!(code, "13104");
+
+        let ids = query_ids(&conn, "SELECT id FROM rtree_layer_geom ORDER BY id");
+        assert_eq!(ids, [2, 4]);
+    }
+
+    #[test]
+    fn test_unexpected_values_are_reported() {
+        let options = TranslateOptions {
+            translate_colnames: true,
+            translate_contents: true,
+            ignore_translation_errors: false,
+            ksj_id: "A15".to_string(),
+            year: 2024,
+            target_shp: String::new(),
+            codelist_validation: CodelistValidation::Reject,
+        };
+        let mut report = TranslationReport::default();
+        let value = dbase::FieldValue::Logical(Some(true));
+        field_value_to_sql_value(value, Some(&CODELIST), &options, "A15_003", 0, &mut report)
+            .unwrap();
+
+        // Recorded only once, in the same way as the other output formats
+        assert_eq!(report.unexpected_values["A15_003"], 1);
+        let reasons: Vec<RejectReason> = report.rejects.iter().map(|r| r.reason).collect();
+        assert_eq!(reasons, [RejectReason::UnexpectedType]);
+    }
+
+    #[test]
+    fn test_write_schema_extension() {
+        let (conn, gpkg) = open_gpkg().unwrap();
+        let columns = [
+            ColumnSpec {
+                name: "行政区域コード".to_string(),
+                column_type: ColumnType::Varchar,
+            },
+            ColumnSpec {
+                name: "管理者".to_string(),
+                column_type: ColumnType::Varchar,
+            },
+        ];
+        gpkg.create_layer(
+            "layer",
+            "geom",
+            GeometryType::MultiPolygon,
+            Dimension::Xy,
+            4326,
+            &columns,
+        )
+        .unwrap();
+        drop(gpkg);
+
+        let data_columns = [
+            DataColumn {
+                name: "行政区域コード".to_string(),
+                ksj_id: "N03_007",
+                codelist: None,
+                translated: false,
+            },
+            DataColumn {
+                name: "管理者".to_string(),
+                ksj_id: "A15_003",
+                codelist: Some(&CODELIST),
+                translated: true,
+            },
+        ];
+        write_schema_extension(&conn, "layer", &data_columns).unwrap();
+
+        let extensions: Vec<(String, String)> = conn
+            .prepare("SELECT table_name, extension_name FROM gpkg_extensions ORDER BY table_name")
+            .unwrap()
+            .query_map([], |row| Ok((row.get(0)?, row.get(1)?)))
+            .unwrap()
+            .collect::<Result<_, _>>()
+            .unwrap();
+        assert_eq!(
+            extensions,
+            [
+                (
+                    "gpkg_data_column_constraints".to_string(),
+                    "gpkg_schema".to_string()
+                ),
+                ("gpkg_data_columns".to_string(), "gpkg_schema".to_string()),
+                ("layer".to_string(), "gpkg_rtree_index".to_string()),
+            ]
+        );
+
+        let data_columns: Vec<(String, String, Option<String>)> = conn
+            .prepare("SELECT column_name, name, constraint_name FROM gpkg_data_columns")
+            .unwrap()
+            .query_map([], |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)))
+            .unwrap()
+            .collect::<Result<_, _>>()
+            .unwrap();
+        assert_eq!(
+            data_columns,
+            [
+                ("行政区域コード".to_string(), "N03_007".to_string(), None),
+                (
+                    "管理者".to_string(),
+                    "A15_003".to_string(),
+                    Some("layer_A15_003".to_string())
+                ),
+            ]
+        );
+
+        // The duplicated label is written only once
+        let values: Vec<String> = conn
+            .prepare(
+                "SELECT value FROM gpkg_data_column_constraints WHERE constraint_type = 'enum'",
             )
-        }
+            .unwrap()
+            .query_map([], |row| row.get(0))
+            .unwrap()
+            .collect::<Result<_, _>>()
+            .unwrap();
+        assert_eq!(values, ["国", "都道府県"]);
     }
 }
@@ -0,0 +1,355 @@
+use std::{
+    fmt::Write as _,
+    io::{Read, Seek, Write},
+};
+
+use arrow_array::Array as _;
+use geo_traits::{
+    CoordTrait, Dimensions, GeometryCollectionTrait, GeometryTrait, GeometryType, LineStringTrait,
+    MultiLineStringTrait, MultiPointTrait, MultiPolygonTrait, PointTrait, PolygonTrait,
+};
+
+use crate::{
+    crs::JapanCrs,
+    error::Ksj2GpError,
+    transform_coord::CoordTransformer,
+    translate::{TranslateOptions, TranslationReport},
+    writer::{
+        KmlOptions, construct_schema_from_crs, csv_writer::format_value, for_each_chunk,
+        get_fields_except_geometry,
+    },
+};
+
+// Write KML, or KMZ (a ZIP file that contains "doc.kml") if `kmz` is true.
+// Since KML only supports WGS84, the coordinates are always transformed.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn write_kml<T: Read + Seek, D: Read + Seek, W: Write + Seek>(
+    reader: &mut shapefile::Reader<T, D>,
+    writer: &mut W,
+    dbf_fields: &[dbase::FieldInfo],
+    crs: JapanCrs,
+    translate_options: &TranslateOptions,
+    report: &mut TranslationReport,
+    options: &KmlOptions,
+    kmz: bool,
+) -> Result<(), Ksj2GpError> {
+    if !kmz {
+        return write_kml_document(
+            reader,
+            writer,
+            dbf_fields,
+            crs,
+            translate_options,
+            report,
+            options,
+        );
+    }
+
+    let mut zip = zip::ZipWriter::new(writer);
+    // Google Earth opens the first .kml file in the archive, which is named
+    // doc.kml by convention
+    zip.start_file("doc.kml", zip::write::SimpleFileOptions::default())?;
+    write_kml_document(
+        reader,
+        &mut zip,
+        dbf_fields,
+        crs,
+        translate_options,
+        report,
+        options,
+    )?;
+    zip.finish()?;
+
+    Ok(())
+}
+
+fn write_kml_document<T: Read + Seek, D: Read + Seek, W: Write>(
+    reader: &mut shapefile::Reader<T, D>,
+    writer: &mut W,
+    dbf_fields: &[dbase::FieldInfo],
+    crs: JapanCrs,
+    translate_options: &TranslateOptions,
+    report: &mut TranslationReport,
+    options: &KmlOptions,
+) -> Result<(), Ksj2GpError> {
+    // KML recommends counter-clockwise exterior rings
+    let transformer = CoordTransformer::new(crs.clone()).with_ccw(true);
+
+    let fields_info =
+        construct_schema_from_crs(dbf_fields, &crs, translate_options, report, false)?;
+    let column_names: Vec<&str> = fields_info
+        .non_geo_fields
+        .iter()
+        .map(|f| f.name().as_str())
+        .collect();
+
+    // The label column can be specified either by the original name or by the
+    // translated name
+    let name_column = match &options.name_column {
+        Some(column) => Some(
+            get_fields_except_geometry(dbf_fields)
+                .iter()
+                .position(|name| name == column)
+                .or_else(|| column_names.iter().position(|name| name == column))
+                .ok_or_else(|| format!("Name column {column} doesn't exist"))?,
+        ),
+        None => None,
+    };
+
+    let document_name = std::path::Path::new(&translate_options.target_shp)
+        .file_stem()
+        .and_then(|s| s.to_str())
+        .unwrap_or("output");
+
+    let mut buf = String::new();
+    buf.push_str(r#"<?xml version="1.0" encoding="UTF-8"?>"#);
+    buf.push('\n');
+    buf.push_str(r#"<kml xmlns="http://www.opengis.net/kml/2.2">"#);
+    buf.push('\n');
+    writeln!(buf, "<Document><name>{}</name>", escape_xml(document_name)).unwrap();
+    writer.write_all(buf.as_bytes())?;
+
+    for_each_chunk(reader, dbf_fields, &fields_info, report, |chunk| {
+        let arrays = chunk.arrays;
+
+        for (i, shape) in chunk.shapes.into_iter().enumerate() {
+            // A Placemark without a geometry is valid KML
+            let geometry = match shape {
+                shapefile::Shape::NullShape => None,
+                shape => Some(transformer.transform(&shape)?),
+            };
+
+            buf.clear();
+            buf.push_str("<Placemark>");
+
+            if let Some(index) = name_column {
+                let name = format_value(arrays[index].as_ref(), i)?;
+                if !name.is_empty() {
+                    write!(buf, "<name>{}</name>", escape_xml(&name)).unwrap();
+                }
+            }
+
+            buf.push_str("<ExtendedData>");
+            for (column_name, array) in column_names.iter().zip(&arrays) {
+                // Null values are omitted
+                if array.is_null(i) {
+                    continue;
+                }
+                write!(
+                    buf,
+                    r#"<Data name="{}"><value>{}</value></Data>"#,
+                    escape_xml(column_name),
+                    escape_xml(&format_value(array.as_ref(), i)?)
+                )
+                .unwrap();
+            }
+            buf.push_str("</ExtendedData>");
+
+            if let Some(geometry) = &geometry {
+                write_geometry(&mut buf, geometry);
+            }
+            buf.push_str("</Placemark>\n");
+
+            writer.write_all(buf.as_bytes())?;
+        }
+
+        Ok(())
+    })?;
+
+    writer.write_all(b"</Document>\n</kml>\n")?;
+    writer.flush()?;
+
+    Ok(())
+}
+
+fn escape_xml(s: &str) -> String {
+    let mut escaped = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+// Write "lon,lat[,alt]" separated by spaces
+fn write_coords(buf: &mut String, coords: impl Iterator<Item = impl CoordTrait<T = f64>>) {
+    buf.push_str("<coordinates>");
+    for (i, coord) in coords.enumerate() {
+        if i > 0 {
+            buf.push(' ');
+        }
+        write!(buf, "{},{}", coord.x(), coord.y()).unwrap();
+        // M values are dropped since KML has no place for them
+        if matches!(coord.dim(), Dimensions::Xyz | Dimensions::Xyzm) {
+            write!(buf, ",{}", coord.nth_or_panic(2)).unwrap();
+        }
+    }
+    buf.push_str("</coordinates>");
+}
+
+fn write_point(buf: &mut String, point: &impl PointTrait<T = f64>) {
+    // KML has no representation of an empty point
+    if let Some(coord) = point.coord() {
+        buf.push_str("<Point>");
+        write_coords(buf, std::iter::once(coord));
+        buf.push_str("</Point>");
+    }
+}
+
+fn write_line_string(buf: &mut String, line_string: &impl LineStringTrait<T = f64>) {
+    buf.push_str("<LineString>");
+    write_coords(buf, line_string.coords());
+    buf.push_str("</LineString>");
+}
+
+fn write_linear_ring(buf: &mut String, ring: &impl LineStringTrait<T = f64>) {
+    buf.push_str("<LinearRing>");
+    write_coords(buf, ring.coords());
+    buf.push_str("</LinearRing>");
+}
+
+fn write_polygon(buf: &mut String, polygon: &impl PolygonTrait<T = f64>) {
+    let Some(exterior) = polygon.exterior() else {
+        return;
+    };
+
+    buf.push_str("<Polygon><outerBoundaryIs>");
+    write_linear_ring(buf, &exterior);
+    buf.push_str("</outerBoundaryIs>");
+    for interior in polygon.interiors() {
+        buf.push_str("<innerBoundaryIs>");
+        write_linear_ring(buf, &interior);
+        buf.push_str("</innerBoundaryIs>");
+    }
+    buf.push_str("</Polygon>");
+}
+
+// Multi-part geometries are written as MultiGeometry unless they have only one
+// part
+fn write_geometry(buf: &mut String, geometry: &impl GeometryTrait<T = f64>) {
+    match geometry.as_type() {
+        GeometryType::Point(p) => write_point(buf, p),
+        GeometryType::LineString(ls) => write_line_string(buf, ls),
+        GeometryType::Polygon(p) => write_polygon(buf, p),
+        GeometryType::MultiPoint(mp) => {
+            if mp.num_points() == 1 {
+                write_point(buf, &mp.point(0).unwrap());
+            } else {
+                buf.push_str("<MultiGeometry>");
+                for p in mp.points() {
+                    write_point(buf, &p);
+                }
+                buf.push_str("</MultiGeometry>");
+            }
+        }
+        GeometryType::MultiLineString(mls) => {
+            if mls.num_line_strings() == 1 {
+                write_line_string(buf, &mls.line_string(0).unwrap());
+            } else {
+                buf.push_str("<MultiGeometry>");
+                for ls in mls.line_strings() {
+                    write_line_string(buf, &ls);
+                }
+                buf.push_str("</MultiGeometry>");
+            }
+        }
+        GeometryType::MultiPolygon(mp) => {
+            if mp.num_polygons() == 1 {
+                write_polygon(buf, &mp.polygon(0).unwrap());
+            } else {
+                buf.push_str("<MultiGeometry>");
+                for p in mp.polygons() {
+                    write_polygon(buf, &p);
+                }
+                buf.push_str("</MultiGeometry>");
+            }
+        }
+        GeometryType::GeometryCollection(gc) => {
+            buf.push_str("<MultiGeometry>");
+            for g in gc.geometries() {
+                write_geometry(buf, &g);
+            }
+            buf.push_str("</MultiGeometry>");
+        }
+        // These are never produced by CoordTransformer
+        GeometryType::Rect(_) | GeometryType::Triangle(_) | GeometryType::Line(_) => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn coord(x: f64, y: f64) -> geo_traits::structs::Coord {
+        geo_traits::structs::Coord {
+            x,
+            y,
+            z: None,
+            m: None,
+        }
+    }
+
+    #[test]
+    fn test_escape_xml() {
+        assert_eq!(escape_xml("東京都"), "東京都");
+        assert_eq!(
+            escape_xml(r#"<a href="x">&'</a>"#),
+            "&lt;a href=&quot;x&quot;&gt;&amp;&apos;&lt;/a&gt;"
+        );
+    }
+
+    #[test]
+    fn test_write_geometry() {
+        let point: geo_traits::structs::Geometry =
+            geo_traits::structs::Point::from_coord(coord(139.5, 35.25)).into();
+        let mut buf = String::new();
+        write_geometry(&mut buf, &point);
+        assert_eq!(buf, "<Point><coordinates>139.5,35.25</coordinates></Point>");
+
+        let ring = |coords: &[(f64, f64)]| {
+            geo_traits::structs::LineString::from_coords(
+                coords.iter().map(|&(x, y)| coord(x, y)).collect::<Vec<_>>(),
+            )
+            .unwrap()
+        };
+        let polygon: geo_traits::structs::Geometry =
+            geo_traits::structs::Polygon::from_rings(vec![
+                ring(&[(0.0, 0.0), (4.0, 0.0), (4.0, 4.0), (0.0, 0.0)]),
+                ring(&[(1.0, 1.0), (2.0, 2.0), (2.0, 1.0), (1.0, 1.0)]),
+            ])
+            .unwrap()
+            .into();
+        let mut buf = String::new();
+        write_geometry(&mut buf, &polygon);
+        assert_eq!(
+            buf,
+            "<Polygon>\
+             <outerBoundaryIs><LinearRing><coordinates>0,0 4,0 4,4 0,0</coordinates></LinearRing></outerBoundaryIs>\
+             <innerBoundaryIs><LinearRing><coordinates>1,1 2,2 2,1 1,1</coordinates></LinearRing></innerBoundaryIs>\
+             </Polygon>"
+        );
+
+        let lines: geo_traits::structs::Geometry =
+            geo_traits::structs::MultiLineString::from_line_strings(vec![
+                ring(&[(0.0, 0.0), (1.0, 1.0)]),
+                ring(&[(2.0, 2.0), (3.0, 3.0)]),
+            ])
+            .unwrap()
+            .into();
+        let mut buf = String::new();
+        write_geometry(&mut buf, &lines);
+        assert_eq!(
+            buf,
+            "<MultiGeometry>\
+             <LineString><coordinates>0,0 1,1</coordinates></LineString>\
+             <LineString><coordinates>2,2 3,3</coordinates></LineString>\
+             </MultiGeometry>"
+        );
+    }
+}
@@ -1,12 +1,540 @@
+use std::{
+    io::{Read, Seek},
+    sync::Arc,
+};
+
+use arrow_array::ArrayRef;
+use geoarrow_array::{GeoArrowArray as _, array::WkbArray, cast::from_wkb};
+use geoarrow_schema::{
+    Dimension, GeoArrowType, MultiLineStringType, MultiPointType, MultiPolygonType, PointType,
+};
+use itertools::Itertools as _;
+
+use crate::{
+    builder::{FieldsWithGeo, construct_schema},
+    crs::JapanCrs,
+    error::Ksj2GpError,
+    transform_coord::{CoordTransformer, assemble_rings},
+    translate::{TranslateOptions, TranslationReport},
+};
+
+mod arrow_ipc_writer;
+mod csv_writer;
+mod dataset_writer;
+mod fgb_writer;
 mod geojson_writer;
 mod geoparquet_writer;
 mod gpkg_writer;
+mod kml_writer;
+mod mvt;
+mod pmtiles_writer;
+mod postgis_writer;
+mod shp_writer;
+mod spatial_sort;
+mod tiler;
+mod topojson_writer;
 
-pub(crate) use geojson_writer::write_geojson;
+pub(crate) use arrow_ipc_writer::write_arrow_ipc;
+pub(crate) use csv_writer::write_csv;
+pub(crate) use dataset_writer::write_geoparquet_dataset;
+pub(crate) use fgb_writer::write_flatgeobuf;
+pub(crate) use geojson_writer::{write_geojson, write_geojson_seq};
 pub(crate) use geoparquet_writer::write_geoparquet;
 pub(crate) use gpkg_writer::write_gpkg;
+pub(crate) use kml_writer::write_kml;
+pub(crate) use pmtiles_writer::write_pmtiles;
+pub(crate) use postgis_writer::write_postgis_sql;
+pub(crate) use shp_writer::write_shapefile_zip;
+pub(crate) use topojson_writer::write_topojson;
+
+/// Options for the output formats. Each writer only looks at its own field.
+#[derive(Debug, Clone, Default)]
+pub struct WriterOptions {
+    pub geojson: GeoJsonOptions,
+    pub csv: CsvOptions,
+    pub arrow_ipc: ArrowIpcOptions,
+    pub geoparquet: GeoParquetOptions,
+    pub dataset: DatasetOptions,
+    pub shapefile: ShapefileOptions,
+    pub kml: KmlOptions,
+    pub pmtiles: PmTilesOptions,
+    pub topojson: TopoJsonOptions,
+    pub postgis: PostgisOptions,
+}
+
+/// Options for GeoJson, GeoJsonSeq and GeoJsonLines
+#[derive(Debug, Clone, Default)]
+pub struct GeoJsonOptions {
+    /// Follow RFC 7946 strictly: no "crs" member, and the exterior rings are
+    /// counter-clockwise. The coordinates are always in WGS84 (JGD2000 and
+    /// JGD2011 are treated as the same as WGS84). Since Japan doesn't cross the
+    /// antimeridian, the geometries are never split.
+    pub rfc7946: bool,
+    /// Make the exterior rings counter-clockwise and the interior rings
+    /// clockwise
+    pub force_ccw: bool,
+    /// Round the coordinates to the number of decimal places (at most 15)
+    pub precision: Option<u32>,
+    /// Add "bbox" to each feature and to the FeatureCollection
+    pub bbox: bool,
+}
+
+/// Options for CSV. The coordinates are transformed to WGS84 in the same way
+/// as GeoJSON.
+#[derive(Debug, Clone, Default)]
+pub struct CsvOptions {
+    /// How to write the geometry
+    pub geometry: CsvGeometry,
+    /// Write UTF-8 BOM at the beginning so that Excel can detect the encoding
+    pub bom: bool,
+}
+
+/// Representation of the geometry in CSV
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum CsvGeometry {
+    /// WKT in "geometry" column
+    #[default]
+    Wkt,
+    /// Hex-encoded WKB in "geometry" column
+    Wkb,
+    /// "longitude" and "latitude" columns. Only available for points.
+    LonLat,
+}
+
+/// Options for ArrowIpc and ArrowIpcStream
+#[derive(Debug, Clone, Default)]
+pub struct ArrowIpcOptions {
+    /// Use the native GeoArrow encodings (e.g. geoarrow.multipolygon) instead
+    /// of WKB
+    pub native_encoding: bool,
+}
+
+/// Options for GeoParquet
+#[derive(Debug, Clone)]
+pub struct GeoParquetOptions {
+    /// Use the native GeoArrow encodings (GeoParquet 1.1) instead of WKB. If
+    /// the shape type has no matching native type, WKB is used.
+    pub native_encoding: bool,
+    /// Compression codec. Snappy and Zstd require the `parquet-snappy` and
+    /// `parquet-zstd` features respectively.
+    pub compression: ParquetCompression,
+    /// When to start a new row group
+    pub row_group_size: RowGroupSize,
+    /// Use dictionary encoding for the columns
+    pub dictionary: bool,
+    /// Level of the column statistics
+    pub statistics: ParquetStatistics,
+    /// Sort the features spatially so that the readers can skip the row
+    /// groups by the bbox covering (or the statistics of the native
+    /// encodings). To be effective, the row groups should be small enough.
+    pub spatial_sort: Option<SpatialSortOptions>,
+}
+
+impl Default for GeoParquetOptions {
+    fn default() -> Self {
+        Self {
+            native_encoding: false,
+            compression: ParquetCompression::default(),
+            row_group_size: RowGroupSize::default(),
+            dictionary: true,
+            statistics: ParquetStatistics::default(),
+            spatial_sort: None,
+        }
+    }
+}
+
+/// Compression codec of Parquet
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ParquetCompression {
+    #[default]
+    Uncompressed,
+    Snappy,
+    /// Zstandard with the compression level (1-22). If None, the default level
+    /// is used.
+    Zstd(Option<i32>),
+}
+
+/// Maximum size of a row group of Parquet
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RowGroupSize {
+    /// Number of rows
+    Rows(usize),
+    /// Bytes of the encoded data (before compression). A row group can be a
+    /// bit larger than this.
+    Bytes(usize),
+}
+
+impl Default for RowGroupSize {
+    fn default() -> Self {
+        // Same as the default of the parquet crate
+        Self::Rows(1024 * 1024)
+    }
+}
+
+/// Level of the column statistics of Parquet
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ParquetStatistics {
+    None,
+    /// Statistics per column chunk (i.e., per row group)
+    Chunk,
+    /// Statistics per page and per column chunk
+    #[default]
+    Page,
+}
+
+/// Options for the Hive-partitioned GeoParquet dataset. The dataset is always
+/// partitioned by the KSJ id and the year (e.g. `ksj_id=N03/year=2024/`), and
+/// optionally by the value of a column.
+#[derive(Debug, Clone)]
+pub struct DatasetOptions {
+    /// Name of the column (the original name in the Shapefile, e.g. "N03_007")
+    /// to partition the dataset by
+    pub partition_column: Option<String>,
+    /// Key of the partition in the path (e.g. "pref"). If None, the column name
+    /// is used.
+    pub partition_key: Option<String>,
+    /// Use only the first characters of the value (e.g. 2 for the prefecture
+    /// code of the administrative area code)
+    pub partition_prefix_len: Option<usize>,
+    /// Maximum number of the files to write at the same time. If the data has
+    /// more partitions than this, the least recently used file is closed, and
+    /// a partition can be split into multiple files (e.g. `part-0.parquet` and
+    /// `part-1.parquet`).
+    pub max_open_files: usize,
+}
+
+impl Default for DatasetOptions {
+    fn default() -> Self {
+        Self {
+            partition_column: None,
+            partition_key: None,
+            partition_prefix_len: None,
+            max_open_files: 64,
+        }
+    }
+}
+
+/// Options for Shapefile (zipped)
+#[derive(Debug, Clone, Default)]
+pub struct ShapefileOptions {
+    /// Transform the coordinates to WGS84. Note that JGD2000 and JGD2011 are
+    /// treated as the same as WGS84, so only Tokyo Datum is actually
+    /// transformed.
+    pub reproject: bool,
+}
+
+/// Options for KML and KMZ. The coordinates are always in WGS84.
+#[derive(Debug, Clone, Default)]
+pub struct KmlOptions {
+    /// Column to use as the label (`<name>`) of the placemarks. Either the
+    /// original name in the Shapefile (e.g. "N03_004") or the translated name
+    /// can be specified.
+    pub name_column: Option<String>,
+}
+
+/// Options for PMTiles (Mapbox Vector Tiles). Below the max zoom, the features
+/// smaller than a pixel are dropped and the dense points are thinned out.
+#[derive(Debug, Clone)]
+pub struct PmTilesOptions {
+    pub min_zoom: u8,
+    pub max_zoom: u8,
+    /// Tolerance of the simplification in pixels of a 256x256 tile. 0 disables
+    /// the simplification.
+    pub simplification: f64,
+    /// Name of the layer. If None, the name of the Shapefile is used.
+    pub layer_name: Option<String>,
+}
+
+impl Default for PmTilesOptions {
+    fn default() -> Self {
+        Self {
+            min_zoom: 0,
+            max_zoom: 10,
+            simplification: 1.0,
+            layer_name: None,
+        }
+    }
+}
+
+/// Options for TopoJSON
+#[derive(Debug, Clone)]
+pub struct TopoJsonOptions {
+    /// Number of the quantized positions in each dimension (e.g. 100000). If
+    /// None, the coordinates are not quantized.
+    pub quantization: Option<u32>,
+}
+
+impl Default for TopoJsonOptions {
+    fn default() -> Self {
+        Self {
+            quantization: Some(100_000),
+        }
+    }
+}
+
+/// Options for the SQL dump for PostGIS
+#[derive(Debug, Clone, Default)]
+pub struct PostgisOptions {
+    /// Name of the table. If None, the file name is used (lowercased, and the
+    /// characters other than `[a-z0-9_]` are replaced with `_`).
+    pub table_name: Option<String>,
+    /// Schema of the table (e.g. "ksj"). The schema must already exist.
+    pub schema: Option<String>,
+    /// Add `DROP TABLE IF EXISTS` before `CREATE TABLE`
+    pub drop_table: bool,
+}
+
+/// Options for sorting the features spatially
+#[derive(Debug, Clone)]
+pub struct SpatialSortOptions {
+    /// Space-filling curve to sort the centers of the bounding boxes by
+    pub key: SpatialSortKey,
+    /// Maximum bytes of the data to keep in memory. If the data exceeds this,
+    /// the sorted data is spilled to temporary files and merged later. The
+    /// actual memory usage is a bit larger than this, since one batch per
+    /// spill file is read at the time of the merge.
+    pub memory_budget: usize,
+    /// Directory to create the spill files in. If None, the system's temporary
+    /// directory is used.
+    pub spill_dir: Option<std::path::PathBuf>,
+}
+
+impl Default for SpatialSortOptions {
+    fn default() -> Self {
+        Self {
+            key: SpatialSortKey::default(),
+            memory_budget: 256 * 1024 * 1024,
+            spill_dir: None,
+        }
+    }
+}
+
+/// Space-filling curve used for the spatial sort
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum SpatialSortKey {
+    #[default]
+    Hilbert,
+    /// Z-order (Morton code)
+    ZOrder,
+}
 
 // dBASE fields doesn't include the geometry column
 fn get_fields_except_geometry(x: &[dbase::FieldInfo]) -> Vec<&str> {
     x.iter().map(|f| f.name()).collect()
 }
+
+// Number of rows to process at once
+const CHUNK_SIZE: usize = 2048;
+
+// Same as construct_schema(), but the CRS of the geometry column is converted
+// from JapanCrs
+fn construct_schema_from_crs(
+    dbf_fields: &[dbase::FieldInfo],
+    crs: &JapanCrs,
+    translate_options: &TranslateOptions,
+    report: &mut TranslationReport,
+    dictionary_encode_codes: bool,
+) -> Result<FieldsWithGeo, Ksj2GpError> {
+    let projjson: serde_json::Value =
+        serde_json::from_str(crs.to_projjson()).expect("embedded PROJJSON should be valid JSON");
+    let crs = geoarrow_schema::Crs::from_projjson(projjson);
+
+    construct_schema(
+        dbf_fields,
+        crs,
+        translate_options,
+        report,
+        dictionary_encode_codes,
+    )
+}
+
+// The attributes and the shapes of CHUNK_SIZE rows (or less, for the last one)
+struct Chunk {
+    // Row number of the first row of the chunk
+    offset: usize,
+    // In the same order as `fields_info.non_geo_fields`
+    arrays: Vec<ArrayRef>,
+    // Not transformed
+    shapes: Vec<shapefile::Shape>,
+}
+
+// Read the shapes and the records by chunk, and call `f` with each chunk
+fn for_each_chunk<T: Read + Seek, D: Read + Seek>(
+    reader: &mut shapefile::Reader<T, D>,
+    dbf_fields: &[dbase::FieldInfo],
+    fields_info: &FieldsWithGeo,
+    report: &mut TranslationReport,
+    mut f: impl FnMut(Chunk) -> Result<(), Ksj2GpError>,
+) -> Result<(), Ksj2GpError> {
+    // Since shapefile::Record is a HashMap, the iterator of it doesn't maintain
+    // the order. So, this column names vector is needed to ensure the consistent
+    // order with the schema.
+    let field_names = get_fields_except_geometry(dbf_fields);
+
+    let chunks = reader
+        .iter_shapes_and_records()
+        .enumerate()
+        .chunks(CHUNK_SIZE);
+    for (chunk_index, chunk) in chunks.into_iter().enumerate() {
+        let mut builders = fields_info.create_builders(CHUNK_SIZE)?;
+        let mut shapes = Vec::with_capacity(CHUNK_SIZE);
+
+        for (row, result) in chunk {
+            let (shape, mut record) = result?;
+
+            for (i, field_name) in field_names.iter().enumerate() {
+                let value = record
+                    .remove(field_name)
+                    .ok_or_else(|| format!("Not found {field_name}"))?;
+                builders.builders[i].push(value, field_name, row, report)?;
+            }
+
+            shapes.push(shape);
+        }
+
+        f(Chunk {
+            offset: chunk_index * CHUNK_SIZE,
+            arrays: builders.builders.iter_mut().map(|b| b.finish()).collect(),
+            shapes,
+        })?;
+    }
+
+    Ok(())
+}
+
+// Convert the shapes into WKB by push_shape()
+fn wkb_array(
+    fields_info: &FieldsWithGeo,
+    shapes: impl IntoIterator<Item = shapefile::Shape>,
+    measures: bool,
+) -> Result<WkbArray, Ksj2GpError> {
+    let mut geo_builder = fields_info.create_geo_builder();
+    for shape in shapes {
+        push_shape(&mut geo_builder, shape, measures)?;
+    }
+    Ok(geo_builder.finish())
+}
+
+// Push a shape to the WKB builder without transforming the coordinates. A null
+// shape is pushed as null. `measures` is the result of has_measures() on the
+// header.
+fn push_shape(
+    geo_builder: &mut geoarrow_array::builder::WkbBuilder<i32>,
+    shape: shapefile::Shape,
+    measures: bool,
+) -> Result<(), crate::Ksj2GpError> {
+    match assemble_rings(shape) {
+        shapefile::Shape::NullShape => {
+            geo_builder.push_wkb(None)?;
+        }
+        shapefile::Shape::Point(point) => {
+            geo_builder.push_geometry(Some(&point))?;
+        }
+        shapefile::Shape::Polyline(polyline) => {
+            geo_builder.push_geometry(Some(&polyline))?;
+        }
+        shapefile::Shape::Polygon(polygon) => {
+            geo_builder.push_geometry(Some(&polygon.try_into_geo_traits()?))?;
+        }
+        shapefile::Shape::Multipoint(multipoint) => {
+            geo_builder.push_geometry(Some(&multipoint))?;
+        }
+        shape => {
+            geo_builder.push_geometry(Some(&shape_to_geometry(&shape, measures)?))?;
+        }
+    }
+
+    Ok(())
+}
+
+// Convert a shape with Z or M values (or a Multipatch, which becomes a
+// MultiPolygon Z) into a geometry without transforming the coordinates. The
+// geo-traits implementation of the shapefile crate is not used for them
+// because it decides the dimension per coordinate (by whether M is no data), so
+// a geometry can end up with mixed dimensions. Here, the dimension is decided
+// per file.
+fn shape_to_geometry(
+    shape: &shapefile::Shape,
+    measures: bool,
+) -> Result<geo_traits::structs::Geometry<f64>, crate::Ksj2GpError> {
+    CoordTransformer::identity()
+        .with_measures(measures)
+        .transform(shape)
+}
+
+// The native GeoArrow type matching the shape type. Polylines and polygons of
+// Shapefile can contain multiple parts, so they are always Multi* types. The
+// metadata (CRS) is taken from `wkb_type`. The Z types are XYZM if `measures`
+// is true. Returns None if there's no matching type, in which case WKB should
+// be used instead.
+fn native_type_from_shape_type(
+    shape_type: shapefile::ShapeType,
+    measures: bool,
+    wkb_type: &GeoArrowType,
+) -> Option<GeoArrowType> {
+    let metadata = wkb_type.metadata().clone();
+    let xyz = if measures {
+        Dimension::XYZM
+    } else {
+        Dimension::XYZ
+    };
+    let native_type = match shape_type {
+        shapefile::ShapeType::Point => GeoArrowType::Point(PointType::new(Dimension::XY, metadata)),
+        shapefile::ShapeType::PointM => {
+            GeoArrowType::Point(PointType::new(Dimension::XYM, metadata))
+        }
+        shapefile::ShapeType::PointZ => GeoArrowType::Point(PointType::new(xyz, metadata)),
+        shapefile::ShapeType::Multipoint => {
+            GeoArrowType::MultiPoint(MultiPointType::new(Dimension::XY, metadata))
+        }
+        shapefile::ShapeType::MultipointM => {
+            GeoArrowType::MultiPoint(MultiPointType::new(Dimension::XYM, metadata))
+        }
+        shapefile::ShapeType::MultipointZ => {
+            GeoArrowType::MultiPoint(MultiPointType::new(xyz, metadata))
+        }
+        shapefile::ShapeType::Polyline => {
+            GeoArrowType::MultiLineString(MultiLineStringType::new(Dimension::XY, metadata))
+        }
+        shapefile::ShapeType::PolylineM => {
+            GeoArrowType::MultiLineString(MultiLineStringType::new(Dimension::XYM, metadata))
+        }
+        shapefile::ShapeType::PolylineZ => {
+            GeoArrowType::MultiLineString(MultiLineStringType::new(xyz, metadata))
+        }
+        shapefile::ShapeType::Polygon => {
+            GeoArrowType::MultiPolygon(MultiPolygonType::new(Dimension::XY, metadata))
+        }
+        shapefile::ShapeType::PolygonM => {
+            GeoArrowType::MultiPolygon(MultiPolygonType::new(Dimension::XYM, metadata))
+        }
+        shapefile::ShapeType::PolygonZ | shapefile::ShapeType::Multipatch => {
+            GeoArrowType::MultiPolygon(MultiPolygonType::new(xyz, metadata))
+        }
+        _ => return None,
+    };
+
+    Some(native_type)
+}
+
+// Same schema as `fields_info.schema_ref`, but the geometry column (always the
+// last one) is of the native type
+fn schema_with_native_geometry(
+    fields_info: &FieldsWithGeo,
+    native_type: &GeoArrowType,
+) -> arrow_schema::SchemaRef {
+    let mut fields = fields_info.non_geo_fields.clone();
+    fields.push(Arc::new(native_type.to_field("geometry", true)));
+    Arc::new(arrow_schema::Schema::new(fields))
+}
+
+// Convert the WKB array to the native type if it's specified
+fn finish_geometry(
+    wkb_array: WkbArray,
+    native_type: Option<&GeoArrowType>,
+) -> Result<arrow_array::ArrayRef, crate::Ksj2GpError> {
+    match native_type {
+        Some(native_type) => Ok(from_wkb(&wkb_array, native_type.clone())?.into_array_ref()),
+        None => Ok(wkb_array.into_array_ref()),
+    }
+}
@@ -0,0 +1,246 @@
+// Minimal encoder of Mapbox Vector Tile (version 2). Since the schema is small,
+// the protobuf messages are written by hand instead of depending on a protobuf
+// crate.
+//
+// cf. https://github.com/mapbox/vector-tile-spec/tree/master/2.1
+
+// Wire types of protobuf
+const VARINT: u32 = 0;
+const FIXED64: u32 = 1;
+const LEN: u32 = 2;
+const FIXED32: u32 = 5;
+
+// Commands of the geometry encoding
+const MOVE_TO: u32 = 1;
+const LINE_TO: u32 = 2;
+const CLOSE_PATH: u32 = 7;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum GeomType {
+    Point = 1,
+    LineString = 2,
+    Polygon = 3,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub(super) enum MvtValue {
+    String(String),
+    Float(f32),
+    Double(f64),
+    SInt(i64),
+    Bool(bool),
+}
+
+#[derive(Debug, Clone)]
+pub(super) struct MvtFeature {
+    pub(super) id: u64,
+    // Pairs of (key index, value index)
+    pub(super) tags: Vec<u32>,
+    pub(super) geom_type: GeomType,
+    pub(super) geometry: Vec<u32>,
+}
+
+pub(super) fn write_varint(buf: &mut Vec<u8>, mut value: u64) {
+    while value >= 0x80 {
+        buf.push((value as u8) | 0x80);
+        value >>= 7;
+    }
+    buf.push(value as u8);
+}
+
+fn zigzag(value: i64) -> u64 {
+    ((value << 1) ^ (value >> 63)) as u64
+}
+
+fn write_key(buf: &mut Vec<u8>, field: u32, wire_type: u32) {
+    write_varint(buf, ((field << 3) | wire_type) as u64);
+}
+
+fn write_bytes(buf: &mut Vec<u8>, field: u32, bytes: &[u8]) {
+    write_key(buf, field, LEN);
+    write_varint(buf, bytes.len() as u64);
+    buf.extend_from_slice(bytes);
+}
+
+fn write_packed(buf: &mut Vec<u8>, field: u32, values: &[u32]) {
+    let mut packed = Vec::with_capacity(values.len() * 2);
+    for &v in values {
+        write_varint(&mut packed, v as u64);
+    }
+    write_bytes(buf, field, &packed);
+}
+
+// Encode the body of a Value message. Since the values are deduplicated by the
+// encoded bytes, the same value always needs to produce the same bytes.
+pub(super) fn encode_value(value: &MvtValue) -> Vec<u8> {
+    let mut buf = Vec::new();
+    match value {
+        MvtValue::String(s) => write_bytes(&mut buf, 1, s.as_bytes()),
+        MvtValue::Float(v) => {
+            write_key(&mut buf, 2, FIXED32);
+            buf.extend_from_slice(&v.to_le_bytes());
+        }
+        MvtValue::Double(v) => {
+            write_key(&mut buf, 3, FIXED64);
+            buf.extend_from_slice(&v.to_le_bytes());
+        }
+        MvtValue::SInt(v) => {
+            write_key(&mut buf, 6, VARINT);
+            write_varint(&mut buf, zigzag(*v));
+        }
+        MvtValue::Bool(v) => {
+            write_key(&mut buf, 7, VARINT);
+            write_varint(&mut buf, *v as u64);
+        }
+    }
+    buf
+}
+
+// Encode a tile with a single layer. `keys` and `values` (the encoded Value
+// messages) are the tables the tags of the features point to.
+pub(super) fn encode_tile(
+    layer_name: &str,
+    extent: u32,
+    features: &[MvtFeature],
+    keys: &[&str],
+    values: &[&[u8]],
+) -> Vec<u8> {
+    let mut layer = Vec::new();
+    write_key(&mut layer, 15, VARINT);
+    write_varint(&mut layer, 2);
+    write_bytes(&mut layer, 1, layer_name.as_bytes());
+
+    let mut feature_buf = Vec::new();
+    for feature in features {
+        feature_buf.clear();
+        write_key(&mut feature_buf, 1, VARINT);
+        write_varint(&mut feature_buf, feature.id);
+        if !feature.tags.is_empty() {
+            write_packed(&mut feature_buf, 2, &feature.tags);
+        }
+        write_key(&mut feature_buf, 3, VARINT);
+        write_varint(&mut feature_buf, feature.geom_type as u64);
+        write_packed(&mut feature_buf, 4, &feature.geometry);
+
+        write_bytes(&mut layer, 2, &feature_buf);
+    }
+
+    for key in keys {
+        write_bytes(&mut layer, 3, key.as_bytes());
+    }
+    for value in values {
+        write_bytes(&mut layer, 4, value);
+    }
+
+    write_key(&mut layer, 5, VARINT);
+    write_varint(&mut layer, extent as u64);
+
+    let mut tile = Vec::with_capacity(layer.len() + 8);
+    write_bytes(&mut tile, 3, &layer);
+    tile
+}
+
+// Encoder of the geometry commands. The cursor is shared among all the parts
+// of a feature.
+#[derive(Debug, Default)]
+pub(super) struct GeometryEncoder {
+    cursor: [i32; 2],
+    commands: Vec<u32>,
+}
+
+impl GeometryEncoder {
+    pub(super) fn is_empty(&self) -> bool {
+        self.commands.is_empty()
+    }
+
+    pub(super) fn finish(self) -> Vec<u32> {
+        self.commands
+    }
+
+    fn command(&mut self, id: u32, count: usize) {
+        self.commands.push(id | ((count as u32) << 3));
+    }
+
+    fn param(&mut self, point: [i32; 2]) {
+        let dx = (point[0] - self.cursor[0]) as i64;
+        let dy = (point[1] - self.cursor[1]) as i64;
+        self.commands.push(zigzag(dx) as u32);
+        self.commands.push(zigzag(dy) as u32);
+        self.cursor = point;
+    }
+
+    pub(super) fn points(&mut self, points: &[[i32; 2]]) {
+        if points.is_empty() {
+            return;
+        }
+        self.command(MOVE_TO, points.len());
+        for &p in points {
+            self.param(p);
+        }
+    }
+
+    pub(super) fn line_string(&mut self, points: &[[i32; 2]]) {
+        if points.len() < 2 {
+            return;
+        }
+        self.command(MOVE_TO, 1);
+        self.param(points[0]);
+        self.command(LINE_TO, points.len() - 1);
+        for &p in &points[1..] {
+            self.param(p);
+        }
+    }
+
+    // `ring` must not contain the closing point
+    pub(super) fn ring(&mut self, ring: &[[i32; 2]]) {
+        if ring.len() < 3 {
+            return;
+        }
+        self.line_string(ring);
+        self.command(CLOSE_PATH, 1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_varint() {
+        let mut buf = Vec::new();
+        write_varint(&mut buf, 1);
+        write_varint(&mut buf, 300);
+        assert_eq!(buf, [0x01, 0xAC, 0x02]);
+
+        assert_eq!(zigzag(0), 0);
+        assert_eq!(zigzag(-1), 1);
+        assert_eq!(zigzag(1), 2);
+        assert_eq!(zigzag(-2), 3);
+    }
+
+    // Examples from the spec
+    #[test]
+    fn test_geometry_encoder() {
+        let mut encoder = GeometryEncoder::default();
+        encoder.points(&[[25, 17]]);
+        assert_eq!(encoder.finish(), [9, 50, 34]);
+
+        let mut encoder = GeometryEncoder::default();
+        encoder.line_string(&[[2, 2], [2, 10], [10, 10]]);
+        assert_eq!(encoder.finish(), [9, 4, 4, 18, 0, 16, 16, 0]);
+
+        let mut encoder = GeometryEncoder::default();
+        encoder.ring(&[[3, 6], [8, 12], [20, 34]]);
+        assert_eq!(encoder.finish(), [9, 6, 12, 18, 10, 12, 24, 44, 15]);
+    }
+
+    #[test]
+    fn test_encode_value() {
+        assert_eq!(
+            encode_value(&MvtValue::String("東".to_string())),
+            [0x0A, 3, 0xE6, 0x9D, 0xB1]
+        );
+        assert_eq!(encode_value(&MvtValue::SInt(-1)), [0x30, 1]);
+        assert_eq!(encode_value(&MvtValue::Bool(true)), [0x38, 1]);
+    }
+}
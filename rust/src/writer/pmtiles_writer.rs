@@ -0,0 +1,490 @@
+use std::{
+    collections::HashMap,
+    io::{Read, Seek, Write},
+};
+
+use arrow_array::{Array, cast::AsArray as _};
+
+use crate::{
+    crs::JapanCrs,
+    error::Ksj2GpError,
+    transform_coord::CoordTransformer,
+    translate::{TranslateOptions, TranslationReport},
+    writer::{
+        PmTilesOptions, construct_schema_from_crs,
+        csv_writer::format_value,
+        for_each_chunk,
+        mvt::{MvtFeature, MvtValue, encode_tile, encode_value, write_varint},
+        tiler::{EXTENT, TileFeature, Tiler, WorldGeometry, unproject},
+    },
+};
+
+// PMTiles v3
+// cf. https://github.com/protomaps/PMTiles/blob/main/spec/v3/spec.md
+const HEADER_SIZE: usize = 127;
+// The header and the root directory must fit in the first 16 KiB
+const MAX_ROOT_DIRECTORY_SIZE: usize = 16384 - HEADER_SIZE;
+const COMPRESSION_GZIP: u8 = 2;
+const TILE_TYPE_MVT: u8 = 1;
+
+// Largest zoom level that doesn't overflow the tile id
+const MAX_ZOOM: u8 = 24;
+
+// Write the features as Mapbox Vector Tiles in a PMTiles archive. The features
+// are always transformed to WGS84 and then projected to Web Mercator. All the
+// features are kept in memory during tiling.
+pub(crate) fn write_pmtiles<T: Read + Seek, D: Read + Seek, W: Write>(
+    reader: &mut shapefile::Reader<T, D>,
+    writer: &mut W,
+    dbf_fields: &[dbase::FieldInfo],
+    crs: JapanCrs,
+    translate_options: &TranslateOptions,
+    report: &mut TranslationReport,
+    options: &PmTilesOptions,
+) -> Result<(), Ksj2GpError> {
+    if options.min_zoom > options.max_zoom {
+        return Err(format!(
+            "min zoom ({}) must be smaller than or equal to max zoom ({})",
+            options.min_zoom, options.max_zoom
+        )
+        .into());
+    }
+    if options.max_zoom > MAX_ZOOM {
+        return Err(format!("max zoom must be {MAX_ZOOM} or smaller").into());
+    }
+
+    let transformer = CoordTransformer::new(crs.clone());
+
+    let fields_info =
+        construct_schema_from_crs(dbf_fields, &crs, translate_options, report, false)?;
+    let keys: Vec<&str> = fields_info
+        .non_geo_fields
+        .iter()
+        .map(|f| f.name().as_str())
+        .collect();
+
+    let layer_name = match &options.layer_name {
+        Some(name) => name.as_str(),
+        None => std::path::Path::new(&translate_options.target_shp)
+            .file_stem()
+            .and_then(|s| s.to_str())
+            .unwrap_or("output"),
+    };
+
+    // The values are deduplicated over the whole dataset, and the tags of the
+    // features point to this table until they are written to each tile.
+    let mut values: Vec<Vec<u8>> = Vec::new();
+    let mut value_ids: HashMap<Vec<u8>, u32> = HashMap::new();
+
+    let mut features = Vec::new();
+    // Bounds in the Web Mercator coordinates
+    let mut world_bbox = [
+        f64::INFINITY,
+        f64::INFINITY,
+        f64::NEG_INFINITY,
+        f64::NEG_INFINITY,
+    ];
+
+    for_each_chunk(reader, dbf_fields, &fields_info, report, |chunk| {
+        for (i, shape) in chunk.shapes.into_iter().enumerate() {
+            let shape = transformer.transform_shape(shape)?;
+            let Some(geometry) = WorldGeometry::from_shape(&shape)? else {
+                continue;
+            };
+
+            let bbox = geometry.bbox();
+            world_bbox[0] = world_bbox[0].min(bbox[0]);
+            world_bbox[1] = world_bbox[1].min(bbox[1]);
+            world_bbox[2] = world_bbox[2].max(bbox[2]);
+            world_bbox[3] = world_bbox[3].max(bbox[3]);
+
+            let mut tags = Vec::new();
+            for (key, array) in chunk.arrays.iter().enumerate() {
+                // Null values are omitted
+                let Some(value) = mvt_value(array.as_ref(), i)? else {
+                    continue;
+                };
+                let encoded = encode_value(&value);
+                let id = match value_ids.get(&encoded) {
+                    Some(&id) => id,
+                    None => {
+                        let id = values.len() as u32;
+                        values.push(encoded.clone());
+                        value_ids.insert(encoded, id);
+                        id
+                    }
+                };
+                tags.push(key as u32);
+                tags.push(id);
+            }
+
+            features.push(TileFeature {
+                id: (chunk.offset + i) as u64,
+                tags,
+                geometry,
+            });
+        }
+
+        Ok(())
+    })?;
+    drop(value_ids);
+
+    let mut tiles = Vec::new();
+    for zoom in options.min_zoom..=options.max_zoom {
+        let mut tiler = Tiler::new(zoom, options.simplification, zoom < options.max_zoom);
+        for feature in &features {
+            tiler.add(feature);
+        }
+
+        for ((x, y), tile_features) in tiler.finish() {
+            let tile = encode_tile_with_local_tags(layer_name, tile_features, &keys, &values);
+            tiles.push((zxy_to_tile_id(zoom, x, y), gzip(&tile)?));
+        }
+    }
+    tiles.sort_by_key(|(tile_id, _)| *tile_id);
+
+    let fields: serde_json::Map<String, serde_json::Value> = fields_info
+        .non_geo_fields
+        .iter()
+        .map(|f| {
+            let field_type = match f.data_type() {
+                arrow_schema::DataType::Boolean => "Boolean",
+                arrow_schema::DataType::Int32
+                | arrow_schema::DataType::Float32
+                | arrow_schema::DataType::Float64 => "Number",
+                _ => "String",
+            };
+            (f.name().clone(), field_type.into())
+        })
+        .collect();
+    let metadata = serde_json::json!({
+        "name": layer_name,
+        "format": "pbf",
+        "attribution": "国土数値情報（国土交通省）",
+        "vector_layers": [{
+            "id": layer_name,
+            "fields": fields,
+            "minzoom": options.min_zoom,
+            "maxzoom": options.max_zoom,
+        }],
+    });
+
+    // min_lon, min_lat, max_lon, max_lat. Note that the y axis of Web Mercator
+    // points down.
+    let bounds = if world_bbox[0] <= world_bbox[2] {
+        let (min_lon, max_lat) = unproject([world_bbox[0], world_bbox[1]]);
+        let (max_lon, min_lat) = unproject([world_bbox[2], world_bbox[3]]);
+        [min_lon, min_lat, max_lon, max_lat]
+    } else {
+        [-180.0, -85.0, 180.0, 85.0]
+    };
+
+    write_archive(
+        writer,
+        &tiles,
+        metadata.to_string().as_bytes(),
+        options.min_zoom,
+        options.max_zoom,
+        bounds,
+    )
+}
+
+// Null is None. Dates are written as strings since MVT has no date type.
+fn mvt_value(array: &dyn Array, i: usize) -> Result<Option<MvtValue>, Ksj2GpError> {
+    if array.is_null(i) {
+        return Ok(None);
+    }
+
+    let value = match array.data_type() {
+        arrow_schema::DataType::Boolean => MvtValue::Bool(array.as_boolean().value(i)),
+        arrow_schema::DataType::Int32 => MvtValue::SInt(
+            array
+                .as_primitive::<arrow_array::types::Int32Type>()
+                .value(i) as i64,
+        ),
+        arrow_schema::DataType::Float32 => MvtValue::Float(
+            array
+                .as_primitive::<arrow_array::types::Float32Type>()
+                .value(i),
+        ),
+        arrow_schema::DataType::Float64 => MvtValue::Double(
+            array
+                .as_primitive::<arrow_array::types::Float64Type>()
+                .value(i),
+        ),
+        _ => MvtValue::String(format_value(array, i)?),
+    };
+
+    Ok(Some(value))
+}
+
+// Rewrite the tags to point to the keys and the values used in the tile, and
+// encode the tile
+fn encode_tile_with_local_tags(
+    layer_name: &str,
+    mut features: Vec<MvtFeature>,
+    keys: &[&str],
+    values: &[Vec<u8>],
+) -> Vec<u8> {
+    let mut local_keys = Vec::new();
+    let mut local_key_ids = HashMap::new();
+    let mut local_values = Vec::new();
+    let mut local_value_ids = HashMap::new();
+
+    for feature in &mut features {
+        for pair in feature.tags.chunks_exact_mut(2) {
+            pair[0] = *local_key_ids.entry(pair[0]).or_insert_with(|| {
+                local_keys.push(keys[pair[0] as usize]);
+                local_keys.len() as u32 - 1
+            });
+            pair[1] = *local_value_ids.entry(pair[1]).or_insert_with(|| {
+                local_values.push(values[pair[1] as usize].as_slice());
+                local_values.len() as u32 - 1
+            });
+        }
+    }
+
+    encode_tile(layer_name, EXTENT, &features, &local_keys, &local_values)
+}
+
+fn gzip(data: &[u8]) -> Result<Vec<u8>, Ksj2GpError> {
+    let mut encoder = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::default());
+    encoder.write_all(data)?;
+    Ok(encoder.finish()?)
+}
+
+// Tile id of PMTiles, i.e. the position on the Hilbert curve plus the number
+// of the tiles of the lower zoom levels
+fn zxy_to_tile_id(z: u8, x: u32, y: u32) -> u64 {
+    let mut acc: u64 = 0;
+    for t in 0..z {
+        acc += 1 << (2 * t);
+    }
+
+    let (mut x, mut y) = (x as u64, y as u64);
+    let mut d: u64 = 0;
+    let mut s: u64 = (1 << z) / 2;
+    while s > 0 {
+        let rx = ((x & s) > 0) as u64;
+        let ry = ((y & s) > 0) as u64;
+        d += s * s * ((3 * rx) ^ ry);
+        // rotate
+        if ry == 0 {
+            if rx == 1 {
+                x = s - 1 - (x & (s - 1));
+                y = s - 1 - (y & (s - 1));
+            }
+            std::mem::swap(&mut x, &mut y);
+        }
+        s /= 2;
+    }
+
+    acc + d
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Entry {
+    tile_id: u64,
+    offset: u64,
+    length: u32,
+    // 0 means the entry points to a leaf directory
+    run_length: u32,
+}
+
+fn serialize_directory(entries: &[Entry]) -> Result<Vec<u8>, Ksj2GpError> {
+    let mut buf = Vec::new();
+    write_varint(&mut buf, entries.len() as u64);
+
+    let mut last_id = 0;
+    for e in entries {
+        write_varint(&mut buf, e.tile_id - last_id);
+        last_id = e.tile_id;
+    }
+    for e in entries {
+        write_varint(&mut buf, e.run_length as u64);
+    }
+    for e in entries {
+        write_varint(&mut buf, e.length as u64);
+    }
+    for (i, e) in entries.iter().enumerate() {
+        if i > 0 && e.offset == entries[i - 1].offset + entries[i - 1].length as u64 {
+            write_varint(&mut buf, 0);
+        } else {
+            write_varint(&mut buf, e.offset + 1);
+        }
+    }
+
+    gzip(&buf)
+}
+
+// Returns the root directory and the leaf directories
+fn build_directories(entries: &[Entry]) -> Result<(Vec<u8>, Vec<u8>), Ksj2GpError> {
+    let root = serialize_directory(entries)?;
+    if root.len() <= MAX_ROOT_DIRECTORY_SIZE {
+        return Ok((root, Vec::new()));
+    }
+
+    // Split the entries into leaf directories, and increase the size of the
+    // leaves until the root directory fits
+    let mut leaf_size = 4096.max(entries.len() / 3500);
+    loop {
+        let mut root_entries = Vec::new();
+        let mut leaves = Vec::new();
+        for chunk in entries.chunks(leaf_size) {
+            let leaf = serialize_directory(chunk)?;
+            root_entries.push(Entry {
+                tile_id: chunk[0].tile_id,
+                offset: leaves.len() as u64,
+                length: leaf.len() as u32,
+                run_length: 0,
+            });
+            leaves.extend_from_slice(&leaf);
+        }
+
+        let root = serialize_directory(&root_entries)?;
+        if root.len() <= MAX_ROOT_DIRECTORY_SIZE {
+            return Ok((root, leaves));
+        }
+        leaf_size += leaf_size / 5;
+    }
+}
+
+// `tiles` must be sorted by the tile id
+fn write_archive<W: Write>(
+    writer: &mut W,
+    tiles: &[(u64, Vec<u8>)],
+    metadata: &[u8],
+    min_zoom: u8,
+    max_zoom: u8,
+    bounds: [f64; 4],
+) -> Result<(), Ksj2GpError> {
+    // Identical tiles (e.g. the tiles fully covered by a polygon) are stored
+    // only once. The consecutive ones are merged into a single entry.
+    let mut entries: Vec<Entry> = Vec::new();
+    let mut offsets: HashMap<&[u8], u64> = HashMap::new();
+    let mut tile_data_length: u64 = 0;
+    for (tile_id, data) in tiles {
+        let offset = match offsets.get(data.as_slice()) {
+            Some(&offset) => offset,
+            None => {
+                let offset = tile_data_length;
+                offsets.insert(data.as_slice(), offset);
+                tile_data_length += data.len() as u64;
+                offset
+            }
+        };
+
+        if let Some(last) = entries.last_mut()
+            && last.offset == offset
+            && last.tile_id + last.run_length as u64 == *tile_id
+        {
+            last.run_length += 1;
+            continue;
+        }
+
+        entries.push(Entry {
+            tile_id: *tile_id,
+            offset,
+            length: data.len() as u32,
+            run_length: 1,
+        });
+    }
+
+    let (root, leaves) = build_directories(&entries)?;
+    let metadata = gzip(metadata)?;
+
+    let root_offset = HEADER_SIZE as u64;
+    let metadata_offset = root_offset + root.len() as u64;
+    let leaves_offset = metadata_offset + metadata.len() as u64;
+    let tile_data_offset = leaves_offset + leaves.len() as u64;
+
+    let mut header = Vec::with_capacity(HEADER_SIZE);
+    header.extend_from_slice(b"PMTiles");
+    header.push(3);
+    for v in [
+        root_offset,
+        root.len() as u64,
+        metadata_offset,
+        metadata.len() as u64,
+        leaves_offset,
+        leaves.len() as u64,
+        tile_data_offset,
+        tile_data_length,
+        tiles.len() as u64,
+        entries.len() as u64,
+        offsets.len() as u64,
+    ] {
+        header.extend_from_slice(&v.to_le_bytes());
+    }
+    // clustered
+    header.push(1);
+    // internal compression and tile compression
+    header.push(COMPRESSION_GZIP);
+    header.push(COMPRESSION_GZIP);
+    header.push(TILE_TYPE_MVT);
+    header.push(min_zoom);
+    header.push(max_zoom);
+    let e7 = |v: f64| ((v * 1e7).round() as i32).to_le_bytes();
+    for v in bounds {
+        header.extend_from_slice(&e7(v));
+    }
+    // center
+    header.push(min_zoom);
+    header.extend_from_slice(&e7((bounds[0] + bounds[2]) / 2.0));
+    header.extend_from_slice(&e7((bounds[1] + bounds[3]) / 2.0));
+    debug_assert_eq!(header.len(), HEADER_SIZE);
+
+    writer.write_all(&header)?;
+    writer.write_all(&root)?;
+    writer.write_all(&metadata)?;
+    writer.write_all(&leaves)?;
+
+    let mut written = std::collections::HashSet::new();
+    for (_, data) in tiles {
+        if written.insert(data.as_slice()) {
+            writer.write_all(data)?;
+        }
+    }
+    writer.flush()?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Examples from the PMTiles test suite
+    #[test]
+    fn test_zxy_to_tile_id() {
+        assert_eq!(zxy_to_tile_id(0, 0, 0), 0);
+        assert_eq!(zxy_to_tile_id(1, 0, 0), 1);
+        assert_eq!(zxy_to_tile_id(1, 0, 1), 2);
+        assert_eq!(zxy_to_tile_id(1, 1, 1), 3);
+        assert_eq!(zxy_to_tile_id(1, 1, 0), 4);
+        assert_eq!(zxy_to_tile_id(2, 0, 0), 5);
+    }
+
+    #[test]
+    fn test_write_archive() {
+        let tiles = vec![
+            (0, b"a".to_vec()),
+            (1, b"b".to_vec()),
+            (2, b"b".to_vec()),
+            (4, b"a".to_vec()),
+        ];
+        let mut out = Vec::new();
+        write_archive(&mut out, &tiles, b"{}", 0, 1, [139.0, 35.0, 140.0, 36.0]).unwrap();
+
+        assert_eq!(&out[0..8], b"PMTiles\x03");
+        let u64_at = |i: usize| u64::from_le_bytes(out[i..i + 8].try_into().unwrap());
+        // tile data
+        let tile_data_offset = u64_at(56) as usize;
+        assert_eq!(&out[tile_data_offset..], b"ab");
+        // addressed tiles, tile entries, tile contents
+        assert_eq!(u64_at(72), 4);
+        assert_eq!(u64_at(80), 3);
+        assert_eq!(u64_at(88), 2);
+        assert_eq!(&out[100..102], [0, 1]);
+    }
+}
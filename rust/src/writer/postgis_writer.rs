@@ -0,0 +1,365 @@
+use std::io::{Read, Seek, Write};
+
+use arrow_array::Array;
+use itertools::Itertools as _;
+
+use crate::{
+    crs::JapanCrs,
+    error::Ksj2GpError,
+    transform_coord::has_measures,
+    translate::{TranslateOptions, TranslationReport},
+    writer::{
+        PostgisOptions, construct_schema_from_crs,
+        csv_writer::{format_value, to_hex},
+        for_each_chunk, get_fields_except_geometry,
+        shp_writer::{shorten_field_names, truncate_bytes},
+        wkb_array,
+    },
+};
+
+// Identifiers longer than this are truncated by PostgreSQL (NAMEDATALEN - 1)
+const MAX_IDENTIFIER_BYTES: usize = 63;
+
+const ID_COLUMN: &str = "fid";
+const GEOMETRY_COLUMN: &str = "geom";
+
+// Flags of EWKB
+const EWKB_Z: u32 = 0x8000_0000;
+const EWKB_M: u32 = 0x4000_0000;
+const EWKB_SRID: u32 = 0x2000_0000;
+
+// Write a SQL file to load into PostGIS with psql. The data is written in COPY
+// format, and the spatial index is created after loading the data.
+pub(crate) fn write_postgis_sql<T: Read + Seek, D: Read + Seek, W: Write>(
+    reader: &mut shapefile::Reader<T, D>,
+    writer: &mut W,
+    dbf_fields: &[dbase::FieldInfo],
+    crs: JapanCrs,
+    translate_options: &TranslateOptions,
+    report: &mut TranslationReport,
+    options: &PostgisOptions,
+) -> Result<(), Ksj2GpError> {
+    let srid: u32 = match crs {
+        JapanCrs::Tokyo => 4301,
+        JapanCrs::JGD2000 => 4612,
+        JapanCrs::JGD2011 => 6668,
+    };
+    let measures = has_measures(reader.header());
+    let geometry_type = geometry_type(reader.header().shape_type, measures);
+
+    let fields_info =
+        construct_schema_from_crs(dbf_fields, &crs, translate_options, report, false)?;
+
+    let column_names = shorten_field_names(
+        &fields_info
+            .non_geo_fields
+            .iter()
+            .map(|f| f.name().as_str())
+            .collect::<Vec<_>>(),
+        MAX_IDENTIFIER_BYTES,
+    );
+    let column_types = fields_info
+        .non_geo_fields
+        .iter()
+        .map(|f| sql_type(f.data_type()))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let stem = std::path::Path::new(&translate_options.target_shp)
+        .file_stem()
+        .and_then(|s| s.to_str())
+        .unwrap_or("output");
+    let table_name = match &options.table_name {
+        Some(name) => truncate_bytes(name, MAX_IDENTIFIER_BYTES).to_string(),
+        None => default_table_name(stem),
+    };
+    let table = match &options.schema {
+        Some(schema) => format!("{}.{}", quote_ident(schema), quote_ident(&table_name)),
+        None => quote_ident(&table_name),
+    };
+
+    let mut ddl = String::new();
+    ddl.push_str(&format!("-- Generated by ksj2gp from {stem}.shp\n"));
+    ddl.push_str("SET client_encoding = 'UTF8';\n");
+    ddl.push_str("SET standard_conforming_strings = on;\n");
+    ddl.push_str("BEGIN;\n");
+    if options.drop_table {
+        ddl.push_str(&format!("DROP TABLE IF EXISTS {table};\n"));
+    }
+    ddl.push_str(&format!("CREATE TABLE {table} (\n"));
+    ddl.push_str(&format!(
+        "    {} integer GENERATED BY DEFAULT AS IDENTITY PRIMARY KEY,\n",
+        quote_ident(ID_COLUMN)
+    ));
+    for (name, sql_type) in column_names.iter().zip(&column_types) {
+        ddl.push_str(&format!("    {} {sql_type},\n", quote_ident(name)));
+    }
+    ddl.push_str(&format!(
+        "    {} geometry({geometry_type}, {srid})\n",
+        quote_ident(GEOMETRY_COLUMN)
+    ));
+    ddl.push_str(");\n");
+
+    // Keep the original column ids of KSJ, since the column names are translated
+    for (name, ksj_column) in column_names
+        .iter()
+        .zip(get_fields_except_geometry(dbf_fields))
+    {
+        ddl.push_str(&format!(
+            "COMMENT ON COLUMN {table}.{} IS {};\n",
+            quote_ident(name),
+            quote_literal(ksj_column)
+        ));
+    }
+
+    ddl.push_str(&format!(
+        "COPY {table} ({}) FROM stdin;\n",
+        column_names
+            .iter()
+            .map(String::as_str)
+            .chain([GEOMETRY_COLUMN])
+            .map(quote_ident)
+            .join(", ")
+    ));
+    writer.write_all(ddl.as_bytes())?;
+
+    let mut line = String::new();
+    for_each_chunk(reader, dbf_fields, &fields_info, report, |chunk| {
+        let num_rows = chunk.shapes.len();
+        let wkb_array = wkb_array(&fields_info, chunk.shapes, measures)?;
+        let wkb = wkb_array.inner();
+
+        for i in 0..num_rows {
+            line.clear();
+            for array in &chunk.arrays {
+                if array.is_null(i) {
+                    line.push_str("\\N");
+                } else {
+                    escape_copy_text(&mut line, &format_value(array.as_ref(), i)?);
+                }
+                line.push('\t');
+            }
+            if wkb.is_null(i) {
+                line.push_str("\\N");
+            } else {
+                line.push_str(&to_hex(&to_ewkb(wkb.value(i), srid)?));
+            }
+            line.push('\n');
+            writer.write_all(line.as_bytes())?;
+        }
+
+        Ok(())
+    })?;
+
+    let index_name = truncate_bytes(
+        &format!("{table_name}_{GEOMETRY_COLUMN}_idx"),
+        MAX_IDENTIFIER_BYTES,
+    )
+    .to_string();
+    let mut footer = String::from("\\.\n");
+    footer.push_str(&format!(
+        "CREATE INDEX {} ON {table} USING GIST ({});\n",
+        quote_ident(&index_name),
+        quote_ident(GEOMETRY_COLUMN)
+    ));
+    footer.push_str("COMMIT;\n");
+    footer.push_str(&format!("ANALYZE {table};\n"));
+    writer.write_all(footer.as_bytes())?;
+    writer.flush()?;
+
+    Ok(())
+}
+
+// Polylines and polygons are always Multi* types, same as the other writers
+// The type modifier of the geometry column, e.g. "MultiLineStringZM"
+fn geometry_type(shape_type: shapefile::ShapeType, measures: bool) -> String {
+    use shapefile::ShapeType;
+
+    let (base, dim) = match shape_type {
+        ShapeType::Point => ("Point", ""),
+        ShapeType::PointM => ("Point", "M"),
+        ShapeType::PointZ => ("Point", "Z"),
+        ShapeType::Multipoint => ("MultiPoint", ""),
+        ShapeType::MultipointM => ("MultiPoint", "M"),
+        ShapeType::MultipointZ => ("MultiPoint", "Z"),
+        ShapeType::Polyline => ("MultiLineString", ""),
+        ShapeType::PolylineM => ("MultiLineString", "M"),
+        ShapeType::PolylineZ => ("MultiLineString", "Z"),
+        ShapeType::Polygon => ("MultiPolygon", ""),
+        ShapeType::PolygonM => ("MultiPolygon", "M"),
+        ShapeType::PolygonZ | ShapeType::Multipatch => ("MultiPolygon", "Z"),
+        ShapeType::NullShape => return "Geometry".to_string(),
+    };
+
+    if dim == "Z" && measures {
+        format!("{base}ZM")
+    } else {
+        format!("{base}{dim}")
+    }
+}
+
+fn sql_type(data_type: &arrow_schema::DataType) -> Result<&'static str, Ksj2GpError> {
+    let sql_type = match data_type {
+        arrow_schema::DataType::Boolean => "boolean",
+        arrow_schema::DataType::Int32 => "integer",
+        arrow_schema::DataType::Float32 => "real",
+        arrow_schema::DataType::Float64 => "double precision",
+        arrow_schema::DataType::Utf8 => "text",
+        arrow_schema::DataType::Date32 => "date",
+        data_type => {
+            return Err(format!("Unsupported column type for PostGIS: {data_type}").into());
+        }
+    };
+    Ok(sql_type)
+}
+
+// Lowercase ASCII name so that the table can be referred to without quotes
+// (e.g. "N03-20240101_13" -> "n03_20240101_13")
+fn default_table_name(stem: &str) -> String {
+    let mut name: String = stem
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() {
+                c.to_ascii_lowercase()
+            } else {
+                '_'
+            }
+        })
+        .collect();
+    if !name.starts_with(|c: char| c.is_ascii_lowercase() || c == '_') {
+        name.insert(0, 't');
+    }
+    name.truncate(MAX_IDENTIFIER_BYTES);
+    name
+}
+
+fn quote_ident(s: &str) -> String {
+    format!("\"{}\"", s.replace('"', "\"\""))
+}
+
+fn quote_literal(s: &str) -> String {
+    format!("'{}'", s.replace('\'', "''"))
+}
+
+fn escape_copy_text(out: &mut String, s: &str) {
+    for c in s.chars() {
+        match c {
+            '\\' => out.push_str("\\\\"),
+            '\t' => out.push_str("\\t"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            c => out.push(c),
+        }
+    }
+}
+
+// Convert ISO WKB to EWKB with the SRID. Only the type of the outermost
+// geometry is rewritten; PostGIS accepts the ISO types of the nested ones.
+fn to_ewkb(wkb: &[u8], srid: u32) -> Result<Vec<u8>, Ksj2GpError> {
+    if wkb.len() < 5 {
+        return Err("Invalid WKB".into());
+    }
+
+    let little_endian = match wkb[0] {
+        0 => false,
+        1 => true,
+        b => return Err(format!("Invalid byte order of WKB: {b}").into()),
+    };
+    let type_bytes: [u8; 4] = wkb[1..5].try_into().unwrap();
+    let wkb_type = if little_endian {
+        u32::from_le_bytes(type_bytes)
+    } else {
+        u32::from_be_bytes(type_bytes)
+    };
+
+    let (base_type, has_z, has_m) = if wkb_type & (EWKB_Z | EWKB_M | EWKB_SRID) != 0 {
+        (
+            wkb_type & 0x0FFF_FFFF,
+            wkb_type & EWKB_Z != 0,
+            wkb_type & EWKB_M != 0,
+        )
+    } else {
+        let dimension = wkb_type / 1000;
+        (
+            wkb_type % 1000,
+            matches!(dimension, 1 | 3),
+            matches!(dimension, 2 | 3),
+        )
+    };
+
+    let mut ewkb_type = base_type | EWKB_SRID;
+    if has_z {
+        ewkb_type |= EWKB_Z;
+    }
+    if has_m {
+        ewkb_type |= EWKB_M;
+    }
+
+    let mut ewkb = Vec::with_capacity(wkb.len() + 4);
+    ewkb.push(wkb[0]);
+    if little_endian {
+        ewkb.extend_from_slice(&ewkb_type.to_le_bytes());
+        ewkb.extend_from_slice(&srid.to_le_bytes());
+    } else {
+        ewkb.extend_from_slice(&ewkb_type.to_be_bytes());
+        ewkb.extend_from_slice(&srid.to_be_bytes());
+    }
+    ewkb.extend_from_slice(&wkb[5..]);
+
+    Ok(ewkb)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_to_ewkb() {
+        // POINT (1 2)
+        let mut wkb = vec![1, 1, 0, 0, 0];
+        wkb.extend_from_slice(&1.0f64.to_le_bytes());
+        wkb.extend_from_slice(&2.0f64.to_le_bytes());
+
+        let ewkb = to_ewkb(&wkb, 6668).unwrap();
+        assert_eq!(
+            to_hex(&ewkb),
+            "01010000200C1A0000000000000000F03F0000000000000040"
+        );
+
+        // POINT Z in ISO WKB
+        let mut wkb = vec![1];
+        wkb.extend_from_slice(&1001u32.to_le_bytes());
+        let ewkb = to_ewkb(&wkb, 4326).unwrap();
+        assert_eq!(&ewkb[1..5], &(1 | EWKB_Z | EWKB_SRID).to_le_bytes());
+    }
+
+    #[test]
+    fn test_geometry_type() {
+        use shapefile::ShapeType;
+
+        assert_eq!(geometry_type(ShapeType::Polyline, false), "MultiLineString");
+        assert_eq!(
+            geometry_type(ShapeType::PolylineM, true),
+            "MultiLineStringM"
+        );
+        assert_eq!(geometry_type(ShapeType::PointZ, false), "PointZ");
+        assert_eq!(geometry_type(ShapeType::PointZ, true), "PointZM");
+        assert_eq!(geometry_type(ShapeType::Multipatch, false), "MultiPolygonZ");
+        assert_eq!(geometry_type(ShapeType::NullShape, false), "Geometry");
+    }
+
+    #[test]
+    fn test_default_table_name() {
+        assert_eq!(default_table_name("N03-20240101_13"), "n03_20240101_13");
+        assert_eq!(default_table_name("2024_data"), "t2024_data");
+    }
+
+    #[test]
+    fn test_escape() {
+        assert_eq!(quote_ident(r#"a"b"#), r#""a""b""#);
+        assert_eq!(quote_literal("it's"), "'it''s'");
+
+        let mut out = String::new();
+        escape_copy_text(&mut out, "a\tb\\c\nd");
+        assert_eq!(out, "a\\tb\\\\c\\nd");
+    }
+}
@@ -0,0 +1,864 @@
+use std::io::{BufWriter, Read, Seek, Write};
+
+use arrow_array::{Array, ArrayRef, cast::AsArray as _};
+use shapefile::record::EsriShape;
+
+use crate::{
+    crs::{ESRI_WKT_WGS84, JapanCrs},
+    error::Ksj2GpError,
+    transform_coord::CoordTransformer,
+    translate::{TranslateOptions, TranslationReport},
+    writer::{
+        ShapefileOptions, construct_schema_from_crs, for_each_chunk, get_fields_except_geometry,
+    },
+};
+
+// Limits of dBASE
+const MAX_FIELD_NAME_BYTES: usize = 10;
+const MAX_CHARACTER_BYTES: usize = 254;
+
+// Same as the default of GDAL for the non-integer floats
+const FLOAT_LEN: u8 = 24;
+const FLOAT_DECIMALS: u8 = 15;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum DbfFieldType {
+    Character(u8),
+    // length, number of decimal places
+    Numeric(u8, u8),
+    Logical,
+    Date,
+}
+
+impl DbfFieldType {
+    // (type, length, number of decimal places) of the field descriptor
+    fn spec(&self) -> (u8, u8, u8) {
+        match *self {
+            DbfFieldType::Character(len) => (b'C', len, 0),
+            DbfFieldType::Numeric(len, decimals) => (b'N', len, decimals),
+            DbfFieldType::Logical => (b'L', 1, 0),
+            DbfFieldType::Date => (b'D', 8, 0),
+        }
+    }
+
+    // The type that can hold the values of both chunks
+    fn merge(self, other: Self) -> Self {
+        match (self, other) {
+            (DbfFieldType::Character(a), DbfFieldType::Character(b)) => {
+                DbfFieldType::Character(a.max(b))
+            }
+            (DbfFieldType::Numeric(a, 0), DbfFieldType::Numeric(b, 0)) => {
+                DbfFieldType::Numeric(a.max(b), 0)
+            }
+            (DbfFieldType::Numeric(..), DbfFieldType::Numeric(..)) => {
+                DbfFieldType::Numeric(FLOAT_LEN, FLOAT_DECIMALS)
+            }
+            // The chunks have the same data type, so the rest are the same
+            (field_type, _) => field_type,
+        }
+    }
+}
+
+struct DbfField {
+    name: String,
+    field_type: DbfFieldType,
+}
+
+// Write a ZIP file that contains a Shapefile (.shp, .shx, .dbf, .prj and .cpg)
+// in UTF-8. Since the field names of dBASE are limited to 10 bytes, the
+// translated column names are shortened, and the mapping to the full names is
+// written to "<name>_columns.csv".
+//
+// The widths of the fields and the bbox are determined by the data, and they
+// are needed in the headers. So, the input is read three times instead of
+// keeping the data in memory: first to collect them, then to write the .shp,
+// and finally to write the .dbf.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn write_shapefile_zip<T: Read + Seek, D: Read + Seek, W: Write + Seek>(
+    reader: &mut shapefile::Reader<T, D>,
+    writer: &mut W,
+    dbf_fields: &[dbase::FieldInfo],
+    crs: JapanCrs,
+    translate_options: &TranslateOptions,
+    report: &mut TranslationReport,
+    options: &ShapefileOptions,
+) -> Result<(), Ksj2GpError> {
+    let prj = if options.reproject {
+        ESRI_WKT_WGS84
+    } else {
+        crs.to_esri_wkt()
+    };
+    let transformer = options
+        .reproject
+        .then(|| CoordTransformer::new(crs.clone()));
+
+    let fields_info =
+        construct_schema_from_crs(dbf_fields, &crs, translate_options, report, false)?;
+    let field_names = get_fields_except_geometry(dbf_fields);
+
+    let shape_type = reader.header().shape_type;
+
+    // 1st pass: the widths of the fields and the bbox
+    let mut field_types: Vec<Option<DbfFieldType>> = vec![None; field_names.len()];
+    let mut shp_index = ShpIndex::new(shape_type);
+    for_each_chunk(reader, dbf_fields, &fields_info, report, |chunk| {
+        for (field_type, array) in field_types.iter_mut().zip(&chunk.arrays) {
+            let t = dbf_field_type(array.as_ref())?;
+            *field_type = Some(match field_type.take() {
+                Some(prev) => prev.merge(t),
+                None => t,
+            });
+        }
+        for shape in chunk.shapes {
+            let shape = match &transformer {
+                Some(transformer) => transformer.transform_shape(shape)?,
+                None => shape,
+            };
+            shp_index.add(&shape)?;
+        }
+        Ok(())
+    })?;
+
+    let full_names: Vec<&str> = fields_info
+        .non_geo_fields
+        .iter()
+        .map(|f| f.name().as_str())
+        .collect();
+    let short_names = shorten_field_names(&full_names, MAX_FIELD_NAME_BYTES);
+
+    let dbf_fields_out: Vec<DbfField> = short_names
+        .iter()
+        .zip(field_types)
+        .map(|(name, field_type)| DbfField {
+            name: name.clone(),
+            // If there's no data, the type doesn't matter
+            field_type: field_type.unwrap_or(DbfFieldType::Character(1)),
+        })
+        .collect();
+
+    let stem = std::path::Path::new(&translate_options.target_shp)
+        .file_stem()
+        .and_then(|s| s.to_str())
+        .unwrap_or("output");
+
+    let mut zip = zip::ZipWriter::new(writer);
+    let zip_options = zip::write::SimpleFileOptions::default();
+
+    for (ext, content) in [("prj", prj.as_bytes()), ("cpg", b"UTF-8".as_slice())] {
+        zip.start_file(format!("{stem}.{ext}"), zip_options)?;
+        zip.write_all(content)?;
+    }
+
+    zip.start_file(format!("{stem}_columns.csv"), zip_options)?;
+    {
+        let mut columns_csv = csv::Writer::from_writer(&mut zip);
+        columns_csv.write_record(["name", "full_name", "ksj_column"])?;
+        for ((short_name, full_name), original_name) in
+            short_names.iter().zip(&full_names).zip(&field_names)
+        {
+            columns_csv.write_record([short_name.as_str(), full_name, original_name])?;
+        }
+        columns_csv.flush()?;
+    }
+
+    zip.start_file(format!("{stem}.shx"), zip_options)?;
+    shp_index.write_shx(&mut zip)?;
+
+    // 2nd pass: the shapes
+    zip.start_file(format!("{stem}.shp"), zip_options)?;
+    {
+        let mut shp = BufWriter::new(&mut zip);
+        shp_index.write_shp_header(&mut shp)?;
+        reader.seek(0)?;
+        for (i, result) in reader.iter_shapes_and_records().enumerate() {
+            let (shape, _) = result?;
+            let shape = match &transformer {
+                Some(transformer) => transformer.transform_shape(shape)?,
+                None => shape,
+            };
+            write_shp_record(&mut shp, i, &shape)?;
+        }
+        shp.flush()?;
+    }
+
+    // 3rd pass: the records. They are already reported in the 1st pass.
+    zip.start_file(format!("{stem}.dbf"), zip_options)?;
+    {
+        let mut dbf_writer = DbfWriter::try_new(
+            BufWriter::new(&mut zip),
+            dbf_fields_out,
+            shp_index.num_records,
+        )?;
+        reader.seek(0)?;
+        for_each_chunk(
+            reader,
+            dbf_fields,
+            &fields_info,
+            &mut TranslationReport::default(),
+            |chunk| {
+                for row in 0..chunk.shapes.len() {
+                    dbf_writer.write_record(&chunk.arrays, row)?;
+                }
+                Ok(())
+            },
+        )?;
+        dbf_writer.finish()?.flush()?;
+    }
+
+    zip.finish()?;
+
+    Ok(())
+}
+
+const SHP_HEADER_SIZE: usize = 100;
+const SHP_RECORD_HEADER_SIZE: usize = 8;
+
+// The bbox and the offsets of the records, which are needed before writing the
+// .shp. The writer of the shapefile crate is not used because it can't write
+// null shapes (the shape type of a record is always the one in the header).
+struct ShpIndex {
+    shape_type: shapefile::ShapeType,
+    // min_x, max_x, min_y, max_y, ...
+    bbox: Option<[[f64; 2]; 4]>,
+    // Content of the .shx without the header
+    shx: Vec<u8>,
+    // Length of the .shp in bytes
+    shp_len: usize,
+    num_records: usize,
+}
+
+impl ShpIndex {
+    fn new(shape_type: shapefile::ShapeType) -> Self {
+        Self {
+            shape_type,
+            bbox: None,
+            shx: Vec::new(),
+            shp_len: SHP_HEADER_SIZE,
+            num_records: 0,
+        }
+    }
+
+    fn add(&mut self, shape: &shapefile::Shape) -> Result<(), Ksj2GpError> {
+        let content_len = match shape_size_and_ranges(shape) {
+            Some((size, ranges)) => {
+                if shape.shapetype() != self.shape_type {
+                    return Err(format!(
+                        "Shape type mismatch: expected {}, but got {}",
+                        self.shape_type,
+                        shape.shapetype()
+                    )
+                    .into());
+                }
+                let bbox = self.bbox.get_or_insert(ranges);
+                for (range, r) in bbox.iter_mut().zip(ranges) {
+                    range[0] = range[0].min(r[0]);
+                    range[1] = range[1].max(r[1]);
+                }
+                4 + size
+            }
+            None => 4,
+        };
+
+        // The lengths and the offsets are in 16-bit words
+        self.shx
+            .extend_from_slice(&((self.shp_len / 2) as i32).to_be_bytes());
+        self.shx
+            .extend_from_slice(&((content_len / 2) as i32).to_be_bytes());
+        self.shp_len += SHP_RECORD_HEADER_SIZE + content_len;
+        self.num_records += 1;
+
+        Ok(())
+    }
+
+    fn write_header<W: Write>(&self, writer: &mut W, len: usize) -> Result<(), Ksj2GpError> {
+        // The unused ranges must be 0
+        let mut bbox = self.bbox.unwrap_or_default();
+        if !self.shape_type.has_z() {
+            bbox[2] = [0.0, 0.0];
+        }
+        if !self.shape_type.has_m() && self.shape_type != shapefile::ShapeType::Multipatch {
+            bbox[3] = [0.0, 0.0];
+        }
+
+        let mut buf = Vec::with_capacity(SHP_HEADER_SIZE);
+        buf.extend_from_slice(&9994i32.to_be_bytes()); // file code
+        buf.extend_from_slice(&[0; 20]); // unused
+        buf.extend_from_slice(&((len / 2) as i32).to_be_bytes());
+        buf.extend_from_slice(&1000i32.to_le_bytes()); // version
+        buf.extend_from_slice(&(self.shape_type as i32).to_le_bytes());
+        for i in [0, 1] {
+            buf.extend_from_slice(&bbox[0][i].to_le_bytes());
+            buf.extend_from_slice(&bbox[1][i].to_le_bytes());
+        }
+        for [min, max] in &bbox[2..] {
+            buf.extend_from_slice(&min.to_le_bytes());
+            buf.extend_from_slice(&max.to_le_bytes());
+        }
+        writer.write_all(&buf)?;
+
+        Ok(())
+    }
+
+    fn write_shp_header<W: Write>(&self, writer: &mut W) -> Result<(), Ksj2GpError> {
+        self.write_header(writer, self.shp_len)
+    }
+
+    fn write_shx<W: Write>(&self, writer: &mut W) -> Result<(), Ksj2GpError> {
+        self.write_header(writer, SHP_HEADER_SIZE + self.shx.len())?;
+        writer.write_all(&self.shx)?;
+        Ok(())
+    }
+}
+
+// Size of the content of the shape (without the shape type), and the ranges of
+// x, y, z and m. None for a null shape.
+fn shape_size_and_ranges(shape: &shapefile::Shape) -> Option<(usize, [[f64; 2]; 4])> {
+    fn f<S: EsriShape>(shape: &S) -> Option<(usize, [[f64; 2]; 4])> {
+        let ranges = [
+            shape.x_range(),
+            shape.y_range(),
+            shape.z_range(),
+            shape.m_range(),
+        ];
+        Some((shape.size_in_bytes(), ranges))
+    }
+
+    match shape {
+        shapefile::Shape::NullShape => None,
+        shapefile::Shape::Point(s) => f(s),
+        shapefile::Shape::PointM(s) => f(s),
+        shapefile::Shape::PointZ(s) => f(s),
+        shapefile::Shape::Polyline(s) => f(s),
+        shapefile::Shape::PolylineM(s) => f(s),
+        shapefile::Shape::PolylineZ(s) => f(s),
+        shapefile::Shape::Polygon(s) => f(s),
+        shapefile::Shape::PolygonM(s) => f(s),
+        shapefile::Shape::PolygonZ(s) => f(s),
+        shapefile::Shape::Multipoint(s) => f(s),
+        shapefile::Shape::MultipointM(s) => f(s),
+        shapefile::Shape::MultipointZ(s) => f(s),
+        shapefile::Shape::Multipatch(s) => f(s),
+    }
+}
+
+// Write the i-th record of the .shp. The shape must have been added to the
+// ShpIndex in the same order.
+fn write_shp_record<W: Write>(
+    writer: &mut W,
+    i: usize,
+    shape: &shapefile::Shape,
+) -> Result<(), Ksj2GpError> {
+    fn f<S: EsriShape, W: Write>(
+        writer: &mut W,
+        i: usize,
+        shape_type: shapefile::ShapeType,
+        shape: &S,
+    ) -> Result<(), Ksj2GpError> {
+        let content_len = ((4 + shape.size_in_bytes()) / 2) as i32;
+        writer.write_all(&(i as i32 + 1).to_be_bytes())?; // record number
+        writer.write_all(&content_len.to_be_bytes())?;
+        writer.write_all(&(shape_type as i32).to_le_bytes())?;
+        shape.write_to(writer)?;
+        Ok(())
+    }
+
+    let shape_type = shape.shapetype();
+    match shape {
+        shapefile::Shape::NullShape => {
+            writer.write_all(&(i as i32 + 1).to_be_bytes())?; // record number
+            writer.write_all(&2i32.to_be_bytes())?;
+            writer.write_all(&(shape_type as i32).to_le_bytes())?;
+            Ok(())
+        }
+        shapefile::Shape::Point(s) => f(writer, i, shape_type, s),
+        shapefile::Shape::PointM(s) => f(writer, i, shape_type, s),
+        shapefile::Shape::PointZ(s) => f(writer, i, shape_type, s),
+        shapefile::Shape::Polyline(s) => f(writer, i, shape_type, s),
+        shapefile::Shape::PolylineM(s) => f(writer, i, shape_type, s),
+        shapefile::Shape::PolylineZ(s) => f(writer, i, shape_type, s),
+        shapefile::Shape::Polygon(s) => f(writer, i, shape_type, s),
+        shapefile::Shape::PolygonM(s) => f(writer, i, shape_type, s),
+        shapefile::Shape::PolygonZ(s) => f(writer, i, shape_type, s),
+        shapefile::Shape::Multipoint(s) => f(writer, i, shape_type, s),
+        shapefile::Shape::MultipointM(s) => f(writer, i, shape_type, s),
+        shapefile::Shape::MultipointZ(s) => f(writer, i, shape_type, s),
+        shapefile::Shape::Multipatch(s) => f(writer, i, shape_type, s),
+    }
+}
+
+// Write the .shp and the .shx of the shapes at once
+#[cfg(test)]
+pub(super) fn write_shp_and_shx(
+    shapes: &[shapefile::Shape],
+    shape_type: shapefile::ShapeType,
+) -> Result<(Vec<u8>, Vec<u8>), Ksj2GpError> {
+    let mut shp_index = ShpIndex::new(shape_type);
+    for shape in shapes {
+        shp_index.add(shape)?;
+    }
+
+    let mut shp = Vec::with_capacity(shp_index.shp_len);
+    shp_index.write_shp_header(&mut shp)?;
+    for (i, shape) in shapes.iter().enumerate() {
+        write_shp_record(&mut shp, i, shape)?;
+    }
+
+    let mut shx = Vec::new();
+    shp_index.write_shx(&mut shx)?;
+
+    Ok((shp, shx))
+}
+
+// Minimal dBASE III writer. The writer of the dbase crate is not used because
+// it updates the number of the records in the header by seeking back at the
+// end, which is not possible on a ZIP entry.
+struct DbfWriter<W: Write> {
+    writer: W,
+    fields: Vec<DbfField>,
+    // Buffer of a record, reused for every record
+    record: Vec<u8>,
+}
+
+impl<W: Write> DbfWriter<W> {
+    fn try_new(
+        mut writer: W,
+        fields: Vec<DbfField>,
+        num_records: usize,
+    ) -> Result<Self, Ksj2GpError> {
+        let header_len = 32 + 32 * fields.len() + 1;
+        // The first byte is the deletion flag
+        let record_len = 1 + fields
+            .iter()
+            .map(|f| f.field_type.spec().1 as usize)
+            .sum::<usize>();
+        if header_len > u16::MAX as usize || record_len > u16::MAX as usize {
+            return Err("Too many fields for dBASE".into());
+        }
+        let num_records = u32::try_from(num_records).map_err(|_| "Too many records for dBASE")?;
+
+        let mut header = Vec::with_capacity(header_len);
+        header.push(0x03); // dBASE III without memo
+        // The date of the last update (YYMMDD). Since the current date is not
+        // available on wasm, this is the same fixed date as shapelib.
+        header.extend_from_slice(&[95, 7, 26]);
+        header.extend_from_slice(&num_records.to_le_bytes());
+        header.extend_from_slice(&(header_len as u16).to_le_bytes());
+        header.extend_from_slice(&(record_len as u16).to_le_bytes());
+        header.extend_from_slice(&[0; 20]);
+
+        for field in &fields {
+            let (field_type, len, decimals) = field.field_type.spec();
+            let mut name = [0u8; 11];
+            name[..field.name.len()].copy_from_slice(field.name.as_bytes());
+            header.extend_from_slice(&name);
+            header.push(field_type);
+            header.extend_from_slice(&[0; 4]);
+            header.push(len);
+            header.push(decimals);
+            header.extend_from_slice(&[0; 14]);
+        }
+        header.push(0x0D); // terminator of the field descriptors
+
+        writer.write_all(&header)?;
+
+        Ok(Self {
+            writer,
+            fields,
+            record: Vec::with_capacity(record_len),
+        })
+    }
+
+    // `arrays` must be in the same order as the fields
+    fn write_record(&mut self, arrays: &[ArrayRef], row: usize) -> Result<(), Ksj2GpError> {
+        self.record.clear();
+        self.record.push(b' '); // not deleted
+        for (field, array) in self.fields.iter().zip(arrays) {
+            push_field_value(&mut self.record, array.as_ref(), row, field.field_type)?;
+        }
+        self.writer.write_all(&self.record)?;
+        Ok(())
+    }
+
+    fn finish(mut self) -> Result<W, Ksj2GpError> {
+        self.writer.write_all(&[0x1A])?; // end of file
+        Ok(self.writer)
+    }
+}
+
+// Shorten the names to fit in `max_bytes` (e.g. 10 bytes of dBASE). If the
+// shortened names conflict, they are suffixed with "_1", "_2", and so on.
+pub(super) fn shorten_field_names(names: &[&str], max_bytes: usize) -> Vec<String> {
+    let mut result: Vec<String> = Vec::with_capacity(names.len());
+    for name in names {
+        let mut short = truncate_bytes(name, max_bytes).to_string();
+        let mut n = 1;
+        while result.contains(&short) {
+            let suffix = format!("_{n}");
+            short = format!("{}{suffix}", truncate_bytes(name, max_bytes - suffix.len()));
+            n += 1;
+        }
+        result.push(short);
+    }
+    result
+}
+
+// Truncate the string at the char boundary so that it fits in `max_bytes`
+pub(super) fn truncate_bytes(s: &str, max_bytes: usize) -> &str {
+    if s.len() <= max_bytes {
+        return s;
+    }
+    let mut end = max_bytes;
+    while !s.is_char_boundary(end) {
+        end -= 1;
+    }
+    &s[..end]
+}
+
+fn dbf_field_type(array: &dyn Array) -> Result<DbfFieldType, Ksj2GpError> {
+    let field_type = match array.data_type() {
+        arrow_schema::DataType::Utf8 => {
+            let max_len = array
+                .as_string::<i32>()
+                .iter()
+                .flatten()
+                .map(|s| s.len())
+                .max()
+                .unwrap_or(0);
+            DbfFieldType::Character(max_len.clamp(1, MAX_CHARACTER_BYTES) as u8)
+        }
+        arrow_schema::DataType::Boolean => DbfFieldType::Logical,
+        arrow_schema::DataType::Date32 => DbfFieldType::Date,
+        arrow_schema::DataType::Int32 => {
+            let max_len = array
+                .as_primitive::<arrow_array::types::Int32Type>()
+                .iter()
+                .flatten()
+                .map(|v| v.to_string().len())
+                .max()
+                .unwrap_or(1);
+            DbfFieldType::Numeric(max_len as u8, 0)
+        }
+        arrow_schema::DataType::Float32 | arrow_schema::DataType::Float64 => {
+            let values: Vec<f64> = float_values(array)?.into_iter().flatten().collect();
+
+            if values.iter().all(|v| v.fract() == 0.0 && v.abs() < 1e15) {
+                let max_len = values
+                    .iter()
+                    .map(|v| format!("{v:.0}").len())
+                    .max()
+                    .unwrap_or(1);
+                DbfFieldType::Numeric(max_len as u8, 0)
+            } else {
+                DbfFieldType::Numeric(FLOAT_LEN, FLOAT_DECIMALS)
+            }
+        }
+        data_type => {
+            return Err(format!("Unsupported column type for Shapefile: {data_type}").into());
+        }
+    };
+
+    Ok(field_type)
+}
+
+fn float_values(array: &dyn Array) -> Result<Vec<Option<f64>>, Ksj2GpError> {
+    match array.data_type() {
+        arrow_schema::DataType::Float32 => Ok(array
+            .as_primitive::<arrow_array::types::Float32Type>()
+            .iter()
+            .map(|v| v.map(f64::from))
+            .collect()),
+        arrow_schema::DataType::Float64 => Ok(array
+            .as_primitive::<arrow_array::types::Float64Type>()
+            .iter()
+            .collect()),
+        data_type => Err(format!("Not a float column: {data_type}").into()),
+    }
+}
+
+// Append the value to the record, padded to the length of the field. Null is
+// written as spaces ("?" for logical).
+fn push_field_value(
+    record: &mut Vec<u8>,
+    array: &dyn Array,
+    i: usize,
+    field_type: DbfFieldType,
+) -> Result<(), Ksj2GpError> {
+    let (_, len, decimals) = field_type.spec();
+    let (len, decimals) = (len as usize, decimals as usize);
+
+    if array.is_null(i) {
+        match field_type {
+            DbfFieldType::Logical => record.push(b'?'),
+            _ => record.resize(record.len() + len, b' '),
+        }
+        return Ok(());
+    }
+
+    // Left-aligned. Note that the width of format!() is not in bytes but in
+    // chars, so the padding is added here.
+    if let arrow_schema::DataType::Utf8 = array.data_type() {
+        let value = truncate_bytes(array.as_string::<i32>().value(i), len);
+        record.extend_from_slice(value.as_bytes());
+        record.resize(record.len() + len - value.len(), b' ');
+        return Ok(());
+    }
+
+    // The others are right-aligned
+    let value = match array.data_type() {
+        arrow_schema::DataType::Boolean => String::from(if array.as_boolean().value(i) {
+            "T"
+        } else {
+            "F"
+        }),
+        arrow_schema::DataType::Int32 => {
+            let v = array
+                .as_primitive::<arrow_array::types::Int32Type>()
+                .value(i);
+            format!("{v:>len$}")
+        }
+        arrow_schema::DataType::Float32 => {
+            let v = array
+                .as_primitive::<arrow_array::types::Float32Type>()
+                .value(i);
+            format_numeric(v as f64, len, decimals)?
+        }
+        arrow_schema::DataType::Float64 => {
+            let v = array
+                .as_primitive::<arrow_array::types::Float64Type>()
+                .value(i);
+            format_numeric(v, len, decimals)?
+        }
+        arrow_schema::DataType::Date32 => {
+            let days = array
+                .as_primitive::<arrow_array::types::Date32Type>()
+                .value(i);
+            let (year, month, day) = civil_from_days(days);
+            format!("{year:04}{month:02}{day:02}")
+        }
+        data_type => {
+            return Err(format!("Unsupported column type for Shapefile: {data_type}").into());
+        }
+    };
+
+    if value.len() != len {
+        return Err(format!("The value {value} doesn't fit in the field of {len} bytes").into());
+    }
+    record.extend_from_slice(value.as_bytes());
+
+    Ok(())
+}
+
+// Format the number right-aligned in `len` bytes. If it doesn't fit, the
+// decimal places are reduced, in the same way as GDAL.
+fn format_numeric(v: f64, len: usize, decimals: usize) -> Result<String, Ksj2GpError> {
+    for decimals in (0..=decimals).rev() {
+        let formatted = format!("{v:>len$.decimals$}");
+        if formatted.len() <= len {
+            return Ok(formatted);
+        }
+    }
+    Err(format!("The value {v} doesn't fit in the field of {len} bytes").into())
+}
+
+// (year, month, day) of the days since 1970-01-01. This is the algorithm of
+// http://howardhinnant.github.io/date_algorithms.html#civil_from_days
+fn civil_from_days(days: i32) -> (u32, u32, u32) {
+    let z = days as i64 + 719468;
+    let era = z.div_euclid(146097);
+    let doe = z.rem_euclid(146097);
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + if month <= 2 { 1 } else { 0 };
+    (year as u32, month as u32, day as u32)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{io::Cursor, sync::Arc};
+
+    use dbase::{FieldValue, encoding::EncodingRs};
+
+    use super::*;
+
+    #[test]
+    fn test_shorten_field_names() {
+        assert_eq!(
+            shorten_field_names(
+                &["行政区域コード", "行政区域名", "ID", "都道府県名"],
+                MAX_FIELD_NAME_BYTES
+            ),
+            vec!["行政区", "行政_1", "ID", "都道府"]
+        );
+    }
+
+    #[test]
+    fn test_truncate_bytes() {
+        assert_eq!(truncate_bytes("abc", 10), "abc");
+        assert_eq!(truncate_bytes("行政区域", 10), "行政区");
+        assert_eq!(truncate_bytes("a行政区域", 10), "a行政区");
+    }
+
+    #[test]
+    fn test_write_shp_and_shx() {
+        let shapes = vec![
+            shapefile::Shape::Point(shapefile::Point::new(139.0, 35.0)),
+            shapefile::Shape::NullShape,
+            shapefile::Shape::Point(shapefile::Point::new(140.0, 36.0)),
+        ];
+        let (shp, shx) = write_shp_and_shx(&shapes, shapefile::ShapeType::Point).unwrap();
+
+        let reader = shapefile::ShapeReader::with_shx(Cursor::new(shp), Cursor::new(shx)).unwrap();
+        let header = reader.header();
+        assert_eq!(header.shape_type, shapefile::ShapeType::Point);
+        assert_eq!((header.bbox.min.x, header.bbox.min.y), (139.0, 35.0));
+        assert_eq!((header.bbox.max.x, header.bbox.max.y), (140.0, 36.0));
+
+        let read = reader.read().unwrap();
+        assert_eq!(read.len(), 3);
+        assert!(matches!(&read[0], shapefile::Shape::Point(p) if (p.x, p.y) == (139.0, 35.0)));
+        assert!(matches!(read[1], shapefile::Shape::NullShape));
+        assert!(matches!(&read[2], shapefile::Shape::Point(p) if (p.x, p.y) == (140.0, 36.0)));
+    }
+
+    #[test]
+    fn test_civil_from_days() {
+        assert_eq!(civil_from_days(0), (1970, 1, 1));
+        assert_eq!(civil_from_days(19000), (2022, 1, 8));
+        assert_eq!(civil_from_days(-1), (1969, 12, 31));
+        assert_eq!(civil_from_days(11016), (2000, 2, 29));
+    }
+
+    #[test]
+    fn test_dbf_field_type() {
+        let strings = arrow_array::StringArray::from(vec![Some("東京都"), None, Some("a")]);
+        assert_eq!(
+            dbf_field_type(&strings).unwrap(),
+            DbfFieldType::Character(9)
+        );
+
+        let ints = arrow_array::Float64Array::from(vec![Some(13101.0), None]);
+        assert_eq!(dbf_field_type(&ints).unwrap(), DbfFieldType::Numeric(5, 0));
+
+        let floats = arrow_array::Float64Array::from(vec![1.5, 2.0]);
+        assert_eq!(
+            dbf_field_type(&floats).unwrap(),
+            DbfFieldType::Numeric(24, 15)
+        );
+    }
+
+    #[test]
+    fn test_merge_dbf_field_type() {
+        assert_eq!(
+            DbfFieldType::Character(3).merge(DbfFieldType::Character(9)),
+            DbfFieldType::Character(9)
+        );
+        assert_eq!(
+            DbfFieldType::Numeric(5, 0).merge(DbfFieldType::Numeric(2, 0)),
+            DbfFieldType::Numeric(5, 0)
+        );
+        assert_eq!(
+            DbfFieldType::Numeric(5, 0).merge(DbfFieldType::Numeric(24, 15)),
+            DbfFieldType::Numeric(24, 15)
+        );
+    }
+
+    #[test]
+    fn test_dbf_writer() {
+        let fields = vec![
+            DbfField {
+                name: "名前".to_string(),
+                field_type: DbfFieldType::Character(9),
+            },
+            DbfField {
+                name: "code".to_string(),
+                field_type: DbfFieldType::Numeric(5, 0),
+            },
+            DbfField {
+                name: "ratio".to_string(),
+                field_type: DbfFieldType::Numeric(24, 15),
+            },
+            DbfField {
+                name: "flag".to_string(),
+                field_type: DbfFieldType::Logical,
+            },
+            DbfField {
+                name: "date".to_string(),
+                field_type: DbfFieldType::Date,
+            },
+        ];
+        let arrays: Vec<ArrayRef> = vec![
+            Arc::new(arrow_array::StringArray::from(vec![
+                Some("東京都"),
+                Some("a"),
+                None,
+            ])),
+            Arc::new(arrow_array::Int32Array::from(vec![
+                Some(13101),
+                Some(1),
+                None,
+            ])),
+            Arc::new(arrow_array::Float64Array::from(vec![
+                Some(1.5),
+                Some(123456789.5),
+                None,
+            ])),
+            Arc::new(arrow_array::BooleanArray::from(vec![
+                Some(true),
+                Some(false),
+                None,
+            ])),
+            Arc::new(arrow_array::Date32Array::from(vec![
+                Some(19000),
+                Some(0),
+                None,
+            ])),
+        ];
+
+        let mut dbf_writer = DbfWriter::try_new(Vec::new(), fields, 3).unwrap();
+        for row in 0..3 {
+            dbf_writer.write_record(&arrays, row).unwrap();
+        }
+        let dbf = dbf_writer.finish().unwrap();
+
+        let mut reader = dbase::Reader::new_with_encoding(
+            Cursor::new(dbf),
+            EncodingRs::from(dbase::encoding_rs::UTF_8),
+        )
+        .unwrap();
+        let records = reader.read().unwrap();
+        assert_eq!(records.len(), 3);
+
+        let get = |row: usize, name: &str| records[row].get(name).unwrap().clone();
+        assert_eq!(
+            get(0, "名前"),
+            FieldValue::Character(Some("東京都".to_string()))
+        );
+        assert_eq!(get(0, "code"), FieldValue::Numeric(Some(13101.0)));
+        assert_eq!(get(0, "ratio"), FieldValue::Numeric(Some(1.5)));
+        assert_eq!(get(0, "flag"), FieldValue::Logical(Some(true)));
+        assert_eq!(
+            get(0, "date"),
+            FieldValue::Date(Some(dbase::Date::new(8, 1, 2022)))
+        );
+
+        assert_eq!(get(1, "名前"), FieldValue::Character(Some("a".to_string())));
+        // The decimal places are reduced to fit in the field
+        assert_eq!(get(1, "ratio"), FieldValue::Numeric(Some(123456789.5)));
+        assert_eq!(get(1, "flag"), FieldValue::Logical(Some(false)));
+
+        assert_eq!(get(2, "名前"), FieldValue::Character(None));
+        assert_eq!(get(2, "code"), FieldValue::Numeric(None));
+        assert_eq!(get(2, "flag"), FieldValue::Logical(None));
+    }
+
+    #[test]
+    fn test_format_numeric() {
+        assert_eq!(format_numeric(1.5, 6, 2).unwrap(), "  1.50");
+        assert_eq!(format_numeric(123.125, 5, 2).unwrap(), "123.1");
+        assert_eq!(format_numeric(12345.0, 5, 2).unwrap(), "12345");
+        assert!(format_numeric(123456.0, 5, 2).is_err());
+    }
+}
@@ -0,0 +1,624 @@
+use std::{
+    cmp::Reverse,
+    collections::BinaryHeap,
+    fs::File,
+    io::{BufReader, BufWriter},
+    path::{Path, PathBuf},
+    sync::{
+        Arc,
+        atomic::{AtomicUsize, Ordering},
+    },
+};
+
+use arrow_array::{RecordBatch, UInt32Array, cast::AsArray as _};
+use arrow_ipc::{reader::StreamReader, writer::StreamWriter};
+use arrow_schema::{DataType, Field, Schema, SchemaRef};
+
+use crate::{
+    error::Ksj2GpError,
+    writer::{CHUNK_SIZE, SpatialSortKey, SpatialSortOptions},
+};
+
+// Name of the column to store the sort keys in the spill files
+const SORT_KEY_COLUMN: &str = "__ksj2gp_sort_key";
+
+// Used for the unique names of the spill files
+static SPILL_FILE_COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+// Sort the RecordBatches by the Hilbert or Z-order value of the center of the
+// bounding box of each feature. This is an external merge sort; when the size
+// of the buffered batches exceeds the memory budget, they are sorted and
+// written to a spill file as an Arrow IPC stream. Finally, the sorted runs are
+// merged.
+//
+// The memory usage is roughly the memory budget plus one chunk per run; the
+// buffered batches are not concatenated, but the sorted chunks are taken from
+// them one by one.
+pub(crate) struct SpatialSorter {
+    schema: SchemaRef,
+    // Schema of the spill files, i.e. `schema` + the sort key column
+    spill_schema: SchemaRef,
+    key: SpatialSortKey,
+    memory_budget: usize,
+    spill_dir: PathBuf,
+    // min_x, min_y, max_x, max_y
+    extent: [f64; 4],
+
+    buffered: Vec<(RecordBatch, Vec<u32>)>,
+    buffered_size: usize,
+    spill_files: Vec<SpillFile>,
+}
+
+impl SpatialSorter {
+    pub(crate) fn new(
+        schema: SchemaRef,
+        options: &SpatialSortOptions,
+        header: &shapefile::header::Header,
+    ) -> Self {
+        let mut fields = schema.fields().to_vec();
+        fields.push(Arc::new(Field::new(
+            SORT_KEY_COLUMN,
+            DataType::UInt32,
+            false,
+        )));
+        let spill_schema = Arc::new(Schema::new_with_metadata(fields, schema.metadata().clone()));
+
+        Self {
+            schema,
+            spill_schema,
+            key: options.key,
+            memory_budget: options.memory_budget,
+            spill_dir: options.spill_dir.clone().unwrap_or_else(std::env::temp_dir),
+            extent: [
+                header.bbox.min.x,
+                header.bbox.min.y,
+                header.bbox.max.x,
+                header.bbox.max.y,
+            ],
+            buffered: Vec::new(),
+            buffered_size: 0,
+            spill_files: Vec::new(),
+        }
+    }
+
+    // The sort key of the shape. This must be called before the shape is
+    // consumed by push_shape().
+    pub(crate) fn sort_key(&self, shape: &shapefile::Shape) -> u32 {
+        let Some((x, y)) = bbox_center(shape) else {
+            return 0;
+        };
+
+        const MAX: f64 = ((1 << 16) - 1) as f64;
+        let [min_x, min_y, max_x, max_y] = self.extent;
+        let scale = |v: f64, min: f64, max: f64| {
+            if max > min {
+                (MAX * (v - min) / (max - min)).clamp(0.0, MAX).floor() as u32
+            } else {
+                0
+            }
+        };
+        let x = scale(x, min_x, max_x);
+        let y = scale(y, min_y, max_y);
+
+        match self.key {
+            SpatialSortKey::Hilbert => hilbert(x, y),
+            SpatialSortKey::ZOrder => zorder(x, y),
+        }
+    }
+
+    // `keys` must have the same length as the batch
+    pub(crate) fn push(&mut self, batch: RecordBatch, keys: Vec<u32>) -> Result<(), Ksj2GpError> {
+        // The index to sort the rows is also counted (see sorted_rows())
+        self.buffered_size +=
+            batch.get_array_memory_size() + keys.len() * size_of::<(u32, usize, usize)>();
+        self.buffered.push((batch, keys));
+
+        if self.buffered_size >= self.memory_budget {
+            self.spill()?;
+        }
+
+        Ok(())
+    }
+
+    // Call `f` with the sorted RecordBatches
+    pub(crate) fn finish(
+        mut self,
+        mut f: impl FnMut(RecordBatch) -> Result<(), Ksj2GpError>,
+    ) -> Result<(), Ksj2GpError> {
+        // If nothing is spilled, no need to merge
+        if self.spill_files.is_empty() {
+            let mut run = self.sort_buffered();
+            while let Some(batch) = run.next_chunk()? {
+                f(self.drop_sort_key(&batch)?)?;
+            }
+            return Ok(());
+        }
+
+        let mut cursors = Vec::new();
+        for spill_file in &self.spill_files {
+            let reader =
+                StreamReader::try_new(BufReader::new(File::open(&spill_file.path)?), None)?;
+            cursors.push(RunCursor::new(Run::Spill(reader))?);
+        }
+        // The last run is kept in memory
+        let last_run = self.sort_buffered();
+        if !last_run.is_empty() {
+            cursors.push(RunCursor::new(Run::Memory(last_run))?);
+        }
+
+        let mut heap = BinaryHeap::new();
+        for (i, cursor) in cursors.iter().enumerate() {
+            if let Some(key) = cursor.current_key() {
+                heap.push(Reverse((key, i)));
+            }
+        }
+
+        // (index of the run, row of the current batch of the run)
+        let mut indices: Vec<(usize, usize)> = Vec::with_capacity(CHUNK_SIZE);
+
+        while let Some(Reverse((_, i))) = heap.pop() {
+            indices.push((i, cursors[i].row));
+            cursors[i].row += 1;
+
+            if cursors[i].row >= cursors[i].batch.num_rows() {
+                // The current batch is about to be replaced, so the rows
+                // referring to it must be emitted first.
+                self.emit(&cursors, &mut indices, &mut f)?;
+                cursors[i].advance()?;
+            } else if indices.len() >= CHUNK_SIZE {
+                self.emit(&cursors, &mut indices, &mut f)?;
+            }
+
+            if let Some(key) = cursors[i].current_key() {
+                heap.push(Reverse((key, i)));
+            }
+        }
+
+        self.emit(&cursors, &mut indices, &mut f)?;
+
+        Ok(())
+    }
+
+    fn emit(
+        &self,
+        cursors: &[RunCursor],
+        indices: &mut Vec<(usize, usize)>,
+        f: &mut impl FnMut(RecordBatch) -> Result<(), Ksj2GpError>,
+    ) -> Result<(), Ksj2GpError> {
+        if indices.is_empty() {
+            return Ok(());
+        }
+
+        let batches: Vec<&RecordBatch> = cursors.iter().map(|c| &c.batch).collect();
+        let batch = arrow_select::interleave::interleave_record_batch(&batches, indices)?;
+        indices.clear();
+
+        f(self.drop_sort_key(&batch)?)
+    }
+
+    fn drop_sort_key(&self, batch: &RecordBatch) -> Result<RecordBatch, Ksj2GpError> {
+        let columns = batch.columns()[..self.schema.fields().len()].to_vec();
+        Ok(RecordBatch::try_new(self.schema.clone(), columns)?)
+    }
+
+    // Sort the buffered batches. The rows are not copied here; the sorted
+    // chunks are materialized one by one by SortedRun::next_chunk().
+    fn sort_buffered(&mut self) -> SortedRun {
+        let buffered = std::mem::take(&mut self.buffered);
+        self.buffered_size = 0;
+
+        let keys: Vec<&[u32]> = buffered.iter().map(|(_, keys)| keys.as_slice()).collect();
+        let rows = sorted_rows(&keys);
+        let batches = buffered.into_iter().map(|(batch, _)| batch).collect();
+
+        SortedRun {
+            spill_schema: self.spill_schema.clone(),
+            batches,
+            rows,
+            offset: 0,
+        }
+    }
+
+    fn spill(&mut self) -> Result<(), Ksj2GpError> {
+        let mut run = self.sort_buffered();
+        if run.is_empty() {
+            return Ok(());
+        }
+
+        let spill_file = SpillFile::new(&self.spill_dir);
+        let file = File::create(&spill_file.path).map_err(|e| {
+            format!(
+                "Failed to create a spill file {}: {e}",
+                spill_file.path.display()
+            )
+        })?;
+        // Register the file first so that it's removed even on error
+        self.spill_files.push(spill_file);
+
+        let mut writer = StreamWriter::try_new(BufWriter::new(file), &self.spill_schema)?;
+        while let Some(batch) = run.next_chunk()? {
+            writer.write(&batch)?;
+        }
+        writer.finish()?;
+
+        Ok(())
+    }
+}
+
+// (sort key, index of the batch, row of the batch) of all the rows, sorted by
+// the key. The sort is stable, so the features with the same key keep the
+// original order.
+fn sorted_rows(keys: &[&[u32]]) -> Vec<(u32, usize, usize)> {
+    let mut rows: Vec<(u32, usize, usize)> = keys
+        .iter()
+        .enumerate()
+        .flat_map(|(i, keys)| {
+            keys.iter()
+                .enumerate()
+                .map(move |(row, &key)| (key, i, row))
+        })
+        .collect();
+    rows.sort_by_key(|&(key, _, _)| key);
+    rows
+}
+
+// The buffered batches in the sorted order
+struct SortedRun {
+    spill_schema: SchemaRef,
+    batches: Vec<RecordBatch>,
+    rows: Vec<(u32, usize, usize)>,
+    // Number of the rows already returned
+    offset: usize,
+}
+
+impl SortedRun {
+    fn is_empty(&self) -> bool {
+        self.rows.is_empty()
+    }
+
+    // The next sorted chunk with the sort key column
+    fn next_chunk(&mut self) -> Result<Option<RecordBatch>, Ksj2GpError> {
+        if self.offset >= self.rows.len() {
+            return Ok(None);
+        }
+
+        let end = (self.offset + CHUNK_SIZE).min(self.rows.len());
+        let rows = &self.rows[self.offset..end];
+        self.offset = end;
+
+        let batches: Vec<&RecordBatch> = self.batches.iter().collect();
+        let indices: Vec<(usize, usize)> = rows.iter().map(|&(_, i, row)| (i, row)).collect();
+        let chunk = arrow_select::interleave::interleave_record_batch(&batches, &indices)?;
+
+        let mut columns = chunk.columns().to_vec();
+        columns.push(Arc::new(UInt32Array::from_iter_values(
+            rows.iter().map(|&(key, _, _)| key),
+        )));
+        Ok(Some(RecordBatch::try_new(
+            self.spill_schema.clone(),
+            columns,
+        )?))
+    }
+}
+
+enum Run {
+    Memory(SortedRun),
+    Spill(StreamReader<BufReader<File>>),
+}
+
+impl Run {
+    // The next batch with the sort key column
+    fn next_batch(&mut self) -> Result<Option<RecordBatch>, Ksj2GpError> {
+        match self {
+            Run::Memory(run) => run.next_chunk(),
+            Run::Spill(reader) => Ok(reader.next().transpose()?),
+        }
+    }
+}
+
+struct RunCursor {
+    run: Run,
+    // The last batch is kept after the run is exhausted, since emit() might
+    // still refer to it.
+    batch: RecordBatch,
+    keys: UInt32Array,
+    row: usize,
+}
+
+impl RunCursor {
+    // The run must not be empty
+    fn new(mut run: Run) -> Result<Self, Ksj2GpError> {
+        let batch = run
+            .next_batch()?
+            .ok_or("A sorted run should have at least one batch")?;
+        let keys = sort_keys_of(&batch);
+        Ok(Self {
+            run,
+            batch,
+            keys,
+            row: 0,
+        })
+    }
+
+    fn current_key(&self) -> Option<u32> {
+        (self.row < self.keys.len()).then(|| self.keys.value(self.row))
+    }
+
+    fn advance(&mut self) -> Result<(), Ksj2GpError> {
+        if let Some(batch) = self.run.next_batch()? {
+            self.keys = sort_keys_of(&batch);
+            self.batch = batch;
+            self.row = 0;
+        }
+        Ok(())
+    }
+}
+
+// The sort key column is always the last one
+fn sort_keys_of(batch: &RecordBatch) -> UInt32Array {
+    batch
+        .columns()
+        .last()
+        .expect("the sort key column should exist")
+        .as_primitive::<arrow_array::types::UInt32Type>()
+        .clone()
+}
+
+// A temporary file that is removed on drop
+struct SpillFile {
+    path: PathBuf,
+}
+
+impl SpillFile {
+    fn new(dir: &Path) -> Self {
+        let n = SPILL_FILE_COUNTER.fetch_add(1, Ordering::Relaxed);
+        let path = dir.join(format!("ksj2gp-sort-{}-{n}.arrows", std::process::id()));
+        Self { path }
+    }
+}
+
+impl Drop for SpillFile {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_file(&self.path);
+    }
+}
+
+// Center of the bounding box of the shape
+fn bbox_center(shape: &shapefile::Shape) -> Option<(f64, f64)> {
+    let (min_x, min_y, max_x, max_y) = match shape {
+        shapefile::Shape::Point(p) => (p.x, p.y, p.x, p.y),
+        shapefile::Shape::PointM(p) => (p.x, p.y, p.x, p.y),
+        shapefile::Shape::PointZ(p) => (p.x, p.y, p.x, p.y),
+        shapefile::Shape::Polyline(s) => (
+            s.bbox().min.x,
+            s.bbox().min.y,
+            s.bbox().max.x,
+            s.bbox().max.y,
+        ),
+        shapefile::Shape::PolylineM(s) => (
+            s.bbox().min.x,
+            s.bbox().min.y,
+            s.bbox().max.x,
+            s.bbox().max.y,
+        ),
+        shapefile::Shape::PolylineZ(s) => (
+            s.bbox().min.x,
+            s.bbox().min.y,
+            s.bbox().max.x,
+            s.bbox().max.y,
+        ),
+        shapefile::Shape::Polygon(s) => (
+            s.bbox().min.x,
+            s.bbox().min.y,
+            s.bbox().max.x,
+            s.bbox().max.y,
+        ),
+        shapefile::Shape::PolygonM(s) => (
+            s.bbox().min.x,
+            s.bbox().min.y,
+            s.bbox().max.x,
+            s.bbox().max.y,
+        ),
+        shapefile::Shape::PolygonZ(s) => (
+            s.bbox().min.x,
+            s.bbox().min.y,
+            s.bbox().max.x,
+            s.bbox().max.y,
+        ),
+        shapefile::Shape::Multipoint(s) => (
+            s.bbox().min.x,
+            s.bbox().min.y,
+            s.bbox().max.x,
+            s.bbox().max.y,
+        ),
+        shapefile::Shape::MultipointM(s) => (
+            s.bbox().min.x,
+            s.bbox().min.y,
+            s.bbox().max.x,
+            s.bbox().max.y,
+        ),
+        shapefile::Shape::MultipointZ(s) => (
+            s.bbox().min.x,
+            s.bbox().min.y,
+            s.bbox().max.x,
+            s.bbox().max.y,
+        ),
+        shapefile::Shape::Multipatch(s) => (
+            s.bbox().min.x,
+            s.bbox().min.y,
+            s.bbox().max.x,
+            s.bbox().max.y,
+        ),
+        shapefile::Shape::NullShape => return None,
+    };
+
+    Some(((min_x + max_x) / 2.0, (min_y + max_y) / 2.0))
+}
+
+// Hilbert curve index of (x, y) on the 2^16 x 2^16 grid.
+//
+// This is a port of the implementation used in flatbush and FlatGeobuf:
+// https://github.com/mourner/flatbush/blob/main/index.js
+pub(super) fn hilbert(x: u32, y: u32) -> u32 {
+    let mut a = x ^ y;
+    let mut b = 0xFFFF ^ a;
+    let mut c = 0xFFFF ^ (x | y);
+    let mut d = x & (y ^ 0xFFFF);
+
+    let mut aa = a | (b >> 1);
+    let mut bb = (a >> 1) ^ a;
+    let mut cc = ((c >> 1) ^ (b & (d >> 1))) ^ c;
+    let mut dd = ((a & (c >> 1)) ^ (d >> 1)) ^ d;
+
+    a = aa;
+    b = bb;
+    c = cc;
+    d = dd;
+    aa = (a & (a >> 2)) ^ (b & (b >> 2));
+    bb = (a & (b >> 2)) ^ (b & ((a ^ b) >> 2));
+    cc ^= (a & (c >> 2)) ^ (b & (d >> 2));
+    dd ^= (b & (c >> 2)) ^ ((a ^ b) & (d >> 2));
+
+    a = aa;
+    b = bb;
+    c = cc;
+    d = dd;
+    aa = (a & (a >> 4)) ^ (b & (b >> 4));
+    bb = (a & (b >> 4)) ^ (b & ((a ^ b) >> 4));
+    cc ^= (a & (c >> 4)) ^ (b & (d >> 4));
+    dd ^= (b & (c >> 4)) ^ ((a ^ b) & (d >> 4));
+
+    a = aa;
+    b = bb;
+    c = cc;
+    d = dd;
+    cc ^= (a & (c >> 8)) ^ (b & (d >> 8));
+    dd ^= (b & (c >> 8)) ^ ((a ^ b) & (d >> 8));
+
+    a = cc ^ (cc >> 1);
+    b = dd ^ (dd >> 1);
+
+    let mut i0 = x ^ y;
+    let mut i1 = b | (0xFFFF ^ (i0 | a));
+
+    i0 = interleave_zeros(i0);
+    i1 = interleave_zeros(i1);
+
+    (i1 << 1) | i0
+}
+
+// Z-order (Morton code) of (x, y) on the 2^16 x 2^16 grid
+fn zorder(x: u32, y: u32) -> u32 {
+    (interleave_zeros(y) << 1) | interleave_zeros(x)
+}
+
+// Spread the lower 16 bits to the even bits
+fn interleave_zeros(mut v: u32) -> u32 {
+    v = (v | (v << 8)) & 0x00FF00FF;
+    v = (v | (v << 4)) & 0x0F0F0F0F;
+    v = (v | (v << 2)) & 0x33333333;
+    v = (v | (v << 1)) & 0x55555555;
+    v
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hilbert() {
+        // corners
+        assert_eq!(hilbert(0, 0), 0);
+        assert_eq!(hilbert(0, 0xFFFF), 0x55555555);
+        assert_eq!(hilbert(0xFFFF, 0xFFFF), 0xAAAAAAAA);
+        assert_eq!(hilbert(0xFFFF, 0), 0xFFFFFFFF);
+
+        // the first cells
+        assert_eq!(hilbert(1, 0), 1);
+        assert_eq!(hilbert(1, 1), 2);
+        assert_eq!(hilbert(0, 1), 3);
+    }
+
+    #[test]
+    fn test_zorder() {
+        assert_eq!(zorder(0, 0), 0);
+        assert_eq!(zorder(1, 0), 1);
+        assert_eq!(zorder(0, 1), 2);
+        assert_eq!(zorder(1, 1), 3);
+        assert_eq!(zorder(2, 0), 4);
+        assert_eq!(zorder(0xFFFF, 0xFFFF), 0xFFFFFFFF);
+    }
+
+    #[test]
+    fn test_sorted_rows() {
+        assert_eq!(
+            sorted_rows(&[&[3, 1], &[2, 1]]),
+            vec![(1, 0, 1), (1, 1, 1), (2, 1, 0), (3, 0, 0)]
+        );
+    }
+
+    #[test]
+    fn test_external_merge() {
+        let spill_dir =
+            std::env::temp_dir().join(format!("ksj2gp-sort-test-{}", std::process::id()));
+        std::fs::create_dir_all(&spill_dir).unwrap();
+
+        let schema = Arc::new(Schema::new(vec![Field::new("id", DataType::UInt32, false)]));
+        let options = SpatialSortOptions {
+            key: SpatialSortKey::Hilbert,
+            // Every push spills
+            memory_budget: 1,
+            spill_dir: Some(spill_dir.clone()),
+        };
+        let mut sorter = SpatialSorter::new(
+            schema.clone(),
+            &options,
+            &shapefile::header::Header::default(),
+        );
+
+        // Each run has more rows than CHUNK_SIZE, so the merge needs to read
+        // several batches from every spill file.
+        let n_runs = 5;
+        let n_rows = 5000;
+        let key_of = |id: u32| id.wrapping_mul(2654435761) % 100_000;
+        for run in 0..n_runs {
+            let ids: Vec<u32> = (run * n_rows..(run + 1) * n_rows).collect();
+            let keys = ids.iter().map(|&id| key_of(id)).collect();
+            let batch =
+                RecordBatch::try_new(schema.clone(), vec![Arc::new(UInt32Array::from(ids))])
+                    .unwrap();
+            sorter.push(batch, keys).unwrap();
+        }
+        assert_eq!(
+            std::fs::read_dir(&spill_dir).unwrap().count(),
+            n_runs as usize
+        );
+
+        let mut ids: Vec<u32> = Vec::new();
+        sorter
+            .finish(|batch| {
+                assert_eq!(batch.schema(), schema);
+                assert!(batch.num_rows() <= CHUNK_SIZE);
+                ids.extend(
+                    batch
+                        .column(0)
+                        .as_primitive::<arrow_array::types::UInt32Type>()
+                        .values(),
+                );
+                Ok(())
+            })
+            .unwrap();
+
+        // globally sorted
+        assert!(ids.windows(2).all(|w| key_of(w[0]) <= key_of(w[1])));
+
+        // no rows are lost or duplicated
+        assert_eq!(ids.len(), (n_runs * n_rows) as usize);
+        ids.sort();
+        assert!(ids.iter().enumerate().all(|(i, &id)| i as u32 == id));
+
+        // the spill files are removed
+        assert_eq!(std::fs::read_dir(&spill_dir).unwrap().count(), 0);
+        std::fs::remove_dir(&spill_dir).unwrap();
+    }
+}
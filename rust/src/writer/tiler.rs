@@ -0,0 +1,839 @@
+// Geometry operations to cut the features into vector tiles: Web Mercator
+// projection, simplification, clipping and quantization.
+
+use std::collections::{BTreeMap, HashSet};
+
+use crate::{
+    error::Ksj2GpError,
+    transform_coord::{group_rings, multipatch_polygons},
+    writer::mvt::{GeomType, GeometryEncoder, MvtFeature},
+};
+
+// Size of a tile in the tile coordinates
+pub(super) const EXTENT: u32 = 4096;
+
+// 1 pixel of a 256x256 tile in the tile coordinates
+const PIXEL: f64 = EXTENT as f64 / 256.0;
+
+// Margin around the tiles to avoid the artifacts at the tile boundaries (5
+// pixels, same as tippecanoe)
+const BUFFER: f64 = PIXEL * 5.0;
+
+// Below the max zoom, only one point is kept in each cell of this size
+const POINT_CELL: f64 = PIXEL * 4.0;
+
+// Limit of Web Mercator
+const MAX_LATITUDE: f64 = 85.051_128_779_806_59;
+
+// Geometry in the normalized Web Mercator coordinates, where (0, 0) is the
+// north-west corner and (1, 1) is the south-east corner of the world
+#[derive(Debug, Clone, PartialEq)]
+pub(super) enum WorldGeometry {
+    Points(Vec<[f64; 2]>),
+    Lines(Vec<Vec<[f64; 2]>>),
+    // Each outer ring is followed by its inner rings, in the same way as
+    // Shapefile
+    Polygons(Vec<Ring>),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub(super) struct Ring {
+    pub(super) is_outer: bool,
+    pub(super) points: Vec<[f64; 2]>,
+}
+
+pub(super) struct TileFeature {
+    pub(super) id: u64,
+    pub(super) tags: Vec<u32>,
+    pub(super) geometry: WorldGeometry,
+}
+
+pub(super) fn project(lon: f64, lat: f64) -> [f64; 2] {
+    let x = (lon + 180.0) / 360.0;
+    let lat = lat.clamp(-MAX_LATITUDE, MAX_LATITUDE).to_radians();
+    let y = (1.0 - (lat.tan() + 1.0 / lat.cos()).ln() / std::f64::consts::PI) / 2.0;
+    [x, y]
+}
+
+// Inverse of project(). Returns (lon, lat).
+pub(super) fn unproject(p: [f64; 2]) -> (f64, f64) {
+    let lon = p[0] * 360.0 - 180.0;
+    let lat = (std::f64::consts::PI * (1.0 - 2.0 * p[1]))
+        .sinh()
+        .atan()
+        .to_degrees();
+    (lon, lat)
+}
+
+impl WorldGeometry {
+    // Returns None for a null shape. The shape must be in WGS84.
+    pub(super) fn from_shape(shape: &shapefile::Shape) -> Result<Option<Self>, Ksj2GpError> {
+        fn project_points<P: shapefile::record::traits::HasXY>(points: &[P]) -> Vec<[f64; 2]> {
+            points.iter().map(|p| project(p.x(), p.y())).collect()
+        }
+
+        // In MVT, each exterior ring must be followed by its interior rings
+        fn rings<P: shapefile::record::traits::HasXY>(
+            rings: &[shapefile::PolygonRing<P>],
+        ) -> Vec<Ring> {
+            group_rings(rings)
+                .into_iter()
+                .flat_map(|group| {
+                    group.into_iter().enumerate().map(|(i, ring_index)| Ring {
+                        is_outer: i == 0,
+                        points: project_points(rings[ring_index].points()),
+                    })
+                })
+                .collect()
+        }
+
+        let geometry = match shape {
+            shapefile::Shape::NullShape => return Ok(None),
+            shapefile::Shape::Point(p) => Self::Points(vec![project(p.x, p.y)]),
+            shapefile::Shape::PointM(p) => Self::Points(vec![project(p.x, p.y)]),
+            shapefile::Shape::PointZ(p) => Self::Points(vec![project(p.x, p.y)]),
+            shapefile::Shape::Multipoint(mp) => Self::Points(project_points(mp.points())),
+            shapefile::Shape::MultipointM(mp) => Self::Points(project_points(mp.points())),
+            shapefile::Shape::MultipointZ(mp) => Self::Points(project_points(mp.points())),
+            shapefile::Shape::Polyline(pl) => {
+                Self::Lines(pl.parts().iter().map(|p| project_points(p)).collect())
+            }
+            shapefile::Shape::PolylineM(pl) => {
+                Self::Lines(pl.parts().iter().map(|p| project_points(p)).collect())
+            }
+            shapefile::Shape::PolylineZ(pl) => {
+                Self::Lines(pl.parts().iter().map(|p| project_points(p)).collect())
+            }
+            shapefile::Shape::Polygon(pg) => Self::Polygons(rings(pg.rings())),
+            shapefile::Shape::PolygonM(pg) => Self::Polygons(rings(pg.rings())),
+            shapefile::Shape::PolygonZ(pg) => Self::Polygons(rings(pg.rings())),
+            shapefile::Shape::Multipatch(mp) => Self::Polygons(
+                multipatch_polygons(mp)
+                    .iter()
+                    .flat_map(|polygon| {
+                        polygon.iter().enumerate().map(|(i, ring)| Ring {
+                            is_outer: i == 0,
+                            points: project_points(ring),
+                        })
+                    })
+                    .collect(),
+            ),
+        };
+
+        Ok(Some(geometry))
+    }
+
+    // min_x, min_y, max_x, max_y
+    pub(super) fn bbox(&self) -> [f64; 4] {
+        let mut rect = Rect::empty();
+        match self {
+            Self::Points(points) => rect.add_points(points),
+            Self::Lines(lines) => {
+                for line in lines {
+                    rect.add_points(line);
+                }
+            }
+            Self::Polygons(rings) => {
+                for ring in rings {
+                    rect.add_points(&ring.points);
+                }
+            }
+        }
+        [rect.min_x, rect.min_y, rect.max_x, rect.max_y]
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Rect {
+    min_x: f64,
+    min_y: f64,
+    max_x: f64,
+    max_y: f64,
+}
+
+impl Rect {
+    fn empty() -> Self {
+        Self {
+            min_x: f64::INFINITY,
+            min_y: f64::INFINITY,
+            max_x: f64::NEG_INFINITY,
+            max_y: f64::NEG_INFINITY,
+        }
+    }
+
+    fn add_points(&mut self, points: &[[f64; 2]]) {
+        for p in points {
+            self.min_x = self.min_x.min(p[0]);
+            self.min_y = self.min_y.min(p[1]);
+            self.max_x = self.max_x.max(p[0]);
+            self.max_y = self.max_y.max(p[1]);
+        }
+    }
+
+    fn contains(&self, other: &Rect) -> bool {
+        self.min_x <= other.min_x
+            && self.min_y <= other.min_y
+            && other.max_x <= self.max_x
+            && other.max_y <= self.max_y
+    }
+
+    fn intersects(&self, other: &Rect) -> bool {
+        self.min_x <= other.max_x
+            && self.min_y <= other.max_y
+            && other.min_x <= self.max_x
+            && other.min_y <= self.max_y
+    }
+
+    // Smaller than a pixel in both directions
+    fn is_tiny(&self) -> bool {
+        self.max_x - self.min_x < PIXEL && self.max_y - self.min_y < PIXEL
+    }
+}
+
+// Cut the features into the tiles of a zoom level. The features are scaled to
+// the "global" tile coordinates, simplified, and then split into the tiles.
+pub(super) struct Tiler {
+    zoom: u8,
+    scale: f64,
+    // Tolerance of the simplification in the tile coordinates
+    tolerance: f64,
+    // Drop the tiny features and the dense points. This is false at the max
+    // zoom so that all the features appear at least at one zoom level.
+    drop_features: bool,
+    tiles: BTreeMap<(u32, u32), Vec<MvtFeature>>,
+    point_cells: HashSet<(u32, u32, i32, i32)>,
+}
+
+impl Tiler {
+    pub(super) fn new(zoom: u8, simplification: f64, drop_features: bool) -> Self {
+        Self {
+            zoom,
+            scale: EXTENT as f64 * (1u64 << zoom) as f64,
+            tolerance: simplification * PIXEL,
+            drop_features,
+            tiles: BTreeMap::new(),
+            point_cells: HashSet::new(),
+        }
+    }
+
+    // Features of each tile, in the order of (x, y)
+    pub(super) fn finish(self) -> BTreeMap<(u32, u32), Vec<MvtFeature>> {
+        self.tiles
+    }
+
+    fn scaled(&self, points: &[[f64; 2]]) -> Vec<[f64; 2]> {
+        points
+            .iter()
+            .map(|p| [p[0] * self.scale, p[1] * self.scale])
+            .collect()
+    }
+
+    fn tile_index(&self, v: f64) -> u32 {
+        let max = (1u32 << self.zoom) - 1;
+        (v / EXTENT as f64).floor().clamp(0.0, max as f64) as u32
+    }
+
+    // Split the parts of a feature into the tiles in the same way as
+    // geojson-vt: the parts are clipped by the quadrants level by level, and only
+    // the quadrants where some parts remain are descended into, so that a large
+    // feature is not clipped by all the tiles in its bbox. The split starts from
+    // the smallest quadrant that contains the bbox (including the buffer).
+    fn split<P: Parts>(&self, parts: P, bbox: &Rect) -> Vec<((u32, u32), P)> {
+        let min_x = self.tile_index(bbox.min_x - BUFFER);
+        let max_x = self.tile_index(bbox.max_x + BUFFER);
+        let min_y = self.tile_index(bbox.min_y - BUFFER);
+        let max_y = self.tile_index(bbox.max_y + BUFFER);
+        let mut shift = 0;
+        while (min_x >> shift, min_y >> shift) != (max_x >> shift, max_y >> shift) {
+            shift += 1;
+        }
+
+        let mut tiles = Vec::new();
+        let mut stack = vec![(self.zoom - shift, min_x >> shift, min_y >> shift, parts)];
+        while let Some((level, x, y, parts)) = stack.pop() {
+            if level == self.zoom {
+                tiles.push(((x, y), parts));
+                continue;
+            }
+
+            let bbox = parts.bbox();
+            let size = EXTENT as f64 * (1u64 << (self.zoom - level - 1)) as f64;
+            for (x, y) in [
+                (x * 2, y * 2),
+                (x * 2 + 1, y * 2),
+                (x * 2, y * 2 + 1),
+                (x * 2 + 1, y * 2 + 1),
+            ] {
+                let rect = clip_rect((x, y), size);
+                let clipped = if rect.contains(&bbox) {
+                    Some(parts.clone())
+                } else if rect.intersects(&bbox) {
+                    parts.clip(&rect)
+                } else {
+                    None
+                };
+                if let Some(clipped) = clipped {
+                    stack.push((level + 1, x, y, clipped));
+                }
+            }
+        }
+        tiles
+    }
+
+    fn push(
+        &mut self,
+        tile: (u32, u32),
+        feature: &TileFeature,
+        geom_type: GeomType,
+        encoder: GeometryEncoder,
+    ) {
+        if encoder.is_empty() {
+            return;
+        }
+        self.tiles.entry(tile).or_default().push(MvtFeature {
+            id: feature.id,
+            tags: feature.tags.clone(),
+            geom_type,
+            geometry: encoder.finish(),
+        });
+    }
+
+    pub(super) fn add(&mut self, feature: &TileFeature) {
+        match &feature.geometry {
+            WorldGeometry::Points(points) => self.add_points(feature, points),
+            WorldGeometry::Lines(lines) => self.add_lines(feature, lines),
+            WorldGeometry::Polygons(rings) => self.add_polygons(feature, rings),
+        }
+    }
+
+    fn add_points(&mut self, feature: &TileFeature, points: &[[f64; 2]]) {
+        let mut points_per_tile: BTreeMap<(u32, u32), Vec<[i32; 2]>> = BTreeMap::new();
+        for p in self.scaled(points) {
+            let tile = (self.tile_index(p[0]), self.tile_index(p[1]));
+            let local = quantize(p, tile);
+
+            if self.drop_features {
+                let cell = (
+                    tile.0,
+                    tile.1,
+                    (local[0] as f64 / POINT_CELL).floor() as i32,
+                    (local[1] as f64 / POINT_CELL).floor() as i32,
+                );
+                if !self.point_cells.insert(cell) {
+                    continue;
+                }
+            }
+
+            points_per_tile.entry(tile).or_default().push(local);
+        }
+
+        for (tile, points) in points_per_tile {
+            let mut encoder = GeometryEncoder::default();
+            encoder.points(&points);
+            self.push(tile, feature, GeomType::Point, encoder);
+        }
+    }
+
+    fn add_lines(&mut self, feature: &TileFeature, lines: &[Vec<[f64; 2]>]) {
+        let lines: Vec<_> = lines
+            .iter()
+            .map(|line| simplify(&self.scaled(line), self.tolerance))
+            .collect();
+
+        let bbox = lines.bbox();
+        if bbox.min_x > bbox.max_x || (self.drop_features && bbox.is_tiny()) {
+            return;
+        }
+
+        for (tile, lines) in self.split(lines, &bbox) {
+            let mut encoder = GeometryEncoder::default();
+            for line in &lines {
+                encoder.line_string(&quantize_points(line, tile));
+            }
+            self.push(tile, feature, GeomType::LineString, encoder);
+        }
+    }
+
+    fn add_polygons(&mut self, feature: &TileFeature, rings: &[Ring]) {
+        let simplified = valid_rings(rings.iter().map(|ring| Ring {
+            is_outer: ring.is_outer,
+            points: simplify(&self.scaled(&ring.points), self.tolerance),
+        }));
+
+        let bbox = simplified.bbox();
+        if bbox.min_x > bbox.max_x || (self.drop_features && bbox.is_tiny()) {
+            return;
+        }
+
+        for (tile, rings) in self.split(simplified, &bbox) {
+            let mut encoder = GeometryEncoder::default();
+            let mut outer_kept = false;
+            for ring in &rings {
+                let mut points = quantize_points(&ring.points, tile);
+                if points.len() > 1 && points.first() == points.last() {
+                    points.pop();
+                }
+
+                let area = ring_area(&points);
+                if points.len() < 3 || area == 0 {
+                    if ring.is_outer {
+                        outer_kept = false;
+                    }
+                    continue;
+                }
+
+                // In the tile coordinates (y axis points down), the outer rings
+                // must have positive area (i.e. clockwise), and the inner rings
+                // negative area.
+                if ring.is_outer {
+                    outer_kept = true;
+                    if area < 0 {
+                        points.reverse();
+                    }
+                } else {
+                    if !outer_kept {
+                        continue;
+                    }
+                    if area > 0 {
+                        points.reverse();
+                    }
+                }
+
+                encoder.ring(&points);
+            }
+            self.push(tile, feature, GeomType::Polygon, encoder);
+        }
+    }
+}
+
+// Parts of a feature, which are split into the tiles by Tiler::split()
+trait Parts: Clone {
+    fn bbox(&self) -> Rect;
+    // None if nothing is left in the rect
+    fn clip(&self, rect: &Rect) -> Option<Self>;
+}
+
+impl Parts for Vec<Vec<[f64; 2]>> {
+    fn bbox(&self) -> Rect {
+        let mut bbox = Rect::empty();
+        for line in self {
+            bbox.add_points(line);
+        }
+        bbox
+    }
+
+    fn clip(&self, rect: &Rect) -> Option<Self> {
+        let lines: Vec<_> = self.iter().flat_map(|line| clip_line(line, rect)).collect();
+        (!lines.is_empty()).then_some(lines)
+    }
+}
+
+impl Parts for Vec<Ring> {
+    fn bbox(&self) -> Rect {
+        let mut bbox = Rect::empty();
+        for ring in self.iter().filter(|r| r.is_outer) {
+            bbox.add_points(&ring.points);
+        }
+        bbox
+    }
+
+    fn clip(&self, rect: &Rect) -> Option<Self> {
+        let rings = valid_rings(self.iter().map(|ring| Ring {
+            is_outer: ring.is_outer,
+            points: clip_ring(&ring.points, rect),
+        }));
+        (!rings.is_empty()).then_some(rings)
+    }
+}
+
+// Drop the rings with too few points, and the inner rings of the dropped outer
+// rings
+fn valid_rings(rings: impl IntoIterator<Item = Ring>) -> Vec<Ring> {
+    let mut valid = Vec::new();
+    let mut outer_kept = false;
+    for ring in rings {
+        // A ring needs at least 3 points + the closing point
+        let is_valid = ring.points.len() >= 4;
+        if ring.is_outer {
+            outer_kept = is_valid;
+        }
+        if is_valid && outer_kept {
+            valid.push(ring);
+        }
+    }
+    valid
+}
+
+// Rect of the quadrant of `size` at (x, y), including the buffer. The buffer is
+// the same at all levels so that a quadrant covers the buffers of its tiles.
+fn clip_rect(quadrant: (u32, u32), size: f64) -> Rect {
+    Rect {
+        min_x: quadrant.0 as f64 * size - BUFFER,
+        min_y: quadrant.1 as f64 * size - BUFFER,
+        max_x: (quadrant.0 + 1) as f64 * size + BUFFER,
+        max_y: (quadrant.1 + 1) as f64 * size + BUFFER,
+    }
+}
+
+// Convert the global tile coordinates to the coordinates in the tile
+fn quantize(p: [f64; 2], tile: (u32, u32)) -> [i32; 2] {
+    let extent = EXTENT as f64;
+    [
+        (p[0] - tile.0 as f64 * extent).round() as i32,
+        (p[1] - tile.1 as f64 * extent).round() as i32,
+    ]
+}
+
+// Same as quantize(), but also removes the consecutive duplicated points
+fn quantize_points(points: &[[f64; 2]], tile: (u32, u32)) -> Vec<[i32; 2]> {
+    let mut quantized: Vec<[i32; 2]> = Vec::with_capacity(points.len());
+    for &p in points {
+        let q = quantize(p, tile);
+        if quantized.last() != Some(&q) {
+            quantized.push(q);
+        }
+    }
+    quantized
+}
+
+// Twice the signed area
+fn ring_area(ring: &[[i32; 2]]) -> i64 {
+    let mut area = 0;
+    for (i, p) in ring.iter().enumerate() {
+        let q = ring[(i + 1) % ring.len()];
+        area += p[0] as i64 * q[1] as i64 - q[0] as i64 * p[1] as i64;
+    }
+    area
+}
+
+// Squared distance from the point to the segment
+fn segment_distance2(p: [f64; 2], a: [f64; 2], b: [f64; 2]) -> f64 {
+    let (dx, dy) = (b[0] - a[0], b[1] - a[1]);
+    let len2 = dx * dx + dy * dy;
+    let t = if len2 == 0.0 {
+        0.0
+    } else {
+        (((p[0] - a[0]) * dx + (p[1] - a[1]) * dy) / len2).clamp(0.0, 1.0)
+    };
+    let (x, y) = (a[0] + t * dx - p[0], a[1] + t * dy - p[1]);
+    x * x + y * y
+}
+
+// Douglas-Peucker algorithm. The first and the last points are always kept, so
+// a closed ring stays closed.
+fn simplify(points: &[[f64; 2]], tolerance: f64) -> Vec<[f64; 2]> {
+    if points.len() <= 2 || tolerance <= 0.0 {
+        return points.to_vec();
+    }
+
+    let tolerance2 = tolerance * tolerance;
+    let mut keep = vec![false; points.len()];
+    keep[0] = true;
+    keep[points.len() - 1] = true;
+
+    let mut stack = vec![(0, points.len() - 1)];
+    while let Some((first, last)) = stack.pop() {
+        let mut max_distance2 = 0.0;
+        let mut index = first;
+        for i in (first + 1)..last {
+            let d = segment_distance2(points[i], points[first], points[last]);
+            if d > max_distance2 {
+                max_distance2 = d;
+                index = i;
+            }
+        }
+
+        if max_distance2 > tolerance2 {
+            keep[index] = true;
+            stack.push((first, index));
+            stack.push((index, last));
+        }
+    }
+
+    points
+        .iter()
+        .zip(keep)
+        .filter_map(|(p, keep)| keep.then_some(*p))
+        .collect()
+}
+
+// Liang-Barsky algorithm
+fn clip_segment(a: [f64; 2], b: [f64; 2], rect: &Rect) -> Option<([f64; 2], [f64; 2])> {
+    let (dx, dy) = (b[0] - a[0], b[1] - a[1]);
+    let mut t0: f64 = 0.0;
+    let mut t1: f64 = 1.0;
+
+    for (p, q) in [
+        (-dx, a[0] - rect.min_x),
+        (dx, rect.max_x - a[0]),
+        (-dy, a[1] - rect.min_y),
+        (dy, rect.max_y - a[1]),
+    ] {
+        if p == 0.0 {
+            if q < 0.0 {
+                return None;
+            }
+        } else {
+            let r = q / p;
+            if p < 0.0 {
+                if r > t1 {
+                    return None;
+                }
+                t0 = t0.max(r);
+            } else {
+                if r < t0 {
+                    return None;
+                }
+                t1 = t1.min(r);
+            }
+        }
+    }
+
+    let start = if t0 > 0.0 {
+        [a[0] + t0 * dx, a[1] + t0 * dy]
+    } else {
+        a
+    };
+    let end = if t1 < 1.0 {
+        [a[0] + t1 * dx, a[1] + t1 * dy]
+    } else {
+        b
+    };
+    Some((start, end))
+}
+
+// Clip a line string. The line string is split where it goes out of the rect.
+fn clip_line(line: &[[f64; 2]], rect: &Rect) -> Vec<Vec<[f64; 2]>> {
+    let mut parts = Vec::new();
+    let mut current: Vec<[f64; 2]> = Vec::new();
+
+    for segment in line.windows(2) {
+        match clip_segment(segment[0], segment[1], rect) {
+            Some((start, end)) => {
+                if current.last() != Some(&start) {
+                    if current.len() >= 2 {
+                        parts.push(std::mem::take(&mut current));
+                    }
+                    current.clear();
+                    current.push(start);
+                }
+                current.push(end);
+            }
+            None => {
+                if current.len() >= 2 {
+                    parts.push(std::mem::take(&mut current));
+                }
+                current.clear();
+            }
+        }
+    }
+
+    if current.len() >= 2 {
+        parts.push(current);
+    }
+
+    parts
+}
+
+// Sutherland-Hodgman algorithm. The result may contain degenerate edges along
+// the rect, which is harmless for rendering.
+fn clip_ring(ring: &[[f64; 2]], rect: &Rect) -> Vec<[f64; 2]> {
+    let mut output = ring.to_vec();
+    // Remove the closing point
+    if output.len() > 1 && output.first() == output.last() {
+        output.pop();
+    }
+
+    for edge in 0..4 {
+        if output.is_empty() {
+            break;
+        }
+        let input = std::mem::take(&mut output);
+
+        let inside = |p: [f64; 2]| match edge {
+            0 => p[0] >= rect.min_x,
+            1 => p[0] <= rect.max_x,
+            2 => p[1] >= rect.min_y,
+            _ => p[1] <= rect.max_y,
+        };
+        let intersection = |a: [f64; 2], b: [f64; 2]| match edge {
+            0 | 1 => {
+                let x = if edge == 0 { rect.min_x } else { rect.max_x };
+                let t = (x - a[0]) / (b[0] - a[0]);
+                [x, a[1] + t * (b[1] - a[1])]
+            }
+            _ => {
+                let y = if edge == 2 { rect.min_y } else { rect.max_y };
+                let t = (y - a[1]) / (b[1] - a[1]);
+                [a[0] + t * (b[0] - a[0]), y]
+            }
+        };
+
+        let mut prev = input[input.len() - 1];
+        for &current in &input {
+            if inside(current) {
+                if !inside(prev) {
+                    output.push(intersection(prev, current));
+                }
+                output.push(current);
+            } else if inside(prev) {
+                output.push(intersection(prev, current));
+            }
+            prev = current;
+        }
+    }
+
+    // Close the ring again
+    if let Some(&first) = output.first() {
+        output.push(first);
+    }
+
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_project() {
+        assert_eq!(project(-180.0, 0.0), [0.0, 0.5]);
+        let [x, y] = project(180.0, MAX_LATITUDE);
+        assert_eq!(x, 1.0);
+        assert!(y.abs() < 1e-9);
+
+        let (lon, lat) = unproject(project(139.75, 35.68));
+        assert!((lon - 139.75).abs() < 1e-9);
+        assert!((lat - 35.68).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_simplify() {
+        let line = [[0.0, 0.0], [1.0, 0.1], [2.0, -0.1], [3.0, 5.0], [4.0, 6.0]];
+        assert_eq!(simplify(&line, 1.0), [[0.0, 0.0], [2.0, -0.1], [4.0, 6.0]]);
+        assert_eq!(simplify(&line, 0.0), line);
+    }
+
+    #[test]
+    fn test_clip_line() {
+        let rect = Rect {
+            min_x: 0.0,
+            min_y: 0.0,
+            max_x: 10.0,
+            max_y: 10.0,
+        };
+        let line = [
+            [-5.0, 5.0],
+            [5.0, 5.0],
+            [5.0, 15.0],
+            [8.0, 15.0],
+            [8.0, 5.0],
+        ];
+        assert_eq!(
+            clip_line(&line, &rect),
+            [
+                vec![[0.0, 5.0], [5.0, 5.0], [5.0, 10.0]],
+                vec![[8.0, 10.0], [8.0, 5.0]]
+            ]
+        );
+    }
+
+    #[test]
+    fn test_clip_ring() {
+        let rect = Rect {
+            min_x: 0.0,
+            min_y: 0.0,
+            max_x: 10.0,
+            max_y: 10.0,
+        };
+        let ring = [[5.0, 5.0], [15.0, 5.0], [15.0, 8.0], [5.0, 8.0], [5.0, 5.0]];
+        assert_eq!(
+            clip_ring(&ring, &rect),
+            [[5.0, 5.0], [10.0, 5.0], [10.0, 8.0], [5.0, 8.0], [5.0, 5.0]]
+        );
+    }
+
+    #[test]
+    fn test_split() {
+        // Same as clipping the parts by each tile
+        fn assert_split<P: Parts + std::fmt::Debug>(
+            tiler: &Tiler,
+            parts: P,
+            quantized: impl Fn(&P, (u32, u32)) -> Vec<Vec<[i32; 2]>>,
+        ) {
+            let mut tiles: Vec<_> = tiler
+                .split(parts.clone(), &parts.bbox())
+                .into_iter()
+                .map(|(tile, parts)| (tile, quantized(&parts, tile)))
+                .collect();
+            tiles.sort_by_key(|(tile, _)| *tile);
+
+            let mut expected = Vec::new();
+            for x in 0..1 << tiler.zoom {
+                for y in 0..1 << tiler.zoom {
+                    if let Some(clipped) = parts.clip(&clip_rect((x, y), EXTENT as f64)) {
+                        expected.push(((x, y), quantized(&clipped, (x, y))));
+                    }
+                }
+            }
+            assert_eq!(tiles, expected);
+        }
+
+        let tiler = Tiler::new(3, 0.0, false);
+        let extent = EXTENT as f64;
+
+        let lines = vec![
+            vec![[100.0, 100.0], [extent * 3.5, extent * 2.5]],
+            vec![[extent * 7.5, 100.0], [extent * 7.5, extent * 1.5]],
+        ];
+        let quantize_lines = |lines: &Vec<Vec<[f64; 2]>>, tile| {
+            lines
+                .iter()
+                .map(|line| quantize_points(line, tile))
+                .collect()
+        };
+        assert_split(&tiler, lines, quantize_lines);
+
+        let rings = vec![
+            Ring {
+                is_outer: true,
+                points: vec![
+                    [100.0, 100.0],
+                    [extent * 2.5, 100.0],
+                    [extent * 2.5, extent * 2.5],
+                    [100.0, 100.0],
+                ],
+            },
+            Ring {
+                is_outer: false,
+                points: vec![
+                    [extent * 1.5, extent * 0.5],
+                    [extent * 1.5, extent * 1.2],
+                    [extent * 2.2, extent * 0.5],
+                    [extent * 1.5, extent * 0.5],
+                ],
+            },
+        ];
+        let quantize_rings = |rings: &Vec<Ring>, tile| {
+            rings
+                .iter()
+                .map(|ring| quantize_points(&ring.points, tile))
+                .collect()
+        };
+        assert_split(&tiler, rings, quantize_rings);
+
+        // Not clipped if the feature fits in a tile
+        let lines = vec![vec![
+            [extent * 5.2, extent * 6.2],
+            [extent * 5.8, extent * 6.8],
+        ]];
+        assert_eq!(tiler.split(lines.clone(), &lines.bbox()), [((5, 6), lines)]);
+    }
+
+    #[test]
+    fn test_ring_area() {
+        // Clockwise in the tile coordinates
+        assert!(ring_area(&[[0, 0], [10, 0], [10, 10], [0, 10]]) > 0);
+        assert!(ring_area(&[[0, 0], [0, 10], [10, 10], [10, 0]]) < 0);
+    }
+}
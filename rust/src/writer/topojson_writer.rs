@@ -0,0 +1,710 @@
+use std::{
+    collections::{HashMap, HashSet, hash_map::Entry},
+    fmt::Write as _,
+    io::{Read, Seek, Write},
+};
+
+use arrow_array::{Array, cast::AsArray as _};
+use itertools::Itertools as _;
+
+use crate::{
+    crs::JapanCrs,
+    error::Ksj2GpError,
+    transform_coord::{CoordTransformer, group_rings, multipatch_polygons},
+    translate::{TranslateOptions, TranslationReport},
+    writer::{
+        TopoJsonOptions, construct_schema_from_crs, csv_writer::format_value, for_each_chunk,
+    },
+};
+
+type Point = [f64; 2];
+type PointKey = (u64, u64);
+
+// Geometry before building the topology. Each polygon is a list of rings where
+// the first one is the exterior ring. The rings are closed.
+enum RawGeometry {
+    Null,
+    Points(Vec<Point>),
+    Lines(Vec<Vec<Point>>),
+    Polygons(Vec<Vec<Vec<Point>>>),
+}
+
+// Geometry that refers to the arcs by index. A negative index `!i` means the
+// arc `i` reversed.
+enum TopoGeometry {
+    Null,
+    Points(Vec<Point>),
+    Lines(Vec<Vec<i64>>),
+    Polygons(Vec<Vec<Vec<i64>>>),
+}
+
+// Write TopoJSON. The coordinates are transformed to WGS84 in the same way as
+// GeoJSON, but the orientation of the rings is kept as it is in the Shapefile
+// (i.e. the exterior rings are clockwise), which is what d3-geo expects. Since
+// the topology needs all the features, they are kept in memory.
+pub(crate) fn write_topojson<T: Read + Seek, D: Read + Seek, W: Write>(
+    reader: &mut shapefile::Reader<T, D>,
+    writer: &mut W,
+    dbf_fields: &[dbase::FieldInfo],
+    crs: JapanCrs,
+    translate_options: &TranslateOptions,
+    report: &mut TranslationReport,
+    options: &TopoJsonOptions,
+) -> Result<(), Ksj2GpError> {
+    if options.quantization.is_some_and(|q| q < 2) {
+        return Err("Quantization must be 2 or larger".into());
+    }
+
+    let transformer = CoordTransformer::new(crs.clone());
+
+    let fields_info =
+        construct_schema_from_crs(dbf_fields, &crs, translate_options, report, false)?;
+    let column_names: Vec<String> = fields_info
+        .non_geo_fields
+        .iter()
+        .map(|f| serde_json::to_string(f.name()).map_err(|e| format!("{e:?}")))
+        .collect::<Result<_, _>>()?;
+
+    let object_name = std::path::Path::new(&translate_options.target_shp)
+        .file_stem()
+        .and_then(|s| s.to_str())
+        .unwrap_or("output");
+
+    // The properties are serialized here to keep the order of the columns
+    let mut properties = Vec::new();
+    let mut geometries = Vec::new();
+
+    for_each_chunk(reader, dbf_fields, &fields_info, report, |chunk| {
+        let num_rows = chunk.shapes.len();
+        for shape in chunk.shapes {
+            geometries.push(raw_geometry(&transformer.transform_shape(shape)?)?);
+        }
+
+        for i in 0..num_rows {
+            let mut buf = String::from("{");
+            for (j, (name, array)) in column_names.iter().zip(&chunk.arrays).enumerate() {
+                if j > 0 {
+                    buf.push(',');
+                }
+                buf.push_str(name);
+                buf.push(':');
+                buf.push_str(&json_value(array.as_ref(), i)?);
+            }
+            buf.push('}');
+            properties.push(buf);
+        }
+
+        Ok(())
+    })?;
+
+    let bbox = bbox(&geometries);
+    let quantization = options.quantization.map(|q| Quantization::new(&bbox, q));
+    if let Some(q) = &quantization {
+        for geometry in &mut geometries {
+            q.quantize_geometry(geometry);
+        }
+    }
+
+    let mut topology = Topology::new(&geometries);
+    let geometries: Vec<TopoGeometry> = geometries
+        .iter()
+        .map(|g| topology.cut_geometry(g))
+        .collect();
+
+    let mut buf = String::from(r#"{"type":"Topology","#);
+    if let Some(q) = &quantization {
+        write!(
+            buf,
+            r#""transform":{{"scale":[{},{}],"translate":[{},{}]}},"#,
+            q.scale[0], q.scale[1], q.translate[0], q.translate[1]
+        )
+        .unwrap();
+    }
+    if bbox[0] <= bbox[2] {
+        write!(
+            buf,
+            r#""bbox":[{},{},{},{}],"#,
+            bbox[0], bbox[1], bbox[2], bbox[3]
+        )
+        .unwrap();
+    }
+    write!(
+        buf,
+        r#""objects":{{{}:{{"type":"GeometryCollection","geometries":["#,
+        serde_json::to_string(object_name).map_err(|e| format!("{e:?}"))?
+    )
+    .unwrap();
+    writer.write_all(buf.as_bytes())?;
+
+    for (i, (geometry, properties)) in geometries.iter().zip(&properties).enumerate() {
+        buf.clear();
+        if i > 0 {
+            buf.push(',');
+        }
+        write_geometry(&mut buf, geometry);
+        write!(buf, r#","properties":{properties}}}"#).unwrap();
+        writer.write_all(buf.as_bytes())?;
+    }
+
+    writer.write_all(br#"]}},"arcs":["#)?;
+    for (i, arc) in topology.arcs.iter().enumerate() {
+        buf.clear();
+        if i > 0 {
+            buf.push(',');
+        }
+        write_arc(&mut buf, arc, quantization.is_some());
+        writer.write_all(buf.as_bytes())?;
+    }
+    writer.write_all(b"]}")?;
+    writer.flush()?;
+
+    Ok(())
+}
+
+// Group the rings into polygons. Each outer ring starts a new polygon, and the
+// inner rings belong to the preceding outer ring.
+fn raw_geometry(shape: &shapefile::Shape) -> Result<RawGeometry, Ksj2GpError> {
+    fn points<P: shapefile::record::traits::HasXY>(points: &[P]) -> Vec<Point> {
+        points.iter().map(|p| [p.x(), p.y()]).collect()
+    }
+
+    fn polygons<P: shapefile::record::traits::HasXY>(
+        rings: &[shapefile::PolygonRing<P>],
+    ) -> Vec<Vec<Vec<Point>>> {
+        group_rings(rings)
+            .into_iter()
+            .map(|group| {
+                group
+                    .into_iter()
+                    .map(|i| points(rings[i].points()))
+                    .collect()
+            })
+            .collect()
+    }
+
+    let geometry = match shape {
+        shapefile::Shape::NullShape => RawGeometry::Null,
+        shapefile::Shape::Point(p) => RawGeometry::Points(vec![[p.x, p.y]]),
+        shapefile::Shape::PointM(p) => RawGeometry::Points(vec![[p.x, p.y]]),
+        shapefile::Shape::PointZ(p) => RawGeometry::Points(vec![[p.x, p.y]]),
+        shapefile::Shape::Multipoint(mp) => RawGeometry::Points(points(mp.points())),
+        shapefile::Shape::MultipointM(mp) => RawGeometry::Points(points(mp.points())),
+        shapefile::Shape::MultipointZ(mp) => RawGeometry::Points(points(mp.points())),
+        shapefile::Shape::Polyline(pl) => {
+            RawGeometry::Lines(pl.parts().iter().map(|p| points(p)).collect())
+        }
+        shapefile::Shape::PolylineM(pl) => {
+            RawGeometry::Lines(pl.parts().iter().map(|p| points(p)).collect())
+        }
+        shapefile::Shape::PolylineZ(pl) => {
+            RawGeometry::Lines(pl.parts().iter().map(|p| points(p)).collect())
+        }
+        shapefile::Shape::Polygon(pg) => RawGeometry::Polygons(polygons(pg.rings())),
+        shapefile::Shape::PolygonM(pg) => RawGeometry::Polygons(polygons(pg.rings())),
+        shapefile::Shape::PolygonZ(pg) => RawGeometry::Polygons(polygons(pg.rings())),
+        shapefile::Shape::Multipatch(mp) => RawGeometry::Polygons(
+            multipatch_polygons(mp)
+                .iter()
+                .map(|rings| rings.iter().map(|ring| points(ring)).collect())
+                .collect(),
+        ),
+    };
+
+    Ok(geometry)
+}
+
+fn for_each_point(geometry: &RawGeometry, f: impl FnMut(&Point)) {
+    match geometry {
+        RawGeometry::Null => {}
+        RawGeometry::Points(points) => points.iter().for_each(f),
+        RawGeometry::Lines(lines) => lines.iter().flatten().for_each(f),
+        RawGeometry::Polygons(polygons) => polygons.iter().flatten().flatten().for_each(f),
+    }
+}
+
+// min_x, min_y, max_x, max_y
+fn bbox(geometries: &[RawGeometry]) -> [f64; 4] {
+    let mut bbox = [
+        f64::INFINITY,
+        f64::INFINITY,
+        f64::NEG_INFINITY,
+        f64::NEG_INFINITY,
+    ];
+    for geometry in geometries {
+        for_each_point(geometry, |p| {
+            bbox[0] = bbox[0].min(p[0]);
+            bbox[1] = bbox[1].min(p[1]);
+            bbox[2] = bbox[2].max(p[0]);
+            bbox[3] = bbox[3].max(p[1]);
+        });
+    }
+    bbox
+}
+
+struct Quantization {
+    scale: [f64; 2],
+    translate: [f64; 2],
+}
+
+impl Quantization {
+    fn new(bbox: &[f64; 4], quantization: u32) -> Self {
+        if bbox[0] > bbox[2] {
+            return Self {
+                scale: [1.0, 1.0],
+                translate: [0.0, 0.0],
+            };
+        }
+
+        let n = (quantization - 1) as f64;
+        let scale = |min: f64, max: f64| if max > min { (max - min) / n } else { 1.0 };
+        Self {
+            scale: [scale(bbox[0], bbox[2]), scale(bbox[1], bbox[3])],
+            translate: [bbox[0], bbox[1]],
+        }
+    }
+
+    fn quantize(&self, p: &Point) -> Point {
+        [
+            ((p[0] - self.translate[0]) / self.scale[0]).round(),
+            ((p[1] - self.translate[1]) / self.scale[1]).round(),
+        ]
+    }
+
+    // Quantize the points and remove the consecutive duplicates. The lines and
+    // the rings that collapse are removed, and so are the polygons whose
+    // exterior ring collapses.
+    fn quantize_geometry(&self, geometry: &mut RawGeometry) {
+        let quantize_line = |line: &[Point]| {
+            let mut quantized: Vec<Point> = Vec::with_capacity(line.len());
+            for p in line {
+                let q = self.quantize(p);
+                if quantized.last() != Some(&q) {
+                    quantized.push(q);
+                }
+            }
+            quantized
+        };
+
+        match geometry {
+            RawGeometry::Null => {}
+            RawGeometry::Points(points) => {
+                for p in points.iter_mut() {
+                    *p = self.quantize(p);
+                }
+            }
+            RawGeometry::Lines(lines) => {
+                for line in lines.iter_mut() {
+                    *line = quantize_line(line);
+                }
+                lines.retain(|line| line.len() >= 2);
+            }
+            RawGeometry::Polygons(polygons) => {
+                for polygon in polygons.iter_mut() {
+                    for ring in polygon.iter_mut() {
+                        *ring = quantize_line(ring);
+                    }
+                    // A ring needs at least 3 points + the closing point
+                    if polygon.first().is_some_and(|exterior| exterior.len() < 4) {
+                        polygon.clear();
+                    }
+                    polygon.retain(|ring| ring.len() >= 4);
+                }
+                polygons.retain(|polygon| !polygon.is_empty());
+            }
+        }
+    }
+}
+
+fn key(p: &Point) -> PointKey {
+    // -0.0 and 0.0 are the same point
+    ((p[0] + 0.0).to_bits(), (p[1] + 0.0).to_bits())
+}
+
+// Builds the arcs shared among the lines and the rings
+struct Topology {
+    // Points where the lines or the rings need to be cut
+    junctions: HashSet<PointKey>,
+    arcs: Vec<Vec<Point>>,
+    arc_ids: HashMap<Vec<PointKey>, usize>,
+}
+
+impl Topology {
+    // Find the junctions. A point is a junction if it's an end of a line, or
+    // if its neighbors differ between the lines or the rings that contain it.
+    fn new(geometries: &[RawGeometry]) -> Self {
+        let mut junctions = HashSet::new();
+        // An end of a line is always a junction
+        let mut line_ends = Vec::new();
+        let mut neighbors: HashMap<PointKey, (Option<PointKey>, Option<PointKey>)> = HashMap::new();
+
+        let mut visit = |point: PointKey, a: Option<PointKey>, b: Option<PointKey>| {
+            let pair = if a <= b { (a, b) } else { (b, a) };
+            match neighbors.entry(point) {
+                Entry::Vacant(entry) => {
+                    entry.insert(pair);
+                }
+                Entry::Occupied(entry) => {
+                    if *entry.get() != pair {
+                        junctions.insert(point);
+                    }
+                }
+            }
+        };
+
+        for geometry in geometries {
+            match geometry {
+                RawGeometry::Null | RawGeometry::Points(_) => {}
+                RawGeometry::Lines(lines) => {
+                    for line in lines {
+                        let keys: Vec<_> = line.iter().map(key).collect();
+                        let n = keys.len();
+                        if n < 2 {
+                            continue;
+                        }
+                        line_ends.push(keys[0]);
+                        line_ends.push(keys[n - 1]);
+                        for i in 1..n - 1 {
+                            visit(keys[i], Some(keys[i - 1]), Some(keys[i + 1]));
+                        }
+                    }
+                }
+                RawGeometry::Polygons(polygons) => {
+                    for ring in polygons.iter().flatten() {
+                        if ring.len() < 2 {
+                            continue;
+                        }
+                        // Without the closing point
+                        let keys: Vec<_> = ring[..ring.len() - 1].iter().map(key).collect();
+                        let n = keys.len();
+                        for i in 0..n {
+                            visit(
+                                keys[i],
+                                Some(keys[(i + n - 1) % n]),
+                                Some(keys[(i + 1) % n]),
+                            );
+                        }
+                    }
+                }
+            }
+        }
+
+        junctions.extend(line_ends);
+
+        Self {
+            junctions,
+            arcs: Vec::new(),
+            arc_ids: HashMap::new(),
+        }
+    }
+
+    // Returns the index of the arc, or `!index` if the arc is found reversed
+    fn arc_id(&mut self, points: &[Point]) -> i64 {
+        let keys: Vec<_> = points.iter().map(key).collect();
+        if let Some(&id) = self.arc_ids.get(&keys) {
+            return id as i64;
+        }
+
+        let reversed: Vec<_> = keys.iter().rev().copied().collect();
+        if let Some(&id) = self.arc_ids.get(&reversed) {
+            return !(id as i64);
+        }
+
+        let id = self.arcs.len();
+        self.arcs.push(points.to_vec());
+        self.arc_ids.insert(keys, id);
+        id as i64
+    }
+
+    fn cut_line(&mut self, line: &[Point]) -> Vec<i64> {
+        let mut arcs = Vec::new();
+        let mut start = 0;
+        for i in 1..line.len() {
+            if i == line.len() - 1 || self.junctions.contains(&key(&line[i])) {
+                arcs.push(self.arc_id(&line[start..=i]));
+                start = i;
+            }
+        }
+        arcs
+    }
+
+    fn cut_ring(&mut self, ring: &[Point]) -> Vec<i64> {
+        if ring.len() < 2 {
+            return Vec::new();
+        }
+        let open = &ring[..ring.len() - 1];
+
+        // Rotate the ring to start at a junction. If there's no junction, the
+        // ring is a single arc; it starts at the smallest point so that the
+        // same ring shared by two polygons becomes the same arc.
+        let start = open
+            .iter()
+            .position(|p| self.junctions.contains(&key(p)))
+            .unwrap_or_else(|| {
+                (0..open.len())
+                    .min_by(|&a, &b| {
+                        open[a][0]
+                            .total_cmp(&open[b][0])
+                            .then(open[a][1].total_cmp(&open[b][1]))
+                    })
+                    .unwrap_or(0)
+            });
+
+        let mut rotated: Vec<Point> = Vec::with_capacity(ring.len());
+        rotated.extend_from_slice(&open[start..]);
+        rotated.extend_from_slice(&open[..start]);
+        rotated.push(open[start]);
+
+        let mut arcs = Vec::new();
+        let mut begin = 0;
+        for i in 1..rotated.len() {
+            if i == rotated.len() - 1 || self.junctions.contains(&key(&rotated[i])) {
+                arcs.push(self.arc_id(&rotated[begin..=i]));
+                begin = i;
+            }
+        }
+        arcs
+    }
+
+    fn cut_geometry(&mut self, geometry: &RawGeometry) -> TopoGeometry {
+        match geometry {
+            RawGeometry::Null => TopoGeometry::Null,
+            RawGeometry::Points(points) => TopoGeometry::Points(points.clone()),
+            RawGeometry::Lines(lines) => {
+                TopoGeometry::Lines(lines.iter().map(|line| self.cut_line(line)).collect())
+            }
+            RawGeometry::Polygons(polygons) => TopoGeometry::Polygons(
+                polygons
+                    .iter()
+                    .map(|polygon| polygon.iter().map(|ring| self.cut_ring(ring)).collect())
+                    .collect(),
+            ),
+        }
+    }
+}
+
+fn write_point(buf: &mut String, p: &Point) {
+    write!(buf, "[{},{}]", p[0], p[1]).unwrap();
+}
+
+fn write_arc_ids(buf: &mut String, arcs: &[i64]) {
+    buf.push('[');
+    buf.push_str(&arcs.iter().join(","));
+    buf.push(']');
+}
+
+fn write_geometry(buf: &mut String, geometry: &TopoGeometry) {
+    match geometry {
+        TopoGeometry::Points(points) if points.len() == 1 => {
+            buf.push_str(r#"{"type":"Point","coordinates":"#);
+            write_point(buf, &points[0]);
+        }
+        TopoGeometry::Points(points) if !points.is_empty() => {
+            buf.push_str(r#"{"type":"MultiPoint","coordinates":["#);
+            for (i, p) in points.iter().enumerate() {
+                if i > 0 {
+                    buf.push(',');
+                }
+                write_point(buf, p);
+            }
+            buf.push(']');
+        }
+        TopoGeometry::Lines(lines) if lines.len() == 1 => {
+            buf.push_str(r#"{"type":"LineString","arcs":"#);
+            write_arc_ids(buf, &lines[0]);
+        }
+        TopoGeometry::Lines(lines) if !lines.is_empty() => {
+            buf.push_str(r#"{"type":"MultiLineString","arcs":["#);
+            for (i, line) in lines.iter().enumerate() {
+                if i > 0 {
+                    buf.push(',');
+                }
+                write_arc_ids(buf, line);
+            }
+            buf.push(']');
+        }
+        TopoGeometry::Polygons(polygons) if !polygons.is_empty() => {
+            let write_polygon = |buf: &mut String, polygon: &[Vec<i64>]| {
+                buf.push('[');
+                for (i, ring) in polygon.iter().enumerate() {
+                    if i > 0 {
+                        buf.push(',');
+                    }
+                    write_arc_ids(buf, ring);
+                }
+                buf.push(']');
+            };
+
+            if polygons.len() == 1 {
+                buf.push_str(r#"{"type":"Polygon","arcs":"#);
+                write_polygon(buf, &polygons[0]);
+            } else {
+                buf.push_str(r#"{"type":"MultiPolygon","arcs":["#);
+                for (i, polygon) in polygons.iter().enumerate() {
+                    if i > 0 {
+                        buf.push(',');
+                    }
+                    write_polygon(buf, polygon);
+                }
+                buf.push(']');
+            }
+        }
+        // Null shapes and the geometries that collapsed by the quantization
+        _ => buf.push_str(r#"{"type":null"#),
+    }
+}
+
+// When quantized, the positions except the first one are delta-encoded
+fn write_arc(buf: &mut String, arc: &[Point], delta: bool) {
+    buf.push('[');
+    let mut prev = [0.0, 0.0];
+    for (i, p) in arc.iter().enumerate() {
+        if i > 0 {
+            buf.push(',');
+        }
+        if delta {
+            write_point(buf, &[p[0] - prev[0], p[1] - prev[1]]);
+            prev = *p;
+        } else {
+            write_point(buf, p);
+        }
+    }
+    buf.push(']');
+}
+
+fn json_value(array: &dyn Array, i: usize) -> Result<String, Ksj2GpError> {
+    if array.is_null(i) {
+        return Ok("null".to_string());
+    }
+
+    let value = match array.data_type() {
+        // NaN and infinity are not valid JSON numbers
+        arrow_schema::DataType::Float32
+            if !array
+                .as_primitive::<arrow_array::types::Float32Type>()
+                .value(i)
+                .is_finite() =>
+        {
+            "null".to_string()
+        }
+        arrow_schema::DataType::Float64
+            if !array
+                .as_primitive::<arrow_array::types::Float64Type>()
+                .value(i)
+                .is_finite() =>
+        {
+            "null".to_string()
+        }
+        arrow_schema::DataType::Boolean
+        | arrow_schema::DataType::Int32
+        | arrow_schema::DataType::Float32
+        | arrow_schema::DataType::Float64 => format_value(array, i)?,
+        arrow_schema::DataType::Utf8 => serde_json::to_string(array.as_string::<i32>().value(i))
+            .map_err(|e| format!("{e:?}"))?,
+        // e.g. dates
+        _ => serde_json::to_string(&format_value(array, i)?).map_err(|e| format!("{e:?}"))?,
+    };
+
+    Ok(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn square(x0: f64, y0: f64, x1: f64, y1: f64) -> Vec<Point> {
+        // clockwise
+        vec![[x0, y0], [x0, y1], [x1, y1], [x1, y0], [x0, y0]]
+    }
+
+    #[test]
+    fn test_shared_edge() {
+        // Two squares sharing the edge x = 1
+        let geometries = vec![
+            RawGeometry::Polygons(vec![vec![square(0.0, 0.0, 1.0, 1.0)]]),
+            RawGeometry::Polygons(vec![vec![square(1.0, 0.0, 2.0, 1.0)]]),
+        ];
+
+        let mut topology = Topology::new(&geometries);
+        let topo: Vec<_> = geometries
+            .iter()
+            .map(|g| topology.cut_geometry(g))
+            .collect();
+
+        // 2 outer arcs + 1 shared arc
+        assert_eq!(topology.arcs.len(), 3);
+
+        let rings: Vec<_> = topo
+            .iter()
+            .map(|g| match g {
+                TopoGeometry::Polygons(p) => p[0][0].clone(),
+                _ => unreachable!(),
+            })
+            .collect();
+        // The shared arc is referenced in the opposite directions
+        let shared: Vec<_> = rings[0]
+            .iter()
+            .filter(|&&a| rings[1].contains(&!a))
+            .collect();
+        assert_eq!(shared.len(), 1);
+    }
+
+    #[test]
+    fn test_shared_ring() {
+        // A hole filled by another polygon
+        let mut hole = square(1.0, 1.0, 2.0, 2.0);
+        hole.reverse();
+        let geometries = vec![
+            RawGeometry::Polygons(vec![vec![square(0.0, 0.0, 3.0, 3.0), hole]]),
+            RawGeometry::Polygons(vec![vec![square(1.0, 1.0, 2.0, 2.0)]]),
+        ];
+
+        let mut topology = Topology::new(&geometries);
+        let topo: Vec<_> = geometries
+            .iter()
+            .map(|g| topology.cut_geometry(g))
+            .collect();
+
+        assert_eq!(topology.arcs.len(), 2);
+        match (&topo[0], &topo[1]) {
+            (TopoGeometry::Polygons(a), TopoGeometry::Polygons(b)) => {
+                assert_eq!(a[0][1], [!b[0][0][0]]);
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn test_quantize() {
+        let q = Quantization::new(&[139.0, 35.0, 140.0, 36.0], 11);
+        assert_eq!(q.quantize(&[139.5, 35.0]), [5.0, 0.0]);
+        assert_eq!(q.quantize(&[140.0, 36.0]), [10.0, 10.0]);
+
+        let mut geometry = RawGeometry::Lines(vec![
+            vec![[139.0, 35.0], [139.01, 35.0], [140.0, 36.0]],
+            vec![[139.0, 35.0], [139.01, 35.01]],
+        ]);
+        q.quantize_geometry(&mut geometry);
+        match geometry {
+            RawGeometry::Lines(lines) => assert_eq!(lines, [vec![[0.0, 0.0], [10.0, 10.0]]]),
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn test_write_arc() {
+        let mut buf = String::new();
+        write_arc(&mut buf, &[[1.0, 2.0], [3.0, 2.0], [3.0, 5.0]], true);
+        assert_eq!(buf, "[[1,2],[2,0],[0,3]]");
+    }
+
+    #[test]
+    fn test_json_value_non_finite() {
+        let array = arrow_array::Float64Array::from(vec![1.5, f64::NAN, f64::INFINITY]);
+        assert_eq!(json_value(&array, 0).unwrap(), "1.5");
+        assert_eq!(json_value(&array, 1).unwrap(), "null");
+        assert_eq!(json_value(&array, 2).unwrap(), "null");
+
+        let array = arrow_array::Float32Array::from(vec![f32::NEG_INFINITY]);
+        assert_eq!(json_value(&array, 0).unwrap(), "null");
+    }
+}
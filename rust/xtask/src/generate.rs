@@ -0,0 +1,695 @@
+//! Update the data modules (`src/translate/data/*.rs`) from the KSJ spec files
+//!
+//! The existing entries are parsed from the data modules and merged with the
+//! ones parsed from the spec files, so that the dictionaries don't depend on
+//! which versions of the spec pages happen to be saved locally.
+
+use std::{
+    collections::HashMap,
+    error::Error,
+    fs,
+    path::{Path, PathBuf},
+    sync::LazyLock,
+};
+
+use regex::Regex;
+
+use crate::{
+    categories::{CATEGORIES, category_of},
+    spec::{codelist_name_from_filename, ksj_id_from_filename, parse_codelist, parse_columns},
+};
+
+const GENERATED_NOTE: &str = "// Generated by `cargo xtask gen-dict`. Don't edit by hand.\n";
+const GENERATED_END: &str = "// End of the generated part\n";
+
+static ENTRY_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r#"\("((?:[^"\\]|\\.)*)",\s*"((?:[^"\\]|\\.)*)"\)"#).unwrap());
+static GROUP_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r#"(?s)\("([^"]+)", &\[(.*?)\]\),"#).unwrap());
+static CODELIST_RE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(
+        r#"(?ms)^((?://[^\n]*\n|#\[cfg\([^\n]*\n)*)#\[rustfmt::skip\]\npub\(crate\) static (\w+): Codelist = Codelist::new\("\w+", &\[(.*?)\]\);"#,
+    )
+    .unwrap()
+});
+static COLUMN_CODELISTS_RE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?s)const CODELIST_COLUMNS_(\w+): &\[\(&str, &Codelist\)\] = &\[\n(.*?)\];")
+        .unwrap()
+});
+static COLUMN_CODELIST_RE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r#"\("((?:[^"\\]|\\.)*)", &(\w+)\),(?:[ \t]*//[ \t]*([^\n]*))?"#).unwrap()
+});
+
+type Entries = Vec<(String, String)>;
+// The comments and the `#[cfg(...)]` above the codelists (e.g. the URLs of the
+// codelist pages, the features to enable the codelist)
+type Preambles = HashMap<String, String>;
+// The comments after the entries of the codelists of the columns (e.g. `// A28`),
+// keyed by the column id
+type Comments = HashMap<String, String>;
+
+pub(crate) fn generate(spec_dir: &Path, data_dir: &Path) -> Result<(), Box<dyn Error>> {
+    let colnames_path = data_dir.join("colnames.rs");
+    let codelists_path = data_dir.join("codelists.rs");
+
+    // Column names
+
+    let (colnames_src, colnames_crlf) = read_source(&colnames_path)?;
+    let (prefix, block, suffix) = split_colnames_block(&colnames_src)?;
+    let mut groups = parse_groups(block);
+    // The KSJ id, the column id, and the file name of the codelist page
+    let mut codelist_links: Vec<(String, String, String)> = Vec::new();
+
+    for path in list_files(spec_dir)? {
+        let Some(ksj_id) = file_name(&path).and_then(ksj_id_from_filename) else {
+            continue;
+        };
+        let columns = parse_columns(&fs::read_to_string(&path)?);
+        if columns.is_empty() {
+            eprintln!("[WARN] No columns found in {}", path.display());
+            continue;
+        }
+        eprintln!("{ksj_id}: {} columns", columns.len());
+
+        let mut colnames = Vec::with_capacity(columns.len());
+        for column in columns {
+            if let Some(codelist) = column.codelist {
+                codelist_links.push((ksj_id.clone(), column.id.clone(), codelist));
+            }
+            colnames.push((column.id, column.name));
+        }
+        merge_group(&mut groups, ksj_id, colnames);
+    }
+
+    let colnames_src = format!("{prefix}{}{suffix}", render_colnames(&groups)?);
+    write_source(&colnames_path, &colnames_src, colnames_crlf)?;
+
+    // Codelists
+
+    let (codelists_src, codelists_crlf) = read_source(&codelists_path)?;
+    let (mut codelists, preambles) = parse_codelists(&codelists_src);
+    let (mut column_codelists, comments) = parse_column_codelists(&codelists_src);
+
+    let codelist_dir = spec_dir.join("codelist");
+    if codelist_dir.is_dir() {
+        for path in list_files(&codelist_dir)? {
+            let Some(name) = file_name(&path).and_then(codelist_name_from_filename) else {
+                continue;
+            };
+            let codes = parse_codelist(&fs::read_to_string(&path)?);
+            if codes.is_empty() {
+                eprintln!("[WARN] No codes found in {}", path.display());
+                continue;
+            }
+            eprintln!("{name}: {} codes", codes.len());
+            merge_group(&mut codelists, name, codes);
+        }
+    }
+
+    // The codelists of the columns
+
+    for (ksj_id, col_id, filename) in codelist_links {
+        let Some(name) = codelist_name_from_filename(&filename) else {
+            continue;
+        };
+        // Use the existing name of the codelist
+        let Some((name, _)) = codelists
+            .iter()
+            .find(|(n, _)| normalize_name(n) == normalize_name(&name))
+        else {
+            eprintln!("[WARN] The codelist of {col_id} is not found: {filename}");
+            continue;
+        };
+        let category = category_of(&ksj_id).ok_or_else(|| unknown_category(&ksj_id))?;
+        merge_group(
+            &mut column_codelists,
+            category.to_string(),
+            vec![(col_id, name.clone())],
+        );
+    }
+
+    let codelists_src = format!(
+        "{}{}",
+        render_codelists(&codelists, &preambles, &column_codelists),
+        render_column_codelists(&column_codelists, &comments)?
+    );
+    write_source(&codelists_path, &codelists_src, codelists_crlf)?;
+
+    Ok(())
+}
+
+fn list_files(dir: &Path) -> Result<Vec<PathBuf>, Box<dyn Error>> {
+    let mut files = Vec::new();
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.is_file() {
+            files.push(path);
+        }
+    }
+    // Sort to make the result reproducible
+    files.sort();
+    Ok(files)
+}
+
+fn file_name(path: &Path) -> Option<&str> {
+    path.file_name().and_then(|f| f.to_str())
+}
+
+// Returns the source with LF, and whether the original file used CRLF.
+fn read_source(path: &Path) -> Result<(String, bool), Box<dyn Error>> {
+    let src = fs::read_to_string(path)?;
+    let crlf = src.contains("\r\n");
+    Ok((src.replace("\r\n", "\n"), crlf))
+}
+
+fn write_source(path: &Path, src: &str, crlf: bool) -> Result<(), Box<dyn Error>> {
+    let src = if crlf {
+        src.replace('\n', "\r\n")
+    } else {
+        src.to_string()
+    };
+    fs::write(path, src)?;
+    Ok(())
+}
+
+/// Split colnames.rs into the part before the generated part, the generated
+/// part, and the part after it (the hand-written column names of A42, L01, etc.)
+fn split_colnames_block(src: &str) -> Result<(&str, &str, &str), Box<dyn Error>> {
+    let start = src
+        .find(GENERATED_NOTE)
+        .ok_or("The generated part is not found in colnames.rs")?;
+    let end = src[start..]
+        .find(GENERATED_END)
+        .map(|i| start + i + GENERATED_END.len())
+        .ok_or("The end of the generated part is not found in colnames.rs")?;
+
+    Ok((&src[..start], &src[start..end], &src[end..]))
+}
+
+fn unknown_category(ksj_id: &str) -> Box<dyn Error> {
+    format!("Unknown category of KSJ id: {ksj_id}").into()
+}
+
+// The names are compared ignoring the case and the underscores so that the
+// existing names like `WelfareFacMiclassCdH23` are reused as they are.
+fn normalize_name(name: &str) -> String {
+    name.replace('_', "").to_ascii_uppercase()
+}
+
+fn parse_entries(src: &str) -> Entries {
+    ENTRY_RE
+        .captures_iter(src)
+        .map(|caps| (unescape(&caps[1]), unescape(&caps[2])))
+        .collect()
+}
+
+fn parse_groups(block: &str) -> Vec<(String, Entries)> {
+    GROUP_RE
+        .captures_iter(block)
+        .map(|caps| (caps[1].to_string(), parse_entries(&caps[2])))
+        .collect()
+}
+
+fn parse_codelists(src: &str) -> (Vec<(String, Entries)>, Preambles) {
+    let mut codelists = Vec::new();
+    let mut preambles = Preambles::new();
+    for caps in CODELIST_RE.captures_iter(src) {
+        let name = caps[2].to_string();
+        if !caps[1].is_empty() {
+            preambles.insert(name.clone(), caps[1].to_string());
+        }
+        codelists.push((name, parse_entries(&caps[3])));
+    }
+    (codelists, preambles)
+}
+
+fn parse_column_codelists(src: &str) -> (Vec<(String, Entries)>, Comments) {
+    let mut column_codelists = Vec::new();
+    let mut comments = Comments::new();
+    for caps in COLUMN_CODELISTS_RE.captures_iter(src) {
+        let category = caps[1].to_ascii_lowercase();
+        let mut entries = Entries::new();
+        for entry in COLUMN_CODELIST_RE.captures_iter(&caps[2]) {
+            let col_id = unescape(&entry[1]);
+            if let Some(comment) = entry.get(3) {
+                comments.insert(col_id.clone(), comment.as_str().trim_end().to_string());
+            }
+            entries.push((col_id, entry[2].to_string()));
+        }
+        column_codelists.push((category, entries));
+    }
+    (column_codelists, comments)
+}
+
+/// Merge the entries into the group of the same name. The existing entries
+/// keep their order and the new ones are appended. The groups are matched
+/// by [`normalize_name`].
+fn merge_group(groups: &mut Vec<(String, Entries)>, name: String, entries: Entries) {
+    let Some((_, existing)) = groups
+        .iter_mut()
+        .find(|(n, _)| normalize_name(n) == normalize_name(&name))
+    else {
+        groups.push((name, entries));
+        return;
+    };
+
+    for (key, value) in entries {
+        match existing.iter_mut().find(|(k, _)| *k == key) {
+            Some((_, v)) => *v = value,
+            None => existing.push((key, value)),
+        }
+    }
+}
+
+fn render_colnames(groups: &[(String, Entries)]) -> Result<String, Box<dyn Error>> {
+    for (ksj_id, _) in groups {
+        if category_of(ksj_id).is_none() {
+            return Err(unknown_category(ksj_id));
+        }
+    }
+
+    let mut out = String::from(GENERATED_NOTE);
+    out.push_str(
+        "\n// The pair of the KSJ id and its column names\ntype Colnames = (&'static str, &'static [(&'static str, &'static str)]);\n",
+    );
+
+    for (category, _) in CATEGORIES {
+        let name = format!("COLNAMES_{}", category.to_ascii_uppercase());
+        let cfg =
+            format!("#[cfg(all(feature = \"dict-colnames\", feature = \"dict-{category}\"))]\n");
+        out.push('\n');
+        out.push_str(&cfg);
+        out.push_str("#[rustfmt::skip]\n");
+        out.push_str(&format!("const {name}: &[Colnames] = &[\n"));
+
+        // Sort by the KSJ id so that the column names of a KSJ id can be
+        // looked up by binary search
+        let mut groups: Vec<&(String, Entries)> = groups
+            .iter()
+            .filter(|(ksj_id, _)| category_of(ksj_id) == Some(category))
+            .collect();
+        groups.sort_by(|(a, _), (b, _)| a.as_bytes().cmp(b.as_bytes()));
+        for (i, (ksj_id, entries)) in groups.into_iter().enumerate() {
+            if i > 0 {
+                out.push('\n');
+            }
+            out.push_str(&format!("  (\"{}\", &[\n", escape(ksj_id)));
+            // Sort the columns as well so that the column name can be looked
+            // up by binary search
+            for (col_id, name) in sort_entries(entries) {
+                out.push_str(&format!(
+                    "    (\"{}\", \"{}\"),\n",
+                    escape(&col_id),
+                    escape(&name)
+                ));
+            }
+            out.push_str("  ]),\n");
+        }
+        out.push_str("];\n");
+        out.push_str(&cfg);
+        out.push_str(&format!(
+            "const _: () = assert!(super::is_sorted_by_key({name}) && super::is_each_sorted_by_key({name}));\n"
+        ));
+    }
+
+    out.push_str("\n// The column names of the enabled categories\n");
+    out.push_str("#[rustfmt::skip]\n");
+    out.push_str("pub(crate) static COLNAMES: &[&[Colnames]] = &[\n");
+    for (category, _) in CATEGORIES {
+        out.push_str(&format!(
+            "    #[cfg(all(feature = \"dict-colnames\", feature = \"dict-{category}\"))]\n"
+        ));
+        out.push_str(&format!(
+            "    COLNAMES_{},\n",
+            category.to_ascii_uppercase()
+        ));
+    }
+    out.push_str("];\n");
+
+    out.push_str(concat!(
+        "\n",
+        "/// Look up the column names of the KSJ id in the enabled categories\n",
+        "pub(crate) fn lookup_colnames(ksj_id: &str) -> Option<&'static [(&'static str, &'static str)]> {\n",
+        "    COLNAMES\n",
+        "        .iter()\n",
+        "        .find_map(|groups| super::search(groups, ksj_id))\n",
+        "}\n",
+        "\n",
+        "/// Look up the column name in all the KSJ ids of the enabled categories. If\n",
+        "/// the same column id appears in more than one KSJ id, the last one wins.\n",
+        "pub(crate) fn lookup_colname(col_id: &str) -> Option<&'static str> {\n",
+        "    COLNAMES\n",
+        "        .iter()\n",
+        "        .flat_map(|groups| groups.iter())\n",
+        "        .rev()\n",
+        "        .find_map(|(_, columns)| super::search(columns, col_id))\n",
+        "}\n",
+    ));
+    out.push_str(GENERATED_END);
+
+    Ok(out)
+}
+
+fn render_codelists(
+    codelists: &[(String, Entries)],
+    preambles: &Preambles,
+    column_codelists: &[(String, Entries)],
+) -> String {
+    let mut out = String::from(GENERATED_NOTE);
+    out.push_str("use crate::translate::codelists::Codelist;\n");
+
+    for (name, entries) in codelists {
+        out.push('\n');
+        let preamble = preambles.get(name).map(String::as_str);
+        for line in preamble.unwrap_or_default().lines() {
+            if !line.starts_with("#[cfg(") {
+                out.push_str(line);
+                out.push('\n');
+            }
+        }
+        if let Some(cfg) = codelist_cfg(name, preamble, column_codelists) {
+            out.push_str(&cfg);
+            out.push('\n');
+        }
+        out.push_str("#[rustfmt::skip]\n");
+        out.push_str(&format!(
+            "pub(crate) static {name}: Codelist = Codelist::new(\"{name}\", &[\n"
+        ));
+        for (code, label) in sort_entries(entries) {
+            out.push_str(&format!(
+                "    (\"{}\", \"{}\"),\n",
+                escape(&code),
+                escape(&label)
+            ));
+        }
+        out.push_str("]);\n");
+    }
+
+    let mut names: Vec<&str> = codelists.iter().map(|(name, _)| name.as_str()).collect();
+    names.sort_by(|a, b| a.as_bytes().cmp(b.as_bytes()));
+
+    out.push_str(
+        "\n// All the codelists of the enabled categories with their names, sorted by the name\n",
+    );
+    out.push_str("#[rustfmt::skip]\n");
+    out.push_str("pub(crate) static CODELISTS: &[(&str, &Codelist)] = &[\n");
+    for name in names {
+        // Use the same cfg as the codelist
+        let preamble = preambles.get(name).map(String::as_str);
+        if let Some(cfg) = codelist_cfg(name, preamble, column_codelists) {
+            out.push_str(&format!("    {cfg}\n"));
+        }
+        out.push_str(&format!("    (\"{name}\", &{name}),\n"));
+    }
+    out.push_str("];\n");
+    out.push_str("const _: () = assert!(super::is_sorted_by_key(CODELISTS));\n");
+    out
+}
+
+/// The `#[cfg(...)]` of the codelist, derived from the categories of the columns
+/// that use it. The codelists that no column uses (e.g. the ones selected by the
+/// year or the shapefile name) keep the cfg in the preamble.
+fn codelist_cfg(
+    name: &str,
+    preamble: Option<&str>,
+    column_codelists: &[(String, Entries)],
+) -> Option<String> {
+    let features: Vec<String> = CATEGORIES
+        .iter()
+        .filter(|(category, _)| {
+            column_codelists
+                .iter()
+                .filter(|(c, _)| c == category)
+                .flat_map(|(_, entries)| entries)
+                .any(|(_, codelist)| codelist == name)
+        })
+        .map(|(category, _)| format!("feature = \"dict-{category}\""))
+        .collect();
+
+    match features.as_slice() {
+        [] => preamble?
+            .lines()
+            .find(|l| l.starts_with("#[cfg("))
+            .map(str::to_string),
+        [feature] => Some(format!("#[cfg({feature})]")),
+        _ => Some(format!("#[cfg(any({}))]", features.join(", "))),
+    }
+}
+
+fn render_column_codelists(
+    column_codelists: &[(String, Entries)],
+    comments: &Comments,
+) -> Result<String, Box<dyn Error>> {
+    for (category, _) in column_codelists {
+        if CATEGORIES.iter().all(|(c, _)| c != category) {
+            return Err(format!("Unknown category: {category}").into());
+        }
+    }
+
+    let mut out = String::from(
+        "\n// The codelists of the columns, sorted by the column name. The codelists of the\n// disabled categories are not included.\n",
+    );
+    for (category, _) in CATEGORIES {
+        let name = format!("CODELIST_COLUMNS_{}", category.to_ascii_uppercase());
+        let cfg = format!("#[cfg(feature = \"dict-{category}\")]\n");
+
+        out.push('\n');
+        out.push_str(&cfg);
+        out.push_str("#[rustfmt::skip]\n");
+        out.push_str(&format!(
+            "pub(crate) const {name}: &[(&str, &Codelist)] = &[\n"
+        ));
+        let entries = column_codelists
+            .iter()
+            .filter(|(c, _)| c == category)
+            .flat_map(|(_, entries)| entries);
+        for (col_id, codelist) in sort_entries(entries) {
+            out.push_str(&format!("    (\"{}\", &{codelist}),", escape(&col_id)));
+            if let Some(comment) = comments.get(&col_id) {
+                out.push_str(&format!(" // {comment}"));
+            }
+            out.push('\n');
+        }
+        out.push_str("];\n");
+        out.push_str(&cfg);
+        out.push_str(&format!(
+            "const _: () = assert!(super::is_sorted_by_key({name}));\n"
+        ));
+    }
+    Ok(out)
+}
+
+/// Sort the entries by the key (in byte order, which is what the binary
+/// search at runtime expects) and remove the duplicated keys. When a key is
+/// duplicated, the last one wins.
+fn sort_entries<'a>(entries: impl IntoIterator<Item = &'a (String, String)>) -> Entries {
+    let mut sorted: Entries = Vec::new();
+    for (key, value) in entries {
+        match sorted.iter_mut().find(|(k, _)| k == key) {
+            Some((_, v)) => *v = value.clone(),
+            None => sorted.push((key.clone(), value.clone())),
+        }
+    }
+    sorted.sort_by(|(a, _), (b, _)| a.as_bytes().cmp(b.as_bytes()));
+    sorted
+}
+
+fn escape(s: &str) -> String {
+    s.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+fn unescape(s: &str) -> String {
+    s.replace("\\\"", "\"").replace("\\\\", "\\")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entries(x: &[(&str, &str)]) -> Entries {
+        x.iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn test_merge_group() {
+        let mut groups = vec![(
+            "MedClassCd".to_string(),
+            entries(&[("1", "病院"), ("2", "?")]),
+        )];
+
+        merge_group(
+            &mut groups,
+            "MED_CLASS_CD".to_string(),
+            entries(&[("2", "診療所"), ("3", "歯科診療所")]),
+        );
+        merge_group(
+            &mut groups,
+            "DIRECTION".to_string(),
+            entries(&[("1", "上り")]),
+        );
+
+        let expected = vec![
+            (
+                "MedClassCd".to_string(),
+                entries(&[("1", "病院"), ("2", "診療所"), ("3", "歯科診療所")]),
+            ),
+            ("DIRECTION".to_string(), entries(&[("1", "上り")])),
+        ];
+        assert_eq!(groups, expected);
+    }
+
+    #[test]
+    fn test_sort_entries() {
+        let unsorted = entries(&[("2", "a"), ("10", "b"), ("1", "c"), ("2", "d")]);
+        let expected = entries(&[("1", "c"), ("10", "b"), ("2", "d")]);
+        assert_eq!(sort_entries(&unsorted), expected);
+    }
+
+    #[test]
+    fn test_colnames_roundtrip() {
+        let groups = vec![
+            ("A03".to_string(), entries(&[("A03_001", "行政区域コード")])),
+            (
+                "A55".to_string(),
+                entries(&[
+                    ("FAR", "容積率"),
+                    ("BCR", "建蔽率"),
+                    ("YoutoName", "用途名"),
+                ]),
+            ),
+            (
+                "P04".to_string(),
+                entries(&[("P04_001", "医療機関分類"), ("P04_002", "施設\"名称\"")]),
+            ),
+        ];
+        let src = format!(
+            "{}\npub(crate) const A42_COLNAMES_NORMAL: [&str; 0] = [];\n",
+            render_colnames(&groups).unwrap()
+        );
+
+        let (prefix, block, suffix) = split_colnames_block(&src).unwrap();
+        assert_eq!(prefix, "");
+        assert_eq!(
+            suffix,
+            "\npub(crate) const A42_COLNAMES_NORMAL: [&str; 0] = [];\n"
+        );
+
+        // The columns are sorted by the column id
+        let mut expected = groups.clone();
+        expected[1].1 = sort_entries(&groups[1].1);
+        assert_eq!(parse_groups(block), expected);
+        assert!(block.contains(concat!(
+            "  (\"A55\", &[\n",
+            "    (\"BCR\", \"建蔽率\"),\n",
+            "    (\"FAR\", \"容積率\"),\n",
+            "    (\"YoutoName\", \"用途名\"),\n",
+            "  ]),\n",
+        )));
+        assert!(block.contains(
+            "#[cfg(all(feature = \"dict-colnames\", feature = \"dict-facility\"))]\n#[rustfmt::skip]\nconst COLNAMES_FACILITY"
+        ));
+
+        let unknown = vec![("X01".to_string(), entries(&[("X01_001", "?")]))];
+        assert!(render_colnames(&unknown).is_err());
+    }
+
+    #[test]
+    fn test_codelists_roundtrip() {
+        let codelists = vec![
+            (
+                "MED_CLASS_CD".to_string(),
+                entries(&[("1", "病院"), ("2", "診療所")]),
+            ),
+            ("DIRECTION".to_string(), entries(&[("1", "上り")])),
+            ("BUS_CLASS_CD".to_string(), entries(&[("1", "路線バス")])),
+        ];
+        let preambles = Preambles::from([
+            (
+                "DIRECTION".to_string(),
+                "// https://nlftp.mlit.go.jp/ksj/gml/codelist/Direction.html\n#[cfg(feature = \"dict-transport\")]\n".to_string(),
+            ),
+            // The cfg of the preamble is replaced with the one derived from the columns
+            (
+                "MED_CLASS_CD".to_string(),
+                "#[cfg(feature = \"dict-policy\")]\n".to_string(),
+            ),
+        ]);
+        let column_codelists = vec![
+            (
+                "transport".to_string(),
+                entries(&[("N07_001", "BUS_CLASS_CD")]),
+            ),
+            (
+                "facility".to_string(),
+                entries(&[("P04_001", "MED_CLASS_CD"), ("P11_003", "BUS_CLASS_CD")]),
+            ),
+        ];
+        let src = render_codelists(&codelists, &preambles, &column_codelists);
+        assert!(src.contains(concat!(
+            "pub(crate) static CODELISTS: &[(&str, &Codelist)] = &[\n",
+            "    #[cfg(any(feature = \"dict-transport\", feature = \"dict-facility\"))]\n",
+            "    (\"BUS_CLASS_CD\", &BUS_CLASS_CD),\n",
+            "    #[cfg(feature = \"dict-transport\")]\n",
+            "    (\"DIRECTION\", &DIRECTION),\n",
+            "    #[cfg(feature = \"dict-facility\")]\n",
+            "    (\"MED_CLASS_CD\", &MED_CLASS_CD),\n",
+            "];\n",
+        )));
+
+        let mut expected_preambles = preambles.clone();
+        expected_preambles.insert(
+            "MED_CLASS_CD".to_string(),
+            "#[cfg(feature = \"dict-facility\")]\n".to_string(),
+        );
+        expected_preambles.insert(
+            "BUS_CLASS_CD".to_string(),
+            "#[cfg(any(feature = \"dict-transport\", feature = \"dict-facility\"))]\n".to_string(),
+        );
+        assert_eq!(parse_codelists(&src), (codelists, expected_preambles));
+    }
+
+    #[test]
+    fn test_column_codelists_roundtrip() {
+        let column_codelists = vec![
+            (
+                "policy".to_string(),
+                entries(&[("A03_007", "A03_SECTION_CD"), ("WHC", "WORLD_HERITAGE_CD")]),
+            ),
+            (
+                "facility".to_string(),
+                entries(&[("P04_001", "MED_CLASS_CD")]),
+            ),
+        ];
+        let comments = Comments::from([("WHC".to_string(), "A28".to_string())]);
+        let src = render_column_codelists(&column_codelists, &comments).unwrap();
+        assert!(src.contains(concat!(
+            "#[cfg(feature = \"dict-policy\")]\n",
+            "#[rustfmt::skip]\n",
+            "pub(crate) const CODELIST_COLUMNS_POLICY: &[(&str, &Codelist)] = &[\n",
+            "    (\"A03_007\", &A03_SECTION_CD),\n",
+            "    (\"WHC\", &WORLD_HERITAGE_CD), // A28\n",
+            "];\n",
+        )));
+
+        // The empty tables are also rendered
+        let (parsed, parsed_comments) = parse_column_codelists(&src);
+        let expected: Vec<(String, Entries)> = CATEGORIES
+            .iter()
+            .map(|(category, _)| {
+                let entries = column_codelists
+                    .iter()
+                    .find(|(c, _)| c == category)
+                    .map(|(_, entries)| entries.clone())
+                    .unwrap_or_default();
+                (category.to_string(), entries)
+            })
+            .collect();
+        assert_eq!(parsed, expected);
+        assert_eq!(parsed_comments, comments);
+
+        let unknown = vec![("unknown".to_string(), entries(&[("X01_001", "?")]))];
+        assert!(render_column_codelists(&unknown, &Comments::new()).is_err());
+    }
+}
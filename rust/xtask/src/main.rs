@@ -0,0 +1,50 @@
+use std::path::PathBuf;
+
+use clap::{Parser, Subcommand};
+
+#[path = "../../src/translate/categories.rs"]
+mod categories;
+mod generate;
+mod spec;
+
+#[derive(Parser, Debug)]
+#[command(about = "Development tasks for ksj2gp", long_about = None)]
+struct Args {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// Update the dictionaries (column names and codelists) from the KSJ spec files
+    ///
+    /// The spec directory is expected to contain the locally saved spec pages
+    /// (e.g. `KsjTmplt-P04-v3_0.html`) and the codelist pages under `codelist/`
+    /// (e.g. `codelist/MedClassCd.html`). The entries already in the data
+    /// modules are kept, so it's fine to save only the pages of new releases.
+    GenDict {
+        /// Directory of the saved KSJ spec files
+        spec_dir: PathBuf,
+
+        /// Directory of the data modules (default: `src/translate/data`)
+        #[arg(long)]
+        data_dir: Option<PathBuf>,
+    },
+}
+
+fn default_data_dir() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("../src/translate/data")
+}
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let args = Args::parse();
+
+    match args.command {
+        Command::GenDict { spec_dir, data_dir } => {
+            let data_dir = data_dir.unwrap_or_else(default_data_dir);
+            generate::generate(&spec_dir, &data_dir)?;
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,238 @@
+//! Parser of the KSJ spec pages
+//!
+//! The spec pages are not well-formed enough to rely on an XML parser, and we
+//! only need the cells of the tables, so this scans `<tr>` and `<td>` with
+//! regexes.
+
+use std::sync::LazyLock;
+
+use regex::Regex;
+
+static ROW_RE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"(?is)<tr[^>]*>(.*?)</tr>").unwrap());
+static CELL_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(?is)<t([dh])[^>]*>(.*?)</t[dh]>").unwrap());
+static TAG_RE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"(?s)<[^>]*>").unwrap());
+static SPACE_RE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"\s+").unwrap());
+// e.g. `<a href="./codelist/MedClassCd.html">`
+static CODELIST_LINK_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r#"(?i)href="[^"]*codelist/([^"/#?]+)""#).unwrap());
+
+// e.g. "医療機関分類（P04_001）", "行政区域コード (A03_001)"
+static COLNAME_RE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"^(.+?)\s*[（(]\s*([A-Za-z][A-Za-z0-9\-]*_[A-Za-z0-9_\-]+)\s*[）)]$").unwrap()
+});
+
+// e.g. "KsjTmplt-P04-v3_0.html", "KsjTmplt-A18s-a.html", "KsjTmplt-N03-2024.html"
+static SPEC_FILENAME_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^KsjTmplt-(.+?)(?:-v\d+_\d+|-\d{4})?\.(?:html?|xml)$").unwrap());
+
+struct Cell {
+    is_header: bool,
+    text: String,
+    // The file names of the codelist pages linked from the cell
+    codelists: Vec<String>,
+}
+
+/// A column found in the spec page
+#[derive(Debug, PartialEq)]
+pub(crate) struct Column {
+    pub(crate) id: String,
+    pub(crate) name: String,
+    /// The file name of the codelist page (e.g. `MedClassCd.html`), if the
+    /// type of the column is a codelist
+    pub(crate) codelist: Option<String>,
+}
+
+fn parse_rows(html: &str) -> Vec<Vec<Cell>> {
+    ROW_RE
+        .captures_iter(html)
+        .map(|row| {
+            CELL_RE
+                .captures_iter(&row[1])
+                .map(|cell| Cell {
+                    is_header: cell[1].eq_ignore_ascii_case("h"),
+                    text: cell_text(&cell[2]),
+                    codelists: CODELIST_LINK_RE
+                        .captures_iter(&cell[2])
+                        .map(|link| link[1].to_string())
+                        .collect(),
+                })
+                .collect()
+        })
+        .collect()
+}
+
+fn cell_text(inner_html: &str) -> String {
+    let text = TAG_RE.replace_all(inner_html, " ");
+    let text = text
+        .replace("&nbsp;", " ")
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&");
+    SPACE_RE.replace_all(text.trim(), " ").into_owned()
+}
+
+/// Extract the KSJ id from the file name of the spec page
+pub(crate) fn ksj_id_from_filename(filename: &str) -> Option<String> {
+    SPEC_FILENAME_RE
+        .captures(filename)
+        .map(|caps| caps[1].to_string())
+}
+
+/// Convert the file name of the codelist page to the name of the static
+/// (e.g. `MedClassCd.html` to `MED_CLASS_CD`)
+pub(crate) fn codelist_name_from_filename(filename: &str) -> Option<String> {
+    let stem = filename
+        .strip_suffix(".html")
+        .or_else(|| filename.strip_suffix(".htm"))
+        .or_else(|| filename.strip_suffix(".xml"))?;
+
+    let chars: Vec<char> = stem.chars().collect();
+    let mut name = String::with_capacity(stem.len() + 8);
+    for (i, &c) in chars.iter().enumerate() {
+        if c == '-' || c == '.' || c == '_' {
+            name.push('_');
+            continue;
+        }
+
+        if i > 0 {
+            let prev = chars[i - 1];
+            let prev2_is_alpha = i > 1 && chars[i - 2].is_ascii_alphabetic();
+            let next_is_lower = chars.get(i + 1).is_some_and(|c| c.is_ascii_lowercase());
+            // e.g. "ClassCd" -> "CLASS_CD", "Cd09" -> "CD_09", "A10Area" -> "A10_AREA",
+            // but "v3" -> "V3" and "L01V1" -> "L01V1"
+            let boundary = (prev.is_ascii_lowercase() && c.is_ascii_uppercase())
+                || (prev.is_ascii_lowercase() && c.is_ascii_digit() && prev2_is_alpha)
+                || (prev.is_ascii_digit() && c.is_ascii_uppercase() && next_is_lower);
+            if boundary && !name.ends_with('_') {
+                name.push('_');
+            }
+        }
+        name.push(c.to_ascii_uppercase());
+    }
+
+    Some(name)
+}
+
+/// Extract the columns from the spec page
+pub(crate) fn parse_columns(html: &str) -> Vec<Column> {
+    let mut columns: Vec<Column> = Vec::new();
+    for row in parse_rows(html) {
+        for (i, cell) in row.iter().enumerate().filter(|(_, c)| !c.is_header) {
+            let Some(caps) = COLNAME_RE.captures(&cell.text) else {
+                continue;
+            };
+            let col_id = caps[2].to_string();
+            // e.g. "場所（GM_Point）"
+            if col_id.starts_with("GM_") {
+                continue;
+            }
+            if columns.iter().all(|c| c.id != col_id) {
+                // The type of the column is described in the cells after the
+                // column name (e.g. "コードリスト「医療機関分類コード」")
+                let codelist = row[i + 1..]
+                    .iter()
+                    .find_map(|c| c.codelists.first().cloned());
+                columns.push(Column {
+                    id: col_id,
+                    name: caps[1].to_string(),
+                    codelist,
+                });
+            }
+            // one column per row
+            break;
+        }
+    }
+    columns
+}
+
+/// Extract the pairs of the code and the label from the codelist page
+pub(crate) fn parse_codelist(html: &str) -> Vec<(String, String)> {
+    let mut codes: Vec<(String, String)> = Vec::new();
+    let mut in_table = false;
+    for row in parse_rows(html) {
+        if row.len() < 2 {
+            continue;
+        }
+
+        // The header row is either <th> or the first cell is "コード"
+        if row[0].is_header || row[0].text == "コード" {
+            in_table = row[0].text.contains("コード");
+            continue;
+        }
+
+        let (code, label) = (&row[0].text, &row[1].text);
+        if in_table && !code.is_empty() && codes.iter().all(|(c, _)| c != code) {
+            codes.push((code.clone(), label.clone()));
+        }
+    }
+    codes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_filenames() {
+        assert_eq!(
+            ksj_id_from_filename("KsjTmplt-P04-v3_0.html").as_deref(),
+            Some("P04")
+        );
+        assert_eq!(
+            ksj_id_from_filename("KsjTmplt-A18s-a.html").as_deref(),
+            Some("A18s-a")
+        );
+        assert_eq!(
+            ksj_id_from_filename("KsjTmplt-N03-2024.html").as_deref(),
+            Some("N03")
+        );
+        assert_eq!(ksj_id_from_filename("index.html"), None);
+
+        let name = |f: &str| codelist_name_from_filename(f).unwrap();
+        assert_eq!(name("MedClassCd.html"), "MED_CLASS_CD");
+        assert_eq!(name("A10AreaCd.html"), "A10_AREA_CD");
+        assert_eq!(name("LandUseCd09-u.html"), "LAND_USE_CD_09_U");
+        assert_eq!(
+            name("SelectLandStatusL01V1.html"),
+            "SELECT_LAND_STATUS_L01V1"
+        );
+        assert_eq!(name("TokusyudojyoCd_v3_0.html"), "TOKUSYUDOJYO_CD_V3_0");
+    }
+
+    #[test]
+    fn test_parse_columns() {
+        let html = include_str!("../fixtures/KsjTmplt-P04-v3_0.html");
+        let expected = [
+            ("P04_001", "医療機関分類", Some("MedClassCd.html")),
+            ("P04_002", "施設名称", None),
+            ("P04_003", "所在地", None),
+            ("P04_004", "診療科目１", None),
+            ("P04_007", "開設者分類", Some("EstClassCd.html")),
+            ("P04_008", "病床数", None),
+            ("P04_010", "救急告示病院", None),
+        ];
+        let expected: Vec<Column> = expected
+            .iter()
+            .map(|(id, name, codelist)| Column {
+                id: id.to_string(),
+                name: name.to_string(),
+                codelist: codelist.map(|c| c.to_string()),
+            })
+            .collect();
+        assert_eq!(parse_columns(html), expected);
+    }
+
+    #[test]
+    fn test_parse_codelist() {
+        let html = include_str!("../fixtures/codelist/MedClassCd.html");
+        let expected = [("1", "病院"), ("2", "診療所"), ("3", "歯科診療所")];
+        let expected: Vec<(String, String)> = expected
+            .iter()
+            .map(|(code, label)| (code.to_string(), label.to_string()))
+            .collect();
+        assert_eq!(parse_codelist(html), expected);
+    }
+}
@@ -13,7 +13,40 @@ export type WorkerRequest = {
 	targetShp?: string;
 };
 
-export type OutputFormat = 'GeoParquet' | 'GeoJson' | 'Gpkg';
+export type OutputFormat =
+	| 'GeoParquet'
+	| 'GeoJson'
+	| 'GeoJsonSeq'
+	| 'Gpkg'
+	| 'FlatGeobuf'
+	| 'Csv'
+	| 'ArrowIpc'
+	| 'Shapefile'
+	| 'Kml'
+	| 'Kmz'
+	| 'PmTiles'
+	| 'TopoJson'
+	| 'PostgisSql';
+
+// Values that couldn't be translated during the conversion. The keys are the
+// original column names.
+export type TranslationReport = {
+	untranslated_columns: string[];
+	unknown_codes: Record<string, Record<string, number>>;
+	unexpected_values: Record<string, number>;
+	// Values that didn't match the codelist (only when the codelist validation
+	// is in the reject mode)
+	rejects: RejectedValue[];
+};
+
+export type RejectedValue = {
+	// 0-based index of the record in the shapefile
+	row: number;
+	// Original column name
+	column: string;
+	value: string;
+	reason: 'unknown_code' | 'unexpected_type' | 'lossy_number';
+};
 
 export type ResultFile = {
 	handle: FileSystemFileHandle;
@@ -28,4 +61,6 @@ export type WorkerResponse = {
 	shpFileCandidates?: string[];
 	// Result file (e.g. GeoParquet)
 	output?: ResultFile;
+	// Summary of the untranslated columns and values
+	report?: TranslationReport;
 };
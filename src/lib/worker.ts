@@ -1,5 +1,5 @@
 import { convert_shp, IntermediateFiles, list_shp_files } from 'ksj2gp';
-import type { OutputFormat, WorkerRequest, WorkerResponse } from './types';
+import type { OutputFormat, TranslationReport, WorkerRequest, WorkerResponse } from './types';
 
 function postTypedMessage(message: WorkerResponse) {
 	postMessage(message);
@@ -26,8 +26,28 @@ function getOutputFilename(x: string, outputFormat: OutputFormat): string {
 		ext = 'parquet';
 	} else if (outputFormat === 'GeoJson') {
 		ext = 'geojson';
+	} else if (outputFormat === 'GeoJsonSeq') {
+		ext = 'geojsons';
 	} else if (outputFormat === 'Gpkg') {
 		ext = 'gpkg';
+	} else if (outputFormat === 'FlatGeobuf') {
+		ext = 'fgb';
+	} else if (outputFormat === 'Csv') {
+		ext = 'csv';
+	} else if (outputFormat === 'ArrowIpc') {
+		ext = 'arrow';
+	} else if (outputFormat === 'Shapefile') {
+		ext = 'zip';
+	} else if (outputFormat === 'Kml') {
+		ext = 'kml';
+	} else if (outputFormat === 'Kmz') {
+		ext = 'kmz';
+	} else if (outputFormat === 'PmTiles') {
+		ext = 'pmtiles';
+	} else if (outputFormat === 'TopoJson') {
+		ext = 'topojson';
+	} else if (outputFormat === 'PostgisSql') {
+		ext = 'sql';
 	}
 
 	const start = x.lastIndexOf('/') + 1;
@@ -77,7 +97,7 @@ self.onmessage = async (event: MessageEvent<WorkerRequest>) => {
 	const intermediateFiles = new IntermediateFiles(shp, dbf, shx);
 
 	try {
-		convert_shp(
+		const report: TranslationReport = convert_shp(
 			file,
 			targetShp,
 			intermediateFiles,
@@ -88,7 +108,7 @@ self.onmessage = async (event: MessageEvent<WorkerRequest>) => {
 			ignoreTranslationErrors
 		);
 		const filename = getOutputFilename(targetShp, outputFormat);
-		postTypedMessage({ output: { handle: outputFileHandle, filename } });
+		postTypedMessage({ output: { handle: outputFileHandle, filename }, report });
 	} catch (e: unknown) {
 		const msg =
 			typeof e === 'string'